@@ -19,6 +19,7 @@
             CssPropertyType::LineHeight => CssProperty::LineHeight(StyleLineHeightValue::$content_type),
             CssPropertyType::WordSpacing => CssProperty::WordSpacing(StyleWordSpacingValue::$content_type),
             CssPropertyType::TabWidth => CssProperty::TabWidth(StyleTabWidthValue::$content_type),
+            CssPropertyType::Hyphens => CssProperty::Hyphens(StyleHyphensValue::$content_type),
             CssPropertyType::Cursor => CssProperty::Cursor(StyleCursorValue::$content_type),
             CssPropertyType::Display => CssProperty::Display(LayoutDisplayValue::$content_type),
             CssPropertyType::Float => CssProperty::Float(LayoutFloatValue::$content_type),
@@ -76,11 +77,24 @@
             CssPropertyType::BoxShadowTop => CssProperty::BoxShadowTop(StyleBoxShadowValue::$content_type),
             CssPropertyType::BoxShadowBottom => CssProperty::BoxShadowBottom(StyleBoxShadowValue::$content_type),
             CssPropertyType::ScrollbarStyle => CssProperty::ScrollbarStyle(ScrollbarStyleValue::$content_type),
+            CssPropertyType::ScrollbarWidth => CssProperty::ScrollbarWidth(StyleScrollbarWidthValue::$content_type),
+            CssPropertyType::ScrollbarColor => CssProperty::ScrollbarColor(StyleScrollbarColorValue::$content_type),
+            CssPropertyType::ScrollbarThumbHoverColor => CssProperty::ScrollbarThumbHoverColor(StyleScrollbarThumbHoverColorValue::$content_type),
+            CssPropertyType::ScrollbarThumbActiveColor => CssProperty::ScrollbarThumbActiveColor(StyleScrollbarThumbActiveColorValue::$content_type),
+            CssPropertyType::WindowDragRegion => CssProperty::WindowDragRegion(StyleWindowDragRegionValue::$content_type),
+            CssPropertyType::CaretAnimationDuration => CssProperty::CaretAnimationDuration(StyleCaretAnimationDurationValue::$content_type),
+            CssPropertyType::ScrollPadding => CssProperty::ScrollPadding(StyleScrollPaddingValue::$content_type),
+            CssPropertyType::Content => CssProperty::Content(StyleContentValue::$content_type),
+            CssPropertyType::CounterReset => CssProperty::CounterReset(StyleCounterResetValue::$content_type),
+            CssPropertyType::CounterIncrement => CssProperty::CounterIncrement(StyleCounterIncrementValue::$content_type),
+            CssPropertyType::OverflowAnchor => CssProperty::OverflowAnchor(StyleOverflowAnchorValue::$content_type),
             CssPropertyType::Opacity => CssProperty::Opacity(StyleOpacityValue::$content_type),
             CssPropertyType::Transform => CssProperty::Transform(StyleTransformVecValue::$content_type),
             CssPropertyType::PerspectiveOrigin => CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::$content_type),
             CssPropertyType::TransformOrigin => CssProperty::TransformOrigin(StyleTransformOriginValue::$content_type),
             CssPropertyType::BackfaceVisibility => CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::$content_type),
+            CssPropertyType::Perspective => CssProperty::Perspective(StylePerspectiveValue::$content_type),
+            CssPropertyType::TransformStyle => CssProperty::TransformStyle(StyleTransformStyleValue::$content_type),
             CssPropertyType::MixBlendMode => CssProperty::MixBlendMode(StyleMixBlendModeValue::$content_type),
             CssPropertyType::Filter => CssProperty::Filter(StyleFilterVecValue::$content_type),
             CssPropertyType::BackdropFilter => CssProperty::BackdropFilter(StyleFilterVecValue::$content_type),
@@ -101,6 +115,7 @@
                 CssProperty::LineHeight(_) => CssPropertyType::LineHeight,
                 CssProperty::WordSpacing(_) => CssPropertyType::WordSpacing,
                 CssProperty::TabWidth(_) => CssPropertyType::TabWidth,
+                CssProperty::Hyphens(_) => CssPropertyType::Hyphens,
                 CssProperty::Cursor(_) => CssPropertyType::Cursor,
                 CssProperty::Display(_) => CssPropertyType::Display,
                 CssProperty::Float(_) => CssPropertyType::Float,
@@ -158,11 +173,24 @@
                 CssProperty::BoxShadowTop(_) => CssPropertyType::BoxShadowTop,
                 CssProperty::BoxShadowBottom(_) => CssPropertyType::BoxShadowBottom,
                 CssProperty::ScrollbarStyle(_) => CssPropertyType::ScrollbarStyle,
+                CssProperty::ScrollbarWidth(_) => CssPropertyType::ScrollbarWidth,
+                CssProperty::ScrollbarColor(_) => CssPropertyType::ScrollbarColor,
+                CssProperty::ScrollbarThumbHoverColor(_) => CssPropertyType::ScrollbarThumbHoverColor,
+                CssProperty::ScrollbarThumbActiveColor(_) => CssPropertyType::ScrollbarThumbActiveColor,
+                CssProperty::WindowDragRegion(_) => CssPropertyType::WindowDragRegion,
+                CssProperty::CaretAnimationDuration(_) => CssPropertyType::CaretAnimationDuration,
+                CssProperty::ScrollPadding(_) => CssPropertyType::ScrollPadding,
+                CssProperty::Content(_) => CssPropertyType::Content,
+                CssProperty::CounterReset(_) => CssPropertyType::CounterReset,
+                CssProperty::CounterIncrement(_) => CssPropertyType::CounterIncrement,
+                CssProperty::OverflowAnchor(_) => CssPropertyType::OverflowAnchor,
                 CssProperty::Opacity(_) => CssPropertyType::Opacity,
                 CssProperty::Transform(_) => CssPropertyType::Transform,
                 CssProperty::PerspectiveOrigin(_) => CssPropertyType::PerspectiveOrigin,
                 CssProperty::TransformOrigin(_) => CssPropertyType::TransformOrigin,
                 CssProperty::BackfaceVisibility(_) => CssPropertyType::BackfaceVisibility,
+                CssProperty::Perspective(_) => CssPropertyType::Perspective,
+                CssProperty::TransformStyle(_) => CssPropertyType::TransformStyle,
                 CssProperty::MixBlendMode(_) => CssPropertyType::MixBlendMode,
                 CssProperty::Filter(_) => CssPropertyType::Filter,
                 CssProperty::BackdropFilter(_) => CssPropertyType::BackdropFilter,
@@ -185,6 +213,7 @@
         pub const fn line_height(input: StyleLineHeight) -> Self { CssProperty::LineHeight(StyleLineHeightValue::Exact(input)) }
         pub const fn word_spacing(input: StyleWordSpacing) -> Self { CssProperty::WordSpacing(StyleWordSpacingValue::Exact(input)) }
         pub const fn tab_width(input: StyleTabWidth) -> Self { CssProperty::TabWidth(StyleTabWidthValue::Exact(input)) }
+        pub const fn hyphens(input: StyleHyphens) -> Self { CssProperty::Hyphens(StyleHyphensValue::Exact(input)) }
         pub const fn cursor(input: StyleCursor) -> Self { CssProperty::Cursor(StyleCursorValue::Exact(input)) }
         pub const fn display(input: LayoutDisplay) -> Self { CssProperty::Display(LayoutDisplayValue::Exact(input)) }
         pub const fn float(input: LayoutFloat) -> Self { CssProperty::Float(LayoutFloatValue::Exact(input)) }
@@ -241,11 +270,24 @@
         pub const fn box_shadow_right(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(input)) }
         pub const fn box_shadow_top(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(input)) }
         pub const fn box_shadow_bottom(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(input)) }
+        pub const fn scrollbar_width(input: StyleScrollbarWidth) -> Self { CssProperty::ScrollbarWidth(StyleScrollbarWidthValue::Exact(input)) }
+        pub const fn scrollbar_color(input: StyleScrollbarColor) -> Self { CssProperty::ScrollbarColor(StyleScrollbarColorValue::Exact(input)) }
+        pub const fn scrollbar_thumb_hover_color(input: StyleScrollbarThumbHoverColor) -> Self { CssProperty::ScrollbarThumbHoverColor(StyleScrollbarThumbHoverColorValue::Exact(input)) }
+        pub const fn scrollbar_thumb_active_color(input: StyleScrollbarThumbActiveColor) -> Self { CssProperty::ScrollbarThumbActiveColor(StyleScrollbarThumbActiveColorValue::Exact(input)) }
+        pub const fn window_drag_region(input: StyleWindowDragRegion) -> Self { CssProperty::WindowDragRegion(StyleWindowDragRegionValue::Exact(input)) }
+        pub const fn caret_animation_duration(input: StyleCaretAnimationDuration) -> Self { CssProperty::CaretAnimationDuration(StyleCaretAnimationDurationValue::Exact(input)) }
+        pub const fn scroll_padding(input: StyleScrollPadding) -> Self { CssProperty::ScrollPadding(StyleScrollPaddingValue::Exact(input)) }
+        pub const fn content(input: StyleContent) -> Self { CssProperty::Content(StyleContentValue::Exact(input)) }
+        pub const fn counter_reset(input: StyleCounterReset) -> Self { CssProperty::CounterReset(StyleCounterResetValue::Exact(input)) }
+        pub const fn counter_increment(input: StyleCounterIncrement) -> Self { CssProperty::CounterIncrement(StyleCounterIncrementValue::Exact(input)) }
+        pub const fn overflow_anchor(input: StyleOverflowAnchor) -> Self { CssProperty::OverflowAnchor(StyleOverflowAnchorValue::Exact(input)) }
         pub const fn opacity(input: StyleOpacity) -> Self { CssProperty::Opacity(StyleOpacityValue::Exact(input)) }
         pub const fn transform(input: StyleTransformVec) -> Self { CssProperty::Transform(StyleTransformVecValue::Exact(input)) }
         pub const fn transform_origin(input: StyleTransformOrigin) -> Self { CssProperty::TransformOrigin(StyleTransformOriginValue::Exact(input)) }
         pub const fn perspective_origin(input: StylePerspectiveOrigin) -> Self { CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::Exact(input)) }
         pub const fn backface_visiblity(input: StyleBackfaceVisibility) -> Self { CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::Exact(input)) }
+        pub const fn perspective(input: StylePerspective) -> Self { CssProperty::Perspective(StylePerspectiveValue::Exact(input)) }
+        pub const fn transform_style(input: StyleTransformStyle) -> Self { CssProperty::TransformStyle(StyleTransformStyleValue::Exact(input)) }
         pub const fn mix_blend_mode(input: StyleMixBlendMode) -> Self { CssProperty::MixBlendMode(StyleMixBlendModeValue::Exact(input)) }
         pub const fn filter(input: StyleFilterVec) -> Self { CssProperty::Filter(StyleFilterVecValue::Exact(input)) }
         pub const fn backdrop_filter(input: StyleFilterVec) -> Self { CssProperty::BackdropFilter(StyleFilterVecValue::Exact(input)) }
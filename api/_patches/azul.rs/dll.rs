@@ -12,6 +12,7 @@
     }
 
     unsafe impl Send for AzThreadSender { }
+    unsafe impl Send for AzEventLoopProxy { }
 
     impl ::core::fmt::Debug for AzCallback                          { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
     
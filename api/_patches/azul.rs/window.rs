@@ -81,4 +81,86 @@
             b_x + b_width <= a_x + a_width &&
             b_y + b_height <= a_y + a_height
         }
-    }
\ No newline at end of file
+    }
+    /// Allows passing the window to crates using `raw-window-handle` v0.6
+    /// (i.e. `wgpu` surfaces or `rfd` dialogs) without unsafe pointer conversions
+    #[cfg(feature = "raw-window-handle")]
+    impl raw_window_handle::HasWindowHandle for RawWindowHandle {
+        fn window_handle(&self) -> Result<raw_window_handle::WindowHandle<'_>, raw_window_handle::HandleError> {
+
+            use core::num::{NonZeroIsize, NonZeroU32};
+            use core::ptr::NonNull;
+            use raw_window_handle as rwh;
+            use raw_window_handle::HandleError::{NotSupported, Unavailable};
+
+            let raw = match self {
+                RawWindowHandle::Windows(h) => {
+                    let hwnd = NonZeroIsize::new(h.hwnd as isize).ok_or(Unavailable)?;
+                    let mut handle = rwh::Win32WindowHandle::new(hwnd);
+                    handle.hinstance = NonZeroIsize::new(h.hinstance as isize);
+                    rwh::RawWindowHandle::Win32(handle)
+                },
+                RawWindowHandle::Xlib(h) => {
+                    if h.window == 0 {
+                        return Err(Unavailable);
+                    }
+                    rwh::RawWindowHandle::Xlib(rwh::XlibWindowHandle::new(h.window as _))
+                },
+                RawWindowHandle::Xcb(h) => {
+                    let window = NonZeroU32::new(h.window).ok_or(Unavailable)?;
+                    rwh::RawWindowHandle::Xcb(rwh::XcbWindowHandle::new(window))
+                },
+                RawWindowHandle::Wayland(h) => {
+                    let surface = NonNull::new(h.surface).ok_or(Unavailable)?;
+                    rwh::RawWindowHandle::Wayland(rwh::WaylandWindowHandle::new(surface))
+                },
+                RawWindowHandle::MacOS(h) => {
+                    let ns_view = NonNull::new(h.ns_view).ok_or(Unavailable)?;
+                    rwh::RawWindowHandle::AppKit(rwh::AppKitWindowHandle::new(ns_view))
+                },
+                RawWindowHandle::IOS(h) => {
+                    let ui_view = NonNull::new(h.ui_view).ok_or(Unavailable)?;
+                    let mut handle = rwh::UiKitWindowHandle::new(ui_view);
+                    handle.ui_view_controller = NonNull::new(h.ui_view_controller);
+                    rwh::RawWindowHandle::UiKit(handle)
+                },
+                RawWindowHandle::Web(h) => rwh::RawWindowHandle::Web(rwh::WebWindowHandle::new(h.id)),
+                RawWindowHandle::Android(h) => {
+                    let a_native_window = NonNull::new(h.a_native_window).ok_or(Unavailable)?;
+                    rwh::RawWindowHandle::AndroidNdk(rwh::AndroidNdkWindowHandle::new(a_native_window))
+                },
+                RawWindowHandle::Unsupported => return Err(NotSupported),
+            };
+
+            // the handle is valid until the window is closed
+            Ok(unsafe { rwh::WindowHandle::borrow_raw(raw) })
+        }
+    }
+
+    #[cfg(feature = "raw-window-handle")]
+    impl raw_window_handle::HasDisplayHandle for RawWindowHandle {
+        fn display_handle(&self) -> Result<raw_window_handle::DisplayHandle<'_>, raw_window_handle::HandleError> {
+
+            use core::ptr::NonNull;
+            use raw_window_handle as rwh;
+            use raw_window_handle::HandleError::{NotSupported, Unavailable};
+
+            let raw = match self {
+                RawWindowHandle::Windows(_) => rwh::RawDisplayHandle::Windows(rwh::WindowsDisplayHandle::new()),
+                RawWindowHandle::Xlib(h) => rwh::RawDisplayHandle::Xlib(rwh::XlibDisplayHandle::new(NonNull::new(h.display), 0)),
+                RawWindowHandle::Xcb(h) => rwh::RawDisplayHandle::Xcb(rwh::XcbDisplayHandle::new(NonNull::new(h.connection), 0)),
+                RawWindowHandle::Wayland(h) => {
+                    let display = NonNull::new(h.display).ok_or(Unavailable)?;
+                    rwh::RawDisplayHandle::Wayland(rwh::WaylandDisplayHandle::new(display))
+                },
+                RawWindowHandle::MacOS(_) => rwh::RawDisplayHandle::AppKit(rwh::AppKitDisplayHandle::new()),
+                RawWindowHandle::IOS(_) => rwh::RawDisplayHandle::UiKit(rwh::UiKitDisplayHandle::new()),
+                RawWindowHandle::Web(_) => rwh::RawDisplayHandle::Web(rwh::WebDisplayHandle::new()),
+                RawWindowHandle::Android(_) => rwh::RawDisplayHandle::Android(rwh::AndroidDisplayHandle::new()),
+                RawWindowHandle::Unsupported => return Err(NotSupported),
+            };
+
+            // the display connection outlives all windows of the application
+            Ok(unsafe { rwh::DisplayHandle::borrow_raw(raw) })
+        }
+    }
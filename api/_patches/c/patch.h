@@ -1,4 +1,28 @@
 
+/* Ownership and lifetimes
+ *
+ * - All Az* structs are plain C structs with a stable layout, they can be
+ *   created on the stack and passed by value (sizes are checked in the tests)
+ * - Passing a struct by value to a function moves it into the function: don't
+ *   use or delete it afterwards (i.e. AzDom_addChild(&dom, child) consumes `child`)
+ * - Structs that are returned by value are owned by the caller and have to be
+ *   released with the matching Az*_delete function (unless they are moved
+ *   into another function before that)
+ * - Pointer arguments (`AzDom* restrict`, `const AzCallbackInfo*`) are borrowed for
+ *   the duration of the call only, don't store them. In particular the *Info structs
+ *   passed to callbacks are only valid until the callback returns
+ * - Data shared with callbacks lives in an AzRefAny (see AZ_REFLECT below), which is
+ *   reference-counted: AzRefAny_deepCopy increases, AzRefAny_delete decreases the refcount
+ * - Types marked as "reference-counted" or "boxed" (AzFile, AzThread, ...) are opaque
+ *   handles, only access them through their functions
+ *
+ * Error handling
+ *
+ * Functions that can fail return an AzResult* or AzOption* union, check the `.tag`
+ * before accessing the `.Ok` / `.Some` payload. Errors are regular structs / enums
+ * (i.e. AzSvgParseError, AzEncodeImageError), so they can be matched on in a switch statement.
+ */
+
 /* Macro to turn a compile-time string into a compile-time AzString
  *
  * static AzString foo = AzString_fromConstStr(\"MyString\");
@@ -43,12 +67,18 @@
  * AzAppConfig foo = AzAppConfig_default();
  */
 #define AzAppConfig_default(...) { \
-    .layout_solver = AzLayoutSolverVersion_March2021, \
+    .layout_solver = AzLayoutSolver_Default, \
     .log_level = AzAppLogLevel_Error, \
     .enable_visual_panic_hook = true, \
     .enable_logging_on_panic = true, \
     .enable_tab_navigation = true, \
     .system_callbacks = AzSystemCallbacks_libraryInternal(), \
+    .user_idle_timeout = AzOptionDuration_None, \
+    .crash_handler = AzOptionCrashHandler_None, \
+    .jank_capture = AzOptionJankCapture_None, \
+    .event_recording = AzOptionEventRecording_None, \
+    .frame_recording = AzOptionFrameRecording_None, \
+    .hover_intent_delay = { .Some = { .tag = AzOptionDurationTag_Some, .payload = { .System = { .tag = AzDurationTag_System, .payload = { .secs = 0, .nanos = 500000000 } } } } }, \
 }
 
 /* Macro to generate reflection metadata for a given struct - for a "structName" of "foo", generates:
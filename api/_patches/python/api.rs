@@ -678,4 +678,24 @@ impl PyGCProtocol for AzNodeData {
             data._py_data = None;
         }
     }
+}
+
+// CrashHandler has no marshal data, so the Python callable is stored globally
+static PY_CRASH_HANDLER: std::sync::Mutex<Option<PyObject>> = std::sync::Mutex::new(None);
+
+extern "C" fn invoke_py_crash_handler(info: &AzCrashInfo) {
+    Python::with_gil(|py| {
+
+        let pyfunction = match PY_CRASH_HANDLER.lock().ok().and_then(|h| h.as_ref().map(|f| f.clone_ref(py))) {
+            Some(s) => s,
+            None => return,
+        };
+
+        // call crash handler into python
+        if let Err(e) = pyfunction.call1(py, (info.clone(),)) {
+            #[cfg(feature = "logging")] {
+                log::error!("Exception caught when invoking CrashHandler: {}", e);
+            }
+        }
+    })
 }
\ No newline at end of file
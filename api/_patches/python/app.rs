@@ -10,4 +10,27 @@
 
         let app_refany = azul_impl::callbacks::RefAny::new(AppDataTy { _py_app_data: Some(data) });
         Ok(unsafe { mem::transmute(crate::AzApp_new(app_refany, mem::transmute(config))) })
+    }
+
+    pub fn run(&self, py: Python, window: AzWindowCreateOptions) -> () {
+
+        // AzApp and AzWindowCreateOptions are not Send, but allow_threads
+        // runs the closure on the current thread, so nothing actually
+        // crosses a thread boundary here
+        struct RunArgs<'a> {
+            app: &'a AzApp,
+            window: AzWindowCreateOptions,
+        }
+
+        unsafe impl<'a> Send for RunArgs<'a> { }
+
+        let args = RunArgs { app: self, window };
+
+        // Release the GIL while the event loop is running, otherwise Python threads
+        // (and azul threads calling back into Python) would block until the app exits.
+        // The marshaled callbacks re-acquire the GIL via Python::with_gil.
+        py.allow_threads(move || {
+            let RunArgs { app, window } = args;
+            crate::AzApp_run(app, window)
+        })
     }
\ No newline at end of file
@@ -0,0 +1,22 @@
+    // impl CrashHandler {
+
+    #[new]
+    pub fn __new__(py: Python, cb: PyObject) -> Result<Self, PyErr> {
+        use pyo3::type_object::PyTypeInfo;
+
+        {
+            let cb_any = cb.as_ref(py);
+            if !cb_any.is_callable() {
+                let type_name = cb_any.get_type().name().unwrap_or("<unknown>");
+                return Err(PyException::new_err(format!("ERROR in CrashHandler.new: - argument \"cb\" is of type \"{}\", expected function", type_name)));
+            }
+        }
+
+        // the crash handler is a plain function pointer without any
+        // user data, so the last created handler replaces the previous one
+        if let Ok(mut handler) = PY_CRASH_HANDLER.lock() {
+            *handler = Some(cb);
+        }
+
+        Ok(Self { cb: invoke_py_crash_handler })
+    }
@@ -0,0 +1,7 @@
+    // impl GetLogLinesFn {
+
+    #[new]
+    pub fn __new__() -> Self {
+        let callbacks: AzSystemCallbacks = unsafe { mem::transmute(crate::AzSystemCallbacks_libraryInternal()) };
+        callbacks.get_log_lines_fn
+    }
@@ -0,0 +1,7 @@
+    // impl GetSystemIdleTimeFn {
+
+    #[new]
+    pub fn __new__() -> Self {
+        let callbacks: AzSystemCallbacks = unsafe { mem::transmute(crate::AzSystemCallbacks_libraryInternal()) };
+        callbacks.get_system_idle_time_fn
+    }
@@ -0,0 +1,7 @@
+    // impl SetLogLevelFn {
+
+    #[new]
+    pub fn __new__() -> Self {
+        let callbacks: AzSystemCallbacks = unsafe { mem::transmute(crate::AzSystemCallbacks_libraryInternal()) };
+        callbacks.set_log_level_fn
+    }
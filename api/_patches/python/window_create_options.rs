@@ -7,4 +7,18 @@
             .. Default::default()
         };
         Ok(unsafe { mem::transmute(window) })
-    }
\ No newline at end of file
+    }
+    fn with_window_data(&self, py: Python, window_data: PyObject) -> Result<Self, PyErr> { // RefAny<DatasetTy>
+        use pyo3::type_object::PyTypeInfo;
+
+        if window_data.as_ref(py.clone()).is_callable() {
+            return Err(PyException::new_err(format!("ERROR in WindowCreateOptions.with_window_data: - argument \"window_data\" is a function callback, expected class")));
+        }
+
+        let window_data_refany = azul_impl::callbacks::RefAny::new(DatasetTy {
+            _py_data: Some(window_data),
+        });
+
+        let window: azul_core::window::WindowCreateOptions = unsafe { mem::transmute(self.clone()) };
+        Ok(unsafe { mem::transmute(window.with_window_data(window_data_refany)) })
+    }
@@ -36,6 +36,14 @@
 #endif
 
 
+struct AzRecordedFrame;
+typedef struct AzRecordedFrame AzRecordedFrame;
+typedef void (*AzFrameRecordingCallbackType)(AzRecordedFrame* const A);
+
+struct AzCrashInfo;
+typedef struct AzCrashInfo AzCrashInfo;
+typedef void (*AzCrashHandlerType)(AzCrashInfo* const A);
+
 struct AzRefAny;
 typedef struct AzRefAny AzRefAny;
 struct AzLayoutCallbackInfo;
@@ -58,6 +66,22 @@ struct AzIFrameCallbackReturn;
 typedef struct AzIFrameCallbackReturn AzIFrameCallbackReturn;
 typedef AzIFrameCallbackReturn (*AzIFrameCallbackType)(AzRefAny* restrict A, AzIFrameCallbackInfo* restrict B);
 
+struct AzMeasureCallbackInfo;
+typedef struct AzMeasureCallbackInfo AzMeasureCallbackInfo;
+struct AzLogicalSize;
+typedef struct AzLogicalSize AzLogicalSize;
+typedef AzLogicalSize (*AzMeasureCallbackType)(AzRefAny* restrict A, AzMeasureCallbackInfo* const B);
+
+struct AzArrangeCallbackInfo;
+typedef struct AzArrangeCallbackInfo AzArrangeCallbackInfo;
+typedef void (*AzArrangeCallbackType)(AzRefAny* restrict A, AzArrangeCallbackInfo* const B);
+
+struct AzPaintCallbackInfo;
+typedef struct AzPaintCallbackInfo AzPaintCallbackInfo;
+struct AzPaintPrimitiveVec;
+typedef struct AzPaintPrimitiveVec AzPaintPrimitiveVec;
+typedef AzPaintPrimitiveVec (*AzPaintCallbackType)(AzRefAny* restrict A, AzPaintCallbackInfo* const B);
+
 struct AzRenderImageCallbackInfo;
 typedef struct AzRenderImageCallbackInfo AzRenderImageCallbackInfo;
 struct AzImageRef;
@@ -104,6 +128,10 @@ typedef AzOnTextInputReturn (*AzTextInputOnVirtualKeyDownCallbackType)(AzRefAny*
 
 typedef AzUpdate (*AzTextInputOnFocusLostCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzTextInputState* const C);
 
+struct AzTextInputSpellingSuggestions;
+typedef struct AzTextInputSpellingSuggestions AzTextInputSpellingSuggestions;
+typedef AzUpdate (*AzTextInputOnSpellingSuggestionsCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzTextInputState* const C, AzTextInputSpellingSuggestions* const D);
+
 struct AzNumberInputState;
 typedef struct AzNumberInputState AzNumberInputState;
 typedef AzUpdate (*AzNumberInputOnValueChangeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzNumberInputState* const C);
@@ -152,6 +180,8 @@ typedef AzUpdate (*AzListViewOnRowClickCallbackType)(AzRefAny* restrict A, AzCal
 
 typedef AzUpdate (*AzDropDownOnChoiceChangeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, size_t C);
 
+typedef AzUpdate (*AzToastOnActionCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B);
+
 typedef void (*AzParsedFontDestructorFnType)(void* restrict A);
 
 struct AzInstantPtr;
@@ -170,6 +200,28 @@ union AzInstant;
 typedef union AzInstant AzInstant;
 typedef AzInstant (*AzGetSystemTimeFnType)();
 
+union AzOptionDuration;
+typedef union AzOptionDuration AzOptionDuration;
+typedef AzOptionDuration (*AzGetSystemIdleTimeFnType)();
+
+struct AzStringVec;
+typedef struct AzStringVec AzStringVec;
+typedef AzStringVec (*AzGetLogLinesFnType)();
+
+enum AzVirtualKeyCode;
+typedef enum AzVirtualKeyCode AzVirtualKeyCode;
+struct AzKeyboardModifiers;
+typedef struct AzKeyboardModifiers AzKeyboardModifiers;
+union AzOptionChar;
+typedef union AzOptionChar AzOptionChar;
+typedef AzOptionChar (*AzKeycodeToCharFnType)(AzVirtualKeyCode A, AzKeyboardModifiers B);
+
+struct AzString;
+typedef struct AzString AzString;
+enum AzAppLogLevel;
+typedef enum AzAppLogLevel AzAppLogLevel;
+typedef void (*AzSetLogLevelFnType)(AzString A, AzAppLogLevel B);
+
 typedef bool (*AzCheckThreadFinishedFnType)(const void* A);
 
 union AzThreadSendMsg;
@@ -278,10 +330,16 @@ struct AzInlineWordVec;
 typedef struct AzInlineWordVec AzInlineWordVec;
 typedef void (*AzInlineWordVecDestructorType)(AzInlineWordVec* restrict A);
 
+typedef void (*AzPaintPrimitiveVecDestructorType)(AzPaintPrimitiveVec* restrict A);
+
 struct AzInlineGlyphVec;
 typedef struct AzInlineGlyphVec AzInlineGlyphVec;
 typedef void (*AzInlineGlyphVecDestructorType)(AzInlineGlyphVec* restrict A);
 
+struct AzTextUnderlineVec;
+typedef struct AzTextUnderlineVec AzTextUnderlineVec;
+typedef void (*AzTextUnderlineVecDestructorType)(AzTextUnderlineVec* restrict A);
+
 struct AzInlineTextHitVec;
 typedef struct AzInlineTextHitVec AzInlineTextHitVec;
 typedef void (*AzInlineTextHitVecDestructorType)(AzInlineTextHitVec* restrict A);
@@ -290,6 +348,10 @@ struct AzMonitorVec;
 typedef struct AzMonitorVec AzMonitorVec;
 typedef void (*AzMonitorVecDestructorType)(AzMonitorVec* restrict A);
 
+struct AzTouchPointVec;
+typedef struct AzTouchPointVec AzTouchPointVec;
+typedef void (*AzTouchPointVecDestructorType)(AzTouchPointVec* restrict A);
+
 struct AzVideoModeVec;
 typedef struct AzVideoModeVec AzVideoModeVec;
 typedef void (*AzVideoModeVecDestructorType)(AzVideoModeVec* restrict A);
@@ -370,6 +432,14 @@ struct AzVirtualKeyCodeVec;
 typedef struct AzVirtualKeyCodeVec AzVirtualKeyCodeVec;
 typedef void (*AzVirtualKeyCodeVecDestructorType)(AzVirtualKeyCodeVec* restrict A);
 
+struct AzShortcutConflictVec;
+typedef struct AzShortcutConflictVec AzShortcutConflictVec;
+typedef void (*AzShortcutConflictVecDestructorType)(AzShortcutConflictVec* restrict A);
+
+struct AzShortcutVec;
+typedef struct AzShortcutVec AzShortcutVec;
+typedef void (*AzShortcutVecDestructorType)(AzShortcutVec* restrict A);
+
 struct AzCascadeInfoVec;
 typedef struct AzCascadeInfoVec AzCascadeInfoVec;
 typedef void (*AzCascadeInfoVecDestructorType)(AzCascadeInfoVec* restrict A);
@@ -422,8 +492,6 @@ struct AzGLintVec;
 typedef struct AzGLintVec AzGLintVec;
 typedef void (*AzGLintVecDestructorType)(AzGLintVec* restrict A);
 
-struct AzStringVec;
-typedef struct AzStringVec AzStringVec;
 typedef void (*AzStringVecDestructorType)(AzStringVec* restrict A);
 
 struct AzStringPairVec;
@@ -479,11 +547,36 @@ enum AzAppLogLevel {
 };
 typedef enum AzAppLogLevel AzAppLogLevel;
 
+struct AzFrameRecordingCallback {
+    AzFrameRecordingCallbackType cb;
+};
+typedef struct AzFrameRecordingCallback AzFrameRecordingCallback;
+
+struct AzCrashHandler {
+    AzCrashHandlerType cb;
+};
+typedef struct AzCrashHandler AzCrashHandler;
+
 enum AzLayoutSolver {
    AzLayoutSolver_Default,
 };
 typedef enum AzLayoutSolver AzLayoutSolver;
 
+enum AzWindowBackgroundEffect {
+   AzWindowBackgroundEffect_None,
+   AzWindowBackgroundEffect_Blur,
+   AzWindowBackgroundEffect_Mica,
+   AzWindowBackgroundEffect_MicaAlt,
+   AzWindowBackgroundEffect_Acrylic,
+};
+typedef enum AzWindowBackgroundEffect AzWindowBackgroundEffect;
+
+enum AzScrollbarMode {
+   AzScrollbarMode_Default,
+   AzScrollbarMode_Overlay,
+};
+typedef enum AzScrollbarMode AzScrollbarMode;
+
 enum AzVsync {
    AzVsync_Enabled,
    AzVsync_Disabled,
@@ -778,6 +871,23 @@ enum AzVirtualKeyCode {
 };
 typedef enum AzVirtualKeyCode AzVirtualKeyCode;
 
+enum AzPoliteness {
+   AzPoliteness_Polite,
+   AzPoliteness_Assertive,
+};
+typedef enum AzPoliteness AzPoliteness;
+
+enum AzHapticKind {
+   AzHapticKind_Selection,
+   AzHapticKind_LightImpact,
+   AzHapticKind_MediumImpact,
+   AzHapticKind_HeavyImpact,
+   AzHapticKind_Success,
+   AzHapticKind_Warning,
+   AzHapticKind_Error,
+};
+typedef enum AzHapticKind AzHapticKind;
+
 enum AzWindowFrame {
    AzWindowFrame_Normal,
    AzWindowFrame_Minimized,
@@ -786,6 +896,22 @@ enum AzWindowFrame {
 };
 typedef enum AzWindowFrame AzWindowFrame;
 
+enum AzFullscreenModeTag {
+   AzFullscreenModeTag_Borderless,
+   AzFullscreenModeTag_Exclusive,
+};
+typedef enum AzFullscreenModeTag AzFullscreenModeTag;
+
+struct AzFullscreenModeVariant_Borderless { AzFullscreenModeTag tag; size_t payload; };
+typedef struct AzFullscreenModeVariant_Borderless AzFullscreenModeVariant_Borderless;
+struct AzFullscreenModeVariant_Exclusive { AzFullscreenModeTag tag; size_t payload; };
+typedef struct AzFullscreenModeVariant_Exclusive AzFullscreenModeVariant_Exclusive;
+union AzFullscreenMode {
+    AzFullscreenModeVariant_Borderless Borderless;
+    AzFullscreenModeVariant_Exclusive Exclusive;
+};
+typedef union AzFullscreenMode AzFullscreenMode;
+
 struct AzDebugState {
     bool  profiler_dbg;
     bool  render_target_dbg;
@@ -817,6 +943,14 @@ struct AzDebugState {
 };
 typedef struct AzDebugState AzDebugState;
 
+struct AzKeyboardModifiers {
+    bool  shift;
+    bool  ctrl;
+    bool  alt;
+    bool  caps_lock;
+};
+typedef struct AzKeyboardModifiers AzKeyboardModifiers;
+
 enum AzMouseCursorType {
    AzMouseCursorType_Default,
    AzMouseCursorType_Crosshair,
@@ -886,10 +1020,20 @@ enum AzWindowTheme {
 };
 typedef enum AzWindowTheme AzWindowTheme;
 
-struct AzTouchState {
-    uint8_t unused;
+enum AzInputModality {
+   AzInputModality_Mouse,
+   AzInputModality_Touch,
+   AzInputModality_Pen,
 };
-typedef struct AzTouchState AzTouchState;
+typedef enum AzInputModality AzInputModality;
+
+struct AzHdrCapabilities {
+    bool  supported;
+    bool  enabled;
+    uint32_t bits_per_color_channel;
+    float sdr_white_level;
+};
+typedef struct AzHdrCapabilities AzHdrCapabilities;
 
 struct AzMarshaledLayoutCallbackInner {
     AzMarshaledLayoutCallbackType cb;
@@ -937,6 +1081,19 @@ struct AzPositionInfoInner {
 };
 typedef struct AzPositionInfoInner AzPositionInfoInner;
 
+enum AzTextUnderlineStyle {
+   AzTextUnderlineStyle_Solid,
+   AzTextUnderlineStyle_Dotted,
+   AzTextUnderlineStyle_Wavy,
+};
+typedef enum AzTextUnderlineStyle AzTextUnderlineStyle;
+
+enum AzCaretAnimation {
+   AzCaretAnimation_Blink,
+   AzCaretAnimation_Fade,
+};
+typedef enum AzCaretAnimation AzCaretAnimation;
+
 enum AzAnimationRepeat {
    AzAnimationRepeat_NoRepeat,
    AzAnimationRepeat_Loop,
@@ -965,6 +1122,21 @@ struct AzIFrameCallback {
 };
 typedef struct AzIFrameCallback AzIFrameCallback;
 
+struct AzMeasureCallback {
+    AzMeasureCallbackType cb;
+};
+typedef struct AzMeasureCallback AzMeasureCallback;
+
+struct AzArrangeCallback {
+    AzArrangeCallbackType cb;
+};
+typedef struct AzArrangeCallback AzArrangeCallback;
+
+struct AzPaintCallback {
+    AzPaintCallbackType cb;
+};
+typedef struct AzPaintCallback AzPaintCallback;
+
 struct AzRenderImageCallback {
     AzRenderImageCallbackType cb;
 };
@@ -1001,6 +1173,9 @@ enum AzOn {
    AzOn_LeftMouseUp,
    AzOn_MiddleMouseUp,
    AzOn_RightMouseUp,
+   AzOn_DoubleClick,
+   AzOn_TripleClick,
+   AzOn_HoverIntent,
    AzOn_MouseEnter,
    AzOn_MouseLeave,
    AzOn_Scroll,
@@ -1012,6 +1187,21 @@ enum AzOn {
    AzOn_HoveredFileCancelled,
    AzOn_FocusReceived,
    AzOn_FocusLost,
+   AzOn_UserIdle,
+   AzOn_UserActive,
+   AzOn_SessionEnding,
+   AzOn_FullscreenEntered,
+   AzOn_FullscreenExited,
+   AzOn_WindowResized,
+   AzOn_WindowMoved,
+   AzOn_WindowFocusReceived,
+   AzOn_WindowFocusLost,
+   AzOn_DpiChanged,
+   AzOn_Minimized,
+   AzOn_Maximized,
+   AzOn_Restored,
+   AzOn_UserEvent,
+   AzOn_InputModalityChanged,
 };
 typedef enum AzOn AzOn;
 
@@ -1025,6 +1215,9 @@ enum AzHoverEventFilter {
    AzHoverEventFilter_LeftMouseUp,
    AzHoverEventFilter_RightMouseUp,
    AzHoverEventFilter_MiddleMouseUp,
+   AzHoverEventFilter_DoubleClick,
+   AzHoverEventFilter_TripleClick,
+   AzHoverEventFilter_HoverIntent,
    AzHoverEventFilter_MouseEnter,
    AzHoverEventFilter_MouseLeave,
    AzHoverEventFilter_Scroll,
@@ -1053,6 +1246,8 @@ enum AzFocusEventFilter {
    AzFocusEventFilter_LeftMouseUp,
    AzFocusEventFilter_RightMouseUp,
    AzFocusEventFilter_MiddleMouseUp,
+   AzFocusEventFilter_DoubleClick,
+   AzFocusEventFilter_TripleClick,
    AzFocusEventFilter_MouseEnter,
    AzFocusEventFilter_MouseLeave,
    AzFocusEventFilter_Scroll,
@@ -1076,6 +1271,9 @@ enum AzWindowEventFilter {
    AzWindowEventFilter_LeftMouseUp,
    AzWindowEventFilter_RightMouseUp,
    AzWindowEventFilter_MiddleMouseUp,
+   AzWindowEventFilter_DoubleClick,
+   AzWindowEventFilter_TripleClick,
+   AzWindowEventFilter_HoverIntent,
    AzWindowEventFilter_MouseEnter,
    AzWindowEventFilter_MouseLeave,
    AzWindowEventFilter_Scroll,
@@ -1097,6 +1295,19 @@ enum AzWindowEventFilter {
    AzWindowEventFilter_FocusLost,
    AzWindowEventFilter_CloseRequested,
    AzWindowEventFilter_ThemeChanged,
+   AzWindowEventFilter_WindowFocusReceived,
+   AzWindowEventFilter_WindowFocusLost,
+   AzWindowEventFilter_UserIdle,
+   AzWindowEventFilter_UserActive,
+   AzWindowEventFilter_SessionEnding,
+   AzWindowEventFilter_FullscreenEntered,
+   AzWindowEventFilter_FullscreenExited,
+   AzWindowEventFilter_DpiChanged,
+   AzWindowEventFilter_Minimized,
+   AzWindowEventFilter_Maximized,
+   AzWindowEventFilter_Restored,
+   AzWindowEventFilter_UserEvent,
+   AzWindowEventFilter_InputModalityChanged,
 };
 typedef enum AzWindowEventFilter AzWindowEventFilter;
 
@@ -1115,6 +1326,51 @@ enum AzApplicationEventFilter {
 };
 typedef enum AzApplicationEventFilter AzApplicationEventFilter;
 
+enum AzFocusDirection {
+   AzFocusDirection_Up,
+   AzFocusDirection_Down,
+   AzFocusDirection_Left,
+   AzFocusDirection_Right,
+};
+typedef enum AzFocusDirection AzFocusDirection;
+
+enum AzInputMode {
+   AzInputMode_Text,
+   AzInputMode_None,
+   AzInputMode_Numeric,
+   AzInputMode_Decimal,
+   AzInputMode_Tel,
+   AzInputMode_Email,
+   AzInputMode_Url,
+   AzInputMode_Search,
+   AzInputMode_Password,
+};
+typedef enum AzInputMode AzInputMode;
+
+enum AzNodeStateFlag {
+   AzNodeStateFlag_Disabled,
+   AzNodeStateFlag_Checked,
+   AzNodeStateFlag_Selected,
+   AzNodeStateFlag_Indeterminate,
+};
+typedef enum AzNodeStateFlag AzNodeStateFlag;
+
+enum AzOverlayPlacement {
+   AzOverlayPlacement_TopStart,
+   AzOverlayPlacement_Top,
+   AzOverlayPlacement_TopEnd,
+   AzOverlayPlacement_BottomStart,
+   AzOverlayPlacement_Bottom,
+   AzOverlayPlacement_BottomEnd,
+   AzOverlayPlacement_LeftStart,
+   AzOverlayPlacement_Left,
+   AzOverlayPlacement_LeftEnd,
+   AzOverlayPlacement_RightStart,
+   AzOverlayPlacement_Right,
+   AzOverlayPlacement_RightEnd,
+};
+typedef enum AzOverlayPlacement AzOverlayPlacement;
+
 enum AzAccessibilityRole {
    AzAccessibilityRole_TitleBar,
    AzAccessibilityRole_MenuBar,
@@ -1251,6 +1507,11 @@ enum AzMenuItemState {
 };
 typedef enum AzMenuItemState AzMenuItemState;
 
+struct AzCssMediaCondition {
+    uint8_t matching_styles;
+};
+typedef struct AzCssMediaCondition AzCssMediaCondition;
+
 enum AzNodeTypeKey {
    AzNodeTypeKey_Body,
    AzNodeTypeKey_Div,
@@ -1276,6 +1537,7 @@ enum AzCssPropertyType {
    AzCssPropertyType_LineHeight,
    AzCssPropertyType_WordSpacing,
    AzCssPropertyType_TabWidth,
+   AzCssPropertyType_Hyphens,
    AzCssPropertyType_Cursor,
    AzCssPropertyType_Display,
    AzCssPropertyType_Float,
@@ -1333,11 +1595,24 @@ enum AzCssPropertyType {
    AzCssPropertyType_BoxShadowTop,
    AzCssPropertyType_BoxShadowBottom,
    AzCssPropertyType_ScrollbarStyle,
+   AzCssPropertyType_ScrollbarWidth,
+   AzCssPropertyType_ScrollbarColor,
+   AzCssPropertyType_ScrollbarThumbHoverColor,
+   AzCssPropertyType_ScrollbarThumbActiveColor,
+   AzCssPropertyType_WindowDragRegion,
+   AzCssPropertyType_CaretAnimationDuration,
+   AzCssPropertyType_ScrollPadding,
+   AzCssPropertyType_Content,
+   AzCssPropertyType_CounterReset,
+   AzCssPropertyType_CounterIncrement,
+   AzCssPropertyType_OverflowAnchor,
    AzCssPropertyType_Opacity,
    AzCssPropertyType_Transform,
    AzCssPropertyType_TransformOrigin,
    AzCssPropertyType_PerspectiveOrigin,
    AzCssPropertyType_BackfaceVisibility,
+   AzCssPropertyType_Perspective,
+   AzCssPropertyType_TransformStyle,
    AzCssPropertyType_MixBlendMode,
    AzCssPropertyType_Filter,
    AzCssPropertyType_BackdropFilter,
@@ -1533,39 +1808,38 @@ enum AzBorderStyle {
 };
 typedef enum AzBorderStyle AzBorderStyle;
 
-enum AzStyleCursor {
-   AzStyleCursor_Alias,
-   AzStyleCursor_AllScroll,
-   AzStyleCursor_Cell,
-   AzStyleCursor_ColResize,
-   AzStyleCursor_ContextMenu,
-   AzStyleCursor_Copy,
-   AzStyleCursor_Crosshair,
-   AzStyleCursor_Default,
-   AzStyleCursor_EResize,
-   AzStyleCursor_EwResize,
-   AzStyleCursor_Grab,
-   AzStyleCursor_Grabbing,
-   AzStyleCursor_Help,
-   AzStyleCursor_Move,
-   AzStyleCursor_NResize,
-   AzStyleCursor_NsResize,
-   AzStyleCursor_NeswResize,
-   AzStyleCursor_NwseResize,
-   AzStyleCursor_Pointer,
-   AzStyleCursor_Progress,
-   AzStyleCursor_RowResize,
-   AzStyleCursor_SResize,
-   AzStyleCursor_SeResize,
-   AzStyleCursor_Text,
-   AzStyleCursor_Unset,
-   AzStyleCursor_VerticalText,
-   AzStyleCursor_WResize,
-   AzStyleCursor_Wait,
-   AzStyleCursor_ZoomIn,
-   AzStyleCursor_ZoomOut,
-};
-typedef enum AzStyleCursor AzStyleCursor;
+enum AzStyleScrollbarWidth {
+   AzStyleScrollbarWidth_Auto,
+   AzStyleScrollbarWidth_Thin,
+   AzStyleScrollbarWidth_None,
+};
+typedef enum AzStyleScrollbarWidth AzStyleScrollbarWidth;
+
+enum AzStyleOverflowAnchor {
+   AzStyleOverflowAnchor_Auto,
+   AzStyleOverflowAnchor_None,
+};
+typedef enum AzStyleOverflowAnchor AzStyleOverflowAnchor;
+
+enum AzStyleWindowDragRegion {
+   AzStyleWindowDragRegion_None,
+   AzStyleWindowDragRegion_Move,
+   AzStyleWindowDragRegion_ResizeN,
+   AzStyleWindowDragRegion_ResizeNE,
+   AzStyleWindowDragRegion_ResizeE,
+   AzStyleWindowDragRegion_ResizeSE,
+   AzStyleWindowDragRegion_ResizeS,
+   AzStyleWindowDragRegion_ResizeSW,
+   AzStyleWindowDragRegion_ResizeW,
+   AzStyleWindowDragRegion_ResizeNW,
+   AzStyleWindowDragRegion_MaximizeButton,
+};
+typedef enum AzStyleWindowDragRegion AzStyleWindowDragRegion;
+
+struct AzStyleCaretAnimationDuration {
+    uint32_t inner;
+};
+typedef struct AzStyleCaretAnimationDuration AzStyleCaretAnimationDuration;
 
 enum AzStyleBackfaceVisibility {
    AzStyleBackfaceVisibility_Hidden,
@@ -1573,6 +1847,12 @@ enum AzStyleBackfaceVisibility {
 };
 typedef enum AzStyleBackfaceVisibility AzStyleBackfaceVisibility;
 
+enum AzStyleTransformStyle {
+   AzStyleTransformStyle_Flat,
+   AzStyleTransformStyle_Preserve3D,
+};
+typedef enum AzStyleTransformStyle AzStyleTransformStyle;
+
 enum AzStyleTextAlign {
    AzStyleTextAlign_Left,
    AzStyleTextAlign_Center,
@@ -1580,6 +1860,13 @@ enum AzStyleTextAlign {
 };
 typedef enum AzStyleTextAlign AzStyleTextAlign;
 
+enum AzStyleHyphens {
+   AzStyleHyphens_None,
+   AzStyleHyphens_Manual,
+   AzStyleHyphens_Auto,
+};
+typedef enum AzStyleHyphens AzStyleHyphens;
+
 struct AzRibbon {
     int32_t tab_active;
 };
@@ -1631,19 +1918,22 @@ struct AzTextInputOnFocusLostCallback {
 };
 typedef struct AzTextInputOnFocusLostCallback AzTextInputOnFocusLostCallback;
 
+struct AzTextInputOnSpellingSuggestionsCallback {
+    AzTextInputOnSpellingSuggestionsCallbackType cb;
+};
+typedef struct AzTextInputOnSpellingSuggestionsCallback AzTextInputOnSpellingSuggestionsCallback;
+
 enum AzTextInputValid {
    AzTextInputValid_Yes,
    AzTextInputValid_No,
 };
 typedef enum AzTextInputValid AzTextInputValid;
 
-struct AzNumberInputState {
-    float previous;
-    float number;
-    float min;
-    float max;
+enum AzNumberInputMode {
+   AzNumberInputMode_Integer,
+   AzNumberInputMode_Float,
 };
-typedef struct AzNumberInputState AzNumberInputState;
+typedef enum AzNumberInputMode AzNumberInputMode;
 
 struct AzNumberInputOnValueChangeCallback {
     AzNumberInputOnValueChangeCallbackType cb;
@@ -1769,6 +2059,16 @@ struct AzDropDownOnChoiceChangeCallback {
 };
 typedef struct AzDropDownOnChoiceChangeCallback AzDropDownOnChoiceChangeCallback;
 
+struct AzToastId {
+    size_t id;
+};
+typedef struct AzToastId AzToastId;
+
+struct AzToastOnActionCallback {
+    AzToastOnActionCallbackType cb;
+};
+typedef struct AzToastOnActionCallback AzToastOnActionCallback;
+
 struct AzNodeHierarchyItem {
     size_t parent;
     size_t previous_sibling;
@@ -1788,6 +2088,10 @@ struct AzStyledNodeState {
     bool  hover;
     bool  active;
     bool  focused;
+    bool  disabled;
+    bool  checked;
+    bool  selected;
+    bool  indeterminate;
 };
 typedef struct AzStyledNodeState AzStyledNodeState;
 
@@ -1813,6 +2117,16 @@ struct AzGlVoidPtrMut {
 };
 typedef struct AzGlVoidPtrMut AzGlVoidPtrMut;
 
+struct AzGlQuirks {
+    bool  force_software_rendering;
+    bool  disable_subpixel_aa;
+    bool  disable_optimized_shaders;
+    bool  disable_program_cache;
+    bool  disable_multithreading;
+    bool  disable_partial_present;
+};
+typedef struct AzGlQuirks AzGlQuirks;
+
 struct AzGlShaderPrecisionFormatReturn {
     int32_t _0;
     int32_t _1;
@@ -2251,6 +2565,12 @@ struct AzThreadReceiver {
 };
 typedef struct AzThreadReceiver AzThreadReceiver;
 
+struct AzEventLoopProxy {
+    void* ptr;
+    bool  run_destructor;
+};
+typedef struct AzEventLoopProxy AzEventLoopProxy;
+
 struct AzCreateThreadFn {
     AzCreateThreadFnType cb;
 };
@@ -2261,6 +2581,26 @@ struct AzGetSystemTimeFn {
 };
 typedef struct AzGetSystemTimeFn AzGetSystemTimeFn;
 
+struct AzGetSystemIdleTimeFn {
+    AzGetSystemIdleTimeFnType cb;
+};
+typedef struct AzGetSystemIdleTimeFn AzGetSystemIdleTimeFn;
+
+struct AzGetLogLinesFn {
+    AzGetLogLinesFnType cb;
+};
+typedef struct AzGetLogLinesFn AzGetLogLinesFn;
+
+struct AzKeycodeToCharFn {
+    AzKeycodeToCharFnType cb;
+};
+typedef struct AzKeycodeToCharFn AzKeycodeToCharFn;
+
+struct AzSetLogLevelFn {
+    AzSetLogLevelFnType cb;
+};
+typedef struct AzSetLogLevelFn AzSetLogLevelFn;
+
 struct AzCheckThreadFinishedFn {
     AzCheckThreadFinishedFnType cb;
 };
@@ -2721,6 +3061,26 @@ union AzInlineWordVecDestructor {
 };
 typedef union AzInlineWordVecDestructor AzInlineWordVecDestructor;
 
+enum AzPaintPrimitiveVecDestructorTag {
+   AzPaintPrimitiveVecDestructorTag_DefaultRust,
+   AzPaintPrimitiveVecDestructorTag_NoDestructor,
+   AzPaintPrimitiveVecDestructorTag_External,
+};
+typedef enum AzPaintPrimitiveVecDestructorTag AzPaintPrimitiveVecDestructorTag;
+
+struct AzPaintPrimitiveVecDestructorVariant_DefaultRust { AzPaintPrimitiveVecDestructorTag tag; };
+typedef struct AzPaintPrimitiveVecDestructorVariant_DefaultRust AzPaintPrimitiveVecDestructorVariant_DefaultRust;
+struct AzPaintPrimitiveVecDestructorVariant_NoDestructor { AzPaintPrimitiveVecDestructorTag tag; };
+typedef struct AzPaintPrimitiveVecDestructorVariant_NoDestructor AzPaintPrimitiveVecDestructorVariant_NoDestructor;
+struct AzPaintPrimitiveVecDestructorVariant_External { AzPaintPrimitiveVecDestructorTag tag; AzPaintPrimitiveVecDestructorType payload; };
+typedef struct AzPaintPrimitiveVecDestructorVariant_External AzPaintPrimitiveVecDestructorVariant_External;
+union AzPaintPrimitiveVecDestructor {
+    AzPaintPrimitiveVecDestructorVariant_DefaultRust DefaultRust;
+    AzPaintPrimitiveVecDestructorVariant_NoDestructor NoDestructor;
+    AzPaintPrimitiveVecDestructorVariant_External External;
+};
+typedef union AzPaintPrimitiveVecDestructor AzPaintPrimitiveVecDestructor;
+
 enum AzInlineGlyphVecDestructorTag {
    AzInlineGlyphVecDestructorTag_DefaultRust,
    AzInlineGlyphVecDestructorTag_NoDestructor,
@@ -2741,6 +3101,26 @@ union AzInlineGlyphVecDestructor {
 };
 typedef union AzInlineGlyphVecDestructor AzInlineGlyphVecDestructor;
 
+enum AzTextUnderlineVecDestructorTag {
+   AzTextUnderlineVecDestructorTag_DefaultRust,
+   AzTextUnderlineVecDestructorTag_NoDestructor,
+   AzTextUnderlineVecDestructorTag_External,
+};
+typedef enum AzTextUnderlineVecDestructorTag AzTextUnderlineVecDestructorTag;
+
+struct AzTextUnderlineVecDestructorVariant_DefaultRust { AzTextUnderlineVecDestructorTag tag; };
+typedef struct AzTextUnderlineVecDestructorVariant_DefaultRust AzTextUnderlineVecDestructorVariant_DefaultRust;
+struct AzTextUnderlineVecDestructorVariant_NoDestructor { AzTextUnderlineVecDestructorTag tag; };
+typedef struct AzTextUnderlineVecDestructorVariant_NoDestructor AzTextUnderlineVecDestructorVariant_NoDestructor;
+struct AzTextUnderlineVecDestructorVariant_External { AzTextUnderlineVecDestructorTag tag; AzTextUnderlineVecDestructorType payload; };
+typedef struct AzTextUnderlineVecDestructorVariant_External AzTextUnderlineVecDestructorVariant_External;
+union AzTextUnderlineVecDestructor {
+    AzTextUnderlineVecDestructorVariant_DefaultRust DefaultRust;
+    AzTextUnderlineVecDestructorVariant_NoDestructor NoDestructor;
+    AzTextUnderlineVecDestructorVariant_External External;
+};
+typedef union AzTextUnderlineVecDestructor AzTextUnderlineVecDestructor;
+
 enum AzInlineTextHitVecDestructorTag {
    AzInlineTextHitVecDestructorTag_DefaultRust,
    AzInlineTextHitVecDestructorTag_NoDestructor,
@@ -2781,6 +3161,26 @@ union AzMonitorVecDestructor {
 };
 typedef union AzMonitorVecDestructor AzMonitorVecDestructor;
 
+enum AzTouchPointVecDestructorTag {
+   AzTouchPointVecDestructorTag_DefaultRust,
+   AzTouchPointVecDestructorTag_NoDestructor,
+   AzTouchPointVecDestructorTag_External,
+};
+typedef enum AzTouchPointVecDestructorTag AzTouchPointVecDestructorTag;
+
+struct AzTouchPointVecDestructorVariant_DefaultRust { AzTouchPointVecDestructorTag tag; };
+typedef struct AzTouchPointVecDestructorVariant_DefaultRust AzTouchPointVecDestructorVariant_DefaultRust;
+struct AzTouchPointVecDestructorVariant_NoDestructor { AzTouchPointVecDestructorTag tag; };
+typedef struct AzTouchPointVecDestructorVariant_NoDestructor AzTouchPointVecDestructorVariant_NoDestructor;
+struct AzTouchPointVecDestructorVariant_External { AzTouchPointVecDestructorTag tag; AzTouchPointVecDestructorType payload; };
+typedef struct AzTouchPointVecDestructorVariant_External AzTouchPointVecDestructorVariant_External;
+union AzTouchPointVecDestructor {
+    AzTouchPointVecDestructorVariant_DefaultRust DefaultRust;
+    AzTouchPointVecDestructorVariant_NoDestructor NoDestructor;
+    AzTouchPointVecDestructorVariant_External External;
+};
+typedef union AzTouchPointVecDestructor AzTouchPointVecDestructor;
+
 enum AzVideoModeVecDestructorTag {
    AzVideoModeVecDestructorTag_DefaultRust,
    AzVideoModeVecDestructorTag_NoDestructor,
@@ -3181,6 +3581,46 @@ union AzVirtualKeyCodeVecDestructor {
 };
 typedef union AzVirtualKeyCodeVecDestructor AzVirtualKeyCodeVecDestructor;
 
+enum AzShortcutConflictVecDestructorTag {
+   AzShortcutConflictVecDestructorTag_DefaultRust,
+   AzShortcutConflictVecDestructorTag_NoDestructor,
+   AzShortcutConflictVecDestructorTag_External,
+};
+typedef enum AzShortcutConflictVecDestructorTag AzShortcutConflictVecDestructorTag;
+
+struct AzShortcutConflictVecDestructorVariant_DefaultRust { AzShortcutConflictVecDestructorTag tag; };
+typedef struct AzShortcutConflictVecDestructorVariant_DefaultRust AzShortcutConflictVecDestructorVariant_DefaultRust;
+struct AzShortcutConflictVecDestructorVariant_NoDestructor { AzShortcutConflictVecDestructorTag tag; };
+typedef struct AzShortcutConflictVecDestructorVariant_NoDestructor AzShortcutConflictVecDestructorVariant_NoDestructor;
+struct AzShortcutConflictVecDestructorVariant_External { AzShortcutConflictVecDestructorTag tag; AzShortcutConflictVecDestructorType payload; };
+typedef struct AzShortcutConflictVecDestructorVariant_External AzShortcutConflictVecDestructorVariant_External;
+union AzShortcutConflictVecDestructor {
+    AzShortcutConflictVecDestructorVariant_DefaultRust DefaultRust;
+    AzShortcutConflictVecDestructorVariant_NoDestructor NoDestructor;
+    AzShortcutConflictVecDestructorVariant_External External;
+};
+typedef union AzShortcutConflictVecDestructor AzShortcutConflictVecDestructor;
+
+enum AzShortcutVecDestructorTag {
+   AzShortcutVecDestructorTag_DefaultRust,
+   AzShortcutVecDestructorTag_NoDestructor,
+   AzShortcutVecDestructorTag_External,
+};
+typedef enum AzShortcutVecDestructorTag AzShortcutVecDestructorTag;
+
+struct AzShortcutVecDestructorVariant_DefaultRust { AzShortcutVecDestructorTag tag; };
+typedef struct AzShortcutVecDestructorVariant_DefaultRust AzShortcutVecDestructorVariant_DefaultRust;
+struct AzShortcutVecDestructorVariant_NoDestructor { AzShortcutVecDestructorTag tag; };
+typedef struct AzShortcutVecDestructorVariant_NoDestructor AzShortcutVecDestructorVariant_NoDestructor;
+struct AzShortcutVecDestructorVariant_External { AzShortcutVecDestructorTag tag; AzShortcutVecDestructorType payload; };
+typedef struct AzShortcutVecDestructorVariant_External AzShortcutVecDestructorVariant_External;
+union AzShortcutVecDestructor {
+    AzShortcutVecDestructorVariant_DefaultRust DefaultRust;
+    AzShortcutVecDestructorVariant_NoDestructor NoDestructor;
+    AzShortcutVecDestructorVariant_External External;
+};
+typedef union AzShortcutVecDestructor AzShortcutVecDestructor;
+
 enum AzCascadeInfoVecDestructorTag {
    AzCascadeInfoVecDestructorTag_DefaultRust,
    AzCascadeInfoVecDestructorTag_NoDestructor,
@@ -3794,9 +4234,33 @@ typedef struct AzSvgParseErrorPosition AzSvgParseErrorPosition;
 struct AzSystemCallbacks {
     AzCreateThreadFn create_thread_fn;
     AzGetSystemTimeFn get_system_time_fn;
+    AzGetSystemIdleTimeFn get_system_idle_time_fn;
+    AzGetLogLinesFn get_log_lines_fn;
+    AzSetLogLevelFn set_log_level_fn;
+    AzKeycodeToCharFn keycode_to_char_fn;
 };
 typedef struct AzSystemCallbacks AzSystemCallbacks;
 
+enum AzFirstFrameModeTag {
+   AzFirstFrameModeTag_ShowImmediately,
+   AzFirstFrameModeTag_HideUntilRendered,
+   AzFirstFrameModeTag_SplashColor,
+};
+typedef enum AzFirstFrameModeTag AzFirstFrameModeTag;
+
+struct AzFirstFrameModeVariant_ShowImmediately { AzFirstFrameModeTag tag; };
+typedef struct AzFirstFrameModeVariant_ShowImmediately AzFirstFrameModeVariant_ShowImmediately;
+struct AzFirstFrameModeVariant_HideUntilRendered { AzFirstFrameModeTag tag; };
+typedef struct AzFirstFrameModeVariant_HideUntilRendered AzFirstFrameModeVariant_HideUntilRendered;
+struct AzFirstFrameModeVariant_SplashColor { AzFirstFrameModeTag tag; AzColorU payload; };
+typedef struct AzFirstFrameModeVariant_SplashColor AzFirstFrameModeVariant_SplashColor;
+union AzFirstFrameMode {
+    AzFirstFrameModeVariant_ShowImmediately ShowImmediately;
+    AzFirstFrameModeVariant_HideUntilRendered HideUntilRendered;
+    AzFirstFrameModeVariant_SplashColor SplashColor;
+};
+typedef union AzFirstFrameMode AzFirstFrameMode;
+
 struct AzRendererOptions {
     AzVsync vsync;
     AzSrgb srgb;
@@ -3890,12 +4354,17 @@ struct AzWindowFlags {
     bool  has_decorations;
     bool  is_visible;
     bool  is_always_on_top;
+    bool  is_click_through;
+    bool  is_tool_window;
     bool  is_resizable;
     bool  has_focus;
     bool  has_extended_window_frame;
     bool  has_blur_behind_window;
     bool  smooth_scroll_enabled;
     bool  autotab_enabled;
+    bool  spatial_navigation_enabled;
+    bool  is_session_ending;
+    AzFullscreenMode fullscreen_mode;
 };
 typedef struct AzWindowFlags AzWindowFlags;
 
@@ -3951,6 +4420,13 @@ union AzImePosition {
 };
 typedef union AzImePosition AzImePosition;
 
+struct AzTouchPoint {
+    uint64_t id;
+    AzLogicalPosition position;
+    float force;
+};
+typedef struct AzTouchPoint AzTouchPoint;
+
 struct AzVideoMode {
     AzLayoutSize size;
     uint16_t bit_depth;
@@ -3988,6 +4464,22 @@ union AzPositionInfo {
 };
 typedef union AzPositionInfo AzPositionInfo;
 
+struct AzNodeRect {
+    AzLogicalRect margin_box;
+    AzLogicalRect border_box;
+    AzLogicalRect padding_box;
+    AzLogicalRect content_box;
+};
+typedef struct AzNodeRect AzNodeRect;
+
+struct AzScrollInfo {
+    AzLogicalPosition scroll_position;
+    AzLogicalRect viewport;
+    AzLogicalSize content_size;
+    AzLogicalSize max_scroll;
+};
+typedef struct AzScrollInfo AzScrollInfo;
+
 struct AzHidpiAdjustedBounds {
     AzLogicalSize logical_size;
     float hidpi_factor;
@@ -4001,6 +4493,14 @@ struct AzInlineGlyph {
 };
 typedef struct AzInlineGlyph AzInlineGlyph;
 
+struct AzTextUnderline {
+    size_t start;
+    size_t end;
+    AzColorU color;
+    AzTextUnderlineStyle style;
+};
+typedef struct AzTextUnderline AzTextUnderline;
+
 struct AzInlineTextHit {
     AzOptionChar unicode_codepoint;
     AzLogicalPosition hit_relative_to_inline_text;
@@ -4035,6 +4535,29 @@ struct AzIFrameCallbackInfo {
 };
 typedef struct AzIFrameCallbackInfo AzIFrameCallbackInfo;
 
+struct AzMeasureCallbackInfo {
+    AzLogicalSize available_size;
+    bool  width_is_final;
+};
+typedef struct AzMeasureCallbackInfo AzMeasureCallbackInfo;
+
+struct AzArrangeCallbackInfo {
+    AzNodeRect bounds;
+};
+typedef struct AzArrangeCallbackInfo AzArrangeCallbackInfo;
+
+struct AzPaintCallbackInfo {
+    AzLogicalSize size;
+};
+typedef struct AzPaintCallbackInfo AzPaintCallbackInfo;
+
+struct AzPaintRect {
+    AzLogicalRect bounds;
+    AzColorU color;
+    float border_radius;
+};
+typedef struct AzPaintRect AzPaintRect;
+
 struct AzTimerCallbackReturn {
     AzUpdate should_update;
     AzTerminateTimer should_terminate;
@@ -4052,6 +4575,7 @@ typedef struct AzRefAny AzRefAny;
 struct AzIFrameNode {
     AzIFrameCallback callback;
     AzRefAny data;
+    AzFloatValue reinvoke_threshold;
 };
 typedef struct AzIFrameNode AzIFrameNode;
 
@@ -4117,6 +4641,26 @@ union AzCssNthChildSelector {
 };
 typedef union AzCssNthChildSelector AzCssNthChildSelector;
 
+struct AzSystemColors {
+    AzColorU canvas;
+    AzColorU canvas_text;
+    AzColorU link_text;
+    AzColorU gray_text;
+    AzColorU highlight;
+    AzColorU highlight_text;
+    AzColorU button_face;
+    AzColorU button_text;
+};
+typedef struct AzSystemColors AzSystemColors;
+
+struct AzSystemStyle {
+    bool  forced_colors;
+    bool  prefers_reduced_motion;
+    bool  pointer_coarse;
+    AzSystemColors colors;
+};
+typedef struct AzSystemStyle AzSystemStyle;
+
 struct AzPixelValue {
     AzSizeMetric metric;
     AzFloatValue number;
@@ -4489,6 +5033,27 @@ struct AzLayoutBorderTopWidth {
 };
 typedef struct AzLayoutBorderTopWidth AzLayoutBorderTopWidth;
 
+struct AzStyleScrollbarColor {
+    AzColorU thumb;
+    AzColorU track;
+};
+typedef struct AzStyleScrollbarColor AzStyleScrollbarColor;
+
+struct AzStyleScrollbarThumbHoverColor {
+    AzColorU inner;
+};
+typedef struct AzStyleScrollbarThumbHoverColor AzStyleScrollbarThumbHoverColor;
+
+struct AzStyleScrollbarThumbActiveColor {
+    AzColorU inner;
+};
+typedef struct AzStyleScrollbarThumbActiveColor AzStyleScrollbarThumbActiveColor;
+
+struct AzStyleScrollPadding {
+    AzPixelValue inner;
+};
+typedef struct AzStyleScrollPadding AzStyleScrollPadding;
+
 struct AzStyleFontSize {
     AzPixelValue inner;
 };
@@ -4526,6 +5091,11 @@ struct AzStylePerspectiveOrigin {
 };
 typedef struct AzStylePerspectiveOrigin AzStylePerspectiveOrigin;
 
+struct AzStylePerspective {
+    AzPixelValue inner;
+};
+typedef struct AzStylePerspective AzStylePerspective;
+
 struct AzStyleTransformMatrix2D {
     AzPixelValue a;
     AzPixelValue b;
@@ -5474,6 +6044,230 @@ union AzLayoutOverflowValue {
 };
 typedef union AzLayoutOverflowValue AzLayoutOverflowValue;
 
+enum AzStyleScrollbarWidthValueTag {
+   AzStyleScrollbarWidthValueTag_Auto,
+   AzStyleScrollbarWidthValueTag_None,
+   AzStyleScrollbarWidthValueTag_Inherit,
+   AzStyleScrollbarWidthValueTag_Initial,
+   AzStyleScrollbarWidthValueTag_Exact,
+};
+typedef enum AzStyleScrollbarWidthValueTag AzStyleScrollbarWidthValueTag;
+
+struct AzStyleScrollbarWidthValueVariant_Auto { AzStyleScrollbarWidthValueTag tag; };
+typedef struct AzStyleScrollbarWidthValueVariant_Auto AzStyleScrollbarWidthValueVariant_Auto;
+struct AzStyleScrollbarWidthValueVariant_None { AzStyleScrollbarWidthValueTag tag; };
+typedef struct AzStyleScrollbarWidthValueVariant_None AzStyleScrollbarWidthValueVariant_None;
+struct AzStyleScrollbarWidthValueVariant_Inherit { AzStyleScrollbarWidthValueTag tag; };
+typedef struct AzStyleScrollbarWidthValueVariant_Inherit AzStyleScrollbarWidthValueVariant_Inherit;
+struct AzStyleScrollbarWidthValueVariant_Initial { AzStyleScrollbarWidthValueTag tag; };
+typedef struct AzStyleScrollbarWidthValueVariant_Initial AzStyleScrollbarWidthValueVariant_Initial;
+struct AzStyleScrollbarWidthValueVariant_Exact { AzStyleScrollbarWidthValueTag tag; AzStyleScrollbarWidth payload; };
+typedef struct AzStyleScrollbarWidthValueVariant_Exact AzStyleScrollbarWidthValueVariant_Exact;
+union AzStyleScrollbarWidthValue {
+    AzStyleScrollbarWidthValueVariant_Auto Auto;
+    AzStyleScrollbarWidthValueVariant_None None;
+    AzStyleScrollbarWidthValueVariant_Inherit Inherit;
+    AzStyleScrollbarWidthValueVariant_Initial Initial;
+    AzStyleScrollbarWidthValueVariant_Exact Exact;
+};
+typedef union AzStyleScrollbarWidthValue AzStyleScrollbarWidthValue;
+
+enum AzStyleScrollbarColorValueTag {
+   AzStyleScrollbarColorValueTag_Auto,
+   AzStyleScrollbarColorValueTag_None,
+   AzStyleScrollbarColorValueTag_Inherit,
+   AzStyleScrollbarColorValueTag_Initial,
+   AzStyleScrollbarColorValueTag_Exact,
+};
+typedef enum AzStyleScrollbarColorValueTag AzStyleScrollbarColorValueTag;
+
+struct AzStyleScrollbarColorValueVariant_Auto { AzStyleScrollbarColorValueTag tag; };
+typedef struct AzStyleScrollbarColorValueVariant_Auto AzStyleScrollbarColorValueVariant_Auto;
+struct AzStyleScrollbarColorValueVariant_None { AzStyleScrollbarColorValueTag tag; };
+typedef struct AzStyleScrollbarColorValueVariant_None AzStyleScrollbarColorValueVariant_None;
+struct AzStyleScrollbarColorValueVariant_Inherit { AzStyleScrollbarColorValueTag tag; };
+typedef struct AzStyleScrollbarColorValueVariant_Inherit AzStyleScrollbarColorValueVariant_Inherit;
+struct AzStyleScrollbarColorValueVariant_Initial { AzStyleScrollbarColorValueTag tag; };
+typedef struct AzStyleScrollbarColorValueVariant_Initial AzStyleScrollbarColorValueVariant_Initial;
+struct AzStyleScrollbarColorValueVariant_Exact { AzStyleScrollbarColorValueTag tag; AzStyleScrollbarColor payload; };
+typedef struct AzStyleScrollbarColorValueVariant_Exact AzStyleScrollbarColorValueVariant_Exact;
+union AzStyleScrollbarColorValue {
+    AzStyleScrollbarColorValueVariant_Auto Auto;
+    AzStyleScrollbarColorValueVariant_None None;
+    AzStyleScrollbarColorValueVariant_Inherit Inherit;
+    AzStyleScrollbarColorValueVariant_Initial Initial;
+    AzStyleScrollbarColorValueVariant_Exact Exact;
+};
+typedef union AzStyleScrollbarColorValue AzStyleScrollbarColorValue;
+
+enum AzStyleScrollbarThumbHoverColorValueTag {
+   AzStyleScrollbarThumbHoverColorValueTag_Auto,
+   AzStyleScrollbarThumbHoverColorValueTag_None,
+   AzStyleScrollbarThumbHoverColorValueTag_Inherit,
+   AzStyleScrollbarThumbHoverColorValueTag_Initial,
+   AzStyleScrollbarThumbHoverColorValueTag_Exact,
+};
+typedef enum AzStyleScrollbarThumbHoverColorValueTag AzStyleScrollbarThumbHoverColorValueTag;
+
+struct AzStyleScrollbarThumbHoverColorValueVariant_Auto { AzStyleScrollbarThumbHoverColorValueTag tag; };
+typedef struct AzStyleScrollbarThumbHoverColorValueVariant_Auto AzStyleScrollbarThumbHoverColorValueVariant_Auto;
+struct AzStyleScrollbarThumbHoverColorValueVariant_None { AzStyleScrollbarThumbHoverColorValueTag tag; };
+typedef struct AzStyleScrollbarThumbHoverColorValueVariant_None AzStyleScrollbarThumbHoverColorValueVariant_None;
+struct AzStyleScrollbarThumbHoverColorValueVariant_Inherit { AzStyleScrollbarThumbHoverColorValueTag tag; };
+typedef struct AzStyleScrollbarThumbHoverColorValueVariant_Inherit AzStyleScrollbarThumbHoverColorValueVariant_Inherit;
+struct AzStyleScrollbarThumbHoverColorValueVariant_Initial { AzStyleScrollbarThumbHoverColorValueTag tag; };
+typedef struct AzStyleScrollbarThumbHoverColorValueVariant_Initial AzStyleScrollbarThumbHoverColorValueVariant_Initial;
+struct AzStyleScrollbarThumbHoverColorValueVariant_Exact { AzStyleScrollbarThumbHoverColorValueTag tag; AzStyleScrollbarThumbHoverColor payload; };
+typedef struct AzStyleScrollbarThumbHoverColorValueVariant_Exact AzStyleScrollbarThumbHoverColorValueVariant_Exact;
+union AzStyleScrollbarThumbHoverColorValue {
+    AzStyleScrollbarThumbHoverColorValueVariant_Auto Auto;
+    AzStyleScrollbarThumbHoverColorValueVariant_None None;
+    AzStyleScrollbarThumbHoverColorValueVariant_Inherit Inherit;
+    AzStyleScrollbarThumbHoverColorValueVariant_Initial Initial;
+    AzStyleScrollbarThumbHoverColorValueVariant_Exact Exact;
+};
+typedef union AzStyleScrollbarThumbHoverColorValue AzStyleScrollbarThumbHoverColorValue;
+
+enum AzStyleScrollbarThumbActiveColorValueTag {
+   AzStyleScrollbarThumbActiveColorValueTag_Auto,
+   AzStyleScrollbarThumbActiveColorValueTag_None,
+   AzStyleScrollbarThumbActiveColorValueTag_Inherit,
+   AzStyleScrollbarThumbActiveColorValueTag_Initial,
+   AzStyleScrollbarThumbActiveColorValueTag_Exact,
+};
+typedef enum AzStyleScrollbarThumbActiveColorValueTag AzStyleScrollbarThumbActiveColorValueTag;
+
+struct AzStyleScrollbarThumbActiveColorValueVariant_Auto { AzStyleScrollbarThumbActiveColorValueTag tag; };
+typedef struct AzStyleScrollbarThumbActiveColorValueVariant_Auto AzStyleScrollbarThumbActiveColorValueVariant_Auto;
+struct AzStyleScrollbarThumbActiveColorValueVariant_None { AzStyleScrollbarThumbActiveColorValueTag tag; };
+typedef struct AzStyleScrollbarThumbActiveColorValueVariant_None AzStyleScrollbarThumbActiveColorValueVariant_None;
+struct AzStyleScrollbarThumbActiveColorValueVariant_Inherit { AzStyleScrollbarThumbActiveColorValueTag tag; };
+typedef struct AzStyleScrollbarThumbActiveColorValueVariant_Inherit AzStyleScrollbarThumbActiveColorValueVariant_Inherit;
+struct AzStyleScrollbarThumbActiveColorValueVariant_Initial { AzStyleScrollbarThumbActiveColorValueTag tag; };
+typedef struct AzStyleScrollbarThumbActiveColorValueVariant_Initial AzStyleScrollbarThumbActiveColorValueVariant_Initial;
+struct AzStyleScrollbarThumbActiveColorValueVariant_Exact { AzStyleScrollbarThumbActiveColorValueTag tag; AzStyleScrollbarThumbActiveColor payload; };
+typedef struct AzStyleScrollbarThumbActiveColorValueVariant_Exact AzStyleScrollbarThumbActiveColorValueVariant_Exact;
+union AzStyleScrollbarThumbActiveColorValue {
+    AzStyleScrollbarThumbActiveColorValueVariant_Auto Auto;
+    AzStyleScrollbarThumbActiveColorValueVariant_None None;
+    AzStyleScrollbarThumbActiveColorValueVariant_Inherit Inherit;
+    AzStyleScrollbarThumbActiveColorValueVariant_Initial Initial;
+    AzStyleScrollbarThumbActiveColorValueVariant_Exact Exact;
+};
+typedef union AzStyleScrollbarThumbActiveColorValue AzStyleScrollbarThumbActiveColorValue;
+
+enum AzStyleWindowDragRegionValueTag {
+   AzStyleWindowDragRegionValueTag_Auto,
+   AzStyleWindowDragRegionValueTag_None,
+   AzStyleWindowDragRegionValueTag_Inherit,
+   AzStyleWindowDragRegionValueTag_Initial,
+   AzStyleWindowDragRegionValueTag_Exact,
+};
+typedef enum AzStyleWindowDragRegionValueTag AzStyleWindowDragRegionValueTag;
+
+struct AzStyleWindowDragRegionValueVariant_Auto { AzStyleWindowDragRegionValueTag tag; };
+typedef struct AzStyleWindowDragRegionValueVariant_Auto AzStyleWindowDragRegionValueVariant_Auto;
+struct AzStyleWindowDragRegionValueVariant_None { AzStyleWindowDragRegionValueTag tag; };
+typedef struct AzStyleWindowDragRegionValueVariant_None AzStyleWindowDragRegionValueVariant_None;
+struct AzStyleWindowDragRegionValueVariant_Inherit { AzStyleWindowDragRegionValueTag tag; };
+typedef struct AzStyleWindowDragRegionValueVariant_Inherit AzStyleWindowDragRegionValueVariant_Inherit;
+struct AzStyleWindowDragRegionValueVariant_Initial { AzStyleWindowDragRegionValueTag tag; };
+typedef struct AzStyleWindowDragRegionValueVariant_Initial AzStyleWindowDragRegionValueVariant_Initial;
+struct AzStyleWindowDragRegionValueVariant_Exact { AzStyleWindowDragRegionValueTag tag; AzStyleWindowDragRegion payload; };
+typedef struct AzStyleWindowDragRegionValueVariant_Exact AzStyleWindowDragRegionValueVariant_Exact;
+union AzStyleWindowDragRegionValue {
+    AzStyleWindowDragRegionValueVariant_Auto Auto;
+    AzStyleWindowDragRegionValueVariant_None None;
+    AzStyleWindowDragRegionValueVariant_Inherit Inherit;
+    AzStyleWindowDragRegionValueVariant_Initial Initial;
+    AzStyleWindowDragRegionValueVariant_Exact Exact;
+};
+typedef union AzStyleWindowDragRegionValue AzStyleWindowDragRegionValue;
+
+enum AzStyleCaretAnimationDurationValueTag {
+   AzStyleCaretAnimationDurationValueTag_Auto,
+   AzStyleCaretAnimationDurationValueTag_None,
+   AzStyleCaretAnimationDurationValueTag_Inherit,
+   AzStyleCaretAnimationDurationValueTag_Initial,
+   AzStyleCaretAnimationDurationValueTag_Exact,
+};
+typedef enum AzStyleCaretAnimationDurationValueTag AzStyleCaretAnimationDurationValueTag;
+
+struct AzStyleCaretAnimationDurationValueVariant_Auto { AzStyleCaretAnimationDurationValueTag tag; };
+typedef struct AzStyleCaretAnimationDurationValueVariant_Auto AzStyleCaretAnimationDurationValueVariant_Auto;
+struct AzStyleCaretAnimationDurationValueVariant_None { AzStyleCaretAnimationDurationValueTag tag; };
+typedef struct AzStyleCaretAnimationDurationValueVariant_None AzStyleCaretAnimationDurationValueVariant_None;
+struct AzStyleCaretAnimationDurationValueVariant_Inherit { AzStyleCaretAnimationDurationValueTag tag; };
+typedef struct AzStyleCaretAnimationDurationValueVariant_Inherit AzStyleCaretAnimationDurationValueVariant_Inherit;
+struct AzStyleCaretAnimationDurationValueVariant_Initial { AzStyleCaretAnimationDurationValueTag tag; };
+typedef struct AzStyleCaretAnimationDurationValueVariant_Initial AzStyleCaretAnimationDurationValueVariant_Initial;
+struct AzStyleCaretAnimationDurationValueVariant_Exact { AzStyleCaretAnimationDurationValueTag tag; AzStyleCaretAnimationDuration payload; };
+typedef struct AzStyleCaretAnimationDurationValueVariant_Exact AzStyleCaretAnimationDurationValueVariant_Exact;
+union AzStyleCaretAnimationDurationValue {
+    AzStyleCaretAnimationDurationValueVariant_Auto Auto;
+    AzStyleCaretAnimationDurationValueVariant_None None;
+    AzStyleCaretAnimationDurationValueVariant_Inherit Inherit;
+    AzStyleCaretAnimationDurationValueVariant_Initial Initial;
+    AzStyleCaretAnimationDurationValueVariant_Exact Exact;
+};
+typedef union AzStyleCaretAnimationDurationValue AzStyleCaretAnimationDurationValue;
+
+enum AzStyleScrollPaddingValueTag {
+   AzStyleScrollPaddingValueTag_Auto,
+   AzStyleScrollPaddingValueTag_None,
+   AzStyleScrollPaddingValueTag_Inherit,
+   AzStyleScrollPaddingValueTag_Initial,
+   AzStyleScrollPaddingValueTag_Exact,
+};
+typedef enum AzStyleScrollPaddingValueTag AzStyleScrollPaddingValueTag;
+
+struct AzStyleScrollPaddingValueVariant_Auto { AzStyleScrollPaddingValueTag tag; };
+typedef struct AzStyleScrollPaddingValueVariant_Auto AzStyleScrollPaddingValueVariant_Auto;
+struct AzStyleScrollPaddingValueVariant_None { AzStyleScrollPaddingValueTag tag; };
+typedef struct AzStyleScrollPaddingValueVariant_None AzStyleScrollPaddingValueVariant_None;
+struct AzStyleScrollPaddingValueVariant_Inherit { AzStyleScrollPaddingValueTag tag; };
+typedef struct AzStyleScrollPaddingValueVariant_Inherit AzStyleScrollPaddingValueVariant_Inherit;
+struct AzStyleScrollPaddingValueVariant_Initial { AzStyleScrollPaddingValueTag tag; };
+typedef struct AzStyleScrollPaddingValueVariant_Initial AzStyleScrollPaddingValueVariant_Initial;
+struct AzStyleScrollPaddingValueVariant_Exact { AzStyleScrollPaddingValueTag tag; AzStyleScrollPadding payload; };
+typedef struct AzStyleScrollPaddingValueVariant_Exact AzStyleScrollPaddingValueVariant_Exact;
+union AzStyleScrollPaddingValue {
+    AzStyleScrollPaddingValueVariant_Auto Auto;
+    AzStyleScrollPaddingValueVariant_None None;
+    AzStyleScrollPaddingValueVariant_Inherit Inherit;
+    AzStyleScrollPaddingValueVariant_Initial Initial;
+    AzStyleScrollPaddingValueVariant_Exact Exact;
+};
+typedef union AzStyleScrollPaddingValue AzStyleScrollPaddingValue;
+
+enum AzStyleOverflowAnchorValueTag {
+   AzStyleOverflowAnchorValueTag_Auto,
+   AzStyleOverflowAnchorValueTag_None,
+   AzStyleOverflowAnchorValueTag_Inherit,
+   AzStyleOverflowAnchorValueTag_Initial,
+   AzStyleOverflowAnchorValueTag_Exact,
+};
+typedef enum AzStyleOverflowAnchorValueTag AzStyleOverflowAnchorValueTag;
+
+struct AzStyleOverflowAnchorValueVariant_Auto { AzStyleOverflowAnchorValueTag tag; };
+typedef struct AzStyleOverflowAnchorValueVariant_Auto AzStyleOverflowAnchorValueVariant_Auto;
+struct AzStyleOverflowAnchorValueVariant_None { AzStyleOverflowAnchorValueTag tag; };
+typedef struct AzStyleOverflowAnchorValueVariant_None AzStyleOverflowAnchorValueVariant_None;
+struct AzStyleOverflowAnchorValueVariant_Inherit { AzStyleOverflowAnchorValueTag tag; };
+typedef struct AzStyleOverflowAnchorValueVariant_Inherit AzStyleOverflowAnchorValueVariant_Inherit;
+struct AzStyleOverflowAnchorValueVariant_Initial { AzStyleOverflowAnchorValueTag tag; };
+typedef struct AzStyleOverflowAnchorValueVariant_Initial AzStyleOverflowAnchorValueVariant_Initial;
+struct AzStyleOverflowAnchorValueVariant_Exact { AzStyleOverflowAnchorValueTag tag; AzStyleOverflowAnchor payload; };
+typedef struct AzStyleOverflowAnchorValueVariant_Exact AzStyleOverflowAnchorValueVariant_Exact;
+union AzStyleOverflowAnchorValue {
+    AzStyleOverflowAnchorValueVariant_Auto Auto;
+    AzStyleOverflowAnchorValueVariant_None None;
+    AzStyleOverflowAnchorValueVariant_Inherit Inherit;
+    AzStyleOverflowAnchorValueVariant_Initial Initial;
+    AzStyleOverflowAnchorValueVariant_Exact Exact;
+};
+typedef union AzStyleOverflowAnchorValue AzStyleOverflowAnchorValue;
+
 enum AzStyleBorderBottomColorValueTag {
    AzStyleBorderBottomColorValueTag_Auto,
    AzStyleBorderBottomColorValueTag_None,
@@ -5922,34 +6716,6 @@ union AzLayoutBorderTopWidthValue {
 };
 typedef union AzLayoutBorderTopWidthValue AzLayoutBorderTopWidthValue;
 
-enum AzStyleCursorValueTag {
-   AzStyleCursorValueTag_Auto,
-   AzStyleCursorValueTag_None,
-   AzStyleCursorValueTag_Inherit,
-   AzStyleCursorValueTag_Initial,
-   AzStyleCursorValueTag_Exact,
-};
-typedef enum AzStyleCursorValueTag AzStyleCursorValueTag;
-
-struct AzStyleCursorValueVariant_Auto { AzStyleCursorValueTag tag; };
-typedef struct AzStyleCursorValueVariant_Auto AzStyleCursorValueVariant_Auto;
-struct AzStyleCursorValueVariant_None { AzStyleCursorValueTag tag; };
-typedef struct AzStyleCursorValueVariant_None AzStyleCursorValueVariant_None;
-struct AzStyleCursorValueVariant_Inherit { AzStyleCursorValueTag tag; };
-typedef struct AzStyleCursorValueVariant_Inherit AzStyleCursorValueVariant_Inherit;
-struct AzStyleCursorValueVariant_Initial { AzStyleCursorValueTag tag; };
-typedef struct AzStyleCursorValueVariant_Initial AzStyleCursorValueVariant_Initial;
-struct AzStyleCursorValueVariant_Exact { AzStyleCursorValueTag tag; AzStyleCursor payload; };
-typedef struct AzStyleCursorValueVariant_Exact AzStyleCursorValueVariant_Exact;
-union AzStyleCursorValue {
-    AzStyleCursorValueVariant_Auto Auto;
-    AzStyleCursorValueVariant_None None;
-    AzStyleCursorValueVariant_Inherit Inherit;
-    AzStyleCursorValueVariant_Initial Initial;
-    AzStyleCursorValueVariant_Exact Exact;
-};
-typedef union AzStyleCursorValue AzStyleCursorValue;
-
 enum AzStyleFontSizeValueTag {
    AzStyleFontSizeValueTag_Auto,
    AzStyleFontSizeValueTag_None,
@@ -6062,6 +6828,34 @@ union AzStyleTabWidthValue {
 };
 typedef union AzStyleTabWidthValue AzStyleTabWidthValue;
 
+enum AzStyleHyphensValueTag {
+   AzStyleHyphensValueTag_Auto,
+   AzStyleHyphensValueTag_None,
+   AzStyleHyphensValueTag_Inherit,
+   AzStyleHyphensValueTag_Initial,
+   AzStyleHyphensValueTag_Exact,
+};
+typedef enum AzStyleHyphensValueTag AzStyleHyphensValueTag;
+
+struct AzStyleHyphensValueVariant_Auto { AzStyleHyphensValueTag tag; };
+typedef struct AzStyleHyphensValueVariant_Auto AzStyleHyphensValueVariant_Auto;
+struct AzStyleHyphensValueVariant_None { AzStyleHyphensValueTag tag; };
+typedef struct AzStyleHyphensValueVariant_None AzStyleHyphensValueVariant_None;
+struct AzStyleHyphensValueVariant_Inherit { AzStyleHyphensValueTag tag; };
+typedef struct AzStyleHyphensValueVariant_Inherit AzStyleHyphensValueVariant_Inherit;
+struct AzStyleHyphensValueVariant_Initial { AzStyleHyphensValueTag tag; };
+typedef struct AzStyleHyphensValueVariant_Initial AzStyleHyphensValueVariant_Initial;
+struct AzStyleHyphensValueVariant_Exact { AzStyleHyphensValueTag tag; AzStyleHyphens payload; };
+typedef struct AzStyleHyphensValueVariant_Exact AzStyleHyphensValueVariant_Exact;
+union AzStyleHyphensValue {
+    AzStyleHyphensValueVariant_Auto Auto;
+    AzStyleHyphensValueVariant_None None;
+    AzStyleHyphensValueVariant_Inherit Inherit;
+    AzStyleHyphensValueVariant_Initial Initial;
+    AzStyleHyphensValueVariant_Exact Exact;
+};
+typedef union AzStyleHyphensValue AzStyleHyphensValue;
+
 enum AzStyleTextAlignValueTag {
    AzStyleTextAlignValueTag_Auto,
    AzStyleTextAlignValueTag_None,
@@ -6258,6 +7052,62 @@ union AzStyleBackfaceVisibilityValue {
 };
 typedef union AzStyleBackfaceVisibilityValue AzStyleBackfaceVisibilityValue;
 
+enum AzStylePerspectiveValueTag {
+   AzStylePerspectiveValueTag_Auto,
+   AzStylePerspectiveValueTag_None,
+   AzStylePerspectiveValueTag_Inherit,
+   AzStylePerspectiveValueTag_Initial,
+   AzStylePerspectiveValueTag_Exact,
+};
+typedef enum AzStylePerspectiveValueTag AzStylePerspectiveValueTag;
+
+struct AzStylePerspectiveValueVariant_Auto { AzStylePerspectiveValueTag tag; };
+typedef struct AzStylePerspectiveValueVariant_Auto AzStylePerspectiveValueVariant_Auto;
+struct AzStylePerspectiveValueVariant_None { AzStylePerspectiveValueTag tag; };
+typedef struct AzStylePerspectiveValueVariant_None AzStylePerspectiveValueVariant_None;
+struct AzStylePerspectiveValueVariant_Inherit { AzStylePerspectiveValueTag tag; };
+typedef struct AzStylePerspectiveValueVariant_Inherit AzStylePerspectiveValueVariant_Inherit;
+struct AzStylePerspectiveValueVariant_Initial { AzStylePerspectiveValueTag tag; };
+typedef struct AzStylePerspectiveValueVariant_Initial AzStylePerspectiveValueVariant_Initial;
+struct AzStylePerspectiveValueVariant_Exact { AzStylePerspectiveValueTag tag; AzStylePerspective payload; };
+typedef struct AzStylePerspectiveValueVariant_Exact AzStylePerspectiveValueVariant_Exact;
+union AzStylePerspectiveValue {
+    AzStylePerspectiveValueVariant_Auto Auto;
+    AzStylePerspectiveValueVariant_None None;
+    AzStylePerspectiveValueVariant_Inherit Inherit;
+    AzStylePerspectiveValueVariant_Initial Initial;
+    AzStylePerspectiveValueVariant_Exact Exact;
+};
+typedef union AzStylePerspectiveValue AzStylePerspectiveValue;
+
+enum AzStyleTransformStyleValueTag {
+   AzStyleTransformStyleValueTag_Auto,
+   AzStyleTransformStyleValueTag_None,
+   AzStyleTransformStyleValueTag_Inherit,
+   AzStyleTransformStyleValueTag_Initial,
+   AzStyleTransformStyleValueTag_Exact,
+};
+typedef enum AzStyleTransformStyleValueTag AzStyleTransformStyleValueTag;
+
+struct AzStyleTransformStyleValueVariant_Auto { AzStyleTransformStyleValueTag tag; };
+typedef struct AzStyleTransformStyleValueVariant_Auto AzStyleTransformStyleValueVariant_Auto;
+struct AzStyleTransformStyleValueVariant_None { AzStyleTransformStyleValueTag tag; };
+typedef struct AzStyleTransformStyleValueVariant_None AzStyleTransformStyleValueVariant_None;
+struct AzStyleTransformStyleValueVariant_Inherit { AzStyleTransformStyleValueTag tag; };
+typedef struct AzStyleTransformStyleValueVariant_Inherit AzStyleTransformStyleValueVariant_Inherit;
+struct AzStyleTransformStyleValueVariant_Initial { AzStyleTransformStyleValueTag tag; };
+typedef struct AzStyleTransformStyleValueVariant_Initial AzStyleTransformStyleValueVariant_Initial;
+struct AzStyleTransformStyleValueVariant_Exact { AzStyleTransformStyleValueTag tag; AzStyleTransformStyle payload; };
+typedef struct AzStyleTransformStyleValueVariant_Exact AzStyleTransformStyleValueVariant_Exact;
+union AzStyleTransformStyleValue {
+    AzStyleTransformStyleValueVariant_Auto Auto;
+    AzStyleTransformStyleValueVariant_None None;
+    AzStyleTransformStyleValueVariant_Inherit Inherit;
+    AzStyleTransformStyleValueVariant_Initial Initial;
+    AzStyleTransformStyleValueVariant_Exact Exact;
+};
+typedef union AzStyleTransformStyleValue AzStyleTransformStyleValue;
+
 enum AzStyleMixBlendModeValueTag {
    AzStyleMixBlendModeValueTag_Auto,
    AzStyleMixBlendModeValueTag_None,
@@ -6349,12 +7199,30 @@ struct AzTextInputOnFocusLost {
 };
 typedef struct AzTextInputOnFocusLost AzTextInputOnFocusLost;
 
+struct AzTextInputOnSpellingSuggestions {
+    AzRefAny data;
+    AzTextInputOnSpellingSuggestionsCallback callback;
+};
+typedef struct AzTextInputOnSpellingSuggestions AzTextInputOnSpellingSuggestions;
+
 struct AzOnTextInputReturn {
     AzUpdate update;
     AzTextInputValid valid;
 };
 typedef struct AzOnTextInputReturn AzOnTextInputReturn;
 
+struct AzNumberInputState {
+    double previous;
+    double number;
+    double min;
+    double max;
+    double step;
+    AzNumberInputMode mode;
+    uint32_t decimal_separator;
+    bool  is_valid;
+};
+typedef struct AzNumberInputState AzNumberInputState;
+
 struct AzNumberInputOnValueChange {
     AzRefAny data;
     AzNumberInputOnValueChangeCallback callback;
@@ -6457,6 +7325,12 @@ struct AzDropDownOnChoiceChange {
 };
 typedef struct AzDropDownOnChoiceChange AzDropDownOnChoiceChange;
 
+struct AzToastOnAction {
+    AzRefAny data;
+    AzToastOnActionCallback callback;
+};
+typedef struct AzToastOnAction AzToastOnAction;
+
 struct AzParentWithNodeDepth {
     size_t depth;
     AzNodeId node_id;
@@ -6699,6 +7573,14 @@ struct AzInlineGlyphVec {
 };
 typedef struct AzInlineGlyphVec AzInlineGlyphVec;
 
+struct AzTextUnderlineVec {
+    AzTextUnderline* ptr;
+    size_t len;
+    size_t cap;
+    AzTextUnderlineVecDestructor destructor;
+};
+typedef struct AzTextUnderlineVec AzTextUnderlineVec;
+
 struct AzInlineTextHitVec {
     AzInlineTextHit* ptr;
     size_t len;
@@ -6707,6 +7589,14 @@ struct AzInlineTextHitVec {
 };
 typedef struct AzInlineTextHitVec AzInlineTextHitVec;
 
+struct AzTouchPointVec {
+    AzTouchPoint* ptr;
+    size_t len;
+    size_t cap;
+    AzTouchPointVecDestructor destructor;
+};
+typedef struct AzTouchPointVec AzTouchPointVec;
+
 struct AzVideoModeVec {
     AzVideoMode* ptr;
     size_t len;
@@ -6981,6 +7871,22 @@ union AzOptionDropDownOnChoiceChange {
 };
 typedef union AzOptionDropDownOnChoiceChange AzOptionDropDownOnChoiceChange;
 
+enum AzOptionToastOnActionTag {
+   AzOptionToastOnActionTag_None,
+   AzOptionToastOnActionTag_Some,
+};
+typedef enum AzOptionToastOnActionTag AzOptionToastOnActionTag;
+
+struct AzOptionToastOnActionVariant_None { AzOptionToastOnActionTag tag; };
+typedef struct AzOptionToastOnActionVariant_None AzOptionToastOnActionVariant_None;
+struct AzOptionToastOnActionVariant_Some { AzOptionToastOnActionTag tag; AzToastOnAction payload; };
+typedef struct AzOptionToastOnActionVariant_Some AzOptionToastOnActionVariant_Some;
+union AzOptionToastOnAction {
+    AzOptionToastOnActionVariant_None None;
+    AzOptionToastOnActionVariant_Some Some;
+};
+typedef union AzOptionToastOnAction AzOptionToastOnAction;
+
 enum AzOptionNodeGraphOnNodeAddedTag {
    AzOptionNodeGraphOnNodeAddedTag_None,
    AzOptionNodeGraphOnNodeAddedTag_Some,
@@ -7237,6 +8143,22 @@ union AzOptionTextInputOnFocusLost {
 };
 typedef union AzOptionTextInputOnFocusLost AzOptionTextInputOnFocusLost;
 
+enum AzOptionTextInputOnSpellingSuggestionsTag {
+   AzOptionTextInputOnSpellingSuggestionsTag_None,
+   AzOptionTextInputOnSpellingSuggestionsTag_Some,
+};
+typedef enum AzOptionTextInputOnSpellingSuggestionsTag AzOptionTextInputOnSpellingSuggestionsTag;
+
+struct AzOptionTextInputOnSpellingSuggestionsVariant_None { AzOptionTextInputOnSpellingSuggestionsTag tag; };
+typedef struct AzOptionTextInputOnSpellingSuggestionsVariant_None AzOptionTextInputOnSpellingSuggestionsVariant_None;
+struct AzOptionTextInputOnSpellingSuggestionsVariant_Some { AzOptionTextInputOnSpellingSuggestionsTag tag; AzTextInputOnSpellingSuggestions payload; };
+typedef struct AzOptionTextInputOnSpellingSuggestionsVariant_Some AzOptionTextInputOnSpellingSuggestionsVariant_Some;
+union AzOptionTextInputOnSpellingSuggestions {
+    AzOptionTextInputOnSpellingSuggestionsVariant_None None;
+    AzOptionTextInputOnSpellingSuggestionsVariant_Some Some;
+};
+typedef union AzOptionTextInputOnSpellingSuggestions AzOptionTextInputOnSpellingSuggestions;
+
 enum AzOptionTextInputSelectionTag {
    AzOptionTextInputSelectionTag_None,
    AzOptionTextInputSelectionTag_Some,
@@ -7333,6 +8255,38 @@ union AzOptionPositionInfo {
 };
 typedef union AzOptionPositionInfo AzOptionPositionInfo;
 
+enum AzOptionNodeRectTag {
+   AzOptionNodeRectTag_None,
+   AzOptionNodeRectTag_Some,
+};
+typedef enum AzOptionNodeRectTag AzOptionNodeRectTag;
+
+struct AzOptionNodeRectVariant_None { AzOptionNodeRectTag tag; };
+typedef struct AzOptionNodeRectVariant_None AzOptionNodeRectVariant_None;
+struct AzOptionNodeRectVariant_Some { AzOptionNodeRectTag tag; AzNodeRect payload; };
+typedef struct AzOptionNodeRectVariant_Some AzOptionNodeRectVariant_Some;
+union AzOptionNodeRect {
+    AzOptionNodeRectVariant_None None;
+    AzOptionNodeRectVariant_Some Some;
+};
+typedef union AzOptionNodeRect AzOptionNodeRect;
+
+enum AzOptionScrollInfoTag {
+   AzOptionScrollInfoTag_None,
+   AzOptionScrollInfoTag_Some,
+};
+typedef enum AzOptionScrollInfoTag AzOptionScrollInfoTag;
+
+struct AzOptionScrollInfoVariant_None { AzOptionScrollInfoTag tag; };
+typedef struct AzOptionScrollInfoVariant_None AzOptionScrollInfoVariant_None;
+struct AzOptionScrollInfoVariant_Some { AzOptionScrollInfoTag tag; AzScrollInfo payload; };
+typedef struct AzOptionScrollInfoVariant_Some AzOptionScrollInfoVariant_Some;
+union AzOptionScrollInfo {
+    AzOptionScrollInfoVariant_None None;
+    AzOptionScrollInfoVariant_Some Some;
+};
+typedef union AzOptionScrollInfo AzOptionScrollInfo;
+
 enum AzOptionTimerIdTag {
    AzOptionTimerIdTag_None,
    AzOptionTimerIdTag_Some,
@@ -7493,6 +8447,38 @@ union AzOptionCallback {
 };
 typedef union AzOptionCallback AzOptionCallback;
 
+enum AzOptionPaintCallbackTag {
+   AzOptionPaintCallbackTag_None,
+   AzOptionPaintCallbackTag_Some,
+};
+typedef enum AzOptionPaintCallbackTag AzOptionPaintCallbackTag;
+
+struct AzOptionPaintCallbackVariant_None { AzOptionPaintCallbackTag tag; };
+typedef struct AzOptionPaintCallbackVariant_None AzOptionPaintCallbackVariant_None;
+struct AzOptionPaintCallbackVariant_Some { AzOptionPaintCallbackTag tag; AzPaintCallback payload; };
+typedef struct AzOptionPaintCallbackVariant_Some AzOptionPaintCallbackVariant_Some;
+union AzOptionPaintCallback {
+    AzOptionPaintCallbackVariant_None None;
+    AzOptionPaintCallbackVariant_Some Some;
+};
+typedef union AzOptionPaintCallback AzOptionPaintCallback;
+
+enum AzOptionCrashHandlerTag {
+   AzOptionCrashHandlerTag_None,
+   AzOptionCrashHandlerTag_Some,
+};
+typedef enum AzOptionCrashHandlerTag AzOptionCrashHandlerTag;
+
+struct AzOptionCrashHandlerVariant_None { AzOptionCrashHandlerTag tag; };
+typedef struct AzOptionCrashHandlerVariant_None AzOptionCrashHandlerVariant_None;
+struct AzOptionCrashHandlerVariant_Some { AzOptionCrashHandlerTag tag; AzCrashHandler payload; };
+typedef struct AzOptionCrashHandlerVariant_Some AzOptionCrashHandlerVariant_Some;
+union AzOptionCrashHandler {
+    AzOptionCrashHandlerVariant_None None;
+    AzOptionCrashHandlerVariant_Some Some;
+};
+typedef union AzOptionCrashHandler AzOptionCrashHandler;
+
 enum AzOptionThreadSendMsgTag {
    AzOptionThreadSendMsgTag_None,
    AzOptionThreadSendMsgTag_Some,
@@ -7653,6 +8639,22 @@ union AzOptionSvgDashPattern {
 };
 typedef union AzOptionSvgDashPattern AzOptionSvgDashPattern;
 
+enum AzOptionRawWindowHandleTag {
+   AzOptionRawWindowHandleTag_None,
+   AzOptionRawWindowHandleTag_Some,
+};
+typedef enum AzOptionRawWindowHandleTag AzOptionRawWindowHandleTag;
+
+struct AzOptionRawWindowHandleVariant_None { AzOptionRawWindowHandleTag tag; };
+typedef struct AzOptionRawWindowHandleVariant_None AzOptionRawWindowHandleVariant_None;
+struct AzOptionRawWindowHandleVariant_Some { AzOptionRawWindowHandleTag tag; AzRawWindowHandle payload; };
+typedef struct AzOptionRawWindowHandleVariant_Some AzOptionRawWindowHandleVariant_Some;
+union AzOptionRawWindowHandle {
+    AzOptionRawWindowHandleVariant_None None;
+    AzOptionRawWindowHandleVariant_Some Some;
+};
+typedef union AzOptionRawWindowHandle AzOptionRawWindowHandle;
+
 enum AzOptionLogicalPositionTag {
    AzOptionLogicalPositionTag_None,
    AzOptionLogicalPositionTag_Some,
@@ -7877,15 +8879,32 @@ struct AzInvalidSpaceError {
 };
 typedef struct AzInvalidSpaceError AzInvalidSpaceError;
 
-struct AzAppConfig {
-    AzLayoutSolver layout_solver;
-    AzAppLogLevel log_level;
-    bool  enable_visual_panic_hook;
-    bool  enable_logging_on_panic;
-    bool  enable_tab_navigation;
-    AzSystemCallbacks system_callbacks;
+struct AzSplashOptions {
+    AzOptionImageRef logo;
+    AzLogicalSize size;
+    AzColorU background_color;
+    AzColorU progress_bar_color;
+    AzDuration cross_fade;
 };
-typedef struct AzAppConfig AzAppConfig;
+typedef struct AzSplashOptions AzSplashOptions;
+
+struct AzRecordedFrame {
+    AzRawWindowHandle window_handle;
+    size_t frame_index;
+    AzDuration timestamp;
+    AzDuration duration;
+    AzImageRef image;
+};
+typedef struct AzRecordedFrame AzRecordedFrame;
+
+struct AzScrollbarConfig {
+    AzScrollbarMode mode;
+    float min_thumb_length;
+    float hit_area_margin;
+    AzOptionDuration auto_hide_delay;
+    AzDuration auto_hide_fade_duration;
+};
+typedef struct AzScrollbarConfig AzScrollbarConfig;
 
 struct AzSmallWindowIconBytes {
     AzIconKey key;
@@ -7926,6 +8945,10 @@ struct AzWindowSize {
     uint32_t dpi;
     AzOptionLogicalSize min_dimensions;
     AzOptionLogicalSize max_dimensions;
+    bool  min_dimensions_from_content;
+    AzOptionLogicalSize resize_increments;
+    AzOptionF32 aspect_ratio_lock;
+    float zoom;
 };
 typedef struct AzWindowSize AzWindowSize;
 
@@ -7934,6 +8957,9 @@ struct AzKeyboardState {
     AzOptionVirtualKeyCode current_virtual_keycode;
     AzVirtualKeyCodeVec pressed_virtual_keycodes;
     AzScanCodeVec pressed_scancodes;
+    bool  caps_lock_enabled;
+    bool  num_lock_enabled;
+    bool  scroll_lock_enabled;
 };
 typedef struct AzKeyboardState AzKeyboardState;
 
@@ -7944,11 +8970,18 @@ struct AzMouseState {
     bool  left_down;
     bool  right_down;
     bool  middle_down;
+    uint32_t click_count;
     AzOptionF32 scroll_x;
     AzOptionF32 scroll_y;
 };
 typedef struct AzMouseState AzMouseState;
 
+struct AzTouchState {
+    AzTouchPointVec touches;
+    AzInputModality input_modality;
+};
+typedef struct AzTouchState AzTouchState;
+
 struct AzMarshaledLayoutCallback {
     AzRefAny marshal_data;
     AzMarshaledLayoutCallbackInner cb;
@@ -8005,6 +9038,18 @@ union AzAnimationEasing {
 };
 typedef union AzAnimationEasing AzAnimationEasing;
 
+enum AzPaintPrimitiveTag {
+   AzPaintPrimitiveTag_Rect,
+};
+typedef enum AzPaintPrimitiveTag AzPaintPrimitiveTag;
+
+struct AzPaintPrimitiveVariant_Rect { AzPaintPrimitiveTag tag; AzPaintRect payload; };
+typedef struct AzPaintPrimitiveVariant_Rect AzPaintPrimitiveVariant_Rect;
+union AzPaintPrimitive {
+    AzPaintPrimitiveVariant_Rect Rect;
+};
+typedef union AzPaintPrimitive AzPaintPrimitive;
+
 struct AzRenderImageCallbackInfo {
     AzDomNodeId callback_node_id;
     AzHidpiAdjustedBounds bounds;
@@ -8032,6 +9077,14 @@ struct AzLayoutCallbackInfo {
 };
 typedef struct AzLayoutCallbackInfo AzLayoutCallbackInfo;
 
+struct AzCustomLayoutNode {
+    AzMeasureCallback measure;
+    AzArrangeCallback arrange;
+    AzOptionPaintCallback paint;
+    AzRefAny data;
+};
+typedef struct AzCustomLayoutNode AzCustomLayoutNode;
+
 enum AzEventFilterTag {
    AzEventFilterTag_Hover,
    AzEventFilterTag_Not,
@@ -8076,6 +9129,20 @@ struct AzVirtualKeyCodeCombo {
 };
 typedef struct AzVirtualKeyCodeCombo AzVirtualKeyCodeCombo;
 
+struct AzShortcut {
+    AzVirtualKeyCodeCombo keys;
+    AzCallback callback;
+    AzRefAny data;
+};
+typedef struct AzShortcut AzShortcut;
+
+struct AzShortcutConflict {
+    AzVirtualKeyCodeCombo keys;
+    size_t existing;
+    size_t conflicting;
+};
+typedef struct AzShortcutConflict AzShortcutConflict;
+
 enum AzCssPathPseudoSelectorTag {
    AzCssPathPseudoSelectorTag_First,
    AzCssPathPseudoSelectorTag_Last,
@@ -8083,6 +9150,12 @@ enum AzCssPathPseudoSelectorTag {
    AzCssPathPseudoSelectorTag_Hover,
    AzCssPathPseudoSelectorTag_Active,
    AzCssPathPseudoSelectorTag_Focus,
+   AzCssPathPseudoSelectorTag_Disabled,
+   AzCssPathPseudoSelectorTag_Checked,
+   AzCssPathPseudoSelectorTag_Selected,
+   AzCssPathPseudoSelectorTag_Indeterminate,
+   AzCssPathPseudoSelectorTag_Before,
+   AzCssPathPseudoSelectorTag_After,
 };
 typedef enum AzCssPathPseudoSelectorTag AzCssPathPseudoSelectorTag;
 
@@ -8098,6 +9171,18 @@ struct AzCssPathPseudoSelectorVariant_Active { AzCssPathPseudoSelectorTag tag; }
 typedef struct AzCssPathPseudoSelectorVariant_Active AzCssPathPseudoSelectorVariant_Active;
 struct AzCssPathPseudoSelectorVariant_Focus { AzCssPathPseudoSelectorTag tag; };
 typedef struct AzCssPathPseudoSelectorVariant_Focus AzCssPathPseudoSelectorVariant_Focus;
+struct AzCssPathPseudoSelectorVariant_Disabled { AzCssPathPseudoSelectorTag tag; };
+typedef struct AzCssPathPseudoSelectorVariant_Disabled AzCssPathPseudoSelectorVariant_Disabled;
+struct AzCssPathPseudoSelectorVariant_Checked { AzCssPathPseudoSelectorTag tag; };
+typedef struct AzCssPathPseudoSelectorVariant_Checked AzCssPathPseudoSelectorVariant_Checked;
+struct AzCssPathPseudoSelectorVariant_Selected { AzCssPathPseudoSelectorTag tag; };
+typedef struct AzCssPathPseudoSelectorVariant_Selected AzCssPathPseudoSelectorVariant_Selected;
+struct AzCssPathPseudoSelectorVariant_Indeterminate { AzCssPathPseudoSelectorTag tag; };
+typedef struct AzCssPathPseudoSelectorVariant_Indeterminate AzCssPathPseudoSelectorVariant_Indeterminate;
+struct AzCssPathPseudoSelectorVariant_Before { AzCssPathPseudoSelectorTag tag; };
+typedef struct AzCssPathPseudoSelectorVariant_Before AzCssPathPseudoSelectorVariant_Before;
+struct AzCssPathPseudoSelectorVariant_After { AzCssPathPseudoSelectorTag tag; };
+typedef struct AzCssPathPseudoSelectorVariant_After AzCssPathPseudoSelectorVariant_After;
 union AzCssPathPseudoSelector {
     AzCssPathPseudoSelectorVariant_First First;
     AzCssPathPseudoSelectorVariant_Last Last;
@@ -8105,6 +9190,12 @@ union AzCssPathPseudoSelector {
     AzCssPathPseudoSelectorVariant_Hover Hover;
     AzCssPathPseudoSelectorVariant_Active Active;
     AzCssPathPseudoSelectorVariant_Focus Focus;
+    AzCssPathPseudoSelectorVariant_Disabled Disabled;
+    AzCssPathPseudoSelectorVariant_Checked Checked;
+    AzCssPathPseudoSelectorVariant_Selected Selected;
+    AzCssPathPseudoSelectorVariant_Indeterminate Indeterminate;
+    AzCssPathPseudoSelectorVariant_Before Before;
+    AzCssPathPseudoSelectorVariant_After After;
 };
 typedef union AzCssPathPseudoSelector AzCssPathPseudoSelector;
 
@@ -8399,13 +9490,6 @@ struct AzCheckBoxStateWrapper {
 };
 typedef struct AzCheckBoxStateWrapper AzCheckBoxStateWrapper;
 
-struct AzNumberInputStateWrapper {
-    AzNumberInputState inner;
-    AzOptionNumberInputOnValueChange on_value_change;
-    AzOptionNumberInputOnFocusLost on_focus_lost;
-};
-typedef struct AzNumberInputStateWrapper AzNumberInputStateWrapper;
-
 struct AzNodeGraphCallbacks {
     AzOptionNodeGraphOnNodeAdded on_node_added;
     AzOptionNodeGraphOnNodeRemoved on_node_removed;
@@ -8652,6 +9736,14 @@ struct AzTessellatedColoredSvgNodeVec {
 };
 typedef struct AzTessellatedColoredSvgNodeVec AzTessellatedColoredSvgNodeVec;
 
+struct AzPaintPrimitiveVec {
+    AzPaintPrimitive* ptr;
+    size_t len;
+    size_t cap;
+    AzPaintPrimitiveVecDestructor destructor;
+};
+typedef struct AzPaintPrimitiveVec AzPaintPrimitiveVec;
+
 struct AzStyleTransformVec {
     AzStyleTransform* ptr;
     size_t len;
@@ -8668,6 +9760,22 @@ struct AzSvgPathElementVec {
 };
 typedef struct AzSvgPathElementVec AzSvgPathElementVec;
 
+struct AzShortcutConflictVec {
+    AzShortcutConflict* ptr;
+    size_t len;
+    size_t cap;
+    AzShortcutConflictVecDestructor destructor;
+};
+typedef struct AzShortcutConflictVec AzShortcutConflictVec;
+
+struct AzShortcutVec {
+    AzShortcut* ptr;
+    size_t len;
+    size_t cap;
+    AzShortcutVecDestructor destructor;
+};
+typedef struct AzShortcutVec AzShortcutVec;
+
 struct AzStringVec {
     AzString* ptr;
     size_t len;
@@ -8740,6 +9848,22 @@ union AzOptionVirtualKeyCodeCombo {
 };
 typedef union AzOptionVirtualKeyCodeCombo AzOptionVirtualKeyCodeCombo;
 
+enum AzOptionShortcutConflictTag {
+   AzOptionShortcutConflictTag_None,
+   AzOptionShortcutConflictTag_Some,
+};
+typedef enum AzOptionShortcutConflictTag AzOptionShortcutConflictTag;
+
+struct AzOptionShortcutConflictVariant_None { AzOptionShortcutConflictTag tag; };
+typedef struct AzOptionShortcutConflictVariant_None AzOptionShortcutConflictVariant_None;
+struct AzOptionShortcutConflictVariant_Some { AzOptionShortcutConflictTag tag; AzShortcutConflict payload; };
+typedef struct AzOptionShortcutConflictVariant_Some AzOptionShortcutConflictVariant_Some;
+union AzOptionShortcutConflict {
+    AzOptionShortcutConflictVariant_None None;
+    AzOptionShortcutConflictVariant_Some Some;
+};
+typedef union AzOptionShortcutConflict AzOptionShortcutConflict;
+
 enum AzOptionMouseStateTag {
    AzOptionMouseStateTag_None,
    AzOptionMouseStateTag_Some,
@@ -8921,6 +10045,56 @@ struct AzInvalidStringError {
 };
 typedef struct AzInvalidStringError AzInvalidStringError;
 
+struct AzJankCapture {
+    AzDuration threshold;
+    bool  dump_display_list;
+    AzOptionString output_directory;
+};
+typedef struct AzJankCapture AzJankCapture;
+
+enum AzEventRecordingTag {
+   AzEventRecordingTag_Record,
+   AzEventRecordingTag_Replay,
+};
+typedef enum AzEventRecordingTag AzEventRecordingTag;
+
+struct AzEventRecordingVariant_Record { AzEventRecordingTag tag; AzString payload; };
+typedef struct AzEventRecordingVariant_Record AzEventRecordingVariant_Record;
+struct AzEventRecordingVariant_Replay { AzEventRecordingTag tag; AzString payload; };
+typedef struct AzEventRecordingVariant_Replay AzEventRecordingVariant_Replay;
+union AzEventRecording {
+    AzEventRecordingVariant_Record Record;
+    AzEventRecordingVariant_Replay Replay;
+};
+typedef union AzEventRecording AzEventRecording;
+
+enum AzFrameRecordingOutputTag {
+   AzFrameRecordingOutputTag_GifDirectory,
+   AzFrameRecordingOutputTag_Callback,
+};
+typedef enum AzFrameRecordingOutputTag AzFrameRecordingOutputTag;
+
+struct AzFrameRecordingOutputVariant_GifDirectory { AzFrameRecordingOutputTag tag; AzString payload; };
+typedef struct AzFrameRecordingOutputVariant_GifDirectory AzFrameRecordingOutputVariant_GifDirectory;
+struct AzFrameRecordingOutputVariant_Callback { AzFrameRecordingOutputTag tag; AzFrameRecordingCallback payload; };
+typedef struct AzFrameRecordingOutputVariant_Callback AzFrameRecordingOutputVariant_Callback;
+union AzFrameRecordingOutput {
+    AzFrameRecordingOutputVariant_GifDirectory GifDirectory;
+    AzFrameRecordingOutputVariant_Callback Callback;
+};
+typedef union AzFrameRecordingOutput AzFrameRecordingOutput;
+
+struct AzCrashInfo {
+    AzString message;
+    AzString location;
+    AzString thread_name;
+    AzString backtrace;
+    AzStringVec last_log_lines;
+    AzString window_state_summary;
+    AzOptionString minidump_path;
+};
+typedef struct AzCrashInfo AzCrashInfo;
+
 struct AzWindowsWindowOptions {
     bool  allow_drag_drop;
     bool  no_redirection_bitmap;
@@ -8992,6 +10166,7 @@ struct AzMonitor {
     double scale_factor;
     AzVideoModeVec video_modes;
     bool  is_primary_monitor;
+    AzHdrCapabilities hdr;
 };
 typedef struct AzMonitor AzMonitor;
 
@@ -9035,6 +10210,14 @@ union AzInlineWord {
 };
 typedef union AzInlineWord AzInlineWord;
 
+struct AzCustomNodeType {
+    AzString name;
+    AzMeasureCallback measure;
+    AzArrangeCallback arrange;
+    AzOptionPaintCallback paint;
+};
+typedef struct AzCustomNodeType AzCustomNodeType;
+
 struct AzCallbackData {
     AzEventFilter event;
     AzCallback callback;
@@ -9049,6 +10232,7 @@ enum AzNodeTypeTag {
    AzNodeTypeTag_Text,
    AzNodeTypeTag_Image,
    AzNodeTypeTag_IFrame,
+   AzNodeTypeTag_CustomLayout,
 };
 typedef enum AzNodeTypeTag AzNodeTypeTag;
 
@@ -9064,6 +10248,8 @@ struct AzNodeTypeVariant_Image { AzNodeTypeTag tag; AzImageRef payload; };
 typedef struct AzNodeTypeVariant_Image AzNodeTypeVariant_Image;
 struct AzNodeTypeVariant_IFrame { AzNodeTypeTag tag; AzIFrameNode payload; };
 typedef struct AzNodeTypeVariant_IFrame AzNodeTypeVariant_IFrame;
+struct AzNodeTypeVariant_CustomLayout { AzNodeTypeTag tag; AzCustomLayoutNode payload; };
+typedef struct AzNodeTypeVariant_CustomLayout AzNodeTypeVariant_CustomLayout;
 union AzNodeType {
     AzNodeTypeVariant_Body Body;
     AzNodeTypeVariant_Div Div;
@@ -9071,6 +10257,7 @@ union AzNodeType {
     AzNodeTypeVariant_Text Text;
     AzNodeTypeVariant_Image Image;
     AzNodeTypeVariant_IFrame IFrame;
+    AzNodeTypeVariant_CustomLayout CustomLayout;
 };
 typedef union AzNodeType AzNodeType;
 
@@ -9084,6 +10271,20 @@ struct AzAccessibilityInfo {
 };
 typedef struct AzAccessibilityInfo AzAccessibilityInfo;
 
+struct AzFocusNavigation {
+    AzOptionString up;
+    AzOptionString down;
+    AzOptionString left;
+    AzOptionString right;
+};
+typedef struct AzFocusNavigation AzFocusNavigation;
+
+struct AzOverlayInfo {
+    AzString anchor;
+    AzOverlayPlacement placement;
+};
+typedef struct AzOverlayInfo AzOverlayInfo;
+
 enum AzIdOrClassTag {
    AzIdOrClassTag_Id,
    AzIdOrClassTag_Class,
@@ -9110,6 +10311,11 @@ struct AzStringMenuItem {
 };
 typedef struct AzStringMenuItem AzStringMenuItem;
 
+struct AzShortcutMap {
+    AzShortcutVec shortcuts;
+};
+typedef struct AzShortcutMap AzShortcutMap;
+
 enum AzCssPathSelectorTag {
    AzCssPathSelectorTag_Global,
    AzCssPathSelectorTag_Type,
@@ -9192,6 +10398,49 @@ struct AzScrollbarStyle {
 };
 typedef struct AzScrollbarStyle AzScrollbarStyle;
 
+enum AzStyleContentTag {
+   AzStyleContentTag_None,
+   AzStyleContentTag_Text,
+   AzStyleContentTag_Image,
+   AzStyleContentTag_Counter,
+};
+typedef enum AzStyleContentTag AzStyleContentTag;
+
+struct AzStyleContentVariant_None { AzStyleContentTag tag; };
+typedef struct AzStyleContentVariant_None AzStyleContentVariant_None;
+struct AzStyleContentVariant_Text { AzStyleContentTag tag; AzString payload; };
+typedef struct AzStyleContentVariant_Text AzStyleContentVariant_Text;
+struct AzStyleContentVariant_Image { AzStyleContentTag tag; AzString payload; };
+typedef struct AzStyleContentVariant_Image AzStyleContentVariant_Image;
+struct AzStyleContentVariant_Counter { AzStyleContentTag tag; AzString payload; };
+typedef struct AzStyleContentVariant_Counter AzStyleContentVariant_Counter;
+union AzStyleContent {
+    AzStyleContentVariant_None None;
+    AzStyleContentVariant_Text Text;
+    AzStyleContentVariant_Image Image;
+    AzStyleContentVariant_Counter Counter;
+};
+typedef union AzStyleContent AzStyleContent;
+
+struct AzStyleCounterReset {
+    AzString counter;
+    int32_t value;
+};
+typedef struct AzStyleCounterReset AzStyleCounterReset;
+
+struct AzStyleCounterIncrement {
+    AzString counter;
+    int32_t value;
+};
+typedef struct AzStyleCounterIncrement AzStyleCounterIncrement;
+
+struct AzStyleCursorImage {
+    AzString image;
+    uint32_t hotspot_x;
+    uint32_t hotspot_y;
+};
+typedef struct AzStyleCursorImage AzStyleCursorImage;
+
 enum AzStyleFontFamilyTag {
    AzStyleFontFamilyTag_System,
    AzStyleFontFamilyTag_File,
@@ -9240,6 +10489,90 @@ union AzScrollbarStyleValue {
 };
 typedef union AzScrollbarStyleValue AzScrollbarStyleValue;
 
+enum AzStyleContentValueTag {
+   AzStyleContentValueTag_Auto,
+   AzStyleContentValueTag_None,
+   AzStyleContentValueTag_Inherit,
+   AzStyleContentValueTag_Initial,
+   AzStyleContentValueTag_Exact,
+};
+typedef enum AzStyleContentValueTag AzStyleContentValueTag;
+
+struct AzStyleContentValueVariant_Auto { AzStyleContentValueTag tag; };
+typedef struct AzStyleContentValueVariant_Auto AzStyleContentValueVariant_Auto;
+struct AzStyleContentValueVariant_None { AzStyleContentValueTag tag; };
+typedef struct AzStyleContentValueVariant_None AzStyleContentValueVariant_None;
+struct AzStyleContentValueVariant_Inherit { AzStyleContentValueTag tag; };
+typedef struct AzStyleContentValueVariant_Inherit AzStyleContentValueVariant_Inherit;
+struct AzStyleContentValueVariant_Initial { AzStyleContentValueTag tag; };
+typedef struct AzStyleContentValueVariant_Initial AzStyleContentValueVariant_Initial;
+struct AzStyleContentValueVariant_Exact { AzStyleContentValueTag tag; AzStyleContent payload; };
+typedef struct AzStyleContentValueVariant_Exact AzStyleContentValueVariant_Exact;
+union AzStyleContentValue {
+    AzStyleContentValueVariant_Auto Auto;
+    AzStyleContentValueVariant_None None;
+    AzStyleContentValueVariant_Inherit Inherit;
+    AzStyleContentValueVariant_Initial Initial;
+    AzStyleContentValueVariant_Exact Exact;
+};
+typedef union AzStyleContentValue AzStyleContentValue;
+
+enum AzStyleCounterResetValueTag {
+   AzStyleCounterResetValueTag_Auto,
+   AzStyleCounterResetValueTag_None,
+   AzStyleCounterResetValueTag_Inherit,
+   AzStyleCounterResetValueTag_Initial,
+   AzStyleCounterResetValueTag_Exact,
+};
+typedef enum AzStyleCounterResetValueTag AzStyleCounterResetValueTag;
+
+struct AzStyleCounterResetValueVariant_Auto { AzStyleCounterResetValueTag tag; };
+typedef struct AzStyleCounterResetValueVariant_Auto AzStyleCounterResetValueVariant_Auto;
+struct AzStyleCounterResetValueVariant_None { AzStyleCounterResetValueTag tag; };
+typedef struct AzStyleCounterResetValueVariant_None AzStyleCounterResetValueVariant_None;
+struct AzStyleCounterResetValueVariant_Inherit { AzStyleCounterResetValueTag tag; };
+typedef struct AzStyleCounterResetValueVariant_Inherit AzStyleCounterResetValueVariant_Inherit;
+struct AzStyleCounterResetValueVariant_Initial { AzStyleCounterResetValueTag tag; };
+typedef struct AzStyleCounterResetValueVariant_Initial AzStyleCounterResetValueVariant_Initial;
+struct AzStyleCounterResetValueVariant_Exact { AzStyleCounterResetValueTag tag; AzStyleCounterReset payload; };
+typedef struct AzStyleCounterResetValueVariant_Exact AzStyleCounterResetValueVariant_Exact;
+union AzStyleCounterResetValue {
+    AzStyleCounterResetValueVariant_Auto Auto;
+    AzStyleCounterResetValueVariant_None None;
+    AzStyleCounterResetValueVariant_Inherit Inherit;
+    AzStyleCounterResetValueVariant_Initial Initial;
+    AzStyleCounterResetValueVariant_Exact Exact;
+};
+typedef union AzStyleCounterResetValue AzStyleCounterResetValue;
+
+enum AzStyleCounterIncrementValueTag {
+   AzStyleCounterIncrementValueTag_Auto,
+   AzStyleCounterIncrementValueTag_None,
+   AzStyleCounterIncrementValueTag_Inherit,
+   AzStyleCounterIncrementValueTag_Initial,
+   AzStyleCounterIncrementValueTag_Exact,
+};
+typedef enum AzStyleCounterIncrementValueTag AzStyleCounterIncrementValueTag;
+
+struct AzStyleCounterIncrementValueVariant_Auto { AzStyleCounterIncrementValueTag tag; };
+typedef struct AzStyleCounterIncrementValueVariant_Auto AzStyleCounterIncrementValueVariant_Auto;
+struct AzStyleCounterIncrementValueVariant_None { AzStyleCounterIncrementValueTag tag; };
+typedef struct AzStyleCounterIncrementValueVariant_None AzStyleCounterIncrementValueVariant_None;
+struct AzStyleCounterIncrementValueVariant_Inherit { AzStyleCounterIncrementValueTag tag; };
+typedef struct AzStyleCounterIncrementValueVariant_Inherit AzStyleCounterIncrementValueVariant_Inherit;
+struct AzStyleCounterIncrementValueVariant_Initial { AzStyleCounterIncrementValueTag tag; };
+typedef struct AzStyleCounterIncrementValueVariant_Initial AzStyleCounterIncrementValueVariant_Initial;
+struct AzStyleCounterIncrementValueVariant_Exact { AzStyleCounterIncrementValueTag tag; AzStyleCounterIncrement payload; };
+typedef struct AzStyleCounterIncrementValueVariant_Exact AzStyleCounterIncrementValueVariant_Exact;
+union AzStyleCounterIncrementValue {
+    AzStyleCounterIncrementValueVariant_Auto Auto;
+    AzStyleCounterIncrementValueVariant_None None;
+    AzStyleCounterIncrementValueVariant_Inherit Inherit;
+    AzStyleCounterIncrementValueVariant_Initial Initial;
+    AzStyleCounterIncrementValueVariant_Exact Exact;
+};
+typedef union AzStyleCounterIncrementValue AzStyleCounterIncrementValue;
+
 enum AzStyleTransformVecValueTag {
    AzStyleTransformVecValueTag_Auto,
    AzStyleTransformVecValueTag_None,
@@ -9317,6 +10650,13 @@ struct AzTextInputState {
 };
 typedef struct AzTextInputState AzTextInputState;
 
+struct AzTextInputSpellingSuggestions {
+    AzTextInputSelectionRange range;
+    AzString word;
+    AzStringVec suggestions;
+};
+typedef struct AzTextInputSpellingSuggestions AzTextInputSpellingSuggestions;
+
 struct AzTabHeader {
     AzStringVec tabs;
     size_t active_tab;
@@ -9401,6 +10741,23 @@ struct AzDropDown {
 };
 typedef struct AzDropDown AzDropDown;
 
+struct AzToast {
+    AzString message;
+    AzOptionDuration duration;
+    AzString action_label;
+    AzOptionToastOnAction on_action;
+};
+typedef struct AzToast AzToast;
+
+struct AzGlQuirkRule {
+    AzOptionString vendor;
+    AzOptionString renderer;
+    AzOptionString version;
+    AzGlQuirks enable;
+    AzGlQuirks disable;
+};
+typedef struct AzGlQuirkRule AzGlQuirkRule;
+
 struct AzVertexAttribute {
     AzString name;
     AzOptionUsize layout_location;
@@ -9704,6 +11061,38 @@ union AzOptionFile {
 };
 typedef union AzOptionFile AzOptionFile;
 
+enum AzOptionJankCaptureTag {
+   AzOptionJankCaptureTag_None,
+   AzOptionJankCaptureTag_Some,
+};
+typedef enum AzOptionJankCaptureTag AzOptionJankCaptureTag;
+
+struct AzOptionJankCaptureVariant_None { AzOptionJankCaptureTag tag; };
+typedef struct AzOptionJankCaptureVariant_None AzOptionJankCaptureVariant_None;
+struct AzOptionJankCaptureVariant_Some { AzOptionJankCaptureTag tag; AzJankCapture payload; };
+typedef struct AzOptionJankCaptureVariant_Some AzOptionJankCaptureVariant_Some;
+union AzOptionJankCapture {
+    AzOptionJankCaptureVariant_None None;
+    AzOptionJankCaptureVariant_Some Some;
+};
+typedef union AzOptionJankCapture AzOptionJankCapture;
+
+enum AzOptionEventRecordingTag {
+   AzOptionEventRecordingTag_None,
+   AzOptionEventRecordingTag_Some,
+};
+typedef enum AzOptionEventRecordingTag AzOptionEventRecordingTag;
+
+struct AzOptionEventRecordingVariant_None { AzOptionEventRecordingTag tag; };
+typedef struct AzOptionEventRecordingVariant_None AzOptionEventRecordingVariant_None;
+struct AzOptionEventRecordingVariant_Some { AzOptionEventRecordingTag tag; AzEventRecording payload; };
+typedef struct AzOptionEventRecordingVariant_Some AzOptionEventRecordingVariant_Some;
+union AzOptionEventRecording {
+    AzOptionEventRecordingVariant_None None;
+    AzOptionEventRecordingVariant_Some Some;
+};
+typedef union AzOptionEventRecording AzOptionEventRecording;
+
 enum AzOptionRawImageTag {
    AzOptionRawImageTag_None,
    AzOptionRawImageTag_Some,
@@ -9812,6 +11201,12 @@ union AzXmlStreamError {
 };
 typedef union AzXmlStreamError AzXmlStreamError;
 
+struct AzFrameRecording {
+    AzFrameRecordingOutput output;
+    uint32_t max_fps;
+};
+typedef struct AzFrameRecording AzFrameRecording;
+
 struct AzLinuxWindowOptions {
     AzOptionX11Visual x11_visual;
     AzOptionI32 x11_screen;
@@ -9859,6 +11254,138 @@ struct AzCssPath {
 };
 typedef struct AzCssPath AzCssPath;
 
+enum AzStyleCursorTag {
+   AzStyleCursorTag_Alias,
+   AzStyleCursorTag_AllScroll,
+   AzStyleCursorTag_Cell,
+   AzStyleCursorTag_ColResize,
+   AzStyleCursorTag_ContextMenu,
+   AzStyleCursorTag_Copy,
+   AzStyleCursorTag_Crosshair,
+   AzStyleCursorTag_Default,
+   AzStyleCursorTag_EResize,
+   AzStyleCursorTag_EwResize,
+   AzStyleCursorTag_Grab,
+   AzStyleCursorTag_Grabbing,
+   AzStyleCursorTag_Help,
+   AzStyleCursorTag_Move,
+   AzStyleCursorTag_NResize,
+   AzStyleCursorTag_NsResize,
+   AzStyleCursorTag_NeswResize,
+   AzStyleCursorTag_NwseResize,
+   AzStyleCursorTag_Pointer,
+   AzStyleCursorTag_Progress,
+   AzStyleCursorTag_RowResize,
+   AzStyleCursorTag_SResize,
+   AzStyleCursorTag_SeResize,
+   AzStyleCursorTag_Text,
+   AzStyleCursorTag_Unset,
+   AzStyleCursorTag_VerticalText,
+   AzStyleCursorTag_WResize,
+   AzStyleCursorTag_Wait,
+   AzStyleCursorTag_ZoomIn,
+   AzStyleCursorTag_ZoomOut,
+   AzStyleCursorTag_Image,
+};
+typedef enum AzStyleCursorTag AzStyleCursorTag;
+
+struct AzStyleCursorVariant_Alias { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Alias AzStyleCursorVariant_Alias;
+struct AzStyleCursorVariant_AllScroll { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_AllScroll AzStyleCursorVariant_AllScroll;
+struct AzStyleCursorVariant_Cell { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Cell AzStyleCursorVariant_Cell;
+struct AzStyleCursorVariant_ColResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_ColResize AzStyleCursorVariant_ColResize;
+struct AzStyleCursorVariant_ContextMenu { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_ContextMenu AzStyleCursorVariant_ContextMenu;
+struct AzStyleCursorVariant_Copy { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Copy AzStyleCursorVariant_Copy;
+struct AzStyleCursorVariant_Crosshair { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Crosshair AzStyleCursorVariant_Crosshair;
+struct AzStyleCursorVariant_Default { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Default AzStyleCursorVariant_Default;
+struct AzStyleCursorVariant_EResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_EResize AzStyleCursorVariant_EResize;
+struct AzStyleCursorVariant_EwResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_EwResize AzStyleCursorVariant_EwResize;
+struct AzStyleCursorVariant_Grab { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Grab AzStyleCursorVariant_Grab;
+struct AzStyleCursorVariant_Grabbing { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Grabbing AzStyleCursorVariant_Grabbing;
+struct AzStyleCursorVariant_Help { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Help AzStyleCursorVariant_Help;
+struct AzStyleCursorVariant_Move { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Move AzStyleCursorVariant_Move;
+struct AzStyleCursorVariant_NResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_NResize AzStyleCursorVariant_NResize;
+struct AzStyleCursorVariant_NsResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_NsResize AzStyleCursorVariant_NsResize;
+struct AzStyleCursorVariant_NeswResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_NeswResize AzStyleCursorVariant_NeswResize;
+struct AzStyleCursorVariant_NwseResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_NwseResize AzStyleCursorVariant_NwseResize;
+struct AzStyleCursorVariant_Pointer { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Pointer AzStyleCursorVariant_Pointer;
+struct AzStyleCursorVariant_Progress { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Progress AzStyleCursorVariant_Progress;
+struct AzStyleCursorVariant_RowResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_RowResize AzStyleCursorVariant_RowResize;
+struct AzStyleCursorVariant_SResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_SResize AzStyleCursorVariant_SResize;
+struct AzStyleCursorVariant_SeResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_SeResize AzStyleCursorVariant_SeResize;
+struct AzStyleCursorVariant_Text { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Text AzStyleCursorVariant_Text;
+struct AzStyleCursorVariant_Unset { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Unset AzStyleCursorVariant_Unset;
+struct AzStyleCursorVariant_VerticalText { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_VerticalText AzStyleCursorVariant_VerticalText;
+struct AzStyleCursorVariant_WResize { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_WResize AzStyleCursorVariant_WResize;
+struct AzStyleCursorVariant_Wait { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_Wait AzStyleCursorVariant_Wait;
+struct AzStyleCursorVariant_ZoomIn { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_ZoomIn AzStyleCursorVariant_ZoomIn;
+struct AzStyleCursorVariant_ZoomOut { AzStyleCursorTag tag; };
+typedef struct AzStyleCursorVariant_ZoomOut AzStyleCursorVariant_ZoomOut;
+struct AzStyleCursorVariant_Image { AzStyleCursorTag tag; AzStyleCursorImage payload; };
+typedef struct AzStyleCursorVariant_Image AzStyleCursorVariant_Image;
+union AzStyleCursor {
+    AzStyleCursorVariant_Alias Alias;
+    AzStyleCursorVariant_AllScroll AllScroll;
+    AzStyleCursorVariant_Cell Cell;
+    AzStyleCursorVariant_ColResize ColResize;
+    AzStyleCursorVariant_ContextMenu ContextMenu;
+    AzStyleCursorVariant_Copy Copy;
+    AzStyleCursorVariant_Crosshair Crosshair;
+    AzStyleCursorVariant_Default Default;
+    AzStyleCursorVariant_EResize EResize;
+    AzStyleCursorVariant_EwResize EwResize;
+    AzStyleCursorVariant_Grab Grab;
+    AzStyleCursorVariant_Grabbing Grabbing;
+    AzStyleCursorVariant_Help Help;
+    AzStyleCursorVariant_Move Move;
+    AzStyleCursorVariant_NResize NResize;
+    AzStyleCursorVariant_NsResize NsResize;
+    AzStyleCursorVariant_NeswResize NeswResize;
+    AzStyleCursorVariant_NwseResize NwseResize;
+    AzStyleCursorVariant_Pointer Pointer;
+    AzStyleCursorVariant_Progress Progress;
+    AzStyleCursorVariant_RowResize RowResize;
+    AzStyleCursorVariant_SResize SResize;
+    AzStyleCursorVariant_SeResize SeResize;
+    AzStyleCursorVariant_Text Text;
+    AzStyleCursorVariant_Unset Unset;
+    AzStyleCursorVariant_VerticalText VerticalText;
+    AzStyleCursorVariant_WResize WResize;
+    AzStyleCursorVariant_Wait Wait;
+    AzStyleCursorVariant_ZoomIn ZoomIn;
+    AzStyleCursorVariant_ZoomOut ZoomOut;
+    AzStyleCursorVariant_Image Image;
+};
+typedef union AzStyleCursor AzStyleCursor;
+
 enum AzStyleBackgroundContentVecValueTag {
    AzStyleBackgroundContentVecValueTag_Auto,
    AzStyleBackgroundContentVecValueTag_None,
@@ -9887,6 +11414,34 @@ union AzStyleBackgroundContentVecValue {
 };
 typedef union AzStyleBackgroundContentVecValue AzStyleBackgroundContentVecValue;
 
+enum AzStyleCursorValueTag {
+   AzStyleCursorValueTag_Auto,
+   AzStyleCursorValueTag_None,
+   AzStyleCursorValueTag_Inherit,
+   AzStyleCursorValueTag_Initial,
+   AzStyleCursorValueTag_Exact,
+};
+typedef enum AzStyleCursorValueTag AzStyleCursorValueTag;
+
+struct AzStyleCursorValueVariant_Auto { AzStyleCursorValueTag tag; };
+typedef struct AzStyleCursorValueVariant_Auto AzStyleCursorValueVariant_Auto;
+struct AzStyleCursorValueVariant_None { AzStyleCursorValueTag tag; };
+typedef struct AzStyleCursorValueVariant_None AzStyleCursorValueVariant_None;
+struct AzStyleCursorValueVariant_Inherit { AzStyleCursorValueTag tag; };
+typedef struct AzStyleCursorValueVariant_Inherit AzStyleCursorValueVariant_Inherit;
+struct AzStyleCursorValueVariant_Initial { AzStyleCursorValueTag tag; };
+typedef struct AzStyleCursorValueVariant_Initial AzStyleCursorValueVariant_Initial;
+struct AzStyleCursorValueVariant_Exact { AzStyleCursorValueTag tag; AzStyleCursor payload; };
+typedef struct AzStyleCursorValueVariant_Exact AzStyleCursorValueVariant_Exact;
+union AzStyleCursorValue {
+    AzStyleCursorValueVariant_Auto Auto;
+    AzStyleCursorValueVariant_None None;
+    AzStyleCursorValueVariant_Inherit Inherit;
+    AzStyleCursorValueVariant_Initial Initial;
+    AzStyleCursorValueVariant_Exact Exact;
+};
+typedef union AzStyleCursorValue AzStyleCursorValue;
+
 enum AzStyleFontFamilyVecValueTag {
    AzStyleFontFamilyVecValueTag_Auto,
    AzStyleFontFamilyVecValueTag_None,
@@ -9924,6 +11479,7 @@ enum AzCssPropertyTag {
    AzCssPropertyTag_LineHeight,
    AzCssPropertyTag_WordSpacing,
    AzCssPropertyTag_TabWidth,
+   AzCssPropertyTag_Hyphens,
    AzCssPropertyTag_Cursor,
    AzCssPropertyTag_Display,
    AzCssPropertyTag_Float,
@@ -9981,11 +11537,24 @@ enum AzCssPropertyTag {
    AzCssPropertyTag_BoxShadowTop,
    AzCssPropertyTag_BoxShadowBottom,
    AzCssPropertyTag_ScrollbarStyle,
+   AzCssPropertyTag_ScrollbarWidth,
+   AzCssPropertyTag_ScrollbarColor,
+   AzCssPropertyTag_ScrollbarThumbHoverColor,
+   AzCssPropertyTag_ScrollbarThumbActiveColor,
+   AzCssPropertyTag_WindowDragRegion,
+   AzCssPropertyTag_CaretAnimationDuration,
+   AzCssPropertyTag_ScrollPadding,
+   AzCssPropertyTag_Content,
+   AzCssPropertyTag_CounterReset,
+   AzCssPropertyTag_CounterIncrement,
+   AzCssPropertyTag_OverflowAnchor,
    AzCssPropertyTag_Opacity,
    AzCssPropertyTag_Transform,
    AzCssPropertyTag_TransformOrigin,
    AzCssPropertyTag_PerspectiveOrigin,
    AzCssPropertyTag_BackfaceVisibility,
+   AzCssPropertyTag_Perspective,
+   AzCssPropertyTag_TransformStyle,
    AzCssPropertyTag_MixBlendMode,
    AzCssPropertyTag_Filter,
    AzCssPropertyTag_BackdropFilter,
@@ -10009,6 +11578,8 @@ struct AzCssPropertyVariant_WordSpacing { AzCssPropertyTag tag; AzStyleWordSpaci
 typedef struct AzCssPropertyVariant_WordSpacing AzCssPropertyVariant_WordSpacing;
 struct AzCssPropertyVariant_TabWidth { AzCssPropertyTag tag; AzStyleTabWidthValue payload; };
 typedef struct AzCssPropertyVariant_TabWidth AzCssPropertyVariant_TabWidth;
+struct AzCssPropertyVariant_Hyphens { AzCssPropertyTag tag; AzStyleHyphensValue payload; };
+typedef struct AzCssPropertyVariant_Hyphens AzCssPropertyVariant_Hyphens;
 struct AzCssPropertyVariant_Cursor { AzCssPropertyTag tag; AzStyleCursorValue payload; };
 typedef struct AzCssPropertyVariant_Cursor AzCssPropertyVariant_Cursor;
 struct AzCssPropertyVariant_Display { AzCssPropertyTag tag; AzLayoutDisplayValue payload; };
@@ -10123,6 +11694,28 @@ struct AzCssPropertyVariant_BoxShadowBottom { AzCssPropertyTag tag; AzStyleBoxSh
 typedef struct AzCssPropertyVariant_BoxShadowBottom AzCssPropertyVariant_BoxShadowBottom;
 struct AzCssPropertyVariant_ScrollbarStyle { AzCssPropertyTag tag; AzScrollbarStyleValue payload; };
 typedef struct AzCssPropertyVariant_ScrollbarStyle AzCssPropertyVariant_ScrollbarStyle;
+struct AzCssPropertyVariant_ScrollbarWidth { AzCssPropertyTag tag; AzStyleScrollbarWidthValue payload; };
+typedef struct AzCssPropertyVariant_ScrollbarWidth AzCssPropertyVariant_ScrollbarWidth;
+struct AzCssPropertyVariant_ScrollbarColor { AzCssPropertyTag tag; AzStyleScrollbarColorValue payload; };
+typedef struct AzCssPropertyVariant_ScrollbarColor AzCssPropertyVariant_ScrollbarColor;
+struct AzCssPropertyVariant_ScrollbarThumbHoverColor { AzCssPropertyTag tag; AzStyleScrollbarThumbHoverColorValue payload; };
+typedef struct AzCssPropertyVariant_ScrollbarThumbHoverColor AzCssPropertyVariant_ScrollbarThumbHoverColor;
+struct AzCssPropertyVariant_ScrollbarThumbActiveColor { AzCssPropertyTag tag; AzStyleScrollbarThumbActiveColorValue payload; };
+typedef struct AzCssPropertyVariant_ScrollbarThumbActiveColor AzCssPropertyVariant_ScrollbarThumbActiveColor;
+struct AzCssPropertyVariant_WindowDragRegion { AzCssPropertyTag tag; AzStyleWindowDragRegionValue payload; };
+typedef struct AzCssPropertyVariant_WindowDragRegion AzCssPropertyVariant_WindowDragRegion;
+struct AzCssPropertyVariant_CaretAnimationDuration { AzCssPropertyTag tag; AzStyleCaretAnimationDurationValue payload; };
+typedef struct AzCssPropertyVariant_CaretAnimationDuration AzCssPropertyVariant_CaretAnimationDuration;
+struct AzCssPropertyVariant_ScrollPadding { AzCssPropertyTag tag; AzStyleScrollPaddingValue payload; };
+typedef struct AzCssPropertyVariant_ScrollPadding AzCssPropertyVariant_ScrollPadding;
+struct AzCssPropertyVariant_Content { AzCssPropertyTag tag; AzStyleContentValue payload; };
+typedef struct AzCssPropertyVariant_Content AzCssPropertyVariant_Content;
+struct AzCssPropertyVariant_CounterReset { AzCssPropertyTag tag; AzStyleCounterResetValue payload; };
+typedef struct AzCssPropertyVariant_CounterReset AzCssPropertyVariant_CounterReset;
+struct AzCssPropertyVariant_CounterIncrement { AzCssPropertyTag tag; AzStyleCounterIncrementValue payload; };
+typedef struct AzCssPropertyVariant_CounterIncrement AzCssPropertyVariant_CounterIncrement;
+struct AzCssPropertyVariant_OverflowAnchor { AzCssPropertyTag tag; AzStyleOverflowAnchorValue payload; };
+typedef struct AzCssPropertyVariant_OverflowAnchor AzCssPropertyVariant_OverflowAnchor;
 struct AzCssPropertyVariant_Opacity { AzCssPropertyTag tag; AzStyleOpacityValue payload; };
 typedef struct AzCssPropertyVariant_Opacity AzCssPropertyVariant_Opacity;
 struct AzCssPropertyVariant_Transform { AzCssPropertyTag tag; AzStyleTransformVecValue payload; };
@@ -10133,6 +11726,10 @@ struct AzCssPropertyVariant_PerspectiveOrigin { AzCssPropertyTag tag; AzStylePer
 typedef struct AzCssPropertyVariant_PerspectiveOrigin AzCssPropertyVariant_PerspectiveOrigin;
 struct AzCssPropertyVariant_BackfaceVisibility { AzCssPropertyTag tag; AzStyleBackfaceVisibilityValue payload; };
 typedef struct AzCssPropertyVariant_BackfaceVisibility AzCssPropertyVariant_BackfaceVisibility;
+struct AzCssPropertyVariant_Perspective { AzCssPropertyTag tag; AzStylePerspectiveValue payload; };
+typedef struct AzCssPropertyVariant_Perspective AzCssPropertyVariant_Perspective;
+struct AzCssPropertyVariant_TransformStyle { AzCssPropertyTag tag; AzStyleTransformStyleValue payload; };
+typedef struct AzCssPropertyVariant_TransformStyle AzCssPropertyVariant_TransformStyle;
 struct AzCssPropertyVariant_MixBlendMode { AzCssPropertyTag tag; AzStyleMixBlendModeValue payload; };
 typedef struct AzCssPropertyVariant_MixBlendMode AzCssPropertyVariant_MixBlendMode;
 struct AzCssPropertyVariant_Filter { AzCssPropertyTag tag; AzStyleFilterVecValue payload; };
@@ -10150,6 +11747,7 @@ union AzCssProperty {
     AzCssPropertyVariant_LineHeight LineHeight;
     AzCssPropertyVariant_WordSpacing WordSpacing;
     AzCssPropertyVariant_TabWidth TabWidth;
+    AzCssPropertyVariant_Hyphens Hyphens;
     AzCssPropertyVariant_Cursor Cursor;
     AzCssPropertyVariant_Display Display;
     AzCssPropertyVariant_Float Float;
@@ -10207,11 +11805,24 @@ union AzCssProperty {
     AzCssPropertyVariant_BoxShadowTop BoxShadowTop;
     AzCssPropertyVariant_BoxShadowBottom BoxShadowBottom;
     AzCssPropertyVariant_ScrollbarStyle ScrollbarStyle;
+    AzCssPropertyVariant_ScrollbarWidth ScrollbarWidth;
+    AzCssPropertyVariant_ScrollbarColor ScrollbarColor;
+    AzCssPropertyVariant_ScrollbarThumbHoverColor ScrollbarThumbHoverColor;
+    AzCssPropertyVariant_ScrollbarThumbActiveColor ScrollbarThumbActiveColor;
+    AzCssPropertyVariant_WindowDragRegion WindowDragRegion;
+    AzCssPropertyVariant_CaretAnimationDuration CaretAnimationDuration;
+    AzCssPropertyVariant_ScrollPadding ScrollPadding;
+    AzCssPropertyVariant_Content Content;
+    AzCssPropertyVariant_CounterReset CounterReset;
+    AzCssPropertyVariant_CounterIncrement CounterIncrement;
+    AzCssPropertyVariant_OverflowAnchor OverflowAnchor;
     AzCssPropertyVariant_Opacity Opacity;
     AzCssPropertyVariant_Transform Transform;
     AzCssPropertyVariant_TransformOrigin TransformOrigin;
     AzCssPropertyVariant_PerspectiveOrigin PerspectiveOrigin;
     AzCssPropertyVariant_BackfaceVisibility BackfaceVisibility;
+    AzCssPropertyVariant_Perspective Perspective;
+    AzCssPropertyVariant_TransformStyle TransformStyle;
     AzCssPropertyVariant_MixBlendMode MixBlendMode;
     AzCssPropertyVariant_Filter Filter;
     AzCssPropertyVariant_BackdropFilter BackdropFilter;
@@ -10236,6 +11847,10 @@ struct AzTextInputStateWrapper {
     bool  update_text_input_before_calling_focus_lost_fn;
     bool  update_text_input_before_calling_vk_down_fn;
     AzOptionTimerId cursor_animation;
+    AzCaretAnimation caret_animation;
+    AzOptionString spellcheck_language;
+    AzOptionTextInputOnSpellingSuggestions on_spelling_suggestions;
+    bool  secure;
 };
 typedef struct AzTextInputStateWrapper AzTextInputStateWrapper;
 
@@ -10430,12 +12045,44 @@ union AzOptionCssProperty {
 };
 typedef union AzOptionCssProperty AzOptionCssProperty;
 
+enum AzOptionFrameRecordingTag {
+   AzOptionFrameRecordingTag_None,
+   AzOptionFrameRecordingTag_Some,
+};
+typedef enum AzOptionFrameRecordingTag AzOptionFrameRecordingTag;
+
+struct AzOptionFrameRecordingVariant_None { AzOptionFrameRecordingTag tag; };
+typedef struct AzOptionFrameRecordingVariant_None AzOptionFrameRecordingVariant_None;
+struct AzOptionFrameRecordingVariant_Some { AzOptionFrameRecordingTag tag; AzFrameRecording payload; };
+typedef struct AzOptionFrameRecordingVariant_Some AzOptionFrameRecordingVariant_Some;
+union AzOptionFrameRecording {
+    AzOptionFrameRecordingVariant_None None;
+    AzOptionFrameRecordingVariant_Some Some;
+};
+typedef union AzOptionFrameRecording AzOptionFrameRecording;
+
 struct AzXmlTextError {
     AzXmlStreamError stream_error;
     AzSvgParseErrorPosition pos;
 };
 typedef struct AzXmlTextError AzXmlTextError;
 
+struct AzAppConfig {
+    AzLayoutSolver layout_solver;
+    AzAppLogLevel log_level;
+    bool  enable_visual_panic_hook;
+    bool  enable_logging_on_panic;
+    bool  enable_tab_navigation;
+    AzSystemCallbacks system_callbacks;
+    AzOptionDuration user_idle_timeout;
+    AzOptionCrashHandler crash_handler;
+    AzOptionJankCapture jank_capture;
+    AzOptionEventRecording event_recording;
+    AzOptionFrameRecording frame_recording;
+    AzOptionDuration hover_intent_delay;
+};
+typedef struct AzAppConfig AzAppConfig;
+
 struct AzPlatformSpecificOptions {
     AzWindowsWindowOptions windows_options;
     AzLinuxWindowOptions linux_options;
@@ -10459,6 +12106,7 @@ struct AzWindowState {
     AzPlatformSpecificOptions platform_specific_options;
     AzRendererOptions renderer_options;
     AzColorU background_color;
+    float opacity;
     AzLayoutCallback layout_callback;
     AzOptionCallback close_callback;
 };
@@ -10480,13 +12128,22 @@ struct AzCallbackInfo {
     void* restrict threads_removed;
     AzRawWindowHandle* current_window_handle;
     void* restrict new_windows;
+    AzShortcutMap* restrict shortcuts;
+    void* restrict window_data;
+    void* restrict announcements;
+    void* restrict haptic_feedback;
+    void* restrict animation_frame_callbacks;
+    void* restrict crossfades;
+    void* restrict subtree_recordings;
     AzSystemCallbacks* system_callbacks;
     bool * restrict stop_propagation;
     void* restrict focus_target;
     void* restrict words_changed_in_callbacks;
+    void* restrict text_underlines_changed_in_callbacks;
     void* restrict images_changed_in_callbacks;
     void* restrict image_masks_changed_in_callbacks;
     void* restrict css_properties_changed_in_callbacks;
+    void* restrict ids_and_classes_changed_in_callbacks;
     void* current_scroll_states;
     void* restrict nodes_scrolled_in_callback;
     AzDomNodeId hit_dom_node;
@@ -10506,6 +12163,12 @@ struct AzInlineText {
 };
 typedef struct AzInlineText AzInlineText;
 
+struct AzTextLayout {
+    AzLogicalRect bounds;
+    AzInlineText inline_text;
+};
+typedef struct AzTextLayout AzTextLayout;
+
 struct AzFocusTargetPath {
     AzDomId dom;
     AzCssPath css_path;
@@ -10564,6 +12227,13 @@ struct AzDynamicCssProperty {
 };
 typedef struct AzDynamicCssProperty AzDynamicCssProperty;
 
+struct AzSystemCssProperty {
+    AzString key;
+    AzString value;
+    AzCssProperty default_value;
+};
+typedef struct AzSystemCssProperty AzSystemCssProperty;
+
 struct AzNode {
     AzNodeTypeId node_type;
     AzNodePosition position;
@@ -10651,6 +12321,22 @@ union AzOptionInlineText {
 };
 typedef union AzOptionInlineText AzOptionInlineText;
 
+enum AzOptionTextLayoutTag {
+   AzOptionTextLayoutTag_None,
+   AzOptionTextLayoutTag_Some,
+};
+typedef enum AzOptionTextLayoutTag AzOptionTextLayoutTag;
+
+struct AzOptionTextLayoutVariant_None { AzOptionTextLayoutTag tag; };
+typedef struct AzOptionTextLayoutVariant_None AzOptionTextLayoutVariant_None;
+struct AzOptionTextLayoutVariant_Some { AzOptionTextLayoutTag tag; AzTextLayout payload; };
+typedef struct AzOptionTextLayoutVariant_Some AzOptionTextLayoutVariant_Some;
+union AzOptionTextLayout {
+    AzOptionTextLayoutVariant_None None;
+    AzOptionTextLayoutVariant_Some Some;
+};
+typedef union AzOptionTextLayout AzOptionTextLayout;
+
 enum AzXmlParseErrorTag {
    AzXmlParseErrorTag_InvalidDeclaration,
    AzXmlParseErrorTag_InvalidComment,
@@ -10706,6 +12392,13 @@ struct AzWindowCreateOptions {
     AzOptionWindowTheme theme;
     AzOptionCallback create_callback;
     bool  hot_reload;
+    AzShortcutMap shortcuts;
+    AzOptionRawWindowHandle parent_handle;
+    AzOptionRefAny window_data;
+    AzWindowBackgroundEffect background_effect;
+    AzOptionDuration fade_in;
+    AzFirstFrameMode first_frame;
+    AzScrollbarConfig scrollbars;
 };
 typedef struct AzWindowCreateOptions AzWindowCreateOptions;
 
@@ -10716,6 +12409,7 @@ enum AzFocusTargetTag {
    AzFocusTargetTag_Next,
    AzFocusTargetTag_First,
    AzFocusTargetTag_Last,
+   AzFocusTargetTag_Direction,
    AzFocusTargetTag_NoFocus,
 };
 typedef enum AzFocusTargetTag AzFocusTargetTag;
@@ -10732,6 +12426,8 @@ struct AzFocusTargetVariant_First { AzFocusTargetTag tag; };
 typedef struct AzFocusTargetVariant_First AzFocusTargetVariant_First;
 struct AzFocusTargetVariant_Last { AzFocusTargetTag tag; };
 typedef struct AzFocusTargetVariant_Last AzFocusTargetVariant_Last;
+struct AzFocusTargetVariant_Direction { AzFocusTargetTag tag; AzFocusDirection payload; };
+typedef struct AzFocusTargetVariant_Direction AzFocusTargetVariant_Direction;
 struct AzFocusTargetVariant_NoFocus { AzFocusTargetTag tag; };
 typedef struct AzFocusTargetVariant_NoFocus AzFocusTargetVariant_NoFocus;
 union AzFocusTarget {
@@ -10741,6 +12437,7 @@ union AzFocusTarget {
     AzFocusTargetVariant_Next Next;
     AzFocusTargetVariant_First First;
     AzFocusTargetVariant_Last Last;
+    AzFocusTargetVariant_Direction Direction;
     AzFocusTargetVariant_NoFocus NoFocus;
 };
 typedef union AzFocusTarget AzFocusTarget;
@@ -10759,6 +12456,7 @@ typedef struct AzNodeData AzNodeData;
 enum AzCssDeclarationTag {
    AzCssDeclarationTag_Static,
    AzCssDeclarationTag_Dynamic,
+   AzCssDeclarationTag_System,
 };
 typedef enum AzCssDeclarationTag AzCssDeclarationTag;
 
@@ -10766,9 +12464,12 @@ struct AzCssDeclarationVariant_Static { AzCssDeclarationTag tag; AzCssProperty p
 typedef struct AzCssDeclarationVariant_Static AzCssDeclarationVariant_Static;
 struct AzCssDeclarationVariant_Dynamic { AzCssDeclarationTag tag; AzDynamicCssProperty payload; };
 typedef struct AzCssDeclarationVariant_Dynamic AzCssDeclarationVariant_Dynamic;
+struct AzCssDeclarationVariant_System { AzCssDeclarationTag tag; AzSystemCssProperty payload; };
+typedef struct AzCssDeclarationVariant_System AzCssDeclarationVariant_System;
 union AzCssDeclaration {
     AzCssDeclarationVariant_Static Static;
     AzCssDeclarationVariant_Dynamic Dynamic;
+    AzCssDeclarationVariant_System System;
 };
 typedef union AzCssDeclaration AzCssDeclaration;
 
@@ -10819,11 +12520,14 @@ struct AzTextInput {
 };
 typedef struct AzTextInput AzTextInput;
 
-struct AzNumberInput {
-    AzTextInput text_input;
-    AzNumberInputStateWrapper state;
+struct AzNumberInputStateWrapper {
+    AzNumberInputState inner;
+    AzOptionNumberInputOnValueChange on_value_change;
+    AzOptionNumberInputOnFocusLost on_focus_lost;
+    AzNodeDataInlineCssPropertyVec invalid_style;
+    AzNodeDataInlineCssPropertyVec valid_style;
 };
-typedef struct AzNumberInput AzNumberInput;
+typedef struct AzNumberInputStateWrapper AzNumberInputStateWrapper;
 
 struct AzNodeIdNodeMap {
     AzNodeGraphNodeId node_id;
@@ -10831,6 +12535,15 @@ struct AzNodeIdNodeMap {
 };
 typedef struct AzNodeIdNodeMap AzNodeIdNodeMap;
 
+struct AzToasts {
+    AzRefAny state;
+    AzNodeDataInlineCssPropertyVec layer_style;
+    AzNodeDataInlineCssPropertyVec toast_style;
+    AzNodeDataInlineCssPropertyVec label_style;
+    AzNodeDataInlineCssPropertyVec action_style;
+};
+typedef struct AzToasts AzToasts;
+
 struct AzNodeIdNodeMapVec {
     AzNodeIdNodeMap* ptr;
     size_t len;
@@ -10941,9 +12654,17 @@ typedef struct AzDom AzDom;
 struct AzCssRuleBlock {
     AzCssPath path;
     AzCssDeclarationVec declarations;
+    AzCssMediaCondition media;
 };
 typedef struct AzCssRuleBlock AzCssRuleBlock;
 
+struct AzNumberInput {
+    AzTextInput text_input;
+    AzNumberInputStateWrapper state;
+    AzNodeDataInlineCssPropertyVec spinner_button_style;
+};
+typedef struct AzNumberInput AzNumberInput;
+
 struct AzTabContent {
     AzDom content;
     bool  has_padding;
@@ -11116,6 +12837,8 @@ struct AzCss {
 };
 typedef struct AzCss AzCss;
 
+#define AzFullscreenMode_Borderless(v) { .Borderless = { .tag = AzFullscreenModeTag_Borderless, .payload = v } }
+#define AzFullscreenMode_Exclusive(v) { .Exclusive = { .tag = AzFullscreenModeTag_Exclusive, .payload = v } }
 #define AzAnimationRepeatCount_Times(v) { .Times = { .tag = AzAnimationRepeatCountTag_Times, .payload = v } }
 #define AzAnimationRepeatCount_Infinite { .Infinite = { .tag = AzAnimationRepeatCountTag_Infinite } }
 #define AzTabIndex_Auto { .Auto = { .tag = AzTabIndexTag_Auto } }
@@ -11191,15 +12914,24 @@ typedef struct AzCss AzCss;
 #define AzInlineWordVecDestructor_DefaultRust { .DefaultRust = { .tag = AzInlineWordVecDestructorTag_DefaultRust } }
 #define AzInlineWordVecDestructor_NoDestructor { .NoDestructor = { .tag = AzInlineWordVecDestructorTag_NoDestructor } }
 #define AzInlineWordVecDestructor_External(v) { .External = { .tag = AzInlineWordVecDestructorTag_External, .payload = v } }
+#define AzPaintPrimitiveVecDestructor_DefaultRust { .DefaultRust = { .tag = AzPaintPrimitiveVecDestructorTag_DefaultRust } }
+#define AzPaintPrimitiveVecDestructor_NoDestructor { .NoDestructor = { .tag = AzPaintPrimitiveVecDestructorTag_NoDestructor } }
+#define AzPaintPrimitiveVecDestructor_External(v) { .External = { .tag = AzPaintPrimitiveVecDestructorTag_External, .payload = v } }
 #define AzInlineGlyphVecDestructor_DefaultRust { .DefaultRust = { .tag = AzInlineGlyphVecDestructorTag_DefaultRust } }
 #define AzInlineGlyphVecDestructor_NoDestructor { .NoDestructor = { .tag = AzInlineGlyphVecDestructorTag_NoDestructor } }
 #define AzInlineGlyphVecDestructor_External(v) { .External = { .tag = AzInlineGlyphVecDestructorTag_External, .payload = v } }
+#define AzTextUnderlineVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTextUnderlineVecDestructorTag_DefaultRust } }
+#define AzTextUnderlineVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTextUnderlineVecDestructorTag_NoDestructor } }
+#define AzTextUnderlineVecDestructor_External(v) { .External = { .tag = AzTextUnderlineVecDestructorTag_External, .payload = v } }
 #define AzInlineTextHitVecDestructor_DefaultRust { .DefaultRust = { .tag = AzInlineTextHitVecDestructorTag_DefaultRust } }
 #define AzInlineTextHitVecDestructor_NoDestructor { .NoDestructor = { .tag = AzInlineTextHitVecDestructorTag_NoDestructor } }
 #define AzInlineTextHitVecDestructor_External(v) { .External = { .tag = AzInlineTextHitVecDestructorTag_External, .payload = v } }
 #define AzMonitorVecDestructor_DefaultRust { .DefaultRust = { .tag = AzMonitorVecDestructorTag_DefaultRust } }
 #define AzMonitorVecDestructor_NoDestructor { .NoDestructor = { .tag = AzMonitorVecDestructorTag_NoDestructor } }
 #define AzMonitorVecDestructor_External(v) { .External = { .tag = AzMonitorVecDestructorTag_External, .payload = v } }
+#define AzTouchPointVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTouchPointVecDestructorTag_DefaultRust } }
+#define AzTouchPointVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTouchPointVecDestructorTag_NoDestructor } }
+#define AzTouchPointVecDestructor_External(v) { .External = { .tag = AzTouchPointVecDestructorTag_External, .payload = v } }
 #define AzVideoModeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzVideoModeVecDestructorTag_DefaultRust } }
 #define AzVideoModeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzVideoModeVecDestructorTag_NoDestructor } }
 #define AzVideoModeVecDestructor_External(v) { .External = { .tag = AzVideoModeVecDestructorTag_External, .payload = v } }
@@ -11260,6 +12992,12 @@ typedef struct AzCss AzCss;
 #define AzVirtualKeyCodeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzVirtualKeyCodeVecDestructorTag_DefaultRust } }
 #define AzVirtualKeyCodeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzVirtualKeyCodeVecDestructorTag_NoDestructor } }
 #define AzVirtualKeyCodeVecDestructor_External(v) { .External = { .tag = AzVirtualKeyCodeVecDestructorTag_External, .payload = v } }
+#define AzShortcutConflictVecDestructor_DefaultRust { .DefaultRust = { .tag = AzShortcutConflictVecDestructorTag_DefaultRust } }
+#define AzShortcutConflictVecDestructor_NoDestructor { .NoDestructor = { .tag = AzShortcutConflictVecDestructorTag_NoDestructor } }
+#define AzShortcutConflictVecDestructor_External(v) { .External = { .tag = AzShortcutConflictVecDestructorTag_External, .payload = v } }
+#define AzShortcutVecDestructor_DefaultRust { .DefaultRust = { .tag = AzShortcutVecDestructorTag_DefaultRust } }
+#define AzShortcutVecDestructor_NoDestructor { .NoDestructor = { .tag = AzShortcutVecDestructorTag_NoDestructor } }
+#define AzShortcutVecDestructor_External(v) { .External = { .tag = AzShortcutVecDestructorTag_External, .payload = v } }
 #define AzCascadeInfoVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCascadeInfoVecDestructorTag_DefaultRust } }
 #define AzCascadeInfoVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCascadeInfoVecDestructorTag_NoDestructor } }
 #define AzCascadeInfoVecDestructor_External(v) { .External = { .tag = AzCascadeInfoVecDestructorTag_External, .payload = v } }
@@ -11347,6 +13085,9 @@ typedef struct AzCss AzCss;
 #define AzOptionChar_Some(v) { .Some = { .tag = AzOptionCharTag_Some, .payload = v } }
 #define AzOptionUsize_None { .None = { .tag = AzOptionUsizeTag_None } }
 #define AzOptionUsize_Some(v) { .Some = { .tag = AzOptionUsizeTag_Some, .payload = v } }
+#define AzFirstFrameMode_ShowImmediately { .ShowImmediately = { .tag = AzFirstFrameModeTag_ShowImmediately } }
+#define AzFirstFrameMode_HideUntilRendered { .HideUntilRendered = { .tag = AzFirstFrameModeTag_HideUntilRendered } }
+#define AzFirstFrameMode_SplashColor(v) { .SplashColor = { .tag = AzFirstFrameModeTag_SplashColor, .payload = v } }
 #define AzRawWindowHandle_IOS(v) { .IOS = { .tag = AzRawWindowHandleTag_IOS, .payload = v } }
 #define AzRawWindowHandle_MacOS(v) { .MacOS = { .tag = AzRawWindowHandleTag_MacOS, .payload = v } }
 #define AzRawWindowHandle_Xlib(v) { .Xlib = { .tag = AzRawWindowHandleTag_Xlib, .payload = v } }
@@ -11554,6 +13295,46 @@ typedef struct AzCss AzCss;
 #define AzLayoutOverflowValue_Inherit { .Inherit = { .tag = AzLayoutOverflowValueTag_Inherit } }
 #define AzLayoutOverflowValue_Initial { .Initial = { .tag = AzLayoutOverflowValueTag_Initial } }
 #define AzLayoutOverflowValue_Exact(v) { .Exact = { .tag = AzLayoutOverflowValueTag_Exact, .payload = v } }
+#define AzStyleScrollbarWidthValue_Auto { .Auto = { .tag = AzStyleScrollbarWidthValueTag_Auto } }
+#define AzStyleScrollbarWidthValue_None { .None = { .tag = AzStyleScrollbarWidthValueTag_None } }
+#define AzStyleScrollbarWidthValue_Inherit { .Inherit = { .tag = AzStyleScrollbarWidthValueTag_Inherit } }
+#define AzStyleScrollbarWidthValue_Initial { .Initial = { .tag = AzStyleScrollbarWidthValueTag_Initial } }
+#define AzStyleScrollbarWidthValue_Exact(v) { .Exact = { .tag = AzStyleScrollbarWidthValueTag_Exact, .payload = v } }
+#define AzStyleScrollbarColorValue_Auto { .Auto = { .tag = AzStyleScrollbarColorValueTag_Auto } }
+#define AzStyleScrollbarColorValue_None { .None = { .tag = AzStyleScrollbarColorValueTag_None } }
+#define AzStyleScrollbarColorValue_Inherit { .Inherit = { .tag = AzStyleScrollbarColorValueTag_Inherit } }
+#define AzStyleScrollbarColorValue_Initial { .Initial = { .tag = AzStyleScrollbarColorValueTag_Initial } }
+#define AzStyleScrollbarColorValue_Exact(v) { .Exact = { .tag = AzStyleScrollbarColorValueTag_Exact, .payload = v } }
+#define AzStyleScrollbarThumbHoverColorValue_Auto { .Auto = { .tag = AzStyleScrollbarThumbHoverColorValueTag_Auto } }
+#define AzStyleScrollbarThumbHoverColorValue_None { .None = { .tag = AzStyleScrollbarThumbHoverColorValueTag_None } }
+#define AzStyleScrollbarThumbHoverColorValue_Inherit { .Inherit = { .tag = AzStyleScrollbarThumbHoverColorValueTag_Inherit } }
+#define AzStyleScrollbarThumbHoverColorValue_Initial { .Initial = { .tag = AzStyleScrollbarThumbHoverColorValueTag_Initial } }
+#define AzStyleScrollbarThumbHoverColorValue_Exact(v) { .Exact = { .tag = AzStyleScrollbarThumbHoverColorValueTag_Exact, .payload = v } }
+#define AzStyleScrollbarThumbActiveColorValue_Auto { .Auto = { .tag = AzStyleScrollbarThumbActiveColorValueTag_Auto } }
+#define AzStyleScrollbarThumbActiveColorValue_None { .None = { .tag = AzStyleScrollbarThumbActiveColorValueTag_None } }
+#define AzStyleScrollbarThumbActiveColorValue_Inherit { .Inherit = { .tag = AzStyleScrollbarThumbActiveColorValueTag_Inherit } }
+#define AzStyleScrollbarThumbActiveColorValue_Initial { .Initial = { .tag = AzStyleScrollbarThumbActiveColorValueTag_Initial } }
+#define AzStyleScrollbarThumbActiveColorValue_Exact(v) { .Exact = { .tag = AzStyleScrollbarThumbActiveColorValueTag_Exact, .payload = v } }
+#define AzStyleWindowDragRegionValue_Auto { .Auto = { .tag = AzStyleWindowDragRegionValueTag_Auto } }
+#define AzStyleWindowDragRegionValue_None { .None = { .tag = AzStyleWindowDragRegionValueTag_None } }
+#define AzStyleWindowDragRegionValue_Inherit { .Inherit = { .tag = AzStyleWindowDragRegionValueTag_Inherit } }
+#define AzStyleWindowDragRegionValue_Initial { .Initial = { .tag = AzStyleWindowDragRegionValueTag_Initial } }
+#define AzStyleWindowDragRegionValue_Exact(v) { .Exact = { .tag = AzStyleWindowDragRegionValueTag_Exact, .payload = v } }
+#define AzStyleCaretAnimationDurationValue_Auto { .Auto = { .tag = AzStyleCaretAnimationDurationValueTag_Auto } }
+#define AzStyleCaretAnimationDurationValue_None { .None = { .tag = AzStyleCaretAnimationDurationValueTag_None } }
+#define AzStyleCaretAnimationDurationValue_Inherit { .Inherit = { .tag = AzStyleCaretAnimationDurationValueTag_Inherit } }
+#define AzStyleCaretAnimationDurationValue_Initial { .Initial = { .tag = AzStyleCaretAnimationDurationValueTag_Initial } }
+#define AzStyleCaretAnimationDurationValue_Exact(v) { .Exact = { .tag = AzStyleCaretAnimationDurationValueTag_Exact, .payload = v } }
+#define AzStyleScrollPaddingValue_Auto { .Auto = { .tag = AzStyleScrollPaddingValueTag_Auto } }
+#define AzStyleScrollPaddingValue_None { .None = { .tag = AzStyleScrollPaddingValueTag_None } }
+#define AzStyleScrollPaddingValue_Inherit { .Inherit = { .tag = AzStyleScrollPaddingValueTag_Inherit } }
+#define AzStyleScrollPaddingValue_Initial { .Initial = { .tag = AzStyleScrollPaddingValueTag_Initial } }
+#define AzStyleScrollPaddingValue_Exact(v) { .Exact = { .tag = AzStyleScrollPaddingValueTag_Exact, .payload = v } }
+#define AzStyleOverflowAnchorValue_Auto { .Auto = { .tag = AzStyleOverflowAnchorValueTag_Auto } }
+#define AzStyleOverflowAnchorValue_None { .None = { .tag = AzStyleOverflowAnchorValueTag_None } }
+#define AzStyleOverflowAnchorValue_Inherit { .Inherit = { .tag = AzStyleOverflowAnchorValueTag_Inherit } }
+#define AzStyleOverflowAnchorValue_Initial { .Initial = { .tag = AzStyleOverflowAnchorValueTag_Initial } }
+#define AzStyleOverflowAnchorValue_Exact(v) { .Exact = { .tag = AzStyleOverflowAnchorValueTag_Exact, .payload = v } }
 #define AzStyleBorderBottomColorValue_Auto { .Auto = { .tag = AzStyleBorderBottomColorValueTag_Auto } }
 #define AzStyleBorderBottomColorValue_None { .None = { .tag = AzStyleBorderBottomColorValueTag_None } }
 #define AzStyleBorderBottomColorValue_Inherit { .Inherit = { .tag = AzStyleBorderBottomColorValueTag_Inherit } }
@@ -11634,11 +13415,6 @@ typedef struct AzCss AzCss;
 #define AzLayoutBorderTopWidthValue_Inherit { .Inherit = { .tag = AzLayoutBorderTopWidthValueTag_Inherit } }
 #define AzLayoutBorderTopWidthValue_Initial { .Initial = { .tag = AzLayoutBorderTopWidthValueTag_Initial } }
 #define AzLayoutBorderTopWidthValue_Exact(v) { .Exact = { .tag = AzLayoutBorderTopWidthValueTag_Exact, .payload = v } }
-#define AzStyleCursorValue_Auto { .Auto = { .tag = AzStyleCursorValueTag_Auto } }
-#define AzStyleCursorValue_None { .None = { .tag = AzStyleCursorValueTag_None } }
-#define AzStyleCursorValue_Inherit { .Inherit = { .tag = AzStyleCursorValueTag_Inherit } }
-#define AzStyleCursorValue_Initial { .Initial = { .tag = AzStyleCursorValueTag_Initial } }
-#define AzStyleCursorValue_Exact(v) { .Exact = { .tag = AzStyleCursorValueTag_Exact, .payload = v } }
 #define AzStyleFontSizeValue_Auto { .Auto = { .tag = AzStyleFontSizeValueTag_Auto } }
 #define AzStyleFontSizeValue_None { .None = { .tag = AzStyleFontSizeValueTag_None } }
 #define AzStyleFontSizeValue_Inherit { .Inherit = { .tag = AzStyleFontSizeValueTag_Inherit } }
@@ -11659,6 +13435,11 @@ typedef struct AzCss AzCss;
 #define AzStyleTabWidthValue_Inherit { .Inherit = { .tag = AzStyleTabWidthValueTag_Inherit } }
 #define AzStyleTabWidthValue_Initial { .Initial = { .tag = AzStyleTabWidthValueTag_Initial } }
 #define AzStyleTabWidthValue_Exact(v) { .Exact = { .tag = AzStyleTabWidthValueTag_Exact, .payload = v } }
+#define AzStyleHyphensValue_Auto { .Auto = { .tag = AzStyleHyphensValueTag_Auto } }
+#define AzStyleHyphensValue_None { .None = { .tag = AzStyleHyphensValueTag_None } }
+#define AzStyleHyphensValue_Inherit { .Inherit = { .tag = AzStyleHyphensValueTag_Inherit } }
+#define AzStyleHyphensValue_Initial { .Initial = { .tag = AzStyleHyphensValueTag_Initial } }
+#define AzStyleHyphensValue_Exact(v) { .Exact = { .tag = AzStyleHyphensValueTag_Exact, .payload = v } }
 #define AzStyleTextAlignValue_Auto { .Auto = { .tag = AzStyleTextAlignValueTag_Auto } }
 #define AzStyleTextAlignValue_None { .None = { .tag = AzStyleTextAlignValueTag_None } }
 #define AzStyleTextAlignValue_Inherit { .Inherit = { .tag = AzStyleTextAlignValueTag_Inherit } }
@@ -11694,6 +13475,16 @@ typedef struct AzCss AzCss;
 #define AzStyleBackfaceVisibilityValue_Inherit { .Inherit = { .tag = AzStyleBackfaceVisibilityValueTag_Inherit } }
 #define AzStyleBackfaceVisibilityValue_Initial { .Initial = { .tag = AzStyleBackfaceVisibilityValueTag_Initial } }
 #define AzStyleBackfaceVisibilityValue_Exact(v) { .Exact = { .tag = AzStyleBackfaceVisibilityValueTag_Exact, .payload = v } }
+#define AzStylePerspectiveValue_Auto { .Auto = { .tag = AzStylePerspectiveValueTag_Auto } }
+#define AzStylePerspectiveValue_None { .None = { .tag = AzStylePerspectiveValueTag_None } }
+#define AzStylePerspectiveValue_Inherit { .Inherit = { .tag = AzStylePerspectiveValueTag_Inherit } }
+#define AzStylePerspectiveValue_Initial { .Initial = { .tag = AzStylePerspectiveValueTag_Initial } }
+#define AzStylePerspectiveValue_Exact(v) { .Exact = { .tag = AzStylePerspectiveValueTag_Exact, .payload = v } }
+#define AzStyleTransformStyleValue_Auto { .Auto = { .tag = AzStyleTransformStyleValueTag_Auto } }
+#define AzStyleTransformStyleValue_None { .None = { .tag = AzStyleTransformStyleValueTag_None } }
+#define AzStyleTransformStyleValue_Inherit { .Inherit = { .tag = AzStyleTransformStyleValueTag_Inherit } }
+#define AzStyleTransformStyleValue_Initial { .Initial = { .tag = AzStyleTransformStyleValueTag_Initial } }
+#define AzStyleTransformStyleValue_Exact(v) { .Exact = { .tag = AzStyleTransformStyleValueTag_Exact, .payload = v } }
 #define AzStyleMixBlendModeValue_Auto { .Auto = { .tag = AzStyleMixBlendModeValueTag_Auto } }
 #define AzStyleMixBlendModeValue_None { .None = { .tag = AzStyleMixBlendModeValueTag_None } }
 #define AzStyleMixBlendModeValue_Inherit { .Inherit = { .tag = AzStyleMixBlendModeValueTag_Inherit } }
@@ -11718,6 +13509,8 @@ typedef struct AzCss AzCss;
 #define AzOptionPixelValueNoPercent_Some(v) { .Some = { .tag = AzOptionPixelValueNoPercentTag_Some, .payload = v } }
 #define AzOptionDropDownOnChoiceChange_None { .None = { .tag = AzOptionDropDownOnChoiceChangeTag_None } }
 #define AzOptionDropDownOnChoiceChange_Some(v) { .Some = { .tag = AzOptionDropDownOnChoiceChangeTag_Some, .payload = v } }
+#define AzOptionToastOnAction_None { .None = { .tag = AzOptionToastOnActionTag_None } }
+#define AzOptionToastOnAction_Some(v) { .Some = { .tag = AzOptionToastOnActionTag_Some, .payload = v } }
 #define AzOptionNodeGraphOnNodeAdded_None { .None = { .tag = AzOptionNodeGraphOnNodeAddedTag_None } }
 #define AzOptionNodeGraphOnNodeAdded_Some(v) { .Some = { .tag = AzOptionNodeGraphOnNodeAddedTag_Some, .payload = v } }
 #define AzOptionNodeGraphOnNodeRemoved_None { .None = { .tag = AzOptionNodeGraphOnNodeRemovedTag_None } }
@@ -11750,6 +13543,8 @@ typedef struct AzCss AzCss;
 #define AzOptionTextInputOnVirtualKeyDown_Some(v) { .Some = { .tag = AzOptionTextInputOnVirtualKeyDownTag_Some, .payload = v } }
 #define AzOptionTextInputOnFocusLost_None { .None = { .tag = AzOptionTextInputOnFocusLostTag_None } }
 #define AzOptionTextInputOnFocusLost_Some(v) { .Some = { .tag = AzOptionTextInputOnFocusLostTag_Some, .payload = v } }
+#define AzOptionTextInputOnSpellingSuggestions_None { .None = { .tag = AzOptionTextInputOnSpellingSuggestionsTag_None } }
+#define AzOptionTextInputOnSpellingSuggestions_Some(v) { .Some = { .tag = AzOptionTextInputOnSpellingSuggestionsTag_Some, .payload = v } }
 #define AzOptionTextInputSelection_None { .None = { .tag = AzOptionTextInputSelectionTag_None } }
 #define AzOptionTextInputSelection_Some(v) { .Some = { .tag = AzOptionTextInputSelectionTag_Some, .payload = v } }
 #define AzOptionNumberInputOnFocusLost_None { .None = { .tag = AzOptionNumberInputOnFocusLostTag_None } }
@@ -11762,6 +13557,10 @@ typedef struct AzCss AzCss;
 #define AzOptionMenuCallback_Some(v) { .Some = { .tag = AzOptionMenuCallbackTag_Some, .payload = v } }
 #define AzOptionPositionInfo_None { .None = { .tag = AzOptionPositionInfoTag_None } }
 #define AzOptionPositionInfo_Some(v) { .Some = { .tag = AzOptionPositionInfoTag_Some, .payload = v } }
+#define AzOptionNodeRect_None { .None = { .tag = AzOptionNodeRectTag_None } }
+#define AzOptionNodeRect_Some(v) { .Some = { .tag = AzOptionNodeRectTag_Some, .payload = v } }
+#define AzOptionScrollInfo_None { .None = { .tag = AzOptionScrollInfoTag_None } }
+#define AzOptionScrollInfo_Some(v) { .Some = { .tag = AzOptionScrollInfoTag_Some, .payload = v } }
 #define AzOptionTimerId_None { .None = { .tag = AzOptionTimerIdTag_None } }
 #define AzOptionTimerId_Some(v) { .Some = { .tag = AzOptionTimerIdTag_Some, .payload = v } }
 #define AzOptionThreadId_None { .None = { .tag = AzOptionThreadIdTag_None } }
@@ -11782,6 +13581,10 @@ typedef struct AzCss AzCss;
 #define AzOptionRendererOptions_Some(v) { .Some = { .tag = AzOptionRendererOptionsTag_Some, .payload = v } }
 #define AzOptionCallback_None { .None = { .tag = AzOptionCallbackTag_None } }
 #define AzOptionCallback_Some(v) { .Some = { .tag = AzOptionCallbackTag_Some, .payload = v } }
+#define AzOptionPaintCallback_None { .None = { .tag = AzOptionPaintCallbackTag_None } }
+#define AzOptionPaintCallback_Some(v) { .Some = { .tag = AzOptionPaintCallbackTag_Some, .payload = v } }
+#define AzOptionCrashHandler_None { .None = { .tag = AzOptionCrashHandlerTag_None } }
+#define AzOptionCrashHandler_Some(v) { .Some = { .tag = AzOptionCrashHandlerTag_Some, .payload = v } }
 #define AzOptionThreadSendMsg_None { .None = { .tag = AzOptionThreadSendMsgTag_None } }
 #define AzOptionThreadSendMsg_Some(v) { .Some = { .tag = AzOptionThreadSendMsgTag_Some, .payload = v } }
 #define AzOptionLayoutRect_None { .None = { .tag = AzOptionLayoutRectTag_None } }
@@ -11802,6 +13605,8 @@ typedef struct AzCss AzCss;
 #define AzOptionColorU_Some(v) { .Some = { .tag = AzOptionColorUTag_Some, .payload = v } }
 #define AzOptionSvgDashPattern_None { .None = { .tag = AzOptionSvgDashPatternTag_None } }
 #define AzOptionSvgDashPattern_Some(v) { .Some = { .tag = AzOptionSvgDashPatternTag_Some, .payload = v } }
+#define AzOptionRawWindowHandle_None { .None = { .tag = AzOptionRawWindowHandleTag_None } }
+#define AzOptionRawWindowHandle_Some(v) { .Some = { .tag = AzOptionRawWindowHandleTag_Some, .payload = v } }
 #define AzOptionLogicalPosition_None { .None = { .tag = AzOptionLogicalPositionTag_None } }
 #define AzOptionLogicalPosition_Some(v) { .Some = { .tag = AzOptionLogicalPositionTag_Some, .payload = v } }
 #define AzOptionPhysicalPositionI32_None { .None = { .tag = AzOptionPhysicalPositionI32Tag_None } }
@@ -11834,6 +13639,7 @@ typedef struct AzCss AzCss;
 #define AzAnimationEasing_EaseOut { .EaseOut = { .tag = AzAnimationEasingTag_EaseOut } }
 #define AzAnimationEasing_EaseInOut { .EaseInOut = { .tag = AzAnimationEasingTag_EaseInOut } }
 #define AzAnimationEasing_CubicBezier(v) { .CubicBezier = { .tag = AzAnimationEasingTag_CubicBezier, .payload = v } }
+#define AzPaintPrimitive_Rect(v) { .Rect = { .tag = AzPaintPrimitiveTag_Rect, .payload = v } }
 #define AzEventFilter_Hover(v) { .Hover = { .tag = AzEventFilterTag_Hover, .payload = v } }
 #define AzEventFilter_Not(v) { .Not = { .tag = AzEventFilterTag_Not, .payload = v } }
 #define AzEventFilter_Focus(v) { .Focus = { .tag = AzEventFilterTag_Focus, .payload = v } }
@@ -11846,6 +13652,12 @@ typedef struct AzCss AzCss;
 #define AzCssPathPseudoSelector_Hover { .Hover = { .tag = AzCssPathPseudoSelectorTag_Hover } }
 #define AzCssPathPseudoSelector_Active { .Active = { .tag = AzCssPathPseudoSelectorTag_Active } }
 #define AzCssPathPseudoSelector_Focus { .Focus = { .tag = AzCssPathPseudoSelectorTag_Focus } }
+#define AzCssPathPseudoSelector_Disabled { .Disabled = { .tag = AzCssPathPseudoSelectorTag_Disabled } }
+#define AzCssPathPseudoSelector_Checked { .Checked = { .tag = AzCssPathPseudoSelectorTag_Checked } }
+#define AzCssPathPseudoSelector_Selected { .Selected = { .tag = AzCssPathPseudoSelectorTag_Selected } }
+#define AzCssPathPseudoSelector_Indeterminate { .Indeterminate = { .tag = AzCssPathPseudoSelectorTag_Indeterminate } }
+#define AzCssPathPseudoSelector_Before { .Before = { .tag = AzCssPathPseudoSelectorTag_Before } }
+#define AzCssPathPseudoSelector_After { .After = { .tag = AzCssPathPseudoSelectorTag_After } }
 #define AzAnimationInterpolationFunction_Ease { .Ease = { .tag = AzAnimationInterpolationFunctionTag_Ease } }
 #define AzAnimationInterpolationFunction_Linear { .Linear = { .tag = AzAnimationInterpolationFunctionTag_Linear } }
 #define AzAnimationInterpolationFunction_EaseIn { .EaseIn = { .tag = AzAnimationInterpolationFunctionTag_EaseIn } }
@@ -11913,6 +13725,8 @@ typedef struct AzCss AzCss;
 #define AzOptionResolvedTextLayoutOptions_Some(v) { .Some = { .tag = AzOptionResolvedTextLayoutOptionsTag_Some, .payload = v } }
 #define AzOptionVirtualKeyCodeCombo_None { .None = { .tag = AzOptionVirtualKeyCodeComboTag_None } }
 #define AzOptionVirtualKeyCodeCombo_Some(v) { .Some = { .tag = AzOptionVirtualKeyCodeComboTag_Some, .payload = v } }
+#define AzOptionShortcutConflict_None { .None = { .tag = AzOptionShortcutConflictTag_None } }
+#define AzOptionShortcutConflict_Some(v) { .Some = { .tag = AzOptionShortcutConflictTag_Some, .payload = v } }
 #define AzOptionMouseState_None { .None = { .tag = AzOptionMouseStateTag_None } }
 #define AzOptionMouseState_Some(v) { .Some = { .tag = AzOptionMouseStateTag_Some, .payload = v } }
 #define AzOptionKeyboardState_None { .None = { .tag = AzOptionKeyboardStateTag_None } }
@@ -11931,6 +13745,10 @@ typedef struct AzCss AzCss;
 #define AzOptionTexture_Some(v) { .Some = { .tag = AzOptionTextureTag_Some, .payload = v } }
 #define AzOptionInstant_None { .None = { .tag = AzOptionInstantTag_None } }
 #define AzOptionInstant_Some(v) { .Some = { .tag = AzOptionInstantTag_Some, .payload = v } }
+#define AzEventRecording_Record(v) { .Record = { .tag = AzEventRecordingTag_Record, .payload = v } }
+#define AzEventRecording_Replay(v) { .Replay = { .tag = AzEventRecordingTag_Replay, .payload = v } }
+#define AzFrameRecordingOutput_GifDirectory(v) { .GifDirectory = { .tag = AzFrameRecordingOutputTag_GifDirectory, .payload = v } }
+#define AzFrameRecordingOutput_Callback(v) { .Callback = { .tag = AzFrameRecordingOutputTag_Callback, .payload = v } }
 #define AzLayoutCallback_Raw(v) { .Raw = { .tag = AzLayoutCallbackTag_Raw, .payload = v } }
 #define AzLayoutCallback_Marshaled(v) { .Marshaled = { .tag = AzLayoutCallbackTag_Marshaled, .payload = v } }
 #define AzInlineWord_Tab { .Tab = { .tag = AzInlineWordTag_Tab } }
@@ -11943,6 +13761,7 @@ typedef struct AzCss AzCss;
 #define AzNodeType_Text(v) { .Text = { .tag = AzNodeTypeTag_Text, .payload = v } }
 #define AzNodeType_Image(v) { .Image = { .tag = AzNodeTypeTag_Image, .payload = v } }
 #define AzNodeType_IFrame(v) { .IFrame = { .tag = AzNodeTypeTag_IFrame, .payload = v } }
+#define AzNodeType_CustomLayout(v) { .CustomLayout = { .tag = AzNodeTypeTag_CustomLayout, .payload = v } }
 #define AzIdOrClass_Id(v) { .Id = { .tag = AzIdOrClassTag_Id, .payload = v } }
 #define AzIdOrClass_Class(v) { .Class = { .tag = AzIdOrClassTag_Class, .payload = v } }
 #define AzCssPathSelector_Global { .Global = { .tag = AzCssPathSelectorTag_Global } }
@@ -11957,6 +13776,10 @@ typedef struct AzCss AzCss;
 #define AzStyleBackgroundContent_ConicGradient(v) { .ConicGradient = { .tag = AzStyleBackgroundContentTag_ConicGradient, .payload = v } }
 #define AzStyleBackgroundContent_Image(v) { .Image = { .tag = AzStyleBackgroundContentTag_Image, .payload = v } }
 #define AzStyleBackgroundContent_Color(v) { .Color = { .tag = AzStyleBackgroundContentTag_Color, .payload = v } }
+#define AzStyleContent_None { .None = { .tag = AzStyleContentTag_None } }
+#define AzStyleContent_Text(v) { .Text = { .tag = AzStyleContentTag_Text, .payload = v } }
+#define AzStyleContent_Image(v) { .Image = { .tag = AzStyleContentTag_Image, .payload = v } }
+#define AzStyleContent_Counter(v) { .Counter = { .tag = AzStyleContentTag_Counter, .payload = v } }
 #define AzStyleFontFamily_System(v) { .System = { .tag = AzStyleFontFamilyTag_System, .payload = v } }
 #define AzStyleFontFamily_File(v) { .File = { .tag = AzStyleFontFamilyTag_File, .payload = v } }
 #define AzStyleFontFamily_Ref(v) { .Ref = { .tag = AzStyleFontFamilyTag_Ref, .payload = v } }
@@ -11965,6 +13788,21 @@ typedef struct AzCss AzCss;
 #define AzScrollbarStyleValue_Inherit { .Inherit = { .tag = AzScrollbarStyleValueTag_Inherit } }
 #define AzScrollbarStyleValue_Initial { .Initial = { .tag = AzScrollbarStyleValueTag_Initial } }
 #define AzScrollbarStyleValue_Exact(v) { .Exact = { .tag = AzScrollbarStyleValueTag_Exact, .payload = v } }
+#define AzStyleContentValue_Auto { .Auto = { .tag = AzStyleContentValueTag_Auto } }
+#define AzStyleContentValue_None { .None = { .tag = AzStyleContentValueTag_None } }
+#define AzStyleContentValue_Inherit { .Inherit = { .tag = AzStyleContentValueTag_Inherit } }
+#define AzStyleContentValue_Initial { .Initial = { .tag = AzStyleContentValueTag_Initial } }
+#define AzStyleContentValue_Exact(v) { .Exact = { .tag = AzStyleContentValueTag_Exact, .payload = v } }
+#define AzStyleCounterResetValue_Auto { .Auto = { .tag = AzStyleCounterResetValueTag_Auto } }
+#define AzStyleCounterResetValue_None { .None = { .tag = AzStyleCounterResetValueTag_None } }
+#define AzStyleCounterResetValue_Inherit { .Inherit = { .tag = AzStyleCounterResetValueTag_Inherit } }
+#define AzStyleCounterResetValue_Initial { .Initial = { .tag = AzStyleCounterResetValueTag_Initial } }
+#define AzStyleCounterResetValue_Exact(v) { .Exact = { .tag = AzStyleCounterResetValueTag_Exact, .payload = v } }
+#define AzStyleCounterIncrementValue_Auto { .Auto = { .tag = AzStyleCounterIncrementValueTag_Auto } }
+#define AzStyleCounterIncrementValue_None { .None = { .tag = AzStyleCounterIncrementValueTag_None } }
+#define AzStyleCounterIncrementValue_Inherit { .Inherit = { .tag = AzStyleCounterIncrementValueTag_Inherit } }
+#define AzStyleCounterIncrementValue_Initial { .Initial = { .tag = AzStyleCounterIncrementValueTag_Initial } }
+#define AzStyleCounterIncrementValue_Exact(v) { .Exact = { .tag = AzStyleCounterIncrementValueTag_Exact, .payload = v } }
 #define AzStyleTransformVecValue_Auto { .Auto = { .tag = AzStyleTransformVecValueTag_Auto } }
 #define AzStyleTransformVecValue_None { .None = { .tag = AzStyleTransformVecValueTag_None } }
 #define AzStyleTransformVecValue_Inherit { .Inherit = { .tag = AzStyleTransformVecValueTag_Inherit } }
@@ -12001,6 +13839,10 @@ typedef struct AzCss AzCss;
 #define AzOptionFileTypeList_Some(v) { .Some = { .tag = AzOptionFileTypeListTag_Some, .payload = v } }
 #define AzOptionFile_None { .None = { .tag = AzOptionFileTag_None } }
 #define AzOptionFile_Some(v) { .Some = { .tag = AzOptionFileTag_Some, .payload = v } }
+#define AzOptionJankCapture_None { .None = { .tag = AzOptionJankCaptureTag_None } }
+#define AzOptionJankCapture_Some(v) { .Some = { .tag = AzOptionJankCaptureTag_Some, .payload = v } }
+#define AzOptionEventRecording_None { .None = { .tag = AzOptionEventRecordingTag_None } }
+#define AzOptionEventRecording_Some(v) { .Some = { .tag = AzOptionEventRecordingTag_Some, .payload = v } }
 #define AzOptionRawImage_None { .None = { .tag = AzOptionRawImageTag_None } }
 #define AzOptionRawImage_Some(v) { .Some = { .tag = AzOptionRawImageTag_Some, .payload = v } }
 #define AzOptionWaylandTheme_None { .None = { .tag = AzOptionWaylandThemeTag_None } }
@@ -12023,11 +13865,47 @@ typedef struct AzCss AzCss;
 #define AzMenuItem_String(v) { .String = { .tag = AzMenuItemTag_String, .payload = v } }
 #define AzMenuItem_Separator { .Separator = { .tag = AzMenuItemTag_Separator } }
 #define AzMenuItem_BreakLine { .BreakLine = { .tag = AzMenuItemTag_BreakLine } }
+#define AzStyleCursor_Alias { .Alias = { .tag = AzStyleCursorTag_Alias } }
+#define AzStyleCursor_AllScroll { .AllScroll = { .tag = AzStyleCursorTag_AllScroll } }
+#define AzStyleCursor_Cell { .Cell = { .tag = AzStyleCursorTag_Cell } }
+#define AzStyleCursor_ColResize { .ColResize = { .tag = AzStyleCursorTag_ColResize } }
+#define AzStyleCursor_ContextMenu { .ContextMenu = { .tag = AzStyleCursorTag_ContextMenu } }
+#define AzStyleCursor_Copy { .Copy = { .tag = AzStyleCursorTag_Copy } }
+#define AzStyleCursor_Crosshair { .Crosshair = { .tag = AzStyleCursorTag_Crosshair } }
+#define AzStyleCursor_Default { .Default = { .tag = AzStyleCursorTag_Default } }
+#define AzStyleCursor_EResize { .EResize = { .tag = AzStyleCursorTag_EResize } }
+#define AzStyleCursor_EwResize { .EwResize = { .tag = AzStyleCursorTag_EwResize } }
+#define AzStyleCursor_Grab { .Grab = { .tag = AzStyleCursorTag_Grab } }
+#define AzStyleCursor_Grabbing { .Grabbing = { .tag = AzStyleCursorTag_Grabbing } }
+#define AzStyleCursor_Help { .Help = { .tag = AzStyleCursorTag_Help } }
+#define AzStyleCursor_Move { .Move = { .tag = AzStyleCursorTag_Move } }
+#define AzStyleCursor_NResize { .NResize = { .tag = AzStyleCursorTag_NResize } }
+#define AzStyleCursor_NsResize { .NsResize = { .tag = AzStyleCursorTag_NsResize } }
+#define AzStyleCursor_NeswResize { .NeswResize = { .tag = AzStyleCursorTag_NeswResize } }
+#define AzStyleCursor_NwseResize { .NwseResize = { .tag = AzStyleCursorTag_NwseResize } }
+#define AzStyleCursor_Pointer { .Pointer = { .tag = AzStyleCursorTag_Pointer } }
+#define AzStyleCursor_Progress { .Progress = { .tag = AzStyleCursorTag_Progress } }
+#define AzStyleCursor_RowResize { .RowResize = { .tag = AzStyleCursorTag_RowResize } }
+#define AzStyleCursor_SResize { .SResize = { .tag = AzStyleCursorTag_SResize } }
+#define AzStyleCursor_SeResize { .SeResize = { .tag = AzStyleCursorTag_SeResize } }
+#define AzStyleCursor_Text { .Text = { .tag = AzStyleCursorTag_Text } }
+#define AzStyleCursor_Unset { .Unset = { .tag = AzStyleCursorTag_Unset } }
+#define AzStyleCursor_VerticalText { .VerticalText = { .tag = AzStyleCursorTag_VerticalText } }
+#define AzStyleCursor_WResize { .WResize = { .tag = AzStyleCursorTag_WResize } }
+#define AzStyleCursor_Wait { .Wait = { .tag = AzStyleCursorTag_Wait } }
+#define AzStyleCursor_ZoomIn { .ZoomIn = { .tag = AzStyleCursorTag_ZoomIn } }
+#define AzStyleCursor_ZoomOut { .ZoomOut = { .tag = AzStyleCursorTag_ZoomOut } }
+#define AzStyleCursor_Image(v) { .Image = { .tag = AzStyleCursorTag_Image, .payload = v } }
 #define AzStyleBackgroundContentVecValue_Auto { .Auto = { .tag = AzStyleBackgroundContentVecValueTag_Auto } }
 #define AzStyleBackgroundContentVecValue_None { .None = { .tag = AzStyleBackgroundContentVecValueTag_None } }
 #define AzStyleBackgroundContentVecValue_Inherit { .Inherit = { .tag = AzStyleBackgroundContentVecValueTag_Inherit } }
 #define AzStyleBackgroundContentVecValue_Initial { .Initial = { .tag = AzStyleBackgroundContentVecValueTag_Initial } }
 #define AzStyleBackgroundContentVecValue_Exact(v) { .Exact = { .tag = AzStyleBackgroundContentVecValueTag_Exact, .payload = v } }
+#define AzStyleCursorValue_Auto { .Auto = { .tag = AzStyleCursorValueTag_Auto } }
+#define AzStyleCursorValue_None { .None = { .tag = AzStyleCursorValueTag_None } }
+#define AzStyleCursorValue_Inherit { .Inherit = { .tag = AzStyleCursorValueTag_Inherit } }
+#define AzStyleCursorValue_Initial { .Initial = { .tag = AzStyleCursorValueTag_Initial } }
+#define AzStyleCursorValue_Exact(v) { .Exact = { .tag = AzStyleCursorValueTag_Exact, .payload = v } }
 #define AzStyleFontFamilyVecValue_Auto { .Auto = { .tag = AzStyleFontFamilyVecValueTag_Auto } }
 #define AzStyleFontFamilyVecValue_None { .None = { .tag = AzStyleFontFamilyVecValueTag_None } }
 #define AzStyleFontFamilyVecValue_Inherit { .Inherit = { .tag = AzStyleFontFamilyVecValueTag_Inherit } }
@@ -12041,6 +13919,7 @@ typedef struct AzCss AzCss;
 #define AzCssProperty_LineHeight(v) { .LineHeight = { .tag = AzCssPropertyTag_LineHeight, .payload = v } }
 #define AzCssProperty_WordSpacing(v) { .WordSpacing = { .tag = AzCssPropertyTag_WordSpacing, .payload = v } }
 #define AzCssProperty_TabWidth(v) { .TabWidth = { .tag = AzCssPropertyTag_TabWidth, .payload = v } }
+#define AzCssProperty_Hyphens(v) { .Hyphens = { .tag = AzCssPropertyTag_Hyphens, .payload = v } }
 #define AzCssProperty_Cursor(v) { .Cursor = { .tag = AzCssPropertyTag_Cursor, .payload = v } }
 #define AzCssProperty_Display(v) { .Display = { .tag = AzCssPropertyTag_Display, .payload = v } }
 #define AzCssProperty_Float(v) { .Float = { .tag = AzCssPropertyTag_Float, .payload = v } }
@@ -12098,11 +13977,24 @@ typedef struct AzCss AzCss;
 #define AzCssProperty_BoxShadowTop(v) { .BoxShadowTop = { .tag = AzCssPropertyTag_BoxShadowTop, .payload = v } }
 #define AzCssProperty_BoxShadowBottom(v) { .BoxShadowBottom = { .tag = AzCssPropertyTag_BoxShadowBottom, .payload = v } }
 #define AzCssProperty_ScrollbarStyle(v) { .ScrollbarStyle = { .tag = AzCssPropertyTag_ScrollbarStyle, .payload = v } }
+#define AzCssProperty_ScrollbarWidth(v) { .ScrollbarWidth = { .tag = AzCssPropertyTag_ScrollbarWidth, .payload = v } }
+#define AzCssProperty_ScrollbarColor(v) { .ScrollbarColor = { .tag = AzCssPropertyTag_ScrollbarColor, .payload = v } }
+#define AzCssProperty_ScrollbarThumbHoverColor(v) { .ScrollbarThumbHoverColor = { .tag = AzCssPropertyTag_ScrollbarThumbHoverColor, .payload = v } }
+#define AzCssProperty_ScrollbarThumbActiveColor(v) { .ScrollbarThumbActiveColor = { .tag = AzCssPropertyTag_ScrollbarThumbActiveColor, .payload = v } }
+#define AzCssProperty_WindowDragRegion(v) { .WindowDragRegion = { .tag = AzCssPropertyTag_WindowDragRegion, .payload = v } }
+#define AzCssProperty_CaretAnimationDuration(v) { .CaretAnimationDuration = { .tag = AzCssPropertyTag_CaretAnimationDuration, .payload = v } }
+#define AzCssProperty_ScrollPadding(v) { .ScrollPadding = { .tag = AzCssPropertyTag_ScrollPadding, .payload = v } }
+#define AzCssProperty_Content(v) { .Content = { .tag = AzCssPropertyTag_Content, .payload = v } }
+#define AzCssProperty_CounterReset(v) { .CounterReset = { .tag = AzCssPropertyTag_CounterReset, .payload = v } }
+#define AzCssProperty_CounterIncrement(v) { .CounterIncrement = { .tag = AzCssPropertyTag_CounterIncrement, .payload = v } }
+#define AzCssProperty_OverflowAnchor(v) { .OverflowAnchor = { .tag = AzCssPropertyTag_OverflowAnchor, .payload = v } }
 #define AzCssProperty_Opacity(v) { .Opacity = { .tag = AzCssPropertyTag_Opacity, .payload = v } }
 #define AzCssProperty_Transform(v) { .Transform = { .tag = AzCssPropertyTag_Transform, .payload = v } }
 #define AzCssProperty_TransformOrigin(v) { .TransformOrigin = { .tag = AzCssPropertyTag_TransformOrigin, .payload = v } }
 #define AzCssProperty_PerspectiveOrigin(v) { .PerspectiveOrigin = { .tag = AzCssPropertyTag_PerspectiveOrigin, .payload = v } }
 #define AzCssProperty_BackfaceVisibility(v) { .BackfaceVisibility = { .tag = AzCssPropertyTag_BackfaceVisibility, .payload = v } }
+#define AzCssProperty_Perspective(v) { .Perspective = { .tag = AzCssPropertyTag_Perspective, .payload = v } }
+#define AzCssProperty_TransformStyle(v) { .TransformStyle = { .tag = AzCssPropertyTag_TransformStyle, .payload = v } }
 #define AzCssProperty_MixBlendMode(v) { .MixBlendMode = { .tag = AzCssPropertyTag_MixBlendMode, .payload = v } }
 #define AzCssProperty_Filter(v) { .Filter = { .tag = AzCssPropertyTag_Filter, .payload = v } }
 #define AzCssProperty_BackdropFilter(v) { .BackdropFilter = { .tag = AzCssPropertyTag_BackdropFilter, .payload = v } }
@@ -12116,6 +14008,8 @@ typedef struct AzCss AzCss;
 #define AzSvgSimpleNode_RectHole(v) { .RectHole = { .tag = AzSvgSimpleNodeTag_RectHole, .payload = v } }
 #define AzOptionCssProperty_None { .None = { .tag = AzOptionCssPropertyTag_None } }
 #define AzOptionCssProperty_Some(v) { .Some = { .tag = AzOptionCssPropertyTag_Some, .payload = v } }
+#define AzOptionFrameRecording_None { .None = { .tag = AzOptionFrameRecordingTag_None } }
+#define AzOptionFrameRecording_Some(v) { .Some = { .tag = AzOptionFrameRecordingTag_Some, .payload = v } }
 #define AzNodeDataInlineCssProperty_Normal(v) { .Normal = { .tag = AzNodeDataInlineCssPropertyTag_Normal, .payload = v } }
 #define AzNodeDataInlineCssProperty_Active(v) { .Active = { .tag = AzNodeDataInlineCssPropertyTag_Active, .payload = v } }
 #define AzNodeDataInlineCssProperty_Focus(v) { .Focus = { .tag = AzNodeDataInlineCssPropertyTag_Focus, .payload = v } }
@@ -12130,6 +14024,8 @@ typedef struct AzCss AzCss;
 #define AzOptionWindowState_Some(v) { .Some = { .tag = AzOptionWindowStateTag_Some, .payload = v } }
 #define AzOptionInlineText_None { .None = { .tag = AzOptionInlineTextTag_None } }
 #define AzOptionInlineText_Some(v) { .Some = { .tag = AzOptionInlineTextTag_Some, .payload = v } }
+#define AzOptionTextLayout_None { .None = { .tag = AzOptionTextLayoutTag_None } }
+#define AzOptionTextLayout_Some(v) { .Some = { .tag = AzOptionTextLayoutTag_Some, .payload = v } }
 #define AzXmlParseError_InvalidDeclaration(v) { .InvalidDeclaration = { .tag = AzXmlParseErrorTag_InvalidDeclaration, .payload = v } }
 #define AzXmlParseError_InvalidComment(v) { .InvalidComment = { .tag = AzXmlParseErrorTag_InvalidComment, .payload = v } }
 #define AzXmlParseError_InvalidPI(v) { .InvalidPI = { .tag = AzXmlParseErrorTag_InvalidPI, .payload = v } }
@@ -12146,9 +14042,11 @@ typedef struct AzCss AzCss;
 #define AzFocusTarget_Next { .Next = { .tag = AzFocusTargetTag_Next } }
 #define AzFocusTarget_First { .First = { .tag = AzFocusTargetTag_First } }
 #define AzFocusTarget_Last { .Last = { .tag = AzFocusTargetTag_Last } }
+#define AzFocusTarget_Direction(v) { .Direction = { .tag = AzFocusTargetTag_Direction, .payload = v } }
 #define AzFocusTarget_NoFocus { .NoFocus = { .tag = AzFocusTargetTag_NoFocus } }
 #define AzCssDeclaration_Static(v) { .Static = { .tag = AzCssDeclarationTag_Static, .payload = v } }
 #define AzCssDeclaration_Dynamic(v) { .Dynamic = { .tag = AzCssDeclarationTag_Dynamic, .payload = v } }
+#define AzCssDeclaration_System(v) { .System = { .tag = AzCssDeclarationTag_System, .payload = v } }
 #define AzXmlError_NoParserAvailable { .NoParserAvailable = { .tag = AzXmlErrorTag_NoParserAvailable } }
 #define AzXmlError_InvalidXmlPrefixUri(v) { .InvalidXmlPrefixUri = { .tag = AzXmlErrorTag_InvalidXmlPrefixUri, .payload = v } }
 #define AzXmlError_UnexpectedXmlUri(v) { .UnexpectedXmlUri = { .tag = AzXmlErrorTag_UnexpectedXmlUri, .payload = v } }
@@ -12264,10 +14162,18 @@ AzInlineWord AzInlineWordVecArray[] = {};
 #define AzInlineWordVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzInlineWord), .cap = sizeof(v) / sizeof(AzInlineWord), .destructor = { .NoDestructor = { .tag = AzInlineWordVecDestructorTag_NoDestructor, }, }, }
 #define AzInlineWordVec_empty { .ptr = &AzInlineWordVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzInlineWordVecDestructorTag_NoDestructor, }, }, }
 
+AzPaintPrimitive AzPaintPrimitiveVecArray[] = {};
+#define AzPaintPrimitiveVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzPaintPrimitive), .cap = sizeof(v) / sizeof(AzPaintPrimitive), .destructor = { .NoDestructor = { .tag = AzPaintPrimitiveVecDestructorTag_NoDestructor, }, }, }
+#define AzPaintPrimitiveVec_empty { .ptr = &AzPaintPrimitiveVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzPaintPrimitiveVecDestructorTag_NoDestructor, }, }, }
+
 AzInlineGlyph AzInlineGlyphVecArray[] = {};
 #define AzInlineGlyphVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzInlineGlyph), .cap = sizeof(v) / sizeof(AzInlineGlyph), .destructor = { .NoDestructor = { .tag = AzInlineGlyphVecDestructorTag_NoDestructor, }, }, }
 #define AzInlineGlyphVec_empty { .ptr = &AzInlineGlyphVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzInlineGlyphVecDestructorTag_NoDestructor, }, }, }
 
+AzTextUnderline AzTextUnderlineVecArray[] = {};
+#define AzTextUnderlineVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTextUnderline), .cap = sizeof(v) / sizeof(AzTextUnderline), .destructor = { .NoDestructor = { .tag = AzTextUnderlineVecDestructorTag_NoDestructor, }, }, }
+#define AzTextUnderlineVec_empty { .ptr = &AzTextUnderlineVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTextUnderlineVecDestructorTag_NoDestructor, }, }, }
+
 AzInlineTextHit AzInlineTextHitVecArray[] = {};
 #define AzInlineTextHitVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzInlineTextHit), .cap = sizeof(v) / sizeof(AzInlineTextHit), .destructor = { .NoDestructor = { .tag = AzInlineTextHitVecDestructorTag_NoDestructor, }, }, }
 #define AzInlineTextHitVec_empty { .ptr = &AzInlineTextHitVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzInlineTextHitVecDestructorTag_NoDestructor, }, }, }
@@ -12276,6 +14182,10 @@ AzMonitor AzMonitorVecArray[] = {};
 #define AzMonitorVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzMonitor), .cap = sizeof(v) / sizeof(AzMonitor), .destructor = { .NoDestructor = { .tag = AzMonitorVecDestructorTag_NoDestructor, }, }, }
 #define AzMonitorVec_empty { .ptr = &AzMonitorVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzMonitorVecDestructorTag_NoDestructor, }, }, }
 
+AzTouchPoint AzTouchPointVecArray[] = {};
+#define AzTouchPointVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTouchPoint), .cap = sizeof(v) / sizeof(AzTouchPoint), .destructor = { .NoDestructor = { .tag = AzTouchPointVecDestructorTag_NoDestructor, }, }, }
+#define AzTouchPointVec_empty { .ptr = &AzTouchPointVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTouchPointVecDestructorTag_NoDestructor, }, }, }
+
 AzVideoMode AzVideoModeVecArray[] = {};
 #define AzVideoModeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzVideoMode), .cap = sizeof(v) / sizeof(AzVideoMode), .destructor = { .NoDestructor = { .tag = AzVideoModeVecDestructorTag_NoDestructor, }, }, }
 #define AzVideoModeVec_empty { .ptr = &AzVideoModeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzVideoModeVecDestructorTag_NoDestructor, }, }, }
@@ -12356,6 +14266,14 @@ AzVirtualKeyCode AzVirtualKeyCodeVecArray[] = {};
 #define AzVirtualKeyCodeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzVirtualKeyCode), .cap = sizeof(v) / sizeof(AzVirtualKeyCode), .destructor = { .NoDestructor = { .tag = AzVirtualKeyCodeVecDestructorTag_NoDestructor, }, }, }
 #define AzVirtualKeyCodeVec_empty { .ptr = &AzVirtualKeyCodeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzVirtualKeyCodeVecDestructorTag_NoDestructor, }, }, }
 
+AzShortcutConflict AzShortcutConflictVecArray[] = {};
+#define AzShortcutConflictVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzShortcutConflict), .cap = sizeof(v) / sizeof(AzShortcutConflict), .destructor = { .NoDestructor = { .tag = AzShortcutConflictVecDestructorTag_NoDestructor, }, }, }
+#define AzShortcutConflictVec_empty { .ptr = &AzShortcutConflictVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzShortcutConflictVecDestructorTag_NoDestructor, }, }, }
+
+AzShortcut AzShortcutVecArray[] = {};
+#define AzShortcutVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzShortcut), .cap = sizeof(v) / sizeof(AzShortcut), .destructor = { .NoDestructor = { .tag = AzShortcutVecDestructorTag_NoDestructor, }, }, }
+#define AzShortcutVec_empty { .ptr = &AzShortcutVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzShortcutVecDestructorTag_NoDestructor, }, }, }
+
 AzCascadeInfo AzCascadeInfoVecArray[] = {};
 #define AzCascadeInfoVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCascadeInfo), .cap = sizeof(v) / sizeof(AzCascadeInfo), .destructor = { .NoDestructor = { .tag = AzCascadeInfoVecDestructorTag_NoDestructor, }, }, }
 #define AzCascadeInfoVec_empty { .ptr = &AzCascadeInfoVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCascadeInfoVecDestructorTag_NoDestructor, }, }, }
@@ -12453,14 +14371,36 @@ AzNodeData AzNodeDataVecArray[] = {};
 extern DLLIMPORT AzApp AzApp_new(AzRefAny  data, AzAppConfig  config);
 extern DLLIMPORT void AzApp_addWindow(AzApp* restrict app, AzWindowCreateOptions  window);
 extern DLLIMPORT void AzApp_addImage(AzApp* restrict app, AzString  id, AzImageRef  image);
+extern DLLIMPORT AzApp AzApp_withSplash(const AzApp app, AzSplashOptions  splash);
+extern DLLIMPORT void AzApp_addGlQuirkRule(AzApp* restrict app, AzGlQuirkRule  rule);
 extern DLLIMPORT AzMonitorVec AzApp_getMonitors(const AzApp* app);
+extern DLLIMPORT AzEventLoopProxy AzApp_createEventLoopProxy(const AzApp* app);
 extern DLLIMPORT void AzApp_run(const AzApp* app, AzWindowCreateOptions  window);
 extern DLLIMPORT void AzApp_delete(AzApp* restrict instance);
 extern DLLIMPORT AzApp AzApp_deepCopy(AzApp* const instance);
 extern DLLIMPORT AzAppConfig AzAppConfig_new(AzLayoutSolver  layout_solver);
+extern DLLIMPORT void AzAppConfig_delete(AzAppConfig* restrict instance);
+extern DLLIMPORT AzJankCapture AzJankCapture_new(AzDuration  threshold);
+extern DLLIMPORT void AzJankCapture_delete(AzJankCapture* restrict instance);
+extern DLLIMPORT AzSplashOptions AzSplashOptions_new();
+extern DLLIMPORT AzSplashOptions AzSplashOptions_withLogo(const AzSplashOptions splashoptions, AzImageRef  logo);
+extern DLLIMPORT void AzSplashOptions_delete(AzSplashOptions* restrict instance);
+extern DLLIMPORT void AzEventRecording_delete(AzEventRecording* restrict instance);
+extern DLLIMPORT AzFrameRecording AzFrameRecording_new(AzFrameRecordingOutput  output);
+extern DLLIMPORT void AzFrameRecording_delete(AzFrameRecording* restrict instance);
+extern DLLIMPORT void AzFrameRecordingOutput_delete(AzFrameRecordingOutput* restrict instance);
+extern DLLIMPORT void AzRecordedFrame_delete(AzRecordedFrame* restrict instance);
+extern DLLIMPORT void AzCrashInfo_delete(AzCrashInfo* restrict instance);
 extern DLLIMPORT AzSystemCallbacks AzSystemCallbacks_libraryInternal();
 extern DLLIMPORT AzWindowCreateOptions AzWindowCreateOptions_new(AzLayoutCallbackType  layout_callback);
+extern DLLIMPORT AzWindowCreateOptions AzWindowCreateOptions_withParentHandle(const AzWindowCreateOptions windowcreateoptions, AzRawWindowHandle  parent_handle);
+extern DLLIMPORT AzWindowCreateOptions AzWindowCreateOptions_withWindowData(const AzWindowCreateOptions windowcreateoptions, AzRefAny  window_data);
+extern DLLIMPORT AzWindowCreateOptions AzWindowCreateOptions_withBackgroundEffect(const AzWindowCreateOptions windowcreateoptions, AzWindowBackgroundEffect  background_effect);
+extern DLLIMPORT AzWindowCreateOptions AzWindowCreateOptions_withFadeIn(const AzWindowCreateOptions windowcreateoptions, AzDuration  duration);
+extern DLLIMPORT AzWindowCreateOptions AzWindowCreateOptions_withFirstFrame(const AzWindowCreateOptions windowcreateoptions, AzFirstFrameMode  first_frame);
+extern DLLIMPORT AzWindowCreateOptions AzWindowCreateOptions_withScrollbars(const AzWindowCreateOptions windowcreateoptions, AzScrollbarConfig  scrollbars);
 extern DLLIMPORT void AzWindowCreateOptions_delete(AzWindowCreateOptions* restrict instance);
+extern DLLIMPORT AzScrollbarConfig AzScrollbarConfig_default();
 extern DLLIMPORT AzLogicalPosition AzLogicalPosition_new(float x, float y);
 extern DLLIMPORT AzLogicalPosition AzLogicalPosition_zero();
 extern DLLIMPORT AzPhysicalSizeU32 AzLogicalSize_toPhysical(const AzLogicalSize* logicalsize, float hidpi_factor);
@@ -12469,11 +14409,22 @@ extern DLLIMPORT void AzLargeWindowIconBytes_delete(AzLargeWindowIconBytes* rest
 extern DLLIMPORT void AzWindowIcon_delete(AzWindowIcon* restrict instance);
 extern DLLIMPORT void AzTaskBarIcon_delete(AzTaskBarIcon* restrict instance);
 extern DLLIMPORT float AzWindowSize_getHidpiFactor(const AzWindowSize* windowsize);
+extern DLLIMPORT void AzWindowSize_setZoom(AzWindowSize* restrict windowsize, float zoom);
 extern DLLIMPORT bool  AzKeyboardState_shiftDown(const AzKeyboardState* keyboardstate);
 extern DLLIMPORT bool  AzKeyboardState_ctrlDown(const AzKeyboardState* keyboardstate);
 extern DLLIMPORT bool  AzKeyboardState_altDown(const AzKeyboardState* keyboardstate);
 extern DLLIMPORT bool  AzKeyboardState_superDown(const AzKeyboardState* keyboardstate);
+extern DLLIMPORT bool  AzKeyboardState_leftShiftDown(const AzKeyboardState* keyboardstate);
+extern DLLIMPORT bool  AzKeyboardState_rightShiftDown(const AzKeyboardState* keyboardstate);
+extern DLLIMPORT bool  AzKeyboardState_leftCtrlDown(const AzKeyboardState* keyboardstate);
+extern DLLIMPORT bool  AzKeyboardState_rightCtrlDown(const AzKeyboardState* keyboardstate);
+extern DLLIMPORT bool  AzKeyboardState_leftAltDown(const AzKeyboardState* keyboardstate);
+extern DLLIMPORT bool  AzKeyboardState_rightAltDown(const AzKeyboardState* keyboardstate);
+extern DLLIMPORT bool  AzKeyboardState_leftSuperDown(const AzKeyboardState* keyboardstate);
+extern DLLIMPORT bool  AzKeyboardState_rightSuperDown(const AzKeyboardState* keyboardstate);
 extern DLLIMPORT bool  AzKeyboardState_isKeyDown(const AzKeyboardState* keyboardstate, AzVirtualKeyCode  key);
+extern DLLIMPORT bool  AzKeyboardState_isScancodeDown(const AzKeyboardState* keyboardstate, uint32_t scancode);
+extern DLLIMPORT AzKeyboardModifiers AzKeyboardState_getModifiers(const AzKeyboardState* keyboardstate);
 extern DLLIMPORT void AzKeyboardState_delete(AzKeyboardState* restrict instance);
 extern DLLIMPORT AzOptionLogicalPosition AzCursorPosition_getPosition(const AzCursorPosition* cursorposition);
 extern DLLIMPORT void AzPlatformSpecificOptions_delete(AzPlatformSpecificOptions* restrict instance);
@@ -12481,10 +14432,13 @@ extern DLLIMPORT void AzWindowsWindowOptions_delete(AzWindowsWindowOptions* rest
 extern DLLIMPORT void AzWaylandTheme_delete(AzWaylandTheme* restrict instance);
 extern DLLIMPORT void AzStringPair_delete(AzStringPair* restrict instance);
 extern DLLIMPORT void AzLinuxWindowOptions_delete(AzLinuxWindowOptions* restrict instance);
+extern DLLIMPORT void AzTouchState_delete(AzTouchState* restrict instance);
 extern DLLIMPORT void AzMonitor_delete(AzMonitor* restrict instance);
 extern DLLIMPORT AzWindowState AzWindowState_new(AzLayoutCallbackType  layout_callback);
 extern DLLIMPORT AzWindowState AzWindowState_default();
+extern DLLIMPORT void AzWindowState_setFullscreen(AzWindowState* restrict windowstate, AzFullscreenMode  mode);
 extern DLLIMPORT void AzWindowState_delete(AzWindowState* restrict instance);
+extern DLLIMPORT AzLayoutCallback AzLayoutCallback_hotReload(AzString  library_path, AzString  symbol);
 extern DLLIMPORT void AzLayoutCallback_delete(AzLayoutCallback* restrict instance);
 extern DLLIMPORT void AzMarshaledLayoutCallback_delete(AzMarshaledLayoutCallback* restrict instance);
 extern DLLIMPORT AzDomNodeId AzCallbackInfo_getHitNode(const AzCallbackInfo* callbackinfo);
@@ -12492,6 +14446,9 @@ extern DLLIMPORT AzGetSystemTimeFn AzCallbackInfo_getSystemTimeFn(const AzCallba
 extern DLLIMPORT AzOptionLogicalPosition AzCallbackInfo_getCursorRelativeToViewport(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionLogicalPosition AzCallbackInfo_getCursorRelativeToNode(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzWindowState AzCallbackInfo_getCurrentWindowState(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT AzOptionRefAny AzCallbackInfo_getUserEvent(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT AzOptionRefAny AzCallbackInfo_getWindowData(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT void AzCallbackInfo_setWindowData(AzCallbackInfo* restrict callbackinfo, AzRefAny  data);
 extern DLLIMPORT AzKeyboardState AzCallbackInfo_getCurrentKeyboardState(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzMouseState AzCallbackInfo_getCurrentMouseState(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionWindowState AzCallbackInfo_getPreviousWindowState(const AzCallbackInfo* callbackinfo);
@@ -12519,8 +14476,13 @@ extern DLLIMPORT AzOptionCssProperty AzCallbackInfo_getComputedCssProperty(AzCal
 extern DLLIMPORT void AzCallbackInfo_setWindowState(AzCallbackInfo* restrict callbackinfo, AzWindowState  new_state);
 extern DLLIMPORT void AzCallbackInfo_setFocus(AzCallbackInfo* restrict callbackinfo, AzFocusTarget  target);
 extern DLLIMPORT void AzCallbackInfo_setCssProperty(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzCssProperty  new_property);
+extern DLLIMPORT void AzCallbackInfo_addClass(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  class);
+extern DLLIMPORT void AzCallbackInfo_removeClass(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  class);
+extern DLLIMPORT void AzCallbackInfo_toggleClass(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  class);
+extern DLLIMPORT void AzCallbackInfo_setNodeStateFlag(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzNodeStateFlag  flag, bool  value);
 extern DLLIMPORT void AzCallbackInfo_setScrollPosition(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzLogicalPosition  scroll_position);
 extern DLLIMPORT void AzCallbackInfo_setStringContents(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  string);
+extern DLLIMPORT void AzCallbackInfo_setTextUnderlines(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzTextUnderlineVec  underlines);
 extern DLLIMPORT void AzCallbackInfo_addImage(AzCallbackInfo* restrict callbackinfo, AzString  id, AzImageRef  image);
 extern DLLIMPORT bool  AzCallbackInfo_hasImage(const AzCallbackInfo* callbackinfo, AzString  id);
 extern DLLIMPORT AzOptionImageRef AzCallbackInfo_getImage(const AzCallbackInfo* callbackinfo, AzString  id);
@@ -12531,10 +14493,27 @@ extern DLLIMPORT void AzCallbackInfo_stopPropagation(AzCallbackInfo* restrict ca
 extern DLLIMPORT void AzCallbackInfo_createWindow(AzCallbackInfo* restrict callbackinfo, AzWindowCreateOptions  new_window);
 extern DLLIMPORT AzTimerId AzCallbackInfo_startTimer(AzCallbackInfo* restrict callbackinfo, AzTimer  timer);
 extern DLLIMPORT AzOptionTimerId AzCallbackInfo_startAnimation(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node, AzAnimation  animation);
+extern DLLIMPORT AzOptionTimerId AzCallbackInfo_startCaretAnimation(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  caret_node, AzCaretAnimation  animation);
+extern DLLIMPORT AzTimerId AzCallbackInfo_animateWindowResize(AzCallbackInfo* restrict callbackinfo, AzLogicalSize  target_size, AzDuration  duration);
 extern DLLIMPORT bool  AzCallbackInfo_stopTimer(AzCallbackInfo* restrict callbackinfo, AzTimerId  timer_id);
 extern DLLIMPORT AzOptionThreadId AzCallbackInfo_startThread(AzCallbackInfo* restrict callbackinfo, AzRefAny  thread_initialize_data, AzRefAny  writeback_data, AzThreadCallbackType  callback);
 extern DLLIMPORT bool  AzCallbackInfo_sendThreadMsg(AzCallbackInfo* restrict callbackinfo, AzThreadId  thread_id, AzThreadSendMsg  msg);
 extern DLLIMPORT bool  AzCallbackInfo_stopThread(AzCallbackInfo* restrict callbackinfo, AzThreadId  thread_id);
+extern DLLIMPORT AzOptionDuration AzCallbackInfo_getSystemIdleTime(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT AzOptionChar AzCallbackInfo_keycodeToChar(const AzCallbackInfo* callbackinfo, AzVirtualKeyCode  virtual_keycode, AzKeyboardModifiers  modifiers);
+extern DLLIMPORT AzStringVec AzCallbackInfo_getLogLines(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT void AzCallbackInfo_setLogLevel(const AzCallbackInfo* callbackinfo, AzString  subsystem, AzAppLogLevel  level);
+extern DLLIMPORT AzOptionNodeRect AzCallbackInfo_getNodeRect(const AzCallbackInfo* callbackinfo, AzDomNodeId  node_id);
+extern DLLIMPORT AzOptionTextLayout AzCallbackInfo_getTextLayout(const AzCallbackInfo* callbackinfo, AzDomNodeId  node_id);
+extern DLLIMPORT AzOptionScrollInfo AzCallbackInfo_getScrollInfo(const AzCallbackInfo* callbackinfo, AzDomNodeId  node_id);
+extern DLLIMPORT AzOptionShortcutConflict AzCallbackInfo_addShortcut(AzCallbackInfo* restrict callbackinfo, AzShortcut  shortcut);
+extern DLLIMPORT bool  AzCallbackInfo_removeShortcut(AzCallbackInfo* restrict callbackinfo, AzVirtualKeyCodeCombo  keys);
+extern DLLIMPORT AzShortcutConflictVec AzCallbackInfo_getShortcutConflicts(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT void AzCallbackInfo_announce(AzCallbackInfo* restrict callbackinfo, AzString  text, AzPoliteness  politeness);
+extern DLLIMPORT void AzCallbackInfo_triggerHaptic(AzCallbackInfo* restrict callbackinfo, AzHapticKind  kind);
+extern DLLIMPORT void AzCallbackInfo_requestAnimationFrame(AzCallbackInfo* restrict callbackinfo, AzRefAny  data, AzTimerCallbackType  callback);
+extern DLLIMPORT void AzCallbackInfo_crossfadeSubtree(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node, AzDuration  duration);
+extern DLLIMPORT void AzCallbackInfo_renderSubtreeToFrames(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node, AzDuration  duration, uint32_t fps, AzString  output_path);
 extern DLLIMPORT void AzCallbackInfo_delete(AzCallbackInfo* restrict instance);
 extern DLLIMPORT bool  AzPositionInfo_isPositioned(const AzPositionInfo* positioninfo);
 extern DLLIMPORT AzLogicalPosition AzPositionInfo_getStaticOffset(const AzPositionInfo* positioninfo);
@@ -12544,6 +14523,7 @@ extern DLLIMPORT AzPhysicalSizeU32 AzHidpiAdjustedBounds_getPhysicalSize(const A
 extern DLLIMPORT float AzHidpiAdjustedBounds_getHidpiFactor(const AzHidpiAdjustedBounds* hidpiadjustedbounds);
 extern DLLIMPORT AzInlineTextHitVec AzInlineText_hitTest(const AzInlineText* inlinetext, AzLogicalPosition  position);
 extern DLLIMPORT void AzInlineText_delete(AzInlineText* restrict instance);
+extern DLLIMPORT void AzTextLayout_delete(AzTextLayout* restrict instance);
 extern DLLIMPORT void AzInlineLine_delete(AzInlineLine* restrict instance);
 extern DLLIMPORT void AzInlineWord_delete(AzInlineWord* restrict instance);
 extern DLLIMPORT void AzInlineTextContents_delete(AzInlineTextContents* restrict instance);
@@ -12581,6 +14561,7 @@ extern DLLIMPORT AzRefAny AzRefAny_deepCopy(AzRefAny* const instance);
 extern DLLIMPORT AzOptionGl AzLayoutCallbackInfo_getGlContext(const AzLayoutCallbackInfo* layoutcallbackinfo);
 extern DLLIMPORT AzStringPairVec AzLayoutCallbackInfo_getSystemFonts(const AzLayoutCallbackInfo* layoutcallbackinfo);
 extern DLLIMPORT AzOptionImageRef AzLayoutCallbackInfo_getImage(const AzLayoutCallbackInfo* layoutcallbackinfo, AzString  id);
+extern DLLIMPORT AzSystemStyle AzLayoutCallbackInfo_getSystemStyle(const AzLayoutCallbackInfo* layoutcallbackinfo);
 extern DLLIMPORT void AzLayoutCallbackInfo_delete(AzLayoutCallbackInfo* restrict instance);
 extern DLLIMPORT AzDom AzDom_new(AzNodeType  node_type);
 extern DLLIMPORT AzDom AzDom_body();
@@ -12589,6 +14570,10 @@ extern DLLIMPORT AzDom AzDom_br();
 extern DLLIMPORT AzDom AzDom_text(AzString  string);
 extern DLLIMPORT AzDom AzDom_image(AzImageRef  image);
 extern DLLIMPORT AzDom AzDom_iframe(AzRefAny  data, AzIFrameCallbackType  callback);
+extern DLLIMPORT AzDom AzDom_customLayout(AzRefAny  data, AzMeasureCallbackType  measure, AzArrangeCallbackType  arrange);
+extern DLLIMPORT AzDom AzDom_customNode(AzString  type_name, AzRefAny  data);
+extern DLLIMPORT AzDom AzDom_iframeWithReinvokeThreshold(AzRefAny  data, AzIFrameCallbackType  callback, float reinvoke_threshold);
+extern DLLIMPORT AzDom AzDom_overlay(AzString  anchor, AzOverlayPlacement  placement);
 extern DLLIMPORT void AzDom_setNodeType(AzDom* restrict dom, AzNodeType  node_type);
 extern DLLIMPORT AzDom AzDom_withNodeType(AzDom* restrict dom, AzNodeType  node_type);
 extern DLLIMPORT void AzDom_setDataset(AzDom* restrict dom, AzRefAny  dataset);
@@ -12635,6 +14620,14 @@ extern DLLIMPORT void AzDom_setMenuBar(AzDom* restrict dom, AzMenu  menu_bar);
 extern DLLIMPORT AzDom AzDom_withMenuBar(AzDom* restrict dom, AzMenu  menu_bar);
 extern DLLIMPORT void AzDom_setContextMenu(AzDom* restrict dom, AzMenu  context_menu);
 extern DLLIMPORT AzDom AzDom_withContextMenu(AzDom* restrict dom, AzMenu  context_menu);
+extern DLLIMPORT void AzDom_setFocusNavigation(AzDom* restrict dom, AzFocusNavigation  focus_navigation);
+extern DLLIMPORT AzDom AzDom_withFocusNavigation(AzDom* restrict dom, AzFocusNavigation  focus_navigation);
+extern DLLIMPORT void AzDom_setLang(AzDom* restrict dom, AzString  lang);
+extern DLLIMPORT AzDom AzDom_withLang(AzDom* restrict dom, AzString  lang);
+extern DLLIMPORT void AzDom_setInputMode(AzDom* restrict dom, AzInputMode  input_mode);
+extern DLLIMPORT AzDom AzDom_withInputMode(AzDom* restrict dom, AzInputMode  input_mode);
+extern DLLIMPORT void AzDom_setStateFlag(AzDom* restrict dom, AzNodeStateFlag  flag, bool  enabled);
+extern DLLIMPORT AzDom AzDom_withStateFlag(AzDom* restrict dom, AzNodeStateFlag  flag, bool  enabled);
 extern DLLIMPORT uint64_t AzDom_hash(const AzDom* dom);
 extern DLLIMPORT size_t AzDom_nodeCount(const AzDom* dom);
 extern DLLIMPORT AzString AzDom_getHtmlString(AzDom* restrict dom);
@@ -12642,6 +14635,11 @@ extern DLLIMPORT AzString AzDom_getHtmlStringTest(AzDom* restrict dom);
 extern DLLIMPORT AzStyledDom AzDom_style(AzDom* restrict dom, AzCss  css);
 extern DLLIMPORT void AzDom_delete(AzDom* restrict instance);
 extern DLLIMPORT void AzIFrameNode_delete(AzIFrameNode* restrict instance);
+extern DLLIMPORT void AzCustomLayoutNode_delete(AzCustomLayoutNode* restrict instance);
+extern DLLIMPORT AzCustomNodeType AzCustomNodeType_new(AzString  name, AzMeasureCallbackType  measure, AzArrangeCallbackType  arrange);
+extern DLLIMPORT AzCustomNodeType AzCustomNodeType_withPaint(const AzCustomNodeType customnodetype, AzPaintCallbackType  paint);
+extern DLLIMPORT void AzCustomNodeType_register(const AzCustomNodeType customnodetype);
+extern DLLIMPORT void AzCustomNodeType_delete(AzCustomNodeType* restrict instance);
 extern DLLIMPORT void AzCallbackData_delete(AzCallbackData* restrict instance);
 extern DLLIMPORT AzNodeData AzNodeData_new(AzNodeType  node_type);
 extern DLLIMPORT AzNodeData AzNodeData_body();
@@ -12650,6 +14648,8 @@ extern DLLIMPORT AzNodeData AzNodeData_br();
 extern DLLIMPORT AzNodeData AzNodeData_text(AzString  string);
 extern DLLIMPORT AzNodeData AzNodeData_image(AzImageRef  image);
 extern DLLIMPORT AzNodeData AzNodeData_iframe(AzRefAny  data, AzIFrameCallbackType  callback);
+extern DLLIMPORT AzNodeData AzNodeData_customLayout(AzRefAny  data, AzMeasureCallbackType  measure, AzArrangeCallbackType  arrange);
+extern DLLIMPORT AzNodeData AzNodeData_iframeWithReinvokeThreshold(AzRefAny  data, AzIFrameCallbackType  callback, float reinvoke_threshold);
 extern DLLIMPORT void AzNodeData_setNodeType(AzNodeData* restrict nodedata, AzNodeType  node_type);
 extern DLLIMPORT AzNodeData AzNodeData_withNodeType(AzNodeData* restrict nodedata, AzNodeType  node_type);
 extern DLLIMPORT void AzNodeData_setDataset(AzNodeData* restrict nodedata, AzRefAny  dataset);
@@ -12675,11 +14675,18 @@ extern DLLIMPORT void AzNodeData_setTabIndex(AzNodeData* restrict nodedata, AzTa
 extern DLLIMPORT void AzNodeData_setAccessibilityInfo(AzNodeData* restrict nodedata, AzAccessibilityInfo  accessibility_info);
 extern DLLIMPORT void AzNodeData_setMenuBar(AzNodeData* restrict nodedata, AzMenu  menu_bar);
 extern DLLIMPORT void AzNodeData_setContextMenu(AzNodeData* restrict nodedata, AzMenu  context_menu);
+extern DLLIMPORT void AzNodeData_setFocusNavigation(AzNodeData* restrict nodedata, AzFocusNavigation  focus_navigation);
 extern DLLIMPORT uint64_t AzNodeData_hash(const AzNodeData* nodedata);
+extern DLLIMPORT void AzNodeData_setOverlay(AzNodeData* restrict nodedata, AzOverlayInfo  overlay);
+extern DLLIMPORT void AzNodeData_setLang(AzNodeData* restrict nodedata, AzString  lang);
+extern DLLIMPORT void AzNodeData_setInputMode(AzNodeData* restrict nodedata, AzInputMode  input_mode);
+extern DLLIMPORT void AzNodeData_setStateFlag(AzNodeData* restrict nodedata, AzNodeStateFlag  flag, bool  enabled);
 extern DLLIMPORT void AzNodeData_delete(AzNodeData* restrict instance);
 extern DLLIMPORT void AzNodeType_delete(AzNodeType* restrict instance);
 extern DLLIMPORT AzEventFilter AzOn_intoEventFilter(const AzOn on);
 extern DLLIMPORT void AzAccessibilityInfo_delete(AzAccessibilityInfo* restrict instance);
+extern DLLIMPORT void AzFocusNavigation_delete(AzFocusNavigation* restrict instance);
+extern DLLIMPORT void AzOverlayInfo_delete(AzOverlayInfo* restrict instance);
 extern DLLIMPORT void AzIdOrClass_delete(AzIdOrClass* restrict instance);
 extern DLLIMPORT void AzNodeDataInlineCssProperty_delete(AzNodeDataInlineCssProperty* restrict instance);
 extern DLLIMPORT AzMenu AzMenu_new(AzMenuItemVec  items);
@@ -12698,10 +14705,19 @@ extern DLLIMPORT void AzStringMenuItem_delete(AzStringMenuItem* restrict instanc
 extern DLLIMPORT void AzVirtualKeyCodeCombo_delete(AzVirtualKeyCodeCombo* restrict instance);
 extern DLLIMPORT AzMenuCallback AzMenuCallback_new(AzRefAny  data, AzCallbackType  callback);
 extern DLLIMPORT void AzMenuCallback_delete(AzMenuCallback* restrict instance);
+extern DLLIMPORT AzShortcut AzShortcut_new(AzVirtualKeyCodeCombo  keys, AzRefAny  data, AzCallbackType  callback);
+extern DLLIMPORT void AzShortcut_delete(AzShortcut* restrict instance);
+extern DLLIMPORT void AzShortcutConflict_delete(AzShortcutConflict* restrict instance);
+extern DLLIMPORT AzShortcutMap AzShortcutMap_new();
+extern DLLIMPORT AzOptionShortcutConflict AzShortcutMap_add(AzShortcutMap* restrict shortcutmap, AzShortcut  shortcut);
+extern DLLIMPORT bool  AzShortcutMap_remove(AzShortcutMap* restrict shortcutmap, AzVirtualKeyCodeCombo  keys);
+extern DLLIMPORT AzShortcutConflictVec AzShortcutMap_getConflicts(const AzShortcutMap* shortcutmap);
+extern DLLIMPORT void AzShortcutMap_delete(AzShortcutMap* restrict instance);
 extern DLLIMPORT void AzMenuItemIcon_delete(AzMenuItemIcon* restrict instance);
 extern DLLIMPORT void AzCssRuleBlock_delete(AzCssRuleBlock* restrict instance);
 extern DLLIMPORT void AzCssDeclaration_delete(AzCssDeclaration* restrict instance);
 extern DLLIMPORT void AzDynamicCssProperty_delete(AzDynamicCssProperty* restrict instance);
+extern DLLIMPORT void AzSystemCssProperty_delete(AzSystemCssProperty* restrict instance);
 extern DLLIMPORT void AzCssPath_delete(AzCssPath* restrict instance);
 extern DLLIMPORT void AzCssPathSelector_delete(AzCssPathSelector* restrict instance);
 extern DLLIMPORT void AzStylesheet_delete(AzStylesheet* restrict instance);
@@ -12720,12 +14736,21 @@ extern DLLIMPORT void AzConicGradient_delete(AzConicGradient* restrict instance)
 extern DLLIMPORT void AzStyleBackgroundContent_delete(AzStyleBackgroundContent* restrict instance);
 extern DLLIMPORT void AzScrollbarInfo_delete(AzScrollbarInfo* restrict instance);
 extern DLLIMPORT void AzScrollbarStyle_delete(AzScrollbarStyle* restrict instance);
+extern DLLIMPORT void AzStyleContent_delete(AzStyleContent* restrict instance);
+extern DLLIMPORT void AzStyleCounterReset_delete(AzStyleCounterReset* restrict instance);
+extern DLLIMPORT void AzStyleCounterIncrement_delete(AzStyleCounterIncrement* restrict instance);
+extern DLLIMPORT void AzStyleCursor_delete(AzStyleCursor* restrict instance);
+extern DLLIMPORT void AzStyleCursorImage_delete(AzStyleCursorImage* restrict instance);
 extern DLLIMPORT void AzStyleFontFamily_delete(AzStyleFontFamily* restrict instance);
 extern DLLIMPORT void AzScrollbarStyleValue_delete(AzScrollbarStyleValue* restrict instance);
+extern DLLIMPORT void AzStyleContentValue_delete(AzStyleContentValue* restrict instance);
+extern DLLIMPORT void AzStyleCounterResetValue_delete(AzStyleCounterResetValue* restrict instance);
+extern DLLIMPORT void AzStyleCounterIncrementValue_delete(AzStyleCounterIncrementValue* restrict instance);
 extern DLLIMPORT void AzStyleBackgroundContentVecValue_delete(AzStyleBackgroundContentVecValue* restrict instance);
 extern DLLIMPORT void AzStyleBackgroundPositionVecValue_delete(AzStyleBackgroundPositionVecValue* restrict instance);
 extern DLLIMPORT void AzStyleBackgroundRepeatVecValue_delete(AzStyleBackgroundRepeatVecValue* restrict instance);
 extern DLLIMPORT void AzStyleBackgroundSizeVecValue_delete(AzStyleBackgroundSizeVecValue* restrict instance);
+extern DLLIMPORT void AzStyleCursorValue_delete(AzStyleCursorValue* restrict instance);
 extern DLLIMPORT void AzStyleFontFamilyVecValue_delete(AzStyleFontFamilyVecValue* restrict instance);
 extern DLLIMPORT void AzStyleTransformVecValue_delete(AzStyleTransformVecValue* restrict instance);
 extern DLLIMPORT void AzStyleFilterVecValue_delete(AzStyleFilterVecValue* restrict instance);
@@ -12779,6 +14804,14 @@ extern DLLIMPORT void AzTextInput_setOnVirtualKeyDown(AzTextInput* restrict text
 extern DLLIMPORT AzTextInput AzTextInput_withOnVirtualKeyDown(AzTextInput* restrict textinput, AzRefAny  data, AzTextInputOnVirtualKeyDownCallbackType  callback);
 extern DLLIMPORT void AzTextInput_setOnFocusLost(AzTextInput* restrict textinput, AzRefAny  data, AzTextInputOnFocusLostCallbackType  callback);
 extern DLLIMPORT AzTextInput AzTextInput_withOnFocusLost(AzTextInput* restrict textinput, AzRefAny  data, AzTextInputOnFocusLostCallbackType  callback);
+extern DLLIMPORT void AzTextInput_setCaretAnimation(AzTextInput* restrict textinput, AzCaretAnimation  caret_animation);
+extern DLLIMPORT AzTextInput AzTextInput_withCaretAnimation(AzTextInput* restrict textinput, AzCaretAnimation  caret_animation);
+extern DLLIMPORT void AzTextInput_setSpellcheck(AzTextInput* restrict textinput, AzString  language);
+extern DLLIMPORT AzTextInput AzTextInput_withSpellcheck(AzTextInput* restrict textinput, AzString  language);
+extern DLLIMPORT void AzTextInput_setOnSpellingSuggestions(AzTextInput* restrict textinput, AzRefAny  data, AzTextInputOnSpellingSuggestionsCallbackType  callback);
+extern DLLIMPORT AzTextInput AzTextInput_withOnSpellingSuggestions(AzTextInput* restrict textinput, AzRefAny  data, AzTextInputOnSpellingSuggestionsCallbackType  callback);
+extern DLLIMPORT void AzTextInput_setSecure(AzTextInput* restrict textinput, bool  secure);
+extern DLLIMPORT AzTextInput AzTextInput_withSecure(AzTextInput* restrict textinput, bool  secure);
 extern DLLIMPORT void AzTextInput_setPlaceholderStyle(AzTextInput* restrict textinput, AzNodeDataInlineCssPropertyVec  placeholder_style);
 extern DLLIMPORT AzTextInput AzTextInput_withPlaceholderStyle(AzTextInput* restrict textinput, AzNodeDataInlineCssPropertyVec  placeholder_style);
 extern DLLIMPORT void AzTextInput_setContainerStyle(AzTextInput* restrict textinput, AzNodeDataInlineCssPropertyVec  container_style);
@@ -12793,7 +14826,9 @@ extern DLLIMPORT void AzTextInputState_delete(AzTextInputState* restrict instanc
 extern DLLIMPORT void AzTextInputOnTextInput_delete(AzTextInputOnTextInput* restrict instance);
 extern DLLIMPORT void AzTextInputOnVirtualKeyDown_delete(AzTextInputOnVirtualKeyDown* restrict instance);
 extern DLLIMPORT void AzTextInputOnFocusLost_delete(AzTextInputOnFocusLost* restrict instance);
-extern DLLIMPORT AzNumberInput AzNumberInput_new(float number);
+extern DLLIMPORT void AzTextInputOnSpellingSuggestions_delete(AzTextInputOnSpellingSuggestions* restrict instance);
+extern DLLIMPORT void AzTextInputSpellingSuggestions_delete(AzTextInputSpellingSuggestions* restrict instance);
+extern DLLIMPORT AzNumberInput AzNumberInput_new(double number);
 extern DLLIMPORT void AzNumberInput_setOnTextInput(AzNumberInput* restrict numberinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
 extern DLLIMPORT AzNumberInput AzNumberInput_withOnTextInput(AzNumberInput* restrict numberinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
 extern DLLIMPORT void AzNumberInput_setOnVirtualKeyDown(AzNumberInput* restrict numberinput, AzRefAny  data, AzTextInputOnVirtualKeyDownCallbackType  callback);
@@ -12808,6 +14843,20 @@ extern DLLIMPORT void AzNumberInput_setLabelStyle(AzNumberInput* restrict number
 extern DLLIMPORT AzNumberInput AzNumberInput_withLabelStyle(AzNumberInput* restrict numberinput, AzNodeDataInlineCssPropertyVec  style);
 extern DLLIMPORT void AzNumberInput_setOnValueChange(AzNumberInput* restrict numberinput, AzRefAny  data, AzNumberInputOnValueChangeCallbackType  callback);
 extern DLLIMPORT AzNumberInput AzNumberInput_withOnValueChange(AzNumberInput* restrict numberinput, AzRefAny  data, AzNumberInputOnValueChangeCallbackType  callback);
+extern DLLIMPORT void AzNumberInput_setSpinnerButtonStyle(AzNumberInput* restrict numberinput, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzNumberInput AzNumberInput_withSpinnerButtonStyle(AzNumberInput* restrict numberinput, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzNumberInput_setInvalidStyle(AzNumberInput* restrict numberinput, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzNumberInput AzNumberInput_withInvalidStyle(AzNumberInput* restrict numberinput, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzNumberInput_setMode(AzNumberInput* restrict numberinput, AzNumberInputMode  mode);
+extern DLLIMPORT AzNumberInput AzNumberInput_withMode(AzNumberInput* restrict numberinput, AzNumberInputMode  mode);
+extern DLLIMPORT void AzNumberInput_setMin(AzNumberInput* restrict numberinput, double min);
+extern DLLIMPORT AzNumberInput AzNumberInput_withMin(AzNumberInput* restrict numberinput, double min);
+extern DLLIMPORT void AzNumberInput_setMax(AzNumberInput* restrict numberinput, double max);
+extern DLLIMPORT AzNumberInput AzNumberInput_withMax(AzNumberInput* restrict numberinput, double max);
+extern DLLIMPORT void AzNumberInput_setStep(AzNumberInput* restrict numberinput, double step);
+extern DLLIMPORT AzNumberInput AzNumberInput_withStep(AzNumberInput* restrict numberinput, double step);
+extern DLLIMPORT void AzNumberInput_setDecimalSeparator(AzNumberInput* restrict numberinput, uint32_t decimal_separator);
+extern DLLIMPORT AzNumberInput AzNumberInput_withDecimalSeparator(AzNumberInput* restrict numberinput, uint32_t decimal_separator);
 extern DLLIMPORT AzDom AzNumberInput_dom(AzNumberInput* restrict numberinput);
 extern DLLIMPORT void AzNumberInput_delete(AzNumberInput* restrict instance);
 extern DLLIMPORT void AzNumberInputStateWrapper_delete(AzNumberInputStateWrapper* restrict instance);
@@ -12877,6 +14926,28 @@ extern DLLIMPORT AzDropDown AzDropDown_new(AzStringVec  choices);
 extern DLLIMPORT AzDom AzDropDown_dom(AzDropDown* restrict dropdown);
 extern DLLIMPORT void AzDropDown_delete(AzDropDown* restrict instance);
 extern DLLIMPORT void AzDropDownOnChoiceChange_delete(AzDropDownOnChoiceChange* restrict instance);
+extern DLLIMPORT AzToasts AzToasts_new();
+extern DLLIMPORT AzToastId AzToasts_show(AzToasts* restrict toasts, AzCallbackInfo * restrict info, AzToast  toast);
+extern DLLIMPORT bool  AzToasts_dismiss(AzToasts* restrict toasts, AzCallbackInfo * restrict info, AzToastId  toast_id);
+extern DLLIMPORT void AzToasts_setMaxVisible(AzToasts* restrict toasts, size_t max_visible);
+extern DLLIMPORT AzToasts AzToasts_withMaxVisible(AzToasts* restrict toasts, size_t max_visible);
+extern DLLIMPORT void AzToasts_setLayerStyle(AzToasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzToasts AzToasts_withLayerStyle(AzToasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzToasts_setToastStyle(AzToasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzToasts AzToasts_withToastStyle(AzToasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzToasts_setLabelStyle(AzToasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzToasts AzToasts_withLabelStyle(AzToasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT void AzToasts_setActionStyle(AzToasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzToasts AzToasts_withActionStyle(AzToasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+extern DLLIMPORT AzDom AzToasts_dom(AzToasts* restrict toasts);
+extern DLLIMPORT void AzToasts_delete(AzToasts* restrict instance);
+extern DLLIMPORT AzToast AzToast_new(AzString  message);
+extern DLLIMPORT void AzToast_setDuration(AzToast* restrict toast, AzOptionDuration  duration);
+extern DLLIMPORT AzToast AzToast_withDuration(AzToast* restrict toast, AzOptionDuration  duration);
+extern DLLIMPORT void AzToast_setAction(AzToast* restrict toast, AzString  label, AzRefAny  data, AzToastOnActionCallbackType  callback);
+extern DLLIMPORT AzToast AzToast_withAction(AzToast* restrict toast, AzString  label, AzRefAny  data, AzToastOnActionCallbackType  callback);
+extern DLLIMPORT void AzToast_delete(AzToast* restrict instance);
+extern DLLIMPORT void AzToastOnAction_delete(AzToastOnAction* restrict instance);
 extern DLLIMPORT void AzCssPropertySource_delete(AzCssPropertySource* restrict instance);
 extern DLLIMPORT void AzTagIdToNodeIdMapping_delete(AzTagIdToNodeIdMapping* restrict instance);
 extern DLLIMPORT void AzCssPropertyCache_delete(AzCssPropertyCache* restrict instance);
@@ -12909,6 +14980,7 @@ extern DLLIMPORT AzTexture AzTexture_deepCopy(AzTexture* const instance);
 extern DLLIMPORT void AzGlVoidPtrConst_delete(AzGlVoidPtrConst* restrict instance);
 extern DLLIMPORT AzGlVoidPtrConst AzGlVoidPtrConst_deepCopy(AzGlVoidPtrConst* const instance);
 extern DLLIMPORT AzGlType AzGl_getType(const AzGl* gl);
+extern DLLIMPORT AzGlQuirks AzGl_getQuirks(const AzGl* gl);
 extern DLLIMPORT void AzGl_bufferDataUntyped(const AzGl* gl, uint32_t target, ssize_t size, AzGlVoidPtrConst  data, uint32_t usage);
 extern DLLIMPORT void AzGl_bufferSubDataUntyped(const AzGl* gl, uint32_t target, ssize_t offset, ssize_t size, AzGlVoidPtrConst  data);
 extern DLLIMPORT AzGlVoidPtrMut AzGl_mapBuffer(const AzGl* gl, uint32_t target, uint32_t access);
@@ -13131,6 +15203,13 @@ extern DLLIMPORT void AzGl_bufferStorage(const AzGl* gl, uint32_t target, ssize_
 extern DLLIMPORT void AzGl_flushMappedBufferRange(const AzGl* gl, uint32_t target, ssize_t offset, ssize_t length);
 extern DLLIMPORT void AzGl_delete(AzGl* restrict instance);
 extern DLLIMPORT AzGl AzGl_deepCopy(AzGl* const instance);
+extern DLLIMPORT AzGlQuirkRule AzGlQuirkRule_new();
+extern DLLIMPORT AzGlQuirkRule AzGlQuirkRule_withVendor(const AzGlQuirkRule glquirkrule, AzString  vendor);
+extern DLLIMPORT AzGlQuirkRule AzGlQuirkRule_withRenderer(const AzGlQuirkRule glquirkrule, AzString  renderer);
+extern DLLIMPORT AzGlQuirkRule AzGlQuirkRule_withVersion(const AzGlQuirkRule glquirkrule, AzString  version);
+extern DLLIMPORT AzGlQuirkRule AzGlQuirkRule_withEnable(const AzGlQuirkRule glquirkrule, AzGlQuirks  enable);
+extern DLLIMPORT AzGlQuirkRule AzGlQuirkRule_withDisable(const AzGlQuirkRule glquirkrule, AzGlQuirks  disable);
+extern DLLIMPORT void AzGlQuirkRule_delete(AzGlQuirkRule* restrict instance);
 extern DLLIMPORT void AzVertexAttribute_delete(AzVertexAttribute* restrict instance);
 extern DLLIMPORT void AzVertexLayout_delete(AzVertexLayout* restrict instance);
 extern DLLIMPORT AzVertexArrayObject AzVertexArrayObject_new(AzVertexLayout  vertex_layout, uint32_t vao_id, AzGl  gl_context);
@@ -13363,6 +15442,10 @@ extern DLLIMPORT AzThreadSender AzThreadSender_deepCopy(AzThreadSender* const in
 extern DLLIMPORT AzOptionThreadSendMsg AzThreadReceiver_receive(AzThreadReceiver* restrict threadreceiver);
 extern DLLIMPORT void AzThreadReceiver_delete(AzThreadReceiver* restrict instance);
 extern DLLIMPORT AzThreadReceiver AzThreadReceiver_deepCopy(AzThreadReceiver* const instance);
+extern DLLIMPORT bool  AzEventLoopProxy_wake(const AzEventLoopProxy* eventloopproxy);
+extern DLLIMPORT bool  AzEventLoopProxy_sendUserEvent(const AzEventLoopProxy* eventloopproxy, AzRefAny  data);
+extern DLLIMPORT void AzEventLoopProxy_delete(AzEventLoopProxy* restrict instance);
+extern DLLIMPORT AzEventLoopProxy AzEventLoopProxy_deepCopy(AzEventLoopProxy* const instance);
 extern DLLIMPORT void AzThreadSendMsg_delete(AzThreadSendMsg* restrict instance);
 extern DLLIMPORT void AzThreadReceiveMsg_delete(AzThreadReceiveMsg* restrict instance);
 extern DLLIMPORT void AzThreadWriteBackMsg_delete(AzThreadWriteBackMsg* restrict instance);
@@ -13396,9 +15479,12 @@ extern DLLIMPORT void AzXmlNodeVec_delete(AzXmlNodeVec* restrict instance);
 extern DLLIMPORT void AzFmtArgVec_delete(AzFmtArgVec* restrict instance);
 extern DLLIMPORT void AzInlineLineVec_delete(AzInlineLineVec* restrict instance);
 extern DLLIMPORT void AzInlineWordVec_delete(AzInlineWordVec* restrict instance);
+extern DLLIMPORT void AzPaintPrimitiveVec_delete(AzPaintPrimitiveVec* restrict instance);
 extern DLLIMPORT void AzInlineGlyphVec_delete(AzInlineGlyphVec* restrict instance);
+extern DLLIMPORT void AzTextUnderlineVec_delete(AzTextUnderlineVec* restrict instance);
 extern DLLIMPORT void AzInlineTextHitVec_delete(AzInlineTextHitVec* restrict instance);
 extern DLLIMPORT void AzMonitorVec_delete(AzMonitorVec* restrict instance);
+extern DLLIMPORT void AzTouchPointVec_delete(AzTouchPointVec* restrict instance);
 extern DLLIMPORT void AzVideoModeVec_delete(AzVideoModeVec* restrict instance);
 extern DLLIMPORT void AzDomVec_delete(AzDomVec* restrict instance);
 extern DLLIMPORT void AzIdOrClassVec_delete(AzIdOrClassVec* restrict instance);
@@ -13419,6 +15505,8 @@ extern DLLIMPORT void AzSvgColoredVertexVec_delete(AzSvgColoredVertexVec* restri
 extern DLLIMPORT void AzU32Vec_delete(AzU32Vec* restrict instance);
 extern DLLIMPORT void AzXWindowTypeVec_delete(AzXWindowTypeVec* restrict instance);
 extern DLLIMPORT void AzVirtualKeyCodeVec_delete(AzVirtualKeyCodeVec* restrict instance);
+extern DLLIMPORT void AzShortcutConflictVec_delete(AzShortcutConflictVec* restrict instance);
+extern DLLIMPORT void AzShortcutVec_delete(AzShortcutVec* restrict instance);
 extern DLLIMPORT void AzCascadeInfoVec_delete(AzCascadeInfoVec* restrict instance);
 extern DLLIMPORT void AzScanCodeVec_delete(AzScanCodeVec* restrict instance);
 extern DLLIMPORT void AzCssDeclarationVec_delete(AzCssDeclarationVec* restrict instance);
@@ -13449,6 +15537,7 @@ extern DLLIMPORT void AzOptionListViewOnColumnClick_delete(AzOptionListViewOnCol
 extern DLLIMPORT void AzOptionListViewOnLazyLoadScroll_delete(AzOptionListViewOnLazyLoadScroll* restrict instance);
 extern DLLIMPORT void AzOptionMenu_delete(AzOptionMenu* restrict instance);
 extern DLLIMPORT void AzOptionDropDownOnChoiceChange_delete(AzOptionDropDownOnChoiceChange* restrict instance);
+extern DLLIMPORT void AzOptionToastOnAction_delete(AzOptionToastOnAction* restrict instance);
 extern DLLIMPORT void AzOptionResolvedTextLayoutOptions_delete(AzOptionResolvedTextLayoutOptions* restrict instance);
 extern DLLIMPORT void AzOptionNodeGraphOnNodeAdded_delete(AzOptionNodeGraphOnNodeAdded* restrict instance);
 extern DLLIMPORT void AzOptionNodeGraphOnNodeRemoved_delete(AzOptionNodeGraphOnNodeRemoved* restrict instance);
@@ -13466,11 +15555,13 @@ extern DLLIMPORT void AzOptionCheckBoxOnToggle_delete(AzOptionCheckBoxOnToggle*
 extern DLLIMPORT void AzOptionTextInputOnTextInput_delete(AzOptionTextInputOnTextInput* restrict instance);
 extern DLLIMPORT void AzOptionTextInputOnVirtualKeyDown_delete(AzOptionTextInputOnVirtualKeyDown* restrict instance);
 extern DLLIMPORT void AzOptionTextInputOnFocusLost_delete(AzOptionTextInputOnFocusLost* restrict instance);
+extern DLLIMPORT void AzOptionTextInputOnSpellingSuggestions_delete(AzOptionTextInputOnSpellingSuggestions* restrict instance);
 extern DLLIMPORT void AzOptionNumberInputOnFocusLost_delete(AzOptionNumberInputOnFocusLost* restrict instance);
 extern DLLIMPORT void AzOptionNumberInputOnValueChange_delete(AzOptionNumberInputOnValueChange* restrict instance);
 extern DLLIMPORT void AzOptionMenuItemIcon_delete(AzOptionMenuItemIcon* restrict instance);
 extern DLLIMPORT void AzOptionMenuCallback_delete(AzOptionMenuCallback* restrict instance);
 extern DLLIMPORT void AzOptionVirtualKeyCodeCombo_delete(AzOptionVirtualKeyCodeCombo* restrict instance);
+extern DLLIMPORT void AzOptionShortcutConflict_delete(AzOptionShortcutConflict* restrict instance);
 extern DLLIMPORT void AzOptionCssProperty_delete(AzOptionCssProperty* restrict instance);
 extern DLLIMPORT void AzOptionImageRef_delete(AzOptionImageRef* restrict instance);
 extern DLLIMPORT void AzOptionFontRef_delete(AzOptionFontRef* restrict instance);
@@ -13482,9 +15573,13 @@ extern DLLIMPORT void AzOptionStringVec_delete(AzOptionStringVec* restrict insta
 extern DLLIMPORT void AzOptionFile_delete(AzOptionFile* restrict instance);
 extern DLLIMPORT void AzOptionGl_delete(AzOptionGl* restrict instance);
 extern DLLIMPORT void AzOptionThreadReceiveMsg_delete(AzOptionThreadReceiveMsg* restrict instance);
+extern DLLIMPORT void AzOptionJankCapture_delete(AzOptionJankCapture* restrict instance);
+extern DLLIMPORT void AzOptionEventRecording_delete(AzOptionEventRecording* restrict instance);
+extern DLLIMPORT void AzOptionFrameRecording_delete(AzOptionFrameRecording* restrict instance);
 extern DLLIMPORT void AzOptionThreadSendMsg_delete(AzOptionThreadSendMsg* restrict instance);
 extern DLLIMPORT void AzOptionRefAny_delete(AzOptionRefAny* restrict instance);
 extern DLLIMPORT void AzOptionInlineText_delete(AzOptionInlineText* restrict instance);
+extern DLLIMPORT void AzOptionTextLayout_delete(AzOptionTextLayout* restrict instance);
 extern DLLIMPORT void AzOptionRawImage_delete(AzOptionRawImage* restrict instance);
 extern DLLIMPORT void AzOptionWaylandTheme_delete(AzOptionWaylandTheme* restrict instance);
 extern DLLIMPORT void AzOptionTaskBarIcon_delete(AzOptionTaskBarIcon* restrict instance);
@@ -14952,6 +17047,76 @@ extern DLLIMPORT void AzInvalidStringError_delete(AzInvalidStringError* restrict
 #define AzGl_ZOOM_X 0x0D16
 #define AzGl_ZOOM_Y 0x0D17
 
+bool AzEventRecording_matchRefRecord(const AzEventRecording* value, const AzString** restrict out) {
+    const AzEventRecordingVariant_Record* casted = (const AzEventRecordingVariant_Record*)value;
+    bool valid = casted->tag == AzEventRecordingTag_Record;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzEventRecording_matchMutRecord(AzEventRecording* restrict value, AzString* restrict * restrict out) {
+    AzEventRecordingVariant_Record* restrict casted = (AzEventRecordingVariant_Record* restrict)value;
+    bool valid = casted->tag == AzEventRecordingTag_Record;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzEventRecording_matchRefReplay(const AzEventRecording* value, const AzString** restrict out) {
+    const AzEventRecordingVariant_Replay* casted = (const AzEventRecordingVariant_Replay*)value;
+    bool valid = casted->tag == AzEventRecordingTag_Replay;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzEventRecording_matchMutReplay(AzEventRecording* restrict value, AzString* restrict * restrict out) {
+    AzEventRecordingVariant_Replay* restrict casted = (AzEventRecordingVariant_Replay* restrict)value;
+    bool valid = casted->tag == AzEventRecordingTag_Replay;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzFrameRecordingOutput_matchRefGifDirectory(const AzFrameRecordingOutput* value, const AzString** restrict out) {
+    const AzFrameRecordingOutputVariant_GifDirectory* casted = (const AzFrameRecordingOutputVariant_GifDirectory*)value;
+    bool valid = casted->tag == AzFrameRecordingOutputTag_GifDirectory;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzFrameRecordingOutput_matchMutGifDirectory(AzFrameRecordingOutput* restrict value, AzString* restrict * restrict out) {
+    AzFrameRecordingOutputVariant_GifDirectory* restrict casted = (AzFrameRecordingOutputVariant_GifDirectory* restrict)value;
+    bool valid = casted->tag == AzFrameRecordingOutputTag_GifDirectory;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzFrameRecordingOutput_matchRefCallback(const AzFrameRecordingOutput* value, const AzFrameRecordingCallback** restrict out) {
+    const AzFrameRecordingOutputVariant_Callback* casted = (const AzFrameRecordingOutputVariant_Callback*)value;
+    bool valid = casted->tag == AzFrameRecordingOutputTag_Callback;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzFrameRecordingOutput_matchMutCallback(AzFrameRecordingOutput* restrict value, AzFrameRecordingCallback* restrict * restrict out) {
+    AzFrameRecordingOutputVariant_Callback* restrict casted = (AzFrameRecordingOutputVariant_Callback* restrict)value;
+    bool valid = casted->tag == AzFrameRecordingOutputTag_Callback;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzFirstFrameMode_matchRefSplashColor(const AzFirstFrameMode* value, const AzColorU** restrict out) {
+    const AzFirstFrameModeVariant_SplashColor* casted = (const AzFirstFrameModeVariant_SplashColor*)value;
+    bool valid = casted->tag == AzFirstFrameModeTag_SplashColor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzFirstFrameMode_matchMutSplashColor(AzFirstFrameMode* restrict value, AzColorU* restrict * restrict out) {
+    AzFirstFrameModeVariant_SplashColor* restrict casted = (AzFirstFrameModeVariant_SplashColor* restrict)value;
+    bool valid = casted->tag == AzFirstFrameModeTag_SplashColor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzRawWindowHandle_matchRefIOS(const AzRawWindowHandle* value, const AzIOSHandle** restrict out) {
     const AzRawWindowHandleVariant_IOS* casted = (const AzRawWindowHandleVariant_IOS*)value;
     bool valid = casted->tag == AzRawWindowHandleTag_IOS;
@@ -15106,6 +17271,34 @@ bool AzAcceleratorKey_matchMutKey(AzAcceleratorKey* restrict value, AzVirtualKey
     return valid;
 }
 
+bool AzFullscreenMode_matchRefBorderless(const AzFullscreenMode* value, const Azusize** restrict out) {
+    const AzFullscreenModeVariant_Borderless* casted = (const AzFullscreenModeVariant_Borderless*)value;
+    bool valid = casted->tag == AzFullscreenModeTag_Borderless;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzFullscreenMode_matchMutBorderless(AzFullscreenMode* restrict value, Azusize* restrict * restrict out) {
+    AzFullscreenModeVariant_Borderless* restrict casted = (AzFullscreenModeVariant_Borderless* restrict)value;
+    bool valid = casted->tag == AzFullscreenModeTag_Borderless;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzFullscreenMode_matchRefExclusive(const AzFullscreenMode* value, const Azusize** restrict out) {
+    const AzFullscreenModeVariant_Exclusive* casted = (const AzFullscreenModeVariant_Exclusive*)value;
+    bool valid = casted->tag == AzFullscreenModeTag_Exclusive;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzFullscreenMode_matchMutExclusive(AzFullscreenMode* restrict value, Azusize* restrict * restrict out) {
+    AzFullscreenModeVariant_Exclusive* restrict casted = (AzFullscreenModeVariant_Exclusive* restrict)value;
+    bool valid = casted->tag == AzFullscreenModeTag_Exclusive;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCursorPosition_matchRefOutOfWindow(const AzCursorPosition* value, const AzLogicalPosition** restrict out) {
     const AzCursorPositionVariant_OutOfWindow* casted = (const AzCursorPositionVariant_OutOfWindow*)value;
     bool valid = casted->tag == AzCursorPositionTag_OutOfWindow;
@@ -15288,6 +17481,20 @@ bool AzFocusTarget_matchMutPath(AzFocusTarget* restrict value, AzFocusTargetPath
     return valid;
 }
 
+bool AzFocusTarget_matchRefDirection(const AzFocusTarget* value, const AzFocusDirection** restrict out) {
+    const AzFocusTargetVariant_Direction* casted = (const AzFocusTargetVariant_Direction*)value;
+    bool valid = casted->tag == AzFocusTargetTag_Direction;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzFocusTarget_matchMutDirection(AzFocusTarget* restrict value, AzFocusDirection* restrict * restrict out) {
+    AzFocusTargetVariant_Direction* restrict casted = (AzFocusTargetVariant_Direction* restrict)value;
+    bool valid = casted->tag == AzFocusTargetTag_Direction;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzAnimationRepeatCount_matchRefTimes(const AzAnimationRepeatCount* value, const Azusize** restrict out) {
     const AzAnimationRepeatCountVariant_Times* casted = (const AzAnimationRepeatCountVariant_Times*)value;
     bool valid = casted->tag == AzAnimationRepeatCountTag_Times;
@@ -15316,6 +17523,20 @@ bool AzAnimationEasing_matchMutCubicBezier(AzAnimationEasing* restrict value, Az
     return valid;
 }
 
+bool AzPaintPrimitive_matchRefRect(const AzPaintPrimitive* value, const AzPaintRect** restrict out) {
+    const AzPaintPrimitiveVariant_Rect* casted = (const AzPaintPrimitiveVariant_Rect*)value;
+    bool valid = casted->tag == AzPaintPrimitiveTag_Rect;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzPaintPrimitive_matchMutRect(AzPaintPrimitive* restrict value, AzPaintRect* restrict * restrict out) {
+    AzPaintPrimitiveVariant_Rect* restrict casted = (AzPaintPrimitiveVariant_Rect* restrict)value;
+    bool valid = casted->tag == AzPaintPrimitiveTag_Rect;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzNodeType_matchRefText(const AzNodeType* value, const AzString** restrict out) {
     const AzNodeTypeVariant_Text* casted = (const AzNodeTypeVariant_Text*)value;
     bool valid = casted->tag == AzNodeTypeTag_Text;
@@ -15358,6 +17579,20 @@ bool AzNodeType_matchMutIFrame(AzNodeType* restrict value, AzIFrameNode* restric
     return valid;
 }
 
+bool AzNodeType_matchRefCustomLayout(const AzNodeType* value, const AzCustomLayoutNode** restrict out) {
+    const AzNodeTypeVariant_CustomLayout* casted = (const AzNodeTypeVariant_CustomLayout*)value;
+    bool valid = casted->tag == AzNodeTypeTag_CustomLayout;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeType_matchMutCustomLayout(AzNodeType* restrict value, AzCustomLayoutNode* restrict * restrict out) {
+    AzNodeTypeVariant_CustomLayout* restrict casted = (AzNodeTypeVariant_CustomLayout* restrict)value;
+    bool valid = casted->tag == AzNodeTypeTag_CustomLayout;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzEventFilter_matchRefHover(const AzEventFilter* value, const AzHoverEventFilter** restrict out) {
     const AzEventFilterVariant_Hover* casted = (const AzEventFilterVariant_Hover*)value;
     bool valid = casted->tag == AzEventFilterTag_Hover;
@@ -15638,6 +17873,20 @@ bool AzCssDeclaration_matchMutDynamic(AzCssDeclaration* restrict value, AzDynami
     return valid;
 }
 
+bool AzCssDeclaration_matchRefSystem(const AzCssDeclaration* value, const AzSystemCssProperty** restrict out) {
+    const AzCssDeclarationVariant_System* casted = (const AzCssDeclarationVariant_System*)value;
+    bool valid = casted->tag == AzCssDeclarationTag_System;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssDeclaration_matchMutSystem(AzCssDeclaration* restrict value, AzSystemCssProperty* restrict * restrict out) {
+    AzCssDeclarationVariant_System* restrict casted = (AzCssDeclarationVariant_System* restrict)value;
+    bool valid = casted->tag == AzCssDeclarationTag_System;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssPathSelector_matchRefType(const AzCssPathSelector* value, const AzNodeTypeKey** restrict out) {
     const AzCssPathSelectorVariant_Type* casted = (const AzCssPathSelectorVariant_Type*)value;
     bool valid = casted->tag == AzCssPathSelectorTag_Type;
@@ -16016,7 +18265,63 @@ bool AzStyleBackgroundSize_matchMutExactSize(AzStyleBackgroundSize* restrict val
     return valid;
 }
 
-bool AzStyleFontFamily_matchRefSystem(const AzStyleFontFamily* value, const AzString** restrict out) {
+bool AzStyleContent_matchRefText(const AzStyleContent* value, const AzString** restrict out) {
+    const AzStyleContentVariant_Text* casted = (const AzStyleContentVariant_Text*)value;
+    bool valid = casted->tag == AzStyleContentTag_Text;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleContent_matchMutText(AzStyleContent* restrict value, AzString* restrict * restrict out) {
+    AzStyleContentVariant_Text* restrict casted = (AzStyleContentVariant_Text* restrict)value;
+    bool valid = casted->tag == AzStyleContentTag_Text;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleContent_matchRefImage(const AzStyleContent* value, const AzString** restrict out) {
+    const AzStyleContentVariant_Image* casted = (const AzStyleContentVariant_Image*)value;
+    bool valid = casted->tag == AzStyleContentTag_Image;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleContent_matchMutImage(AzStyleContent* restrict value, AzString* restrict * restrict out) {
+    AzStyleContentVariant_Image* restrict casted = (AzStyleContentVariant_Image* restrict)value;
+    bool valid = casted->tag == AzStyleContentTag_Image;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleContent_matchRefCounter(const AzStyleContent* value, const AzString** restrict out) {
+    const AzStyleContentVariant_Counter* casted = (const AzStyleContentVariant_Counter*)value;
+    bool valid = casted->tag == AzStyleContentTag_Counter;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleContent_matchMutCounter(AzStyleContent* restrict value, AzString* restrict * restrict out) {
+    AzStyleContentVariant_Counter* restrict casted = (AzStyleContentVariant_Counter* restrict)value;
+    bool valid = casted->tag == AzStyleContentTag_Counter;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleCursor_matchRefImage(const AzStyleCursor* value, const AzStyleCursorImage** restrict out) {
+    const AzStyleCursorVariant_Image* casted = (const AzStyleCursorVariant_Image*)value;
+    bool valid = casted->tag == AzStyleCursorTag_Image;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleCursor_matchMutImage(AzStyleCursor* restrict value, AzStyleCursorImage* restrict * restrict out) {
+    AzStyleCursorVariant_Image* restrict casted = (AzStyleCursorVariant_Image* restrict)value;
+    bool valid = casted->tag == AzStyleCursorTag_Image;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleFontFamily_matchRefSystem(const AzStyleFontFamily* value, const AzString** restrict out) {
     const AzStyleFontFamilyVariant_System* casted = (const AzStyleFontFamilyVariant_System*)value;
     bool valid = casted->tag == AzStyleFontFamilyTag_System;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -16800,6 +19105,160 @@ bool AzScrollbarStyleValue_matchMutExact(AzScrollbarStyleValue* restrict value,
     return valid;
 }
 
+bool AzStyleScrollbarWidthValue_matchRefExact(const AzStyleScrollbarWidthValue* value, const AzStyleScrollbarWidth** restrict out) {
+    const AzStyleScrollbarWidthValueVariant_Exact* casted = (const AzStyleScrollbarWidthValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleScrollbarWidthValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleScrollbarWidthValue_matchMutExact(AzStyleScrollbarWidthValue* restrict value, AzStyleScrollbarWidth* restrict * restrict out) {
+    AzStyleScrollbarWidthValueVariant_Exact* restrict casted = (AzStyleScrollbarWidthValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleScrollbarWidthValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleScrollbarColorValue_matchRefExact(const AzStyleScrollbarColorValue* value, const AzStyleScrollbarColor** restrict out) {
+    const AzStyleScrollbarColorValueVariant_Exact* casted = (const AzStyleScrollbarColorValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleScrollbarColorValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleScrollbarColorValue_matchMutExact(AzStyleScrollbarColorValue* restrict value, AzStyleScrollbarColor* restrict * restrict out) {
+    AzStyleScrollbarColorValueVariant_Exact* restrict casted = (AzStyleScrollbarColorValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleScrollbarColorValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleScrollbarThumbHoverColorValue_matchRefExact(const AzStyleScrollbarThumbHoverColorValue* value, const AzStyleScrollbarThumbHoverColor** restrict out) {
+    const AzStyleScrollbarThumbHoverColorValueVariant_Exact* casted = (const AzStyleScrollbarThumbHoverColorValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleScrollbarThumbHoverColorValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleScrollbarThumbHoverColorValue_matchMutExact(AzStyleScrollbarThumbHoverColorValue* restrict value, AzStyleScrollbarThumbHoverColor* restrict * restrict out) {
+    AzStyleScrollbarThumbHoverColorValueVariant_Exact* restrict casted = (AzStyleScrollbarThumbHoverColorValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleScrollbarThumbHoverColorValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleScrollbarThumbActiveColorValue_matchRefExact(const AzStyleScrollbarThumbActiveColorValue* value, const AzStyleScrollbarThumbActiveColor** restrict out) {
+    const AzStyleScrollbarThumbActiveColorValueVariant_Exact* casted = (const AzStyleScrollbarThumbActiveColorValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleScrollbarThumbActiveColorValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleScrollbarThumbActiveColorValue_matchMutExact(AzStyleScrollbarThumbActiveColorValue* restrict value, AzStyleScrollbarThumbActiveColor* restrict * restrict out) {
+    AzStyleScrollbarThumbActiveColorValueVariant_Exact* restrict casted = (AzStyleScrollbarThumbActiveColorValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleScrollbarThumbActiveColorValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleWindowDragRegionValue_matchRefExact(const AzStyleWindowDragRegionValue* value, const AzStyleWindowDragRegion** restrict out) {
+    const AzStyleWindowDragRegionValueVariant_Exact* casted = (const AzStyleWindowDragRegionValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleWindowDragRegionValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleWindowDragRegionValue_matchMutExact(AzStyleWindowDragRegionValue* restrict value, AzStyleWindowDragRegion* restrict * restrict out) {
+    AzStyleWindowDragRegionValueVariant_Exact* restrict casted = (AzStyleWindowDragRegionValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleWindowDragRegionValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleCaretAnimationDurationValue_matchRefExact(const AzStyleCaretAnimationDurationValue* value, const AzStyleCaretAnimationDuration** restrict out) {
+    const AzStyleCaretAnimationDurationValueVariant_Exact* casted = (const AzStyleCaretAnimationDurationValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleCaretAnimationDurationValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleCaretAnimationDurationValue_matchMutExact(AzStyleCaretAnimationDurationValue* restrict value, AzStyleCaretAnimationDuration* restrict * restrict out) {
+    AzStyleCaretAnimationDurationValueVariant_Exact* restrict casted = (AzStyleCaretAnimationDurationValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleCaretAnimationDurationValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleScrollPaddingValue_matchRefExact(const AzStyleScrollPaddingValue* value, const AzStyleScrollPadding** restrict out) {
+    const AzStyleScrollPaddingValueVariant_Exact* casted = (const AzStyleScrollPaddingValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleScrollPaddingValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleScrollPaddingValue_matchMutExact(AzStyleScrollPaddingValue* restrict value, AzStyleScrollPadding* restrict * restrict out) {
+    AzStyleScrollPaddingValueVariant_Exact* restrict casted = (AzStyleScrollPaddingValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleScrollPaddingValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleContentValue_matchRefExact(const AzStyleContentValue* value, const AzStyleContent** restrict out) {
+    const AzStyleContentValueVariant_Exact* casted = (const AzStyleContentValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleContentValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleContentValue_matchMutExact(AzStyleContentValue* restrict value, AzStyleContent* restrict * restrict out) {
+    AzStyleContentValueVariant_Exact* restrict casted = (AzStyleContentValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleContentValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleCounterResetValue_matchRefExact(const AzStyleCounterResetValue* value, const AzStyleCounterReset** restrict out) {
+    const AzStyleCounterResetValueVariant_Exact* casted = (const AzStyleCounterResetValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleCounterResetValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleCounterResetValue_matchMutExact(AzStyleCounterResetValue* restrict value, AzStyleCounterReset* restrict * restrict out) {
+    AzStyleCounterResetValueVariant_Exact* restrict casted = (AzStyleCounterResetValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleCounterResetValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleCounterIncrementValue_matchRefExact(const AzStyleCounterIncrementValue* value, const AzStyleCounterIncrement** restrict out) {
+    const AzStyleCounterIncrementValueVariant_Exact* casted = (const AzStyleCounterIncrementValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleCounterIncrementValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleCounterIncrementValue_matchMutExact(AzStyleCounterIncrementValue* restrict value, AzStyleCounterIncrement* restrict * restrict out) {
+    AzStyleCounterIncrementValueVariant_Exact* restrict casted = (AzStyleCounterIncrementValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleCounterIncrementValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleOverflowAnchorValue_matchRefExact(const AzStyleOverflowAnchorValue* value, const AzStyleOverflowAnchor** restrict out) {
+    const AzStyleOverflowAnchorValueVariant_Exact* casted = (const AzStyleOverflowAnchorValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleOverflowAnchorValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleOverflowAnchorValue_matchMutExact(AzStyleOverflowAnchorValue* restrict value, AzStyleOverflowAnchor* restrict * restrict out) {
+    AzStyleOverflowAnchorValueVariant_Exact* restrict casted = (AzStyleOverflowAnchorValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleOverflowAnchorValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzStyleBackgroundContentVecValue_matchRefExact(const AzStyleBackgroundContentVecValue* value, const AzStyleBackgroundContentVec** restrict out) {
     const AzStyleBackgroundContentVecValueVariant_Exact* casted = (const AzStyleBackgroundContentVecValueVariant_Exact*)value;
     bool valid = casted->tag == AzStyleBackgroundContentVecValueTag_Exact;
@@ -17164,6 +19623,20 @@ bool AzStyleTabWidthValue_matchMutExact(AzStyleTabWidthValue* restrict value, Az
     return valid;
 }
 
+bool AzStyleHyphensValue_matchRefExact(const AzStyleHyphensValue* value, const AzStyleHyphens** restrict out) {
+    const AzStyleHyphensValueVariant_Exact* casted = (const AzStyleHyphensValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleHyphensValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleHyphensValue_matchMutExact(AzStyleHyphensValue* restrict value, AzStyleHyphens* restrict * restrict out) {
+    AzStyleHyphensValueVariant_Exact* restrict casted = (AzStyleHyphensValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleHyphensValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzStyleTextAlignValue_matchRefExact(const AzStyleTextAlignValue* value, const AzStyleTextAlign** restrict out) {
     const AzStyleTextAlignValueVariant_Exact* casted = (const AzStyleTextAlignValueVariant_Exact*)value;
     bool valid = casted->tag == AzStyleTextAlignValueTag_Exact;
@@ -17276,6 +19749,34 @@ bool AzStyleBackfaceVisibilityValue_matchMutExact(AzStyleBackfaceVisibilityValue
     return valid;
 }
 
+bool AzStylePerspectiveValue_matchRefExact(const AzStylePerspectiveValue* value, const AzStylePerspective** restrict out) {
+    const AzStylePerspectiveValueVariant_Exact* casted = (const AzStylePerspectiveValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStylePerspectiveValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStylePerspectiveValue_matchMutExact(AzStylePerspectiveValue* restrict value, AzStylePerspective* restrict * restrict out) {
+    AzStylePerspectiveValueVariant_Exact* restrict casted = (AzStylePerspectiveValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStylePerspectiveValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransformStyleValue_matchRefExact(const AzStyleTransformStyleValue* value, const AzStyleTransformStyle** restrict out) {
+    const AzStyleTransformStyleValueVariant_Exact* casted = (const AzStyleTransformStyleValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleTransformStyleValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransformStyleValue_matchMutExact(AzStyleTransformStyleValue* restrict value, AzStyleTransformStyle* restrict * restrict out) {
+    AzStyleTransformStyleValueVariant_Exact* restrict casted = (AzStyleTransformStyleValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleTransformStyleValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzStyleMixBlendModeValue_matchRefExact(const AzStyleMixBlendModeValue* value, const AzStyleMixBlendMode** restrict out) {
     const AzStyleMixBlendModeValueVariant_Exact* casted = (const AzStyleMixBlendModeValueVariant_Exact*)value;
     bool valid = casted->tag == AzStyleMixBlendModeValueTag_Exact;
@@ -17416,6 +19917,20 @@ bool AzCssProperty_matchMutTabWidth(AzCssProperty* restrict value, AzStyleTabWid
     return valid;
 }
 
+bool AzCssProperty_matchRefHyphens(const AzCssProperty* value, const AzStyleHyphensValue** restrict out) {
+    const AzCssPropertyVariant_Hyphens* casted = (const AzCssPropertyVariant_Hyphens*)value;
+    bool valid = casted->tag == AzCssPropertyTag_Hyphens;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutHyphens(AzCssProperty* restrict value, AzStyleHyphensValue* restrict * restrict out) {
+    AzCssPropertyVariant_Hyphens* restrict casted = (AzCssPropertyVariant_Hyphens* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_Hyphens;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssProperty_matchRefCursor(const AzCssProperty* value, const AzStyleCursorValue** restrict out) {
     const AzCssPropertyVariant_Cursor* casted = (const AzCssPropertyVariant_Cursor*)value;
     bool valid = casted->tag == AzCssPropertyTag_Cursor;
@@ -18214,6 +20729,160 @@ bool AzCssProperty_matchMutScrollbarStyle(AzCssProperty* restrict value, AzScrol
     return valid;
 }
 
+bool AzCssProperty_matchRefScrollbarWidth(const AzCssProperty* value, const AzStyleScrollbarWidthValue** restrict out) {
+    const AzCssPropertyVariant_ScrollbarWidth* casted = (const AzCssPropertyVariant_ScrollbarWidth*)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollbarWidth;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutScrollbarWidth(AzCssProperty* restrict value, AzStyleScrollbarWidthValue* restrict * restrict out) {
+    AzCssPropertyVariant_ScrollbarWidth* restrict casted = (AzCssPropertyVariant_ScrollbarWidth* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollbarWidth;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefScrollbarColor(const AzCssProperty* value, const AzStyleScrollbarColorValue** restrict out) {
+    const AzCssPropertyVariant_ScrollbarColor* casted = (const AzCssPropertyVariant_ScrollbarColor*)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollbarColor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutScrollbarColor(AzCssProperty* restrict value, AzStyleScrollbarColorValue* restrict * restrict out) {
+    AzCssPropertyVariant_ScrollbarColor* restrict casted = (AzCssPropertyVariant_ScrollbarColor* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollbarColor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefScrollbarThumbHoverColor(const AzCssProperty* value, const AzStyleScrollbarThumbHoverColorValue** restrict out) {
+    const AzCssPropertyVariant_ScrollbarThumbHoverColor* casted = (const AzCssPropertyVariant_ScrollbarThumbHoverColor*)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollbarThumbHoverColor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutScrollbarThumbHoverColor(AzCssProperty* restrict value, AzStyleScrollbarThumbHoverColorValue* restrict * restrict out) {
+    AzCssPropertyVariant_ScrollbarThumbHoverColor* restrict casted = (AzCssPropertyVariant_ScrollbarThumbHoverColor* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollbarThumbHoverColor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefScrollbarThumbActiveColor(const AzCssProperty* value, const AzStyleScrollbarThumbActiveColorValue** restrict out) {
+    const AzCssPropertyVariant_ScrollbarThumbActiveColor* casted = (const AzCssPropertyVariant_ScrollbarThumbActiveColor*)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollbarThumbActiveColor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutScrollbarThumbActiveColor(AzCssProperty* restrict value, AzStyleScrollbarThumbActiveColorValue* restrict * restrict out) {
+    AzCssPropertyVariant_ScrollbarThumbActiveColor* restrict casted = (AzCssPropertyVariant_ScrollbarThumbActiveColor* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollbarThumbActiveColor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefWindowDragRegion(const AzCssProperty* value, const AzStyleWindowDragRegionValue** restrict out) {
+    const AzCssPropertyVariant_WindowDragRegion* casted = (const AzCssPropertyVariant_WindowDragRegion*)value;
+    bool valid = casted->tag == AzCssPropertyTag_WindowDragRegion;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutWindowDragRegion(AzCssProperty* restrict value, AzStyleWindowDragRegionValue* restrict * restrict out) {
+    AzCssPropertyVariant_WindowDragRegion* restrict casted = (AzCssPropertyVariant_WindowDragRegion* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_WindowDragRegion;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefCaretAnimationDuration(const AzCssProperty* value, const AzStyleCaretAnimationDurationValue** restrict out) {
+    const AzCssPropertyVariant_CaretAnimationDuration* casted = (const AzCssPropertyVariant_CaretAnimationDuration*)value;
+    bool valid = casted->tag == AzCssPropertyTag_CaretAnimationDuration;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutCaretAnimationDuration(AzCssProperty* restrict value, AzStyleCaretAnimationDurationValue* restrict * restrict out) {
+    AzCssPropertyVariant_CaretAnimationDuration* restrict casted = (AzCssPropertyVariant_CaretAnimationDuration* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_CaretAnimationDuration;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefScrollPadding(const AzCssProperty* value, const AzStyleScrollPaddingValue** restrict out) {
+    const AzCssPropertyVariant_ScrollPadding* casted = (const AzCssPropertyVariant_ScrollPadding*)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollPadding;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutScrollPadding(AzCssProperty* restrict value, AzStyleScrollPaddingValue* restrict * restrict out) {
+    AzCssPropertyVariant_ScrollPadding* restrict casted = (AzCssPropertyVariant_ScrollPadding* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollPadding;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefContent(const AzCssProperty* value, const AzStyleContentValue** restrict out) {
+    const AzCssPropertyVariant_Content* casted = (const AzCssPropertyVariant_Content*)value;
+    bool valid = casted->tag == AzCssPropertyTag_Content;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutContent(AzCssProperty* restrict value, AzStyleContentValue* restrict * restrict out) {
+    AzCssPropertyVariant_Content* restrict casted = (AzCssPropertyVariant_Content* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_Content;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefCounterReset(const AzCssProperty* value, const AzStyleCounterResetValue** restrict out) {
+    const AzCssPropertyVariant_CounterReset* casted = (const AzCssPropertyVariant_CounterReset*)value;
+    bool valid = casted->tag == AzCssPropertyTag_CounterReset;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutCounterReset(AzCssProperty* restrict value, AzStyleCounterResetValue* restrict * restrict out) {
+    AzCssPropertyVariant_CounterReset* restrict casted = (AzCssPropertyVariant_CounterReset* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_CounterReset;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefCounterIncrement(const AzCssProperty* value, const AzStyleCounterIncrementValue** restrict out) {
+    const AzCssPropertyVariant_CounterIncrement* casted = (const AzCssPropertyVariant_CounterIncrement*)value;
+    bool valid = casted->tag == AzCssPropertyTag_CounterIncrement;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutCounterIncrement(AzCssProperty* restrict value, AzStyleCounterIncrementValue* restrict * restrict out) {
+    AzCssPropertyVariant_CounterIncrement* restrict casted = (AzCssPropertyVariant_CounterIncrement* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_CounterIncrement;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefOverflowAnchor(const AzCssProperty* value, const AzStyleOverflowAnchorValue** restrict out) {
+    const AzCssPropertyVariant_OverflowAnchor* casted = (const AzCssPropertyVariant_OverflowAnchor*)value;
+    bool valid = casted->tag == AzCssPropertyTag_OverflowAnchor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutOverflowAnchor(AzCssProperty* restrict value, AzStyleOverflowAnchorValue* restrict * restrict out) {
+    AzCssPropertyVariant_OverflowAnchor* restrict casted = (AzCssPropertyVariant_OverflowAnchor* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_OverflowAnchor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssProperty_matchRefOpacity(const AzCssProperty* value, const AzStyleOpacityValue** restrict out) {
     const AzCssPropertyVariant_Opacity* casted = (const AzCssPropertyVariant_Opacity*)value;
     bool valid = casted->tag == AzCssPropertyTag_Opacity;
@@ -18284,6 +20953,34 @@ bool AzCssProperty_matchMutBackfaceVisibility(AzCssProperty* restrict value, AzS
     return valid;
 }
 
+bool AzCssProperty_matchRefPerspective(const AzCssProperty* value, const AzStylePerspectiveValue** restrict out) {
+    const AzCssPropertyVariant_Perspective* casted = (const AzCssPropertyVariant_Perspective*)value;
+    bool valid = casted->tag == AzCssPropertyTag_Perspective;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutPerspective(AzCssProperty* restrict value, AzStylePerspectiveValue* restrict * restrict out) {
+    AzCssPropertyVariant_Perspective* restrict casted = (AzCssPropertyVariant_Perspective* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_Perspective;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefTransformStyle(const AzCssProperty* value, const AzStyleTransformStyleValue** restrict out) {
+    const AzCssPropertyVariant_TransformStyle* casted = (const AzCssPropertyVariant_TransformStyle*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransformStyle;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutTransformStyle(AzCssProperty* restrict value, AzStyleTransformStyleValue* restrict * restrict out) {
+    AzCssPropertyVariant_TransformStyle* restrict casted = (AzCssPropertyVariant_TransformStyle* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransformStyle;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssProperty_matchRefMixBlendMode(const AzCssProperty* value, const AzStyleMixBlendModeValue** restrict out) {
     const AzCssPropertyVariant_MixBlendMode* casted = (const AzCssPropertyVariant_MixBlendMode*)value;
     bool valid = casted->tag == AzCssPropertyTag_MixBlendMode;
@@ -19362,6 +22059,20 @@ bool AzInlineWordVecDestructor_matchMutExternal(AzInlineWordVecDestructor* restr
     return valid;
 }
 
+bool AzPaintPrimitiveVecDestructor_matchRefExternal(const AzPaintPrimitiveVecDestructor* value, const AzPaintPrimitiveVecDestructorType** restrict out) {
+    const AzPaintPrimitiveVecDestructorVariant_External* casted = (const AzPaintPrimitiveVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzPaintPrimitiveVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzPaintPrimitiveVecDestructor_matchMutExternal(AzPaintPrimitiveVecDestructor* restrict value, AzPaintPrimitiveVecDestructorType* restrict * restrict out) {
+    AzPaintPrimitiveVecDestructorVariant_External* restrict casted = (AzPaintPrimitiveVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzPaintPrimitiveVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzInlineGlyphVecDestructor_matchRefExternal(const AzInlineGlyphVecDestructor* value, const AzInlineGlyphVecDestructorType** restrict out) {
     const AzInlineGlyphVecDestructorVariant_External* casted = (const AzInlineGlyphVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzInlineGlyphVecDestructorTag_External;
@@ -19376,6 +22087,20 @@ bool AzInlineGlyphVecDestructor_matchMutExternal(AzInlineGlyphVecDestructor* res
     return valid;
 }
 
+bool AzTextUnderlineVecDestructor_matchRefExternal(const AzTextUnderlineVecDestructor* value, const AzTextUnderlineVecDestructorType** restrict out) {
+    const AzTextUnderlineVecDestructorVariant_External* casted = (const AzTextUnderlineVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTextUnderlineVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTextUnderlineVecDestructor_matchMutExternal(AzTextUnderlineVecDestructor* restrict value, AzTextUnderlineVecDestructorType* restrict * restrict out) {
+    AzTextUnderlineVecDestructorVariant_External* restrict casted = (AzTextUnderlineVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTextUnderlineVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzInlineTextHitVecDestructor_matchRefExternal(const AzInlineTextHitVecDestructor* value, const AzInlineTextHitVecDestructorType** restrict out) {
     const AzInlineTextHitVecDestructorVariant_External* casted = (const AzInlineTextHitVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzInlineTextHitVecDestructorTag_External;
@@ -19404,6 +22129,20 @@ bool AzMonitorVecDestructor_matchMutExternal(AzMonitorVecDestructor* restrict va
     return valid;
 }
 
+bool AzTouchPointVecDestructor_matchRefExternal(const AzTouchPointVecDestructor* value, const AzTouchPointVecDestructorType** restrict out) {
+    const AzTouchPointVecDestructorVariant_External* casted = (const AzTouchPointVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTouchPointVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTouchPointVecDestructor_matchMutExternal(AzTouchPointVecDestructor* restrict value, AzTouchPointVecDestructorType* restrict * restrict out) {
+    AzTouchPointVecDestructorVariant_External* restrict casted = (AzTouchPointVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTouchPointVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzVideoModeVecDestructor_matchRefExternal(const AzVideoModeVecDestructor* value, const AzVideoModeVecDestructorType** restrict out) {
     const AzVideoModeVecDestructorVariant_External* casted = (const AzVideoModeVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzVideoModeVecDestructorTag_External;
@@ -19684,6 +22423,34 @@ bool AzVirtualKeyCodeVecDestructor_matchMutExternal(AzVirtualKeyCodeVecDestructo
     return valid;
 }
 
+bool AzShortcutConflictVecDestructor_matchRefExternal(const AzShortcutConflictVecDestructor* value, const AzShortcutConflictVecDestructorType** restrict out) {
+    const AzShortcutConflictVecDestructorVariant_External* casted = (const AzShortcutConflictVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzShortcutConflictVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzShortcutConflictVecDestructor_matchMutExternal(AzShortcutConflictVecDestructor* restrict value, AzShortcutConflictVecDestructorType* restrict * restrict out) {
+    AzShortcutConflictVecDestructorVariant_External* restrict casted = (AzShortcutConflictVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzShortcutConflictVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzShortcutVecDestructor_matchRefExternal(const AzShortcutVecDestructor* value, const AzShortcutVecDestructorType** restrict out) {
+    const AzShortcutVecDestructorVariant_External* casted = (const AzShortcutVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzShortcutVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzShortcutVecDestructor_matchMutExternal(AzShortcutVecDestructor* restrict value, AzShortcutVecDestructorType* restrict * restrict out) {
+    AzShortcutVecDestructorVariant_External* restrict casted = (AzShortcutVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzShortcutVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCascadeInfoVecDestructor_matchRefExternal(const AzCascadeInfoVecDestructor* value, const AzCascadeInfoVecDestructorType** restrict out) {
     const AzCascadeInfoVecDestructorVariant_External* casted = (const AzCascadeInfoVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzCascadeInfoVecDestructorTag_External;
@@ -20104,6 +22871,20 @@ bool AzOptionDropDownOnChoiceChange_matchMutSome(AzOptionDropDownOnChoiceChange*
     return valid;
 }
 
+bool AzOptionToastOnAction_matchRefSome(const AzOptionToastOnAction* value, const AzToastOnAction** restrict out) {
+    const AzOptionToastOnActionVariant_Some* casted = (const AzOptionToastOnActionVariant_Some*)value;
+    bool valid = casted->tag == AzOptionToastOnActionTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionToastOnAction_matchMutSome(AzOptionToastOnAction* restrict value, AzToastOnAction* restrict * restrict out) {
+    AzOptionToastOnActionVariant_Some* restrict casted = (AzOptionToastOnActionVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionToastOnActionTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionResolvedTextLayoutOptions_matchRefSome(const AzOptionResolvedTextLayoutOptions* value, const AzResolvedTextLayoutOptions** restrict out) {
     const AzOptionResolvedTextLayoutOptionsVariant_Some* casted = (const AzOptionResolvedTextLayoutOptionsVariant_Some*)value;
     bool valid = casted->tag == AzOptionResolvedTextLayoutOptionsTag_Some;
@@ -20342,6 +23123,20 @@ bool AzOptionTextInputOnFocusLost_matchMutSome(AzOptionTextInputOnFocusLost* res
     return valid;
 }
 
+bool AzOptionTextInputOnSpellingSuggestions_matchRefSome(const AzOptionTextInputOnSpellingSuggestions* value, const AzTextInputOnSpellingSuggestions** restrict out) {
+    const AzOptionTextInputOnSpellingSuggestionsVariant_Some* casted = (const AzOptionTextInputOnSpellingSuggestionsVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTextInputOnSpellingSuggestionsTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextInputOnSpellingSuggestions_matchMutSome(AzOptionTextInputOnSpellingSuggestions* restrict value, AzTextInputOnSpellingSuggestions* restrict * restrict out) {
+    AzOptionTextInputOnSpellingSuggestionsVariant_Some* restrict casted = (AzOptionTextInputOnSpellingSuggestionsVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTextInputOnSpellingSuggestionsTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionTextInputSelection_matchRefSome(const AzOptionTextInputSelection* value, const AzTextInputSelection** restrict out) {
     const AzOptionTextInputSelectionVariant_Some* casted = (const AzOptionTextInputSelectionVariant_Some*)value;
     bool valid = casted->tag == AzOptionTextInputSelectionTag_Some;
@@ -20426,6 +23221,20 @@ bool AzOptionVirtualKeyCodeCombo_matchMutSome(AzOptionVirtualKeyCodeCombo* restr
     return valid;
 }
 
+bool AzOptionShortcutConflict_matchRefSome(const AzOptionShortcutConflict* value, const AzShortcutConflict** restrict out) {
+    const AzOptionShortcutConflictVariant_Some* casted = (const AzOptionShortcutConflictVariant_Some*)value;
+    bool valid = casted->tag == AzOptionShortcutConflictTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionShortcutConflict_matchMutSome(AzOptionShortcutConflict* restrict value, AzShortcutConflict* restrict * restrict out) {
+    AzOptionShortcutConflictVariant_Some* restrict casted = (AzOptionShortcutConflictVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionShortcutConflictTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionCssProperty_matchRefSome(const AzOptionCssProperty* value, const AzCssProperty** restrict out) {
     const AzOptionCssPropertyVariant_Some* casted = (const AzOptionCssPropertyVariant_Some*)value;
     bool valid = casted->tag == AzOptionCssPropertyTag_Some;
@@ -20454,6 +23263,34 @@ bool AzOptionPositionInfo_matchMutSome(AzOptionPositionInfo* restrict value, AzP
     return valid;
 }
 
+bool AzOptionNodeRect_matchRefSome(const AzOptionNodeRect* value, const AzNodeRect** restrict out) {
+    const AzOptionNodeRectVariant_Some* casted = (const AzOptionNodeRectVariant_Some*)value;
+    bool valid = casted->tag == AzOptionNodeRectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionNodeRect_matchMutSome(AzOptionNodeRect* restrict value, AzNodeRect* restrict * restrict out) {
+    AzOptionNodeRectVariant_Some* restrict casted = (AzOptionNodeRectVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionNodeRectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionScrollInfo_matchRefSome(const AzOptionScrollInfo* value, const AzScrollInfo** restrict out) {
+    const AzOptionScrollInfoVariant_Some* casted = (const AzOptionScrollInfoVariant_Some*)value;
+    bool valid = casted->tag == AzOptionScrollInfoTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionScrollInfo_matchMutSome(AzOptionScrollInfo* restrict value, AzScrollInfo* restrict * restrict out) {
+    AzOptionScrollInfoVariant_Some* restrict casted = (AzOptionScrollInfoVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionScrollInfoTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionTimerId_matchRefSome(const AzOptionTimerId* value, const AzTimerId** restrict out) {
     const AzOptionTimerIdVariant_Some* casted = (const AzOptionTimerIdVariant_Some*)value;
     bool valid = casted->tag == AzOptionTimerIdTag_Some;
@@ -20734,6 +23571,76 @@ bool AzOptionCallback_matchMutSome(AzOptionCallback* restrict value, AzCallback*
     return valid;
 }
 
+bool AzOptionPaintCallback_matchRefSome(const AzOptionPaintCallback* value, const AzPaintCallback** restrict out) {
+    const AzOptionPaintCallbackVariant_Some* casted = (const AzOptionPaintCallbackVariant_Some*)value;
+    bool valid = casted->tag == AzOptionPaintCallbackTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionPaintCallback_matchMutSome(AzOptionPaintCallback* restrict value, AzPaintCallback* restrict * restrict out) {
+    AzOptionPaintCallbackVariant_Some* restrict casted = (AzOptionPaintCallbackVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionPaintCallbackTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionJankCapture_matchRefSome(const AzOptionJankCapture* value, const AzJankCapture** restrict out) {
+    const AzOptionJankCaptureVariant_Some* casted = (const AzOptionJankCaptureVariant_Some*)value;
+    bool valid = casted->tag == AzOptionJankCaptureTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionJankCapture_matchMutSome(AzOptionJankCapture* restrict value, AzJankCapture* restrict * restrict out) {
+    AzOptionJankCaptureVariant_Some* restrict casted = (AzOptionJankCaptureVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionJankCaptureTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionEventRecording_matchRefSome(const AzOptionEventRecording* value, const AzEventRecording** restrict out) {
+    const AzOptionEventRecordingVariant_Some* casted = (const AzOptionEventRecordingVariant_Some*)value;
+    bool valid = casted->tag == AzOptionEventRecordingTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionEventRecording_matchMutSome(AzOptionEventRecording* restrict value, AzEventRecording* restrict * restrict out) {
+    AzOptionEventRecordingVariant_Some* restrict casted = (AzOptionEventRecordingVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionEventRecordingTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionFrameRecording_matchRefSome(const AzOptionFrameRecording* value, const AzFrameRecording** restrict out) {
+    const AzOptionFrameRecordingVariant_Some* casted = (const AzOptionFrameRecordingVariant_Some*)value;
+    bool valid = casted->tag == AzOptionFrameRecordingTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionFrameRecording_matchMutSome(AzOptionFrameRecording* restrict value, AzFrameRecording* restrict * restrict out) {
+    AzOptionFrameRecordingVariant_Some* restrict casted = (AzOptionFrameRecordingVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionFrameRecordingTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionCrashHandler_matchRefSome(const AzOptionCrashHandler* value, const AzCrashHandler** restrict out) {
+    const AzOptionCrashHandlerVariant_Some* casted = (const AzOptionCrashHandlerVariant_Some*)value;
+    bool valid = casted->tag == AzOptionCrashHandlerTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionCrashHandler_matchMutSome(AzOptionCrashHandler* restrict value, AzCrashHandler* restrict * restrict out) {
+    AzOptionCrashHandlerVariant_Some* restrict casted = (AzOptionCrashHandlerVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionCrashHandlerTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionThreadSendMsg_matchRefSome(const AzOptionThreadSendMsg* value, const AzThreadSendMsg** restrict out) {
     const AzOptionThreadSendMsgVariant_Some* casted = (const AzOptionThreadSendMsgVariant_Some*)value;
     bool valid = casted->tag == AzOptionThreadSendMsgTag_Some;
@@ -20790,6 +23697,20 @@ bool AzOptionInlineText_matchMutSome(AzOptionInlineText* restrict value, AzInlin
     return valid;
 }
 
+bool AzOptionTextLayout_matchRefSome(const AzOptionTextLayout* value, const AzTextLayout** restrict out) {
+    const AzOptionTextLayoutVariant_Some* casted = (const AzOptionTextLayoutVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTextLayoutTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextLayout_matchMutSome(AzOptionTextLayout* restrict value, AzTextLayout* restrict * restrict out) {
+    AzOptionTextLayoutVariant_Some* restrict casted = (AzOptionTextLayoutVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTextLayoutTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionLayoutPoint_matchRefSome(const AzOptionLayoutPoint* value, const AzLayoutPoint** restrict out) {
     const AzOptionLayoutPointVariant_Some* casted = (const AzOptionLayoutPointVariant_Some*)value;
     bool valid = casted->tag == AzOptionLayoutPointTag_Some;
@@ -20930,6 +23851,20 @@ bool AzOptionTaskBarIcon_matchMutSome(AzOptionTaskBarIcon* restrict value, AzTas
     return valid;
 }
 
+bool AzOptionRawWindowHandle_matchRefSome(const AzOptionRawWindowHandle* value, const AzRawWindowHandle** restrict out) {
+    const AzOptionRawWindowHandleVariant_Some* casted = (const AzOptionRawWindowHandleVariant_Some*)value;
+    bool valid = casted->tag == AzOptionRawWindowHandleTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionRawWindowHandle_matchMutSome(AzOptionRawWindowHandle* restrict value, AzRawWindowHandle* restrict * restrict out) {
+    AzOptionRawWindowHandleVariant_Some* restrict casted = (AzOptionRawWindowHandleVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionRawWindowHandleTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionHwndHandle_matchRefSome(const AzOptionHwndHandle* value, const Az*mut c_void** restrict out) {
     const AzOptionHwndHandleVariant_Some* casted = (const AzOptionHwndHandleVariant_Some*)value;
     bool valid = casted->tag == AzOptionHwndHandleTag_Some;
@@ -21814,6 +24749,30 @@ bool AzXmlStreamError_matchMutInvalidString(AzXmlStreamError* restrict value, Az
 
 
 
+/* Ownership and lifetimes
+ *
+ * - All Az* structs are plain C structs with a stable layout, they can be
+ *   created on the stack and passed by value (sizes are checked in the tests)
+ * - Passing a struct by value to a function moves it into the function: don't
+ *   use or delete it afterwards (i.e. AzDom_addChild(&dom, child) consumes `child`)
+ * - Structs that are returned by value are owned by the caller and have to be
+ *   released with the matching Az*_delete function (unless they are moved
+ *   into another function before that)
+ * - Pointer arguments (`AzDom* restrict`, `const AzCallbackInfo*`) are borrowed for
+ *   the duration of the call only, don't store them. In particular the *Info structs
+ *   passed to callbacks are only valid until the callback returns
+ * - Data shared with callbacks lives in an AzRefAny (see AZ_REFLECT below), which is
+ *   reference-counted: AzRefAny_deepCopy increases, AzRefAny_delete decreases the refcount
+ * - Types marked as "reference-counted" or "boxed" (AzFile, AzThread, ...) are opaque
+ *   handles, only access them through their functions
+ *
+ * Error handling
+ *
+ * Functions that can fail return an AzResult* or AzOption* union, check the `.tag`
+ * before accessing the `.Ok` / `.Some` payload. Errors are regular structs / enums
+ * (i.e. AzSvgParseError, AzEncodeImageError), so they can be matched on in a switch statement.
+ */
+
 /* Macro to turn a compile-time string into a compile-time AzString
  *
  * static AzString foo = AzString_fromConstStr(\"MyString\");
@@ -21858,12 +24817,18 @@ bool AzXmlStreamError_matchMutInvalidString(AzXmlStreamError* restrict value, Az
  * AzAppConfig foo = AzAppConfig_default();
  */
 #define AzAppConfig_default(...) { \
-    .layout_solver = AzLayoutSolverVersion_March2021, \
+    .layout_solver = AzLayoutSolver_Default, \
     .log_level = AzAppLogLevel_Error, \
     .enable_visual_panic_hook = true, \
     .enable_logging_on_panic = true, \
     .enable_tab_navigation = true, \
     .system_callbacks = AzSystemCallbacks_libraryInternal(), \
+    .user_idle_timeout = AzOptionDuration_None, \
+    .crash_handler = AzOptionCrashHandler_None, \
+    .jank_capture = AzOptionJankCapture_None, \
+    .event_recording = AzOptionEventRecording_None, \
+    .frame_recording = AzOptionFrameRecording_None, \
+    .hover_intent_delay = { .Some = { .tag = AzOptionDurationTag_Some, .payload = { .System = { .tag = AzDurationTag_System, .payload = { .secs = 0, .nanos = 500000000 } } } } }, \
 }
 
 /* Macro to generate reflection metadata for a given struct - for a "structName" of "foo", generates:
@@ -6,6 +6,12 @@ namespace dll {
     #include <cstdint>
     #include <cstddef>
     
+    struct RecordedFrame;
+    using FrameRecordingCallbackType = void(*)(RecordedFrame* const);
+    
+    struct CrashInfo;
+    using CrashHandlerType = void(*)(CrashInfo* const);
+    
     struct RefAny;
     struct LayoutCallbackInfo;
     struct StyledDom;
@@ -21,6 +27,17 @@ namespace dll {
     struct IFrameCallbackReturn;
     using IFrameCallbackType = IFrameCallbackReturn(*)(RefAny* restrict, IFrameCallbackInfo* restrict);
     
+    struct MeasureCallbackInfo;
+    struct LogicalSize;
+    using MeasureCallbackType = LogicalSize(*)(RefAny* restrict, MeasureCallbackInfo* const);
+    
+    struct ArrangeCallbackInfo;
+    using ArrangeCallbackType = void(*)(RefAny* restrict, ArrangeCallbackInfo* const);
+    
+    struct PaintCallbackInfo;
+    struct PaintPrimitiveVec;
+    using PaintCallbackType = PaintPrimitiveVec(*)(RefAny* restrict, PaintCallbackInfo* const);
+    
     struct RenderImageCallbackInfo;
     struct ImageRef;
     using RenderImageCallbackType = ImageRef(*)(RefAny* restrict, RenderImageCallbackInfo* restrict);
@@ -56,6 +73,9 @@ namespace dll {
     
     using TextInputOnFocusLostCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, TextInputState* const);
     
+    struct TextInputSpellingSuggestions;
+    using TextInputOnSpellingSuggestionsCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, TextInputState* const, TextInputSpellingSuggestions* const);
+    
     struct NumberInputState;
     using NumberInputOnValueChangeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, NumberInputState* const);
     
@@ -95,6 +115,8 @@ namespace dll {
     
     using DropDownOnChoiceChangeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, size_t);
     
+    using ToastOnActionCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict);
+    
     using ParsedFontDestructorFnType = void(*)(void* restrict);
     
     struct InstantPtr;
@@ -109,6 +131,21 @@ namespace dll {
     union Instant;
     using GetSystemTimeFnType = Instant(*)();
     
+    union OptionDuration;
+    using GetSystemIdleTimeFnType = OptionDuration(*)();
+    
+    struct StringVec;
+    using GetLogLinesFnType = StringVec(*)();
+    
+    enum VirtualKeyCode;
+    struct KeyboardModifiers;
+    union OptionChar;
+    using KeycodeToCharFnType = OptionChar(*)(VirtualKeyCode, KeyboardModifiers);
+    
+    struct String;
+    enum AppLogLevel;
+    using SetLogLevelFnType = void(*)(String, AppLogLevel);
+    
     using CheckThreadFinishedFnType = bool(*)(const void*);
     
     union ThreadSendMsg;
@@ -192,15 +229,23 @@ namespace dll {
     struct InlineWordVec;
     using InlineWordVecDestructorType = void(*)(InlineWordVec* restrict);
     
+    using PaintPrimitiveVecDestructorType = void(*)(PaintPrimitiveVec* restrict);
+    
     struct InlineGlyphVec;
     using InlineGlyphVecDestructorType = void(*)(InlineGlyphVec* restrict);
     
+    struct TextUnderlineVec;
+    using TextUnderlineVecDestructorType = void(*)(TextUnderlineVec* restrict);
+    
     struct InlineTextHitVec;
     using InlineTextHitVecDestructorType = void(*)(InlineTextHitVec* restrict);
     
     struct MonitorVec;
     using MonitorVecDestructorType = void(*)(MonitorVec* restrict);
     
+    struct TouchPointVec;
+    using TouchPointVecDestructorType = void(*)(TouchPointVec* restrict);
+    
     struct VideoModeVec;
     using VideoModeVecDestructorType = void(*)(VideoModeVec* restrict);
     
@@ -261,6 +306,12 @@ namespace dll {
     struct VirtualKeyCodeVec;
     using VirtualKeyCodeVecDestructorType = void(*)(VirtualKeyCodeVec* restrict);
     
+    struct ShortcutConflictVec;
+    using ShortcutConflictVecDestructorType = void(*)(ShortcutConflictVec* restrict);
+    
+    struct ShortcutVec;
+    using ShortcutVecDestructorType = void(*)(ShortcutVec* restrict);
+    
     struct CascadeInfoVec;
     using CascadeInfoVecDestructorType = void(*)(CascadeInfoVec* restrict);
     
@@ -300,7 +351,6 @@ namespace dll {
     struct GLintVec;
     using GLintVecDestructorType = void(*)(GLintVec* restrict);
     
-    struct StringVec;
     using StringVecDestructorType = void(*)(StringVec* restrict);
     
     struct StringPairVec;
@@ -348,10 +398,37 @@ namespace dll {
        Trace,
     };
     
+    struct FrameRecordingCallback {
+        FrameRecordingCallbackType cb;
+        FrameRecordingCallback& operator=(const FrameRecordingCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        FrameRecordingCallback(const FrameRecordingCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        FrameRecordingCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct CrashHandler {
+        CrashHandlerType cb;
+        CrashHandler& operator=(const CrashHandler&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CrashHandler(const CrashHandler&) = delete; /* disable copy constructor, use explicit .clone() */
+        CrashHandler() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class LayoutSolver {
        Default,
     };
     
+    enum class WindowBackgroundEffect {
+       None,
+       Blur,
+       Mica,
+       MicaAlt,
+       Acrylic,
+    };
+    
+    enum class ScrollbarMode {
+       Default,
+       Overlay,
+    };
+    
     enum class Vsync {
        Enabled,
        Disabled,
@@ -656,6 +733,21 @@ namespace dll {
        Cut,
     };
     
+    enum class Politeness {
+       Polite,
+       Assertive,
+    };
+    
+    enum class HapticKind {
+       Selection,
+       LightImpact,
+       MediumImpact,
+       HeavyImpact,
+       Success,
+       Warning,
+       Error,
+    };
+    
     enum class WindowFrame {
        Normal,
        Minimized,
@@ -663,6 +755,19 @@ namespace dll {
        Fullscreen,
     };
     
+    enum class FullscreenModeTag {
+       Borderless,
+       Exclusive,
+    };
+    
+    struct FullscreenModeVariant_Borderless { FullscreenModeTag tag; size_t payload; };
+    struct FullscreenModeVariant_Exclusive { FullscreenModeTag tag; size_t payload; };
+    union FullscreenMode {
+        FullscreenModeVariant_Borderless Borderless;
+        FullscreenModeVariant_Exclusive Exclusive;
+    };
+    
+    
     struct DebugState {
         bool  profiler_dbg;
         bool  render_target_dbg;
@@ -695,6 +800,15 @@ namespace dll {
         DebugState() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct KeyboardModifiers {
+        bool  shift;
+        bool  ctrl;
+        bool  alt;
+        bool  caps_lock;
+        KeyboardModifiers& operator=(const KeyboardModifiers&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        KeyboardModifiers() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class MouseCursorType {
        Default,
        Crosshair,
@@ -762,10 +876,19 @@ namespace dll {
        LightMode,
     };
     
-    struct TouchState {
-        uint8_t unused;
-        TouchState& operator=(const TouchState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        TouchState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    enum class InputModality {
+       Mouse,
+       Touch,
+       Pen,
+    };
+    
+    struct HdrCapabilities {
+        bool  supported;
+        bool  enabled;
+        uint32_t bits_per_color_channel;
+        float sdr_white_level;
+        HdrCapabilities& operator=(const HdrCapabilities&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        HdrCapabilities() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
     struct MarshaledLayoutCallbackInner {
@@ -819,6 +942,17 @@ namespace dll {
         PositionInfoInner() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class TextUnderlineStyle {
+       Solid,
+       Dotted,
+       Wavy,
+    };
+    
+    enum class CaretAnimation {
+       Blink,
+       Fade,
+    };
+    
     enum class AnimationRepeat {
        NoRepeat,
        Loop,
@@ -844,6 +978,24 @@ namespace dll {
         IFrameCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct MeasureCallback {
+        MeasureCallbackType cb;
+        MeasureCallback& operator=(const MeasureCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        MeasureCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ArrangeCallback {
+        ArrangeCallbackType cb;
+        ArrangeCallback& operator=(const ArrangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ArrangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct PaintCallback {
+        PaintCallbackType cb;
+        PaintCallback& operator=(const PaintCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        PaintCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct RenderImageCallback {
         RenderImageCallbackType cb;
         RenderImageCallback& operator=(const RenderImageCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -888,6 +1040,9 @@ namespace dll {
        LeftMouseUp,
        MiddleMouseUp,
        RightMouseUp,
+       DoubleClick,
+       TripleClick,
+       HoverIntent,
        MouseEnter,
        MouseLeave,
        Scroll,
@@ -899,6 +1054,21 @@ namespace dll {
        HoveredFileCancelled,
        FocusReceived,
        FocusLost,
+       UserIdle,
+       UserActive,
+       SessionEnding,
+       FullscreenEntered,
+       FullscreenExited,
+       WindowResized,
+       WindowMoved,
+       WindowFocusReceived,
+       WindowFocusLost,
+       DpiChanged,
+       Minimized,
+       Maximized,
+       Restored,
+       UserEvent,
+       InputModalityChanged,
     };
     
     enum class HoverEventFilter {
@@ -911,6 +1081,9 @@ namespace dll {
        LeftMouseUp,
        RightMouseUp,
        MiddleMouseUp,
+       DoubleClick,
+       TripleClick,
+       HoverIntent,
        MouseEnter,
        MouseLeave,
        Scroll,
@@ -938,6 +1111,8 @@ namespace dll {
        LeftMouseUp,
        RightMouseUp,
        MiddleMouseUp,
+       DoubleClick,
+       TripleClick,
        MouseEnter,
        MouseLeave,
        Scroll,
@@ -960,6 +1135,9 @@ namespace dll {
        LeftMouseUp,
        RightMouseUp,
        MiddleMouseUp,
+       DoubleClick,
+       TripleClick,
+       HoverIntent,
        MouseEnter,
        MouseLeave,
        Scroll,
@@ -981,6 +1159,19 @@ namespace dll {
        FocusLost,
        CloseRequested,
        ThemeChanged,
+       WindowFocusReceived,
+       WindowFocusLost,
+       UserIdle,
+       UserActive,
+       SessionEnding,
+       FullscreenEntered,
+       FullscreenExited,
+       DpiChanged,
+       Minimized,
+       Maximized,
+       Restored,
+       UserEvent,
+       InputModalityChanged,
     };
     
     enum class ComponentEventFilter {
@@ -996,6 +1187,47 @@ namespace dll {
        DeviceDisconnected,
     };
     
+    enum class FocusDirection {
+       Up,
+       Down,
+       Left,
+       Right,
+    };
+    
+    enum class InputMode {
+       Text,
+       None,
+       Numeric,
+       Decimal,
+       Tel,
+       Email,
+       Url,
+       Search,
+       Password,
+    };
+    
+    enum class NodeStateFlag {
+       Disabled,
+       Checked,
+       Selected,
+       Indeterminate,
+    };
+    
+    enum class OverlayPlacement {
+       TopStart,
+       Top,
+       TopEnd,
+       BottomStart,
+       Bottom,
+       BottomEnd,
+       LeftStart,
+       Left,
+       LeftEnd,
+       RightStart,
+       Right,
+       RightEnd,
+    };
+    
     enum class AccessibilityRole {
        TitleBar,
        MenuBar,
@@ -1123,6 +1355,12 @@ namespace dll {
        Disabled,
     };
     
+    struct CssMediaCondition {
+        uint8_t matching_styles;
+        CssMediaCondition& operator=(const CssMediaCondition&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssMediaCondition() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class NodeTypeKey {
        Body,
        Div,
@@ -1148,6 +1386,7 @@ namespace dll {
        LineHeight,
        WordSpacing,
        TabWidth,
+       Hyphens,
        Cursor,
        Display,
        Float,
@@ -1205,11 +1444,24 @@ namespace dll {
        BoxShadowTop,
        BoxShadowBottom,
        ScrollbarStyle,
+       ScrollbarWidth,
+       ScrollbarColor,
+       ScrollbarThumbHoverColor,
+       ScrollbarThumbActiveColor,
+       WindowDragRegion,
+       CaretAnimationDuration,
+       ScrollPadding,
+       Content,
+       CounterReset,
+       CounterIncrement,
+       OverflowAnchor,
        Opacity,
        Transform,
        TransformOrigin,
        PerspectiveOrigin,
        BackfaceVisibility,
+       Perspective,
+       TransformStyle,
        MixBlendMode,
        Filter,
        BackdropFilter,
@@ -1386,37 +1638,35 @@ namespace dll {
        Outset,
     };
     
-    enum class StyleCursor {
-       Alias,
-       AllScroll,
-       Cell,
-       ColResize,
-       ContextMenu,
-       Copy,
-       Crosshair,
-       Default,
-       EResize,
-       EwResize,
-       Grab,
-       Grabbing,
-       Help,
+    enum class StyleScrollbarWidth {
+       Auto,
+       Thin,
+       None,
+    };
+    
+    enum class StyleOverflowAnchor {
+       Auto,
+       None,
+    };
+    
+    enum class StyleWindowDragRegion {
+       None,
        Move,
-       NResize,
-       NsResize,
-       NeswResize,
-       NwseResize,
-       Pointer,
-       Progress,
-       RowResize,
-       SResize,
-       SeResize,
-       Text,
-       Unset,
-       VerticalText,
-       WResize,
-       Wait,
-       ZoomIn,
-       ZoomOut,
+       ResizeN,
+       ResizeNE,
+       ResizeE,
+       ResizeSE,
+       ResizeS,
+       ResizeSW,
+       ResizeW,
+       ResizeNW,
+       MaximizeButton,
+    };
+    
+    struct StyleCaretAnimationDuration {
+        uint32_t inner;
+        StyleCaretAnimationDuration& operator=(const StyleCaretAnimationDuration&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleCaretAnimationDuration() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
     enum class StyleBackfaceVisibility {
@@ -1424,12 +1674,23 @@ namespace dll {
        Visible,
     };
     
+    enum class StyleTransformStyle {
+       Flat,
+       Preserve3D,
+    };
+    
     enum class StyleTextAlign {
        Left,
        Center,
        Right,
     };
     
+    enum class StyleHyphens {
+       None,
+       Manual,
+       Auto,
+    };
+    
     struct Ribbon {
         int32_t tab_active;
         Ribbon& operator=(const Ribbon&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -1500,19 +1761,21 @@ namespace dll {
         TextInputOnFocusLostCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextInputOnSpellingSuggestionsCallback {
+        TextInputOnSpellingSuggestionsCallbackType cb;
+        TextInputOnSpellingSuggestionsCallback& operator=(const TextInputOnSpellingSuggestionsCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextInputOnSpellingSuggestionsCallback(const TextInputOnSpellingSuggestionsCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextInputOnSpellingSuggestionsCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class TextInputValid {
        Yes,
        No,
     };
     
-    struct NumberInputState {
-        float previous;
-        float number;
-        float min;
-        float max;
-        NumberInputState& operator=(const NumberInputState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        NumberInputState(const NumberInputState&) = delete; /* disable copy constructor, use explicit .clone() */
-        NumberInputState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    enum class NumberInputMode {
+       Integer,
+       Float,
     };
     
     struct NumberInputOnValueChangeCallback {
@@ -1678,6 +1941,19 @@ namespace dll {
         DropDownOnChoiceChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct ToastId {
+        size_t id;
+        ToastId& operator=(const ToastId&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ToastId() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ToastOnActionCallback {
+        ToastOnActionCallbackType cb;
+        ToastOnActionCallback& operator=(const ToastOnActionCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ToastOnActionCallback(const ToastOnActionCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        ToastOnActionCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NodeHierarchyItem {
         size_t parent;
         size_t previous_sibling;
@@ -1699,6 +1975,10 @@ namespace dll {
         bool  hover;
         bool  active;
         bool  focused;
+        bool  disabled;
+        bool  checked;
+        bool  selected;
+        bool  indeterminate;
         StyledNodeState& operator=(const StyledNodeState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         StyledNodeState() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
@@ -1732,6 +2012,17 @@ namespace dll {
         GlVoidPtrMut() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct GlQuirks {
+        bool  force_software_rendering;
+        bool  disable_subpixel_aa;
+        bool  disable_optimized_shaders;
+        bool  disable_program_cache;
+        bool  disable_multithreading;
+        bool  disable_partial_present;
+        GlQuirks& operator=(const GlQuirks&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GlQuirks() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct GlShaderPrecisionFormatReturn {
         int32_t _0;
         int32_t _1;
@@ -2208,6 +2499,14 @@ namespace dll {
         ThreadReceiver() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct EventLoopProxy {
+        void* ptr;
+        bool  run_destructor;
+        EventLoopProxy& operator=(const EventLoopProxy&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        EventLoopProxy(const EventLoopProxy&) = delete; /* disable copy constructor, use explicit .clone() */
+        EventLoopProxy() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct CreateThreadFn {
         CreateThreadFnType cb;
         CreateThreadFn& operator=(const CreateThreadFn&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -2222,6 +2521,34 @@ namespace dll {
         GetSystemTimeFn() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct GetSystemIdleTimeFn {
+        GetSystemIdleTimeFnType cb;
+        GetSystemIdleTimeFn& operator=(const GetSystemIdleTimeFn&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GetSystemIdleTimeFn(const GetSystemIdleTimeFn&) = delete; /* disable copy constructor, use explicit .clone() */
+        GetSystemIdleTimeFn() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct GetLogLinesFn {
+        GetLogLinesFnType cb;
+        GetLogLinesFn& operator=(const GetLogLinesFn&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GetLogLinesFn(const GetLogLinesFn&) = delete; /* disable copy constructor, use explicit .clone() */
+        GetLogLinesFn() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct KeycodeToCharFn {
+        KeycodeToCharFnType cb;
+        KeycodeToCharFn& operator=(const KeycodeToCharFn&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        KeycodeToCharFn(const KeycodeToCharFn&) = delete; /* disable copy constructor, use explicit .clone() */
+        KeycodeToCharFn() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SetLogLevelFn {
+        SetLogLevelFnType cb;
+        SetLogLevelFn& operator=(const SetLogLevelFn&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SetLogLevelFn(const SetLogLevelFn&) = delete; /* disable copy constructor, use explicit .clone() */
+        SetLogLevelFn() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct CheckThreadFinishedFn {
         CheckThreadFinishedFnType cb;
         CheckThreadFinishedFn& operator=(const CheckThreadFinishedFn&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -2614,6 +2941,22 @@ namespace dll {
     };
     
     
+    enum class PaintPrimitiveVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct PaintPrimitiveVecDestructorVariant_DefaultRust { PaintPrimitiveVecDestructorTag tag; };
+    struct PaintPrimitiveVecDestructorVariant_NoDestructor { PaintPrimitiveVecDestructorTag tag; };
+    struct PaintPrimitiveVecDestructorVariant_External { PaintPrimitiveVecDestructorTag tag; PaintPrimitiveVecDestructorType payload; };
+    union PaintPrimitiveVecDestructor {
+        PaintPrimitiveVecDestructorVariant_DefaultRust DefaultRust;
+        PaintPrimitiveVecDestructorVariant_NoDestructor NoDestructor;
+        PaintPrimitiveVecDestructorVariant_External External;
+    };
+    
+    
     enum class InlineGlyphVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -2630,6 +2973,22 @@ namespace dll {
     };
     
     
+    enum class TextUnderlineVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct TextUnderlineVecDestructorVariant_DefaultRust { TextUnderlineVecDestructorTag tag; };
+    struct TextUnderlineVecDestructorVariant_NoDestructor { TextUnderlineVecDestructorTag tag; };
+    struct TextUnderlineVecDestructorVariant_External { TextUnderlineVecDestructorTag tag; TextUnderlineVecDestructorType payload; };
+    union TextUnderlineVecDestructor {
+        TextUnderlineVecDestructorVariant_DefaultRust DefaultRust;
+        TextUnderlineVecDestructorVariant_NoDestructor NoDestructor;
+        TextUnderlineVecDestructorVariant_External External;
+    };
+    
+    
     enum class InlineTextHitVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -2662,6 +3021,22 @@ namespace dll {
     };
     
     
+    enum class TouchPointVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct TouchPointVecDestructorVariant_DefaultRust { TouchPointVecDestructorTag tag; };
+    struct TouchPointVecDestructorVariant_NoDestructor { TouchPointVecDestructorTag tag; };
+    struct TouchPointVecDestructorVariant_External { TouchPointVecDestructorTag tag; TouchPointVecDestructorType payload; };
+    union TouchPointVecDestructor {
+        TouchPointVecDestructorVariant_DefaultRust DefaultRust;
+        TouchPointVecDestructorVariant_NoDestructor NoDestructor;
+        TouchPointVecDestructorVariant_External External;
+    };
+    
+    
     enum class VideoModeVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -2982,6 +3357,38 @@ namespace dll {
     };
     
     
+    enum class ShortcutConflictVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct ShortcutConflictVecDestructorVariant_DefaultRust { ShortcutConflictVecDestructorTag tag; };
+    struct ShortcutConflictVecDestructorVariant_NoDestructor { ShortcutConflictVecDestructorTag tag; };
+    struct ShortcutConflictVecDestructorVariant_External { ShortcutConflictVecDestructorTag tag; ShortcutConflictVecDestructorType payload; };
+    union ShortcutConflictVecDestructor {
+        ShortcutConflictVecDestructorVariant_DefaultRust DefaultRust;
+        ShortcutConflictVecDestructorVariant_NoDestructor NoDestructor;
+        ShortcutConflictVecDestructorVariant_External External;
+    };
+    
+    
+    enum class ShortcutVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct ShortcutVecDestructorVariant_DefaultRust { ShortcutVecDestructorTag tag; };
+    struct ShortcutVecDestructorVariant_NoDestructor { ShortcutVecDestructorTag tag; };
+    struct ShortcutVecDestructorVariant_External { ShortcutVecDestructorTag tag; ShortcutVecDestructorType payload; };
+    union ShortcutVecDestructor {
+        ShortcutVecDestructorVariant_DefaultRust DefaultRust;
+        ShortcutVecDestructorVariant_NoDestructor NoDestructor;
+        ShortcutVecDestructorVariant_External External;
+    };
+    
+    
     enum class CascadeInfoVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -3477,11 +3884,31 @@ namespace dll {
     struct SystemCallbacks {
         CreateThreadFn create_thread_fn;
         GetSystemTimeFn get_system_time_fn;
+        GetSystemIdleTimeFn get_system_idle_time_fn;
+        GetLogLinesFn get_log_lines_fn;
+        SetLogLevelFn set_log_level_fn;
+        KeycodeToCharFn keycode_to_char_fn;
         SystemCallbacks& operator=(const SystemCallbacks&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         SystemCallbacks(const SystemCallbacks&) = delete; /* disable copy constructor, use explicit .clone() */
         SystemCallbacks() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class FirstFrameModeTag {
+       ShowImmediately,
+       HideUntilRendered,
+       SplashColor,
+    };
+    
+    struct FirstFrameModeVariant_ShowImmediately { FirstFrameModeTag tag; };
+    struct FirstFrameModeVariant_HideUntilRendered { FirstFrameModeTag tag; };
+    struct FirstFrameModeVariant_SplashColor { FirstFrameModeTag tag; ColorU payload; };
+    union FirstFrameMode {
+        FirstFrameModeVariant_ShowImmediately ShowImmediately;
+        FirstFrameModeVariant_HideUntilRendered HideUntilRendered;
+        FirstFrameModeVariant_SplashColor SplashColor;
+    };
+    
+    
     struct RendererOptions {
         Vsync vsync;
         Srgb srgb;
@@ -3563,12 +3990,17 @@ namespace dll {
         bool  has_decorations;
         bool  is_visible;
         bool  is_always_on_top;
+        bool  is_click_through;
+        bool  is_tool_window;
         bool  is_resizable;
         bool  has_focus;
         bool  has_extended_window_frame;
         bool  has_blur_behind_window;
         bool  smooth_scroll_enabled;
         bool  autotab_enabled;
+        bool  spatial_navigation_enabled;
+        bool  is_session_ending;
+        FullscreenMode fullscreen_mode;
         WindowFlags& operator=(const WindowFlags&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         WindowFlags() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
@@ -3615,6 +4047,14 @@ namespace dll {
     };
     
     
+    struct TouchPoint {
+        uint64_t id;
+        LogicalPosition position;
+        float force;
+        TouchPoint& operator=(const TouchPoint&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TouchPoint() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct VideoMode {
         LayoutSize size;
         uint16_t bit_depth;
@@ -3649,6 +4089,24 @@ namespace dll {
     };
     
     
+    struct NodeRect {
+        LogicalRect margin_box;
+        LogicalRect border_box;
+        LogicalRect padding_box;
+        LogicalRect content_box;
+        NodeRect& operator=(const NodeRect&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NodeRect() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ScrollInfo {
+        LogicalPosition scroll_position;
+        LogicalRect viewport;
+        LogicalSize content_size;
+        LogicalSize max_scroll;
+        ScrollInfo& operator=(const ScrollInfo&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ScrollInfo() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct HidpiAdjustedBounds {
         LogicalSize logical_size;
         float hidpi_factor;
@@ -3665,6 +4123,15 @@ namespace dll {
         InlineGlyph() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextUnderline {
+        size_t start;
+        size_t end;
+        ColorU color;
+        TextUnderlineStyle style;
+        TextUnderline& operator=(const TextUnderline&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextUnderline() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct InlineTextHit {
         OptionChar unicode_codepoint;
         LogicalPosition hit_relative_to_inline_text;
@@ -3703,6 +4170,33 @@ namespace dll {
         IFrameCallbackInfo() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct MeasureCallbackInfo {
+        LogicalSize available_size;
+        bool  width_is_final;
+        MeasureCallbackInfo& operator=(const MeasureCallbackInfo&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        MeasureCallbackInfo() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ArrangeCallbackInfo {
+        NodeRect bounds;
+        ArrangeCallbackInfo& operator=(const ArrangeCallbackInfo&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ArrangeCallbackInfo() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct PaintCallbackInfo {
+        LogicalSize size;
+        PaintCallbackInfo& operator=(const PaintCallbackInfo&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        PaintCallbackInfo() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct PaintRect {
+        LogicalRect bounds;
+        ColorU color;
+        float border_radius;
+        PaintRect& operator=(const PaintRect&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        PaintRect() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct TimerCallbackReturn {
         Update should_update;
         TerminateTimer should_terminate;
@@ -3724,6 +4218,7 @@ namespace dll {
     struct IFrameNode {
         IFrameCallback callback;
         RefAny data;
+        FloatValue reinvoke_threshold;
         IFrameNode& operator=(const IFrameNode&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         IFrameNode(const IFrameNode&) = delete; /* disable copy constructor, use explicit .clone() */
         IFrameNode() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -3782,6 +4277,28 @@ namespace dll {
     };
     
     
+    struct SystemColors {
+        ColorU canvas;
+        ColorU canvas_text;
+        ColorU link_text;
+        ColorU gray_text;
+        ColorU highlight;
+        ColorU highlight_text;
+        ColorU button_face;
+        ColorU button_text;
+        SystemColors& operator=(const SystemColors&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SystemColors() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SystemStyle {
+        bool  forced_colors;
+        bool  prefers_reduced_motion;
+        bool  pointer_coarse;
+        SystemColors colors;
+        SystemStyle& operator=(const SystemStyle&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SystemStyle() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct PixelValue {
         SizeMetric metric;
         FloatValue number;
@@ -4177,6 +4694,31 @@ namespace dll {
         LayoutBorderTopWidth() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct StyleScrollbarColor {
+        ColorU thumb;
+        ColorU track;
+        StyleScrollbarColor& operator=(const StyleScrollbarColor&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleScrollbarColor() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleScrollbarThumbHoverColor {
+        ColorU inner;
+        StyleScrollbarThumbHoverColor& operator=(const StyleScrollbarThumbHoverColor&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleScrollbarThumbHoverColor() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleScrollbarThumbActiveColor {
+        ColorU inner;
+        StyleScrollbarThumbActiveColor& operator=(const StyleScrollbarThumbActiveColor&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleScrollbarThumbActiveColor() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleScrollPadding {
+        PixelValue inner;
+        StyleScrollPadding& operator=(const StyleScrollPadding&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleScrollPadding() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StyleFontSize {
         PixelValue inner;
         StyleFontSize& operator=(const StyleFontSize&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -4221,6 +4763,12 @@ namespace dll {
         StylePerspectiveOrigin() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct StylePerspective {
+        PixelValue inner;
+        StylePerspective& operator=(const StylePerspective&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StylePerspective() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StyleTransformMatrix2D {
         PixelValue a;
         PixelValue b;
@@ -4993,7 +5541,7 @@ namespace dll {
     };
     
     
-    enum class StyleBorderBottomColorValueTag {
+    enum class StyleScrollbarWidthValueTag {
        Auto,
        None,
        Inherit,
@@ -5001,15 +5549,191 @@ namespace dll {
        Exact,
     };
     
-    struct StyleBorderBottomColorValueVariant_Auto { StyleBorderBottomColorValueTag tag; };
-    struct StyleBorderBottomColorValueVariant_None { StyleBorderBottomColorValueTag tag; };
-    struct StyleBorderBottomColorValueVariant_Inherit { StyleBorderBottomColorValueTag tag; };
-    struct StyleBorderBottomColorValueVariant_Initial { StyleBorderBottomColorValueTag tag; };
-    struct StyleBorderBottomColorValueVariant_Exact { StyleBorderBottomColorValueTag tag; StyleBorderBottomColor payload; };
-    union StyleBorderBottomColorValue {
-        StyleBorderBottomColorValueVariant_Auto Auto;
-        StyleBorderBottomColorValueVariant_None None;
-        StyleBorderBottomColorValueVariant_Inherit Inherit;
+    struct StyleScrollbarWidthValueVariant_Auto { StyleScrollbarWidthValueTag tag; };
+    struct StyleScrollbarWidthValueVariant_None { StyleScrollbarWidthValueTag tag; };
+    struct StyleScrollbarWidthValueVariant_Inherit { StyleScrollbarWidthValueTag tag; };
+    struct StyleScrollbarWidthValueVariant_Initial { StyleScrollbarWidthValueTag tag; };
+    struct StyleScrollbarWidthValueVariant_Exact { StyleScrollbarWidthValueTag tag; StyleScrollbarWidth payload; };
+    union StyleScrollbarWidthValue {
+        StyleScrollbarWidthValueVariant_Auto Auto;
+        StyleScrollbarWidthValueVariant_None None;
+        StyleScrollbarWidthValueVariant_Inherit Inherit;
+        StyleScrollbarWidthValueVariant_Initial Initial;
+        StyleScrollbarWidthValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleScrollbarColorValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleScrollbarColorValueVariant_Auto { StyleScrollbarColorValueTag tag; };
+    struct StyleScrollbarColorValueVariant_None { StyleScrollbarColorValueTag tag; };
+    struct StyleScrollbarColorValueVariant_Inherit { StyleScrollbarColorValueTag tag; };
+    struct StyleScrollbarColorValueVariant_Initial { StyleScrollbarColorValueTag tag; };
+    struct StyleScrollbarColorValueVariant_Exact { StyleScrollbarColorValueTag tag; StyleScrollbarColor payload; };
+    union StyleScrollbarColorValue {
+        StyleScrollbarColorValueVariant_Auto Auto;
+        StyleScrollbarColorValueVariant_None None;
+        StyleScrollbarColorValueVariant_Inherit Inherit;
+        StyleScrollbarColorValueVariant_Initial Initial;
+        StyleScrollbarColorValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleScrollbarThumbHoverColorValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleScrollbarThumbHoverColorValueVariant_Auto { StyleScrollbarThumbHoverColorValueTag tag; };
+    struct StyleScrollbarThumbHoverColorValueVariant_None { StyleScrollbarThumbHoverColorValueTag tag; };
+    struct StyleScrollbarThumbHoverColorValueVariant_Inherit { StyleScrollbarThumbHoverColorValueTag tag; };
+    struct StyleScrollbarThumbHoverColorValueVariant_Initial { StyleScrollbarThumbHoverColorValueTag tag; };
+    struct StyleScrollbarThumbHoverColorValueVariant_Exact { StyleScrollbarThumbHoverColorValueTag tag; StyleScrollbarThumbHoverColor payload; };
+    union StyleScrollbarThumbHoverColorValue {
+        StyleScrollbarThumbHoverColorValueVariant_Auto Auto;
+        StyleScrollbarThumbHoverColorValueVariant_None None;
+        StyleScrollbarThumbHoverColorValueVariant_Inherit Inherit;
+        StyleScrollbarThumbHoverColorValueVariant_Initial Initial;
+        StyleScrollbarThumbHoverColorValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleScrollbarThumbActiveColorValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleScrollbarThumbActiveColorValueVariant_Auto { StyleScrollbarThumbActiveColorValueTag tag; };
+    struct StyleScrollbarThumbActiveColorValueVariant_None { StyleScrollbarThumbActiveColorValueTag tag; };
+    struct StyleScrollbarThumbActiveColorValueVariant_Inherit { StyleScrollbarThumbActiveColorValueTag tag; };
+    struct StyleScrollbarThumbActiveColorValueVariant_Initial { StyleScrollbarThumbActiveColorValueTag tag; };
+    struct StyleScrollbarThumbActiveColorValueVariant_Exact { StyleScrollbarThumbActiveColorValueTag tag; StyleScrollbarThumbActiveColor payload; };
+    union StyleScrollbarThumbActiveColorValue {
+        StyleScrollbarThumbActiveColorValueVariant_Auto Auto;
+        StyleScrollbarThumbActiveColorValueVariant_None None;
+        StyleScrollbarThumbActiveColorValueVariant_Inherit Inherit;
+        StyleScrollbarThumbActiveColorValueVariant_Initial Initial;
+        StyleScrollbarThumbActiveColorValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleWindowDragRegionValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleWindowDragRegionValueVariant_Auto { StyleWindowDragRegionValueTag tag; };
+    struct StyleWindowDragRegionValueVariant_None { StyleWindowDragRegionValueTag tag; };
+    struct StyleWindowDragRegionValueVariant_Inherit { StyleWindowDragRegionValueTag tag; };
+    struct StyleWindowDragRegionValueVariant_Initial { StyleWindowDragRegionValueTag tag; };
+    struct StyleWindowDragRegionValueVariant_Exact { StyleWindowDragRegionValueTag tag; StyleWindowDragRegion payload; };
+    union StyleWindowDragRegionValue {
+        StyleWindowDragRegionValueVariant_Auto Auto;
+        StyleWindowDragRegionValueVariant_None None;
+        StyleWindowDragRegionValueVariant_Inherit Inherit;
+        StyleWindowDragRegionValueVariant_Initial Initial;
+        StyleWindowDragRegionValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleCaretAnimationDurationValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleCaretAnimationDurationValueVariant_Auto { StyleCaretAnimationDurationValueTag tag; };
+    struct StyleCaretAnimationDurationValueVariant_None { StyleCaretAnimationDurationValueTag tag; };
+    struct StyleCaretAnimationDurationValueVariant_Inherit { StyleCaretAnimationDurationValueTag tag; };
+    struct StyleCaretAnimationDurationValueVariant_Initial { StyleCaretAnimationDurationValueTag tag; };
+    struct StyleCaretAnimationDurationValueVariant_Exact { StyleCaretAnimationDurationValueTag tag; StyleCaretAnimationDuration payload; };
+    union StyleCaretAnimationDurationValue {
+        StyleCaretAnimationDurationValueVariant_Auto Auto;
+        StyleCaretAnimationDurationValueVariant_None None;
+        StyleCaretAnimationDurationValueVariant_Inherit Inherit;
+        StyleCaretAnimationDurationValueVariant_Initial Initial;
+        StyleCaretAnimationDurationValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleScrollPaddingValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleScrollPaddingValueVariant_Auto { StyleScrollPaddingValueTag tag; };
+    struct StyleScrollPaddingValueVariant_None { StyleScrollPaddingValueTag tag; };
+    struct StyleScrollPaddingValueVariant_Inherit { StyleScrollPaddingValueTag tag; };
+    struct StyleScrollPaddingValueVariant_Initial { StyleScrollPaddingValueTag tag; };
+    struct StyleScrollPaddingValueVariant_Exact { StyleScrollPaddingValueTag tag; StyleScrollPadding payload; };
+    union StyleScrollPaddingValue {
+        StyleScrollPaddingValueVariant_Auto Auto;
+        StyleScrollPaddingValueVariant_None None;
+        StyleScrollPaddingValueVariant_Inherit Inherit;
+        StyleScrollPaddingValueVariant_Initial Initial;
+        StyleScrollPaddingValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleOverflowAnchorValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleOverflowAnchorValueVariant_Auto { StyleOverflowAnchorValueTag tag; };
+    struct StyleOverflowAnchorValueVariant_None { StyleOverflowAnchorValueTag tag; };
+    struct StyleOverflowAnchorValueVariant_Inherit { StyleOverflowAnchorValueTag tag; };
+    struct StyleOverflowAnchorValueVariant_Initial { StyleOverflowAnchorValueTag tag; };
+    struct StyleOverflowAnchorValueVariant_Exact { StyleOverflowAnchorValueTag tag; StyleOverflowAnchor payload; };
+    union StyleOverflowAnchorValue {
+        StyleOverflowAnchorValueVariant_Auto Auto;
+        StyleOverflowAnchorValueVariant_None None;
+        StyleOverflowAnchorValueVariant_Inherit Inherit;
+        StyleOverflowAnchorValueVariant_Initial Initial;
+        StyleOverflowAnchorValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleBorderBottomColorValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleBorderBottomColorValueVariant_Auto { StyleBorderBottomColorValueTag tag; };
+    struct StyleBorderBottomColorValueVariant_None { StyleBorderBottomColorValueTag tag; };
+    struct StyleBorderBottomColorValueVariant_Inherit { StyleBorderBottomColorValueTag tag; };
+    struct StyleBorderBottomColorValueVariant_Initial { StyleBorderBottomColorValueTag tag; };
+    struct StyleBorderBottomColorValueVariant_Exact { StyleBorderBottomColorValueTag tag; StyleBorderBottomColor payload; };
+    union StyleBorderBottomColorValue {
+        StyleBorderBottomColorValueVariant_Auto Auto;
+        StyleBorderBottomColorValueVariant_None None;
+        StyleBorderBottomColorValueVariant_Inherit Inherit;
         StyleBorderBottomColorValueVariant_Initial Initial;
         StyleBorderBottomColorValueVariant_Exact Exact;
     };
@@ -5345,28 +6069,6 @@ namespace dll {
     };
     
     
-    enum class StyleCursorValueTag {
-       Auto,
-       None,
-       Inherit,
-       Initial,
-       Exact,
-    };
-    
-    struct StyleCursorValueVariant_Auto { StyleCursorValueTag tag; };
-    struct StyleCursorValueVariant_None { StyleCursorValueTag tag; };
-    struct StyleCursorValueVariant_Inherit { StyleCursorValueTag tag; };
-    struct StyleCursorValueVariant_Initial { StyleCursorValueTag tag; };
-    struct StyleCursorValueVariant_Exact { StyleCursorValueTag tag; StyleCursor payload; };
-    union StyleCursorValue {
-        StyleCursorValueVariant_Auto Auto;
-        StyleCursorValueVariant_None None;
-        StyleCursorValueVariant_Inherit Inherit;
-        StyleCursorValueVariant_Initial Initial;
-        StyleCursorValueVariant_Exact Exact;
-    };
-    
-    
     enum class StyleFontSizeValueTag {
        Auto,
        None,
@@ -5455,6 +6157,28 @@ namespace dll {
     };
     
     
+    enum class StyleHyphensValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleHyphensValueVariant_Auto { StyleHyphensValueTag tag; };
+    struct StyleHyphensValueVariant_None { StyleHyphensValueTag tag; };
+    struct StyleHyphensValueVariant_Inherit { StyleHyphensValueTag tag; };
+    struct StyleHyphensValueVariant_Initial { StyleHyphensValueTag tag; };
+    struct StyleHyphensValueVariant_Exact { StyleHyphensValueTag tag; StyleHyphens payload; };
+    union StyleHyphensValue {
+        StyleHyphensValueVariant_Auto Auto;
+        StyleHyphensValueVariant_None None;
+        StyleHyphensValueVariant_Inherit Inherit;
+        StyleHyphensValueVariant_Initial Initial;
+        StyleHyphensValueVariant_Exact Exact;
+    };
+    
+    
     enum class StyleTextAlignValueTag {
        Auto,
        None,
@@ -5609,6 +6333,50 @@ namespace dll {
     };
     
     
+    enum class StylePerspectiveValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StylePerspectiveValueVariant_Auto { StylePerspectiveValueTag tag; };
+    struct StylePerspectiveValueVariant_None { StylePerspectiveValueTag tag; };
+    struct StylePerspectiveValueVariant_Inherit { StylePerspectiveValueTag tag; };
+    struct StylePerspectiveValueVariant_Initial { StylePerspectiveValueTag tag; };
+    struct StylePerspectiveValueVariant_Exact { StylePerspectiveValueTag tag; StylePerspective payload; };
+    union StylePerspectiveValue {
+        StylePerspectiveValueVariant_Auto Auto;
+        StylePerspectiveValueVariant_None None;
+        StylePerspectiveValueVariant_Inherit Inherit;
+        StylePerspectiveValueVariant_Initial Initial;
+        StylePerspectiveValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleTransformStyleValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleTransformStyleValueVariant_Auto { StyleTransformStyleValueTag tag; };
+    struct StyleTransformStyleValueVariant_None { StyleTransformStyleValueTag tag; };
+    struct StyleTransformStyleValueVariant_Inherit { StyleTransformStyleValueTag tag; };
+    struct StyleTransformStyleValueVariant_Initial { StyleTransformStyleValueTag tag; };
+    struct StyleTransformStyleValueVariant_Exact { StyleTransformStyleValueTag tag; StyleTransformStyle payload; };
+    union StyleTransformStyleValue {
+        StyleTransformStyleValueVariant_Auto Auto;
+        StyleTransformStyleValueVariant_None None;
+        StyleTransformStyleValueVariant_Inherit Inherit;
+        StyleTransformStyleValueVariant_Initial Initial;
+        StyleTransformStyleValueVariant_Exact Exact;
+    };
+    
+    
     enum class StyleMixBlendModeValueTag {
        Auto,
        None,
@@ -5707,6 +6475,14 @@ namespace dll {
         TextInputOnFocusLost() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextInputOnSpellingSuggestions {
+        RefAny data;
+        TextInputOnSpellingSuggestionsCallback callback;
+        TextInputOnSpellingSuggestions& operator=(const TextInputOnSpellingSuggestions&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextInputOnSpellingSuggestions(const TextInputOnSpellingSuggestions&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextInputOnSpellingSuggestions() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct OnTextInputReturn {
         Update update;
         TextInputValid valid;
@@ -5715,6 +6491,20 @@ namespace dll {
         OnTextInputReturn() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct NumberInputState {
+        double previous;
+        double number;
+        double min;
+        double max;
+        double step;
+        NumberInputMode mode;
+        uint32_t decimal_separator;
+        bool  is_valid;
+        NumberInputState& operator=(const NumberInputState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NumberInputState(const NumberInputState&) = delete; /* disable copy constructor, use explicit .clone() */
+        NumberInputState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NumberInputOnValueChange {
         RefAny data;
         NumberInputOnValueChangeCallback callback;
@@ -5851,6 +6641,14 @@ namespace dll {
         DropDownOnChoiceChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct ToastOnAction {
+        RefAny data;
+        ToastOnActionCallback callback;
+        ToastOnAction& operator=(const ToastOnAction&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ToastOnAction(const ToastOnAction&) = delete; /* disable copy constructor, use explicit .clone() */
+        ToastOnAction() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct ParentWithNodeDepth {
         size_t depth;
         NodeId node_id;
@@ -6119,6 +6917,16 @@ namespace dll {
         InlineGlyphVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextUnderlineVec {
+        TextUnderline* ptr;
+        size_t len;
+        size_t cap;
+        TextUnderlineVecDestructor destructor;
+        TextUnderlineVec& operator=(const TextUnderlineVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextUnderlineVec(const TextUnderlineVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextUnderlineVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct InlineTextHitVec {
         InlineTextHit* ptr;
         size_t len;
@@ -6129,6 +6937,16 @@ namespace dll {
         InlineTextHitVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TouchPointVec {
+        TouchPoint* ptr;
+        size_t len;
+        size_t cap;
+        TouchPointVecDestructor destructor;
+        TouchPointVec& operator=(const TouchPointVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TouchPointVec(const TouchPointVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TouchPointVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct VideoModeVec {
         VideoMode* ptr;
         size_t len;
@@ -6428,6 +7246,19 @@ namespace dll {
     };
     
     
+    enum class OptionToastOnActionTag {
+       None,
+       Some,
+    };
+    
+    struct OptionToastOnActionVariant_None { OptionToastOnActionTag tag; };
+    struct OptionToastOnActionVariant_Some { OptionToastOnActionTag tag; ToastOnAction payload; };
+    union OptionToastOnAction {
+        OptionToastOnActionVariant_None None;
+        OptionToastOnActionVariant_Some Some;
+    };
+    
+    
     enum class OptionNodeGraphOnNodeAddedTag {
        None,
        Some,
@@ -6636,6 +7467,19 @@ namespace dll {
     };
     
     
+    enum class OptionTextInputOnSpellingSuggestionsTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTextInputOnSpellingSuggestionsVariant_None { OptionTextInputOnSpellingSuggestionsTag tag; };
+    struct OptionTextInputOnSpellingSuggestionsVariant_Some { OptionTextInputOnSpellingSuggestionsTag tag; TextInputOnSpellingSuggestions payload; };
+    union OptionTextInputOnSpellingSuggestions {
+        OptionTextInputOnSpellingSuggestionsVariant_None None;
+        OptionTextInputOnSpellingSuggestionsVariant_Some Some;
+    };
+    
+    
     enum class OptionTextInputSelectionTag {
        None,
        Some,
@@ -6714,6 +7558,32 @@ namespace dll {
     };
     
     
+    enum class OptionNodeRectTag {
+       None,
+       Some,
+    };
+    
+    struct OptionNodeRectVariant_None { OptionNodeRectTag tag; };
+    struct OptionNodeRectVariant_Some { OptionNodeRectTag tag; NodeRect payload; };
+    union OptionNodeRect {
+        OptionNodeRectVariant_None None;
+        OptionNodeRectVariant_Some Some;
+    };
+    
+    
+    enum class OptionScrollInfoTag {
+       None,
+       Some,
+    };
+    
+    struct OptionScrollInfoVariant_None { OptionScrollInfoTag tag; };
+    struct OptionScrollInfoVariant_Some { OptionScrollInfoTag tag; ScrollInfo payload; };
+    union OptionScrollInfo {
+        OptionScrollInfoVariant_None None;
+        OptionScrollInfoVariant_Some Some;
+    };
+    
+    
     enum class OptionTimerIdTag {
        None,
        Some,
@@ -6844,6 +7714,32 @@ namespace dll {
     };
     
     
+    enum class OptionPaintCallbackTag {
+       None,
+       Some,
+    };
+    
+    struct OptionPaintCallbackVariant_None { OptionPaintCallbackTag tag; };
+    struct OptionPaintCallbackVariant_Some { OptionPaintCallbackTag tag; PaintCallback payload; };
+    union OptionPaintCallback {
+        OptionPaintCallbackVariant_None None;
+        OptionPaintCallbackVariant_Some Some;
+    };
+    
+    
+    enum class OptionCrashHandlerTag {
+       None,
+       Some,
+    };
+    
+    struct OptionCrashHandlerVariant_None { OptionCrashHandlerTag tag; };
+    struct OptionCrashHandlerVariant_Some { OptionCrashHandlerTag tag; CrashHandler payload; };
+    union OptionCrashHandler {
+        OptionCrashHandlerVariant_None None;
+        OptionCrashHandlerVariant_Some Some;
+    };
+    
+    
     enum class OptionThreadSendMsgTag {
        None,
        Some,
@@ -6974,6 +7870,19 @@ namespace dll {
     };
     
     
+    enum class OptionRawWindowHandleTag {
+       None,
+       Some,
+    };
+    
+    struct OptionRawWindowHandleVariant_None { OptionRawWindowHandleTag tag; };
+    struct OptionRawWindowHandleVariant_Some { OptionRawWindowHandleTag tag; RawWindowHandle payload; };
+    union OptionRawWindowHandle {
+        OptionRawWindowHandleVariant_None None;
+        OptionRawWindowHandleVariant_Some Some;
+    };
+    
+    
     enum class OptionLogicalPositionTag {
        None,
        Some,
@@ -7168,16 +8077,36 @@ namespace dll {
         InvalidSpaceError() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct AppConfig {
-        LayoutSolver layout_solver;
-        AppLogLevel log_level;
-        bool  enable_visual_panic_hook;
-        bool  enable_logging_on_panic;
-        bool  enable_tab_navigation;
-        SystemCallbacks system_callbacks;
-        AppConfig& operator=(const AppConfig&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        AppConfig(const AppConfig&) = delete; /* disable copy constructor, use explicit .clone() */
-        AppConfig() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    struct SplashOptions {
+        OptionImageRef logo;
+        LogicalSize size;
+        ColorU background_color;
+        ColorU progress_bar_color;
+        Duration cross_fade;
+        SplashOptions& operator=(const SplashOptions&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplashOptions(const SplashOptions&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplashOptions() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct RecordedFrame {
+        RawWindowHandle window_handle;
+        size_t frame_index;
+        Duration timestamp;
+        Duration duration;
+        ImageRef image;
+        RecordedFrame& operator=(const RecordedFrame&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RecordedFrame(const RecordedFrame&) = delete; /* disable copy constructor, use explicit .clone() */
+        RecordedFrame() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ScrollbarConfig {
+        ScrollbarMode mode;
+        float min_thumb_length;
+        float hit_area_margin;
+        OptionDuration auto_hide_delay;
+        Duration auto_hide_fade_duration;
+        ScrollbarConfig& operator=(const ScrollbarConfig&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ScrollbarConfig() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
     struct SmallWindowIconBytes {
@@ -7222,6 +8151,10 @@ namespace dll {
         uint32_t dpi;
         OptionLogicalSize min_dimensions;
         OptionLogicalSize max_dimensions;
+        bool  min_dimensions_from_content;
+        OptionLogicalSize resize_increments;
+        OptionF32 aspect_ratio_lock;
+        float zoom;
         WindowSize& operator=(const WindowSize&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         WindowSize() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
@@ -7231,6 +8164,9 @@ namespace dll {
         OptionVirtualKeyCode current_virtual_keycode;
         VirtualKeyCodeVec pressed_virtual_keycodes;
         ScanCodeVec pressed_scancodes;
+        bool  caps_lock_enabled;
+        bool  num_lock_enabled;
+        bool  scroll_lock_enabled;
         KeyboardState& operator=(const KeyboardState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         KeyboardState(const KeyboardState&) = delete; /* disable copy constructor, use explicit .clone() */
         KeyboardState() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -7243,6 +8179,7 @@ namespace dll {
         bool  left_down;
         bool  right_down;
         bool  middle_down;
+        uint32_t click_count;
         OptionF32 scroll_x;
         OptionF32 scroll_y;
         MouseState& operator=(const MouseState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -7250,6 +8187,14 @@ namespace dll {
         MouseState() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TouchState {
+        TouchPointVec touches;
+        InputModality input_modality;
+        TouchState& operator=(const TouchState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TouchState(const TouchState&) = delete; /* disable copy constructor, use explicit .clone() */
+        TouchState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct MarshaledLayoutCallback {
         RefAny marshal_data;
         MarshaledLayoutCallbackInner cb;
@@ -7305,6 +8250,16 @@ namespace dll {
     };
     
     
+    enum class PaintPrimitiveTag {
+       Rect,
+    };
+    
+    struct PaintPrimitiveVariant_Rect { PaintPrimitiveTag tag; PaintRect payload; };
+    union PaintPrimitive {
+        PaintPrimitiveVariant_Rect Rect;
+    };
+    
+    
     struct RenderImageCallbackInfo {
         DomNodeId callback_node_id;
         HidpiAdjustedBounds bounds;
@@ -7336,6 +8291,16 @@ namespace dll {
         LayoutCallbackInfo() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CustomLayoutNode {
+        MeasureCallback measure;
+        ArrangeCallback arrange;
+        OptionPaintCallback paint;
+        RefAny data;
+        CustomLayoutNode& operator=(const CustomLayoutNode&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CustomLayoutNode(const CustomLayoutNode&) = delete; /* disable copy constructor, use explicit .clone() */
+        CustomLayoutNode() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class EventFilterTag {
        Hover,
        Not,
@@ -7377,6 +8342,24 @@ namespace dll {
         VirtualKeyCodeCombo() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct Shortcut {
+        VirtualKeyCodeCombo keys;
+        Callback callback;
+        RefAny data;
+        Shortcut& operator=(const Shortcut&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Shortcut(const Shortcut&) = delete; /* disable copy constructor, use explicit .clone() */
+        Shortcut() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ShortcutConflict {
+        VirtualKeyCodeCombo keys;
+        size_t existing;
+        size_t conflicting;
+        ShortcutConflict& operator=(const ShortcutConflict&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ShortcutConflict(const ShortcutConflict&) = delete; /* disable copy constructor, use explicit .clone() */
+        ShortcutConflict() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class CssPathPseudoSelectorTag {
        First,
        Last,
@@ -7384,6 +8367,12 @@ namespace dll {
        Hover,
        Active,
        Focus,
+       Disabled,
+       Checked,
+       Selected,
+       Indeterminate,
+       Before,
+       After,
     };
     
     struct CssPathPseudoSelectorVariant_First { CssPathPseudoSelectorTag tag; };
@@ -7392,6 +8381,12 @@ namespace dll {
     struct CssPathPseudoSelectorVariant_Hover { CssPathPseudoSelectorTag tag; };
     struct CssPathPseudoSelectorVariant_Active { CssPathPseudoSelectorTag tag; };
     struct CssPathPseudoSelectorVariant_Focus { CssPathPseudoSelectorTag tag; };
+    struct CssPathPseudoSelectorVariant_Disabled { CssPathPseudoSelectorTag tag; };
+    struct CssPathPseudoSelectorVariant_Checked { CssPathPseudoSelectorTag tag; };
+    struct CssPathPseudoSelectorVariant_Selected { CssPathPseudoSelectorTag tag; };
+    struct CssPathPseudoSelectorVariant_Indeterminate { CssPathPseudoSelectorTag tag; };
+    struct CssPathPseudoSelectorVariant_Before { CssPathPseudoSelectorTag tag; };
+    struct CssPathPseudoSelectorVariant_After { CssPathPseudoSelectorTag tag; };
     union CssPathPseudoSelector {
         CssPathPseudoSelectorVariant_First First;
         CssPathPseudoSelectorVariant_Last Last;
@@ -7399,6 +8394,12 @@ namespace dll {
         CssPathPseudoSelectorVariant_Hover Hover;
         CssPathPseudoSelectorVariant_Active Active;
         CssPathPseudoSelectorVariant_Focus Focus;
+        CssPathPseudoSelectorVariant_Disabled Disabled;
+        CssPathPseudoSelectorVariant_Checked Checked;
+        CssPathPseudoSelectorVariant_Selected Selected;
+        CssPathPseudoSelectorVariant_Indeterminate Indeterminate;
+        CssPathPseudoSelectorVariant_Before Before;
+        CssPathPseudoSelectorVariant_After After;
     };
     
     
@@ -7645,15 +8646,6 @@ namespace dll {
         CheckBoxStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct NumberInputStateWrapper {
-        NumberInputState inner;
-        OptionNumberInputOnValueChange on_value_change;
-        OptionNumberInputOnFocusLost on_focus_lost;
-        NumberInputStateWrapper& operator=(const NumberInputStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        NumberInputStateWrapper(const NumberInputStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
-        NumberInputStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
-    };
-    
     struct NodeGraphCallbacks {
         OptionNodeGraphOnNodeAdded on_node_added;
         OptionNodeGraphOnNodeRemoved on_node_removed;
@@ -7931,6 +8923,16 @@ namespace dll {
         TessellatedColoredSvgNodeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct PaintPrimitiveVec {
+        PaintPrimitive* ptr;
+        size_t len;
+        size_t cap;
+        PaintPrimitiveVecDestructor destructor;
+        PaintPrimitiveVec& operator=(const PaintPrimitiveVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        PaintPrimitiveVec(const PaintPrimitiveVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        PaintPrimitiveVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StyleTransformVec {
         StyleTransform* ptr;
         size_t len;
@@ -7951,6 +8953,26 @@ namespace dll {
         SvgPathElementVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct ShortcutConflictVec {
+        ShortcutConflict* ptr;
+        size_t len;
+        size_t cap;
+        ShortcutConflictVecDestructor destructor;
+        ShortcutConflictVec& operator=(const ShortcutConflictVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ShortcutConflictVec(const ShortcutConflictVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        ShortcutConflictVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ShortcutVec {
+        Shortcut* ptr;
+        size_t len;
+        size_t cap;
+        ShortcutVecDestructor destructor;
+        ShortcutVec& operator=(const ShortcutVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ShortcutVec(const ShortcutVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        ShortcutVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StringVec {
         String* ptr;
         size_t len;
@@ -8020,6 +9042,19 @@ namespace dll {
     };
     
     
+    enum class OptionShortcutConflictTag {
+       None,
+       Some,
+    };
+    
+    struct OptionShortcutConflictVariant_None { OptionShortcutConflictTag tag; };
+    struct OptionShortcutConflictVariant_Some { OptionShortcutConflictTag tag; ShortcutConflict payload; };
+    union OptionShortcutConflict {
+        OptionShortcutConflictVariant_None None;
+        OptionShortcutConflictVariant_Some Some;
+    };
+    
+    
     enum class OptionMouseStateTag {
        None,
        Some,
@@ -8186,6 +9221,54 @@ namespace dll {
         InvalidStringError() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct JankCapture {
+        Duration threshold;
+        bool  dump_display_list;
+        OptionString output_directory;
+        JankCapture& operator=(const JankCapture&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        JankCapture(const JankCapture&) = delete; /* disable copy constructor, use explicit .clone() */
+        JankCapture() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class EventRecordingTag {
+       Record,
+       Replay,
+    };
+    
+    struct EventRecordingVariant_Record { EventRecordingTag tag; String payload; };
+    struct EventRecordingVariant_Replay { EventRecordingTag tag; String payload; };
+    union EventRecording {
+        EventRecordingVariant_Record Record;
+        EventRecordingVariant_Replay Replay;
+    };
+    
+    
+    enum class FrameRecordingOutputTag {
+       GifDirectory,
+       Callback,
+    };
+    
+    struct FrameRecordingOutputVariant_GifDirectory { FrameRecordingOutputTag tag; String payload; };
+    struct FrameRecordingOutputVariant_Callback { FrameRecordingOutputTag tag; FrameRecordingCallback payload; };
+    union FrameRecordingOutput {
+        FrameRecordingOutputVariant_GifDirectory GifDirectory;
+        FrameRecordingOutputVariant_Callback Callback;
+    };
+    
+    
+    struct CrashInfo {
+        String message;
+        String location;
+        String thread_name;
+        String backtrace;
+        StringVec last_log_lines;
+        String window_state_summary;
+        OptionString minidump_path;
+        CrashInfo& operator=(const CrashInfo&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CrashInfo(const CrashInfo&) = delete; /* disable copy constructor, use explicit .clone() */
+        CrashInfo() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct WindowsWindowOptions {
         bool  allow_drag_drop;
         bool  no_redirection_bitmap;
@@ -8263,6 +9346,7 @@ namespace dll {
         double scale_factor;
         VideoModeVec video_modes;
         bool  is_primary_monitor;
+        HdrCapabilities hdr;
         Monitor& operator=(const Monitor&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         Monitor(const Monitor&) = delete; /* disable copy constructor, use explicit .clone() */
         Monitor() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -8300,6 +9384,16 @@ namespace dll {
     };
     
     
+    struct CustomNodeType {
+        String name;
+        MeasureCallback measure;
+        ArrangeCallback arrange;
+        OptionPaintCallback paint;
+        CustomNodeType& operator=(const CustomNodeType&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CustomNodeType(const CustomNodeType&) = delete; /* disable copy constructor, use explicit .clone() */
+        CustomNodeType() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct CallbackData {
         EventFilter event;
         Callback callback;
@@ -8316,6 +9410,7 @@ namespace dll {
        Text,
        Image,
        IFrame,
+       CustomLayout,
     };
     
     struct NodeTypeVariant_Body { NodeTypeTag tag; };
@@ -8324,6 +9419,7 @@ namespace dll {
     struct NodeTypeVariant_Text { NodeTypeTag tag; String payload; };
     struct NodeTypeVariant_Image { NodeTypeTag tag; ImageRef payload; };
     struct NodeTypeVariant_IFrame { NodeTypeTag tag; IFrameNode payload; };
+    struct NodeTypeVariant_CustomLayout { NodeTypeTag tag; CustomLayoutNode payload; };
     union NodeType {
         NodeTypeVariant_Body Body;
         NodeTypeVariant_Div Div;
@@ -8331,6 +9427,7 @@ namespace dll {
         NodeTypeVariant_Text Text;
         NodeTypeVariant_Image Image;
         NodeTypeVariant_IFrame IFrame;
+        NodeTypeVariant_CustomLayout CustomLayout;
     };
     
     
@@ -8346,6 +9443,24 @@ namespace dll {
         AccessibilityInfo() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct FocusNavigation {
+        OptionString up;
+        OptionString down;
+        OptionString left;
+        OptionString right;
+        FocusNavigation& operator=(const FocusNavigation&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        FocusNavigation(const FocusNavigation&) = delete; /* disable copy constructor, use explicit .clone() */
+        FocusNavigation() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct OverlayInfo {
+        String anchor;
+        OverlayPlacement placement;
+        OverlayInfo& operator=(const OverlayInfo&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        OverlayInfo(const OverlayInfo&) = delete; /* disable copy constructor, use explicit .clone() */
+        OverlayInfo() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class IdOrClassTag {
        Id,
        Class,
@@ -8371,6 +9486,13 @@ namespace dll {
         StringMenuItem() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct ShortcutMap {
+        ShortcutVec shortcuts;
+        ShortcutMap& operator=(const ShortcutMap&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ShortcutMap(const ShortcutMap&) = delete; /* disable copy constructor, use explicit .clone() */
+        ShortcutMap() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class CssPathSelectorTag {
        Global,
        Type,
@@ -8443,6 +9565,50 @@ namespace dll {
         ScrollbarStyle() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class StyleContentTag {
+       None,
+       Text,
+       Image,
+       Counter,
+    };
+    
+    struct StyleContentVariant_None { StyleContentTag tag; };
+    struct StyleContentVariant_Text { StyleContentTag tag; String payload; };
+    struct StyleContentVariant_Image { StyleContentTag tag; String payload; };
+    struct StyleContentVariant_Counter { StyleContentTag tag; String payload; };
+    union StyleContent {
+        StyleContentVariant_None None;
+        StyleContentVariant_Text Text;
+        StyleContentVariant_Image Image;
+        StyleContentVariant_Counter Counter;
+    };
+    
+    
+    struct StyleCounterReset {
+        String counter;
+        int32_t value;
+        StyleCounterReset& operator=(const StyleCounterReset&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleCounterReset(const StyleCounterReset&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleCounterReset() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleCounterIncrement {
+        String counter;
+        int32_t value;
+        StyleCounterIncrement& operator=(const StyleCounterIncrement&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleCounterIncrement(const StyleCounterIncrement&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleCounterIncrement() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleCursorImage {
+        String image;
+        uint32_t hotspot_x;
+        uint32_t hotspot_y;
+        StyleCursorImage& operator=(const StyleCursorImage&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleCursorImage(const StyleCursorImage&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleCursorImage() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StyleFontFamilyTag {
        System,
        File,
@@ -8481,6 +9647,72 @@ namespace dll {
     };
     
     
+    enum class StyleContentValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleContentValueVariant_Auto { StyleContentValueTag tag; };
+    struct StyleContentValueVariant_None { StyleContentValueTag tag; };
+    struct StyleContentValueVariant_Inherit { StyleContentValueTag tag; };
+    struct StyleContentValueVariant_Initial { StyleContentValueTag tag; };
+    struct StyleContentValueVariant_Exact { StyleContentValueTag tag; StyleContent payload; };
+    union StyleContentValue {
+        StyleContentValueVariant_Auto Auto;
+        StyleContentValueVariant_None None;
+        StyleContentValueVariant_Inherit Inherit;
+        StyleContentValueVariant_Initial Initial;
+        StyleContentValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleCounterResetValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleCounterResetValueVariant_Auto { StyleCounterResetValueTag tag; };
+    struct StyleCounterResetValueVariant_None { StyleCounterResetValueTag tag; };
+    struct StyleCounterResetValueVariant_Inherit { StyleCounterResetValueTag tag; };
+    struct StyleCounterResetValueVariant_Initial { StyleCounterResetValueTag tag; };
+    struct StyleCounterResetValueVariant_Exact { StyleCounterResetValueTag tag; StyleCounterReset payload; };
+    union StyleCounterResetValue {
+        StyleCounterResetValueVariant_Auto Auto;
+        StyleCounterResetValueVariant_None None;
+        StyleCounterResetValueVariant_Inherit Inherit;
+        StyleCounterResetValueVariant_Initial Initial;
+        StyleCounterResetValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleCounterIncrementValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleCounterIncrementValueVariant_Auto { StyleCounterIncrementValueTag tag; };
+    struct StyleCounterIncrementValueVariant_None { StyleCounterIncrementValueTag tag; };
+    struct StyleCounterIncrementValueVariant_Inherit { StyleCounterIncrementValueTag tag; };
+    struct StyleCounterIncrementValueVariant_Initial { StyleCounterIncrementValueTag tag; };
+    struct StyleCounterIncrementValueVariant_Exact { StyleCounterIncrementValueTag tag; StyleCounterIncrement payload; };
+    union StyleCounterIncrementValue {
+        StyleCounterIncrementValueVariant_Auto Auto;
+        StyleCounterIncrementValueVariant_None None;
+        StyleCounterIncrementValueVariant_Inherit Inherit;
+        StyleCounterIncrementValueVariant_Initial Initial;
+        StyleCounterIncrementValueVariant_Exact Exact;
+    };
+    
+    
     enum class StyleTransformVecValueTag {
        Auto,
        None,
@@ -8552,6 +9784,15 @@ namespace dll {
         TextInputState() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextInputSpellingSuggestions {
+        TextInputSelectionRange range;
+        String word;
+        StringVec suggestions;
+        TextInputSpellingSuggestions& operator=(const TextInputSpellingSuggestions&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextInputSpellingSuggestions(const TextInputSpellingSuggestions&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextInputSpellingSuggestions() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct TabHeader {
         StringVec tabs;
         size_t active_tab;
@@ -8644,6 +9885,27 @@ namespace dll {
         DropDown() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct Toast {
+        String message;
+        OptionDuration duration;
+        String action_label;
+        OptionToastOnAction on_action;
+        Toast& operator=(const Toast&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Toast(const Toast&) = delete; /* disable copy constructor, use explicit .clone() */
+        Toast() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct GlQuirkRule {
+        OptionString vendor;
+        OptionString renderer;
+        OptionString version;
+        GlQuirks enable;
+        GlQuirks disable;
+        GlQuirkRule& operator=(const GlQuirkRule&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GlQuirkRule(const GlQuirkRule&) = delete; /* disable copy constructor, use explicit .clone() */
+        GlQuirkRule() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct VertexAttribute {
         String name;
         OptionUsize layout_location;
@@ -8968,6 +10230,32 @@ namespace dll {
     };
     
     
+    enum class OptionJankCaptureTag {
+       None,
+       Some,
+    };
+    
+    struct OptionJankCaptureVariant_None { OptionJankCaptureTag tag; };
+    struct OptionJankCaptureVariant_Some { OptionJankCaptureTag tag; JankCapture payload; };
+    union OptionJankCapture {
+        OptionJankCaptureVariant_None None;
+        OptionJankCaptureVariant_Some Some;
+    };
+    
+    
+    enum class OptionEventRecordingTag {
+       None,
+       Some,
+    };
+    
+    struct OptionEventRecordingVariant_None { OptionEventRecordingTag tag; };
+    struct OptionEventRecordingVariant_Some { OptionEventRecordingTag tag; EventRecording payload; };
+    union OptionEventRecording {
+        OptionEventRecordingVariant_None None;
+        OptionEventRecordingVariant_Some Some;
+    };
+    
+    
     enum class OptionRawImageTag {
        None,
        Some,
@@ -9053,6 +10341,14 @@ namespace dll {
     };
     
     
+    struct FrameRecording {
+        FrameRecordingOutput output;
+        uint32_t max_fps;
+        FrameRecording& operator=(const FrameRecording&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        FrameRecording(const FrameRecording&) = delete; /* disable copy constructor, use explicit .clone() */
+        FrameRecording() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct LinuxWindowOptions {
         OptionX11Visual x11_visual;
         OptionI32 x11_screen;
@@ -9102,6 +10398,106 @@ namespace dll {
         CssPath() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class StyleCursorTag {
+       Alias,
+       AllScroll,
+       Cell,
+       ColResize,
+       ContextMenu,
+       Copy,
+       Crosshair,
+       Default,
+       EResize,
+       EwResize,
+       Grab,
+       Grabbing,
+       Help,
+       Move,
+       NResize,
+       NsResize,
+       NeswResize,
+       NwseResize,
+       Pointer,
+       Progress,
+       RowResize,
+       SResize,
+       SeResize,
+       Text,
+       Unset,
+       VerticalText,
+       WResize,
+       Wait,
+       ZoomIn,
+       ZoomOut,
+       Image,
+    };
+    
+    struct StyleCursorVariant_Alias { StyleCursorTag tag; };
+    struct StyleCursorVariant_AllScroll { StyleCursorTag tag; };
+    struct StyleCursorVariant_Cell { StyleCursorTag tag; };
+    struct StyleCursorVariant_ColResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_ContextMenu { StyleCursorTag tag; };
+    struct StyleCursorVariant_Copy { StyleCursorTag tag; };
+    struct StyleCursorVariant_Crosshair { StyleCursorTag tag; };
+    struct StyleCursorVariant_Default { StyleCursorTag tag; };
+    struct StyleCursorVariant_EResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_EwResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_Grab { StyleCursorTag tag; };
+    struct StyleCursorVariant_Grabbing { StyleCursorTag tag; };
+    struct StyleCursorVariant_Help { StyleCursorTag tag; };
+    struct StyleCursorVariant_Move { StyleCursorTag tag; };
+    struct StyleCursorVariant_NResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_NsResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_NeswResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_NwseResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_Pointer { StyleCursorTag tag; };
+    struct StyleCursorVariant_Progress { StyleCursorTag tag; };
+    struct StyleCursorVariant_RowResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_SResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_SeResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_Text { StyleCursorTag tag; };
+    struct StyleCursorVariant_Unset { StyleCursorTag tag; };
+    struct StyleCursorVariant_VerticalText { StyleCursorTag tag; };
+    struct StyleCursorVariant_WResize { StyleCursorTag tag; };
+    struct StyleCursorVariant_Wait { StyleCursorTag tag; };
+    struct StyleCursorVariant_ZoomIn { StyleCursorTag tag; };
+    struct StyleCursorVariant_ZoomOut { StyleCursorTag tag; };
+    struct StyleCursorVariant_Image { StyleCursorTag tag; StyleCursorImage payload; };
+    union StyleCursor {
+        StyleCursorVariant_Alias Alias;
+        StyleCursorVariant_AllScroll AllScroll;
+        StyleCursorVariant_Cell Cell;
+        StyleCursorVariant_ColResize ColResize;
+        StyleCursorVariant_ContextMenu ContextMenu;
+        StyleCursorVariant_Copy Copy;
+        StyleCursorVariant_Crosshair Crosshair;
+        StyleCursorVariant_Default Default;
+        StyleCursorVariant_EResize EResize;
+        StyleCursorVariant_EwResize EwResize;
+        StyleCursorVariant_Grab Grab;
+        StyleCursorVariant_Grabbing Grabbing;
+        StyleCursorVariant_Help Help;
+        StyleCursorVariant_Move Move;
+        StyleCursorVariant_NResize NResize;
+        StyleCursorVariant_NsResize NsResize;
+        StyleCursorVariant_NeswResize NeswResize;
+        StyleCursorVariant_NwseResize NwseResize;
+        StyleCursorVariant_Pointer Pointer;
+        StyleCursorVariant_Progress Progress;
+        StyleCursorVariant_RowResize RowResize;
+        StyleCursorVariant_SResize SResize;
+        StyleCursorVariant_SeResize SeResize;
+        StyleCursorVariant_Text Text;
+        StyleCursorVariant_Unset Unset;
+        StyleCursorVariant_VerticalText VerticalText;
+        StyleCursorVariant_WResize WResize;
+        StyleCursorVariant_Wait Wait;
+        StyleCursorVariant_ZoomIn ZoomIn;
+        StyleCursorVariant_ZoomOut ZoomOut;
+        StyleCursorVariant_Image Image;
+    };
+    
+    
     enum class StyleBackgroundContentVecValueTag {
        Auto,
        None,
@@ -9124,6 +10520,28 @@ namespace dll {
     };
     
     
+    enum class StyleCursorValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleCursorValueVariant_Auto { StyleCursorValueTag tag; };
+    struct StyleCursorValueVariant_None { StyleCursorValueTag tag; };
+    struct StyleCursorValueVariant_Inherit { StyleCursorValueTag tag; };
+    struct StyleCursorValueVariant_Initial { StyleCursorValueTag tag; };
+    struct StyleCursorValueVariant_Exact { StyleCursorValueTag tag; StyleCursor payload; };
+    union StyleCursorValue {
+        StyleCursorValueVariant_Auto Auto;
+        StyleCursorValueVariant_None None;
+        StyleCursorValueVariant_Inherit Inherit;
+        StyleCursorValueVariant_Initial Initial;
+        StyleCursorValueVariant_Exact Exact;
+    };
+    
+    
     enum class StyleFontFamilyVecValueTag {
        Auto,
        None,
@@ -9155,6 +10573,7 @@ namespace dll {
        LineHeight,
        WordSpacing,
        TabWidth,
+       Hyphens,
        Cursor,
        Display,
        Float,
@@ -9212,11 +10631,24 @@ namespace dll {
        BoxShadowTop,
        BoxShadowBottom,
        ScrollbarStyle,
+       ScrollbarWidth,
+       ScrollbarColor,
+       ScrollbarThumbHoverColor,
+       ScrollbarThumbActiveColor,
+       WindowDragRegion,
+       CaretAnimationDuration,
+       ScrollPadding,
+       Content,
+       CounterReset,
+       CounterIncrement,
+       OverflowAnchor,
        Opacity,
        Transform,
        TransformOrigin,
        PerspectiveOrigin,
        BackfaceVisibility,
+       Perspective,
+       TransformStyle,
        MixBlendMode,
        Filter,
        BackdropFilter,
@@ -9231,6 +10663,7 @@ namespace dll {
     struct CssPropertyVariant_LineHeight { CssPropertyTag tag; StyleLineHeightValue payload; };
     struct CssPropertyVariant_WordSpacing { CssPropertyTag tag; StyleWordSpacingValue payload; };
     struct CssPropertyVariant_TabWidth { CssPropertyTag tag; StyleTabWidthValue payload; };
+    struct CssPropertyVariant_Hyphens { CssPropertyTag tag; StyleHyphensValue payload; };
     struct CssPropertyVariant_Cursor { CssPropertyTag tag; StyleCursorValue payload; };
     struct CssPropertyVariant_Display { CssPropertyTag tag; LayoutDisplayValue payload; };
     struct CssPropertyVariant_Float { CssPropertyTag tag; LayoutFloatValue payload; };
@@ -9288,11 +10721,24 @@ namespace dll {
     struct CssPropertyVariant_BoxShadowTop { CssPropertyTag tag; StyleBoxShadowValue payload; };
     struct CssPropertyVariant_BoxShadowBottom { CssPropertyTag tag; StyleBoxShadowValue payload; };
     struct CssPropertyVariant_ScrollbarStyle { CssPropertyTag tag; ScrollbarStyleValue payload; };
+    struct CssPropertyVariant_ScrollbarWidth { CssPropertyTag tag; StyleScrollbarWidthValue payload; };
+    struct CssPropertyVariant_ScrollbarColor { CssPropertyTag tag; StyleScrollbarColorValue payload; };
+    struct CssPropertyVariant_ScrollbarThumbHoverColor { CssPropertyTag tag; StyleScrollbarThumbHoverColorValue payload; };
+    struct CssPropertyVariant_ScrollbarThumbActiveColor { CssPropertyTag tag; StyleScrollbarThumbActiveColorValue payload; };
+    struct CssPropertyVariant_WindowDragRegion { CssPropertyTag tag; StyleWindowDragRegionValue payload; };
+    struct CssPropertyVariant_CaretAnimationDuration { CssPropertyTag tag; StyleCaretAnimationDurationValue payload; };
+    struct CssPropertyVariant_ScrollPadding { CssPropertyTag tag; StyleScrollPaddingValue payload; };
+    struct CssPropertyVariant_Content { CssPropertyTag tag; StyleContentValue payload; };
+    struct CssPropertyVariant_CounterReset { CssPropertyTag tag; StyleCounterResetValue payload; };
+    struct CssPropertyVariant_CounterIncrement { CssPropertyTag tag; StyleCounterIncrementValue payload; };
+    struct CssPropertyVariant_OverflowAnchor { CssPropertyTag tag; StyleOverflowAnchorValue payload; };
     struct CssPropertyVariant_Opacity { CssPropertyTag tag; StyleOpacityValue payload; };
     struct CssPropertyVariant_Transform { CssPropertyTag tag; StyleTransformVecValue payload; };
     struct CssPropertyVariant_TransformOrigin { CssPropertyTag tag; StyleTransformOriginValue payload; };
     struct CssPropertyVariant_PerspectiveOrigin { CssPropertyTag tag; StylePerspectiveOriginValue payload; };
     struct CssPropertyVariant_BackfaceVisibility { CssPropertyTag tag; StyleBackfaceVisibilityValue payload; };
+    struct CssPropertyVariant_Perspective { CssPropertyTag tag; StylePerspectiveValue payload; };
+    struct CssPropertyVariant_TransformStyle { CssPropertyTag tag; StyleTransformStyleValue payload; };
     struct CssPropertyVariant_MixBlendMode { CssPropertyTag tag; StyleMixBlendModeValue payload; };
     struct CssPropertyVariant_Filter { CssPropertyTag tag; StyleFilterVecValue payload; };
     struct CssPropertyVariant_BackdropFilter { CssPropertyTag tag; StyleFilterVecValue payload; };
@@ -9306,6 +10752,7 @@ namespace dll {
         CssPropertyVariant_LineHeight LineHeight;
         CssPropertyVariant_WordSpacing WordSpacing;
         CssPropertyVariant_TabWidth TabWidth;
+        CssPropertyVariant_Hyphens Hyphens;
         CssPropertyVariant_Cursor Cursor;
         CssPropertyVariant_Display Display;
         CssPropertyVariant_Float Float;
@@ -9363,11 +10810,24 @@ namespace dll {
         CssPropertyVariant_BoxShadowTop BoxShadowTop;
         CssPropertyVariant_BoxShadowBottom BoxShadowBottom;
         CssPropertyVariant_ScrollbarStyle ScrollbarStyle;
+        CssPropertyVariant_ScrollbarWidth ScrollbarWidth;
+        CssPropertyVariant_ScrollbarColor ScrollbarColor;
+        CssPropertyVariant_ScrollbarThumbHoverColor ScrollbarThumbHoverColor;
+        CssPropertyVariant_ScrollbarThumbActiveColor ScrollbarThumbActiveColor;
+        CssPropertyVariant_WindowDragRegion WindowDragRegion;
+        CssPropertyVariant_CaretAnimationDuration CaretAnimationDuration;
+        CssPropertyVariant_ScrollPadding ScrollPadding;
+        CssPropertyVariant_Content Content;
+        CssPropertyVariant_CounterReset CounterReset;
+        CssPropertyVariant_CounterIncrement CounterIncrement;
+        CssPropertyVariant_OverflowAnchor OverflowAnchor;
         CssPropertyVariant_Opacity Opacity;
         CssPropertyVariant_Transform Transform;
         CssPropertyVariant_TransformOrigin TransformOrigin;
         CssPropertyVariant_PerspectiveOrigin PerspectiveOrigin;
         CssPropertyVariant_BackfaceVisibility BackfaceVisibility;
+        CssPropertyVariant_Perspective Perspective;
+        CssPropertyVariant_TransformStyle TransformStyle;
         CssPropertyVariant_MixBlendMode MixBlendMode;
         CssPropertyVariant_Filter Filter;
         CssPropertyVariant_BackdropFilter BackdropFilter;
@@ -9394,6 +10854,10 @@ namespace dll {
         bool  update_text_input_before_calling_focus_lost_fn;
         bool  update_text_input_before_calling_vk_down_fn;
         OptionTimerId cursor_animation;
+        CaretAnimation caret_animation;
+        OptionString spellcheck_language;
+        OptionTextInputOnSpellingSuggestions on_spelling_suggestions;
+        bool  secure;
         TextInputStateWrapper& operator=(const TextInputStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         TextInputStateWrapper(const TextInputStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
         TextInputStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -9614,6 +11078,19 @@ namespace dll {
     };
     
     
+    enum class OptionFrameRecordingTag {
+       None,
+       Some,
+    };
+    
+    struct OptionFrameRecordingVariant_None { OptionFrameRecordingTag tag; };
+    struct OptionFrameRecordingVariant_Some { OptionFrameRecordingTag tag; FrameRecording payload; };
+    union OptionFrameRecording {
+        OptionFrameRecordingVariant_None None;
+        OptionFrameRecordingVariant_Some Some;
+    };
+    
+    
     struct XmlTextError {
         XmlStreamError stream_error;
         SvgParseErrorPosition pos;
@@ -9622,6 +11099,24 @@ namespace dll {
         XmlTextError() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct AppConfig {
+        LayoutSolver layout_solver;
+        AppLogLevel log_level;
+        bool  enable_visual_panic_hook;
+        bool  enable_logging_on_panic;
+        bool  enable_tab_navigation;
+        SystemCallbacks system_callbacks;
+        OptionDuration user_idle_timeout;
+        OptionCrashHandler crash_handler;
+        OptionJankCapture jank_capture;
+        OptionEventRecording event_recording;
+        OptionFrameRecording frame_recording;
+        OptionDuration hover_intent_delay;
+        AppConfig& operator=(const AppConfig&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        AppConfig(const AppConfig&) = delete; /* disable copy constructor, use explicit .clone() */
+        AppConfig() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct PlatformSpecificOptions {
         WindowsWindowOptions windows_options;
         LinuxWindowOptions linux_options;
@@ -9647,6 +11142,7 @@ namespace dll {
         PlatformSpecificOptions platform_specific_options;
         RendererOptions renderer_options;
         ColorU background_color;
+        float opacity;
         LayoutCallback layout_callback;
         OptionCallback close_callback;
         WindowState& operator=(const WindowState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -9670,13 +11166,22 @@ namespace dll {
         void* restrict threads_removed;
         RawWindowHandle* current_window_handle;
         void* restrict new_windows;
+        ShortcutMap* restrict shortcuts;
+        void* restrict window_data;
+        void* restrict announcements;
+        void* restrict haptic_feedback;
+        void* restrict animation_frame_callbacks;
+        void* restrict crossfades;
+        void* restrict subtree_recordings;
         SystemCallbacks* system_callbacks;
         bool * restrict stop_propagation;
         void* restrict focus_target;
         void* restrict words_changed_in_callbacks;
+        void* restrict text_underlines_changed_in_callbacks;
         void* restrict images_changed_in_callbacks;
         void* restrict image_masks_changed_in_callbacks;
         void* restrict css_properties_changed_in_callbacks;
+        void* restrict ids_and_classes_changed_in_callbacks;
         void* current_scroll_states;
         void* restrict nodes_scrolled_in_callback;
         DomNodeId hit_dom_node;
@@ -9700,6 +11205,14 @@ namespace dll {
         InlineText() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextLayout {
+        LogicalRect bounds;
+        InlineText inline_text;
+        TextLayout& operator=(const TextLayout&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextLayout(const TextLayout&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextLayout() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct FocusTargetPath {
         DomId dom;
         CssPath css_path;
@@ -9761,6 +11274,15 @@ namespace dll {
         DynamicCssProperty() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct SystemCssProperty {
+        String key;
+        String value;
+        CssProperty default_value;
+        SystemCssProperty& operator=(const SystemCssProperty&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SystemCssProperty(const SystemCssProperty&) = delete; /* disable copy constructor, use explicit .clone() */
+        SystemCssProperty() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct Node {
         NodeTypeId node_type;
         NodePosition position;
@@ -9841,6 +11363,19 @@ namespace dll {
     };
     
     
+    enum class OptionTextLayoutTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTextLayoutVariant_None { OptionTextLayoutTag tag; };
+    struct OptionTextLayoutVariant_Some { OptionTextLayoutTag tag; TextLayout payload; };
+    union OptionTextLayout {
+        OptionTextLayoutVariant_None None;
+        OptionTextLayoutVariant_Some Some;
+    };
+    
+    
     enum class XmlParseErrorTag {
        InvalidDeclaration,
        InvalidComment,
@@ -9885,6 +11420,13 @@ namespace dll {
         OptionWindowTheme theme;
         OptionCallback create_callback;
         bool  hot_reload;
+        ShortcutMap shortcuts;
+        OptionRawWindowHandle parent_handle;
+        OptionRefAny window_data;
+        WindowBackgroundEffect background_effect;
+        OptionDuration fade_in;
+        FirstFrameMode first_frame;
+        ScrollbarConfig scrollbars;
         WindowCreateOptions& operator=(const WindowCreateOptions&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         WindowCreateOptions(const WindowCreateOptions&) = delete; /* disable copy constructor, use explicit .clone() */
         WindowCreateOptions() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -9897,6 +11439,7 @@ namespace dll {
        Next,
        First,
        Last,
+       Direction,
        NoFocus,
     };
     
@@ -9906,6 +11449,7 @@ namespace dll {
     struct FocusTargetVariant_Next { FocusTargetTag tag; };
     struct FocusTargetVariant_First { FocusTargetTag tag; };
     struct FocusTargetVariant_Last { FocusTargetTag tag; };
+    struct FocusTargetVariant_Direction { FocusTargetTag tag; FocusDirection payload; };
     struct FocusTargetVariant_NoFocus { FocusTargetTag tag; };
     union FocusTarget {
         FocusTargetVariant_Id Id;
@@ -9914,6 +11458,7 @@ namespace dll {
         FocusTargetVariant_Next Next;
         FocusTargetVariant_First First;
         FocusTargetVariant_Last Last;
+        FocusTargetVariant_Direction Direction;
         FocusTargetVariant_NoFocus NoFocus;
     };
     
@@ -9934,13 +11479,16 @@ namespace dll {
     enum class CssDeclarationTag {
        Static,
        Dynamic,
+       System,
     };
     
     struct CssDeclarationVariant_Static { CssDeclarationTag tag; CssProperty payload; };
     struct CssDeclarationVariant_Dynamic { CssDeclarationTag tag; DynamicCssProperty payload; };
+    struct CssDeclarationVariant_System { CssDeclarationTag tag; SystemCssProperty payload; };
     union CssDeclaration {
         CssDeclarationVariant_Static Static;
         CssDeclarationVariant_Dynamic Dynamic;
+        CssDeclarationVariant_System System;
     };
     
     
@@ -10003,12 +11551,15 @@ namespace dll {
         TextInput() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct NumberInput {
-        TextInput text_input;
-        NumberInputStateWrapper state;
-        NumberInput& operator=(const NumberInput&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        NumberInput(const NumberInput&) = delete; /* disable copy constructor, use explicit .clone() */
-        NumberInput() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    struct NumberInputStateWrapper {
+        NumberInputState inner;
+        OptionNumberInputOnValueChange on_value_change;
+        OptionNumberInputOnFocusLost on_focus_lost;
+        NodeDataInlineCssPropertyVec invalid_style;
+        NodeDataInlineCssPropertyVec valid_style;
+        NumberInputStateWrapper& operator=(const NumberInputStateWrapper&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NumberInputStateWrapper(const NumberInputStateWrapper&) = delete; /* disable copy constructor, use explicit .clone() */
+        NumberInputStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
     struct NodeIdNodeMap {
@@ -10019,6 +11570,17 @@ namespace dll {
         NodeIdNodeMap() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct Toasts {
+        RefAny state;
+        NodeDataInlineCssPropertyVec layer_style;
+        NodeDataInlineCssPropertyVec toast_style;
+        NodeDataInlineCssPropertyVec label_style;
+        NodeDataInlineCssPropertyVec action_style;
+        Toasts& operator=(const Toasts&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Toasts(const Toasts&) = delete; /* disable copy constructor, use explicit .clone() */
+        Toasts() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NodeIdNodeMapVec {
         NodeIdNodeMap* ptr;
         size_t len;
@@ -10119,11 +11681,21 @@ namespace dll {
     struct CssRuleBlock {
         CssPath path;
         CssDeclarationVec declarations;
+        CssMediaCondition media;
         CssRuleBlock& operator=(const CssRuleBlock&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         CssRuleBlock(const CssRuleBlock&) = delete; /* disable copy constructor, use explicit .clone() */
         CssRuleBlock() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct NumberInput {
+        TextInput text_input;
+        NumberInputStateWrapper state;
+        NodeDataInlineCssPropertyVec spinner_button_style;
+        NumberInput& operator=(const NumberInput&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        NumberInput(const NumberInput&) = delete; /* disable copy constructor, use explicit .clone() */
+        NumberInput() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct TabContent {
         Dom content;
         bool  has_padding;
@@ -10301,14 +11873,36 @@ namespace dll {
         App App_new(AzRefAny  data, AzAppConfig  config);
         void App_addWindow(App* restrict app, AzWindowCreateOptions  window);
         void App_addImage(App* restrict app, AzString  id, AzImageRef  image);
+        App App_withSplash(const App app, AzSplashOptions  splash);
+        void App_addGlQuirkRule(App* restrict app, AzGlQuirkRule  rule);
         MonitorVec App_getMonitors(const App* app);
+        EventLoopProxy App_createEventLoopProxy(const App* app);
         void App_run(const App* app, AzWindowCreateOptions  window);
         void App_delete(App* restrict instance);
         App App_deepCopy(App* const instance);
         AppConfig AppConfig_new(AzLayoutSolver  layout_solver);
+        void AppConfig_delete(AppConfig* restrict instance);
+        JankCapture JankCapture_new(AzDuration  threshold);
+        void JankCapture_delete(JankCapture* restrict instance);
+        SplashOptions SplashOptions_new();
+        SplashOptions SplashOptions_withLogo(const SplashOptions splashoptions, AzImageRef  logo);
+        void SplashOptions_delete(SplashOptions* restrict instance);
+        void EventRecording_delete(EventRecording* restrict instance);
+        FrameRecording FrameRecording_new(AzFrameRecordingOutput  output);
+        void FrameRecording_delete(FrameRecording* restrict instance);
+        void FrameRecordingOutput_delete(FrameRecordingOutput* restrict instance);
+        void RecordedFrame_delete(RecordedFrame* restrict instance);
+        void CrashInfo_delete(CrashInfo* restrict instance);
         SystemCallbacks SystemCallbacks_libraryInternal();
         WindowCreateOptions WindowCreateOptions_new(AzLayoutCallbackType  layout_callback);
+        WindowCreateOptions WindowCreateOptions_withParentHandle(const WindowCreateOptions windowcreateoptions, AzRawWindowHandle  parent_handle);
+        WindowCreateOptions WindowCreateOptions_withWindowData(const WindowCreateOptions windowcreateoptions, AzRefAny  window_data);
+        WindowCreateOptions WindowCreateOptions_withBackgroundEffect(const WindowCreateOptions windowcreateoptions, AzWindowBackgroundEffect  background_effect);
+        WindowCreateOptions WindowCreateOptions_withFadeIn(const WindowCreateOptions windowcreateoptions, AzDuration  duration);
+        WindowCreateOptions WindowCreateOptions_withFirstFrame(const WindowCreateOptions windowcreateoptions, AzFirstFrameMode  first_frame);
+        WindowCreateOptions WindowCreateOptions_withScrollbars(const WindowCreateOptions windowcreateoptions, AzScrollbarConfig  scrollbars);
         void WindowCreateOptions_delete(WindowCreateOptions* restrict instance);
+        ScrollbarConfig ScrollbarConfig_default();
         LogicalPosition LogicalPosition_new(float x, float y);
         LogicalPosition LogicalPosition_zero();
         PhysicalSizeU32 LogicalSize_toPhysical(const LogicalSize* logicalsize, float hidpi_factor);
@@ -10317,11 +11911,22 @@ namespace dll {
         void WindowIcon_delete(WindowIcon* restrict instance);
         void TaskBarIcon_delete(TaskBarIcon* restrict instance);
         float WindowSize_getHidpiFactor(const WindowSize* windowsize);
+        void WindowSize_setZoom(WindowSize* restrict windowsize, float zoom);
         bool  KeyboardState_shiftDown(const KeyboardState* keyboardstate);
         bool  KeyboardState_ctrlDown(const KeyboardState* keyboardstate);
         bool  KeyboardState_altDown(const KeyboardState* keyboardstate);
         bool  KeyboardState_superDown(const KeyboardState* keyboardstate);
+        bool  KeyboardState_leftShiftDown(const KeyboardState* keyboardstate);
+        bool  KeyboardState_rightShiftDown(const KeyboardState* keyboardstate);
+        bool  KeyboardState_leftCtrlDown(const KeyboardState* keyboardstate);
+        bool  KeyboardState_rightCtrlDown(const KeyboardState* keyboardstate);
+        bool  KeyboardState_leftAltDown(const KeyboardState* keyboardstate);
+        bool  KeyboardState_rightAltDown(const KeyboardState* keyboardstate);
+        bool  KeyboardState_leftSuperDown(const KeyboardState* keyboardstate);
+        bool  KeyboardState_rightSuperDown(const KeyboardState* keyboardstate);
         bool  KeyboardState_isKeyDown(const KeyboardState* keyboardstate, AzVirtualKeyCode  key);
+        bool  KeyboardState_isScancodeDown(const KeyboardState* keyboardstate, uint32_t scancode);
+        KeyboardModifiers KeyboardState_getModifiers(const KeyboardState* keyboardstate);
         void KeyboardState_delete(KeyboardState* restrict instance);
         OptionLogicalPosition CursorPosition_getPosition(const CursorPosition* cursorposition);
         void PlatformSpecificOptions_delete(PlatformSpecificOptions* restrict instance);
@@ -10329,10 +11934,13 @@ namespace dll {
         void WaylandTheme_delete(WaylandTheme* restrict instance);
         void StringPair_delete(StringPair* restrict instance);
         void LinuxWindowOptions_delete(LinuxWindowOptions* restrict instance);
+        void TouchState_delete(TouchState* restrict instance);
         void Monitor_delete(Monitor* restrict instance);
         WindowState WindowState_new(AzLayoutCallbackType  layout_callback);
         WindowState WindowState_default();
+        void WindowState_setFullscreen(WindowState* restrict windowstate, AzFullscreenMode  mode);
         void WindowState_delete(WindowState* restrict instance);
+        LayoutCallback LayoutCallback_hotReload(AzString  library_path, AzString  symbol);
         void LayoutCallback_delete(LayoutCallback* restrict instance);
         void MarshaledLayoutCallback_delete(MarshaledLayoutCallback* restrict instance);
         DomNodeId CallbackInfo_getHitNode(const CallbackInfo* callbackinfo);
@@ -10340,6 +11948,9 @@ namespace dll {
         OptionLogicalPosition CallbackInfo_getCursorRelativeToViewport(const CallbackInfo* callbackinfo);
         OptionLogicalPosition CallbackInfo_getCursorRelativeToNode(const CallbackInfo* callbackinfo);
         WindowState CallbackInfo_getCurrentWindowState(const CallbackInfo* callbackinfo);
+        OptionRefAny CallbackInfo_getUserEvent(const CallbackInfo* callbackinfo);
+        OptionRefAny CallbackInfo_getWindowData(const CallbackInfo* callbackinfo);
+        void CallbackInfo_setWindowData(CallbackInfo* restrict callbackinfo, AzRefAny  data);
         KeyboardState CallbackInfo_getCurrentKeyboardState(const CallbackInfo* callbackinfo);
         MouseState CallbackInfo_getCurrentMouseState(const CallbackInfo* callbackinfo);
         OptionWindowState CallbackInfo_getPreviousWindowState(const CallbackInfo* callbackinfo);
@@ -10367,8 +11978,13 @@ namespace dll {
         void CallbackInfo_setWindowState(CallbackInfo* restrict callbackinfo, AzWindowState  new_state);
         void CallbackInfo_setFocus(CallbackInfo* restrict callbackinfo, AzFocusTarget  target);
         void CallbackInfo_setCssProperty(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzCssProperty  new_property);
+        void CallbackInfo_addClass(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  class);
+        void CallbackInfo_removeClass(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  class);
+        void CallbackInfo_toggleClass(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  class);
+        void CallbackInfo_setNodeStateFlag(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzNodeStateFlag  flag, bool  value);
         void CallbackInfo_setScrollPosition(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzLogicalPosition  scroll_position);
         void CallbackInfo_setStringContents(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  string);
+        void CallbackInfo_setTextUnderlines(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzTextUnderlineVec  underlines);
         void CallbackInfo_addImage(CallbackInfo* restrict callbackinfo, AzString  id, AzImageRef  image);
         bool  CallbackInfo_hasImage(const CallbackInfo* callbackinfo, AzString  id);
         OptionImageRef CallbackInfo_getImage(const CallbackInfo* callbackinfo, AzString  id);
@@ -10379,10 +11995,27 @@ namespace dll {
         void CallbackInfo_createWindow(CallbackInfo* restrict callbackinfo, AzWindowCreateOptions  new_window);
         TimerId CallbackInfo_startTimer(CallbackInfo* restrict callbackinfo, AzTimer  timer);
         OptionTimerId CallbackInfo_startAnimation(CallbackInfo* restrict callbackinfo, AzDomNodeId  node, AzAnimation  animation);
+        OptionTimerId CallbackInfo_startCaretAnimation(CallbackInfo* restrict callbackinfo, AzDomNodeId  caret_node, AzCaretAnimation  animation);
+        TimerId CallbackInfo_animateWindowResize(CallbackInfo* restrict callbackinfo, AzLogicalSize  target_size, AzDuration  duration);
         bool  CallbackInfo_stopTimer(CallbackInfo* restrict callbackinfo, AzTimerId  timer_id);
         OptionThreadId CallbackInfo_startThread(CallbackInfo* restrict callbackinfo, AzRefAny  thread_initialize_data, AzRefAny  writeback_data, AzThreadCallbackType  callback);
         bool  CallbackInfo_sendThreadMsg(CallbackInfo* restrict callbackinfo, AzThreadId  thread_id, AzThreadSendMsg  msg);
         bool  CallbackInfo_stopThread(CallbackInfo* restrict callbackinfo, AzThreadId  thread_id);
+        OptionDuration CallbackInfo_getSystemIdleTime(const CallbackInfo* callbackinfo);
+        OptionChar CallbackInfo_keycodeToChar(const CallbackInfo* callbackinfo, AzVirtualKeyCode  virtual_keycode, AzKeyboardModifiers  modifiers);
+        StringVec CallbackInfo_getLogLines(const CallbackInfo* callbackinfo);
+        void CallbackInfo_setLogLevel(const CallbackInfo* callbackinfo, AzString  subsystem, AzAppLogLevel  level);
+        OptionNodeRect CallbackInfo_getNodeRect(const CallbackInfo* callbackinfo, AzDomNodeId  node_id);
+        OptionTextLayout CallbackInfo_getTextLayout(const CallbackInfo* callbackinfo, AzDomNodeId  node_id);
+        OptionScrollInfo CallbackInfo_getScrollInfo(const CallbackInfo* callbackinfo, AzDomNodeId  node_id);
+        OptionShortcutConflict CallbackInfo_addShortcut(CallbackInfo* restrict callbackinfo, AzShortcut  shortcut);
+        bool  CallbackInfo_removeShortcut(CallbackInfo* restrict callbackinfo, AzVirtualKeyCodeCombo  keys);
+        ShortcutConflictVec CallbackInfo_getShortcutConflicts(const CallbackInfo* callbackinfo);
+        void CallbackInfo_announce(CallbackInfo* restrict callbackinfo, AzString  text, AzPoliteness  politeness);
+        void CallbackInfo_triggerHaptic(CallbackInfo* restrict callbackinfo, AzHapticKind  kind);
+        void CallbackInfo_requestAnimationFrame(CallbackInfo* restrict callbackinfo, AzRefAny  data, AzTimerCallbackType  callback);
+        void CallbackInfo_crossfadeSubtree(CallbackInfo* restrict callbackinfo, AzDomNodeId  node, AzDuration  duration);
+        void CallbackInfo_renderSubtreeToFrames(CallbackInfo* restrict callbackinfo, AzDomNodeId  node, AzDuration  duration, uint32_t fps, AzString  output_path);
         void CallbackInfo_delete(CallbackInfo* restrict instance);
         bool  PositionInfo_isPositioned(const PositionInfo* positioninfo);
         LogicalPosition PositionInfo_getStaticOffset(const PositionInfo* positioninfo);
@@ -10392,6 +12025,7 @@ namespace dll {
         float HidpiAdjustedBounds_getHidpiFactor(const HidpiAdjustedBounds* hidpiadjustedbounds);
         InlineTextHitVec InlineText_hitTest(const InlineText* inlinetext, AzLogicalPosition  position);
         void InlineText_delete(InlineText* restrict instance);
+        void TextLayout_delete(TextLayout* restrict instance);
         void InlineLine_delete(InlineLine* restrict instance);
         void InlineWord_delete(InlineWord* restrict instance);
         void InlineTextContents_delete(InlineTextContents* restrict instance);
@@ -10429,6 +12063,7 @@ namespace dll {
         OptionGl LayoutCallbackInfo_getGlContext(const LayoutCallbackInfo* layoutcallbackinfo);
         StringPairVec LayoutCallbackInfo_getSystemFonts(const LayoutCallbackInfo* layoutcallbackinfo);
         OptionImageRef LayoutCallbackInfo_getImage(const LayoutCallbackInfo* layoutcallbackinfo, AzString  id);
+        SystemStyle LayoutCallbackInfo_getSystemStyle(const LayoutCallbackInfo* layoutcallbackinfo);
         void LayoutCallbackInfo_delete(LayoutCallbackInfo* restrict instance);
         Dom Dom_new(AzNodeType  node_type);
         Dom Dom_body();
@@ -10437,6 +12072,10 @@ namespace dll {
         Dom Dom_text(AzString  string);
         Dom Dom_image(AzImageRef  image);
         Dom Dom_iframe(AzRefAny  data, AzIFrameCallbackType  callback);
+        Dom Dom_customLayout(AzRefAny  data, AzMeasureCallbackType  measure, AzArrangeCallbackType  arrange);
+        Dom Dom_customNode(AzString  type_name, AzRefAny  data);
+        Dom Dom_iframeWithReinvokeThreshold(AzRefAny  data, AzIFrameCallbackType  callback, float reinvoke_threshold);
+        Dom Dom_overlay(AzString  anchor, AzOverlayPlacement  placement);
         void Dom_setNodeType(Dom* restrict dom, AzNodeType  node_type);
         Dom Dom_withNodeType(Dom* restrict dom, AzNodeType  node_type);
         void Dom_setDataset(Dom* restrict dom, AzRefAny  dataset);
@@ -10483,6 +12122,14 @@ namespace dll {
         Dom Dom_withMenuBar(Dom* restrict dom, AzMenu  menu_bar);
         void Dom_setContextMenu(Dom* restrict dom, AzMenu  context_menu);
         Dom Dom_withContextMenu(Dom* restrict dom, AzMenu  context_menu);
+        void Dom_setFocusNavigation(Dom* restrict dom, AzFocusNavigation  focus_navigation);
+        Dom Dom_withFocusNavigation(Dom* restrict dom, AzFocusNavigation  focus_navigation);
+        void Dom_setLang(Dom* restrict dom, AzString  lang);
+        Dom Dom_withLang(Dom* restrict dom, AzString  lang);
+        void Dom_setInputMode(Dom* restrict dom, AzInputMode  input_mode);
+        Dom Dom_withInputMode(Dom* restrict dom, AzInputMode  input_mode);
+        void Dom_setStateFlag(Dom* restrict dom, AzNodeStateFlag  flag, bool  enabled);
+        Dom Dom_withStateFlag(Dom* restrict dom, AzNodeStateFlag  flag, bool  enabled);
         uint64_t Dom_hash(const Dom* dom);
         size_t Dom_nodeCount(const Dom* dom);
         String Dom_getHtmlString(Dom* restrict dom);
@@ -10490,6 +12137,11 @@ namespace dll {
         StyledDom Dom_style(Dom* restrict dom, AzCss  css);
         void Dom_delete(Dom* restrict instance);
         void IFrameNode_delete(IFrameNode* restrict instance);
+        void CustomLayoutNode_delete(CustomLayoutNode* restrict instance);
+        CustomNodeType CustomNodeType_new(AzString  name, AzMeasureCallbackType  measure, AzArrangeCallbackType  arrange);
+        CustomNodeType CustomNodeType_withPaint(const CustomNodeType customnodetype, AzPaintCallbackType  paint);
+        void CustomNodeType_register(const CustomNodeType customnodetype);
+        void CustomNodeType_delete(CustomNodeType* restrict instance);
         void CallbackData_delete(CallbackData* restrict instance);
         NodeData NodeData_new(AzNodeType  node_type);
         NodeData NodeData_body();
@@ -10498,6 +12150,8 @@ namespace dll {
         NodeData NodeData_text(AzString  string);
         NodeData NodeData_image(AzImageRef  image);
         NodeData NodeData_iframe(AzRefAny  data, AzIFrameCallbackType  callback);
+        NodeData NodeData_customLayout(AzRefAny  data, AzMeasureCallbackType  measure, AzArrangeCallbackType  arrange);
+        NodeData NodeData_iframeWithReinvokeThreshold(AzRefAny  data, AzIFrameCallbackType  callback, float reinvoke_threshold);
         void NodeData_setNodeType(NodeData* restrict nodedata, AzNodeType  node_type);
         NodeData NodeData_withNodeType(NodeData* restrict nodedata, AzNodeType  node_type);
         void NodeData_setDataset(NodeData* restrict nodedata, AzRefAny  dataset);
@@ -10523,11 +12177,18 @@ namespace dll {
         void NodeData_setAccessibilityInfo(NodeData* restrict nodedata, AzAccessibilityInfo  accessibility_info);
         void NodeData_setMenuBar(NodeData* restrict nodedata, AzMenu  menu_bar);
         void NodeData_setContextMenu(NodeData* restrict nodedata, AzMenu  context_menu);
+        void NodeData_setFocusNavigation(NodeData* restrict nodedata, AzFocusNavigation  focus_navigation);
         uint64_t NodeData_hash(const NodeData* nodedata);
+        void NodeData_setOverlay(NodeData* restrict nodedata, AzOverlayInfo  overlay);
+        void NodeData_setLang(NodeData* restrict nodedata, AzString  lang);
+        void NodeData_setInputMode(NodeData* restrict nodedata, AzInputMode  input_mode);
+        void NodeData_setStateFlag(NodeData* restrict nodedata, AzNodeStateFlag  flag, bool  enabled);
         void NodeData_delete(NodeData* restrict instance);
         void NodeType_delete(NodeType* restrict instance);
         EventFilter On_intoEventFilter(const On on);
         void AccessibilityInfo_delete(AccessibilityInfo* restrict instance);
+        void FocusNavigation_delete(FocusNavigation* restrict instance);
+        void OverlayInfo_delete(OverlayInfo* restrict instance);
         void IdOrClass_delete(IdOrClass* restrict instance);
         void NodeDataInlineCssProperty_delete(NodeDataInlineCssProperty* restrict instance);
         Menu Menu_new(AzMenuItemVec  items);
@@ -10546,10 +12207,19 @@ namespace dll {
         void VirtualKeyCodeCombo_delete(VirtualKeyCodeCombo* restrict instance);
         MenuCallback MenuCallback_new(AzRefAny  data, AzCallbackType  callback);
         void MenuCallback_delete(MenuCallback* restrict instance);
+        Shortcut Shortcut_new(AzVirtualKeyCodeCombo  keys, AzRefAny  data, AzCallbackType  callback);
+        void Shortcut_delete(Shortcut* restrict instance);
+        void ShortcutConflict_delete(ShortcutConflict* restrict instance);
+        ShortcutMap ShortcutMap_new();
+        OptionShortcutConflict ShortcutMap_add(ShortcutMap* restrict shortcutmap, AzShortcut  shortcut);
+        bool  ShortcutMap_remove(ShortcutMap* restrict shortcutmap, AzVirtualKeyCodeCombo  keys);
+        ShortcutConflictVec ShortcutMap_getConflicts(const ShortcutMap* shortcutmap);
+        void ShortcutMap_delete(ShortcutMap* restrict instance);
         void MenuItemIcon_delete(MenuItemIcon* restrict instance);
         void CssRuleBlock_delete(CssRuleBlock* restrict instance);
         void CssDeclaration_delete(CssDeclaration* restrict instance);
         void DynamicCssProperty_delete(DynamicCssProperty* restrict instance);
+        void SystemCssProperty_delete(SystemCssProperty* restrict instance);
         void CssPath_delete(CssPath* restrict instance);
         void CssPathSelector_delete(CssPathSelector* restrict instance);
         void Stylesheet_delete(Stylesheet* restrict instance);
@@ -10568,12 +12238,21 @@ namespace dll {
         void StyleBackgroundContent_delete(StyleBackgroundContent* restrict instance);
         void ScrollbarInfo_delete(ScrollbarInfo* restrict instance);
         void ScrollbarStyle_delete(ScrollbarStyle* restrict instance);
+        void StyleContent_delete(StyleContent* restrict instance);
+        void StyleCounterReset_delete(StyleCounterReset* restrict instance);
+        void StyleCounterIncrement_delete(StyleCounterIncrement* restrict instance);
+        void StyleCursor_delete(StyleCursor* restrict instance);
+        void StyleCursorImage_delete(StyleCursorImage* restrict instance);
         void StyleFontFamily_delete(StyleFontFamily* restrict instance);
         void ScrollbarStyleValue_delete(ScrollbarStyleValue* restrict instance);
+        void StyleContentValue_delete(StyleContentValue* restrict instance);
+        void StyleCounterResetValue_delete(StyleCounterResetValue* restrict instance);
+        void StyleCounterIncrementValue_delete(StyleCounterIncrementValue* restrict instance);
         void StyleBackgroundContentVecValue_delete(StyleBackgroundContentVecValue* restrict instance);
         void StyleBackgroundPositionVecValue_delete(StyleBackgroundPositionVecValue* restrict instance);
         void StyleBackgroundRepeatVecValue_delete(StyleBackgroundRepeatVecValue* restrict instance);
         void StyleBackgroundSizeVecValue_delete(StyleBackgroundSizeVecValue* restrict instance);
+        void StyleCursorValue_delete(StyleCursorValue* restrict instance);
         void StyleFontFamilyVecValue_delete(StyleFontFamilyVecValue* restrict instance);
         void StyleTransformVecValue_delete(StyleTransformVecValue* restrict instance);
         void StyleFilterVecValue_delete(StyleFilterVecValue* restrict instance);
@@ -10627,6 +12306,14 @@ namespace dll {
         TextInput TextInput_withOnVirtualKeyDown(TextInput* restrict textinput, AzRefAny  data, AzTextInputOnVirtualKeyDownCallbackType  callback);
         void TextInput_setOnFocusLost(TextInput* restrict textinput, AzRefAny  data, AzTextInputOnFocusLostCallbackType  callback);
         TextInput TextInput_withOnFocusLost(TextInput* restrict textinput, AzRefAny  data, AzTextInputOnFocusLostCallbackType  callback);
+        void TextInput_setCaretAnimation(TextInput* restrict textinput, AzCaretAnimation  caret_animation);
+        TextInput TextInput_withCaretAnimation(TextInput* restrict textinput, AzCaretAnimation  caret_animation);
+        void TextInput_setSpellcheck(TextInput* restrict textinput, AzString  language);
+        TextInput TextInput_withSpellcheck(TextInput* restrict textinput, AzString  language);
+        void TextInput_setOnSpellingSuggestions(TextInput* restrict textinput, AzRefAny  data, AzTextInputOnSpellingSuggestionsCallbackType  callback);
+        TextInput TextInput_withOnSpellingSuggestions(TextInput* restrict textinput, AzRefAny  data, AzTextInputOnSpellingSuggestionsCallbackType  callback);
+        void TextInput_setSecure(TextInput* restrict textinput, bool  secure);
+        TextInput TextInput_withSecure(TextInput* restrict textinput, bool  secure);
         void TextInput_setPlaceholderStyle(TextInput* restrict textinput, AzNodeDataInlineCssPropertyVec  placeholder_style);
         TextInput TextInput_withPlaceholderStyle(TextInput* restrict textinput, AzNodeDataInlineCssPropertyVec  placeholder_style);
         void TextInput_setContainerStyle(TextInput* restrict textinput, AzNodeDataInlineCssPropertyVec  container_style);
@@ -10641,7 +12328,9 @@ namespace dll {
         void TextInputOnTextInput_delete(TextInputOnTextInput* restrict instance);
         void TextInputOnVirtualKeyDown_delete(TextInputOnVirtualKeyDown* restrict instance);
         void TextInputOnFocusLost_delete(TextInputOnFocusLost* restrict instance);
-        NumberInput NumberInput_new(float number);
+        void TextInputOnSpellingSuggestions_delete(TextInputOnSpellingSuggestions* restrict instance);
+        void TextInputSpellingSuggestions_delete(TextInputSpellingSuggestions* restrict instance);
+        NumberInput NumberInput_new(double number);
         void NumberInput_setOnTextInput(NumberInput* restrict numberinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
         NumberInput NumberInput_withOnTextInput(NumberInput* restrict numberinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
         void NumberInput_setOnVirtualKeyDown(NumberInput* restrict numberinput, AzRefAny  data, AzTextInputOnVirtualKeyDownCallbackType  callback);
@@ -10656,6 +12345,20 @@ namespace dll {
         NumberInput NumberInput_withLabelStyle(NumberInput* restrict numberinput, AzNodeDataInlineCssPropertyVec  style);
         void NumberInput_setOnValueChange(NumberInput* restrict numberinput, AzRefAny  data, AzNumberInputOnValueChangeCallbackType  callback);
         NumberInput NumberInput_withOnValueChange(NumberInput* restrict numberinput, AzRefAny  data, AzNumberInputOnValueChangeCallbackType  callback);
+        void NumberInput_setSpinnerButtonStyle(NumberInput* restrict numberinput, AzNodeDataInlineCssPropertyVec  style);
+        NumberInput NumberInput_withSpinnerButtonStyle(NumberInput* restrict numberinput, AzNodeDataInlineCssPropertyVec  style);
+        void NumberInput_setInvalidStyle(NumberInput* restrict numberinput, AzNodeDataInlineCssPropertyVec  style);
+        NumberInput NumberInput_withInvalidStyle(NumberInput* restrict numberinput, AzNodeDataInlineCssPropertyVec  style);
+        void NumberInput_setMode(NumberInput* restrict numberinput, AzNumberInputMode  mode);
+        NumberInput NumberInput_withMode(NumberInput* restrict numberinput, AzNumberInputMode  mode);
+        void NumberInput_setMin(NumberInput* restrict numberinput, double min);
+        NumberInput NumberInput_withMin(NumberInput* restrict numberinput, double min);
+        void NumberInput_setMax(NumberInput* restrict numberinput, double max);
+        NumberInput NumberInput_withMax(NumberInput* restrict numberinput, double max);
+        void NumberInput_setStep(NumberInput* restrict numberinput, double step);
+        NumberInput NumberInput_withStep(NumberInput* restrict numberinput, double step);
+        void NumberInput_setDecimalSeparator(NumberInput* restrict numberinput, uint32_t decimal_separator);
+        NumberInput NumberInput_withDecimalSeparator(NumberInput* restrict numberinput, uint32_t decimal_separator);
         Dom NumberInput_dom(NumberInput* restrict numberinput);
         void NumberInput_delete(NumberInput* restrict instance);
         void NumberInputStateWrapper_delete(NumberInputStateWrapper* restrict instance);
@@ -10725,6 +12428,28 @@ namespace dll {
         Dom DropDown_dom(DropDown* restrict dropdown);
         void DropDown_delete(DropDown* restrict instance);
         void DropDownOnChoiceChange_delete(DropDownOnChoiceChange* restrict instance);
+        Toasts Toasts_new();
+        ToastId Toasts_show(Toasts* restrict toasts, AzCallbackInfo * restrict info, AzToast  toast);
+        bool  Toasts_dismiss(Toasts* restrict toasts, AzCallbackInfo * restrict info, AzToastId  toast_id);
+        void Toasts_setMaxVisible(Toasts* restrict toasts, size_t max_visible);
+        Toasts Toasts_withMaxVisible(Toasts* restrict toasts, size_t max_visible);
+        void Toasts_setLayerStyle(Toasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+        Toasts Toasts_withLayerStyle(Toasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+        void Toasts_setToastStyle(Toasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+        Toasts Toasts_withToastStyle(Toasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+        void Toasts_setLabelStyle(Toasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+        Toasts Toasts_withLabelStyle(Toasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+        void Toasts_setActionStyle(Toasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+        Toasts Toasts_withActionStyle(Toasts* restrict toasts, AzNodeDataInlineCssPropertyVec  style);
+        Dom Toasts_dom(Toasts* restrict toasts);
+        void Toasts_delete(Toasts* restrict instance);
+        Toast Toast_new(AzString  message);
+        void Toast_setDuration(Toast* restrict toast, AzOptionDuration  duration);
+        Toast Toast_withDuration(Toast* restrict toast, AzOptionDuration  duration);
+        void Toast_setAction(Toast* restrict toast, AzString  label, AzRefAny  data, AzToastOnActionCallbackType  callback);
+        Toast Toast_withAction(Toast* restrict toast, AzString  label, AzRefAny  data, AzToastOnActionCallbackType  callback);
+        void Toast_delete(Toast* restrict instance);
+        void ToastOnAction_delete(ToastOnAction* restrict instance);
         void CssPropertySource_delete(CssPropertySource* restrict instance);
         void TagIdToNodeIdMapping_delete(TagIdToNodeIdMapping* restrict instance);
         void CssPropertyCache_delete(CssPropertyCache* restrict instance);
@@ -10757,6 +12482,7 @@ namespace dll {
         void GlVoidPtrConst_delete(GlVoidPtrConst* restrict instance);
         GlVoidPtrConst GlVoidPtrConst_deepCopy(GlVoidPtrConst* const instance);
         GlType Gl_getType(const Gl* gl);
+        GlQuirks Gl_getQuirks(const Gl* gl);
         void Gl_bufferDataUntyped(const Gl* gl, uint32_t target, ssize_t size, AzGlVoidPtrConst  data, uint32_t usage);
         void Gl_bufferSubDataUntyped(const Gl* gl, uint32_t target, ssize_t offset, ssize_t size, AzGlVoidPtrConst  data);
         GlVoidPtrMut Gl_mapBuffer(const Gl* gl, uint32_t target, uint32_t access);
@@ -10979,6 +12705,13 @@ namespace dll {
         void Gl_flushMappedBufferRange(const Gl* gl, uint32_t target, ssize_t offset, ssize_t length);
         void Gl_delete(Gl* restrict instance);
         Gl Gl_deepCopy(Gl* const instance);
+        GlQuirkRule GlQuirkRule_new();
+        GlQuirkRule GlQuirkRule_withVendor(const GlQuirkRule glquirkrule, AzString  vendor);
+        GlQuirkRule GlQuirkRule_withRenderer(const GlQuirkRule glquirkrule, AzString  renderer);
+        GlQuirkRule GlQuirkRule_withVersion(const GlQuirkRule glquirkrule, AzString  version);
+        GlQuirkRule GlQuirkRule_withEnable(const GlQuirkRule glquirkrule, AzGlQuirks  enable);
+        GlQuirkRule GlQuirkRule_withDisable(const GlQuirkRule glquirkrule, AzGlQuirks  disable);
+        void GlQuirkRule_delete(GlQuirkRule* restrict instance);
         void VertexAttribute_delete(VertexAttribute* restrict instance);
         void VertexLayout_delete(VertexLayout* restrict instance);
         VertexArrayObject VertexArrayObject_new(AzVertexLayout  vertex_layout, uint32_t vao_id, AzGl  gl_context);
@@ -11211,6 +12944,10 @@ namespace dll {
         OptionThreadSendMsg ThreadReceiver_receive(ThreadReceiver* restrict threadreceiver);
         void ThreadReceiver_delete(ThreadReceiver* restrict instance);
         ThreadReceiver ThreadReceiver_deepCopy(ThreadReceiver* const instance);
+        bool  EventLoopProxy_wake(const EventLoopProxy* eventloopproxy);
+        bool  EventLoopProxy_sendUserEvent(const EventLoopProxy* eventloopproxy, AzRefAny  data);
+        void EventLoopProxy_delete(EventLoopProxy* restrict instance);
+        EventLoopProxy EventLoopProxy_deepCopy(EventLoopProxy* const instance);
         void ThreadSendMsg_delete(ThreadSendMsg* restrict instance);
         void ThreadReceiveMsg_delete(ThreadReceiveMsg* restrict instance);
         void ThreadWriteBackMsg_delete(ThreadWriteBackMsg* restrict instance);
@@ -11244,9 +12981,12 @@ namespace dll {
         void FmtArgVec_delete(FmtArgVec* restrict instance);
         void InlineLineVec_delete(InlineLineVec* restrict instance);
         void InlineWordVec_delete(InlineWordVec* restrict instance);
+        void PaintPrimitiveVec_delete(PaintPrimitiveVec* restrict instance);
         void InlineGlyphVec_delete(InlineGlyphVec* restrict instance);
+        void TextUnderlineVec_delete(TextUnderlineVec* restrict instance);
         void InlineTextHitVec_delete(InlineTextHitVec* restrict instance);
         void MonitorVec_delete(MonitorVec* restrict instance);
+        void TouchPointVec_delete(TouchPointVec* restrict instance);
         void VideoModeVec_delete(VideoModeVec* restrict instance);
         void DomVec_delete(DomVec* restrict instance);
         void IdOrClassVec_delete(IdOrClassVec* restrict instance);
@@ -11267,6 +13007,8 @@ namespace dll {
         void U32Vec_delete(U32Vec* restrict instance);
         void XWindowTypeVec_delete(XWindowTypeVec* restrict instance);
         void VirtualKeyCodeVec_delete(VirtualKeyCodeVec* restrict instance);
+        void ShortcutConflictVec_delete(ShortcutConflictVec* restrict instance);
+        void ShortcutVec_delete(ShortcutVec* restrict instance);
         void CascadeInfoVec_delete(CascadeInfoVec* restrict instance);
         void ScanCodeVec_delete(ScanCodeVec* restrict instance);
         void CssDeclarationVec_delete(CssDeclarationVec* restrict instance);
@@ -11297,6 +13039,7 @@ namespace dll {
         void OptionListViewOnLazyLoadScroll_delete(OptionListViewOnLazyLoadScroll* restrict instance);
         void OptionMenu_delete(OptionMenu* restrict instance);
         void OptionDropDownOnChoiceChange_delete(OptionDropDownOnChoiceChange* restrict instance);
+        void OptionToastOnAction_delete(OptionToastOnAction* restrict instance);
         void OptionResolvedTextLayoutOptions_delete(OptionResolvedTextLayoutOptions* restrict instance);
         void OptionNodeGraphOnNodeAdded_delete(OptionNodeGraphOnNodeAdded* restrict instance);
         void OptionNodeGraphOnNodeRemoved_delete(OptionNodeGraphOnNodeRemoved* restrict instance);
@@ -11314,11 +13057,13 @@ namespace dll {
         void OptionTextInputOnTextInput_delete(OptionTextInputOnTextInput* restrict instance);
         void OptionTextInputOnVirtualKeyDown_delete(OptionTextInputOnVirtualKeyDown* restrict instance);
         void OptionTextInputOnFocusLost_delete(OptionTextInputOnFocusLost* restrict instance);
+        void OptionTextInputOnSpellingSuggestions_delete(OptionTextInputOnSpellingSuggestions* restrict instance);
         void OptionNumberInputOnFocusLost_delete(OptionNumberInputOnFocusLost* restrict instance);
         void OptionNumberInputOnValueChange_delete(OptionNumberInputOnValueChange* restrict instance);
         void OptionMenuItemIcon_delete(OptionMenuItemIcon* restrict instance);
         void OptionMenuCallback_delete(OptionMenuCallback* restrict instance);
         void OptionVirtualKeyCodeCombo_delete(OptionVirtualKeyCodeCombo* restrict instance);
+        void OptionShortcutConflict_delete(OptionShortcutConflict* restrict instance);
         void OptionCssProperty_delete(OptionCssProperty* restrict instance);
         void OptionImageRef_delete(OptionImageRef* restrict instance);
         void OptionFontRef_delete(OptionFontRef* restrict instance);
@@ -11330,9 +13075,13 @@ namespace dll {
         void OptionFile_delete(OptionFile* restrict instance);
         void OptionGl_delete(OptionGl* restrict instance);
         void OptionThreadReceiveMsg_delete(OptionThreadReceiveMsg* restrict instance);
+        void OptionJankCapture_delete(OptionJankCapture* restrict instance);
+        void OptionEventRecording_delete(OptionEventRecording* restrict instance);
+        void OptionFrameRecording_delete(OptionFrameRecording* restrict instance);
         void OptionThreadSendMsg_delete(OptionThreadSendMsg* restrict instance);
         void OptionRefAny_delete(OptionRefAny* restrict instance);
         void OptionInlineText_delete(OptionInlineText* restrict instance);
+        void OptionTextLayout_delete(OptionTextLayout* restrict instance);
         void OptionRawImage_delete(OptionRawImage* restrict instance);
         void OptionWaylandTheme_delete(OptionWaylandTheme* restrict instance);
         void OptionTaskBarIcon_delete(OptionTaskBarIcon* restrict instance);
@@ -427,6 +427,7 @@ mod dll {
     }
 
     unsafe impl Send for AzThreadSender { }
+    unsafe impl Send for AzEventLoopProxy { }
 
     impl ::core::fmt::Debug for AzCallback                          { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { write!(f, "{:x}", self.cb as usize) }}
     
@@ -707,6 +708,26 @@ mod dll {
             Trace,
         }
 
+        /// `AzFrameRecordingCallbackType` struct
+        pub type AzFrameRecordingCallbackType = extern "C" fn(&AzRecordedFrame);
+
+        /// Function that is invoked with every recorded frame, once the next frame has been presented (or the window is closed)
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzFrameRecordingCallback {
+            pub cb: AzFrameRecordingCallbackType,
+        }
+
+        /// `AzCrashHandlerType` struct
+        pub type AzCrashHandlerType = extern "C" fn(&AzCrashInfo);
+
+        /// Function that is invoked when the app panics or crashes
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzCrashHandler {
+            pub cb: AzCrashHandlerType,
+        }
+
         /// Version of the layout solver to use - future binary versions of azul may have more fields here, necessary so that old compiled applications don't break with newer releases of azul. Newer layout versions are opt-in only.
         #[repr(C)]
         #[derive(Debug)]
@@ -718,6 +739,32 @@ mod dll {
             Default,
         }
 
+        /// Translucent backdrop of a window, see `WindowCreateOptions::background_effect`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+        pub enum AzWindowBackgroundEffect {
+            None,
+            Blur,
+            Mica,
+            MicaAlt,
+            Acrylic,
+        }
+
+        /// How the built-in scrollbars are drawn, see `ScrollbarConfig::mode`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzScrollbarMode {
+            Default,
+            Overlay,
+        }
+
         /// Whether the renderer has VSync enabled
         #[repr(C)]
         #[derive(Debug)]
@@ -1123,6 +1170,33 @@ mod dll {
             Cut,
         }
 
+        /// How urgently a screen reader should speak an announcement, see `CallbackInfo::announce`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzPoliteness {
+            Polite,
+            Assertive,
+        }
+
+        /// Kind of tactile feedback, see `CallbackInfo::trigger_haptic`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzHapticKind {
+            Selection,
+            LightImpact,
+            MediumImpact,
+            HeavyImpact,
+            Success,
+            Warning,
+            Error,
+        }
+
         /// State of the window frame (minimized, maximized, fullscreen or normal window)
         #[repr(C)]
         #[derive(Debug)]
@@ -1137,6 +1211,18 @@ mod dll {
             Fullscreen,
         }
 
+        /// How a window covers the monitor in fullscreen mode - the `usize` is the `Monitor::id` of the target monitor (the current monitor of the window if no monitor with that id exists)
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+        pub enum AzFullscreenMode {
+            Borderless(usize),
+            Exclusive(usize),
+        }
+
         /// Debugging information, will be rendered as an overlay on top of the UI
         #[repr(C)]
         #[derive(Debug)]
@@ -1174,6 +1260,19 @@ mod dll {
             pub force_picture_invalidation: bool,
         }
 
+        /// Modifier keys that influence which character a key produces, see `CallbackInfo::keycode_to_char`. `ctrl + alt` is treated as AltGr.
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzKeyboardModifiers {
+            pub shift: bool,
+            pub ctrl: bool,
+            pub alt: bool,
+            pub caps_lock: bool,
+        }
+
         /// Current icon of the mouse cursor
         #[repr(C)]
         #[derive(Debug)]
@@ -1276,14 +1375,29 @@ mod dll {
             LightMode,
         }
 
-        /// Current state of touch devices / touch inputs
+        /// Device that the last pointer input of the window came from
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub struct AzTouchState {
-            pub unused: u8,
+        pub enum AzInputModality {
+            Mouse,
+            Touch,
+            Pen,
+        }
+
+        /// HDR / wide color gamut capabilities of a monitor
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzHdrCapabilities {
+            pub supported: bool,
+            pub enabled: bool,
+            pub bits_per_color_channel: u32,
+            pub sdr_white_level: f32,
         }
 
         /// C-ABI stable wrapper over a `MarshaledLayoutCallbackInner`
@@ -1376,6 +1490,29 @@ mod dll {
             pub static_y_offset: f32,
         }
 
+        /// Line style of a `TextUnderline`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzTextUnderlineStyle {
+            Solid,
+            Dotted,
+            Wavy,
+        }
+
+        /// How the text caret of an editable node is animated
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzCaretAnimation {
+            Blink,
+            Fade,
+        }
+
         /// How should an animation repeat (loop, ping-pong, etc.)
         #[repr(C)]
         #[derive(Debug)]
@@ -1412,6 +1549,39 @@ mod dll {
         /// `AzIFrameCallbackType` struct
         pub type AzIFrameCallbackType = extern "C" fn(&mut AzRefAny, &mut AzIFrameCallbackInfo) -> AzIFrameCallbackReturn;
 
+        /// C-ABI wrapper over a `MeasureCallbackType`
+        #[repr(C)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub struct AzMeasureCallback {
+            pub cb: AzMeasureCallbackType,
+        }
+
+        /// `AzMeasureCallbackType` struct
+        pub type AzMeasureCallbackType = extern "C" fn(&mut AzRefAny, &AzMeasureCallbackInfo) -> AzLogicalSize;
+
+        /// C-ABI wrapper over an `ArrangeCallbackType`
+        #[repr(C)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub struct AzArrangeCallback {
+            pub cb: AzArrangeCallbackType,
+        }
+
+        /// `AzArrangeCallbackType` struct
+        pub type AzArrangeCallbackType = extern "C" fn(&mut AzRefAny, &AzArrangeCallbackInfo);
+
+        /// C-ABI wrapper over a `PaintCallbackType`
+        #[repr(C)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub struct AzPaintCallback {
+            pub cb: AzPaintCallbackType,
+        }
+
+        /// `AzPaintCallbackType` struct
+        pub type AzPaintCallbackType = extern "C" fn(&mut AzRefAny, &AzPaintCallbackInfo) -> AzPaintPrimitiveVec;
+
         /// Re-export of rust-allocated (stack based) `RenderImageCallback` struct
         #[repr(C)]
         #[derive(Clone)]
@@ -1482,6 +1652,9 @@ mod dll {
             LeftMouseUp,
             MiddleMouseUp,
             RightMouseUp,
+            DoubleClick,
+            TripleClick,
+            HoverIntent,
             MouseEnter,
             MouseLeave,
             Scroll,
@@ -1493,6 +1666,21 @@ mod dll {
             HoveredFileCancelled,
             FocusReceived,
             FocusLost,
+            UserIdle,
+            UserActive,
+            SessionEnding,
+            FullscreenEntered,
+            FullscreenExited,
+            WindowResized,
+            WindowMoved,
+            WindowFocusReceived,
+            WindowFocusLost,
+            DpiChanged,
+            Minimized,
+            Maximized,
+            Restored,
+            UserEvent,
+            InputModalityChanged,
         }
 
         /// Re-export of rust-allocated (stack based) `HoverEventFilter` struct
@@ -1511,6 +1699,9 @@ mod dll {
             LeftMouseUp,
             RightMouseUp,
             MiddleMouseUp,
+            DoubleClick,
+            TripleClick,
+            HoverIntent,
             MouseEnter,
             MouseLeave,
             Scroll,
@@ -1544,6 +1735,8 @@ mod dll {
             LeftMouseUp,
             RightMouseUp,
             MiddleMouseUp,
+            DoubleClick,
+            TripleClick,
             MouseEnter,
             MouseLeave,
             Scroll,
@@ -1572,6 +1765,9 @@ mod dll {
             LeftMouseUp,
             RightMouseUp,
             MiddleMouseUp,
+            DoubleClick,
+            TripleClick,
+            HoverIntent,
             MouseEnter,
             MouseLeave,
             Scroll,
@@ -1593,6 +1789,19 @@ mod dll {
             FocusLost,
             CloseRequested,
             ThemeChanged,
+            WindowFocusReceived,
+            WindowFocusLost,
+            UserIdle,
+            UserActive,
+            SessionEnding,
+            FullscreenEntered,
+            FullscreenExited,
+            DpiChanged,
+            Minimized,
+            Maximized,
+            Restored,
+            UserEvent,
+            InputModalityChanged,
         }
 
         /// Re-export of rust-allocated (stack based) `ComponentEventFilter` struct
@@ -1620,6 +1829,71 @@ mod dll {
             DeviceDisconnected,
         }
 
+        /// Direction of the spatial (arrow key) focus navigation
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzFocusDirection {
+            Up,
+            Down,
+            Left,
+            Right,
+        }
+
+        /// Kind of text that is entered into a text input node, same as the HTML `inputmode` attribute. Forwarded to the operating system so that touch keyboards and IMEs can show the appropriate layout
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzInputMode {
+            Text,
+            None,
+            Numeric,
+            Decimal,
+            Tel,
+            Email,
+            Url,
+            Search,
+            Password,
+        }
+
+        /// Semantic state of a node that is set by the application (instead of by the mouse / keyboard), matched by the `:disabled`, `:checked`, `:selected` and `:indeterminate` CSS selectors
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzNodeStateFlag {
+            Disabled,
+            Checked,
+            Selected,
+            Indeterminate,
+        }
+
+        /// Where an overlay is placed relative to its anchor node: the first part is the side of the anchor, the second part the alignment along that side (`BottomStart` = below the anchor, left edges aligned)
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzOverlayPlacement {
+            TopStart,
+            Top,
+            TopEnd,
+            BottomStart,
+            Bottom,
+            BottomEnd,
+            LeftStart,
+            Left,
+            LeftEnd,
+            RightStart,
+            Right,
+            RightEnd,
+        }
+
         /// MSAA Accessibility role constants. For information on what each role does, see the <a href="https://docs.microsoft.com/en-us/windows/win32/winauto/object-roles">MSDN Role Constants page</a>
         #[repr(C)]
         #[derive(Debug)]
@@ -1770,6 +2044,16 @@ mod dll {
             Disabled,
         }
 
+        /// Re-export of rust-allocated (stack based) `CssMediaCondition` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzCssMediaCondition {
+            pub matching_styles: u8,
+        }
+
         /// Re-export of rust-allocated (stack based) `NodeTypeKey` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -1811,6 +2095,7 @@ mod dll {
             LineHeight,
             WordSpacing,
             TabWidth,
+            Hyphens,
             Cursor,
             Display,
             Float,
@@ -1868,11 +2153,24 @@ mod dll {
             BoxShadowTop,
             BoxShadowBottom,
             ScrollbarStyle,
+            ScrollbarWidth,
+            ScrollbarColor,
+            ScrollbarThumbHoverColor,
+            ScrollbarThumbActiveColor,
+            WindowDragRegion,
+            CaretAnimationDuration,
+            ScrollPadding,
+            Content,
+            CounterReset,
+            CounterIncrement,
+            OverflowAnchor,
             Opacity,
             Transform,
             TransformOrigin,
             PerspectiveOrigin,
             BackfaceVisibility,
+            Perspective,
+            TransformStyle,
             MixBlendMode,
             Filter,
             BackdropFilter,
@@ -2186,43 +2484,57 @@ mod dll {
             Outset,
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleCursor` struct
+        /// Re-export of rust-allocated (stack based) `StyleScrollbarWidth` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleCursor {
-            Alias,
-            AllScroll,
-            Cell,
-            ColResize,
-            ContextMenu,
-            Copy,
-            Crosshair,
-            Default,
-            EResize,
-            EwResize,
-            Grab,
-            Grabbing,
-            Help,
+        pub enum AzStyleScrollbarWidth {
+            Auto,
+            Thin,
+            None,
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleOverflowAnchor` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleOverflowAnchor {
+            Auto,
+            None,
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleWindowDragRegion` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleWindowDragRegion {
+            None,
             Move,
-            NResize,
-            NsResize,
-            NeswResize,
-            NwseResize,
-            Pointer,
-            Progress,
-            RowResize,
-            SResize,
-            SeResize,
-            Text,
-            Unset,
-            VerticalText,
-            WResize,
-            Wait,
-            ZoomIn,
-            ZoomOut,
+            ResizeN,
+            ResizeNE,
+            ResizeE,
+            ResizeSE,
+            ResizeS,
+            ResizeSW,
+            ResizeW,
+            ResizeNW,
+            MaximizeButton,
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleCaretAnimationDuration` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzStyleCaretAnimationDuration {
+            pub inner: u32,
         }
 
         /// Re-export of rust-allocated (stack based) `StyleBackfaceVisibility` struct
@@ -2236,6 +2548,17 @@ mod dll {
             Visible,
         }
 
+        /// Whether the children of a node are flattened into its plane or positioned in the 3D space
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleTransformStyle {
+            Flat,
+            Preserve3D,
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleTextAlign` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -2248,6 +2571,18 @@ mod dll {
             Right,
         }
 
+        /// Whether words may be hyphenated when a line is broken inside of them
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleHyphens {
+            None,
+            Manual,
+            Auto,
+        }
+
         /// Re-export of rust-allocated (stack based) `Ribbon` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -2347,6 +2682,16 @@ mod dll {
         /// `AzTextInputOnFocusLostCallbackType` struct
         pub type AzTextInputOnFocusLostCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTextInputState) -> AzUpdate;
 
+        /// Re-export of rust-allocated (stack based) `TextInputOnSpellingSuggestionsCallback` struct
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzTextInputOnSpellingSuggestionsCallback {
+            pub cb: AzTextInputOnSpellingSuggestionsCallbackType,
+        }
+
+        /// `AzTextInputOnSpellingSuggestionsCallbackType` struct
+        pub type AzTextInputOnSpellingSuggestionsCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTextInputState, &AzTextInputSpellingSuggestions) -> AzUpdate;
+
         /// Re-export of rust-allocated (stack based) `TextInputValid` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -2357,16 +2702,15 @@ mod dll {
             No,
         }
 
-        /// Re-export of rust-allocated (stack based) `NumberInputState` struct
+        /// Whether the `NumberInput` accepts any number or only integers
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzNumberInputState {
-            pub previous: f32,
-            pub number: f32,
-            pub min: f32,
-            pub max: f32,
+        #[derive(Copy)]
+        pub enum AzNumberInputMode {
+            Integer,
+            Float,
         }
 
         /// Re-export of rust-allocated (stack based) `NumberInputOnValueChangeCallback` struct
@@ -2629,6 +2973,26 @@ mod dll {
             pub cb: AzDropDownOnChoiceChangeCallbackType,
         }
 
+        /// Identifies a toast that was shown with `Toasts::show`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzToastId {
+            pub id: usize,
+        }
+
+        /// Re-export of rust-allocated (stack based) `ToastOnActionCallback` struct
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzToastOnActionCallback {
+            pub cb: AzToastOnActionCallbackType,
+        }
+
+        /// `AzToastOnActionCallbackType` struct
+        pub type AzToastOnActionCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo) -> AzUpdate;
+
         /// Re-export of rust-allocated (stack based) `NodeHierarchyItem` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -2664,6 +3028,10 @@ mod dll {
             pub hover: bool,
             pub active: bool,
             pub focused: bool,
+            pub disabled: bool,
+            pub checked: bool,
+            pub selected: bool,
+            pub indeterminate: bool,
         }
 
         /// Re-export of rust-allocated (stack based) `TagId` struct
@@ -2703,16 +3071,31 @@ mod dll {
             pub(crate) ptr: *mut c_void,
         }
 
-        /// Re-export of rust-allocated (stack based) `GlShaderPrecisionFormatReturn` struct
+        /// Workarounds for driver bugs, applied when the renderer is created
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub struct AzGlShaderPrecisionFormatReturn {
-            pub _0: i32,
-            pub _1: i32,
-            pub _2: i32,
+        pub struct AzGlQuirks {
+            pub force_software_rendering: bool,
+            pub disable_subpixel_aa: bool,
+            pub disable_optimized_shaders: bool,
+            pub disable_program_cache: bool,
+            pub disable_multithreading: bool,
+            pub disable_partial_present: bool,
+        }
+
+        /// Re-export of rust-allocated (stack based) `GlShaderPrecisionFormatReturn` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzGlShaderPrecisionFormatReturn {
+            pub _0: i32,
+            pub _1: i32,
+            pub _2: i32,
         }
 
         /// Re-export of rust-allocated (stack based) `VertexAttributeType` struct
@@ -3385,6 +3768,15 @@ mod dll {
             pub run_destructor: bool,
         }
 
+        /// Handle to wake up the event loop from other threads, created by `App::create_event_loop_proxy()`. NOTE: user events are currently only delivered on Windows, the X11 event loop can't invoke callbacks or run timers yet and rejects them.
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzEventLoopProxy {
+            pub(crate) ptr: *const c_void,
+            pub run_destructor: bool,
+        }
+
         /// `AzCreateThreadFnType` struct
         pub type AzCreateThreadFnType = extern "C" fn(AzRefAny, AzRefAny, AzThreadCallback) -> AzThread;
 
@@ -3405,6 +3797,46 @@ mod dll {
             pub cb: AzGetSystemTimeFnType,
         }
 
+        /// `AzGetSystemIdleTimeFnType` struct
+        pub type AzGetSystemIdleTimeFnType = extern "C" fn() -> AzOptionDuration;
+
+        /// Get the time since the last user input on the entire system, `None` if the platform does not support querying the idle time
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzGetSystemIdleTimeFn {
+            pub cb: AzGetSystemIdleTimeFnType,
+        }
+
+        /// `AzGetLogLinesFnType` struct
+        pub type AzGetLogLinesFnType = extern "C" fn() -> AzStringVec;
+
+        /// Returns the most recent lines of the log ring buffer (oldest first)
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzGetLogLinesFn {
+            pub cb: AzGetLogLinesFnType,
+        }
+
+        /// `AzKeycodeToCharFnType` struct
+        pub type AzKeycodeToCharFnType = extern "C" fn(AzVirtualKeyCode, AzKeyboardModifiers) -> AzOptionChar;
+
+        /// Returns the character that the key produces with the given modifiers in the current keyboard layout of the operating system
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzKeycodeToCharFn {
+            pub cb: AzKeycodeToCharFnType,
+        }
+
+        /// `AzSetLogLevelFnType` struct
+        pub type AzSetLogLevelFnType = extern "C" fn(AzString, AzAppLogLevel);
+
+        /// Sets the log level of a subsystem at runtime
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzSetLogLevelFn {
+            pub cb: AzSetLogLevelFnType,
+        }
+
         /// `AzCheckThreadFinishedFnType` struct
         pub type AzCheckThreadFinishedFnType = extern "C" fn(&c_void) -> bool;
 
@@ -3758,6 +4190,19 @@ mod dll {
         /// `AzInlineWordVecDestructorType` struct
         pub type AzInlineWordVecDestructorType = extern "C" fn(&mut AzInlineWordVec);
 
+        /// Re-export of rust-allocated (stack based) `PaintPrimitiveVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzPaintPrimitiveVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzPaintPrimitiveVecDestructorType),
+        }
+
+        /// `AzPaintPrimitiveVecDestructorType` struct
+        pub type AzPaintPrimitiveVecDestructorType = extern "C" fn(&mut AzPaintPrimitiveVec);
+
         /// Re-export of rust-allocated (stack based) `InlineGlyphVecDestructor` struct
         #[repr(C, u8)]
         #[derive(Clone)]
@@ -3771,6 +4216,19 @@ mod dll {
         /// `AzInlineGlyphVecDestructorType` struct
         pub type AzInlineGlyphVecDestructorType = extern "C" fn(&mut AzInlineGlyphVec);
 
+        /// Re-export of rust-allocated (stack based) `TextUnderlineVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzTextUnderlineVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzTextUnderlineVecDestructorType),
+        }
+
+        /// `AzTextUnderlineVecDestructorType` struct
+        pub type AzTextUnderlineVecDestructorType = extern "C" fn(&mut AzTextUnderlineVec);
+
         /// Re-export of rust-allocated (stack based) `InlineTextHitVecDestructor` struct
         #[repr(C, u8)]
         #[derive(Clone)]
@@ -3797,6 +4255,19 @@ mod dll {
         /// `AzMonitorVecDestructorType` struct
         pub type AzMonitorVecDestructorType = extern "C" fn(&mut AzMonitorVec);
 
+        /// Re-export of rust-allocated (stack based) `TouchPointVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzTouchPointVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzTouchPointVecDestructorType),
+        }
+
+        /// `AzTouchPointVecDestructorType` struct
+        pub type AzTouchPointVecDestructorType = extern "C" fn(&mut AzTouchPointVec);
+
         /// Re-export of rust-allocated (stack based) `VideoModeVecDestructor` struct
         #[repr(C, u8)]
         #[derive(Clone)]
@@ -4057,6 +4528,32 @@ mod dll {
         /// `AzVirtualKeyCodeVecDestructorType` struct
         pub type AzVirtualKeyCodeVecDestructorType = extern "C" fn(&mut AzVirtualKeyCodeVec);
 
+        /// Re-export of rust-allocated (stack based) `ShortcutConflictVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzShortcutConflictVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzShortcutConflictVecDestructorType),
+        }
+
+        /// `AzShortcutConflictVecDestructorType` struct
+        pub type AzShortcutConflictVecDestructorType = extern "C" fn(&mut AzShortcutConflictVec);
+
+        /// Re-export of rust-allocated (stack based) `ShortcutVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzShortcutVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzShortcutVecDestructorType),
+        }
+
+        /// `AzShortcutVecDestructorType` struct
+        pub type AzShortcutVecDestructorType = extern "C" fn(&mut AzShortcutVec);
+
         /// Re-export of rust-allocated (stack based) `CascadeInfoVecDestructor` struct
         #[repr(C, u8)]
         #[derive(Clone)]
@@ -4471,6 +4968,22 @@ mod dll {
         pub struct AzSystemCallbacks {
             pub create_thread_fn: AzCreateThreadFn,
             pub get_system_time_fn: AzGetSystemTimeFn,
+            pub get_system_idle_time_fn: AzGetSystemIdleTimeFn,
+            pub get_log_lines_fn: AzGetLogLinesFn,
+            pub set_log_level_fn: AzSetLogLevelFn,
+            pub keycode_to_char_fn: AzKeycodeToCharFn,
+        }
+
+        /// What a window shows between being mapped and presenting the first rendered frame, see `WindowCreateOptions::first_frame`
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzFirstFrameMode {
+            ShowImmediately,
+            HideUntilRendered,
+            SplashColor(AzColorU),
         }
 
         /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
@@ -4555,12 +5068,17 @@ mod dll {
             pub has_decorations: bool,
             pub is_visible: bool,
             pub is_always_on_top: bool,
+            pub is_click_through: bool,
+            pub is_tool_window: bool,
             pub is_resizable: bool,
             pub has_focus: bool,
             pub has_extended_window_frame: bool,
             pub has_blur_behind_window: bool,
             pub smooth_scroll_enabled: bool,
             pub autotab_enabled: bool,
+            pub spatial_navigation_enabled: bool,
+            pub is_session_ending: bool,
+            pub fullscreen_mode: AzFullscreenMode,
         }
 
         /// Current position of the mouse cursor, relative to the window. Set to `Uninitialized` on startup (gets initialized on the first frame).
@@ -4600,6 +5118,18 @@ mod dll {
             Initialized(AzLogicalPosition),
         }
 
+        /// Single finger / stylus that is currently touching the screen
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzTouchPoint {
+            pub id: u64,
+            pub position: AzLogicalPosition,
+            pub force: f32,
+        }
+
         /// Describes a rendering configuration for a monitor
         #[repr(C)]
         #[derive(Debug)]
@@ -4636,6 +5166,32 @@ mod dll {
             Relative(AzPositionInfoInner),
         }
 
+        /// Post-layout geometry of a node, all rects are relative to the window origin
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzNodeRect {
+            pub margin_box: AzLogicalRect,
+            pub border_box: AzLogicalRect,
+            pub padding_box: AzLogicalRect,
+            pub content_box: AzLogicalRect,
+        }
+
+        /// Scroll extents of a scrollable (overflowing) node
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzScrollInfo {
+            pub scroll_position: AzLogicalPosition,
+            pub viewport: AzLogicalRect,
+            pub content_size: AzLogicalSize,
+            pub max_scroll: AzLogicalSize,
+        }
+
         /// Re-export of rust-allocated (stack based) `HidpiAdjustedBounds` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -4659,6 +5215,19 @@ mod dll {
             pub glyph_index: u32,
         }
 
+        /// Underlines a range of characters of a text node, see `CallbackInfo::set_text_underlines`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzTextUnderline {
+            pub start: usize,
+            pub end: usize,
+            pub color: AzColorU,
+            pub style: AzTextUnderlineStyle,
+        }
+
         /// Re-export of rust-allocated (stack based) `InlineTextHit` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -4701,6 +5270,49 @@ mod dll {
             pub _reserved_mut: *mut c_void,
         }
 
+        /// Re-export of rust-allocated (stack based) `MeasureCallbackInfo` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzMeasureCallbackInfo {
+            pub available_size: AzLogicalSize,
+            pub width_is_final: bool,
+        }
+
+        /// Re-export of rust-allocated (stack based) `ArrangeCallbackInfo` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzArrangeCallbackInfo {
+            pub bounds: AzNodeRect,
+        }
+
+        /// Re-export of rust-allocated (stack based) `PaintCallbackInfo` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzPaintCallbackInfo {
+            pub size: AzLogicalSize,
+        }
+
+        /// Filled (rounded) rectangle
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzPaintRect {
+            pub bounds: AzLogicalRect,
+            pub color: AzColorU,
+            pub border_radius: f32,
+        }
+
         /// Re-export of rust-allocated (stack based) `TimerCallbackReturn` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -4730,6 +5342,7 @@ mod dll {
         pub struct AzIFrameNode {
             pub callback: AzIFrameCallback,
             pub data: AzRefAny,
+            pub reinvoke_threshold: AzFloatValue,
         }
 
         /// Re-export of rust-allocated (stack based) `NotEventFilter` struct
@@ -4776,6 +5389,36 @@ mod dll {
             Pattern(AzCssNthChildPattern),
         }
 
+        /// Colors of the CSS system color keywords (`Canvas`, `CanvasText`, `Highlight`, ...)
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzSystemColors {
+            pub canvas: AzColorU,
+            pub canvas_text: AzColorU,
+            pub link_text: AzColorU,
+            pub gray_text: AzColorU,
+            pub highlight: AzColorU,
+            pub highlight_text: AzColorU,
+            pub button_face: AzColorU,
+            pub button_text: AzColorU,
+        }
+
+        /// High contrast / forced colors mode, reduced motion preference and system colors of the operating system
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzSystemStyle {
+            pub forced_colors: bool,
+            pub prefers_reduced_motion: bool,
+            pub pointer_coarse: bool,
+            pub colors: AzSystemColors,
+        }
+
         /// Re-export of rust-allocated (stack based) `PixelValue` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -5348,6 +5991,47 @@ mod dll {
             pub inner: AzPixelValue,
         }
 
+        /// Re-export of rust-allocated (stack based) `StyleScrollbarColor` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzStyleScrollbarColor {
+            pub thumb: AzColorU,
+            pub track: AzColorU,
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleScrollbarThumbHoverColor` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzStyleScrollbarThumbHoverColor {
+            pub inner: AzColorU,
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleScrollbarThumbActiveColor` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzStyleScrollbarThumbActiveColor {
+            pub inner: AzColorU,
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleScrollPadding` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzStyleScrollPadding {
+            pub inner: AzPixelValue,
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleFontSize` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -5420,6 +6104,16 @@ mod dll {
             pub y: AzPixelValue,
         }
 
+        /// Distance between the z = 0 plane and the viewer, applied to the children of the node
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzStylePerspective {
+            pub inner: AzPixelValue,
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleTransformMatrix2D` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -5984,242 +6678,340 @@ mod dll {
             Exact(AzLayoutOverflow),
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleBorderBottomColorValue` struct
+        /// Re-export of rust-allocated (stack based) `StyleScrollbarWidthValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleBorderBottomColorValue {
+        pub enum AzStyleScrollbarWidthValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBorderBottomColor),
+            Exact(AzStyleScrollbarWidth),
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleBorderBottomLeftRadiusValue` struct
+        /// Re-export of rust-allocated (stack based) `StyleScrollbarColorValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleBorderBottomLeftRadiusValue {
+        pub enum AzStyleScrollbarColorValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBorderBottomLeftRadius),
+            Exact(AzStyleScrollbarColor),
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleBorderBottomRightRadiusValue` struct
+        /// Re-export of rust-allocated (stack based) `StyleScrollbarThumbHoverColorValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleBorderBottomRightRadiusValue {
+        pub enum AzStyleScrollbarThumbHoverColorValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBorderBottomRightRadius),
+            Exact(AzStyleScrollbarThumbHoverColor),
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleBorderBottomStyleValue` struct
+        /// Re-export of rust-allocated (stack based) `StyleScrollbarThumbActiveColorValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleBorderBottomStyleValue {
+        pub enum AzStyleScrollbarThumbActiveColorValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBorderBottomStyle),
+            Exact(AzStyleScrollbarThumbActiveColor),
         }
 
-        /// Re-export of rust-allocated (stack based) `LayoutBorderBottomWidthValue` struct
+        /// Re-export of rust-allocated (stack based) `StyleWindowDragRegionValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzLayoutBorderBottomWidthValue {
+        pub enum AzStyleWindowDragRegionValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzLayoutBorderBottomWidth),
+            Exact(AzStyleWindowDragRegion),
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleBorderLeftColorValue` struct
+        /// Re-export of rust-allocated (stack based) `StyleCaretAnimationDurationValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleBorderLeftColorValue {
+        pub enum AzStyleCaretAnimationDurationValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBorderLeftColor),
+            Exact(AzStyleCaretAnimationDuration),
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleBorderLeftStyleValue` struct
+        /// Re-export of rust-allocated (stack based) `StyleScrollPaddingValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleBorderLeftStyleValue {
+        pub enum AzStyleScrollPaddingValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBorderLeftStyle),
+            Exact(AzStyleScrollPadding),
         }
 
-        /// Re-export of rust-allocated (stack based) `LayoutBorderLeftWidthValue` struct
+        /// Re-export of rust-allocated (stack based) `StyleOverflowAnchorValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzLayoutBorderLeftWidthValue {
+        pub enum AzStyleOverflowAnchorValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzLayoutBorderLeftWidth),
+            Exact(AzStyleOverflowAnchor),
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleBorderRightColorValue` struct
+        /// Re-export of rust-allocated (stack based) `StyleBorderBottomColorValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleBorderRightColorValue {
+        pub enum AzStyleBorderBottomColorValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBorderRightColor),
+            Exact(AzStyleBorderBottomColor),
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleBorderRightStyleValue` struct
+        /// Re-export of rust-allocated (stack based) `StyleBorderBottomLeftRadiusValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleBorderRightStyleValue {
+        pub enum AzStyleBorderBottomLeftRadiusValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBorderRightStyle),
+            Exact(AzStyleBorderBottomLeftRadius),
         }
 
-        /// Re-export of rust-allocated (stack based) `LayoutBorderRightWidthValue` struct
+        /// Re-export of rust-allocated (stack based) `StyleBorderBottomRightRadiusValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzLayoutBorderRightWidthValue {
+        pub enum AzStyleBorderBottomRightRadiusValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzLayoutBorderRightWidth),
+            Exact(AzStyleBorderBottomRightRadius),
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleBorderTopColorValue` struct
+        /// Re-export of rust-allocated (stack based) `StyleBorderBottomStyleValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleBorderTopColorValue {
+        pub enum AzStyleBorderBottomStyleValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBorderTopColor),
+            Exact(AzStyleBorderBottomStyle),
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleBorderTopLeftRadiusValue` struct
+        /// Re-export of rust-allocated (stack based) `LayoutBorderBottomWidthValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleBorderTopLeftRadiusValue {
+        pub enum AzLayoutBorderBottomWidthValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBorderTopLeftRadius),
+            Exact(AzLayoutBorderBottomWidth),
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleBorderTopRightRadiusValue` struct
+        /// Re-export of rust-allocated (stack based) `StyleBorderLeftColorValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleBorderTopRightRadiusValue {
+        pub enum AzStyleBorderLeftColorValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBorderTopRightRadius),
+            Exact(AzStyleBorderLeftColor),
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleBorderTopStyleValue` struct
+        /// Re-export of rust-allocated (stack based) `StyleBorderLeftStyleValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleBorderTopStyleValue {
+        pub enum AzStyleBorderLeftStyleValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBorderTopStyle),
+            Exact(AzStyleBorderLeftStyle),
         }
 
-        /// Re-export of rust-allocated (stack based) `LayoutBorderTopWidthValue` struct
+        /// Re-export of rust-allocated (stack based) `LayoutBorderLeftWidthValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzLayoutBorderTopWidthValue {
+        pub enum AzLayoutBorderLeftWidthValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzLayoutBorderTopWidth),
+            Exact(AzLayoutBorderLeftWidth),
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleCursorValue` struct
+        /// Re-export of rust-allocated (stack based) `StyleBorderRightColorValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleCursorValue {
+        pub enum AzStyleBorderRightColorValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStyleCursor),
+            Exact(AzStyleBorderRightColor),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleBorderRightStyleValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleBorderRightStyleValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleBorderRightStyle),
+        }
+
+        /// Re-export of rust-allocated (stack based) `LayoutBorderRightWidthValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzLayoutBorderRightWidthValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzLayoutBorderRightWidth),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleBorderTopColorValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleBorderTopColorValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleBorderTopColor),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleBorderTopLeftRadiusValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleBorderTopLeftRadiusValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleBorderTopLeftRadius),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleBorderTopRightRadiusValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleBorderTopRightRadiusValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleBorderTopRightRadius),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleBorderTopStyleValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleBorderTopStyleValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleBorderTopStyle),
+        }
+
+        /// Re-export of rust-allocated (stack based) `LayoutBorderTopWidthValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzLayoutBorderTopWidthValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzLayoutBorderTopWidth),
         }
 
         /// Re-export of rust-allocated (stack based) `StyleFontSizeValue` struct
@@ -6278,6 +7070,20 @@ mod dll {
             Exact(AzStyleTabWidth),
         }
 
+        /// Re-export of rust-allocated (stack based) `StyleHyphensValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleHyphensValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleHyphens),
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleTextAlignValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -6376,6 +7182,34 @@ mod dll {
             Exact(AzStyleBackfaceVisibility),
         }
 
+        /// Re-export of rust-allocated (stack based) `StylePerspectiveValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStylePerspectiveValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStylePerspective),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleTransformStyleValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleTransformStyleValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleTransformStyle),
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleMixBlendModeValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -6479,6 +7313,16 @@ mod dll {
             pub callback: AzTextInputOnFocusLostCallback,
         }
 
+        /// Re-export of rust-allocated (stack based) `TextInputOnSpellingSuggestions` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzTextInputOnSpellingSuggestions {
+            pub data: AzRefAny,
+            pub callback: AzTextInputOnSpellingSuggestionsCallback,
+        }
+
         /// Re-export of rust-allocated (stack based) `OnTextInputReturn` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -6489,6 +7333,22 @@ mod dll {
             pub valid: AzTextInputValid,
         }
 
+        /// Re-export of rust-allocated (stack based) `NumberInputState` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNumberInputState {
+            pub previous: f64,
+            pub number: f64,
+            pub min: f64,
+            pub max: f64,
+            pub step: f64,
+            pub mode: AzNumberInputMode,
+            pub decimal_separator: u32,
+            pub is_valid: bool,
+        }
+
         /// Re-export of rust-allocated (stack based) `NumberInputOnValueChange` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -6659,6 +7519,16 @@ mod dll {
             pub callback: AzDropDownOnChoiceChangeCallback,
         }
 
+        /// Re-export of rust-allocated (stack based) `ToastOnAction` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzToastOnAction {
+            pub data: AzRefAny,
+            pub callback: AzToastOnActionCallback,
+        }
+
         /// Re-export of rust-allocated (stack based) `ParentWithNodeDepth` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -6945,6 +7815,15 @@ mod dll {
             pub destructor: AzInlineGlyphVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<TextUnderline>`
+        #[repr(C)]
+        pub struct AzTextUnderlineVec {
+            pub(crate) ptr: *const AzTextUnderline,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzTextUnderlineVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<InlineTextHit>`
         #[repr(C)]
         pub struct AzInlineTextHitVec {
@@ -6954,6 +7833,15 @@ mod dll {
             pub destructor: AzInlineTextHitVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<TouchPoint>`
+        #[repr(C)]
+        pub struct AzTouchPointVec {
+            pub(crate) ptr: *const AzTouchPoint,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzTouchPointVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<VideoMode>`
         #[repr(C)]
         pub struct AzVideoModeVec {
@@ -7212,6 +8100,16 @@ mod dll {
             Some(AzDropDownOnChoiceChange),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionToastOnAction` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionToastOnAction {
+            None,
+            Some(AzToastOnAction),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeAdded` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -7372,6 +8270,16 @@ mod dll {
             Some(AzTextInputOnFocusLost),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionTextInputOnSpellingSuggestions` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionTextInputOnSpellingSuggestions {
+            None,
+            Some(AzTextInputOnSpellingSuggestions),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionTextInputSelection` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -7432,6 +8340,28 @@ mod dll {
             Some(AzPositionInfo),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionNodeRect` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzOptionNodeRect {
+            None,
+            Some(AzNodeRect),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionScrollInfo` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzOptionScrollInfo {
+            None,
+            Some(AzScrollInfo),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionTimerId` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -7536,6 +8466,28 @@ mod dll {
             Some(AzCallback),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionPaintCallback` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzOptionPaintCallback {
+            None,
+            Some(AzPaintCallback),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionCrashHandler` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzOptionCrashHandler {
+            None,
+            Some(AzCrashHandler),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionThreadSendMsg` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -7644,6 +8596,17 @@ mod dll {
             Some(AzSvgDashPattern),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionRawWindowHandle` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzOptionRawWindowHandle {
+            None,
+            Some(AzRawWindowHandle),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionLogicalPosition` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -7830,31 +8793,57 @@ mod dll {
             pub pos: AzSvgParseErrorPosition,
         }
 
-        /// Configuration for optional features, such as whether to enable logging or panic hooks
+        /// Startup splash screen (logo + progress bar), see `App::with_splash`
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzAppConfig {
-            pub layout_solver: AzLayoutSolver,
-            pub log_level: AzAppLogLevel,
-            pub enable_visual_panic_hook: bool,
-            pub enable_logging_on_panic: bool,
-            pub enable_tab_navigation: bool,
-            pub system_callbacks: AzSystemCallbacks,
+        pub struct AzSplashOptions {
+            pub logo: AzOptionImageRef,
+            pub size: AzLogicalSize,
+            pub background_color: AzColorU,
+            pub progress_bar_color: AzColorU,
+            pub cross_fade: AzDuration,
         }
 
-        /// Small (16x16x4) window icon, usually shown in the window titlebar
+        /// A frame that was presented by a window, passed to the `FrameRecordingCallback`
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzSmallWindowIconBytes {
-            pub key: AzIconKey,
-            pub rgba_bytes: AzU8Vec,
+        pub struct AzRecordedFrame {
+            pub window_handle: AzRawWindowHandle,
+            pub frame_index: usize,
+            pub timestamp: AzDuration,
+            pub duration: AzDuration,
+            pub image: AzImageRef,
         }
 
-        /// Large (32x32x4) window icon, usually used on high-resolution displays (instead of `SmallWindowIcon`)
+        /// Behavior of the built-in scrollbars of a window, see `WindowCreateOptions::scrollbars`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzScrollbarConfig {
+            pub mode: AzScrollbarMode,
+            pub min_thumb_length: f32,
+            pub hit_area_margin: f32,
+            pub auto_hide_delay: AzOptionDuration,
+            pub auto_hide_fade_duration: AzDuration,
+        }
+
+        /// Small (16x16x4) window icon, usually shown in the window titlebar
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzSmallWindowIconBytes {
+            pub key: AzIconKey,
+            pub rgba_bytes: AzU8Vec,
+        }
+
+        /// Large (32x32x4) window icon, usually used on high-resolution displays (instead of `SmallWindowIcon`)
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
@@ -7896,6 +8885,10 @@ mod dll {
             pub dpi: u32,
             pub min_dimensions: AzOptionLogicalSize,
             pub max_dimensions: AzOptionLogicalSize,
+            pub min_dimensions_from_content: bool,
+            pub resize_increments: AzOptionLogicalSize,
+            pub aspect_ratio_lock: AzOptionF32,
+            pub zoom: f32,
         }
 
         /// Current keyboard state, stores what keys / characters have been pressed
@@ -7908,6 +8901,9 @@ mod dll {
             pub current_virtual_keycode: AzOptionVirtualKeyCode,
             pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
             pub pressed_scancodes: AzScanCodeVec,
+            pub caps_lock_enabled: bool,
+            pub num_lock_enabled: bool,
+            pub scroll_lock_enabled: bool,
         }
 
         /// Current mouse / cursor state
@@ -7922,10 +8918,21 @@ mod dll {
             pub left_down: bool,
             pub right_down: bool,
             pub middle_down: bool,
+            pub click_count: u32,
             pub scroll_x: AzOptionF32,
             pub scroll_y: AzOptionF32,
         }
 
+        /// Current state of touch devices / touch inputs
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzTouchState {
+            pub touches: AzTouchPointVec,
+            pub input_modality: AzInputModality,
+        }
+
         /// C-ABI stable wrapper over a `MarshaledLayoutCallback`
         #[repr(C)]
         #[derive(Debug)]
@@ -7978,6 +8985,16 @@ mod dll {
             CubicBezier(AzSvgCubicCurve),
         }
 
+        /// Primitive drawn by a `PaintCallback`
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzPaintPrimitive {
+            Rect(AzPaintRect),
+        }
+
         /// Re-export of rust-allocated (stack based) `RenderImageCallbackInfo` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -8013,6 +9030,18 @@ mod dll {
             pub _reserved_mut: *mut c_void,
         }
 
+        /// Re-export of rust-allocated (stack based) `CustomLayoutNode` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzCustomLayoutNode {
+            pub measure: AzMeasureCallback,
+            pub arrange: AzArrangeCallback,
+            pub paint: AzOptionPaintCallback,
+            pub data: AzRefAny,
+        }
+
         /// Re-export of rust-allocated (stack based) `EventFilter` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -8048,6 +9077,28 @@ mod dll {
             pub keys: AzVirtualKeyCodeVec,
         }
 
+        /// Window-level keyboard shortcut: invokes the callback when the key combination is pressed, instead of the `VirtualKeyDown` callbacks of the focused node
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzShortcut {
+            pub keys: AzVirtualKeyCodeCombo,
+            pub callback: AzCallback,
+            pub data: AzRefAny,
+        }
+
+        /// Two shortcuts in a `ShortcutMap` that use the same key combination
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzShortcutConflict {
+            pub keys: AzVirtualKeyCodeCombo,
+            pub existing: usize,
+            pub conflicting: usize,
+        }
+
         /// Re-export of rust-allocated (stack based) `CssPathPseudoSelector` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -8061,6 +9112,12 @@ mod dll {
             Hover,
             Active,
             Focus,
+            Disabled,
+            Checked,
+            Selected,
+            Indeterminate,
+            Before,
+            After,
         }
 
         /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
@@ -8225,17 +9282,6 @@ mod dll {
             pub on_toggle: AzOptionCheckBoxOnToggle,
         }
 
-        /// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
-        #[repr(C)]
-        #[derive(Debug)]
-        #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        pub struct AzNumberInputStateWrapper {
-            pub inner: AzNumberInputState,
-            pub on_value_change: AzOptionNumberInputOnValueChange,
-            pub on_focus_lost: AzOptionNumberInputOnFocusLost,
-        }
-
         /// Re-export of rust-allocated (stack based) `NodeGraphCallbacks` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -8527,6 +9573,15 @@ mod dll {
             pub destructor: AzTessellatedColoredSvgNodeVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<PaintPrimitive>`
+        #[repr(C)]
+        pub struct AzPaintPrimitiveVec {
+            pub(crate) ptr: *const AzPaintPrimitive,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzPaintPrimitiveVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<StyleTransform>`
         #[repr(C)]
         pub struct AzStyleTransformVec {
@@ -8545,6 +9600,24 @@ mod dll {
             pub destructor: AzSvgPathElementVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `ShortcutConflict`
+        #[repr(C)]
+        pub struct AzShortcutConflictVec {
+            pub(crate) ptr: *const AzShortcutConflict,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzShortcutConflictVecDestructor,
+        }
+
+        /// Wrapper over a Rust-allocated `Shortcut`
+        #[repr(C)]
+        pub struct AzShortcutVec {
+            pub(crate) ptr: *const AzShortcut,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzShortcutVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `StringVec`
         #[repr(C)]
         pub struct AzStringVec {
@@ -8602,6 +9675,16 @@ mod dll {
             Some(AzVirtualKeyCodeCombo),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionShortcutConflict` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionShortcutConflict {
+            None,
+            Some(AzShortcutConflict),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionMouseState` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -8754,6 +9837,52 @@ mod dll {
             pub pos: AzSvgParseErrorPosition,
         }
 
+        /// Configuration of the jank detector, see `AppConfig::jank_capture`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzJankCapture {
+            pub threshold: AzDuration,
+            pub dump_display_list: bool,
+            pub output_directory: AzOptionString,
+        }
+
+        /// Whether input events are recorded or replayed, see `AppConfig::event_recording`
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzEventRecording {
+            Record(AzString),
+            Replay(AzString),
+        }
+
+        /// Where the frames of a `FrameRecording` are written to
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzFrameRecordingOutput {
+            GifDirectory(AzString),
+            Callback(AzFrameRecordingCallback),
+        }
+
+        /// Information about a panic or crash, passed to the `CrashHandler`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzCrashInfo {
+            pub message: AzString,
+            pub location: AzString,
+            pub thread_name: AzString,
+            pub backtrace: AzString,
+            pub last_log_lines: AzStringVec,
+            pub window_state_summary: AzString,
+            pub minidump_path: AzOptionString,
+        }
+
         /// Window configuration specific to Win32
         #[repr(C)]
         #[derive(Debug)]
@@ -8843,6 +9972,7 @@ mod dll {
             pub scale_factor: f64,
             pub video_modes: AzVideoModeVec,
             pub is_primary_monitor: bool,
+            pub hdr: AzHdrCapabilities,
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutCallback` struct
@@ -8867,6 +9997,18 @@ mod dll {
             Word(AzInlineTextContents),
         }
 
+        /// Node type defined outside of azul (map view, terminal widget, ...): the measure / arrange / paint hooks that all nodes of this type share
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzCustomNodeType {
+            pub name: AzString,
+            pub measure: AzMeasureCallback,
+            pub arrange: AzArrangeCallback,
+            pub paint: AzOptionPaintCallback,
+        }
+
         /// Re-export of rust-allocated (stack based) `CallbackData` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -8890,6 +10032,7 @@ mod dll {
             Text(AzString),
             Image(AzImageRef),
             IFrame(AzIFrameNode),
+            CustomLayout(AzCustomLayoutNode),
         }
 
         /// Accessibility information (MSAA wrapper). See `NodeData.set_accessibility_info()`
@@ -8906,6 +10049,28 @@ mod dll {
             pub default_action: AzOptionString,
         }
 
+        /// Per-node overrides for the spatial focus navigation, similar to the `nav-up: #id` CSS properties
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzFocusNavigation {
+            pub up: AzOptionString,
+            pub down: AzOptionString,
+            pub left: AzOptionString,
+            pub right: AzOptionString,
+        }
+
+        /// Marks a node as an overlay: the node is taken out of the regular layout, positioned next to the node with the ID `anchor` and rendered above all other content, clipped only by the window. If the overlay doesn't fit on the requested side, it is flipped to the opposite side and shifted back into the window
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzOverlayInfo {
+            pub anchor: AzString,
+            pub placement: AzOverlayPlacement,
+        }
+
         /// Re-export of rust-allocated (stack based) `IdOrClass` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -8930,6 +10095,15 @@ mod dll {
             pub children: AzMenuItemVec,
         }
 
+        /// List of window-level keyboard shortcuts
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzShortcutMap {
+            pub shortcuts: AzShortcutVec,
+        }
+
         /// Re-export of rust-allocated (stack based) `CssPathSelector` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -8984,6 +10158,49 @@ mod dll {
             pub vertical: AzScrollbarInfo,
         }
 
+        /// Re-export of rust-allocated (stack based) `StyleContent` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzStyleContent {
+            None,
+            Text(AzString),
+            Image(AzString),
+            Counter(AzString),
+        }
+
+        /// Represents a `counter-reset` attribute: `counter-reset: name [<integer>]`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleCounterReset {
+            pub counter: AzString,
+            pub value: i32,
+        }
+
+        /// Represents a `counter-increment` attribute: `counter-increment: name [<integer>]`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleCounterIncrement {
+            pub counter: AzString,
+            pub value: i32,
+        }
+
+        /// Custom cursor image, the hotspot is in pixels from the top left of the image
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleCursorImage {
+            pub image: AzString,
+            pub hotspot_x: u32,
+            pub hotspot_y: u32,
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleFontFamily` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -9008,6 +10225,45 @@ mod dll {
             Exact(AzScrollbarStyle),
         }
 
+        /// Re-export of rust-allocated (stack based) `StyleContentValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzStyleContentValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleContent),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleCounterResetValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzStyleCounterResetValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleCounterReset),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleCounterIncrementValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzStyleCounterIncrementValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleCounterIncrement),
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleTransformVecValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -9067,6 +10323,17 @@ mod dll {
             pub cursor_pos: usize,
         }
 
+        /// Misspelled word that was right-clicked
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzTextInputSpellingSuggestions {
+            pub range: AzTextInputSelectionRange,
+            pub word: AzString,
+            pub suggestions: AzStringVec,
+        }
+
         /// Re-export of rust-allocated (stack based) `TabHeader` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -9167,6 +10434,31 @@ mod dll {
             pub on_choice_change: AzOptionDropDownOnChoiceChange,
         }
 
+        /// Single notification message
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzToast {
+            pub message: AzString,
+            pub duration: AzOptionDuration,
+            pub action_label: AzString,
+            pub on_action: AzOptionToastOnAction,
+        }
+
+        /// Enables / disables workarounds for all drivers that match the rule. The patterns are matched case-insensitively against substrings of the `GL_VENDOR`, `GL_RENDERER` and `GL_VERSION` strings, a `None` pattern matches every driver. The `version` pattern can also compare version numbers: `"Mesa < 21.2"` matches if `GL_VERSION` contains "Mesa" and the number after it is lower than 21.2, without a prefix (`">= 4.5"`) the first number of `GL_VERSION` is compared. Supported operators are `<`, `<=`, `>`, `>=` and `==`.
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzGlQuirkRule {
+            pub vendor: AzOptionString,
+            pub renderer: AzOptionString,
+            pub version: AzOptionString,
+            pub enable: AzGlQuirks,
+            pub disable: AzGlQuirks,
+        }
+
         /// Re-export of rust-allocated (stack based) `VertexAttribute` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -9464,6 +10756,26 @@ mod dll {
             Some(AzFile),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionJankCapture` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionJankCapture {
+            None,
+            Some(AzJankCapture),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionEventRecording` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionEventRecording {
+            None,
+            Some(AzEventRecording),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionRawImage` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -9515,6 +10827,16 @@ mod dll {
             InvalidCharacterData,
         }
 
+        /// Recording of the presented frames of all windows, see `AppConfig::frame_recording`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzFrameRecording {
+            pub output: AzFrameRecordingOutput,
+            pub max_fps: u32,
+        }
+
         /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -9565,17 +10887,69 @@ mod dll {
             pub selectors: AzCssPathSelectorVec,
         }
 
+        /// Re-export of rust-allocated (stack based) `StyleCursor` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzStyleCursor {
+            Alias,
+            AllScroll,
+            Cell,
+            ColResize,
+            ContextMenu,
+            Copy,
+            Crosshair,
+            Default,
+            EResize,
+            EwResize,
+            Grab,
+            Grabbing,
+            Help,
+            Move,
+            NResize,
+            NsResize,
+            NeswResize,
+            NwseResize,
+            Pointer,
+            Progress,
+            RowResize,
+            SResize,
+            SeResize,
+            Text,
+            Unset,
+            VerticalText,
+            WResize,
+            Wait,
+            ZoomIn,
+            ZoomOut,
+            Image(AzStyleCursorImage),
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub enum AzStyleBackgroundContentVecValue {
+        pub enum AzStyleBackgroundContentVecValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleBackgroundContentVec),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleCursorValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzStyleCursorValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBackgroundContentVec),
+            Exact(AzStyleCursor),
         }
 
         /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecValue` struct
@@ -9605,6 +10979,7 @@ mod dll {
             LineHeight(AzStyleLineHeightValue),
             WordSpacing(AzStyleWordSpacingValue),
             TabWidth(AzStyleTabWidthValue),
+            Hyphens(AzStyleHyphensValue),
             Cursor(AzStyleCursorValue),
             Display(AzLayoutDisplayValue),
             Float(AzLayoutFloatValue),
@@ -9662,11 +11037,24 @@ mod dll {
             BoxShadowTop(AzStyleBoxShadowValue),
             BoxShadowBottom(AzStyleBoxShadowValue),
             ScrollbarStyle(AzScrollbarStyleValue),
+            ScrollbarWidth(AzStyleScrollbarWidthValue),
+            ScrollbarColor(AzStyleScrollbarColorValue),
+            ScrollbarThumbHoverColor(AzStyleScrollbarThumbHoverColorValue),
+            ScrollbarThumbActiveColor(AzStyleScrollbarThumbActiveColorValue),
+            WindowDragRegion(AzStyleWindowDragRegionValue),
+            CaretAnimationDuration(AzStyleCaretAnimationDurationValue),
+            ScrollPadding(AzStyleScrollPaddingValue),
+            Content(AzStyleContentValue),
+            CounterReset(AzStyleCounterResetValue),
+            CounterIncrement(AzStyleCounterIncrementValue),
+            OverflowAnchor(AzStyleOverflowAnchorValue),
             Opacity(AzStyleOpacityValue),
             Transform(AzStyleTransformVecValue),
             TransformOrigin(AzStyleTransformOriginValue),
             PerspectiveOrigin(AzStylePerspectiveOriginValue),
             BackfaceVisibility(AzStyleBackfaceVisibilityValue),
+            Perspective(AzStylePerspectiveValue),
+            TransformStyle(AzStyleTransformStyleValue),
             MixBlendMode(AzStyleMixBlendModeValue),
             Filter(AzStyleFilterVecValue),
             BackdropFilter(AzStyleFilterVecValue),
@@ -9699,6 +11087,10 @@ mod dll {
             pub update_text_input_before_calling_focus_lost_fn: bool,
             pub update_text_input_before_calling_vk_down_fn: bool,
             pub cursor_animation: AzOptionTimerId,
+            pub caret_animation: AzCaretAnimation,
+            pub spellcheck_language: AzOptionString,
+            pub on_spelling_suggestions: AzOptionTextInputOnSpellingSuggestions,
+            pub secure: bool,
         }
 
         /// Re-export of rust-allocated (stack based) `ProgressBar` struct
@@ -9917,6 +11309,16 @@ mod dll {
             Some(AzCssProperty),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionFrameRecording` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionFrameRecording {
+            None,
+            Some(AzFrameRecording),
+        }
+
         /// Re-export of rust-allocated (stack based) `XmlTextError` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -9927,6 +11329,26 @@ mod dll {
             pub pos: AzSvgParseErrorPosition,
         }
 
+        /// Configuration for optional features, such as whether to enable logging or panic hooks
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzAppConfig {
+            pub layout_solver: AzLayoutSolver,
+            pub log_level: AzAppLogLevel,
+            pub enable_visual_panic_hook: bool,
+            pub enable_logging_on_panic: bool,
+            pub enable_tab_navigation: bool,
+            pub system_callbacks: AzSystemCallbacks,
+            pub user_idle_timeout: AzOptionDuration,
+            pub crash_handler: AzOptionCrashHandler,
+            pub jank_capture: AzOptionJankCapture,
+            pub event_recording: AzOptionEventRecording,
+            pub frame_recording: AzOptionFrameRecording,
+            pub hover_intent_delay: AzOptionDuration,
+        }
+
         /// Platform-specific window configuration, i.e. WM options that are not cross-platform
         #[repr(C)]
         #[derive(Debug)]
@@ -9959,6 +11381,7 @@ mod dll {
             pub platform_specific_options: AzPlatformSpecificOptions,
             pub renderer_options: AzRendererOptions,
             pub background_color: AzColorU,
+            pub opacity: f32,
             pub layout_callback: AzLayoutCallback,
             pub close_callback: AzOptionCallback,
         }
@@ -9984,13 +11407,22 @@ mod dll {
             pub threads_removed: *mut c_void,
             pub current_window_handle: *const AzRawWindowHandle,
             pub new_windows: *mut c_void,
+            pub shortcuts: *mut AzShortcutMap,
+            pub window_data: *mut c_void,
+            pub announcements: *mut c_void,
+            pub haptic_feedback: *mut c_void,
+            pub animation_frame_callbacks: *mut c_void,
+            pub crossfades: *mut c_void,
+            pub subtree_recordings: *mut c_void,
             pub system_callbacks: *const AzSystemCallbacks,
             pub stop_propagation: *mut bool,
             pub focus_target: *mut c_void,
             pub words_changed_in_callbacks: *mut c_void,
+            pub text_underlines_changed_in_callbacks: *mut c_void,
             pub images_changed_in_callbacks: *mut c_void,
             pub image_masks_changed_in_callbacks: *mut c_void,
             pub css_properties_changed_in_callbacks: *mut c_void,
+            pub ids_and_classes_changed_in_callbacks: *mut c_void,
             pub current_scroll_states: *const c_void,
             pub nodes_scrolled_in_callback: *mut c_void,
             pub hit_dom_node: AzDomNodeId,
@@ -10013,6 +11445,16 @@ mod dll {
             pub baseline_descender_px: f32,
         }
 
+        /// Laid out text of a `Text` node, together with the rect that the text lines are positioned in
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzTextLayout {
+            pub bounds: AzLogicalRect,
+            pub inline_text: AzInlineText,
+        }
+
         /// CSS path to set the keyboard input focus
         #[repr(C)]
         #[derive(Debug)]
@@ -10075,6 +11517,17 @@ mod dll {
             pub default_value: AzCssProperty,
         }
 
+        /// Re-export of rust-allocated (stack based) `SystemCssProperty` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzSystemCssProperty {
+            pub key: AzString,
+            pub value: AzString,
+            pub default_value: AzCssProperty,
+        }
+
         /// Re-export of rust-allocated (stack based) `Node` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -10141,6 +11594,16 @@ mod dll {
             Some(AzInlineText),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionTextLayout` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionTextLayout {
+            None,
+            Some(AzTextLayout),
+        }
+
         /// Re-export of rust-allocated (stack based) `XmlParseError` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -10171,6 +11634,13 @@ mod dll {
             pub theme: AzOptionWindowTheme,
             pub create_callback: AzOptionCallback,
             pub hot_reload: bool,
+            pub shortcuts: AzShortcutMap,
+            pub parent_handle: AzOptionRawWindowHandle,
+            pub window_data: AzOptionRefAny,
+            pub background_effect: AzWindowBackgroundEffect,
+            pub fade_in: AzOptionDuration,
+            pub first_frame: AzFirstFrameMode,
+            pub scrollbars: AzScrollbarConfig,
         }
 
         /// Defines the keyboard input focus target
@@ -10185,6 +11655,7 @@ mod dll {
             Next,
             First,
             Last,
+            Direction(AzFocusDirection),
             NoFocus,
         }
 
@@ -10211,6 +11682,7 @@ mod dll {
         pub enum AzCssDeclaration {
             Static(AzCssProperty),
             Dynamic(AzDynamicCssProperty),
+            System(AzSystemCssProperty),
         }
 
         /// Re-export of rust-allocated (stack based) `Button` struct
@@ -10284,14 +11756,17 @@ mod dll {
             pub label_style: AzNodeDataInlineCssPropertyVec,
         }
 
-        /// Re-export of rust-allocated (stack based) `NumberInput` struct
+        /// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzNumberInput {
-            pub text_input: AzTextInput,
-            pub state: AzNumberInputStateWrapper,
+        pub struct AzNumberInputStateWrapper {
+            pub inner: AzNumberInputState,
+            pub on_value_change: AzOptionNumberInputOnValueChange,
+            pub on_focus_lost: AzOptionNumberInputOnFocusLost,
+            pub invalid_style: AzNodeDataInlineCssPropertyVec,
+            pub valid_style: AzNodeDataInlineCssPropertyVec,
         }
 
         /// Re-export of rust-allocated (stack based) `NodeIdNodeMap` struct
@@ -10304,6 +11779,19 @@ mod dll {
             pub node: AzNode,
         }
 
+        /// Manages the queue of toast / snackbar notifications of a window. Store it in the application data and insert `Toasts::dom()` as the last child of the root node
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzToasts {
+            pub state: AzRefAny,
+            pub layer_style: AzNodeDataInlineCssPropertyVec,
+            pub toast_style: AzNodeDataInlineCssPropertyVec,
+            pub label_style: AzNodeDataInlineCssPropertyVec,
+            pub action_style: AzNodeDataInlineCssPropertyVec,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<NodeIdNodeMap>`
         #[repr(C)]
         pub struct AzNodeIdNodeMapVec {
@@ -10375,6 +11863,18 @@ mod dll {
         pub struct AzCssRuleBlock {
             pub path: AzCssPath,
             pub declarations: AzCssDeclarationVec,
+            pub media: AzCssMediaCondition,
+        }
+
+        /// Re-export of rust-allocated (stack based) `NumberInput` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzNumberInput {
+            pub text_input: AzTextInput,
+            pub state: AzNumberInputStateWrapper,
+            pub spinner_button_style: AzNodeDataInlineCssPropertyVec,
         }
 
         /// Re-export of rust-allocated (stack based) `TabContent` struct
@@ -10549,30 +12049,60 @@ mod dll {
         pub(crate) fn AzApp_new(data: AzRefAny, config: AzAppConfig) -> AzApp { unsafe { transmute(azul::AzApp_new(transmute(data), transmute(config))) } }
         pub(crate) fn AzApp_addWindow(app: &mut AzApp, window: AzWindowCreateOptions) { unsafe { transmute(azul::AzApp_addWindow(transmute(app), transmute(window))) } }
         pub(crate) fn AzApp_addImage(app: &mut AzApp, id: AzString, image: AzImageRef) { unsafe { transmute(azul::AzApp_addImage(transmute(app), transmute(id), transmute(image))) } }
+        pub(crate) fn AzApp_withSplash(app: AzApp, splash: AzSplashOptions) -> AzApp { unsafe { transmute(azul::AzApp_withSplash(transmute(app), transmute(splash))) } }
+        pub(crate) fn AzApp_addGlQuirkRule(app: &mut AzApp, rule: AzGlQuirkRule) { unsafe { transmute(azul::AzApp_addGlQuirkRule(transmute(app), transmute(rule))) } }
         pub(crate) fn AzApp_getMonitors(app: &AzApp) -> AzMonitorVec { unsafe { transmute(azul::AzApp_getMonitors(transmute(app))) } }
+        pub(crate) fn AzApp_createEventLoopProxy(app: &AzApp) -> AzEventLoopProxy { unsafe { transmute(azul::AzApp_createEventLoopProxy(transmute(app))) } }
         pub(crate) fn AzApp_run(app: &AzApp, window: AzWindowCreateOptions) { unsafe { transmute(azul::AzApp_run(transmute(app), transmute(window))) } }
         pub(crate) fn AzApp_delete(object: &mut AzApp) { unsafe { transmute(azul::AzApp_delete(transmute(object))) } }
         pub(crate) fn AzApp_deepCopy(object: &AzApp) -> AzApp { unsafe { transmute(azul::AzApp_deepCopy(transmute(object))) } }
         pub(crate) fn AzAppConfig_new(layout_solver: AzLayoutSolver) -> AzAppConfig { unsafe { transmute(azul::AzAppConfig_new(transmute(layout_solver))) } }
+        pub(crate) fn AzJankCapture_new(threshold: AzDuration) -> AzJankCapture { unsafe { transmute(azul::AzJankCapture_new(transmute(threshold))) } }
+        pub(crate) fn AzSplashOptions_new() -> AzSplashOptions { unsafe { transmute(azul::AzSplashOptions_new()) } }
+        pub(crate) fn AzSplashOptions_withLogo(splashoptions: AzSplashOptions, logo: AzImageRef) -> AzSplashOptions { unsafe { transmute(azul::AzSplashOptions_withLogo(transmute(splashoptions), transmute(logo))) } }
+        pub(crate) fn AzFrameRecording_new(output: AzFrameRecordingOutput) -> AzFrameRecording { unsafe { transmute(azul::AzFrameRecording_new(transmute(output))) } }
         pub(crate) fn AzSystemCallbacks_libraryInternal() -> AzSystemCallbacks { unsafe { transmute(azul::AzSystemCallbacks_libraryInternal()) } }
         pub(crate) fn AzWindowCreateOptions_new(layout_callback: AzLayoutCallbackType) -> AzWindowCreateOptions { unsafe { transmute(azul::AzWindowCreateOptions_new(transmute(layout_callback))) } }
+        pub(crate) fn AzWindowCreateOptions_withParentHandle(windowcreateoptions: AzWindowCreateOptions, parent_handle: AzRawWindowHandle) -> AzWindowCreateOptions { unsafe { transmute(azul::AzWindowCreateOptions_withParentHandle(transmute(windowcreateoptions), transmute(parent_handle))) } }
+        pub(crate) fn AzWindowCreateOptions_withWindowData(windowcreateoptions: AzWindowCreateOptions, window_data: AzRefAny) -> AzWindowCreateOptions { unsafe { transmute(azul::AzWindowCreateOptions_withWindowData(transmute(windowcreateoptions), transmute(window_data))) } }
+        pub(crate) fn AzWindowCreateOptions_withBackgroundEffect(windowcreateoptions: AzWindowCreateOptions, background_effect: AzWindowBackgroundEffect) -> AzWindowCreateOptions { unsafe { transmute(azul::AzWindowCreateOptions_withBackgroundEffect(transmute(windowcreateoptions), transmute(background_effect))) } }
+        pub(crate) fn AzWindowCreateOptions_withFadeIn(windowcreateoptions: AzWindowCreateOptions, duration: AzDuration) -> AzWindowCreateOptions { unsafe { transmute(azul::AzWindowCreateOptions_withFadeIn(transmute(windowcreateoptions), transmute(duration))) } }
+        pub(crate) fn AzWindowCreateOptions_withFirstFrame(windowcreateoptions: AzWindowCreateOptions, first_frame: AzFirstFrameMode) -> AzWindowCreateOptions { unsafe { transmute(azul::AzWindowCreateOptions_withFirstFrame(transmute(windowcreateoptions), transmute(first_frame))) } }
+        pub(crate) fn AzWindowCreateOptions_withScrollbars(windowcreateoptions: AzWindowCreateOptions, scrollbars: AzScrollbarConfig) -> AzWindowCreateOptions { unsafe { transmute(azul::AzWindowCreateOptions_withScrollbars(transmute(windowcreateoptions), transmute(scrollbars))) } }
+        pub(crate) fn AzScrollbarConfig_default() -> AzScrollbarConfig { unsafe { transmute(azul::AzScrollbarConfig_default()) } }
         pub(crate) fn AzLogicalPosition_new(x: f32, y: f32) -> AzLogicalPosition { unsafe { transmute(azul::AzLogicalPosition_new(transmute(x), transmute(y))) } }
         pub(crate) fn AzLogicalPosition_zero() -> AzLogicalPosition { unsafe { transmute(azul::AzLogicalPosition_zero()) } }
         pub(crate) fn AzLogicalSize_toPhysical(logicalsize: &AzLogicalSize, hidpi_factor: f32) -> AzPhysicalSizeU32 { unsafe { transmute(azul::AzLogicalSize_toPhysical(transmute(logicalsize), transmute(hidpi_factor))) } }
         pub(crate) fn AzWindowSize_getHidpiFactor(windowsize: &AzWindowSize) -> f32 { unsafe { transmute(azul::AzWindowSize_getHidpiFactor(transmute(windowsize))) } }
+        pub(crate) fn AzWindowSize_setZoom(windowsize: &mut AzWindowSize, zoom: f32) { unsafe { transmute(azul::AzWindowSize_setZoom(transmute(windowsize), transmute(zoom))) } }
         pub(crate) fn AzKeyboardState_shiftDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_shiftDown(transmute(keyboardstate))) } }
         pub(crate) fn AzKeyboardState_ctrlDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_ctrlDown(transmute(keyboardstate))) } }
         pub(crate) fn AzKeyboardState_altDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_altDown(transmute(keyboardstate))) } }
         pub(crate) fn AzKeyboardState_superDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_superDown(transmute(keyboardstate))) } }
+        pub(crate) fn AzKeyboardState_leftShiftDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_leftShiftDown(transmute(keyboardstate))) } }
+        pub(crate) fn AzKeyboardState_rightShiftDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_rightShiftDown(transmute(keyboardstate))) } }
+        pub(crate) fn AzKeyboardState_leftCtrlDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_leftCtrlDown(transmute(keyboardstate))) } }
+        pub(crate) fn AzKeyboardState_rightCtrlDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_rightCtrlDown(transmute(keyboardstate))) } }
+        pub(crate) fn AzKeyboardState_leftAltDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_leftAltDown(transmute(keyboardstate))) } }
+        pub(crate) fn AzKeyboardState_rightAltDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_rightAltDown(transmute(keyboardstate))) } }
+        pub(crate) fn AzKeyboardState_leftSuperDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_leftSuperDown(transmute(keyboardstate))) } }
+        pub(crate) fn AzKeyboardState_rightSuperDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_rightSuperDown(transmute(keyboardstate))) } }
         pub(crate) fn AzKeyboardState_isKeyDown(keyboardstate: &AzKeyboardState, key: AzVirtualKeyCode) -> bool { unsafe { transmute(azul::AzKeyboardState_isKeyDown(transmute(keyboardstate), transmute(key))) } }
+        pub(crate) fn AzKeyboardState_isScancodeDown(keyboardstate: &AzKeyboardState, scancode: u32) -> bool { unsafe { transmute(azul::AzKeyboardState_isScancodeDown(transmute(keyboardstate), transmute(scancode))) } }
+        pub(crate) fn AzKeyboardState_getModifiers(keyboardstate: &AzKeyboardState) -> AzKeyboardModifiers { unsafe { transmute(azul::AzKeyboardState_getModifiers(transmute(keyboardstate))) } }
         pub(crate) fn AzCursorPosition_getPosition(cursorposition: &AzCursorPosition) -> AzOptionLogicalPosition { unsafe { transmute(azul::AzCursorPosition_getPosition(transmute(cursorposition))) } }
         pub(crate) fn AzWindowState_new(layout_callback: AzLayoutCallbackType) -> AzWindowState { unsafe { transmute(azul::AzWindowState_new(transmute(layout_callback))) } }
         pub(crate) fn AzWindowState_default() -> AzWindowState { unsafe { transmute(azul::AzWindowState_default()) } }
+        pub(crate) fn AzWindowState_setFullscreen(windowstate: &mut AzWindowState, mode: AzFullscreenMode) { unsafe { transmute(azul::AzWindowState_setFullscreen(transmute(windowstate), transmute(mode))) } }
+        pub(crate) fn AzLayoutCallback_hotReload(library_path: AzString, symbol: AzString) -> AzLayoutCallback { unsafe { transmute(azul::AzLayoutCallback_hotReload(transmute(library_path), transmute(symbol))) } }
         pub(crate) fn AzCallbackInfo_getHitNode(callbackinfo: &AzCallbackInfo) -> AzDomNodeId { unsafe { transmute(azul::AzCallbackInfo_getHitNode(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getSystemTimeFn(callbackinfo: &AzCallbackInfo) -> AzGetSystemTimeFn { unsafe { transmute(azul::AzCallbackInfo_getSystemTimeFn(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getCursorRelativeToViewport(callbackinfo: &AzCallbackInfo) -> AzOptionLogicalPosition { unsafe { transmute(azul::AzCallbackInfo_getCursorRelativeToViewport(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getCursorRelativeToNode(callbackinfo: &AzCallbackInfo) -> AzOptionLogicalPosition { unsafe { transmute(azul::AzCallbackInfo_getCursorRelativeToNode(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getCurrentWindowState(callbackinfo: &AzCallbackInfo) -> AzWindowState { unsafe { transmute(azul::AzCallbackInfo_getCurrentWindowState(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_getUserEvent(callbackinfo: &AzCallbackInfo) -> AzOptionRefAny { unsafe { transmute(azul::AzCallbackInfo_getUserEvent(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_getWindowData(callbackinfo: &AzCallbackInfo) -> AzOptionRefAny { unsafe { transmute(azul::AzCallbackInfo_getWindowData(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_setWindowData(callbackinfo: &mut AzCallbackInfo, data: AzRefAny) { unsafe { transmute(azul::AzCallbackInfo_setWindowData(transmute(callbackinfo), transmute(data))) } }
         pub(crate) fn AzCallbackInfo_getCurrentKeyboardState(callbackinfo: &AzCallbackInfo) -> AzKeyboardState { unsafe { transmute(azul::AzCallbackInfo_getCurrentKeyboardState(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getCurrentMouseState(callbackinfo: &AzCallbackInfo) -> AzMouseState { unsafe { transmute(azul::AzCallbackInfo_getCurrentMouseState(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getPreviousWindowState(callbackinfo: &AzCallbackInfo) -> AzOptionWindowState { unsafe { transmute(azul::AzCallbackInfo_getPreviousWindowState(transmute(callbackinfo))) } }
@@ -10600,8 +12130,13 @@ mod dll {
         pub(crate) fn AzCallbackInfo_setWindowState(callbackinfo: &mut AzCallbackInfo, new_state: AzWindowState) { unsafe { transmute(azul::AzCallbackInfo_setWindowState(transmute(callbackinfo), transmute(new_state))) } }
         pub(crate) fn AzCallbackInfo_setFocus(callbackinfo: &mut AzCallbackInfo, target: AzFocusTarget) { unsafe { transmute(azul::AzCallbackInfo_setFocus(transmute(callbackinfo), transmute(target))) } }
         pub(crate) fn AzCallbackInfo_setCssProperty(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_property: AzCssProperty) { unsafe { transmute(azul::AzCallbackInfo_setCssProperty(transmute(callbackinfo), transmute(node_id), transmute(new_property))) } }
+        pub(crate) fn AzCallbackInfo_addClass(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, class: AzString) { unsafe { transmute(azul::AzCallbackInfo_addClass(transmute(callbackinfo), transmute(node_id), transmute(class))) } }
+        pub(crate) fn AzCallbackInfo_removeClass(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, class: AzString) { unsafe { transmute(azul::AzCallbackInfo_removeClass(transmute(callbackinfo), transmute(node_id), transmute(class))) } }
+        pub(crate) fn AzCallbackInfo_toggleClass(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, class: AzString) { unsafe { transmute(azul::AzCallbackInfo_toggleClass(transmute(callbackinfo), transmute(node_id), transmute(class))) } }
+        pub(crate) fn AzCallbackInfo_setNodeStateFlag(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, flag: AzNodeStateFlag, value: bool) { unsafe { transmute(azul::AzCallbackInfo_setNodeStateFlag(transmute(callbackinfo), transmute(node_id), transmute(flag), transmute(value))) } }
         pub(crate) fn AzCallbackInfo_setScrollPosition(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) { unsafe { transmute(azul::AzCallbackInfo_setScrollPosition(transmute(callbackinfo), transmute(node_id), transmute(scroll_position))) } }
         pub(crate) fn AzCallbackInfo_setStringContents(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, string: AzString) { unsafe { transmute(azul::AzCallbackInfo_setStringContents(transmute(callbackinfo), transmute(node_id), transmute(string))) } }
+        pub(crate) fn AzCallbackInfo_setTextUnderlines(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, underlines: AzTextUnderlineVec) { unsafe { transmute(azul::AzCallbackInfo_setTextUnderlines(transmute(callbackinfo), transmute(node_id), transmute(underlines))) } }
         pub(crate) fn AzCallbackInfo_addImage(callbackinfo: &mut AzCallbackInfo, id: AzString, image: AzImageRef) { unsafe { transmute(azul::AzCallbackInfo_addImage(transmute(callbackinfo), transmute(id), transmute(image))) } }
         pub(crate) fn AzCallbackInfo_hasImage(callbackinfo: &AzCallbackInfo, id: AzString) -> bool { unsafe { transmute(azul::AzCallbackInfo_hasImage(transmute(callbackinfo), transmute(id))) } }
         pub(crate) fn AzCallbackInfo_getImage(callbackinfo: &AzCallbackInfo, id: AzString) -> AzOptionImageRef { unsafe { transmute(azul::AzCallbackInfo_getImage(transmute(callbackinfo), transmute(id))) } }
@@ -10612,10 +12147,27 @@ mod dll {
         pub(crate) fn AzCallbackInfo_createWindow(callbackinfo: &mut AzCallbackInfo, new_window: AzWindowCreateOptions) { unsafe { transmute(azul::AzCallbackInfo_createWindow(transmute(callbackinfo), transmute(new_window))) } }
         pub(crate) fn AzCallbackInfo_startTimer(callbackinfo: &mut AzCallbackInfo, timer: AzTimer) -> AzTimerId { unsafe { transmute(azul::AzCallbackInfo_startTimer(transmute(callbackinfo), transmute(timer))) } }
         pub(crate) fn AzCallbackInfo_startAnimation(callbackinfo: &mut AzCallbackInfo, node: AzDomNodeId, animation: AzAnimation) -> AzOptionTimerId { unsafe { transmute(azul::AzCallbackInfo_startAnimation(transmute(callbackinfo), transmute(node), transmute(animation))) } }
+        pub(crate) fn AzCallbackInfo_startCaretAnimation(callbackinfo: &mut AzCallbackInfo, caret_node: AzDomNodeId, animation: AzCaretAnimation) -> AzOptionTimerId { unsafe { transmute(azul::AzCallbackInfo_startCaretAnimation(transmute(callbackinfo), transmute(caret_node), transmute(animation))) } }
+        pub(crate) fn AzCallbackInfo_animateWindowResize(callbackinfo: &mut AzCallbackInfo, target_size: AzLogicalSize, duration: AzDuration) -> AzTimerId { unsafe { transmute(azul::AzCallbackInfo_animateWindowResize(transmute(callbackinfo), transmute(target_size), transmute(duration))) } }
         pub(crate) fn AzCallbackInfo_stopTimer(callbackinfo: &mut AzCallbackInfo, timer_id: AzTimerId) -> bool { unsafe { transmute(azul::AzCallbackInfo_stopTimer(transmute(callbackinfo), transmute(timer_id))) } }
         pub(crate) fn AzCallbackInfo_startThread(callbackinfo: &mut AzCallbackInfo, thread_initialize_data: AzRefAny, writeback_data: AzRefAny, callback: AzThreadCallbackType) -> AzOptionThreadId { unsafe { transmute(azul::AzCallbackInfo_startThread(transmute(callbackinfo), transmute(thread_initialize_data), transmute(writeback_data), transmute(callback))) } }
         pub(crate) fn AzCallbackInfo_sendThreadMsg(callbackinfo: &mut AzCallbackInfo, thread_id: AzThreadId, msg: AzThreadSendMsg) -> bool { unsafe { transmute(azul::AzCallbackInfo_sendThreadMsg(transmute(callbackinfo), transmute(thread_id), transmute(msg))) } }
         pub(crate) fn AzCallbackInfo_stopThread(callbackinfo: &mut AzCallbackInfo, thread_id: AzThreadId) -> bool { unsafe { transmute(azul::AzCallbackInfo_stopThread(transmute(callbackinfo), transmute(thread_id))) } }
+        pub(crate) fn AzCallbackInfo_getSystemIdleTime(callbackinfo: &AzCallbackInfo) -> AzOptionDuration { unsafe { transmute(azul::AzCallbackInfo_getSystemIdleTime(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_keycodeToChar(callbackinfo: &AzCallbackInfo, virtual_keycode: AzVirtualKeyCode, modifiers: AzKeyboardModifiers) -> AzOptionChar { unsafe { transmute(azul::AzCallbackInfo_keycodeToChar(transmute(callbackinfo), transmute(virtual_keycode), transmute(modifiers))) } }
+        pub(crate) fn AzCallbackInfo_getLogLines(callbackinfo: &AzCallbackInfo) -> AzStringVec { unsafe { transmute(azul::AzCallbackInfo_getLogLines(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_setLogLevel(callbackinfo: &AzCallbackInfo, subsystem: AzString, level: AzAppLogLevel) { unsafe { transmute(azul::AzCallbackInfo_setLogLevel(transmute(callbackinfo), transmute(subsystem), transmute(level))) } }
+        pub(crate) fn AzCallbackInfo_getNodeRect(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionNodeRect { unsafe { transmute(azul::AzCallbackInfo_getNodeRect(transmute(callbackinfo), transmute(node_id))) } }
+        pub(crate) fn AzCallbackInfo_getTextLayout(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionTextLayout { unsafe { transmute(azul::AzCallbackInfo_getTextLayout(transmute(callbackinfo), transmute(node_id))) } }
+        pub(crate) fn AzCallbackInfo_getScrollInfo(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionScrollInfo { unsafe { transmute(azul::AzCallbackInfo_getScrollInfo(transmute(callbackinfo), transmute(node_id))) } }
+        pub(crate) fn AzCallbackInfo_addShortcut(callbackinfo: &mut AzCallbackInfo, shortcut: AzShortcut) -> AzOptionShortcutConflict { unsafe { transmute(azul::AzCallbackInfo_addShortcut(transmute(callbackinfo), transmute(shortcut))) } }
+        pub(crate) fn AzCallbackInfo_removeShortcut(callbackinfo: &mut AzCallbackInfo, keys: AzVirtualKeyCodeCombo) -> bool { unsafe { transmute(azul::AzCallbackInfo_removeShortcut(transmute(callbackinfo), transmute(keys))) } }
+        pub(crate) fn AzCallbackInfo_getShortcutConflicts(callbackinfo: &AzCallbackInfo) -> AzShortcutConflictVec { unsafe { transmute(azul::AzCallbackInfo_getShortcutConflicts(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_announce(callbackinfo: &mut AzCallbackInfo, text: AzString, politeness: AzPoliteness) { unsafe { transmute(azul::AzCallbackInfo_announce(transmute(callbackinfo), transmute(text), transmute(politeness))) } }
+        pub(crate) fn AzCallbackInfo_triggerHaptic(callbackinfo: &mut AzCallbackInfo, kind: AzHapticKind) { unsafe { transmute(azul::AzCallbackInfo_triggerHaptic(transmute(callbackinfo), transmute(kind))) } }
+        pub(crate) fn AzCallbackInfo_requestAnimationFrame(callbackinfo: &mut AzCallbackInfo, data: AzRefAny, callback: AzTimerCallbackType) { unsafe { transmute(azul::AzCallbackInfo_requestAnimationFrame(transmute(callbackinfo), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzCallbackInfo_crossfadeSubtree(callbackinfo: &mut AzCallbackInfo, node: AzDomNodeId, duration: AzDuration) { unsafe { transmute(azul::AzCallbackInfo_crossfadeSubtree(transmute(callbackinfo), transmute(node), transmute(duration))) } }
+        pub(crate) fn AzCallbackInfo_renderSubtreeToFrames(callbackinfo: &mut AzCallbackInfo, node: AzDomNodeId, duration: AzDuration, fps: u32, output_path: AzString) { unsafe { transmute(azul::AzCallbackInfo_renderSubtreeToFrames(transmute(callbackinfo), transmute(node), transmute(duration), transmute(fps), transmute(output_path))) } }
         pub(crate) fn AzPositionInfo_isPositioned(positioninfo: &AzPositionInfo) -> bool { unsafe { transmute(azul::AzPositionInfo_isPositioned(transmute(positioninfo))) } }
         pub(crate) fn AzPositionInfo_getStaticOffset(positioninfo: &AzPositionInfo) -> AzLogicalPosition { unsafe { transmute(azul::AzPositionInfo_getStaticOffset(transmute(positioninfo))) } }
         pub(crate) fn AzPositionInfo_getRelativeOffset(positioninfo: &AzPositionInfo) -> AzLogicalPosition { unsafe { transmute(azul::AzPositionInfo_getRelativeOffset(transmute(positioninfo))) } }
@@ -10650,6 +12202,7 @@ mod dll {
         pub(crate) fn AzLayoutCallbackInfo_getGlContext(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzOptionGl { unsafe { transmute(azul::AzLayoutCallbackInfo_getGlContext(transmute(layoutcallbackinfo))) } }
         pub(crate) fn AzLayoutCallbackInfo_getSystemFonts(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzStringPairVec { unsafe { transmute(azul::AzLayoutCallbackInfo_getSystemFonts(transmute(layoutcallbackinfo))) } }
         pub(crate) fn AzLayoutCallbackInfo_getImage(layoutcallbackinfo: &AzLayoutCallbackInfo, id: AzString) -> AzOptionImageRef { unsafe { transmute(azul::AzLayoutCallbackInfo_getImage(transmute(layoutcallbackinfo), transmute(id))) } }
+        pub(crate) fn AzLayoutCallbackInfo_getSystemStyle(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzSystemStyle { unsafe { transmute(azul::AzLayoutCallbackInfo_getSystemStyle(transmute(layoutcallbackinfo))) } }
         pub(crate) fn AzDom_new(node_type: AzNodeType) -> AzDom { unsafe { transmute(azul::AzDom_new(transmute(node_type))) } }
         pub(crate) fn AzDom_body() -> AzDom { unsafe { transmute(azul::AzDom_body()) } }
         pub(crate) fn AzDom_div() -> AzDom { unsafe { transmute(azul::AzDom_div()) } }
@@ -10657,6 +12210,10 @@ mod dll {
         pub(crate) fn AzDom_text(string: AzString) -> AzDom { unsafe { transmute(azul::AzDom_text(transmute(string))) } }
         pub(crate) fn AzDom_image(image: AzImageRef) -> AzDom { unsafe { transmute(azul::AzDom_image(transmute(image))) } }
         pub(crate) fn AzDom_iframe(data: AzRefAny, callback: AzIFrameCallbackType) -> AzDom { unsafe { transmute(azul::AzDom_iframe(transmute(data), transmute(callback))) } }
+        pub(crate) fn AzDom_customLayout(data: AzRefAny, measure: AzMeasureCallbackType, arrange: AzArrangeCallbackType) -> AzDom { unsafe { transmute(azul::AzDom_customLayout(transmute(data), transmute(measure), transmute(arrange))) } }
+        pub(crate) fn AzDom_customNode(type_name: AzString, data: AzRefAny) -> AzOptionDom { unsafe { transmute(azul::AzDom_customNode(transmute(type_name), transmute(data))) } }
+        pub(crate) fn AzDom_iframeWithReinvokeThreshold(data: AzRefAny, callback: AzIFrameCallbackType, reinvoke_threshold: f32) -> AzDom { unsafe { transmute(azul::AzDom_iframeWithReinvokeThreshold(transmute(data), transmute(callback), transmute(reinvoke_threshold))) } }
+        pub(crate) fn AzDom_overlay(anchor: AzString, placement: AzOverlayPlacement) -> AzDom { unsafe { transmute(azul::AzDom_overlay(transmute(anchor), transmute(placement))) } }
         pub(crate) fn AzDom_setNodeType(dom: &mut AzDom, node_type: AzNodeType) { unsafe { transmute(azul::AzDom_setNodeType(transmute(dom), transmute(node_type))) } }
         pub(crate) fn AzDom_withNodeType(dom: &mut AzDom, node_type: AzNodeType) -> AzDom { unsafe { transmute(azul::AzDom_withNodeType(transmute(dom), transmute(node_type))) } }
         pub(crate) fn AzDom_setDataset(dom: &mut AzDom, dataset: AzRefAny) { unsafe { transmute(azul::AzDom_setDataset(transmute(dom), transmute(dataset))) } }
@@ -10703,11 +12260,22 @@ mod dll {
         pub(crate) fn AzDom_withMenuBar(dom: &mut AzDom, menu_bar: AzMenu) -> AzDom { unsafe { transmute(azul::AzDom_withMenuBar(transmute(dom), transmute(menu_bar))) } }
         pub(crate) fn AzDom_setContextMenu(dom: &mut AzDom, context_menu: AzMenu) { unsafe { transmute(azul::AzDom_setContextMenu(transmute(dom), transmute(context_menu))) } }
         pub(crate) fn AzDom_withContextMenu(dom: &mut AzDom, context_menu: AzMenu) -> AzDom { unsafe { transmute(azul::AzDom_withContextMenu(transmute(dom), transmute(context_menu))) } }
+        pub(crate) fn AzDom_setFocusNavigation(dom: &mut AzDom, focus_navigation: AzFocusNavigation) { unsafe { transmute(azul::AzDom_setFocusNavigation(transmute(dom), transmute(focus_navigation))) } }
+        pub(crate) fn AzDom_withFocusNavigation(dom: &mut AzDom, focus_navigation: AzFocusNavigation) -> AzDom { unsafe { transmute(azul::AzDom_withFocusNavigation(transmute(dom), transmute(focus_navigation))) } }
+        pub(crate) fn AzDom_setLang(dom: &mut AzDom, lang: AzString) { unsafe { transmute(azul::AzDom_setLang(transmute(dom), transmute(lang))) } }
+        pub(crate) fn AzDom_withLang(dom: &mut AzDom, lang: AzString) -> AzDom { unsafe { transmute(azul::AzDom_withLang(transmute(dom), transmute(lang))) } }
+        pub(crate) fn AzDom_setInputMode(dom: &mut AzDom, input_mode: AzInputMode) { unsafe { transmute(azul::AzDom_setInputMode(transmute(dom), transmute(input_mode))) } }
+        pub(crate) fn AzDom_withInputMode(dom: &mut AzDom, input_mode: AzInputMode) -> AzDom { unsafe { transmute(azul::AzDom_withInputMode(transmute(dom), transmute(input_mode))) } }
+        pub(crate) fn AzDom_setStateFlag(dom: &mut AzDom, flag: AzNodeStateFlag, enabled: bool) { unsafe { transmute(azul::AzDom_setStateFlag(transmute(dom), transmute(flag), transmute(enabled))) } }
+        pub(crate) fn AzDom_withStateFlag(dom: &mut AzDom, flag: AzNodeStateFlag, enabled: bool) -> AzDom { unsafe { transmute(azul::AzDom_withStateFlag(transmute(dom), transmute(flag), transmute(enabled))) } }
         pub(crate) fn AzDom_hash(dom: &AzDom) -> u64 { unsafe { transmute(azul::AzDom_hash(transmute(dom))) } }
         pub(crate) fn AzDom_nodeCount(dom: &AzDom) -> usize { unsafe { transmute(azul::AzDom_nodeCount(transmute(dom))) } }
         pub(crate) fn AzDom_getHtmlString(dom: &mut AzDom) -> AzString { unsafe { transmute(azul::AzDom_getHtmlString(transmute(dom))) } }
         pub(crate) fn AzDom_getHtmlStringTest(dom: &mut AzDom) -> AzString { unsafe { transmute(azul::AzDom_getHtmlStringTest(transmute(dom))) } }
         pub(crate) fn AzDom_style(dom: &mut AzDom, css: AzCss) -> AzStyledDom { unsafe { transmute(azul::AzDom_style(transmute(dom), transmute(css))) } }
+        pub(crate) fn AzCustomNodeType_new(name: AzString, measure: AzMeasureCallbackType, arrange: AzArrangeCallbackType) -> AzCustomNodeType { unsafe { transmute(azul::AzCustomNodeType_new(transmute(name), transmute(measure), transmute(arrange))) } }
+        pub(crate) fn AzCustomNodeType_withPaint(customnodetype: AzCustomNodeType, paint: AzPaintCallbackType) -> AzCustomNodeType { unsafe { transmute(azul::AzCustomNodeType_withPaint(transmute(customnodetype), transmute(paint))) } }
+        pub(crate) fn AzCustomNodeType_register(customnodetype: AzCustomNodeType) { unsafe { transmute(azul::AzCustomNodeType_register(transmute(customnodetype))) } }
         pub(crate) fn AzNodeData_new(node_type: AzNodeType) -> AzNodeData { unsafe { transmute(azul::AzNodeData_new(transmute(node_type))) } }
         pub(crate) fn AzNodeData_body() -> AzNodeData { unsafe { transmute(azul::AzNodeData_body()) } }
         pub(crate) fn AzNodeData_div() -> AzNodeData { unsafe { transmute(azul::AzNodeData_div()) } }
@@ -10715,6 +12283,8 @@ mod dll {
         pub(crate) fn AzNodeData_text(string: AzString) -> AzNodeData { unsafe { transmute(azul::AzNodeData_text(transmute(string))) } }
         pub(crate) fn AzNodeData_image(image: AzImageRef) -> AzNodeData { unsafe { transmute(azul::AzNodeData_image(transmute(image))) } }
         pub(crate) fn AzNodeData_iframe(data: AzRefAny, callback: AzIFrameCallbackType) -> AzNodeData { unsafe { transmute(azul::AzNodeData_iframe(transmute(data), transmute(callback))) } }
+        pub(crate) fn AzNodeData_customLayout(data: AzRefAny, measure: AzMeasureCallbackType, arrange: AzArrangeCallbackType) -> AzNodeData { unsafe { transmute(azul::AzNodeData_customLayout(transmute(data), transmute(measure), transmute(arrange))) } }
+        pub(crate) fn AzNodeData_iframeWithReinvokeThreshold(data: AzRefAny, callback: AzIFrameCallbackType, reinvoke_threshold: f32) -> AzNodeData { unsafe { transmute(azul::AzNodeData_iframeWithReinvokeThreshold(transmute(data), transmute(callback), transmute(reinvoke_threshold))) } }
         pub(crate) fn AzNodeData_setNodeType(nodedata: &mut AzNodeData, node_type: AzNodeType) { unsafe { transmute(azul::AzNodeData_setNodeType(transmute(nodedata), transmute(node_type))) } }
         pub(crate) fn AzNodeData_withNodeType(nodedata: &mut AzNodeData, node_type: AzNodeType) -> AzNodeData { unsafe { transmute(azul::AzNodeData_withNodeType(transmute(nodedata), transmute(node_type))) } }
         pub(crate) fn AzNodeData_setDataset(nodedata: &mut AzNodeData, dataset: AzRefAny) { unsafe { transmute(azul::AzNodeData_setDataset(transmute(nodedata), transmute(dataset))) } }
@@ -10740,7 +12310,12 @@ mod dll {
         pub(crate) fn AzNodeData_setAccessibilityInfo(nodedata: &mut AzNodeData, accessibility_info: AzAccessibilityInfo) { unsafe { transmute(azul::AzNodeData_setAccessibilityInfo(transmute(nodedata), transmute(accessibility_info))) } }
         pub(crate) fn AzNodeData_setMenuBar(nodedata: &mut AzNodeData, menu_bar: AzMenu) { unsafe { transmute(azul::AzNodeData_setMenuBar(transmute(nodedata), transmute(menu_bar))) } }
         pub(crate) fn AzNodeData_setContextMenu(nodedata: &mut AzNodeData, context_menu: AzMenu) { unsafe { transmute(azul::AzNodeData_setContextMenu(transmute(nodedata), transmute(context_menu))) } }
+        pub(crate) fn AzNodeData_setFocusNavigation(nodedata: &mut AzNodeData, focus_navigation: AzFocusNavigation) { unsafe { transmute(azul::AzNodeData_setFocusNavigation(transmute(nodedata), transmute(focus_navigation))) } }
         pub(crate) fn AzNodeData_hash(nodedata: &AzNodeData) -> u64 { unsafe { transmute(azul::AzNodeData_hash(transmute(nodedata))) } }
+        pub(crate) fn AzNodeData_setOverlay(nodedata: &mut AzNodeData, overlay: AzOverlayInfo) { unsafe { transmute(azul::AzNodeData_setOverlay(transmute(nodedata), transmute(overlay))) } }
+        pub(crate) fn AzNodeData_setLang(nodedata: &mut AzNodeData, lang: AzString) { unsafe { transmute(azul::AzNodeData_setLang(transmute(nodedata), transmute(lang))) } }
+        pub(crate) fn AzNodeData_setInputMode(nodedata: &mut AzNodeData, input_mode: AzInputMode) { unsafe { transmute(azul::AzNodeData_setInputMode(transmute(nodedata), transmute(input_mode))) } }
+        pub(crate) fn AzNodeData_setStateFlag(nodedata: &mut AzNodeData, flag: AzNodeStateFlag, enabled: bool) { unsafe { transmute(azul::AzNodeData_setStateFlag(transmute(nodedata), transmute(flag), transmute(enabled))) } }
         pub(crate) fn AzOn_intoEventFilter(on: AzOn) -> AzEventFilter { unsafe { transmute(azul::AzOn_intoEventFilter(transmute(on))) } }
         pub(crate) fn AzMenu_new(items: AzMenuItemVec) -> AzMenu { unsafe { transmute(azul::AzMenu_new(transmute(items))) } }
         pub(crate) fn AzMenu_setPopupPosition(menu: &mut AzMenu, position: AzMenuPopupPosition) { unsafe { transmute(azul::AzMenu_setPopupPosition(transmute(menu), transmute(position))) } }
@@ -10753,6 +12328,11 @@ mod dll {
         pub(crate) fn AzStringMenuItem_setChildren(stringmenuitem: &mut AzStringMenuItem, children: AzMenuItemVec) { unsafe { transmute(azul::AzStringMenuItem_setChildren(transmute(stringmenuitem), transmute(children))) } }
         pub(crate) fn AzStringMenuItem_withChildren(stringmenuitem: &mut AzStringMenuItem, children: AzMenuItemVec) -> AzStringMenuItem { unsafe { transmute(azul::AzStringMenuItem_withChildren(transmute(stringmenuitem), transmute(children))) } }
         pub(crate) fn AzMenuCallback_new(data: AzRefAny, callback: AzCallbackType) -> AzMenuCallback { unsafe { transmute(azul::AzMenuCallback_new(transmute(data), transmute(callback))) } }
+        pub(crate) fn AzShortcut_new(keys: AzVirtualKeyCodeCombo, data: AzRefAny, callback: AzCallbackType) -> AzShortcut { unsafe { transmute(azul::AzShortcut_new(transmute(keys), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzShortcutMap_new() -> AzShortcutMap { unsafe { transmute(azul::AzShortcutMap_new()) } }
+        pub(crate) fn AzShortcutMap_add(shortcutmap: &mut AzShortcutMap, shortcut: AzShortcut) -> AzOptionShortcutConflict { unsafe { transmute(azul::AzShortcutMap_add(transmute(shortcutmap), transmute(shortcut))) } }
+        pub(crate) fn AzShortcutMap_remove(shortcutmap: &mut AzShortcutMap, keys: AzVirtualKeyCodeCombo) -> bool { unsafe { transmute(azul::AzShortcutMap_remove(transmute(shortcutmap), transmute(keys))) } }
+        pub(crate) fn AzShortcutMap_getConflicts(shortcutmap: &AzShortcutMap) -> AzShortcutConflictVec { unsafe { transmute(azul::AzShortcutMap_getConflicts(transmute(shortcutmap))) } }
         pub(crate) fn AzCss_empty() -> AzCss { unsafe { transmute(azul::AzCss_empty()) } }
         pub(crate) fn AzCss_fromString(s: AzString) -> AzCss { unsafe { transmute(azul::AzCss_fromString(transmute(s))) } }
         pub(crate) fn AzColorU_fromStr(string: AzString) -> AzColorU { unsafe { transmute(azul::AzColorU_fromStr(transmute(string))) } }
@@ -10797,6 +12377,14 @@ mod dll {
         pub(crate) fn AzTextInput_withOnVirtualKeyDown(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnVirtualKeyDownCallbackType) -> AzTextInput { unsafe { transmute(azul::AzTextInput_withOnVirtualKeyDown(transmute(textinput), transmute(data), transmute(callback))) } }
         pub(crate) fn AzTextInput_setOnFocusLost(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnFocusLostCallbackType) { unsafe { transmute(azul::AzTextInput_setOnFocusLost(transmute(textinput), transmute(data), transmute(callback))) } }
         pub(crate) fn AzTextInput_withOnFocusLost(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnFocusLostCallbackType) -> AzTextInput { unsafe { transmute(azul::AzTextInput_withOnFocusLost(transmute(textinput), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzTextInput_setCaretAnimation(textinput: &mut AzTextInput, caret_animation: AzCaretAnimation) { unsafe { transmute(azul::AzTextInput_setCaretAnimation(transmute(textinput), transmute(caret_animation))) } }
+        pub(crate) fn AzTextInput_withCaretAnimation(textinput: &mut AzTextInput, caret_animation: AzCaretAnimation) -> AzTextInput { unsafe { transmute(azul::AzTextInput_withCaretAnimation(transmute(textinput), transmute(caret_animation))) } }
+        pub(crate) fn AzTextInput_setSpellcheck(textinput: &mut AzTextInput, language: AzString) { unsafe { transmute(azul::AzTextInput_setSpellcheck(transmute(textinput), transmute(language))) } }
+        pub(crate) fn AzTextInput_withSpellcheck(textinput: &mut AzTextInput, language: AzString) -> AzTextInput { unsafe { transmute(azul::AzTextInput_withSpellcheck(transmute(textinput), transmute(language))) } }
+        pub(crate) fn AzTextInput_setOnSpellingSuggestions(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnSpellingSuggestionsCallbackType) { unsafe { transmute(azul::AzTextInput_setOnSpellingSuggestions(transmute(textinput), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzTextInput_withOnSpellingSuggestions(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnSpellingSuggestionsCallbackType) -> AzTextInput { unsafe { transmute(azul::AzTextInput_withOnSpellingSuggestions(transmute(textinput), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzTextInput_setSecure(textinput: &mut AzTextInput, secure: bool) { unsafe { transmute(azul::AzTextInput_setSecure(transmute(textinput), transmute(secure))) } }
+        pub(crate) fn AzTextInput_withSecure(textinput: &mut AzTextInput, secure: bool) -> AzTextInput { unsafe { transmute(azul::AzTextInput_withSecure(transmute(textinput), transmute(secure))) } }
         pub(crate) fn AzTextInput_setPlaceholderStyle(textinput: &mut AzTextInput, placeholder_style: AzNodeDataInlineCssPropertyVec) { unsafe { transmute(azul::AzTextInput_setPlaceholderStyle(transmute(textinput), transmute(placeholder_style))) } }
         pub(crate) fn AzTextInput_withPlaceholderStyle(textinput: &mut AzTextInput, placeholder_style: AzNodeDataInlineCssPropertyVec) -> AzTextInput { unsafe { transmute(azul::AzTextInput_withPlaceholderStyle(transmute(textinput), transmute(placeholder_style))) } }
         pub(crate) fn AzTextInput_setContainerStyle(textinput: &mut AzTextInput, container_style: AzNodeDataInlineCssPropertyVec) { unsafe { transmute(azul::AzTextInput_setContainerStyle(transmute(textinput), transmute(container_style))) } }
@@ -10805,7 +12393,7 @@ mod dll {
         pub(crate) fn AzTextInput_withLabelStyle(textinput: &mut AzTextInput, label_style: AzNodeDataInlineCssPropertyVec) -> AzTextInput { unsafe { transmute(azul::AzTextInput_withLabelStyle(transmute(textinput), transmute(label_style))) } }
         pub(crate) fn AzTextInput_dom(textinput: &mut AzTextInput) -> AzDom { unsafe { transmute(azul::AzTextInput_dom(transmute(textinput))) } }
         pub(crate) fn AzTextInputState_getText(textinputstate: &AzTextInputState) -> AzString { unsafe { transmute(azul::AzTextInputState_getText(transmute(textinputstate))) } }
-        pub(crate) fn AzNumberInput_new(number: f32) -> AzNumberInput { unsafe { transmute(azul::AzNumberInput_new(transmute(number))) } }
+        pub(crate) fn AzNumberInput_new(number: f64) -> AzNumberInput { unsafe { transmute(azul::AzNumberInput_new(transmute(number))) } }
         pub(crate) fn AzNumberInput_setOnTextInput(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzTextInputOnTextInputCallbackType) { unsafe { transmute(azul::AzNumberInput_setOnTextInput(transmute(numberinput), transmute(data), transmute(callback))) } }
         pub(crate) fn AzNumberInput_withOnTextInput(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzTextInputOnTextInputCallbackType) -> AzNumberInput { unsafe { transmute(azul::AzNumberInput_withOnTextInput(transmute(numberinput), transmute(data), transmute(callback))) } }
         pub(crate) fn AzNumberInput_setOnVirtualKeyDown(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzTextInputOnVirtualKeyDownCallbackType) { unsafe { transmute(azul::AzNumberInput_setOnVirtualKeyDown(transmute(numberinput), transmute(data), transmute(callback))) } }
@@ -10820,6 +12408,20 @@ mod dll {
         pub(crate) fn AzNumberInput_withLabelStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) -> AzNumberInput { unsafe { transmute(azul::AzNumberInput_withLabelStyle(transmute(numberinput), transmute(style))) } }
         pub(crate) fn AzNumberInput_setOnValueChange(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzNumberInputOnValueChangeCallbackType) { unsafe { transmute(azul::AzNumberInput_setOnValueChange(transmute(numberinput), transmute(data), transmute(callback))) } }
         pub(crate) fn AzNumberInput_withOnValueChange(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzNumberInputOnValueChangeCallbackType) -> AzNumberInput { unsafe { transmute(azul::AzNumberInput_withOnValueChange(transmute(numberinput), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzNumberInput_setSpinnerButtonStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) { unsafe { transmute(azul::AzNumberInput_setSpinnerButtonStyle(transmute(numberinput), transmute(style))) } }
+        pub(crate) fn AzNumberInput_withSpinnerButtonStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) -> AzNumberInput { unsafe { transmute(azul::AzNumberInput_withSpinnerButtonStyle(transmute(numberinput), transmute(style))) } }
+        pub(crate) fn AzNumberInput_setInvalidStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) { unsafe { transmute(azul::AzNumberInput_setInvalidStyle(transmute(numberinput), transmute(style))) } }
+        pub(crate) fn AzNumberInput_withInvalidStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) -> AzNumberInput { unsafe { transmute(azul::AzNumberInput_withInvalidStyle(transmute(numberinput), transmute(style))) } }
+        pub(crate) fn AzNumberInput_setMode(numberinput: &mut AzNumberInput, mode: AzNumberInputMode) { unsafe { transmute(azul::AzNumberInput_setMode(transmute(numberinput), transmute(mode))) } }
+        pub(crate) fn AzNumberInput_withMode(numberinput: &mut AzNumberInput, mode: AzNumberInputMode) -> AzNumberInput { unsafe { transmute(azul::AzNumberInput_withMode(transmute(numberinput), transmute(mode))) } }
+        pub(crate) fn AzNumberInput_setMin(numberinput: &mut AzNumberInput, min: f64) { unsafe { transmute(azul::AzNumberInput_setMin(transmute(numberinput), transmute(min))) } }
+        pub(crate) fn AzNumberInput_withMin(numberinput: &mut AzNumberInput, min: f64) -> AzNumberInput { unsafe { transmute(azul::AzNumberInput_withMin(transmute(numberinput), transmute(min))) } }
+        pub(crate) fn AzNumberInput_setMax(numberinput: &mut AzNumberInput, max: f64) { unsafe { transmute(azul::AzNumberInput_setMax(transmute(numberinput), transmute(max))) } }
+        pub(crate) fn AzNumberInput_withMax(numberinput: &mut AzNumberInput, max: f64) -> AzNumberInput { unsafe { transmute(azul::AzNumberInput_withMax(transmute(numberinput), transmute(max))) } }
+        pub(crate) fn AzNumberInput_setStep(numberinput: &mut AzNumberInput, step: f64) { unsafe { transmute(azul::AzNumberInput_setStep(transmute(numberinput), transmute(step))) } }
+        pub(crate) fn AzNumberInput_withStep(numberinput: &mut AzNumberInput, step: f64) -> AzNumberInput { unsafe { transmute(azul::AzNumberInput_withStep(transmute(numberinput), transmute(step))) } }
+        pub(crate) fn AzNumberInput_setDecimalSeparator(numberinput: &mut AzNumberInput, decimal_separator: u32) { unsafe { transmute(azul::AzNumberInput_setDecimalSeparator(transmute(numberinput), transmute(decimal_separator))) } }
+        pub(crate) fn AzNumberInput_withDecimalSeparator(numberinput: &mut AzNumberInput, decimal_separator: u32) -> AzNumberInput { unsafe { transmute(azul::AzNumberInput_withDecimalSeparator(transmute(numberinput), transmute(decimal_separator))) } }
         pub(crate) fn AzNumberInput_dom(numberinput: &mut AzNumberInput) -> AzDom { unsafe { transmute(azul::AzNumberInput_dom(transmute(numberinput))) } }
         pub(crate) fn AzProgressBar_new(percent_done: f32) -> AzProgressBar { unsafe { transmute(azul::AzProgressBar_new(transmute(percent_done))) } }
         pub(crate) fn AzProgressBar_setHeight(progressbar: &mut AzProgressBar, height: AzPixelValue) { unsafe { transmute(azul::AzProgressBar_setHeight(transmute(progressbar), transmute(height))) } }
@@ -10851,6 +12453,25 @@ mod dll {
         pub(crate) fn AzTreeView_dom(treeview: &mut AzTreeView) -> AzDom { unsafe { transmute(azul::AzTreeView_dom(transmute(treeview))) } }
         pub(crate) fn AzDropDown_new(choices: AzStringVec) -> AzDropDown { unsafe { transmute(azul::AzDropDown_new(transmute(choices))) } }
         pub(crate) fn AzDropDown_dom(dropdown: &mut AzDropDown) -> AzDom { unsafe { transmute(azul::AzDropDown_dom(transmute(dropdown))) } }
+        pub(crate) fn AzToasts_new() -> AzToasts { unsafe { transmute(azul::AzToasts_new()) } }
+        pub(crate) fn AzToasts_show(toasts: &mut AzToasts, info: &mut AzCallbackInfo, toast: AzToast) -> AzToastId { unsafe { transmute(azul::AzToasts_show(transmute(toasts), transmute(info), transmute(toast))) } }
+        pub(crate) fn AzToasts_dismiss(toasts: &mut AzToasts, info: &mut AzCallbackInfo, toast_id: AzToastId) -> bool { unsafe { transmute(azul::AzToasts_dismiss(transmute(toasts), transmute(info), transmute(toast_id))) } }
+        pub(crate) fn AzToasts_setMaxVisible(toasts: &mut AzToasts, max_visible: usize) { unsafe { transmute(azul::AzToasts_setMaxVisible(transmute(toasts), transmute(max_visible))) } }
+        pub(crate) fn AzToasts_withMaxVisible(toasts: &mut AzToasts, max_visible: usize) -> AzToasts { unsafe { transmute(azul::AzToasts_withMaxVisible(transmute(toasts), transmute(max_visible))) } }
+        pub(crate) fn AzToasts_setLayerStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) { unsafe { transmute(azul::AzToasts_setLayerStyle(transmute(toasts), transmute(style))) } }
+        pub(crate) fn AzToasts_withLayerStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) -> AzToasts { unsafe { transmute(azul::AzToasts_withLayerStyle(transmute(toasts), transmute(style))) } }
+        pub(crate) fn AzToasts_setToastStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) { unsafe { transmute(azul::AzToasts_setToastStyle(transmute(toasts), transmute(style))) } }
+        pub(crate) fn AzToasts_withToastStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) -> AzToasts { unsafe { transmute(azul::AzToasts_withToastStyle(transmute(toasts), transmute(style))) } }
+        pub(crate) fn AzToasts_setLabelStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) { unsafe { transmute(azul::AzToasts_setLabelStyle(transmute(toasts), transmute(style))) } }
+        pub(crate) fn AzToasts_withLabelStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) -> AzToasts { unsafe { transmute(azul::AzToasts_withLabelStyle(transmute(toasts), transmute(style))) } }
+        pub(crate) fn AzToasts_setActionStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) { unsafe { transmute(azul::AzToasts_setActionStyle(transmute(toasts), transmute(style))) } }
+        pub(crate) fn AzToasts_withActionStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) -> AzToasts { unsafe { transmute(azul::AzToasts_withActionStyle(transmute(toasts), transmute(style))) } }
+        pub(crate) fn AzToasts_dom(toasts: &mut AzToasts) -> AzDom { unsafe { transmute(azul::AzToasts_dom(transmute(toasts))) } }
+        pub(crate) fn AzToast_new(message: AzString) -> AzToast { unsafe { transmute(azul::AzToast_new(transmute(message))) } }
+        pub(crate) fn AzToast_setDuration(toast: &mut AzToast, duration: AzOptionDuration) { unsafe { transmute(azul::AzToast_setDuration(transmute(toast), transmute(duration))) } }
+        pub(crate) fn AzToast_withDuration(toast: &mut AzToast, duration: AzOptionDuration) -> AzToast { unsafe { transmute(azul::AzToast_withDuration(transmute(toast), transmute(duration))) } }
+        pub(crate) fn AzToast_setAction(toast: &mut AzToast, label: AzString, data: AzRefAny, callback: AzToastOnActionCallbackType) { unsafe { transmute(azul::AzToast_setAction(transmute(toast), transmute(label), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzToast_withAction(toast: &mut AzToast, label: AzString, data: AzRefAny, callback: AzToastOnActionCallbackType) -> AzToast { unsafe { transmute(azul::AzToast_withAction(transmute(toast), transmute(label), transmute(data), transmute(callback))) } }
         pub(crate) fn AzCssPropertyCache_delete(object: &mut AzCssPropertyCache) { unsafe { transmute(azul::AzCssPropertyCache_delete(transmute(object))) } }
         pub(crate) fn AzCssPropertyCache_deepCopy(object: &AzCssPropertyCache) -> AzCssPropertyCache { unsafe { transmute(azul::AzCssPropertyCache_deepCopy(transmute(object))) } }
         pub(crate) fn AzStyledDom_new(dom: AzDom, css: AzCss) -> AzStyledDom { unsafe { transmute(azul::AzStyledDom_new(transmute(dom), transmute(css))) } }
@@ -10880,6 +12501,7 @@ mod dll {
         pub(crate) fn AzGlVoidPtrConst_delete(object: &mut AzGlVoidPtrConst) { unsafe { transmute(azul::AzGlVoidPtrConst_delete(transmute(object))) } }
         pub(crate) fn AzGlVoidPtrConst_deepCopy(object: &AzGlVoidPtrConst) -> AzGlVoidPtrConst { unsafe { transmute(azul::AzGlVoidPtrConst_deepCopy(transmute(object))) } }
         pub(crate) fn AzGl_getType(gl: &AzGl) -> AzGlType { unsafe { transmute(azul::AzGl_getType(transmute(gl))) } }
+        pub(crate) fn AzGl_getQuirks(gl: &AzGl) -> AzGlQuirks { unsafe { transmute(azul::AzGl_getQuirks(transmute(gl))) } }
         pub(crate) fn AzGl_bufferDataUntyped(gl: &AzGl, target: u32, size: isize, data: AzGlVoidPtrConst, usage: u32) { unsafe { transmute(azul::AzGl_bufferDataUntyped(transmute(gl), transmute(target), transmute(size), transmute(data), transmute(usage))) } }
         pub(crate) fn AzGl_bufferSubDataUntyped(gl: &AzGl, target: u32, offset: isize, size: isize, data: AzGlVoidPtrConst) { unsafe { transmute(azul::AzGl_bufferSubDataUntyped(transmute(gl), transmute(target), transmute(offset), transmute(size), transmute(data))) } }
         pub(crate) fn AzGl_mapBuffer(gl: &AzGl, target: u32, access: u32) -> AzGlVoidPtrMut { unsafe { transmute(azul::AzGl_mapBuffer(transmute(gl), transmute(target), transmute(access))) } }
@@ -11102,6 +12724,12 @@ mod dll {
         pub(crate) fn AzGl_flushMappedBufferRange(gl: &AzGl, target: u32, offset: isize, length: isize) { unsafe { transmute(azul::AzGl_flushMappedBufferRange(transmute(gl), transmute(target), transmute(offset), transmute(length))) } }
         pub(crate) fn AzGl_delete(object: &mut AzGl) { unsafe { transmute(azul::AzGl_delete(transmute(object))) } }
         pub(crate) fn AzGl_deepCopy(object: &AzGl) -> AzGl { unsafe { transmute(azul::AzGl_deepCopy(transmute(object))) } }
+        pub(crate) fn AzGlQuirkRule_new() -> AzGlQuirkRule { unsafe { transmute(azul::AzGlQuirkRule_new()) } }
+        pub(crate) fn AzGlQuirkRule_withVendor(glquirkrule: AzGlQuirkRule, vendor: AzString) -> AzGlQuirkRule { unsafe { transmute(azul::AzGlQuirkRule_withVendor(transmute(glquirkrule), transmute(vendor))) } }
+        pub(crate) fn AzGlQuirkRule_withRenderer(glquirkrule: AzGlQuirkRule, renderer: AzString) -> AzGlQuirkRule { unsafe { transmute(azul::AzGlQuirkRule_withRenderer(transmute(glquirkrule), transmute(renderer))) } }
+        pub(crate) fn AzGlQuirkRule_withVersion(glquirkrule: AzGlQuirkRule, version: AzString) -> AzGlQuirkRule { unsafe { transmute(azul::AzGlQuirkRule_withVersion(transmute(glquirkrule), transmute(version))) } }
+        pub(crate) fn AzGlQuirkRule_withEnable(glquirkrule: AzGlQuirkRule, enable: AzGlQuirks) -> AzGlQuirkRule { unsafe { transmute(azul::AzGlQuirkRule_withEnable(transmute(glquirkrule), transmute(enable))) } }
+        pub(crate) fn AzGlQuirkRule_withDisable(glquirkrule: AzGlQuirkRule, disable: AzGlQuirks) -> AzGlQuirkRule { unsafe { transmute(azul::AzGlQuirkRule_withDisable(transmute(glquirkrule), transmute(disable))) } }
         pub(crate) fn AzVertexArrayObject_new(vertex_layout: AzVertexLayout, vao_id: u32, gl_context: AzGl) -> AzVertexArrayObject { unsafe { transmute(azul::AzVertexArrayObject_new(transmute(vertex_layout), transmute(vao_id), transmute(gl_context))) } }
         pub(crate) fn AzVertexArrayObject_delete(object: &mut AzVertexArrayObject) { unsafe { transmute(azul::AzVertexArrayObject_delete(transmute(object))) } }
         pub(crate) fn AzVertexArrayObject_deepCopy(object: &AzVertexArrayObject) -> AzVertexArrayObject { unsafe { transmute(azul::AzVertexArrayObject_deepCopy(transmute(object))) } }
@@ -11307,6 +12935,10 @@ mod dll {
         pub(crate) fn AzThreadReceiver_receive(threadreceiver: &mut AzThreadReceiver) -> AzOptionThreadSendMsg { unsafe { transmute(azul::AzThreadReceiver_receive(transmute(threadreceiver))) } }
         pub(crate) fn AzThreadReceiver_delete(object: &mut AzThreadReceiver) { unsafe { transmute(azul::AzThreadReceiver_delete(transmute(object))) } }
         pub(crate) fn AzThreadReceiver_deepCopy(object: &AzThreadReceiver) -> AzThreadReceiver { unsafe { transmute(azul::AzThreadReceiver_deepCopy(transmute(object))) } }
+        pub(crate) fn AzEventLoopProxy_wake(eventloopproxy: &AzEventLoopProxy) -> bool { unsafe { transmute(azul::AzEventLoopProxy_wake(transmute(eventloopproxy))) } }
+        pub(crate) fn AzEventLoopProxy_sendUserEvent(eventloopproxy: &AzEventLoopProxy, data: AzRefAny) -> bool { unsafe { transmute(azul::AzEventLoopProxy_sendUserEvent(transmute(eventloopproxy), transmute(data))) } }
+        pub(crate) fn AzEventLoopProxy_delete(object: &mut AzEventLoopProxy) { unsafe { transmute(azul::AzEventLoopProxy_delete(transmute(object))) } }
+        pub(crate) fn AzEventLoopProxy_deepCopy(object: &AzEventLoopProxy) -> AzEventLoopProxy { unsafe { transmute(azul::AzEventLoopProxy_deepCopy(transmute(object))) } }
         pub(crate) fn AzString_format(format: AzString, args: AzFmtArgVec) -> AzString { unsafe { transmute(azul::AzString_format(transmute(format), transmute(args))) } }
         pub(crate) fn AzString_copyFromBytes(ptr: *const u8, start: usize, len: usize) -> AzString { unsafe { transmute(azul::AzString_copyFromBytes(transmute(ptr), transmute(start), transmute(len))) } }
         pub(crate) fn AzString_trim(string: &AzString) -> AzString { unsafe { transmute(azul::AzString_trim(transmute(string))) } }
@@ -11334,9 +12966,12 @@ mod dll {
         pub(crate) fn AzFmtArgVec_delete(object: &mut AzFmtArgVec) { unsafe { transmute(azul::AzFmtArgVec_delete(transmute(object))) } }
         pub(crate) fn AzInlineLineVec_delete(object: &mut AzInlineLineVec) { unsafe { transmute(azul::AzInlineLineVec_delete(transmute(object))) } }
         pub(crate) fn AzInlineWordVec_delete(object: &mut AzInlineWordVec) { unsafe { transmute(azul::AzInlineWordVec_delete(transmute(object))) } }
+        pub(crate) fn AzPaintPrimitiveVec_delete(object: &mut AzPaintPrimitiveVec) { unsafe { transmute(azul::AzPaintPrimitiveVec_delete(transmute(object))) } }
         pub(crate) fn AzInlineGlyphVec_delete(object: &mut AzInlineGlyphVec) { unsafe { transmute(azul::AzInlineGlyphVec_delete(transmute(object))) } }
+        pub(crate) fn AzTextUnderlineVec_delete(object: &mut AzTextUnderlineVec) { unsafe { transmute(azul::AzTextUnderlineVec_delete(transmute(object))) } }
         pub(crate) fn AzInlineTextHitVec_delete(object: &mut AzInlineTextHitVec) { unsafe { transmute(azul::AzInlineTextHitVec_delete(transmute(object))) } }
         pub(crate) fn AzMonitorVec_delete(object: &mut AzMonitorVec) { unsafe { transmute(azul::AzMonitorVec_delete(transmute(object))) } }
+        pub(crate) fn AzTouchPointVec_delete(object: &mut AzTouchPointVec) { unsafe { transmute(azul::AzTouchPointVec_delete(transmute(object))) } }
         pub(crate) fn AzVideoModeVec_delete(object: &mut AzVideoModeVec) { unsafe { transmute(azul::AzVideoModeVec_delete(transmute(object))) } }
         pub(crate) fn AzDomVec_delete(object: &mut AzDomVec) { unsafe { transmute(azul::AzDomVec_delete(transmute(object))) } }
         pub(crate) fn AzIdOrClassVec_delete(object: &mut AzIdOrClassVec) { unsafe { transmute(azul::AzIdOrClassVec_delete(transmute(object))) } }
@@ -11357,6 +12992,8 @@ mod dll {
         pub(crate) fn AzU32Vec_delete(object: &mut AzU32Vec) { unsafe { transmute(azul::AzU32Vec_delete(transmute(object))) } }
         pub(crate) fn AzXWindowTypeVec_delete(object: &mut AzXWindowTypeVec) { unsafe { transmute(azul::AzXWindowTypeVec_delete(transmute(object))) } }
         pub(crate) fn AzVirtualKeyCodeVec_delete(object: &mut AzVirtualKeyCodeVec) { unsafe { transmute(azul::AzVirtualKeyCodeVec_delete(transmute(object))) } }
+        pub(crate) fn AzShortcutConflictVec_delete(object: &mut AzShortcutConflictVec) { unsafe { transmute(azul::AzShortcutConflictVec_delete(transmute(object))) } }
+        pub(crate) fn AzShortcutVec_delete(object: &mut AzShortcutVec) { unsafe { transmute(azul::AzShortcutVec_delete(transmute(object))) } }
         pub(crate) fn AzCascadeInfoVec_delete(object: &mut AzCascadeInfoVec) { unsafe { transmute(azul::AzCascadeInfoVec_delete(transmute(object))) } }
         pub(crate) fn AzScanCodeVec_delete(object: &mut AzScanCodeVec) { unsafe { transmute(azul::AzScanCodeVec_delete(transmute(object))) } }
         pub(crate) fn AzCssDeclarationVec_delete(object: &mut AzCssDeclarationVec) { unsafe { transmute(azul::AzCssDeclarationVec_delete(transmute(object))) } }
@@ -11396,30 +13033,60 @@ mod dll {
             pub(crate) fn AzApp_new(_:  AzRefAny, _:  AzAppConfig) -> AzApp;
             pub(crate) fn AzApp_addWindow(_:  &mut AzApp, _:  AzWindowCreateOptions);
             pub(crate) fn AzApp_addImage(_:  &mut AzApp, _:  AzString, _:  AzImageRef);
+            pub(crate) fn AzApp_withSplash(_:  AzApp, _:  AzSplashOptions) -> AzApp;
+            pub(crate) fn AzApp_addGlQuirkRule(_:  &mut AzApp, _:  AzGlQuirkRule);
             pub(crate) fn AzApp_getMonitors(_:  &AzApp) -> AzMonitorVec;
+            pub(crate) fn AzApp_createEventLoopProxy(_:  &AzApp) -> AzEventLoopProxy;
             pub(crate) fn AzApp_run(_:  &AzApp, _:  AzWindowCreateOptions);
             pub(crate) fn AzApp_delete(_:  &mut AzApp);
             pub(crate) fn AzApp_deepCopy(_:  &AzApp) -> AzApp;
             pub(crate) fn AzAppConfig_new(_:  AzLayoutSolver) -> AzAppConfig;
+            pub(crate) fn AzJankCapture_new(_:  AzDuration) -> AzJankCapture;
+            pub(crate) fn AzSplashOptions_new() -> AzSplashOptions;
+            pub(crate) fn AzSplashOptions_withLogo(_:  AzSplashOptions, _:  AzImageRef) -> AzSplashOptions;
+            pub(crate) fn AzFrameRecording_new(_:  AzFrameRecordingOutput) -> AzFrameRecording;
             pub(crate) fn AzSystemCallbacks_libraryInternal() -> AzSystemCallbacks;
             pub(crate) fn AzWindowCreateOptions_new(_:  AzLayoutCallbackType) -> AzWindowCreateOptions;
+            pub(crate) fn AzWindowCreateOptions_withParentHandle(_:  AzWindowCreateOptions, _:  AzRawWindowHandle) -> AzWindowCreateOptions;
+            pub(crate) fn AzWindowCreateOptions_withWindowData(_:  AzWindowCreateOptions, _:  AzRefAny) -> AzWindowCreateOptions;
+            pub(crate) fn AzWindowCreateOptions_withBackgroundEffect(_:  AzWindowCreateOptions, _:  AzWindowBackgroundEffect) -> AzWindowCreateOptions;
+            pub(crate) fn AzWindowCreateOptions_withFadeIn(_:  AzWindowCreateOptions, _:  AzDuration) -> AzWindowCreateOptions;
+            pub(crate) fn AzWindowCreateOptions_withFirstFrame(_:  AzWindowCreateOptions, _:  AzFirstFrameMode) -> AzWindowCreateOptions;
+            pub(crate) fn AzWindowCreateOptions_withScrollbars(_:  AzWindowCreateOptions, _:  AzScrollbarConfig) -> AzWindowCreateOptions;
+            pub(crate) fn AzScrollbarConfig_default() -> AzScrollbarConfig;
             pub(crate) fn AzLogicalPosition_new(_:  f32, _:  f32) -> AzLogicalPosition;
             pub(crate) fn AzLogicalPosition_zero() -> AzLogicalPosition;
             pub(crate) fn AzLogicalSize_toPhysical(_:  &AzLogicalSize, _:  f32) -> AzPhysicalSizeU32;
             pub(crate) fn AzWindowSize_getHidpiFactor(_:  &AzWindowSize) -> f32;
+            pub(crate) fn AzWindowSize_setZoom(_:  &mut AzWindowSize, _:  f32);
             pub(crate) fn AzKeyboardState_shiftDown(_:  &AzKeyboardState) -> bool;
             pub(crate) fn AzKeyboardState_ctrlDown(_:  &AzKeyboardState) -> bool;
             pub(crate) fn AzKeyboardState_altDown(_:  &AzKeyboardState) -> bool;
             pub(crate) fn AzKeyboardState_superDown(_:  &AzKeyboardState) -> bool;
+            pub(crate) fn AzKeyboardState_leftShiftDown(_:  &AzKeyboardState) -> bool;
+            pub(crate) fn AzKeyboardState_rightShiftDown(_:  &AzKeyboardState) -> bool;
+            pub(crate) fn AzKeyboardState_leftCtrlDown(_:  &AzKeyboardState) -> bool;
+            pub(crate) fn AzKeyboardState_rightCtrlDown(_:  &AzKeyboardState) -> bool;
+            pub(crate) fn AzKeyboardState_leftAltDown(_:  &AzKeyboardState) -> bool;
+            pub(crate) fn AzKeyboardState_rightAltDown(_:  &AzKeyboardState) -> bool;
+            pub(crate) fn AzKeyboardState_leftSuperDown(_:  &AzKeyboardState) -> bool;
+            pub(crate) fn AzKeyboardState_rightSuperDown(_:  &AzKeyboardState) -> bool;
             pub(crate) fn AzKeyboardState_isKeyDown(_:  &AzKeyboardState, _:  AzVirtualKeyCode) -> bool;
+            pub(crate) fn AzKeyboardState_isScancodeDown(_:  &AzKeyboardState, _:  u32) -> bool;
+            pub(crate) fn AzKeyboardState_getModifiers(_:  &AzKeyboardState) -> AzKeyboardModifiers;
             pub(crate) fn AzCursorPosition_getPosition(_:  &AzCursorPosition) -> AzOptionLogicalPosition;
             pub(crate) fn AzWindowState_new(_:  AzLayoutCallbackType) -> AzWindowState;
             pub(crate) fn AzWindowState_default() -> AzWindowState;
+            pub(crate) fn AzWindowState_setFullscreen(_:  &mut AzWindowState, _:  AzFullscreenMode);
+            pub(crate) fn AzLayoutCallback_hotReload(_:  AzString, _:  AzString) -> AzLayoutCallback;
             pub(crate) fn AzCallbackInfo_getHitNode(_:  &AzCallbackInfo) -> AzDomNodeId;
             pub(crate) fn AzCallbackInfo_getSystemTimeFn(_:  &AzCallbackInfo) -> AzGetSystemTimeFn;
             pub(crate) fn AzCallbackInfo_getCursorRelativeToViewport(_:  &AzCallbackInfo) -> AzOptionLogicalPosition;
             pub(crate) fn AzCallbackInfo_getCursorRelativeToNode(_:  &AzCallbackInfo) -> AzOptionLogicalPosition;
             pub(crate) fn AzCallbackInfo_getCurrentWindowState(_:  &AzCallbackInfo) -> AzWindowState;
+            pub(crate) fn AzCallbackInfo_getUserEvent(_:  &AzCallbackInfo) -> AzOptionRefAny;
+            pub(crate) fn AzCallbackInfo_getWindowData(_:  &AzCallbackInfo) -> AzOptionRefAny;
+            pub(crate) fn AzCallbackInfo_setWindowData(_:  &mut AzCallbackInfo, _:  AzRefAny);
             pub(crate) fn AzCallbackInfo_getCurrentKeyboardState(_:  &AzCallbackInfo) -> AzKeyboardState;
             pub(crate) fn AzCallbackInfo_getCurrentMouseState(_:  &AzCallbackInfo) -> AzMouseState;
             pub(crate) fn AzCallbackInfo_getPreviousWindowState(_:  &AzCallbackInfo) -> AzOptionWindowState;
@@ -11447,8 +13114,13 @@ mod dll {
             pub(crate) fn AzCallbackInfo_setWindowState(_:  &mut AzCallbackInfo, _:  AzWindowState);
             pub(crate) fn AzCallbackInfo_setFocus(_:  &mut AzCallbackInfo, _:  AzFocusTarget);
             pub(crate) fn AzCallbackInfo_setCssProperty(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzCssProperty);
+            pub(crate) fn AzCallbackInfo_addClass(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzString);
+            pub(crate) fn AzCallbackInfo_removeClass(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzString);
+            pub(crate) fn AzCallbackInfo_toggleClass(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzString);
+            pub(crate) fn AzCallbackInfo_setNodeStateFlag(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzNodeStateFlag, _:  bool);
             pub(crate) fn AzCallbackInfo_setScrollPosition(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzLogicalPosition);
             pub(crate) fn AzCallbackInfo_setStringContents(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzString);
+            pub(crate) fn AzCallbackInfo_setTextUnderlines(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzTextUnderlineVec);
             pub(crate) fn AzCallbackInfo_addImage(_:  &mut AzCallbackInfo, _:  AzString, _:  AzImageRef);
             pub(crate) fn AzCallbackInfo_hasImage(_:  &AzCallbackInfo, _:  AzString) -> bool;
             pub(crate) fn AzCallbackInfo_getImage(_:  &AzCallbackInfo, _:  AzString) -> AzOptionImageRef;
@@ -11459,10 +13131,27 @@ mod dll {
             pub(crate) fn AzCallbackInfo_createWindow(_:  &mut AzCallbackInfo, _:  AzWindowCreateOptions);
             pub(crate) fn AzCallbackInfo_startTimer(_:  &mut AzCallbackInfo, _:  AzTimer) -> AzTimerId;
             pub(crate) fn AzCallbackInfo_startAnimation(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzAnimation) -> AzOptionTimerId;
+            pub(crate) fn AzCallbackInfo_startCaretAnimation(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzCaretAnimation) -> AzOptionTimerId;
+            pub(crate) fn AzCallbackInfo_animateWindowResize(_:  &mut AzCallbackInfo, _:  AzLogicalSize, _:  AzDuration) -> AzTimerId;
             pub(crate) fn AzCallbackInfo_stopTimer(_:  &mut AzCallbackInfo, _:  AzTimerId) -> bool;
             pub(crate) fn AzCallbackInfo_startThread(_:  &mut AzCallbackInfo, _:  AzRefAny, _:  AzRefAny, _:  AzThreadCallbackType) -> AzOptionThreadId;
             pub(crate) fn AzCallbackInfo_sendThreadMsg(_:  &mut AzCallbackInfo, _:  AzThreadId, _:  AzThreadSendMsg) -> bool;
             pub(crate) fn AzCallbackInfo_stopThread(_:  &mut AzCallbackInfo, _:  AzThreadId) -> bool;
+            pub(crate) fn AzCallbackInfo_getSystemIdleTime(_:  &AzCallbackInfo) -> AzOptionDuration;
+            pub(crate) fn AzCallbackInfo_keycodeToChar(_:  &AzCallbackInfo, _:  AzVirtualKeyCode, _:  AzKeyboardModifiers) -> AzOptionChar;
+            pub(crate) fn AzCallbackInfo_getLogLines(_:  &AzCallbackInfo) -> AzStringVec;
+            pub(crate) fn AzCallbackInfo_setLogLevel(_:  &AzCallbackInfo, _:  AzString, _:  AzAppLogLevel);
+            pub(crate) fn AzCallbackInfo_getNodeRect(_:  &AzCallbackInfo, _:  AzDomNodeId) -> AzOptionNodeRect;
+            pub(crate) fn AzCallbackInfo_getTextLayout(_:  &AzCallbackInfo, _:  AzDomNodeId) -> AzOptionTextLayout;
+            pub(crate) fn AzCallbackInfo_getScrollInfo(_:  &AzCallbackInfo, _:  AzDomNodeId) -> AzOptionScrollInfo;
+            pub(crate) fn AzCallbackInfo_addShortcut(_:  &mut AzCallbackInfo, _:  AzShortcut) -> AzOptionShortcutConflict;
+            pub(crate) fn AzCallbackInfo_removeShortcut(_:  &mut AzCallbackInfo, _:  AzVirtualKeyCodeCombo) -> bool;
+            pub(crate) fn AzCallbackInfo_getShortcutConflicts(_:  &AzCallbackInfo) -> AzShortcutConflictVec;
+            pub(crate) fn AzCallbackInfo_announce(_:  &mut AzCallbackInfo, _:  AzString, _:  AzPoliteness);
+            pub(crate) fn AzCallbackInfo_triggerHaptic(_:  &mut AzCallbackInfo, _:  AzHapticKind);
+            pub(crate) fn AzCallbackInfo_requestAnimationFrame(_:  &mut AzCallbackInfo, _:  AzRefAny, _:  AzTimerCallbackType);
+            pub(crate) fn AzCallbackInfo_crossfadeSubtree(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzDuration);
+            pub(crate) fn AzCallbackInfo_renderSubtreeToFrames(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzDuration, _:  u32, _:  AzString);
             pub(crate) fn AzPositionInfo_isPositioned(_:  &AzPositionInfo) -> bool;
             pub(crate) fn AzPositionInfo_getStaticOffset(_:  &AzPositionInfo) -> AzLogicalPosition;
             pub(crate) fn AzPositionInfo_getRelativeOffset(_:  &AzPositionInfo) -> AzLogicalPosition;
@@ -11497,6 +13186,7 @@ mod dll {
             pub(crate) fn AzLayoutCallbackInfo_getGlContext(_:  &AzLayoutCallbackInfo) -> AzOptionGl;
             pub(crate) fn AzLayoutCallbackInfo_getSystemFonts(_:  &AzLayoutCallbackInfo) -> AzStringPairVec;
             pub(crate) fn AzLayoutCallbackInfo_getImage(_:  &AzLayoutCallbackInfo, _:  AzString) -> AzOptionImageRef;
+            pub(crate) fn AzLayoutCallbackInfo_getSystemStyle(_:  &AzLayoutCallbackInfo) -> AzSystemStyle;
             pub(crate) fn AzDom_new(_:  AzNodeType) -> AzDom;
             pub(crate) fn AzDom_body() -> AzDom;
             pub(crate) fn AzDom_div() -> AzDom;
@@ -11504,6 +13194,10 @@ mod dll {
             pub(crate) fn AzDom_text(_:  AzString) -> AzDom;
             pub(crate) fn AzDom_image(_:  AzImageRef) -> AzDom;
             pub(crate) fn AzDom_iframe(_:  AzRefAny, _:  AzIFrameCallbackType) -> AzDom;
+            pub(crate) fn AzDom_customLayout(_:  AzRefAny, _:  AzMeasureCallbackType, _:  AzArrangeCallbackType) -> AzDom;
+            pub(crate) fn AzDom_customNode(_:  AzString, _:  AzRefAny) -> AzOptionDom;
+            pub(crate) fn AzDom_iframeWithReinvokeThreshold(_:  AzRefAny, _:  AzIFrameCallbackType, _:  f32) -> AzDom;
+            pub(crate) fn AzDom_overlay(_:  AzString, _:  AzOverlayPlacement) -> AzDom;
             pub(crate) fn AzDom_setNodeType(_:  &mut AzDom, _:  AzNodeType);
             pub(crate) fn AzDom_withNodeType(_:  &mut AzDom, _:  AzNodeType) -> AzDom;
             pub(crate) fn AzDom_setDataset(_:  &mut AzDom, _:  AzRefAny);
@@ -11550,11 +13244,22 @@ mod dll {
             pub(crate) fn AzDom_withMenuBar(_:  &mut AzDom, _:  AzMenu) -> AzDom;
             pub(crate) fn AzDom_setContextMenu(_:  &mut AzDom, _:  AzMenu);
             pub(crate) fn AzDom_withContextMenu(_:  &mut AzDom, _:  AzMenu) -> AzDom;
+            pub(crate) fn AzDom_setFocusNavigation(_:  &mut AzDom, _:  AzFocusNavigation);
+            pub(crate) fn AzDom_withFocusNavigation(_:  &mut AzDom, _:  AzFocusNavigation) -> AzDom;
+            pub(crate) fn AzDom_setLang(_:  &mut AzDom, _:  AzString);
+            pub(crate) fn AzDom_withLang(_:  &mut AzDom, _:  AzString) -> AzDom;
+            pub(crate) fn AzDom_setInputMode(_:  &mut AzDom, _:  AzInputMode);
+            pub(crate) fn AzDom_withInputMode(_:  &mut AzDom, _:  AzInputMode) -> AzDom;
+            pub(crate) fn AzDom_setStateFlag(_:  &mut AzDom, _:  AzNodeStateFlag, _:  bool);
+            pub(crate) fn AzDom_withStateFlag(_:  &mut AzDom, _:  AzNodeStateFlag, _:  bool) -> AzDom;
             pub(crate) fn AzDom_hash(_:  &AzDom) -> u64;
             pub(crate) fn AzDom_nodeCount(_:  &AzDom) -> usize;
             pub(crate) fn AzDom_getHtmlString(_:  &mut AzDom) -> AzString;
             pub(crate) fn AzDom_getHtmlStringTest(_:  &mut AzDom) -> AzString;
             pub(crate) fn AzDom_style(_:  &mut AzDom, _:  AzCss) -> AzStyledDom;
+            pub(crate) fn AzCustomNodeType_new(_:  AzString, _:  AzMeasureCallbackType, _:  AzArrangeCallbackType) -> AzCustomNodeType;
+            pub(crate) fn AzCustomNodeType_withPaint(_:  AzCustomNodeType, _:  AzPaintCallbackType) -> AzCustomNodeType;
+            pub(crate) fn AzCustomNodeType_register(_:  AzCustomNodeType);
             pub(crate) fn AzNodeData_new(_:  AzNodeType) -> AzNodeData;
             pub(crate) fn AzNodeData_body() -> AzNodeData;
             pub(crate) fn AzNodeData_div() -> AzNodeData;
@@ -11562,6 +13267,8 @@ mod dll {
             pub(crate) fn AzNodeData_text(_:  AzString) -> AzNodeData;
             pub(crate) fn AzNodeData_image(_:  AzImageRef) -> AzNodeData;
             pub(crate) fn AzNodeData_iframe(_:  AzRefAny, _:  AzIFrameCallbackType) -> AzNodeData;
+            pub(crate) fn AzNodeData_customLayout(_:  AzRefAny, _:  AzMeasureCallbackType, _:  AzArrangeCallbackType) -> AzNodeData;
+            pub(crate) fn AzNodeData_iframeWithReinvokeThreshold(_:  AzRefAny, _:  AzIFrameCallbackType, _:  f32) -> AzNodeData;
             pub(crate) fn AzNodeData_setNodeType(_:  &mut AzNodeData, _:  AzNodeType);
             pub(crate) fn AzNodeData_withNodeType(_:  &mut AzNodeData, _:  AzNodeType) -> AzNodeData;
             pub(crate) fn AzNodeData_setDataset(_:  &mut AzNodeData, _:  AzRefAny);
@@ -11587,7 +13294,12 @@ mod dll {
             pub(crate) fn AzNodeData_setAccessibilityInfo(_:  &mut AzNodeData, _:  AzAccessibilityInfo);
             pub(crate) fn AzNodeData_setMenuBar(_:  &mut AzNodeData, _:  AzMenu);
             pub(crate) fn AzNodeData_setContextMenu(_:  &mut AzNodeData, _:  AzMenu);
+            pub(crate) fn AzNodeData_setFocusNavigation(_:  &mut AzNodeData, _:  AzFocusNavigation);
             pub(crate) fn AzNodeData_hash(_:  &AzNodeData) -> u64;
+            pub(crate) fn AzNodeData_setOverlay(_:  &mut AzNodeData, _:  AzOverlayInfo);
+            pub(crate) fn AzNodeData_setLang(_:  &mut AzNodeData, _:  AzString);
+            pub(crate) fn AzNodeData_setInputMode(_:  &mut AzNodeData, _:  AzInputMode);
+            pub(crate) fn AzNodeData_setStateFlag(_:  &mut AzNodeData, _:  AzNodeStateFlag, _:  bool);
             pub(crate) fn AzOn_intoEventFilter(_:  AzOn) -> AzEventFilter;
             pub(crate) fn AzMenu_new(_:  AzMenuItemVec) -> AzMenu;
             pub(crate) fn AzMenu_setPopupPosition(_:  &mut AzMenu, _:  AzMenuPopupPosition);
@@ -11600,6 +13312,11 @@ mod dll {
             pub(crate) fn AzStringMenuItem_setChildren(_:  &mut AzStringMenuItem, _:  AzMenuItemVec);
             pub(crate) fn AzStringMenuItem_withChildren(_:  &mut AzStringMenuItem, _:  AzMenuItemVec) -> AzStringMenuItem;
             pub(crate) fn AzMenuCallback_new(_:  AzRefAny, _:  AzCallbackType) -> AzMenuCallback;
+            pub(crate) fn AzShortcut_new(_:  AzVirtualKeyCodeCombo, _:  AzRefAny, _:  AzCallbackType) -> AzShortcut;
+            pub(crate) fn AzShortcutMap_new() -> AzShortcutMap;
+            pub(crate) fn AzShortcutMap_add(_:  &mut AzShortcutMap, _:  AzShortcut) -> AzOptionShortcutConflict;
+            pub(crate) fn AzShortcutMap_remove(_:  &mut AzShortcutMap, _:  AzVirtualKeyCodeCombo) -> bool;
+            pub(crate) fn AzShortcutMap_getConflicts(_:  &AzShortcutMap) -> AzShortcutConflictVec;
             pub(crate) fn AzCss_empty() -> AzCss;
             pub(crate) fn AzCss_fromString(_:  AzString) -> AzCss;
             pub(crate) fn AzColorU_fromStr(_:  AzString) -> AzColorU;
@@ -11644,6 +13361,14 @@ mod dll {
             pub(crate) fn AzTextInput_withOnVirtualKeyDown(_:  &mut AzTextInput, _:  AzRefAny, _:  AzTextInputOnVirtualKeyDownCallbackType) -> AzTextInput;
             pub(crate) fn AzTextInput_setOnFocusLost(_:  &mut AzTextInput, _:  AzRefAny, _:  AzTextInputOnFocusLostCallbackType);
             pub(crate) fn AzTextInput_withOnFocusLost(_:  &mut AzTextInput, _:  AzRefAny, _:  AzTextInputOnFocusLostCallbackType) -> AzTextInput;
+            pub(crate) fn AzTextInput_setCaretAnimation(_:  &mut AzTextInput, _:  AzCaretAnimation);
+            pub(crate) fn AzTextInput_withCaretAnimation(_:  &mut AzTextInput, _:  AzCaretAnimation) -> AzTextInput;
+            pub(crate) fn AzTextInput_setSpellcheck(_:  &mut AzTextInput, _:  AzString);
+            pub(crate) fn AzTextInput_withSpellcheck(_:  &mut AzTextInput, _:  AzString) -> AzTextInput;
+            pub(crate) fn AzTextInput_setOnSpellingSuggestions(_:  &mut AzTextInput, _:  AzRefAny, _:  AzTextInputOnSpellingSuggestionsCallbackType);
+            pub(crate) fn AzTextInput_withOnSpellingSuggestions(_:  &mut AzTextInput, _:  AzRefAny, _:  AzTextInputOnSpellingSuggestionsCallbackType) -> AzTextInput;
+            pub(crate) fn AzTextInput_setSecure(_:  &mut AzTextInput, _:  bool);
+            pub(crate) fn AzTextInput_withSecure(_:  &mut AzTextInput, _:  bool) -> AzTextInput;
             pub(crate) fn AzTextInput_setPlaceholderStyle(_:  &mut AzTextInput, _:  AzNodeDataInlineCssPropertyVec);
             pub(crate) fn AzTextInput_withPlaceholderStyle(_:  &mut AzTextInput, _:  AzNodeDataInlineCssPropertyVec) -> AzTextInput;
             pub(crate) fn AzTextInput_setContainerStyle(_:  &mut AzTextInput, _:  AzNodeDataInlineCssPropertyVec);
@@ -11652,7 +13377,7 @@ mod dll {
             pub(crate) fn AzTextInput_withLabelStyle(_:  &mut AzTextInput, _:  AzNodeDataInlineCssPropertyVec) -> AzTextInput;
             pub(crate) fn AzTextInput_dom(_:  &mut AzTextInput) -> AzDom;
             pub(crate) fn AzTextInputState_getText(_:  &AzTextInputState) -> AzString;
-            pub(crate) fn AzNumberInput_new(_:  f32) -> AzNumberInput;
+            pub(crate) fn AzNumberInput_new(_:  f64) -> AzNumberInput;
             pub(crate) fn AzNumberInput_setOnTextInput(_:  &mut AzNumberInput, _:  AzRefAny, _:  AzTextInputOnTextInputCallbackType);
             pub(crate) fn AzNumberInput_withOnTextInput(_:  &mut AzNumberInput, _:  AzRefAny, _:  AzTextInputOnTextInputCallbackType) -> AzNumberInput;
             pub(crate) fn AzNumberInput_setOnVirtualKeyDown(_:  &mut AzNumberInput, _:  AzRefAny, _:  AzTextInputOnVirtualKeyDownCallbackType);
@@ -11667,6 +13392,20 @@ mod dll {
             pub(crate) fn AzNumberInput_withLabelStyle(_:  &mut AzNumberInput, _:  AzNodeDataInlineCssPropertyVec) -> AzNumberInput;
             pub(crate) fn AzNumberInput_setOnValueChange(_:  &mut AzNumberInput, _:  AzRefAny, _:  AzNumberInputOnValueChangeCallbackType);
             pub(crate) fn AzNumberInput_withOnValueChange(_:  &mut AzNumberInput, _:  AzRefAny, _:  AzNumberInputOnValueChangeCallbackType) -> AzNumberInput;
+            pub(crate) fn AzNumberInput_setSpinnerButtonStyle(_:  &mut AzNumberInput, _:  AzNodeDataInlineCssPropertyVec);
+            pub(crate) fn AzNumberInput_withSpinnerButtonStyle(_:  &mut AzNumberInput, _:  AzNodeDataInlineCssPropertyVec) -> AzNumberInput;
+            pub(crate) fn AzNumberInput_setInvalidStyle(_:  &mut AzNumberInput, _:  AzNodeDataInlineCssPropertyVec);
+            pub(crate) fn AzNumberInput_withInvalidStyle(_:  &mut AzNumberInput, _:  AzNodeDataInlineCssPropertyVec) -> AzNumberInput;
+            pub(crate) fn AzNumberInput_setMode(_:  &mut AzNumberInput, _:  AzNumberInputMode);
+            pub(crate) fn AzNumberInput_withMode(_:  &mut AzNumberInput, _:  AzNumberInputMode) -> AzNumberInput;
+            pub(crate) fn AzNumberInput_setMin(_:  &mut AzNumberInput, _:  f64);
+            pub(crate) fn AzNumberInput_withMin(_:  &mut AzNumberInput, _:  f64) -> AzNumberInput;
+            pub(crate) fn AzNumberInput_setMax(_:  &mut AzNumberInput, _:  f64);
+            pub(crate) fn AzNumberInput_withMax(_:  &mut AzNumberInput, _:  f64) -> AzNumberInput;
+            pub(crate) fn AzNumberInput_setStep(_:  &mut AzNumberInput, _:  f64);
+            pub(crate) fn AzNumberInput_withStep(_:  &mut AzNumberInput, _:  f64) -> AzNumberInput;
+            pub(crate) fn AzNumberInput_setDecimalSeparator(_:  &mut AzNumberInput, _:  u32);
+            pub(crate) fn AzNumberInput_withDecimalSeparator(_:  &mut AzNumberInput, _:  u32) -> AzNumberInput;
             pub(crate) fn AzNumberInput_dom(_:  &mut AzNumberInput) -> AzDom;
             pub(crate) fn AzProgressBar_new(_:  f32) -> AzProgressBar;
             pub(crate) fn AzProgressBar_setHeight(_:  &mut AzProgressBar, _:  AzPixelValue);
@@ -11698,6 +13437,25 @@ mod dll {
             pub(crate) fn AzTreeView_dom(_:  &mut AzTreeView) -> AzDom;
             pub(crate) fn AzDropDown_new(_:  AzStringVec) -> AzDropDown;
             pub(crate) fn AzDropDown_dom(_:  &mut AzDropDown) -> AzDom;
+            pub(crate) fn AzToasts_new() -> AzToasts;
+            pub(crate) fn AzToasts_show(_:  &mut AzToasts, _:  &mut AzCallbackInfo, _:  AzToast) -> AzToastId;
+            pub(crate) fn AzToasts_dismiss(_:  &mut AzToasts, _:  &mut AzCallbackInfo, _:  AzToastId) -> bool;
+            pub(crate) fn AzToasts_setMaxVisible(_:  &mut AzToasts, _:  usize);
+            pub(crate) fn AzToasts_withMaxVisible(_:  &mut AzToasts, _:  usize) -> AzToasts;
+            pub(crate) fn AzToasts_setLayerStyle(_:  &mut AzToasts, _:  AzNodeDataInlineCssPropertyVec);
+            pub(crate) fn AzToasts_withLayerStyle(_:  &mut AzToasts, _:  AzNodeDataInlineCssPropertyVec) -> AzToasts;
+            pub(crate) fn AzToasts_setToastStyle(_:  &mut AzToasts, _:  AzNodeDataInlineCssPropertyVec);
+            pub(crate) fn AzToasts_withToastStyle(_:  &mut AzToasts, _:  AzNodeDataInlineCssPropertyVec) -> AzToasts;
+            pub(crate) fn AzToasts_setLabelStyle(_:  &mut AzToasts, _:  AzNodeDataInlineCssPropertyVec);
+            pub(crate) fn AzToasts_withLabelStyle(_:  &mut AzToasts, _:  AzNodeDataInlineCssPropertyVec) -> AzToasts;
+            pub(crate) fn AzToasts_setActionStyle(_:  &mut AzToasts, _:  AzNodeDataInlineCssPropertyVec);
+            pub(crate) fn AzToasts_withActionStyle(_:  &mut AzToasts, _:  AzNodeDataInlineCssPropertyVec) -> AzToasts;
+            pub(crate) fn AzToasts_dom(_:  &mut AzToasts) -> AzDom;
+            pub(crate) fn AzToast_new(_:  AzString) -> AzToast;
+            pub(crate) fn AzToast_setDuration(_:  &mut AzToast, _:  AzOptionDuration);
+            pub(crate) fn AzToast_withDuration(_:  &mut AzToast, _:  AzOptionDuration) -> AzToast;
+            pub(crate) fn AzToast_setAction(_:  &mut AzToast, _:  AzString, _:  AzRefAny, _:  AzToastOnActionCallbackType);
+            pub(crate) fn AzToast_withAction(_:  &mut AzToast, _:  AzString, _:  AzRefAny, _:  AzToastOnActionCallbackType) -> AzToast;
             pub(crate) fn AzCssPropertyCache_delete(_:  &mut AzCssPropertyCache);
             pub(crate) fn AzCssPropertyCache_deepCopy(_:  &AzCssPropertyCache) -> AzCssPropertyCache;
             pub(crate) fn AzStyledDom_new(_:  AzDom, _:  AzCss) -> AzStyledDom;
@@ -11727,6 +13485,7 @@ mod dll {
             pub(crate) fn AzGlVoidPtrConst_delete(_:  &mut AzGlVoidPtrConst);
             pub(crate) fn AzGlVoidPtrConst_deepCopy(_:  &AzGlVoidPtrConst) -> AzGlVoidPtrConst;
             pub(crate) fn AzGl_getType(_:  &AzGl) -> AzGlType;
+            pub(crate) fn AzGl_getQuirks(_:  &AzGl) -> AzGlQuirks;
             pub(crate) fn AzGl_bufferDataUntyped(_:  &AzGl, _:  u32, _:  isize, _:  AzGlVoidPtrConst, _:  u32);
             pub(crate) fn AzGl_bufferSubDataUntyped(_:  &AzGl, _:  u32, _:  isize, _:  isize, _:  AzGlVoidPtrConst);
             pub(crate) fn AzGl_mapBuffer(_:  &AzGl, _:  u32, _:  u32) -> AzGlVoidPtrMut;
@@ -11949,6 +13708,12 @@ mod dll {
             pub(crate) fn AzGl_flushMappedBufferRange(_:  &AzGl, _:  u32, _:  isize, _:  isize);
             pub(crate) fn AzGl_delete(_:  &mut AzGl);
             pub(crate) fn AzGl_deepCopy(_:  &AzGl) -> AzGl;
+            pub(crate) fn AzGlQuirkRule_new() -> AzGlQuirkRule;
+            pub(crate) fn AzGlQuirkRule_withVendor(_:  AzGlQuirkRule, _:  AzString) -> AzGlQuirkRule;
+            pub(crate) fn AzGlQuirkRule_withRenderer(_:  AzGlQuirkRule, _:  AzString) -> AzGlQuirkRule;
+            pub(crate) fn AzGlQuirkRule_withVersion(_:  AzGlQuirkRule, _:  AzString) -> AzGlQuirkRule;
+            pub(crate) fn AzGlQuirkRule_withEnable(_:  AzGlQuirkRule, _:  AzGlQuirks) -> AzGlQuirkRule;
+            pub(crate) fn AzGlQuirkRule_withDisable(_:  AzGlQuirkRule, _:  AzGlQuirks) -> AzGlQuirkRule;
             pub(crate) fn AzVertexArrayObject_new(_:  AzVertexLayout, _:  u32, _:  AzGl) -> AzVertexArrayObject;
             pub(crate) fn AzVertexArrayObject_delete(_:  &mut AzVertexArrayObject);
             pub(crate) fn AzVertexArrayObject_deepCopy(_:  &AzVertexArrayObject) -> AzVertexArrayObject;
@@ -12154,6 +13919,10 @@ mod dll {
             pub(crate) fn AzThreadReceiver_receive(_:  &mut AzThreadReceiver) -> AzOptionThreadSendMsg;
             pub(crate) fn AzThreadReceiver_delete(_:  &mut AzThreadReceiver);
             pub(crate) fn AzThreadReceiver_deepCopy(_:  &AzThreadReceiver) -> AzThreadReceiver;
+            pub(crate) fn AzEventLoopProxy_wake(_:  &AzEventLoopProxy) -> bool;
+            pub(crate) fn AzEventLoopProxy_sendUserEvent(_:  &AzEventLoopProxy, _:  AzRefAny) -> bool;
+            pub(crate) fn AzEventLoopProxy_delete(_:  &mut AzEventLoopProxy);
+            pub(crate) fn AzEventLoopProxy_deepCopy(_:  &AzEventLoopProxy) -> AzEventLoopProxy;
             pub(crate) fn AzString_format(_:  AzString, _:  AzFmtArgVec) -> AzString;
             pub(crate) fn AzString_copyFromBytes(_:  *const u8, _:  usize, _:  usize) -> AzString;
             pub(crate) fn AzString_trim(_:  &AzString) -> AzString;
@@ -12181,9 +13950,12 @@ mod dll {
             pub(crate) fn AzFmtArgVec_delete(_:  &mut AzFmtArgVec);
             pub(crate) fn AzInlineLineVec_delete(_:  &mut AzInlineLineVec);
             pub(crate) fn AzInlineWordVec_delete(_:  &mut AzInlineWordVec);
+            pub(crate) fn AzPaintPrimitiveVec_delete(_:  &mut AzPaintPrimitiveVec);
             pub(crate) fn AzInlineGlyphVec_delete(_:  &mut AzInlineGlyphVec);
+            pub(crate) fn AzTextUnderlineVec_delete(_:  &mut AzTextUnderlineVec);
             pub(crate) fn AzInlineTextHitVec_delete(_:  &mut AzInlineTextHitVec);
             pub(crate) fn AzMonitorVec_delete(_:  &mut AzMonitorVec);
+            pub(crate) fn AzTouchPointVec_delete(_:  &mut AzTouchPointVec);
             pub(crate) fn AzVideoModeVec_delete(_:  &mut AzVideoModeVec);
             pub(crate) fn AzDomVec_delete(_:  &mut AzDomVec);
             pub(crate) fn AzIdOrClassVec_delete(_:  &mut AzIdOrClassVec);
@@ -12204,6 +13976,8 @@ mod dll {
             pub(crate) fn AzU32Vec_delete(_:  &mut AzU32Vec);
             pub(crate) fn AzXWindowTypeVec_delete(_:  &mut AzXWindowTypeVec);
             pub(crate) fn AzVirtualKeyCodeVec_delete(_:  &mut AzVirtualKeyCodeVec);
+            pub(crate) fn AzShortcutConflictVec_delete(_:  &mut AzShortcutConflictVec);
+            pub(crate) fn AzShortcutVec_delete(_:  &mut AzShortcutVec);
             pub(crate) fn AzCascadeInfoVec_delete(_:  &mut AzCascadeInfoVec);
             pub(crate) fn AzScanCodeVec_delete(_:  &mut AzScanCodeVec);
             pub(crate) fn AzCssDeclarationVec_delete(_:  &mut AzCssDeclarationVec);
@@ -12246,6 +14020,8 @@ pub mod app {
     use crate::window::WindowCreateOptions;
     use crate::str::String;
     use crate::image::ImageRef;
+    use crate::gl::GlQuirkRule;
+    use crate::time::Duration;
     /// Main application class
     
     #[doc(inline)] pub use crate::dll::AzApp as App;
@@ -12257,8 +14033,14 @@ pub mod app {
         pub fn add_window<_1: Into<WindowCreateOptions>>(&mut self, window: _1)  { unsafe { crate::dll::AzApp_addWindow(self, window.into()) } }
         /// Adds a new image identified by an ID to the image cache
         pub fn add_image<_1: Into<String>, _2: Into<ImageRef>>(&mut self, id: _1, image: _2)  { unsafe { crate::dll::AzApp_addImage(self, id.into(), image.into()) } }
+        /// Shows a splash screen (logo + progress bar) while the font cache is built and the first layout runs, the splash cross-fades into the root window once it has rendered its first frame. Currently only implemented on Windows.
+        pub fn with_splash<_1: Into<SplashOptions>>(self, splash: _1)  -> crate::app::App { unsafe { crate::dll::AzApp_withSplash(self, splash.into()) } }
+        /// Adds a rule that enables or disables driver workarounds (i.e. forcing software rendering on a specific GPU). The rules are evaluated in order after the built-in rules when a window is created.
+        pub fn add_gl_quirk_rule<_1: Into<GlQuirkRule>>(&mut self, rule: _1)  { unsafe { crate::dll::AzApp_addGlQuirkRule(self, rule.into()) } }
         /// Returns a list of monitors - useful for setting the monitor that a window should spawn on.
         pub fn get_monitors(&self)  -> crate::vec::MonitorVec { unsafe { crate::dll::AzApp_getMonitors(self) } }
+        /// Returns a handle that other threads can use to wake up the event loop or to send data to the windows (`On::UserEvent`)
+        pub fn create_event_loop_proxy(&self)  -> crate::task::EventLoopProxy { unsafe { crate::dll::AzApp_createEventLoopProxy(self) } }
         /// Runs the application. Due to platform restrictions (specifically `WinMain` on Windows), this function never returns.
         pub fn run<_1: Into<WindowCreateOptions>>(&self, window: _1)  { unsafe { crate::dll::AzApp_run(self, window.into()) } }
     }
@@ -12277,6 +14059,59 @@ pub mod app {
     /// Configuration to set which messages should be logged.
     
     #[doc(inline)] pub use crate::dll::AzAppLogLevel as AppLogLevel;
+    /// Configuration of the jank detector, see `AppConfig::jank_capture`
+    
+    #[doc(inline)] pub use crate::dll::AzJankCapture as JankCapture;
+    impl JankCapture {
+
+        /// Creates a new `JankCapture` that writes the reports (without display list) to the temporary directory
+        pub fn new<_1: Into<Duration>>(threshold: _1) -> Self { unsafe { crate::dll::AzJankCapture_new(threshold.into()) } }
+    }
+
+    /// Startup splash screen (logo + progress bar), see `App::with_splash`
+    
+    #[doc(inline)] pub use crate::dll::AzSplashOptions as SplashOptions;
+    impl SplashOptions {
+
+        /// Creates a white 480x300 splash window without a logo
+        pub fn new() -> Self { unsafe { crate::dll::AzSplashOptions_new() } }
+        /// Sets the logo of the splash window
+        pub fn with_logo<_1: Into<ImageRef>>(self, logo: _1)  -> crate::app::SplashOptions { unsafe { crate::dll::AzSplashOptions_withLogo(self, logo.into()) } }
+    }
+
+    /// Whether input events are recorded or replayed, see `AppConfig::event_recording`
+    
+    #[doc(inline)] pub use crate::dll::AzEventRecording as EventRecording;
+    /// Recording of the presented frames of all windows, see `AppConfig::frame_recording`
+    
+    #[doc(inline)] pub use crate::dll::AzFrameRecording as FrameRecording;
+    impl FrameRecording {
+
+        /// Creates a new `FrameRecording` that records at most 30 frames per second
+        pub fn new<_1: Into<FrameRecordingOutput>>(output: _1) -> Self { unsafe { crate::dll::AzFrameRecording_new(output.into()) } }
+    }
+
+    /// Where the frames of a `FrameRecording` are written to
+    
+    #[doc(inline)] pub use crate::dll::AzFrameRecordingOutput as FrameRecordingOutput;
+    /// A frame that was presented by a window, passed to the `FrameRecordingCallback`
+    
+    #[doc(inline)] pub use crate::dll::AzRecordedFrame as RecordedFrame;
+    /// `FrameRecordingCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzFrameRecordingCallbackType as FrameRecordingCallbackType;
+    /// Function that is invoked with every recorded frame, once the next frame has been presented (or the window is closed)
+    
+    #[doc(inline)] pub use crate::dll::AzFrameRecordingCallback as FrameRecordingCallback;
+    /// Information about a panic or crash, passed to the `CrashHandler`
+    
+    #[doc(inline)] pub use crate::dll::AzCrashInfo as CrashInfo;
+    /// `CrashHandlerType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzCrashHandlerType as CrashHandlerType;
+    /// Function that is invoked when the app panics or crashes
+    
+    #[doc(inline)] pub use crate::dll::AzCrashHandler as CrashHandler;
     /// Version of the layout solver to use - future binary versions of azul may have more fields here, necessary so that old compiled applications don't break with newer releases of azul. Newer layout versions are opt-in only.
     
     #[doc(inline)] pub use crate::dll::AzLayoutSolver as LayoutSolver;
@@ -12379,7 +14214,91 @@ pub mod window {
             b_x + b_width <= a_x + a_width &&
             b_y + b_height <= a_y + a_height
         }
-    }    use crate::callbacks::LayoutCallbackType;
+    }
+    /// Allows passing the window to crates using `raw-window-handle` v0.6
+    /// (i.e. `wgpu` surfaces or `rfd` dialogs) without unsafe pointer conversions
+    #[cfg(feature = "raw-window-handle")]
+    impl raw_window_handle::HasWindowHandle for RawWindowHandle {
+        fn window_handle(&self) -> Result<raw_window_handle::WindowHandle<'_>, raw_window_handle::HandleError> {
+
+            use core::num::{NonZeroIsize, NonZeroU32};
+            use core::ptr::NonNull;
+            use raw_window_handle as rwh;
+            use raw_window_handle::HandleError::{NotSupported, Unavailable};
+
+            let raw = match self {
+                RawWindowHandle::Windows(h) => {
+                    let hwnd = NonZeroIsize::new(h.hwnd as isize).ok_or(Unavailable)?;
+                    let mut handle = rwh::Win32WindowHandle::new(hwnd);
+                    handle.hinstance = NonZeroIsize::new(h.hinstance as isize);
+                    rwh::RawWindowHandle::Win32(handle)
+                },
+                RawWindowHandle::Xlib(h) => {
+                    if h.window == 0 {
+                        return Err(Unavailable);
+                    }
+                    rwh::RawWindowHandle::Xlib(rwh::XlibWindowHandle::new(h.window as _))
+                },
+                RawWindowHandle::Xcb(h) => {
+                    let window = NonZeroU32::new(h.window).ok_or(Unavailable)?;
+                    rwh::RawWindowHandle::Xcb(rwh::XcbWindowHandle::new(window))
+                },
+                RawWindowHandle::Wayland(h) => {
+                    let surface = NonNull::new(h.surface).ok_or(Unavailable)?;
+                    rwh::RawWindowHandle::Wayland(rwh::WaylandWindowHandle::new(surface))
+                },
+                RawWindowHandle::MacOS(h) => {
+                    let ns_view = NonNull::new(h.ns_view).ok_or(Unavailable)?;
+                    rwh::RawWindowHandle::AppKit(rwh::AppKitWindowHandle::new(ns_view))
+                },
+                RawWindowHandle::IOS(h) => {
+                    let ui_view = NonNull::new(h.ui_view).ok_or(Unavailable)?;
+                    let mut handle = rwh::UiKitWindowHandle::new(ui_view);
+                    handle.ui_view_controller = NonNull::new(h.ui_view_controller);
+                    rwh::RawWindowHandle::UiKit(handle)
+                },
+                RawWindowHandle::Web(h) => rwh::RawWindowHandle::Web(rwh::WebWindowHandle::new(h.id)),
+                RawWindowHandle::Android(h) => {
+                    let a_native_window = NonNull::new(h.a_native_window).ok_or(Unavailable)?;
+                    rwh::RawWindowHandle::AndroidNdk(rwh::AndroidNdkWindowHandle::new(a_native_window))
+                },
+                RawWindowHandle::Unsupported => return Err(NotSupported),
+            };
+
+            // the handle is valid until the window is closed
+            Ok(unsafe { rwh::WindowHandle::borrow_raw(raw) })
+        }
+    }
+
+    #[cfg(feature = "raw-window-handle")]
+    impl raw_window_handle::HasDisplayHandle for RawWindowHandle {
+        fn display_handle(&self) -> Result<raw_window_handle::DisplayHandle<'_>, raw_window_handle::HandleError> {
+
+            use core::ptr::NonNull;
+            use raw_window_handle as rwh;
+            use raw_window_handle::HandleError::{NotSupported, Unavailable};
+
+            let raw = match self {
+                RawWindowHandle::Windows(_) => rwh::RawDisplayHandle::Windows(rwh::WindowsDisplayHandle::new()),
+                RawWindowHandle::Xlib(h) => rwh::RawDisplayHandle::Xlib(rwh::XlibDisplayHandle::new(NonNull::new(h.display), 0)),
+                RawWindowHandle::Xcb(h) => rwh::RawDisplayHandle::Xcb(rwh::XcbDisplayHandle::new(NonNull::new(h.connection), 0)),
+                RawWindowHandle::Wayland(h) => {
+                    let display = NonNull::new(h.display).ok_or(Unavailable)?;
+                    rwh::RawDisplayHandle::Wayland(rwh::WaylandDisplayHandle::new(display))
+                },
+                RawWindowHandle::MacOS(_) => rwh::RawDisplayHandle::AppKit(rwh::AppKitDisplayHandle::new()),
+                RawWindowHandle::IOS(_) => rwh::RawDisplayHandle::UiKit(rwh::UiKitDisplayHandle::new()),
+                RawWindowHandle::Web(_) => rwh::RawDisplayHandle::Web(rwh::WebDisplayHandle::new()),
+                RawWindowHandle::Android(_) => rwh::RawDisplayHandle::Android(rwh::AndroidDisplayHandle::new()),
+                RawWindowHandle::Unsupported => return Err(NotSupported),
+            };
+
+            // the display connection outlives all windows of the application
+            Ok(unsafe { rwh::DisplayHandle::borrow_raw(raw) })
+        }
+    }
+    use crate::callbacks::{LayoutCallbackType, RefAny};
+    use crate::time::Duration;
     /// Options on how to initially create the window
     
     #[doc(inline)] pub use crate::dll::AzWindowCreateOptions as WindowCreateOptions;
@@ -12387,8 +14306,38 @@ pub mod window {
 
         /// Creates a new window configuration with a custom layout callback
         pub fn new(layout_callback: LayoutCallbackType) -> Self { unsafe { crate::dll::AzWindowCreateOptions_new(layout_callback) } }
+        /// Embeds the window as a child window inside of a foreign window (Win32 HWND, X11 window or NSView)
+        pub fn with_parent_handle<_1: Into<RawWindowHandle>>(self, parent_handle: _1)  -> crate::window::WindowCreateOptions { unsafe { crate::dll::AzWindowCreateOptions_withParentHandle(self, parent_handle.into()) } }
+        /// Attaches window-local data to the window, see `WindowCreateOptions::window_data`
+        pub fn with_window_data<_1: Into<RefAny>>(self, window_data: _1)  -> crate::window::WindowCreateOptions { unsafe { crate::dll::AzWindowCreateOptions_withWindowData(self, window_data.into()) } }
+        /// Sets the translucent backdrop of the window, see `WindowCreateOptions::background_effect`
+        pub fn with_background_effect<_1: Into<WindowBackgroundEffect>>(self, background_effect: _1)  -> crate::window::WindowCreateOptions { unsafe { crate::dll::AzWindowCreateOptions_withBackgroundEffect(self, background_effect.into()) } }
+        /// Fades the window in when it is shown, see `WindowCreateOptions::fade_in`
+        pub fn with_fade_in<_1: Into<Duration>>(self, duration: _1)  -> crate::window::WindowCreateOptions { unsafe { crate::dll::AzWindowCreateOptions_withFadeIn(self, duration.into()) } }
+        /// Sets what the window shows before the first frame, see `WindowCreateOptions::first_frame`
+        pub fn with_first_frame<_1: Into<FirstFrameMode>>(self, first_frame: _1)  -> crate::window::WindowCreateOptions { unsafe { crate::dll::AzWindowCreateOptions_withFirstFrame(self, first_frame.into()) } }
+        /// Configures the built-in scrollbars, see `WindowCreateOptions::scrollbars`
+        pub fn with_scrollbars<_1: Into<ScrollbarConfig>>(self, scrollbars: _1)  -> crate::window::WindowCreateOptions { unsafe { crate::dll::AzWindowCreateOptions_withScrollbars(self, scrollbars.into()) } }
+    }
+
+    /// Translucent backdrop of a window, see `WindowCreateOptions::background_effect`
+    
+    #[doc(inline)] pub use crate::dll::AzWindowBackgroundEffect as WindowBackgroundEffect;
+    /// What a window shows between being mapped and presenting the first rendered frame, see `WindowCreateOptions::first_frame`
+    
+    #[doc(inline)] pub use crate::dll::AzFirstFrameMode as FirstFrameMode;
+    /// Behavior of the built-in scrollbars of a window, see `WindowCreateOptions::scrollbars`
+    
+    #[doc(inline)] pub use crate::dll::AzScrollbarConfig as ScrollbarConfig;
+    impl ScrollbarConfig {
+
+        /// Creates the default scrollbar configuration (always visible, 20px minimum thumb length)
+        pub fn default() -> Self { unsafe { crate::dll::AzScrollbarConfig_default() } }
     }
 
+    /// How the built-in scrollbars are drawn, see `ScrollbarConfig::mode`
+    
+    #[doc(inline)] pub use crate::dll::AzScrollbarMode as ScrollbarMode;
     /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
     
     #[doc(inline)] pub use crate::dll::AzRendererOptions as RendererOptions;
@@ -12495,16 +14444,27 @@ pub mod window {
     #[doc(inline)] pub use crate::dll::AzWindowSize as WindowSize;
     impl WindowSize {
 
-        /// Returns the hidpi factor of the bounds
+        /// Returns the hidpi factor of the bounds (DPI scale factor multiplied with the zoom factor)
         pub fn get_hidpi_factor(&self)  -> f32 { unsafe { crate::dll::AzWindowSize_getHidpiFactor(self) } }
+        /// Sets the UI zoom factor (i.e. for a ctrl + scroll zoom) and adjusts the logical `dimensions`, so that the physical size of the window stays the same. Set the modified `WindowState` via `CallbackInfo::set_window_state()`
+        pub fn set_zoom(&mut self, zoom: f32)  { unsafe { crate::dll::AzWindowSize_setZoom(self, zoom) } }
     }
 
     /// Boolean flags relating to the current window state
     
     #[doc(inline)] pub use crate::dll::AzWindowFlags as WindowFlags;
+    /// How urgently a screen reader should speak an announcement, see `CallbackInfo::announce`
+    
+    #[doc(inline)] pub use crate::dll::AzPoliteness as Politeness;
+    /// Kind of tactile feedback, see `CallbackInfo::trigger_haptic`
+    
+    #[doc(inline)] pub use crate::dll::AzHapticKind as HapticKind;
     /// State of the window frame (minimized, maximized, fullscreen or normal window)
     
     #[doc(inline)] pub use crate::dll::AzWindowFrame as WindowFrame;
+    /// How a window covers the monitor in fullscreen mode - the `usize` is the `Monitor::id` of the target monitor (the current monitor of the window if no monitor with that id exists)
+    
+    #[doc(inline)] pub use crate::dll::AzFullscreenMode as FullscreenMode;
     /// Debugging information, will be rendered as an overlay on top of the UI
     
     #[doc(inline)] pub use crate::dll::AzDebugState as DebugState;
@@ -12521,10 +14481,33 @@ pub mod window {
         pub fn alt_down(&self)  -> bool { unsafe { crate::dll::AzKeyboardState_altDown(self) } }
         /// Returns if the `SUPER` ("Windows") key is held down
         pub fn super_down(&self)  -> bool { unsafe { crate::dll::AzKeyboardState_superDown(self) } }
+        /// Returns if the left `SHIFT` key is held down
+        pub fn left_shift_down(&self)  -> bool { unsafe { crate::dll::AzKeyboardState_leftShiftDown(self) } }
+        /// Returns if the right `SHIFT` key is held down
+        pub fn right_shift_down(&self)  -> bool { unsafe { crate::dll::AzKeyboardState_rightShiftDown(self) } }
+        /// Returns if the left `CTRL` key is held down
+        pub fn left_ctrl_down(&self)  -> bool { unsafe { crate::dll::AzKeyboardState_leftCtrlDown(self) } }
+        /// Returns if the right `CTRL` key is held down
+        pub fn right_ctrl_down(&self)  -> bool { unsafe { crate::dll::AzKeyboardState_rightCtrlDown(self) } }
+        /// Returns if the left `ALT` key is held down
+        pub fn left_alt_down(&self)  -> bool { unsafe { crate::dll::AzKeyboardState_leftAltDown(self) } }
+        /// Returns if the right `ALT` key (`AltGr` on layouts that have one) is held down
+        pub fn right_alt_down(&self)  -> bool { unsafe { crate::dll::AzKeyboardState_rightAltDown(self) } }
+        /// Returns if the left `SUPER` ("Windows") key is held down
+        pub fn left_super_down(&self)  -> bool { unsafe { crate::dll::AzKeyboardState_leftSuperDown(self) } }
+        /// Returns if the right `SUPER` ("Windows") key is held down
+        pub fn right_super_down(&self)  -> bool { unsafe { crate::dll::AzKeyboardState_rightSuperDown(self) } }
         /// Returns if a key is held down
         pub fn is_key_down<_1: Into<VirtualKeyCode>>(&self, key: _1)  -> bool { unsafe { crate::dll::AzKeyboardState_isKeyDown(self, key.into()) } }
+        /// Returns if the physical key is held down, independent of the keyboard layout
+        pub fn is_scancode_down(&self, scancode: u32)  -> bool { unsafe { crate::dll::AzKeyboardState_isScancodeDown(self, scancode) } }
+        /// Returns the currently held down modifiers and the CapsLock state, i.e. to pass them to `CallbackInfo::keycode_to_char`
+        pub fn get_modifiers(&self)  -> crate::window::KeyboardModifiers { unsafe { crate::dll::AzKeyboardState_getModifiers(self) } }
     }
 
+    /// Modifier keys that influence which character a key produces, see `CallbackInfo::keycode_to_char`. `ctrl + alt` is treated as AltGr.
+    
+    #[doc(inline)] pub use crate::dll::AzKeyboardModifiers as KeyboardModifiers;
     /// Current icon of the mouse cursor
     
     #[doc(inline)] pub use crate::dll::AzMouseCursorType as MouseCursorType;
@@ -12579,9 +14562,18 @@ pub mod window {
     /// Current state of touch devices / touch inputs
     
     #[doc(inline)] pub use crate::dll::AzTouchState as TouchState;
+    /// Device that the last pointer input of the window came from
+    
+    #[doc(inline)] pub use crate::dll::AzInputModality as InputModality;
+    /// Single finger / stylus that is currently touching the screen
+    
+    #[doc(inline)] pub use crate::dll::AzTouchPoint as TouchPoint;
     /// Information about a single (or many) monitors, useful for dock widgets
     
     #[doc(inline)] pub use crate::dll::AzMonitor as Monitor;
+    /// HDR / wide color gamut capabilities of a monitor
+    
+    #[doc(inline)] pub use crate::dll::AzHdrCapabilities as HdrCapabilities;
     /// Describes a rendering configuration for a monitor
     
     #[doc(inline)] pub use crate::dll::AzVideoMode as VideoMode;
@@ -12594,6 +14586,8 @@ pub mod window {
         pub fn new(layout_callback: LayoutCallbackType) -> Self { unsafe { crate::dll::AzWindowState_new(layout_callback) } }
         /// Creates a default WindowState with an empty layout callback - useful only if you use the Rust `WindowState { .. WindowState::default() }` intialization syntax.
         pub fn default() -> Self { unsafe { crate::dll::AzWindowState_default() } }
+        /// Puts the window into fullscreen mode on the given monitor. Set `flags.frame` back to `WindowFrame::Normal` to leave fullscreen mode.
+        pub fn set_fullscreen<_1: Into<FullscreenMode>>(&mut self, mode: _1)  { unsafe { crate::dll::AzWindowState_setFullscreen(self, mode.into()) } }
     }
 
 }
@@ -12741,12 +14735,23 @@ pub mod callbacks {
         }
     }    use crate::str::String;
     use crate::css::{CssProperty, CssPropertyType};
-    use crate::window::{LogicalPosition, WindowCreateOptions, WindowState};
+    use crate::window::{HapticKind, KeyboardModifiers, LogicalPosition, LogicalSize, Politeness, VirtualKeyCode, WindowCreateOptions, WindowState};
+    use crate::dom::NodeStateFlag;
+    use crate::vec::TextUnderlineVec;
     use crate::image::{ImageMask, ImageRef};
     use crate::task::{ThreadId, ThreadSendMsg, Timer, TimerId};
+    use crate::time::Duration;
+    use crate::app::AppLogLevel;
+    use crate::menu::{Shortcut, VirtualKeyCodeCombo};
     /// `LayoutCallback` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutCallback as LayoutCallback;
+    impl LayoutCallback {
+
+        /// Development mode: loads the layout function `symbol` from the dynamic library at `library_path` and reloads the library whenever it changes. The app data is kept across reloads. Use together with `WindowCreateOptions::hot_reload`
+        pub fn hot_reload<_1: Into<String>, _2: Into<String>>(library_path: _1, symbol: _2) -> Self { unsafe { crate::dll::AzLayoutCallback_hotReload(library_path.into(), symbol.into()) } }
+    }
+
     /// C-ABI stable wrapper over a `MarshaledLayoutCallback`
     
     #[doc(inline)] pub use crate::dll::AzMarshaledLayoutCallback as MarshaledLayoutCallback;
@@ -12783,6 +14788,12 @@ pub mod callbacks {
         pub fn get_cursor_relative_to_node(&self)  -> crate::option::OptionLogicalPosition { unsafe { crate::dll::AzCallbackInfo_getCursorRelativeToNode(self) } }
         /// Returns a copy of the current windows `WindowState`.
         pub fn get_current_window_state(&self)  -> crate::window::WindowState { unsafe { crate::dll::AzCallbackInfo_getCurrentWindowState(self) } }
+        /// Returns the data of the `EventLoopProxy::send_user_event()` call that triggered the current `On::UserEvent` callback
+        pub fn get_user_event(&self)  -> crate::option::OptionRefAny { unsafe { crate::dll::AzCallbackInfo_getUserEvent(self) } }
+        /// Returns the window-local data of the current window (see `WindowCreateOptions::window_data`)
+        pub fn get_window_data(&self)  -> crate::option::OptionRefAny { unsafe { crate::dll::AzCallbackInfo_getWindowData(self) } }
+        /// Replaces the window-local data of the current window
+        pub fn set_window_data<_1: Into<RefAny>>(&mut self, data: _1)  { unsafe { crate::dll::AzCallbackInfo_setWindowData(self, data.into()) } }
         /// Returns a copy of the internal `KeyboardState`. Same as `self.get_window_state().keyboard_state`
         pub fn get_current_keyboard_state(&self)  -> crate::window::KeyboardState { unsafe { crate::dll::AzCallbackInfo_getCurrentKeyboardState(self) } }
         /// Returns a copy of the internal `MouseState`. Same as `self.get_window_state().mouse_state`
@@ -12837,10 +14848,20 @@ pub mod callbacks {
         pub fn set_focus<_1: Into<FocusTarget>>(&mut self, target: _1)  { unsafe { crate::dll::AzCallbackInfo_setFocus(self, target.into()) } }
         /// Sets a `CssProperty` on a given node to its new value. If this property change affects the layout, this will automatically trigger a relayout and redraw of the screen.
         pub fn set_css_property<_1: Into<DomNodeId>, _2: Into<CssProperty>>(&mut self, node_id: _1, new_property: _2)  { unsafe { crate::dll::AzCallbackInfo_setCssProperty(self, node_id.into(), new_property.into()) } }
+        /// Adds a class to the node. After the callback returns, only the node and its children are restyled, the DOM does not have to be regenerated.
+        pub fn add_class<_1: Into<DomNodeId>, _2: Into<String>>(&mut self, node_id: _1, class: _2)  { unsafe { crate::dll::AzCallbackInfo_addClass(self, node_id.into(), class.into()) } }
+        /// Removes a class from the node, see `add_class`
+        pub fn remove_class<_1: Into<DomNodeId>, _2: Into<String>>(&mut self, node_id: _1, class: _2)  { unsafe { crate::dll::AzCallbackInfo_removeClass(self, node_id.into(), class.into()) } }
+        /// Adds the class to the node if it is not present, removes it otherwise
+        pub fn toggle_class<_1: Into<DomNodeId>, _2: Into<String>>(&mut self, node_id: _1, class: _2)  { unsafe { crate::dll::AzCallbackInfo_toggleClass(self, node_id.into(), class.into()) } }
+        /// Sets or clears the `:disabled`, `:checked`, `:selected` or `:indeterminate` state of the node, the node is restyled after the callback returns
+        pub fn set_node_state_flag<_1: Into<DomNodeId>, _2: Into<NodeStateFlag>>(&mut self, node_id: _1, flag: _2, value: bool)  { unsafe { crate::dll::AzCallbackInfo_setNodeStateFlag(self, node_id.into(), flag.into(), value) } }
         /// Sets the scroll position of the node
         pub fn set_scroll_position<_1: Into<DomNodeId>, _2: Into<LogicalPosition>>(&mut self, node_id: _1, scroll_position: _2)  { unsafe { crate::dll::AzCallbackInfo_setScrollPosition(self, node_id.into(), scroll_position.into()) } }
         /// If the node is a `Text` node, overwrites the `Text` content with the new string, without requiring the entire UI to be rebuilt.
         pub fn set_string_contents<_1: Into<DomNodeId>, _2: Into<String>>(&mut self, node_id: _1, string: _2)  { unsafe { crate::dll::AzCallbackInfo_setStringContents(self, node_id.into(), string.into()) } }
+        /// Underlines character ranges of a `Text` node (i.e. spelling errors) without requiring the entire UI to be rebuilt. The underlines replace the previous underlines of the node and are removed when the text of the node changes.
+        pub fn set_text_underlines<_1: Into<DomNodeId>, _2: Into<TextUnderlineVec>>(&mut self, node_id: _1, underlines: _2)  { unsafe { crate::dll::AzCallbackInfo_setTextUnderlines(self, node_id.into(), underlines.into()) } }
         /// Adds a new image identified by an ID to the image cache
         pub fn add_image<_1: Into<String>, _2: Into<ImageRef>>(&mut self, id: _1, image: _2)  { unsafe { crate::dll::AzCallbackInfo_addImage(self, id.into(), image.into()) } }
         /// Returns whether an image with a given CSS ID already exists
@@ -12861,6 +14882,10 @@ pub mod callbacks {
         pub fn start_timer<_1: Into<Timer>>(&mut self, timer: _1)  -> crate::task::TimerId { unsafe { crate::dll::AzCallbackInfo_startTimer(self, timer.into()) } }
         /// Starts an animation timer on a give NodeId - same as a `Timer`, but uses a pre-configured interpolation function to drive the animation timer
         pub fn start_animation<_1: Into<DomNodeId>, _2: Into<Animation>>(&mut self, node: _1, animation: _2)  -> crate::option::OptionTimerId { unsafe { crate::dll::AzCallbackInfo_startAnimation(self, node.into(), animation.into()) } }
+        /// Shows the text caret and starts blinking / fading it every `-azul-caret-animation-duration`, restart the animation on every key press to not blink while typing. Returns `None` if the duration is `0`
+        pub fn start_caret_animation<_1: Into<DomNodeId>, _2: Into<CaretAnimation>>(&mut self, caret_node: _1, animation: _2)  -> crate::option::OptionTimerId { unsafe { crate::dll::AzCallbackInfo_startCaretAnimation(self, caret_node.into(), animation.into()) } }
+        /// Resizes the window to the `target_size` over the `duration` (ease-in-out), re-layouting the UI at every intermediate size. Stop the returned timer to cancel the animation
+        pub fn animate_window_resize<_1: Into<LogicalSize>, _2: Into<Duration>>(&mut self, target_size: _1, duration: _2)  -> crate::task::TimerId { unsafe { crate::dll::AzCallbackInfo_animateWindowResize(self, target_size.into(), duration.into()) } }
         /// Stops / cancels a `Timer`. See the documentation for `Timer` for more information.
         pub fn stop_timer<_1: Into<TimerId>>(&mut self, timer_id: _1)  -> bool { unsafe { crate::dll::AzCallbackInfo_stopTimer(self, timer_id.into()) } }
         /// Starts a new `Thread` to the runtime. See the documentation for `Thread` for more information.
@@ -12869,6 +14894,36 @@ pub mod callbacks {
         pub fn send_thread_msg<_1: Into<ThreadId>, _2: Into<ThreadSendMsg>>(&mut self, thread_id: _1, msg: _2)  -> bool { unsafe { crate::dll::AzCallbackInfo_sendThreadMsg(self, thread_id.into(), msg.into()) } }
         /// Stops a thread at the nearest possible opportunity. Sends a `ThreadSendMsg::TerminateThread` message to the thread and joins the thread.
         pub fn stop_thread<_1: Into<ThreadId>>(&mut self, thread_id: _1)  -> bool { unsafe { crate::dll::AzCallbackInfo_stopThread(self, thread_id.into()) } }
+        /// Returns how long the user has not interacted with the system, `None` if the platform can't report the idle time
+        pub fn get_system_idle_time(&self)  -> crate::option::OptionDuration { unsafe { crate::dll::AzCallbackInfo_getSystemIdleTime(self) } }
+        /// Returns the character that the key produces in the current keyboard layout (i.e. to display shortcuts in menus), `None` if the key doesn't produce a character or the platform can't map it
+        pub fn keycode_to_char<_1: Into<VirtualKeyCode>, _2: Into<KeyboardModifiers>>(&self, virtual_keycode: _1, modifiers: _2)  -> crate::option::OptionChar { unsafe { crate::dll::AzCallbackInfo_keycodeToChar(self, virtual_keycode.into(), modifiers.into()) } }
+        /// Returns the most recent log lines (oldest first), i.e. to display them in a diagnostics panel. Empty if logging is disabled.
+        pub fn get_log_lines(&self)  -> crate::vec::StringVec { unsafe { crate::dll::AzCallbackInfo_getLogLines(self) } }
+        /// Sets the log level of a subsystem (`azul::layout`, `azul::restyle` or `azul::render`) at runtime - an empty string sets the global log level
+        pub fn set_log_level<_1: Into<String>, _2: Into<AppLogLevel>>(&self, subsystem: _1, level: _2)  { unsafe { crate::dll::AzCallbackInfo_setLogLevel(self, subsystem.into(), level.into()) } }
+        /// Returns the margin / border / padding / content boxes of the node after the last layout pass, relative to the window origin. Useful for positioning popups or drag handles relative to a node.
+        pub fn get_node_rect<_1: Into<DomNodeId>>(&self, node_id: _1)  -> crate::option::OptionNodeRect { unsafe { crate::dll::AzCallbackInfo_getNodeRect(self, node_id.into()) } }
+        /// Same as `get_inline_text()`, but also returns the content box that the text lines are positioned in
+        pub fn get_text_layout<_1: Into<DomNodeId>>(&self, node_id: _1)  -> crate::option::OptionTextLayout { unsafe { crate::dll::AzCallbackInfo_getTextLayout(self, node_id.into()) } }
+        /// Returns the viewport, content size, current and maximum scroll offset of the node or `None` if the node does not overflow its parent
+        pub fn get_scroll_info<_1: Into<DomNodeId>>(&self, node_id: _1)  -> crate::option::OptionScrollInfo { unsafe { crate::dll::AzCallbackInfo_getScrollInfo(self, node_id.into()) } }
+        /// Adds a window-level keyboard shortcut. If the key combination is already used by another shortcut, the shortcut is not added and the conflict is returned
+        pub fn add_shortcut<_1: Into<Shortcut>>(&mut self, shortcut: _1)  -> crate::option::OptionShortcutConflict { unsafe { crate::dll::AzCallbackInfo_addShortcut(self, shortcut.into()) } }
+        /// Removes the window-level shortcut with the given key combination, returns whether a shortcut was removed
+        pub fn remove_shortcut<_1: Into<VirtualKeyCodeCombo>>(&mut self, keys: _1)  -> bool { unsafe { crate::dll::AzCallbackInfo_removeShortcut(self, keys.into()) } }
+        /// Returns all window-level shortcuts that share the same key combination
+        pub fn get_shortcut_conflicts(&self)  -> crate::vec::ShortcutConflictVec { unsafe { crate::dll::AzCallbackInfo_getShortcutConflicts(self) } }
+        /// Asks the screen reader to speak the `text` (i.e. "3 results found"), without having to move the focus. Currently only implemented on Windows (UI Automation notifications)
+        pub fn announce<_1: Into<String>, _2: Into<Politeness>>(&mut self, text: _1, politeness: _2)  { unsafe { crate::dll::AzCallbackInfo_announce(self, text.into(), politeness.into()) } }
+        /// Plays a short tactile feedback on devices that support it. Currently only implemented for haptic pens on Windows 11 (requires the `haptics` feature), ignored elsewhere
+        pub fn trigger_haptic<_1: Into<HapticKind>>(&mut self, kind: _1)  { unsafe { crate::dll::AzCallbackInfo_triggerHaptic(self, kind.into()) } }
+        /// Runs the `callback` once, right before the next frame is rendered (synchronized to the vertical blank where supported). `TimerCallbackInfo::frame_start` is the same for all callbacks of one frame. Call this function again from the callback to run it on the next frame, too.
+        pub fn request_animation_frame<_1: Into<RefAny>>(&mut self, data: _1, callback: TimerCallbackType)  { unsafe { crate::dll::AzCallbackInfo_requestAnimationFrame(self, data.into(), callback) } }
+        /// Cross-fades the subtree of the `node` to its new content after the next DOM update: the current content is kept as a (non-interactive) snapshot and faded out over the new content during the `duration`. Only subtrees of the root DOM can be cross-faded. Currently only implemented on Windows
+        pub fn crossfade_subtree<_1: Into<DomNodeId>, _2: Into<Duration>>(&mut self, node: _1, duration: _2)  { unsafe { crate::dll::AzCallbackInfo_crossfadeSubtree(self, node.into(), duration.into()) } }
+        /// Records the subtree of the `node` (including its running animations) for the given `duration` at up to `fps` frames per second and writes it as an animated GIF to the `output_path` (requires the `gif` feature), i.e. to generate animated screenshots of widgets for documentation. The frames are taken from the rendered window, so the node has to be visible. Only nodes of the root DOM can be recorded. Currently only implemented on Windows
+        pub fn render_subtree_to_frames<_1: Into<DomNodeId>, _2: Into<Duration>, _4: Into<String>>(&mut self, node: _1, duration: _2, fps: u32, output_path: _4)  { unsafe { crate::dll::AzCallbackInfo_renderSubtreeToFrames(self, node.into(), duration.into(), fps, output_path.into()) } }
     }
 
     /// Which type of image should be updated: background image (the CSS background) or content image (the <img src=""> content)
@@ -12899,6 +14954,12 @@ pub mod callbacks {
         pub fn get_relative_offset(&self)  -> crate::window::LogicalPosition { unsafe { crate::dll::AzPositionInfo_getRelativeOffset(self) } }
     }
 
+    /// Post-layout geometry of a node, all rects are relative to the window origin
+    
+    #[doc(inline)] pub use crate::dll::AzNodeRect as NodeRect;
+    /// Scroll extents of a scrollable (overflowing) node
+    
+    #[doc(inline)] pub use crate::dll::AzScrollInfo as ScrollInfo;
     /// `PositionInfoInner` struct
     
     #[doc(inline)] pub use crate::dll::AzPositionInfoInner as PositionInfoInner;
@@ -12924,6 +14985,9 @@ pub mod callbacks {
         pub fn hit_test<_1: Into<LogicalPosition>>(&self, position: _1)  -> crate::vec::InlineTextHitVec { unsafe { crate::dll::AzInlineText_hitTest(self, position.into()) } }
     }
 
+    /// Laid out text of a `Text` node, together with the rect that the text lines are positioned in
+    
+    #[doc(inline)] pub use crate::dll::AzTextLayout as TextLayout;
     /// `InlineLine` struct
     
     #[doc(inline)] pub use crate::dll::AzInlineLine as InlineLine;
@@ -12936,6 +15000,12 @@ pub mod callbacks {
     /// `InlineGlyph` struct
     
     #[doc(inline)] pub use crate::dll::AzInlineGlyph as InlineGlyph;
+    /// Underlines a range of characters of a text node, see `CallbackInfo::set_text_underlines`
+    
+    #[doc(inline)] pub use crate::dll::AzTextUnderline as TextUnderline;
+    /// Line style of a `TextUnderline`
+    
+    #[doc(inline)] pub use crate::dll::AzTextUnderlineStyle as TextUnderlineStyle;
     /// `InlineTextHit` struct
     
     #[doc(inline)] pub use crate::dll::AzInlineTextHit as InlineTextHit;
@@ -12957,6 +15027,9 @@ pub mod callbacks {
     /// Animation struct to start a new animation
     
     #[doc(inline)] pub use crate::dll::AzAnimation as Animation;
+    /// How the text caret of an editable node is animated
+    
+    #[doc(inline)] pub use crate::dll::AzCaretAnimation as CaretAnimation;
     /// How should an animation repeat (loop, ping-pong, etc.)
     
     #[doc(inline)] pub use crate::dll::AzAnimationRepeat as AnimationRepeat;
@@ -12975,6 +15048,39 @@ pub mod callbacks {
     /// `IFrameCallbackInfo` struct
     
     #[doc(inline)] pub use crate::dll::AzIFrameCallbackInfo as IFrameCallbackInfo;
+    /// C-ABI wrapper over a `MeasureCallbackType`
+    
+    #[doc(inline)] pub use crate::dll::AzMeasureCallback as MeasureCallback;
+    /// Returns the desired size of a `NodeType::CustomLayout` node, given the space available to it. Invoked twice per layout: once before the widths are solved and once with the final width of the node.
+    
+    #[doc(inline)] pub use crate::dll::AzMeasureCallbackType as MeasureCallbackType;
+    /// `MeasureCallbackInfo` struct
+    
+    #[doc(inline)] pub use crate::dll::AzMeasureCallbackInfo as MeasureCallbackInfo;
+    /// C-ABI wrapper over an `ArrangeCallbackType`
+    
+    #[doc(inline)] pub use crate::dll::AzArrangeCallback as ArrangeCallback;
+    /// Invoked after the layout with the final position and size of a `NodeType::CustomLayout` node, so that externally rendered content can be placed at the correct location
+    
+    #[doc(inline)] pub use crate::dll::AzArrangeCallbackType as ArrangeCallbackType;
+    /// `ArrangeCallbackInfo` struct
+    
+    #[doc(inline)] pub use crate::dll::AzArrangeCallbackInfo as ArrangeCallbackInfo;
+    /// C-ABI wrapper over a `PaintCallbackType`
+    
+    #[doc(inline)] pub use crate::dll::AzPaintCallback as PaintCallback;
+    /// Returns the primitives to draw into the content box of a `NodeType::CustomLayout` node, invoked when the display list is built
+    
+    #[doc(inline)] pub use crate::dll::AzPaintCallbackType as PaintCallbackType;
+    /// `PaintCallbackInfo` struct
+    
+    #[doc(inline)] pub use crate::dll::AzPaintCallbackInfo as PaintCallbackInfo;
+    /// Primitive drawn by a `PaintCallback`
+    
+    #[doc(inline)] pub use crate::dll::AzPaintPrimitive as PaintPrimitive;
+    /// Filled (rounded) rectangle
+    
+    #[doc(inline)] pub use crate::dll::AzPaintRect as PaintRect;
     /// <img src="../images/scrollbounds.png"/>
     
     #[doc(inline)] pub use crate::dll::AzIFrameCallbackReturn as IFrameCallbackReturn;
@@ -13085,6 +15191,8 @@ pub mod callbacks {
         pub fn get_system_fonts(&self)  -> crate::vec::StringPairVec { unsafe { crate::dll::AzLayoutCallbackInfo_getSystemFonts(self) } }
         /// Returns an `ImageRef` referenced by a CSS ID
         pub fn get_image<_1: Into<String>>(&self, id: _1)  -> crate::option::OptionImageRef { unsafe { crate::dll::AzLayoutCallbackInfo_getImage(self, id.into()) } }
+        /// Returns whether high contrast mode is enabled, whether the user prefers reduced motion and the current system colors
+        pub fn get_system_style(&self)  -> crate::css::SystemStyle { unsafe { crate::dll::AzLayoutCallbackInfo_getSystemStyle(self) } }
     }
 
 }
@@ -13223,7 +15331,7 @@ pub mod dom {
         }
     }    use crate::str::String;
     use crate::image::{ImageMask, ImageRef};
-    use crate::callbacks::{CallbackType, IFrameCallbackType, RefAny};
+    use crate::callbacks::{ArrangeCallbackType, CallbackType, IFrameCallbackType, MeasureCallbackType, PaintCallbackType, RefAny};
     use crate::vec::{CallbackDataVec, DomVec, IdOrClassVec, NodeDataInlineCssPropertyVec};
     use crate::css::{Css, CssProperty};
     use crate::menu::Menu;
@@ -13246,6 +15354,14 @@ pub mod dom {
         pub fn image<_1: Into<ImageRef>>(image: _1) -> Self { unsafe { crate::dll::AzDom_image(image.into()) } }
         /// Creates a new `Dom` instance.
         pub fn iframe<_1: Into<RefAny>>(data: _1, callback: IFrameCallbackType) -> Self { unsafe { crate::dll::AzDom_iframe(data.into(), callback) } }
+        /// Creates a new `Dom` instance.
+        pub fn custom_layout<_1: Into<RefAny>>(data: _1, measure: MeasureCallbackType, arrange: ArrangeCallbackType) -> Self { unsafe { crate::dll::AzDom_customLayout(data.into(), measure, arrange) } }
+        /// Creates a node of a type that was registered with `CustomNodeType::register`, returns `None` if no type with the name `type_name` is registered
+        pub fn custom_node<_1: Into<String>, _2: Into<RefAny>>(type_name: _1, data: _2) ->  crate::option::OptionDom { unsafe { crate::dll::AzDom_customNode(type_name.into(), data.into()) } }
+        /// Same as `iframe()`, but only re-invokes the callback if the position, width or height of the iframe changes by more than `reinvoke_threshold` logical pixels
+        pub fn iframe_with_reinvoke_threshold<_1: Into<RefAny>>(data: _1, callback: IFrameCallbackType, reinvoke_threshold: f32) -> Self { unsafe { crate::dll::AzDom_iframeWithReinvokeThreshold(data.into(), callback, reinvoke_threshold) } }
+        /// Creates a container whose children are rendered in a top-level layer above all other content, positioned next to the node with the ID `anchor` (dropdowns, comboboxes). The overlay is flipped / shifted so that it stays inside the window
+        pub fn overlay<_1: Into<String>, _2: Into<OverlayPlacement>>(anchor: _1, placement: _2) -> Self { unsafe { crate::dll::AzDom_overlay(anchor.into(), placement.into()) } }
         /// Calls the `Dom::set_node_type` function.
         pub fn set_node_type<_1: Into<NodeType>>(&mut self, node_type: _1)  { unsafe { crate::dll::AzDom_setNodeType(self, node_type.into()) } }
         /// Calls the `Dom::with_node_type` function.
@@ -13338,6 +15454,22 @@ pub mod dom {
         pub fn set_context_menu<_1: Into<Menu>>(&mut self, context_menu: _1)  { unsafe { crate::dll::AzDom_setContextMenu(self, context_menu.into()) } }
         /// Same as set_context_menu, but as a builder method
         pub fn with_context_menu<_1: Into<Menu>>(&mut self, context_menu: _1)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withContextMenu(self, context_menu.into()) } }
+        /// Overrides the arrow key focus navigation for the DOM root node. See `NodeData::set_focus_navigation` for more information.
+        pub fn set_focus_navigation<_1: Into<FocusNavigation>>(&mut self, focus_navigation: _1)  { unsafe { crate::dll::AzDom_setFocusNavigation(self, focus_navigation.into()) } }
+        /// Same as set_focus_navigation, but as a builder method
+        pub fn with_focus_navigation<_1: Into<FocusNavigation>>(&mut self, focus_navigation: _1)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withFocusNavigation(self, focus_navigation.into()) } }
+        /// Sets the language of the text in the DOM root node and its children (BCP 47 tag, i.e. `"de"` or `"en-US"`), used for hyphenation (`hyphens: auto`) and line breaking
+        pub fn set_lang<_1: Into<String>>(&mut self, lang: _1)  { unsafe { crate::dll::AzDom_setLang(self, lang.into()) } }
+        /// Same as set_lang, but as a builder method
+        pub fn with_lang<_1: Into<String>>(&mut self, lang: _1)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withLang(self, lang.into()) } }
+        /// Sets the kind of text that is entered into the DOM root node (same as the HTML `inputmode` attribute), forwarded to the IME / touch keyboard when the node is focused
+        pub fn set_input_mode<_1: Into<InputMode>>(&mut self, input_mode: _1)  { unsafe { crate::dll::AzDom_setInputMode(self, input_mode.into()) } }
+        /// Same as set_input_mode, but as a builder method
+        pub fn with_input_mode<_1: Into<InputMode>>(&mut self, input_mode: _1)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withInputMode(self, input_mode.into()) } }
+        /// Sets the initial `:disabled`, `:checked`, `:selected` or `:indeterminate` state of the DOM root node
+        pub fn set_state_flag<_1: Into<NodeStateFlag>>(&mut self, flag: _1, enabled: bool)  { unsafe { crate::dll::AzDom_setStateFlag(self, flag.into(), enabled) } }
+        /// Same as set_state_flag, but as a builder method
+        pub fn with_state_flag<_1: Into<NodeStateFlag>>(&mut self, flag: _1, enabled: bool)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withStateFlag(self, flag.into(), enabled) } }
         /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
         pub fn hash(&self)  -> u64 { unsafe { crate::dll::AzDom_hash(self) } }
         /// Returns the number of nodes in the DOM, including all child DOM trees. Result is equal to `self.total_children + 1` (count of all child trees + the root node)
@@ -13353,6 +15485,22 @@ pub mod dom {
     /// `IFrameNode` struct
     
     #[doc(inline)] pub use crate::dll::AzIFrameNode as IFrameNode;
+    /// `CustomLayoutNode` struct
+    
+    #[doc(inline)] pub use crate::dll::AzCustomLayoutNode as CustomLayoutNode;
+    /// Node type defined outside of azul (map view, terminal widget, ...): the measure / arrange / paint hooks that all nodes of this type share
+    
+    #[doc(inline)] pub use crate::dll::AzCustomNodeType as CustomNodeType;
+    impl CustomNodeType {
+
+        /// Creates a new `CustomNodeType` instance.
+        pub fn new<_1: Into<String>>(name: _1, measure: MeasureCallbackType, arrange: ArrangeCallbackType) -> Self { unsafe { crate::dll::AzCustomNodeType_new(name.into(), measure, arrange) } }
+        /// Draws the content of the nodes of this type with the given callback
+        pub fn with_paint(self, paint: PaintCallbackType)  -> crate::dom::CustomNodeType { unsafe { crate::dll::AzCustomNodeType_withPaint(self, paint) } }
+        /// Registers the node type (replacing a type with the same name), so that nodes can be created by name via `Dom::custom_node`
+        pub fn register(self)  { unsafe { crate::dll::AzCustomNodeType_register(self) } }
+    }
+
     /// `CallbackData` struct
     
     #[doc(inline)] pub use crate::dll::AzCallbackData as CallbackData;
@@ -13375,6 +15523,10 @@ pub mod dom {
         pub fn image<_1: Into<ImageRef>>(image: _1) -> Self { unsafe { crate::dll::AzNodeData_image(image.into()) } }
         /// Creates a new `NodeData` instance.
         pub fn iframe<_1: Into<RefAny>>(data: _1, callback: IFrameCallbackType) -> Self { unsafe { crate::dll::AzNodeData_iframe(data.into(), callback) } }
+        /// Creates a new `NodeData` instance.
+        pub fn custom_layout<_1: Into<RefAny>>(data: _1, measure: MeasureCallbackType, arrange: ArrangeCallbackType) -> Self { unsafe { crate::dll::AzNodeData_customLayout(data.into(), measure, arrange) } }
+        /// Same as `iframe()`, but only re-invokes the callback if the position, width or height of the iframe changes by more than `reinvoke_threshold` logical pixels
+        pub fn iframe_with_reinvoke_threshold<_1: Into<RefAny>>(data: _1, callback: IFrameCallbackType, reinvoke_threshold: f32) -> Self { unsafe { crate::dll::AzNodeData_iframeWithReinvokeThreshold(data.into(), callback, reinvoke_threshold) } }
         /// Calls the `NodeData::set_node_type` function.
         pub fn set_node_type<_1: Into<NodeType>>(&mut self, node_type: _1)  { unsafe { crate::dll::AzNodeData_setNodeType(self, node_type.into()) } }
         /// Calls the `NodeData::with_node_type` function.
@@ -13425,8 +15577,18 @@ pub mod dom {
         pub fn set_menu_bar<_1: Into<Menu>>(&mut self, menu_bar: _1)  { unsafe { crate::dll::AzNodeData_setMenuBar(self, menu_bar.into()) } }
         /// Signalizes that this node has a (native) context-aware menu. If set, the user can left-click the node to open the menu
         pub fn set_context_menu<_1: Into<Menu>>(&mut self, context_menu: _1)  { unsafe { crate::dll::AzNodeData_setContextMenu(self, context_menu.into()) } }
+        /// Overrides the node that receives the focus when an arrow key is pressed while this node is focused (see `WindowFlags::spatial_navigation_enabled`)
+        pub fn set_focus_navigation<_1: Into<FocusNavigation>>(&mut self, focus_navigation: _1)  { unsafe { crate::dll::AzNodeData_setFocusNavigation(self, focus_navigation.into()) } }
         /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
         pub fn hash(&self)  -> u64 { unsafe { crate::dll::AzNodeData_hash(self) } }
+        /// Turns the node into an overlay that is positioned next to the node with the ID `overlay.anchor` and rendered above all other content (see `Dom::overlay`)
+        pub fn set_overlay<_1: Into<OverlayInfo>>(&mut self, overlay: _1)  { unsafe { crate::dll::AzNodeData_setOverlay(self, overlay.into()) } }
+        /// Sets the language of the text in this node and its children (BCP 47 tag, i.e. `"de"` or `"en-US"`), same as the HTML `lang` attribute. Used for hyphenation (`hyphens: auto`) and line breaking
+        pub fn set_lang<_1: Into<String>>(&mut self, lang: _1)  { unsafe { crate::dll::AzNodeData_setLang(self, lang.into()) } }
+        /// Sets the kind of text that is entered into this node (same as the HTML `inputmode` attribute), forwarded to the IME / touch keyboard when the node is focused
+        pub fn set_input_mode<_1: Into<InputMode>>(&mut self, input_mode: _1)  { unsafe { crate::dll::AzNodeData_setInputMode(self, input_mode.into()) } }
+        /// Sets the initial `:disabled`, `:checked`, `:selected` or `:indeterminate` state of this node
+        pub fn set_state_flag<_1: Into<NodeStateFlag>>(&mut self, flag: _1, enabled: bool)  { unsafe { crate::dll::AzNodeData_setStateFlag(self, flag.into(), enabled) } }
     }
 
     /// List of core DOM node types built-into by `azul`
@@ -13465,6 +15627,24 @@ pub mod dom {
     /// Accessibility information (MSAA wrapper). See `NodeData.set_accessibility_info()`
     
     #[doc(inline)] pub use crate::dll::AzAccessibilityInfo as AccessibilityInfo;
+    /// Direction of the spatial (arrow key) focus navigation
+    
+    #[doc(inline)] pub use crate::dll::AzFocusDirection as FocusDirection;
+    /// Kind of text that is entered into a text input node, same as the HTML `inputmode` attribute. Forwarded to the operating system so that touch keyboards and IMEs can show the appropriate layout
+    
+    #[doc(inline)] pub use crate::dll::AzInputMode as InputMode;
+    /// Semantic state of a node that is set by the application (instead of by the mouse / keyboard), matched by the `:disabled`, `:checked`, `:selected` and `:indeterminate` CSS selectors
+    
+    #[doc(inline)] pub use crate::dll::AzNodeStateFlag as NodeStateFlag;
+    /// Per-node overrides for the spatial focus navigation, similar to the `nav-up: #id` CSS properties
+    
+    #[doc(inline)] pub use crate::dll::AzFocusNavigation as FocusNavigation;
+    /// Where an overlay is placed relative to its anchor node: the first part is the side of the anchor, the second part the alignment along that side (`BottomStart` = below the anchor, left edges aligned)
+    
+    #[doc(inline)] pub use crate::dll::AzOverlayPlacement as OverlayPlacement;
+    /// Marks a node as an overlay: the node is taken out of the regular layout, positioned next to the node with the ID `anchor` and rendered above all other content, clipped only by the window. If the overlay doesn't fit on the requested side, it is flipped to the opposite side and shifted back into the window
+    
+    #[doc(inline)] pub use crate::dll::AzOverlayInfo as OverlayInfo;
     /// MSAA Accessibility role constants. For information on what each role does, see the <a href="https://docs.microsoft.com/en-us/windows/win32/winauto/object-roles">MSDN Role Constants page</a>
     
     #[doc(inline)] pub use crate::dll::AzAccessibilityRole as AccessibilityRole;
@@ -13544,6 +15724,33 @@ pub mod menu {
         pub fn new<_1: Into<RefAny>>(data: _1, callback: CallbackType) -> Self { unsafe { crate::dll::AzMenuCallback_new(data.into(), callback) } }
     }
 
+    /// Window-level keyboard shortcut: invokes the callback when the key combination is pressed, instead of the `VirtualKeyDown` callbacks of the focused node
+    
+    #[doc(inline)] pub use crate::dll::AzShortcut as Shortcut;
+    impl Shortcut {
+
+        /// Creates a new shortcut from a key combination and a callback
+        pub fn new<_1: Into<VirtualKeyCodeCombo>, _2: Into<RefAny>>(keys: _1, data: _2, callback: CallbackType) -> Self { unsafe { crate::dll::AzShortcut_new(keys.into(), data.into(), callback) } }
+    }
+
+    /// Two shortcuts in a `ShortcutMap` that use the same key combination
+    
+    #[doc(inline)] pub use crate::dll::AzShortcutConflict as ShortcutConflict;
+    /// List of window-level keyboard shortcuts
+    
+    #[doc(inline)] pub use crate::dll::AzShortcutMap as ShortcutMap;
+    impl ShortcutMap {
+
+        /// Creates an empty shortcut map
+        pub fn new() -> Self { unsafe { crate::dll::AzShortcutMap_new() } }
+        /// Adds a shortcut to the map. If another shortcut already uses the same key combination, the new shortcut is NOT added and the conflict is returned
+        pub fn add<_1: Into<Shortcut>>(&mut self, shortcut: _1)  -> crate::option::OptionShortcutConflict { unsafe { crate::dll::AzShortcutMap_add(self, shortcut.into()) } }
+        /// Removes the shortcut with the given key combination, returns whether a shortcut was removed
+        pub fn remove<_1: Into<VirtualKeyCodeCombo>>(&mut self, keys: _1)  -> bool { unsafe { crate::dll::AzShortcutMap_remove(self, keys.into()) } }
+        /// Returns all pairs of shortcuts with the same key combination
+        pub fn get_conflicts(&self)  -> crate::vec::ShortcutConflictVec { unsafe { crate::dll::AzShortcutMap_getConflicts(self) } }
+    }
+
     /// Icon of a menu entry
     
     #[doc(inline)] pub use crate::dll::AzMenuItemIcon as MenuItemIcon;
@@ -13578,6 +15785,7 @@ pub mod css {
             CssPropertyType::LineHeight => CssProperty::LineHeight(StyleLineHeightValue::$content_type),
             CssPropertyType::WordSpacing => CssProperty::WordSpacing(StyleWordSpacingValue::$content_type),
             CssPropertyType::TabWidth => CssProperty::TabWidth(StyleTabWidthValue::$content_type),
+            CssPropertyType::Hyphens => CssProperty::Hyphens(StyleHyphensValue::$content_type),
             CssPropertyType::Cursor => CssProperty::Cursor(StyleCursorValue::$content_type),
             CssPropertyType::Display => CssProperty::Display(LayoutDisplayValue::$content_type),
             CssPropertyType::Float => CssProperty::Float(LayoutFloatValue::$content_type),
@@ -13635,11 +15843,24 @@ pub mod css {
             CssPropertyType::BoxShadowTop => CssProperty::BoxShadowTop(StyleBoxShadowValue::$content_type),
             CssPropertyType::BoxShadowBottom => CssProperty::BoxShadowBottom(StyleBoxShadowValue::$content_type),
             CssPropertyType::ScrollbarStyle => CssProperty::ScrollbarStyle(ScrollbarStyleValue::$content_type),
+            CssPropertyType::ScrollbarWidth => CssProperty::ScrollbarWidth(StyleScrollbarWidthValue::$content_type),
+            CssPropertyType::ScrollbarColor => CssProperty::ScrollbarColor(StyleScrollbarColorValue::$content_type),
+            CssPropertyType::ScrollbarThumbHoverColor => CssProperty::ScrollbarThumbHoverColor(StyleScrollbarThumbHoverColorValue::$content_type),
+            CssPropertyType::ScrollbarThumbActiveColor => CssProperty::ScrollbarThumbActiveColor(StyleScrollbarThumbActiveColorValue::$content_type),
+            CssPropertyType::WindowDragRegion => CssProperty::WindowDragRegion(StyleWindowDragRegionValue::$content_type),
+            CssPropertyType::CaretAnimationDuration => CssProperty::CaretAnimationDuration(StyleCaretAnimationDurationValue::$content_type),
+            CssPropertyType::ScrollPadding => CssProperty::ScrollPadding(StyleScrollPaddingValue::$content_type),
+            CssPropertyType::Content => CssProperty::Content(StyleContentValue::$content_type),
+            CssPropertyType::CounterReset => CssProperty::CounterReset(StyleCounterResetValue::$content_type),
+            CssPropertyType::CounterIncrement => CssProperty::CounterIncrement(StyleCounterIncrementValue::$content_type),
+            CssPropertyType::OverflowAnchor => CssProperty::OverflowAnchor(StyleOverflowAnchorValue::$content_type),
             CssPropertyType::Opacity => CssProperty::Opacity(StyleOpacityValue::$content_type),
             CssPropertyType::Transform => CssProperty::Transform(StyleTransformVecValue::$content_type),
             CssPropertyType::PerspectiveOrigin => CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::$content_type),
             CssPropertyType::TransformOrigin => CssProperty::TransformOrigin(StyleTransformOriginValue::$content_type),
             CssPropertyType::BackfaceVisibility => CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::$content_type),
+            CssPropertyType::Perspective => CssProperty::Perspective(StylePerspectiveValue::$content_type),
+            CssPropertyType::TransformStyle => CssProperty::TransformStyle(StyleTransformStyleValue::$content_type),
             CssPropertyType::MixBlendMode => CssProperty::MixBlendMode(StyleMixBlendModeValue::$content_type),
             CssPropertyType::Filter => CssProperty::Filter(StyleFilterVecValue::$content_type),
             CssPropertyType::BackdropFilter => CssProperty::BackdropFilter(StyleFilterVecValue::$content_type),
@@ -13660,6 +15881,7 @@ pub mod css {
                 CssProperty::LineHeight(_) => CssPropertyType::LineHeight,
                 CssProperty::WordSpacing(_) => CssPropertyType::WordSpacing,
                 CssProperty::TabWidth(_) => CssPropertyType::TabWidth,
+                CssProperty::Hyphens(_) => CssPropertyType::Hyphens,
                 CssProperty::Cursor(_) => CssPropertyType::Cursor,
                 CssProperty::Display(_) => CssPropertyType::Display,
                 CssProperty::Float(_) => CssPropertyType::Float,
@@ -13717,11 +15939,24 @@ pub mod css {
                 CssProperty::BoxShadowTop(_) => CssPropertyType::BoxShadowTop,
                 CssProperty::BoxShadowBottom(_) => CssPropertyType::BoxShadowBottom,
                 CssProperty::ScrollbarStyle(_) => CssPropertyType::ScrollbarStyle,
+                CssProperty::ScrollbarWidth(_) => CssPropertyType::ScrollbarWidth,
+                CssProperty::ScrollbarColor(_) => CssPropertyType::ScrollbarColor,
+                CssProperty::ScrollbarThumbHoverColor(_) => CssPropertyType::ScrollbarThumbHoverColor,
+                CssProperty::ScrollbarThumbActiveColor(_) => CssPropertyType::ScrollbarThumbActiveColor,
+                CssProperty::WindowDragRegion(_) => CssPropertyType::WindowDragRegion,
+                CssProperty::CaretAnimationDuration(_) => CssPropertyType::CaretAnimationDuration,
+                CssProperty::ScrollPadding(_) => CssPropertyType::ScrollPadding,
+                CssProperty::Content(_) => CssPropertyType::Content,
+                CssProperty::CounterReset(_) => CssPropertyType::CounterReset,
+                CssProperty::CounterIncrement(_) => CssPropertyType::CounterIncrement,
+                CssProperty::OverflowAnchor(_) => CssPropertyType::OverflowAnchor,
                 CssProperty::Opacity(_) => CssPropertyType::Opacity,
                 CssProperty::Transform(_) => CssPropertyType::Transform,
                 CssProperty::PerspectiveOrigin(_) => CssPropertyType::PerspectiveOrigin,
                 CssProperty::TransformOrigin(_) => CssPropertyType::TransformOrigin,
                 CssProperty::BackfaceVisibility(_) => CssPropertyType::BackfaceVisibility,
+                CssProperty::Perspective(_) => CssPropertyType::Perspective,
+                CssProperty::TransformStyle(_) => CssPropertyType::TransformStyle,
                 CssProperty::MixBlendMode(_) => CssPropertyType::MixBlendMode,
                 CssProperty::Filter(_) => CssPropertyType::Filter,
                 CssProperty::BackdropFilter(_) => CssPropertyType::BackdropFilter,
@@ -13744,6 +15979,7 @@ pub mod css {
         pub const fn line_height(input: StyleLineHeight) -> Self { CssProperty::LineHeight(StyleLineHeightValue::Exact(input)) }
         pub const fn word_spacing(input: StyleWordSpacing) -> Self { CssProperty::WordSpacing(StyleWordSpacingValue::Exact(input)) }
         pub const fn tab_width(input: StyleTabWidth) -> Self { CssProperty::TabWidth(StyleTabWidthValue::Exact(input)) }
+        pub const fn hyphens(input: StyleHyphens) -> Self { CssProperty::Hyphens(StyleHyphensValue::Exact(input)) }
         pub const fn cursor(input: StyleCursor) -> Self { CssProperty::Cursor(StyleCursorValue::Exact(input)) }
         pub const fn display(input: LayoutDisplay) -> Self { CssProperty::Display(LayoutDisplayValue::Exact(input)) }
         pub const fn float(input: LayoutFloat) -> Self { CssProperty::Float(LayoutFloatValue::Exact(input)) }
@@ -13800,11 +16036,24 @@ pub mod css {
         pub const fn box_shadow_right(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(input)) }
         pub const fn box_shadow_top(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(input)) }
         pub const fn box_shadow_bottom(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(input)) }
+        pub const fn scrollbar_width(input: StyleScrollbarWidth) -> Self { CssProperty::ScrollbarWidth(StyleScrollbarWidthValue::Exact(input)) }
+        pub const fn scrollbar_color(input: StyleScrollbarColor) -> Self { CssProperty::ScrollbarColor(StyleScrollbarColorValue::Exact(input)) }
+        pub const fn scrollbar_thumb_hover_color(input: StyleScrollbarThumbHoverColor) -> Self { CssProperty::ScrollbarThumbHoverColor(StyleScrollbarThumbHoverColorValue::Exact(input)) }
+        pub const fn scrollbar_thumb_active_color(input: StyleScrollbarThumbActiveColor) -> Self { CssProperty::ScrollbarThumbActiveColor(StyleScrollbarThumbActiveColorValue::Exact(input)) }
+        pub const fn window_drag_region(input: StyleWindowDragRegion) -> Self { CssProperty::WindowDragRegion(StyleWindowDragRegionValue::Exact(input)) }
+        pub const fn caret_animation_duration(input: StyleCaretAnimationDuration) -> Self { CssProperty::CaretAnimationDuration(StyleCaretAnimationDurationValue::Exact(input)) }
+        pub const fn scroll_padding(input: StyleScrollPadding) -> Self { CssProperty::ScrollPadding(StyleScrollPaddingValue::Exact(input)) }
+        pub const fn content(input: StyleContent) -> Self { CssProperty::Content(StyleContentValue::Exact(input)) }
+        pub const fn counter_reset(input: StyleCounterReset) -> Self { CssProperty::CounterReset(StyleCounterResetValue::Exact(input)) }
+        pub const fn counter_increment(input: StyleCounterIncrement) -> Self { CssProperty::CounterIncrement(StyleCounterIncrementValue::Exact(input)) }
+        pub const fn overflow_anchor(input: StyleOverflowAnchor) -> Self { CssProperty::OverflowAnchor(StyleOverflowAnchorValue::Exact(input)) }
         pub const fn opacity(input: StyleOpacity) -> Self { CssProperty::Opacity(StyleOpacityValue::Exact(input)) }
         pub const fn transform(input: StyleTransformVec) -> Self { CssProperty::Transform(StyleTransformVecValue::Exact(input)) }
         pub const fn transform_origin(input: StyleTransformOrigin) -> Self { CssProperty::TransformOrigin(StyleTransformOriginValue::Exact(input)) }
         pub const fn perspective_origin(input: StylePerspectiveOrigin) -> Self { CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::Exact(input)) }
         pub const fn backface_visiblity(input: StyleBackfaceVisibility) -> Self { CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::Exact(input)) }
+        pub const fn perspective(input: StylePerspective) -> Self { CssProperty::Perspective(StylePerspectiveValue::Exact(input)) }
+        pub const fn transform_style(input: StyleTransformStyle) -> Self { CssProperty::TransformStyle(StyleTransformStyleValue::Exact(input)) }
         pub const fn mix_blend_mode(input: StyleMixBlendMode) -> Self { CssProperty::MixBlendMode(StyleMixBlendModeValue::Exact(input)) }
         pub const fn filter(input: StyleFilterVec) -> Self { CssProperty::Filter(StyleFilterVecValue::Exact(input)) }
         pub const fn backdrop_filter(input: StyleFilterVec) -> Self { CssProperty::BackdropFilter(StyleFilterVecValue::Exact(input)) }
@@ -14212,12 +16461,18 @@ pub mod css {
     /// `CssRuleBlock` struct
     
     #[doc(inline)] pub use crate::dll::AzCssRuleBlock as CssRuleBlock;
+    /// `CssMediaCondition` struct
+    
+    #[doc(inline)] pub use crate::dll::AzCssMediaCondition as CssMediaCondition;
     /// `CssDeclaration` struct
     
     #[doc(inline)] pub use crate::dll::AzCssDeclaration as CssDeclaration;
     /// `DynamicCssProperty` struct
     
     #[doc(inline)] pub use crate::dll::AzDynamicCssProperty as DynamicCssProperty;
+    /// `SystemCssProperty` struct
+    
+    #[doc(inline)] pub use crate::dll::AzSystemCssProperty as SystemCssProperty;
     /// `CssPath` struct
     
     #[doc(inline)] pub use crate::dll::AzCssPath as CssPath;
@@ -14276,6 +16531,12 @@ pub mod css {
         pub fn to_hash(&self)  -> crate::str::String { unsafe { crate::dll::AzColorU_toHash(self) } }
     }
 
+    /// Colors of the CSS system color keywords (`Canvas`, `CanvasText`, `Highlight`, ...)
+    
+    #[doc(inline)] pub use crate::dll::AzSystemColors as SystemColors;
+    /// High contrast / forced colors mode, reduced motion preference and system colors of the operating system
+    
+    #[doc(inline)] pub use crate::dll::AzSystemStyle as SystemStyle;
     /// `SizeMetric` struct
     
     #[doc(inline)] pub use crate::dll::AzSizeMetric as SizeMetric;
@@ -14525,9 +16786,45 @@ pub mod css {
     /// `ScrollbarStyle` struct
     
     #[doc(inline)] pub use crate::dll::AzScrollbarStyle as ScrollbarStyle;
+    /// `StyleScrollbarWidth` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollbarWidth as StyleScrollbarWidth;
+    /// `StyleOverflowAnchor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleOverflowAnchor as StyleOverflowAnchor;
+    /// `StyleScrollbarColor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollbarColor as StyleScrollbarColor;
+    /// `StyleScrollbarThumbHoverColor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollbarThumbHoverColor as StyleScrollbarThumbHoverColor;
+    /// `StyleScrollbarThumbActiveColor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollbarThumbActiveColor as StyleScrollbarThumbActiveColor;
+    /// `StyleWindowDragRegion` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleWindowDragRegion as StyleWindowDragRegion;
+    /// `StyleCaretAnimationDuration` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleCaretAnimationDuration as StyleCaretAnimationDuration;
+    /// `StyleScrollPadding` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollPadding as StyleScrollPadding;
+    /// `StyleContent` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleContent as StyleContent;
+    /// Represents a `counter-reset` attribute: `counter-reset: name [<integer>]`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleCounterReset as StyleCounterReset;
+    /// Represents a `counter-increment` attribute: `counter-increment: name [<integer>]`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleCounterIncrement as StyleCounterIncrement;
     /// `StyleCursor` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleCursor as StyleCursor;
+    /// Custom cursor image, the hotspot is in pixels from the top left of the image
+    
+    #[doc(inline)] pub use crate::dll::AzStyleCursorImage as StyleCursorImage;
     /// `StyleFontFamily` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleFontFamily as StyleFontFamily;
@@ -14555,6 +16852,12 @@ pub mod css {
     /// `StyleBackfaceVisibility` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleBackfaceVisibility as StyleBackfaceVisibility;
+    /// Distance between the z = 0 plane and the viewer, applied to the children of the node
+    
+    #[doc(inline)] pub use crate::dll::AzStylePerspective as StylePerspective;
+    /// Whether the children of a node are flattened into its plane or positioned in the 3D space
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTransformStyle as StyleTransformStyle;
     /// `StyleTransform` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTransform as StyleTransform;
@@ -14585,6 +16888,9 @@ pub mod css {
     /// `StyleTextAlign` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTextAlign as StyleTextAlign;
+    /// Whether words may be hyphenated when a line is broken inside of them
+    
+    #[doc(inline)] pub use crate::dll::AzStyleHyphens as StyleHyphens;
     /// `StyleTextColor` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTextColor as StyleTextColor;
@@ -14687,6 +16993,39 @@ pub mod css {
     /// `ScrollbarStyleValue` struct
     
     #[doc(inline)] pub use crate::dll::AzScrollbarStyleValue as ScrollbarStyleValue;
+    /// `StyleScrollbarWidthValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollbarWidthValue as StyleScrollbarWidthValue;
+    /// `StyleScrollbarColorValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollbarColorValue as StyleScrollbarColorValue;
+    /// `StyleScrollbarThumbHoverColorValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollbarThumbHoverColorValue as StyleScrollbarThumbHoverColorValue;
+    /// `StyleScrollbarThumbActiveColorValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollbarThumbActiveColorValue as StyleScrollbarThumbActiveColorValue;
+    /// `StyleWindowDragRegionValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleWindowDragRegionValue as StyleWindowDragRegionValue;
+    /// `StyleCaretAnimationDurationValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleCaretAnimationDurationValue as StyleCaretAnimationDurationValue;
+    /// `StyleScrollPaddingValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleScrollPaddingValue as StyleScrollPaddingValue;
+    /// `StyleContentValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleContentValue as StyleContentValue;
+    /// `StyleCounterResetValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleCounterResetValue as StyleCounterResetValue;
+    /// `StyleCounterIncrementValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleCounterIncrementValue as StyleCounterIncrementValue;
+    /// `StyleOverflowAnchorValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleOverflowAnchorValue as StyleOverflowAnchorValue;
     /// `StyleBackgroundContentVecValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleBackgroundContentVecValue as StyleBackgroundContentVecValue;
@@ -14765,6 +17104,9 @@ pub mod css {
     /// `StyleTabWidthValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTabWidthValue as StyleTabWidthValue;
+    /// `StyleHyphensValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleHyphensValue as StyleHyphensValue;
     /// `StyleTextAlignValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTextAlignValue as StyleTextAlignValue;
@@ -14789,6 +17131,12 @@ pub mod css {
     /// `StyleBackfaceVisibilityValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleBackfaceVisibilityValue as StyleBackfaceVisibilityValue;
+    /// `StylePerspectiveValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStylePerspectiveValue as StylePerspectiveValue;
+    /// `StyleTransformStyleValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTransformStyleValue as StyleTransformStyleValue;
     /// `StyleMixBlendModeValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleMixBlendModeValue as StyleMixBlendModeValue;
@@ -14817,9 +17165,9 @@ pub mod widgets {
     //! Default, built-in widgets (button, label, textinput, etc.)
     use crate::dll::*;
     use core::ffi::c_void;
-    use crate::callbacks::{CallbackType, RefAny};
+    use crate::callbacks::{CallbackInfo, CallbackType, CaretAnimation, RefAny};
     use crate::str::String;
-    use crate::option::OptionString;
+    use crate::option::{OptionDuration, OptionString};
     use crate::css::{ColorU, PixelValue};
     use crate::vec::{ListViewRowVec, NodeDataInlineCssPropertyVec, StringVec, StyleBackgroundContentVec};
     use crate::dom::Dom;
@@ -14988,6 +17336,22 @@ pub mod widgets {
         pub fn set_on_focus_lost<_1: Into<RefAny>>(&mut self, data: _1, callback: TextInputOnFocusLostCallbackType)  { unsafe { crate::dll::AzTextInput_setOnFocusLost(self, data.into(), callback) } }
         /// Calls the `TextInput::with_on_focus_lost` function.
         pub fn with_on_focus_lost<_1: Into<RefAny>>(&mut self, data: _1, callback: TextInputOnFocusLostCallbackType)  -> crate::widgets::TextInput { unsafe { crate::dll::AzTextInput_withOnFocusLost(self, data.into(), callback) } }
+        /// Calls the `TextInput::set_caret_animation` function.
+        pub fn set_caret_animation<_1: Into<CaretAnimation>>(&mut self, caret_animation: _1)  { unsafe { crate::dll::AzTextInput_setCaretAnimation(self, caret_animation.into()) } }
+        /// Calls the `TextInput::with_caret_animation` function.
+        pub fn with_caret_animation<_1: Into<CaretAnimation>>(&mut self, caret_animation: _1)  -> crate::widgets::TextInput { unsafe { crate::dll::AzTextInput_withCaretAnimation(self, caret_animation.into()) } }
+        /// Underlines misspelled words, the text is checked on a background thread whenever it changes. Does nothing if there is no spellchecker for the language (i.e. "en-US").
+        pub fn set_spellcheck<_1: Into<String>>(&mut self, language: _1)  { unsafe { crate::dll::AzTextInput_setSpellcheck(self, language.into()) } }
+        /// Calls the `TextInput::with_spellcheck` function.
+        pub fn with_spellcheck<_1: Into<String>>(&mut self, language: _1)  -> crate::widgets::TextInput { unsafe { crate::dll::AzTextInput_withSpellcheck(self, language.into()) } }
+        /// Calls the `TextInput::set_on_spelling_suggestions` function.
+        pub fn set_on_spelling_suggestions<_1: Into<RefAny>>(&mut self, data: _1, callback: TextInputOnSpellingSuggestionsCallbackType)  { unsafe { crate::dll::AzTextInput_setOnSpellingSuggestions(self, data.into(), callback) } }
+        /// Calls the `TextInput::with_on_spelling_suggestions` function.
+        pub fn with_on_spelling_suggestions<_1: Into<RefAny>>(&mut self, data: _1, callback: TextInputOnSpellingSuggestionsCallbackType)  -> crate::widgets::TextInput { unsafe { crate::dll::AzTextInput_withOnSpellingSuggestions(self, data.into(), callback) } }
+        /// Secure text entry for passwords: renders the text as bullets, disables the spellchecker and the IME while the input is focused and zeroizes the text when it is freed. NOTE: the IME is only disabled on Windows - the X11 shell doesn't use an input method yet and the macOS shell doesn't track the focused node yet.
+        pub fn set_secure(&mut self, secure: bool)  { unsafe { crate::dll::AzTextInput_setSecure(self, secure) } }
+        /// Calls the `TextInput::with_secure` function.
+        pub fn with_secure(&mut self, secure: bool)  -> crate::widgets::TextInput { unsafe { crate::dll::AzTextInput_withSecure(self, secure) } }
         /// Calls the `TextInput::set_placeholder_style` function.
         pub fn set_placeholder_style<_1: Into<NodeDataInlineCssPropertyVec>>(&mut self, placeholder_style: _1)  { unsafe { crate::dll::AzTextInput_setPlaceholderStyle(self, placeholder_style.into()) } }
         /// Calls the `TextInput::with_placeholder_style` function.
@@ -15049,6 +17413,18 @@ pub mod widgets {
     /// `TextInputOnFocusLostCallbackType` struct
     
     #[doc(inline)] pub use crate::dll::AzTextInputOnFocusLostCallbackType as TextInputOnFocusLostCallbackType;
+    /// `TextInputOnSpellingSuggestions` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTextInputOnSpellingSuggestions as TextInputOnSpellingSuggestions;
+    /// `TextInputOnSpellingSuggestionsCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTextInputOnSpellingSuggestionsCallback as TextInputOnSpellingSuggestionsCallback;
+    /// Invoked when a misspelled word is right-clicked, i.e. to show a context menu with the suggestions
+    
+    #[doc(inline)] pub use crate::dll::AzTextInputOnSpellingSuggestionsCallbackType as TextInputOnSpellingSuggestionsCallbackType;
+    /// Misspelled word that was right-clicked
+    
+    #[doc(inline)] pub use crate::dll::AzTextInputSpellingSuggestions as TextInputSpellingSuggestions;
     /// `OnTextInputReturn` struct
     
     #[doc(inline)] pub use crate::dll::AzOnTextInputReturn as OnTextInputReturn;
@@ -15061,7 +17437,7 @@ pub mod widgets {
     impl NumberInput {
 
         /// Creates a new `NumberInput` instance.
-        pub fn new(number: f32) -> Self { unsafe { crate::dll::AzNumberInput_new(number) } }
+        pub fn new(number: f64) -> Self { unsafe { crate::dll::AzNumberInput_new(number) } }
         /// Calls the `NumberInput::set_on_text_input` function.
         pub fn set_on_text_input<_1: Into<RefAny>>(&mut self, data: _1, callback: TextInputOnTextInputCallbackType)  { unsafe { crate::dll::AzNumberInput_setOnTextInput(self, data.into(), callback) } }
         /// Calls the `NumberInput::with_on_text_input` function.
@@ -15090,6 +17466,34 @@ pub mod widgets {
         pub fn set_on_value_change<_1: Into<RefAny>>(&mut self, data: _1, callback: NumberInputOnValueChangeCallbackType)  { unsafe { crate::dll::AzNumberInput_setOnValueChange(self, data.into(), callback) } }
         /// Calls the `NumberInput::with_on_value_change` function.
         pub fn with_on_value_change<_1: Into<RefAny>>(&mut self, data: _1, callback: NumberInputOnValueChangeCallbackType)  -> crate::widgets::NumberInput { unsafe { crate::dll::AzNumberInput_withOnValueChange(self, data.into(), callback) } }
+        /// Calls the `NumberInput::set_spinner_button_style` function.
+        pub fn set_spinner_button_style<_1: Into<NodeDataInlineCssPropertyVec>>(&mut self, style: _1)  { unsafe { crate::dll::AzNumberInput_setSpinnerButtonStyle(self, style.into()) } }
+        /// Calls the `NumberInput::with_spinner_button_style` function.
+        pub fn with_spinner_button_style<_1: Into<NodeDataInlineCssPropertyVec>>(&mut self, style: _1)  -> crate::widgets::NumberInput { unsafe { crate::dll::AzNumberInput_withSpinnerButtonStyle(self, style.into()) } }
+        /// Sets the properties that are applied to the text input container while the input is not a valid number (default: red border)
+        pub fn set_invalid_style<_1: Into<NodeDataInlineCssPropertyVec>>(&mut self, style: _1)  { unsafe { crate::dll::AzNumberInput_setInvalidStyle(self, style.into()) } }
+        /// Calls the `NumberInput::with_invalid_style` function.
+        pub fn with_invalid_style<_1: Into<NodeDataInlineCssPropertyVec>>(&mut self, style: _1)  -> crate::widgets::NumberInput { unsafe { crate::dll::AzNumberInput_withInvalidStyle(self, style.into()) } }
+        /// Calls the `NumberInput::set_mode` function.
+        pub fn set_mode<_1: Into<NumberInputMode>>(&mut self, mode: _1)  { unsafe { crate::dll::AzNumberInput_setMode(self, mode.into()) } }
+        /// Calls the `NumberInput::with_mode` function.
+        pub fn with_mode<_1: Into<NumberInputMode>>(&mut self, mode: _1)  -> crate::widgets::NumberInput { unsafe { crate::dll::AzNumberInput_withMode(self, mode.into()) } }
+        /// Calls the `NumberInput::set_min` function.
+        pub fn set_min(&mut self, min: f64)  { unsafe { crate::dll::AzNumberInput_setMin(self, min) } }
+        /// Calls the `NumberInput::with_min` function.
+        pub fn with_min(&mut self, min: f64)  -> crate::widgets::NumberInput { unsafe { crate::dll::AzNumberInput_withMin(self, min) } }
+        /// Calls the `NumberInput::set_max` function.
+        pub fn set_max(&mut self, max: f64)  { unsafe { crate::dll::AzNumberInput_setMax(self, max) } }
+        /// Calls the `NumberInput::with_max` function.
+        pub fn with_max(&mut self, max: f64)  -> crate::widgets::NumberInput { unsafe { crate::dll::AzNumberInput_withMax(self, max) } }
+        /// Sets the amount that the spinner buttons and the mouse wheel add or subtract
+        pub fn set_step(&mut self, step: f64)  { unsafe { crate::dll::AzNumberInput_setStep(self, step) } }
+        /// Calls the `NumberInput::with_step` function.
+        pub fn with_step(&mut self, step: f64)  -> crate::widgets::NumberInput { unsafe { crate::dll::AzNumberInput_withStep(self, step) } }
+        /// Sets the character used to display the decimal point (i.e. `,` for German locales)
+        pub fn set_decimal_separator(&mut self, decimal_separator: u32)  { unsafe { crate::dll::AzNumberInput_setDecimalSeparator(self, decimal_separator) } }
+        /// Calls the `NumberInput::with_decimal_separator` function.
+        pub fn with_decimal_separator(&mut self, decimal_separator: u32)  -> crate::widgets::NumberInput { unsafe { crate::dll::AzNumberInput_withDecimalSeparator(self, decimal_separator) } }
         /// Calls the `NumberInput::dom` function.
         pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzNumberInput_dom(self) } }
     }
@@ -15097,6 +17501,9 @@ pub mod widgets {
     /// `NumberInputStateWrapper` struct
     
     #[doc(inline)] pub use crate::dll::AzNumberInputStateWrapper as NumberInputStateWrapper;
+    /// Whether the `NumberInput` accepts any number or only integers
+    
+    #[doc(inline)] pub use crate::dll::AzNumberInputMode as NumberInputMode;
     /// `NumberInputState` struct
     
     #[doc(inline)] pub use crate::dll::AzNumberInputState as NumberInputState;
@@ -15423,6 +17830,70 @@ pub mod widgets {
     /// `DropDownOnChoiceChange` struct
     
     #[doc(inline)] pub use crate::dll::AzDropDownOnChoiceChange as DropDownOnChoiceChange;
+    /// Manages the queue of toast / snackbar notifications of a window. Store it in the application data and insert `Toasts::dom()` as the last child of the root node
+    
+    #[doc(inline)] pub use crate::dll::AzToasts as Toasts;
+    impl Toasts {
+
+        /// Creates a new `Toasts` instance.
+        pub fn new() -> Self { unsafe { crate::dll::AzToasts_new() } }
+        /// Queues the toast and starts the timer that animates it in and out. The DOM is refreshed automatically once the toast becomes visible
+        pub fn show<_2: Into<Toast>>(&mut self, info: &mut CallbackInfo, toast: _2)  -> crate::widgets::ToastId { unsafe { crate::dll::AzToasts_show(self, info, toast.into()) } }
+        /// Fades out the toast (or removes it from the queue if it isn't visible yet). Returns false if the toast doesn't exist anymore
+        pub fn dismiss<_2: Into<ToastId>>(&mut self, info: &mut CallbackInfo, toast_id: _2)  -> bool { unsafe { crate::dll::AzToasts_dismiss(self, info, toast_id.into()) } }
+        /// Sets how many toasts can be visible at the same time, all other toasts are queued
+        pub fn set_max_visible(&mut self, max_visible: usize)  { unsafe { crate::dll::AzToasts_setMaxVisible(self, max_visible) } }
+        /// Calls the `Toasts::with_max_visible` function.
+        pub fn with_max_visible(&mut self, max_visible: usize)  -> crate::widgets::Toasts { unsafe { crate::dll::AzToasts_withMaxVisible(self, max_visible) } }
+        /// Calls the `Toasts::set_layer_style` function.
+        pub fn set_layer_style<_1: Into<NodeDataInlineCssPropertyVec>>(&mut self, style: _1)  { unsafe { crate::dll::AzToasts_setLayerStyle(self, style.into()) } }
+        /// Calls the `Toasts::with_layer_style` function.
+        pub fn with_layer_style<_1: Into<NodeDataInlineCssPropertyVec>>(&mut self, style: _1)  -> crate::widgets::Toasts { unsafe { crate::dll::AzToasts_withLayerStyle(self, style.into()) } }
+        /// Calls the `Toasts::set_toast_style` function.
+        pub fn set_toast_style<_1: Into<NodeDataInlineCssPropertyVec>>(&mut self, style: _1)  { unsafe { crate::dll::AzToasts_setToastStyle(self, style.into()) } }
+        /// Calls the `Toasts::with_toast_style` function.
+        pub fn with_toast_style<_1: Into<NodeDataInlineCssPropertyVec>>(&mut self, style: _1)  -> crate::widgets::Toasts { unsafe { crate::dll::AzToasts_withToastStyle(self, style.into()) } }
+        /// Calls the `Toasts::set_label_style` function.
+        pub fn set_label_style<_1: Into<NodeDataInlineCssPropertyVec>>(&mut self, style: _1)  { unsafe { crate::dll::AzToasts_setLabelStyle(self, style.into()) } }
+        /// Calls the `Toasts::with_label_style` function.
+        pub fn with_label_style<_1: Into<NodeDataInlineCssPropertyVec>>(&mut self, style: _1)  -> crate::widgets::Toasts { unsafe { crate::dll::AzToasts_withLabelStyle(self, style.into()) } }
+        /// Calls the `Toasts::set_action_style` function.
+        pub fn set_action_style<_1: Into<NodeDataInlineCssPropertyVec>>(&mut self, style: _1)  { unsafe { crate::dll::AzToasts_setActionStyle(self, style.into()) } }
+        /// Calls the `Toasts::with_action_style` function.
+        pub fn with_action_style<_1: Into<NodeDataInlineCssPropertyVec>>(&mut self, style: _1)  -> crate::widgets::Toasts { unsafe { crate::dll::AzToasts_withActionStyle(self, style.into()) } }
+        /// Calls the `Toasts::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzToasts_dom(self) } }
+    }
+
+    /// Single notification message
+    
+    #[doc(inline)] pub use crate::dll::AzToast as Toast;
+    impl Toast {
+
+        /// Creates a new `Toast` instance.
+        pub fn new<_1: Into<String>>(message: _1) -> Self { unsafe { crate::dll::AzToast_new(message.into()) } }
+        /// Calls the `Toast::set_duration` function.
+        pub fn set_duration<_1: Into<OptionDuration>>(&mut self, duration: _1)  { unsafe { crate::dll::AzToast_setDuration(self, duration.into()) } }
+        /// Calls the `Toast::with_duration` function.
+        pub fn with_duration<_1: Into<OptionDuration>>(&mut self, duration: _1)  -> crate::widgets::Toast { unsafe { crate::dll::AzToast_withDuration(self, duration.into()) } }
+        /// Calls the `Toast::set_action` function.
+        pub fn set_action<_1: Into<String>, _2: Into<RefAny>>(&mut self, label: _1, data: _2, callback: ToastOnActionCallbackType)  { unsafe { crate::dll::AzToast_setAction(self, label.into(), data.into(), callback) } }
+        /// Calls the `Toast::with_action` function.
+        pub fn with_action<_1: Into<String>, _2: Into<RefAny>>(&mut self, label: _1, data: _2, callback: ToastOnActionCallbackType)  -> crate::widgets::Toast { unsafe { crate::dll::AzToast_withAction(self, label.into(), data.into(), callback) } }
+    }
+
+    /// Identifies a toast that was shown with `Toasts::show`
+    
+    #[doc(inline)] pub use crate::dll::AzToastId as ToastId;
+    /// `ToastOnAction` struct
+    
+    #[doc(inline)] pub use crate::dll::AzToastOnAction as ToastOnAction;
+    /// `ToastOnActionCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzToastOnActionCallback as ToastOnActionCallback;
+    /// `ToastOnActionCallbackType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzToastOnActionCallbackType as ToastOnActionCallbackType;
 }
 
 pub mod style {
@@ -15781,6 +18252,7 @@ pub mod gl {
     use crate::svg::{TessellatedColoredGPUSvgNode, TessellatedGPUSvgNode, TessellatedSvgNode};
     use crate::vec::{GLuintVec, StringVec, StyleTransformVec};
     use crate::option::OptionU8VecRef;
+    use crate::str::String;
     /// `Texture` struct
     
     #[doc(inline)] pub use crate::dll::AzTexture as Texture;
@@ -17258,6 +19730,8 @@ pub mod gl {
 
         /// Calls the `Gl::get_type` function.
         pub fn get_type(&self)  -> crate::gl::GlType { unsafe { crate::dll::AzGl_getType(self) } }
+        /// Returns the driver workarounds that were applied to the context
+        pub fn get_quirks(&self)  -> crate::gl::GlQuirks { unsafe { crate::dll::AzGl_getQuirks(self) } }
         /// Calls the `Gl::buffer_data_untyped` function.
         pub fn buffer_data_untyped<_3: Into<GlVoidPtrConst>>(&self, target: u32, size: isize, data: _3, usage: u32)  { unsafe { crate::dll::AzGl_bufferDataUntyped(self, target, size, data.into(), usage) } }
         /// Calls the `Gl::buffer_sub_data_untyped` function.
@@ -17702,6 +20176,28 @@ pub mod gl {
 
     impl Clone for Gl { fn clone(&self) -> Self { unsafe { crate::dll::AzGl_deepCopy(self) } } }
     impl Drop for Gl { fn drop(&mut self) { if self.run_destructor { unsafe { crate::dll::AzGl_delete(self) } } } }
+    /// Workarounds for driver bugs, applied when the renderer is created
+    
+    #[doc(inline)] pub use crate::dll::AzGlQuirks as GlQuirks;
+    /// Enables / disables workarounds for all drivers that match the rule. The patterns are matched case-insensitively against substrings of the `GL_VENDOR`, `GL_RENDERER` and `GL_VERSION` strings, a `None` pattern matches every driver. The `version` pattern can also compare version numbers: `"Mesa < 21.2"` matches if `GL_VERSION` contains "Mesa" and the number after it is lower than 21.2, without a prefix (`">= 4.5"`) the first number of `GL_VERSION` is compared. Supported operators are `<`, `<=`, `>`, `>=` and `==`.
+    
+    #[doc(inline)] pub use crate::dll::AzGlQuirkRule as GlQuirkRule;
+    impl GlQuirkRule {
+
+        /// Creates a rule that matches every driver and doesn't change anything
+        pub fn new() -> Self { unsafe { crate::dll::AzGlQuirkRule_new() } }
+        /// Calls the `GlQuirkRule::with_vendor` function.
+        pub fn with_vendor<_1: Into<String>>(self, vendor: _1)  -> crate::gl::GlQuirkRule { unsafe { crate::dll::AzGlQuirkRule_withVendor(self, vendor.into()) } }
+        /// Calls the `GlQuirkRule::with_renderer` function.
+        pub fn with_renderer<_1: Into<String>>(self, renderer: _1)  -> crate::gl::GlQuirkRule { unsafe { crate::dll::AzGlQuirkRule_withRenderer(self, renderer.into()) } }
+        /// Calls the `GlQuirkRule::with_version` function.
+        pub fn with_version<_1: Into<String>>(self, version: _1)  -> crate::gl::GlQuirkRule { unsafe { crate::dll::AzGlQuirkRule_withVersion(self, version.into()) } }
+        /// Calls the `GlQuirkRule::with_enable` function.
+        pub fn with_enable<_1: Into<GlQuirks>>(self, enable: _1)  -> crate::gl::GlQuirkRule { unsafe { crate::dll::AzGlQuirkRule_withEnable(self, enable.into()) } }
+        /// Calls the `GlQuirkRule::with_disable` function.
+        pub fn with_disable<_1: Into<GlQuirks>>(self, disable: _1)  -> crate::gl::GlQuirkRule { unsafe { crate::dll::AzGlQuirkRule_withDisable(self, disable.into()) } }
+    }
+
     /// `GlShaderPrecisionFormatReturn` struct
     
     #[doc(inline)] pub use crate::dll::AzGlShaderPrecisionFormatReturn as GlShaderPrecisionFormatReturn;
@@ -18643,6 +21139,19 @@ pub mod task {
 
     impl Clone for ThreadReceiver { fn clone(&self) -> Self { unsafe { crate::dll::AzThreadReceiver_deepCopy(self) } } }
     impl Drop for ThreadReceiver { fn drop(&mut self) { if self.run_destructor { unsafe { crate::dll::AzThreadReceiver_delete(self) } } } }
+    /// Handle to wake up the event loop from other threads, created by `App::create_event_loop_proxy()`. NOTE: user events are currently only delivered on Windows, the X11 event loop can't invoke callbacks or run timers yet and rejects them.
+    
+    #[doc(inline)] pub use crate::dll::AzEventLoopProxy as EventLoopProxy;
+    impl EventLoopProxy {
+
+        /// Wakes up the event loop. Returns `false` if the event loop is not running yet (it will then wake up once it starts).
+        pub fn wake(&self)  -> bool { unsafe { crate::dll::AzEventLoopProxy_wake(self) } }
+        /// Sends the data to all windows, which invoke their `On::UserEvent` callbacks (see `CallbackInfo::get_user_event()`). Returns `false` and drops the data if the event loop can't deliver user events (currently on X11).
+        pub fn send_user_event<_1: Into<RefAny>>(&self, data: _1)  -> bool { unsafe { crate::dll::AzEventLoopProxy_sendUserEvent(self, data.into()) } }
+    }
+
+    impl Clone for EventLoopProxy { fn clone(&self) -> Self { unsafe { crate::dll::AzEventLoopProxy_deepCopy(self) } } }
+    impl Drop for EventLoopProxy { fn drop(&mut self) { if self.run_destructor { unsafe { crate::dll::AzEventLoopProxy_delete(self) } } } }
     /// `ThreadSendMsg` struct
     
     #[doc(inline)] pub use crate::dll::AzThreadSendMsg as ThreadSendMsg;
@@ -18664,6 +21173,30 @@ pub mod task {
     /// Get the current system time, equivalent to `std::time::Instant::now()`, except it also works on systems that work with "ticks" instead of timers
     
     #[doc(inline)] pub use crate::dll::AzGetSystemTimeFn as GetSystemTimeFn;
+    /// `GetSystemIdleTimeFnType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzGetSystemIdleTimeFnType as GetSystemIdleTimeFnType;
+    /// Get the time since the last user input on the entire system, `None` if the platform does not support querying the idle time
+    
+    #[doc(inline)] pub use crate::dll::AzGetSystemIdleTimeFn as GetSystemIdleTimeFn;
+    /// `GetLogLinesFnType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzGetLogLinesFnType as GetLogLinesFnType;
+    /// Returns the most recent lines of the log ring buffer (oldest first)
+    
+    #[doc(inline)] pub use crate::dll::AzGetLogLinesFn as GetLogLinesFn;
+    /// `KeycodeToCharFnType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzKeycodeToCharFnType as KeycodeToCharFnType;
+    /// Returns the character that the key produces with the given modifiers in the current keyboard layout of the operating system
+    
+    #[doc(inline)] pub use crate::dll::AzKeycodeToCharFn as KeycodeToCharFn;
+    /// `SetLogLevelFnType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzSetLogLevelFnType as SetLogLevelFnType;
+    /// Sets the log level of a subsystem at runtime
+    
+    #[doc(inline)] pub use crate::dll::AzSetLogLevelFn as SetLogLevelFn;
     /// Callback that checks whether the thread has finished - the input argument is the `dropcheck` field on the Thread.
     
     #[doc(inline)] pub use crate::dll::AzCheckThreadFinishedFnType as CheckThreadFinishedFnType;
@@ -19107,6 +21640,8 @@ pub mod vec {
     impl_vec_clone!(AzFmtArg,  AzFmtArgVec,  AzFmtArgVecDestructor);
     impl_vec!(AzInlineTextHit,  AzInlineTextHitVec,  AzInlineTextHitVecDestructor, az_inline_text_hit_vec_destructor, AzInlineTextHitVec_delete);
     impl_vec_clone!(AzInlineTextHit,  AzInlineTextHitVec,  AzInlineTextHitVecDestructor);
+    impl_vec!(AzTextUnderline,  AzTextUnderlineVec,  AzTextUnderlineVecDestructor, az_text_underline_vec_destructor, AzTextUnderlineVec_delete);
+    impl_vec_clone!(AzTextUnderline,  AzTextUnderlineVec,  AzTextUnderlineVecDestructor);
     impl_vec!(AzTessellatedSvgNode,  AzTessellatedSvgNodeVec,  AzTessellatedSvgNodeVecDestructor, az_tesselated_svg_node_vec_destructor, AzTessellatedSvgNodeVec_delete);
     impl_vec_clone!(AzTessellatedSvgNode,  AzTessellatedSvgNodeVec,  AzTessellatedSvgNodeVecDestructor);
     impl_vec!(AzNodeDataInlineCssProperty, AzNodeDataInlineCssPropertyVec, NodeDataInlineCssPropertyVecDestructor, az_node_data_inline_css_property_vec_destructor, AzNodeDataInlineCssPropertyVec_delete);
@@ -19317,15 +21852,24 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `Vec<InlineWord>`
     
     #[doc(inline)] pub use crate::dll::AzInlineWordVec as InlineWordVec;
+    /// Wrapper over a Rust-allocated `Vec<PaintPrimitive>`
+    
+    #[doc(inline)] pub use crate::dll::AzPaintPrimitiveVec as PaintPrimitiveVec;
     /// Wrapper over a Rust-allocated `Vec<InlineGlyph>`
     
     #[doc(inline)] pub use crate::dll::AzInlineGlyphVec as InlineGlyphVec;
+    /// Wrapper over a Rust-allocated `Vec<TextUnderline>`
+    
+    #[doc(inline)] pub use crate::dll::AzTextUnderlineVec as TextUnderlineVec;
     /// Wrapper over a Rust-allocated `Vec<InlineTextHit>`
     
     #[doc(inline)] pub use crate::dll::AzInlineTextHitVec as InlineTextHitVec;
     /// Wrapper over a Rust-allocated `Vec<Monitor>`
     
     #[doc(inline)] pub use crate::dll::AzMonitorVec as MonitorVec;
+    /// Wrapper over a Rust-allocated `Vec<TouchPoint>`
+    
+    #[doc(inline)] pub use crate::dll::AzTouchPointVec as TouchPointVec;
     /// Wrapper over a Rust-allocated `Vec<VideoMode>`
     
     #[doc(inline)] pub use crate::dll::AzVideoModeVec as VideoModeVec;
@@ -19386,6 +21930,12 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `VirtualKeyCode`
     
     #[doc(inline)] pub use crate::dll::AzVirtualKeyCodeVec as VirtualKeyCodeVec;
+    /// Wrapper over a Rust-allocated `ShortcutConflict`
+    
+    #[doc(inline)] pub use crate::dll::AzShortcutConflictVec as ShortcutConflictVec;
+    /// Wrapper over a Rust-allocated `Shortcut`
+    
+    #[doc(inline)] pub use crate::dll::AzShortcutVec as ShortcutVec;
     /// Wrapper over a Rust-allocated `CascadeInfo`
     
     #[doc(inline)] pub use crate::dll::AzCascadeInfoVec as CascadeInfoVec;
@@ -19589,12 +22139,24 @@ pub mod vec {
     /// `InlineWordVecDestructorType` struct
     
     #[doc(inline)] pub use crate::dll::AzInlineWordVecDestructorType as InlineWordVecDestructorType;
+    /// `PaintPrimitiveVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzPaintPrimitiveVecDestructor as PaintPrimitiveVecDestructor;
+    /// `PaintPrimitiveVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzPaintPrimitiveVecDestructorType as PaintPrimitiveVecDestructorType;
     /// `InlineGlyphVecDestructor` struct
     
     #[doc(inline)] pub use crate::dll::AzInlineGlyphVecDestructor as InlineGlyphVecDestructor;
     /// `InlineGlyphVecDestructorType` struct
     
     #[doc(inline)] pub use crate::dll::AzInlineGlyphVecDestructorType as InlineGlyphVecDestructorType;
+    /// `TextUnderlineVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTextUnderlineVecDestructor as TextUnderlineVecDestructor;
+    /// `TextUnderlineVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTextUnderlineVecDestructorType as TextUnderlineVecDestructorType;
     /// `InlineTextHitVecDestructor` struct
     
     #[doc(inline)] pub use crate::dll::AzInlineTextHitVecDestructor as InlineTextHitVecDestructor;
@@ -19607,6 +22169,12 @@ pub mod vec {
     /// `MonitorVecDestructorType` struct
     
     #[doc(inline)] pub use crate::dll::AzMonitorVecDestructorType as MonitorVecDestructorType;
+    /// `TouchPointVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTouchPointVecDestructor as TouchPointVecDestructor;
+    /// `TouchPointVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzTouchPointVecDestructorType as TouchPointVecDestructorType;
     /// `VideoModeVecDestructor` struct
     
     #[doc(inline)] pub use crate::dll::AzVideoModeVecDestructor as VideoModeVecDestructor;
@@ -19727,6 +22295,18 @@ pub mod vec {
     /// `VirtualKeyCodeVecDestructorType` struct
     
     #[doc(inline)] pub use crate::dll::AzVirtualKeyCodeVecDestructorType as VirtualKeyCodeVecDestructorType;
+    /// `ShortcutConflictVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzShortcutConflictVecDestructor as ShortcutConflictVecDestructor;
+    /// `ShortcutConflictVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzShortcutConflictVecDestructorType as ShortcutConflictVecDestructorType;
+    /// `ShortcutVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzShortcutVecDestructor as ShortcutVecDestructor;
+    /// `ShortcutVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzShortcutVecDestructorType as ShortcutVecDestructorType;
     /// `CascadeInfoVecDestructor` struct
     
     #[doc(inline)] pub use crate::dll::AzCascadeInfoVecDestructor as CascadeInfoVecDestructor;
@@ -20081,6 +22661,9 @@ pub mod option {
     /// `OptionDropDownOnChoiceChange` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionDropDownOnChoiceChange as OptionDropDownOnChoiceChange;
+    /// `OptionToastOnAction` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionToastOnAction as OptionToastOnAction;
     /// `OptionResolvedTextLayoutOptions` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionResolvedTextLayoutOptions as OptionResolvedTextLayoutOptions;
@@ -20132,6 +22715,9 @@ pub mod option {
     /// `OptionTextInputOnFocusLost` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionTextInputOnFocusLost as OptionTextInputOnFocusLost;
+    /// `OptionTextInputOnSpellingSuggestions` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionTextInputOnSpellingSuggestions as OptionTextInputOnSpellingSuggestions;
     /// `OptionTextInputSelection` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionTextInputSelection as OptionTextInputSelection;
@@ -20150,12 +22736,21 @@ pub mod option {
     /// `OptionVirtualKeyCodeCombo` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionVirtualKeyCodeCombo as OptionVirtualKeyCodeCombo;
+    /// `OptionShortcutConflict` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionShortcutConflict as OptionShortcutConflict;
     /// `OptionCssProperty` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionCssProperty as OptionCssProperty;
     /// `OptionPositionInfo` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionPositionInfo as OptionPositionInfo;
+    /// `OptionNodeRect` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionNodeRect as OptionNodeRect;
+    /// `OptionScrollInfo` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionScrollInfo as OptionScrollInfo;
     /// `OptionTimerId` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionTimerId as OptionTimerId;
@@ -20216,6 +22811,21 @@ pub mod option {
     /// `OptionCallback` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionCallback as OptionCallback;
+    /// `OptionPaintCallback` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionPaintCallback as OptionPaintCallback;
+    /// `OptionJankCapture` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionJankCapture as OptionJankCapture;
+    /// `OptionEventRecording` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionEventRecording as OptionEventRecording;
+    /// `OptionFrameRecording` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionFrameRecording as OptionFrameRecording;
+    /// `OptionCrashHandler` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionCrashHandler as OptionCrashHandler;
     /// `OptionThreadSendMsg` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionThreadSendMsg as OptionThreadSendMsg;
@@ -20228,6 +22838,9 @@ pub mod option {
     /// `OptionInlineText` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionInlineText as OptionInlineText;
+    /// `OptionTextLayout` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionTextLayout as OptionTextLayout;
     /// `OptionLayoutPoint` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionLayoutPoint as OptionLayoutPoint;
@@ -20258,6 +22871,9 @@ pub mod option {
     /// `OptionTaskBarIcon` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionTaskBarIcon as OptionTaskBarIcon;
+    /// `OptionRawWindowHandle` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionRawWindowHandle as OptionRawWindowHandle;
     /// `OptionHwndHandle` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionHwndHandle as OptionHwndHandle;
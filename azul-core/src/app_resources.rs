@@ -10,10 +10,10 @@ use crate::{
     styled_dom::{
         DomId, NodeHierarchyItemId, StyleFontFamiliesHash, StyleFontFamilyHash, StyledDom,
     },
-    task::ExternalSystemCallbacks,
+    task::{Duration, ExternalSystemCallbacks, OptionDuration, SystemTimeDiff},
     ui_solver::LayoutResult,
     ui_solver::{InlineTextLayout, InlineTextLine, ResolvedTextLayoutOptions},
-    window::{LogicalPosition, LogicalRect, LogicalSize, OptionChar},
+    window::{LogicalPosition, LogicalRect, LogicalSize, OptionChar, RawWindowHandle},
     FastBTreeSet, FastHashMap,
 };
 use alloc::boxed::Box;
@@ -22,8 +22,9 @@ use alloc::string::String;
 use alloc::vec::Vec;
 pub use azul_css::FontMetrics;
 use azul_css::{
-    AzString, ColorU, F32Vec, FontRef, LayoutRect, LayoutSize, OptionI32, StyleFontFamily,
-    StyleFontFamilyVec, StyleFontSize, U16Vec, U32Vec, U8Vec, FloatValue,
+    AzString, ColorU, F32Vec, FontRef, LayoutRect, LayoutSize, OptionAzString, OptionI32,
+    StringVec, StyleFontFamily, StyleFontFamilyVec, StyleFontSize, U16Vec, U32Vec, U8Vec,
+    FloatValue,
 };
 use core::{
     fmt,
@@ -60,6 +61,33 @@ pub struct AppConfig {
     pub enable_tab_navigation: bool,
     /// External callbacks to create a thread or get the curent time
     pub system_callbacks: ExternalSystemCallbacks,
+    /// If set, the system idle time is polled and the windows receive an `On::UserIdle`
+    /// event once the user has not touched the mouse / keyboard for longer than this
+    /// duration (and `On::UserActive` once the user returns). The timeout applies to all
+    /// `On::UserIdle` callbacks of the application. Default: `None` (disabled)
+    pub user_idle_timeout: OptionDuration,
+    /// If set, this function is called when the application panics or crashes
+    /// (segfault, illegal instruction, unhandled SEH exception), i.e. to offer
+    /// a "send crash report" dialog. Only active if logging is enabled.
+    pub crash_handler: OptionCrashHandler,
+    /// If set, frames that take longer than the configured threshold are captured
+    /// (timings of the layout / restyle / display list / render phases and optionally
+    /// a dump of the display list) and written to a file for post-mortem analysis.
+    /// Default: `None` (disabled)
+    pub jank_capture: OptionJankCapture,
+    /// If set, all input events of all windows are recorded to a file (or replayed
+    /// from a previously recorded file), i.e. to reproduce user-reported bugs.
+    /// Default: `None` (disabled)
+    pub event_recording: OptionEventRecording,
+    /// If set, the presented frames of all windows are recorded (as animated GIFs
+    /// or passed to a callback), i.e. to attach a recording of a bug to a report.
+    /// Default: `None` (disabled)
+    pub frame_recording: OptionFrameRecording,
+    /// How long the mouse cursor has to rest over a node before the node receives
+    /// an `On::HoverIntent` event (i.e. to show tooltips). Moving the cursor restarts
+    /// the delay, leaving the node cancels it. `None` disables the event.
+    /// Default: 500ms
+    pub hover_intent_delay: OptionDuration,
 }
 
 impl AppConfig {
@@ -71,10 +99,213 @@ impl AppConfig {
             enable_logging_on_panic: true,
             enable_tab_navigation: true,
             system_callbacks: ExternalSystemCallbacks::rust_internal(),
+            user_idle_timeout: OptionDuration::None,
+            crash_handler: OptionCrashHandler::None,
+            jank_capture: OptionJankCapture::None,
+            event_recording: OptionEventRecording::None,
+            frame_recording: OptionFrameRecording::None,
+            hover_intent_delay: OptionDuration::Some(Duration::System(SystemTimeDiff::from_millis(500))),
         }
     }
 }
 
+/// Configuration of the jank detector, see `AppConfig::jank_capture`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C)]
+pub struct JankCapture {
+    /// Frames that take longer than this duration (from the first layout / restyle
+    /// of the frame until the frame is presented) are captured
+    pub threshold: Duration,
+    /// Whether the display list of the slow frame should be written to the report
+    /// (and as a binary `.azdl` file, see `CachedDisplayList::from_bytes`)
+    pub dump_display_list: bool,
+    /// Directory that the reports are written to (default: temporary directory)
+    pub output_directory: OptionAzString,
+}
+
+impl JankCapture {
+    pub fn new(threshold: Duration) -> Self {
+        Self {
+            threshold,
+            dump_display_list: false,
+            output_directory: OptionAzString::None,
+        }
+    }
+}
+
+impl_option!(
+    JankCapture,
+    OptionJankCapture,
+    copy = false,
+    [Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash]
+);
+
+/// Startup splash screen (logo + progress bar), shown while the font cache is
+/// built and the first layout of the windows runs, see `App::with_splash()`
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct SplashOptions {
+    /// Logo, centered above the progress bar and scaled down to fit if necessary
+    pub logo: OptionImageRef,
+    /// Size of the splash window in logical pixels, centered on the primary monitor
+    pub size: LogicalSize,
+    /// Background color of the splash window
+    pub background_color: ColorU,
+    /// Color of the progress bar
+    pub progress_bar_color: ColorU,
+    /// Duration of the cross-fade from the splash window into the main window,
+    /// started once the main window has presented its first frame
+    pub cross_fade: Duration,
+}
+
+impl SplashOptions {
+    pub fn new() -> Self {
+        Self {
+            logo: OptionImageRef::None,
+            size: LogicalSize::new(480.0, 300.0),
+            background_color: ColorU { r: 255, g: 255, b: 255, a: 255 },
+            progress_bar_color: ColorU { r: 0, g: 120, b: 215, a: 255 },
+            cross_fade: Duration::System(SystemTimeDiff::from_millis(200)),
+        }
+    }
+
+    /// Sets the logo of the splash window
+    pub fn with_logo(self, logo: ImageRef) -> Self {
+        Self {
+            logo: OptionImageRef::Some(logo),
+            ..self
+        }
+    }
+}
+
+impl Default for SplashOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether input events are recorded or replayed, see `AppConfig::event_recording`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C, u8)]
+pub enum EventRecording {
+    /// Records all mouse / keyboard / focus / resize events (with timestamps)
+    /// to the file at the given path, the file is overwritten
+    Record(AzString),
+    /// Replays the events of a file that was recorded with `EventRecording::Record`,
+    /// with the same timing as the original events
+    Replay(AzString),
+}
+
+impl_option!(
+    EventRecording,
+    OptionEventRecording,
+    copy = false,
+    [Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash]
+);
+
+/// Recording of the presented frames of all windows, see `AppConfig::frame_recording`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C)]
+pub struct FrameRecording {
+    /// Where the recorded frames are written to
+    pub output: FrameRecordingOutput,
+    /// Maximum number of recorded frames per second: if a window presents frames
+    /// faster than that, only the last frame of each interval is recorded
+    pub max_fps: u32,
+}
+
+impl FrameRecording {
+    pub fn new(output: FrameRecordingOutput) -> Self {
+        Self {
+            output,
+            max_fps: 30,
+        }
+    }
+}
+
+impl_option!(
+    FrameRecording,
+    OptionFrameRecording,
+    copy = false,
+    [Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash]
+);
+
+/// Where the frames of a `FrameRecording` are written to
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C, u8)]
+pub enum FrameRecordingOutput {
+    /// Writes one animated GIF per window into the directory at the given path
+    /// (requires the `gif` feature), the GIF is finished when the window is closed
+    GifDirectory(AzString),
+    /// Invokes the callback with every recorded frame
+    Callback(FrameRecordingCallback),
+}
+
+/// A frame that was presented by a window, passed to the `FrameRecordingCallback`
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct RecordedFrame {
+    /// Window that presented the frame
+    pub window_handle: RawWindowHandle,
+    /// Index of the frame in the recording of the window, starts at 0
+    pub frame_index: usize,
+    /// Time since the recording of the window started, at which the frame was presented
+    pub timestamp: Duration,
+    /// How long the frame was visible until the next recorded frame replaced it
+    pub duration: Duration,
+    /// Contents of the window (RGBA8, without the window frame)
+    pub image: ImageRef,
+}
+
+/// Function that is invoked with every recorded frame - frames are passed to the
+/// callback once the next frame has been presented (or the window is closed),
+/// since the duration of a frame is only known at that point
+pub type FrameRecordingCallbackType = extern "C" fn(&RecordedFrame);
+
+#[repr(C)]
+pub struct FrameRecordingCallback {
+    pub cb: FrameRecordingCallbackType,
+}
+
+impl_callback!(FrameRecordingCallback);
+
+/// Information about a panic or crash, passed to the `CrashHandler`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C)]
+pub struct CrashInfo {
+    /// Panic message or description of the signal / exception
+    pub message: AzString,
+    /// Source location of the panic, empty for crashes caused by signals
+    pub location: AzString,
+    /// Name of the thread that crashed
+    pub thread_name: AzString,
+    /// Symbolized backtrace of the crashed thread
+    pub backtrace: AzString,
+    /// The last lines that were logged before the crash (oldest first)
+    pub last_log_lines: StringVec,
+    /// Short summary of the state of all open windows
+    pub window_state_summary: AzString,
+    /// Path to the minidump file, if one could be written (currently Windows-only)
+    pub minidump_path: OptionAzString,
+}
+
+/// Function that is invoked when the app panics or crashes - note that the process
+/// is in an undefined state, the handler should do as little work as possible
+pub type CrashHandlerCallbackType = extern "C" fn(&CrashInfo);
+
+#[repr(C)]
+pub struct CrashHandler {
+    pub cb: CrashHandlerCallbackType,
+}
+
+impl_callback!(CrashHandler);
+
+impl_option!(
+    CrashHandler,
+    OptionCrashHandler,
+    [Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash]
+);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub enum LayoutSolverVersion {
@@ -1981,6 +2212,27 @@ impl Words {
     }
 }
 
+/// Words that were re-split after the text of a node was edited: the words before
+/// and after the edited paragraphs are the same as in the old text (only their indices
+/// are shifted), so only the words in between have to be re-shaped and re-positioned
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct WordsChange {
+    /// Number of unchanged words at the start of the text
+    pub prefix_words: usize,
+    /// Number of unchanged words at the end of the text
+    pub suffix_words: usize,
+    /// Number of words in the old text
+    pub old_words: usize,
+}
+
+impl WordsChange {
+    /// All words have changed (i.e. the text was split from scratch)
+    pub fn all(old_words: usize) -> Self {
+        Self { prefix_words: 0, suffix_words: 0, old_words }
+    }
+}
+
 /// Section of a certain type
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -2011,6 +2263,9 @@ pub enum WordType {
     Return,
     /// Space character
     Space,
+    /// Hyphenation point between two parts of a word: empty (or the soft hyphen
+    /// character U+00AD), a hyphen is only displayed if the line is broken here
+    SoftHyphen,
 }
 
 /// A paragraph of words that are shaped and scaled (* but not yet layouted / positioned*!)
@@ -2239,7 +2494,8 @@ pub fn get_inline_text(
                 .filter_map(|(word_idx, word)| {
                     let word_idx = word_start + word_idx;
                     match word.word_type {
-                        WordType::Word => {
+                        // hidden soft hyphens don't have a shaped word
+                        WordType::Word | WordType::SoftHyphen => {
                             let word_position = word_positions.word_positions.get(word_idx)?;
                             let shaped_word_index = word_position.shaped_word_index?;
                             let shaped_word = shaped_words.items.get(shaped_word_index)?;
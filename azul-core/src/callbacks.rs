@@ -3,26 +3,28 @@
 use crate::gl::OptionGlContextPtr;
 use crate::{
     app_resources::{
-        FontInstanceKey, IdNamespace, ImageCache, ImageMask, ImageRef, LayoutedGlyphs,
+        AppLogLevel, FontInstanceKey, IdNamespace, ImageCache, ImageMask, ImageRef, LayoutedGlyphs,
         RendererResources, ShapedWords, WordPositions, Words,
     },
+    dom::{FocusDirection, IdOrClass, IdOrClassVec, NodeStateFlag},
     id_tree::{NodeDataContainer, NodeId},
     styled_dom::{CssPropertyCache, StyledDom, StyledNode},
     styled_dom::{DomId, NodeHierarchyItemId, NodeHierarchyItemVec, StyledNodeVec},
     task::{
-        CreateThreadCallback, Duration as AzDuration, ExternalSystemCallbacks,
-        GetSystemTimeCallback, Instant as AzInstant, Instant, TerminateTimer, Thread, ThreadId,
-        ThreadReceiver, ThreadSendMsg, ThreadSender, Timer, TimerId,
+        AnimationFrameCallback, CreateThreadCallback, Duration as AzDuration, ExternalSystemCallbacks,
+        GetSystemTimeCallback, Instant as AzInstant, Instant, OptionDuration, TerminateTimer,
+        Thread, ThreadId, ThreadReceiver, ThreadSendMsg, ThreadSender, Timer, TimerId,
     },
     ui_solver::{
-        LayoutResult, OverflowingScrollNode, PositionInfo, PositionedRectangle,
+        LayoutResult, NodeRect, OverflowingScrollNode, PositionInfo, PositionedRectangle,
         ResolvedTextLayoutOptions, TextLayoutOptions,
     },
     window::{AzStringPair, OptionLogicalPosition},
     window::{
-        FullWindowState, KeyboardState, LogicalPosition, LogicalRect, LogicalSize, MouseState,
-        OptionChar, PhysicalSize, RawWindowHandle, UpdateFocusWarning, WindowCreateOptions,
-        WindowFlags, WindowSize, WindowState, WindowTheme,
+        Announcement, FullWindowState, HapticKind, KeyboardModifiers, KeyboardState, LogicalPosition, LogicalRect, LogicalSize, MouseState,
+        OptionChar, OptionShortcutConflict, PhysicalSize, Politeness, RawWindowHandle, Shortcut,
+        ShortcutConflictVec, ShortcutMap, UpdateFocusWarning, VirtualKeyCode, VirtualKeyCodeCombo,
+        WindowCreateOptions, WindowFlags, WindowSize, WindowState, WindowTheme,
     },
     FastBTreeSet, FastHashMap,
 };
@@ -31,8 +33,9 @@ use alloc::boxed::Box;
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 use azul_css::{
-    AnimationInterpolationFunction, AzString, CssPath, CssProperty, CssPropertyType, FontRef,
-    InterpolateResolver, LayoutRect, LayoutSize,
+    AnimationInterpolationFunction, AzString, ColorU, CssPath, CssPathSelector, CssProperty,
+    CssPropertyType, FontRef, StyleCaretAnimationDuration, StyleOpacity,
+    InterpolateResolver, LayoutRect, LayoutSize, StringVec, SystemStyle,
 };
 use core::{
     ffi::c_void,
@@ -544,6 +547,26 @@ pub struct ScrollPosition {
     pub children_rect: LogicalRect,
 }
 
+/// Scroll extents of a scrollable (overflowing) node
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct ScrollInfo {
+    /// Current scroll offset of the content
+    pub scroll_position: LogicalPosition,
+    /// Visible area of the node (relative to the window origin)
+    pub viewport: LogicalRect,
+    /// Size of the union of all children
+    pub content_size: LogicalSize,
+    /// Maximum scroll offset on each axis (`content_size - viewport.size`, clamped to 0)
+    pub max_scroll: LogicalSize,
+}
+
+impl_option!(
+    ScrollInfo,
+    OptionScrollInfo,
+    [Debug, Copy, Clone, PartialEq, PartialOrd]
+);
+
 #[derive(Copy, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct DocumentId {
     pub namespace_id: IdNamespace,
@@ -868,6 +891,24 @@ impl_option!(
     [Debug, Clone, PartialEq, PartialOrd]
 );
 
+/// Laid out text of a `Text` node, together with the rect it is positioned in
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TextLayout {
+    /// Content box of the text node, relative to the window origin -
+    /// the lines of the `inline_text` are relative to the origin of this rect
+    pub bounds: LogicalRect,
+    /// Shaped and positioned lines / words / glyphs
+    pub inline_text: InlineText,
+}
+
+impl_option!(
+    TextLayout,
+    OptionTextLayout,
+    copy = false,
+    [Debug, Clone, PartialEq, PartialOrd]
+);
+
 impl InlineText {
     /// Returns the final, positioned glyphs from an inline text
     ///
@@ -924,6 +965,57 @@ impl InlineText {
         }
     }
 
+    /// Returns the area below the characters `start..end` (one rect per line), in the
+    /// same coordinate space as the glyphs of `get_layouted_glyphs()`. Spaces, tabs and
+    /// line breaks count as one character each. The underline is drawn in the top third
+    /// of the rect, wavy underlines use the entire height.
+    pub fn get_underline_rects(&self, start: usize, end: usize) -> Vec<LogicalRect> {
+        let line_thickness = (self.font_size_px / 14.0).max(1.0);
+
+        let mut rects = Vec::new();
+        let mut char_index = 0;
+
+        for line in self.lines.iter() {
+            // descender_px is NEGATIVE
+            let baseline_y = line.bounds.origin.y + self.baseline_descender_px;
+            let mut underlined_x: Option<(f32, f32)> = None;
+
+            for word in line.words.iter() {
+                let text_contents = match word {
+                    InlineWord::Tab | InlineWord::Return | InlineWord::Space => {
+                        char_index += 1;
+                        continue;
+                    }
+                    InlineWord::Word(text_contents) => text_contents,
+                };
+
+                for glyph in text_contents.glyphs.iter() {
+                    if !glyph.has_codepoint() {
+                        continue;
+                    }
+                    if char_index >= start && char_index < end {
+                        let x_start = text_contents.bounds.origin.x + glyph.bounds.origin.x;
+                        let x_end = x_start + glyph.bounds.size.width;
+                        underlined_x = Some(match underlined_x {
+                            Some((s, e)) => (s.min(x_start), e.max(x_end)),
+                            None => (x_start, x_end),
+                        });
+                    }
+                    char_index += 1;
+                }
+            }
+
+            if let Some((x_start, x_end)) = underlined_x {
+                rects.push(LogicalRect::new(
+                    LogicalPosition::new(line.bounds.origin.x + x_start, baseline_y + line_thickness),
+                    LogicalSize::new(x_end - x_start, line_thickness * 3.0),
+                ));
+            }
+        }
+
+        rects
+    }
+
     /// Hit tests all glyphs, returns the hit glyphs - note that the result may
     /// be empty (no glyphs hit), or it may contain more than one result
     /// (overlapping glyphs - more than one glyph hit)
@@ -1113,6 +1205,34 @@ impl_vec_debug!(InlineGlyph, InlineGlyphVec);
 impl_vec_partialeq!(InlineGlyph, InlineGlyphVec);
 impl_vec_partialord!(InlineGlyph, InlineGlyphVec);
 
+/// Line style of a `TextUnderline`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum TextUnderlineStyle {
+    Solid,
+    Dotted,
+    /// Used for spelling errors
+    Wavy,
+}
+
+/// Underlines a range of characters of a text node, see `CallbackInfo::set_text_underlines`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct TextUnderline {
+    /// Index of the first underlined character (counted in `char`s, not in bytes)
+    pub start: usize,
+    /// Index of the character after the last underlined character
+    pub end: usize,
+    pub color: ColorU,
+    pub style: TextUnderlineStyle,
+}
+
+impl_vec!(TextUnderline, TextUnderlineVec, TextUnderlineVecDestructor);
+impl_vec_clone!(TextUnderline, TextUnderlineVec, TextUnderlineVecDestructor);
+impl_vec_debug!(TextUnderline, TextUnderlineVec);
+impl_vec_partialeq!(TextUnderline, TextUnderlineVec);
+impl_vec_partialord!(TextUnderline, TextUnderlineVec);
+
 /// Information about the callback that is passed to the callback whenever a callback is invoked
 #[derive(Debug)]
 #[repr(C)]
@@ -1147,6 +1267,20 @@ pub struct CallbackInfo {
     current_window_handle: *const RawWindowHandle,
     /// Used to spawn new windows from callbacks. You can use `get_current_window_handle()` to spawn child windows.
     new_windows: *mut Vec<WindowCreateOptions>,
+    /// Window-level keyboard shortcuts, can be modified at runtime
+    shortcuts: *mut ShortcutMap,
+    /// Window-local data, see `WindowCreateOptions::window_data`
+    window_data: *mut OptionRefAny,
+    /// Texts that should be spoken by the screen reader
+    announcements: *mut Vec<Announcement>,
+    /// Tactile feedback that should be played after the callback returns
+    haptic_feedback: *mut Vec<HapticKind>,
+    /// Callbacks that should run right before the next frame is rendered
+    animation_frame_callbacks: *mut Vec<AnimationFrameCallback>,
+    /// Subtrees that should be cross-faded after the next DOM update
+    crossfades: *mut Vec<SubtreeCrossfade>,
+    /// Subtrees that should be recorded into animated images
+    subtree_recordings: *mut Vec<SubtreeRecording>,
     /// Callbacks for creating threads and getting the system time (since this crate uses no_std)
     system_callbacks: *const ExternalSystemCallbacks,
     /// Sets whether the event should be propagated to the parent hit node or not
@@ -1156,6 +1290,8 @@ pub struct CallbackInfo {
     focus_target: *mut Option<FocusTarget>,
     /// Mutable reference to a list of words / text items that were changed in the callback
     words_changed_in_callbacks: *mut BTreeMap<DomId, BTreeMap<NodeId, AzString>>,
+    /// Mutable reference to the text underlines (i.e. spelling errors) that were changed in the callback
+    text_underlines_changed_in_callbacks: *mut BTreeMap<DomId, BTreeMap<NodeId, TextUnderlineVec>>,
     /// Mutable reference to a list of images that were changed in the callback
     images_changed_in_callbacks:
         *mut BTreeMap<DomId, BTreeMap<NodeId, (ImageRef, UpdateImageType)>>,
@@ -1163,6 +1299,10 @@ pub struct CallbackInfo {
     image_masks_changed_in_callbacks: *mut BTreeMap<DomId, BTreeMap<NodeId, ImageMask>>,
     /// Mutable reference to a list of CSS property changes, so that the callbacks can change CSS properties
     css_properties_changed_in_callbacks: *mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>,
+    /// Mutable reference to the new ids and classes of nodes, so that callbacks can add / remove classes
+    ids_and_classes_changed_in_callbacks: *mut BTreeMap<DomId, BTreeMap<NodeId, IdOrClassVec>>,
+    /// Mutable reference to the new :disabled / :checked / ... states of nodes
+    node_state_flags_changed_in_callbacks: *mut BTreeMap<DomId, BTreeMap<NodeId, BTreeMap<NodeStateFlag, bool>>>,
     /// Immutable (!) reference to where the nodes are currently scrolled (current position)
     current_scroll_states: *const BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, ScrollPosition>>,
     /// Mutable map where a user can set where he wants the nodes to be scrolled to (for the next frame)
@@ -1202,10 +1342,18 @@ impl CallbackInfo {
         threads_removed: &'a mut FastBTreeSet<ThreadId>,
         current_window_handle: &'a RawWindowHandle,
         new_windows: &'a mut Vec<WindowCreateOptions>,
+        shortcuts: &'a mut ShortcutMap,
+        window_data: &'a mut OptionRefAny,
+        announcements: &'a mut Vec<Announcement>,
+        haptic_feedback: &'a mut Vec<HapticKind>,
+        animation_frame_callbacks: &'a mut Vec<AnimationFrameCallback>,
+        crossfades: &'a mut Vec<SubtreeCrossfade>,
+        subtree_recordings: &'a mut Vec<SubtreeRecording>,
         system_callbacks: &'a ExternalSystemCallbacks,
         stop_propagation: &'a mut bool,
         focus_target: &'a mut Option<FocusTarget>,
         words_changed_in_callbacks: &'a mut BTreeMap<DomId, BTreeMap<NodeId, AzString>>,
+        text_underlines_changed_in_callbacks: &'a mut BTreeMap<DomId, BTreeMap<NodeId, TextUnderlineVec>>,
         images_changed_in_callbacks: &'a mut BTreeMap<
             DomId,
             BTreeMap<NodeId, (ImageRef, UpdateImageType)>,
@@ -1215,6 +1363,8 @@ impl CallbackInfo {
             DomId,
             BTreeMap<NodeId, Vec<CssProperty>>,
         >,
+        ids_and_classes_changed_in_callbacks: &'a mut BTreeMap<DomId, BTreeMap<NodeId, IdOrClassVec>>,
+        node_state_flags_changed_in_callbacks: &'a mut BTreeMap<DomId, BTreeMap<NodeId, BTreeMap<NodeStateFlag, bool>>>,
         current_scroll_states: &'a BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, ScrollPosition>>,
         nodes_scrolled_in_callback: &'a mut BTreeMap<
             DomId,
@@ -1239,18 +1389,31 @@ impl CallbackInfo {
             timers_removed: timers_removed as *mut FastBTreeSet<TimerId>,
             threads_removed: threads_removed as *mut FastBTreeSet<ThreadId>,
             new_windows: new_windows as *mut Vec<WindowCreateOptions>,
+            shortcuts: shortcuts as *mut ShortcutMap,
+            window_data: window_data as *mut OptionRefAny,
+            announcements: announcements as *mut Vec<Announcement>,
+            haptic_feedback: haptic_feedback as *mut Vec<HapticKind>,
+            animation_frame_callbacks: animation_frame_callbacks as *mut Vec<AnimationFrameCallback>,
+            crossfades: crossfades as *mut Vec<SubtreeCrossfade>,
+            subtree_recordings: subtree_recordings as *mut Vec<SubtreeRecording>,
             current_window_handle: current_window_handle as *const RawWindowHandle,
             system_callbacks: system_callbacks as *const ExternalSystemCallbacks,
             stop_propagation: stop_propagation as *mut bool,
             focus_target: focus_target as *mut Option<FocusTarget>,
             words_changed_in_callbacks: words_changed_in_callbacks
                 as *mut BTreeMap<DomId, BTreeMap<NodeId, AzString>>,
+            text_underlines_changed_in_callbacks: text_underlines_changed_in_callbacks
+                as *mut BTreeMap<DomId, BTreeMap<NodeId, TextUnderlineVec>>,
             images_changed_in_callbacks: images_changed_in_callbacks
                 as *mut BTreeMap<DomId, BTreeMap<NodeId, (ImageRef, UpdateImageType)>>,
             image_masks_changed_in_callbacks: image_masks_changed_in_callbacks
                 as *mut BTreeMap<DomId, BTreeMap<NodeId, ImageMask>>,
             css_properties_changed_in_callbacks: css_properties_changed_in_callbacks
                 as *mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>,
+            ids_and_classes_changed_in_callbacks: ids_and_classes_changed_in_callbacks
+                as *mut BTreeMap<DomId, BTreeMap<NodeId, IdOrClassVec>>,
+            node_state_flags_changed_in_callbacks: node_state_flags_changed_in_callbacks
+                as *mut BTreeMap<DomId, BTreeMap<NodeId, BTreeMap<NodeStateFlag, bool>>>,
             current_scroll_states: current_scroll_states
                 as *const BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, ScrollPosition>>,
             nodes_scrolled_in_callback: nodes_scrolled_in_callback
@@ -1305,6 +1468,33 @@ impl CallbackInfo {
     fn internal_get_new_windows<'a>(&'a mut self) -> &'a mut Vec<WindowCreateOptions> {
         unsafe { &mut *self.new_windows }
     }
+    fn internal_get_shortcuts<'a>(&'a mut self) -> &'a mut ShortcutMap {
+        unsafe { &mut *self.shortcuts }
+    }
+    fn internal_get_shortcuts_ref<'a>(&'a self) -> &'a ShortcutMap {
+        unsafe { &*self.shortcuts }
+    }
+    fn internal_get_window_data<'a>(&'a mut self) -> &'a mut OptionRefAny {
+        unsafe { &mut *self.window_data }
+    }
+    fn internal_get_window_data_ref<'a>(&'a self) -> &'a OptionRefAny {
+        unsafe { &*self.window_data }
+    }
+    fn internal_get_announcements<'a>(&'a mut self) -> &'a mut Vec<Announcement> {
+        unsafe { &mut *self.announcements }
+    }
+    fn internal_get_haptic_feedback<'a>(&'a mut self) -> &'a mut Vec<HapticKind> {
+        unsafe { &mut *self.haptic_feedback }
+    }
+    fn internal_get_animation_frame_callbacks<'a>(&'a mut self) -> &'a mut Vec<AnimationFrameCallback> {
+        unsafe { &mut *self.animation_frame_callbacks }
+    }
+    fn internal_get_crossfades<'a>(&'a mut self) -> &'a mut Vec<SubtreeCrossfade> {
+        unsafe { &mut *self.crossfades }
+    }
+    fn internal_get_subtree_recordings<'a>(&'a mut self) -> &'a mut Vec<SubtreeRecording> {
+        unsafe { &mut *self.subtree_recordings }
+    }
     fn internal_get_current_window_handle<'a>(&'a self) -> &'a RawWindowHandle {
         unsafe { &*self.current_window_handle }
     }
@@ -1327,6 +1517,16 @@ impl CallbackInfo {
     ) -> &'a mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>> {
         unsafe { &mut *self.css_properties_changed_in_callbacks }
     }
+    fn internal_get_ids_and_classes_changed_in_callbacks<'a>(
+        &'a mut self,
+    ) -> &'a mut BTreeMap<DomId, BTreeMap<NodeId, IdOrClassVec>> {
+        unsafe { &mut *self.ids_and_classes_changed_in_callbacks }
+    }
+    fn internal_get_node_state_flags_changed_in_callbacks<'a>(
+        &'a mut self,
+    ) -> &'a mut BTreeMap<DomId, BTreeMap<NodeId, BTreeMap<NodeStateFlag, bool>>> {
+        unsafe { &mut *self.node_state_flags_changed_in_callbacks }
+    }
     fn internal_get_nodes_scrolled_in_callback<'a>(
         &'a mut self,
     ) -> &'a mut BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, LogicalPosition>> {
@@ -1346,6 +1546,11 @@ impl CallbackInfo {
     ) -> &'a mut BTreeMap<DomId, BTreeMap<NodeId, AzString>> {
         unsafe { &mut *self.words_changed_in_callbacks }
     }
+    fn internal_get_text_underlines_changed_in_callbacks<'a>(
+        &'a mut self,
+    ) -> &'a mut BTreeMap<DomId, BTreeMap<NodeId, TextUnderlineVec>> {
+        unsafe { &mut *self.text_underlines_changed_in_callbacks }
+    }
     fn internal_get_images_changed_in_callbacks<'a>(
         &'a mut self,
     ) -> &'a mut BTreeMap<DomId, BTreeMap<NodeId, (ImageRef, UpdateImageType)>> {
@@ -1380,6 +1585,20 @@ impl CallbackInfo {
     pub fn get_current_window_flags(&self) -> WindowFlags {
         self.internal_get_current_window_state().flags.clone()
     }
+    /// Returns the data of the `EventLoopProxy::send_user_event()` call
+    /// that triggered the current `On::UserEvent` callback
+    pub fn get_user_event(&self) -> OptionRefAny {
+        self.internal_get_current_window_state().user_event.clone()
+    }
+    /// Returns the window-local data of the current window (see `WindowCreateOptions::window_data`),
+    /// the returned `RefAny` points to the same data, so modifications are visible to later callbacks
+    pub fn get_window_data(&self) -> OptionRefAny {
+        self.internal_get_window_data_ref().clone()
+    }
+    /// Replaces the window-local data of the current window
+    pub fn set_window_data(&mut self, data: RefAny) {
+        *self.internal_get_window_data() = OptionRefAny::Some(data);
+    }
     pub fn get_current_keyboard_state(&self) -> KeyboardState {
         self.internal_get_current_window_state()
             .keyboard_state
@@ -1421,6 +1640,43 @@ impl CallbackInfo {
             .get_system_time_fn
             .cb)()
     }
+    /// Returns how long the user has not interacted with the system (not only
+    /// the current application) or `None` if the platform can't report it
+    pub fn get_system_idle_time(&self) -> OptionDuration {
+        (self
+            .internal_get_extern_system_callbacks()
+            .get_system_idle_time_fn
+            .cb)()
+    }
+    /// Returns the character that the key produces in the current keyboard layout
+    /// (i.e. `VirtualKeyCode::Key7` + Shift is `/` on a German keyboard),
+    /// `None` if the key doesn't produce a character or the platform can't map it
+    pub fn keycode_to_char(
+        &self,
+        virtual_keycode: VirtualKeyCode,
+        modifiers: KeyboardModifiers,
+    ) -> OptionChar {
+        (self
+            .internal_get_extern_system_callbacks()
+            .keycode_to_char_fn
+            .cb)(virtual_keycode, modifiers)
+    }
+    /// Returns the most recent log lines (oldest first), i.e. to display
+    /// them in a diagnostics panel. Empty if logging is disabled.
+    pub fn get_log_lines(&self) -> StringVec {
+        (self
+            .internal_get_extern_system_callbacks()
+            .get_log_lines_fn
+            .cb)()
+    }
+    /// Sets the log level of a subsystem (i.e. `"azul::layout"`, `"azul::restyle"`
+    /// or `"azul::render"`) at runtime - an empty string sets the global log level
+    pub fn set_log_level(&self, subsystem: AzString, level: AppLogLevel) {
+        (self
+            .internal_get_extern_system_callbacks()
+            .set_log_level_fn
+            .cb)(subsystem, level)
+    }
     pub fn get_gl_context(&self) -> OptionGlContextPtr {
         self.internal_get_gl_context().clone()
     }
@@ -1437,6 +1693,37 @@ impl CallbackInfo {
             })
     }
 
+    /// Returns the margin / border / padding / content boxes of the node after
+    /// the last layout pass, relative to the window origin
+    pub fn get_node_rect(&self, node_id: DomNodeId) -> Option<NodeRect> {
+        let layout_result = self.internal_get_layout_results().get(node_id.dom.inner)?;
+        let nid = node_id.node.into_crate_internal()?;
+        let positioned_rectangles = layout_result.rects.as_ref();
+        let positioned_rectangle = positioned_rectangles.get(nid)?;
+        Some(positioned_rectangle.get_node_rect())
+    }
+
+    /// Returns the viewport, content size and current scroll offset of the node
+    /// or `None` if the node does not overflow its parent
+    pub fn get_scroll_info(&self, node_id: DomNodeId) -> Option<ScrollInfo> {
+        let sp = self
+            .internal_get_current_scroll_states()
+            .get(&node_id.dom)?
+            .get(&node_id.node)?;
+        Some(ScrollInfo {
+            scroll_position: LogicalPosition::new(
+                sp.children_rect.origin.x - sp.parent_rect.origin.x,
+                sp.children_rect.origin.y - sp.parent_rect.origin.y,
+            ),
+            viewport: sp.parent_rect,
+            content_size: sp.children_rect.size,
+            max_scroll: LogicalSize::new(
+                (sp.children_rect.size.width - sp.parent_rect.size.width).max(0.0),
+                (sp.children_rect.size.height - sp.parent_rect.size.height).max(0.0),
+            ),
+        })
+    }
+
     pub fn set_scroll_position(&mut self, node_id: DomNodeId, scroll_position: LogicalPosition) {
         self.internal_get_nodes_scrolled_in_callback()
             .entry(node_id.dom)
@@ -1459,15 +1746,19 @@ impl CallbackInfo {
             })
     }
 
+    // the generated :before / :after pseudo-elements are skipped,
+    // they are not part of the DOM that the application created
+
     pub fn get_previous_sibling(&self, node_id: DomNodeId) -> Option<DomNodeId> {
         let nid = node_id.node.into_crate_internal()?;
-        self.internal_get_layout_results()
-            .get(node_id.dom.inner)?
-            .styled_dom
+        let styled_dom = &self.internal_get_layout_results().get(node_id.dom.inner)?.styled_dom;
+        let previous_sibling = styled_dom
             .node_hierarchy
             .as_container()
-            .get(node_id.node.into_crate_internal()?)?
-            .previous_sibling_id()
+            .get(nid)?
+            .previous_sibling_id();
+        styled_dom
+            .skip_pseudo_elements(previous_sibling, |n| n.previous_sibling_id())
             .map(|nid| DomNodeId {
                 dom: node_id.dom,
                 node: NodeHierarchyItemId::from_crate_internal(Some(nid)),
@@ -1476,13 +1767,14 @@ impl CallbackInfo {
 
     pub fn get_next_sibling(&self, node_id: DomNodeId) -> Option<DomNodeId> {
         let nid = node_id.node.into_crate_internal()?;
-        self.internal_get_layout_results()
-            .get(node_id.dom.inner)?
-            .styled_dom
+        let styled_dom = &self.internal_get_layout_results().get(node_id.dom.inner)?.styled_dom;
+        let next_sibling = styled_dom
             .node_hierarchy
             .as_container()
-            .get(node_id.node.into_crate_internal()?)?
-            .next_sibling_id()
+            .get(nid)?
+            .next_sibling_id();
+        styled_dom
+            .skip_pseudo_elements(next_sibling, |n| n.next_sibling_id())
             .map(|nid| DomNodeId {
                 dom: node_id.dom,
                 node: NodeHierarchyItemId::from_crate_internal(Some(nid)),
@@ -1491,13 +1783,14 @@ impl CallbackInfo {
 
     pub fn get_first_child(&self, node_id: DomNodeId) -> Option<DomNodeId> {
         let nid = node_id.node.into_crate_internal()?;
-        self.internal_get_layout_results()
-            .get(node_id.dom.inner)?
-            .styled_dom
+        let styled_dom = &self.internal_get_layout_results().get(node_id.dom.inner)?.styled_dom;
+        let first_child = styled_dom
             .node_hierarchy
             .as_container()
             .get(nid)?
-            .first_child_id(nid)
+            .first_child_id(nid);
+        styled_dom
+            .skip_pseudo_elements(first_child, |n| n.next_sibling_id())
             .map(|nid| DomNodeId {
                 dom: node_id.dom,
                 node: NodeHierarchyItemId::from_crate_internal(Some(nid)),
@@ -1506,13 +1799,14 @@ impl CallbackInfo {
 
     pub fn get_last_child(&self, node_id: DomNodeId) -> Option<DomNodeId> {
         let nid = node_id.node.into_crate_internal()?;
-        self.internal_get_layout_results()
-            .get(node_id.dom.inner)?
-            .styled_dom
+        let styled_dom = &self.internal_get_layout_results().get(node_id.dom.inner)?.styled_dom;
+        let last_child = styled_dom
             .node_hierarchy
             .as_container()
-            .get(node_id.node.into_crate_internal()?)?
-            .last_child_id()
+            .get(nid)?
+            .last_child_id();
+        styled_dom
+            .skip_pseudo_elements(last_child, |n| n.previous_sibling_id())
             .map(|nid| DomNodeId {
                 dom: node_id.dom,
                 node: NodeHierarchyItemId::from_crate_internal(Some(nid)),
@@ -1584,6 +1878,95 @@ impl CallbackInfo {
         }
     }
 
+    /// Adds a class to the node. After the callback returns, only the node and its
+    /// children are restyled - the DOM does not have to be regenerated.
+    pub fn add_class(&mut self, node_id: DomNodeId, class: AzString) {
+        self.internal_change_ids_and_classes(node_id, |ids_and_classes| {
+            let class = IdOrClass::Class(class);
+            if !ids_and_classes.contains(&class) {
+                ids_and_classes.push(class);
+            }
+        });
+    }
+
+    /// Removes a class from the node, see `add_class`
+    pub fn remove_class(&mut self, node_id: DomNodeId, class: AzString) {
+        self.internal_change_ids_and_classes(node_id, |ids_and_classes| {
+            let class = IdOrClass::Class(class);
+            ids_and_classes.retain(|c| *c != class);
+        });
+    }
+
+    /// Adds the class to the node if it is not present, removes it otherwise
+    pub fn toggle_class(&mut self, node_id: DomNodeId, class: AzString) {
+        self.internal_change_ids_and_classes(node_id, |ids_and_classes| {
+            let class = IdOrClass::Class(class);
+            if ids_and_classes.contains(&class) {
+                ids_and_classes.retain(|c| *c != class);
+            } else {
+                ids_and_classes.push(class);
+            }
+        });
+    }
+
+    // Applies the change to the ids and classes that were already changed
+    // in this callback or (if the node wasn't changed yet) the current ones
+    fn internal_change_ids_and_classes<F: FnOnce(&mut Vec<IdOrClass>)>(
+        &mut self,
+        node_id: DomNodeId,
+        change: F,
+    ) {
+        let nid = match node_id.node.into_crate_internal() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let pending = self
+            .internal_get_ids_and_classes_changed_in_callbacks()
+            .get(&node_id.dom)
+            .and_then(|m| m.get(&nid))
+            .cloned();
+
+        let current = match pending {
+            Some(s) => s,
+            None => match self
+                .internal_get_layout_results()
+                .get(node_id.dom.inner)
+                .and_then(|l| l.styled_dom.node_data.as_container().get(nid).map(|n| n.ids_and_classes.clone()))
+            {
+                Some(s) => s,
+                None => return,
+            },
+        };
+
+        let mut ids_and_classes = current.into_library_owned_vec();
+        change(&mut ids_and_classes);
+
+        self.internal_get_ids_and_classes_changed_in_callbacks()
+            .entry(node_id.dom)
+            .or_insert_with(|| BTreeMap::new())
+            .insert(nid, ids_and_classes.into());
+    }
+
+    /// Sets or clears an application-defined state of the node (`:disabled`, `:checked`,
+    /// `:selected` or `:indeterminate`), the node is restyled after the callback returns.
+    ///
+    /// NOTE: The state is reset to the state of the `Dom` when the DOM is regenerated,
+    /// use `Dom::with_state_flag` in the layout callback to keep it.
+    pub fn set_node_state_flag(&mut self, node_id: DomNodeId, flag: NodeStateFlag, value: bool) {
+        let nid = match node_id.node.into_crate_internal() {
+            Some(s) => s,
+            None => return,
+        };
+
+        self.internal_get_node_state_flags_changed_in_callbacks()
+            .entry(node_id.dom)
+            .or_insert_with(|| BTreeMap::new())
+            .entry(nid)
+            .or_insert_with(|| BTreeMap::new())
+            .insert(flag, value);
+    }
+
     pub fn set_focus(&mut self, target: FocusTarget) {
         *self.internal_get_focus_target() = Some(target);
     }
@@ -1605,6 +1988,18 @@ impl CallbackInfo {
         }
     }
 
+    /// Underlines character ranges of a text node (i.e. spelling errors) without
+    /// regenerating the DOM. The underlines replace the previous underlines of the
+    /// node and are removed when the text of the node changes.
+    pub fn set_text_underlines(&mut self, node_id: DomNodeId, underlines: TextUnderlineVec) {
+        if let Some(nid) = node_id.node.into_crate_internal() {
+            self.internal_get_text_underlines_changed_in_callbacks()
+                .entry(node_id.dom)
+                .or_insert_with(|| BTreeMap::new())
+                .insert(nid, underlines);
+        }
+    }
+
     #[cfg(feature = "multithreading")]
     pub fn get_inline_text(&self, node_id: DomNodeId) -> Option<InlineText> {
         let nid = node_id.node.into_crate_internal()?;
@@ -1623,6 +2018,15 @@ impl CallbackInfo {
         ))
     }
 
+    /// Same as `get_inline_text`, but also returns the content box that the
+    /// text lines are positioned in (necessary for positioning carets / popups)
+    #[cfg(feature = "multithreading")]
+    pub fn get_text_layout(&self, node_id: DomNodeId) -> Option<TextLayout> {
+        let inline_text = self.get_inline_text(node_id)?;
+        let bounds = self.get_node_rect(node_id)?.content_box;
+        Some(TextLayout { bounds, inline_text })
+    }
+
     /// Returns the FontRef for the given NodeId
     pub fn get_font_ref(&self, node_id: DomNodeId) -> Option<FontRef> {
         use crate::styled_dom::StyleFontFamiliesHash;
@@ -1699,6 +2103,89 @@ impl CallbackInfo {
         self.internal_get_new_windows().push(window);
     }
 
+    /// Adds a window-level keyboard shortcut. If the key combination is already
+    /// used by another shortcut, the shortcut is not added and the conflict is returned
+    pub fn add_shortcut(&mut self, shortcut: Shortcut) -> OptionShortcutConflict {
+        self.internal_get_shortcuts().add(shortcut)
+    }
+
+    /// Removes the window-level shortcut with the given key combination,
+    /// returns whether a shortcut was removed
+    pub fn remove_shortcut(&mut self, keys: VirtualKeyCodeCombo) -> bool {
+        self.internal_get_shortcuts().remove(&keys)
+    }
+
+    /// Returns all window-level shortcuts that share the same key combination
+    pub fn get_shortcut_conflicts(&self) -> ShortcutConflictVec {
+        self.internal_get_shortcuts_ref().get_conflicts()
+    }
+
+    /// Asks the screen reader to speak the `text` (i.e. "3 results found"), without
+    /// having to move the focus. The text is sent to the platform accessibility API
+    /// after the callback returns (currently UI Automation on Windows, ignored on
+    /// other platforms).
+    pub fn announce(&mut self, text: AzString, politeness: Politeness) {
+        self.internal_get_announcements()
+            .push(Announcement { text, politeness });
+    }
+
+    /// Plays a short tactile feedback (i.e. when a touch-dragged slider snaps to the next
+    /// value) on devices that support it, after the callback returns. Currently supported
+    /// for haptic pens on Windows 11 (requires the `haptics` feature), ignored on other
+    /// platforms and devices.
+    pub fn trigger_haptic(&mut self, kind: HapticKind) {
+        self.internal_get_haptic_feedback().push(kind);
+    }
+
+    /// Schedules the `callback` to run once, right before the next frame of the window
+    /// is rendered (synchronized to the vertical blank where the platform supports it).
+    /// `TimerCallbackInfo::frame_start` is the same for all callbacks of one frame, which
+    /// makes it a steady time base for animations. Call this function again from the
+    /// callback to run the callback on the next frame, too.
+    pub fn request_animation_frame(&mut self, data: RefAny, callback: TimerCallbackType) {
+        self.internal_get_animation_frame_callbacks()
+            .push(AnimationFrameCallback {
+                data,
+                callback: TimerCallback { cb: callback },
+            });
+    }
+
+    /// Cross-fades the subtree of the `node_id` to its new content: the current content
+    /// of the subtree is kept as a snapshot and faded out over the new content during
+    /// the `duration`. Call this function right before returning `Update::RefreshDom`.
+    ///
+    /// Only subtrees of the root DOM can be cross-faded, the snapshot is not interactive.
+    /// Currently only implemented on Windows. Does nothing if the user prefers reduced motion.
+    pub fn crossfade_subtree(&mut self, node_id: DomNodeId, duration: AzDuration) {
+        if self.internal_get_current_window_state().system_style.prefers_reduced_motion {
+            return;
+        }
+        let start = self.get_current_time();
+        self.internal_get_crossfades().push(SubtreeCrossfade {
+            node: node_id,
+            start,
+            duration,
+        });
+    }
+
+    /// Records the subtree of the `node_id` (including its running animations) for the
+    /// given `duration` at up to `fps` frames per second and writes it as an animated GIF
+    /// to the `output_path` (requires the `gif` feature), i.e. to generate animated
+    /// screenshots of widgets for documentation.
+    ///
+    /// The frames are taken from the rendered window, so the node has to be visible
+    /// (and not covered by other nodes). The area of the node is fixed when the
+    /// recording starts. Only nodes of the root DOM can be recorded, currently only
+    /// implemented on Windows.
+    pub fn render_subtree_to_frames(&mut self, node_id: DomNodeId, duration: AzDuration, fps: u32, output_path: AzString) {
+        self.internal_get_subtree_recordings().push(SubtreeRecording {
+            node: node_id,
+            duration,
+            fps,
+            output_path,
+        });
+    }
+
     /// Starts a thread, returns Some(thread_id) if the `thread_initialize_data` is the only copy
     pub fn start_thread(
         &mut self,
@@ -1744,13 +2231,23 @@ impl CallbackInfo {
         timer_id
     }
 
+    /// Animates a CSS property of the node. If the user prefers reduced motion
+    /// (see `SystemStyle`), `NoRepeat` animations jump to their end state and
+    /// repeating animations are not started at all (returns `None`).
     pub fn start_animation(
         &mut self,
         dom_node_id: DomNodeId,
-        animation: Animation,
+        mut animation: Animation,
     ) -> Option<TimerId> {
         use crate::task::SystemTimeDiff;
 
+        if self.internal_get_current_window_state().system_style.prefers_reduced_motion {
+            if animation.repeat != AnimationRepeat::NoRepeat {
+                return None;
+            }
+            animation.duration = AzDuration::System(SystemTimeDiff::from_millis(0));
+        }
+
         let layout_result = self
             .internal_get_layout_results()
             .get(dom_node_id.dom.inner)?;
@@ -1822,6 +2319,154 @@ impl CallbackInfo {
         self.internal_get_timers_removed().insert(timer_id)
     }
 
+    /// Starts the animation of the text caret of an editable node: the caret is shown
+    /// immediately and then hidden / shown again every `-azul-caret-animation-duration`
+    /// (computed on the caret node). Restarting the animation on every key press keeps
+    /// the caret visible while the user is typing.
+    ///
+    /// Returns `None` if the duration is `0`: the caret stays visible and doesn't blink.
+    pub fn start_caret_animation(
+        &mut self,
+        caret_node: DomNodeId,
+        animation: CaretAnimation,
+    ) -> Option<TimerId> {
+        use crate::task::SystemTimeDiff;
+
+        let duration_millis = self.get_caret_animation_duration(caret_node).inner as u64;
+
+        self.set_css_property(
+            caret_node,
+            CssProperty::const_opacity(StyleOpacity::const_new(100)),
+        );
+
+        if duration_millis == 0 {
+            return None;
+        }
+
+        // no fading frames if the user prefers reduced motion
+        let animation = match animation {
+            CaretAnimation::Fade if self.internal_get_current_window_state().system_style.prefers_reduced_motion => CaretAnimation::Blink,
+            other => other,
+        };
+
+        let interval_millis = match animation {
+            CaretAnimation::Blink => duration_millis,
+            CaretAnimation::Fade => CARET_FADE_FRAME_MILLIS.min(duration_millis),
+        };
+
+        let timer_id = TimerId::unique();
+        let now = self.get_current_time();
+
+        let caret_data = CaretAnimationData {
+            animation,
+            start: now.clone(),
+            duration: AzDuration::System(SystemTimeDiff::from_millis(duration_millis)),
+            get_system_time_fn: self
+                .internal_get_extern_system_callbacks()
+                .get_system_time_fn
+                .clone(),
+        };
+
+        let timer = Timer {
+            data: RefAny::new(caret_data),
+            node_id: Some(caret_node).into(),
+            created: now,
+            run_count: 0,
+            last_run: None.into(),
+            delay: None.into(),
+            interval: Some(AzDuration::System(SystemTimeDiff::from_millis(interval_millis))).into(),
+            timeout: None.into(),
+            callback: TimerCallback {
+                cb: drive_caret_animation_func,
+            },
+        };
+
+        self.internal_get_timers().insert(timer_id, timer);
+
+        Some(timer_id)
+    }
+
+    /// Resizes the window to the `target_size` (in logical units) over the `duration`
+    /// (ease-in-out), i.e. to expand a tool palette or a side panel. Every step of the
+    /// animation resizes the native window and re-layouts the UI at the intermediate size.
+    ///
+    /// Stop the returned timer to cancel the animation at the current size.
+    /// If the user prefers reduced motion, the window is resized in one step.
+    pub fn animate_window_resize(
+        &mut self,
+        target_size: LogicalSize,
+        duration: AzDuration,
+    ) -> TimerId {
+        use crate::task::SystemTimeDiff;
+
+        let duration = if self.internal_get_current_window_state().system_style.prefers_reduced_motion {
+            AzDuration::System(SystemTimeDiff::from_millis(0))
+        } else {
+            duration
+        };
+
+        let timer_id = TimerId::unique();
+        let now = self.get_current_time();
+
+        let resize_data = WindowResizeAnimationData {
+            from: self.internal_get_current_window_state().size.dimensions,
+            to: target_size,
+            start: now.clone(),
+            duration,
+            get_system_time_fn: self
+                .internal_get_extern_system_callbacks()
+                .get_system_time_fn
+                .clone(),
+        };
+
+        let timer = Timer {
+            data: RefAny::new(resize_data),
+            node_id: None.into(),
+            created: now,
+            run_count: 0,
+            last_run: None.into(),
+            delay: None.into(),
+            interval: Some(AzDuration::System(SystemTimeDiff::from_millis(
+                WINDOW_RESIZE_FRAME_MILLIS,
+            )))
+            .into(),
+            timeout: None.into(),
+            callback: TimerCallback {
+                cb: drive_window_resize_animation_func,
+            },
+        };
+
+        self.internal_get_timers().insert(timer_id, timer);
+
+        timer_id
+    }
+
+    /// Returns the computed `-azul-caret-animation-duration` of the node
+    fn get_caret_animation_duration(&self, node_id: DomNodeId) -> StyleCaretAnimationDuration {
+        let layout_result = match self.internal_get_layout_results().get(node_id.dom.inner) {
+            Some(s) => s,
+            None => return StyleCaretAnimationDuration::default(),
+        };
+        let nid = match node_id.node.into_crate_internal() {
+            Some(s) => s,
+            None => return StyleCaretAnimationDuration::default(),
+        };
+        let node_data = layout_result.styled_dom.node_data.as_container();
+        let styled_nodes = layout_result.styled_dom.styled_nodes.as_container();
+        node_data
+            .get(nid)
+            .and_then(|node_data| {
+                let state = &styled_nodes.get(nid)?.state;
+                layout_result
+                    .styled_dom
+                    .css_property_cache
+                    .ptr
+                    .get_caret_animation_duration(node_data, &nid, state)
+            })
+            .and_then(|p| p.get_property().copied())
+            .unwrap_or_default()
+    }
+
     pub fn get_node_position(&self, node_id: DomNodeId) -> Option<PositionInfo> {
         let layout_result = self.internal_get_layout_results().get(node_id.dom.inner)?;
         let nid = node_id.node.into_crate_internal()?;
@@ -1903,13 +2548,23 @@ impl Clone for CallbackInfo {
             threads_removed: self.threads_removed,
             current_window_handle: self.current_window_handle,
             new_windows: self.new_windows,
+            shortcuts: self.shortcuts,
+            window_data: self.window_data,
+            announcements: self.announcements,
+            haptic_feedback: self.haptic_feedback,
+            animation_frame_callbacks: self.animation_frame_callbacks,
+            crossfades: self.crossfades,
+            subtree_recordings: self.subtree_recordings,
             system_callbacks: self.system_callbacks,
             stop_propagation: self.stop_propagation,
             focus_target: self.focus_target,
             words_changed_in_callbacks: self.words_changed_in_callbacks,
+            text_underlines_changed_in_callbacks: self.text_underlines_changed_in_callbacks,
             images_changed_in_callbacks: self.images_changed_in_callbacks,
             image_masks_changed_in_callbacks: self.image_masks_changed_in_callbacks,
             css_properties_changed_in_callbacks: self.css_properties_changed_in_callbacks,
+            ids_and_classes_changed_in_callbacks: self.ids_and_classes_changed_in_callbacks,
+            node_state_flags_changed_in_callbacks: self.node_state_flags_changed_in_callbacks,
             current_scroll_states: self.current_scroll_states,
             nodes_scrolled_in_callback: self.nodes_scrolled_in_callback,
             hit_dom_node: self.hit_dom_node,
@@ -1944,6 +2599,23 @@ pub struct AnimationData {
     pub get_system_time_fn: GetSystemTimeCallback,
 }
 
+/// Cross-fade requested via `CallbackInfo::crossfade_subtree()`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubtreeCrossfade {
+    pub node: DomNodeId,
+    pub start: AzInstant,
+    pub duration: AzDuration,
+}
+
+/// Recording requested via `CallbackInfo::render_subtree_to_frames()`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubtreeRecording {
+    pub node: DomNodeId,
+    pub duration: AzDuration,
+    pub fps: u32,
+    pub output_path: AzString,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[repr(C)]
 pub struct Animation {
@@ -1971,6 +2643,145 @@ pub enum AnimationRepeatCount {
     Infinite,
 }
 
+/// How the text caret is animated, see `CallbackInfo::start_caret_animation`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum CaretAnimation {
+    /// Caret is shown and hidden every `-azul-caret-animation-duration`
+    Blink,
+    /// Caret stays visible for one duration, then fades out and in again
+    Fade,
+}
+
+/// Interval of the timer that fades the caret
+const CARET_FADE_FRAME_MILLIS: u64 = 16;
+
+#[derive(Debug, Clone, PartialEq)]
+struct CaretAnimationData {
+    animation: CaretAnimation,
+    /// Time when the caret was (re-)started
+    start: AzInstant,
+    /// Computed `-azul-caret-animation-duration`
+    duration: AzDuration,
+    get_system_time_fn: GetSystemTimeCallback,
+}
+
+// callback that blinks or fades the text caret
+extern "C" fn drive_caret_animation_func(
+    caret_data: &mut RefAny,
+    info: &mut TimerCallbackInfo,
+) -> TimerCallbackReturn {
+    let caret_data = match caret_data.downcast_ref::<CaretAnimationData>() {
+        Some(s) => s,
+        None => {
+            return TimerCallbackReturn {
+                should_update: Update::DoNothing,
+                should_terminate: TerminateTimer::Terminate,
+            };
+        }
+    };
+
+    let node_id = match info.node_id.into_option() {
+        Some(s) => s,
+        None => {
+            return TimerCallbackReturn {
+                should_update: Update::DoNothing,
+                should_terminate: TerminateTimer::Terminate,
+            };
+        }
+    };
+
+    // number of durations since the caret was started
+    let now = (caret_data.get_system_time_fn.cb)();
+    let cycles = now.duration_since(&caret_data.start).div(&caret_data.duration);
+
+    let opacity = match caret_data.animation {
+        CaretAnimation::Blink => {
+            // the blink timer runs once per duration, round to
+            // not skip a toggle if the timer runs slightly early
+            if libm::roundf(cycles) as usize % 2 == 0 {
+                100.0
+            } else {
+                0.0
+            }
+        }
+        CaretAnimation::Fade => {
+            let cycle = cycles as usize;
+            let t = AnimationInterpolationFunction::EaseInOut
+                .evaluate((cycles - cycle as f32) as f64);
+            if cycle == 0 {
+                100.0
+            } else if cycle % 2 == 1 {
+                100.0 * (1.0 - t)
+            } else {
+                100.0 * t
+            }
+        }
+    };
+
+    info.callback_info
+        .set_css_property(node_id, CssProperty::opacity(StyleOpacity::new(opacity)));
+
+    TimerCallbackReturn {
+        should_terminate: TerminateTimer::Continue,
+        should_update: Update::DoNothing,
+    }
+}
+
+// interval of the window resize animation timer (~60 FPS)
+const WINDOW_RESIZE_FRAME_MILLIS: u64 = 16;
+
+#[derive(Debug, Clone, PartialEq)]
+struct WindowResizeAnimationData {
+    from: LogicalSize,
+    to: LogicalSize,
+    start: AzInstant,
+    duration: AzDuration,
+    get_system_time_fn: GetSystemTimeCallback,
+}
+
+// callback that tweens the window size for CallbackInfo::animate_window_resize
+extern "C" fn drive_window_resize_animation_func(
+    resize_data: &mut RefAny,
+    info: &mut TimerCallbackInfo,
+) -> TimerCallbackReturn {
+    let resize_data = match resize_data.downcast_ref::<WindowResizeAnimationData>() {
+        Some(s) => s,
+        None => {
+            return TimerCallbackReturn {
+                should_update: Update::DoNothing,
+                should_terminate: TerminateTimer::Terminate,
+            };
+        }
+    };
+
+    let end = resize_data
+        .start
+        .add_optional_duration(Some(&resize_data.duration));
+    let now = (resize_data.get_system_time_fn.cb)();
+    let t = now.linear_interpolate(resize_data.start.clone(), end.clone());
+    let t = AnimationInterpolationFunction::EaseInOut.evaluate(t as f64);
+
+    let from = resize_data.from;
+    let to = resize_data.to;
+    info.callback_info
+        .internal_get_modifiable_window_state()
+        .size
+        .dimensions = LogicalSize::new(
+        from.width + (to.width - from.width) * t,
+        from.height + (to.height - from.height) * t,
+    );
+
+    TimerCallbackReturn {
+        should_update: Update::DoNothing,
+        should_terminate: if now >= end {
+            TerminateTimer::Terminate
+        } else {
+            TerminateTimer::Continue
+        },
+    }
+}
+
 // callback that drives an animation
 extern "C" fn drive_animation_func(
     anim_data: &mut RefAny,
@@ -2407,6 +3218,92 @@ impl Default for IFrameCallbackReturn {
     }
 }
 
+// -- custom layout callbacks
+
+/// Callback that returns the desired size of a `NodeType::CustomLayout` node,
+/// given the space that is available to it
+pub type MeasureCallbackType = extern "C" fn(&mut RefAny, &MeasureCallbackInfo) -> LogicalSize;
+
+#[repr(C)]
+pub struct MeasureCallback {
+    pub cb: MeasureCallbackType,
+}
+impl_callback!(MeasureCallback);
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct MeasureCallbackInfo {
+    /// Space available to the node. The callback is invoked twice per layout:
+    /// once before the widths are solved (`available_size` = size of the DOM root)
+    /// and once after, with `available_size.width` set to the final width of the node
+    pub available_size: LogicalSize,
+    /// Whether `available_size.width` is the final width of the node
+    pub width_is_final: bool,
+}
+
+/// Callback that is invoked after the layout with the final position and size
+/// of a `NodeType::CustomLayout` node, so that the externally rendered content
+/// (terminal grid, game viewport, etc.) can be placed at the correct location
+pub type ArrangeCallbackType = extern "C" fn(&mut RefAny, &ArrangeCallbackInfo);
+
+#[repr(C)]
+pub struct ArrangeCallback {
+    pub cb: ArrangeCallbackType,
+}
+impl_callback!(ArrangeCallback);
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct ArrangeCallbackInfo {
+    /// Final margin / border / padding / content boxes of the node, relative to the window
+    pub bounds: NodeRect,
+}
+
+/// Callback that returns the primitives to draw into the content box of a
+/// `NodeType::CustomLayout` node, invoked when the display list is built
+pub type PaintCallbackType = extern "C" fn(&mut RefAny, &PaintCallbackInfo) -> PaintPrimitiveVec;
+
+#[repr(C)]
+pub struct PaintCallback {
+    pub cb: PaintCallbackType,
+}
+impl_callback!(PaintCallback);
+
+impl_option!(
+    PaintCallback,
+    OptionPaintCallback,
+    [Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
+);
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct PaintCallbackInfo {
+    /// Size of the content box of the node
+    pub size: LogicalSize,
+}
+
+/// Primitive drawn by a `PaintCallback`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C, u8)]
+pub enum PaintPrimitive {
+    Rect(PaintRect),
+}
+
+/// Filled (rounded) rectangle, `bounds` are relative to the content box of the node
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct PaintRect {
+    pub bounds: LogicalRect,
+    pub color: ColorU,
+    pub border_radius: f32,
+}
+
+impl_vec!(PaintPrimitive, PaintPrimitiveVec, PaintPrimitiveVecDestructor);
+impl_vec_clone!(PaintPrimitive, PaintPrimitiveVec, PaintPrimitiveVecDestructor);
+impl_vec_debug!(PaintPrimitive, PaintPrimitiveVec);
+impl_vec_partialeq!(PaintPrimitive, PaintPrimitiveVec);
+impl_vec_partialord!(PaintPrimitive, PaintPrimitiveVec);
+
 // --  thread callback
 pub type ThreadCallbackType = extern "C" fn(RefAny, ThreadSender, ThreadReceiver);
 
@@ -2501,8 +3398,9 @@ pub struct LayoutCallbackInfo {
     pub gl_context: *const OptionGlContextPtr,
     /// Reference to the system font cache
     system_fonts: *const FcFontCache,
-    /// Extension for future ABI stability (referenced data)
-    _abi_ref: *const c_void,
+    /// System settings of the window (takes the place of the
+    /// reserved `_abi_ref` field, so the layout stays the same)
+    system_style: *const SystemStyle,
     /// Extension for future ABI stability (mutable data)
     _abi_mut: *mut c_void,
 }
@@ -2515,7 +3413,7 @@ impl Clone for LayoutCallbackInfo {
             image_cache: self.image_cache,
             gl_context: self.gl_context,
             system_fonts: self.system_fonts,
-            _abi_ref: self._abi_ref,
+            system_style: self.system_style,
             _abi_mut: self._abi_mut,
         }
     }
@@ -2528,6 +3426,7 @@ impl LayoutCallbackInfo {
         image_cache: &'a ImageCache,
         gl_context: &'a OptionGlContextPtr,
         fc_cache: &'a FcFontCache,
+        system_style: &'a SystemStyle,
     ) -> Self {
         Self {
             window_size: window_size,
@@ -2535,7 +3434,7 @@ impl LayoutCallbackInfo {
             image_cache: image_cache as *const ImageCache,
             gl_context: gl_context as *const OptionGlContextPtr,
             system_fonts: fc_cache as *const FcFontCache,
-            _abi_ref: core::ptr::null(),
+            system_style: system_style as *const SystemStyle,
             _abi_mut: core::ptr::null_mut(),
         }
     }
@@ -2549,6 +3448,9 @@ impl LayoutCallbackInfo {
     fn internal_get_gl_context<'a>(&'a self) -> &'a OptionGlContextPtr {
         unsafe { &*self.gl_context }
     }
+    fn internal_get_system_style<'a>(&'a self) -> &'a SystemStyle {
+        unsafe { &*self.system_style }
+    }
 
     pub fn get_gl_context(&self) -> OptionGlContextPtr {
         self.internal_get_gl_context().clone()
@@ -2572,6 +3474,13 @@ impl LayoutCallbackInfo {
             .get_css_image_id(image_id)
             .cloned()
     }
+
+    /// Returns whether high contrast mode is enabled, whether the user prefers
+    /// reduced motion and the current system colors - the system colors and
+    /// `@media` blocks of the returned DOM are resolved with these values
+    pub fn get_system_style(&self) -> SystemStyle {
+        *self.internal_get_system_style()
+    }
 }
 
 /// Information about the bounds of a laid-out div rectangle.
@@ -2619,6 +3528,9 @@ pub enum FocusTarget {
     Next,
     First,
     Last,
+    /// Moves the focus to the geometrically nearest focusable node in the given
+    /// direction (or to the node set via `NodeData::set_focus_navigation()`)
+    Direction(FocusDirection),
     NoFocus,
 }
 
@@ -2889,7 +3801,125 @@ impl FocusTarget {
                     saturating_add
                 );
             }
+            Direction(direction) => {
+                // nothing focused yet: start at the first focusable node
+                let current_focus = match current_focus {
+                    Some(s) if s.node.into_crate_internal().is_some() => s,
+                    _ => return First.resolve(layout_results, None),
+                };
+
+                let current_node_id = current_focus.node.into_crate_internal().unwrap();
+                let layout_result = layout_results.get(current_focus.dom.inner).ok_or(
+                    UpdateFocusWarning::FocusInvalidDomId(current_focus.dom.clone()),
+                )?;
+                let node_data = layout_result.styled_dom.node_data.as_container();
+                let current_node = node_data.get(current_node_id).ok_or(
+                    UpdateFocusWarning::FocusInvalidNodeId(current_focus.node.clone()),
+                )?;
+
+                // explicit override on the focused node
+                if let Some(target_id) = current_node
+                    .get_focus_navigation()
+                    .and_then(|n| n.get(*direction))
+                {
+                    let target_node_id = node_data
+                        .linear_iter()
+                        .find(|node_id| node_data[*node_id].has_id(target_id))
+                        .ok_or_else(|| {
+                            UpdateFocusWarning::CouldNotFindFocusNode(CssPath {
+                                selectors: vec![CssPathSelector::Id(target_id.into())]
+                                    .into(),
+                            })
+                        })?;
+                    return Ok(Some(DomNodeId {
+                        dom: current_focus.dom,
+                        node: NodeHierarchyItemId::from_crate_internal(Some(target_node_id)),
+                    }));
+                }
+
+                let rects = layout_result.rects.as_ref();
+                let current_rect = rects
+                    .get(current_node_id)
+                    .ok_or(UpdateFocusWarning::FocusInvalidNodeId(
+                        current_focus.node.clone(),
+                    ))?
+                    .get_node_rect()
+                    .border_box;
+
+                let nearest_node_id = node_data
+                    .linear_iter()
+                    .filter(|node_id| *node_id != current_node_id)
+                    .filter(|node_id| node_data[*node_id].is_focusable())
+                    .filter_map(|node_id| {
+                        let rect = rects.get(node_id)?.get_node_rect().border_box;
+                        // skip nodes that are not visible (display: none, etc.)
+                        if rect.size.width <= 0.0 || rect.size.height <= 0.0 {
+                            return None;
+                        }
+                        let distance = get_spatial_focus_distance(*direction, &current_rect, &rect)?;
+                        Some((distance, node_id))
+                    })
+                    .min_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal))
+                    .map(|(_, node_id)| node_id);
+
+                // no node in that direction: keep the current focus
+                Ok(Some(match nearest_node_id {
+                    Some(node_id) => DomNodeId {
+                        dom: current_focus.dom,
+                        node: NodeHierarchyItemId::from_crate_internal(Some(node_id)),
+                    },
+                    None => current_focus,
+                }))
+            }
             NoFocus => Ok(None),
         }
     }
 }
+
+/// Returns how "far away" the `to` rect is from the `from` rect when moving the
+/// focus in the given direction or `None` if `to` is not in that direction at all.
+///
+/// The distance along the direction is weighted less than the offset orthogonal
+/// to it, so that a node that is directly below is preferred over a node that is
+/// slightly closer, but diagonally below.
+fn get_spatial_focus_distance(
+    direction: FocusDirection,
+    from: &LogicalRect,
+    to: &LogicalRect,
+) -> Option<f32> {
+    const ORTHOGONAL_WEIGHT: f32 = 2.0;
+
+    let from_center_x = from.origin.x + from.size.width / 2.0;
+    let from_center_y = from.origin.y + from.size.height / 2.0;
+    let to_center_x = to.origin.x + to.size.width / 2.0;
+    let to_center_y = to.origin.y + to.size.height / 2.0;
+
+    let (is_in_direction, distance, orthogonal) = match direction {
+        FocusDirection::Up => (
+            to_center_y < from_center_y,
+            from.min_y() - to.max_y(),
+            to_center_x - from_center_x,
+        ),
+        FocusDirection::Down => (
+            to_center_y > from_center_y,
+            to.min_y() - from.max_y(),
+            to_center_x - from_center_x,
+        ),
+        FocusDirection::Left => (
+            to_center_x < from_center_x,
+            from.min_x() - to.max_x(),
+            to_center_y - from_center_y,
+        ),
+        FocusDirection::Right => (
+            to_center_x > from_center_x,
+            to.min_x() - from.max_x(),
+            to_center_y - from_center_y,
+        ),
+    };
+
+    if !is_in_direction {
+        return None;
+    }
+
+    Some(distance.max(0.0) + orthogonal.abs() * ORTHOGONAL_WEIGHT)
+}
@@ -179,6 +179,21 @@ impl VecContents {
             CssProperty::Transform(CssPropertyValue::Exact(v)) => {
                 self.style_transforms.insert(v.get_hash(), v.clone());
             }
+            CssProperty::Cursor(CssPropertyValue::Exact(StyleCursor::Image(i))) => {
+                self.strings.insert(i.image.get_hash(), i.image.clone());
+            }
+            CssProperty::Content(CssPropertyValue::Exact(c)) => match c {
+                StyleContent::Text(s) | StyleContent::Image(s) | StyleContent::Counter(s) => {
+                    self.strings.insert(s.get_hash(), s.clone());
+                }
+                StyleContent::None => {}
+            },
+            CssProperty::CounterReset(CssPropertyValue::Exact(c)) => {
+                self.strings.insert(c.counter.get_hash(), c.counter.clone());
+            }
+            CssProperty::CounterIncrement(CssPropertyValue::Exact(c)) => {
+                self.strings.insert(c.counter.get_hash(), c.counter.clone());
+            }
             CssProperty::BackgroundRepeat(CssPropertyValue::Exact(v)) => {
                 self.style_background_repeats
                     .insert(v.get_hash(), v.clone());
@@ -253,6 +268,11 @@ pub fn css_to_rust_code(css: &Css) -> String {
 
             output.push_str("\t\t\t\t\t]\r\n");
 
+            output.push_str(&format!(
+                "\t\t\t\t\tmedia: CssMediaCondition {{ matching_styles: {:#04x} }},\r\n",
+                block.media.matching_styles
+            ));
+
             output.push_str("\t\t\t\t},\r\n");
         }
 
@@ -332,6 +352,12 @@ fn format_pseudo_selector_type(p: &CssPathPseudoSelector) -> String {
         CssPathPseudoSelector::Hover => format!("CssPathPseudoSelector::Hover"),
         CssPathPseudoSelector::Active => format!("CssPathPseudoSelector::Active"),
         CssPathPseudoSelector::Focus => format!("CssPathPseudoSelector::Focus"),
+        CssPathPseudoSelector::Disabled => format!("CssPathPseudoSelector::Disabled"),
+        CssPathPseudoSelector::Checked => format!("CssPathPseudoSelector::Checked"),
+        CssPathPseudoSelector::Selected => format!("CssPathPseudoSelector::Selected"),
+        CssPathPseudoSelector::Indeterminate => format!("CssPathPseudoSelector::Indeterminate"),
+        CssPathPseudoSelector::Before => format!("CssPathPseudoSelector::Before"),
+        CssPathPseudoSelector::After => format!("CssPathPseudoSelector::After"),
     }
 }
 
@@ -357,6 +383,10 @@ fn print_declaraction(decl: &CssDeclaration, tabs: usize) -> String {
             "CssDeclaration::Dynamic({})",
             format_dynamic_css_prop(d, tabs)
         ),
+        CssDeclaration::System(s) => format!(
+            "CssDeclaration::System({})",
+            format_system_css_prop(s, tabs)
+        ),
     }
 }
 
@@ -411,6 +441,10 @@ pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String
             "CssProperty::TabWidth({})",
             print_css_property_value(p, tabs, "StyleTabWidth")
         ),
+        CssProperty::Hyphens(p) => format!(
+            "CssProperty::Hyphens({})",
+            print_css_property_value(p, tabs, "StyleHyphens")
+        ),
         CssProperty::Cursor(p) => format!(
             "CssProperty::Cursor({})",
             print_css_property_value(p, tabs, "StyleCursor")
@@ -639,6 +673,50 @@ pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String
             "CssProperty::ScrollbarStyle({})",
             print_css_property_value(p, tabs, "ScrollbarStyle")
         ),
+        CssProperty::ScrollbarWidth(p) => format!(
+            "CssProperty::ScrollbarWidth({})",
+            print_css_property_value(p, tabs, "StyleScrollbarWidth")
+        ),
+        CssProperty::ScrollbarColor(p) => format!(
+            "CssProperty::ScrollbarColor({})",
+            print_css_property_value(p, tabs, "StyleScrollbarColor")
+        ),
+        CssProperty::ScrollbarThumbHoverColor(p) => format!(
+            "CssProperty::ScrollbarThumbHoverColor({})",
+            print_css_property_value(p, tabs, "StyleScrollbarThumbHoverColor")
+        ),
+        CssProperty::ScrollbarThumbActiveColor(p) => format!(
+            "CssProperty::ScrollbarThumbActiveColor({})",
+            print_css_property_value(p, tabs, "StyleScrollbarThumbActiveColor")
+        ),
+        CssProperty::WindowDragRegion(p) => format!(
+            "CssProperty::WindowDragRegion({})",
+            print_css_property_value(p, tabs, "StyleWindowDragRegion")
+        ),
+        CssProperty::CaretAnimationDuration(p) => format!(
+            "CssProperty::CaretAnimationDuration({})",
+            print_css_property_value(p, tabs, "StyleCaretAnimationDuration")
+        ),
+        CssProperty::ScrollPadding(p) => format!(
+            "CssProperty::ScrollPadding({})",
+            print_css_property_value(p, tabs, "StyleScrollPadding")
+        ),
+        CssProperty::Content(p) => format!(
+            "CssProperty::Content({})",
+            print_css_property_value(p, tabs, "StyleContent")
+        ),
+        CssProperty::CounterReset(p) => format!(
+            "CssProperty::CounterReset({})",
+            print_css_property_value(p, tabs, "StyleCounterReset")
+        ),
+        CssProperty::CounterIncrement(p) => format!(
+            "CssProperty::CounterIncrement({})",
+            print_css_property_value(p, tabs, "StyleCounterIncrement")
+        ),
+        CssProperty::OverflowAnchor(p) => format!(
+            "CssProperty::OverflowAnchor({})",
+            print_css_property_value(p, tabs, "StyleOverflowAnchor")
+        ),
         CssProperty::Opacity(p) => format!(
             "CssProperty::Opacity({})",
             print_css_property_value(p, tabs, "StyleOpacity")
@@ -659,6 +737,14 @@ pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String
             "CssProperty::BackfaceVisibility({})",
             print_css_property_value(p, tabs, "StyleBackfaceVisibility")
         ),
+        CssProperty::Perspective(p) => format!(
+            "CssProperty::Perspective({})",
+            print_css_property_value(p, tabs, "StylePerspective")
+        ),
+        CssProperty::TransformStyle(p) => format!(
+            "CssProperty::TransformStyle({})",
+            print_css_property_value(p, tabs, "StyleTransformStyle")
+        ),
         CssProperty::MixBlendMode(p) => format!(
             "CssProperty::MixBlendMode({})",
             print_css_property_value(p, tabs, "StyleMixBlendMode")
@@ -708,6 +794,20 @@ fn format_dynamic_css_prop(decl: &DynamicCssProperty, tabs: usize) -> String {
     )
 }
 
+fn format_system_css_prop(decl: &SystemCssProperty, tabs: usize) -> String {
+    let t = String::from("    ").repeat(tabs);
+    format!(
+        "SystemCssProperty {{\r\n{}    key: {:?},\r\n{}    value: {:?},\r\n{}    default_value: {},\r\n{}}}",
+        t,
+        decl.key,
+        t,
+        decl.value,
+        t,
+        format_static_css_prop(&decl.default_value, tabs + 1),
+        t
+    )
+}
+
 fn format_pixel_value(p: &PixelValue) -> String {
     match p.metric {
         SizeMetric::Px => format!(
@@ -811,6 +911,7 @@ macro_rules! impl_pixel_value_fmt {
 }
 
 impl_pixel_value_fmt!(StyleBorderTopLeftRadius);
+impl_pixel_value_fmt!(StyleScrollPadding);
 impl_pixel_value_fmt!(StyleBorderBottomLeftRadius);
 impl_pixel_value_fmt!(StyleBorderTopRightRadius);
 impl_pixel_value_fmt!(StyleBorderBottomRightRadius);
@@ -820,6 +921,7 @@ impl_pixel_value_fmt!(LayoutBorderLeftWidth);
 impl_pixel_value_fmt!(LayoutBorderRightWidth);
 impl_pixel_value_fmt!(LayoutBorderBottomWidth);
 impl_pixel_value_fmt!(StyleLetterSpacing);
+impl_pixel_value_fmt!(StylePerspective);
 impl_pixel_value_fmt!(StyleWordSpacing);
 impl_pixel_value_fmt!(StyleFontSize);
 
@@ -863,6 +965,18 @@ impl_color_value_fmt!(StyleBorderTopColor);
 impl_color_value_fmt!(StyleBorderLeftColor);
 impl_color_value_fmt!(StyleBorderRightColor);
 impl_color_value_fmt!(StyleBorderBottomColor);
+impl_color_value_fmt!(StyleScrollbarThumbHoverColor);
+impl_color_value_fmt!(StyleScrollbarThumbActiveColor);
+
+impl FormatAsRustCode for StyleScrollbarColor {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!(
+            "StyleScrollbarColor {{ thumb: {}, track: {} }}",
+            format_color_value(&self.thumb),
+            format_color_value(&self.track)
+        )
+    }
+}
 
 macro_rules! impl_enum_fmt {($enum_name:ident, $($enum_type:ident),+) => (
     impl FormatAsRustCode for $enum_name {
@@ -896,39 +1010,51 @@ impl_enum_fmt!(
     Luminosity
 );
 
-impl_enum_fmt!(
-    StyleCursor,
-    Alias,
-    AllScroll,
-    Cell,
-    ColResize,
-    ContextMenu,
-    Copy,
-    Crosshair,
-    Default,
-    EResize,
-    EwResize,
-    Grab,
-    Grabbing,
-    Help,
-    Move,
-    NResize,
-    NsResize,
-    NeswResize,
-    NwseResize,
-    Pointer,
-    Progress,
-    RowResize,
-    SResize,
-    SeResize,
-    Text,
-    Unset,
-    VerticalText,
-    WResize,
-    Wait,
-    ZoomIn,
-    ZoomOut
-);
+impl FormatAsRustCode for StyleCursor {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        match self {
+            StyleCursor::Image(i) => format!(
+                "StyleCursor::Image(StyleCursorImage {{ image: STRING_{}, hotspot_x: {}, hotspot_y: {} }})",
+                i.image.get_hash(),
+                i.hotspot_x,
+                i.hotspot_y
+            ),
+            // unit variants: Debug prints the variant name
+            other => format!("StyleCursor::{:?}", other),
+        }
+    }
+}
+
+impl FormatAsRustCode for StyleContent {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        match self {
+            StyleContent::None => format!("StyleContent::None"),
+            StyleContent::Text(s) => format!("StyleContent::Text(STRING_{})", s.get_hash()),
+            StyleContent::Image(s) => format!("StyleContent::Image(STRING_{})", s.get_hash()),
+            StyleContent::Counter(s) => format!("StyleContent::Counter(STRING_{})", s.get_hash()),
+        }
+    }
+}
+
+impl FormatAsRustCode for StyleCounterReset {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!(
+            "StyleCounterReset {{ counter: STRING_{}, value: {} }}",
+            self.counter.get_hash(),
+            self.value
+        )
+    }
+}
+
+impl FormatAsRustCode for StyleCounterIncrement {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!(
+            "StyleCounterIncrement {{ counter: STRING_{}, value: {} }}",
+            self.counter.get_hash(),
+            self.value
+        )
+    }
+}
 
 impl_enum_fmt!(
     BorderStyle,
@@ -1023,6 +1149,25 @@ impl_enum_fmt!(LayoutFloat, Left, Right);
 
 impl_enum_fmt!(LayoutBoxSizing, ContentBox, BorderBox);
 
+impl_enum_fmt!(StyleScrollbarWidth, Auto, Thin, None);
+
+impl_enum_fmt!(StyleOverflowAnchor, Auto, None);
+
+impl_enum_fmt!(
+    StyleWindowDragRegion,
+    None,
+    Move,
+    ResizeN,
+    ResizeNE,
+    ResizeE,
+    ResizeSE,
+    ResizeS,
+    ResizeSW,
+    ResizeW,
+    ResizeNW,
+    MaximizeButton
+);
+
 impl_enum_fmt!(LayoutFlexDirection, Row, RowReverse, Column, ColumnReverse);
 
 impl_enum_fmt!(LayoutFlexWrap, Wrap, NoWrap);
@@ -1057,6 +1202,8 @@ impl_enum_fmt!(LayoutOverflow, Auto, Scroll, Visible, Hidden);
 
 impl_enum_fmt!(StyleTextAlign, Center, Left, Right);
 
+impl_enum_fmt!(StyleHyphens, None, Manual, Auto);
+
 impl_enum_fmt!(
     DirectionCorner,
     Right,
@@ -1073,6 +1220,8 @@ impl_enum_fmt!(ExtendMode, Clamp, Repeat);
 
 impl_enum_fmt!(StyleBackfaceVisibility, Visible, Hidden);
 
+impl_enum_fmt!(StyleTransformStyle, Flat, Preserve3D);
+
 impl FormatAsRustCode for StyleBackgroundContentVec {
     fn format_as_rust_code(&self, tabs: usize) -> String {
         format!(
@@ -1433,6 +1582,12 @@ impl FormatAsRustCode for StyleBoxShadow {
     }
 }
 
+impl FormatAsRustCode for StyleCaretAnimationDuration {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!("StyleCaretAnimationDuration {{ inner: {} }}", self.inner)
+    }
+}
+
 impl FormatAsRustCode for StyleTransformOrigin {
     fn format_as_rust_code(&self, _tabs: usize) -> String {
         format!(
@@ -1,262 +1,390 @@
-use core::{
-    fmt,
-};
-use alloc::vec::Vec;
-use alloc::string::String;
-use alloc::collections::btree_set::BTreeSet;
+//! Structural diff between two `StyledDom`s, mainly for test assertions:
+//! instead of comparing the HTML of the entire DOM, a test can assert that
+//! only a certain node or property changed.
+//!
+//! Children are matched by their node type (using the longest common subsequence,
+//! preferring children with the same ids and classes), so that inserting a node
+//! in the middle of a list shows up as one added node instead of a change to
+//! every following node, while changing the class of a node shows up as a class
+//! change instead of a removed and an added node.
+
 use crate::{
-    id_tree::{NodeId, NodeDataContainer},
-    dom::{CompactDom, NodeData},
+    dom::{NodeData, NodeType},
+    id_tree::NodeId,
+    styled_dom::StyledDom,
 };
-
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct DomRange {
-    pub start: NodeId,
-    pub end: NodeId,
+use alloc::string::{String, ToString};
+use azul_css::{get_css_key_map, CssProperty, CssPropertyType};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// What changed about a node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeChangeKind {
+    /// Node only exists in the new DOM
+    Added,
+    /// Node only exists in the old DOM
+    Removed,
+    /// Node type changed (i.e. div -> image), the content of the node is not compared
+    NodeTypeChanged { old: String, new: String },
+    /// Content of a text node changed
+    TextChanged { old: String, new: String },
+    /// Ids or classes of the node changed, `old` and `new` are labels like "div#id.class"
+    IdsAndClassesChanged { old: String, new: String },
+    /// Inline style of the node changed
+    InlineStyleChanged { old: String, new: String },
+    /// Computed value of a CSS property changed, `None` = property is not set
+    PropertyChanged { property: CssPropertyType, old: Option<CssProperty>, new: Option<CssProperty> },
 }
 
-impl DomRange {
-
-    pub fn new(start: NodeId, end: NodeId) -> Self {
-        Self { start, end }
-    }
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeChange {
+    /// Path to the node, i.e. "body > div.list > p[2]"
+    pub path: String,
+    /// Id of the node in the old DOM (`None` if the node was added)
+    pub old_node_id: Option<NodeId>,
+    /// Id of the node in the new DOM (`None` if the node was removed)
+    pub new_node_id: Option<NodeId>,
+    pub kind: NodeChangeKind,
+}
 
-    pub fn single_node(node_id: NodeId) -> Self {
-        Self {
-            start: node_id,
-            end: node_id,
+impl fmt::Display for NodeChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::NodeChangeKind::*;
+        match &self.kind {
+            Added => write!(f, "+ {}", self.path),
+            Removed => write!(f, "- {}", self.path),
+            NodeTypeChanged { old, new } => write!(f, "~ {}: node type {} -> {}", self.path, old, new),
+            TextChanged { old, new } => write!(f, "~ {}: text {:?} -> {:?}", self.path, old, new),
+            IdsAndClassesChanged { old, new } => write!(f, "~ {}: ids / classes \"{}\" -> \"{}\"", self.path, old, new),
+            InlineStyleChanged { old, new } => write!(f, "~ {}: inline style \"{}\" -> \"{}\"", self.path, old, new),
+            PropertyChanged { property, old, new } => write!(
+                f, "~ {}: {}: {} -> {}",
+                self.path,
+                property.to_str(),
+                old.as_ref().map(|p| p.value()).unwrap_or_else(|| "(unset)".to_string()),
+                new.as_ref().map(|p| p.value()).unwrap_or_else(|| "(unset)".to_string()),
+            ),
         }
     }
 }
 
-impl fmt::Debug for DomRange {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}..{}", self.start, self.end)
-    }
+/// List of changes between two `StyledDom`s, in depth-first order
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DomDiff {
+    pub changes: Vec<NodeChange>,
 }
 
-impl fmt::Display for DomRange {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}..{}", self.start, self.end)
+impl DomDiff {
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
     }
-}
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum DomChange {
-    /// Node is present on the new DOM, but not on the old one = add node
-    Added(DomRange),
-    /// Node is present on the old DOM, but not on the new one = remove node
-    Removed(DomRange),
+    /// Returns the changes of the node at the given path (as printed in the diff)
+    pub fn get_changes_of(&self, path: &str) -> Vec<&NodeChange> {
+        self.changes.iter().filter(|c| c.path == path).collect()
+    }
 }
 
-impl fmt::Display for DomChange {
+impl fmt::Display for DomDiff {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use self::DomChange::*;
-        match self {
-            Added(c) => write!(f, "+ {}", c),
-            Removed(c) => write!(f, "- {}", c),
+        for change in self.changes.iter() {
+            writeln!(f, "{}", change)?;
         }
+        Ok(())
     }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct DomDiff {
-    /// What the actual changes nodes (not trees / subtrees) were in this diff, in order of appearance
-    pub changed_nodes: Vec<DomChange>,
+impl StyledDom {
+
+    /// Compares the DOM structure, node content, inline styles and computed CSS
+    /// properties of `self` (old) and `other` (new)
+    pub fn diff(&self, other: &StyledDom) -> DomDiff {
+        let mut diff = DomDiff::default();
+        let property_types = get_css_key_map().non_shorthands.values().copied().collect::<Vec<_>>();
+        match (self.root.into_crate_internal(), other.root.into_crate_internal()) {
+            (Some(old_root), Some(new_root)) => {
+                let path = get_node_label(&self.node_data.as_ref()[old_root.index()], None);
+                diff_node(self, other, old_root, new_root, path, &property_types, &mut diff.changes);
+            },
+            (Some(old_root), None) => {
+                let path = get_node_label(&self.node_data.as_ref()[old_root.index()], None);
+                push_subtree(self, old_root, path, true, &mut diff.changes);
+            },
+            (None, Some(new_root)) => {
+                let path = get_node_label(&other.node_data.as_ref()[new_root.index()], None);
+                push_subtree(other, new_root, path, false, &mut diff.changes);
+            },
+            (None, None) => { },
+        }
+        diff
+    }
 }
 
-impl DomDiff {
-    pub fn new(old: &CompactDom, new: &CompactDom) -> Self {
-
-        // TODO: Check if old root = new root, if not, change entire tree
-
-        let mut changes = BTreeSet::new();
-        let mut visited_nodes = NodeDataContainer::new(vec![false; new.len()]);
-
-        visited_nodes[NodeId::ZERO] = true;
-
-        let has_root_changed = node_has_changed(
-            &old.arena.node_data[NodeId::ZERO],
-            &new.arena.node_data[NodeId::ZERO]
-        );
-
-        if has_root_changed == NODE_CHANGED_NOTHING {
-
-            diff_tree_inner(NodeId::ZERO, old, new, &mut changes, &mut visited_nodes);
-            add_visited_nodes(visited_nodes, &mut changes);
-
-            Self {
-                changed_nodes: optimize_changeset(changes)
-            }
-
-        } else {
-
-            // Root changed = everything changed
-            changes.insert(DomChange::Removed(DomRange {
-                start: NodeId::ZERO,
-                end: NodeId::new(old.len() - 1)
-            }));
-
-            changes.insert(DomChange::Added(DomRange {
-                start: NodeId::ZERO,
-                end: NodeId::new(new.len() - 1)
-            }));
-
-            Self {
-                changed_nodes: optimize_changeset(changes)
-            }
+/// Returns "div#id.class", with "[index]" appended if the index is given
+fn get_node_label(node_data: &NodeData, index: Option<usize>) -> String {
+    let mut label = node_data.get_node_type().get_path().to_string();
+    for id_or_class in node_data.get_ids_and_classes().as_ref().iter() {
+        if let Some(id) = id_or_class.as_id() {
+            label.push('#');
+            label.push_str(id);
+        }
+        if let Some(class) = id_or_class.as_class() {
+            label.push('.');
+            label.push_str(class);
         }
     }
+    if let Some(index) = index {
+        label.push_str(&format!("[{}]", index));
+    }
+    label
+}
 
-    /// Formats the diff into a git-like `+ Node1 / - Node3` form
-    pub fn format_nicely(&self, old: &CompactDom, new: &CompactDom) -> String {
-        use self::DomChange::*;
-        self.changed_nodes.iter().map(|change| {
-            match change {
-                Added(c) => format!("+\t{}", new.arena.node_data[c.start]),
-                Removed(c) => format!("-\t{}", old.arena.node_data[c.start]),
-            }
-        }).collect::<Vec<String>>().join("\r\n")
+/// Key that is used to match children of the old and new DOM (`NodeData`
+/// has no explicit key, so children are matched by their node type)
+fn node_key(node_data: &NodeData) -> NodeTypeKey {
+    match node_data.get_node_type() {
+        NodeType::Text(_) => NodeTypeKey::Text,
+        other => NodeTypeKey::Other(other.get_path()),
     }
 }
 
-impl fmt::Display for DomDiff {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for c in self.changed_nodes.iter() {
-            write!(f, "{}", c)?;
-        }
-        Ok(())
+/// How well two children match: 0 = different node type (can't be matched),
+/// 1 = same node type, 2 = same node type, ids and classes
+fn match_score(old: &NodeData, new: &NodeData) -> usize {
+    if node_key(old) != node_key(new) {
+        0
+    } else if old.get_ids_and_classes() != new.get_ids_and_classes() {
+        1
+    } else {
+        2
     }
 }
 
-impl DomRange {
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum NodeTypeKey {
+    Text,
+    Other(azul_css::NodeTypeTag),
+}
 
-    /// Is `other` a subtree of `self`? - Assumes that the DOM was
-    /// constructed in a linear order, i.e. the child being within
-    /// the parents start / end bounds
-    pub fn contains(&self, other: &Self) -> bool {
-        other.start.index() >= self.start.index() &&
-        other.end.index() <= self.end.index()
-    }
+fn get_children(styled_dom: &StyledDom, node_id: NodeId) -> Vec<NodeId> {
+    node_id.az_children(&styled_dom.node_hierarchy.as_container()).collect()
+}
 
-    /// Compares two DOM ranges *without* looking at the DOM hashes (not equivalent to `==`)
-    pub fn equals_range(&self, other: &Self) -> bool {
-        other.start == self.start &&
-        other.end == self.end
+/// Returns the computed value of the CSS property, `None` if the property is not set
+fn get_computed_property<'a>(styled_dom: &'a StyledDom, node_id: NodeId, property_type: &CssPropertyType) -> Option<&'a CssProperty> {
+    let node_data = &styled_dom.node_data.as_ref()[node_id.index()];
+    let state = &styled_dom.styled_nodes.as_ref()[node_id.index()].state;
+    styled_dom
+        .get_css_property_cache()
+        .get_property(node_data, &node_id, state, property_type)
+}
+
+fn push_subtree(styled_dom: &StyledDom, node_id: NodeId, path: String, removed: bool, changes: &mut Vec<NodeChange>) {
+    changes.push(NodeChange {
+        path: path.clone(),
+        old_node_id: if removed { Some(node_id) } else { None },
+        new_node_id: if removed { None } else { Some(node_id) },
+        kind: if removed { NodeChangeKind::Removed } else { NodeChangeKind::Added },
+    });
+    for (index, child) in get_children(styled_dom, node_id).into_iter().enumerate() {
+        let child_path = format!("{} > {}", path, get_node_label(&styled_dom.node_data.as_ref()[child.index()], Some(index)));
+        push_subtree(styled_dom, child, child_path, removed, changes);
     }
 }
 
-const NODE_CHANGED_NOTHING: u8  = 0x01;
-const NODE_CHANGED_TYPE: u8     = 0x02;
-const NODE_CHANGED_CLASSES: u8  = 0x04;
-const NODE_CHANGED_IDS: u8      = 0x08;
-
-// In order to test two DOM nodes for "equality", you'd need to
-// test if the node type, the classes and the ids are the same.
-// The rest of the attributes can be ignored, since they are not
-// used by the CSS engine.
-//
-// NOTE: The callbacks / etc. need to be changed!
-#[inline]
-fn node_has_changed(old: &NodeData, new: &NodeData) -> u8 {
-    let mut result = NODE_CHANGED_NOTHING;
-
-    if old.get_node_type() != new.get_node_type() {
-        result &= NODE_CHANGED_TYPE;
+fn diff_node(
+    old: &StyledDom,
+    new: &StyledDom,
+    old_id: NodeId,
+    new_id: NodeId,
+    path: String,
+    property_types: &[CssPropertyType],
+    changes: &mut Vec<NodeChange>,
+) {
+    let old_data = &old.node_data.as_ref()[old_id.index()];
+    let new_data = &new.node_data.as_ref()[new_id.index()];
+
+    let mut push = |kind: NodeChangeKind| changes.push(NodeChange {
+        path: path.clone(),
+        old_node_id: Some(old_id),
+        new_node_id: Some(new_id),
+        kind,
+    });
+
+    match (old_data.get_node_type(), new_data.get_node_type()) {
+        (NodeType::Text(a), NodeType::Text(b)) => {
+            if a != b {
+                push(NodeChangeKind::TextChanged { old: a.as_str().to_string(), new: b.as_str().to_string() });
+            }
+        },
+        (a, b) => {
+            if a.get_path() != b.get_path() {
+                push(NodeChangeKind::NodeTypeChanged { old: a.get_path().to_string(), new: b.get_path().to_string() });
+            }
+        },
     }
 
-    if old.get_classes() != new.get_classes() {
-        result &= NODE_CHANGED_CLASSES;
+    if old_data.get_ids_and_classes() != new_data.get_ids_and_classes() {
+        let fmt = |d: &NodeData| get_node_label(d, None);
+        push(NodeChangeKind::IdsAndClassesChanged { old: fmt(old_data), new: fmt(new_data) });
+    }
+
+    if old_data.get_inline_css_props() != new_data.get_inline_css_props() {
+        push(NodeChangeKind::InlineStyleChanged {
+            old: format!("{:?}", old_data.get_inline_css_props().as_ref()),
+            new: format!("{:?}", new_data.get_inline_css_props().as_ref()),
+        });
     }
 
-    if old.get_ids() != new.get_ids() {
-        result &= NODE_CHANGED_IDS;
+    for property_type in property_types.iter() {
+        let old_value = get_computed_property(old, old_id, property_type);
+        let new_value = get_computed_property(new, new_id, property_type);
+        if old_value != new_value {
+            push(NodeChangeKind::PropertyChanged {
+                property: *property_type,
+                old: old_value.cloned(),
+                new: new_value.cloned(),
+            });
+        }
     }
 
-    result
+    diff_children(old, new, old_id, new_id, &path, property_types, changes);
 }
 
-fn diff_tree_inner(
-    old_root_id: NodeId,
-    old: &CompactDom,
-    new: &CompactDom,
-    changes: &mut BTreeSet<DomChange>,
-    visited_nodes: &mut NodeDataContainer<bool>,
+/// Matches the children of the two nodes with the longest common subsequence
+/// of their keys (weighted by `match_score`), then recurses into the matched pairs
+fn diff_children(
+    old: &StyledDom,
+    new: &StyledDom,
+    old_parent: NodeId,
+    new_parent: NodeId,
+    path: &str,
+    property_types: &[CssPropertyType],
+    changes: &mut Vec<NodeChange>,
 ) {
-    let mut node_shift = 0_isize;
-
-    for old_node_id in old_root_id.children(&old.arena.node_hierarchy) {
+    let old_children = get_children(old, old_parent);
+    let new_children = get_children(new, new_parent);
 
-        // Node ID that corresponds to the same node in the new node tree
-        let new_node_id = NodeId::new((old_node_id.index() as isize + node_shift) as usize);
+    let score = |i: usize, j: usize| match_score(
+        &old.node_data.as_ref()[old_children[i].index()],
+        &new.node_data.as_ref()[new_children[j].index()],
+    );
 
-        let old_node_last_child = NodeId::new(match old.arena.node_hierarchy[old_node_id].next_sibling {
-            Some(s) => s.index(),
-            None => old.arena.node_hierarchy.len() - 1,
-        });
+    // lcs[i][j] = score of the LCS of old_children[i..] and new_children[j..]
+    let (n, m) = (old_children.len(), new_children.len());
+    let mut lcs = vec![vec![0_usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            let skip = lcs[i + 1][j].max(lcs[i][j + 1]);
+            lcs[i][j] = match score(i, j) {
+                0 => skip,
+                s => skip.max(lcs[i + 1][j + 1] + s),
+            };
+        }
+    }
 
-        match new.arena.node_data.get(new_node_id) {
-            None => {
-                // Couldn't find the new node in the new tree, old tree has more children than new
-                changes.insert(DomChange::Removed(DomRange {
-                    start: old_node_id,
-                    end: old_node_last_child,
-                }));
-            },
-            Some(new_node_data) => {
+    let child_path = |styled_dom: &StyledDom, child: NodeId, index: usize| {
+        format!("{} > {}", path, get_node_label(&styled_dom.node_data.as_ref()[child.index()], Some(index)))
+    };
+
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        let s = if i < n && j < m { score(i, j) } else { 0 };
+        if s != 0 && lcs[i][j] == lcs[i + 1][j + 1] + s {
+            diff_node(old, new, old_children[i], new_children[j], child_path(new, new_children[j], j), property_types, changes);
+            i += 1;
+            j += 1;
+        } else if j < m && (i == n || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            push_subtree(new, new_children[j], child_path(new, new_children[j], j), false, changes);
+            j += 1;
+        } else {
+            push_subtree(old, old_children[i], child_path(old, old_children[i], i), true, changes);
+            i += 1;
+        }
+    }
+}
 
-                visited_nodes[new_node_id] = true;
+#[cfg(all(test, feature = "multithreading", feature = "css_parser"))]
+mod tests {
 
-                let old_node_data = &old.arena.node_data[old_node_id];
-                let compare_nodes = node_has_changed(old_node_data, new_node_data);
+    use super::*;
+    use crate::dom::{Dom, IdOrClass};
+    use azul_css_parser::CssApiWrapper;
 
-                if compare_nodes == NODE_CHANGED_NOTHING {
-                    diff_tree_inner(old_node_id, old, new, changes, visited_nodes);
-                } else {
+    fn styled(mut dom: Dom) -> StyledDom {
+        dom.style(CssApiWrapper::empty())
+    }
 
-                    let new_node_subtree_len = new.arena.node_hierarchy.subtree_len(new_node_id);
+    fn label(text: &str) -> Dom {
+        Dom::div()
+        .with_ids_and_classes(vec![IdOrClass::Class("label".into())].into())
+        .with_children(vec![Dom::text(text)].into())
+    }
 
-                    let next_node_id = match new.arena.node_hierarchy[new_node_id].next_sibling {
-                        Some(s) => s.index(),
-                        None => new.arena.node_hierarchy.len() - 1,
-                    };
+    #[test]
+    fn test_diff_identical() {
+        let a = styled(Dom::body().with_children(vec![label("a"), label("b")].into()));
+        assert!(a.diff(&a.clone()).is_empty());
+    }
 
-                    // remove entire old subtree, including the node itself
-                    changes.insert(DomChange::Removed(DomRange {
-                        start: old_node_id,
-                        end: old_node_last_child,
-                    }));
+    #[test]
+    fn test_diff_text_changed() {
+        let a = styled(Dom::body().with_children(vec![label("a"), label("b")].into()));
+        let b = styled(Dom::body().with_children(vec![label("a"), label("c")].into()));
+        let diff = a.diff(&b);
+        assert_eq!(diff.changes.len(), 1);
+        assert_eq!(diff.changes[0].kind, NodeChangeKind::TextChanged { old: "b".into(), new: "c".into() });
+        assert_eq!(diff.to_string(), "~ body > div.label[1] > p[0]: text \"b\" -> \"c\"\n");
+    }
 
-                    // add entire new subtree, including the node itself
-                    changes.insert(DomChange::Added(DomRange {
-                        start: new_node_id,
-                        end: NodeId::new(next_node_id),
-                    }));
+    #[test]
+    fn test_diff_inserted_child() {
+        let a = styled(Dom::body().with_children(vec![label("a"), label("c")].into()));
+        let b = styled(Dom::body().with_children(vec![label("a"), Dom::div(), label("c")].into()));
+        let diff = a.diff(&b);
+        assert_eq!(diff.changes.len(), 1);
+        assert_eq!(diff.changes[0].kind, NodeChangeKind::Added);
+        assert_eq!(diff.changes[0].path, "body > div[1]");
+    }
 
-                    node_shift += new_node_subtree_len as isize;
+    #[test]
+    fn test_diff_child_class_changed() {
+        let a = styled(Dom::body().with_children(vec![label("a"), label("b")].into()));
+        let b = styled(Dom::body().with_children(vec![
+            label("a"),
+            Dom::div()
+            .with_ids_and_classes(vec![IdOrClass::Class("title".into())].into())
+            .with_children(vec![Dom::text("b")].into()),
+        ].into()));
+        let diff = a.diff(&b);
+        assert_eq!(diff.changes.len(), 1);
+        assert_eq!(diff.changes[0].kind, NodeChangeKind::IdsAndClassesChanged {
+            old: "div.label".into(),
+            new: "div.title".into(),
+        });
+        assert_eq!(diff.changes[0].path, "body > div.title[1]");
+    }
 
-                    for n in new_node_id.index()..next_node_id {
-                        visited_nodes[NodeId::new(n)] = true;
-                    }
-                }
-            }
+    #[test]
+    fn test_diff_property_changed() {
+        let styled_css = |css: &str| Dom::body()
+            .with_children(vec![label("a")].into())
+            .style(CssApiWrapper::from_string(css.into()));
+        let a = styled_css(".label { width: 10px; }");
+        assert!(a.diff(&styled_css(".label { width: 10.0px; }")).is_empty());
+
+        let diff = a.diff(&styled_css(".label { width: 20px; }"));
+        assert_eq!(diff.changes.len(), 1);
+        match &diff.changes[0].kind {
+            NodeChangeKind::PropertyChanged { property, old, new } => {
+                assert_eq!(*property, CssPropertyType::Width);
+                assert_eq!(old.as_ref().map(|p| p.get_type()), Some(CssPropertyType::Width));
+                assert_ne!(old, new);
+            },
+            other => panic!("unexpected change: {:?}", other),
         }
+        assert_eq!(diff.to_string(), "~ body > div.label[0]: width: 10px -> 20px\n");
     }
 }
-
-fn add_visited_nodes(
-    visited_nodes: NodeDataContainer<bool>,
-    changes: &mut BTreeSet<DomChange>,
-) {
-    changes.extend(
-        visited_nodes
-        .linear_iter()
-        .filter_map(|node_id| if visited_nodes[node_id] { None } else { Some(node_id) })
-        .map(|node_id| DomChange::Added(DomRange::single_node(node_id)))
-    );
-}
-
-fn optimize_changeset(changes: BTreeSet<DomChange>) -> Vec<DomChange> {
-    // TODO: optimize changeset into larger chunks!
-    changes.into_iter().collect()
-}
@@ -5,24 +5,31 @@ use crate::{
         IdNamespace, ImageCache, ImageDescriptor, ImageKey, LoadFontFn, OpacityKey, ParseFontFn,
         PrimitiveFlags, RendererResources, ResourceUpdate, TransformKey, DpiScaleFactor,
     },
-    callbacks::{DocumentId, DomNodeId, PipelineId},
+    callbacks::{
+        DocumentId, DomNodeId, PaintCallbackInfo, PaintPrimitive, PipelineId, TextUnderlineStyle,
+    },
     dom::{ScrollTagId, TagId},
     id_tree::NodeId,
     styled_dom::{ContentGroup, DomId, NodeHierarchyItemId, StyledDom},
-    ui_solver::{ComputedTransform3D, ExternalScrollId, LayoutResult, PositionInfo},
-    window::{FullWindowState, LogicalPosition, LogicalRect, LogicalSize},
+    task::{Duration, Instant},
+    ui_solver::{ComputedTransform3D, ExternalScrollId, LayoutResult, PositionInfo, PositionInfoInner},
+    window::{
+        FullWindowState, LogicalPosition, LogicalRect, LogicalSize, ScrollStates, ScrollbarId,
+        ScrollbarInteraction,
+    },
 };
 use alloc::collections::btree_map::BTreeMap;
 use alloc::vec::Vec;
 use azul_css::{
-    BoxShadowClipMode, ColorU, ConicGradient, CssPropertyValue, LayoutBorderBottomWidth,
+    AnimationInterpolationFunction, BoxShadowClipMode, ColorU, ConicGradient, CssPropertyValue, LayoutBorderBottomWidth,
     LayoutBorderLeftWidth, LayoutBorderRightWidth, LayoutBorderTopWidth, LayoutPoint, LayoutRect,
     LayoutSize, LinearGradient, RadialGradient, StyleBackgroundPosition, StyleBackgroundRepeat,
     StyleBackgroundSize, StyleBorderBottomColor, StyleBorderBottomLeftRadius,
     StyleBorderBottomRightRadius, StyleBorderBottomStyle, StyleBorderLeftColor,
     StyleBorderLeftStyle, StyleBorderRightColor, StyleBorderRightStyle, StyleBorderTopColor,
     StyleBorderTopLeftRadius, StyleBorderTopRightRadius, StyleBorderTopStyle, StyleBoxShadow,
-    StyleMixBlendMode,
+    StyleBackfaceVisibility, StyleMixBlendMode, StylePerspectiveOrigin, StyleTransformStyle,
+    PixelValue,
 };
 use core::fmt;
 use rust_fontconfig::FcFontCache;
@@ -80,6 +87,122 @@ impl CachedDisplayList {
     }
 }
 
+/// Snapshot of the display list of a subtree, faded out over the
+/// new content of the subtree, see `CallbackInfo::crossfade_subtree()`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Crossfade {
+    /// Frame (positioned relative to the window) containing the old subtree
+    pub snapshot: DisplayListFrame,
+    pub opacity_key: OpacityKey,
+    /// Current opacity of the snapshot, updated every frame
+    pub opacity: f32,
+    pub start: Instant,
+    pub duration: Duration,
+}
+
+impl Crossfade {
+    /// Creates a snapshot of the `subtree`, `parent_position` is the
+    /// position of the parent of the subtree in window coordinates
+    pub fn new(
+        subtree: DisplayListMsg,
+        parent_position: LogicalPosition,
+        scroll_states: &ScrollStates,
+        start: Instant,
+        duration: Duration,
+    ) -> Self {
+        let mut snapshot = DisplayListFrame::root(LayoutSize::zero(), LayoutPoint::zero());
+        snapshot.position = PositionInfo::Fixed(PositionInfoInner {
+            x_offset: parent_position.x,
+            y_offset: parent_position.y,
+            static_x_offset: parent_position.x,
+            static_y_offset: parent_position.y,
+        });
+        snapshot.children.push(freeze_display_list_msg(subtree, scroll_states));
+
+        Self {
+            snapshot,
+            opacity_key: OpacityKey::unique(),
+            opacity: 1.0,
+            start,
+            duration,
+        }
+    }
+
+    #[inline]
+    fn get_progress(&self, now: &Instant) -> f32 {
+        now.duration_since(&self.start).div(&self.duration)
+    }
+
+    pub fn is_finished(&self, now: &Instant) -> bool {
+        // NaN for a zero duration
+        !(self.get_progress(now) < 1.0)
+    }
+
+    /// Updates the opacity of the snapshot
+    pub fn advance(&mut self, now: &Instant) {
+        let t = self.get_progress(now);
+        self.opacity = if t < 1.0 {
+            1.0 - AnimationInterpolationFunction::EaseInOut.evaluate(t.max(0.0) as f64)
+        } else {
+            0.0
+        };
+    }
+
+    /// Returns the snapshot (with the current opacity), drawn on top of the display list
+    pub fn get_display_list_msg(&self) -> DisplayListMsg {
+        let mut snapshot = self.snapshot.clone();
+        snapshot.opacity = Some((self.opacity_key, self.opacity));
+        DisplayListMsg::Frame(snapshot)
+    }
+}
+
+/// Detaches the snapshot of a subtree from the DOM it was created from: removes the
+/// hit-testing tags, replaces scroll frames with clipped frames (at the current scroll
+/// position) and re-keys animated transforms / opacities, so that the snapshot is not
+/// affected by the new content of the subtree
+fn freeze_display_list_msg(msg: DisplayListMsg, scroll_states: &ScrollStates) -> DisplayListMsg {
+
+    fn freeze_frame(mut frame: DisplayListFrame, scroll_states: &ScrollStates) -> DisplayListFrame {
+        frame.tag = None;
+        frame.transform = frame.transform.map(|(_, t)| (TransformKey::unique(), t));
+        frame.opacity = frame.opacity.map(|(_, o)| (OpacityKey::unique(), o));
+        frame.children = frame
+            .children
+            .into_iter()
+            .map(|c| freeze_display_list_msg(c, scroll_states))
+            .collect();
+        frame
+    }
+
+    match msg {
+        DisplayListMsg::IFrame(..) => msg,
+        DisplayListMsg::Frame(f) => DisplayListMsg::Frame(freeze_frame(f, scroll_states)),
+        DisplayListMsg::ScrollFrame(sf) => {
+            let scroll_position = scroll_states
+                .get_scroll_position(&sf.scroll_id)
+                .unwrap_or(LogicalPosition::zero());
+            let offset_x = sf.content_rect.origin.x - sf.parent_rect.origin.x + scroll_position.x;
+            let offset_y = sf.content_rect.origin.y - sf.parent_rect.origin.y + scroll_position.y;
+
+            let mut frame = sf.frame;
+            frame.clip_children = Some(frame.size);
+
+            // the children are shifted by the scroll offset of the frame
+            let mut scrolled = DisplayListFrame::root(LayoutSize::zero(), LayoutPoint::zero());
+            scrolled.position = PositionInfo::Static(PositionInfoInner {
+                x_offset: -offset_x,
+                y_offset: -offset_y,
+                static_x_offset: -offset_x,
+                static_y_offset: -offset_y,
+            });
+            scrolled.children = core::mem::replace(&mut frame.children, Vec::new());
+            frame.children.push(DisplayListMsg::Frame(scrolled));
+
+            DisplayListMsg::Frame(freeze_frame(frame, scroll_states))
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub enum DisplayListMsg {
     // nested display list
@@ -115,6 +238,15 @@ impl DisplayListMsg {
         }
     }
 
+    pub fn get_transform_style(&self) -> StyleTransformStyle {
+        use self::DisplayListMsg::*;
+        match self {
+            Frame(f) => f.transform_style,
+            ScrollFrame(sf) => sf.frame.transform_style,
+            IFrame(_, _, _, _) => StyleTransformStyle::Flat,
+        }
+    }
+
     pub fn get_mix_blend_mode(&self) -> Option<&StyleMixBlendMode> {
         use self::DisplayListMsg::*;
         match self {
@@ -246,6 +378,9 @@ pub struct DisplayListScrollFrame {
     pub scroll_tag: ScrollTagId,
     /// Content + children of the scroll clip
     pub frame: DisplayListFrame,
+    /// Built-in scrollbars, drawn on top of the children
+    /// (relative to the frame, not scrolled with the content)
+    pub scrollbars: Vec<DisplayListScrollbar>,
 }
 
 impl DisplayListScrollFrame {
@@ -253,6 +388,26 @@ impl DisplayListScrollFrame {
         self.parent_rect.scale_for_dpi(scale_factor);
         self.content_rect.scale_for_dpi(scale_factor);
         self.frame.scale_for_dpi(scale_factor);
+        for scrollbar in self.scrollbars.iter_mut() {
+            scrollbar.scale_for_dpi(scale_factor);
+        }
+    }
+}
+
+/// Track and thumb of a built-in scrollbar
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct DisplayListScrollbar {
+    pub track: LogicalRect,
+    pub track_color: ColorU,
+    pub thumb: LogicalRect,
+    /// Thumb color, already resolved for the hover / active state
+    pub thumb_color: ColorU,
+}
+
+impl DisplayListScrollbar {
+    pub fn scale_for_dpi(&mut self, scale_factor: f32) {
+        self.track.scale_for_dpi(scale_factor);
+        self.thumb.scale_for_dpi(scale_factor);
     }
 }
 
@@ -262,6 +417,7 @@ impl fmt::Debug for DisplayListScrollFrame {
         write!(f, "    parent_rect: {}\r\n", self.parent_rect)?;
         write!(f, "    content_rect: {}\r\n", self.content_rect)?;
         write!(f, "    scroll_tag: {}\r\n", self.scroll_tag)?;
+        write!(f, "    scrollbars: {:?}\r\n", self.scrollbars)?;
         write!(f, "    frame: DisplayListFrame {{\r\n")?;
         let frame = format!("{:#?}", self.frame);
         let frame = frame
@@ -290,6 +446,11 @@ pub struct DisplayListFrame {
     // box shadow has to be pushed twice: once as inset and once as outset
     pub box_shadow: Option<BoxShadow>,
     pub transform: Option<(TransformKey, ComputedTransform3D)>,
+    /// Perspective that is applied to the children (`perspective` + `perspective-origin`)
+    pub perspective: Option<ComputedTransform3D>,
+    /// `transform-style: preserve-3d` positions the children in the 3D space of this
+    /// frame, so that they are depth-sorted (and split if they intersect) together
+    pub transform_style: StyleTransformStyle,
     pub opacity: Option<(OpacityKey, f32)>,
     pub content: Vec<LayoutRectContent>,
     pub children: Vec<DisplayListMsg>,
@@ -336,6 +497,7 @@ impl DisplayListFrame {
         self.clip_mask.as_mut().map(|s| s.scale_for_dpi(scale_factor));
         self.border_radius.scale_for_dpi(scale_factor);
         self.transform.as_mut().map(|(k, v)| v.scale_for_dpi(scale_factor));
+        self.perspective.as_mut().map(|p| p.scale_for_dpi(scale_factor));
         for c in self.content.iter_mut() {
             c.scale_for_dpi(scale_factor);
         }
@@ -367,6 +529,8 @@ impl DisplayListFrame {
             border_radius: StyleBorderRadius::default(),
             box_shadow: None,
             transform: None,
+            perspective: None,
+            transform_style: StyleTransformStyle::Flat,
             opacity: None,
             content: vec![],
             children: vec![],
@@ -584,6 +748,13 @@ pub enum LayoutRectContent {
         colors: StyleBorderColors,
         styles: StyleBorderStyles,
     },
+    /// Underline of a text range (i.e. a spelling error), the line is
+    /// drawn in the top third of the bounds, wavy lines use the full height
+    Underline {
+        bounds: LogicalRect,
+        color: ColorU,
+        style: TextUnderlineStyle,
+    },
 }
 
 impl LayoutRectContent {
@@ -631,6 +802,13 @@ impl LayoutRectContent {
             } => {
                 widths.scale_for_dpi(scale_factor);
             },
+            Underline {
+                bounds,
+                color,
+                style,
+            } => {
+                bounds.scale_for_dpi(scale_factor);
+            },
         }
     }
 }
@@ -714,6 +892,21 @@ impl fmt::Debug for LayoutRectContent {
                     widths, colors, styles,
                 )
             }
+            Underline {
+                bounds,
+                color,
+                style,
+            } => {
+                write!(
+                    f,
+                    "Underline {{\r\n\
+                        bounds: {:?},\r\n\
+                        color: {},\r\n\
+                        style: {:?}\r\n\
+                    }}",
+                    bounds, color, style,
+                )
+            }
         }
     }
 }
@@ -786,6 +979,10 @@ pub struct DisplayListParametersRef<'a> {
     pub image_cache: &'a ImageCache,
     /// Reference to the RendererResources, necessary to query info about image and font keys
     pub renderer_resources: &'a RendererResources,
+    /// Current scroll positions, necessary to position the scrollbar thumbs
+    pub scroll_states: &'a ScrollStates,
+    /// Hover / drag state of the scrollbar thumbs
+    pub scrollbar_interaction: &'a ScrollbarInteraction,
 }
 
 // todo: very unclean
@@ -851,6 +1048,119 @@ impl SolvedLayout {
     }
 }
 
+/// Margin (in logical pixels) around the viewport in which offscreen items are still
+/// pushed into the display list, so that scrolling by less than the margin can be
+/// rendered by only re-compositing the last display list
+pub const DISPLAY_LIST_CULL_MARGIN: f32 = 1000.0;
+
+/// Removes all content groups whose bounds (in window coordinates, after scrolling)
+/// are fully outside of the `cull_rect` from the `content_group`
+///
+/// Fixed / absolute positioned, transformed and overlay nodes (and their ancestors)
+/// are never culled, since their final position is not known by the layout.
+pub fn cull_offscreen_content_groups(
+    content_group: &mut ContentGroup,
+    layout_result: &LayoutResult,
+    scroll_states: &ScrollStates,
+    cull_rect: &LogicalRect,
+) {
+    let _ = cull_content_group_recursive(
+        content_group,
+        layout_result,
+        scroll_states,
+        cull_rect,
+        LogicalPosition::zero(),
+    );
+}
+
+/// Culls the children of the `content_group`, returns the bounds of the remaining
+/// content group or `None` if the bounds are unknown (the group must not be culled)
+fn cull_content_group_recursive(
+    content_group: &mut ContentGroup,
+    layout_result: &LayoutResult,
+    scroll_states: &ScrollStates,
+    cull_rect: &LogicalRect,
+    scroll_offset: LogicalPosition,
+) -> Option<LogicalRect> {
+    // the children of a scroll frame are shifted by the scroll position of the frame
+    let children_scroll_offset = match layout_result
+        .scrollable_nodes
+        .overflowing_nodes
+        .get(&content_group.root)
+    {
+        Some(scroll_node) => {
+            let scroll_position = scroll_states
+                .get_scroll_position(&scroll_node.parent_external_scroll_id)
+                .unwrap_or(LogicalPosition::zero());
+            LogicalPosition::new(
+                scroll_offset.x + scroll_position.x,
+                scroll_offset.y + scroll_position.y,
+            )
+        }
+        None => scroll_offset,
+    };
+
+    let mut is_bounded = true;
+    let mut children_bounds = Vec::new();
+
+    let children = core::mem::replace(&mut content_group.children, Vec::new().into())
+        .into_library_owned_vec()
+        .into_iter()
+        .filter_map(|mut child| {
+            match cull_content_group_recursive(
+                &mut child,
+                layout_result,
+                scroll_states,
+                cull_rect,
+                children_scroll_offset,
+            ) {
+                Some(bounds) => {
+                    let is_visible = bounds.min_x() < cull_rect.max_x()
+                        && bounds.max_x() > cull_rect.min_x()
+                        && bounds.min_y() < cull_rect.max_y()
+                        && bounds.max_y() > cull_rect.min_y();
+                    if !is_visible {
+                        return None;
+                    }
+                    children_bounds.push(bounds);
+                }
+                None => {
+                    is_bounded = false;
+                }
+            }
+            Some(child)
+        })
+        .collect::<Vec<_>>();
+
+    content_group.children = children.into();
+
+    let node_id = content_group.root.into_crate_internal()?;
+
+    if !is_bounded
+        || layout_result.gpu_value_cache.transform_keys.contains_key(&node_id)
+        || layout_result.styled_dom.node_data.as_container()[node_id].is_overlay()
+    {
+        return None;
+    }
+
+    let positioned_rect = &layout_result.rects.as_ref()[node_id];
+    match positioned_rect.position {
+        PositionInfo::Static(_) | PositionInfo::Relative(_) => {}
+        PositionInfo::Fixed(_) | PositionInfo::Absolute(_) => return None,
+    }
+
+    let static_offset = positioned_rect.position.get_static_offset();
+    let own_bounds = LogicalRect::new(
+        LogicalPosition::new(
+            static_offset.x - scroll_offset.x,
+            static_offset.y - scroll_offset.y,
+        ),
+        positioned_rect.size,
+    );
+
+    LogicalRect::union(core::iter::once(own_bounds).chain(children_bounds.into_iter()))
+}
+
 #[cfg(feature = "multithreading")]
 pub fn push_rectangles_into_displaylist<'a>(
     root_content_group: &ContentGroup,
@@ -879,6 +1189,45 @@ pub fn push_rectangles_into_displaylist<'a>(
 
 /// Push a single rectangle into the display list builder
 #[cfg(feature = "multithreading")]
+/// Converts a primitive returned by a `PaintCallback` into a frame, positioned
+/// relative to the custom layout node that painted it
+fn get_paint_primitive_frame(primitive: &PaintPrimitive, content_offset: LogicalPosition) -> DisplayListFrame {
+    use crate::ui_solver::PositionInfoInner;
+
+    match primitive {
+        PaintPrimitive::Rect(r) => {
+            let x = content_offset.x + r.bounds.origin.x;
+            let y = content_offset.y + r.bounds.origin.y;
+            let mut frame = DisplayListFrame::root(LayoutSize::zero(), LayoutPoint::zero());
+            frame.size = r.bounds.size;
+            frame.position = PositionInfo::Static(PositionInfoInner {
+                x_offset: x,
+                y_offset: y,
+                static_x_offset: x,
+                static_y_offset: y,
+            });
+            frame.flags.prefer_compositor_surface = false;
+            frame.flags.supports_external_compositor_surface = false;
+            if r.border_radius > 0.0 {
+                let radius = PixelValue::px(r.border_radius);
+                frame.border_radius = StyleBorderRadius {
+                    top_left: Some(CssPropertyValue::Exact(StyleBorderTopLeftRadius { inner: radius })),
+                    top_right: Some(CssPropertyValue::Exact(StyleBorderTopRightRadius { inner: radius })),
+                    bottom_left: Some(CssPropertyValue::Exact(StyleBorderBottomLeftRadius { inner: radius })),
+                    bottom_right: Some(CssPropertyValue::Exact(StyleBorderBottomRightRadius { inner: radius })),
+                };
+            }
+            frame.content.push(LayoutRectContent::Background {
+                content: RectBackground::Color(r.color),
+                size: None,
+                offset: None,
+                repeat: None,
+            });
+            frame
+        }
+    }
+}
+
 pub fn displaylist_handle_rect<'a>(
     rect_idx: NodeId,
     referenced_content: &DisplayListParametersRef<'a>,
@@ -894,6 +1243,8 @@ pub fn displaylist_handle_rect<'a>(
         gl_texture_cache,
         renderer_resources,
         image_cache,
+        scroll_states,
+        scrollbar_interaction,
         ..
     } = referenced_content;
 
@@ -960,6 +1311,49 @@ pub fn displaylist_handle_rect<'a>(
         .and_then(|p| p.get_property())
         .cloned();
 
+    let is_backface_visible = layout_result
+        .styled_dom
+        .get_css_property_cache()
+        .get_backface_visibility(&html_node, &rect_idx, &styled_node.state)
+        .and_then(|p| p.get_property())
+        .copied()
+        .unwrap_or_default()
+        == StyleBackfaceVisibility::Visible;
+
+    let transform_style = layout_result
+        .styled_dom
+        .get_css_property_cache()
+        .get_transform_style(&html_node, &rect_idx, &styled_node.state)
+        .and_then(|p| p.get_property())
+        .copied()
+        .unwrap_or_default();
+
+    let perspective = layout_result
+        .styled_dom
+        .get_css_property_cache()
+        .get_perspective(&html_node, &rect_idx, &styled_node.state)
+        .and_then(|p| p.get_property())
+        .map(|p| p.inner.to_pixels(0.0))
+        .filter(|d| *d > 0.0)
+        .map(|d| {
+            // initial value of perspective-origin is the center of the node
+            let origin = layout_result
+                .styled_dom
+                .get_css_property_cache()
+                .get_perspective_origin(&html_node, &rect_idx, &styled_node.state)
+                .and_then(|p| p.get_property())
+                .copied()
+                .unwrap_or(StylePerspectiveOrigin {
+                    x: PixelValue::const_percent(50),
+                    y: PixelValue::const_percent(50),
+                });
+            let origin_x = origin.x.to_pixels(positioned_rect.size.width);
+            let origin_y = origin.y.to_pixels(positioned_rect.size.height);
+            ComputedTransform3D::new_translation(-origin_x, -origin_y, 0.0)
+                .then(&ComputedTransform3D::new_perspective(d))
+                .then(&ComputedTransform3D::new_translation(origin_x, origin_y, 0.0))
+        });
+
     let mut frame = DisplayListFrame {
         tag: tag_id.map(|t| t.into_crate_internal()),
         size: positioned_rect.size,
@@ -1012,7 +1406,7 @@ pub fn displaylist_handle_rect<'a>(
                 .cloned(),
         },
         flags: PrimitiveFlags {
-            is_backface_visible: false, // TODO!
+            is_backface_visible,
             is_scrollbar_container: false,
             is_scrollbar_thumb: false,
             prefer_compositor_surface: false,
@@ -1035,6 +1429,8 @@ pub fn displaylist_handle_rect<'a>(
                         .cloned()?,
                 ))
             }),
+        perspective,
+        transform_style,
         opacity: layout_result
             .gpu_value_cache
             .opacity_keys
@@ -1186,6 +1582,27 @@ pub fn displaylist_handle_rect<'a>(
 
     match html_node.get_node_type() {
         Div | Body | Br => {}
+        CustomLayout(c) => {
+            // without a paint callback, the content is drawn by the embedder
+            // and only the background / border is drawn here
+            if let Some(paint) = c.paint.as_ref() {
+                let node_rect = positioned_rect.get_node_rect();
+                let content_offset = LogicalPosition::new(
+                    node_rect.content_box.origin.x - node_rect.border_box.origin.x,
+                    node_rect.content_box.origin.y - node_rect.border_box.origin.y,
+                );
+                let info = PaintCallbackInfo {
+                    size: node_rect.content_box.size,
+                };
+                let mut data = c.data.clone();
+                let primitives = (paint.cb)(&mut data, &info);
+                for primitive in primitives.as_ref().iter() {
+                    frame.children.push(DisplayListMsg::Frame(
+                        get_paint_primitive_frame(primitive, content_offset),
+                    ));
+                }
+            }
+        }
         Text(_) => {
             use crate::app_resources::get_inline_text;
 
@@ -1234,6 +1651,18 @@ pub fn displaylist_handle_rect<'a>(
                         glyph_options: None,
                         overflow: (overflow_horizontal_visible, overflow_vertical_visible),
                     });
+
+                    if let Some(underlines) = layout_result.text_underlines.get(&rect_idx) {
+                        for underline in underlines.iter() {
+                            for bounds in inline_text.get_underline_rects(underline.start, underline.end) {
+                                frame.content.push(LayoutRectContent::Underline {
+                                    bounds,
+                                    color: underline.color,
+                                    style: underline.style,
+                                });
+                            }
+                        }
+                    }
                 }
             }
         }
@@ -1313,6 +1742,8 @@ pub fn displaylist_handle_rect<'a>(
                     referenced_content.gl_texture_cache,
                     referenced_content.renderer_resources,
                     referenced_content.image_cache,
+                    referenced_content.scroll_states,
+                    referenced_content.scrollbar_interaction,
                 );
                 let iframe_clip_size = positioned_rect.size;
                 frame.children.push(DisplayListMsg::IFrame(
@@ -1405,13 +1836,66 @@ pub fn displaylist_handle_rect<'a>(
         .overflowing_nodes
         .get(&NodeHierarchyItemId::from_crate_internal(Some(rect_idx)))
     {
-        Some(scroll_node) => Some(DisplayListMsg::ScrollFrame(DisplayListScrollFrame {
-            parent_rect: scroll_node.parent_rect,
-            content_rect: scroll_node.child_rect,
-            scroll_id: scroll_node.parent_external_scroll_id,
-            scroll_tag: scroll_node.scroll_tag_id,
-            frame,
-        })),
+        Some(scroll_node) => {
+            let scroll_position = scroll_states
+                .get_scroll_position(&scroll_node.parent_external_scroll_id)
+                .unwrap_or(LogicalPosition::zero());
+
+            let config = &scrollbar_interaction.config;
+            let scrollbars = match layout_result.get_scrollbar_style(rect_idx, config.mode) {
+                // auto-hiding scrollbars that have faded out completely
+                Some(_) if scrollbar_interaction.opacity <= 0.0 => Vec::new(),
+                Some(style) => {
+                    let (horizontal, vertical) = scroll_node.get_scrollbar_rects(
+                        scroll_position,
+                        style.thickness,
+                        config.min_thumb_length,
+                    );
+                    horizontal
+                        .into_iter()
+                        .chain(vertical.into_iter())
+                        .map(|rects| {
+                            let scrollbar_id = ScrollbarId {
+                                dom_id: *dom_id,
+                                node_id: NodeHierarchyItemId::from_crate_internal(Some(rect_idx)),
+                                orientation: rects.orientation,
+                            };
+                            let thumb_color = if scrollbar_interaction.is_thumb_dragged(&scrollbar_id) {
+                                style.thumb_active
+                            } else if scrollbar_interaction.is_thumb_hovered(&scrollbar_id) {
+                                style.thumb_hover
+                            } else {
+                                style.thumb
+                            };
+                            DisplayListScrollbar {
+                                track: rects.track,
+                                track_color: scale_alpha(style.track, scrollbar_interaction.opacity),
+                                thumb: rects.thumb,
+                                thumb_color: scale_alpha(thumb_color, scrollbar_interaction.opacity),
+                            }
+                        })
+                        .collect()
+                }
+                None => Vec::new(), // scrollbar-width: none
+            };
+
+            Some(DisplayListMsg::ScrollFrame(DisplayListScrollFrame {
+                parent_rect: scroll_node.parent_rect,
+                content_rect: scroll_node.child_rect,
+                scroll_id: scroll_node.parent_external_scroll_id,
+                scroll_tag: scroll_node.scroll_tag_id,
+                frame,
+                scrollbars,
+            }))
+        }
         None => Some(DisplayListMsg::Frame(frame)),
     }
 }
+
+/// Multiplies the alpha of the color with the opacity of (auto-hiding) scrollbars
+fn scale_alpha(color: ColorU, opacity: f32) -> ColorU {
+    ColorU {
+        a: libm::roundf(color.a as f32 * opacity.max(0.0).min(1.0)) as u8,
+        ..color
+    }
+}
@@ -0,0 +1,959 @@
+//! Binary serialization of a `CachedDisplayList`
+//!
+//! The format is a little-endian, versioned dump of the display list tree, so that
+//! built frames can be cached on disk, sent to a compositor process or attached to
+//! bug reports and replayed later. Image and font keys are serialized as-is: the
+//! process that replays the display list has to register the resources under the
+//! same keys (or only the geometry of the frame will be meaningful).
+
+use crate::{
+    app_resources::{
+        Epoch, FontInstanceKey, FontRenderMode, GlyphOptions, IdNamespace, ImageDescriptor,
+        ImageDescriptorFlags, ImageKey, OpacityKey, PrimitiveFlags, RawImageFormat, TransformKey,
+    },
+    callbacks::{PipelineId, TextUnderlineStyle},
+    display_list::{
+        AlphaType, BoxShadow, CachedDisplayList, DisplayListFrame, DisplayListImageMask,
+        DisplayListMsg, DisplayListScrollFrame, DisplayListScrollbar, GlyphInstance,
+        ImageRendering, LayoutRectContent, RectBackground, StyleBorderColors, StyleBorderRadius,
+        StyleBorderStyles, StyleBorderWidths,
+    },
+    dom::{ScrollTagId, TagId},
+    ui_solver::{ComputedTransform3D, ExternalScrollId, PositionInfo, PositionInfoInner},
+    window::{LogicalPosition, LogicalRect, LogicalSize},
+};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use azul_css::{
+    AngleMetric, AngleValue, BackgroundPositionHorizontal, BackgroundPositionVertical,
+    BorderStyle, BoxShadowClipMode, ColorU, ConicGradient, CssPropertyValue, Direction,
+    DirectionCorner, DirectionCorners, ExtendMode, FloatValue, LayoutBorderBottomWidth,
+    LayoutBorderLeftWidth, LayoutBorderRightWidth, LayoutBorderTopWidth, LinearGradient,
+    NormalizedLinearColorStop, NormalizedLinearColorStopVec, NormalizedRadialColorStop,
+    NormalizedRadialColorStopVec, OptionI32, PercentageValue, PixelValue, PixelValueNoPercent,
+    RadialGradient, RadialGradientSize, Shape, SizeMetric, StyleBackgroundPosition,
+    StyleBackgroundRepeat, StyleBackgroundSize, StyleBorderBottomColor,
+    StyleBorderBottomLeftRadius, StyleBorderBottomRightRadius, StyleBorderBottomStyle,
+    StyleBorderLeftColor, StyleBorderLeftStyle, StyleBorderRightColor, StyleBorderRightStyle,
+    StyleBorderTopColor, StyleBorderTopLeftRadius, StyleBorderTopRightRadius,
+    StyleBorderTopStyle, StyleBoxShadow, StyleMixBlendMode, StyleTransformStyle,
+};
+use core::fmt;
+
+/// Magic bytes at the start of every serialized display list
+pub const DISPLAY_LIST_MAGIC: [u8; 4] = *b"AZDL";
+/// Version of the binary format, incremented on every incompatible change
+pub const DISPLAY_LIST_FORMAT_VERSION: u32 = 1;
+/// Maximum nesting depth of frames, scroll frames and iframes that will be decoded
+pub const DISPLAY_LIST_MAX_DEPTH: usize = 128;
+
+/// Upper bound (in bytes) of what a length prefix may allocate before its items are decoded
+const MAX_PREALLOCATION: usize = 64 * 1024;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DisplayListDecodeError {
+    /// Input does not start with `DISPLAY_LIST_MAGIC`
+    InvalidMagic,
+    /// Display list was serialized with a different version of the format
+    UnsupportedVersion(u32),
+    /// Input ended in the middle of the display list
+    UnexpectedEnd,
+    /// Input contains bytes after the end of the display list
+    TrailingBytes(usize),
+    /// Enum discriminant that does not exist
+    InvalidTag { type_name: &'static str, tag: u8 },
+    /// Frames are nested deeper than `DISPLAY_LIST_MAX_DEPTH`
+    NestingTooDeep,
+}
+
+impl fmt::Display for DisplayListDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::DisplayListDecodeError::*;
+        match self {
+            InvalidMagic => write!(f, "not a serialized display list"),
+            UnsupportedVersion(v) => write!(
+                f,
+                "unsupported display list format version {} (expected {})",
+                v, DISPLAY_LIST_FORMAT_VERSION
+            ),
+            UnexpectedEnd => write!(f, "unexpected end of the display list"),
+            TrailingBytes(n) => write!(f, "{} trailing bytes after the display list", n),
+            InvalidTag { type_name, tag } => write!(f, "invalid tag {} for {}", tag, type_name),
+            NestingTooDeep => write!(
+                f,
+                "display list is nested deeper than {} frames",
+                DISPLAY_LIST_MAX_DEPTH
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DisplayListDecodeError {}
+
+impl CachedDisplayList {
+    /// Serializes the display list into the binary display list format
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut w = Vec::new();
+        w.extend_from_slice(&DISPLAY_LIST_MAGIC);
+        DISPLAY_LIST_FORMAT_VERSION.encode(&mut w);
+        self.encode(&mut w);
+        w
+    }
+
+    /// Deserializes a display list that was serialized with `CachedDisplayList::to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DisplayListDecodeError> {
+        let mut r = Reader {
+            bytes,
+            position: 0,
+            depth: 0,
+        };
+        if r.read_bytes(DISPLAY_LIST_MAGIC.len())? != &DISPLAY_LIST_MAGIC[..] {
+            return Err(DisplayListDecodeError::InvalidMagic);
+        }
+        let version = u32::decode(&mut r)?;
+        if version != DISPLAY_LIST_FORMAT_VERSION {
+            return Err(DisplayListDecodeError::UnsupportedVersion(version));
+        }
+        let display_list = CachedDisplayList::decode(&mut r)?;
+        match r.remaining() {
+            0 => Ok(display_list),
+            n => Err(DisplayListDecodeError::TrailingBytes(n)),
+        }
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    /// Number of `DisplayListMsg`s that are currently being decoded
+    depth: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], DisplayListDecodeError> {
+        if self.remaining() < len {
+            return Err(DisplayListDecodeError::UnexpectedEnd);
+        }
+        let bytes = &self.bytes[self.position..self.position + len];
+        self.position += len;
+        Ok(bytes)
+    }
+}
+
+trait Encode {
+    fn encode(&self, w: &mut Vec<u8>);
+}
+
+trait Decode: Sized {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError>;
+}
+
+macro_rules! impl_codec_number {
+    ($($ty:ident),*) => {$(
+        impl Encode for $ty {
+            fn encode(&self, w: &mut Vec<u8>) {
+                w.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+
+        impl Decode for $ty {
+            fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+                let mut bytes = [0; core::mem::size_of::<$ty>()];
+                bytes.copy_from_slice(r.read_bytes(core::mem::size_of::<$ty>())?);
+                Ok($ty::from_le_bytes(bytes))
+            }
+        }
+    )*};
+}
+
+impl_codec_number!(u8, u32, u64, i32, i64);
+
+/// Encodes the fields of a struct in declaration order
+macro_rules! impl_codec_struct {
+    ($ty:ident { $($field:tt),* $(,)? }) => {
+        impl Encode for $ty {
+            fn encode(&self, w: &mut Vec<u8>) {
+                $(self.$field.encode(w);)*
+            }
+        }
+
+        impl Decode for $ty {
+            fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+                Ok($ty { $($field: Decode::decode(r)?,)* })
+            }
+        }
+    };
+}
+
+/// Encodes a fieldless enum as a one-byte tag
+macro_rules! impl_codec_unit_enum {
+    ($ty:ident { $($tag:literal => $variant:ident),* $(,)? }) => {
+        impl Encode for $ty {
+            fn encode(&self, w: &mut Vec<u8>) {
+                let tag: u8 = match self { $($ty::$variant => $tag,)* };
+                tag.encode(w);
+            }
+        }
+
+        impl Decode for $ty {
+            fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+                match u8::decode(r)? {
+                    $($tag => Ok($ty::$variant),)*
+                    tag => Err(DisplayListDecodeError::InvalidTag { type_name: stringify!($ty), tag }),
+                }
+            }
+        }
+    };
+}
+
+/// Encodes an `impl_vec!` type like a `Vec`
+macro_rules! impl_codec_az_vec {
+    ($($ty:ident => $item:ident),*) => {$(
+        impl Encode for $ty {
+            fn encode(&self, w: &mut Vec<u8>) {
+                encode_slice(self.as_ref(), w);
+            }
+        }
+
+        impl Decode for $ty {
+            fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+                Vec::<$item>::decode(r).map($ty::from_vec)
+            }
+        }
+    )*};
+}
+
+fn encode_slice<T: Encode>(slice: &[T], w: &mut Vec<u8>) {
+    (slice.len() as u64).encode(w);
+    for item in slice {
+        item.encode(w);
+    }
+}
+
+impl Encode for usize {
+    fn encode(&self, w: &mut Vec<u8>) {
+        (*self as u64).encode(w);
+    }
+}
+
+impl Decode for usize {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        u64::decode(r).map(|u| u as usize)
+    }
+}
+
+impl Encode for isize {
+    fn encode(&self, w: &mut Vec<u8>) {
+        (*self as i64).encode(w);
+    }
+}
+
+impl Decode for isize {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        i64::decode(r).map(|i| i as isize)
+    }
+}
+
+impl Encode for f32 {
+    fn encode(&self, w: &mut Vec<u8>) {
+        self.to_bits().encode(w);
+    }
+}
+
+impl Decode for f32 {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        u32::decode(r).map(f32::from_bits)
+    }
+}
+
+impl Encode for bool {
+    fn encode(&self, w: &mut Vec<u8>) {
+        (*self as u8).encode(w);
+    }
+}
+
+impl Decode for bool {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        match u8::decode(r)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            tag => Err(DisplayListDecodeError::InvalidTag { type_name: "bool", tag }),
+        }
+    }
+}
+
+impl<T: Encode> Encode for Option<T> {
+    fn encode(&self, w: &mut Vec<u8>) {
+        match self {
+            None => 0_u8.encode(w),
+            Some(s) => {
+                1_u8.encode(w);
+                s.encode(w);
+            }
+        }
+    }
+}
+
+impl<T: Decode> Decode for Option<T> {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        match u8::decode(r)? {
+            0 => Ok(None),
+            1 => Ok(Some(T::decode(r)?)),
+            tag => Err(DisplayListDecodeError::InvalidTag { type_name: "Option", tag }),
+        }
+    }
+}
+
+impl<T: Encode> Encode for Vec<T> {
+    fn encode(&self, w: &mut Vec<u8>) {
+        encode_slice(self.as_slice(), w);
+    }
+}
+
+impl<T: Decode> Decode for Vec<T> {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        let len = usize::decode(r)?;
+        // every item takes at least one byte, don't trust the length for the allocation
+        let max_items = MAX_PREALLOCATION / core::mem::size_of::<T>().max(1);
+        let mut v = Vec::with_capacity(len.min(r.remaining()).min(max_items));
+        for _ in 0..len {
+            v.push(T::decode(r)?);
+        }
+        Ok(v)
+    }
+}
+
+impl<T: Encode> Encode for Box<T> {
+    fn encode(&self, w: &mut Vec<u8>) {
+        (**self).encode(w);
+    }
+}
+
+impl<T: Decode> Decode for Box<T> {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        T::decode(r).map(Box::new)
+    }
+}
+
+impl<A: Encode, B: Encode> Encode for (A, B) {
+    fn encode(&self, w: &mut Vec<u8>) {
+        self.0.encode(w);
+        self.1.encode(w);
+    }
+}
+
+impl<A: Decode, B: Decode> Decode for (A, B) {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        Ok((A::decode(r)?, B::decode(r)?))
+    }
+}
+
+impl<T: Encode> Encode for [T; 2] {
+    fn encode(&self, w: &mut Vec<u8>) {
+        self[0].encode(w);
+        self[1].encode(w);
+    }
+}
+
+impl<T: Decode> Decode for [T; 2] {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        Ok([T::decode(r)?, T::decode(r)?])
+    }
+}
+
+impl<T: Encode> Encode for [T; 4] {
+    fn encode(&self, w: &mut Vec<u8>) {
+        for item in self.iter() {
+            item.encode(w);
+        }
+    }
+}
+
+impl<T: Decode> Decode for [T; 4] {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        Ok([T::decode(r)?, T::decode(r)?, T::decode(r)?, T::decode(r)?])
+    }
+}
+
+impl<T: Encode> Encode for CssPropertyValue<T> {
+    fn encode(&self, w: &mut Vec<u8>) {
+        match self {
+            CssPropertyValue::Auto => 0_u8.encode(w),
+            CssPropertyValue::None => 1_u8.encode(w),
+            CssPropertyValue::Initial => 2_u8.encode(w),
+            CssPropertyValue::Inherit => 3_u8.encode(w),
+            CssPropertyValue::Exact(e) => {
+                4_u8.encode(w);
+                e.encode(w);
+            }
+        }
+    }
+}
+
+impl<T: Decode> Decode for CssPropertyValue<T> {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        match u8::decode(r)? {
+            0 => Ok(CssPropertyValue::Auto),
+            1 => Ok(CssPropertyValue::None),
+            2 => Ok(CssPropertyValue::Initial),
+            3 => Ok(CssPropertyValue::Inherit),
+            4 => Ok(CssPropertyValue::Exact(T::decode(r)?)),
+            tag => Err(DisplayListDecodeError::InvalidTag { type_name: "CssPropertyValue", tag }),
+        }
+    }
+}
+
+// --- display list
+
+impl_codec_struct!(CachedDisplayList { root, root_size });
+
+impl Encode for DisplayListMsg {
+    fn encode(&self, w: &mut Vec<u8>) {
+        match self {
+            DisplayListMsg::IFrame(pipeline_id, size, epoch, display_list) => {
+                0_u8.encode(w);
+                pipeline_id.encode(w);
+                size.encode(w);
+                epoch.encode(w);
+                display_list.encode(w);
+            }
+            DisplayListMsg::Frame(frame) => {
+                1_u8.encode(w);
+                frame.encode(w);
+            }
+            DisplayListMsg::ScrollFrame(scroll_frame) => {
+                2_u8.encode(w);
+                scroll_frame.encode(w);
+            }
+        }
+    }
+}
+
+impl Decode for DisplayListMsg {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        if r.depth >= DISPLAY_LIST_MAX_DEPTH {
+            return Err(DisplayListDecodeError::NestingTooDeep);
+        }
+        r.depth += 1;
+        let msg = DisplayListMsg::decode_inner(r);
+        r.depth -= 1;
+        msg
+    }
+}
+
+impl DisplayListMsg {
+    fn decode_inner(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        match u8::decode(r)? {
+            0 => Ok(DisplayListMsg::IFrame(
+                Decode::decode(r)?,
+                Decode::decode(r)?,
+                Decode::decode(r)?,
+                Decode::decode(r)?,
+            )),
+            1 => Ok(DisplayListMsg::Frame(Decode::decode(r)?)),
+            2 => Ok(DisplayListMsg::ScrollFrame(Decode::decode(r)?)),
+            tag => Err(DisplayListDecodeError::InvalidTag { type_name: "DisplayListMsg", tag }),
+        }
+    }
+}
+
+impl_codec_struct!(DisplayListFrame {
+    size,
+    position,
+    flags,
+    mix_blend_mode,
+    clip_children,
+    clip_mask,
+    border_radius,
+    tag,
+    box_shadow,
+    transform,
+    perspective,
+    transform_style,
+    opacity,
+    content,
+    children,
+});
+
+impl_codec_struct!(DisplayListScrollFrame {
+    parent_rect,
+    content_rect,
+    scroll_id,
+    scroll_tag,
+    frame,
+    scrollbars,
+});
+
+impl_codec_struct!(DisplayListScrollbar { track, track_color, thumb, thumb_color });
+impl_codec_struct!(DisplayListImageMask { image, rect, repeat });
+impl_codec_struct!(GlyphInstance { index, point, size });
+impl_codec_struct!(StyleBorderRadius { top_left, top_right, bottom_left, bottom_right });
+impl_codec_struct!(StyleBorderWidths { top, right, bottom, left });
+impl_codec_struct!(StyleBorderColors { top, right, bottom, left });
+impl_codec_struct!(StyleBorderStyles { top, right, bottom, left });
+impl_codec_struct!(BoxShadow { clip_mode, top, right, bottom, left });
+
+impl_codec_unit_enum!(ImageRendering { 0 => Auto, 1 => CrispEdges, 2 => Pixelated });
+impl_codec_unit_enum!(AlphaType { 0 => Alpha, 1 => PremultipliedAlpha });
+
+impl Encode for LayoutRectContent {
+    fn encode(&self, w: &mut Vec<u8>) {
+        match self {
+            LayoutRectContent::Text {
+                glyphs,
+                font_instance_key,
+                color,
+                glyph_options,
+                overflow,
+                text_shadow,
+            } => {
+                0_u8.encode(w);
+                glyphs.encode(w);
+                font_instance_key.encode(w);
+                color.encode(w);
+                glyph_options.encode(w);
+                overflow.encode(w);
+                text_shadow.encode(w);
+            }
+            LayoutRectContent::Background {
+                content,
+                size,
+                offset,
+                repeat,
+            } => {
+                1_u8.encode(w);
+                content.encode(w);
+                size.encode(w);
+                offset.encode(w);
+                repeat.encode(w);
+            }
+            LayoutRectContent::Image {
+                size,
+                offset,
+                image_rendering,
+                alpha_type,
+                image_key,
+                background_color,
+            } => {
+                2_u8.encode(w);
+                size.encode(w);
+                offset.encode(w);
+                image_rendering.encode(w);
+                alpha_type.encode(w);
+                image_key.encode(w);
+                background_color.encode(w);
+            }
+            LayoutRectContent::Border {
+                widths,
+                colors,
+                styles,
+            } => {
+                3_u8.encode(w);
+                widths.encode(w);
+                colors.encode(w);
+                styles.encode(w);
+            }
+            LayoutRectContent::Underline {
+                bounds,
+                color,
+                style,
+            } => {
+                4_u8.encode(w);
+                bounds.encode(w);
+                color.encode(w);
+                style.encode(w);
+            }
+        }
+    }
+}
+
+impl Decode for LayoutRectContent {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        match u8::decode(r)? {
+            0 => Ok(LayoutRectContent::Text {
+                glyphs: Decode::decode(r)?,
+                font_instance_key: Decode::decode(r)?,
+                color: Decode::decode(r)?,
+                glyph_options: Decode::decode(r)?,
+                overflow: Decode::decode(r)?,
+                text_shadow: Decode::decode(r)?,
+            }),
+            1 => Ok(LayoutRectContent::Background {
+                content: Decode::decode(r)?,
+                size: Decode::decode(r)?,
+                offset: Decode::decode(r)?,
+                repeat: Decode::decode(r)?,
+            }),
+            2 => Ok(LayoutRectContent::Image {
+                size: Decode::decode(r)?,
+                offset: Decode::decode(r)?,
+                image_rendering: Decode::decode(r)?,
+                alpha_type: Decode::decode(r)?,
+                image_key: Decode::decode(r)?,
+                background_color: Decode::decode(r)?,
+            }),
+            3 => Ok(LayoutRectContent::Border {
+                widths: Decode::decode(r)?,
+                colors: Decode::decode(r)?,
+                styles: Decode::decode(r)?,
+            }),
+            4 => Ok(LayoutRectContent::Underline {
+                bounds: Decode::decode(r)?,
+                color: Decode::decode(r)?,
+                style: Decode::decode(r)?,
+            }),
+            tag => Err(DisplayListDecodeError::InvalidTag { type_name: "LayoutRectContent", tag }),
+        }
+    }
+}
+
+impl Encode for RectBackground {
+    fn encode(&self, w: &mut Vec<u8>) {
+        match self {
+            RectBackground::LinearGradient(g) => {
+                0_u8.encode(w);
+                g.encode(w);
+            }
+            RectBackground::RadialGradient(g) => {
+                1_u8.encode(w);
+                g.encode(w);
+            }
+            RectBackground::ConicGradient(g) => {
+                2_u8.encode(w);
+                g.encode(w);
+            }
+            RectBackground::Image(image) => {
+                3_u8.encode(w);
+                image.encode(w);
+            }
+            RectBackground::Color(c) => {
+                4_u8.encode(w);
+                c.encode(w);
+            }
+        }
+    }
+}
+
+impl Decode for RectBackground {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        match u8::decode(r)? {
+            0 => Ok(RectBackground::LinearGradient(Decode::decode(r)?)),
+            1 => Ok(RectBackground::RadialGradient(Decode::decode(r)?)),
+            2 => Ok(RectBackground::ConicGradient(Decode::decode(r)?)),
+            3 => Ok(RectBackground::Image(Decode::decode(r)?)),
+            4 => Ok(RectBackground::Color(Decode::decode(r)?)),
+            tag => Err(DisplayListDecodeError::InvalidTag { type_name: "RectBackground", tag }),
+        }
+    }
+}
+
+// --- geometry, IDs and resource keys
+
+impl_codec_struct!(LogicalPosition { x, y });
+impl_codec_struct!(LogicalSize { width, height });
+impl_codec_struct!(LogicalRect { origin, size });
+impl_codec_struct!(ComputedTransform3D { m });
+impl_codec_struct!(PositionInfoInner { x_offset, y_offset, static_x_offset, static_y_offset });
+
+impl Encode for PositionInfo {
+    fn encode(&self, w: &mut Vec<u8>) {
+        let (tag, inner): (u8, _) = match self {
+            PositionInfo::Static(p) => (0, p),
+            PositionInfo::Fixed(p) => (1, p),
+            PositionInfo::Absolute(p) => (2, p),
+            PositionInfo::Relative(p) => (3, p),
+        };
+        tag.encode(w);
+        inner.encode(w);
+    }
+}
+
+impl Decode for PositionInfo {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        match u8::decode(r)? {
+            0 => Ok(PositionInfo::Static(Decode::decode(r)?)),
+            1 => Ok(PositionInfo::Fixed(Decode::decode(r)?)),
+            2 => Ok(PositionInfo::Absolute(Decode::decode(r)?)),
+            3 => Ok(PositionInfo::Relative(Decode::decode(r)?)),
+            tag => Err(DisplayListDecodeError::InvalidTag { type_name: "PositionInfo", tag }),
+        }
+    }
+}
+
+impl_codec_struct!(PrimitiveFlags {
+    is_backface_visible,
+    is_scrollbar_container,
+    is_scrollbar_thumb,
+    prefer_compositor_surface,
+    supports_external_compositor_surface,
+});
+
+impl_codec_struct!(TagId { 0 });
+impl_codec_struct!(ScrollTagId { 0 });
+impl_codec_struct!(ExternalScrollId { 0, 1 });
+impl_codec_struct!(PipelineId { 0, 1 });
+impl_codec_struct!(IdNamespace { 0 });
+impl_codec_struct!(ImageKey { namespace, key });
+impl_codec_struct!(FontInstanceKey { namespace, key });
+impl_codec_struct!(TransformKey { id });
+impl_codec_struct!(OpacityKey { id });
+impl_codec_struct!(GlyphOptions { render_mode, flags });
+impl_codec_unit_enum!(FontRenderMode { 0 => Mono, 1 => Alpha, 2 => Subpixel });
+impl_codec_unit_enum!(TextUnderlineStyle { 0 => Solid, 1 => Dotted, 2 => Wavy });
+
+impl Encode for Epoch {
+    fn encode(&self, w: &mut Vec<u8>) {
+        self.into_u32().encode(w);
+    }
+}
+
+impl Decode for Epoch {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        u32::decode(r).map(Epoch::from)
+    }
+}
+
+impl_codec_struct!(ImageDescriptor { format, width, height, stride, offset, flags });
+impl_codec_struct!(ImageDescriptorFlags { is_opaque, allow_mipmaps });
+impl_codec_unit_enum!(RawImageFormat {
+    0 => R8,
+    1 => RG8,
+    2 => RGB8,
+    3 => RGBA8,
+    4 => R16,
+    5 => RG16,
+    6 => RGB16,
+    7 => RGBA16,
+    8 => BGR8,
+    9 => BGRA8,
+    10 => RGBF32,
+    11 => RGBAF32,
+});
+
+impl Encode for OptionI32 {
+    fn encode(&self, w: &mut Vec<u8>) {
+        self.into_option().encode(w);
+    }
+}
+
+impl Decode for OptionI32 {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        Option::<i32>::decode(r).map(|o| o.into())
+    }
+}
+
+// --- CSS values
+
+impl_codec_struct!(ColorU { r, g, b, a });
+impl_codec_struct!(FloatValue { number });
+impl_codec_struct!(PixelValue { metric, number });
+impl_codec_struct!(PixelValueNoPercent { inner });
+impl_codec_struct!(AngleValue { metric, number });
+impl_codec_unit_enum!(SizeMetric { 0 => Px, 1 => Pt, 2 => Em, 3 => Percent });
+impl_codec_unit_enum!(AngleMetric { 0 => Degree, 1 => Radians, 2 => Grad, 3 => Turn, 4 => Percent });
+
+impl Encode for PercentageValue {
+    fn encode(&self, w: &mut Vec<u8>) {
+        self.get_float_value().encode(w);
+    }
+}
+
+impl Decode for PercentageValue {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        FloatValue::decode(r).map(PercentageValue::from_float_value)
+    }
+}
+
+impl_codec_struct!(StyleBorderTopLeftRadius { inner });
+impl_codec_struct!(StyleBorderTopRightRadius { inner });
+impl_codec_struct!(StyleBorderBottomLeftRadius { inner });
+impl_codec_struct!(StyleBorderBottomRightRadius { inner });
+impl_codec_struct!(LayoutBorderTopWidth { inner });
+impl_codec_struct!(LayoutBorderRightWidth { inner });
+impl_codec_struct!(LayoutBorderBottomWidth { inner });
+impl_codec_struct!(LayoutBorderLeftWidth { inner });
+impl_codec_struct!(StyleBorderTopColor { inner });
+impl_codec_struct!(StyleBorderRightColor { inner });
+impl_codec_struct!(StyleBorderBottomColor { inner });
+impl_codec_struct!(StyleBorderLeftColor { inner });
+impl_codec_struct!(StyleBorderTopStyle { inner });
+impl_codec_struct!(StyleBorderRightStyle { inner });
+impl_codec_struct!(StyleBorderBottomStyle { inner });
+impl_codec_struct!(StyleBorderLeftStyle { inner });
+
+impl_codec_unit_enum!(BorderStyle {
+    0 => None,
+    1 => Solid,
+    2 => Double,
+    3 => Dotted,
+    4 => Dashed,
+    5 => Hidden,
+    6 => Groove,
+    7 => Ridge,
+    8 => Inset,
+    9 => Outset,
+});
+
+impl_codec_struct!(StyleBoxShadow { offset, color, blur_radius, spread_radius, clip_mode });
+impl_codec_unit_enum!(BoxShadowClipMode { 0 => Outset, 1 => Inset });
+
+impl_codec_unit_enum!(StyleMixBlendMode {
+    0 => Normal,
+    1 => Multiply,
+    2 => Screen,
+    3 => Overlay,
+    4 => Darken,
+    5 => Lighten,
+    6 => ColorDodge,
+    7 => ColorBurn,
+    8 => HardLight,
+    9 => SoftLight,
+    10 => Difference,
+    11 => Exclusion,
+    12 => Hue,
+    13 => Saturation,
+    14 => Color,
+    15 => Luminosity,
+});
+
+impl_codec_unit_enum!(StyleTransformStyle { 0 => Flat, 1 => Preserve3D });
+
+impl Encode for StyleBackgroundSize {
+    fn encode(&self, w: &mut Vec<u8>) {
+        match self {
+            StyleBackgroundSize::ExactSize(size) => {
+                0_u8.encode(w);
+                size.encode(w);
+            }
+            StyleBackgroundSize::Contain => 1_u8.encode(w),
+            StyleBackgroundSize::Cover => 2_u8.encode(w),
+        }
+    }
+}
+
+impl Decode for StyleBackgroundSize {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        match u8::decode(r)? {
+            0 => Ok(StyleBackgroundSize::ExactSize(Decode::decode(r)?)),
+            1 => Ok(StyleBackgroundSize::Contain),
+            2 => Ok(StyleBackgroundSize::Cover),
+            tag => Err(DisplayListDecodeError::InvalidTag { type_name: "StyleBackgroundSize", tag }),
+        }
+    }
+}
+
+impl_codec_struct!(StyleBackgroundPosition { horizontal, vertical });
+
+impl Encode for BackgroundPositionHorizontal {
+    fn encode(&self, w: &mut Vec<u8>) {
+        match self {
+            BackgroundPositionHorizontal::Left => 0_u8.encode(w),
+            BackgroundPositionHorizontal::Center => 1_u8.encode(w),
+            BackgroundPositionHorizontal::Right => 2_u8.encode(w),
+            BackgroundPositionHorizontal::Exact(p) => {
+                3_u8.encode(w);
+                p.encode(w);
+            }
+        }
+    }
+}
+
+impl Decode for BackgroundPositionHorizontal {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        match u8::decode(r)? {
+            0 => Ok(BackgroundPositionHorizontal::Left),
+            1 => Ok(BackgroundPositionHorizontal::Center),
+            2 => Ok(BackgroundPositionHorizontal::Right),
+            3 => Ok(BackgroundPositionHorizontal::Exact(Decode::decode(r)?)),
+            tag => Err(DisplayListDecodeError::InvalidTag { type_name: "BackgroundPositionHorizontal", tag }),
+        }
+    }
+}
+
+impl Encode for BackgroundPositionVertical {
+    fn encode(&self, w: &mut Vec<u8>) {
+        match self {
+            BackgroundPositionVertical::Top => 0_u8.encode(w),
+            BackgroundPositionVertical::Center => 1_u8.encode(w),
+            BackgroundPositionVertical::Bottom => 2_u8.encode(w),
+            BackgroundPositionVertical::Exact(p) => {
+                3_u8.encode(w);
+                p.encode(w);
+            }
+        }
+    }
+}
+
+impl Decode for BackgroundPositionVertical {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        match u8::decode(r)? {
+            0 => Ok(BackgroundPositionVertical::Top),
+            1 => Ok(BackgroundPositionVertical::Center),
+            2 => Ok(BackgroundPositionVertical::Bottom),
+            3 => Ok(BackgroundPositionVertical::Exact(Decode::decode(r)?)),
+            tag => Err(DisplayListDecodeError::InvalidTag { type_name: "BackgroundPositionVertical", tag }),
+        }
+    }
+}
+
+impl_codec_unit_enum!(StyleBackgroundRepeat { 0 => NoRepeat, 1 => Repeat, 2 => RepeatX, 3 => RepeatY });
+
+impl_codec_struct!(LinearGradient { direction, extend_mode, stops });
+impl_codec_struct!(RadialGradient { shape, size, position, extend_mode, stops });
+impl_codec_struct!(ConicGradient { extend_mode, center, angle, stops });
+impl_codec_struct!(NormalizedLinearColorStop { offset, color });
+impl_codec_struct!(NormalizedRadialColorStop { angle, color });
+impl_codec_az_vec!(
+    NormalizedLinearColorStopVec => NormalizedLinearColorStop,
+    NormalizedRadialColorStopVec => NormalizedRadialColorStop
+);
+impl_codec_unit_enum!(ExtendMode { 0 => Clamp, 1 => Repeat });
+impl_codec_unit_enum!(Shape { 0 => Ellipse, 1 => Circle });
+impl_codec_unit_enum!(RadialGradientSize {
+    0 => ClosestSide,
+    1 => ClosestCorner,
+    2 => FarthestSide,
+    3 => FarthestCorner,
+});
+
+impl Encode for Direction {
+    fn encode(&self, w: &mut Vec<u8>) {
+        match self {
+            Direction::Angle(a) => {
+                0_u8.encode(w);
+                a.encode(w);
+            }
+            Direction::FromTo(corners) => {
+                1_u8.encode(w);
+                corners.encode(w);
+            }
+        }
+    }
+}
+
+impl Decode for Direction {
+    fn decode(r: &mut Reader) -> Result<Self, DisplayListDecodeError> {
+        match u8::decode(r)? {
+            0 => Ok(Direction::Angle(Decode::decode(r)?)),
+            1 => Ok(Direction::FromTo(Decode::decode(r)?)),
+            tag => Err(DisplayListDecodeError::InvalidTag { type_name: "Direction", tag }),
+        }
+    }
+}
+
+impl_codec_struct!(DirectionCorners { from, to });
+impl_codec_unit_enum!(DirectionCorner {
+    0 => Right,
+    1 => Left,
+    2 => Top,
+    3 => Bottom,
+    4 => TopRight,
+    5 => TopLeft,
+    6 => BottomRight,
+    7 => BottomLeft,
+});
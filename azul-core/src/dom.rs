@@ -1,7 +1,11 @@
 use crate::{
     app_resources::{ImageCallback, ImageMask, RendererResources},
     app_resources::{ImageRef, ImageRefHash},
-    callbacks::{Callback, CallbackType, IFrameCallback, IFrameCallbackType, OptionRefAny, RefAny},
+    callbacks::{
+        ArrangeCallback, ArrangeCallbackType, Callback, CallbackType, IFrameCallback,
+        IFrameCallbackType, MeasureCallback, MeasureCallbackType, OptionPaintCallback,
+        OptionRefAny, PaintCallback, PaintCallbackType, RefAny,
+    },
     id_tree::{NodeDataContainer, NodeDataContainerRef, NodeDataContainerRefMut},
     styled_dom::{
         CssPropertyCache, CssPropertyCachePtr, StyleFontFamilyHash, StyledNode, StyledNodeState,
@@ -13,7 +17,9 @@ use alloc::boxed::Box;
 use alloc::collections::btree_map::BTreeMap;
 use alloc::string::String;
 use alloc::vec::Vec;
-use azul_css::{AzString, Css, CssProperty, FontRef, NodeTypeTag, OptionAzString};
+use azul_css::{
+    AzString, Css, CssProperty, FloatValue, FontRef, NodeTypeTag, OptionAzString, Style,
+};
 use core::{
     fmt,
     hash::{Hash, Hasher},
@@ -90,6 +96,10 @@ impl ::core::fmt::Debug for DomNodeHash {
     }
 }
 
+/// Default `IFrameNode::reinvoke_threshold`: re-invoke the iframe callback whenever
+/// the size or the position of the iframe changes
+pub const DEFAULT_IFRAME_REINVOKE_THRESHOLD: f32 = 0.0;
+
 /// List of core DOM node types built-into by `azul`.
 #[derive(Debug, Clone, PartialEq, Hash, Eq, PartialOrd, Ord)]
 #[repr(C, u8)]
@@ -109,6 +119,9 @@ pub enum NodeType {
     /// width / height after the layout step, necessary to render
     /// infinite datastructures
     IFrame(IFrameNode),
+    /// Leaf node whose content is measured and positioned by user callbacks,
+    /// used to embed externally rendered content into the flex layout
+    CustomLayout(CustomLayoutNode),
 }
 
 impl NodeType {
@@ -123,6 +136,13 @@ impl NodeType {
             IFrame(i) => IFrame(IFrameNode {
                 callback: i.callback,
                 data: i.data.clone(),
+                reinvoke_threshold: i.reinvoke_threshold,
+            }),
+            CustomLayout(c) => CustomLayout(CustomLayoutNode {
+                measure: c.measure,
+                arrange: c.arrange,
+                paint: c.paint,
+                data: c.data.clone(),
             }),
         }
     }
@@ -134,6 +154,7 @@ impl NodeType {
             Text(s) => Some(format!("{}", s)),
             Image(id) => Some(format!("image({:?})", id)),
             IFrame(i) => Some(format!("iframe({:?})", i)),
+            CustomLayout(c) => Some(format!("custom_layout({:?})", c)),
         }
     }
 
@@ -147,6 +168,7 @@ impl NodeType {
             Text(_) => NodeTypeTag::P,
             Image(_) => NodeTypeTag::Img,
             IFrame(_) => NodeTypeTag::IFrame,
+            CustomLayout(_) => NodeTypeTag::Div,
         }
     }
 }
@@ -180,6 +202,17 @@ pub enum On {
     /// (Specialization of `MouseUp`). Fires only if the right mouse button has
     /// been released while cursor was over the element
     RightMouseUp,
+    /// The left mouse button has been pressed twice in quick succession
+    /// over the element (fires on the second `LeftMouseDown`, using the
+    /// double-click time and distance of the operating system)
+    DoubleClick,
+    /// The left mouse button has been pressed three times in quick succession
+    /// over the element, i.e. to select an entire paragraph of text
+    TripleClick,
+    /// The mouse cursor has rested over the element for longer than the
+    /// `AppConfig::hover_intent_delay` (i.e. to show a tooltip or to prefetch data).
+    /// Does not fire if the cursor leaves the element before the delay is over.
+    HoverIntent,
     /// Mouse cursor has entered the element
     MouseEnter,
     /// Mouse cursor has left the element
@@ -208,6 +241,54 @@ pub enum On {
     FocusReceived,
     /// Equivalent to `onblur`
     FocusLost,
+    /// The user has not interacted with the system (mouse, keyboard, touch) for
+    /// longer than the `AppConfig::user_idle_timeout` - useful for presence
+    /// indicators or auto-locking the application
+    ///
+    /// NOTE: the timeout is the same for all callbacks of the application, there
+    /// is no per-callback threshold. For several thresholds, set the timeout to the
+    /// shortest one and compare `CallbackInfo::get_system_idle_time()` against the
+    /// longer ones in a timer. The idle time is polled on Windows and Linux, the
+    /// macOS event loop doesn't poll it yet, so the event never fires there.
+    UserIdle,
+    /// The user interacted with the system again after being idle
+    UserActive,
+    /// The operating system is logging out or shutting down. Set
+    /// `WindowFlags::is_session_ending` back to `false` to cancel
+    /// (if supported by the OS), i.e. to save documents first.
+    ///
+    /// NOTE: only fires on Windows (`WM_QUERYENDSESSION`). Session management
+    /// is not implemented on Linux (XSMP) and macOS (`NSWorkspaceWillPowerOff`)
+    /// yet, so there the application is shut down without this event.
+    SessionEnding,
+    /// The window entered fullscreen mode (via `WindowState::set_fullscreen`
+    /// or by the user / operating system, i.e. the macOS fullscreen button)
+    FullscreenEntered,
+    /// The window left fullscreen mode
+    FullscreenExited,
+    /// The size of the window changed (also fires if only the DPI changed).
+    /// Use `CallbackInfo::get_previous_window_state()` to get the old size.
+    WindowResized,
+    /// The position of the window changed
+    WindowMoved,
+    /// The window became the active window
+    WindowFocusReceived,
+    /// The window stopped being the active window
+    WindowFocusLost,
+    /// The DPI of the window changed, i.e. because it was moved to a different monitor
+    DpiChanged,
+    /// The window was minimized
+    Minimized,
+    /// The window was maximized
+    Maximized,
+    /// The window was restored from being minimized or maximized
+    Restored,
+    /// Another thread sent data to the application via `EventLoopProxy::send_user_event()`,
+    /// use `CallbackInfo::get_user_event()` to access the data
+    UserEvent,
+    /// The user switched between mouse, touch and pen input (i.e. on a 2-in-1 device),
+    /// see `TouchState::input_modality`
+    InputModalityChanged,
 }
 
 /// Sets the target for what events can reach the callbacks specifically.
@@ -315,6 +396,9 @@ impl From<On> for EventFilter {
             LeftMouseUp => EventFilter::Hover(HoverEventFilter::LeftMouseUp),
             MiddleMouseUp => EventFilter::Hover(HoverEventFilter::MiddleMouseUp),
             RightMouseUp => EventFilter::Hover(HoverEventFilter::RightMouseUp),
+            DoubleClick => EventFilter::Hover(HoverEventFilter::DoubleClick),
+            TripleClick => EventFilter::Hover(HoverEventFilter::TripleClick),
+            HoverIntent => EventFilter::Hover(HoverEventFilter::HoverIntent),
 
             MouseEnter => EventFilter::Hover(HoverEventFilter::MouseEnter),
             MouseLeave => EventFilter::Hover(HoverEventFilter::MouseLeave),
@@ -327,6 +411,21 @@ impl From<On> for EventFilter {
             HoveredFileCancelled => EventFilter::Hover(HoverEventFilter::HoveredFileCancelled),
             FocusReceived => EventFilter::Focus(FocusEventFilter::FocusReceived), // focus!
             FocusLost => EventFilter::Focus(FocusEventFilter::FocusLost),         // focus!
+            UserIdle => EventFilter::Window(WindowEventFilter::UserIdle),         // window!
+            UserActive => EventFilter::Window(WindowEventFilter::UserActive),     // window!
+            SessionEnding => EventFilter::Window(WindowEventFilter::SessionEnding), // window!
+            FullscreenEntered => EventFilter::Window(WindowEventFilter::FullscreenEntered), // window!
+            FullscreenExited => EventFilter::Window(WindowEventFilter::FullscreenExited), // window!
+            WindowResized => EventFilter::Window(WindowEventFilter::Resized), // window!
+            WindowMoved => EventFilter::Window(WindowEventFilter::Moved),     // window!
+            WindowFocusReceived => EventFilter::Window(WindowEventFilter::WindowFocusReceived), // window!
+            WindowFocusLost => EventFilter::Window(WindowEventFilter::WindowFocusLost), // window!
+            DpiChanged => EventFilter::Window(WindowEventFilter::DpiChanged), // window!
+            Minimized => EventFilter::Window(WindowEventFilter::Minimized),   // window!
+            Maximized => EventFilter::Window(WindowEventFilter::Maximized),   // window!
+            Restored => EventFilter::Window(WindowEventFilter::Restored),     // window!
+            UserEvent => EventFilter::Window(WindowEventFilter::UserEvent), // window!
+            InputModalityChanged => EventFilter::Window(WindowEventFilter::InputModalityChanged), // window!
         }
     }
 }
@@ -344,6 +443,9 @@ pub enum HoverEventFilter {
     LeftMouseUp,
     RightMouseUp,
     MiddleMouseUp,
+    DoubleClick,
+    TripleClick,
+    HoverIntent,
     MouseEnter,
     MouseLeave,
     Scroll,
@@ -373,6 +475,9 @@ impl HoverEventFilter {
             HoverEventFilter::LeftMouseUp => Some(FocusEventFilter::LeftMouseUp),
             HoverEventFilter::RightMouseUp => Some(FocusEventFilter::RightMouseUp),
             HoverEventFilter::MiddleMouseUp => Some(FocusEventFilter::MiddleMouseUp),
+            HoverEventFilter::DoubleClick => Some(FocusEventFilter::DoubleClick),
+            HoverEventFilter::TripleClick => Some(FocusEventFilter::TripleClick),
+            HoverEventFilter::HoverIntent => None,
             HoverEventFilter::MouseEnter => Some(FocusEventFilter::MouseEnter),
             HoverEventFilter::MouseLeave => Some(FocusEventFilter::MouseLeave),
             HoverEventFilter::Scroll => Some(FocusEventFilter::Scroll),
@@ -427,6 +532,8 @@ pub enum FocusEventFilter {
     LeftMouseUp,
     RightMouseUp,
     MiddleMouseUp,
+    DoubleClick,
+    TripleClick,
     MouseEnter,
     MouseLeave,
     Scroll,
@@ -453,6 +560,9 @@ pub enum WindowEventFilter {
     LeftMouseUp,
     RightMouseUp,
     MiddleMouseUp,
+    DoubleClick,
+    TripleClick,
+    HoverIntent,
     MouseEnter,
     MouseLeave,
     Scroll,
@@ -476,6 +586,17 @@ pub enum WindowEventFilter {
     ThemeChanged,
     WindowFocusReceived,
     WindowFocusLost,
+    UserIdle,
+    UserActive,
+    SessionEnding,
+    FullscreenEntered,
+    FullscreenExited,
+    DpiChanged,
+    Minimized,
+    Maximized,
+    Restored,
+    UserEvent,
+    InputModalityChanged,
 }
 
 impl WindowEventFilter {
@@ -490,6 +611,9 @@ impl WindowEventFilter {
             WindowEventFilter::LeftMouseUp => Some(HoverEventFilter::LeftMouseUp),
             WindowEventFilter::RightMouseUp => Some(HoverEventFilter::RightMouseUp),
             WindowEventFilter::MiddleMouseUp => Some(HoverEventFilter::MiddleMouseUp),
+            WindowEventFilter::DoubleClick => Some(HoverEventFilter::DoubleClick),
+            WindowEventFilter::TripleClick => Some(HoverEventFilter::TripleClick),
+            WindowEventFilter::HoverIntent => Some(HoverEventFilter::HoverIntent),
             WindowEventFilter::Scroll => Some(HoverEventFilter::Scroll),
             WindowEventFilter::ScrollStart => Some(HoverEventFilter::ScrollStart),
             WindowEventFilter::ScrollEnd => Some(HoverEventFilter::ScrollEnd),
@@ -515,6 +639,17 @@ impl WindowEventFilter {
             WindowEventFilter::ThemeChanged => None,
             WindowEventFilter::WindowFocusReceived => None, // specific to window!
             WindowEventFilter::WindowFocusLost => None,     // specific to window!
+            WindowEventFilter::UserIdle => None,            // specific to window!
+            WindowEventFilter::UserActive => None,          // specific to window!
+            WindowEventFilter::SessionEnding => None,       // specific to window!
+            WindowEventFilter::FullscreenEntered => None,   // specific to window!
+            WindowEventFilter::FullscreenExited => None,    // specific to window!
+            WindowEventFilter::DpiChanged => None,          // specific to window!
+            WindowEventFilter::Minimized => None,           // specific to window!
+            WindowEventFilter::Maximized => None,           // specific to window!
+            WindowEventFilter::Restored => None,            // specific to window!
+            WindowEventFilter::UserEvent => None,           // specific to window!
+            WindowEventFilter::InputModalityChanged => None, // specific to window!
         }
     }
 }
@@ -542,8 +677,86 @@ pub enum ApplicationEventFilter {
 pub struct IFrameNode {
     pub callback: IFrameCallback,
     pub data: RefAny,
+    /// Minimum change of the iframe position, width or height (in logical
+    /// pixels) that re-invokes the callback on relayout. Smaller changes
+    /// only re-layout the DOM that the callback returned last time.
+    pub reinvoke_threshold: FloatValue,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct CustomLayoutNode {
+    pub measure: MeasureCallback,
+    pub arrange: ArrangeCallback,
+    /// Draws the content of the node, if `None` the content is drawn by the embedder
+    pub paint: OptionPaintCallback,
+    pub data: RefAny,
+}
+
+/// Node type defined outside of azul-core (map view, terminal widget, ...):
+/// the measure / arrange / paint hooks that all nodes of this type share
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct CustomNodeType {
+    pub name: AzString,
+    pub measure: MeasureCallback,
+    pub arrange: ArrangeCallback,
+    pub paint: OptionPaintCallback,
+}
+
+impl CustomNodeType {
+    pub fn new(name: AzString, measure: MeasureCallbackType, arrange: ArrangeCallbackType) -> Self {
+        Self {
+            name,
+            measure: MeasureCallback { cb: measure },
+            arrange: ArrangeCallback { cb: arrange },
+            paint: OptionPaintCallback::None,
+        }
+    }
+
+    pub fn with_paint(mut self, paint: PaintCallbackType) -> Self {
+        self.paint = OptionPaintCallback::Some(PaintCallback { cb: paint });
+        self
+    }
+
+    /// Creates a node of this type with the given node-local data
+    pub fn create_node(&self, data: RefAny) -> CustomLayoutNode {
+        CustomLayoutNode {
+            measure: self.measure,
+            arrange: self.arrange,
+            paint: self.paint,
+            data,
+        }
+    }
+
+    /// Registers the node type (replacing a type with the same name),
+    /// so that nodes can be created by name via `Dom::custom_node`
+    #[cfg(feature = "std")]
+    pub fn register(self) {
+        let mut registry = match CUSTOM_NODE_TYPES.lock() {
+            Ok(o) => o,
+            Err(e) => e.into_inner(),
+        };
+        match registry.iter_mut().find(|t| t.name == self.name) {
+            Some(existing) => *existing = self,
+            None => registry.push(self),
+        }
+    }
+
+    /// Returns the registered node type with the given name
+    #[cfg(feature = "std")]
+    pub fn get(name: &str) -> Option<Self> {
+        let registry = match CUSTOM_NODE_TYPES.lock() {
+            Ok(o) => o,
+            Err(e) => e.into_inner(),
+        };
+        registry.iter().find(|t| t.name.as_str() == name).cloned()
+    }
 }
 
+#[cfg(feature = "std")]
+static CUSTOM_NODE_TYPES: std::sync::Mutex<Vec<CustomNodeType>> = std::sync::Mutex::new(Vec::new());
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub struct CallbackData {
@@ -652,6 +865,11 @@ macro_rules! parse_from_str {
                         CssDeclaration::Static(d) => {
                             Some(NodeDataInlineCssProperty::$prop_type(d.clone()))
                         }
+                        // inline properties are not restyled when the system
+                        // settings change, use the default system colors
+                        CssDeclaration::System(s) => {
+                            Some(NodeDataInlineCssProperty::$prop_type(s.default_value.clone()))
+                        }
                         _ => None,
                     })
                     .collect::<Vec<_>>();
@@ -692,6 +910,36 @@ impl NodeDataInlineCssPropertyVec {
         return parse_from_str!(s, Focus);
     }
 
+    // given Style::new().display_flex(), returns vec![NodeDataInlineCssProperty::Normal(Display::Flex)]
+    pub fn from_style(style: Style) -> Self {
+        Self::from_style_with(style, NodeDataInlineCssProperty::Normal)
+    }
+
+    // given Style::new().display_flex(), returns vec![NodeDataInlineCssProperty::Hover(Display::Flex)]
+    pub fn from_style_hover(style: Style) -> Self {
+        Self::from_style_with(style, NodeDataInlineCssProperty::Hover)
+    }
+
+    // given Style::new().display_flex(), returns vec![NodeDataInlineCssProperty::Active(Display::Flex)]
+    pub fn from_style_active(style: Style) -> Self {
+        Self::from_style_with(style, NodeDataInlineCssProperty::Active)
+    }
+
+    // given Style::new().display_flex(), returns vec![NodeDataInlineCssProperty::Focus(Display::Flex)]
+    pub fn from_style_focus(style: Style) -> Self {
+        Self::from_style_with(style, NodeDataInlineCssProperty::Focus)
+    }
+
+    fn from_style_with(style: Style, f: fn(CssProperty) -> NodeDataInlineCssProperty) -> Self {
+        style
+            .into_properties()
+            .into_library_owned_vec()
+            .into_iter()
+            .map(f)
+            .collect::<Vec<_>>()
+            .into()
+    }
+
     // appends two NodeDataInlineCssPropertyVec, even if both are &'static arrays
     pub fn with_append(&self, mut other: Self) -> Self {
         let mut m = self.clone().into_library_owned_vec();
@@ -700,6 +948,12 @@ impl NodeDataInlineCssPropertyVec {
     }
 }
 
+impl From<Style> for NodeDataInlineCssPropertyVec {
+    fn from(style: Style) -> Self {
+        Self::from_style(style)
+    }
+}
+
 impl fmt::Debug for NodeDataInlineCssProperty {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::NodeDataInlineCssProperty::*;
@@ -781,6 +1035,19 @@ impl Hash for NodeData {
             if let Some(c) = ext.context_menu.as_ref() {
                 c.hash(state);
             }
+            if let Some(c) = ext.focus_navigation.as_ref() {
+                c.hash(state);
+            }
+            if let Some(c) = ext.overlay.as_ref() {
+                c.hash(state);
+            }
+            if let Some(c) = ext.lang.as_ref() {
+                c.hash(state);
+            }
+            if let Some(c) = ext.input_mode.as_ref() {
+                c.hash(state);
+            }
+            ext.state_flags.hash(state);
         }
     }
 }
@@ -798,9 +1065,216 @@ pub struct NodeDataExt {
     pub(crate) menu_bar: Option<Box<Menu>>,
     /// Context menu that should be opened when the item is left-clicked
     pub(crate) context_menu: Option<Box<Menu>>,
+    /// Overrides for the arrow key (spatial) focus navigation
+    pub(crate) focus_navigation: Option<Box<FocusNavigation>>,
+    /// If set, this node is an overlay that is rendered above all other content
+    pub(crate) overlay: Option<Box<OverlayInfo>>,
+    /// Language of the text in this node and its children (BCP 47 tag, i.e. `"de"`),
+    /// used for hyphenation and line breaking, same as the HTML `lang` attribute
+    pub(crate) lang: Option<AzString>,
+    /// Kind of text that is entered into this node, forwarded to the IME / touch keyboard
+    pub(crate) input_mode: Option<InputMode>,
+    /// Application-defined states of this node, matched by `:disabled`, `:checked`, etc.
+    pub(crate) state_flags: Vec<NodeStateFlag>,
     // ... insert further API extensions here...
 }
 
+/// Direction of the spatial (arrow key) focus navigation
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C)]
+pub enum FocusDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Per-node overrides for the spatial focus navigation, similar to the
+/// `nav-up: #id` CSS properties: if set, pressing the arrow key moves the focus to
+/// the node with the given ID instead of the geometrically nearest focusable node.
+///
+/// See `WindowFlags::spatial_navigation_enabled`
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C)]
+pub struct FocusNavigation {
+    /// ID of the node to focus when pressing the up arrow key
+    pub up: OptionAzString,
+    /// ID of the node to focus when pressing the down arrow key
+    pub down: OptionAzString,
+    /// ID of the node to focus when pressing the left arrow key
+    pub left: OptionAzString,
+    /// ID of the node to focus when pressing the right arrow key
+    pub right: OptionAzString,
+}
+
+impl FocusNavigation {
+    /// Returns the ID of the node to focus for the given direction (without the leading `#`)
+    pub fn get(&self, direction: FocusDirection) -> Option<&str> {
+        let id = match direction {
+            FocusDirection::Up => self.up.as_ref(),
+            FocusDirection::Down => self.down.as_ref(),
+            FocusDirection::Left => self.left.as_ref(),
+            FocusDirection::Right => self.right.as_ref(),
+        }?;
+        let id = id.as_str().trim();
+        Some(id.strip_prefix('#').unwrap_or(id))
+    }
+}
+
+/// Kind of text that is entered into a text input node, same as the HTML `inputmode`
+/// attribute. Forwarded to the operating system when the node is focused, so that
+/// touch keyboards and IMEs can show the appropriate layout (i.e. a number pad).
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C)]
+pub enum InputMode {
+    /// Regular text input (default)
+    Text,
+    /// No virtual keyboard, the node implements its own input (i.e. a color picker)
+    None,
+    /// Whole numbers (i.e. a PIN or an amount of items)
+    Numeric,
+    /// Fractional numbers, the keyboard shows the decimal separator
+    Decimal,
+    /// Telephone numbers
+    Tel,
+    /// E-Mail addresses
+    Email,
+    /// URLs
+    Url,
+    /// Search queries
+    Search,
+    /// Passwords: the IME is disabled, same as for `AccessibilityState::Protected` nodes
+    Password,
+}
+
+impl Default for InputMode {
+    fn default() -> Self {
+        InputMode::Text
+    }
+}
+
+impl InputMode {
+    /// Parses the value of an HTML `inputmode` attribute (plus `"password"`)
+    pub fn from_str(s: &str) -> Option<Self> {
+        use self::InputMode::*;
+        match s.trim() {
+            "text" => Some(Text),
+            "none" => Some(None),
+            "numeric" => Some(Numeric),
+            "decimal" => Some(Decimal),
+            "tel" => Some(Tel),
+            "email" => Some(Email),
+            "url" => Some(Url),
+            "search" => Some(Search),
+            "password" => Some(Password),
+            _ => Option::None,
+        }
+    }
+}
+
+/// Semantic state of a node that is set by the application (instead of by the
+/// mouse / keyboard like `:hover` or `:focus`), so that widgets can be styled
+/// with the `:disabled`, `:checked`, `:selected` and `:indeterminate` selectors
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C)]
+pub enum NodeStateFlag {
+    /// The node can't be interacted with (i.e. a grayed-out button)
+    Disabled,
+    /// Checked checkbox, radio button or switch
+    Checked,
+    /// Selected item of a list, tab bar or tree view
+    Selected,
+    /// Checkbox that is neither checked nor unchecked (i.e. "some items checked")
+    Indeterminate,
+}
+
+/// Where an overlay is placed relative to its anchor node: the first part
+/// is the side of the anchor, the second part the alignment along that side
+/// (`BottomStart` = below the anchor, left edges aligned)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C)]
+pub enum OverlayPlacement {
+    TopStart,
+    Top,
+    TopEnd,
+    BottomStart,
+    Bottom,
+    BottomEnd,
+    LeftStart,
+    Left,
+    LeftEnd,
+    RightStart,
+    Right,
+    RightEnd,
+}
+
+impl Default for OverlayPlacement {
+    fn default() -> Self {
+        OverlayPlacement::BottomStart
+    }
+}
+
+/// Side of the anchor node that an overlay is placed on
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OverlaySide {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// Alignment of an overlay along the side of its anchor node
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OverlayAlignment {
+    Start,
+    Center,
+    End,
+}
+
+impl OverlayPlacement {
+    pub fn get_side(&self) -> OverlaySide {
+        use self::OverlayPlacement::*;
+        match self {
+            TopStart | Top | TopEnd => OverlaySide::Top,
+            BottomStart | Bottom | BottomEnd => OverlaySide::Bottom,
+            LeftStart | Left | LeftEnd => OverlaySide::Left,
+            RightStart | Right | RightEnd => OverlaySide::Right,
+        }
+    }
+
+    pub fn get_alignment(&self) -> OverlayAlignment {
+        use self::OverlayPlacement::*;
+        match self {
+            TopStart | BottomStart | LeftStart | RightStart => OverlayAlignment::Start,
+            Top | Bottom | Left | Right => OverlayAlignment::Center,
+            TopEnd | BottomEnd | LeftEnd | RightEnd => OverlayAlignment::End,
+        }
+    }
+}
+
+/// Marks a node as an overlay (dropdown, combobox popup, tooltip): the node
+/// is taken out of the regular layout, positioned next to the node with the
+/// ID `anchor` and rendered above all other content, clipped only by the window.
+///
+/// If the overlay doesn't fit into the window on the requested side, it is
+/// flipped to the opposite side and then shifted back into the window.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C)]
+pub struct OverlayInfo {
+    /// ID of the node that the overlay is positioned relative to
+    pub anchor: AzString,
+    /// Side + alignment of the overlay relative to the anchor node
+    pub placement: OverlayPlacement,
+}
+
+impl OverlayInfo {
+    /// Returns the ID of the anchor node, without the leading `#`
+    pub fn get_anchor_id(&self) -> &str {
+        let id = self.anchor.as_str().trim();
+        id.strip_prefix('#').unwrap_or(id)
+    }
+}
+
 /// Accessibility information (MSAA wrapper). See `NodeData.set_accessibility_info()`
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[repr(C)]
@@ -1152,11 +1626,43 @@ impl NodeData {
 
     #[inline(always)]
     pub fn iframe(data: RefAny, callback: IFrameCallbackType) -> Self {
+        Self::iframe_with_reinvoke_threshold(data, callback, DEFAULT_IFRAME_REINVOKE_THRESHOLD)
+    }
+    /// Same as `iframe()`, but only re-invokes the callback if the position, width
+    /// or height of the iframe changes by more than `reinvoke_threshold` logical pixels
+    #[inline(always)]
+    pub fn iframe_with_reinvoke_threshold(
+        data: RefAny,
+        callback: IFrameCallbackType,
+        reinvoke_threshold: f32,
+    ) -> Self {
         Self::new(NodeType::IFrame(IFrameNode {
             callback: IFrameCallback { cb: callback },
             data,
+            reinvoke_threshold: FloatValue::new(reinvoke_threshold),
+        }))
+    }
+    #[inline(always)]
+    pub fn custom_layout(
+        data: RefAny,
+        measure: MeasureCallbackType,
+        arrange: ArrangeCallbackType,
+    ) -> Self {
+        Self::new(NodeType::CustomLayout(CustomLayoutNode {
+            measure: MeasureCallback { cb: measure },
+            arrange: ArrangeCallback { cb: arrange },
+            paint: OptionPaintCallback::None,
+            data,
         }))
     }
+    /// Creates a node of a type that was registered with `CustomNodeType::register`,
+    /// returns `None` if no type with the name `type_name` is registered
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn custom_node(type_name: &str, data: RefAny) -> Option<Self> {
+        let node_type = CustomNodeType::get(type_name)?;
+        Some(Self::new(NodeType::CustomLayout(node_type.create_node(data))))
+    }
 
     /// Checks whether this node is of the given node type (div, image, text)
     #[inline]
@@ -1240,6 +1746,30 @@ impl NodeData {
     pub fn get_context_menu(&self) -> Option<&Box<Menu>> {
         self.extra.as_ref().and_then(|e| e.context_menu.as_ref())
     }
+    #[inline]
+    pub fn get_focus_navigation(&self) -> Option<&Box<FocusNavigation>> {
+        self.extra.as_ref().and_then(|e| e.focus_navigation.as_ref())
+    }
+    #[inline]
+    pub fn get_overlay(&self) -> Option<&Box<OverlayInfo>> {
+        self.extra.as_ref().and_then(|e| e.overlay.as_ref())
+    }
+    #[inline]
+    pub fn is_overlay(&self) -> bool {
+        self.get_overlay().is_some()
+    }
+    #[inline]
+    pub fn get_lang(&self) -> Option<&AzString> {
+        self.extra.as_ref().and_then(|e| e.lang.as_ref())
+    }
+    #[inline]
+    pub fn get_input_mode(&self) -> Option<InputMode> {
+        self.extra.as_ref().and_then(|e| e.input_mode)
+    }
+    #[inline]
+    pub fn get_state_flags(&self) -> &[NodeStateFlag] {
+        self.extra.as_ref().map(|e| e.state_flags.as_slice()).unwrap_or(&[])
+    }
 
     #[inline(always)]
     pub fn set_node_type(&mut self, node_type: NodeType) {
@@ -1289,6 +1819,43 @@ impl NodeData {
             .get_or_insert_with(|| Box::new(NodeDataExt::default()))
             .context_menu = Some(Box::new(context_menu));
     }
+    #[inline]
+    pub fn set_focus_navigation(&mut self, focus_navigation: FocusNavigation) {
+        self.extra
+            .get_or_insert_with(|| Box::new(NodeDataExt::default()))
+            .focus_navigation = Some(Box::new(focus_navigation));
+    }
+    #[inline]
+    pub fn set_overlay(&mut self, overlay: OverlayInfo) {
+        self.extra
+            .get_or_insert_with(|| Box::new(NodeDataExt::default()))
+            .overlay = Some(Box::new(overlay));
+    }
+    #[inline]
+    pub fn set_lang(&mut self, lang: AzString) {
+        self.extra
+            .get_or_insert_with(|| Box::new(NodeDataExt::default()))
+            .lang = Some(lang);
+    }
+    #[inline]
+    pub fn set_input_mode(&mut self, input_mode: InputMode) {
+        self.extra
+            .get_or_insert_with(|| Box::new(NodeDataExt::default()))
+            .input_mode = Some(input_mode);
+    }
+    /// Sets the initial state of the node (`:disabled`, `:checked`, etc.), use
+    /// `CallbackInfo::set_node_state_flag` to change the state of a styled node
+    #[inline]
+    pub fn set_state_flag(&mut self, flag: NodeStateFlag, enabled: bool) {
+        let state_flags = &mut self
+            .extra
+            .get_or_insert_with(|| Box::new(NodeDataExt::default()))
+            .state_flags;
+        state_flags.retain(|f| *f != flag);
+        if enabled {
+            state_flags.push(flag);
+        }
+    }
 
     #[inline]
     pub fn with_context_menu(mut self, context_menu: Menu) -> Self {
@@ -1296,6 +1863,30 @@ impl NodeData {
         self
     }
 
+    #[inline]
+    pub fn with_focus_navigation(mut self, focus_navigation: FocusNavigation) -> Self {
+        self.set_focus_navigation(focus_navigation);
+        self
+    }
+
+    #[inline]
+    pub fn with_lang(mut self, lang: AzString) -> Self {
+        self.set_lang(lang);
+        self
+    }
+
+    #[inline]
+    pub fn with_input_mode(mut self, input_mode: InputMode) -> Self {
+        self.set_input_mode(input_mode);
+        self
+    }
+
+    #[inline]
+    pub fn with_state_flag(mut self, flag: NodeStateFlag, enabled: bool) -> Self {
+        self.set_state_flag(flag, enabled);
+        self
+    }
+
     #[inline]
     pub fn add_callback(&mut self, event: EventFilter, data: RefAny, callback: CallbackType) {
         let mut v: CallbackDataVec = Vec::new().into();
@@ -1431,6 +2022,13 @@ impl NodeData {
         }
     }
 
+    pub fn get_custom_layout_node(&mut self) -> Option<&mut CustomLayoutNode> {
+        match &mut self.node_type {
+            NodeType::CustomLayout(c) => Some(c),
+            _ => None,
+        }
+    }
+
     pub fn get_render_image_callback_node<'a>(
         &'a mut self,
     ) -> Option<(&'a mut ImageCallback, ImageRefHash)> {
@@ -1524,11 +2122,56 @@ impl Dom {
     }
     #[inline(always)]
     pub fn iframe(data: RefAny, callback: IFrameCallbackType) -> Self {
+        Self::iframe_with_reinvoke_threshold(data, callback, DEFAULT_IFRAME_REINVOKE_THRESHOLD)
+    }
+    /// Same as `iframe()`, but only re-invokes the callback if the position, width
+    /// or height of the iframe changes by more than `reinvoke_threshold` logical pixels
+    #[inline(always)]
+    pub fn iframe_with_reinvoke_threshold(
+        data: RefAny,
+        callback: IFrameCallbackType,
+        reinvoke_threshold: f32,
+    ) -> Self {
         Self::new(NodeType::IFrame(IFrameNode {
             callback: IFrameCallback { cb: callback },
             data,
+            reinvoke_threshold: FloatValue::new(reinvoke_threshold),
         }))
     }
+    #[inline(always)]
+    pub fn custom_layout(
+        data: RefAny,
+        measure: MeasureCallbackType,
+        arrange: ArrangeCallbackType,
+    ) -> Self {
+        Self::new(NodeType::CustomLayout(CustomLayoutNode {
+            measure: MeasureCallback { cb: measure },
+            arrange: ArrangeCallback { cb: arrange },
+            paint: OptionPaintCallback::None,
+            data,
+        }))
+    }
+    /// Creates a node of a type that was registered with `CustomNodeType::register`,
+    /// returns `None` if no type with the name `type_name` is registered
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn custom_node(type_name: &str, data: RefAny) -> Option<Self> {
+        let node_type = CustomNodeType::get(type_name)?;
+        Some(Self::new(NodeType::CustomLayout(node_type.create_node(data))))
+    }
+    /// Creates a container whose children are rendered in a top-level layer above
+    /// all other content, positioned next to the node with the ID `anchor`.
+    /// See `OverlayInfo`
+    #[inline]
+    pub fn overlay(anchor: AzString, placement: OverlayPlacement) -> Self {
+        let mut root = NodeData::div();
+        root.set_overlay(OverlayInfo { anchor, placement });
+        Self {
+            root,
+            children: Vec::new().into(),
+            estimated_total_children: 0,
+        }
+    }
 
     // Swaps `self` with a default DOM, necessary for builder methods
     #[inline(always)]
@@ -1633,6 +2276,30 @@ impl Dom {
         self
     }
 
+    #[inline]
+    pub fn with_focus_navigation(mut self, focus_navigation: FocusNavigation) -> Self {
+        self.root.set_focus_navigation(focus_navigation);
+        self
+    }
+
+    #[inline]
+    pub fn with_lang(mut self, lang: AzString) -> Self {
+        self.root.set_lang(lang);
+        self
+    }
+
+    #[inline]
+    pub fn with_input_mode(mut self, input_mode: InputMode) -> Self {
+        self.root.set_input_mode(input_mode);
+        self
+    }
+
+    #[inline]
+    pub fn with_state_flag(mut self, flag: NodeStateFlag, enabled: bool) -> Self {
+        self.root.set_state_flag(flag, enabled);
+        self
+    }
+
     fn fixup_children_estimated(&mut self) -> usize {
         if self.children.is_empty() {
             self.estimated_total_children = 0;
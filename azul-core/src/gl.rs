@@ -12,7 +12,7 @@ use alloc::boxed::Box;
 use alloc::rc::Rc;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use azul_css::{AzString, ColorF, ColorU, StringVec, U8Vec};
+use azul_css::{AzString, ColorF, ColorU, OptionAzString, StringVec, U8Vec};
 use core::{
     fmt,
     hash::{Hash, Hasher},
@@ -859,6 +859,10 @@ impl GlContextPtr {
     pub fn get_svg_shader(&self) -> GLuint {
         self.ptr.svg_shader
     }
+    /// Returns the driver workarounds that were applied to the context
+    pub fn get_quirks(&self) -> GlQuirks {
+        self.ptr.quirks
+    }
     pub fn get_fxaa_shader(&self) -> GLuint {
         self.ptr.fxaa_shader
     }
@@ -873,6 +877,262 @@ pub struct GlContextPtrInner {
     pub svg_multicolor_shader: GLuint,
     /// FXAA shader program (library-internal use)
     pub fxaa_shader: GLuint,
+    /// Driver workarounds that were applied to this context
+    pub quirks: GlQuirks,
+}
+
+/// Workarounds for driver bugs, applied when the renderer is created.
+/// See `GlQuirkRule` and `GlContextPtr::get_quirks()`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct GlQuirks {
+    /// Don't use the OpenGL context, fall back to software rendering
+    pub force_software_rendering: bool,
+    /// Render text with grayscale instead of subpixel anti-aliasing
+    pub disable_subpixel_aa: bool,
+    /// Use the unoptimized shader sources (some drivers miscompile the optimized shaders)
+    pub disable_optimized_shaders: bool,
+    /// Don't cache the compiled shader programs
+    pub disable_program_cache: bool,
+    /// Build the frames on the main thread only
+    pub disable_multithreading: bool,
+    /// Always redraw and present the entire window, even if the driver
+    /// reports the age of the back buffer (`EGL_EXT_buffer_age`)
+    pub disable_partial_present: bool,
+}
+
+impl GlQuirks {
+    /// Returns whether no workaround is enabled
+    pub fn is_empty(&self) -> bool {
+        *self == GlQuirks::default()
+    }
+
+    /// Workarounds that are enabled in `self` or `other`
+    pub fn union(&self, other: &GlQuirks) -> GlQuirks {
+        GlQuirks {
+            force_software_rendering: self.force_software_rendering || other.force_software_rendering,
+            disable_subpixel_aa: self.disable_subpixel_aa || other.disable_subpixel_aa,
+            disable_optimized_shaders: self.disable_optimized_shaders || other.disable_optimized_shaders,
+            disable_program_cache: self.disable_program_cache || other.disable_program_cache,
+            disable_multithreading: self.disable_multithreading || other.disable_multithreading,
+            disable_partial_present: self.disable_partial_present || other.disable_partial_present,
+        }
+    }
+
+    /// Workarounds that are enabled in `self`, but not in `other`
+    pub fn difference(&self, other: &GlQuirks) -> GlQuirks {
+        GlQuirks {
+            force_software_rendering: self.force_software_rendering && !other.force_software_rendering,
+            disable_subpixel_aa: self.disable_subpixel_aa && !other.disable_subpixel_aa,
+            disable_optimized_shaders: self.disable_optimized_shaders && !other.disable_optimized_shaders,
+            disable_program_cache: self.disable_program_cache && !other.disable_program_cache,
+            disable_multithreading: self.disable_multithreading && !other.disable_multithreading,
+            disable_partial_present: self.disable_partial_present && !other.disable_partial_present,
+        }
+    }
+}
+
+/// `GL_VENDOR`, `GL_RENDERER` and `GL_VERSION` strings of an OpenGL context
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct GlDriverInfo {
+    pub vendor: AzString,
+    pub renderer: AzString,
+    pub version: AzString,
+}
+
+impl GlDriverInfo {
+    /// Queries the strings of the context, the context has to be current
+    pub fn query(gl_context: &GenericGlContext) -> Self {
+        Self {
+            vendor: gl_context.get_string(gl::VENDOR).into(),
+            renderer: gl_context.get_string(gl::RENDERER).into(),
+            version: gl_context.get_string(gl::VERSION).into(),
+        }
+    }
+}
+
+/// Enables / disables workarounds for all drivers that match the rule.
+///
+/// The patterns are matched case-insensitively against substrings of the
+/// `GL_VENDOR`, `GL_RENDERER` and `GL_VERSION` strings, a `None` pattern
+/// matches every driver. Rules added by the app are applied after the
+/// built-in rules, so they can disable built-in workarounds.
+///
+/// The `version` pattern can also compare version numbers: `"Mesa < 21.2"`
+/// matches if `GL_VERSION` contains "Mesa" and the number after it is lower
+/// than 21.2 (i.e. "4.6 (Core Profile) Mesa 21.1.8"). Without a prefix (`">= 4.5"`),
+/// the first number of `GL_VERSION` (the OpenGL version) is compared.
+/// Supported operators are `<`, `<=`, `>`, `>=` and `==`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct GlQuirkRule {
+    pub vendor: OptionAzString,
+    pub renderer: OptionAzString,
+    pub version: OptionAzString,
+    /// Workarounds to enable if the rule matches
+    pub enable: GlQuirks,
+    /// Workarounds to disable if the rule matches
+    pub disable: GlQuirks,
+}
+
+impl GlQuirkRule {
+    /// Creates a rule that matches every driver and doesn't change anything
+    pub fn new() -> Self {
+        Self {
+            vendor: OptionAzString::None,
+            renderer: OptionAzString::None,
+            version: OptionAzString::None,
+            enable: GlQuirks::default(),
+            disable: GlQuirks::default(),
+        }
+    }
+
+    pub fn with_vendor(self, vendor: AzString) -> Self {
+        Self { vendor: OptionAzString::Some(vendor), ..self }
+    }
+
+    pub fn with_renderer(self, renderer: AzString) -> Self {
+        Self { renderer: OptionAzString::Some(renderer), ..self }
+    }
+
+    pub fn with_version(self, version: AzString) -> Self {
+        Self { version: OptionAzString::Some(version), ..self }
+    }
+
+    pub fn with_enable(self, enable: GlQuirks) -> Self {
+        Self { enable, ..self }
+    }
+
+    pub fn with_disable(self, disable: GlQuirks) -> Self {
+        Self { disable, ..self }
+    }
+
+    /// Returns whether all patterns of the rule match the driver
+    pub fn matches(&self, driver: &GlDriverInfo) -> bool {
+        fn contains(pattern: &OptionAzString, s: &AzString) -> bool {
+            match pattern.as_ref() {
+                Some(p) => s.as_str().to_lowercase().contains(&p.as_str().to_lowercase()),
+                None => true,
+            }
+        }
+        let version_matches = match self.version.as_ref() {
+            Some(p) => match parse_version_range(p.as_str()) {
+                Some(range) => range.matches(driver.version.as_str()),
+                None => contains(&self.version, &driver.version),
+            },
+            None => true,
+        };
+        contains(&self.vendor, &driver.vendor) &&
+        contains(&self.renderer, &driver.renderer) &&
+        version_matches
+    }
+
+    /// Applies the rules in order and returns the resulting workarounds
+    pub fn resolve(rules: &[GlQuirkRule], driver: &GlDriverInfo) -> GlQuirks {
+        rules.iter()
+            .filter(|r| r.matches(driver))
+            .fold(GlQuirks::default(), |quirks, r| quirks.union(&r.enable).difference(&r.disable))
+    }
+}
+
+/// Version pattern of a `GlQuirkRule`, i.e. "Mesa < 21.2"
+struct VersionRange<'a> {
+    /// Text that has to precede the version number (empty = first number of the string)
+    prefix: &'a str,
+    operator: VersionOperator,
+    version: Vec<u32>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum VersionOperator {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+}
+
+/// Returns `None` if the pattern has no comparison operator (plain substring match)
+fn parse_version_range(pattern: &str) -> Option<VersionRange> {
+    use self::VersionOperator::*;
+    const OPERATORS: &[(&str, VersionOperator)] = &[
+        ("<=", LessOrEqual),
+        (">=", GreaterOrEqual),
+        ("==", Equal),
+        ("<", Less),
+        (">", Greater),
+    ];
+    let (pos, op, operator) = OPERATORS.iter()
+        .filter_map(|(op, operator)| pattern.find(op).map(|pos| (pos, *op, *operator)))
+        .min_by_key(|(pos, _, _)| *pos)?;
+    let (version, rest) = parse_version_number(pattern[pos + op.len()..].trim_start())?;
+    if !rest.trim().is_empty() {
+        return None;
+    }
+    Some(VersionRange { prefix: pattern[..pos].trim(), operator, version })
+}
+
+/// Parses "21.1.8" at the start of the string, returns the
+/// components and the remaining string after the number
+fn parse_version_number(s: &str) -> Option<(Vec<u32>, &str)> {
+    let mut components = Vec::new();
+    let mut rest = s;
+    loop {
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        if digits == 0 {
+            return None;
+        }
+        components.push(rest[..digits].parse().ok()?);
+        rest = &rest[digits..];
+        match rest.strip_prefix('.') {
+            Some(r) if r.starts_with(|c: char| c.is_ascii_digit()) => { rest = r; },
+            _ => return Some((components, rest)),
+        }
+    }
+}
+
+impl<'a> VersionRange<'a> {
+    /// Compares the first version number after the prefix in `GL_VERSION`
+    fn matches(&self, gl_version: &str) -> bool {
+        use self::VersionOperator::*;
+
+        let gl_version = gl_version.to_lowercase();
+        let prefix = self.prefix.to_lowercase();
+        let after_prefix = match gl_version.find(&prefix) {
+            Some(pos) => &gl_version[pos + prefix.len()..],
+            None => return false,
+        };
+        let version = match after_prefix.find(|c: char| c.is_ascii_digit()) {
+            Some(start) => match parse_version_number(&after_prefix[start..]) {
+                Some((v, _)) => v,
+                None => return false,
+            },
+            None => return false,
+        };
+
+        // missing components are zero: 21.2 == 21.2.0
+        let len = version.len().max(self.version.len());
+        let component = |v: &[u32], i: usize| v.get(i).copied().unwrap_or(0);
+        let ordering = (0..len)
+            .map(|i| component(&version, i).cmp(&component(&self.version, i)))
+            .find(|o| *o != core::cmp::Ordering::Equal)
+            .unwrap_or(core::cmp::Ordering::Equal);
+
+        match self.operator {
+            Less => ordering.is_lt(),
+            LessOrEqual => ordering.is_le(),
+            Greater => ordering.is_gt(),
+            GreaterOrEqual => ordering.is_ge(),
+            Equal => ordering.is_eq(),
+        }
+    }
+}
+
+impl Default for GlQuirkRule {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Drop for GlContextPtrInner {
@@ -975,7 +1235,7 @@ void main() {
 }";
 
 impl GlContextPtr {
-    pub fn new(renderer_type: RendererType, gl_context: Rc<GenericGlContext>) -> Self {
+    pub fn new(renderer_type: RendererType, gl_context: Rc<GenericGlContext>, quirks: GlQuirks) -> Self {
         // Compile basic shader
         let vertex_shader_object = gl_context.create_shader(gl::VERTEX_SHADER);
         gl_context.shader_source(vertex_shader_object, &[SVG_VERTEX_SHADER]);
@@ -1021,6 +1281,7 @@ impl GlContextPtr {
                 svg_multicolor_shader: svg_multicolor_program_id,
                 fxaa_shader: 0, // TODO
                 ptr: gl_context,
+                quirks,
             })),
             renderer_type,
             run_destructor: true,
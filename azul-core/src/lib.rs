@@ -30,10 +30,12 @@ pub mod app_resources;
 pub mod css;
 /// Layout and display list creation algorithm, z-index reordering of a `CachedDisplayList`
 pub mod display_list;
+/// Binary serialization of a `CachedDisplayList`, to cache, transfer or replay built frames
+pub mod display_list_codec;
 /// `Dom` construction, `NodeData` and `NodeType` management functions
 pub mod dom;
-// Algorithms to create git-like diffs between two doms in linear time
-// pub mod diff;
+/// Git-like diffs between two styled DOMs, for test assertions
+pub mod diff;
 /// Contains OpenGL helper functions (to compile / link shaders), `VirtualGlDriver` for unit testing
 pub mod gl;
 /// Internal, arena-based storage for Dom nodes
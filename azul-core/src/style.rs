@@ -51,10 +51,11 @@ pub(crate) fn matches_html_element(
     let mut current_node = Some(node_id);
     let mut direct_parent_has_to_match = false;
     let mut last_selector_matched = true;
+    // the groups are iterated from right to left: the first group is the last group of the path
+    let mut is_last_content_group = true;
 
     let mut iterator = CssGroupIterator::new(css_path.selectors.as_ref());
     while let Some((content_group, reason)) = iterator.next() {
-        let is_last_content_group = iterator.is_last_content_group();
         let cur_node_id = match current_node {
             Some(c) => c,
             None => {
@@ -90,11 +91,89 @@ pub(crate) fn matches_html_element(
         // Select if the next content group has to exactly match or if it can potentially be skipped
         direct_parent_has_to_match = reason == DirectChildren;
         current_node = node_hierarchy[cur_node_id].parent_id();
+        is_last_content_group = false;
     }
 
     last_selector_matched
 }
 
+/// Same as `matches_html_element`, but a descendant combinator (`".foo .bar"`) can be
+/// matched by any ancestor, not only by the direct parent: if the rest of the path doesn't
+/// match from the nearest matching ancestor, the next one further up is tried
+pub(crate) fn matches_html_element_exact(
+    css_path: &CssPath,
+    node_id: NodeId,
+    node_hierarchy: &NodeDataContainerRef<NodeHierarchyItem>,
+    node_data: &NodeDataContainerRef<NodeData>,
+    html_node_tree: &NodeDataContainerRef<CascadeInfo>,
+    expected_path_ending: Option<CssPathPseudoSelector>,
+) -> bool {
+    use self::CssGroupSplitReason::*;
+
+    fn matches_content_groups(
+        content_groups: &[(CssContentGroup, CssGroupSplitReason)],
+        group_idx: usize,
+        node_id: NodeId,
+        node_hierarchy: &NodeDataContainerRef<NodeHierarchyItem>,
+        node_data: &NodeDataContainerRef<NodeData>,
+        html_node_tree: &NodeDataContainerRef<CascadeInfo>,
+        expected_path_ending: Option<CssPathPseudoSelector>,
+    ) -> bool {
+        let (content_group, reason) = &content_groups[group_idx];
+
+        if !selector_group_matches(
+            content_group,
+            &html_node_tree[node_id],
+            &node_data[node_id],
+            expected_path_ending,
+            group_idx == 0,
+        ) {
+            return false;
+        }
+
+        if group_idx + 1 == content_groups.len() {
+            return true;
+        }
+
+        let mut ancestor = node_hierarchy[node_id].parent_id();
+        while let Some(a) = ancestor {
+            if matches_content_groups(
+                content_groups,
+                group_idx + 1,
+                a,
+                node_hierarchy,
+                node_data,
+                html_node_tree,
+                expected_path_ending,
+            ) {
+                return true;
+            }
+            if *reason == DirectChildren {
+                return false;
+            }
+            ancestor = node_hierarchy[a].parent_id();
+        }
+
+        false
+    }
+
+    // content groups from right to left, together with the combinator to their left
+    let content_groups = CssGroupIterator::new(css_path.selectors.as_ref()).collect::<Vec<_>>();
+    if content_groups.is_empty() {
+        return false;
+    }
+
+    matches_content_groups(
+        &content_groups,
+        0,
+        node_id,
+        node_hierarchy,
+        node_data,
+        html_node_tree,
+        expected_path_ending,
+    )
+}
+
 /// A CSS group is a group of css selectors in a path that specify the rule that a
 /// certain node has to match, i.e. "div.main.foo" has to match three requirements:
 ///
@@ -130,9 +209,6 @@ impl<'a> CssGroupIterator<'a> {
             last_reason: CssGroupSplitReason::Children,
         }
     }
-    pub fn is_last_content_group(&self) -> bool {
-        self.current_idx.saturating_add(1) == self.css_path.len().saturating_sub(1)
-    }
 }
 
 impl<'a> Iterator for CssGroupIterator<'a> {
@@ -185,9 +261,13 @@ impl<'a> Iterator for CssGroupIterator<'a> {
     }
 }
 
+/// Computes the position of every node among its siblings (for `:first`, `:last` and
+/// `:nth-child`), nodes for which `is_anonymous` returns true (generated `:before` /
+/// `:after` pseudo-elements) are skipped, so that they don't shift their siblings
 pub(crate) fn construct_html_cascade_tree(
     node_hierarchy: &NodeHierarchyRef,
     node_depths_sorted: &[(usize, NodeId)],
+    is_anonymous: impl Fn(NodeId) -> bool,
 ) -> NodeDataContainer<CascadeInfo> {
     let mut nodes = (0..node_hierarchy.len())
         .map(|_| CascadeInfo {
@@ -197,20 +277,28 @@ pub(crate) fn construct_html_cascade_tree(
         .collect::<Vec<_>>();
 
     for (_depth, parent_id) in node_depths_sorted {
-        // Note: :nth-child() starts at 1 instead of 0
-        let index_in_parent = parent_id.preceding_siblings(node_hierarchy).count();
+        // all other parents are updated as the child of their own parent
+        if node_hierarchy[*parent_id].parent.is_none() {
+            // Note: :nth-child() starts at 1 instead of 0
+            let index_in_parent = parent_id.preceding_siblings(node_hierarchy).count();
+
+            let parent_html_matcher = CascadeInfo {
+                index_in_parent: (index_in_parent - 1) as u32,
+                is_last_child: node_hierarchy[*parent_id].next_sibling.is_none(), // Necessary for :last selectors
+            };
 
-        let parent_html_matcher = CascadeInfo {
-            index_in_parent: (index_in_parent - 1) as u32,
-            is_last_child: node_hierarchy[*parent_id].next_sibling.is_none(), // Necessary for :last selectors
-        };
+            nodes[parent_id.index()] = parent_html_matcher;
+        }
 
-        nodes[parent_id.index()] = parent_html_matcher;
+        let children = parent_id
+            .children(node_hierarchy)
+            .filter(|child_id| !is_anonymous(*child_id))
+            .collect::<Vec<_>>();
 
-        for (child_idx, child_id) in parent_id.children(node_hierarchy).enumerate() {
+        for (child_idx, child_id) in children.iter().enumerate() {
             let child_html_matcher = CascadeInfo {
                 index_in_parent: child_idx as u32,
-                is_last_child: node_hierarchy[child_id].next_sibling.is_none(),
+                is_last_child: child_idx + 1 == children.len(),
             };
 
             nodes[child_id.index()] = child_html_matcher;
@@ -227,6 +315,10 @@ pub fn rule_ends_with(path: &CssPath, target: Option<CssPathPseudoSelector>) ->
         None => match path.selectors.as_ref().last() {
             None => false,
             Some(q) => match q {
+                // structural selectors don't depend on the state of the node
+                CssPathSelector::PseudoSelector(CssPathPseudoSelector::First)
+                | CssPathSelector::PseudoSelector(CssPathPseudoSelector::Last)
+                | CssPathSelector::PseudoSelector(CssPathPseudoSelector::NthChild(_)) => true,
                 CssPathSelector::PseudoSelector(_) => false,
                 _ => true,
             },
@@ -352,6 +444,55 @@ pub(crate) fn selector_group_matches(
                             return false;
                         }
                     }
+                    CssPathPseudoSelector::Disabled => {
+                        if !is_last_content_group {
+                            return false;
+                        }
+                        if expected_path_ending != Some(CssPathPseudoSelector::Disabled) {
+                            return false;
+                        }
+                    }
+                    CssPathPseudoSelector::Checked => {
+                        if !is_last_content_group {
+                            return false;
+                        }
+                        if expected_path_ending != Some(CssPathPseudoSelector::Checked) {
+                            return false;
+                        }
+                    }
+                    CssPathPseudoSelector::Selected => {
+                        if !is_last_content_group {
+                            return false;
+                        }
+                        if expected_path_ending != Some(CssPathPseudoSelector::Selected) {
+                            return false;
+                        }
+                    }
+                    CssPathPseudoSelector::Indeterminate => {
+                        if !is_last_content_group {
+                            return false;
+                        }
+                        if expected_path_ending != Some(CssPathPseudoSelector::Indeterminate) {
+                            return false;
+                        }
+                    }
+                    // :before / :after select the generated pseudo-element, not the node itself
+                    CssPathPseudoSelector::Before => {
+                        if !is_last_content_group {
+                            return false;
+                        }
+                        if expected_path_ending != Some(CssPathPseudoSelector::Before) {
+                            return false;
+                        }
+                    }
+                    CssPathPseudoSelector::After => {
+                        if !is_last_content_group {
+                            return false;
+                        }
+                        if expected_path_ending != Some(CssPathPseudoSelector::After) {
+                            return false;
+                        }
+                    }
                 }
             }
             DirectChildren | Children => {
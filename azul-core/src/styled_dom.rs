@@ -2,23 +2,28 @@ use crate::{
     app_resources::{Au, ImageCache, ImageRef, ImmediateFontId, RendererResources},
     callbacks::{CallbackInfo, RefAny, Update},
     dom::{
-        CompactDom, Dom, NodeData, NodeDataInlineCssProperty, NodeDataVec, OptionTabIndex,
-        TabIndex, TagId,
+        CompactDom, Dom, IdOrClassVec, NodeData, NodeDataInlineCssProperty, NodeDataVec,
+        NodeStateFlag, OptionTabIndex, TabIndex, TagId,
+    },
+    id_tree::{
+        Node, NodeDataContainer, NodeDataContainerRef, NodeDataContainerRefMut, NodeHierarchy,
+        NodeId,
     },
-    id_tree::{Node, NodeDataContainer, NodeDataContainerRef, NodeDataContainerRefMut, NodeId},
     style::{
-        construct_html_cascade_tree, matches_html_element, rule_ends_with, CascadeInfo,
-        CascadeInfoVec,
+        construct_html_cascade_tree, matches_html_element, matches_html_element_exact,
+        rule_ends_with, CascadeInfo, CascadeInfoVec,
     },
     window::Menu,
     FastBTreeSet, FastHashMap,
 };
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::collections::btree_map::BTreeMap;
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use azul_css::{
-    AzString, Css, CssPath, CssProperty, CssPropertyType, LayoutAlignContentValue,
+    AzString, Css, CssPath, CssPathPseudoSelector, CssProperty, CssPropertyType, LayoutAlignContentValue,
     LayoutAlignItemsValue, LayoutBorderBottomWidthValue, LayoutBorderLeftWidthValue,
     LayoutBorderRightWidthValue, LayoutBorderTopWidthValue, LayoutBottomValue,
     LayoutBoxSizingValue, LayoutDisplayValue, LayoutFlexDirectionValue, LayoutFlexGrowValue,
@@ -26,8 +31,9 @@ use azul_css::{
     LayoutJustifyContentValue, LayoutLeftValue, LayoutMarginBottomValue, LayoutMarginLeftValue,
     LayoutMarginRightValue, LayoutMarginTopValue, LayoutMaxHeightValue, LayoutMaxWidthValue,
     LayoutMinHeightValue, LayoutMinWidthValue, LayoutOverflowValue, LayoutPaddingBottomValue,
-    LayoutPaddingLeftValue, LayoutPaddingRightValue, LayoutPaddingTopValue, LayoutPositionValue,
+    LayoutPaddingLeftValue, LayoutPaddingRightValue, LayoutPaddingTopValue, LayoutPositionValue, PixelValue,
     LayoutRightValue, LayoutTopValue, LayoutWidthValue, StyleBackfaceVisibilityValue,
+    StylePerspectiveValue, StyleTransformStyleValue,
     StyleBackgroundContentVecValue, StyleBackgroundPositionVecValue, StyleBackgroundRepeatVecValue,
     StyleBackgroundSizeVecValue, StyleBorderBottomColorValue, StyleBorderBottomLeftRadiusValue,
     StyleBorderBottomRightRadiusValue, StyleBorderBottomStyleValue, StyleBorderLeftColorValue,
@@ -36,9 +42,14 @@ use azul_css::{
     StyleBorderTopStyleValue, StyleBoxShadowValue, StyleCursorValue, StyleFilterVecValue,
     StyleFontFamily, StyleFontFamilyVec, StyleFontFamilyVecValue, StyleFontSize,
     StyleFontSizeValue, StyleLetterSpacingValue, StyleLineHeightValue, StyleMixBlendModeValue,
-    StyleOpacityValue, StylePerspectiveOriginValue, StyleTabWidthValue, StyleTextAlignValue,
+    StyleOpacityValue, StylePerspectiveOriginValue, StyleScrollbarColorValue,
+    StyleScrollbarThumbActiveColorValue, StyleScrollbarThumbHoverColorValue,
+    StyleScrollbarWidthValue, StyleTabWidthValue, StyleHyphensValue, StyleTextAlignValue,
     StyleTextColor, StyleTextColorValue, StyleTransformOriginValue, StyleTransformVecValue,
-    StyleWordSpacingValue,
+    StyleWindowDragRegion, StyleWindowDragRegionValue, StyleCaretAnimationDurationValue, StyleScrollPaddingValue, StyleContentValue,
+    StyleCounterResetValue, StyleCounterIncrementValue,
+    StyleOverflowAnchorValue,
+    StyleWordSpacingValue, SystemStyle,
 };
 use azul_css_parser::CssApiWrapper;
 use core::{
@@ -86,6 +97,11 @@ pub struct StyledNodeState {
     pub hover: bool,
     pub active: bool,
     pub focused: bool,
+    /// Application-defined states, see `NodeStateFlag`
+    pub disabled: bool,
+    pub checked: bool,
+    pub selected: bool,
+    pub indeterminate: bool,
 }
 
 impl core::fmt::Debug for StyledNodeState {
@@ -103,6 +119,18 @@ impl core::fmt::Debug for StyledNodeState {
         if self.focused {
             v.push("focused");
         }
+        if self.disabled {
+            v.push("disabled");
+        }
+        if self.checked {
+            v.push("checked");
+        }
+        if self.selected {
+            v.push("selected");
+        }
+        if self.indeterminate {
+            v.push("indeterminate");
+        }
         write!(f, "{:?}", v)
     }
 }
@@ -120,6 +148,28 @@ impl StyledNodeState {
             hover: false,
             active: false,
             focused: false,
+            disabled: false,
+            checked: false,
+            selected: false,
+            indeterminate: false,
+        }
+    }
+
+    pub const fn get_flag(&self, flag: NodeStateFlag) -> bool {
+        match flag {
+            NodeStateFlag::Disabled => self.disabled,
+            NodeStateFlag::Checked => self.checked,
+            NodeStateFlag::Selected => self.selected,
+            NodeStateFlag::Indeterminate => self.indeterminate,
+        }
+    }
+
+    pub fn set_flag(&mut self, flag: NodeStateFlag, value: bool) {
+        match flag {
+            NodeStateFlag::Disabled => self.disabled = value,
+            NodeStateFlag::Checked => self.checked = value,
+            NodeStateFlag::Selected => self.selected = value,
+            NodeStateFlag::Indeterminate => self.indeterminate = value,
         }
     }
 }
@@ -205,12 +255,408 @@ pub struct CssPropertyCache {
     pub cascaded_hover_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub cascaded_active_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub cascaded_focus_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+    pub cascaded_disabled_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+    pub cascaded_checked_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+    pub cascaded_selected_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+    pub cascaded_indeterminate_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
 
     // non-default CSS properties that were set via a CSS file
     pub css_normal_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub css_hover_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub css_active_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub css_focus_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+    pub css_disabled_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+    pub css_checked_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+    pub css_selected_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+    pub css_indeterminate_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+
+    // CSS that the nodes were styled with: (first node, node count, sorted stylesheet),
+    // kept so that a subtree can be restyled when the classes of a node change or the
+    // system settings change (only if the stylesheet has id / class selectors or
+    // depends on the system settings, otherwise nothing can change)
+    pub stylesheets: Vec<(NodeId, usize, Arc<Css>)>,
+
+    // system settings that the system colors and `@media` blocks were resolved with
+    pub system_style: SystemStyle,
+
+    // generated :before / :after boxes (the text of a box is always the next node)
+    pub pseudo_elements: BTreeMap<NodeId, PseudoElement>,
+}
+
+/// Returns whether changing the ids / classes of a node can change which rules match it
+fn css_has_id_or_class_selectors(css: &Css) -> bool {
+    use azul_css::CssPathSelector;
+    css.rules().any(|rule| {
+        rule.path.selectors.iter().any(|selector| match selector {
+            CssPathSelector::Id(_) | CssPathSelector::Class(_) => true,
+            _ => false,
+        })
+    })
+}
+
+/// Returns the stylesheet with the system colors and `@media` blocks resolved
+/// for the system settings, borrowed if it doesn't depend on them
+fn resolve_system_style<'a>(css: &'a Css, system_style: &SystemStyle) -> Cow<'a, Css> {
+    if css.depends_on_system_style() {
+        Cow::Owned(azul_css_parser::resolve_system_style(css, system_style))
+    } else {
+        Cow::Borrowed(css)
+    }
+}
+
+/// Returns the properties of all rules in the (sorted, resolved) stylesheet that match the node
+fn get_matching_css_properties(
+    css: &Css,
+    expected_pseudo_selector: Option<CssPathPseudoSelector>,
+    node_id: NodeId,
+    node_data: &NodeDataContainerRef<NodeData>,
+    node_hierarchy: &NodeHierarchyItemVec,
+    html_tree: &NodeDataContainerRef<CascadeInfo>,
+) -> Vec<CssProperty> {
+    use azul_css::CssDeclaration;
+
+    css
+    .rules() // can not be parallelized due to specificity order matching
+    .filter(|rule_block| rule_ends_with(&rule_block.path, expected_pseudo_selector))
+    .filter(|rule_block| matches_html_element(
+        &rule_block.path,
+        node_id,
+        &node_hierarchy.as_container(),
+        node_data,
+        html_tree,
+        expected_pseudo_selector
+    ))
+    // rule matched, now copy all the styles of this rule
+    .flat_map(|matched_rule| {
+        matched_rule.declarations
+        .iter()
+        .filter_map(move |declaration| {
+            match declaration {
+                CssDeclaration::Static(s) => Some(s),
+                CssDeclaration::Dynamic(_d) => None, // TODO: No variable support yet!
+                CssDeclaration::System(_s) => None, // resolved in resolve_system_style
+            }
+        })
+    })
+    .map(|prop| prop.clone())
+    .collect::<Vec<CssProperty>>()
+}
+
+/// Returns the (exclusive) end of the range of the node and its descendants:
+/// descendants are always stored directly after their parent
+fn get_subtree_end(node_id: NodeId, node_hierarchy: &NodeDataContainerRef<NodeHierarchyItem>) -> usize {
+    let mut current = Some(node_id);
+    while let Some(c) = current {
+        if let Some(next) = node_hierarchy[c].next_sibling_id() {
+            return next.index();
+        }
+        current = node_hierarchy[c].parent_id();
+    }
+    node_hierarchy.len()
+}
+
+/// Generated `:before` / `:after` pseudo-element: an anonymous box (the first / last child
+/// of the node) whose only child is a text node with the generated text. The box is laid
+/// out and rendered like a regular node, but it is not matched by selectors, doesn't count
+/// as a sibling for `:nth-child` and always has the same state (`:hover`, ...) as its node.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PseudoElement {
+    /// Node that generated the pseudo-element
+    pub origin: NodeId,
+    /// `CssPathPseudoSelector::Before` or `CssPathPseudoSelector::After`
+    pub selector: CssPathPseudoSelector,
+}
+
+/// Splits a `:before` / `:after` rule into the path that the node generating the pseudo-element
+/// has to match and the state (`.item:hover:after`) that the node has to be in
+fn get_pseudo_element_origin_path(
+    path: &CssPath,
+    selector: CssPathPseudoSelector,
+) -> Option<(CssPath, Option<CssPathPseudoSelector>)> {
+    use azul_css::CssPathPseudoSelector::*;
+    use azul_css::CssPathSelector;
+
+    let selectors = path.selectors.as_ref();
+    match selectors.last() {
+        Some(CssPathSelector::PseudoSelector(p)) if *p == selector => {}
+        _ => return None,
+    }
+
+    let mut origin_path = selectors[..selectors.len() - 1].to_vec();
+
+    // ":after" or ".list :after" apply to any node
+    match origin_path.last() {
+        None | Some(CssPathSelector::Children) | Some(CssPathSelector::DirectChildren) => {
+            origin_path.push(CssPathSelector::Global);
+        }
+        _ => {}
+    }
+
+    let state = match origin_path.last() {
+        Some(CssPathSelector::PseudoSelector(
+            p @ (Hover | Active | Focus | Disabled | Checked | Selected | Indeterminate),
+        )) => Some(*p),
+        _ => None,
+    };
+
+    Some((
+        CssPath {
+            selectors: origin_path.into(),
+        },
+        state,
+    ))
+}
+
+/// Returns the properties of all `:before` / `:after` rules in the (sorted, resolved) stylesheet
+/// that match the node in the given state (`None` = the rules without a state)
+fn get_matching_pseudo_element_properties(
+    css: &Css,
+    selector: CssPathPseudoSelector,
+    state: Option<CssPathPseudoSelector>,
+    node_id: NodeId,
+    node_data: &NodeDataContainerRef<NodeData>,
+    node_hierarchy: &NodeHierarchyItemVec,
+    html_tree: &NodeDataContainerRef<CascadeInfo>,
+) -> Vec<CssProperty> {
+    use azul_css::CssDeclaration;
+
+    css.rules()
+        .filter(|rule_block| {
+            let (origin_path, rule_state) =
+                match get_pseudo_element_origin_path(&rule_block.path, selector) {
+                    Some(s) => s,
+                    None => return false,
+                };
+            rule_state == state
+                && matches_html_element(
+                    &origin_path,
+                    node_id,
+                    &node_hierarchy.as_container(),
+                    node_data,
+                    html_tree,
+                    state,
+                )
+        })
+        .flat_map(|matched_rule| {
+            matched_rule
+                .declarations
+                .iter()
+                .filter_map(move |declaration| match declaration {
+                    CssDeclaration::Static(s) => Some(s),
+                    CssDeclaration::Dynamic(_d) => None, // TODO: No variable support yet!
+                    CssDeclaration::System(_s) => None, // resolved in resolve_system_style
+                })
+        })
+        .map(|prop| prop.clone())
+        .collect::<Vec<CssProperty>>()
+}
+
+/// Returns whether the properties of a pseudo-element generate any content
+fn pseudo_element_has_content(props: &BTreeMap<CssPropertyType, CssProperty>) -> bool {
+    use azul_css::{CssPropertyValue, StyleContent};
+
+    match props.get(&CssPropertyType::Content) {
+        Some(CssProperty::Content(CssPropertyValue::Exact(c))) => *c != StyleContent::None,
+        _ => false,
+    }
+}
+
+/// Returns whether the node is the box or the text of a generated `:before` / `:after` pseudo-element
+fn is_pseudo_element_node(pseudo_elements: &BTreeMap<NodeId, PseudoElement>, node_id: NodeId) -> bool {
+    if pseudo_elements.is_empty() {
+        return false;
+    }
+
+    pseudo_elements.contains_key(&node_id)
+        || node_id
+            .index()
+            .checked_sub(1)
+            .map(|box_id| pseudo_elements.contains_key(&NodeId::new(box_id)))
+            .unwrap_or(false)
+}
+
+/// Generates the boxes of the `:before` / `:after` pseudo-elements: every div or body that
+/// a `:before` / `:after` rule with a `content` property could match (in any state) gets a
+/// box + text node as its first / last child. The boxes are styled in `CssPropertyCache::restyle`,
+/// so that restyling (new classes, `:hover`, ...) can show or hide the generated content later on.
+/// Returns the DOM unchanged if no node has such a rule.
+fn insert_pseudo_elements(
+    compact_dom: CompactDom,
+    css: &Css,
+) -> (CompactDom, BTreeMap<NodeId, PseudoElement>) {
+    use crate::dom::NodeType;
+    use azul_css::CssPathPseudoSelector::{After, Before};
+    use azul_css::CssDeclaration;
+
+    let mut pseudo_element_rules = css
+        .rules()
+        .filter(|r| {
+            r.declarations.iter().any(|d| match d {
+                CssDeclaration::Static(p) => p.get_type() == CssPropertyType::Content,
+                CssDeclaration::Dynamic(_) => false,
+                CssDeclaration::System(p) => p.default_value.get_type() == CssPropertyType::Content,
+            })
+        })
+        .filter_map(|r| {
+            get_pseudo_element_origin_path(&r.path, Before)
+                .map(|p| (Before, p))
+                .or_else(|| get_pseudo_element_origin_path(&r.path, After).map(|p| (After, p)))
+        })
+        .peekable();
+
+    if pseudo_element_rules.peek().is_none() {
+        return (compact_dom, BTreeMap::new());
+    }
+
+    let pseudo_element_rules = pseudo_element_rules.collect::<Vec<_>>();
+
+    let non_leaf_nodes = compact_dom
+        .node_hierarchy
+        .as_ref()
+        .get_parents_sorted_by_depth();
+    let html_tree = construct_html_cascade_tree(
+        &compact_dom.node_hierarchy.as_ref(),
+        &non_leaf_nodes[..],
+        |_| false,
+    );
+    let node_hierarchy: NodeHierarchyItemVec = compact_dom
+        .node_hierarchy
+        .as_ref()
+        .internal
+        .iter()
+        .map(|i| (*i).into())
+        .collect::<Vec<NodeHierarchyItem>>()
+        .into();
+
+    // (has :before, has :after) for every node
+    let slots = compact_dom
+        .node_data
+        .as_ref()
+        .internal
+        .iter()
+        .enumerate()
+        .map(|(node_id, node_data)| {
+            // text, images, iframes etc. can't have children
+            match node_data.get_node_type() {
+                NodeType::Body | NodeType::Div => {}
+                _ => return (false, false),
+            }
+
+            let node_id = NodeId::new(node_id);
+            let could_match = |selector: CssPathPseudoSelector| {
+                pseudo_element_rules
+                    .iter()
+                    .filter(|(s, _)| *s == selector)
+                    .any(|(_, (origin_path, state))| {
+                        matches_html_element(
+                            origin_path,
+                            node_id,
+                            &node_hierarchy.as_container(),
+                            &compact_dom.node_data.as_ref(),
+                            &html_tree.as_ref(),
+                            *state,
+                        )
+                    })
+            };
+
+            (could_match(Before), could_match(After))
+        })
+        .collect::<Vec<_>>();
+
+    if !slots.iter().any(|(before, after)| *before || *after) {
+        return (compact_dom, BTreeMap::new());
+    }
+
+    let CompactDom {
+        node_hierarchy: old_node_hierarchy,
+        node_data: mut old_node_data,
+        root: _,
+    } = compact_dom;
+
+    let old_node_hierarchy = old_node_hierarchy.as_ref();
+    let new_len = old_node_data.len()
+        + slots
+            .iter()
+            .map(|(before, after)| (*before as usize + *after as usize) * 2)
+            .sum::<usize>();
+
+    let mut parents = Vec::<Option<NodeId>>::with_capacity(new_len);
+    let mut node_data = Vec::<NodeData>::with_capacity(new_len);
+    let mut pseudo_elements = BTreeMap::new();
+
+    fn push_pseudo_element(
+        parents: &mut Vec<Option<NodeId>>,
+        node_data: &mut Vec<NodeData>,
+        pseudo_elements: &mut BTreeMap<NodeId, PseudoElement>,
+        origin: NodeId,
+        selector: CssPathPseudoSelector,
+    ) {
+        let box_id = NodeId::new(node_data.len());
+        parents.push(Some(origin));
+        node_data.push(NodeData::div());
+        parents.push(Some(box_id));
+        node_data.push(NodeData::text(""));
+        pseudo_elements.insert(box_id, PseudoElement { origin, selector });
+    }
+
+    // the nodes are stored in depth-first order, so the :after box of a node can be
+    // inserted as soon as the next node is not a descendant anymore
+    // (old node ID, new node ID) of the node and its ancestors
+    let mut open_nodes = Vec::<(NodeId, NodeId)>::new();
+
+    for old_id in 0..old_node_data.len() {
+        let old_id = NodeId::new(old_id);
+        let old_parent = old_node_hierarchy[old_id].parent;
+
+        while let Some((open_old_id, open_new_id)) = open_nodes.last().copied() {
+            if Some(open_old_id) == old_parent {
+                break;
+            }
+            open_nodes.pop();
+            if slots[open_old_id.index()].1 {
+                push_pseudo_element(&mut parents, &mut node_data, &mut pseudo_elements, open_new_id, After);
+            }
+        }
+
+        let new_id = NodeId::new(node_data.len());
+        parents.push(open_nodes.last().map(|(_, new_id)| *new_id));
+        node_data.push(core::mem::take(&mut old_node_data.internal[old_id.index()]));
+
+        if slots[old_id.index()].0 {
+            push_pseudo_element(&mut parents, &mut node_data, &mut pseudo_elements, new_id, Before);
+        }
+
+        open_nodes.push((old_id, new_id));
+    }
+
+    while let Some((open_old_id, open_new_id)) = open_nodes.pop() {
+        if slots[open_old_id.index()].1 {
+            push_pseudo_element(&mut parents, &mut node_data, &mut pseudo_elements, open_new_id, After);
+        }
+    }
+
+    // link the nodes: children are visited in order, so the previous child of
+    // the parent is the previous sibling of the node
+    let mut nodes = vec![Node::ROOT; node_data.len()];
+    for (node_id, parent) in parents.iter().enumerate() {
+        let node_id = NodeId::new(node_id);
+        if let Some(parent) = parent {
+            nodes[node_id.index()].parent = Some(*parent);
+            if let Some(previous_sibling) = nodes[parent.index()].last_child {
+                nodes[previous_sibling.index()].next_sibling = Some(node_id);
+                nodes[node_id.index()].previous_sibling = Some(previous_sibling);
+            }
+            nodes[parent.index()].last_child = Some(node_id);
+        }
+    }
+
+    let compact_dom = CompactDom {
+        node_hierarchy: NodeHierarchy { internal: nodes },
+        node_data: NodeDataContainer { internal: node_data },
+        root: NodeId::ZERO,
+    };
+
+    (compact_dom, pseudo_elements)
 }
 
 impl CssPropertyCache {
@@ -218,15 +664,13 @@ impl CssPropertyCache {
     #[must_use]
     pub fn restyle(
         &mut self,
-        css: &mut Css,
+        mut css: Css,
         node_data: &NodeDataContainerRef<NodeData>,
         node_hierarchy: &NodeHierarchyItemVec,
         non_leaf_nodes: &ParentWithNodeDepthVec,
         html_tree: &NodeDataContainerRef<CascadeInfo>,
     ) -> Vec<TagIdToNodeIdMapping> {
-        use azul_css::CssDeclaration;
         use azul_css::CssPathPseudoSelector::*;
-        use azul_css::LayoutDisplay;
         use rayon::prelude::*;
 
         let css_is_empty = css.is_empty();
@@ -234,31 +678,25 @@ impl CssPropertyCache {
         if !css_is_empty {
             css.sort_by_specificity();
 
+            let resolved_css = resolve_system_style(&css, &self.system_style);
+            let resolved_css = &*resolved_css;
+
+            // the :before / :after boxes are styled by the rules of their node
+            let pseudo_elements = &self.pseudo_elements;
+
             macro_rules! filter_rules {($expected_pseudo_selector:expr, $node_id:expr) => {{
-                css
-                .rules() // can not be parallelized due to specificity order matching
-                .filter(|rule_block| rule_ends_with(&rule_block.path, $expected_pseudo_selector))
-                .filter(|rule_block| matches_html_element(
-                    &rule_block.path,
-                    $node_id,
-                    &node_hierarchy.as_container(),
-                    &node_data,
-                    &html_tree,
-                    $expected_pseudo_selector
-                ))
-                // rule matched, now copy all the styles of this rule
-                .flat_map(|matched_rule| {
-                    matched_rule.declarations
-                    .iter()
-                    .filter_map(move |declaration| {
-                        match declaration {
-                            CssDeclaration::Static(s) => Some(s),
-                            CssDeclaration::Dynamic(_d) => None, // TODO: No variable support yet!
-                        }
-                    })
-                })
-                .map(|prop| prop.clone())
-                .collect::<Vec<CssProperty>>()
+                if is_pseudo_element_node(pseudo_elements, $node_id) {
+                    Vec::new()
+                } else {
+                    get_matching_css_properties(
+                        resolved_css,
+                        $expected_pseudo_selector,
+                        $node_id,
+                        node_data,
+                        node_hierarchy,
+                        html_tree,
+                    )
+                }
             }};}
 
             // NOTE: This is wrong, but fast
@@ -310,6 +748,46 @@ impl CssPropertyCache {
                     }
                 });
 
+            let css_disabled_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)> = node_data
+                .transform_nodeid_multithreaded_optional(|node_id| {
+                    let r = filter_rules!(Some(Disabled), node_id);
+                    if r.is_empty() {
+                        None
+                    } else {
+                        Some((node_id, r))
+                    }
+                });
+
+            let css_checked_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)> = node_data
+                .transform_nodeid_multithreaded_optional(|node_id| {
+                    let r = filter_rules!(Some(Checked), node_id);
+                    if r.is_empty() {
+                        None
+                    } else {
+                        Some((node_id, r))
+                    }
+                });
+
+            let css_selected_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)> = node_data
+                .transform_nodeid_multithreaded_optional(|node_id| {
+                    let r = filter_rules!(Some(Selected), node_id);
+                    if r.is_empty() {
+                        None
+                    } else {
+                        Some((node_id, r))
+                    }
+                });
+
+            let css_indeterminate_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)> = node_data
+                .transform_nodeid_multithreaded_optional(|node_id| {
+                    let r = filter_rules!(Some(Indeterminate), node_id);
+                    if r.is_empty() {
+                        None
+                    } else {
+                        Some((node_id, r))
+                    }
+                });
+
             self.css_normal_props = css_normal_rules
                 .internal
                 .into_iter()
@@ -361,8 +839,72 @@ impl CssPropertyCache {
                     )
                 })
                 .collect();
+
+            self.css_disabled_props = css_disabled_rules
+                .internal
+                .into_iter()
+                .map(|(n, map)| {
+                    (
+                        n,
+                        map.into_iter()
+                            .map(|prop| (prop.get_type(), prop))
+                            .collect(),
+                    )
+                })
+                .collect();
+
+            self.css_checked_props = css_checked_rules
+                .internal
+                .into_iter()
+                .map(|(n, map)| {
+                    (
+                        n,
+                        map.into_iter()
+                            .map(|prop| (prop.get_type(), prop))
+                            .collect(),
+                    )
+                })
+                .collect();
+
+            self.css_selected_props = css_selected_rules
+                .internal
+                .into_iter()
+                .map(|(n, map)| {
+                    (
+                        n,
+                        map.into_iter()
+                            .map(|prop| (prop.get_type(), prop))
+                            .collect(),
+                    )
+                })
+                .collect();
+
+            self.css_indeterminate_props = css_indeterminate_rules
+                .internal
+                .into_iter()
+                .map(|(n, map)| {
+                    (
+                        n,
+                        map.into_iter()
+                            .map(|prop| (prop.get_type(), prop))
+                            .collect(),
+                    )
+                })
+                .collect();
+
+            let pseudo_element_boxes = self.pseudo_elements.keys().copied().collect::<Vec<_>>();
+            for box_id in pseudo_element_boxes {
+                self.restyle_pseudo_element(resolved_css, box_id, node_data, node_hierarchy, html_tree);
+            }
         }
 
+        let keep_css = css_has_id_or_class_selectors(&css) || css.depends_on_system_style();
+        self.stylesheets = if css_is_empty || !keep_css {
+            Vec::new()
+        } else {
+            vec![(NodeId::ZERO, self.node_count, Arc::new(css))]
+        };
+
         // Inheritance: Inherit all values of the parent to the children, but
         // only if the property is inheritable and isn't yet set
         for ParentWithNodeDepth { depth: _, node_id } in non_leaf_nodes.iter() {
@@ -371,241 +913,577 @@ impl CssPropertyCache {
                 None => continue,
             };
 
-            // Inherit CSS properties from map A -> map B
-            // map B will be populated with all inherited CSS properties
-            macro_rules! inherit_props {($from_inherit_map:expr, $to_inherit_map:expr) => {
-                let parent_inheritable_css_props = $from_inherit_map
-                .get(&parent_id)
-                .and_then(|map| {
-                    let parent_inherit_props = map
-                    .iter()
-                    .filter(|(css_prop_type, _)| css_prop_type.is_inheritable())
-                    .map(|(css_prop_type, css_prop)| (*css_prop_type, css_prop.clone()))
-                    .collect::<Vec<(CssPropertyType, CssProperty)>>();
-                    if parent_inherit_props.is_empty() { None } else { Some(parent_inherit_props) }
-                });
+            self.inherit_props_to_children(parent_id, node_data, node_hierarchy, !css_is_empty);
+        }
 
+        // When restyling, the tag / node ID mappings may change, regenerate them
+        // See if the node should have a hit-testing tag ID
 
-                match parent_inheritable_css_props {
-                    Some(pi) => {
-                        // only override the rule if the child does not already have an inherited rule
-                        for child_id in parent_id.az_children(&node_hierarchy.as_container()) {
-                            let child_map = $to_inherit_map
-                                .entry(child_id)
-                                .or_insert_with(|| BTreeMap::new());
+        // In order to hit-test `:hover` and `:active` selectors,
+        // we need to insert "tag IDs" for all rectangles
+        // that have a non-normal path ending, for example if we have
+        // `#thing:hover`, then all nodes selected by `#thing`
+        // need to get a TagId, otherwise, they can't be hit-tested.
 
-                            for (inherited_rule_type, inherited_rule_value) in pi.iter() {
-                                let _ = child_map
-                                .entry(*inherited_rule_type)
-                                .or_insert_with(|| inherited_rule_value.clone());
-                            }
-                        }
-                    },
-                    None => { },
+        // NOTE: restyling a DOM may change the :hover nodes, which is
+        // why the tag IDs have to be re-generated on every .restyle() call!
+        node_data
+            .internal
+            .par_iter()
+            .enumerate()
+            .filter_map(|(node_id, node_data)| {
+                self.get_tag_id_mapping(NodeId::new(node_id), node_data, node_hierarchy)
+            })
+            .collect()
+    }
+
+    /// Re-runs the CSS matching and inheritance for the node and its descendants,
+    /// i.e. after the ids / classes of the node have changed. Other nodes are not
+    /// affected, since selectors can only depend on the node and its ancestors.
+    pub fn restyle_subtree(
+        &mut self,
+        node_id: NodeId,
+        node_data: &NodeDataContainerRef<NodeData>,
+        node_hierarchy: &NodeHierarchyItemVec,
+        non_leaf_nodes: &ParentWithNodeDepthVec,
+        html_tree: &NodeDataContainerRef<CascadeInfo>,
+    ) {
+        let subtree = node_id.index()..get_subtree_end(node_id, &node_hierarchy.as_container());
+
+        let stylesheet = self
+            .stylesheets
+            .iter()
+            .find(|(first_node, node_count, _)| {
+                node_id.index() >= first_node.index()
+                    && node_id.index() < first_node.index() + node_count
+            })
+            .map(|(first_node, node_count, css)| (first_node.index() + node_count, css.clone()));
+
+        // nodes of appended DOMs in the subtree were styled with their own stylesheet
+        if let Some((stylesheet_end, css)) = stylesheet {
+            let css = resolve_system_style(&css, &self.system_style);
+            let nodes = subtree.start..subtree.end.min(stylesheet_end);
+            self.rematch_nodes(&css, nodes, node_data, node_hierarchy, html_tree);
+        }
+
+        // the cascaded properties of the node itself come from its parent
+        // (which didn't change), only the descendants have to inherit again
+        for n in (subtree.start + 1)..subtree.end {
+            let n = NodeId::new(n);
+            self.cascaded_normal_props.remove(&n);
+            self.cascaded_hover_props.remove(&n);
+            self.cascaded_active_props.remove(&n);
+            self.cascaded_focus_props.remove(&n);
+            self.cascaded_disabled_props.remove(&n);
+            self.cascaded_checked_props.remove(&n);
+            self.cascaded_selected_props.remove(&n);
+            self.cascaded_indeterminate_props.remove(&n);
+        }
+
+        // non_leaf_nodes is sorted by depth, so parents always inherit before their children
+        for ParentWithNodeDepth { depth: _, node_id } in non_leaf_nodes.iter() {
+            let parent_id = match node_id.into_crate_internal() {
+                Some(s) if subtree.contains(&s.index()) => s,
+                _ => continue,
+            };
+
+            self.inherit_props_to_children(parent_id, node_data, node_hierarchy, true);
+        }
+    }
+
+    /// Sets the system settings that the system colors and `@media` blocks are resolved with
+    /// and re-runs the CSS matching and inheritance for the nodes whose stylesheet depends on
+    /// the system settings, returns whether any node was restyled
+    pub fn set_system_style(
+        &mut self,
+        system_style: &SystemStyle,
+        node_data: &NodeDataContainerRef<NodeData>,
+        node_hierarchy: &NodeHierarchyItemVec,
+        non_leaf_nodes: &ParentWithNodeDepthVec,
+        html_tree: &NodeDataContainerRef<CascadeInfo>,
+    ) -> bool {
+        if self.system_style == *system_style {
+            return false;
+        }
+
+        self.system_style = *system_style;
+
+        let stylesheets = self
+            .stylesheets
+            .iter()
+            .filter(|(_, _, css)| css.depends_on_system_style())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if stylesheets.is_empty() {
+            return false;
+        }
+
+        for (first_node, node_count, css) in stylesheets {
+            let css = resolve_system_style(&css, system_style);
+            let nodes = first_node.index()..(first_node.index() + node_count);
+            self.rematch_nodes(&css, nodes, node_data, node_hierarchy, html_tree);
+        }
+
+        self.cascaded_normal_props.clear();
+        self.cascaded_hover_props.clear();
+        self.cascaded_active_props.clear();
+        self.cascaded_focus_props.clear();
+        self.cascaded_disabled_props.clear();
+        self.cascaded_checked_props.clear();
+        self.cascaded_selected_props.clear();
+        self.cascaded_indeterminate_props.clear();
+
+        for ParentWithNodeDepth { depth: _, node_id } in non_leaf_nodes.iter() {
+            let parent_id = match node_id.into_crate_internal() {
+                Some(s) => s,
+                None => continue,
+            };
+
+            self.inherit_props_to_children(parent_id, node_data, node_hierarchy, true);
+        }
+
+        true
+    }
+
+    /// Re-runs the CSS matching for the nodes in the range with the (sorted, resolved) stylesheet
+    fn rematch_nodes(
+        &mut self,
+        css: &Css,
+        nodes: core::ops::Range<usize>,
+        node_data: &NodeDataContainerRef<NodeData>,
+        node_hierarchy: &NodeHierarchyItemVec,
+        html_tree: &NodeDataContainerRef<CascadeInfo>,
+    ) {
+        use azul_css::CssPathPseudoSelector::*;
+
+        for n in nodes {
+            let n = NodeId::new(n);
+
+            if self.pseudo_elements.contains_key(&n) {
+                self.restyle_pseudo_element(css, n, node_data, node_hierarchy, html_tree);
+                continue;
+            }
+
+            if self.is_pseudo_element(n) {
+                continue;
+            }
+
+            macro_rules! rematch_rules {($expected_pseudo_selector:expr, $field_name:ident) => {{
+                let r = get_matching_css_properties(
+                    css,
+                    $expected_pseudo_selector,
+                    n,
+                    node_data,
+                    node_hierarchy,
+                    html_tree,
+                );
+                if r.is_empty() {
+                    self.$field_name.remove(&n);
+                } else {
+                    self.$field_name.insert(n, r.into_iter().map(|prop| (prop.get_type(), prop)).collect());
                 }
-            };}
+            }};}
 
-            // Same as inherit_props, but filters along the inline node data instead
-            macro_rules! inherit_inline_css_props {($filter_type:ident, $to_inherit_map:expr) => {
-                let parent_inheritable_css_props = &node_data[parent_id]
-                .inline_css_props
+            rematch_rules!(None, css_normal_props);
+            rematch_rules!(Some(Hover), css_hover_props);
+            rematch_rules!(Some(Active), css_active_props);
+            rematch_rules!(Some(Focus), css_focus_props);
+            rematch_rules!(Some(Disabled), css_disabled_props);
+            rematch_rules!(Some(Checked), css_checked_props);
+            rematch_rules!(Some(Selected), css_selected_props);
+            rematch_rules!(Some(Indeterminate), css_indeterminate_props);
+        }
+    }
+
+    /// Matches the `:before` / `:after` rules against the node that generated the pseudo-element
+    /// and stores the properties as the CSS properties of the generated box, per state of the
+    /// node (the box always has the same state as its node). In a state without `content`, all
+    /// properties of the box are reset, so that the empty box doesn't take up any space.
+    fn restyle_pseudo_element(
+        &mut self,
+        css: &Css,
+        box_id: NodeId,
+        node_data: &NodeDataContainerRef<NodeData>,
+        node_hierarchy: &NodeHierarchyItemVec,
+        html_tree: &NodeDataContainerRef<CascadeInfo>,
+    ) {
+        use alloc::collections::btree_set::BTreeSet;
+        use azul_css::CssPathPseudoSelector::*;
+        use azul_css::{CssPropertyValue, StyleBackgroundContent, StyleContent};
+
+        let PseudoElement { origin, selector } = match self.pseudo_elements.get(&box_id) {
+            Some(s) => *s,
+            None => return,
+        };
+
+        macro_rules! match_rules {($state:expr) => {{
+            get_matching_pseudo_element_properties(
+                css,
+                selector,
+                $state,
+                origin,
+                node_data,
+                node_hierarchy,
+                html_tree,
+            )
+            .into_iter()
+            .map(|prop| (prop.get_type(), prop))
+            .collect::<BTreeMap<CssPropertyType, CssProperty>>()
+        }};}
+
+        // url(image) content is rendered as the background of the box,
+        // its size has to be set with the width / height of the rule
+        fn with_content_image(
+            mut props: BTreeMap<CssPropertyType, CssProperty>,
+        ) -> BTreeMap<CssPropertyType, CssProperty> {
+            let image = match props.get(&CssPropertyType::Content) {
+                Some(CssProperty::Content(CssPropertyValue::Exact(StyleContent::Image(id)))) => {
+                    id.clone()
+                }
+                _ => return props,
+            };
+            props
+                .entry(CssPropertyType::BackgroundContent)
+                .or_insert_with(|| {
+                    CssProperty::BackgroundContent(CssPropertyValue::Exact(
+                        vec![StyleBackgroundContent::Image(image)].into(),
+                    ))
+                });
+            props
+        }
+
+        let normal_props = match_rules!(None);
+        let normal_has_content = pseudo_element_has_content(&normal_props);
+
+        let state_props = [
+            Hover,
+            Active,
+            Focus,
+            Disabled,
+            Checked,
+            Selected,
+            Indeterminate,
+        ]
+        .iter()
+        .map(|state| match_rules!(Some(*state)))
+        .collect::<Vec<_>>();
+
+        let all_property_types = normal_props
+            .keys()
+            .chain(state_props.iter().flat_map(|props| props.keys()))
+            .copied()
+            .collect::<BTreeSet<_>>();
+
+        let state_props = state_props.into_iter().map(|props| {
+            if props.is_empty() {
+                // the properties of the normal state apply
+                return props;
+            }
+
+            let mut merged_props = normal_props.clone();
+            merged_props.extend(props.clone().into_iter());
+
+            if !pseudo_element_has_content(&merged_props) {
+                all_property_types
+                    .iter()
+                    .map(|t| (*t, CssProperty::none(*t)))
+                    .collect()
+            } else if normal_has_content {
+                with_content_image(props)
+            } else {
+                // the normal state has no properties to fall back to
+                with_content_image(merged_props)
+            }
+        })
+        .collect::<Vec<_>>();
+        let mut state_props = state_props.into_iter();
+
+        let normal_props = if normal_has_content {
+            with_content_image(normal_props)
+        } else {
+            BTreeMap::new()
+        };
+
+        macro_rules! set_props {($field_name:ident, $props:expr) => {{
+            let props: BTreeMap<CssPropertyType, CssProperty> = $props;
+            if props.is_empty() {
+                self.$field_name.remove(&box_id);
+            } else {
+                self.$field_name.insert(box_id, props);
+            }
+        }};}
+
+        set_props!(css_normal_props, normal_props);
+        set_props!(css_hover_props, state_props.next().unwrap_or_default());
+        set_props!(css_active_props, state_props.next().unwrap_or_default());
+        set_props!(css_focus_props, state_props.next().unwrap_or_default());
+        set_props!(css_disabled_props, state_props.next().unwrap_or_default());
+        set_props!(css_checked_props, state_props.next().unwrap_or_default());
+        set_props!(css_selected_props, state_props.next().unwrap_or_default());
+        set_props!(css_indeterminate_props, state_props.next().unwrap_or_default());
+    }
+
+    /// Inherits the inheritable properties of the parent (inline, CSS file and
+    /// previously inherited properties) to its direct children
+    fn inherit_props_to_children(
+        &mut self,
+        parent_id: NodeId,
+        node_data: &NodeDataContainerRef<NodeData>,
+        node_hierarchy: &NodeHierarchyItemVec,
+        inherit_css_props: bool,
+    ) {
+        // Inherit CSS properties from map A -> map B
+        // map B will be populated with all inherited CSS properties
+        macro_rules! inherit_props {($from_inherit_map:expr, $to_inherit_map:expr) => {
+            let parent_inheritable_css_props = $from_inherit_map
+            .get(&parent_id)
+            .and_then(|map| {
+                let parent_inherit_props = map
                 .iter()
-                 // test whether the property is a [normal, hover, focus, active] property
-                .filter_map(|css_prop| if let NodeDataInlineCssProperty::$filter_type(p) = css_prop { Some(p) } else { None })
-                // test whether the property is inheritable
-                .filter(|css_prop| css_prop.get_type().is_inheritable())
-                .cloned()
-                .collect::<Vec<CssProperty>>();
-
-                if !parent_inheritable_css_props.is_empty() {
+                .filter(|(css_prop_type, _)| css_prop_type.is_inheritable())
+                .map(|(css_prop_type, css_prop)| (*css_prop_type, css_prop.clone()))
+                .collect::<Vec<(CssPropertyType, CssProperty)>>();
+                if parent_inherit_props.is_empty() { None } else { Some(parent_inherit_props) }
+            });
+
+
+            match parent_inheritable_css_props {
+                Some(pi) => {
                     // only override the rule if the child does not already have an inherited rule
                     for child_id in parent_id.az_children(&node_hierarchy.as_container()) {
-                        let child_map = $to_inherit_map.entry(child_id).or_insert_with(|| BTreeMap::new());
-                        for inherited_rule in parent_inheritable_css_props.iter() {
+                        let child_map = $to_inherit_map
+                            .entry(child_id)
+                            .or_insert_with(|| BTreeMap::new());
+
+                        for (inherited_rule_type, inherited_rule_value) in pi.iter() {
                             let _ = child_map
-                            .entry(inherited_rule.get_type())
-                            .or_insert_with(|| inherited_rule.clone());
+                            .entry(*inherited_rule_type)
+                            .or_insert_with(|| inherited_rule_value.clone());
                         }
                     }
+                },
+                None => { },
+            }
+        };}
+
+        // Same as inherit_props, but filters along the inline node data instead
+        macro_rules! inherit_inline_css_props {($filter_type:ident, $to_inherit_map:expr) => {
+            let parent_inheritable_css_props = &node_data[parent_id]
+            .inline_css_props
+            .iter()
+             // test whether the property is a [normal, hover, focus, active] property
+            .filter_map(|css_prop| if let NodeDataInlineCssProperty::$filter_type(p) = css_prop { Some(p) } else { None })
+            // test whether the property is inheritable
+            .filter(|css_prop| css_prop.get_type().is_inheritable())
+            .cloned()
+            .collect::<Vec<CssProperty>>();
+
+            if !parent_inheritable_css_props.is_empty() {
+                // only override the rule if the child does not already have an inherited rule
+                for child_id in parent_id.az_children(&node_hierarchy.as_container()) {
+                    let child_map = $to_inherit_map.entry(child_id).or_insert_with(|| BTreeMap::new());
+                    for inherited_rule in parent_inheritable_css_props.iter() {
+                        let _ = child_map
+                        .entry(inherited_rule.get_type())
+                        .or_insert_with(|| inherited_rule.clone());
+                    }
                 }
+            }
 
-            };}
+        };}
+
+        // strongest inheritance first
+
+        // Inherit inline CSS properties
+        inherit_inline_css_props!(Normal, self.cascaded_normal_props);
+        inherit_inline_css_props!(Hover, self.cascaded_hover_props);
+        inherit_inline_css_props!(Active, self.cascaded_active_props);
+        inherit_inline_css_props!(Focus, self.cascaded_focus_props);
+
+        // Inherit the CSS properties from the CSS file
+        if inherit_css_props {
+            inherit_props!(self.css_normal_props, self.cascaded_normal_props);
+            inherit_props!(self.css_hover_props, self.cascaded_hover_props);
+            inherit_props!(self.css_active_props, self.cascaded_active_props);
+            inherit_props!(self.css_focus_props, self.cascaded_focus_props);
+            inherit_props!(self.css_disabled_props, self.cascaded_disabled_props);
+            inherit_props!(self.css_checked_props, self.cascaded_checked_props);
+            inherit_props!(self.css_selected_props, self.cascaded_selected_props);
+            inherit_props!(self.css_indeterminate_props, self.cascaded_indeterminate_props);
+        }
 
-            // strongest inheritance first
+        // Inherit properties that were inherited in a previous iteration of the loop
+        inherit_props!(self.cascaded_normal_props, self.cascaded_normal_props);
+        inherit_props!(self.cascaded_hover_props, self.cascaded_hover_props);
+        inherit_props!(self.cascaded_active_props, self.cascaded_active_props);
+        inherit_props!(self.cascaded_focus_props, self.cascaded_focus_props);
+        inherit_props!(self.cascaded_disabled_props, self.cascaded_disabled_props);
+        inherit_props!(self.cascaded_checked_props, self.cascaded_checked_props);
+        inherit_props!(self.cascaded_selected_props, self.cascaded_selected_props);
+        inherit_props!(self.cascaded_indeterminate_props, self.cascaded_indeterminate_props);
+    }
 
-            // Inherit inline CSS properties
-            inherit_inline_css_props!(Normal, self.cascaded_normal_props);
-            inherit_inline_css_props!(Hover, self.cascaded_hover_props);
-            inherit_inline_css_props!(Active, self.cascaded_active_props);
-            inherit_inline_css_props!(Focus, self.cascaded_focus_props);
+    /// Returns a new tag ID for the node if it has to be hit-tested, i.e.
+    /// because it is focusable or has :hover, :active or :focus properties
+    fn get_tag_id_mapping(
+        &self,
+        node_id: NodeId,
+        node_data: &NodeData,
+        node_hierarchy: &NodeHierarchyItemVec,
+    ) -> Option<TagIdToNodeIdMapping> {
+        use azul_css::LayoutDisplay;
+
+        let default_node_state = StyledNodeState::default();
+
+        let should_auto_insert_tabindex = node_data
+            .get_callbacks()
+            .iter()
+            .any(|cb| cb.event.is_focus_callback());
 
-            // Inherit the CSS properties from the CSS file
-            if !css_is_empty {
-                inherit_props!(self.css_normal_props, self.cascaded_normal_props);
-                inherit_props!(self.css_hover_props, self.cascaded_hover_props);
-                inherit_props!(self.css_active_props, self.cascaded_active_props);
-                inherit_props!(self.css_focus_props, self.cascaded_focus_props);
+        let tab_index = match node_data.get_tab_index() {
+            Some(s) => Some(*s),
+            None => {
+                if should_auto_insert_tabindex {
+                    Some(TabIndex::Auto)
+                } else {
+                    None
+                }
             }
+        };
 
-            // Inherit properties that were inherited in a previous iteration of the loop
-            inherit_props!(self.cascaded_normal_props, self.cascaded_normal_props);
-            inherit_props!(self.cascaded_hover_props, self.cascaded_hover_props);
-            inherit_props!(self.cascaded_active_props, self.cascaded_active_props);
-            inherit_props!(self.cascaded_focus_props, self.cascaded_focus_props);
+        // generated pseudo-elements are hit-tested as part of their node
+        if self.is_pseudo_element(node_id) {
+            return None;
         }
 
-        // When restyling, the tag / node ID mappings may change, regenerate them
-        // See if the node should have a hit-testing tag ID
-        let default_node_state = StyledNodeState::default();
+        let mut node_should_have_tag = false;
 
-        // In order to hit-test `:hover` and `:active` selectors,
-        // we need to insert "tag IDs" for all rectangles
-        // that have a non-normal path ending, for example if we have
-        // `#thing:hover`, then all nodes selected by `#thing`
-        // need to get a TagId, otherwise, they can't be hit-tested.
+        // workaround for "goto end" - early break if
+        // one of the conditions is true
+        loop {
+            // check for display: none
+            let display = self
+                .get_display(&node_data, &node_id, &default_node_state)
+                .and_then(|p| p.get_property_or_default())
+                .unwrap_or_default();
 
-        // NOTE: restyling a DOM may change the :hover nodes, which is
-        // why the tag IDs have to be re-generated on every .restyle() call!
-        node_data
-            .internal
-            .par_iter()
-            .enumerate()
-            .filter_map(|(node_id, node_data)| {
-                let node_id = NodeId::new(node_id);
+            if display == LayoutDisplay::None {
+                node_should_have_tag = false;
+                break;
+            }
 
-                let should_auto_insert_tabindex = node_data
-                    .get_callbacks()
-                    .iter()
-                    .any(|cb| cb.event.is_focus_callback());
+            if node_data.has_context_menu() {
+                node_should_have_tag = true;
+                break;
+            }
 
-                let tab_index = match node_data.get_tab_index() {
-                    Some(s) => Some(*s),
-                    None => {
-                        if should_auto_insert_tabindex {
-                            Some(TabIndex::Auto)
-                        } else {
-                            None
-                        }
-                    }
-                };
+            if tab_index.is_some() {
+                node_should_have_tag = true;
+                break;
+            }
 
-                let mut node_should_have_tag = false;
+            // check for context menu
+            if node_data.get_context_menu().is_some() {
+                node_should_have_tag = true;
+                break;
+            }
 
-                // workaround for "goto end" - early break if
-                // one of the conditions is true
-                loop {
-                    // check for display: none
-                    let display = self
-                        .get_display(&node_data, &node_id, &default_node_state)
-                        .and_then(|p| p.get_property_or_default())
-                        .unwrap_or_default();
+            // check for :hover
+            let node_has_hover_props =
+                node_data.inline_css_props.as_ref().iter().any(|p| match p {
+                    NodeDataInlineCssProperty::Hover(_) => true,
+                    _ => false,
+                }) || self.css_hover_props.get(&node_id).is_some()
+                    || self.cascaded_hover_props.get(&node_id).is_some();
 
-                    if display == LayoutDisplay::None {
-                        node_should_have_tag = false;
-                        break;
-                    }
+            if node_has_hover_props {
+                node_should_have_tag = true;
+                break;
+            }
 
-                    if node_data.has_context_menu() {
-                        node_should_have_tag = true;
-                        break;
-                    }
+            // check for :active
+            let node_has_active_props =
+                node_data.inline_css_props.as_ref().iter().any(|p| match p {
+                    NodeDataInlineCssProperty::Active(_) => true,
+                    _ => false,
+                }) || self.css_active_props.get(&node_id).is_some()
+                    || self.cascaded_active_props.get(&node_id).is_some();
 
-                    if tab_index.is_some() {
-                        node_should_have_tag = true;
-                        break;
-                    }
+            if node_has_active_props {
+                node_should_have_tag = true;
+                break;
+            }
 
-                    // check for context menu
-                    if node_data.get_context_menu().is_some() {
-                        node_should_have_tag = true;
-                        break;
-                    }
+            // check for :focus
+            let node_has_focus_props =
+                node_data.inline_css_props.as_ref().iter().any(|p| match p {
+                    NodeDataInlineCssProperty::Focus(_) => true,
+                    _ => false,
+                }) || self.css_focus_props.get(&node_id).is_some()
+                    || self.cascaded_focus_props.get(&node_id).is_some();
 
-                    // check for :hover
-                    let node_has_hover_props =
-                        node_data.inline_css_props.as_ref().iter().any(|p| match p {
-                            NodeDataInlineCssProperty::Hover(_) => true,
-                            _ => false,
-                        }) || self.css_hover_props.get(&node_id).is_some()
-                            || self.cascaded_hover_props.get(&node_id).is_some();
-
-                    if node_has_hover_props {
-                        node_should_have_tag = true;
-                        break;
-                    }
+            if node_has_focus_props {
+                node_should_have_tag = true;
+                break;
+            }
 
-                    // check for :active
-                    let node_has_active_props =
-                        node_data.inline_css_props.as_ref().iter().any(|p| match p {
-                            NodeDataInlineCssProperty::Active(_) => true,
-                            _ => false,
-                        }) || self.css_active_props.get(&node_id).is_some()
-                            || self.cascaded_active_props.get(&node_id).is_some();
-
-                    if node_has_active_props {
-                        node_should_have_tag = true;
-                        break;
-                    }
+            // check whether any Hover(), Active() or Focus() callbacks are present
+            let node_only_window_callbacks = node_data.get_callbacks().is_empty()
+                || node_data
+                    .get_callbacks()
+                    .iter()
+                    .all(|cb| cb.event.is_window_callback());
 
-                    // check for :focus
-                    let node_has_focus_props =
-                        node_data.inline_css_props.as_ref().iter().any(|p| match p {
-                            NodeDataInlineCssProperty::Focus(_) => true,
-                            _ => false,
-                        }) || self.css_focus_props.get(&node_id).is_some()
-                            || self.cascaded_focus_props.get(&node_id).is_some();
-
-                    if node_has_focus_props {
-                        node_should_have_tag = true;
-                        break;
-                    }
+            if !node_only_window_callbacks {
+                node_should_have_tag = true;
+                break;
+            }
 
-                    // check whether any Hover(), Active() or Focus() callbacks are present
-                    let node_only_window_callbacks = node_data.get_callbacks().is_empty()
-                        || node_data
-                            .get_callbacks()
-                            .iter()
-                            .all(|cb| cb.event.is_window_callback());
+            // check for non-default cursor: property - needed for hit-testing cursor
+            let node_has_non_default_cursor = self
+                .get_cursor(&node_data, &node_id, &default_node_state)
+                .is_some();
 
-                    if !node_only_window_callbacks {
-                        node_should_have_tag = true;
-                        break;
-                    }
+            if node_has_non_default_cursor {
+                node_should_have_tag = true;
+                break;
+            }
 
-                    // check for non-default cursor: property - needed for hit-testing cursor
-                    let node_has_non_default_cursor = self
-                        .get_cursor(&node_data, &node_id, &default_node_state)
-                        .is_some();
+            // check for -azul-window-drag-region: property - needed for
+            // moving / resizing windows without decorations
+            let node_has_window_drag_region = self
+                .get_window_drag_region(&node_data, &node_id, &default_node_state)
+                .and_then(|p| p.get_property().copied())
+                .unwrap_or_default() != StyleWindowDragRegion::None;
 
-                    if node_has_non_default_cursor {
-                        node_should_have_tag = true;
-                        break;
-                    }
+            if node_has_window_drag_region {
+                node_should_have_tag = true;
+                break;
+            }
 
-                    break;
-                }
+            break;
+        }
 
-                if !node_should_have_tag {
-                    None
-                } else {
-                    Some(TagIdToNodeIdMapping {
-                        tag_id: AzTagId::from_crate_internal(TagId::unique()),
-                        node_id: NodeHierarchyItemId::from_crate_internal(Some(node_id)),
-                        tab_index: tab_index.into(),
-                        parent_node_ids: {
-                            let mut parents = Vec::new();
-                            let mut cur_parent = node_hierarchy.as_container()[node_id].parent_id();
-                            while let Some(c) = cur_parent.clone() {
-                                parents.push(NodeHierarchyItemId::from_crate_internal(Some(c)));
-                                cur_parent = node_hierarchy.as_container()[c].parent_id();
-                            }
-                            parents.reverse(); // parents sorted in depth-increasing order
-                            parents.into()
-                        },
-                    })
-                }
+        if !node_should_have_tag {
+            None
+        } else {
+            Some(TagIdToNodeIdMapping {
+                tag_id: AzTagId::from_crate_internal(TagId::unique()),
+                node_id: NodeHierarchyItemId::from_crate_internal(Some(node_id)),
+                tab_index: tab_index.into(),
+                parent_node_ids: {
+                    let mut parents = Vec::new();
+                    let mut cur_parent = node_hierarchy.as_container()[node_id].parent_id();
+                    while let Some(c) = cur_parent.clone() {
+                        parents.push(NodeHierarchyItemId::from_crate_internal(Some(c)));
+                        cur_parent = node_hierarchy.as_container()[c].parent_id();
+                    }
+                    parents.reverse(); // parents sorted in depth-increasing order
+                    parents.into()
+                },
             })
-            .collect()
+        }
     }
 
     pub fn get_computed_css_style_string(
@@ -651,6 +1529,9 @@ impl CssPropertyCache {
         if let Some(p) = self.get_tab_width(&node_data, node_id, node_state) {
             s.push_str(&format!("tab-width: {};", p.get_css_value_fmt()));
         }
+        if let Some(p) = self.get_hyphens(&node_data, node_id, node_state) {
+            s.push_str(&format!("hyphens: {};", p.get_css_value_fmt()));
+        }
         if let Some(p) = self.get_cursor(&node_data, node_id, node_state) {
             s.push_str(&format!("cursor: {};", p.get_css_value_fmt()));
         }
@@ -729,6 +1610,33 @@ impl CssPropertyCache {
         if let Some(p) = self.get_transform(&node_data, node_id, node_state) {
             s.push_str(&format!("transform: {};", p.get_css_value_fmt()));
         }
+        if let Some(p) = self.get_scrollbar_width(&node_data, node_id, node_state) {
+            s.push_str(&format!("scrollbar-width: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_scrollbar_color(&node_data, node_id, node_state) {
+            s.push_str(&format!("scrollbar-color: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_scrollbar_thumb_hover_color(&node_data, node_id, node_state) {
+            s.push_str(&format!("-azul-scrollbar-thumb-hover-color: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_scrollbar_thumb_active_color(&node_data, node_id, node_state) {
+            s.push_str(&format!("-azul-scrollbar-thumb-active-color: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_window_drag_region(&node_data, node_id, node_state) {
+            s.push_str(&format!("-azul-window-drag-region: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_caret_animation_duration(&node_data, node_id, node_state) {
+            s.push_str(&format!("-azul-caret-animation-duration: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_scroll_padding(&node_data, node_id, node_state) {
+            s.push_str(&format!("scroll-padding: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_content(&node_data, node_id, node_state) {
+            s.push_str(&format!("content: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_overflow_anchor(&node_data, node_id, node_state) {
+            s.push_str(&format!("overflow-anchor: {};", p.get_css_value_fmt()));
+        }
         if let Some(p) = self.get_transform_origin(&node_data, node_id, node_state) {
             s.push_str(&format!("transform-origin: {};", p.get_css_value_fmt()));
         }
@@ -738,6 +1646,12 @@ impl CssPropertyCache {
         if let Some(p) = self.get_backface_visibility(&node_data, node_id, node_state) {
             s.push_str(&format!("backface-visibility: {};", p.get_css_value_fmt()));
         }
+        if let Some(p) = self.get_perspective(&node_data, node_id, node_state) {
+            s.push_str(&format!("perspective: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_transform_style(&node_data, node_id, node_state) {
+            s.push_str(&format!("transform-style: {};", p.get_css_value_fmt()));
+        }
         if let Some(p) = self.get_display(&node_data, node_id, node_state) {
             s.push_str(&format!("display: {};", p.get_css_value_fmt()));
         }
@@ -897,11 +1811,48 @@ impl CssPropertyCache {
             cascaded_hover_props: BTreeMap::new(),
             cascaded_active_props: BTreeMap::new(),
             cascaded_focus_props: BTreeMap::new(),
+            cascaded_disabled_props: BTreeMap::new(),
+            cascaded_checked_props: BTreeMap::new(),
+            cascaded_selected_props: BTreeMap::new(),
+            cascaded_indeterminate_props: BTreeMap::new(),
 
             css_normal_props: BTreeMap::new(),
             css_hover_props: BTreeMap::new(),
             css_active_props: BTreeMap::new(),
             css_focus_props: BTreeMap::new(),
+            css_disabled_props: BTreeMap::new(),
+            css_checked_props: BTreeMap::new(),
+            css_selected_props: BTreeMap::new(),
+            css_indeterminate_props: BTreeMap::new(),
+
+            stylesheets: Vec::new(),
+            system_style: SystemStyle::DEFAULT,
+            pseudo_elements: BTreeMap::new(),
+        }
+    }
+
+    /// Returns whether the node is the box or the text of a generated `:before` / `:after`
+    /// pseudo-element, which are not part of the DOM that the application created
+    pub fn is_pseudo_element(&self, node_id: NodeId) -> bool {
+        is_pseudo_element_node(&self.pseudo_elements, node_id)
+    }
+
+    /// Returns the (CSS, cascaded) properties that apply when the node has the state
+    fn get_state_flag_props(
+        &self,
+        flag: NodeStateFlag,
+    ) -> (
+        &BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+        &BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+    ) {
+        match flag {
+            NodeStateFlag::Disabled => (&self.css_disabled_props, &self.cascaded_disabled_props),
+            NodeStateFlag::Checked => (&self.css_checked_props, &self.cascaded_checked_props),
+            NodeStateFlag::Selected => (&self.css_selected_props, &self.cascaded_selected_props),
+            NodeStateFlag::Indeterminate => (
+                &self.css_indeterminate_props,
+                &self.cascaded_indeterminate_props,
+            ),
         }
     }
 
@@ -922,10 +1873,32 @@ impl CssPropertyCache {
         append_css_property_vec!(cascaded_hover_props);
         append_css_property_vec!(cascaded_active_props);
         append_css_property_vec!(cascaded_focus_props);
+        append_css_property_vec!(cascaded_disabled_props);
+        append_css_property_vec!(cascaded_checked_props);
+        append_css_property_vec!(cascaded_selected_props);
+        append_css_property_vec!(cascaded_indeterminate_props);
         append_css_property_vec!(css_normal_props);
         append_css_property_vec!(css_hover_props);
         append_css_property_vec!(css_active_props);
         append_css_property_vec!(css_focus_props);
+        append_css_property_vec!(css_disabled_props);
+        append_css_property_vec!(css_checked_props);
+        append_css_property_vec!(css_selected_props);
+        append_css_property_vec!(css_indeterminate_props);
+
+        for (first_node, node_count, css) in other.stylesheets.drain(..) {
+            self.stylesheets.push((first_node + self.node_count, node_count, css));
+        }
+
+        for (box_id, pseudo_element) in core::mem::take(&mut other.pseudo_elements) {
+            self.pseudo_elements.insert(
+                box_id + self.node_count,
+                PseudoElement {
+                    origin: pseudo_element.origin + self.node_count,
+                    selector: pseudo_element.selector,
+                },
+            );
+        }
 
         self.node_count += other.node_count;
     }
@@ -1079,12 +2052,41 @@ impl CssPropertyCache {
             return Some(p);
         }
 
-        if !(node_state.normal || node_state.active || node_state.hover || node_state.focused) {
+        if !(node_state.normal
+            || node_state.active
+            || node_state.hover
+            || node_state.focused
+            || node_state.disabled
+            || node_state.checked
+            || node_state.selected
+            || node_state.indeterminate)
+        {
             return None;
         }
 
         // If that fails, see if there is an inline CSS property that matches
-        // :focus > :active > :hover > :normal
+        // :disabled > :focus > :active > :hover > :indeterminate > :checked > :selected > :normal
+        //
+        // (a disabled node doesn't react to the mouse, the interaction states
+        // override the application-defined states otherwise)
+        if node_state.disabled {
+            if let Some(p) = self
+                .css_disabled_props
+                .get(node_id)
+                .and_then(|map| map.get(css_property_type))
+            {
+                return Some(p);
+            }
+
+            if let Some(p) = self
+                .cascaded_disabled_props
+                .get(node_id)
+                .and_then(|map| map.get(css_property_type))
+            {
+                return Some(p);
+            }
+        }
+
         if node_state.focused {
             if let Some(p) = self
                 .css_focus_props
@@ -1187,6 +2189,60 @@ impl CssPropertyCache {
             }
         }
 
+        if node_state.indeterminate {
+            if let Some(p) = self
+                .css_indeterminate_props
+                .get(node_id)
+                .and_then(|map| map.get(css_property_type))
+            {
+                return Some(p);
+            }
+
+            if let Some(p) = self
+                .cascaded_indeterminate_props
+                .get(node_id)
+                .and_then(|map| map.get(css_property_type))
+            {
+                return Some(p);
+            }
+        }
+
+        if node_state.checked {
+            if let Some(p) = self
+                .css_checked_props
+                .get(node_id)
+                .and_then(|map| map.get(css_property_type))
+            {
+                return Some(p);
+            }
+
+            if let Some(p) = self
+                .cascaded_checked_props
+                .get(node_id)
+                .and_then(|map| map.get(css_property_type))
+            {
+                return Some(p);
+            }
+        }
+
+        if node_state.selected {
+            if let Some(p) = self
+                .css_selected_props
+                .get(node_id)
+                .and_then(|map| map.get(css_property_type))
+            {
+                return Some(p);
+            }
+
+            if let Some(p) = self
+                .cascaded_selected_props
+                .get(node_id)
+                .and_then(|map| map.get(css_property_type))
+            {
+                return Some(p);
+            }
+        }
+
         if node_state.normal {
             if let Some(p) = self
                 .css_normal_props
@@ -1363,6 +2419,15 @@ impl CssPropertyCache {
         self.get_property(node_data, node_id, node_state, &CssPropertyType::TabWidth)
             .and_then(|p| p.as_tab_width())
     }
+    pub fn get_hyphens<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleHyphensValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::Hyphens)
+            .and_then(|p| p.as_hyphens())
+    }
     pub fn get_cursor<'a>(
         &'a self,
         node_data: &'a NodeData,
@@ -1605,6 +2670,105 @@ impl CssPropertyCache {
         self.get_property(node_data, node_id, node_state, &CssPropertyType::Opacity)
             .and_then(|p| p.as_opacity())
     }
+    pub fn get_scrollbar_width<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleScrollbarWidthValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::ScrollbarWidth)
+            .and_then(|p| p.as_scrollbar_width())
+    }
+    pub fn get_scrollbar_color<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleScrollbarColorValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::ScrollbarColor)
+            .and_then(|p| p.as_scrollbar_color())
+    }
+    pub fn get_scrollbar_thumb_hover_color<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleScrollbarThumbHoverColorValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::ScrollbarThumbHoverColor)
+            .and_then(|p| p.as_scrollbar_thumb_hover_color())
+    }
+    pub fn get_scrollbar_thumb_active_color<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleScrollbarThumbActiveColorValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::ScrollbarThumbActiveColor)
+            .and_then(|p| p.as_scrollbar_thumb_active_color())
+    }
+    pub fn get_window_drag_region<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleWindowDragRegionValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::WindowDragRegion)
+            .and_then(|p| p.as_window_drag_region())
+    }
+    pub fn get_caret_animation_duration<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleCaretAnimationDurationValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::CaretAnimationDuration)
+            .and_then(|p| p.as_caret_animation_duration())
+    }
+    pub fn get_scroll_padding<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleScrollPaddingValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::ScrollPadding)
+            .and_then(|p| p.as_scroll_padding())
+    }
+    pub fn get_content<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleContentValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::Content)
+            .and_then(|p| p.as_content())
+    }
+    pub fn get_counter_reset<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleCounterResetValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::CounterReset)
+            .and_then(|p| p.as_counter_reset())
+    }
+    pub fn get_counter_increment<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleCounterIncrementValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::CounterIncrement)
+            .and_then(|p| p.as_counter_increment())
+    }
+    pub fn get_overflow_anchor<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleOverflowAnchorValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::OverflowAnchor)
+            .and_then(|p| p.as_overflow_anchor())
+    }
     pub fn get_transform<'a>(
         &'a self,
         node_data: &'a NodeData,
@@ -1656,6 +2820,24 @@ impl CssPropertyCache {
         )
         .and_then(|p| p.as_backface_visibility())
     }
+    pub fn get_perspective<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StylePerspectiveValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::Perspective)
+            .and_then(|p| p.as_perspective())
+    }
+    pub fn get_transform_style<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleTransformStyleValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::TransformStyle)
+            .and_then(|p| p.as_transform_style())
+    }
     pub fn get_display<'a>(
         &'a self,
         node_data: &'a NodeData,
@@ -2396,7 +3578,7 @@ impl StyledDom {
     //
     // The CSS will be left in-place, but will be re-ordered
     #[cfg(feature = "multithreading")]
-    pub fn new(dom: &mut Dom, mut css: CssApiWrapper) -> Self {
+    pub fn new(dom: &mut Dom, css: CssApiWrapper) -> Self {
         use crate::dom::EventFilter;
         use core::mem;
         use rayon::prelude::*;
@@ -2406,6 +3588,7 @@ impl StyledDom {
         mem::swap(dom, &mut swap_dom);
 
         let compact_dom: CompactDom = swap_dom.into();
+        let (compact_dom, pseudo_elements) = insert_pseudo_elements(compact_dom, &css.css);
         let non_leaf_nodes = compact_dom
             .node_hierarchy
             .as_ref()
@@ -2427,13 +3610,30 @@ impl StyledDom {
             compact_dom.len()
         ];
 
+        // initial :disabled / :checked / ... states of the nodes
+        for (node_id, node_data) in compact_dom.node_data.as_ref().internal.iter().enumerate() {
+            for flag in node_data.get_state_flags() {
+                styled_nodes[node_id].state.set_flag(*flag, true);
+            }
+        }
+
+        for (box_id, pseudo_element) in pseudo_elements.iter() {
+            let origin_state = styled_nodes[pseudo_element.origin.index()].state.clone();
+            styled_nodes[box_id.index()].state = origin_state.clone();
+            styled_nodes[box_id.index() + 1].state = origin_state;
+        }
+
         // fill out the css property cache: compute the inline properties first so that
         // we can early-return in case the css is empty
 
         let mut css_property_cache = CssPropertyCache::empty(compact_dom.node_data.len());
+        css_property_cache.pseudo_elements = pseudo_elements;
 
-        let html_tree =
-            construct_html_cascade_tree(&compact_dom.node_hierarchy.as_ref(), &non_leaf_nodes[..]);
+        let html_tree = construct_html_cascade_tree(
+            &compact_dom.node_hierarchy.as_ref(),
+            &non_leaf_nodes[..],
+            |node_id| css_property_cache.is_pseudo_element(node_id),
+        );
 
         let non_leaf_nodes = non_leaf_nodes
             .par_iter()
@@ -2447,7 +3647,7 @@ impl StyledDom {
 
         // apply all the styles from the CSS
         let tag_ids = css_property_cache.restyle(
-            &mut css.css,
+            css.css,
             &compact_dom.node_data.as_ref(),
             &node_hierarchy,
             &non_leaf_nodes,
@@ -2528,7 +3728,7 @@ impl StyledDom {
             })
             .collect::<Vec<_>>();
 
-        StyledDom {
+        let mut styled_dom = StyledDom {
             root: NodeHierarchyItemId::from_crate_internal(Some(compact_dom.root)),
             node_hierarchy,
             node_data: compact_dom.node_data.internal.into(),
@@ -2540,11 +3740,16 @@ impl StyledDom {
             nodes_with_datasets: nodes_with_datasets.into(),
             non_leaf_nodes,
             css_property_cache: CssPropertyCachePtr::new(css_property_cache),
-        }
+        };
+
+        let _ = styled_dom.update_pseudo_element_texts();
+
+        styled_dom
     }
 
     /// Appends another `StyledDom` as a child to the `self.root`
-    /// without re-styling the DOM itself
+    /// without re-styling the DOM itself (if the root has an `:after`
+    /// pseudo-element, the new child is inserted after it)
     pub fn append_child(&mut self, mut other: Self) {
         // shift all the node ids in other by self.len()
         let self_len = self.node_hierarchy.as_ref().len();
@@ -2554,9 +3759,16 @@ impl StyledDom {
         let other_root_id = other.root.into_crate_internal().unwrap_or(NodeId::ZERO);
 
         // iterate through the direct root children and adjust the cascade_info
-        let current_root_children_count = self_root_id
+        let current_root_children = self_root_id
             .az_children(&self.node_hierarchy.as_container())
-            .count();
+            .filter(|child_id| !self.get_css_property_cache().is_pseudo_element(*child_id))
+            .collect::<Vec<_>>();
+        let current_root_children_count = current_root_children.len();
+
+        // the appended node is the new :last child (an :after box stays behind it)
+        if let Some(current_last_child) = current_root_children.last() {
+            self.cascade_info.as_mut()[current_last_child.index()].is_last_child = false;
+        }
 
         other.cascade_info.as_mut()[other_root_id.index()].index_in_parent =
             current_root_children_count as u32;
@@ -2800,11 +4012,11 @@ impl StyledDom {
         s
     }
 
-    pub fn restyle(&mut self, mut css: CssApiWrapper) {
+    pub fn restyle(&mut self, css: CssApiWrapper) {
         use rayon::prelude::*;
 
         let new_tag_ids = self.css_property_cache.downcast_mut().restyle(
-            &mut css.css,
+            css.css,
             &self.node_data.as_container(),
             &self.node_hierarchy,
             &self.non_leaf_nodes,
@@ -2821,23 +4033,183 @@ impl StyledDom {
                 styled_node.tag_id = None.into();
             });
 
-        new_tag_ids
-            .iter()
-            .filter_map(|tag_id_node_id_mapping| {
-                tag_id_node_id_mapping
-                    .node_id
-                    .into_crate_internal()
-                    .map(|node_id| (node_id, tag_id_node_id_mapping.tag_id))
-            })
-            .for_each(|(nid, tag_id)| {
-                styled_nodes_mut[nid].tag_id = Some(tag_id).into();
-            });
+        new_tag_ids
+            .iter()
+            .filter_map(|tag_id_node_id_mapping| {
+                tag_id_node_id_mapping
+                    .node_id
+                    .into_crate_internal()
+                    .map(|node_id| (node_id, tag_id_node_id_mapping.tag_id))
+            })
+            .for_each(|(nid, tag_id)| {
+                styled_nodes_mut[nid].tag_id = Some(tag_id).into();
+            });
+
+        self.tag_ids_to_node_ids = new_tag_ids.into();
+
+        let _ = self.update_pseudo_element_texts();
+    }
+
+    /// Sets the text of the generated `:before` / `:after` pseudo-elements to the `content`
+    /// of their boxes in the current state, returns the text nodes whose text changed
+    /// (which have to be laid out again)
+    pub fn update_pseudo_element_texts(&mut self) -> BTreeMap<NodeId, AzString> {
+        use crate::dom::NodeType;
+        use azul_css::StyleContent;
+
+        let mut changed_texts = BTreeMap::new();
+
+        let css_property_cache = &*self.css_property_cache.ptr;
+
+        if css_property_cache.pseudo_elements.is_empty() {
+            return changed_texts;
+        }
+
+        let styled_nodes = self.styled_nodes.as_container();
+        let node_hierarchy = self.node_hierarchy.as_container();
+        let mut node_data = self.node_data.as_container_mut();
+
+        // The counters are evaluated in document order: a counter is visible in the element
+        // that creates it, its descendants and its following siblings (and their descendants),
+        // so it goes out of scope as soon as a node is less deep than the element.
+        // Since the boxes are children of their node, a :before box sees the counters of its
+        // node and an :after box also sees the counters of its descendants.
+        //
+        // (name, value, depth of the element that created the counter)
+        let mut counters = Vec::<(AzString, i32, usize)>::new();
+        let mut depths = Vec::<usize>::with_capacity(node_hierarchy.len());
+        let mut texts = Vec::new();
+
+        for node_id in node_hierarchy.linear_iter() {
+            let depth = node_hierarchy[node_id]
+                .parent_id()
+                .map(|p| depths[p.index()] + 1)
+                .unwrap_or(0);
+            depths.push(depth);
+
+            while counters.last().map(|c| c.2 > depth).unwrap_or(false) {
+                counters.pop();
+            }
+
+            let state = &styled_nodes[node_id].state;
+
+            if let Some(reset) = css_property_cache
+                .get_counter_reset(&node_data[node_id], &node_id, state)
+                .and_then(|c| c.get_property())
+            {
+                // resetting a counter of a previous sibling replaces it
+                match counters.iter_mut().rev().find(|c| c.0 == reset.counter) {
+                    Some(c) if c.2 == depth => c.1 = reset.value,
+                    _ => counters.push((reset.counter.clone(), reset.value, depth)),
+                }
+            }
+
+            if let Some(increment) = css_property_cache
+                .get_counter_increment(&node_data[node_id], &node_id, state)
+                .and_then(|c| c.get_property())
+            {
+                // incrementing a counter that is not in scope creates it on the element
+                match counters.iter_mut().rev().find(|c| c.0 == increment.counter) {
+                    Some(c) => c.1 = c.1.wrapping_add(increment.value),
+                    None => counters.push((increment.counter.clone(), increment.value, depth)),
+                }
+            }
+
+            if !css_property_cache.pseudo_elements.contains_key(&node_id) {
+                continue;
+            }
+
+            let content = css_property_cache
+                .get_content(&node_data[node_id], &node_id, state)
+                .and_then(|c| c.get_property());
+
+            let text: AzString = match content {
+                Some(StyleContent::Text(t)) => t.clone(),
+                // counter(name) = value of the innermost counter, 0 if there is none
+                Some(StyleContent::Counter(name)) => {
+                    let value = counters
+                        .iter()
+                        .rev()
+                        .find(|c| c.0 == *name)
+                        .map(|c| c.1)
+                        .unwrap_or(0);
+                    format!("{}", value).into()
+                }
+                _ => AzString::from_const_str(""),
+            };
+
+            texts.push((node_id + 1, text));
+        }
+
+        for (text_id, text) in texts {
+            if let NodeType::Text(current_text) = node_data[text_id].get_node_type() {
+                if *current_text == text {
+                    continue;
+                }
+            }
+
+            node_data[text_id].set_node_type(NodeType::Text(text.clone()));
+            changed_texts.insert(text_id, text);
+        }
+
+        changed_texts
+    }
+
+    /// Returns the nodes together with the boxes and texts of their `:before` / `:after`
+    /// pseudo-elements, which always have the same state as their node
+    fn with_pseudo_elements(&self, nodes: &[NodeId]) -> Vec<NodeId> {
+        let css_property_cache = self.get_css_property_cache();
+        let node_hierarchy = self.node_hierarchy.as_container();
+
+        let mut with_pseudo_elements = nodes.to_vec();
+
+        if css_property_cache.pseudo_elements.is_empty() {
+            return with_pseudo_elements;
+        }
+
+        for node_id in nodes.iter() {
+            let first_child = node_hierarchy[*node_id].first_child_id(*node_id);
+            let last_child = node_hierarchy[*node_id].last_child_id();
+            for child_id in first_child.into_iter().chain(last_child.into_iter()) {
+                let is_pseudo_element_of_node = css_property_cache
+                    .pseudo_elements
+                    .get(&child_id)
+                    .map(|p| p.origin == *node_id)
+                    .unwrap_or(false);
+                if is_pseudo_element_of_node && !with_pseudo_elements.contains(&child_id) {
+                    with_pseudo_elements.push(child_id);
+                    with_pseudo_elements.push(child_id + 1);
+                }
+            }
+        }
+
+        with_pseudo_elements
+    }
+
+    /// Returns the node or, if it is a generated `:before` / `:after` pseudo-element,
+    /// the first node along `next` (i.e. the next sibling) that isn't one
+    pub fn skip_pseudo_elements(
+        &self,
+        node_id: Option<NodeId>,
+        next: impl Fn(&NodeHierarchyItem) -> Option<NodeId>,
+    ) -> Option<NodeId> {
+        let css_property_cache = self.get_css_property_cache();
+        let node_hierarchy = self.node_hierarchy.as_container();
+
+        let mut current = node_id;
+        while let Some(c) = current {
+            if !css_property_cache.is_pseudo_element(c) {
+                return Some(c);
+            }
+            current = next(&node_hierarchy[c]);
+        }
 
-        self.tag_ids_to_node_ids = new_tag_ids.into();
+        None
     }
 
     /// Inserts default On::Scroll and On::Tab handle for scroll-able
-    /// and tabindex-able nodes.
+    /// and tabindex-able nodes, as well as the keyboard scrolling
+    /// (arrow keys, PageUp / PageDown, Home / End) for focusable nodes.
     #[inline]
     pub fn insert_default_system_callbacks(&mut self, config: DefaultCallbacksCfg) {
         use crate::callbacks::Callback;
@@ -2864,6 +4236,46 @@ impl StyledDom {
             }
         }
 
+        let default_node_state = StyledNodeState::default();
+        for focusable_node in self.tag_ids_to_node_ids.iter() {
+            if focusable_node.tab_index.is_none() {
+                continue;
+            }
+
+            let focusable_node_id = match focusable_node.node_id.into_crate_internal() {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let scroll_padding = self
+                .css_property_cache
+                .ptr
+                .get_scroll_padding(
+                    &self.node_data.as_container()[focusable_node_id],
+                    &focusable_node_id,
+                    &default_node_state,
+                )
+                .and_then(|p| p.get_property().copied())
+                .unwrap_or_default();
+
+            let node_data = &mut self.node_data.as_container_mut()[focusable_node_id];
+            if !node_data
+                .callbacks
+                .iter()
+                .any(|cb| cb.event == EventFilter::Focus(FocusEventFilter::VirtualKeyDown))
+            {
+                node_data.callbacks.push(CallbackData {
+                    event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                    data: RefAny::new(DefaultScrollKeyCallbackData {
+                        scroll_padding: scroll_padding.inner,
+                    }),
+                    callback: Callback {
+                        cb: default_on_scroll_key,
+                    },
+                });
+            }
+        }
+
         if !config.enable_autotab {
             return;
         }
@@ -2877,11 +4289,10 @@ impl StyledDom {
                 };
 
                 let mut node_data = &mut self.node_data.as_container_mut()[focusable_node_id];
-                if !node_data
-                    .callbacks
-                    .iter()
-                    .any(|cb| cb.event == EventFilter::Focus(FocusEventFilter::VirtualKeyDown))
-                {
+                if !node_data.callbacks.iter().any(|cb| {
+                    cb.event == EventFilter::Focus(FocusEventFilter::VirtualKeyDown)
+                        && cb.callback.cb as usize != default_on_scroll_key as usize
+                }) {
                     node_data.callbacks.push(CallbackData {
                         event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
                         data: tab_data.clone(),
@@ -3074,6 +4485,9 @@ impl StyledDom {
     ) -> BTreeMap<NodeId, Vec<ChangedCssProperty>> {
         use rayon::prelude::*;
 
+        // the :before / :after pseudo-elements have the same state as their node
+        let nodes = &self.with_pseudo_elements(nodes)[..];
+
         // save the old node state
         let old_node_states = nodes
             .par_iter()
@@ -3184,6 +4598,9 @@ impl StyledDom {
     ) -> BTreeMap<NodeId, Vec<ChangedCssProperty>> {
         use rayon::prelude::*;
 
+        // the :before / :after pseudo-elements have the same state as their node
+        let nodes = &self.with_pseudo_elements(nodes)[..];
+
         // save the old node state
         let old_node_states = nodes
             .par_iter()
@@ -3296,6 +4713,9 @@ impl StyledDom {
     ) -> BTreeMap<NodeId, Vec<ChangedCssProperty>> {
         use rayon::prelude::*;
 
+        // the :before / :after pseudo-elements have the same state as their node
+        let nodes = &self.with_pseudo_elements(nodes)[..];
+
         // save the old node state
         let old_node_states = nodes
             .par_iter()
@@ -3399,6 +4819,292 @@ impl StyledDom {
         v.into_iter().collect()
     }
 
+    /// Sets or clears an application-defined state (`:disabled`, `:checked`, ...)
+    /// of the nodes, returns the properties that changed because of the new state
+    #[cfg(feature = "multithreading")]
+    #[must_use]
+    pub fn restyle_nodes_state_flag(
+        &mut self,
+        nodes: &[NodeId],
+        flag: NodeStateFlag,
+        new_flag_state: bool,
+    ) -> BTreeMap<NodeId, Vec<ChangedCssProperty>> {
+        use rayon::prelude::*;
+
+        // the :before / :after pseudo-elements have the same state as their node
+        let nodes = &self.with_pseudo_elements(nodes)[..];
+
+        // save the old node state
+        let old_node_states = nodes
+            .par_iter()
+            .map(|nid| self.styled_nodes.as_container()[*nid].state.clone())
+            .collect::<Vec<_>>();
+
+        for nid in nodes.iter() {
+            self.styled_nodes.as_container_mut()[*nid]
+                .state
+                .set_flag(flag, new_flag_state);
+        }
+
+        let css_property_cache = self.get_css_property_cache();
+        let styled_nodes = self.styled_nodes.as_container();
+        let node_data = self.node_data.as_container();
+        let (css_props, cascaded_props) = css_property_cache.get_state_flag_props(flag);
+
+        let default_map = BTreeMap::default();
+
+        // scan all properties that could have changed because of addition / removal
+        let v = nodes
+            .par_iter()
+            .zip(old_node_states.par_iter())
+            .filter_map(|(node_id, old_node_state)| {
+                let node_properties_that_could_have_changed = css_props
+                    .get(node_id)
+                    .unwrap_or(&default_map)
+                    .keys()
+                    .chain(cascaded_props.get(node_id).unwrap_or(&default_map).keys())
+                    .collect::<Vec<_>>();
+
+                if node_properties_that_could_have_changed.is_empty() {
+                    return None;
+                }
+
+                let new_node_state = &styled_nodes[*node_id].state;
+                let node_data = &node_data[*node_id];
+
+                let changes = node_properties_that_could_have_changed
+                    .into_iter()
+                    .filter_map(|prop| {
+                        // calculate both the old and the new state
+                        let old = css_property_cache.get_property(
+                            node_data,
+                            node_id,
+                            old_node_state,
+                            prop,
+                        );
+                        let new = css_property_cache.get_property(
+                            node_data,
+                            node_id,
+                            new_node_state,
+                            prop,
+                        );
+                        if old == new {
+                            None
+                        } else {
+                            Some(ChangedCssProperty {
+                                previous_state: old_node_state.clone(),
+                                previous_prop: match old {
+                                    None => CssProperty::auto(*prop),
+                                    Some(s) => s.clone(),
+                                },
+                                current_state: new_node_state.clone(),
+                                current_prop: match new {
+                                    None => CssProperty::auto(*prop),
+                                    Some(s) => s.clone(),
+                                },
+                            })
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                if changes.is_empty() {
+                    None
+                } else {
+                    Some((*node_id, changes))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        v.into_iter().collect()
+    }
+
+    /// Replaces the ids and classes of a node and restyles only the node and its
+    /// descendants, returns the properties that changed in the current node states
+    #[must_use]
+    pub fn restyle_ids_and_classes(
+        &mut self,
+        node_id: &NodeId,
+        ids_and_classes: IdOrClassVec,
+    ) -> BTreeMap<NodeId, Vec<ChangedCssProperty>> {
+        use alloc::collections::btree_set::BTreeSet;
+
+        let mut map = BTreeMap::default();
+
+        if self.node_data.as_container()[*node_id].ids_and_classes == ids_and_classes {
+            return map;
+        }
+
+        let subtree = node_id.index()..get_subtree_end(*node_id, &self.node_hierarchy.as_container());
+
+        // copy of the CSS properties of the subtree before restyling
+        let old_cache = {
+            let css_property_cache = self.get_css_property_cache();
+            let mut old_cache = CssPropertyCache::empty(css_property_cache.node_count);
+            macro_rules! copy_subtree {($field_name:ident) => {
+                old_cache.$field_name = css_property_cache
+                    .$field_name
+                    .range(NodeId::new(subtree.start)..NodeId::new(subtree.end))
+                    .map(|(k, v)| (*k, v.clone()))
+                    .collect();
+            };}
+            copy_subtree!(user_overridden_properties);
+            copy_subtree!(cascaded_normal_props);
+            copy_subtree!(cascaded_hover_props);
+            copy_subtree!(cascaded_active_props);
+            copy_subtree!(cascaded_focus_props);
+            copy_subtree!(cascaded_disabled_props);
+            copy_subtree!(cascaded_checked_props);
+            copy_subtree!(cascaded_selected_props);
+            copy_subtree!(cascaded_indeterminate_props);
+            copy_subtree!(css_normal_props);
+            copy_subtree!(css_hover_props);
+            copy_subtree!(css_active_props);
+            copy_subtree!(css_focus_props);
+            copy_subtree!(css_disabled_props);
+            copy_subtree!(css_checked_props);
+            copy_subtree!(css_selected_props);
+            copy_subtree!(css_indeterminate_props);
+            old_cache
+        };
+
+        self.node_data.as_container_mut()[*node_id].set_ids_and_classes(ids_and_classes);
+
+        let node_data = self.node_data.as_container();
+        self.css_property_cache.downcast_mut().restyle_subtree(
+            *node_id,
+            &node_data,
+            &self.node_hierarchy,
+            &self.non_leaf_nodes,
+            &self.cascade_info.as_container(),
+        );
+
+        let css_property_cache = self.get_css_property_cache();
+        let styled_nodes = self.styled_nodes.as_container();
+
+        for n in subtree.clone() {
+            let n = NodeId::new(n);
+            let node_state = &styled_nodes[n].state;
+
+            let mut property_types = BTreeSet::new();
+            for cache in [&old_cache, css_property_cache] {
+                for props in [
+                    &cache.css_normal_props,
+                    &cache.css_hover_props,
+                    &cache.css_active_props,
+                    &cache.css_focus_props,
+                    &cache.css_disabled_props,
+                    &cache.css_checked_props,
+                    &cache.css_selected_props,
+                    &cache.css_indeterminate_props,
+                    &cache.cascaded_normal_props,
+                    &cache.cascaded_hover_props,
+                    &cache.cascaded_active_props,
+                    &cache.cascaded_focus_props,
+                    &cache.cascaded_disabled_props,
+                    &cache.cascaded_checked_props,
+                    &cache.cascaded_selected_props,
+                    &cache.cascaded_indeterminate_props,
+                ] {
+                    if let Some(p) = props.get(&n) {
+                        property_types.extend(p.keys().copied());
+                    }
+                }
+            }
+
+            let changes = property_types
+                .iter()
+                .filter_map(|prop| {
+                    let old = old_cache.get_property(&node_data[n], &n, node_state, prop);
+                    let new = css_property_cache.get_property(&node_data[n], &n, node_state, prop);
+                    if old == new {
+                        None
+                    } else {
+                        Some(ChangedCssProperty {
+                            previous_state: node_state.clone(),
+                            previous_prop: match old {
+                                None => CssProperty::auto(*prop),
+                                Some(s) => s.clone(),
+                            },
+                            current_state: node_state.clone(),
+                            current_prop: match new {
+                                None => CssProperty::auto(*prop),
+                                Some(s) => s.clone(),
+                            },
+                        })
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            if !changes.is_empty() {
+                map.insert(n, changes);
+            }
+        }
+
+        self.update_tag_ids(subtree);
+
+        map
+    }
+
+    /// Restyles the nodes whose stylesheet uses system colors or `@media` blocks
+    /// after the system settings changed (i.e. the user enabled a high contrast theme)
+    pub fn set_system_style(&mut self, system_style: &SystemStyle) {
+        let restyled = self.css_property_cache.downcast_mut().set_system_style(
+            system_style,
+            &self.node_data.as_container(),
+            &self.node_hierarchy,
+            &self.non_leaf_nodes,
+            &self.cascade_info.as_container(),
+        );
+
+        if !restyled {
+            return;
+        }
+
+        self.update_tag_ids(0..self.node_data.len());
+        let _ = self.update_pseudo_element_texts();
+    }
+
+    /// Regenerates the tag IDs of the restyled nodes: a node may need a tag ID for hit-testing
+    /// now (i.e. for a new :hover rule) or not need one anymore - existing tag IDs are kept
+    /// to not lose the hover state
+    fn update_tag_ids(&mut self, nodes: core::ops::Range<usize>) {
+        let css_property_cache = self.get_css_property_cache();
+        let node_data = self.node_data.as_container();
+
+        let new_tags = nodes
+            .clone()
+            .map(|n| {
+                let n = NodeId::new(n);
+                let mapping = css_property_cache.get_tag_id_mapping(n, &node_data[n], &self.node_hierarchy);
+                (n, mapping)
+            })
+            .collect::<Vec<_>>();
+
+        let mut tag_ids_to_node_ids = self.tag_ids_to_node_ids.clone().into_library_owned_vec();
+        tag_ids_to_node_ids.retain(|m| match m.node_id.into_crate_internal() {
+            Some(n) => !nodes.contains(&n.index()),
+            None => true,
+        });
+
+        let mut styled_nodes_mut = self.styled_nodes.as_container_mut();
+        for (n, mapping) in new_tags {
+            match mapping {
+                Some(mut mapping) => {
+                    if let Some(existing) = styled_nodes_mut[n].tag_id.into_option() {
+                        mapping.tag_id = existing;
+                    }
+                    styled_nodes_mut[n].tag_id = Some(mapping.tag_id).into();
+                    tag_ids_to_node_ids.push(mapping);
+                }
+                None => {
+                    styled_nodes_mut[n].tag_id = None.into();
+                }
+            }
+        }
+
+        self.tag_ids_to_node_ids = tag_ids_to_node_ids.into();
+    }
+
     // Inserts a property into the self.user_overridden_properties
     #[cfg(feature = "multithreading")]
     #[must_use]
@@ -3673,6 +5379,72 @@ impl StyledDom {
         }
     }
 
+    /// Returns the first node (in document order) that matches the CSS selector,
+    /// i.e. `"div.sidebar > .item"`
+    #[cfg(feature = "css_parser")]
+    pub fn query_selector<'a>(
+        &self,
+        selector: &'a str,
+    ) -> Result<Option<NodeId>, azul_css_parser::CssPathParseError<'a>> {
+        let css_path = azul_css_parser::parse_css_path(selector)?;
+        Ok(self.query_selector_path(&css_path))
+    }
+
+    /// Returns all nodes (in document order) that match the CSS selector,
+    /// i.e. `"div.sidebar > .item"`
+    #[cfg(feature = "css_parser")]
+    pub fn query_selector_all<'a>(
+        &self,
+        selector: &'a str,
+    ) -> Result<Vec<NodeId>, azul_css_parser::CssPathParseError<'a>> {
+        let css_path = azul_css_parser::parse_css_path(selector)?;
+        Ok(self.query_selector_all_path(&css_path))
+    }
+
+    /// Same as `query_selector`, but with an already parsed `CssPath`
+    pub fn query_selector_path(&self, css_path: &CssPath) -> Option<NodeId> {
+        self.node_data
+            .as_container()
+            .linear_iter()
+            .find(|node_id| self.node_matches_css_path(css_path, *node_id))
+    }
+
+    /// Same as `query_selector_all`, but with an already parsed `CssPath`
+    pub fn query_selector_all_path(&self, css_path: &CssPath) -> Vec<NodeId> {
+        self.node_data
+            .as_container()
+            .linear_iter()
+            .filter(|node_id| self.node_matches_css_path(css_path, *node_id))
+            .collect()
+    }
+
+    fn node_matches_css_path(&self, css_path: &CssPath, node_id: NodeId) -> bool {
+        !self.get_css_property_cache().is_pseudo_element(node_id)
+            && matches_html_element_exact(
+                css_path,
+                node_id,
+                &self.node_hierarchy.as_container(),
+                &self.node_data.as_container(),
+                &self.cascade_info.as_container(),
+                None,
+            )
+    }
+
+    /// Returns the innermost overlay node (see `Dom::overlay`) that
+    /// contains `node_id`, or `node_id` itself if it is an overlay
+    pub fn get_overlay_root(&self, node_id: NodeId) -> Option<NodeId> {
+        let node_data = self.node_data.as_container();
+        let node_hierarchy = self.node_hierarchy.as_container();
+        let mut current = Some(node_id);
+        while let Some(c) = current {
+            if node_data[c].is_overlay() {
+                return Some(c);
+            }
+            current = node_hierarchy[c].parent_id();
+        }
+        None
+    }
+
     #[cfg(feature = "multithreading")]
     pub fn get_rects_in_rendering_order(&self) -> ContentGroup {
         Self::determine_rendering_order(
@@ -3712,9 +5484,30 @@ impl StyledDom {
             })
             .collect::<Vec<_>>();
 
-        let children_sorted: BTreeMap<NodeHierarchyItemId, Vec<NodeHierarchyItemId>> =
+        let mut children_sorted: BTreeMap<NodeHierarchyItemId, Vec<NodeHierarchyItemId>> =
             children_sorted.into_iter().collect();
 
+        // Overlays are rendered last, as direct children of the root node,
+        // so that they are drawn (and hit-tested) above all other content
+        let overlays = node_data_container
+            .internal
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, node_data)| node_data.is_overlay())
+            .map(|(node_id, _)| NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(node_id))))
+            .collect::<Vec<_>>();
+
+        if !overlays.is_empty() {
+            for children in children_sorted.values_mut() {
+                children.retain(|c| !overlays.contains(c));
+            }
+            children_sorted
+                .entry(NodeHierarchyItemId::from_crate_internal(Some(NodeId::ZERO)))
+                .or_insert_with(Vec::new)
+                .extend(overlays);
+        }
+
         let mut root_content_group = ContentGroup {
             root: NodeHierarchyItemId::from_crate_internal(Some(NodeId::ZERO)),
             children: Vec::new().into(),
@@ -3779,6 +5572,15 @@ pub struct DefaultScrollCallbackData {
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct DefaultTabIndexCallbackData {}
 
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DefaultScrollKeyCallbackData {
+    /// `scroll-padding` of the node, subtracted from the page size
+    pub scroll_padding: PixelValue,
+}
+
+/// Distance in pixels that a single arrow key press scrolls
+const SCROLL_KEY_LINE_STEP: f32 = 40.0;
+
 /// Default On::TabIndex event handler
 extern "C" fn default_on_tabindex(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
     let mut data = match data.downcast_mut::<DefaultTabIndexCallbackData>() {
@@ -3822,6 +5624,60 @@ extern "C" fn default_on_scroll(data: &mut RefAny, info: &mut CallbackInfo) -> U
     Update::DoNothing
 }
 
+/// Default keyboard scrolling for a focused scroll container
+extern "C" fn default_on_scroll_key(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+    use crate::window::VirtualKeyCode;
+
+    let data = match data.downcast_ref::<DefaultScrollKeyCallbackData>() {
+        Some(s) => *s,
+        None => return Update::DoNothing,
+    };
+
+    let keycode = match info
+        .get_current_keyboard_state()
+        .current_virtual_keycode
+        .into_option()
+    {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    let focused_node_id = info.get_hit_node();
+
+    // None if the node does not overflow, i.e. there is nothing to scroll
+    let scroll_info = match info.get_scroll_info(focused_node_id) {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    let viewport_height = scroll_info.viewport.size.height;
+    let scroll_padding = data.scroll_padding.to_pixels(viewport_height);
+    // always scroll at least one line, even if the padding covers the entire viewport
+    let page_step = (viewport_height - 2.0 * scroll_padding).max(SCROLL_KEY_LINE_STEP);
+
+    let mut new_position = scroll_info.scroll_position;
+    match keycode {
+        VirtualKeyCode::Up => new_position.y -= SCROLL_KEY_LINE_STEP,
+        VirtualKeyCode::Down => new_position.y += SCROLL_KEY_LINE_STEP,
+        VirtualKeyCode::Left => new_position.x -= SCROLL_KEY_LINE_STEP,
+        VirtualKeyCode::Right => new_position.x += SCROLL_KEY_LINE_STEP,
+        VirtualKeyCode::PageUp => new_position.y -= page_step,
+        VirtualKeyCode::PageDown => new_position.y += page_step,
+        VirtualKeyCode::Home => new_position.y = 0.0,
+        VirtualKeyCode::End => new_position.y = scroll_info.max_scroll.height,
+        _ => return Update::DoNothing,
+    }
+
+    new_position.x = new_position.x.max(0.0).min(scroll_info.max_scroll.width);
+    new_position.y = new_position.y.max(0.0).min(scroll_info.max_scroll.height);
+
+    if new_position != scroll_info.scroll_position {
+        info.set_scroll_position(focused_node_id, new_position);
+    }
+
+    Update::DoNothing
+}
+
 fn fill_content_group_children(
     group: &mut ContentGroup,
     children_sorted: &BTreeMap<NodeHierarchyItemId, Vec<NodeHierarchyItemId>>,
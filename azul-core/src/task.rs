@@ -21,7 +21,7 @@ use std::sync::Mutex;
 
 use crate::gl::OptionGlContextPtr;
 use crate::{
-    app_resources::{ImageCache, ImageMask, ImageRef},
+    app_resources::{AppLogLevel, ImageCache, ImageMask, ImageRef},
     callbacks::{
         CallbackInfo, DomNodeId, FocusTarget, OptionDomNodeId, RefAny, ScrollPosition,
         ThreadCallback, TimerCallback, TimerCallbackInfo, TimerCallbackReturn, TimerCallbackType,
@@ -31,12 +31,12 @@ use crate::{
     styled_dom::{DomId, NodeHierarchyItemId},
     ui_solver::LayoutResult,
     window::{
-        FullWindowState, LogicalPosition, OptionLogicalPosition, RawWindowHandle,
-        WindowCreateOptions, WindowState,
+        FullWindowState, KeyboardModifiers, LogicalPosition, OptionChar, OptionLogicalPosition,
+        RawWindowHandle, VirtualKeyCode, WindowCreateOptions, WindowState,
     },
     FastBTreeSet, FastHashMap,
 };
-use azul_css::{AzString, CssProperty};
+use azul_css::{AzString, CssProperty, StringVec};
 use rust_fontconfig::FcFontCache;
 
 /// Should a timer terminate or not - used to remove active timers
@@ -675,17 +675,29 @@ impl Timer {
         finish
     }
 
-    /// Returns when the timer needs to run again
+    /// Returns when the timer needs to run again (same calculation as in `invoke()`:
+    /// the delay only applies to the first run of the timer). Timers without an
+    /// interval run again after `tick_millis()`.
     pub fn instant_of_next_run(&self) -> Instant {
         let last_run = match self.last_run.as_ref() {
-            Some(s) => s,
-            None => &self.created,
+            Some(s) => s.clone(),
+            None => return self
+                .created
+                .add_optional_duration(self.delay.as_ref())
+                .add_optional_duration(self.interval.as_ref()),
+        };
+
+        let interval = match (self.interval, &last_run) {
+            (OptionDuration::Some(interval), _) => interval,
+            (OptionDuration::None, Instant::System(_)) => {
+                Duration::System(SystemTimeDiff::from_millis(self.tick_millis()))
+            }
+            (OptionDuration::None, Instant::Tick(_)) => Duration::Tick(SystemTickDiff {
+                tick_diff: self.tick_millis(),
+            }),
         };
 
-        last_run
-            .clone()
-            .add_optional_duration(self.delay.as_ref())
-            .add_optional_duration(self.interval.as_ref())
+        last_run.add_optional_duration(Some(&interval))
     }
 
     /// Delays the timer to not start immediately but rather
@@ -763,6 +775,33 @@ impl Timer {
     }
 }
 
+/// Callback that runs once, right before the next frame of the window
+/// is rendered, see `CallbackInfo::request_animation_frame()`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnimationFrameCallback {
+    /// Data that is passed to the callback
+    pub data: RefAny,
+    /// Callback to be called before the next frame
+    pub callback: TimerCallback,
+}
+
+impl AnimationFrameCallback {
+    /// Invokes the callback - `frame_start` is the same for all callbacks of
+    /// one frame, so that animations can use it as a steady time base
+    pub fn invoke(&mut self, callback_info: CallbackInfo, frame_start: Instant) -> Update {
+        let mut timer_callback_info = TimerCallbackInfo {
+            callback_info,
+            node_id: OptionDomNodeId::None,
+            frame_start,
+            call_count: 0,
+            is_about_to_finish: false,
+            _abi_ref: core::ptr::null(),
+            _abi_mut: core::ptr::null_mut(),
+        };
+        (self.callback.cb)(&mut self.data, &mut timer_callback_info).should_update
+    }
+}
+
 /// Message that can be sent from the main thread to the Thread using the ThreadId.
 ///
 /// The thread can ignore the event.
@@ -903,6 +942,140 @@ impl ThreadReceiver {
     }
 }
 
+/// Handle to wake up the (blocking) event loop from other threads, i.e. when a
+/// background thread received data from the network. Created with
+/// `App::create_event_loop_proxy()`, can be cloned and sent to other threads.
+///
+/// NOTE: user events are currently only delivered on Windows. The X11 event loop
+/// can't invoke callbacks (or run timers) yet, so it rejects user events and
+/// `send_user_event()` returns `false` instead of queueing data that would never
+/// arrive. `wake()` works on both platforms.
+#[derive(Debug)]
+#[repr(C)]
+pub struct EventLoopProxy {
+    #[cfg(feature = "std")]
+    pub ptr: Box<Arc<Mutex<EventLoopProxyInner>>>,
+    #[cfg(not(feature = "std"))]
+    pub ptr: *const c_void,
+    pub run_destructor: bool,
+}
+
+impl Clone for EventLoopProxy {
+    fn clone(&self) -> Self {
+        Self {
+            ptr: self.ptr.clone(),
+            run_destructor: true,
+        }
+    }
+}
+
+impl Drop for EventLoopProxy {
+    fn drop(&mut self) {
+        self.run_destructor = false;
+    }
+}
+
+impl EventLoopProxy {
+    pub fn new() -> Self {
+        Self {
+            ptr: Box::new(Arc::new(Mutex::new(EventLoopProxyInner::default()))),
+            run_destructor: true,
+        }
+    }
+
+    /// Wakes up the event loop (to process finished threads / timers).
+    /// Returns `false` if the event loop is not running yet - in that
+    /// case the event loop will wake up immediately once it starts.
+    pub fn wake(&self) -> bool {
+        let mut inner = match self.ptr.lock().ok() {
+            Some(s) => s,
+            None => return false,
+        };
+        match inner.waker.as_ref() {
+            Some(waker) => {
+                (waker)();
+                true
+            }
+            None => {
+                inner.wake_requested = true;
+                false
+            }
+        }
+    }
+
+    /// Queues the `data` and wakes up the event loop, which then invokes all
+    /// `On::UserEvent` callbacks (see `CallbackInfo::get_user_event()`).
+    /// Returns `false` and drops the `data` if the event loop can't deliver
+    /// user events (currently on X11).
+    pub fn send_user_event(&self, data: RefAny) -> bool {
+        match self.ptr.lock().ok() {
+            Some(s) if s.rejects_user_events => return false,
+            Some(mut s) => s.user_events.push(data),
+            None => return false,
+        }
+        self.wake()
+    }
+
+    /// Called by event loops that can't invoke `On::UserEvent` callbacks:
+    /// drops the already queued user events and rejects all further ones
+    pub fn reject_user_events(&self) {
+        let mut inner = match self.ptr.lock().ok() {
+            Some(s) => s,
+            None => return,
+        };
+        inner.rejects_user_events = true;
+        inner.user_events.clear();
+    }
+
+    /// Called by the event loop, sets the platform-specific function to wake
+    /// up the event loop and replays wakeups that happened before
+    pub fn set_waker(&self, waker: Box<dyn Fn() + Send>) {
+        let mut inner = match self.ptr.lock().ok() {
+            Some(s) => s,
+            None => return,
+        };
+        if inner.wake_requested || !inner.user_events.is_empty() {
+            (waker)();
+        }
+        inner.wake_requested = false;
+        inner.waker = Some(waker);
+    }
+
+    /// Called by the event loop, returns the next queued user event
+    pub fn pop_user_event(&self) -> Option<RefAny> {
+        let mut inner = self.ptr.lock().ok()?;
+        if inner.user_events.is_empty() {
+            None
+        } else {
+            Some(inner.user_events.remove(0))
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct EventLoopProxyInner {
+    /// User events that were not yet delivered to the windows
+    pub user_events: Vec<RefAny>,
+    /// Platform-specific function to wake up the event loop (i.e. posting a
+    /// message to the window), set once the event loop is running
+    pub waker: Option<Box<dyn Fn() + Send>>,
+    /// Whether `wake()` was called before the event loop was running
+    pub wake_requested: bool,
+    /// Set by event loops that can't deliver user events, see `reject_user_events()`
+    pub rejects_user_events: bool,
+}
+
+impl fmt::Debug for EventLoopProxyInner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EventLoopProxyInner")
+            .field("user_events", &self.user_events.len())
+            .field("has_waker", &self.waker.is_some())
+            .field("wake_requested", &self.wake_requested)
+            .field("rejects_user_events", &self.rejects_user_events)
+            .finish()
+    }
+}
+
 #[derive(Debug)]
 #[cfg_attr(not(feature = "std"), derive(PartialEq, PartialOrd, Eq, Ord))]
 #[repr(C)]
@@ -1022,6 +1195,10 @@ impl Drop for ThreadReceiverInner {
 pub struct ExternalSystemCallbacks {
     pub create_thread_fn: CreateThreadCallback,
     pub get_system_time_fn: GetSystemTimeCallback,
+    pub get_system_idle_time_fn: GetSystemIdleTimeCallback,
+    pub get_log_lines_fn: GetLogLinesCallback,
+    pub set_log_level_fn: SetLogLevelCallback,
+    pub keycode_to_char_fn: KeycodeToCharCallback,
 }
 
 #[cfg(feature = "std")]
@@ -1034,6 +1211,18 @@ impl ExternalSystemCallbacks {
             get_system_time_fn: GetSystemTimeCallback {
                 cb: get_system_time_libstd,
             },
+            get_system_idle_time_fn: GetSystemIdleTimeCallback {
+                cb: get_system_idle_time_unsupported,
+            },
+            get_log_lines_fn: GetLogLinesCallback {
+                cb: get_log_lines_unsupported,
+            },
+            set_log_level_fn: SetLogLevelCallback {
+                cb: set_log_level_unsupported,
+            },
+            keycode_to_char_fn: KeycodeToCharCallback {
+                cb: keycode_to_char_unsupported,
+            },
         }
     }
 }
@@ -1055,6 +1244,45 @@ pub struct GetSystemTimeCallback {
 }
 impl_callback!(GetSystemTimeCallback);
 
+/// Get the time since the last user input on the entire system (not only the
+/// current application), i.e. how long the user has been idle. Returns `None`
+/// if the platform does not support querying the idle time.
+pub type GetSystemIdleTimeCallbackType = extern "C" fn() -> OptionDuration;
+#[repr(C)]
+pub struct GetSystemIdleTimeCallback {
+    pub cb: GetSystemIdleTimeCallbackType,
+}
+impl_callback!(GetSystemIdleTimeCallback);
+
+/// Returns the most recent lines of the log ring buffer (oldest first),
+/// i.e. for displaying them in an in-app diagnostics panel
+pub type GetLogLinesCallbackType = extern "C" fn() -> StringVec;
+#[repr(C)]
+pub struct GetLogLinesCallback {
+    pub cb: GetLogLinesCallbackType,
+}
+impl_callback!(GetLogLinesCallback);
+
+/// Returns the character that the key produces with the given modifiers in the
+/// current keyboard layout of the operating system (i.e. to display shortcuts in
+/// menus), `None` if the key doesn't produce a character or the platform doesn't
+/// support the query.
+pub type KeycodeToCharCallbackType = extern "C" fn(VirtualKeyCode, KeyboardModifiers) -> OptionChar;
+#[repr(C)]
+pub struct KeycodeToCharCallback {
+    pub cb: KeycodeToCharCallbackType,
+}
+impl_callback!(KeycodeToCharCallback);
+
+/// Sets the log level of a subsystem (i.e. `"azul::layout"`) at runtime,
+/// an empty subsystem string sets the global log level
+pub type SetLogLevelCallbackType = extern "C" fn(AzString, AppLogLevel);
+#[repr(C)]
+pub struct SetLogLevelCallback {
+    pub cb: SetLogLevelCallbackType,
+}
+impl_callback!(SetLogLevelCallback);
+
 // function called to check if the thread has finished
 pub type CheckThreadFinishedCallbackType =
     extern "C" fn(/* dropcheck */ *const c_void) -> bool;
@@ -1218,6 +1446,30 @@ pub extern "C" fn get_system_time_libstd() -> Instant {
     StdInstant::now().into()
 }
 
+/// Default idle time function: the standard library has no way to query
+/// the system-wide idle time, so the windowing backend has to override this
+pub extern "C" fn get_system_idle_time_unsupported() -> OptionDuration {
+    OptionDuration::None
+}
+
+/// Default log buffer function: logging is implemented by the
+/// windowing backend, without it there are no log lines to return
+pub extern "C" fn get_log_lines_unsupported() -> StringVec {
+    StringVec::from_vec(Vec::new())
+}
+
+/// Default log level function, does nothing
+pub extern "C" fn set_log_level_unsupported(_subsystem: AzString, _level: AppLogLevel) {}
+
+/// Default keyboard layout function: the layout is only known
+/// to the windowing backend, so no key can be mapped
+pub extern "C" fn keycode_to_char_unsupported(
+    _virtual_keycode: VirtualKeyCode,
+    _modifiers: KeyboardModifiers,
+) -> OptionChar {
+    OptionChar::None
+}
+
 #[cfg(feature = "std")]
 pub extern "C" fn create_thread_libstd(
     thread_initialize_data: RefAny,
@@ -1339,3 +1591,18 @@ extern "C" fn thread_sender_drop(_: *mut ThreadSenderInner) {}
 
 #[cfg(feature = "std")]
 extern "C" fn thread_receiver_drop(_: *mut ThreadReceiverInner) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejected_user_events_are_not_queued() {
+        let proxy = EventLoopProxy::new();
+        proxy.send_user_event(RefAny::new(1_usize));
+        proxy.reject_user_events();
+        assert!(proxy.pop_user_event().is_none());
+        assert!(!proxy.send_user_event(RefAny::new(2_usize)));
+        assert!(proxy.pop_user_event().is_none());
+    }
+}
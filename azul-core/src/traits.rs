@@ -1,4 +1,5 @@
 use crate::{
+    app_resources::{WordPositions, WordsChange},
     callbacks::DocumentId,
     id_tree::NodeId,
     ui_solver::{InlineTextLayout, ResolvedTextLayoutOptions},
@@ -12,4 +13,18 @@ pub trait GetTextLayout {
         node_id: NodeId,
         text_layout_options: &ResolvedTextLayoutOptions,
     ) -> InlineTextLayout;
+
+    /// Lays out the text again after it was edited, `previous` is the layout of the old
+    /// text. Implementations only have to position the words of the edited paragraphs and
+    /// can shift the lines after them. The default implementation lays out the entire text.
+    fn get_text_layout_incremental(
+        &mut self,
+        document_id: &DocumentId,
+        node_id: NodeId,
+        text_layout_options: &ResolvedTextLayoutOptions,
+        _previous: &WordPositions,
+        _change: &WordsChange,
+    ) -> InlineTextLayout {
+        self.get_text_layout(document_id, node_id, text_layout_options)
+    }
 }
@@ -5,7 +5,7 @@ use crate::{
     },
     callbacks::{
         DocumentId, HidpiAdjustedBounds, HitTestItem, IFrameCallbackInfo, IFrameCallbackReturn,
-        PipelineId, ScrollHitTestItem,
+        PipelineId, ScrollHitTestItem, TextUnderlineVec,
     },
     display_list::{CachedDisplayList, RenderCallbacks},
     dom::{DomNodeHash, ScrollTagId, TagId},
@@ -14,7 +14,7 @@ use crate::{
     styled_dom::{DomId, NodeHierarchyItemId, StyledDom},
     window::{
         FullWindowState, LogicalPosition, LogicalRect, LogicalRectVec, LogicalSize, ScrollStates,
-        WindowSize, WindowTheme,
+        ScrollbarInteraction, ScrollbarMode, WindowSize, WindowTheme,
     },
     window_state::RelayoutFn,
 };
@@ -28,7 +28,8 @@ use azul_css::{
     LayoutMarginRight, LayoutMarginTop, LayoutOverflow, LayoutPaddingBottom, LayoutPaddingLeft,
     LayoutPaddingRight, LayoutPaddingTop, LayoutPoint, LayoutPosition, LayoutRect, LayoutRectVec,
     LayoutRight, LayoutSize, LayoutTop, OptionF32, PixelValue, StyleBoxShadow, StyleFontSize,
-    StyleTextAlign, StyleTextColor, StyleTransform, StyleTransformOrigin, StyleVerticalAlign,
+    StyleScrollbarColor, StyleScrollbarWidth, StyleTextAlign, StyleTextColor, StyleTransform,
+    StyleTransformOrigin, StyleVerticalAlign, SystemStyle,
 };
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::__m256;
@@ -243,6 +244,140 @@ impl Default for OverflowingScrollNode {
     }
 }
 
+/// Default minimum length of a scrollbar thumb, so that it stays
+/// draggable even if the content is very long, see `ScrollbarConfig::min_thumb_length`
+pub const MIN_SCROLLBAR_THUMB_LENGTH: f32 = 20.0;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScrollbarOrientation {
+    Horizontal,
+    Vertical,
+}
+
+/// Track and thumb of a scrollbar, relative to the top left corner of the scroll frame
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct ScrollbarRects {
+    pub orientation: ScrollbarOrientation,
+    pub track: LogicalRect,
+    pub thumb: LogicalRect,
+    /// How many pixels the content scrolls when the thumb is dragged by one pixel
+    pub scroll_per_thumb_px: f32,
+    /// Maximum scroll offset in the direction of the scrollbar
+    pub max_scroll: f32,
+}
+
+impl OverflowingScrollNode {
+    /// Returns the horizontal and vertical scrollbar of this node, given the current
+    /// scroll position, or `None` if the content doesn't overflow in that direction
+    pub fn get_scrollbar_rects(
+        &self,
+        scroll_position: LogicalPosition,
+        thickness: f32,
+        min_thumb_length: f32,
+    ) -> (Option<ScrollbarRects>, Option<ScrollbarRects>) {
+        let viewport = self.parent_rect.size;
+        let content = self.child_rect.size;
+
+        let has_horizontal = content.width > viewport.width;
+        let has_vertical = content.height > viewport.height;
+
+        // leave the bottom right corner free if both scrollbars are visible
+        let corner = if has_horizontal && has_vertical {
+            thickness
+        } else {
+            0.0
+        };
+
+        let horizontal = if has_horizontal {
+            let track = LogicalRect::new(
+                LogicalPosition::new(0.0, viewport.height - thickness),
+                LogicalSize::new((viewport.width - corner).max(0.0), thickness),
+            );
+            let (offset, length, scroll_per_thumb_px, max_scroll) = get_scrollbar_thumb_extent(
+                track.size.width,
+                viewport.width,
+                content.width,
+                scroll_position.x,
+                min_thumb_length,
+            );
+            Some(ScrollbarRects {
+                orientation: ScrollbarOrientation::Horizontal,
+                track,
+                thumb: LogicalRect::new(
+                    LogicalPosition::new(track.origin.x + offset, track.origin.y),
+                    LogicalSize::new(length, thickness),
+                ),
+                scroll_per_thumb_px,
+                max_scroll,
+            })
+        } else {
+            None
+        };
+
+        let vertical = if has_vertical {
+            let track = LogicalRect::new(
+                LogicalPosition::new(viewport.width - thickness, 0.0),
+                LogicalSize::new(thickness, (viewport.height - corner).max(0.0)),
+            );
+            let (offset, length, scroll_per_thumb_px, max_scroll) = get_scrollbar_thumb_extent(
+                track.size.height,
+                viewport.height,
+                content.height,
+                scroll_position.y,
+                min_thumb_length,
+            );
+            Some(ScrollbarRects {
+                orientation: ScrollbarOrientation::Vertical,
+                track,
+                thumb: LogicalRect::new(
+                    LogicalPosition::new(track.origin.x, track.origin.y + offset),
+                    LogicalSize::new(thickness, length),
+                ),
+                scroll_per_thumb_px,
+                max_scroll,
+            })
+        } else {
+            None
+        };
+
+        (horizontal, vertical)
+    }
+}
+
+/// Returns the offset and length of the thumb along the track, how many pixels
+/// the content scrolls per thumb pixel and the maximum scroll offset
+fn get_scrollbar_thumb_extent(
+    track_length: f32,
+    viewport_length: f32,
+    content_length: f32,
+    scroll_offset: f32,
+    min_thumb_length: f32,
+) -> (f32, f32, f32, f32) {
+    let max_scroll = (content_length - viewport_length).max(0.0);
+    let thumb_length = (track_length * viewport_length / content_length)
+        .max(min_thumb_length)
+        .min(track_length);
+    let free_length = track_length - thumb_length;
+
+    if max_scroll <= 0.0 || free_length <= 0.0 {
+        return (0.0, thumb_length, 0.0, max_scroll);
+    }
+
+    let offset = free_length * (scroll_offset / max_scroll).max(0.0).min(1.0);
+    (offset, thumb_length, max_scroll / free_length, max_scroll)
+}
+
+/// Colors and thickness of the scrollbars of a scroll frame, resolved from the
+/// `scrollbar-width`, `scrollbar-color` and `-azul-scrollbar-thumb-*-color` properties
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct ResolvedScrollbarStyle {
+    pub thickness: f32,
+    pub track: StyleColorU,
+    pub thumb: StyleColorU,
+    pub thumb_hover: StyleColorU,
+    pub thumb_active: StyleColorU,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum WhConstraint {
     /// between min, max
@@ -621,7 +756,12 @@ pub struct LayoutResult {
     pub positioned_words_cache: BTreeMap<NodeId, (WordPositions, FontInstanceKey)>,
     pub scrollable_nodes: ScrolledNodes,
     pub iframe_mapping: BTreeMap<NodeId, DomId>,
+    /// Rect of each iframe node when its callback was last invoked,
+    /// used to decide whether a relayout needs to re-invoke the callback
+    pub iframe_invoked_rects: BTreeMap<NodeId, LogicalRect>,
     pub gpu_value_cache: GpuValueCache,
+    /// Underlines of text nodes, set by `CallbackInfo::set_text_underlines`
+    pub text_underlines: BTreeMap<NodeId, TextUnderlineVec>,
 }
 
 impl fmt::Debug for LayoutResult {
@@ -690,6 +830,71 @@ impl LayoutResult {
         LayoutRect::new(self.root_position, self.root_size)
     }
 
+    /// Returns the scrollbar style of the given node or `None` if the node
+    /// has `scrollbar-width: none` set, `ScrollbarMode::Overlay` turns
+    /// all other scrollbars into thin overlay scrollbars
+    pub fn get_scrollbar_style(&self, node_id: NodeId, mode: ScrollbarMode) -> Option<ResolvedScrollbarStyle> {
+        let css_property_cache = self.styled_dom.get_css_property_cache();
+        let node_data = &self.styled_dom.node_data.as_container()[node_id];
+        let node_state = &self.styled_dom.styled_nodes.as_container()[node_id].state;
+
+        let scrollbar_width = match css_property_cache.get_scrollbar_width(node_data, &node_id, node_state) {
+            Some(s) => s.clone().get_property_or_default()?,
+            None => StyleScrollbarWidth::default(),
+        };
+        let scrollbar_width = match (mode, scrollbar_width) {
+            (ScrollbarMode::Overlay, StyleScrollbarWidth::Auto) => StyleScrollbarWidth::Thin,
+            (_, w) => w,
+        };
+        let thickness = scrollbar_width.get_thickness()?;
+
+        let (default_colors, default_hover, default_active) = if scrollbar_width.is_overlay() {
+            (
+                StyleScrollbarColor {
+                    thumb: StyleColorU { r: 0, g: 0, b: 0, a: 100 },
+                    track: StyleColorU { r: 0, g: 0, b: 0, a: 0 },
+                },
+                StyleColorU { r: 0, g: 0, b: 0, a: 150 },
+                StyleColorU { r: 0, g: 0, b: 0, a: 200 },
+            )
+        } else {
+            (
+                StyleScrollbarColor::default(),
+                StyleColorU { r: 168, g: 168, b: 168, a: 255 },
+                StyleColorU { r: 120, g: 120, b: 120, a: 255 },
+            )
+        };
+
+        let custom_colors = css_property_cache
+            .get_scrollbar_color(node_data, &node_id, node_state)
+            .and_then(|p| p.get_property())
+            .copied();
+
+        let colors = custom_colors.unwrap_or(default_colors);
+
+        // if the thumb color is overridden, hover and active states
+        // default to the custom thumb color instead of the built-in grays
+        let thumb_hover = css_property_cache
+            .get_scrollbar_thumb_hover_color(node_data, &node_id, node_state)
+            .and_then(|p| p.get_property())
+            .map(|c| c.inner)
+            .unwrap_or(if custom_colors.is_some() { colors.thumb } else { default_hover });
+
+        let thumb_active = css_property_cache
+            .get_scrollbar_thumb_active_color(node_data, &node_id, node_state)
+            .and_then(|p| p.get_property())
+            .map(|c| c.inner)
+            .unwrap_or(if custom_colors.is_some() { thumb_hover } else { default_active });
+
+        Some(ResolvedScrollbarStyle {
+            thickness,
+            track: colors.track,
+            thumb: colors.thumb,
+            thumb_hover,
+            thumb_active,
+        })
+    }
+
     #[cfg(feature = "multithreading")]
     pub fn get_cached_display_list(
         document_id: &DocumentId,
@@ -700,10 +905,14 @@ impl LayoutResult {
         gl_texture_cache: &GlTextureCache,
         renderer_resources: &RendererResources,
         image_cache: &ImageCache,
+        scroll_states: &ScrollStates,
+        scrollbar_interaction: &ScrollbarInteraction,
     ) -> CachedDisplayList {
         use crate::display_list::{
-            displaylist_handle_rect, push_rectangles_into_displaylist, DisplayListFrame,
-            DisplayListMsg, DisplayListParametersRef, LayoutRectContent, RectBackground,
+            cull_offscreen_content_groups, displaylist_handle_rect,
+            push_rectangles_into_displaylist, DisplayListFrame, DisplayListMsg,
+            DisplayListParametersRef, LayoutRectContent, RectBackground,
+            DISPLAY_LIST_CULL_MARGIN,
         };
         use rayon::prelude::*;
 
@@ -712,7 +921,33 @@ impl LayoutResult {
             None => return CachedDisplayList::empty(),
         };
 
-        let rects_in_rendering_order = layout_result.styled_dom.get_rects_in_rendering_order();
+        let mut rects_in_rendering_order = layout_result.styled_dom.get_rects_in_rendering_order();
+
+        // skip items that are scrolled far out of the window (or iframe) - the
+        // rects of iframe DOMs are relative to the iframe, the root size of an
+        // iframe DOM is the size of the iframe
+        let viewport_size = if dom_id == DomId::ROOT_ID {
+            full_window_state.size.get_logical_size()
+        } else {
+            LogicalSize::new(
+                layout_result.root_size.width as f32,
+                layout_result.root_size.height as f32,
+            )
+        };
+        let cull_rect = LogicalRect::new(
+            LogicalPosition::new(-DISPLAY_LIST_CULL_MARGIN, -DISPLAY_LIST_CULL_MARGIN),
+            LogicalSize::new(
+                viewport_size.width + 2.0 * DISPLAY_LIST_CULL_MARGIN,
+                viewport_size.height + 2.0 * DISPLAY_LIST_CULL_MARGIN,
+            ),
+        );
+        cull_offscreen_content_groups(
+            &mut rects_in_rendering_order,
+            layout_result,
+            scroll_states,
+            &cull_rect,
+        );
+
         let referenced_content = DisplayListParametersRef {
             dom_id,
             document_id,
@@ -722,6 +957,8 @@ impl LayoutResult {
             gl_texture_cache,
             renderer_resources,
             image_cache,
+            scroll_states,
+            scrollbar_interaction,
         };
 
         let root_width =
@@ -789,6 +1026,7 @@ impl LayoutResult {
         fc_cache: &FcFontCache,
         window_size: &WindowSize,
         window_theme: WindowTheme,
+        system_style: &SystemStyle,
     ) -> QuickResizeResult {
         let dom_bounds = LogicalRect::new(LogicalPosition::zero(), window_size.dimensions);
         let mut dom_ids_to_resize = vec![(dom_id, dom_bounds)];
@@ -817,23 +1055,65 @@ impl LayoutResult {
 
                 gpu_event_changes.merge(&mut resized_nodes.gpu_key_changes);
 
-                for node_id in resized_nodes.resized_nodes.into_iter() {
+                // iframes that only moved aren't resized, but can still
+                // exceed the reinvoke_threshold of their position
+                let mut iframe_nodes_to_check = resized_nodes.resized_nodes;
+                let moved_iframe_nodes = {
+                    let layout_result = &layout_results[dom_id.inner];
+                    layout_result
+                        .iframe_invoked_rects
+                        .iter()
+                        .filter(|(node_id, invoked_rect)| {
+                            !iframe_nodes_to_check.contains(node_id)
+                                && layout_result.rects.as_ref()[**node_id]
+                                    .position
+                                    .get_static_offset()
+                                    != invoked_rect.origin
+                        })
+                        .map(|(node_id, _)| *node_id)
+                        .collect::<Vec<_>>()
+                };
+                iframe_nodes_to_check.extend(moved_iframe_nodes);
+
+                for node_id in iframe_nodes_to_check.into_iter() {
                     let iframe_dom_id =
                         match layout_results[dom_id.inner].iframe_mapping.get(&node_id) {
                             Some(dom_id) => *dom_id,
                             None => continue,
                         };
 
-                    let iframe_rect_relative_to_parent = LayoutRect {
-                        origin: layout_results[iframe_dom_id.inner].root_position,
-                        size: layout_results[iframe_dom_id.inner].root_size,
+                    let iframe_rect = &layout_results[dom_id.inner].rects.as_ref()[node_id];
+                    let iframe_size = iframe_rect.size;
+                    let iframe_invoked_rect = LogicalRect {
+                        origin: iframe_rect.position.get_static_offset(),
+                        size: iframe_size,
+                    };
+                    let new_iframe_rect = LogicalRect {
+                        // TODO: correct? or layout_results[dom_id.0].positioned_rects[node_id]?
+                        origin: LogicalPosition::zero(),
+                        size: iframe_size,
                     };
 
-                    let iframe_needs_to_be_invoked =
-                        !layout_size.contains_rect(&iframe_rect_relative_to_parent);
+                    let iframe_needs_to_be_invoked = {
+                        let layout_result = &mut layout_results[dom_id.inner];
+                        let invoked_rect = layout_result.iframe_invoked_rects.get(&node_id).copied();
+                        let mut node_data_mut =
+                            layout_result.styled_dom.node_data.as_container_mut();
+                        match (node_data_mut[node_id].get_iframe_node(), invoked_rect) {
+                            (Some(iframe_node), Some(invoked_rect)) => iframe_needs_reinvoke(
+                                invoked_rect,
+                                iframe_invoked_rect,
+                                iframe_node.reinvoke_threshold.get(),
+                            ),
+                            (Some(_), None) => true,
+                            (None, _) => continue, // not an iframe
+                        }
+                    };
 
                     if !iframe_needs_to_be_invoked {
-                        continue; // old iframe size still covers the new extent
+                        // change is below the threshold: only relayout the old iframe DOM
+                        new_dom_ids_to_resize.push((iframe_dom_id, new_iframe_rect));
+                        continue;
                     }
 
                     let iframe_return: IFrameCallbackReturn = {
@@ -848,7 +1128,10 @@ impl LayoutResult {
 
                         // invoke the iframe with the new size and replace the dom with the DOM ID
                         let hidpi_bounds = HidpiAdjustedBounds::from_bounds(
-                            layout_size.size,
+                            LayoutSize::new(
+                                libm::roundf(iframe_size.width) as isize,
+                                libm::roundf(iframe_size.height) as isize,
+                            ),
                             window_size.get_hidpi_factor(),
                         );
                         let scroll_node = layout_result
@@ -878,13 +1161,12 @@ impl LayoutResult {
                     // TODO: what to do if the new iframe has less or more sub-iframes
                     // than the current one? edge-case, solve later.
 
-                    layout_results[iframe_dom_id.inner].styled_dom = iframe_return.dom;
-
-                    let new_iframe_rect = LogicalRect {
-                        // TODO: correct? or layout_results[dom_id.0].positioned_rects[node_id]?
-                        origin: LogicalPosition::zero(),
-                        size: layout_results[dom_id.inner].rects.as_ref()[node_id].size,
-                    };
+                    let mut iframe_dom = iframe_return.dom;
+                    iframe_dom.set_system_style(system_style);
+                    layout_results[iframe_dom_id.inner].styled_dom = iframe_dom;
+                    layout_results[dom_id.inner]
+                        .iframe_invoked_rects
+                        .insert(node_id, iframe_invoked_rect);
 
                     // Store the new scroll position
                     // (trust the iframe to return these values correctly)
@@ -1385,7 +1667,60 @@ impl Default for PositionedRectangle {
     }
 }
 
+/// Post-layout geometry of a node, all rects are relative to the window origin
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct NodeRect {
+    /// Border box, expanded by the margins of the node
+    pub margin_box: LogicalRect,
+    /// Area that the background and the borders are drawn into
+    pub border_box: LogicalRect,
+    /// Border box minus the border widths
+    pub padding_box: LogicalRect,
+    /// Padding box minus the padding (i.e. the area that the text / children are laid out in)
+    pub content_box: LogicalRect,
+}
+
+impl_option!(
+    NodeRect,
+    OptionNodeRect,
+    [Debug, Copy, Clone, PartialEq, PartialOrd]
+);
+
+fn shrink_rect(rect: LogicalRect, offsets: &ResolvedOffsets) -> LogicalRect {
+    LogicalRect::new(
+        LogicalPosition::new(rect.origin.x + offsets.left, rect.origin.y + offsets.top),
+        LogicalSize::new(
+            (rect.size.width - offsets.total_horizontal()).max(0.0),
+            (rect.size.height - offsets.total_vertical()).max(0.0),
+        ),
+    )
+}
+
+fn grow_rect(rect: LogicalRect, offsets: &ResolvedOffsets) -> LogicalRect {
+    LogicalRect::new(
+        LogicalPosition::new(rect.origin.x - offsets.left, rect.origin.y - offsets.top),
+        LogicalSize::new(
+            rect.size.width + offsets.total_horizontal(),
+            rect.size.height + offsets.total_vertical(),
+        ),
+    )
+}
+
 impl PositionedRectangle {
+    /// Returns the margin / border / padding / content boxes of this rectangle,
+    /// relative to the window origin
+    pub fn get_node_rect(&self) -> NodeRect {
+        let border_box = LogicalRect::new(self.get_logical_static_offset(), self.size);
+        let padding_box = shrink_rect(border_box, &self.border_widths);
+        NodeRect {
+            margin_box: grow_rect(border_box, &self.margin),
+            border_box,
+            padding_box,
+            content_box: shrink_rect(padding_box, &self.padding),
+        }
+    }
+
     #[inline]
     pub fn get_approximate_static_bounds(&self) -> LayoutRect {
         LayoutRect::new(self.get_static_offset(), self.get_content_size())
@@ -2470,3 +2805,46 @@ impl ComputedTransform3D {
         pre_transform.then(&rotate_transform).then(&post_transform)
     }
 }
+
+/// Returns whether an iframe that was last invoked at `invoked_rect` has to re-invoke
+/// its callback after being laid out at `new_rect`: the callback is re-invoked once the
+/// origin or the size changes (in any direction) by more than `reinvoke_threshold`
+/// logical pixels.
+fn iframe_needs_reinvoke(
+    invoked_rect: LogicalRect,
+    new_rect: LogicalRect,
+    reinvoke_threshold: f32,
+) -> bool {
+    let exceeds = |old: f32, new: f32| (new - old).abs() > reinvoke_threshold;
+    exceeds(invoked_rect.origin.x, new_rect.origin.x)
+        || exceeds(invoked_rect.origin.y, new_rect.origin.y)
+        || exceeds(invoked_rect.size.width, new_rect.size.width)
+        || exceeds(invoked_rect.size.height, new_rect.size.height)
+}
+
+#[test]
+fn test_iframe_needs_reinvoke() {
+    let rect = |x: f32, y: f32, width: f32, height: f32| LogicalRect::new(
+        LogicalPosition::new(x, y),
+        LogicalSize::new(width, height),
+    );
+    let invoked = rect(10.0, 20.0, 400.0, 300.0);
+
+    // default threshold: any change of the size or the origin re-invokes
+    assert!(!iframe_needs_reinvoke(invoked, invoked, 0.0));
+    assert!(iframe_needs_reinvoke(invoked, rect(10.0, 20.0, 401.0, 300.0), 0.0));
+    assert!(iframe_needs_reinvoke(invoked, rect(10.0, 20.0, 400.0, 299.0), 0.0));
+    assert!(iframe_needs_reinvoke(invoked, rect(11.0, 20.0, 400.0, 300.0), 0.0));
+    assert!(iframe_needs_reinvoke(invoked, rect(10.0, 19.0, 400.0, 300.0), 0.0));
+
+    // the threshold applies to growing and shrinking
+    assert!(!iframe_needs_reinvoke(invoked, rect(10.0, 20.0, 450.0, 250.0), 50.0));
+    assert!(iframe_needs_reinvoke(invoked, rect(10.0, 20.0, 451.0, 300.0), 50.0));
+    assert!(iframe_needs_reinvoke(invoked, rect(10.0, 20.0, 349.0, 300.0), 50.0));
+    assert!(iframe_needs_reinvoke(invoked, rect(10.0, 20.0, 0.0, 0.0), 50.0));
+
+    // ... and to moving the iframe
+    assert!(!iframe_needs_reinvoke(invoked, rect(60.0, -30.0, 400.0, 300.0), 50.0));
+    assert!(iframe_needs_reinvoke(invoked, rect(61.0, 20.0, 400.0, 300.0), 50.0));
+    assert!(iframe_needs_reinvoke(invoked, rect(10.0, -31.0, 400.0, 300.0), 50.0));
+}
@@ -4,18 +4,24 @@ use crate::{
         Epoch, GlTextureCache, IdNamespace, ImageCache, ImageMask, ImageRef, RendererResources,
         ResourceUpdate, DpiScaleFactor,
     },
-    callbacks::{Callback, HitTestItem, UpdateImageType},
+    callbacks::{Callback, HitTestItem, SubtreeCrossfade, SubtreeRecording, UpdateImageType},
     callbacks::{
         CallbackType, DocumentId, DomNodeId, LayoutCallback, LayoutCallbackType, OptionCallback,
-        PipelineId, RefAny, ScrollPosition, Update,
+        OptionRefAny, PipelineId, RefAny, ScrollPosition, TextUnderlineVec, Update,
+    },
+    display_list::{Crossfade, RenderCallbacks},
+    dom::{
+        AccessibilityState, DomNodeHash, IdOrClassVec, InputMode, NodeHierarchy, NodeStateFlag,
     },
-    display_list::RenderCallbacks,
-    dom::NodeHierarchy,
     id_tree::NodeId,
     styled_dom::{DomId, NodeHierarchyItemId},
-    task::{ExternalSystemCallbacks, Instant, Thread, ThreadId, Timer, TimerId},
+    task::{
+        AnimationFrameCallback, Duration, ExternalSystemCallbacks, Instant, OptionDuration,
+        SystemTimeDiff, Thread, ThreadId, Timer, TimerId,
+    },
     ui_solver::{
-        ExternalScrollId, HitTest, LayoutResult, OverflowingScrollNode, QuickResizeResult,
+        ExternalScrollId, HitTest, LayoutResult, OverflowingScrollNode, PositionInfo,
+        QuickResizeResult, ScrollbarOrientation, ScrollbarRects, MIN_SCROLLBAR_THUMB_LENGTH,
     },
     window_state::RelayoutFn,
     FastBTreeSet, FastHashMap,
@@ -26,7 +32,7 @@ use alloc::collections::btree_set::BTreeSet;
 use alloc::vec::Vec;
 use azul_css::{
     AzString, ColorU, CssPath, CssProperty, LayoutPoint, LayoutRect, LayoutSize, OptionAzString,
-    OptionF32, OptionI32, U8Vec, FloatValue,
+    OptionF32, OptionI32, StyleOverflowAnchor, StyleWindowDragRegion, SystemStyle, U8Vec, FloatValue,
 };
 use core::{
     cmp::Ordering,
@@ -174,6 +180,12 @@ pub enum RawWindowHandle {
 
 unsafe impl Send for RawWindowHandle {}
 
+impl_option!(
+    RawWindowHandle,
+    OptionRawWindowHandle,
+    [Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
+);
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub struct IOSHandle {
@@ -316,6 +328,12 @@ pub struct KeyboardState {
     /// Use when the physical location of the key is more important than the key's host GUI semantics,
     /// such as for movement controls in a first-person game (German keyboard: Z key, UK keyboard: Y key, etc.)
     pub pressed_scancodes: ScanCodeVec,
+    /// Whether CapsLock is toggled on (READONLY)
+    pub caps_lock_enabled: bool,
+    /// Whether NumLock is toggled on (READONLY)
+    pub num_lock_enabled: bool,
+    /// Whether ScrollLock is toggled on (READONLY)
+    pub scroll_lock_enabled: bool,
 }
 
 impl KeyboardState {
@@ -331,9 +349,59 @@ impl KeyboardState {
     pub fn super_down(&self) -> bool {
         self.is_key_down(VirtualKeyCode::LWin) || self.is_key_down(VirtualKeyCode::RWin)
     }
+    pub fn left_shift_down(&self) -> bool {
+        self.is_key_down(VirtualKeyCode::LShift)
+    }
+    pub fn right_shift_down(&self) -> bool {
+        self.is_key_down(VirtualKeyCode::RShift)
+    }
+    pub fn left_ctrl_down(&self) -> bool {
+        self.is_key_down(VirtualKeyCode::LControl)
+    }
+    pub fn right_ctrl_down(&self) -> bool {
+        self.is_key_down(VirtualKeyCode::RControl)
+    }
+    pub fn left_alt_down(&self) -> bool {
+        self.is_key_down(VirtualKeyCode::LAlt)
+    }
+    /// Note: on keyboard layouts with an AltGr key, the right Alt key is the AltGr key
+    pub fn right_alt_down(&self) -> bool {
+        self.is_key_down(VirtualKeyCode::RAlt)
+    }
+    pub fn left_super_down(&self) -> bool {
+        self.is_key_down(VirtualKeyCode::LWin)
+    }
+    pub fn right_super_down(&self) -> bool {
+        self.is_key_down(VirtualKeyCode::RWin)
+    }
     pub fn is_key_down(&self, key: VirtualKeyCode) -> bool {
         self.pressed_virtual_keycodes.iter().any(|k| *k == key)
     }
+    /// Returns whether the physical key is held down, independent of the keyboard layout
+    pub fn is_scancode_down(&self, scancode: ScanCode) -> bool {
+        self.pressed_scancodes.iter().any(|s| *s == scancode)
+    }
+    /// Returns the currently held down modifiers (and the CapsLock state),
+    /// i.e. to pass them to `CallbackInfo::keycode_to_char`
+    pub fn get_modifiers(&self) -> KeyboardModifiers {
+        KeyboardModifiers {
+            shift: self.shift_down(),
+            ctrl: self.ctrl_down(),
+            alt: self.alt_down(),
+            caps_lock: self.caps_lock_enabled,
+        }
+    }
+}
+
+/// Modifier keys that influence which character a key produces,
+/// see `CallbackInfo::keycode_to_char`. `ctrl + alt` is treated as AltGr.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct KeyboardModifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub caps_lock: bool,
 }
 
 impl_option!(
@@ -401,6 +469,10 @@ pub struct MouseState {
     pub right_down: bool,
     /// Is the middle mouse button down? (READONLY)
     pub middle_down: bool,
+    /// Number of consecutive left clicks within the double-click time and
+    /// distance of the operating system (1 = single click, 2 = double click,
+    /// 3 = triple click, ...), 0 if the left mouse button hasn't been pressed yet (READONLY)
+    pub click_count: u32,
     /// Scroll amount in pixels in the horizontal direction. Gets reset to 0 after every frame (READONLY)
     pub scroll_x: OptionF32,
     /// Scroll amount in pixels in the vertical direction. Gets reset to 0 after every frame (READONLY)
@@ -439,6 +511,7 @@ impl Default for MouseState {
             left_down: false,
             right_down: false,
             middle_down: false,
+            click_count: 0,
             scroll_x: None.into(),
             scroll_y: None.into(),
         }
@@ -587,6 +660,26 @@ impl ScrollStates {
         self.0.get(&scroll_id).map(|entry| entry.get())
     }
 
+    /// Returns a copy of the current scroll positions
+    pub fn get_scroll_positions(&self) -> FastHashMap<ExternalScrollId, LogicalPosition> {
+        self.0.iter().map(|(k, v)| (*k, v.get())).collect()
+    }
+
+    /// Returns whether any scroll position moved by more than `max_distance`
+    /// (on either axis) compared to the given, previous scroll positions
+    pub fn has_moved_further_than(
+        &self,
+        previous: &FastHashMap<ExternalScrollId, LogicalPosition>,
+        max_distance: f32,
+    ) -> bool {
+        self.0.iter().any(|(k, v)| {
+            let current = v.get();
+            let previous = previous.get(k).copied().unwrap_or(LogicalPosition::zero());
+            (current.x - previous.x).abs() > max_distance
+                || (current.y - previous.y).abs() > max_distance
+        })
+    }
+
     /// Set the scroll amount - does not update the `entry.used_this_frame`,
     /// since that is only relevant when we are actually querying the renderer.
     pub fn set_scroll_position(
@@ -613,6 +706,241 @@ impl ScrollStates {
             .or_insert_with(|| ScrollState::default())
             .add(scroll_by_x, scroll_by_y, &node.child_rect);
     }
+
+    /// Selects the scroll anchor of every scrolled container, called before a relayout
+    /// so that `restore_scroll_anchors()` can keep the anchors in place afterwards
+    pub fn get_scroll_anchors(&self, layout_results: &[LayoutResult]) -> Vec<ScrollAnchor> {
+        let mut anchors = Vec::new();
+
+        for (dom_id, layout_result) in layout_results.iter().enumerate() {
+            for (scroll_node_id, scroll_node) in layout_result.scrollable_nodes.overflowing_nodes.iter() {
+                let scroll_node_id = match scroll_node_id.into_crate_internal() {
+                    Some(s) => s,
+                    None => continue,
+                };
+
+                let scroll_position = match self.get_scroll_position(&scroll_node.parent_external_scroll_id) {
+                    Some(s) => s,
+                    None => continue,
+                };
+
+                // nothing can jump if the container is scrolled to the top left
+                if scroll_position.x <= 0.0 && scroll_position.y <= 0.0 {
+                    continue;
+                }
+
+                if let Some(anchor) = ScrollAnchor::select(
+                    DomId { inner: dom_id },
+                    layout_result,
+                    scroll_node_id,
+                    scroll_node,
+                    scroll_position,
+                ) {
+                    anchors.push(anchor);
+                }
+            }
+        }
+
+        anchors
+    }
+
+    /// Scrolls every container by the distance that its anchor moved during the
+    /// relayout, returns whether any scroll position changed
+    pub fn restore_scroll_anchors(
+        &mut self,
+        anchors: &[ScrollAnchor],
+        layout_results: &[LayoutResult],
+    ) -> bool {
+        let mut changed = false;
+
+        for anchor in anchors {
+            let layout_result = match layout_results.get(anchor.dom_id.inner) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            // the NodeId of the container may have changed if the DOM was regenerated
+            let (scroll_node_id, scroll_node) = match layout_result
+                .scrollable_nodes
+                .overflowing_nodes
+                .iter()
+                .find(|(_, n)| n.parent_external_scroll_id == anchor.scroll_id)
+            {
+                Some((id, n)) => match id.into_crate_internal() {
+                    Some(id) => (id, n),
+                    None => continue,
+                },
+                None => continue,
+            };
+
+            let anchor_node_id = match anchor.find_node(layout_result, scroll_node_id) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let offset = get_offset_in_scroll_container(layout_result, scroll_node_id, anchor_node_id);
+            let dx = offset.x - anchor.offset.x;
+            let dy = offset.y - anchor.offset.y;
+            if dx == 0.0 && dy == 0.0 {
+                continue;
+            }
+
+            let current = self.get_scroll_position(&anchor.scroll_id).unwrap_or(LogicalPosition::zero());
+            self.set_scroll_position(scroll_node, LogicalPosition::new(current.x + dx, current.y + dy));
+            changed = changed || self.get_scroll_position(&anchor.scroll_id) != Some(current);
+        }
+
+        changed
+    }
+}
+
+/// Node that stays at the same position in the viewport of its scroll container
+/// when the content above it changes its size (i.e. when an image finishes loading):
+/// after the relayout, the container is scrolled by the distance the anchor moved.
+///
+/// The anchor is the first node (in DOM order) that starts inside the viewport, or the
+/// innermost node that is cut off by the top edge of the viewport. Nodes with
+/// `overflow-anchor: none` and their children are never selected, `overflow-anchor: none`
+/// on the scroll container itself disables the anchoring of the container.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ScrollAnchor {
+    pub dom_id: DomId,
+    /// Scroll container that is adjusted
+    pub scroll_id: ExternalScrollId,
+    pub node_id: NodeId,
+    /// Hash of the anchor node, to find the anchor again if the DOM was regenerated
+    pub node_hash: DomNodeHash,
+    /// Position of the anchor relative to the scroll container, before the relayout
+    pub offset: LogicalPosition,
+}
+
+impl ScrollAnchor {
+    fn select(
+        dom_id: DomId,
+        layout_result: &LayoutResult,
+        scroll_node_id: NodeId,
+        scroll_node: &OverflowingScrollNode,
+        scroll_position: LogicalPosition,
+    ) -> Option<Self> {
+        if !is_scroll_anchor_candidate(layout_result, scroll_node_id) {
+            return None;
+        }
+
+        let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+        let viewport = LogicalRect::new(
+            LogicalPosition::new(
+                scroll_node.parent_rect.origin.x + scroll_position.x,
+                scroll_node.parent_rect.origin.y + scroll_position.y,
+            ),
+            scroll_node.parent_rect.size,
+        );
+
+        // depth-first search, descends into the nodes that are only partially visible
+        let mut stack = scroll_node_id.az_children_collect(&node_hierarchy);
+        stack.reverse();
+
+        while let Some(node_id) = stack.pop() {
+            let rect = match get_scroll_anchor_rect(layout_result, node_id) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let is_visible = rect.max_x() > viewport.min_x()
+                && rect.min_x() < viewport.max_x()
+                && rect.max_y() > viewport.min_y()
+                && rect.min_y() < viewport.max_y();
+
+            if !is_visible || !is_scroll_anchor_candidate(layout_result, node_id) {
+                continue;
+            }
+
+            let children = node_id.az_children_collect(&node_hierarchy);
+            let starts_in_viewport = rect.min_x() >= viewport.min_x() && rect.min_y() >= viewport.min_y();
+
+            if starts_in_viewport || children.is_empty() {
+                return Some(ScrollAnchor {
+                    dom_id,
+                    scroll_id: scroll_node.parent_external_scroll_id,
+                    node_id,
+                    node_hash: layout_result.styled_dom.node_data.as_container()[node_id]
+                        .calculate_node_data_hash(),
+                    offset: get_offset_in_scroll_container(layout_result, scroll_node_id, node_id),
+                });
+            }
+
+            stack.extend(children.into_iter().rev());
+        }
+
+        None
+    }
+
+    /// Returns the anchor node in the new layout: the same NodeId if the node didn't
+    /// change, otherwise the first node in the scroll container with the same hash
+    fn find_node(&self, layout_result: &LayoutResult, scroll_node_id: NodeId) -> Option<NodeId> {
+        let node_data = layout_result.styled_dom.node_data.as_container();
+        let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+
+        if node_data
+            .get(self.node_id)
+            .map(|n| n.calculate_node_data_hash() == self.node_hash)
+            .unwrap_or(false)
+        {
+            return Some(self.node_id);
+        }
+
+        let mut stack = scroll_node_id.az_children_collect(&node_hierarchy);
+        stack.reverse();
+
+        while let Some(node_id) = stack.pop() {
+            if node_data[node_id].calculate_node_data_hash() == self.node_hash {
+                return Some(node_id);
+            }
+            stack.extend(node_id.az_children_collect(&node_hierarchy).into_iter().rev());
+        }
+
+        None
+    }
+}
+
+/// Returns false if the node has `overflow-anchor: none`
+fn is_scroll_anchor_candidate(layout_result: &LayoutResult, node_id: NodeId) -> bool {
+    let styled_dom = &layout_result.styled_dom;
+    let overflow_anchor = styled_dom
+        .get_css_property_cache()
+        .get_overflow_anchor(
+            &styled_dom.node_data.as_container()[node_id],
+            &node_id,
+            &styled_dom.styled_nodes.as_container()[node_id].state,
+        )
+        .and_then(|p| p.get_property().copied())
+        .unwrap_or_default();
+
+    overflow_anchor != StyleOverflowAnchor::None
+}
+
+/// Layout rect of the node, `None` for empty and out-of-flow nodes (which don't scroll
+/// with the content above them)
+fn get_scroll_anchor_rect(layout_result: &LayoutResult, node_id: NodeId) -> Option<LogicalRect> {
+    let rect = &layout_result.rects.as_ref()[node_id];
+    match rect.position {
+        PositionInfo::Absolute(_) | PositionInfo::Fixed(_) => return None,
+        _ => {}
+    }
+    if rect.size.width <= 0.0 || rect.size.height <= 0.0 {
+        return None;
+    }
+    Some(LogicalRect::new(rect.position.get_static_offset(), rect.size))
+}
+
+fn get_offset_in_scroll_container(
+    layout_result: &LayoutResult,
+    scroll_node_id: NodeId,
+    node_id: NodeId,
+) -> LogicalPosition {
+    let rects = layout_result.rects.as_ref();
+    let container = rects[scroll_node_id].position.get_static_offset();
+    let node = rects[node_id].position.get_static_offset();
+    LogicalPosition::new(node.x - container.x, node.y - container.y)
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
@@ -652,6 +980,206 @@ impl Default for ScrollState {
     }
 }
 
+/// Identifies the horizontal or vertical scrollbar of a scroll frame
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScrollbarId {
+    pub dom_id: DomId,
+    pub node_id: NodeHierarchyItemId,
+    pub orientation: ScrollbarOrientation,
+}
+
+/// Scrollbar thumb that is currently being dragged with the mouse
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct ScrollbarDrag {
+    pub scrollbar: ScrollbarId,
+    /// Cursor position when the drag started
+    pub start_cursor_position: LogicalPosition,
+    /// Scroll position of the scroll frame when the drag started
+    pub start_scroll_position: LogicalPosition,
+}
+
+/// How the built-in scrollbars are drawn, see `ScrollbarConfig::mode`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Hash, Ord, Eq)]
+#[repr(C)]
+pub enum ScrollbarMode {
+    /// The scrollbars are drawn as specified by the `scrollbar-width` property (default)
+    Default,
+    /// All scrollbars are drawn as thin overlay scrollbars (only the thumb, on top of
+    /// the content), same as `scrollbar-width: thin` - `scrollbar-width: none` is kept
+    Overlay,
+}
+
+impl Default for ScrollbarMode {
+    fn default() -> Self {
+        ScrollbarMode::Default
+    }
+}
+
+/// Behavior of the built-in scrollbars of a window, see `WindowCreateOptions::scrollbars`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct ScrollbarConfig {
+    /// Default or overlay scrollbars
+    pub mode: ScrollbarMode,
+    /// Minimum length of a scrollbar thumb, so that it stays
+    /// draggable even if the content is very long (default: 20px)
+    pub min_thumb_length: f32,
+    /// Invisible margin around the thumb that still counts as a hit,
+    /// makes thin scrollbars easier to grab (default: 0px)
+    pub hit_area_margin: f32,
+    /// If set, the scrollbars fade out once they haven't been scrolled, hovered
+    /// or dragged for the given duration (default: scrollbars are always visible)
+    pub auto_hide_delay: OptionDuration,
+    /// Duration of the fade-out of auto-hiding scrollbars (default: 200ms)
+    pub auto_hide_fade_duration: Duration,
+}
+
+impl Default for ScrollbarConfig {
+    fn default() -> Self {
+        Self {
+            mode: ScrollbarMode::Default,
+            min_thumb_length: MIN_SCROLLBAR_THUMB_LENGTH,
+            hit_area_margin: 0.0,
+            auto_hide_delay: OptionDuration::None,
+            auto_hide_fade_duration: Duration::System(SystemTimeDiff::from_millis(200)),
+        }
+    }
+}
+
+impl ScrollbarConfig {
+    /// Returns the auto-hide delay in milliseconds, `None` if the scrollbars
+    /// don't auto-hide (tick-based durations are not supported)
+    pub fn get_auto_hide_delay_millis(&self) -> Option<u64> {
+        match self.auto_hide_delay.into_option() {
+            Some(Duration::System(d)) => Some(d.millis()),
+            _ => None,
+        }
+    }
+
+    /// Returns the opacity of the scrollbars, given how long ago they were last
+    /// used: 1.0 during the delay, then fading to 0.0 over the fade duration
+    pub fn get_auto_hide_opacity(&self, idle_millis: u64) -> f32 {
+        let delay = match self.get_auto_hide_delay_millis() {
+            Some(s) => s,
+            None => return 1.0,
+        };
+        let fade = match self.auto_hide_fade_duration {
+            Duration::System(d) => d.millis(),
+            Duration::Tick(_) => 0,
+        };
+        if idle_millis <= delay {
+            1.0
+        } else if idle_millis >= delay + fade {
+            0.0
+        } else {
+            1.0 - (idle_millis - delay) as f32 / fade as f32
+        }
+    }
+}
+
+/// Hover / drag state of the built-in scrollbars, used to
+/// pick the `-azul-scrollbar-thumb-hover-color` / `-active-color`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct ScrollbarInteraction {
+    /// Scrollbar whose thumb is under the cursor
+    pub hovered_thumb: Option<ScrollbarId>,
+    /// Scrollbar whose thumb is being dragged
+    pub dragged_thumb: Option<ScrollbarDrag>,
+    /// Configuration of the window (initialized from `WindowCreateOptions::scrollbars`)
+    pub config: ScrollbarConfig,
+    /// Opacity of all scrollbars (< 1.0 while auto-hiding scrollbars fade out)
+    pub opacity: f32,
+}
+
+impl Default for ScrollbarInteraction {
+    fn default() -> Self {
+        Self {
+            hovered_thumb: None,
+            dragged_thumb: None,
+            config: ScrollbarConfig::default(),
+            opacity: 1.0,
+        }
+    }
+}
+
+impl ScrollbarInteraction {
+    pub fn new(config: ScrollbarConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    pub fn is_thumb_hovered(&self, scrollbar: &ScrollbarId) -> bool {
+        self.hovered_thumb.as_ref() == Some(scrollbar)
+    }
+
+    pub fn is_thumb_dragged(&self, scrollbar: &ScrollbarId) -> bool {
+        self.dragged_thumb.as_ref().map(|d| &d.scrollbar) == Some(scrollbar)
+    }
+}
+
+/// Returns the scroll node and the current track / thumb rects of a scrollbar,
+/// or `None` if the scroll frame doesn't exist anymore or has `scrollbar-width: none`
+fn get_scrollbar_rects(
+    layout_results: &[LayoutResult],
+    scroll_states: &ScrollStates,
+    config: &ScrollbarConfig,
+    scrollbar: &ScrollbarId,
+) -> Option<(OverflowingScrollNode, ScrollbarRects)> {
+    let layout_result = layout_results.get(scrollbar.dom_id.inner)?;
+    let scroll_node = layout_result.scrollable_nodes.overflowing_nodes.get(&scrollbar.node_id)?;
+    let style = layout_result.get_scrollbar_style(scrollbar.node_id.into_crate_internal()?, config.mode)?;
+    let scroll_position = scroll_states
+        .get_scroll_position(&scroll_node.parent_external_scroll_id)
+        .unwrap_or(LogicalPosition::zero());
+    let rects = match scroll_node.get_scrollbar_rects(scroll_position, style.thickness, config.min_thumb_length) {
+        (Some(h), _) if scrollbar.orientation == ScrollbarOrientation::Horizontal => h,
+        (_, Some(v)) if scrollbar.orientation == ScrollbarOrientation::Vertical => v,
+        _ => return None,
+    };
+    Some((*scroll_node, rects))
+}
+
+/// Returns the scrollbar whose thumb (enlarged by the `hit_area_margin`) is under the cursor
+///
+/// NOTE: only scroll frames of the root DOM are tested, since the
+/// rects of iframe DOMs are relative to the iframe, not to the window
+fn hit_test_scrollbar_thumbs(
+    layout_results: &[LayoutResult],
+    scroll_states: &ScrollStates,
+    config: &ScrollbarConfig,
+    cursor_position: &LogicalPosition,
+) -> Option<ScrollbarId> {
+    let layout_result = layout_results.get(DomId::ROOT_ID.inner)?;
+    let margin = config.hit_area_margin.max(0.0);
+
+    // iterate in reverse, so that nested scroll frames take precedence
+    layout_result.scrollable_nodes.overflowing_nodes.iter().rev().find_map(|(node_id, scroll_node)| {
+        let style = layout_result.get_scrollbar_style(node_id.into_crate_internal()?, config.mode)?;
+        let scroll_position = scroll_states
+            .get_scroll_position(&scroll_node.parent_external_scroll_id)
+            .unwrap_or(LogicalPosition::zero());
+        let (horizontal, vertical) = scroll_node.get_scrollbar_rects(scroll_position, style.thickness, config.min_thumb_length);
+        let origin = scroll_node.parent_rect.origin;
+        horizontal.into_iter().chain(vertical.into_iter()).find_map(|rects| {
+            let thumb = LogicalRect::new(
+                LogicalPosition::new(
+                    origin.x + rects.thumb.origin.x - margin,
+                    origin.y + rects.thumb.origin.y - margin,
+                ),
+                LogicalSize::new(rects.thumb.size.width + margin * 2.0, rects.thumb.size.height + margin * 2.0),
+            );
+            thumb.hit_test(cursor_position)?;
+            Some(ScrollbarId {
+                dom_id: DomId::ROOT_ID,
+                node_id: *node_id,
+                orientation: rects.orientation,
+            })
+        })
+    })
+}
+
 /// Overwrites all fields of the `FullWindowState` with the fields of the `WindowState`,
 /// but leaves the extra fields such as `.hover_nodes` untouched
 pub fn update_full_window_state(
@@ -696,18 +1224,226 @@ pub struct WindowInternal {
     pub timers: BTreeMap<TimerId, Timer>,
     /// List of threads running in the background
     pub threads: BTreeMap<ThreadId, Thread>,
+    /// Callbacks that run right before the next frame is rendered
+    pub animation_frame_callbacks: Vec<AnimationFrameCallback>,
+    /// Snapshots of subtrees that are currently cross-faded to their new content
+    pub crossfades: Vec<Crossfade>,
+    /// Window-level keyboard shortcuts (initialized from `WindowCreateOptions::shortcuts`)
+    pub shortcuts: ShortcutMap,
+    /// Window-local data (initialized from `WindowCreateOptions::window_data`)
+    pub window_data: OptionRefAny,
+    /// Hover / drag state of the built-in scrollbars
+    pub scrollbar_interaction: ScrollbarInteraction,
+    /// Scroll positions at the time the display list was last built: offscreen items
+    /// are culled from the display list, so it has to be rebuilt on large scroll jumps
+    pub display_list_scroll_positions: FastHashMap<ExternalScrollId, LogicalPosition>,
 }
 
 impl WindowInternal {
     pub fn get_dpi_scale_factor(&self) -> DpiScaleFactor {
         DpiScaleFactor { inner: FloatValue::new(self.current_window_state.size.get_hidpi_factor()) }
     }
+
+    /// Sets the scroll positions of the nodes that were scrolled via
+    /// `CallbackInfo::set_scroll_position`, returns whether any node was scrolled
+    pub fn set_scroll_positions(
+        &mut self,
+        nodes_scrolled: &BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, LogicalPosition>>,
+    ) -> bool {
+        let mut scrolled = false;
+        for (dom_id, nodes) in nodes_scrolled.iter() {
+            let layout_result = match self.layout_results.get(dom_id.inner) {
+                Some(s) => s,
+                None => continue,
+            };
+            for (node_id, scroll_position) in nodes.iter() {
+                if let Some(scroll_node) = layout_result.scrollable_nodes.overflowing_nodes.get(node_id) {
+                    self.scroll_states.set_scroll_position(scroll_node, *scroll_position);
+                    scrolled = true;
+                }
+            }
+        }
+        scrolled
+    }
+
+    /// Returns whether the window was scrolled further than the `DISPLAY_LIST_CULL_MARGIN`
+    /// since the display list was built, i.e. whether items that were culled from the
+    /// display list might be visible now and scrolling on the GPU is not enough
+    pub fn scroll_exceeds_cull_margin(&self) -> bool {
+        use crate::display_list::DISPLAY_LIST_CULL_MARGIN;
+        self.scroll_states.has_moved_further_than(
+            &self.display_list_scroll_positions,
+            DISPLAY_LIST_CULL_MARGIN,
+        )
+    }
+
+    /// Removes the underlines of text nodes whose text changed in the callbacks
+    /// (since the underlined ranges are now outdated) and applies the underlines
+    /// set via `CallbackInfo::set_text_underlines`. Empty underline lists are removed.
+    ///
+    /// Returns whether the display list has to be rebuilt
+    pub fn update_text_underlines(
+        &mut self,
+        words_changed: Option<&BTreeMap<DomId, BTreeMap<NodeId, AzString>>>,
+        text_underlines_changed: Option<&BTreeMap<DomId, BTreeMap<NodeId, TextUnderlineVec>>>,
+    ) -> bool {
+
+        let mut should_update_display_list = false;
+
+        for (dom_id, nodes) in words_changed.into_iter().flat_map(|w| w.iter()) {
+            let layout_result = match self.layout_results.get_mut(dom_id.inner) {
+                Some(s) => s,
+                None => continue,
+            };
+            for node_id in nodes.keys() {
+                should_update_display_list |= layout_result.text_underlines.remove(node_id).is_some();
+            }
+        }
+
+        for (dom_id, nodes) in text_underlines_changed.into_iter().flat_map(|w| w.iter()) {
+            let layout_result = match self.layout_results.get_mut(dom_id.inner) {
+                Some(s) => s,
+                None => continue,
+            };
+            for (node_id, underlines) in nodes.iter() {
+                if underlines.is_empty() {
+                    should_update_display_list |= layout_result.text_underlines.remove(node_id).is_some();
+                } else {
+                    layout_result.text_underlines.insert(*node_id, underlines.clone());
+                    should_update_display_list = true;
+                }
+            }
+        }
+
+        should_update_display_list
+    }
+
+    /// Returns whether the focused node is a secure text field (marked with
+    /// `AccessibilityState::Protected` or `InputMode::Password`), for which
+    /// the shell has to disable the IME
+    pub fn focused_node_is_protected(&self) -> bool {
+        let focused_node = match self.current_window_state.focused_node {
+            Some(s) => s,
+            None => return false,
+        };
+        let node_id = match focused_node.node.into_crate_internal() {
+            Some(s) => s,
+            None => return false,
+        };
+        let layout_result = match self.layout_results.get(focused_node.dom.inner) {
+            Some(s) => s,
+            None => return false,
+        };
+        let node_data_container = layout_result.styled_dom.node_data.as_container();
+        let node_data = match node_data_container.get(node_id) {
+            Some(s) => s,
+            None => return false,
+        };
+        node_data.get_input_mode() == Some(InputMode::Password)
+            || node_data
+                .get_accessibility_info()
+                .map(|a| a.states.iter().any(|s| *s == AccessibilityState::Protected))
+                .unwrap_or(false)
+    }
+
+    /// Returns the `InputMode` of the focused node (`InputMode::Text` if
+    /// nothing is focused), which the shell forwards to the IME / touch keyboard
+    pub fn get_focused_input_mode(&self) -> InputMode {
+        let focused_node = match self.current_window_state.focused_node {
+            Some(s) => s,
+            None => return InputMode::default(),
+        };
+        let node_id = match focused_node.node.into_crate_internal() {
+            Some(s) => s,
+            None => return InputMode::default(),
+        };
+        self.layout_results
+            .get(focused_node.dom.inner)
+            .and_then(|lr| lr.styled_dom.node_data.as_container().get(node_id).and_then(|nd| nd.get_input_mode()))
+            .unwrap_or_default()
+    }
+
+    /// Updates the hover / drag state of the built-in scrollbars from the current
+    /// mouse state and scrolls the scroll frame whose thumb is being dragged.
+    ///
+    /// Returns whether the display list has to be rebuilt, because
+    /// a thumb moved or changed its hover / active color
+    pub fn process_scrollbar_interaction(&mut self) -> bool {
+
+        let previous_interaction = self.scrollbar_interaction;
+        let config = self.scrollbar_interaction.config;
+        let left_down = self.current_window_state.mouse_state.left_down;
+        let previous_left_down = self.previous_window_state
+            .as_ref()
+            .map(|s| s.mouse_state.left_down)
+            .unwrap_or(false);
+        let cursor_position = self.current_window_state.mouse_state.cursor_position.get_position();
+
+        let mut scrolled = false;
+
+        if !left_down {
+            self.scrollbar_interaction.dragged_thumb = None;
+        }
+
+        if let (Some(drag), Some(cursor)) = (self.scrollbar_interaction.dragged_thumb, cursor_position) {
+            match get_scrollbar_rects(&self.layout_results, &self.scroll_states, &config, &drag.scrollbar) {
+                Some((scroll_node, rects)) => {
+                    let mut scroll_position = self.scroll_states
+                        .get_scroll_position(&scroll_node.parent_external_scroll_id)
+                        .unwrap_or(LogicalPosition::zero());
+                    match rects.orientation {
+                        ScrollbarOrientation::Horizontal => {
+                            let delta = cursor.x - drag.start_cursor_position.x;
+                            scroll_position.x = (drag.start_scroll_position.x + delta * rects.scroll_per_thumb_px)
+                                .max(0.0)
+                                .min(rects.max_scroll);
+                        },
+                        ScrollbarOrientation::Vertical => {
+                            let delta = cursor.y - drag.start_cursor_position.y;
+                            scroll_position.y = (drag.start_scroll_position.y + delta * rects.scroll_per_thumb_px)
+                                .max(0.0)
+                                .min(rects.max_scroll);
+                        },
+                    }
+                    self.scroll_states.set_scroll_position(&scroll_node, scroll_position);
+                    scrolled = true;
+                },
+                None => {
+                    // scroll frame was removed, i.e. because the DOM was regenerated
+                    self.scrollbar_interaction.dragged_thumb = None;
+                },
+            }
+        }
+
+        self.scrollbar_interaction.hovered_thumb = cursor_position.and_then(|cursor| {
+            hit_test_scrollbar_thumbs(&self.layout_results, &self.scroll_states, &config, &cursor)
+        });
+
+        // start dragging on mouse down over a thumb
+        if left_down && !previous_left_down && self.scrollbar_interaction.dragged_thumb.is_none() {
+            if let (Some(scrollbar), Some(cursor)) = (self.scrollbar_interaction.hovered_thumb, cursor_position) {
+                if let Some((scroll_node, _)) = get_scrollbar_rects(&self.layout_results, &self.scroll_states, &config, &scrollbar) {
+                    self.scrollbar_interaction.dragged_thumb = Some(ScrollbarDrag {
+                        scrollbar,
+                        start_cursor_position: cursor,
+                        start_scroll_position: self.scroll_states
+                            .get_scroll_position(&scroll_node.parent_external_scroll_id)
+                            .unwrap_or(LogicalPosition::zero()),
+                    });
+                }
+            }
+        }
+
+        scrolled || self.scrollbar_interaction != previous_interaction
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct FullHitTest {
     pub hovered_nodes: BTreeMap<DomId, HitTest>,
     pub focused_node: Option<(DomId, NodeId)>,
+    /// Hit nodes of each touch in the `TouchState`, indexed by the touch ID
+    pub touch_hit_tests: BTreeMap<u64, BTreeMap<DomId, HitTest>>,
 }
 
 impl FullHitTest {
@@ -715,6 +1451,7 @@ impl FullHitTest {
         Self {
             hovered_nodes: BTreeMap::new(),
             focused_node: focused_node.and_then(|f| Some((f.dom, f.node.into_crate_internal()?))),
+            touch_hit_tests: BTreeMap::new(),
         }
     }
 }
@@ -727,14 +1464,26 @@ pub struct CursorTypeHitTest {
     pub cursor_node: Option<(DomId, NodeId)>,
     /// Mouse cursor type to set (if cursor_node is None, this is set to `MouseCursorType::Default`)
     pub cursor_icon: MouseCursorType,
+    /// Image of a `cursor: url(id) x y` property, `cursor_icon` is the
+    /// fallback if the platform can't create a cursor from the image
+    pub custom_cursor: Option<CustomCursor>,
+}
+
+/// Cursor created from an image, the hotspot is in pixels from the top left of the image
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomCursor {
+    pub image: ImageRef,
+    pub hotspot_x: u32,
+    pub hotspot_y: u32,
 }
 
 impl CursorTypeHitTest {
-    pub fn new(hit_test: &FullHitTest, layout_results: &[LayoutResult]) -> Self {
+    pub fn new(hit_test: &FullHitTest, layout_results: &[LayoutResult], image_cache: &ImageCache) -> Self {
         use azul_css::StyleCursor;
 
         let mut cursor_node = None;
         let mut cursor_icon = MouseCursorType::Default;
+        let mut custom_cursor = None;
 
         for (dom_id, hit_nodes) in hit_test.hovered_nodes.iter() {
             for (node_id, _) in hit_nodes.regular_hit_test_nodes.iter() {
@@ -747,7 +1496,8 @@ impl CursorTypeHitTest {
                     &styled_dom.styled_nodes.as_container()[*node_id].state,
                 ) {
                     cursor_node = Some((*dom_id, *node_id));
-                    cursor_icon = match cursor_prop.get_property().copied().unwrap_or_default() {
+                    custom_cursor = None;
+                    cursor_icon = match cursor_prop.get_property().cloned().unwrap_or_default() {
                         StyleCursor::Alias => MouseCursorType::Alias,
                         StyleCursor::AllScroll => MouseCursorType::AllScroll,
                         StyleCursor::Cell => MouseCursorType::Cell,
@@ -778,6 +1528,15 @@ impl CursorTypeHitTest {
                         StyleCursor::Wait => MouseCursorType::Wait,
                         StyleCursor::ZoomIn => MouseCursorType::ZoomIn,
                         StyleCursor::ZoomOut => MouseCursorType::ZoomOut,
+                        StyleCursor::Image(i) => {
+                            // unknown image IDs fall back to the default cursor
+                            custom_cursor = image_cache.get_css_image_id(&i.image).map(|image| CustomCursor {
+                                image: image.clone(),
+                                hotspot_x: i.hotspot_x,
+                                hotspot_y: i.hotspot_y,
+                            });
+                            MouseCursorType::Default
+                        }
                     }
                 }
             }
@@ -786,6 +1545,63 @@ impl CursorTypeHitTest {
         Self {
             cursor_node,
             cursor_icon,
+            custom_cursor,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WindowDragRegionHitTest {
+    /// Hovered node that has a `-azul-window-drag-region` property
+    pub drag_region_node: Option<(DomId, NodeId)>,
+    /// Drag region under the cursor: if not `None`, the platform window handles
+    /// the mouse (native move / resize, Snap Layouts for the maximize button)
+    pub drag_region: StyleWindowDragRegion,
+}
+
+impl WindowDragRegionHitTest {
+    pub fn new(hit_test: &FullHitTest, layout_results: &[LayoutResult]) -> Self {
+        let mut drag_region_node = None;
+        let mut drag_region = StyleWindowDragRegion::None;
+
+        'outer: for (dom_id, hit_nodes) in hit_test.hovered_nodes.iter() {
+            let styled_dom = &layout_results[dom_id.inner].styled_dom;
+            let node_data_container = styled_dom.node_data.as_container();
+            let styled_nodes = styled_dom.styled_nodes.as_container();
+
+            // iterate from the innermost to the outermost hit node: the first node
+            // that either has a drag region or handles mouse events itself (i.e. a
+            // button inside of a title bar) decides whether the window is dragged
+            for (node_id, _) in hit_nodes.regular_hit_test_nodes.iter().rev() {
+                let node_data = &node_data_container[*node_id];
+
+                if let Some(region) = styled_dom.get_css_property_cache().get_window_drag_region(
+                    node_data,
+                    node_id,
+                    &styled_nodes[*node_id].state,
+                ) {
+                    let region = region.get_property().copied().unwrap_or_default();
+                    if region != StyleWindowDragRegion::None {
+                        drag_region_node = Some((*dom_id, *node_id));
+                        drag_region = region;
+                    }
+                    break 'outer;
+                }
+
+                let node_has_callbacks = node_data
+                    .get_callbacks()
+                    .iter()
+                    .any(|cb| !cb.event.is_window_callback());
+
+                if node_has_callbacks {
+                    break 'outer;
+                }
+            }
+        }
+
+        Self {
+            drag_region_node,
+            drag_region,
         }
     }
 }
@@ -793,6 +1609,8 @@ pub struct WindowInternalInit {
     pub window_create_options: WindowCreateOptions,
     pub document_id: DocumentId,
     pub id_namespace: IdNamespace,
+    /// System settings queried from the operating system (`SystemStyle::DEFAULT` if unsupported)
+    pub system_style: SystemStyle,
 }
 
 impl WindowInternal {
@@ -820,7 +1638,7 @@ impl WindowInternal {
 
         let epoch = Epoch::new();
 
-        let styled_dom = {
+        let mut styled_dom = {
             let layout_callback = &mut init.window_create_options.state.layout_callback;
             let mut layout_info = LayoutCallbackInfo::new(
                 init.window_create_options.state.size,
@@ -828,6 +1646,7 @@ impl WindowInternal {
                 image_cache,
                 gl_context,
                 &fc_cache_real,
+                &init.system_style,
             );
 
             match layout_callback {
@@ -839,12 +1658,18 @@ impl WindowInternal {
             }
         };
 
+        styled_dom.set_system_style(&init.system_style);
+
         let mut current_window_state = FullWindowState::from_window_state(
             /*window_state: */ &init.window_create_options.state,
             /*dropped_file: */ None,
             /*hovered_file: */ None,
             /*focused_node: */ None,
             /*last_hit_test: */ FullHitTest::empty(/*current_focus*/ None),
+            /*user_is_idle: */ false,
+            /*user_event: */ OptionRefAny::None,
+            /*hover_intent: */ false,
+            /*system_style: */ init.system_style,
         );
 
         let SolvedLayout { mut layout_results } = SolvedLayout::new(
@@ -882,6 +1707,8 @@ impl WindowInternal {
             current_window_state.size.get_layout_size(),
             &init.document_id,
             Some(&BTreeMap::new()),
+            None,
+            None,
             Some(&BTreeMap::new()),
             &None,
             relayout_fn,
@@ -913,6 +1740,12 @@ impl WindowInternal {
             gl_texture_cache,
             timers: BTreeMap::new(),
             threads: BTreeMap::new(),
+            animation_frame_callbacks: Vec::new(),
+            crossfades: Vec::new(),
+            shortcuts: init.window_create_options.shortcuts.clone(),
+            window_data: init.window_create_options.window_data.clone(),
+            scrollbar_interaction: ScrollbarInteraction::new(init.window_create_options.scrollbars),
+            display_list_scroll_positions: FastHashMap::default(),
             scroll_states,
         }
     }
@@ -940,6 +1773,7 @@ impl WindowInternal {
         use crate::window_state::{NodesToCheck, StyleAndLayoutChanges};
 
         let id_namespace = self.id_namespace;
+        let scroll_anchors = self.scroll_states.get_scroll_anchors(&self.layout_results);
 
         let mut styled_dom = {
             let layout_callback = &mut self.current_window_state.layout_callback;
@@ -949,6 +1783,7 @@ impl WindowInternal {
                 image_cache,
                 gl_context,
                 &fc_cache_real,
+                &self.current_window_state.system_style,
             );
 
             match layout_callback {
@@ -960,6 +1795,7 @@ impl WindowInternal {
             }
         };
 
+        styled_dom.set_system_style(&self.current_window_state.system_style);
         styled_dom.insert_default_system_callbacks(DefaultCallbacksCfg {
             smooth_scroll: self.current_window_state.flags.smooth_scroll_enabled,
             enable_autotab: self.current_window_state.flags.autotab_enabled,
@@ -1002,6 +1838,8 @@ impl WindowInternal {
             self.current_window_state.size.get_layout_size(),
             &self.document_id,
             Some(&BTreeMap::new()),
+            None,
+            None,
             Some(&BTreeMap::new()),
             &None,
             relayout_fn,
@@ -1037,6 +1875,8 @@ impl WindowInternal {
         self.epoch.increment();
         self.layout_results = layout_results;
         self.gl_texture_cache = gl_texture_cache;
+
+        self.scroll_states.restore_scroll_anchors(&scroll_anchors, &self.layout_results);
     }
 
     /// Returns a copy of the current scroll states + scroll positions
@@ -1083,6 +1923,44 @@ impl WindowInternal {
         LogicalSize::new(root_width, root_height)
     }
 
+    /// Returns the min-content size of the root body node, i.e. the size
+    /// below which the UI can't shrink anymore and starts to overflow
+    pub fn get_min_content_size(&self) -> LogicalSize {
+        let layout_result = match self.layout_results.get(0) {
+            Some(s) => s,
+            None => return LogicalSize::zero(),
+        };
+        let window_size = self.current_window_state.size.get_logical_size();
+        let min_width = layout_result.width_calculated_rects.as_ref()[NodeId::ZERO]
+            .get_flex_basis_horizontal(window_size.width);
+        let min_height = layout_result.height_calculated_rects.as_ref()[NodeId::ZERO]
+            .get_flex_basis_vertical(window_size.height);
+        LogicalSize::new(min_width, min_height)
+    }
+
+    /// Returns the (minimum, maximum) size of the window in logical units, the minimum
+    /// includes the min-content size of the UI if `WindowSize::min_dimensions_from_content`
+    /// is set. The maximum size is never smaller than the minimum size.
+    pub fn get_min_max_size(&self) -> (Option<LogicalSize>, Option<LogicalSize>) {
+        let size = &self.current_window_state.size;
+
+        let mut min = size.min_dimensions.into_option();
+        if size.min_dimensions_from_content {
+            let content = self.get_min_content_size();
+            min = Some(match min {
+                Some(m) => LogicalSize::new(m.width.max(content.width), m.height.max(content.height)),
+                None => content,
+            });
+        }
+
+        let max = size.max_dimensions.into_option().map(|max| match min {
+            Some(m) => LogicalSize::new(max.width.max(m.width), max.height.max(m.height)),
+            None => max,
+        });
+
+        (min, max)
+    }
+
     /// Does a full re-layout (without calling layout()) again:
     /// called in simple resize() scenarios
     pub fn do_quick_resize(
@@ -1095,7 +1973,10 @@ impl WindowInternal {
         window_size: &WindowSize,
         window_theme: WindowTheme,
     ) -> QuickResizeResult {
-        LayoutResult::do_quick_resize(
+        let scroll_anchors = self.scroll_states.get_scroll_anchors(&self.layout_results);
+        let system_style = self.current_window_state.system_style;
+
+        let result = LayoutResult::do_quick_resize(
             self.id_namespace,
             self.document_id,
             self.epoch,
@@ -1110,7 +1991,12 @@ impl WindowInternal {
             fc_cache,
             window_size,
             window_theme,
-        )
+            &system_style,
+        );
+
+        self.scroll_states.restore_scroll_anchors(&scroll_anchors, &self.layout_results);
+
+        result
     }
 
     /// Returns whether the size or position of the window changed (if true,
@@ -1173,6 +2059,43 @@ impl WindowInternal {
         context_menu
     }
 
+    /// Updates `user_is_idle` from the system idle time (see `AppConfig::user_idle_timeout`),
+    /// returns true if the state changed and the window has to be hit-tested again in order
+    /// to emit `On::UserIdle` / `On::UserActive`
+    ///
+    /// The previous window state is only initialized if it doesn't exist yet: a pending
+    /// state change that hasn't been hit-tested yet is kept, the idle change is added to it.
+    pub fn update_user_is_idle(&mut self, idle_time: &Duration, idle_timeout: &Duration) -> bool {
+        let user_is_idle = idle_time.greater_than(idle_timeout);
+        if self.current_window_state.user_is_idle == user_is_idle {
+            return false;
+        }
+
+        if self.previous_window_state.is_none() {
+            self.previous_window_state = Some(self.current_window_state.clone());
+        }
+        self.current_window_state.user_is_idle = user_is_idle;
+        true
+    }
+
+    /// Returns the IDs of all timers that are ready to run at `instant_now`
+    pub fn get_timers_ready_to_run(&self, instant_now: &Instant) -> Vec<TimerId> {
+        self.timers
+            .iter()
+            .filter(|(_, timer)| timer.instant_of_next_run() <= *instant_now)
+            .map(|(timer_id, _)| *timer_id)
+            .collect()
+    }
+
+    /// Returns when the next timer of this window has to run (`None` if the
+    /// window has no timers), so that the event loop can sleep until then
+    pub fn get_next_timer_instant(&self) -> Option<Instant> {
+        self.timers
+            .values()
+            .map(|timer| timer.instant_of_next_run())
+            .min()
+    }
+
     /// Runs a single timer, similar to CallbacksOfHitTest.call()
     ///
     /// NOTE: The timer has to be selected first by the calling code and verified
@@ -1195,7 +2118,10 @@ impl WindowInternal {
             callbacks_update_screen: Update::DoNothing,
             modified_window_state: None,
             css_properties_changed: None,
+            ids_and_classes_changed: None,
+            node_state_flags_changed: None,
             words_changed: None,
+            text_underlines_changed: None,
             images_changed: None,
             image_masks_changed: None,
             nodes_scrolled_in_callbacks: None,
@@ -1205,6 +2131,11 @@ impl WindowInternal {
             timers_removed: None,
             threads_removed: None,
             windows_created: Vec::new(),
+            announcements: Vec::new(),
+            haptic_feedback: Vec::new(),
+            animation_frame_callbacks: Vec::new(),
+            crossfades: Vec::new(),
+            subtree_recordings: Vec::new(),
             cursor_changed: false,
         };
 
@@ -1215,9 +2146,12 @@ impl WindowInternal {
         let mut ret_threads = FastHashMap::new();
         let mut ret_threads_removed = FastBTreeSet::new();
         let mut ret_words_changed = BTreeMap::new();
+        let mut ret_text_underlines_changed = BTreeMap::new();
         let mut ret_images_changed = BTreeMap::new();
         let mut ret_image_masks_changed = BTreeMap::new();
         let mut ret_css_properties_changed = BTreeMap::new();
+        let mut ret_ids_and_classes_changed = BTreeMap::new();
+        let mut ret_node_state_flags_changed = BTreeMap::new();
         let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
 
         let mut should_terminate = TerminateTimer::Continue;
@@ -1254,13 +2188,23 @@ impl WindowInternal {
                 &mut ret_threads_removed,
                 current_window_handle,
                 &mut ret.windows_created,
+                &mut self.shortcuts,
+                &mut self.window_data,
+                &mut ret.announcements,
+                &mut ret.haptic_feedback,
+                &mut ret.animation_frame_callbacks,
+                &mut ret.crossfades,
+                &mut ret.subtree_recordings,
                 system_callbacks,
                 &mut stop_propagation,
                 &mut new_focus_target,
                 &mut ret_words_changed,
+                &mut ret_text_underlines_changed,
                 &mut ret_images_changed,
                 &mut ret_image_masks_changed,
                 &mut ret_css_properties_changed,
+                &mut ret_ids_and_classes_changed,
+                &mut ret_node_state_flags_changed,
                 &current_scroll_states,
                 &mut ret_nodes_scrolled_in_callbacks,
                 hit_dom_node,
@@ -1295,6 +2239,9 @@ impl WindowInternal {
             if !ret_words_changed.is_empty() {
                 ret.words_changed = Some(ret_words_changed);
             }
+            if !ret_text_underlines_changed.is_empty() {
+                ret.text_underlines_changed = Some(ret_text_underlines_changed);
+            }
             if !ret_images_changed.is_empty() {
                 ret.images_changed = Some(ret_images_changed);
             }
@@ -1304,6 +2251,12 @@ impl WindowInternal {
             if !ret_css_properties_changed.is_empty() {
                 ret.css_properties_changed = Some(ret_css_properties_changed);
             }
+            if !ret_ids_and_classes_changed.is_empty() {
+                ret.ids_and_classes_changed = Some(ret_ids_and_classes_changed);
+            }
+            if !ret_node_state_flags_changed.is_empty() {
+                ret.node_state_flags_changed = Some(ret_node_state_flags_changed);
+            }
             if !ret_nodes_scrolled_in_callbacks.is_empty() {
                 ret.nodes_scrolled_in_callbacks = Some(ret_nodes_scrolled_in_callbacks);
             }
@@ -1317,13 +2270,292 @@ impl WindowInternal {
             }
         }
 
-        if should_terminate == TerminateTimer::Terminate {
-            ret.timers_removed
-                .get_or_insert_with(|| BTreeSet::new())
-                .insert(TimerId { id: timer_id });
+        if should_terminate == TerminateTimer::Terminate {
+            ret.timers_removed
+                .get_or_insert_with(|| BTreeSet::new())
+                .insert(TimerId { id: timer_id });
+        }
+
+        return ret;
+    }
+
+    /// Runs all callbacks requested via `CallbackInfo::request_animation_frame()`,
+    /// called by the event loop right before the next frame is rendered.
+    ///
+    /// Callbacks that are requested again while running are returned in
+    /// `CallCallbacksResult::animation_frame_callbacks` and run on the next frame.
+    pub fn run_animation_frame_callbacks(
+        &mut self,
+        frame_start: Instant,
+        current_window_handle: &RawWindowHandle,
+        gl_context: &OptionGlContextPtr,
+        image_cache: &mut ImageCache,
+        system_fonts: &mut FcFontCache,
+        system_callbacks: &ExternalSystemCallbacks,
+    ) -> CallCallbacksResult {
+        use crate::callbacks::CallbackInfo;
+
+        let mut ret = CallCallbacksResult {
+            should_scroll_render: false,
+            callbacks_update_screen: Update::DoNothing,
+            modified_window_state: None,
+            css_properties_changed: None,
+            ids_and_classes_changed: None,
+            node_state_flags_changed: None,
+            words_changed: None,
+            text_underlines_changed: None,
+            images_changed: None,
+            image_masks_changed: None,
+            nodes_scrolled_in_callbacks: None,
+            update_focused_node: None,
+            timers: None,
+            threads: None,
+            timers_removed: None,
+            threads_removed: None,
+            windows_created: Vec::new(),
+            announcements: Vec::new(),
+            haptic_feedback: Vec::new(),
+            animation_frame_callbacks: Vec::new(),
+            crossfades: Vec::new(),
+            subtree_recordings: Vec::new(),
+            cursor_changed: false,
+        };
+
+        let mut ret_modified_window_state: WindowState = self.current_window_state.clone().into();
+        let ret_window_state = ret_modified_window_state.clone();
+        let mut ret_timers = FastHashMap::new();
+        let mut ret_timers_removed = FastBTreeSet::new();
+        let mut ret_threads = FastHashMap::new();
+        let mut ret_threads_removed = FastBTreeSet::new();
+        let mut ret_words_changed = BTreeMap::new();
+        let mut ret_text_underlines_changed = BTreeMap::new();
+        let mut ret_images_changed = BTreeMap::new();
+        let mut ret_image_masks_changed = BTreeMap::new();
+        let mut ret_css_properties_changed = BTreeMap::new();
+        let mut ret_ids_and_classes_changed = BTreeMap::new();
+        let mut ret_node_state_flags_changed = BTreeMap::new();
+        let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
+        let mut new_focus_target = None;
+        let mut stop_propagation = false;
+        let current_scroll_states = self.get_current_scroll_states();
+
+        let mut animation_frame_callbacks = core::mem::replace(&mut self.animation_frame_callbacks, Vec::new());
+
+        for animation_frame_callback in animation_frame_callbacks.iter_mut() {
+            let hit_dom_node = DomNodeId {
+                dom: DomId::ROOT_ID,
+                node: NodeHierarchyItemId::from_crate_internal(None),
+            };
+            let cursor_relative_to_item = OptionLogicalPosition::None;
+            let cursor_in_viewport = OptionLogicalPosition::None;
+
+            let callback_info = CallbackInfo::new(
+                &self.layout_results,
+                &self.renderer_resources,
+                &self.previous_window_state,
+                &self.current_window_state,
+                &mut ret_modified_window_state,
+                gl_context,
+                image_cache,
+                system_fonts,
+                &mut ret_timers,
+                &mut ret_threads,
+                &mut ret_timers_removed,
+                &mut ret_threads_removed,
+                current_window_handle,
+                &mut ret.windows_created,
+                &mut self.shortcuts,
+                &mut self.window_data,
+                &mut ret.announcements,
+                &mut ret.haptic_feedback,
+                &mut ret.animation_frame_callbacks,
+                &mut ret.crossfades,
+                &mut ret.subtree_recordings,
+                system_callbacks,
+                &mut stop_propagation,
+                &mut new_focus_target,
+                &mut ret_words_changed,
+                &mut ret_text_underlines_changed,
+                &mut ret_images_changed,
+                &mut ret_image_masks_changed,
+                &mut ret_css_properties_changed,
+                &mut ret_ids_and_classes_changed,
+                &mut ret_node_state_flags_changed,
+                &current_scroll_states,
+                &mut ret_nodes_scrolled_in_callbacks,
+                hit_dom_node,
+                cursor_relative_to_item,
+                cursor_in_viewport,
+            );
+
+            let callback_update = animation_frame_callback.invoke(callback_info, frame_start.clone());
+            ret.callbacks_update_screen.max_self(callback_update);
+        }
+
+        if !ret_timers.is_empty() {
+            ret.timers = Some(ret_timers);
+        }
+        if !ret_threads.is_empty() {
+            ret.threads = Some(ret_threads);
+        }
+        if ret_modified_window_state != ret_window_state {
+            ret.modified_window_state = Some(ret_modified_window_state);
+        }
+        if !ret_threads_removed.is_empty() {
+            ret.threads_removed = Some(ret_threads_removed);
+        }
+        if !ret_timers_removed.is_empty() {
+            ret.timers_removed = Some(ret_timers_removed);
+        }
+        if !ret_words_changed.is_empty() {
+            ret.words_changed = Some(ret_words_changed);
+        }
+        if !ret_text_underlines_changed.is_empty() {
+            ret.text_underlines_changed = Some(ret_text_underlines_changed);
+        }
+        if !ret_images_changed.is_empty() {
+            ret.images_changed = Some(ret_images_changed);
+        }
+        if !ret_image_masks_changed.is_empty() {
+            ret.image_masks_changed = Some(ret_image_masks_changed);
+        }
+        if !ret_css_properties_changed.is_empty() {
+            ret.css_properties_changed = Some(ret_css_properties_changed);
+        }
+        if !ret_ids_and_classes_changed.is_empty() {
+            ret.ids_and_classes_changed = Some(ret_ids_and_classes_changed);
+        }
+        if !ret_node_state_flags_changed.is_empty() {
+            ret.node_state_flags_changed = Some(ret_node_state_flags_changed);
+        }
+        if !ret_nodes_scrolled_in_callbacks.is_empty() {
+            ret.nodes_scrolled_in_callbacks = Some(ret_nodes_scrolled_in_callbacks);
+        }
+
+        if let Some(ft) = new_focus_target {
+            if let Ok(new_focus_node) =
+                ft.resolve(&self.layout_results, self.current_window_state.focused_node)
+            {
+                ret.update_focused_node = Some(new_focus_node);
+            }
+        }
+
+        return ret;
+    }
+
+    /// Creates the snapshots of the subtrees that should be cross-faded (see
+    /// `CallbackInfo::crossfade_subtree()`), has to be called before the DOM
+    /// is regenerated, while the layout results still contain the old content
+    #[cfg(feature = "multithreading")]
+    pub fn start_crossfades(&mut self, crossfades: Vec<SubtreeCrossfade>, image_cache: &ImageCache) {
+        use crate::display_list::{push_rectangles_into_displaylist, DisplayListParametersRef};
+        use crate::styled_dom::ContentGroup;
+
+        fn find_content_group<'a>(group: &'a ContentGroup, node: NodeHierarchyItemId) -> Option<&'a ContentGroup> {
+            if group.root == node {
+                return Some(group);
+            }
+            group.children.as_ref().iter().find_map(|child| find_content_group(child, node))
         }
 
-        return ret;
+        let layout_result = match self.layout_results.get(DomId::ROOT_ID.inner) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let rendering_order = layout_result.styled_dom.get_rects_in_rendering_order();
+
+        let referenced_content = DisplayListParametersRef {
+            dom_id: DomId::ROOT_ID,
+            document_id: &self.document_id,
+            epoch: self.epoch,
+            full_window_state: &self.current_window_state,
+            layout_results: &self.layout_results,
+            gl_texture_cache: &self.gl_texture_cache,
+            renderer_resources: &self.renderer_resources,
+            image_cache,
+            scroll_states: &self.scroll_states,
+            scrollbar_interaction: &self.scrollbar_interaction,
+        };
+
+        let new_crossfades = crossfades
+            .into_iter()
+            .filter_map(|crossfade| {
+                // the snapshot is positioned in window coordinates,
+                // so only nodes of the root DOM can be cross-faded
+                if crossfade.node.dom != DomId::ROOT_ID {
+                    return None;
+                }
+
+                let node_id = crossfade.node.node.into_crate_internal()?;
+                let content_group = find_content_group(&rendering_order, crossfade.node.node)?;
+                let subtree = push_rectangles_into_displaylist(content_group, &referenced_content)?;
+
+                // position of the parent node in window coordinates
+                let node_rect = self.get_node_rect_in_window(crossfade.node)?;
+                let relative_offset = layout_result.rects.as_ref()[node_id].position.get_relative_offset();
+                let parent_position = LogicalPosition::new(
+                    node_rect.origin.x - relative_offset.x,
+                    node_rect.origin.y - relative_offset.y,
+                );
+
+                Some(Crossfade::new(
+                    subtree,
+                    parent_position,
+                    &self.scroll_states,
+                    crossfade.start,
+                    crossfade.duration,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        self.crossfades.extend(new_crossfades);
+    }
+
+    /// Returns the area of a node of the root DOM in window coordinates: the
+    /// static offset minus the scroll offsets of all parent scroll frames
+    pub fn get_node_rect_in_window(&self, node: DomNodeId) -> Option<LogicalRect> {
+
+        if node.dom != DomId::ROOT_ID {
+            return None;
+        }
+
+        let layout_result = self.layout_results.get(DomId::ROOT_ID.inner)?;
+        let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+        let node_id = node.node.into_crate_internal()?;
+        let rect = layout_result.rects.as_ref().get_extended_lifetime(node_id)?;
+
+        let static_offset = rect.position.get_static_offset();
+        let mut origin = LogicalPosition::new(static_offset.x, static_offset.y);
+
+        let mut ancestor = node_hierarchy[node_id].parent_id();
+        while let Some(a) = ancestor {
+            let scroll_position = layout_result
+                .scrollable_nodes
+                .overflowing_nodes
+                .get(&NodeHierarchyItemId::from_crate_internal(Some(a)))
+                .and_then(|scroll_node| {
+                    self.scroll_states.get_scroll_position(&scroll_node.parent_external_scroll_id)
+                });
+            if let Some(p) = scroll_position {
+                origin.x -= p.x;
+                origin.y -= p.y;
+            }
+            ancestor = node_hierarchy[a].parent_id();
+        }
+
+        Some(LogicalRect::new(origin, rect.size))
+    }
+
+    /// Updates the opacity of the cross-fade snapshots and removes the finished ones.
+    /// Returns whether a snapshot was removed (the display list has to be rebuilt).
+    pub fn advance_crossfades(&mut self, now: &Instant) -> bool {
+        let count = self.crossfades.len();
+        self.crossfades.retain(|crossfade| !crossfade.is_finished(now));
+        for crossfade in self.crossfades.iter_mut() {
+            crossfade.advance(now);
+        }
+        self.crossfades.len() != count
     }
 
     pub fn run_all_threads(
@@ -1346,7 +2578,10 @@ impl WindowInternal {
             callbacks_update_screen: Update::DoNothing,
             modified_window_state: None,
             css_properties_changed: None,
+            ids_and_classes_changed: None,
+            node_state_flags_changed: None,
             words_changed: None,
+            text_underlines_changed: None,
             images_changed: None,
             image_masks_changed: None,
             nodes_scrolled_in_callbacks: None,
@@ -1356,6 +2591,11 @@ impl WindowInternal {
             timers_removed: None,
             threads_removed: None,
             windows_created: Vec::new(),
+            announcements: Vec::new(),
+            haptic_feedback: Vec::new(),
+            animation_frame_callbacks: Vec::new(),
+            crossfades: Vec::new(),
+            subtree_recordings: Vec::new(),
             cursor_changed: false,
         };
 
@@ -1366,9 +2606,12 @@ impl WindowInternal {
         let mut ret_threads = FastHashMap::new();
         let mut ret_threads_removed = FastBTreeSet::new();
         let mut ret_words_changed = BTreeMap::new();
+        let mut ret_text_underlines_changed = BTreeMap::new();
         let mut ret_images_changed = BTreeMap::new();
         let mut ret_image_masks_changed = BTreeMap::new();
         let mut ret_css_properties_changed = BTreeMap::new();
+        let mut ret_ids_and_classes_changed = BTreeMap::new();
+        let mut ret_node_state_flags_changed = BTreeMap::new();
         let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
         let mut new_focus_target = None;
         let mut stop_propagation = false;
@@ -1422,13 +2665,23 @@ impl WindowInternal {
                 &mut ret_threads_removed,
                 current_window_handle,
                 &mut ret.windows_created,
+                &mut self.shortcuts,
+                &mut self.window_data,
+                &mut ret.announcements,
+                &mut ret.haptic_feedback,
+                &mut ret.animation_frame_callbacks,
+                &mut ret.crossfades,
+                &mut ret.subtree_recordings,
                 system_callbacks,
                 &mut stop_propagation,
                 &mut new_focus_target,
                 &mut ret_words_changed,
+                &mut ret_text_underlines_changed,
                 &mut ret_images_changed,
                 &mut ret_image_masks_changed,
                 &mut ret_css_properties_changed,
+                &mut ret_ids_and_classes_changed,
+                &mut ret_node_state_flags_changed,
                 &current_scroll_states,
                 &mut ret_nodes_scrolled_in_callbacks,
                 hit_dom_node,
@@ -1465,6 +2718,9 @@ impl WindowInternal {
         if !ret_words_changed.is_empty() {
             ret.words_changed = Some(ret_words_changed);
         }
+        if !ret_text_underlines_changed.is_empty() {
+            ret.text_underlines_changed = Some(ret_text_underlines_changed);
+        }
         if !ret_images_changed.is_empty() {
             ret.images_changed = Some(ret_images_changed);
         }
@@ -1474,6 +2730,12 @@ impl WindowInternal {
         if !ret_css_properties_changed.is_empty() {
             ret.css_properties_changed = Some(ret_css_properties_changed);
         }
+        if !ret_ids_and_classes_changed.is_empty() {
+            ret.ids_and_classes_changed = Some(ret_ids_and_classes_changed);
+        }
+        if !ret_node_state_flags_changed.is_empty() {
+            ret.node_state_flags_changed = Some(ret_node_state_flags_changed);
+        }
         if !ret_nodes_scrolled_in_callbacks.is_empty() {
             ret.nodes_scrolled_in_callbacks = Some(ret_nodes_scrolled_in_callbacks);
         }
@@ -1513,7 +2775,10 @@ impl WindowInternal {
             callbacks_update_screen: Update::DoNothing,
             modified_window_state: None,
             css_properties_changed: None,
+            ids_and_classes_changed: None,
+            node_state_flags_changed: None,
             words_changed: None,
+            text_underlines_changed: None,
             images_changed: None,
             image_masks_changed: None,
             nodes_scrolled_in_callbacks: None,
@@ -1523,6 +2788,11 @@ impl WindowInternal {
             timers_removed: None,
             threads_removed: None,
             windows_created: Vec::new(),
+            announcements: Vec::new(),
+            haptic_feedback: Vec::new(),
+            animation_frame_callbacks: Vec::new(),
+            crossfades: Vec::new(),
+            subtree_recordings: Vec::new(),
             cursor_changed: false,
         };
 
@@ -1533,9 +2803,12 @@ impl WindowInternal {
         let mut ret_threads = FastHashMap::new();
         let mut ret_threads_removed = FastBTreeSet::new();
         let mut ret_words_changed = BTreeMap::new();
+        let mut ret_text_underlines_changed = BTreeMap::new();
         let mut ret_images_changed = BTreeMap::new();
         let mut ret_image_masks_changed = BTreeMap::new();
         let mut ret_css_properties_changed = BTreeMap::new();
+        let mut ret_ids_and_classes_changed = BTreeMap::new();
+        let mut ret_node_state_flags_changed = BTreeMap::new();
         let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
         let mut new_focus_target = None;
         let mut stop_propagation = false;
@@ -1559,13 +2832,23 @@ impl WindowInternal {
             &mut ret_threads_removed,
             current_window_handle,
             &mut ret.windows_created,
+            &mut self.shortcuts,
+            &mut self.window_data,
+            &mut ret.announcements,
+            &mut ret.haptic_feedback,
+            &mut ret.animation_frame_callbacks,
+            &mut ret.crossfades,
+            &mut ret.subtree_recordings,
             system_callbacks,
             &mut stop_propagation,
             &mut new_focus_target,
             &mut ret_words_changed,
+            &mut ret_text_underlines_changed,
             &mut ret_images_changed,
             &mut ret_image_masks_changed,
             &mut ret_css_properties_changed,
+            &mut ret_ids_and_classes_changed,
+            &mut ret_node_state_flags_changed,
             &current_scroll_states,
             &mut ret_nodes_scrolled_in_callbacks,
             hit_dom_node,
@@ -1593,6 +2876,9 @@ impl WindowInternal {
         if !ret_words_changed.is_empty() {
             ret.words_changed = Some(ret_words_changed);
         }
+        if !ret_text_underlines_changed.is_empty() {
+            ret.text_underlines_changed = Some(ret_text_underlines_changed);
+        }
         if !ret_images_changed.is_empty() {
             ret.images_changed = Some(ret_images_changed);
         }
@@ -1602,6 +2888,12 @@ impl WindowInternal {
         if !ret_css_properties_changed.is_empty() {
             ret.css_properties_changed = Some(ret_css_properties_changed);
         }
+        if !ret_ids_and_classes_changed.is_empty() {
+            ret.ids_and_classes_changed = Some(ret_ids_and_classes_changed);
+        }
+        if !ret_node_state_flags_changed.is_empty() {
+            ret.node_state_flags_changed = Some(ret_node_state_flags_changed);
+        }
         if !ret_nodes_scrolled_in_callbacks.is_empty() {
             ret.nodes_scrolled_in_callbacks = Some(ret_nodes_scrolled_in_callbacks);
         }
@@ -1634,7 +2926,10 @@ impl WindowInternal {
             callbacks_update_screen: Update::DoNothing,
             modified_window_state: None,
             css_properties_changed: None,
+            ids_and_classes_changed: None,
+            node_state_flags_changed: None,
             words_changed: None,
+            text_underlines_changed: None,
             images_changed: None,
             image_masks_changed: None,
             nodes_scrolled_in_callbacks: None,
@@ -1644,6 +2939,11 @@ impl WindowInternal {
             timers_removed: None,
             threads_removed: None,
             windows_created: Vec::new(),
+            announcements: Vec::new(),
+            haptic_feedback: Vec::new(),
+            animation_frame_callbacks: Vec::new(),
+            crossfades: Vec::new(),
+            subtree_recordings: Vec::new(),
             cursor_changed: false,
         };
 
@@ -1654,9 +2954,12 @@ impl WindowInternal {
         let mut ret_threads = FastHashMap::new();
         let mut ret_threads_removed = FastBTreeSet::new();
         let mut ret_words_changed = BTreeMap::new();
+        let mut ret_text_underlines_changed = BTreeMap::new();
         let mut ret_images_changed = BTreeMap::new();
         let mut ret_image_masks_changed = BTreeMap::new();
         let mut ret_css_properties_changed = BTreeMap::new();
+        let mut ret_ids_and_classes_changed = BTreeMap::new();
+        let mut ret_node_state_flags_changed = BTreeMap::new();
         let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
         let mut new_focus_target = None;
         let mut stop_propagation = false;
@@ -1680,13 +2983,23 @@ impl WindowInternal {
             &mut ret_threads_removed,
             current_window_handle,
             &mut ret.windows_created,
+            &mut self.shortcuts,
+            &mut self.window_data,
+            &mut ret.announcements,
+            &mut ret.haptic_feedback,
+            &mut ret.animation_frame_callbacks,
+            &mut ret.crossfades,
+            &mut ret.subtree_recordings,
             system_callbacks,
             &mut stop_propagation,
             &mut new_focus_target,
             &mut ret_words_changed,
+            &mut ret_text_underlines_changed,
             &mut ret_images_changed,
             &mut ret_image_masks_changed,
             &mut ret_css_properties_changed,
+            &mut ret_ids_and_classes_changed,
+            &mut ret_node_state_flags_changed,
             &current_scroll_states,
             &mut ret_nodes_scrolled_in_callbacks,
             hit_dom_node,
@@ -1715,6 +3028,9 @@ impl WindowInternal {
         if !ret_words_changed.is_empty() {
             ret.words_changed = Some(ret_words_changed);
         }
+        if !ret_text_underlines_changed.is_empty() {
+            ret.text_underlines_changed = Some(ret_text_underlines_changed);
+        }
         if !ret_images_changed.is_empty() {
             ret.images_changed = Some(ret_images_changed);
         }
@@ -1724,6 +3040,12 @@ impl WindowInternal {
         if !ret_css_properties_changed.is_empty() {
             ret.css_properties_changed = Some(ret_css_properties_changed);
         }
+        if !ret_ids_and_classes_changed.is_empty() {
+            ret.ids_and_classes_changed = Some(ret_ids_and_classes_changed);
+        }
+        if !ret_node_state_flags_changed.is_empty() {
+            ret.node_state_flags_changed = Some(ret_node_state_flags_changed);
+        }
         if !ret_nodes_scrolled_in_callbacks.is_empty() {
             ret.nodes_scrolled_in_callbacks = Some(ret_nodes_scrolled_in_callbacks);
         }
@@ -1740,11 +3062,75 @@ impl WindowInternal {
     }
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd, Hash, Ord, Eq)]
+/// Single finger / stylus that is currently touching the screen
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TouchPoint {
+    /// Platform-assigned ID of the touch, stays the same from the
+    /// touch start until the finger is lifted (IDs can be reused afterwards)
+    pub id: u64,
+    /// Position of the touch relative to the top left of the window
+    pub position: LogicalPosition,
+    /// Pressure of the touch from 0.0 to 1.0 (1.0 if the device does not report the pressure)
+    pub force: f32,
+}
+
+impl_vec!(TouchPoint, TouchPointVec, TouchPointVecDestructor);
+impl_vec_debug!(TouchPoint, TouchPointVec);
+impl_vec_clone!(TouchPoint, TouchPointVec, TouchPointVecDestructor);
+impl_vec_partialeq!(TouchPoint, TouchPointVec);
+impl_vec_partialord!(TouchPoint, TouchPointVec);
+
+/// Device that the last pointer input of the window came from
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum InputModality {
+    Mouse,
+    Touch,
+    Pen,
+}
+
+impl Default for InputModality {
+    fn default() -> Self {
+        InputModality::Mouse
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
 #[repr(C)]
 pub struct TouchState {
-    /// TODO: not yet implemented
-    pub unimplemented: u8,
+    /// All touches that are currently on the screen, in the order they started
+    pub touches: TouchPointVec,
+    /// Device of the last pointer input (mouse, touch or pen). On change, it will
+    /// emit a `WindowEventFilter::InputModalityChanged` event
+    pub input_modality: InputModality,
+}
+
+impl TouchState {
+    pub fn get(&self, id: u64) -> Option<&TouchPoint> {
+        self.touches.iter().find(|t| t.id == id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.touches.is_empty()
+    }
+
+    /// Inserts a new touch or updates the position / force of an existing touch
+    pub fn set_touch(&mut self, touch: TouchPoint) {
+        let mut touches = self.touches.clone().into_library_owned_vec();
+        match touches.iter_mut().find(|t| t.id == touch.id) {
+            Some(existing) => *existing = touch,
+            None => touches.push(touch),
+        }
+        self.touches = touches.into();
+    }
+
+    /// Removes the touch (finger lifted or touch cancelled by the OS)
+    pub fn remove_touch(&mut self, id: u64) {
+        let mut touches = self.touches.clone().into_library_owned_vec();
+        touches.retain(|t| t.id != id);
+        self.touches = touches.into();
+    }
 }
 
 /// State, size, etc of the window, for comparing to the last frame
@@ -1777,6 +3163,7 @@ pub struct Monitor {
     pub scale_factor: f64,
     pub video_modes: VideoModeVec,
     pub is_primary_monitor: bool,
+    pub hdr: HdrCapabilities,
 }
 
 impl_vec!(Monitor, MonitorVec, MonitorVecDestructor);
@@ -1804,9 +3191,27 @@ impl Default for Monitor {
             scale_factor: 1.0,
             video_modes: Vec::new().into(),
             is_primary_monitor: false,
+            hdr: HdrCapabilities::default(),
         }
     }
 }
+
+/// HDR / wide color gamut capabilities of a monitor
+///
+/// Windows are currently always rendered in 8-bit sRGB: on a monitor with
+/// `enabled` HDR, the OS maps the SDR content to `sdr_white_level`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct HdrCapabilities {
+    /// Whether the monitor can display HDR content
+    pub supported: bool,
+    /// Whether HDR is currently turned on in the OS display settings
+    pub enabled: bool,
+    /// Bits per color channel of the current display output (usually 8 or 10), 0 if unknown
+    pub bits_per_color_channel: u32,
+    /// Luminance of SDR white in nits while HDR is enabled, 0.0 if unknown
+    pub sdr_white_level: f32,
+}
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub struct VideoMode {
@@ -1858,6 +3263,9 @@ pub struct WindowState {
     pub renderer_options: RendererOptions,
     /// Color of the window background (can be transparent if necessary)
     pub background_color: ColorU,
+    /// Opacity of the entire window including the decorations,
+    /// from 0.0 (invisible) to 1.0 (opaque, default)
+    pub opacity: f32,
     /// The `layout()` function for this window, stored as a callback function pointer,
     /// There are multiple reasons for doing this (instead of requiring `T: Layout` everywhere):
     ///
@@ -1940,6 +3348,8 @@ pub struct FullWindowState {
     pub renderer_options: RendererOptions,
     /// Background color of the window
     pub background_color: ColorU,
+    /// Opacity of the entire window (0.0 - 1.0)
+    pub opacity: f32,
     /// The `layout()` function for this window, stored as a callback function pointer,
     /// There are multiple reasons for doing this (instead of requiring `T: Layout` everywhere):
     ///
@@ -1968,6 +3378,18 @@ pub struct FullWindowState {
     /// events are stored in a queue and only storing the hovered
     /// nodes is not sufficient to correctly determine events
     pub last_hit_test: FullHitTest,
+    /// Whether the user has been idle for longer than the `AppConfig::user_idle_timeout`,
+    /// used to emit `On::UserIdle` and `On::UserActive` events
+    pub user_is_idle: bool,
+    /// Data of the `EventLoopProxy::send_user_event()` call that is currently
+    /// being delivered to the window, used to emit `On::UserEvent`
+    pub user_event: OptionRefAny,
+    /// Whether the cursor has rested for longer than the `AppConfig::hover_intent_delay`,
+    /// reset by the event loop after the `On::HoverIntent` event has been delivered
+    pub hover_intent: bool,
+    /// High contrast mode, reduced motion, input modality and system colors: the DOM
+    /// is restyled with these settings, updated by the windowing backend
+    pub system_style: SystemStyle,
 }
 
 impl Default for FullWindowState {
@@ -1985,6 +3407,7 @@ impl Default for FullWindowState {
             ime_position: ImePosition::Uninitialized,
             platform_specific_options: PlatformSpecificOptions::default(),
             background_color: ColorU::WHITE,
+            opacity: 1.0,
             layout_callback: LayoutCallback::default(),
             close_callback: OptionCallback::None,
             renderer_options: RendererOptions::default(),
@@ -1994,6 +3417,10 @@ impl Default for FullWindowState {
             dropped_file: None,
             focused_node: None,
             last_hit_test: FullHitTest::empty(None),
+            user_is_idle: false,
+            user_event: OptionRefAny::None,
+            hover_intent: false,
+            system_style: SystemStyle::DEFAULT,
         }
     }
 }
@@ -2037,6 +3464,10 @@ impl FullWindowState {
         hovered_file: Option<AzString>,
         focused_node: Option<DomNodeId>,
         last_hit_test: FullHitTest,
+        user_is_idle: bool,
+        user_event: OptionRefAny,
+        hover_intent: bool,
+        system_style: SystemStyle,
     ) -> Self {
         Self {
             monitor: window_state.monitor.clone(),
@@ -2048,10 +3479,11 @@ impl FullWindowState {
             debug_state: window_state.debug_state,
             keyboard_state: window_state.keyboard_state.clone(),
             mouse_state: window_state.mouse_state,
-            touch_state: window_state.touch_state,
+            touch_state: window_state.touch_state.clone(),
             ime_position: window_state.ime_position.into(),
             platform_specific_options: window_state.platform_specific_options.clone(),
             background_color: window_state.background_color,
+            opacity: window_state.opacity,
             layout_callback: window_state.layout_callback.clone(),
             close_callback: window_state.close_callback,
             renderer_options: window_state.renderer_options,
@@ -2059,6 +3491,10 @@ impl FullWindowState {
             hovered_file,
             focused_node,
             last_hit_test,
+            user_is_idle,
+            user_event,
+            hover_intent,
+            system_style,
         }
     }
 
@@ -2085,6 +3521,7 @@ impl From<FullWindowState> for WindowState {
             ime_position: full_window_state.ime_position.into(),
             platform_specific_options: full_window_state.platform_specific_options,
             background_color: full_window_state.background_color,
+            opacity: full_window_state.opacity,
             layout_callback: full_window_state.layout_callback,
             close_callback: full_window_state.close_callback,
             renderer_options: full_window_state.renderer_options,
@@ -2104,6 +3541,9 @@ pub struct CallCallbacksResult {
     /// the whole screen. The result is passed to the `relayout()` function, which will only change the
     /// single node that was modified
     pub words_changed: Option<BTreeMap<DomId, BTreeMap<NodeId, AzString>>>,
+    /// Underlines (i.e. spelling errors) that were set on text nodes via
+    /// `CallbackInfo::set_text_underlines`, only requires a new display list
+    pub text_underlines_changed: Option<BTreeMap<DomId, BTreeMap<NodeId, TextUnderlineVec>>>,
     /// A callback can "exchange" and image for a new one without requiring a new display list to be
     /// rebuilt. This is important for animated images, especially video.
     pub images_changed: Option<BTreeMap<DomId, BTreeMap<NodeId, (ImageRef, UpdateImageType)>>>,
@@ -2112,6 +3552,11 @@ pub struct CallCallbacksResult {
     /// If the focus target changes in the callbacks, the function will automatically
     /// restyle the DOM and set the new focus target
     pub css_properties_changed: Option<BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>>,
+    /// Ids and classes that were changed in callbacks (`CallbackInfo::add_class`, etc.),
+    /// only the changed nodes and their children are restyled
+    pub ids_and_classes_changed: Option<BTreeMap<DomId, BTreeMap<NodeId, IdOrClassVec>>>,
+    /// States (`:disabled`, `:checked`, ...) that were set in callbacks
+    pub node_state_flags_changed: Option<BTreeMap<DomId, BTreeMap<NodeId, BTreeMap<NodeStateFlag, bool>>>>,
     /// If the callbacks have scrolled any nodes, the new scroll position will be stored here
     pub nodes_scrolled_in_callbacks:
         Option<BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, LogicalPosition>>>,
@@ -2127,10 +3572,63 @@ pub struct CallCallbacksResult {
     pub threads_removed: Option<FastBTreeSet<ThreadId>>,
     /// Windows that were created in the callbacks
     pub windows_created: Vec<WindowCreateOptions>,
+    /// Texts that should be spoken by the screen reader, see `CallbackInfo::announce()`
+    pub announcements: Vec<Announcement>,
+    /// Tactile feedback that should be played, see `CallbackInfo::trigger_haptic()`
+    pub haptic_feedback: Vec<HapticKind>,
+    /// Callbacks that should run before the next frame, see `CallbackInfo::request_animation_frame()`
+    pub animation_frame_callbacks: Vec<AnimationFrameCallback>,
+    /// Subtrees that should be cross-faded, see `CallbackInfo::crossfade_subtree()`
+    pub crossfades: Vec<SubtreeCrossfade>,
+    /// Subtrees that should be recorded, see `CallbackInfo::render_subtree_to_frames()`
+    pub subtree_recordings: Vec<SubtreeRecording>,
     /// Whether the cursor changed in the callbacks
     pub cursor_changed: bool,
 }
 
+/// How urgently a screen reader should speak an `Announcement`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum Politeness {
+    /// Spoken after the screen reader finishes the current speech
+    /// (i.e. "3 results found"), same as `aria-live="polite"`
+    Polite,
+    /// Interrupts the current speech (i.e. error messages),
+    /// same as `aria-live="assertive"`
+    Assertive,
+}
+
+/// Kind of tactile feedback, see `CallbackInfo::trigger_haptic()`
+///
+/// Modeled after the iOS feedback generators, platforms without an
+/// equivalent pattern use the closest available one
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum HapticKind {
+    /// The selected value changed (i.e. a picker or slider moved to the next value)
+    Selection,
+    /// Collision of small UI elements (i.e. an item snapped into place)
+    LightImpact,
+    /// Collision of medium-sized UI elements
+    MediumImpact,
+    /// Collision of large UI elements (i.e. a drawer hit the end of its track)
+    HeavyImpact,
+    /// A task or action completed successfully
+    Success,
+    /// A task or action produced a warning
+    Warning,
+    /// A task or action failed
+    Error,
+}
+
+/// Text that should be spoken by the screen reader, independent of the focused node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct Announcement {
+    pub text: AzString,
+    pub politeness: Politeness,
+}
+
 impl CallCallbacksResult {
     pub fn cursor_changed(&self) -> bool {
         self.cursor_changed
@@ -2153,6 +3651,12 @@ pub struct WindowFlags {
     pub is_visible: bool,
     /// Is the window always on top?
     pub is_always_on_top: bool,
+    /// If enabled, mouse and touch input passes through the window to the windows
+    /// below it (i.e. for overlay HUDs or on-screen annotations). Default: false
+    pub is_click_through: bool,
+    /// Tool windows are not shown in the taskbar / window switcher and use a
+    /// thinner title bar (i.e. for floating palettes). Default: false
+    pub is_tool_window: bool,
     /// Whether the window is resizable
     pub is_resizable: bool,
     /// Whether the window has focus or not (mutating this will request user attention)
@@ -2166,6 +3670,19 @@ pub struct WindowFlags {
     pub smooth_scroll_enabled: bool,
     /// Is automatic TAB switching supported?
     pub autotab_enabled: bool,
+    /// If enabled, the arrow keys move the focus to the nearest focusable node
+    /// in that direction (TV / kiosk-style navigation). Callbacks on the focused
+    /// node can still handle the arrow keys themselves by stopping the propagation
+    /// of the `VirtualKeyDown` event. Default: false
+    pub spatial_navigation_enabled: bool,
+    /// Set by the operating system when the user is logging out or the system is
+    /// shutting down (emits `On::SessionEnding`). A callback can reset this to `false`
+    /// to ask the OS to delay the shutdown, i.e. to save unsaved documents first.
+    /// Only set on Windows, Linux and macOS never emit `On::SessionEnding` yet.
+    pub is_session_ending: bool,
+    /// Monitor and mode used when `frame` is `WindowFrame::Fullscreen`,
+    /// see `WindowState::set_fullscreen`
+    pub fullscreen_mode: FullscreenMode,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
@@ -2177,6 +3694,26 @@ pub enum WindowFrame {
     Fullscreen,
 }
 
+/// How a window covers the monitor in fullscreen mode. The `usize` is the
+/// `Monitor::id` of the target monitor (see `App::get_monitors`) - if no monitor
+/// with that id exists, the monitor the window is currently on is used.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[repr(C, u8)]
+pub enum FullscreenMode {
+    /// Borderless window that covers the entire monitor,
+    /// the video mode of the monitor stays unchanged (fast switching)
+    Borderless(usize),
+    /// Changes the video mode of the monitor to the size of the window
+    /// (useful for games / video playback on low-end hardware)
+    Exclusive(usize),
+}
+
+impl Default for FullscreenMode {
+    fn default() -> Self {
+        FullscreenMode::Borderless(0)
+    }
+}
+
 impl Default for WindowFlags {
     fn default() -> Self {
         Self {
@@ -2185,12 +3722,17 @@ impl Default for WindowFlags {
             has_decorations: true,
             is_visible: true,
             is_always_on_top: false,
+            is_click_through: false,
+            is_tool_window: false,
             is_resizable: true,
             has_focus: true,
             has_extended_window_frame: false,
             has_blur_behind_window: false,
             smooth_scroll_enabled: true,
             autotab_enabled: true,
+            spatial_navigation_enabled: false,
+            is_session_ending: false,
+            fullscreen_mode: FullscreenMode::default(),
         }
     }
 }
@@ -2469,6 +4011,13 @@ impl WindowState {
     pub fn get_hidpi_factor(&self) -> f32 {
         self.size.get_hidpi_factor()
     }
+
+    /// Puts the window into fullscreen mode on the given monitor. Set
+    /// `flags.frame` back to `WindowFrame::Normal` to leave fullscreen mode.
+    pub fn set_fullscreen(&mut self, mode: FullscreenMode) {
+        self.flags.frame = WindowFrame::Fullscreen;
+        self.flags.fullscreen_mode = mode;
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2549,6 +4098,19 @@ pub struct WindowSize {
     pub min_dimensions: OptionLogicalSize,
     /// Maximum dimensions of the window
     pub max_dimensions: OptionLogicalSize,
+    /// If set, the window can't be resized smaller than the min-content
+    /// size of the UI (in addition to the `min_dimensions`)
+    pub min_dimensions_from_content: bool,
+    /// If set, the window is resized in steps of the given size (i.e. the cell
+    /// size of a terminal), starting from the `min_dimensions`
+    pub resize_increments: OptionLogicalSize,
+    /// If set, interactive resizing keeps the ratio of `width / height`
+    /// of the window (i.e. for video players)
+    pub aspect_ratio_lock: OptionF32,
+    /// UI zoom factor (default: 1.0), multiplied with the DPI scale factor: the UI is
+    /// laid out and rendered at the higher resolution instead of scaling up the
+    /// rendered image. Use `set_zoom()` to keep the physical size of the window.
+    pub zoom: f32,
 }
 
 impl WindowSize {
@@ -2569,7 +4131,18 @@ impl WindowSize {
     }
 
     pub fn get_hidpi_factor(&self) -> f32 {
-        self.dpi as f32 / 96.0
+        self.dpi as f32 / 96.0 * self.zoom
+    }
+
+    /// Sets the UI zoom factor (i.e. for a ctrl + scroll zoom) and adjusts
+    /// the logical `dimensions`, so that the physical size stays the same
+    pub fn set_zoom(&mut self, zoom: f32) {
+        if !(zoom > 0.0) {
+            return;
+        }
+        let physical_size = self.get_physical_size();
+        self.zoom = zoom;
+        self.dimensions = physical_size.to_logical(self.get_hidpi_factor());
     }
 }
 
@@ -2581,6 +4154,10 @@ impl Default for WindowSize {
             dpi: 96,
             min_dimensions: None.into(),
             max_dimensions: None.into(),
+            min_dimensions_from_content: false,
+            resize_increments: None.into(),
+            aspect_ratio_lock: None.into(),
+            zoom: 1.0,
         }
     }
 }
@@ -2607,8 +4184,76 @@ pub struct WindowCreateOptions {
     /// Optional callback to run when the window has been created (runs only once on startup)
     pub create_callback: OptionCallback,
     /// If set to true, will hot-reload the UI every 200ms, useful in combination with `StyledDom::from_file()`
-    /// to hot-reload the UI from a file while developing.
+    /// to hot-reload the UI from a file while developing. Supported on Windows and X11, on macOS
+    /// `App::run` fails with an error.
     pub hot_reload: bool,
+    /// Window-level keyboard shortcuts, evaluated before the callbacks of the focused node.
+    /// Shortcuts can be added / removed at runtime with `CallbackInfo::add_shortcut()`.
+    /// Currently only supported on Windows, ignored (with a warning) on other platforms.
+    pub shortcuts: ShortcutMap,
+    /// If set, the window is created as a child window / view inside of the given
+    /// foreign window (i.e. the editor window of an audio plugin or a tool panel of
+    /// another application). The child window has no decorations, is positioned
+    /// relative to the parent and is not shown in the taskbar.
+    pub parent_handle: OptionRawWindowHandle,
+    /// Optional data that belongs only to this window (i.e. the document of an editor
+    /// window), accessible from the callbacks of this window via `CallbackInfo::get_window_data()`
+    /// in addition to the global application data
+    pub window_data: OptionRefAny,
+    /// Translucent material behind the window contents (blur-behind, Mica, Acrylic).
+    /// The effect is only visible where the UI is transparent, unsupported effects
+    /// fall back to `Blur` or are ignored
+    pub background_effect: WindowBackgroundEffect,
+    /// If set, the window fades in from fully transparent to `state.opacity`
+    /// over the given duration when it is shown for the first time, so that
+    /// the window doesn't flash a blank frame before the first render
+    pub fade_in: OptionDuration,
+    /// What the window shows before the first frame has been rendered
+    pub first_frame: FirstFrameMode,
+    /// Overlay mode, minimum thumb length, hit area and auto-hiding of the built-in scrollbars
+    pub scrollbars: ScrollbarConfig,
+}
+
+/// What a window shows between being mapped and presenting the first
+/// rendered frame, see `WindowCreateOptions::first_frame`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Hash, Ord, Eq)]
+#[repr(C, u8)]
+pub enum FirstFrameMode {
+    /// Show the window immediately, the window may flash blank (default)
+    ShowImmediately,
+    /// Keep the window invisible until the first frame has been presented
+    /// (DWM cloaking on Windows, `_NET_WM_WINDOW_OPACITY` on X11)
+    HideUntilRendered,
+    /// Show the window immediately, filled with the color until the first frame is presented
+    SplashColor(ColorU),
+}
+
+impl Default for FirstFrameMode {
+    fn default() -> Self {
+        FirstFrameMode::ShowImmediately
+    }
+}
+
+/// Translucent backdrop of a window, see `WindowCreateOptions::background_effect`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Hash, Ord, Eq)]
+#[repr(C)]
+pub enum WindowBackgroundEffect {
+    /// Opaque window background (default)
+    None,
+    /// Blurs the content behind the window (DWM blur-behind on Windows, KWin blur on X11)
+    Blur,
+    /// Mica material of the main window (Windows 11, falls back to `Blur`)
+    Mica,
+    /// Mica variant for tabbed windows (Windows 11, falls back to `Blur`)
+    MicaAlt,
+    /// Acrylic material for transient windows such as popups (Windows 11, falls back to `Blur`)
+    Acrylic,
+}
+
+impl Default for WindowBackgroundEffect {
+    fn default() -> Self {
+        WindowBackgroundEffect::None
+    }
 }
 
 impl Default for WindowCreateOptions {
@@ -2620,6 +4265,13 @@ impl Default for WindowCreateOptions {
             theme: OptionWindowTheme::None,
             create_callback: OptionCallback::None,
             hot_reload: false,
+            shortcuts: ShortcutMap::default(),
+            parent_handle: OptionRawWindowHandle::None,
+            window_data: OptionRefAny::None,
+            background_effect: WindowBackgroundEffect::None,
+            fade_in: OptionDuration::None,
+            first_frame: FirstFrameMode::ShowImmediately,
+            scrollbars: ScrollbarConfig::default(),
         }
     }
 }
@@ -2631,6 +4283,55 @@ impl WindowCreateOptions {
             ..WindowCreateOptions::default()
         }
     }
+
+    /// Embeds the window as a child window inside of a foreign window,
+    /// see `WindowCreateOptions::parent_handle`
+    pub fn with_parent_handle(self, parent_handle: RawWindowHandle) -> Self {
+        Self {
+            parent_handle: OptionRawWindowHandle::Some(parent_handle),
+            ..self
+        }
+    }
+
+    /// Attaches window-local data to the window, see `WindowCreateOptions::window_data`
+    pub fn with_window_data(self, window_data: RefAny) -> Self {
+        Self {
+            window_data: OptionRefAny::Some(window_data),
+            ..self
+        }
+    }
+
+    /// Sets the translucent backdrop of the window, see `WindowCreateOptions::background_effect`
+    pub fn with_background_effect(self, background_effect: WindowBackgroundEffect) -> Self {
+        Self {
+            background_effect,
+            ..self
+        }
+    }
+
+    /// Fades the window in when it is shown, see `WindowCreateOptions::fade_in`
+    pub fn with_fade_in(self, duration: crate::task::Duration) -> Self {
+        Self {
+            fade_in: OptionDuration::Some(duration),
+            ..self
+        }
+    }
+
+    /// Sets what the window shows before the first frame, see `WindowCreateOptions::first_frame`
+    pub fn with_first_frame(self, first_frame: FirstFrameMode) -> Self {
+        Self {
+            first_frame,
+            ..self
+        }
+    }
+
+    /// Configures the built-in scrollbars, see `WindowCreateOptions::scrollbars`
+    pub fn with_scrollbars(self, scrollbars: ScrollbarConfig) -> Self {
+        Self {
+            scrollbars,
+            ..self
+        }
+    }
 }
 
 #[repr(C)]
@@ -3283,6 +4984,296 @@ pub enum VirtualKeyCode {
     Cut,
 }
 
+impl VirtualKeyCode {
+    /// Parses the name of the key, the inverse of the `Debug` representation (i.e. "Key1", "LControl")
+    pub fn from_name(name: &str) -> Option<Self> {
+        use self::VirtualKeyCode::*;
+        match name {
+            "Key1" => Some(Key1),
+            "Key2" => Some(Key2),
+            "Key3" => Some(Key3),
+            "Key4" => Some(Key4),
+            "Key5" => Some(Key5),
+            "Key6" => Some(Key6),
+            "Key7" => Some(Key7),
+            "Key8" => Some(Key8),
+            "Key9" => Some(Key9),
+            "Key0" => Some(Key0),
+            "A" => Some(A),
+            "B" => Some(B),
+            "C" => Some(C),
+            "D" => Some(D),
+            "E" => Some(E),
+            "F" => Some(F),
+            "G" => Some(G),
+            "H" => Some(H),
+            "I" => Some(I),
+            "J" => Some(J),
+            "K" => Some(K),
+            "L" => Some(L),
+            "M" => Some(M),
+            "N" => Some(N),
+            "O" => Some(O),
+            "P" => Some(P),
+            "Q" => Some(Q),
+            "R" => Some(R),
+            "S" => Some(S),
+            "T" => Some(T),
+            "U" => Some(U),
+            "V" => Some(V),
+            "W" => Some(W),
+            "X" => Some(X),
+            "Y" => Some(Y),
+            "Z" => Some(Z),
+            "Escape" => Some(Escape),
+            "F1" => Some(F1),
+            "F2" => Some(F2),
+            "F3" => Some(F3),
+            "F4" => Some(F4),
+            "F5" => Some(F5),
+            "F6" => Some(F6),
+            "F7" => Some(F7),
+            "F8" => Some(F8),
+            "F9" => Some(F9),
+            "F10" => Some(F10),
+            "F11" => Some(F11),
+            "F12" => Some(F12),
+            "F13" => Some(F13),
+            "F14" => Some(F14),
+            "F15" => Some(F15),
+            "F16" => Some(F16),
+            "F17" => Some(F17),
+            "F18" => Some(F18),
+            "F19" => Some(F19),
+            "F20" => Some(F20),
+            "F21" => Some(F21),
+            "F22" => Some(F22),
+            "F23" => Some(F23),
+            "F24" => Some(F24),
+            "Snapshot" => Some(Snapshot),
+            "Scroll" => Some(Scroll),
+            "Pause" => Some(Pause),
+            "Insert" => Some(Insert),
+            "Home" => Some(Home),
+            "Delete" => Some(Delete),
+            "End" => Some(End),
+            "PageDown" => Some(PageDown),
+            "PageUp" => Some(PageUp),
+            "Left" => Some(Left),
+            "Up" => Some(Up),
+            "Right" => Some(Right),
+            "Down" => Some(Down),
+            "Back" => Some(Back),
+            "Return" => Some(Return),
+            "Space" => Some(Space),
+            "Compose" => Some(Compose),
+            "Caret" => Some(Caret),
+            "Numlock" => Some(Numlock),
+            "Numpad0" => Some(Numpad0),
+            "Numpad1" => Some(Numpad1),
+            "Numpad2" => Some(Numpad2),
+            "Numpad3" => Some(Numpad3),
+            "Numpad4" => Some(Numpad4),
+            "Numpad5" => Some(Numpad5),
+            "Numpad6" => Some(Numpad6),
+            "Numpad7" => Some(Numpad7),
+            "Numpad8" => Some(Numpad8),
+            "Numpad9" => Some(Numpad9),
+            "NumpadAdd" => Some(NumpadAdd),
+            "NumpadDivide" => Some(NumpadDivide),
+            "NumpadDecimal" => Some(NumpadDecimal),
+            "NumpadComma" => Some(NumpadComma),
+            "NumpadEnter" => Some(NumpadEnter),
+            "NumpadEquals" => Some(NumpadEquals),
+            "NumpadMultiply" => Some(NumpadMultiply),
+            "NumpadSubtract" => Some(NumpadSubtract),
+            "AbntC1" => Some(AbntC1),
+            "AbntC2" => Some(AbntC2),
+            "Apostrophe" => Some(Apostrophe),
+            "Apps" => Some(Apps),
+            "Asterisk" => Some(Asterisk),
+            "At" => Some(At),
+            "Ax" => Some(Ax),
+            "Backslash" => Some(Backslash),
+            "Calculator" => Some(Calculator),
+            "Capital" => Some(Capital),
+            "Colon" => Some(Colon),
+            "Comma" => Some(Comma),
+            "Convert" => Some(Convert),
+            "Equals" => Some(Equals),
+            "Grave" => Some(Grave),
+            "Kana" => Some(Kana),
+            "Kanji" => Some(Kanji),
+            "LAlt" => Some(LAlt),
+            "LBracket" => Some(LBracket),
+            "LControl" => Some(LControl),
+            "LShift" => Some(LShift),
+            "LWin" => Some(LWin),
+            "Mail" => Some(Mail),
+            "MediaSelect" => Some(MediaSelect),
+            "MediaStop" => Some(MediaStop),
+            "Minus" => Some(Minus),
+            "Mute" => Some(Mute),
+            "MyComputer" => Some(MyComputer),
+            "NavigateForward" => Some(NavigateForward),
+            "NavigateBackward" => Some(NavigateBackward),
+            "NextTrack" => Some(NextTrack),
+            "NoConvert" => Some(NoConvert),
+            "OEM102" => Some(OEM102),
+            "Period" => Some(Period),
+            "PlayPause" => Some(PlayPause),
+            "Plus" => Some(Plus),
+            "Power" => Some(Power),
+            "PrevTrack" => Some(PrevTrack),
+            "RAlt" => Some(RAlt),
+            "RBracket" => Some(RBracket),
+            "RControl" => Some(RControl),
+            "RShift" => Some(RShift),
+            "RWin" => Some(RWin),
+            "Semicolon" => Some(Semicolon),
+            "Slash" => Some(Slash),
+            "Sleep" => Some(Sleep),
+            "Stop" => Some(Stop),
+            "Sysrq" => Some(Sysrq),
+            "Tab" => Some(Tab),
+            "Underline" => Some(Underline),
+            "Unlabeled" => Some(Unlabeled),
+            "VolumeDown" => Some(VolumeDown),
+            "VolumeUp" => Some(VolumeUp),
+            "Wake" => Some(Wake),
+            "WebBack" => Some(WebBack),
+            "WebFavorites" => Some(WebFavorites),
+            "WebForward" => Some(WebForward),
+            "WebHome" => Some(WebHome),
+            "WebRefresh" => Some(WebRefresh),
+            "WebSearch" => Some(WebSearch),
+            "WebStop" => Some(WebStop),
+            "Yen" => Some(Yen),
+            "Copy" => Some(Copy),
+            "Paste" => Some(Paste),
+            "Cut" => Some(Cut),
+            _ => None,
+        }
+    }
+}
+
+/// Mouse button of a `SyntheticEvent::MouseDown` / `SyntheticEvent::MouseUp` event
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SyntheticMouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+/// Platform-independent input event that can be injected into a window as if
+/// it came from the operating system, i.e. to replay the events recorded with
+/// `AppConfig::event_recording`.
+///
+/// Positions and sizes are in logical pixels, so that a recording can be
+/// replayed on a monitor with a different DPI.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SyntheticEvent {
+    MouseMove(LogicalPosition),
+    MouseLeave,
+    MouseDown(SyntheticMouseButton),
+    MouseUp(SyntheticMouseButton),
+    /// Scroll wheel, in lines (positive = scroll up / left)
+    Scroll { x: f32, y: f32 },
+    /// Key was pressed: `scancode` is the platform-specific scancode,
+    /// `key` is the symbolic key (if the platform could map the scancode)
+    KeyDown { scancode: u32, key: OptionVirtualKeyCode },
+    KeyUp { scancode: u32, key: OptionVirtualKeyCode },
+    /// Text input (after the keyboard layout / IME was applied)
+    Char(char),
+    FocusIn,
+    FocusOut,
+    Resize(LogicalSize),
+}
+
+impl SyntheticMouseButton {
+    fn get_name(&self) -> &'static str {
+        match self {
+            SyntheticMouseButton::Left => "left",
+            SyntheticMouseButton::Right => "right",
+            SyntheticMouseButton::Middle => "middle",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "left" => Some(SyntheticMouseButton::Left),
+            "right" => Some(SyntheticMouseButton::Right),
+            "middle" => Some(SyntheticMouseButton::Middle),
+            _ => None,
+        }
+    }
+}
+
+/// Formats the event as a single line of whitespace-separated words,
+/// i.e. `mouse-move 10.5 20` or `key-down 30 A` - can be parsed again with `SyntheticEvent::parse`
+impl core::fmt::Display for SyntheticEvent {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        use self::SyntheticEvent::*;
+        match self {
+            MouseMove(p) => write!(f, "mouse-move {} {}", p.x, p.y),
+            MouseLeave => write!(f, "mouse-leave"),
+            MouseDown(b) => write!(f, "mouse-down {}", b.get_name()),
+            MouseUp(b) => write!(f, "mouse-up {}", b.get_name()),
+            Scroll { x, y } => write!(f, "scroll {} {}", x, y),
+            KeyDown { scancode, key } | KeyUp { scancode, key } => {
+                let name = if let KeyDown { .. } = self { "key-down" } else { "key-up" };
+                match key.into_option() {
+                    Some(k) => write!(f, "{} {} {:?}", name, scancode, k),
+                    None => write!(f, "{} {}", name, scancode),
+                }
+            },
+            // as a code point, so that whitespace doesn't need to be escaped
+            Char(c) => write!(f, "char {}", *c as u32),
+            FocusIn => write!(f, "focus-in"),
+            FocusOut => write!(f, "focus-out"),
+            Resize(s) => write!(f, "resize {} {}", s.width, s.height),
+        }
+    }
+}
+
+impl SyntheticEvent {
+    /// Parses an event in the format of `SyntheticEvent::fmt`
+    pub fn parse(s: &str) -> Option<Self> {
+        use self::SyntheticEvent::*;
+
+        let mut words = s.split_whitespace();
+        let name = words.next()?;
+        let args = words.collect::<Vec<_>>();
+
+        let f32_arg = |i: usize| args.get(i)?.parse::<f32>().ok();
+        let key_args = || -> Option<(u32, OptionVirtualKeyCode)> {
+            let scancode = args.get(0)?.parse::<u32>().ok()?;
+            let key = match args.get(1) {
+                Some(k) => Some(VirtualKeyCode::from_name(k)?),
+                None => None,
+            };
+            Some((scancode, key.into()))
+        };
+
+        let event = match name {
+            "mouse-move" => MouseMove(LogicalPosition::new(f32_arg(0)?, f32_arg(1)?)),
+            "mouse-leave" => MouseLeave,
+            "mouse-down" => MouseDown(SyntheticMouseButton::from_name(args.get(0)?)?),
+            "mouse-up" => MouseUp(SyntheticMouseButton::from_name(args.get(0)?)?),
+            "scroll" => Scroll { x: f32_arg(0)?, y: f32_arg(1)? },
+            "key-down" => { let (scancode, key) = key_args()?; KeyDown { scancode, key } },
+            "key-up" => { let (scancode, key) = key_args()?; KeyUp { scancode, key } },
+            "char" => Char(core::char::from_u32(args.get(0)?.parse::<u32>().ok()?)?),
+            "focus-in" => FocusIn,
+            "focus-out" => FocusOut,
+            "resize" => Resize(LogicalSize::new(f32_arg(0)?, f32_arg(1)?)),
+            _ => return None,
+        };
+
+        Some(event)
+    }
+}
+
 /// 16x16x4 bytes icon
 #[derive(Debug, Clone)]
 #[repr(C)]
@@ -3579,6 +5570,212 @@ impl_option!(
     [Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord]
 );
 
+impl VirtualKeyCodeCombo {
+    /// Returns the keys with left / right modifier keys mapped to the left key,
+    /// sorted and deduplicated (so that `[RControl, S]` equals `[S, LControl]`)
+    pub fn normalized(&self) -> Vec<VirtualKeyCode> {
+        let mut keys = self
+            .keys
+            .iter()
+            .map(|k| normalize_modifier_key(*k))
+            .collect::<Vec<_>>();
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+
+    /// Returns whether the combination is currently pressed: all keys have to be down,
+    /// the last pressed key has to be part of the combination and no other modifier
+    /// key may be held down (i.e. `Ctrl + S` does not match `Ctrl + Shift + S`)
+    pub fn matches(&self, keyboard_state: &KeyboardState) -> bool {
+        let keys = self.normalized();
+
+        let current_key = match keyboard_state.current_virtual_keycode.into_option() {
+            Some(s) => normalize_modifier_key(s),
+            None => return false,
+        };
+
+        if keys.is_empty() || !keys.contains(&current_key) {
+            return false;
+        }
+
+        let pressed = keyboard_state
+            .pressed_virtual_keycodes
+            .iter()
+            .map(|k| normalize_modifier_key(*k))
+            .collect::<Vec<_>>();
+
+        keys.iter().all(|k| pressed.contains(k))
+            && pressed
+                .iter()
+                .filter(|k| is_modifier_key(**k))
+                .all(|k| keys.contains(k))
+    }
+}
+
+fn normalize_modifier_key(key: VirtualKeyCode) -> VirtualKeyCode {
+    use self::VirtualKeyCode::*;
+    match key {
+        RControl => LControl,
+        RShift => LShift,
+        RAlt => LAlt,
+        RWin => LWin,
+        other => other,
+    }
+}
+
+fn is_modifier_key(key: VirtualKeyCode) -> bool {
+    use self::VirtualKeyCode::*;
+    match key {
+        LControl | RControl | LShift | RShift | LAlt | RAlt | LWin | RWin => true,
+        _ => false,
+    }
+}
+
+/// Window-level keyboard shortcut: invokes the callback when the key combination
+/// is pressed, instead of the `VirtualKeyDown` callbacks of the focused node
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+#[repr(C)]
+pub struct Shortcut {
+    /// Key combination, ex. `[LControl, S]` (left / right modifier keys are treated as equal)
+    pub keys: VirtualKeyCodeCombo,
+    pub callback: Callback,
+    pub data: RefAny,
+}
+
+impl Shortcut {
+    pub fn new(keys: VirtualKeyCodeCombo, data: RefAny, callback: CallbackType) -> Self {
+        Self {
+            keys,
+            callback: Callback { cb: callback },
+            data,
+        }
+    }
+}
+
+impl_vec!(Shortcut, ShortcutVec, ShortcutVecDestructor);
+impl_vec_clone!(Shortcut, ShortcutVec, ShortcutVecDestructor);
+impl_vec_mut!(Shortcut, ShortcutVec);
+impl_vec_debug!(Shortcut, ShortcutVec);
+impl_vec_partialeq!(Shortcut, ShortcutVec);
+impl_vec_eq!(Shortcut, ShortcutVec);
+impl_vec_partialord!(Shortcut, ShortcutVec);
+impl_vec_ord!(Shortcut, ShortcutVec);
+impl_vec_hash!(Shortcut, ShortcutVec);
+
+/// Two shortcuts in a `ShortcutMap` that use the same key combination
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+#[repr(C)]
+pub struct ShortcutConflict {
+    /// Key combination that is used by both shortcuts
+    pub keys: VirtualKeyCodeCombo,
+    /// Index of the shortcut that was registered first (this one will be invoked)
+    pub existing: usize,
+    /// Index of the shortcut that is shadowed by the existing one
+    pub conflicting: usize,
+}
+
+impl_option!(
+    ShortcutConflict,
+    OptionShortcutConflict,
+    copy = false,
+    [Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord]
+);
+
+impl_vec!(ShortcutConflict, ShortcutConflictVec, ShortcutConflictVecDestructor);
+impl_vec_clone!(ShortcutConflict, ShortcutConflictVec, ShortcutConflictVecDestructor);
+impl_vec_debug!(ShortcutConflict, ShortcutConflictVec);
+impl_vec_partialeq!(ShortcutConflict, ShortcutConflictVec);
+impl_vec_eq!(ShortcutConflict, ShortcutConflictVec);
+impl_vec_partialord!(ShortcutConflict, ShortcutConflictVec);
+impl_vec_ord!(ShortcutConflict, ShortcutConflictVec);
+impl_vec_hash!(ShortcutConflict, ShortcutConflictVec);
+
+/// List of window-level keyboard shortcuts, see `WindowCreateOptions::shortcuts`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+#[repr(C)]
+pub struct ShortcutMap {
+    pub shortcuts: ShortcutVec,
+}
+
+impl Default for ShortcutMap {
+    fn default() -> Self {
+        Self {
+            shortcuts: ShortcutVec::from_const_slice(&[]),
+        }
+    }
+}
+
+impl ShortcutMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a shortcut to the map. If another shortcut already uses the same key
+    /// combination, the new shortcut is NOT added and the conflict is returned.
+    pub fn add(&mut self, shortcut: Shortcut) -> OptionShortcutConflict {
+        let keys = shortcut.keys.normalized();
+        if let Some(existing) = self
+            .shortcuts
+            .iter()
+            .position(|s| s.keys.normalized() == keys)
+        {
+            return Some(ShortcutConflict {
+                keys: shortcut.keys,
+                existing,
+                conflicting: self.shortcuts.len(),
+            })
+            .into();
+        }
+        self.shortcuts.push(shortcut);
+        None.into()
+    }
+
+    /// Removes the shortcut with the given key combination,
+    /// returns whether a shortcut was removed
+    pub fn remove(&mut self, keys: &VirtualKeyCodeCombo) -> bool {
+        let keys = keys.normalized();
+        match self
+            .shortcuts
+            .iter()
+            .position(|s| s.keys.normalized() == keys)
+        {
+            Some(index) => {
+                self.shortcuts.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns all pairs of shortcuts with the same key combination (only possible
+    /// if the `ShortcutMap` was constructed directly instead of using `add()`)
+    pub fn get_conflicts(&self) -> ShortcutConflictVec {
+        let normalized = self
+            .shortcuts
+            .iter()
+            .map(|s| s.keys.normalized())
+            .collect::<Vec<_>>();
+
+        let mut conflicts = Vec::new();
+        for (conflicting, keys) in normalized.iter().enumerate() {
+            if let Some(existing) = normalized[..conflicting].iter().position(|k| k == keys) {
+                conflicts.push(ShortcutConflict {
+                    keys: self.shortcuts.as_ref()[conflicting].keys.clone(),
+                    existing,
+                    conflicting,
+                });
+            }
+        }
+        conflicts.into()
+    }
+
+    /// Returns the first shortcut whose key combination is currently pressed
+    pub fn get_matching_shortcut(&self, keyboard_state: &KeyboardState) -> Option<&Shortcut> {
+        self.shortcuts.iter().find(|s| s.keys.matches(keyboard_state))
+    }
+}
+
 /// Menu callback: What data / function pointer should
 /// be called when the menu item is clicked?
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
@@ -28,7 +28,7 @@
 //!      current_window_state.hovered_nodes = hit_test.hovered_nodes;
 //!
 //!      let nodes_to_check = NodesToCheck::new(&hit_test, &events);
-//!      let callbacks = CallbacksOfHitTest::new(&nodes_to_check, &events, &window.layout_results);
+//!      let callbacks = CallbacksOfHitTest::new(&nodes_to_check, &events, &window.layout_results, &window.shortcuts, &current_window_state);
 //!      let callback_result = call_callbacks(&callbacks, &hit_test);
 //!
 //!      if callbacks.update_screen = Update::Relayout {
@@ -66,13 +66,21 @@
 use crate::gl::OptionGlContextPtr;
 use crate::{
     app_resources::{ImageCache, RendererResources},
-    callbacks::{DocumentId, DomNodeId, HitTestItem, ScrollPosition, Update},
-    dom::{EventFilter, FocusEventFilter, HoverEventFilter, NotEventFilter, WindowEventFilter},
+    callbacks::{
+        DocumentId, DomNodeId, FocusTarget, HitTestItem, OptionRefAny, ScrollPosition, Update,
+    },
+    dom::{
+        EventFilter, FocusDirection, FocusEventFilter, HoverEventFilter, IdOrClassVec,
+        NodeStateFlag, NotEventFilter, WindowEventFilter,
+    },
     id_tree::NodeId,
     styled_dom::{ChangedCssProperty, DomId, NodeHierarchyItemId},
     task::ExternalSystemCallbacks,
     ui_solver::{GpuEventChanges, LayoutResult, RelayoutChanges},
-    window::{CallCallbacksResult, FullHitTest, FullWindowState, RawWindowHandle, ScrollStates},
+    window::{
+        CallCallbacksResult, FullHitTest, FullWindowState, RawWindowHandle, ScrollStates,
+        Shortcut, ShortcutMap, TouchState,
+    },
     FastBTreeSet, FastHashMap,
 };
 use alloc::boxed::Box;
@@ -82,6 +90,9 @@ use alloc::vec::Vec;
 use azul_css::{AzString, CssProperty, LayoutPoint, LayoutRect, LayoutSize};
 use rust_fontconfig::FcFontCache;
 
+/// Hit nodes of each touch, indexed by the touch ID
+pub type TouchHitNodeIds = BTreeMap<u64, BTreeMap<DomId, BTreeMap<NodeId, HitTestItem>>>;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Events {
     pub window_events: Vec<WindowEventFilter>,
@@ -94,6 +105,14 @@ pub struct Events {
     pub event_was_mouse_down: bool,
     pub event_was_mouse_leave: bool,
     pub event_was_mouse_release: bool,
+    /// Hit nodes of each touch in the previous frame, indexed by the touch ID
+    pub old_touch_hit_node_ids: TouchHitNodeIds,
+    /// IDs of the touches that started in this frame
+    pub touches_started: Vec<u64>,
+    /// IDs of the touches that moved in this frame
+    pub touches_moved: Vec<u64>,
+    /// IDs of the touches that ended in this frame
+    pub touches_ended: Vec<u64>,
 }
 
 impl Events {
@@ -142,10 +161,25 @@ impl Events {
             })
             .unwrap_or_default();
 
+        let old_touch_hit_node_ids = previous_window_state
+            .as_ref()
+            .map(|f| get_touch_hit_node_ids(&f.last_hit_test))
+            .unwrap_or_default();
+
+        let (touches_started, touches_moved, touches_ended) = match previous_window_state.as_ref() {
+            Some(prev_state) => {
+                get_touch_changes(&current_window_state.touch_state, &prev_state.touch_state)
+            }
+            None => (Vec::new(), Vec::new(), Vec::new()),
+        };
+
         if let Some(prev_state) = previous_window_state.as_ref() {
             if prev_state.theme != current_window_state.theme {
                 current_window_events.push(WindowEventFilter::ThemeChanged);
             }
+            if prev_state.touch_state.input_modality != current_window_state.touch_state.input_modality {
+                current_window_events.push(WindowEventFilter::InputModalityChanged);
+            }
             if current_window_state.last_hit_test.hovered_nodes
                 != prev_state.last_hit_test.hovered_nodes.clone()
             {
@@ -171,6 +205,10 @@ impl Events {
             previous_window_state_mouse_is_down,
             old_focus_node,
             old_hit_node_ids,
+            old_touch_hit_node_ids,
+            touches_started,
+            touches_moved,
+            touches_ended,
         }
     }
 
@@ -204,6 +242,12 @@ pub struct NodesToCheck {
     pub old_focus_node: Option<DomNodeId>,
     pub new_focus_node: Option<DomNodeId>,
     pub current_window_state_mouse_is_down: bool,
+    pub new_touch_hit_node_ids: TouchHitNodeIds,
+    pub old_touch_hit_node_ids: TouchHitNodeIds,
+    /// Nodes that are touched by at least one touch, but were not touched before
+    pub ontouchenter_nodes: BTreeMap<DomId, BTreeSet<NodeId>>,
+    /// Nodes that were touched before, but are not touched by any touch anymore
+    pub ontouchleave_nodes: BTreeMap<DomId, BTreeSet<NodeId>>,
 }
 
 impl NodesToCheck {
@@ -220,6 +264,8 @@ impl NodesToCheck {
             .map(|(k, v)| (k.clone(), v.regular_hit_test_nodes.clone()))
             .collect::<BTreeMap<_, _>>();
 
+        let new_touch_hit_node_ids = get_touch_hit_node_ids(hit_test);
+
         Self {
             new_hit_node_ids: new_hit_node_ids.clone(),
             old_hit_node_ids: BTreeMap::new(),
@@ -228,6 +274,10 @@ impl NodesToCheck {
             old_focus_node: old_focus_node,
             new_focus_node: old_focus_node,
             current_window_state_mouse_is_down: mouse_down,
+            ontouchenter_nodes: get_touched_nodes(&new_touch_hit_node_ids),
+            ontouchleave_nodes: BTreeMap::new(),
+            new_touch_hit_node_ids,
+            old_touch_hit_node_ids: BTreeMap::new(),
         }
     }
 
@@ -300,6 +350,12 @@ impl NodesToCheck {
             })
             .collect::<BTreeMap<_, _>>();
 
+        // Every touch is tracked separately, a node stays :active as long
+        // as at least one touch is still on top of it
+        let new_touch_hit_node_ids = get_touch_hit_node_ids(hit_test);
+        let new_touched_nodes = get_touched_nodes(&new_touch_hit_node_ids);
+        let old_touched_nodes = get_touched_nodes(&events.old_touch_hit_node_ids);
+
         NodesToCheck {
             new_hit_node_ids,
             old_hit_node_ids: events.old_hit_node_ids.clone(),
@@ -308,6 +364,10 @@ impl NodesToCheck {
             old_focus_node: events.old_focus_node.clone(),
             new_focus_node: new_focus_node,
             current_window_state_mouse_is_down: events.current_window_state_mouse_is_down,
+            ontouchenter_nodes: subtract_node_sets(&new_touched_nodes, &old_touched_nodes),
+            ontouchleave_nodes: subtract_node_sets(&old_touched_nodes, &new_touched_nodes),
+            new_touch_hit_node_ids,
+            old_touch_hit_node_ids: events.old_touch_hit_node_ids.clone(),
         }
     }
 
@@ -320,11 +380,18 @@ impl NodesToCheck {
             old_focus_node: old_focus_node,
             new_focus_node: old_focus_node,
             current_window_state_mouse_is_down: mouse_down,
+            new_touch_hit_node_ids: BTreeMap::new(),
+            old_touch_hit_node_ids: BTreeMap::new(),
+            ontouchenter_nodes: BTreeMap::new(),
+            ontouchleave_nodes: BTreeMap::new(),
         }
     }
 
     pub fn needs_hover_active_restyle(&self) -> bool {
-        !(self.onmouseenter_nodes.is_empty() && self.onmouseleave_nodes.is_empty())
+        !(self.onmouseenter_nodes.is_empty()
+            && self.onmouseleave_nodes.is_empty()
+            && self.ontouchenter_nodes.is_empty()
+            && self.ontouchleave_nodes.is_empty())
     }
 
     pub fn needs_focus_result(&self) -> bool {
@@ -332,6 +399,53 @@ impl NodesToCheck {
     }
 }
 
+fn get_touch_hit_node_ids(hit_test: &FullHitTest) -> TouchHitNodeIds {
+    hit_test
+        .touch_hit_tests
+        .iter()
+        .map(|(touch_id, hit_test)| {
+            let hit_nodes = hit_test
+                .iter()
+                .map(|(dom_id, ht)| (*dom_id, ht.regular_hit_test_nodes.clone()))
+                .collect();
+            (*touch_id, hit_nodes)
+        })
+        .collect()
+}
+
+/// Nodes that are hit by at least one touch
+fn get_touched_nodes(touch_hit_node_ids: &TouchHitNodeIds) -> BTreeMap<DomId, BTreeSet<NodeId>> {
+    let mut touched_nodes = BTreeMap::<DomId, BTreeSet<NodeId>>::new();
+    for hit_nodes in touch_hit_node_ids.values() {
+        for (dom_id, nodes) in hit_nodes.iter() {
+            touched_nodes
+                .entry(*dom_id)
+                .or_default()
+                .extend(nodes.keys().copied());
+        }
+    }
+    touched_nodes
+}
+
+/// Returns the nodes in `a` that are not in `b`
+fn subtract_node_sets(
+    a: &BTreeMap<DomId, BTreeSet<NodeId>>,
+    b: &BTreeMap<DomId, BTreeSet<NodeId>>,
+) -> BTreeMap<DomId, BTreeSet<NodeId>> {
+    let empty = BTreeSet::new();
+    a.iter()
+        .filter_map(|(dom_id, nodes)| {
+            let other = b.get(dom_id).unwrap_or(&empty);
+            let diff = nodes.difference(other).copied().collect::<BTreeSet<_>>();
+            if diff.is_empty() {
+                None
+            } else {
+                Some((*dom_id, diff))
+            }
+        })
+        .collect()
+}
+
 pub type RestyleNodes = BTreeMap<NodeId, Vec<ChangedCssProperty>>;
 pub type RelayoutNodes = BTreeMap<NodeId, Vec<ChangedCssProperty>>;
 pub type RelayoutWords = BTreeMap<NodeId, AzString>;
@@ -382,6 +496,8 @@ impl StyleAndLayoutChanges {
         window_size: LayoutSize,
         document_id: &DocumentId,
         css_changes: Option<&BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>>,
+        ids_and_classes_changes: Option<&BTreeMap<DomId, BTreeMap<NodeId, IdOrClassVec>>>,
+        node_state_flag_changes: Option<&BTreeMap<DomId, BTreeMap<NodeId, BTreeMap<NodeStateFlag, bool>>>>,
         word_changes: Option<&BTreeMap<DomId, BTreeMap<NodeId, AzString>>>,
         callbacks_new_focus: &Option<Option<DomNodeId>>,
         relayout_cb: RelayoutFn,
@@ -392,18 +508,6 @@ impl StyleAndLayoutChanges {
         let mut layout_changes = None;
 
         let is_mouse_down = nodes.current_window_state_mouse_is_down;
-        let nodes_that_changed_text_content = word_changes.and_then(|word_changes| {
-            if word_changes.is_empty() {
-                None
-            } else {
-                Some(
-                    word_changes
-                        .iter()
-                        .map(|(dom_id, m)| (*dom_id, m.keys().cloned().collect()))
-                        .collect(),
-                )
-            }
-        });
 
         macro_rules! insert_props {
             ($dom_id:expr, $prop_map:expr) => {{
@@ -433,16 +537,38 @@ impl StyleAndLayoutChanges {
             }};
         }
 
+        // the mouse cursor and every touch have their own hit nodes: a node stays
+        // :hover / :active as long as either the mouse or any touch is on top of it
+        let touched_nodes = get_touched_nodes(&nodes.new_touch_hit_node_ids);
+        let is_touched = |dom_id: &DomId, node_id: &NodeId| {
+            touched_nodes
+                .get(dom_id)
+                .map(|n| n.contains(node_id))
+                .unwrap_or(false)
+        };
+        let is_mouse_hovered = |dom_id: &DomId, node_id: &NodeId| {
+            nodes
+                .new_hit_node_ids
+                .get(dom_id)
+                .map(|n| n.contains_key(node_id))
+                .unwrap_or(false)
+        };
+
         for (dom_id, onmouseenter_nodes) in nodes.onmouseenter_nodes.iter() {
             let layout_result = &mut layout_results[dom_id.inner];
 
             let keys = onmouseenter_nodes.keys().copied().collect::<Vec<_>>();
+            let active_keys = keys
+                .iter()
+                .copied()
+                .filter(|node_id| is_mouse_down || !is_touched(dom_id, node_id))
+                .collect::<Vec<_>>();
             let onmouseenter_nodes_hover_restyle_props = layout_result
                 .styled_dom
                 .restyle_nodes_hover(&keys, /* currently_hovered = */ true);
             let onmouseleave_nodes_active_restyle_props = layout_result
                 .styled_dom
-                .restyle_nodes_active(&keys, /* currently_active = */ is_mouse_down);
+                .restyle_nodes_active(&active_keys, /* currently_active = */ is_mouse_down);
 
             insert_props!(*dom_id, onmouseenter_nodes_hover_restyle_props);
             insert_props!(*dom_id, onmouseleave_nodes_active_restyle_props);
@@ -450,7 +576,11 @@ impl StyleAndLayoutChanges {
 
         for (dom_id, onmouseleave_nodes) in nodes.onmouseleave_nodes.iter() {
             let layout_result = &mut layout_results[dom_id.inner];
-            let keys = onmouseleave_nodes.keys().copied().collect::<Vec<_>>();
+            let keys = onmouseleave_nodes
+                .keys()
+                .copied()
+                .filter(|node_id| !is_touched(dom_id, node_id))
+                .collect::<Vec<_>>();
             let onmouseleave_nodes_hover_restyle_props = layout_result
                 .styled_dom
                 .restyle_nodes_hover(&keys, /* currently_hovered = */ false);
@@ -462,6 +592,43 @@ impl StyleAndLayoutChanges {
             insert_props!(*dom_id, onmouseleave_nodes_active_restyle_props);
         }
 
+        for (dom_id, ontouchenter_nodes) in nodes.ontouchenter_nodes.iter() {
+            let layout_result = &mut layout_results[dom_id.inner];
+            let keys = ontouchenter_nodes.iter().copied().collect::<Vec<_>>();
+            let ontouchenter_nodes_hover_restyle_props = layout_result
+                .styled_dom
+                .restyle_nodes_hover(&keys, /* currently_hovered = */ true);
+            let ontouchenter_nodes_active_restyle_props = layout_result
+                .styled_dom
+                .restyle_nodes_active(&keys, /* currently_active = */ true);
+
+            insert_props!(*dom_id, ontouchenter_nodes_hover_restyle_props);
+            insert_props!(*dom_id, ontouchenter_nodes_active_restyle_props);
+        }
+
+        for (dom_id, ontouchleave_nodes) in nodes.ontouchleave_nodes.iter() {
+            let layout_result = &mut layout_results[dom_id.inner];
+            let hover_keys = ontouchleave_nodes
+                .iter()
+                .copied()
+                .filter(|node_id| !is_mouse_hovered(dom_id, node_id))
+                .collect::<Vec<_>>();
+            let active_keys = ontouchleave_nodes
+                .iter()
+                .copied()
+                .filter(|node_id| !(is_mouse_down && is_mouse_hovered(dom_id, node_id)))
+                .collect::<Vec<_>>();
+            let ontouchleave_nodes_hover_restyle_props = layout_result
+                .styled_dom
+                .restyle_nodes_hover(&hover_keys, /* currently_hovered = */ false);
+            let ontouchleave_nodes_active_restyle_props = layout_result
+                .styled_dom
+                .restyle_nodes_active(&active_keys, /* currently_active = */ false);
+
+            insert_props!(*dom_id, ontouchleave_nodes_hover_restyle_props);
+            insert_props!(*dom_id, ontouchleave_nodes_active_restyle_props);
+        }
+
         let new_focus_node = if let Some(new) = callbacks_new_focus.as_ref() {
             new
         } else {
@@ -499,6 +666,37 @@ impl StyleAndLayoutChanges {
             None
         };
 
+        // re-run the CSS matching for nodes whose classes changed (and their children),
+        // before the user-overridden properties, which take precedence
+        if let Some(ids_and_classes_changes) = ids_and_classes_changes {
+            for (dom_id, changed_nodes) in ids_and_classes_changes.iter() {
+                let layout_result = &mut layout_results[dom_id.inner];
+                let dom_id: DomId = *dom_id;
+                for (node_id, ids_and_classes) in changed_nodes.iter() {
+                    let current_prop_changes = layout_result
+                        .styled_dom
+                        .restyle_ids_and_classes(node_id, ids_and_classes.clone());
+                    insert_props!(dom_id, current_prop_changes);
+                }
+            }
+        }
+
+        // :disabled, :checked, ... states that were set in callbacks
+        if let Some(node_state_flag_changes) = node_state_flag_changes {
+            for (dom_id, changed_nodes) in node_state_flag_changes.iter() {
+                let layout_result = &mut layout_results[dom_id.inner];
+                let dom_id: DomId = *dom_id;
+                for (node_id, flags) in changed_nodes.iter() {
+                    for (flag, value) in flags.iter() {
+                        let current_prop_changes = layout_result
+                            .styled_dom
+                            .restyle_nodes_state_flag(&[*node_id], *flag, *value);
+                        insert_props!(dom_id, current_prop_changes);
+                    }
+                }
+            }
+        }
+
         // restyle all the nodes according to the existing_changed_styles
         if let Some(css_changes) = css_changes {
             for (dom_id, existing_changes_map) in css_changes.iter() {
@@ -513,6 +711,40 @@ impl StyleAndLayoutChanges {
             }
         }
 
+        // the text of the generated :before / :after pseudo-elements
+        // may have changed with the states / classes of the restyled nodes
+        let restyled_doms = style_changes
+            .iter()
+            .chain(layout_changes.iter())
+            .flat_map(|changes| changes.keys().copied())
+            .collect::<BTreeSet<DomId>>();
+
+        let mut word_changes = word_changes.cloned().unwrap_or_default();
+        for dom_id in restyled_doms {
+            let pseudo_element_texts = layout_results[dom_id.inner]
+                .styled_dom
+                .update_pseudo_element_texts();
+            if !pseudo_element_texts.is_empty() {
+                word_changes
+                    .entry(dom_id)
+                    .or_insert_with(|| BTreeMap::new())
+                    .extend(pseudo_element_texts.into_iter());
+            }
+        }
+
+        let word_changes = if word_changes.is_empty() {
+            None
+        } else {
+            Some(word_changes)
+        };
+
+        let nodes_that_changed_text_content = word_changes.as_ref().map(|word_changes| {
+            word_changes
+                .iter()
+                .map(|(dom_id, m)| (*dom_id, m.keys().cloned().collect()))
+                .collect()
+        });
+
         let mut nodes_that_changed_size = None;
         let mut gpu_key_change_events = None;
 
@@ -560,7 +792,7 @@ impl StyleAndLayoutChanges {
                 };
 
                 let layout_changes = layout_changes.as_ref().and_then(|w| w.get(&dom_id));
-                let word_changes = word_changes.and_then(|w| w.get(&dom_id));
+                let word_changes = word_changes.as_ref().and_then(|w| w.get(&dom_id));
 
                 // TODO: avoid allocation
                 let RelayoutChanges {
@@ -706,6 +938,9 @@ pub struct CallbacksOfHitTest {
     /// meaning in order to get the proper callbacks, you simply have to iterate through
     /// all node IDs
     pub nodes_with_callbacks: BTreeMap<DomId, Vec<CallbackToCall>>,
+    /// Window-level shortcut that matches the pressed keys: invoked before the
+    /// node callbacks, instead of the `VirtualKeyDown` callbacks of the focused node
+    pub shortcut: Option<Shortcut>,
 }
 
 impl CallbacksOfHitTest {
@@ -717,6 +952,8 @@ impl CallbacksOfHitTest {
         nodes_to_check: &NodesToCheck,
         events: &Events,
         layout_results: &[LayoutResult],
+        shortcuts: &ShortcutMap,
+        full_window_state: &FullWindowState,
     ) -> Self {
         use rayon::prelude::*;
 
@@ -725,9 +962,21 @@ impl CallbacksOfHitTest {
         if events.is_empty() {
             return Self {
                 nodes_with_callbacks,
+                shortcut: None,
             };
         }
 
+        // clone the shortcut, since the callback may add / remove shortcuts
+        let shortcut = if events.window_events.contains(&WindowEventFilter::VirtualKeyDown) {
+            shortcuts
+                .get_matching_shortcut(&full_window_state.keyboard_state)
+                .cloned()
+        } else {
+            None
+        };
+
+        let focused_key_down_filter = EventFilter::Focus(FocusEventFilter::VirtualKeyDown);
+
         let default_map = BTreeMap::new();
         let mouseenter_filter = EventFilter::Hover(HoverEventFilter::MouseEnter);
         let mouseleave_filter = EventFilter::Hover(HoverEventFilter::MouseEnter);
@@ -826,6 +1075,14 @@ impl CallbacksOfHitTest {
                 .iter()
             {
                 for hev in events.hover_events.iter() {
+                    // touch events are only sent to the nodes under the touch, see below
+                    match hev {
+                        HoverEventFilter::TouchStart
+                        | HoverEventFilter::TouchMove
+                        | HoverEventFilter::TouchEnd
+                        | HoverEventFilter::TouchCancel => continue,
+                        _ => {}
+                    }
                     window_callbacks_this_dom.extend(
                         layout_result.styled_dom.node_data.as_container()[*nid]
                             .get_callbacks()
@@ -848,6 +1105,52 @@ impl CallbacksOfHitTest {
                 }
             }
 
+            // insert Hover::Touch* events: every touch calls the callbacks
+            // of the nodes under that touch, independent of the other touches
+            let touch_events = [
+                (
+                    HoverEventFilter::TouchStart,
+                    &events.touches_started,
+                    &nodes_to_check.new_touch_hit_node_ids,
+                ),
+                (
+                    HoverEventFilter::TouchMove,
+                    &events.touches_moved,
+                    &nodes_to_check.new_touch_hit_node_ids,
+                ),
+                (
+                    HoverEventFilter::TouchEnd,
+                    &events.touches_ended,
+                    &nodes_to_check.old_touch_hit_node_ids,
+                ),
+            ];
+
+            for (hev, touch_ids, touch_hit_node_ids) in touch_events.iter() {
+                let touch_filter = EventFilter::Hover(*hev);
+                for touch_id in touch_ids.iter() {
+                    let hit_nodes = match touch_hit_node_ids
+                        .get(touch_id)
+                        .and_then(|hit_nodes| hit_nodes.get(&dom_id))
+                    {
+                        Some(s) => s,
+                        None => continue,
+                    };
+                    for (nid, ht) in hit_nodes.iter() {
+                        if layout_result.styled_dom.node_data.as_container()[*nid]
+                            .get_callbacks()
+                            .iter()
+                            .any(|e| e.event == touch_filter)
+                        {
+                            window_callbacks_this_dom.push(CallbackToCall {
+                                event_filter: touch_filter.clone(),
+                                hit_test_item: Some(*ht),
+                                node_id: *nid,
+                            });
+                        }
+                    }
+                }
+            }
+
             // insert Focus(FocusReceived / FocusLost) event
             if nodes_to_check.new_focus_node != nodes_to_check.old_focus_node {
                 if let Some(DomNodeId {
@@ -919,6 +1222,7 @@ impl CallbacksOfHitTest {
                                 if cb.event == EventFilter::Focus(*fev)
                                     && cb.event != focus_received_filter
                                     && cb.event != focus_lost_filter
+                                    && !(shortcut.is_some() && cb.event == focused_key_down_filter)
                                 {
                                     window_callbacks_this_dom.push(CallbackToCall {
                                         event_filter: EventFilter::Focus(fev.clone()),
@@ -995,6 +1299,7 @@ impl CallbacksOfHitTest {
 
         CallbacksOfHitTest {
             nodes_with_callbacks,
+            shortcut,
         }
     }
 
@@ -1012,6 +1317,8 @@ impl CallbacksOfHitTest {
         system_fonts: &mut FcFontCache,
         system_callbacks: &ExternalSystemCallbacks,
         renderer_resources: &RendererResources,
+        shortcuts: &mut ShortcutMap,
+        window_data: &mut OptionRefAny,
     ) -> CallCallbacksResult {
         use crate::callbacks::CallbackInfo;
         use crate::styled_dom::ParentWithNodeDepth;
@@ -1022,7 +1329,10 @@ impl CallbacksOfHitTest {
             callbacks_update_screen: Update::DoNothing,
             modified_window_state: None,
             css_properties_changed: None,
+            ids_and_classes_changed: None,
+            node_state_flags_changed: None,
             words_changed: None,
+            text_underlines_changed: None,
             images_changed: None,
             image_masks_changed: None,
             nodes_scrolled_in_callbacks: None,
@@ -1032,14 +1342,32 @@ impl CallbacksOfHitTest {
             timers_removed: None,
             threads_removed: None,
             windows_created: Vec::new(),
+            announcements: Vec::new(),
+            haptic_feedback: Vec::new(),
+            animation_frame_callbacks: Vec::new(),
+            crossfades: Vec::new(),
+            subtree_recordings: Vec::new(),
             cursor_changed: false,
         };
         let mut new_focus_target = None;
 
         let current_cursor = full_window_state.mouse_state.mouse_cursor_type.clone();
 
-        if self.nodes_with_callbacks.is_empty() {
+        // arrow keys move the focus, unless a callback handles the key itself
+        let spatial_focus_target =
+            get_spatial_focus_direction(previous_window_state, full_window_state)
+                .map(FocusTarget::Direction);
+        let mut key_down_handled = false;
+
+        if self.nodes_with_callbacks.is_empty() && self.shortcut.is_none() {
             // common case
+            if let Some(ft) = spatial_focus_target {
+                if let Ok(new_focus_node) =
+                    ft.resolve(&layout_results, full_window_state.focused_node)
+                {
+                    ret.update_focused_node = Some(new_focus_node);
+                }
+            }
             return ret;
         }
 
@@ -1050,11 +1378,78 @@ impl CallbacksOfHitTest {
         let mut ret_threads = FastHashMap::new();
         let mut ret_threads_removed = FastBTreeSet::new();
         let mut ret_words_changed = BTreeMap::new();
+        let mut ret_text_underlines_changed = BTreeMap::new();
         let mut ret_images_changed = BTreeMap::new();
         let mut ret_image_masks_changed = BTreeMap::new();
         let mut ret_css_properties_changed = BTreeMap::new();
+        let mut ret_ids_and_classes_changed = BTreeMap::new();
+        let mut ret_node_state_flags_changed = BTreeMap::new();
         let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
 
+        // the shortcut handles the key: it runs first and
+        // prevents the arrow keys from moving the focus
+        if let Some(mut shortcut) = self.shortcut.take() {
+            let mut new_focus = None;
+            let mut stop_propagation = false;
+
+            let mut callback_info = CallbackInfo::new(
+                /*layout_results:*/ &layout_results,
+                /*renderer_resources:*/ renderer_resources,
+                /*previous_window_state:*/ &previous_window_state,
+                /*current_window_state:*/ &full_window_state,
+                /*modifiable_window_state:*/ &mut ret_modified_window_state,
+                /*gl_context,*/ gl_context,
+                /*image_cache,*/ image_cache,
+                /*system_fonts,*/ system_fonts,
+                /*timers:*/ &mut ret_timers,
+                /*threads:*/ &mut ret_threads,
+                /*timers_removed:*/ &mut ret_timers_removed,
+                /*threads_removed:*/ &mut ret_threads_removed,
+                /*current_window_handle:*/ raw_window_handle,
+                /*new_windows:*/ &mut ret.windows_created,
+                /*shortcuts:*/ shortcuts,
+                /*window_data:*/ window_data,
+                /*announcements:*/ &mut ret.announcements,
+                /*haptic_feedback:*/ &mut ret.haptic_feedback,
+                /*animation_frame_callbacks:*/ &mut ret.animation_frame_callbacks,
+                /*crossfades:*/ &mut ret.crossfades,
+                /*subtree_recordings:*/ &mut ret.subtree_recordings,
+                /*system_callbacks*/ system_callbacks,
+                /*stop_propagation:*/ &mut stop_propagation,
+                /*focus_target:*/ &mut new_focus,
+                /*words_changed_in_callbacks:*/ &mut ret_words_changed,
+                /*text_underlines_changed_in_callbacks:*/ &mut ret_text_underlines_changed,
+                /*images_changed_in_callbacks:*/ &mut ret_images_changed,
+                /*image_masks_changed_in_callbacks:*/
+                &mut ret_image_masks_changed,
+                /*css_properties_changed_in_callbacks:*/
+                &mut ret_css_properties_changed,
+                /*ids_and_classes_changed_in_callbacks:*/
+                &mut ret_ids_and_classes_changed,
+                /*node_state_flags_changed_in_callbacks:*/
+                &mut ret_node_state_flags_changed,
+                /*current_scroll_states:*/ scroll_states,
+                /*nodes_scrolled_in_callback:*/
+                &mut ret_nodes_scrolled_in_callbacks,
+                /*hit_dom_node:*/
+                DomNodeId {
+                    dom: DomId::ROOT_ID,
+                    node: NodeHierarchyItemId::from_crate_internal(None),
+                },
+                /*cursor_relative_to_item:*/ None.into(),
+                /*cursor_in_viewport:*/ None.into(),
+            );
+
+            let callback_return = (shortcut.callback.cb)(&mut shortcut.data, &mut callback_info);
+            ret.callbacks_update_screen.max_self(callback_return);
+
+            if let Some(new_focus) = new_focus {
+                new_focus_target = Some(new_focus);
+            }
+
+            key_down_handled = true;
+        }
+
         {
             for (dom_id, callbacks_filter_list) in self.nodes_with_callbacks.iter() {
                 let mut callbacks = BTreeMap::new();
@@ -1106,15 +1501,27 @@ impl CallbacksOfHitTest {
                                 /*threads_removed:*/ &mut ret_threads_removed,
                                 /*current_window_handle:*/ raw_window_handle,
                                 /*new_windows:*/ &mut ret.windows_created,
+                                /*shortcuts:*/ shortcuts,
+                                /*window_data:*/ window_data,
+                                /*announcements:*/ &mut ret.announcements,
+                                /*haptic_feedback:*/ &mut ret.haptic_feedback,
+                                /*animation_frame_callbacks:*/ &mut ret.animation_frame_callbacks,
+                                /*crossfades:*/ &mut ret.crossfades,
+                                /*subtree_recordings:*/ &mut ret.subtree_recordings,
                                 /*system_callbacks*/ system_callbacks,
                                 /*stop_propagation:*/ &mut stop_propagation,
                                 /*focus_target:*/ &mut new_focus,
                                 /*words_changed_in_callbacks:*/ &mut ret_words_changed,
+                                /*text_underlines_changed_in_callbacks:*/ &mut ret_text_underlines_changed,
                                 /*images_changed_in_callbacks:*/ &mut ret_images_changed,
                                 /*image_masks_changed_in_callbacks:*/
                                 &mut ret_image_masks_changed,
                                 /*css_properties_changed_in_callbacks:*/
                                 &mut ret_css_properties_changed,
+                                /*ids_and_classes_changed_in_callbacks:*/
+                                &mut ret_ids_and_classes_changed,
+                                /*node_state_flags_changed_in_callbacks:*/
+                                &mut ret_node_state_flags_changed,
                                 /*current_scroll_states:*/ scroll_states,
                                 /*nodes_scrolled_in_callback:*/
                                 &mut ret_nodes_scrolled_in_callbacks,
@@ -1161,6 +1568,7 @@ impl CallbacksOfHitTest {
                             }
 
                             if stop_propagation {
+                                key_down_handled |= is_virtual_key_down(event_filter);
                                 blacklisted_event_types.insert(event_filter.clone());
                             }
                         }
@@ -1201,15 +1609,27 @@ impl CallbacksOfHitTest {
                             /*threads_removed:*/ &mut ret_threads_removed,
                             /*current_window_handle:*/ raw_window_handle,
                             /*new_windows:*/ &mut ret.windows_created,
+                            /*shortcuts:*/ shortcuts,
+                            /*window_data:*/ window_data,
+                            /*announcements:*/ &mut ret.announcements,
+                            /*haptic_feedback:*/ &mut ret.haptic_feedback,
+                            /*animation_frame_callbacks:*/ &mut ret.animation_frame_callbacks,
+                            /*crossfades:*/ &mut ret.crossfades,
+                            /*subtree_recordings:*/ &mut ret.subtree_recordings,
                             /*system_callbacks*/ system_callbacks,
                             /*stop_propagation:*/ &mut stop_propagation,
                             /*focus_target:*/ &mut new_focus,
                             /*words_changed_in_callbacks:*/ &mut ret_words_changed,
+                            /*text_underlines_changed_in_callbacks:*/ &mut ret_text_underlines_changed,
                             /*images_changed_in_callbacks:*/ &mut ret_images_changed,
                             /*image_masks_changed_in_callbacks:*/
                             &mut ret_image_masks_changed,
                             /*css_properties_changed_in_callbacks:*/
                             &mut ret_css_properties_changed,
+                            /*ids_and_classes_changed_in_callbacks:*/
+                            &mut ret_ids_and_classes_changed,
+                            /*node_state_flags_changed_in_callbacks:*/
+                            &mut ret_node_state_flags_changed,
                             /*current_scroll_states:*/ scroll_states,
                             /*nodes_scrolled_in_callback:*/
                             &mut ret_nodes_scrolled_in_callbacks,
@@ -1256,6 +1676,7 @@ impl CallbacksOfHitTest {
                         }
 
                         if stop_propagation {
+                            key_down_handled |= is_virtual_key_down(event_filter);
                             blacklisted_event_types.insert(event_filter.clone());
                         }
                     }
@@ -1280,6 +1701,10 @@ impl CallbacksOfHitTest {
             }
         }
 
+        if new_focus_target.is_none() && !key_down_handled {
+            new_focus_target = spatial_focus_target;
+        }
+
         // Resolve the new focus target
         if let Some(ft) = new_focus_target {
             if let Ok(new_focus_node) = ft.resolve(&layout_results, full_window_state.focused_node)
@@ -1310,6 +1735,9 @@ impl CallbacksOfHitTest {
         if !ret_words_changed.is_empty() {
             ret.words_changed = Some(ret_words_changed);
         }
+        if !ret_text_underlines_changed.is_empty() {
+            ret.text_underlines_changed = Some(ret_text_underlines_changed);
+        }
         if !ret_images_changed.is_empty() {
             ret.images_changed = Some(ret_images_changed);
         }
@@ -1319,6 +1747,12 @@ impl CallbacksOfHitTest {
         if !ret_css_properties_changed.is_empty() {
             ret.css_properties_changed = Some(ret_css_properties_changed);
         }
+        if !ret_ids_and_classes_changed.is_empty() {
+            ret.ids_and_classes_changed = Some(ret_ids_and_classes_changed);
+        }
+        if !ret_node_state_flags_changed.is_empty() {
+            ret.node_state_flags_changed = Some(ret_node_state_flags_changed);
+        }
         if !ret_nodes_scrolled_in_callbacks.is_empty() {
             ret.nodes_scrolled_in_callbacks = Some(ret_nodes_scrolled_in_callbacks);
         }
@@ -1327,12 +1761,60 @@ impl CallbacksOfHitTest {
     }
 }
 
+/// Returns the direction to move the focus in if `WindowFlags::spatial_navigation_enabled`
+/// is set and an arrow key (without any modifier keys) was just pressed
+fn get_spatial_focus_direction(
+    previous_window_state: &Option<FullWindowState>,
+    current_window_state: &FullWindowState,
+) -> Option<FocusDirection> {
+    use crate::window::VirtualKeyCode;
+
+    if !current_window_state.flags.spatial_navigation_enabled {
+        return None;
+    }
+
+    let previous_window_state = previous_window_state.as_ref()?;
+    if previous_window_state
+        .keyboard_state
+        .current_virtual_keycode
+        .is_some()
+    {
+        return None;
+    }
+
+    let keyboard_state = &current_window_state.keyboard_state;
+    if keyboard_state.shift_down()
+        || keyboard_state.ctrl_down()
+        || keyboard_state.alt_down()
+        || keyboard_state.super_down()
+    {
+        return None;
+    }
+
+    match keyboard_state.current_virtual_keycode.into_option()? {
+        VirtualKeyCode::Up => Some(FocusDirection::Up),
+        VirtualKeyCode::Down => Some(FocusDirection::Down),
+        VirtualKeyCode::Left => Some(FocusDirection::Left),
+        VirtualKeyCode::Right => Some(FocusDirection::Right),
+        _ => None,
+    }
+}
+
+fn is_virtual_key_down(event_filter: &EventFilter) -> bool {
+    match event_filter {
+        EventFilter::Window(WindowEventFilter::VirtualKeyDown)
+        | EventFilter::Hover(HoverEventFilter::VirtualKeyDown)
+        | EventFilter::Focus(FocusEventFilter::VirtualKeyDown) => true,
+        _ => false,
+    }
+}
+
 fn get_window_events(
     current_window_state: &FullWindowState,
     previous_window_state: &Option<FullWindowState>,
 ) -> Vec<WindowEventFilter> {
     use crate::window::CursorPosition::*;
-    use crate::window::WindowPosition;
+    use crate::window::{WindowFrame, WindowPosition};
 
     let mut events = Vec::new();
 
@@ -1369,6 +1851,11 @@ fn get_window_events(
 
     if current_window_state.mouse_state.left_down && !previous_window_state.mouse_state.left_down {
         events.push(WindowEventFilter::LeftMouseDown);
+        match current_window_state.mouse_state.click_count {
+            2 => events.push(WindowEventFilter::DoubleClick),
+            3 => events.push(WindowEventFilter::TripleClick),
+            _ => {}
+        }
     }
 
     if current_window_state.mouse_state.right_down && !previous_window_state.mouse_state.right_down
@@ -1403,6 +1890,25 @@ fn get_window_events(
         events.push(WindowEventFilter::MiddleMouseUp);
     }
 
+    // touch events
+
+    let (touches_started, touches_moved, touches_ended) = get_touch_changes(
+        &current_window_state.touch_state,
+        &previous_window_state.touch_state,
+    );
+
+    if !touches_started.is_empty() {
+        events.push(WindowEventFilter::TouchStart);
+    }
+
+    if !touches_moved.is_empty() {
+        events.push(WindowEventFilter::TouchMove);
+    }
+
+    if !touches_ended.is_empty() {
+        events.push(WindowEventFilter::TouchEnd);
+    }
+
     // resize, move, close events
 
     if current_window_state.flags.has_focus != previous_window_state.flags.has_focus {
@@ -1421,6 +1927,10 @@ fn get_window_events(
         events.push(WindowEventFilter::Resized);
     }
 
+    if current_window_state.size.dpi != previous_window_state.size.dpi {
+        events.push(WindowEventFilter::DpiChanged);
+    }
+
     match (
         current_window_state.position,
         previous_window_state.position,
@@ -1442,6 +1952,35 @@ fn get_window_events(
         events.push(WindowEventFilter::CloseRequested);
     }
 
+    if current_window_state.flags.is_session_ending
+        && !previous_window_state.flags.is_session_ending
+    {
+        events.push(WindowEventFilter::SessionEnding);
+    }
+
+    let is_fullscreen_now = current_window_state.flags.frame == WindowFrame::Fullscreen;
+    let was_fullscreen = previous_window_state.flags.frame == WindowFrame::Fullscreen;
+
+    if is_fullscreen_now && !was_fullscreen {
+        events.push(WindowEventFilter::FullscreenEntered);
+    } else if !is_fullscreen_now && was_fullscreen {
+        events.push(WindowEventFilter::FullscreenExited);
+    }
+
+    match (previous_window_state.flags.frame, current_window_state.flags.frame) {
+        (prev, WindowFrame::Minimized) if prev != WindowFrame::Minimized => {
+            events.push(WindowEventFilter::Minimized);
+        }
+        (prev, WindowFrame::Maximized) if prev != WindowFrame::Maximized => {
+            events.push(WindowEventFilter::Maximized);
+        }
+        (WindowFrame::Minimized, WindowFrame::Normal)
+        | (WindowFrame::Maximized, WindowFrame::Normal) => {
+            events.push(WindowEventFilter::Restored);
+        }
+        _ => {}
+    }
+
     // scroll events
 
     let is_scroll_previous = previous_window_state.mouse_state.scroll_x.is_some()
@@ -1521,9 +2060,60 @@ fn get_window_events(
         events.push(WindowEventFilter::ThemeChanged);
     }
 
+    if current_window_state.touch_state.input_modality != previous_window_state.touch_state.input_modality {
+        events.push(WindowEventFilter::InputModalityChanged);
+    }
+
+    if current_window_state.user_is_idle != previous_window_state.user_is_idle {
+        if current_window_state.user_is_idle {
+            events.push(WindowEventFilter::UserIdle);
+        } else {
+            events.push(WindowEventFilter::UserActive);
+        }
+    }
+
+    // the event loop resets the user event after it has been delivered
+    if current_window_state.user_event.is_some() {
+        events.push(WindowEventFilter::UserEvent);
+    }
+
+    // the event loop resets the flag after the event has been delivered
+    if current_window_state.hover_intent {
+        events.push(WindowEventFilter::HoverIntent);
+    }
+
     events
 }
 
+/// Returns the IDs of the touches that (started, moved, ended) between the two states
+fn get_touch_changes(
+    current_touch_state: &TouchState,
+    previous_touch_state: &TouchState,
+) -> (Vec<u64>, Vec<u64>, Vec<u64>) {
+    let mut started = Vec::new();
+    let mut moved = Vec::new();
+    let mut ended = Vec::new();
+
+    for touch in current_touch_state.touches.iter() {
+        match previous_touch_state.get(touch.id) {
+            None => started.push(touch.id),
+            Some(previous) => {
+                if previous.position != touch.position {
+                    moved.push(touch.id);
+                }
+            }
+        }
+    }
+
+    for touch in previous_touch_state.touches.iter() {
+        if current_touch_state.get(touch.id).is_none() {
+            ended.push(touch.id);
+        }
+    }
+
+    (started, moved, ended)
+}
+
 fn get_hover_events(input: &[WindowEventFilter]) -> Vec<HoverEventFilter> {
     input
         .iter()
@@ -9,7 +9,7 @@ use azul_css::{
     AzString, Css, CssPath, CssPathPseudoSelector, CssPathSelector, CssRuleBlock, NodeTypeTag,
     NormalizedLinearColorStopVec, NormalizedRadialColorStopVec, OptionAzString,
     StyleBackgroundContentVec, StyleBackgroundPositionVec, StyleBackgroundRepeatVec,
-    StyleBackgroundSizeVec, StyleFontFamilyVec, StyleTransformVec, U8Vec,
+    StyleBackgroundSizeVec, StyleFontFamilyVec, StyleTransformVec, SystemStyle, U8Vec,
 };
 #[cfg(feature = "css_parser")]
 use azul_css_parser::{CssApiWrapper, CssParseError};
@@ -34,16 +34,25 @@ pub type ComponentName = String;
 pub type CompiledComponent = String;
 pub type FilteredComponentArguments = ComponentArguments;
 
-pub const DEFAULT_ARGS: [&str; 7] = [
+pub const DEFAULT_ARGS: [&str; 13] = [
     "id",
     "class",
     "tabindex",
     "focusable",
+    "nav-up",
+    "nav-down",
+    "nav-left",
+    "nav-right",
+    "lang",
+    "inputmode",
     "accepts_text",
     "name",
     "args",
 ];
 
+/// XML attributes that override the spatial focus navigation (`nav-up="#id"`)
+const FOCUS_NAVIGATION_ARGS: [&str; 4] = ["nav-up", "nav-down", "nav-left", "nav-right"];
+
 #[allow(non_camel_case_types)]
 pub enum c_void {}
 
@@ -1222,7 +1231,9 @@ pub fn str_to_rust_code<'a>(
         if let Some(style_node) = find_node_by_type(head_node.children.as_ref(), "style") {
             if let Some(text) = style_node.text.as_ref().map(|s| s.as_str()) {
                 let parsed_css = azul_css_parser::new_from_str(&text)?;
-                global_style = parsed_css;
+                // the compiled code is static, so the system colors and
+                // @media blocks are resolved with the default system settings
+                global_style = azul_css_parser::resolve_system_style(&parsed_css, &SystemStyle::DEFAULT);
             }
         }
     }
@@ -1306,8 +1317,9 @@ fn main() {
         StyleFontFamilyVec, StyleBackgroundPositionVec,
         NormalizedLinearColorStopVec, NormalizedRadialColorStopVec,
     }};
+    use azul::option::OptionString;
     use azul::dom::{{
-        Dom, IdOrClass, TabIndex,
+        Dom, IdOrClass, TabIndex, FocusNavigation, InputMode,
         IdOrClass::{{Id, Class}},
         NodeDataInlineCssProperty,
     }};\r\n\r\n{}\r\n\r\n{}
@@ -1484,7 +1496,7 @@ pub fn set_attributes(
     filtered_xml_attributes: &FilteredComponentArguments,
 ) {
     use crate::dom::IdOrClass::{Class, Id};
-    use crate::dom::TabIndex;
+    use crate::dom::{FocusNavigation, InputMode, TabIndex};
 
     let mut ids_and_classes = Vec::new();
     let dom_root = match dom.root.into_crate_internal() {
@@ -1533,6 +1545,35 @@ pub fn set_attributes(
             _ => node_data.set_tab_index(TabIndex::NoKeyboardFocus),
         }
     }
+
+    if FOCUS_NAVIGATION_ARGS
+        .iter()
+        .any(|arg| xml_attributes.get_key(arg).is_some())
+    {
+        let get_nav_target = |key: &str| -> OptionAzString {
+            xml_attributes
+                .get_key(key)
+                .map(|val| AzString::from(format_args_dynamic(val, &filtered_xml_attributes.args)))
+                .into()
+        };
+        node_data.set_focus_navigation(FocusNavigation {
+            up: get_nav_target("nav-up"),
+            down: get_nav_target("nav-down"),
+            left: get_nav_target("nav-left"),
+            right: get_nav_target("nav-right"),
+        });
+    }
+
+    if let Some(lang) = xml_attributes.get_key("lang") {
+        node_data.set_lang(format_args_dynamic(lang, &filtered_xml_attributes.args).into());
+    }
+
+    if let Some(input_mode) = xml_attributes
+        .get_key("inputmode")
+        .and_then(|val| InputMode::from_str(&format_args_dynamic(val, &filtered_xml_attributes.args)))
+    {
+        node_data.set_input_mode(input_mode);
+    }
 }
 
 pub fn set_stringified_attributes(
@@ -1614,6 +1655,42 @@ pub fn set_stringified_attributes(
             )),
         }
     }
+
+    if FOCUS_NAVIGATION_ARGS
+        .iter()
+        .any(|arg| xml_attributes.get_key(arg).is_some())
+    {
+        let get_nav_target = |key: &str| match xml_attributes.get_key(key) {
+            Some(val) => format!(
+                "OptionString::Some(AzString::from_const_str(\"{}\"))",
+                format_args_dynamic(val, &filtered_xml_attributes)
+            ),
+            None => String::from("OptionString::None"),
+        };
+        dom_string.push_str(&format!(
+            "\r\n{t}.with_focus_navigation(FocusNavigation {{\r\n{t}    up: {up},\r\n{t}    down: {down},\r\n{t}    left: {left},\r\n{t}    right: {right},\r\n{t}}})",
+            t = t,
+            up = get_nav_target("nav-up"),
+            down = get_nav_target("nav-down"),
+            left = get_nav_target("nav-left"),
+            right = get_nav_target("nav-right"),
+        ));
+    }
+
+    if let Some(lang) = xml_attributes.get_key("lang") {
+        dom_string.push_str(&format!(
+            "\r\n{}.with_lang(AzString::from_const_str(\"{}\"))",
+            t,
+            format_args_dynamic(lang, &filtered_xml_attributes)
+        ));
+    }
+
+    if let Some(input_mode) = xml_attributes
+        .get_key("inputmode")
+        .and_then(|val| crate::dom::InputMode::from_str(&format_args_dynamic(val, &filtered_xml_attributes)))
+    {
+        dom_string.push_str(&format!("\r\n{}.with_input_mode(InputMode::{:?})", t, input_mode));
+    }
 }
 
 /// Item of a split string - either a variable name or a string
@@ -1844,7 +1921,10 @@ pub fn render_component_inner<'a>(
     let mut css = match find_node_by_type(xml_node.children.as_ref(), "style")
         .and_then(|style_node| style_node.text.as_ref().map(|s| s.as_str()))
     {
-        Some(text) => azul_css_parser::new_from_str(&text)?,
+        Some(text) => azul_css_parser::resolve_system_style(
+            &azul_css_parser::new_from_str(&text)?,
+            &SystemStyle::DEFAULT,
+        ),
         None => Css::empty(),
     };
 
@@ -2203,6 +2283,7 @@ pub fn compile_body_node_to_rust_code<'a>(
                     let prop = match declaration {
                         CssDeclaration::Static(s) => s,
                         CssDeclaration::Dynamic(d) => &d.default_value,
+                        CssDeclaration::System(s) => &s.default_value,
                     };
                     extra_blocks.insert_from_css_property(prop);
                 }
@@ -2224,6 +2305,11 @@ pub fn compile_body_node_to_rust_code<'a>(
                             wrapper,
                             format_static_css_prop(&d.default_value, 1)
                         ),
+                        CssDeclaration::System(s) => format!(
+                            "NodeDataInlineCssProperty::{}({})",
+                            wrapper,
+                            format_static_css_prop(&s.default_value, 1)
+                        ),
                     })
                     .collect::<Vec<String>>();
 
@@ -2492,6 +2578,7 @@ pub fn compile_node_to_rust_code_inner<'a>(
                     let prop = match declaration {
                         CssDeclaration::Static(s) => s,
                         CssDeclaration::Dynamic(d) => &d.default_value,
+                        CssDeclaration::System(s) => &s.default_value,
                     };
                     extra_blocks.insert_from_css_property(prop);
                 }
@@ -2513,6 +2600,11 @@ pub fn compile_node_to_rust_code_inner<'a>(
                             wrapper,
                             format_static_css_prop(&d.default_value, 1)
                         ),
+                        CssDeclaration::System(s) => format!(
+                            "NodeDataInlineCssProperty::{}({})",
+                            wrapper,
+                            format_static_css_prop(&s.default_value, 1)
+                        ),
                     })
                     .collect::<Vec<String>>();
 
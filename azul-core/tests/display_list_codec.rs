@@ -0,0 +1,347 @@
+//! Roundtrip and error handling of the binary display list format
+
+extern crate azul_core;
+extern crate azul_css;
+
+use azul_core::{
+    app_resources::{
+        Epoch, FontInstanceKey, FontRenderMode, GlyphOptions, IdNamespace, ImageDescriptor,
+        ImageDescriptorFlags, ImageKey, OpacityKey, RawImageFormat, TransformKey,
+        FONT_INSTANCE_FLAG_SYNTHETIC_BOLD,
+    },
+    callbacks::{PipelineId, TextUnderlineStyle},
+    display_list::{
+        AlphaType, BoxShadow, CachedDisplayList, DisplayListFrame, DisplayListImageMask,
+        DisplayListMsg, DisplayListScrollFrame, DisplayListScrollbar, GlyphInstance,
+        ImageRendering, LayoutRectContent, RectBackground, StyleBorderColors, StyleBorderRadius,
+        StyleBorderStyles, StyleBorderWidths,
+    },
+    display_list_codec::{DisplayListDecodeError, DISPLAY_LIST_MAGIC, DISPLAY_LIST_MAX_DEPTH},
+    dom::{ScrollTagId, TagId},
+    ui_solver::{ComputedTransform3D, ExternalScrollId, PositionInfo, PositionInfoInner},
+    window::{LogicalPosition, LogicalRect, LogicalSize},
+};
+use azul_css::{
+    AngleValue, BackgroundPositionHorizontal, BackgroundPositionVertical, BorderStyle,
+    BoxShadowClipMode, ColorU, ConicGradient, CssPropertyValue, Direction, DirectionCorner,
+    DirectionCorners, ExtendMode, LayoutBorderBottomWidth, LayoutBorderLeftWidth,
+    LayoutBorderRightWidth, LayoutBorderTopWidth, LayoutPoint, LayoutSize, LinearGradient,
+    NormalizedLinearColorStop, NormalizedLinearColorStopVec, NormalizedRadialColorStop,
+    NormalizedRadialColorStopVec, OptionI32, PercentageValue, PixelValue, PixelValueNoPercent,
+    RadialGradient, RadialGradientSize, Shape, StyleBackgroundPosition, StyleBackgroundRepeat,
+    StyleBackgroundSize, StyleBorderBottomColor, StyleBorderBottomStyle, StyleBorderLeftColor,
+    StyleBorderLeftStyle, StyleBorderRightColor, StyleBorderRightStyle, StyleBorderTopColor,
+    StyleBorderTopLeftRadius, StyleBorderTopStyle, StyleBoxShadow, StyleMixBlendMode,
+    StyleTransformStyle,
+};
+
+fn color(r: u8) -> ColorU {
+    ColorU { r, g: 20, b: 30, a: 255 }
+}
+
+fn rect(x: f32, y: f32, width: f32, height: f32) -> LogicalRect {
+    LogicalRect::new(LogicalPosition::new(x, y), LogicalSize::new(width, height))
+}
+
+fn shadow() -> StyleBoxShadow {
+    StyleBoxShadow {
+        offset: [
+            PixelValueNoPercent { inner: PixelValue::const_px(1) },
+            PixelValueNoPercent { inner: PixelValue::const_px(-2) },
+        ],
+        color: color(40),
+        blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(3) },
+        spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(0) },
+        clip_mode: BoxShadowClipMode::Inset,
+    }
+}
+
+fn linear_stops() -> NormalizedLinearColorStopVec {
+    NormalizedLinearColorStopVec::from_vec(vec![
+        NormalizedLinearColorStop { offset: PercentageValue::const_new(0), color: color(1) },
+        NormalizedLinearColorStop { offset: PercentageValue::const_new(100), color: color(2) },
+    ])
+}
+
+fn image_key(key: u32) -> ImageKey {
+    ImageKey { namespace: IdNamespace(3), key }
+}
+
+// display list that contains every variant of every enum in the display list tree
+fn test_display_list() -> CachedDisplayList {
+    let mut frame = DisplayListFrame::root(LayoutSize::new(800, 600), LayoutPoint::new(5, 10));
+    frame.mix_blend_mode = Some(StyleMixBlendMode::Multiply);
+    frame.clip_children = Some(LogicalSize::new(400.0, 300.0));
+    frame.clip_mask = Some(DisplayListImageMask {
+        image: image_key(7),
+        rect: rect(0.0, 0.0, 10.0, 10.0),
+        repeat: true,
+    });
+    frame.border_radius = StyleBorderRadius {
+        top_left: Some(CssPropertyValue::Exact(StyleBorderTopLeftRadius::const_px(4))),
+        top_right: Some(CssPropertyValue::Auto),
+        bottom_left: Some(CssPropertyValue::Initial),
+        bottom_right: None,
+    };
+    frame.tag = Some(TagId(42));
+    frame.box_shadow = Some(BoxShadow {
+        clip_mode: BoxShadowClipMode::Outset,
+        top: Some(CssPropertyValue::Exact(shadow())),
+        right: Some(CssPropertyValue::None),
+        bottom: Some(CssPropertyValue::Inherit),
+        left: None,
+    });
+    frame.transform = Some((TransformKey::unique(), ComputedTransform3D::IDENTITY));
+    frame.perspective = Some(ComputedTransform3D::IDENTITY);
+    frame.transform_style = StyleTransformStyle::Preserve3D;
+    frame.opacity = Some((OpacityKey::unique(), 0.5));
+
+    frame.content = vec![
+        LayoutRectContent::Text {
+            glyphs: vec![GlyphInstance {
+                index: 12,
+                point: LogicalPosition::new(1.5, 2.5),
+                size: LogicalSize::new(8.0, 16.0),
+            }],
+            font_instance_key: FontInstanceKey { namespace: IdNamespace(3), key: 9 },
+            color: color(50),
+            glyph_options: Some(GlyphOptions {
+                render_mode: FontRenderMode::Subpixel,
+                flags: FONT_INSTANCE_FLAG_SYNTHETIC_BOLD,
+            }),
+            overflow: (true, false),
+            text_shadow: Some(shadow()),
+        },
+        LayoutRectContent::Background {
+            content: RectBackground::LinearGradient(LinearGradient {
+                direction: Direction::FromTo(DirectionCorners {
+                    from: DirectionCorner::TopLeft,
+                    to: DirectionCorner::BottomRight,
+                }),
+                extend_mode: ExtendMode::Repeat,
+                stops: linear_stops(),
+            }),
+            size: Some(StyleBackgroundSize::ExactSize([
+                PixelValue::const_px(10),
+                PixelValue::const_percent(50),
+            ])),
+            offset: Some(StyleBackgroundPosition {
+                horizontal: BackgroundPositionHorizontal::Exact(PixelValue::const_px(3)),
+                vertical: BackgroundPositionVertical::Bottom,
+            }),
+            repeat: Some(StyleBackgroundRepeat::RepeatY),
+        },
+        LayoutRectContent::Background {
+            content: RectBackground::RadialGradient(RadialGradient {
+                shape: Shape::Circle,
+                size: RadialGradientSize::FarthestSide,
+                position: StyleBackgroundPosition {
+                    horizontal: BackgroundPositionHorizontal::Center,
+                    vertical: BackgroundPositionVertical::Exact(PixelValue::const_em(2)),
+                },
+                extend_mode: ExtendMode::Clamp,
+                stops: linear_stops(),
+            }),
+            size: Some(StyleBackgroundSize::Cover),
+            offset: None,
+            repeat: None,
+        },
+        LayoutRectContent::Background {
+            content: RectBackground::ConicGradient(ConicGradient {
+                extend_mode: ExtendMode::Clamp,
+                center: StyleBackgroundPosition {
+                    horizontal: BackgroundPositionHorizontal::Left,
+                    vertical: BackgroundPositionVertical::Top,
+                },
+                angle: AngleValue::const_deg(45),
+                stops: NormalizedRadialColorStopVec::from_vec(vec![NormalizedRadialColorStop {
+                    angle: AngleValue::const_turn(1),
+                    color: color(3),
+                }]),
+            }),
+            size: Some(StyleBackgroundSize::Contain),
+            offset: None,
+            repeat: Some(StyleBackgroundRepeat::NoRepeat),
+        },
+        LayoutRectContent::Background {
+            content: RectBackground::LinearGradient(LinearGradient {
+                direction: Direction::Angle(AngleValue::const_deg(90)),
+                extend_mode: ExtendMode::Clamp,
+                stops: NormalizedLinearColorStopVec::from_vec(Vec::new()),
+            }),
+            size: None,
+            offset: None,
+            repeat: None,
+        },
+        LayoutRectContent::Background {
+            content: RectBackground::Image((
+                image_key(8),
+                ImageDescriptor {
+                    format: RawImageFormat::BGRA8,
+                    width: 64,
+                    height: 32,
+                    stride: OptionI32::Some(256),
+                    offset: 0,
+                    flags: ImageDescriptorFlags { is_opaque: false, allow_mipmaps: true },
+                },
+            )),
+            size: None,
+            offset: None,
+            repeat: Some(StyleBackgroundRepeat::Repeat),
+        },
+        LayoutRectContent::Background {
+            content: RectBackground::Color(color(60)),
+            size: None,
+            offset: None,
+            repeat: None,
+        },
+        LayoutRectContent::Image {
+            size: LogicalSize::new(64.0, 32.0),
+            offset: LogicalPosition::new(2.0, 3.0),
+            image_rendering: ImageRendering::Pixelated,
+            alpha_type: AlphaType::PremultipliedAlpha,
+            image_key: image_key(8),
+            background_color: color(70),
+        },
+        LayoutRectContent::Border {
+            widths: StyleBorderWidths {
+                top: Some(CssPropertyValue::Exact(LayoutBorderTopWidth::const_px(1))),
+                right: Some(CssPropertyValue::Exact(LayoutBorderRightWidth::const_px(2))),
+                bottom: Some(CssPropertyValue::Exact(LayoutBorderBottomWidth::const_px(3))),
+                left: Some(CssPropertyValue::Exact(LayoutBorderLeftWidth::const_px(4))),
+            },
+            colors: StyleBorderColors {
+                top: Some(CssPropertyValue::Exact(StyleBorderTopColor { inner: color(80) })),
+                right: Some(CssPropertyValue::Exact(StyleBorderRightColor { inner: color(81) })),
+                bottom: Some(CssPropertyValue::Exact(StyleBorderBottomColor { inner: color(82) })),
+                left: Some(CssPropertyValue::Exact(StyleBorderLeftColor { inner: color(83) })),
+            },
+            styles: StyleBorderStyles {
+                top: Some(CssPropertyValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Dashed })),
+                right: Some(CssPropertyValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Ridge })),
+                bottom: Some(CssPropertyValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Outset })),
+                left: Some(CssPropertyValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::None })),
+            },
+        },
+        LayoutRectContent::Underline {
+            bounds: rect(0.0, 14.0, 40.0, 3.0),
+            color: color(90),
+            style: TextUnderlineStyle::Wavy,
+        },
+    ];
+
+    let mut scroll_content = DisplayListFrame::root(LayoutSize::new(100, 1000), LayoutPoint::zero());
+    scroll_content.position = PositionInfo::Absolute(PositionInfoInner {
+        x_offset: 1.0,
+        y_offset: -20.0,
+        static_x_offset: 0.0,
+        static_y_offset: 0.0,
+    });
+
+    let mut fixed = DisplayListFrame::root(LayoutSize::new(10, 10), LayoutPoint::zero());
+    fixed.position = PositionInfo::Fixed(PositionInfoInner::zero());
+    let mut relative = DisplayListFrame::root(LayoutSize::new(10, 10), LayoutPoint::zero());
+    relative.position = PositionInfo::Relative(PositionInfoInner::zero());
+
+    frame.children = vec![
+        DisplayListMsg::ScrollFrame(DisplayListScrollFrame {
+            parent_rect: rect(0.0, 0.0, 100.0, 200.0),
+            content_rect: rect(0.0, 0.0, 100.0, 1000.0),
+            scroll_id: ExternalScrollId(77, PipelineId(1, 2)),
+            scroll_tag: ScrollTagId(TagId(43)),
+            frame: scroll_content,
+            scrollbars: vec![DisplayListScrollbar {
+                track: rect(92.0, 0.0, 8.0, 200.0),
+                track_color: color(100),
+                thumb: rect(92.0, 10.0, 8.0, 40.0),
+                thumb_color: color(101),
+            }],
+        }),
+        DisplayListMsg::Frame(fixed),
+        DisplayListMsg::Frame(relative),
+        DisplayListMsg::IFrame(
+            PipelineId(3, 4),
+            LogicalSize::new(50.0, 50.0),
+            Epoch::from(5),
+            Box::new(CachedDisplayList::empty()),
+        ),
+    ];
+
+    CachedDisplayList {
+        root: DisplayListMsg::Frame(frame),
+        root_size: LogicalSize::new(800.0, 600.0),
+    }
+}
+
+#[test]
+fn display_list_roundtrip() {
+    let display_list = test_display_list();
+    let bytes = display_list.to_bytes();
+    assert_eq!(&bytes[..4], &DISPLAY_LIST_MAGIC[..]);
+    assert_eq!(CachedDisplayList::from_bytes(&bytes), Ok(display_list));
+}
+
+#[test]
+fn truncated_display_list_is_rejected() {
+    let bytes = test_display_list().to_bytes();
+    // every prefix of the input is invalid, none of them may panic
+    for len in 0..bytes.len() {
+        assert!(CachedDisplayList::from_bytes(&bytes[..len]).is_err(), "prefix of {} bytes", len);
+    }
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(
+        CachedDisplayList::from_bytes(&trailing),
+        Err(DisplayListDecodeError::TrailingBytes(1))
+    );
+}
+
+#[test]
+fn corrupt_display_list_is_rejected() {
+    let bytes = test_display_list().to_bytes();
+
+    let mut magic = bytes.clone();
+    magic[0] = b'X';
+    assert_eq!(CachedDisplayList::from_bytes(&magic), Err(DisplayListDecodeError::InvalidMagic));
+
+    let mut version = bytes.clone();
+    version[4] = 99;
+    assert_eq!(
+        CachedDisplayList::from_bytes(&version),
+        Err(DisplayListDecodeError::UnsupportedVersion(99))
+    );
+
+    // the root message tag comes right after the header
+    let mut tag = bytes.clone();
+    tag[8] = 200;
+    assert_eq!(
+        CachedDisplayList::from_bytes(&tag),
+        Err(DisplayListDecodeError::InvalidTag { type_name: "DisplayListMsg", tag: 200 })
+    );
+
+    // flipping any single byte must not panic (huge lengths must not be allocated upfront)
+    for i in 0..bytes.len() {
+        let mut corrupt = bytes.clone();
+        corrupt[i] ^= 0xff;
+        let _ = CachedDisplayList::from_bytes(&corrupt);
+    }
+}
+
+#[test]
+fn deeply_nested_display_list_is_rejected() {
+    let mut root = DisplayListFrame::root(LayoutSize::new(10, 10), LayoutPoint::zero());
+    for _ in 0..DISPLAY_LIST_MAX_DEPTH {
+        let mut parent = DisplayListFrame::root(LayoutSize::new(10, 10), LayoutPoint::zero());
+        parent.children.push(DisplayListMsg::Frame(root));
+        root = parent;
+    }
+    let display_list = CachedDisplayList {
+        root: DisplayListMsg::Frame(root),
+        root_size: LogicalSize::new(10.0, 10.0),
+    };
+    assert_eq!(
+        CachedDisplayList::from_bytes(&display_list.to_bytes()),
+        Err(DisplayListDecodeError::NestingTooDeep)
+    );
+}
@@ -0,0 +1,66 @@
+//! Matching of driver workaround rules against the GL driver strings
+
+extern crate azul_core;
+
+use azul_core::gl::{GlDriverInfo, GlQuirkRule, GlQuirks};
+
+fn driver(vendor: &str, renderer: &str, version: &str) -> GlDriverInfo {
+    GlDriverInfo {
+        vendor: vendor.to_string().into(),
+        renderer: renderer.to_string().into(),
+        version: version.to_string().into(),
+    }
+}
+
+fn version_rule(version: &str) -> GlQuirkRule {
+    GlQuirkRule::new().with_version(version.to_string().into())
+}
+
+const MESA_21_1: &str = "4.6 (Core Profile) Mesa 21.1.8";
+const MESA_21_2: &str = "4.6 (Core Profile) Mesa 21.2.0-devel (git-1e3f2a4)";
+const NVIDIA_470: &str = "4.6.0 NVIDIA 470.57.02";
+
+#[test]
+fn test_quirk_rule_substring() {
+    let intel = driver("Intel", "Mesa Intel(R) UHD Graphics 620 (KBL GT2)", MESA_21_1);
+    assert!(GlQuirkRule::new().with_renderer("uhd graphics".to_string().into()).matches(&intel));
+    assert!(!GlQuirkRule::new().with_vendor("NVIDIA".to_string().into()).matches(&intel));
+    assert!(version_rule("Mesa 21.1").matches(&intel));
+}
+
+#[test]
+fn test_quirk_rule_version_range() {
+    let mesa_21_1 = driver("Intel", "Mesa Intel(R) UHD Graphics 620", MESA_21_1);
+    let mesa_21_2 = driver("AMD", "AMD Radeon RX 580 (polaris10, LLVM 12.0.0)", MESA_21_2);
+    let nvidia = driver("NVIDIA Corporation", "NVIDIA GeForce GTX 1060/PCIe/SSE2", NVIDIA_470);
+
+    assert!(version_rule("Mesa < 21.2").matches(&mesa_21_1));
+    assert!(!version_rule("Mesa < 21.2").matches(&mesa_21_2));
+    assert!(version_rule("mesa >= 21.2").matches(&mesa_21_2));
+    assert!(version_rule("Mesa == 21.2").matches(&mesa_21_2));
+    assert!(version_rule("Mesa <= 21.1.8").matches(&mesa_21_1));
+    assert!(!version_rule("Mesa > 21.1.8").matches(&mesa_21_1));
+    // no "Mesa" in the version string
+    assert!(!version_rule("Mesa < 21.2").matches(&nvidia));
+
+    assert!(version_rule("NVIDIA < 470.57.3").matches(&nvidia));
+    assert!(!version_rule("NVIDIA < 470").matches(&nvidia));
+
+    // without a prefix, the OpenGL version is compared
+    assert!(version_rule(">= 4.5").matches(&nvidia));
+    assert!(!version_rule("< 4.6").matches(&mesa_21_1));
+}
+
+#[test]
+fn test_quirk_rule_resolve() {
+    let rules = [
+        version_rule("Mesa < 21.2").with_enable(GlQuirks { disable_partial_present: true, ..GlQuirks::default() }),
+        GlQuirkRule::new()
+            .with_renderer("Radeon".to_string().into())
+            .with_disable(GlQuirks { disable_partial_present: true, ..GlQuirks::default() }),
+    ];
+    let intel = driver("Intel", "Mesa Intel(R) UHD Graphics 620", MESA_21_1);
+    let amd = driver("AMD", "AMD Radeon RX 580", "4.6 (Core Profile) Mesa 20.3.5");
+    assert!(GlQuirkRule::resolve(&rules, &intel).disable_partial_present);
+    assert!(GlQuirkRule::resolve(&rules, &amd).is_empty());
+}
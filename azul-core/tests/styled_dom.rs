@@ -0,0 +1,446 @@
+//! Restyling, pseudo-elements and selector queries of a `StyledDom`
+
+extern crate azul_core;
+extern crate azul_css;
+extern crate azul_css_parser;
+
+use azul_core::{
+    dom::{Dom, IdOrClass, IdOrClassVec},
+    id_tree::NodeId,
+    styled_dom::{PseudoElement, StyledDom, StyledNodeState},
+};
+use azul_css::CssPropertyType;
+use azul_css_parser::CssApiWrapper;
+
+fn classes(c: &[&str]) -> IdOrClassVec {
+    c.iter()
+        .map(|c| IdOrClass::Class(c.to_string().into()))
+        .collect::<Vec<_>>()
+        .into()
+}
+
+// Styles the DOM with the given stylesheet
+fn test_dom(mut dom: Dom, css: &str) -> StyledDom {
+    let css = azul_css_parser::new_from_str(css).unwrap();
+    StyledDom::new(&mut dom, CssApiWrapper { css })
+}
+
+const RESTYLE_TEST_CSS: &str = "
+    .root { font-size: 10px; }
+    .selected { color: #ff0000; }
+    .selected .inner { width: 50px; }
+    .item.selected:hover { background: #00ff00; }
+    .selected .inner:focus { color: #0000ff; }
+";
+
+//  0: [div .root]
+//   |-- 1: [div .item (+ classes)]
+//   |    |-- 2: [text]
+//   |    |-- 3: [div .inner]
+//   |-- 4: [div .item]
+fn restyle_test_dom(item_classes: &[&str]) -> StyledDom {
+    let dom = Dom::div()
+        .with_ids_and_classes(classes(&["root"]))
+        .with_child(
+            Dom::div()
+                .with_ids_and_classes(classes(item_classes))
+                .with_child(Dom::text("text"))
+                .with_child(Dom::div().with_ids_and_classes(classes(&["inner"]))),
+        )
+        .with_child(Dom::div().with_ids_and_classes(classes(&["item"])));
+
+    test_dom(dom, RESTYLE_TEST_CSS)
+}
+
+// Compares the CSS properties and the presence of tag IDs of the nodes
+fn assert_same_styles(a: &StyledDom, b: &StyledDom, nodes: core::ops::Range<usize>) {
+    let ca = a.get_css_property_cache();
+    let cb = b.get_css_property_cache();
+    for n in nodes {
+        let n = NodeId::new(n);
+        for (pa, pb) in [
+            (&ca.css_normal_props, &cb.css_normal_props),
+            (&ca.css_hover_props, &cb.css_hover_props),
+            (&ca.css_active_props, &cb.css_active_props),
+            (&ca.css_focus_props, &cb.css_focus_props),
+            (&ca.cascaded_normal_props, &cb.cascaded_normal_props),
+            (&ca.cascaded_hover_props, &cb.cascaded_hover_props),
+            (&ca.cascaded_active_props, &cb.cascaded_active_props),
+            (&ca.cascaded_focus_props, &cb.cascaded_focus_props),
+        ] {
+            assert_eq!(pa.get(&n), pb.get(&n), "node {}", n.index());
+        }
+        assert_eq!(
+            a.styled_nodes.as_container()[n].tag_id.is_some(),
+            b.styled_nodes.as_container()[n].tag_id.is_some(),
+            "tag ID of node {}",
+            n.index()
+        );
+    }
+}
+
+#[test]
+fn test_restyle_add_class() {
+    use azul_css::{ColorU, StyleTextColor};
+
+    let mut restyled = restyle_test_dom(&["item"]);
+    let before = restyled.clone();
+    let expected = restyle_test_dom(&["item", "selected"]);
+
+    let changes =
+        restyled.restyle_ids_and_classes(&NodeId::new(1), classes(&["item", "selected"]));
+
+    assert_same_styles(&restyled, &expected, 0..5);
+    // nodes outside of the subtree are untouched
+    assert_same_styles(&restyled, &before, 0..1);
+    assert_same_styles(&restyled, &before, 4..5);
+
+    // the color is inherited into the text node
+    let cache = restyled.get_css_property_cache();
+    let node_data = restyled.node_data.as_container();
+    let red = StyleTextColor { inner: ColorU { r: 255, g: 0, b: 0, a: 255 } };
+    for n in [1, 2] {
+        let n = NodeId::new(n);
+        let color = cache
+            .get_text_color(&node_data[n], &n, &StyledNodeState::default())
+            .and_then(|p| p.get_property().copied());
+        assert_eq!(color, Some(red));
+    }
+    assert!(changes.contains_key(&NodeId::new(1)));
+    assert!(changes.contains_key(&NodeId::new(2)));
+    assert!(!changes.contains_key(&NodeId::new(4)));
+
+    // new :hover rule on the node, new :focus rule on the descendant
+    assert!(restyled.styled_nodes.as_container()[NodeId::new(1)].tag_id.is_some());
+    assert!(cache.css_hover_props.contains_key(&NodeId::new(1)));
+    assert!(cache.css_focus_props.contains_key(&NodeId::new(3)));
+}
+
+#[test]
+fn test_restyle_remove_class() {
+    let mut restyled = restyle_test_dom(&["item", "selected"]);
+    let before = restyled.clone();
+    let expected = restyle_test_dom(&["item"]);
+
+    let _ = restyled.restyle_ids_and_classes(&NodeId::new(1), classes(&["item"]));
+
+    assert_same_styles(&restyled, &expected, 0..5);
+    assert_same_styles(&restyled, &before, 0..1);
+    assert_same_styles(&restyled, &before, 4..5);
+
+    let cache = restyled.get_css_property_cache();
+    assert!(restyled.styled_nodes.as_container()[NodeId::new(1)].tag_id.is_none());
+    assert!(!cache.css_hover_props.contains_key(&NodeId::new(1)));
+    assert!(!cache.css_focus_props.contains_key(&NodeId::new(3)));
+    let inherited_color = cache
+        .cascaded_normal_props
+        .get(&NodeId::new(2))
+        .and_then(|p| p.get(&CssPropertyType::TextColor));
+    assert!(inherited_color.is_none());
+}
+
+#[test]
+fn test_restyle_toggle_class() {
+    let mut restyled = restyle_test_dom(&["item"]);
+    let original = restyle_test_dom(&["item"]);
+
+    let _ = restyled.restyle_ids_and_classes(&NodeId::new(1), classes(&["item", "selected"]));
+    assert_same_styles(&restyled, &restyle_test_dom(&["item", "selected"]), 0..5);
+
+    let _ = restyled.restyle_ids_and_classes(&NodeId::new(1), classes(&["item"]));
+    assert_same_styles(&restyled, &original, 0..5);
+}
+
+#[test]
+fn test_restyle_stylesheet_only_kept_for_class_selectors() {
+    let styled_dom = restyle_test_dom(&["item"]);
+    assert_eq!(styled_dom.get_css_property_cache().stylesheets.len(), 1);
+
+    // without id / class selectors, changing the classes can't change any styles
+    let styled_dom = test_dom(
+        Dom::div().with_child(Dom::text("text")),
+        "div { color: red; } div:hover { width: 10px; }",
+    );
+    assert!(styled_dom.get_css_property_cache().stylesheets.is_empty());
+}
+
+const PSEUDO_ELEMENT_TEST_CSS: &str = "
+    .list:before { content: \"start\"; }
+    .item:nth-child(2) { width: 20px; }
+    div:first { height: 10px; }
+    .item:after { content: \"!\"; color: #ff0000; }
+    .item:hover:after { content: none; }
+    .item { counter-increment: item; }
+    .item.new:before { content: counter(item); }
+";
+
+// With the classes ["item", "new"] for the second item:
+//
+//  0: [div .list]
+//   |-- 1: [:before box]
+//   |    |-- 2: [text "start"]
+//   |-- 3: [div .item]
+//   |    |-- 4: [:after box]
+//   |         |-- 5: [text "!"]
+//   |-- 6: [div .item .new]
+//        |-- 7: [:before box]
+//        |    |-- 8: [text "2"]
+//        |-- 9: [:after box]
+//             |-- 10: [text "!"]
+fn pseudo_element_test_dom(second_item_classes: &[&str]) -> StyledDom {
+    let dom = Dom::div()
+        .with_ids_and_classes(classes(&["list"]))
+        .with_child(Dom::div().with_ids_and_classes(classes(&["item"])))
+        .with_child(Dom::div().with_ids_and_classes(classes(second_item_classes)));
+
+    test_dom(dom, PSEUDO_ELEMENT_TEST_CSS)
+}
+
+fn pseudo_element_test_text(styled_dom: &StyledDom, node_id: usize) -> String {
+    use azul_core::dom::NodeType;
+    match styled_dom.node_data.as_container()[NodeId::new(node_id)].get_node_type() {
+        NodeType::Text(t) => t.as_str().to_string(),
+        other => panic!("node {} is not a text node: {:?}", node_id, other),
+    }
+}
+
+#[test]
+fn test_pseudo_element_generation() {
+    use azul_css::CssPathPseudoSelector::{After, Before};
+
+    let styled_dom = pseudo_element_test_dom(&["item", "new"]);
+    let cache = styled_dom.get_css_property_cache();
+
+    assert_eq!(styled_dom.node_count(), 11);
+    assert_eq!(
+        cache.pseudo_elements.keys().copied().collect::<Vec<_>>(),
+        vec![NodeId::new(1), NodeId::new(4), NodeId::new(7), NodeId::new(9)]
+    );
+    assert_eq!(
+        cache.pseudo_elements.get(&NodeId::new(1)),
+        Some(&PseudoElement { origin: NodeId::new(0), selector: Before })
+    );
+    assert_eq!(
+        cache.pseudo_elements.get(&NodeId::new(9)),
+        Some(&PseudoElement { origin: NodeId::new(6), selector: After })
+    );
+
+    // the :before box is the first child, the :after box the last child
+    let node_hierarchy = styled_dom.node_hierarchy.as_container();
+    assert_eq!(node_hierarchy[NodeId::new(0)].first_child_id(NodeId::new(0)), Some(NodeId::new(1)));
+    assert_eq!(node_hierarchy[NodeId::new(0)].last_child_id(), Some(NodeId::new(6)));
+    assert_eq!(node_hierarchy[NodeId::new(6)].first_child_id(NodeId::new(6)), Some(NodeId::new(7)));
+    assert_eq!(node_hierarchy[NodeId::new(6)].last_child_id(), Some(NodeId::new(9)));
+    assert_eq!(node_hierarchy[NodeId::new(6)].previous_sibling_id(), Some(NodeId::new(3)));
+
+    assert_eq!(pseudo_element_test_text(&styled_dom, 2), "start");
+    assert_eq!(pseudo_element_test_text(&styled_dom, 5), "!");
+    assert_eq!(pseudo_element_test_text(&styled_dom, 10), "!");
+    // the counter is incremented by both items
+    assert_eq!(pseudo_element_test_text(&styled_dom, 8), "2");
+
+    // generated nodes are not hit-tested on their own
+    for n in [1, 2, 4, 5, 7, 8, 9, 10] {
+        assert!(styled_dom.styled_nodes.as_container()[NodeId::new(n)].tag_id.is_none());
+    }
+}
+
+#[test]
+fn test_pseudo_element_nth_child() {
+    use azul_css::{LayoutHeight, LayoutWidth, PixelValue};
+
+    let styled_dom = pseudo_element_test_dom(&["item", "new"]);
+    let cache = styled_dom.get_css_property_cache();
+    let node_data = styled_dom.node_data.as_container();
+    let state = StyledNodeState::default();
+
+    // the :before box of the list doesn't shift the items
+    let cascade_info = styled_dom.cascade_info.as_container();
+    assert_eq!(cascade_info[NodeId::new(3)].index_in_parent, 0);
+    assert_eq!(cascade_info[NodeId::new(6)].index_in_parent, 1);
+    assert!(cascade_info[NodeId::new(6)].is_last_child);
+
+    let width = |n: usize| {
+        let n = NodeId::new(n);
+        cache
+            .get_width(&node_data[n], &n, &state)
+            .and_then(|p| p.get_property().copied())
+    };
+    let height = |n: usize| {
+        let n = NodeId::new(n);
+        cache
+            .get_height(&node_data[n], &n, &state)
+            .and_then(|p| p.get_property().copied())
+    };
+
+    assert_eq!(width(3), None);
+    assert_eq!(width(6), Some(LayoutWidth { inner: PixelValue::px(20.0) }));
+    assert_eq!(height(3), Some(LayoutHeight { inner: PixelValue::px(10.0) }));
+    assert_eq!(height(6), None);
+
+    // the generated boxes are not matched by selectors
+    for n in [1, 4, 7, 9] {
+        assert_eq!(height(n), None, "node {}", n);
+    }
+
+    assert_eq!(
+        styled_dom.query_selector_all("div").unwrap(),
+        vec![NodeId::new(0), NodeId::new(3), NodeId::new(6)]
+    );
+}
+
+#[test]
+fn test_pseudo_element_restyle_hover() {
+    let mut styled_dom = pseudo_element_test_dom(&["item"]);
+
+    // .item:hover:after { content: none; } hides the :after box of the hovered item
+    let changes = styled_dom.restyle_nodes_hover(&[NodeId::new(3)], true);
+    assert!(changes.contains_key(&NodeId::new(4)));
+    assert!(styled_dom.styled_nodes.as_container()[NodeId::new(4)].state.hover);
+    assert!(styled_dom.styled_nodes.as_container()[NodeId::new(5)].state.hover);
+
+    let changed_texts = styled_dom.update_pseudo_element_texts();
+    assert_eq!(changed_texts.get(&NodeId::new(5)).map(|s| s.as_str()), Some(""));
+    assert_eq!(changed_texts.len(), 1);
+    assert_eq!(pseudo_element_test_text(&styled_dom, 8), "!");
+
+    // the properties of the hidden box are reset
+    let cache = styled_dom.get_css_property_cache();
+    let node_data = styled_dom.node_data.as_container();
+    let n = NodeId::new(4);
+    let state = &styled_dom.styled_nodes.as_container()[n].state;
+    assert!(cache
+        .get_text_color(&node_data[n], &n, state)
+        .and_then(|p| p.get_property())
+        .is_none());
+
+    let _ = styled_dom.restyle_nodes_hover(&[NodeId::new(3)], false);
+    let changed_texts = styled_dom.update_pseudo_element_texts();
+    assert_eq!(changed_texts.get(&NodeId::new(5)).map(|s| s.as_str()), Some("!"));
+}
+
+#[test]
+fn test_pseudo_element_restyle_class() {
+    let mut styled_dom = pseudo_element_test_dom(&["item", "new"]);
+
+    let changes =
+        styled_dom.restyle_ids_and_classes(&NodeId::new(6), classes(&["item"]));
+    assert!(changes.contains_key(&NodeId::new(7)));
+
+    let changed_texts = styled_dom.update_pseudo_element_texts();
+    assert_eq!(changed_texts.get(&NodeId::new(8)).map(|s| s.as_str()), Some(""));
+    assert!(styled_dom
+        .get_css_property_cache()
+        .css_normal_props
+        .get(&NodeId::new(7))
+        .is_none());
+
+    let _ = styled_dom
+        .restyle_ids_and_classes(&NodeId::new(6), classes(&["item", "new"]));
+    let _ = styled_dom.update_pseudo_element_texts();
+    assert_eq!(pseudo_element_test_text(&styled_dom, 8), "2");
+}
+
+#[test]
+fn test_pseudo_element_counters() {
+    let item = || Dom::div().with_ids_and_classes(classes(&["item"]));
+    let list = || Dom::div().with_ids_and_classes(classes(&["list"]));
+
+    // the nested list starts a new counter, the outer counter continues after it
+    let dom = list()
+        .with_child(item())
+        .with_child(item().with_child(list().with_child(item()).with_child(item())))
+        .with_child(item());
+
+    let styled_dom = test_dom(
+        dom,
+        "
+        .list { counter-reset: item; }
+        .item { counter-increment: item; }
+        .item:before { content: counter(item); }
+        .list:after { content: counter(item); }
+        ",
+    );
+
+    let texts = styled_dom
+        .get_css_property_cache()
+        .pseudo_elements
+        .keys()
+        .map(|box_id| pseudo_element_test_text(&styled_dom, box_id.index() + 1))
+        .collect::<Vec<_>>();
+
+    assert_eq!(texts, vec!["1", "2", "1", "2", "2", "3", "3"]);
+}
+
+//  0: [div #main .sidebar]
+//   |-- 1: [div .item]
+//   |    |-- 2: [text]
+//   |-- 3: [div .group]
+//        |-- 4: [div .item]
+fn query_selector_test_dom() -> StyledDom {
+    let dom = Dom::div()
+        .with_ids_and_classes(
+            vec![
+                IdOrClass::Id("main".to_string().into()),
+                IdOrClass::Class("sidebar".to_string().into()),
+            ]
+            .into(),
+        )
+        .with_child(
+            Dom::div()
+                .with_ids_and_classes(classes(&["item"]))
+                .with_child(Dom::text("text")),
+        )
+        .with_child(
+            Dom::div()
+                .with_ids_and_classes(classes(&["group"]))
+                .with_child(Dom::div().with_ids_and_classes(classes(&["item"]))),
+        );
+
+    test_dom(dom, "")
+}
+
+#[test]
+fn test_query_selector_id_and_class() {
+    let styled_dom = query_selector_test_dom();
+    let ids = |v: &[usize]| v.iter().map(|i| NodeId::new(*i)).collect::<Vec<_>>();
+
+    assert_eq!(styled_dom.query_selector("#main").unwrap(), Some(NodeId::new(0)));
+    assert_eq!(styled_dom.query_selector("div.sidebar").unwrap(), Some(NodeId::new(0)));
+    assert_eq!(styled_dom.query_selector(".item").unwrap(), Some(NodeId::new(1)));
+    assert_eq!(styled_dom.query_selector_all(".item").unwrap(), ids(&[1, 4]));
+}
+
+#[test]
+fn test_query_selector_combinators() {
+    let styled_dom = query_selector_test_dom();
+    let ids = |v: &[usize]| v.iter().map(|i| NodeId::new(*i)).collect::<Vec<_>>();
+
+    // descendant combinator
+    assert_eq!(styled_dom.query_selector_all("#main .item").unwrap(), ids(&[1, 4]));
+    assert_eq!(styled_dom.query_selector_all(".group .item").unwrap(), ids(&[4]));
+    // the nearest div ancestor of the first item has no #main ancestor itself
+    assert_eq!(styled_dom.query_selector_all("#main div .item").unwrap(), ids(&[4]));
+    assert_eq!(styled_dom.query_selector_all("#main div > .item").unwrap(), ids(&[4]));
+    // child combinator
+    assert_eq!(styled_dom.query_selector_all("#main > .item").unwrap(), ids(&[1]));
+    assert_eq!(styled_dom.query_selector_all(".sidebar > .group > .item").unwrap(), ids(&[4]));
+
+    let css_path = azul_css_parser::parse_css_path(".group > .item").unwrap();
+    assert_eq!(styled_dom.query_selector_path(&css_path), Some(NodeId::new(4)));
+    assert_eq!(styled_dom.query_selector_all_path(&css_path), ids(&[4]));
+}
+
+#[test]
+fn test_query_selector_no_match() {
+    let styled_dom = query_selector_test_dom();
+
+    assert_eq!(styled_dom.query_selector(".missing").unwrap(), None);
+    assert!(styled_dom.query_selector_all(".missing").unwrap().is_empty());
+    // .group is not a child of .item
+    assert_eq!(styled_dom.query_selector(".item > .group").unwrap(), None);
+
+    let css_path = azul_css_parser::parse_css_path("#other").unwrap();
+    assert_eq!(styled_dom.query_selector_path(&css_path), None);
+    assert!(styled_dom.query_selector_all_path(&css_path).is_empty());
+}
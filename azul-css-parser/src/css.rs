@@ -16,6 +16,7 @@ use azul_css::{
     CssPropertyType, CssRuleBlock, CssPath, CssPathSelector,
     CssNthChildSelector, CssPathPseudoSelector, CssNthChildSelector::*,
     NodeTypeTag, NodeTypeTagParseError, CombinedCssPropertyType, CssKeyMap,
+    SystemStyle, SystemColors, SystemCssProperty, CssMediaCondition, CssProperty,
 };
 
 #[derive(Debug, Default, PartialEq, PartialOrd, Clone)]
@@ -163,6 +164,13 @@ fn pseudo_selector_from_str<'a>(selector: &'a str, value: Option<&'a str>)
         "hover" => Ok(CssPathPseudoSelector::Hover),
         "active" => Ok(CssPathPseudoSelector::Active),
         "focus" => Ok(CssPathPseudoSelector::Focus),
+        "disabled" => Ok(CssPathPseudoSelector::Disabled),
+        "checked" => Ok(CssPathPseudoSelector::Checked),
+        "selected" => Ok(CssPathPseudoSelector::Selected),
+        "indeterminate" => Ok(CssPathPseudoSelector::Indeterminate),
+        // CSS2 syntax of the pseudo-elements (`:before`), also accepted as `::before`
+        "before" | ":before" => Ok(CssPathPseudoSelector::Before),
+        "after" | ":after" => Ok(CssPathPseudoSelector::After),
         "nth-child" => {
             let value = value.ok_or(CssPseudoSelectorParseError::EmptyNthChild)?;
             let parsed = parse_nth_child_selector(value)?;
@@ -248,6 +256,12 @@ fn test_css_pseudo_selector_parse() {
         (("hover", None), Hover),
         (("active", None), Active),
         (("focus", None), Focus),
+        (("disabled", None), Disabled),
+        (("checked", None), Checked),
+        (("selected", None), Selected),
+        (("indeterminate", None), Indeterminate),
+        (("before", None), Before),
+        (("after", None), After),
         (("nth-child", Some("4")), NthChild(Number(4))),
         (("nth-child", Some("even")), NthChild(Even)),
         (("nth-child", Some("odd")), NthChild(Odd)),
@@ -311,9 +325,178 @@ impl<'a> fmt::Display for CssParseError<'a> {
 }
 
 pub fn new_from_str<'a>(css_string: &'a str) -> Result<Css, CssParseError<'a>> {
-    let mut tokenizer = Tokenizer::new(css_string);
-    let (stylesheet, _warnings) = new_from_str_inner(css_string, &mut tokenizer)?;
-    Ok(Css { stylesheets: vec![stylesheet].into() })
+    let mut rules = Vec::new();
+    for (segment, media) in split_media_queries(css_string)? {
+        let mut tokenizer = Tokenizer::new(segment);
+        let (stylesheet, _warnings) = new_from_str_inner(segment, &mut tokenizer)?;
+        rules.extend(stylesheet.rules.into_library_owned_vec().into_iter().map(|mut rule| {
+            rule.media = media;
+            rule
+        }));
+    }
+    Ok(Css { stylesheets: vec![Stylesheet::from(rules)].into() })
+}
+
+/// Splits the CSS at the top-level `@media` blocks and returns the parts of the
+/// source in source order, together with the condition under which they apply -
+/// the contents of `@media` blocks that can never match are skipped
+fn split_media_queries<'a>(css_string: &'a str)
+-> Result<Vec<(&'a str, CssMediaCondition)>, CssParseError<'a>>
+{
+    const MEDIA: &[u8] = b"@media";
+
+    let bytes = css_string.as_bytes();
+    let mut segments = Vec::new();
+    let mut segment_start = 0;
+    let mut depth = 0_usize;
+    let mut i = 0;
+
+    while i < bytes.len() {
+
+        if let Some(end) = skip_comment_or_string(bytes, i) {
+            i = end;
+            continue;
+        }
+
+        match bytes[i] {
+            b'{' => depth += 1,
+            b'}' => depth = depth.saturating_sub(1),
+            b'@' if depth == 0 &&
+                    bytes.len() >= i + MEDIA.len() &&
+                    bytes[i..i + MEDIA.len()].eq_ignore_ascii_case(MEDIA) => {
+
+                let unclosed_block = || CssParseError {
+                    css_string,
+                    error: CssParseErrorInner::UnclosedBlock,
+                    location: (ErrorLocation { original_pos: i }, ErrorLocation { original_pos: bytes.len() }),
+                };
+
+                let block_start = find_block_start(bytes, i).ok_or_else(unclosed_block)?;
+                let block_end = find_block_end(bytes, block_start).ok_or_else(unclosed_block)?;
+
+                segments.push((&css_string[segment_start..i], CssMediaCondition::ALWAYS));
+                let condition = &css_string[i + MEDIA.len()..block_start];
+                let media = CssMediaCondition::new(|system_style| media_condition_matches(condition, system_style));
+                if !media.is_never() {
+                    segments.push((&css_string[block_start + 1..block_end], media));
+                }
+
+                i = block_end + 1;
+                segment_start = i;
+                continue;
+            },
+            _ => { },
+        }
+
+        i += 1;
+    }
+
+    segments.push((&css_string[segment_start..], CssMediaCondition::ALWAYS));
+
+    Ok(segments)
+}
+
+/// If a comment or a string starts at `i`, returns the position after its end
+fn skip_comment_or_string(bytes: &[u8], i: usize) -> Option<usize> {
+    match bytes[i] {
+        b'/' if bytes.get(i + 1) == Some(&b'*') => {
+            let end = bytes[i + 2..].windows(2).position(|w| w == b"*/");
+            Some(end.map(|e| i + 2 + e + 2).unwrap_or(bytes.len()))
+        },
+        quote @ b'"' | quote @ b'\'' => {
+            let mut j = i + 1;
+            while j < bytes.len() && bytes[j] != quote {
+                j += if bytes[j] == b'\\' { 2 } else { 1 };
+            }
+            Some((j + 1).min(bytes.len()))
+        },
+        _ => None,
+    }
+}
+
+/// Returns the position of the `{` that opens the block of an at-rule
+fn find_block_start(bytes: &[u8], mut i: usize) -> Option<usize> {
+    while i < bytes.len() {
+        if let Some(end) = skip_comment_or_string(bytes, i) {
+            i = end;
+            continue;
+        }
+        match bytes[i] {
+            b'{' => return Some(i),
+            b';' | b'}' => return None,
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Returns the position of the `}` that closes the block opened at `block_start`
+fn find_block_end(bytes: &[u8], block_start: usize) -> Option<usize> {
+    let mut depth = 0_usize;
+    let mut i = block_start;
+    while i < bytes.len() {
+        if let Some(end) = skip_comment_or_string(bytes, i) {
+            i = end;
+            continue;
+        }
+        match bytes[i] {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            },
+            _ => { },
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Evaluates a media query list such as `screen and (forced-colors: active)`,
+/// `(prefers-reduced-motion: reduce)` or `(pointer: coarse)`,
+/// unknown media types and media features never match
+fn media_condition_matches(condition: &str, system_style: &SystemStyle) -> bool {
+
+    if condition.trim().is_empty() {
+        return true;
+    }
+
+    condition.split(',').any(|query| {
+        query.split(" and ").all(|part| {
+            let part = part.trim();
+            let part = part.strip_prefix("only ").unwrap_or(part).trim();
+            match part {
+                "all" | "screen" => true,
+                _ => {
+                    let feature = match part.strip_prefix('(').and_then(|p| p.strip_suffix(')')) {
+                        Some(s) => s,
+                        None => return false,
+                    };
+                    let (name, value) = match feature.split_once(':') {
+                        Some((name, value)) => (name.trim(), Some(value.trim())),
+                        None => (feature.trim(), None),
+                    };
+                    match (name, value) {
+                        ("forced-colors", None) |
+                        ("forced-colors", Some("active")) => system_style.forced_colors,
+                        ("forced-colors", Some("none")) => !system_style.forced_colors,
+                        ("prefers-reduced-motion", None) |
+                        ("prefers-reduced-motion", Some("reduce")) => system_style.prefers_reduced_motion,
+                        ("prefers-reduced-motion", Some("no-preference")) => !system_style.prefers_reduced_motion,
+                        ("pointer", None) => true,
+                        ("pointer", Some("fine")) => !system_style.pointer_coarse,
+                        ("pointer", Some("coarse")) => system_style.pointer_coarse,
+                        ("hover", None) |
+                        ("hover", Some("hover")) => !system_style.pointer_coarse,
+                        ("hover", Some("none")) => system_style.pointer_coarse,
+                        _ => false,
+                    }
+                }
+            }
+        })
+    })
 }
 
 /// Returns the location of where the parser is currently in the document
@@ -627,6 +810,7 @@ fn unparsed_css_blocks_to_stylesheet<'a>(css_blocks: Vec<UnparsedCssRuleBlock<'a
         Ok(CssRuleBlock {
             path: unparsed_css_block.path.into(),
             declarations: declarations.into(),
+            media: CssMediaCondition::ALWAYS,
         })
     }).collect::<Result<Vec<CssRuleBlock>, CssParseError>>()?;
 
@@ -651,11 +835,19 @@ pub fn parse_css_declaration<'a>(
             return Err(VarOnShorthandProperty { key: combined_key, value: unparsed_css_value });
         } else {
             // margin: 10px;
-            let parsed_css_properties =
+            let (parsed_css_properties, uses_system_colors) = css_parser::with_system_colors(&SystemColors::DEFAULT, || {
                 css_parser::parse_combined_css_property(combined_key, unparsed_css_value)
-                .map_err(|e| DynamicCssParseError(e.into()))?;
+            });
+            let parsed_css_properties = parsed_css_properties.map_err(|e| DynamicCssParseError(e.into()))?;
 
-            declarations.extend(parsed_css_properties.into_iter().map(|val| CssDeclaration::Static(val)));
+            if uses_system_colors {
+                // border: 1px solid ButtonText;
+                declarations.extend(parsed_css_properties.into_iter().map(|val| {
+                    new_system_declaration(unparsed_css_key, unparsed_css_value, val)
+                }));
+            } else {
+                declarations.extend(parsed_css_properties.into_iter().map(|val| CssDeclaration::Static(val)));
+            }
         }
     } else if let Some(normal_key) = CssPropertyType::from_str(unparsed_css_key, css_key_map) {
         if let Some(css_var) = check_if_value_is_css_var(unparsed_css_value) {
@@ -671,11 +863,17 @@ pub fn parse_css_declaration<'a>(
             }));
         } else {
             // margin-left: 10px;
-            let parsed_css_value =
+            let (parsed_css_value, uses_system_colors) = css_parser::with_system_colors(&SystemColors::DEFAULT, || {
                 css_parser::parse_css_property(normal_key, unparsed_css_value)
-                .map_err(|e| DynamicCssParseError(e.into()))?;
+            });
+            let parsed_css_value = parsed_css_value.map_err(|e| DynamicCssParseError(e.into()))?;
 
-            declarations.push(CssDeclaration::Static(parsed_css_value));
+            if uses_system_colors {
+                // color: CanvasText;
+                declarations.push(new_system_declaration(unparsed_css_key, unparsed_css_value, parsed_css_value));
+            } else {
+                declarations.push(CssDeclaration::Static(parsed_css_value));
+            }
         }
     } else {
         // asldfkjasdf: 10px;
@@ -688,6 +886,66 @@ pub fn parse_css_declaration<'a>(
     Ok(())
 }
 
+fn new_system_declaration(unparsed_css_key: &str, unparsed_css_value: &str, default_value: CssProperty) -> CssDeclaration {
+    CssDeclaration::System(SystemCssProperty {
+        key: unparsed_css_key.trim().to_string().into(),
+        value: unparsed_css_value.trim().to_string().into(),
+        default_value,
+    })
+}
+
+/// Resolves a declaration that uses system colors with the given colors
+/// (falls back to the default value if the value can't be parsed)
+pub fn resolve_system_css_property(
+    system_property: &SystemCssProperty,
+    colors: &SystemColors,
+    css_key_map: &CssKeyMap,
+) -> CssProperty {
+
+    let value = system_property.value.as_str();
+    let property_type = system_property.default_value.get_type();
+
+    let (resolved, _) = css_parser::with_system_colors(colors, || {
+        match CombinedCssPropertyType::from_str(system_property.key.as_str(), css_key_map) {
+            Some(combined_key) => {
+                css_parser::parse_combined_css_property(combined_key, value)
+                .ok()
+                .and_then(|props| props.into_iter().find(|p| p.get_type() == property_type))
+            },
+            None => css_parser::parse_css_property(property_type, value).ok(),
+        }
+    });
+
+    resolved.unwrap_or_else(|| system_property.default_value.clone())
+}
+
+/// Returns the CSS without the rules of the `@media` blocks that don't match the `SystemStyle`
+/// and with the system colors resolved to the colors of the `SystemStyle` - called when a DOM
+/// is styled, so that the parsed CSS doesn't depend on the system settings
+pub fn resolve_system_style(css: &Css, system_style: &SystemStyle) -> Css {
+
+    let css_key_map = azul_css::get_css_key_map();
+
+    let stylesheets = css.stylesheets.iter().map(|stylesheet| {
+        let rules = stylesheet.rules.iter()
+        .filter(|rule| rule.media.matches(system_style))
+        .map(|rule| CssRuleBlock {
+            path: rule.path.clone(),
+            declarations: rule.declarations.iter().map(|declaration| match declaration {
+                CssDeclaration::System(s) => {
+                    CssDeclaration::Static(resolve_system_css_property(s, &system_style.colors, &css_key_map))
+                },
+                other => other.clone(),
+            }).collect::<Vec<_>>().into(),
+            media: CssMediaCondition::ALWAYS,
+        })
+        .collect::<Vec<_>>();
+        Stylesheet::from(rules)
+    }).collect::<Vec<_>>();
+
+    Css { stylesheets: stylesheets.into() }
+}
+
 fn check_if_value_is_css_var<'a>(unparsed_css_value: &'a str) -> Option<Result<(&'a str, &'a str), CssParseErrorInner<'a>>> {
 
     const DEFAULT_VARIABLE_DEFAULT: &str = "none";
@@ -754,6 +1012,7 @@ fn test_css_parse_1() {
                 a: 255,
             })].into()),
         ))].into(),
+        media: CssMediaCondition::ALWAYS,
     }].into();
 
     assert_eq!(
@@ -783,6 +1042,7 @@ fn test_css_simple_selector_parse() {
             rules: vec![CssRuleBlock {
                 path: CssPath { selectors: parsed.into() },
                 declarations: Vec::new().into(),
+                media: CssMediaCondition::ALWAYS,
             }].into(),
         }].into(),
     });
@@ -836,6 +1096,7 @@ mod stylesheet_parse {
                         selectors: vec![CssPathSelector::Class("my_class".to_string().into())].into(),
                     },
                     declarations: vec![CssDeclaration::Static(red.clone())].into(),
+                    media: CssMediaCondition::ALWAYS,
                 },
             ].into();
             test_css(css_1, expected_rules);
@@ -848,10 +1109,12 @@ mod stylesheet_parse {
                 CssRuleBlock {
                     path: CssPath { selectors: vec![CssPathSelector::Id("my_id".to_string().into())].into(), },
                     declarations: vec![CssDeclaration::Static(red.clone())].into(),
+                    media: CssMediaCondition::ALWAYS,
                 },
                 CssRuleBlock {
                     path: CssPath { selectors: vec![CssPathSelector::Class("my_class".to_string().into())].into(), },
                     declarations: vec![CssDeclaration::Static(blue.clone())].into(),
+                    media: CssMediaCondition::ALWAYS,
                 },
             ];
             test_css(css_2, expected_rules);
@@ -864,14 +1127,17 @@ mod stylesheet_parse {
                 CssRuleBlock {
                     path: CssPath { selectors: vec![CssPathSelector::Global].into() },
                     declarations: vec![CssDeclaration::Static(black.clone())].into(),
+                    media: CssMediaCondition::ALWAYS,
                 },
                 CssRuleBlock {
                     path: CssPath { selectors: vec![CssPathSelector::Class("my_class".to_string().into()), CssPathSelector::Id("my_id".to_string().into())].into(), },
                     declarations: vec![CssDeclaration::Static(red.clone())].into(),
+                    media: CssMediaCondition::ALWAYS,
                 },
                 CssRuleBlock {
                     path: CssPath { selectors: vec![CssPathSelector::Class("my_class".to_string().into())].into() },
                     declarations: vec![CssDeclaration::Static(blue.clone())].into(),
+                    media: CssMediaCondition::ALWAYS,
                 },
             ].into();
             test_css(css_3, expected_rules);
@@ -895,11 +1161,11 @@ fn test_multiple_rules() {
 
     let expected_rules = vec![
         // Rules are sorted by order of appearance in source string
-        CssRuleBlock { path: CssPath { selectors: vec![Global].into() }, declarations: Vec::new().into() },
-        CssRuleBlock { path: CssPath { selectors: vec![Global, Type(NodeTypeTag::Div), Class("my_class".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-        CssRuleBlock { path: CssPath { selectors: vec![Global, Type(NodeTypeTag::Div), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-        CssRuleBlock { path: CssPath { selectors: vec![Global, Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-        CssRuleBlock { path: CssPath { selectors: vec![Type(NodeTypeTag::Div), Class("my_class".to_string().into()), Class("specific".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
+        CssRuleBlock { path: CssPath { selectors: vec![Global].into() }, declarations: Vec::new().into(), media: CssMediaCondition::ALWAYS },
+        CssRuleBlock { path: CssPath { selectors: vec![Global, Type(NodeTypeTag::Div), Class("my_class".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media: CssMediaCondition::ALWAYS },
+        CssRuleBlock { path: CssPath { selectors: vec![Global, Type(NodeTypeTag::Div), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media: CssMediaCondition::ALWAYS },
+        CssRuleBlock { path: CssPath { selectors: vec![Global, Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media: CssMediaCondition::ALWAYS },
+        CssRuleBlock { path: CssPath { selectors: vec![Type(NodeTypeTag::Div), Class("my_class".to_string().into()), Class("specific".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media: CssMediaCondition::ALWAYS },
     ];
 
     assert_eq!(parsed_css, Css { stylesheets: vec![expected_rules.into()].into() });
@@ -933,6 +1199,7 @@ fn test_case_issue_93() {
             declarations: vec![CssDeclaration::Static(CssProperty::TextColor(
                 CssPropertyValue::Exact(StyleTextColor { inner: color }),
             ))].into(),
+            media: CssMediaCondition::ALWAYS,
         }
     }
 
@@ -943,4 +1210,113 @@ fn test_case_issue_93() {
     ];
 
     assert_eq!(parsed_css, Css { stylesheets: vec![expected_rules.into()].into() });
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_media_forced_colors() {
+
+    let css = "
+        .a { color: red; }
+        @media (forced-colors: active) {
+            .a { color: CanvasText; }
+        }
+        @media screen and (forced-colors: none) {
+            .b { color: blue; }
+        }
+    ";
+
+    let default_style = SystemStyle::DEFAULT;
+    let forced_style = SystemStyle { forced_colors: true, .. SystemStyle::DEFAULT };
+
+    let parsed_css = new_from_str(css).unwrap();
+    let rules = parsed_css.rules().collect::<Vec<_>>();
+    assert_eq!(rules.len(), 3);
+    assert!(rules[0].media.is_always());
+    assert!(!rules[1].media.matches(&default_style));
+    assert!(rules[1].media.matches(&forced_style));
+    assert!(rules[2].media.matches(&default_style));
+    assert!(!rules[2].media.matches(&forced_style));
+
+    let resolved_default = resolve_system_style(&parsed_css, &default_style);
+    assert_eq!(resolved_default.rules().count(), 2);
+    let resolved_forced = resolve_system_style(&parsed_css, &forced_style);
+    assert_eq!(resolved_forced.rules().count(), 2);
+    assert!(resolved_forced.rules().all(|r| r.path.selectors.as_ref() != &[CssPathSelector::Class("b".to_string().into())]));
+
+    // blocks that can never match are skipped
+    assert_eq!(new_from_str("@media print { .a { color: red; } }").unwrap().rules().count(), 0);
+
+    assert!(split_media_queries("@media (forced-colors: active) { .a { }").is_err());
+}
+
+#[test]
+fn test_system_colors() {
+
+    use azul_css::*;
+
+    let parsed_css = new_from_str("
+        .a { color: CanvasText; font-family: Highlight; }
+        .b { border: 1px solid buttontext; background: image(\"Canvas\"); }
+        .c { content: \"Canvas\"; }
+    ").unwrap();
+
+    let rules = parsed_css.rules().collect::<Vec<_>>();
+    let is_system = |d: &CssDeclaration| match d { CssDeclaration::System(_) => true, _ => false };
+
+    // font names that look like system colors are not system colors
+    assert_eq!(rules[0].declarations.iter().filter(|d| is_system(d)).count(), 1);
+    // border expands to width, style and color for each side
+    assert_eq!(rules[1].declarations.iter().filter(|d| is_system(d)).count(), 12);
+    assert!(parsed_css.depends_on_system_style());
+
+    let text_color = |css: &Css| css.rules().next().unwrap().declarations.iter().find_map(|d| match d {
+        CssDeclaration::Static(CssProperty::TextColor(CssPropertyValue::Exact(c))) => Some(c.inner),
+        _ => None,
+    });
+
+    let dark_style = SystemStyle {
+        colors: SystemColors { canvas_text: ColorU::WHITE, button_text: ColorU::WHITE, .. SystemColors::DEFAULT },
+        .. SystemStyle::DEFAULT
+    };
+
+    assert_eq!(text_color(&resolve_system_style(&parsed_css, &SystemStyle::DEFAULT)), Some(ColorU::BLACK));
+    assert_eq!(text_color(&resolve_system_style(&parsed_css, &dark_style)), Some(ColorU::WHITE));
+
+    let border_color = resolve_system_style(&parsed_css, &dark_style).rules().nth(1).unwrap().declarations.iter().find_map(|d| match d {
+        CssDeclaration::Static(CssProperty::BorderTopColor(CssPropertyValue::Exact(c))) => Some(c.inner),
+        _ => None,
+    });
+    assert_eq!(border_color, Some(ColorU::WHITE));
+
+    assert!(!new_from_str(".a { color: red; }").unwrap().depends_on_system_style());
+    // image ids and strings are not parsed as colors
+    assert!(rules[2].declarations.iter().all(|d| !is_system(d)));
+}
+
+#[test]
+fn test_media_prefers_reduced_motion() {
+
+    let default_style = SystemStyle::DEFAULT;
+    let reduced_style = SystemStyle { prefers_reduced_motion: true, .. SystemStyle::DEFAULT };
+
+    assert!(!media_condition_matches("(prefers-reduced-motion: reduce)", &default_style));
+    assert!(media_condition_matches("(prefers-reduced-motion: no-preference)", &default_style));
+    assert!(media_condition_matches("(prefers-reduced-motion: reduce)", &reduced_style));
+    assert!(media_condition_matches("screen and (prefers-reduced-motion)", &reduced_style));
+    assert!(!media_condition_matches("print, (prefers-reduced-motion: no-preference)", &reduced_style));
+}
+
+#[test]
+fn test_media_pointer() {
+
+    let mouse_style = SystemStyle::DEFAULT;
+    let touch_style = SystemStyle { pointer_coarse: true, .. SystemStyle::DEFAULT };
+
+    assert!(media_condition_matches("(pointer: fine)", &mouse_style));
+    assert!(!media_condition_matches("(pointer: coarse)", &mouse_style));
+    assert!(media_condition_matches("(hover: hover)", &mouse_style));
+    assert!(media_condition_matches("(pointer: coarse)", &touch_style));
+    assert!(!media_condition_matches("(pointer: fine)", &touch_style));
+    assert!(media_condition_matches("(pointer)", &touch_style));
+    assert!(media_condition_matches("(hover: none)", &touch_style));
+}
@@ -14,19 +14,24 @@ use azul_css::{
     StyleBoxShadow, StyleBorderSide, BorderStyle,
     SizeMetric, BoxShadowClipMode, ExtendMode, OptionPercentageValue,
     BackgroundPositionHorizontal, BackgroundPositionVertical, ScrollbarStyle,
+    StyleScrollbarWidth, StyleScrollbarColor, StyleScrollbarThumbHoverColor, StyleScrollbarThumbActiveColor,
+    StyleWindowDragRegion, StyleCaretAnimationDuration, StyleScrollPadding, StyleContent,
+    StyleCounterReset, StyleCounterIncrement,
+    StyleOverflowAnchor,
+    SystemColors,
     RadialGradientSize, AzString, NormalizedLinearColorStop, NormalizedRadialColorStop,
 
     StyleFilter, StyleMixBlendMode,
     StyleTextColor, StyleFontSize, StyleFontFamily, StyleTextAlign,
-    StyleLetterSpacing, StyleLineHeight, StyleWordSpacing, StyleTabWidth,
-    StyleCursor, StyleBackgroundContent, StyleBackgroundPosition, StyleBackgroundSize,
+    StyleLetterSpacing, StyleLineHeight, StyleWordSpacing, StyleTabWidth, StyleHyphens,
+    StyleCursor, StyleCursorImage, StyleBackgroundContent, StyleBackgroundPosition, StyleBackgroundSize,
     StyleBackgroundRepeat, StyleBorderTopLeftRadius, StyleBorderTopRightRadius,
     StyleBorderBottomLeftRadius, StyleBorderBottomRightRadius, StyleBorderTopColor,
     StyleBorderRightColor, StyleBorderLeftColor, StyleBorderBottomColor,
     StyleBorderTopStyle, StyleBorderRightStyle, StyleBorderLeftStyle,
     StyleBorderBottomStyle, LayoutBorderTopWidth, LayoutBorderRightWidth,
     LayoutBorderLeftWidth, LayoutBorderBottomWidth, StyleTransform, StyleTransformOrigin,
-    StylePerspectiveOrigin, StyleBackfaceVisibility, StyleOpacity, StyleTransformVec,
+    StylePerspectiveOrigin, StyleBackfaceVisibility, StylePerspective, StyleTransformStyle, StyleOpacity, StyleTransformVec,
     StyleBackgroundContentVec, StyleBackgroundPositionVec, StyleBackgroundSizeVec,
     StyleBackgroundRepeatVec, StyleFontFamilyVec, StyleFilterVec,
 
@@ -214,6 +219,7 @@ pub fn parse_css_property<'a>(key: CssPropertyType, value: &'a str) -> Result<Cs
             LineHeight                  => parse_style_line_height(value)?.into(),
             WordSpacing                 => parse_style_word_spacing(value)?.into(),
             TabWidth                    => parse_style_tab_width(value)?.into(),
+            Hyphens                     => parse_style_hyphens(value)?.into(),
             Cursor                      => parse_style_cursor(value)?.into(),
 
             Display                     => parse_layout_display(value)?.into(),
@@ -282,12 +288,25 @@ pub fn parse_css_property<'a>(key: CssPropertyType, value: &'a str) -> Result<Cs
             BoxShadowBottom             => CssProperty::BoxShadowBottom(CssPropertyValue::Exact(parse_style_box_shadow(value)?)).into(),
 
             ScrollbarStyle              => parse_scrollbar_style(value)?.into(), // TODO: stub - always returns default style
+            ScrollbarWidth              => parse_style_scrollbar_width(value)?.into(),
+            ScrollbarColor              => parse_style_scrollbar_color(value)?.into(),
+            ScrollbarThumbHoverColor    => StyleScrollbarThumbHoverColor { inner: parse_css_color(value)? }.into(),
+            ScrollbarThumbActiveColor   => StyleScrollbarThumbActiveColor { inner: parse_css_color(value)? }.into(),
+            WindowDragRegion            => parse_style_window_drag_region(value)?.into(),
+            CaretAnimationDuration      => parse_style_caret_animation_duration(value)?.into(),
+            ScrollPadding               => parse_style_scroll_padding(value)?.into(),
+            Content                     => parse_style_content(value)?.into(),
+            CounterReset                => parse_style_counter_reset(value)?.into(),
+            CounterIncrement            => parse_style_counter_increment(value)?.into(),
+            OverflowAnchor              => parse_style_overflow_anchor(value)?.into(),
 
             Opacity                     => parse_style_opacity(value)?.into(),
             Transform                   => parse_style_transform_vec(value)?.into(),
             TransformOrigin             => parse_style_transform_origin(value)?.into(),
             PerspectiveOrigin           => parse_style_perspective_origin(value)?.into(),
             BackfaceVisibility          => parse_style_backface_visibility(value)?.into(),
+            Perspective                 => parse_style_perspective(value)?.into(),
+            TransformStyle              => parse_style_transform_style(value)?.into(),
 
             MixBlendMode                => parse_style_mix_blend_mode(value)?.into(),
             Filter                      => CssProperty::Filter(CssPropertyValue::Exact(parse_style_filter_vec(value)?)).into(),
@@ -569,7 +588,11 @@ pub enum CssParsingError<'a> {
     PerspectiveOriginParseError(CssStylePerspectiveOriginParseError<'a>),
     Opacity(OpacityParseError<'a>),
     Scrollbar(CssScrollbarStyleParseError<'a>),
+    ScrollbarColor(CssScrollbarColorParseError<'a>),
     Filter(CssStyleFilterParseError<'a>),
+    Cursor(CssCursorParseError<'a>),
+    Content(CssContentParseError<'a>),
+    Counter(CssCounterParseError<'a>),
 }
 
 impl_debug_as_display!(CssParsingError<'a>);
@@ -594,7 +617,11 @@ impl_display!{ CssParsingError<'a>, {
     PerspectiveOriginParseError(e) => format!("{}", e),
     Opacity(e) => format!("{}", e),
     Scrollbar(e) => format!("{}", e),
+    ScrollbarColor(e) => format!("{}", e),
     Filter(e) => format!("{}", e),
+    Cursor(e) => format!("{}", e),
+    Content(e) => format!("{}", e),
+    Counter(e) => format!("{}", e),
 }}
 
 impl_from!(CssBorderParseError<'a>, CssParsingError::CssBorderParseError);
@@ -616,7 +643,11 @@ impl_from!(CssStyleTransformOriginParseError<'a>, CssParsingError::TransformOrig
 impl_from!(CssStylePerspectiveOriginParseError<'a>, CssParsingError::PerspectiveOriginParseError);
 impl_from!(OpacityParseError<'a>, CssParsingError::Opacity);
 impl_from!(CssScrollbarStyleParseError<'a>, CssParsingError::Scrollbar);
+impl_from!(CssScrollbarColorParseError<'a>, CssParsingError::ScrollbarColor);
 impl_from!(CssStyleFilterParseError<'a>, CssParsingError::Filter);
+impl_from!(CssCursorParseError<'a>, CssParsingError::Cursor);
+impl_from!(CssContentParseError<'a>, CssParsingError::Content);
+impl_from!(CssCounterParseError<'a>, CssParsingError::Counter);
 
 impl<'a> From<PercentageParseError> for CssParsingError<'a> {
     fn from(e: PercentageParseError) -> Self {
@@ -668,12 +699,13 @@ pub enum CssColorParseError<'a> {
     DirectionParseError(CssDirectionParseError<'a>),
     UnsupportedDirection(&'a str),
     InvalidPercentage(PercentageParseError),
+    UnsupportedColorSpace(&'a str),
 }
 
 impl_debug_as_display!(CssColorParseError<'a>);
 impl_display!{CssColorParseError<'a>, {
     InvalidColor(i) => format!("Invalid CSS color: \"{}\"", i),
-    InvalidFunctionName(i) => format!("Invalid function name, expected one of: \"rgb\", \"rgba\", \"hsl\", \"hsla\" got: \"{}\"", i),
+    InvalidFunctionName(i) => format!("Invalid function name, expected one of: \"rgb\", \"rgba\", \"hsl\", \"hsla\", \"color\" got: \"{}\"", i),
     InvalidColorComponent(i) => format!("Invalid color component when parsing CSS color: \"{}\"", i),
     IntValueParseErr(e) => format!("CSS color component: Value not in range between 00 - FF: \"{}\"", e),
     FloatValueParseErr(e) => format!("CSS color component: Value cannot be parsed as floating point number: \"{}\"", e),
@@ -685,6 +717,7 @@ impl_display!{CssColorParseError<'a>, {
     DirectionParseError(e) => format!("Could not parse direction argument for CSS color: \"{}\"", e),
     UnsupportedDirection(d) => format!("Unsupported direction type for CSS color: \"{}\"", d),
     InvalidPercentage(p) => format!("Invalid percentage when parsing CSS color: \"{}\"", p),
+    UnsupportedColorSpace(s) => format!("Unsupported color space, expected one of: \"srgb\", \"srgb-linear\", \"display-p3\" got: \"{}\"", s),
 }}
 
 impl<'a> From<ParseIntError> for CssColorParseError<'a> {
@@ -948,15 +981,14 @@ impl_display! { PercentageParseError, {
 pub fn parse_percentage_value(input: &str)
 -> Result<PercentageValue, PercentageParseError>
 {
+    // split after the last digit (which may be a multi-byte char, since is_numeric() accepts non-ASCII digits)
     let mut split_pos = 0;
     for (idx, ch) in input.char_indices() {
         if ch.is_numeric() || ch == '.' {
-            split_pos = idx;
+            split_pos = idx + ch.len_utf8();
         }
     }
 
-    split_pos += 1;
-
     let unit = &input[split_pos..];
     let mut number = input[..split_pos].parse::<f32>().map_err(|e| PercentageParseError::ValueParseErr(e))?;
 
@@ -982,13 +1014,14 @@ pub fn parse_css_color<'a>(input: &'a str)
     } else {
         use self::ParenthesisParseError::*;
 
-        match parse_parentheses(input, &["rgba", "rgb", "hsla", "hsl"]) {
+        match parse_parentheses(input, &["rgba", "rgb", "hsla", "hsl", "color"]) {
             Ok((stopword, inner_value)) => {
                 match stopword {
                     "rgba" => parse_color_rgb(inner_value, true),
                     "rgb" => parse_color_rgb(inner_value, false),
                     "hsla" => parse_color_hsl(inner_value, true),
                     "hsl" => parse_color_hsl(inner_value, false),
+                    "color" => parse_color_function(inner_value),
                     _ => unreachable!(),
                 }
             },
@@ -1178,11 +1211,45 @@ pub fn parse_color_builtin<'a>(input: &'a str)
         "Yellow"                | "yellow"                    =>  (255, 255,   0, 255),
         "YellowGreen"           | "yellowgreen"               =>  (154, 205,  50, 255),
         "Transparent"           | "transparent"               =>  (255, 255, 255,   0),
-        _ => { return Err(CssColorParseError::InvalidColor(input)); }
+        _ => {
+            // system color keywords (Canvas, CanvasText, Highlight, ...): resolved with the
+            // colors of `with_system_colors`, or with the default colors outside of it
+            return SYSTEM_COLORS.with(|system_colors| {
+                let mut system_colors = system_colors.borrow_mut();
+                let color = system_colors.colors.get(input).ok_or(CssColorParseError::InvalidColor(input))?;
+                system_colors.used = true;
+                Ok(color)
+            });
+        }
     };
     Ok(ColorU { r, g, b, a })
 }
 
+struct SystemColorsContext {
+    colors: SystemColors,
+    used: bool,
+}
+
+std::thread_local! {
+    static SYSTEM_COLORS: core::cell::RefCell<SystemColorsContext> = core::cell::RefCell::new(SystemColorsContext {
+        colors: SystemColors::DEFAULT,
+        used: false,
+    });
+}
+
+/// Runs the parsing function `f` with the system color keywords (`Canvas`, `ButtonText`, ...)
+/// resolved to `colors` and returns whether `f` parsed any system color. Only the color parser
+/// resolves the keywords, so identifiers in strings, image ids or font names are left alone.
+pub fn with_system_colors<T>(colors: &SystemColors, f: impl FnOnce() -> T) -> (T, bool) {
+    let previous = SYSTEM_COLORS.with(|c| core::mem::replace(&mut *c.borrow_mut(), SystemColorsContext {
+        colors: *colors,
+        used: false,
+    }));
+    let result = f();
+    let used = SYSTEM_COLORS.with(|c| core::mem::replace(&mut *c.borrow_mut(), previous).used);
+    (result, used)
+}
+
 /// Parse a color of the form `rgb([0-255], [0-255], [0-255])`, or `rgba([0-255], [0-255], [0-255],
 /// [0.0-1.0])` without the leading `rgb[a](` or trailing `)`. Alpha defaults to 255.
 pub fn parse_color_rgb<'a>(input: &'a str, parse_alpha: bool)
@@ -1281,7 +1348,8 @@ pub fn parse_color_hsl_components<'a>(components: &mut dyn Iterator<Item = &'a s
         let s = s / 100.0;
         let l = l / 100.0;
         let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
-        let h = h / 60.0;
+        // hue is an angle, 360deg / -90deg are valid inputs
+        let h = h.rem_euclid(360.0) / 60.0;
         let x = c * (1.0 - ((h % 2.0) - 1.0).abs());
         let (r1, g1, b1) = match h as u8 {
             0 => (c, x, 0.0),
@@ -1289,10 +1357,8 @@ pub fn parse_color_hsl_components<'a>(components: &mut dyn Iterator<Item = &'a s
             2 => (0.0, c, x),
             3 => (0.0, x, c),
             4 => (x, 0.0, c),
-            5 => (c, 0.0, x),
-            _ => {
-                unreachable!();
-            }
+            // 6 can happen due to rounding (h = 359.99999), NaN is mapped to 0
+            _ => (c, 0.0, x),
         };
         let m = l - c / 2.0;
         (
@@ -1313,6 +1379,85 @@ pub fn parse_color_hsl_components<'a>(components: &mut dyn Iterator<Item = &'a s
     Ok(ColorU { r, g, b, a: 255 })
 }
 
+/// Parse a color of the form 'color([srgb | srgb-linear | display-p3] r g b [/ a])' without the
+/// leading 'color(' or trailing ')'. Components are numbers (0.0 - 1.0) or percentages.
+///
+/// Colors are stored in sRGB, wide-gamut (display-p3) colors are converted to sRGB and
+/// clipped to the sRGB gamut, so that they don't look oversaturated on sRGB displays.
+pub fn parse_color_function<'a>(input: &'a str)
+-> Result<ColorU, CssColorParseError<'a>>
+{
+    #[inline]
+    fn component_from_str<'a>(components: &mut dyn Iterator<Item = &'a str>, which: CssColorComponent)
+    -> Result<f32, CssColorParseError<'a>>
+    {
+        let c = components.next().ok_or(CssColorParseError::MissingColorComponent(which))?;
+        let value = if c.ends_with('%') {
+            parse_percentage(c).map_err(|e| CssColorParseError::InvalidPercentage(e))?.normalized()
+        } else {
+            c.parse::<f32>()?
+        };
+        // out-of-gamut values are valid, they are clipped after the conversion
+        Ok(value)
+    }
+
+    /// sRGB transfer function, also used by display-p3
+    #[inline]
+    fn srgb_to_linear(c: f32) -> f32 {
+        if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+    }
+
+    #[inline]
+    fn linear_to_srgb(c: f32) -> f32 {
+        if c <= 0.0031308 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 }
+    }
+
+    #[inline]
+    fn to_u8(c: f32) -> u8 {
+        (c.max(0.0).min(1.0) * 255.0).round() as u8
+    }
+
+    let (color, alpha) = match input.find('/') {
+        Some(slash) => (&input[..slash], Some(input[(slash + 1)..].trim())),
+        None => (input, None),
+    };
+
+    let mut components = color.split_whitespace();
+    let color_space = components.next().ok_or(CssColorParseError::EmptyInput)?;
+
+    let rgb = (
+        component_from_str(&mut components, CssColorComponent::Red)?,
+        component_from_str(&mut components, CssColorComponent::Green)?,
+        component_from_str(&mut components, CssColorComponent::Blue)?,
+    );
+
+    if let Some(arg) = components.next() {
+        return Err(CssColorParseError::ExtraArguments(arg));
+    }
+
+    let (r, g, b) = match color_space {
+        "srgb" => rgb,
+        "srgb-linear" => (linear_to_srgb(rgb.0), linear_to_srgb(rgb.1), linear_to_srgb(rgb.2)),
+        "display-p3" => {
+            let (r, g, b) = (srgb_to_linear(rgb.0), srgb_to_linear(rgb.1), srgb_to_linear(rgb.2));
+            // linear display-p3 -> linear sRGB (both use the D65 white point)
+            (
+                linear_to_srgb(1.2249401 * r - 0.2249404 * g),
+                linear_to_srgb(-0.0420569 * r + 1.0420571 * g),
+                linear_to_srgb(-0.0196376 * r - 0.0786361 * g + 1.0982735 * b),
+            )
+        },
+        other => return Err(CssColorParseError::UnsupportedColorSpace(other)),
+    };
+
+    let a = match alpha {
+        Some(a) => parse_alpha_component(&mut a.split_whitespace())?,
+        None => 255,
+    };
+
+    Ok(ColorU { r: to_u8(r), g: to_u8(g), b: to_u8(b), a })
+}
+
 fn parse_alpha_component<'a>(components: &mut dyn Iterator<Item=&'a str>) -> Result<u8, CssColorParseError<'a>> {
     let a = components.next().ok_or(CssColorParseError::MissingColorComponent(CssColorComponent::Alpha))?;
     if a.is_empty() {
@@ -1345,11 +1490,13 @@ pub fn parse_color_no_hash<'a>(input: &'a str)
 
     match input.len() {
         3 => {
-            let mut input_iter = input.chars();
+            // input.len() is the length in bytes: iterate over bytes, not chars,
+            // non-ASCII characters are rejected by from_hex()
+            let mut input_iter = input.bytes();
 
-            let r = input_iter.next().unwrap() as u8;
-            let g = input_iter.next().unwrap() as u8;
-            let b = input_iter.next().unwrap() as u8;
+            let r = input_iter.next().unwrap();
+            let g = input_iter.next().unwrap();
+            let b = input_iter.next().unwrap();
 
             let r = from_hex(r)? * 16 + from_hex(r)?;
             let g = from_hex(g)? * 16 + from_hex(g)?;
@@ -1363,12 +1510,12 @@ pub fn parse_color_no_hash<'a>(input: &'a str)
             })
         },
         4 => {
-            let mut input_iter = input.chars();
+            let mut input_iter = input.bytes();
 
-            let r = input_iter.next().unwrap() as u8;
-            let g = input_iter.next().unwrap() as u8;
-            let b = input_iter.next().unwrap() as u8;
-            let a = input_iter.next().unwrap() as u8;
+            let r = input_iter.next().unwrap();
+            let g = input_iter.next().unwrap();
+            let b = input_iter.next().unwrap();
+            let a = input_iter.next().unwrap();
 
             let r = from_hex(r)? * 16 + from_hex(r)?;
             let g = from_hex(g)? * 16 + from_hex(g)?;
@@ -1891,6 +2038,49 @@ pub fn parse_scrollbar_style<'a>(input: &'a str) -> Result<ScrollbarStyle, CssSc
     Ok(ScrollbarStyle::default()) // TODO!
 }
 
+#[derive(Clone, PartialEq)]
+pub enum CssScrollbarColorParseError<'a> {
+    WrongNumberOfColors(&'a str),
+    Color(CssColorParseError<'a>),
+}
+
+impl_debug_as_display!(CssScrollbarColorParseError<'a>);
+impl_display!{ CssScrollbarColorParseError<'a>, {
+    WrongNumberOfColors(e) => format!("Expected two colors (thumb and track) for scrollbar-color, got: \"{}\"", e),
+    Color(e) => format!("{}", e),
+}}
+
+impl_from!(CssColorParseError<'a>, CssScrollbarColorParseError::Color);
+
+/// Parses a `scrollbar-color: <thumb-color> <track-color>` value
+/// (`scrollbar-color: auto` is handled by the generic "auto" value)
+pub fn parse_style_scrollbar_color<'a>(input: &'a str) -> Result<StyleScrollbarColor, CssScrollbarColorParseError<'a>> {
+
+    let input = input.trim();
+
+    // split at the first whitespace that is not inside of a "rgb(...)" / "hsl(...)"
+    let mut depth = 0_usize;
+    let split_pos = input.char_indices().find_map(|(i, c)| {
+        match c {
+            '(' => { depth += 1; None },
+            ')' => { depth = depth.saturating_sub(1); None },
+            c if c.is_whitespace() && depth == 0 => Some(i),
+            _ => None,
+        }
+    }).ok_or(CssScrollbarColorParseError::WrongNumberOfColors(input))?;
+
+    let (thumb, track) = input.split_at(split_pos);
+    let track = track.trim();
+    if track.is_empty() {
+        return Err(CssScrollbarColorParseError::WrongNumberOfColors(input));
+    }
+
+    Ok(StyleScrollbarColor {
+        thumb: parse_css_color(thumb)?,
+        track: parse_css_color(track)?,
+    })
+}
+
 #[derive(Clone, PartialEq)]
 pub enum CssStyleFilterParseError<'a> {
     InvalidFilter(&'a str),
@@ -2888,7 +3078,114 @@ impl_display!{CssShapeParseError<'a>, {
 }}
 
 typed_pixel_value_parser!(parse_style_letter_spacing, StyleLetterSpacing);
+typed_pixel_value_parser!(parse_style_perspective, StylePerspective);
 typed_pixel_value_parser!(parse_style_word_spacing, StyleWordSpacing);
+typed_pixel_value_parser!(parse_style_scroll_padding, StyleScrollPadding);
+
+#[derive(Clone, PartialEq)]
+pub enum CssContentParseError<'a> {
+    InvalidValue(&'a str),
+    UnclosedQuotes(UnclosedQuotesError<'a>),
+    Image(CssImageParseError<'a>),
+}
+
+impl_debug_as_display!(CssContentParseError<'a>);
+impl_display!{ CssContentParseError<'a>, {
+    InvalidValue(e) => format!("Invalid content: \"{}\", expected none, \"text\", url(id) or counter(name)", e),
+    UnclosedQuotes(e) => format!("Unclosed quotes in content: \"{}\"", e.0),
+    Image(e) => format!("{}", e),
+}}
+
+impl_from!(UnclosedQuotesError<'a>, CssContentParseError::UnclosedQuotes);
+impl_from!(CssImageParseError<'a>, CssContentParseError::Image);
+
+/// Parses a `content: normal | "text" | url(<image-id>) | counter(<name>)` value
+/// (`none` is handled by `parse_css_property`)
+pub fn parse_style_content<'a>(input: &'a str) -> Result<StyleContent, CssContentParseError<'a>> {
+
+    let input = input.trim();
+
+    if input == "normal" {
+        return Ok(StyleContent::None);
+    }
+
+    if input.starts_with('"') || input.starts_with('\'') {
+        return Ok(StyleContent::Text(strip_quotes(input)?.into()));
+    }
+
+    match parse_parentheses(input, &["url", "counter"]) {
+        Ok(("url", brace_contents)) => {
+            let brace_contents = brace_contents.trim();
+            // url(image.png) does not need quotes
+            let image = if brace_contents.starts_with('"') || brace_contents.starts_with('\'') {
+                parse_image(brace_contents)?
+            } else {
+                AzString::from(String::from(brace_contents))
+            };
+            Ok(StyleContent::Image(image))
+        },
+        Ok((_, brace_contents)) => {
+            let counter_name = brace_contents.trim();
+            if counter_name.is_empty() {
+                return Err(CssContentParseError::InvalidValue(input));
+            }
+            Ok(StyleContent::Counter(AzString::from(String::from(counter_name))))
+        },
+        Err(_) => Err(CssContentParseError::InvalidValue(input)),
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub enum CssCounterParseError<'a> {
+    InvalidValue(&'a str),
+    Value(ParseIntError),
+}
+
+impl_debug_as_display!(CssCounterParseError<'a>);
+impl_display!{ CssCounterParseError<'a>, {
+    InvalidValue(e) => format!("Invalid counter: \"{}\", expected \"name\" or \"name <integer>\"", e),
+    Value(e) => format!("Invalid counter value: {}", e),
+}}
+
+impl<'a> From<ParseIntError> for CssCounterParseError<'a> {
+    fn from(e: ParseIntError) -> Self {
+        CssCounterParseError::Value(e)
+    }
+}
+
+/// Parses a `<name> [<integer>]` value of `counter-reset` / `counter-increment`
+fn parse_counter<'a>(input: &'a str) -> Result<(AzString, Option<i32>), CssCounterParseError<'a>> {
+
+    let mut iter = input.split_whitespace();
+
+    let counter = match iter.next() {
+        Some(s) if s.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_') => s,
+        _ => return Err(CssCounterParseError::InvalidValue(input)),
+    };
+
+    let value = match iter.next() {
+        Some(v) => Some(v.parse::<i32>()?),
+        None => None,
+    };
+
+    if iter.next().is_some() {
+        return Err(CssCounterParseError::InvalidValue(input));
+    }
+
+    Ok((AzString::from(String::from(counter)), value))
+}
+
+/// Parses a `counter-reset: <name> [<integer>]` value (`none` is handled by `parse_css_property`)
+pub fn parse_style_counter_reset<'a>(input: &'a str) -> Result<StyleCounterReset, CssCounterParseError<'a>> {
+    let (counter, value) = parse_counter(input)?;
+    Ok(StyleCounterReset { counter, value: value.unwrap_or(0) })
+}
+
+/// Parses a `counter-increment: <name> [<integer>]` value (`none` is handled by `parse_css_property`)
+pub fn parse_style_counter_increment<'a>(input: &'a str) -> Result<StyleCounterIncrement, CssCounterParseError<'a>> {
+    let (counter, value) = parse_counter(input)?;
+    Ok(StyleCounterIncrement { counter, value: value.unwrap_or(1) })
+}
 
 typed_pixel_value_parser!(parse_layout_width, LayoutWidth);
 typed_pixel_value_parser!(parse_layout_height, LayoutHeight);
@@ -3096,6 +3393,9 @@ pub fn parse_parentheses<'a>(
 
     let validated_stopword = validated_stopword.ok_or(StopWordNotFound(found_stopword))?;
     let last_closing_brace = input.rfind(')').ok_or(NoClosingBraceFound)?;
+    if last_closing_brace < first_open_brace {
+        return Err(NoClosingBraceFound); // "rgb)(", would panic when slicing
+    }
 
     Ok((validated_stopword, &input[(first_open_brace + 1)..last_closing_brace]))
 }
@@ -3130,42 +3430,151 @@ multi_type_parser!(parse_style_border_style, BorderStyle,
     ["inset", Inset],
     ["outset", Outset]);
 
-multi_type_parser!(parse_style_cursor, StyleCursor,
-                    ["alias", Alias],
-                    ["all-scroll", AllScroll],
-                    ["cell", Cell],
-                    ["col-resize", ColResize],
-                    ["context-menu", ContextMenu],
-                    ["copy", Copy],
-                    ["crosshair", Crosshair],
-                    ["default", Default],
-                    ["e-resize", EResize],
-                    ["ew-resize", EwResize],
-                    ["grab", Grab],
-                    ["grabbing", Grabbing],
-                    ["help", Help],
-                    ["move", Move],
-                    ["n-resize", NResize],
-                    ["ns-resize", NsResize],
-                    ["nesw-resize", NeswResize],
-                    ["nwse-resize", NwseResize],
-                    ["pointer", Pointer],
-                    ["progress", Progress],
-                    ["row-resize", RowResize],
-                    ["s-resize", SResize],
-                    ["se-resize", SeResize],
-                    ["text", Text],
-                    ["unset", Unset],
-                    ["vertical-text", VerticalText],
-                    ["w-resize", WResize],
-                    ["wait", Wait],
-                    ["zoom-in", ZoomIn],
-                    ["zoom-out", ZoomOut]);
+fn parse_style_cursor_keyword<'a>(input: &'a str) -> Result<StyleCursor, InvalidValueErr<'a>> {
+    Ok(match input {
+        "alias" => StyleCursor::Alias,
+        "all-scroll" => StyleCursor::AllScroll,
+        "cell" => StyleCursor::Cell,
+        "col-resize" => StyleCursor::ColResize,
+        "context-menu" => StyleCursor::ContextMenu,
+        "copy" => StyleCursor::Copy,
+        "crosshair" => StyleCursor::Crosshair,
+        "default" => StyleCursor::Default,
+        "e-resize" => StyleCursor::EResize,
+        "ew-resize" => StyleCursor::EwResize,
+        "grab" => StyleCursor::Grab,
+        "grabbing" => StyleCursor::Grabbing,
+        "help" => StyleCursor::Help,
+        "move" => StyleCursor::Move,
+        "n-resize" => StyleCursor::NResize,
+        "ns-resize" => StyleCursor::NsResize,
+        "nesw-resize" => StyleCursor::NeswResize,
+        "nwse-resize" => StyleCursor::NwseResize,
+        "pointer" => StyleCursor::Pointer,
+        "progress" => StyleCursor::Progress,
+        "row-resize" => StyleCursor::RowResize,
+        "s-resize" => StyleCursor::SResize,
+        "se-resize" => StyleCursor::SeResize,
+        "text" => StyleCursor::Text,
+        "unset" => StyleCursor::Unset,
+        "vertical-text" => StyleCursor::VerticalText,
+        "w-resize" => StyleCursor::WResize,
+        "wait" => StyleCursor::Wait,
+        "zoom-in" => StyleCursor::ZoomIn,
+        "zoom-out" => StyleCursor::ZoomOut,
+        _ => return Err(InvalidValueErr(input)),
+    })
+}
+
+#[derive(Clone, PartialEq)]
+pub enum CssCursorParseError<'a> {
+    InvalidValue(InvalidValueErr<'a>),
+    Image(CssImageParseError<'a>),
+    InvalidHotspot(&'a str),
+}
+
+impl_debug_as_display!(CssCursorParseError<'a>);
+impl_display!{ CssCursorParseError<'a>, {
+    InvalidValue(e) => format!("Invalid cursor: \"{}\"", e.0),
+    Image(e) => format!("{}", e),
+    InvalidHotspot(e) => format!("Expected two integers (x and y) as the cursor hotspot, got: \"{}\"", e),
+}}
+
+impl_from!(InvalidValueErr<'a>, CssCursorParseError::InvalidValue);
+impl_from!(CssImageParseError<'a>, CssCursorParseError::Image);
+
+/// Parses a `cursor: <keyword>` or `cursor: url(<image-id>) [<x> <y>]` value,
+/// the hotspot defaults to the top left corner of the image
+pub fn parse_style_cursor<'a>(input: &'a str) -> Result<StyleCursor, CssCursorParseError<'a>> {
+
+    let input = input.trim();
+
+    let image_contents = match parse_parentheses(input, &["url", "image"]) {
+        Ok((_, brace_contents)) => brace_contents.trim(),
+        Err(_) => return Ok(parse_style_cursor_keyword(input)?),
+    };
+
+    // url(image.png) does not need quotes
+    let image = if image_contents.starts_with('"') || image_contents.starts_with('\'') {
+        parse_image(image_contents)?
+    } else {
+        AzString::from(String::from(image_contents))
+    };
+
+    let hotspot = input[(input.rfind(')').unwrap_or(input.len() - 1) + 1)..].trim();
+    let (hotspot_x, hotspot_y) = if hotspot.is_empty() {
+        (0, 0)
+    } else {
+        let mut coords = hotspot.split_whitespace().map(|c| c.parse::<u32>().ok());
+        match (coords.next(), coords.next(), coords.next()) {
+            (Some(Some(x)), Some(Some(y)), None) => (x, y),
+            _ => return Err(CssCursorParseError::InvalidHotspot(hotspot)),
+        }
+    };
+
+    Ok(StyleCursor::Image(StyleCursorImage { image, hotspot_x, hotspot_y }))
+}
+
+impl FormatAsCssValue for StyleCursor {
+    fn format_as_css_value(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use azul_css::PrintAsCssValue;
+        write!(f, "{}", self.print_as_css_value())
+    }
+}
 
 multi_type_parser!(parse_style_backface_visibility, StyleBackfaceVisibility,
                     ["hidden", Hidden],
                     ["visible", Visible]);
 
+multi_type_parser!(parse_style_transform_style, StyleTransformStyle,
+                    ["flat", Flat],
+                    ["preserve-3d", Preserve3D]);
+
+multi_type_parser!(parse_style_scrollbar_width, StyleScrollbarWidth,
+                    ["auto", Auto],
+                    ["thin", Thin],
+                    ["none", None]);
+
+multi_type_parser!(parse_style_overflow_anchor, StyleOverflowAnchor,
+                    ["auto", Auto],
+                    ["none", None]);
+
+multi_type_parser!(parse_style_window_drag_region, StyleWindowDragRegion,
+                    ["none", None],
+                    ["move", Move],
+                    ["resize-n", ResizeN],
+                    ["resize-ne", ResizeNE],
+                    ["resize-e", ResizeE],
+                    ["resize-se", ResizeSE],
+                    ["resize-s", ResizeS],
+                    ["resize-sw", ResizeSW],
+                    ["resize-w", ResizeW],
+                    ["resize-nw", ResizeNW],
+                    ["maximize-button", MaximizeButton]);
+
+/// Parses a `-azul-caret-animation-duration` value: `530ms`, `0.5s` or `0`
+pub fn parse_style_caret_animation_duration<'a>(input: &'a str)
+-> Result<StyleCaretAnimationDuration, InvalidValueErr<'a>>
+{
+    let input = input.trim();
+
+    let millis = if let Some(ms) = input.strip_suffix("ms") {
+        ms.trim().parse::<f32>().map_err(|_| InvalidValueErr(input))?
+    } else if let Some(s) = input.strip_suffix('s') {
+        s.trim().parse::<f32>().map_err(|_| InvalidValueErr(input))? * 1000.0
+    } else if input == "0" {
+        0.0
+    } else {
+        return Err(InvalidValueErr(input));
+    };
+
+    if !millis.is_finite() || millis < 0.0 {
+        return Err(InvalidValueErr(input));
+    }
+
+    Ok(StyleCaretAnimationDuration { inner: (millis + 0.5) as u32 })
+}
+
 pub fn parse_style_background_size<'a>(input: &'a str)
 -> Result<StyleBackgroundSize, InvalidValueErr<'a>>
 {
@@ -3268,6 +3677,11 @@ multi_type_parser!(parse_layout_text_align, StyleTextAlign,
                     ["left", Left],
                     ["right", Right]);
 
+multi_type_parser!(parse_style_hyphens, StyleHyphens,
+                    ["none", None],
+                    ["manual", Manual],
+                    ["auto", Auto]);
+
 #[cfg(test)]
 mod css_tests {
     use super::*;
@@ -3880,6 +4294,14 @@ mod css_tests {
         assert_eq!(parse_css_color("rgb(283, 8, 105)"), Err(CssColorParseError::IntValueParseErr("283".parse::<u8>().err().unwrap())));
     }
 
+    #[test]
+    fn test_parse_css_color_system_color() {
+        let colors = SystemColors::DEFAULT;
+        assert_eq!(parse_css_color("Canvas"), Ok(colors.canvas));
+        assert_eq!(parse_css_color("canvastext"), Ok(colors.canvas_text));
+        assert_eq!(parse_css_color("Highlight"), Ok(colors.highlight));
+    }
+
     #[test]
     fn test_parse_css_color_6() {
         assert_eq!(parse_css_color("rgba(192, 14, 12, 80)"), Err(CssColorParseError::FloatValueOutOfRange(80.0)));
@@ -4050,6 +4472,53 @@ mod css_tests {
         assert_eq!(parse_css_color("rgb(255,0, 0)"), Ok(ColorU { r: 255, g: 0, b: 0, a: 255 }));
     }
 
+    #[test]
+    fn test_parse_css_color_37() {
+        assert_eq!(parse_css_color("hsl(360deg, 100%, 50%)"), Ok(ColorU { r: 255, g: 0, b: 0, a: 255 }));
+    }
+
+    #[test]
+    fn test_parse_css_color_38() {
+        // 3 bytes, but only one char
+        assert_eq!(parse_css_color("#€"), Err(CssColorParseError::InvalidColorComponent(0xE2)));
+    }
+
+    #[test]
+    fn test_parse_css_color_39() {
+        assert_eq!(parse_css_color("color(srgb 1 0.4 0)"), Ok(ColorU { r: 255, g: 102, b: 0, a: 255 }));
+    }
+
+    #[test]
+    fn test_parse_css_color_40() {
+        assert_eq!(parse_css_color("color(srgb 100% 0% 20% / 0.5)"), Ok(ColorU { r: 255, g: 0, b: 51, a: 128 }));
+    }
+
+    #[test]
+    fn test_parse_css_color_41() {
+        // fully saturated display-p3 red is outside of the sRGB gamut and gets clipped
+        assert_eq!(parse_css_color("color(display-p3 1 0 0)"), Ok(ColorU { r: 255, g: 0, b: 0, a: 255 }));
+        // grays are identical in both color spaces
+        assert_eq!(parse_css_color("color(display-p3 0.6 0.6 0.6)"), Ok(ColorU { r: 153, g: 153, b: 153, a: 255 }));
+    }
+
+    #[test]
+    fn test_parse_css_color_42() {
+        assert_eq!(parse_css_color("color(srgb-linear 0 1 0)"), Ok(ColorU { r: 0, g: 255, b: 0, a: 255 }));
+    }
+
+    #[test]
+    fn test_parse_css_color_43() {
+        assert_eq!(parse_css_color("color(rec2020 1 0 0)"), Err(CssColorParseError::UnsupportedColorSpace("rec2020")));
+        assert_eq!(parse_css_color("color(display-p3 1 0)"), Err(CssColorParseError::MissingColorComponent(CssColorComponent::Blue)));
+    }
+
+    #[test]
+    fn test_parse_invalid_input_does_not_panic() {
+        assert!(parse_css_color("rgb)(").is_err());
+        assert!(parse_percentage_value("").is_err());
+        assert!(parse_percentage_value("٣").is_err());
+    }
+
     #[test]
     fn test_parse_pixel_value_1() {
         assert_eq!(parse_pixel_value("15px"), Ok(PixelValue::px(15.0)));
@@ -4245,4 +4714,87 @@ mod css_tests {
             Ok(AngleValue::grad(20.4))
         );
     }
+
+    #[test]
+    fn test_parse_caret_animation_duration() {
+        assert_eq!(parse_style_caret_animation_duration("530ms"), Ok(StyleCaretAnimationDuration { inner: 530 }));
+        assert_eq!(parse_style_caret_animation_duration("0.5s"), Ok(StyleCaretAnimationDuration { inner: 500 }));
+        assert_eq!(parse_style_caret_animation_duration("0"), Ok(StyleCaretAnimationDuration { inner: 0 }));
+        assert!(parse_style_caret_animation_duration("-1s").is_err());
+        assert!(parse_style_caret_animation_duration("fast").is_err());
+    }
+
+    #[test]
+    fn test_parse_scroll_padding() {
+        assert_eq!(parse_style_scroll_padding("40px"), Ok(StyleScrollPadding { inner: PixelValue::px(40.0) }));
+        assert_eq!(parse_style_scroll_padding("10%"), Ok(StyleScrollPadding { inner: PixelValue::percent(10.0) }));
+        assert!(parse_style_scroll_padding("auto").is_err());
+    }
+
+    #[test]
+    fn test_parse_overflow_anchor() {
+        assert_eq!(parse_style_overflow_anchor("auto"), Ok(StyleOverflowAnchor::Auto));
+        assert_eq!(parse_style_overflow_anchor("none"), Ok(StyleOverflowAnchor::None));
+        assert!(parse_style_overflow_anchor("hidden").is_err());
+    }
+
+    #[test]
+    fn test_parse_content() {
+        assert_eq!(parse_style_content("\"New\""), Ok(StyleContent::Text("New".to_string().into())));
+        assert_eq!(parse_style_content("'→ '"), Ok(StyleContent::Text("→ ".to_string().into())));
+        assert_eq!(parse_style_content("url(badge)"), Ok(StyleContent::Image("badge".to_string().into())));
+        assert_eq!(parse_style_content("url(\"badge\")"), Ok(StyleContent::Image("badge".to_string().into())));
+        assert_eq!(parse_style_content("counter(item)"), Ok(StyleContent::Counter("item".to_string().into())));
+        assert_eq!(parse_style_content("normal"), Ok(StyleContent::None));
+        assert!(parse_style_content("counter()").is_err());
+        assert!(parse_style_content("\"unclosed").is_err());
+        assert!(parse_style_content("attr(title)").is_err());
+    }
+
+    #[test]
+    fn test_parse_counter() {
+        assert_eq!(parse_style_counter_reset("item"), Ok(StyleCounterReset { counter: "item".to_string().into(), value: 0 }));
+        assert_eq!(parse_style_counter_reset("item 5"), Ok(StyleCounterReset { counter: "item".to_string().into(), value: 5 }));
+        assert_eq!(parse_style_counter_increment("item"), Ok(StyleCounterIncrement { counter: "item".to_string().into(), value: 1 }));
+        assert_eq!(parse_style_counter_increment("  section-2   -2 "), Ok(StyleCounterIncrement { counter: "section-2".to_string().into(), value: -2 }));
+        assert!(parse_style_counter_reset("").is_err());
+        assert!(parse_style_counter_reset("item 1.5").is_err());
+        assert!(parse_style_counter_increment("a 1 b 2").is_err());
+        assert!(parse_style_counter_increment("counter(a)").is_err());
+    }
+
+    #[test]
+    fn test_parse_hyphens() {
+        assert_eq!(parse_style_hyphens("auto"), Ok(StyleHyphens::Auto));
+        assert_eq!(parse_style_hyphens("manual"), Ok(StyleHyphens::Manual));
+        assert_eq!(parse_style_hyphens("none"), Ok(StyleHyphens::None));
+        assert!(parse_style_hyphens("all").is_err());
+    }
+
+    #[test]
+    fn test_parse_3d_transform_properties() {
+        assert_eq!(parse_style_transform_style("preserve-3d"), Ok(StyleTransformStyle::Preserve3D));
+        assert_eq!(parse_style_transform_style("flat"), Ok(StyleTransformStyle::Flat));
+        assert!(parse_style_transform_style("3d").is_err());
+        assert_eq!(parse_style_perspective("800px"), Ok(StylePerspective::px(800.0)));
+        assert_eq!(
+            parse_css_property(CssPropertyType::Perspective, "none"),
+            Ok(CssProperty::Perspective(CssPropertyValue::None))
+        );
+    }
+
+    #[test]
+    fn test_parse_cursor() {
+        assert_eq!(parse_style_cursor("pointer"), Ok(StyleCursor::Pointer));
+        assert_eq!(
+            parse_style_cursor("url(\"crosshair-red\") 4 5"),
+            Ok(StyleCursor::Image(StyleCursorImage { image: "crosshair-red".into(), hotspot_x: 4, hotspot_y: 5 }))
+        );
+        assert_eq!(
+            parse_style_cursor("url(pen.png)"),
+            Ok(StyleCursor::Image(StyleCursorImage { image: "pen.png".into(), hotspot_x: 0, hotspot_y: 0 }))
+        );
+        assert!(parse_style_cursor("url(pen.png) 4").is_err());
+        assert!(parse_style_cursor("finger").is_err());
+    }
 }
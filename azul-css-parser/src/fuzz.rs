@@ -0,0 +1,76 @@
+//! Entry points for fuzzing the CSS parser (see the `fuzz` directory in the repository root).
+//!
+//! The entry points accept arbitrary bytes and never panic when built with `panic = "unwind"`:
+//! parse errors are returned as `FuzzError::Parse`, panics are caught and returned as
+//! `FuzzError::Panic`, so that a fuzz target can distinguish "invalid input" from a crash.
+
+use alloc::string::{String, ToString};
+use std::panic::{catch_unwind, UnwindSafe};
+use azul_css::{CssPropertyType, CombinedCssPropertyType, get_css_key_map};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FuzzError {
+    /// Input was not valid UTF-8 (not a parser error)
+    InvalidUtf8,
+    /// Parser returned an error - expected for random input
+    Parse(String),
+    /// Parser panicked - this is always a bug
+    Panic(String),
+}
+
+impl FuzzError {
+    pub fn is_panic(&self) -> bool {
+        match self {
+            FuzzError::Panic(_) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Runs the function and converts a panic into `FuzzError::Panic`
+pub fn catch_panic<F: FnOnce() -> Result<(), FuzzError> + UnwindSafe>(f: F) -> Result<(), FuzzError> {
+    match catch_unwind(f) {
+        Ok(r) => r,
+        Err(panic) => {
+            let message = panic.downcast_ref::<&str>().map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            Err(FuzzError::Panic(message))
+        }
+    }
+}
+
+/// Parses the input as a CSS stylesheet
+#[doc(hidden)]
+pub fn fuzz_parse_css(data: &[u8]) -> Result<(), FuzzError> {
+    let input = core::str::from_utf8(data).map_err(|_| FuzzError::InvalidUtf8)?;
+    catch_panic(|| {
+        crate::css::new_from_str(input)
+            .map(|_| ())
+            .map_err(|e| FuzzError::Parse(format!("{}", e)))
+    })
+}
+
+/// Parses the input as a single `key: value` declaration, i.e. "border: 1px solid red"
+#[doc(hidden)]
+pub fn fuzz_parse_css_property(data: &[u8]) -> Result<(), FuzzError> {
+    let input = core::str::from_utf8(data).map_err(|_| FuzzError::InvalidUtf8)?;
+    let mut split = input.splitn(2, ':');
+    let key = split.next().unwrap_or("");
+    let value = split.next().ok_or_else(|| FuzzError::Parse("missing ':'".to_string()))?;
+
+    catch_panic(|| {
+        let key_map = get_css_key_map();
+        if let Some(key) = CombinedCssPropertyType::from_str(key, &key_map) {
+            crate::css_parser::parse_combined_css_property(key, value)
+                .map(|_| ())
+                .map_err(|e| FuzzError::Parse(format!("{}", e)))
+        } else if let Some(key) = CssPropertyType::from_str(key, &key_map) {
+            crate::css_parser::parse_css_property(key, value)
+                .map(|_| ())
+                .map_err(|e| FuzzError::Parse(format!("{}", e)))
+        } else {
+            Err(FuzzError::Parse(format!("unknown key \"{}\"", key)))
+        }
+    })
+}
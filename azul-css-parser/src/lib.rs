@@ -25,6 +25,8 @@ mod macros;
 
 mod css_parser;
 mod css;
+#[doc(hidden)]
+pub mod fuzz;
 
 pub use crate::css::*;
 pub use crate::css_parser::*;
@@ -1,5 +1,5 @@
 //! Types and methods used to describe the style of an application
-use crate::css_properties::{CssProperty, CssPropertyType};
+use crate::css_properties::{CssProperty, CssPropertyType, SystemStyle};
 use crate::AzString;
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -33,6 +33,18 @@ impl Css {
             stylesheets: stylesheets.into(),
         }
     }
+
+    /// Returns whether styling a DOM with this CSS depends on the `SystemStyle`, i.e.
+    /// if the CSS uses system colors or `@media` blocks
+    pub fn depends_on_system_style(&self) -> bool {
+        self.rules().any(|rule| {
+            !rule.media.is_always()
+                || rule.declarations.iter().any(|d| match d {
+                    CssDeclaration::System(_) => true,
+                    _ => false,
+                })
+        })
+    }
 }
 
 #[derive(Debug, Default, PartialEq, PartialOrd, Clone)]
@@ -73,6 +85,8 @@ pub enum CssDeclaration {
     Static(CssProperty),
     /// Dynamic key-value pair with default value, such as `width: [[ my_id | 500px ]]`
     Dynamic(DynamicCssProperty),
+    /// Key-value pair that uses system color keywords, such as `color: CanvasText`
+    System(SystemCssProperty),
 }
 
 impl CssDeclaration {
@@ -90,6 +104,7 @@ impl CssDeclaration {
         match self {
             Static(s) => s.get_type(),
             Dynamic(d) => d.default_value.get_type(),
+            System(s) => s.default_value.get_type(),
         }
     }
 
@@ -100,6 +115,7 @@ impl CssDeclaration {
         match self {
             Static(s) => s.get_type().is_inheritable(),
             Dynamic(d) => d.is_inheritable(),
+            System(s) => s.default_value.get_type().is_inheritable(),
         }
     }
 
@@ -110,6 +126,7 @@ impl CssDeclaration {
         match self {
             Static(s) => s.get_type().can_trigger_relayout(),
             Dynamic(d) => d.can_trigger_relayout(),
+            System(s) => s.default_value.get_type().can_trigger_relayout(),
         }
     }
 
@@ -118,6 +135,7 @@ impl CssDeclaration {
         match self {
             Static(s) => format!("{:?}", s),
             Dynamic(d) => format!("var(--{}, {:?})", d.dynamic_id, d.default_value),
+            System(s) => format!("{}: {}", s.key, s.value),
         }
     }
 }
@@ -289,6 +307,79 @@ impl DynamicCssProperty {
     }
 }
 
+/// Declaration whose value uses the CSS system color keywords (`Canvas`, `CanvasText`, ...).
+/// The value is kept unparsed and resolved with the `SystemColors` of the window when the
+/// DOM is styled, so that the colors follow the system settings without re-parsing the CSS.
+///
+/// A shorthand such as `border: 1px solid ButtonText` creates one declaration per property.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub struct SystemCssProperty {
+    /// The key as written in the stylesheet, i.e. `border`
+    pub key: AzString,
+    /// The unparsed value, i.e. `1px solid ButtonText`
+    pub value: AzString,
+    /// Value resolved with the default system colors, also determines the property type
+    pub default_value: CssProperty,
+}
+
+/// Condition of the `@media` block that a rule is in. Only the media features that depend on
+/// the `SystemStyle` (`forced-colors`, `prefers-reduced-motion`, `pointer` / `hover`) can change
+/// at runtime, so any media query list is stored as a truth table over these three settings:
+/// bit `n` is set if the rule applies when `CssMediaCondition::get_bit(system_style) == n`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub struct CssMediaCondition {
+    pub matching_styles: u8,
+}
+
+impl CssMediaCondition {
+    /// Condition of rules outside of `@media` blocks
+    pub const ALWAYS: Self = Self { matching_styles: 0xff };
+
+    /// Evaluates the condition for every combination of the system settings
+    pub fn new<F: Fn(&SystemStyle) -> bool>(condition_matches: F) -> Self {
+        let mut matching_styles = 0;
+        for bit in 0..8 {
+            let system_style = SystemStyle {
+                forced_colors: bit & 1 != 0,
+                prefers_reduced_motion: bit & 2 != 0,
+                pointer_coarse: bit & 4 != 0,
+                ..SystemStyle::DEFAULT
+            };
+            if condition_matches(&system_style) {
+                matching_styles |= 1 << bit;
+            }
+        }
+        Self { matching_styles }
+    }
+
+    fn get_bit(system_style: &SystemStyle) -> u8 {
+        system_style.forced_colors as u8
+            | (system_style.prefers_reduced_motion as u8) << 1
+            | (system_style.pointer_coarse as u8) << 2
+    }
+
+    pub fn matches(&self, system_style: &SystemStyle) -> bool {
+        self.matching_styles & (1 << Self::get_bit(system_style)) != 0
+    }
+
+    pub fn is_always(&self) -> bool {
+        *self == Self::ALWAYS
+    }
+
+    /// Returns whether the rule can never apply, i.e. `@media print { ... }`
+    pub fn is_never(&self) -> bool {
+        self.matching_styles == 0
+    }
+}
+
+impl Default for CssMediaCondition {
+    fn default() -> Self {
+        Self::ALWAYS
+    }
+}
+
 /// One block of rules that applies a bunch of rules to a "path" in the style, i.e.
 /// `div#myid.myclass -> { ("justify-content", "center") }`
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
@@ -299,6 +390,8 @@ pub struct CssRuleBlock {
     /// `"justify-content: center"` =>
     /// `CssDeclaration::Static(CssProperty::JustifyContent(LayoutJustifyContent::Center))`
     pub declarations: CssDeclarationVec,
+    /// Condition of the `@media` block that the rule is in (`CssMediaCondition::ALWAYS` if none)
+    pub media: CssMediaCondition,
 }
 
 impl_vec!(
@@ -324,6 +417,7 @@ impl CssRuleBlock {
         Self {
             path,
             declarations: declarations.into(),
+            media: CssMediaCondition::ALWAYS,
         }
     }
 }
@@ -494,6 +588,18 @@ pub enum CssPathPseudoSelector {
     Active,
     /// `:focus` - element has received focus
     Focus,
+    /// `:disabled` - element has the `NodeStateFlag::Disabled` state
+    Disabled,
+    /// `:checked` - element has the `NodeStateFlag::Checked` state
+    Checked,
+    /// `:selected` - element has the `NodeStateFlag::Selected` state
+    Selected,
+    /// `:indeterminate` - element has the `NodeStateFlag::Indeterminate` state
+    Indeterminate,
+    /// `:before` - pseudo-element that is inserted as the first child of the element
+    Before,
+    /// `:after` - pseudo-element that is inserted as the last child of the element
+    After,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -534,6 +640,12 @@ impl fmt::Display for CssPathPseudoSelector {
             Hover => write!(f, "hover"),
             Active => write!(f, "active"),
             Focus => write!(f, "focus"),
+            Disabled => write!(f, "disabled"),
+            Checked => write!(f, "checked"),
+            Selected => write!(f, "selected"),
+            Indeterminate => write!(f, "indeterminate"),
+            Before => write!(f, "before"),
+            After => write!(f, "after"),
         }
     }
 }
@@ -684,6 +796,7 @@ fn test_specificity_sort() {
                     selectors: vec![Global].into(),
                 },
                 declarations: Vec::new().into(),
+                media: CssMediaCondition::ALWAYS,
             },
             CssRuleBlock {
                 path: CssPath {
@@ -696,18 +809,21 @@ fn test_specificity_sort() {
                     .into(),
                 },
                 declarations: Vec::new().into(),
+                media: CssMediaCondition::ALWAYS,
             },
             CssRuleBlock {
                 path: CssPath {
                     selectors: vec![Global, Type(Div), Id("my_id".to_string().into())].into(),
                 },
                 declarations: Vec::new().into(),
+                media: CssMediaCondition::ALWAYS,
             },
             CssRuleBlock {
                 path: CssPath {
                     selectors: vec![Global, Id("my_id".to_string().into())].into(),
                 },
                 declarations: Vec::new().into(),
+                media: CssMediaCondition::ALWAYS,
             },
             CssRuleBlock {
                 path: CssPath {
@@ -720,6 +836,7 @@ fn test_specificity_sort() {
                     .into(),
                 },
                 declarations: Vec::new().into(),
+                media: CssMediaCondition::ALWAYS,
             },
         ]
         .into(),
@@ -734,18 +851,21 @@ fn test_specificity_sort() {
                     selectors: vec![Global].into(),
                 },
                 declarations: Vec::new().into(),
+                media: CssMediaCondition::ALWAYS,
             },
             CssRuleBlock {
                 path: CssPath {
                     selectors: vec![Global, Id("my_id".to_string().into())].into(),
                 },
                 declarations: Vec::new().into(),
+                media: CssMediaCondition::ALWAYS,
             },
             CssRuleBlock {
                 path: CssPath {
                     selectors: vec![Global, Type(Div), Id("my_id".to_string().into())].into(),
                 },
                 declarations: Vec::new().into(),
+                media: CssMediaCondition::ALWAYS,
             },
             CssRuleBlock {
                 path: CssPath {
@@ -758,6 +878,7 @@ fn test_specificity_sort() {
                     .into(),
                 },
                 declarations: Vec::new().into(),
+                media: CssMediaCondition::ALWAYS,
             },
             CssRuleBlock {
                 path: CssPath {
@@ -770,6 +891,7 @@ fn test_specificity_sort() {
                     .into(),
                 },
                 declarations: Vec::new().into(),
+                media: CssMediaCondition::ALWAYS,
             },
         ]
         .into(),
@@ -32,7 +32,7 @@ const COMBINED_CSS_PROPERTIES_KEY_MAP: [(CombinedCssPropertyType, &'static str);
 ];
 
 /// Map between CSS keys and a statically typed enum
-const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str); 74] = [
+const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str); 88] = [
     (CssPropertyType::Display, "display"),
     (CssPropertyType::Float, "float"),
     (CssPropertyType::BoxSizing, "box-sizing"),
@@ -44,6 +44,7 @@ const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str); 74] = [
     (CssPropertyType::LineHeight, "line-height"),
     (CssPropertyType::WordSpacing, "word-spacing"),
     (CssPropertyType::TabWidth, "tab-width"),
+    (CssPropertyType::Hyphens, "hyphens"),
     (CssPropertyType::Cursor, "cursor"),
     (CssPropertyType::Width, "width"),
     (CssPropertyType::Height, "height"),
@@ -110,11 +111,24 @@ const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str); 74] = [
     (CssPropertyType::BoxShadowLeft, "-azul-box-shadow-left"),
     (CssPropertyType::BoxShadowBottom, "-azul-box-shadow-bottom"),
     (CssPropertyType::ScrollbarStyle, "-azul-scrollbar-style"),
+    (CssPropertyType::ScrollbarWidth, "scrollbar-width"),
+    (CssPropertyType::ScrollbarColor, "scrollbar-color"),
+    (CssPropertyType::ScrollbarThumbHoverColor, "-azul-scrollbar-thumb-hover-color"),
+    (CssPropertyType::ScrollbarThumbActiveColor, "-azul-scrollbar-thumb-active-color"),
+    (CssPropertyType::WindowDragRegion, "-azul-window-drag-region"),
+    (CssPropertyType::CaretAnimationDuration, "-azul-caret-animation-duration"),
+    (CssPropertyType::ScrollPadding, "scroll-padding"),
+    (CssPropertyType::Content, "content"),
+    (CssPropertyType::CounterReset, "counter-reset"),
+    (CssPropertyType::CounterIncrement, "counter-increment"),
+    (CssPropertyType::OverflowAnchor, "overflow-anchor"),
     (CssPropertyType::Opacity, "opacity"),
     (CssPropertyType::Transform, "transform"),
     (CssPropertyType::PerspectiveOrigin, "perspective-origin"),
     (CssPropertyType::TransformOrigin, "transform-origin"),
     (CssPropertyType::BackfaceVisibility, "backface-visibility"),
+    (CssPropertyType::Perspective, "perspective"),
+    (CssPropertyType::TransformStyle, "transform-style"),
     (CssPropertyType::MixBlendMode, "mix-blend-mode"),
     (CssPropertyType::Filter, "filter"),
     (CssPropertyType::BackdropFilter, "backdrop-filter"),
@@ -490,6 +504,105 @@ impl ColorU {
     }
 }
 
+/// Colors of the CSS system color keywords (`Canvas`, `CanvasText`, `Highlight`, ...),
+/// queried from the operating system on platforms that support it
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct SystemColors {
+    /// `Canvas`: background of the window
+    pub canvas: ColorU,
+    /// `CanvasText`: text on the `Canvas` background
+    pub canvas_text: ColorU,
+    /// `LinkText`: text of hyperlinks
+    pub link_text: ColorU,
+    /// `GrayText`: text of disabled controls
+    pub gray_text: ColorU,
+    /// `Highlight`: background of selected items
+    pub highlight: ColorU,
+    /// `HighlightText`: text of selected items
+    pub highlight_text: ColorU,
+    /// `ButtonFace`: background of buttons
+    pub button_face: ColorU,
+    /// `ButtonText`: text of buttons
+    pub button_text: ColorU,
+}
+
+impl SystemColors {
+    /// Default colors (same as the defaults of web browsers)
+    pub const DEFAULT: SystemColors = SystemColors {
+        canvas: ColorU::WHITE,
+        canvas_text: ColorU::BLACK,
+        link_text: ColorU::new_rgb(0, 0, 238),
+        gray_text: ColorU::new_rgb(109, 109, 109),
+        highlight: ColorU::new_rgb(0, 120, 215),
+        highlight_text: ColorU::WHITE,
+        button_face: ColorU::new_rgb(240, 240, 240),
+        button_text: ColorU::BLACK,
+    };
+
+    /// Resolves a CSS system color keyword (case-insensitive), `None` if
+    /// the keyword is not a system color
+    pub fn get(&self, keyword: &str) -> Option<ColorU> {
+        let color = match keyword.to_ascii_lowercase().as_str() {
+            "canvas" => self.canvas,
+            "canvastext" => self.canvas_text,
+            "linktext" => self.link_text,
+            "graytext" => self.gray_text,
+            "highlight" => self.highlight,
+            "highlighttext" => self.highlight_text,
+            "buttonface" => self.button_face,
+            "buttontext" => self.button_text,
+            _ => return None,
+        };
+        Some(color)
+    }
+}
+
+impl Default for SystemColors {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Accessibility settings of the operating system and the current input device that
+/// are taken into account when a DOM is styled (system color keywords, `@media (forced-colors: active)`,
+/// `@media (prefers-reduced-motion: reduce)`, `@media (pointer: coarse)`)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct SystemStyle {
+    /// Whether the user has enabled a high contrast / forced colors mode
+    pub forced_colors: bool,
+    /// Whether the user has turned off animations: the built-in animations
+    /// jump to their end state instead of animating
+    pub prefers_reduced_motion: bool,
+    /// Whether the last input came from a touch screen instead of
+    /// a mouse or pen: `@media (pointer: coarse)` / `@media (hover: none)`
+    pub pointer_coarse: bool,
+    /// Colors of the system color keywords
+    pub colors: SystemColors,
+}
+
+impl Default for SystemStyle {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl SystemStyle {
+    pub const DEFAULT: SystemStyle = SystemStyle {
+        forced_colors: false,
+        prefers_reduced_motion: false,
+        pointer_coarse: false,
+        colors: SystemColors::DEFAULT,
+    };
+
+    /// Resolves a CSS system color keyword (case-insensitive), `None` if
+    /// the keyword is not a system color
+    pub fn get_system_color(&self, keyword: &str) -> Option<ColorU> {
+        self.colors.get(keyword)
+    }
+}
+
 /// f32-based color, range 0.0 to 1.0 (similar to webrenders ColorF)
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct ColorF {
@@ -1004,6 +1117,7 @@ pub enum CssPropertyType {
     LineHeight,
     WordSpacing,
     TabWidth,
+    Hyphens,
     Cursor,
     Display,
     Float,
@@ -1061,11 +1175,24 @@ pub enum CssPropertyType {
     BoxShadowTop,
     BoxShadowBottom,
     ScrollbarStyle,
+    ScrollbarWidth,
+    ScrollbarColor,
+    ScrollbarThumbHoverColor,
+    ScrollbarThumbActiveColor,
+    WindowDragRegion,
+    CaretAnimationDuration,
+    ScrollPadding,
+    Content,
+    CounterReset,
+    CounterIncrement,
+    OverflowAnchor,
     Opacity,
     Transform,
     TransformOrigin,
     PerspectiveOrigin,
     BackfaceVisibility,
+    Perspective,
+    TransformStyle,
     MixBlendMode,
     Filter,
     BackdropFilter,
@@ -1100,6 +1227,7 @@ impl CssPropertyType {
             CssPropertyType::LineHeight => "line-height",
             CssPropertyType::WordSpacing => "word-spacing",
             CssPropertyType::TabWidth => "tab-width",
+            CssPropertyType::Hyphens => "hyphens",
             CssPropertyType::Cursor => "cursor",
             CssPropertyType::Display => "display",
             CssPropertyType::Float => "float",
@@ -1157,11 +1285,24 @@ impl CssPropertyType {
             CssPropertyType::BoxShadowTop => "-azul-box-shadow-top",
             CssPropertyType::BoxShadowBottom => "-azul-box-shadow-bottom",
             CssPropertyType::ScrollbarStyle => "-azul-scrollbar-style",
+            CssPropertyType::ScrollbarWidth => "scrollbar-width",
+            CssPropertyType::ScrollbarColor => "scrollbar-color",
+            CssPropertyType::ScrollbarThumbHoverColor => "-azul-scrollbar-thumb-hover-color",
+            CssPropertyType::ScrollbarThumbActiveColor => "-azul-scrollbar-thumb-active-color",
+            CssPropertyType::WindowDragRegion => "-azul-window-drag-region",
+            CssPropertyType::CaretAnimationDuration => "-azul-caret-animation-duration",
+            CssPropertyType::ScrollPadding => "scroll-padding",
+            CssPropertyType::Content => "content",
+            CssPropertyType::CounterReset => "counter-reset",
+            CssPropertyType::CounterIncrement => "counter-increment",
+            CssPropertyType::OverflowAnchor => "overflow-anchor",
             CssPropertyType::Opacity => "opacity",
             CssPropertyType::Transform => "transform",
             CssPropertyType::TransformOrigin => "transform-origin",
             CssPropertyType::PerspectiveOrigin => "perspective-origin",
             CssPropertyType::BackfaceVisibility => "backface-visibility",
+            CssPropertyType::Perspective => "perspective",
+            CssPropertyType::TransformStyle => "transform-style",
             CssPropertyType::MixBlendMode => "mix-blend-mode",
             CssPropertyType::Filter => "filter",
             CssPropertyType::BackdropFilter => "backdrop-filter",
@@ -1173,7 +1314,7 @@ impl CssPropertyType {
     pub fn is_inheritable(&self) -> bool {
         use self::CssPropertyType::*;
         match self {
-            TextColor | FontFamily | FontSize | LineHeight | TextAlign => true,
+            TextColor | FontFamily | FontSize | LineHeight | TextAlign | Hyphens => true,
             _ => false,
         }
     }
@@ -1212,11 +1353,24 @@ impl CssPropertyType {
             | BoxShadowTop
             | BoxShadowBottom
             | ScrollbarStyle
+            | ScrollbarWidth
+            | ScrollbarColor
+            | ScrollbarThumbHoverColor
+            | ScrollbarThumbActiveColor
+            | WindowDragRegion
+            | CaretAnimationDuration
+            | ScrollPadding
+            | Content
+            | CounterReset
+            | CounterIncrement
+            | OverflowAnchor
             | Opacity
             | Transform
             | TransformOrigin
             | PerspectiveOrigin
             | BackfaceVisibility
+            | Perspective
+            | TransformStyle
             | MixBlendMode
             | Filter
             | BackdropFilter
@@ -1259,6 +1413,7 @@ pub enum CssProperty {
     LineHeight(StyleLineHeightValue),
     WordSpacing(StyleWordSpacingValue),
     TabWidth(StyleTabWidthValue),
+    Hyphens(StyleHyphensValue),
     Cursor(StyleCursorValue),
     Display(LayoutDisplayValue),
     Float(LayoutFloatValue),
@@ -1316,11 +1471,24 @@ pub enum CssProperty {
     BoxShadowTop(StyleBoxShadowValue),
     BoxShadowBottom(StyleBoxShadowValue),
     ScrollbarStyle(ScrollbarStyleValue),
+    ScrollbarWidth(StyleScrollbarWidthValue),
+    ScrollbarColor(StyleScrollbarColorValue),
+    ScrollbarThumbHoverColor(StyleScrollbarThumbHoverColorValue),
+    ScrollbarThumbActiveColor(StyleScrollbarThumbActiveColorValue),
+    WindowDragRegion(StyleWindowDragRegionValue),
+    CaretAnimationDuration(StyleCaretAnimationDurationValue),
+    ScrollPadding(StyleScrollPaddingValue),
+    Content(StyleContentValue),
+    CounterReset(StyleCounterResetValue),
+    CounterIncrement(StyleCounterIncrementValue),
+    OverflowAnchor(StyleOverflowAnchorValue),
     Opacity(StyleOpacityValue),
     Transform(StyleTransformVecValue),
     TransformOrigin(StyleTransformOriginValue),
     PerspectiveOrigin(StylePerspectiveOriginValue),
     BackfaceVisibility(StyleBackfaceVisibilityValue),
+    Perspective(StylePerspectiveValue),
+    TransformStyle(StyleTransformStyleValue),
     MixBlendMode(StyleMixBlendModeValue),
     Filter(StyleFilterVecValue),
     BackdropFilter(StyleFilterVecValue),
@@ -1357,6 +1525,7 @@ macro_rules! css_property_from_type {
                 CssProperty::WordSpacing(StyleWordSpacingValue::$content_type)
             }
             CssPropertyType::TabWidth => CssProperty::TabWidth(StyleTabWidthValue::$content_type),
+            CssPropertyType::Hyphens => CssProperty::Hyphens(StyleHyphensValue::$content_type),
             CssPropertyType::Cursor => CssProperty::Cursor(StyleCursorValue::$content_type),
             CssPropertyType::Display => CssProperty::Display(LayoutDisplayValue::$content_type),
             CssPropertyType::Float => CssProperty::Float(LayoutFloatValue::$content_type),
@@ -1500,6 +1669,37 @@ macro_rules! css_property_from_type {
             CssPropertyType::ScrollbarStyle => {
                 CssProperty::ScrollbarStyle(ScrollbarStyleValue::$content_type)
             }
+            CssPropertyType::ScrollbarWidth => {
+                CssProperty::ScrollbarWidth(StyleScrollbarWidthValue::$content_type)
+            }
+            CssPropertyType::ScrollbarColor => {
+                CssProperty::ScrollbarColor(StyleScrollbarColorValue::$content_type)
+            }
+            CssPropertyType::ScrollbarThumbHoverColor => {
+                CssProperty::ScrollbarThumbHoverColor(StyleScrollbarThumbHoverColorValue::$content_type)
+            }
+            CssPropertyType::ScrollbarThumbActiveColor => {
+                CssProperty::ScrollbarThumbActiveColor(StyleScrollbarThumbActiveColorValue::$content_type)
+            }
+            CssPropertyType::WindowDragRegion => {
+                CssProperty::WindowDragRegion(StyleWindowDragRegionValue::$content_type)
+            }
+            CssPropertyType::CaretAnimationDuration => {
+                CssProperty::CaretAnimationDuration(StyleCaretAnimationDurationValue::$content_type)
+            }
+            CssPropertyType::ScrollPadding => {
+                CssProperty::ScrollPadding(StyleScrollPaddingValue::$content_type)
+            }
+            CssPropertyType::Content => CssProperty::Content(StyleContentValue::$content_type),
+            CssPropertyType::CounterReset => {
+                CssProperty::CounterReset(StyleCounterResetValue::$content_type)
+            }
+            CssPropertyType::CounterIncrement => {
+                CssProperty::CounterIncrement(StyleCounterIncrementValue::$content_type)
+            }
+            CssPropertyType::OverflowAnchor => {
+                CssProperty::OverflowAnchor(StyleOverflowAnchorValue::$content_type)
+            }
             CssPropertyType::Opacity => CssProperty::Opacity(StyleOpacityValue::$content_type),
             CssPropertyType::Transform => {
                 CssProperty::Transform(StyleTransformVecValue::$content_type)
@@ -1513,6 +1713,12 @@ macro_rules! css_property_from_type {
             CssPropertyType::BackfaceVisibility => {
                 CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::$content_type)
             }
+            CssPropertyType::Perspective => {
+                CssProperty::Perspective(StylePerspectiveValue::$content_type)
+            }
+            CssPropertyType::TransformStyle => {
+                CssProperty::TransformStyle(StyleTransformStyleValue::$content_type)
+            }
             CssPropertyType::MixBlendMode => {
                 CssProperty::MixBlendMode(StyleMixBlendModeValue::$content_type)
             }
@@ -1539,6 +1745,7 @@ impl CssProperty {
             LineHeight(c) => c.is_initial(),
             WordSpacing(c) => c.is_initial(),
             TabWidth(c) => c.is_initial(),
+            Hyphens(c) => c.is_initial(),
             Cursor(c) => c.is_initial(),
             Display(c) => c.is_initial(),
             Float(c) => c.is_initial(),
@@ -1596,11 +1803,24 @@ impl CssProperty {
             BoxShadowTop(c) => c.is_initial(),
             BoxShadowBottom(c) => c.is_initial(),
             ScrollbarStyle(c) => c.is_initial(),
+            ScrollbarWidth(c) => c.is_initial(),
+            ScrollbarColor(c) => c.is_initial(),
+            ScrollbarThumbHoverColor(c) => c.is_initial(),
+            ScrollbarThumbActiveColor(c) => c.is_initial(),
+            WindowDragRegion(c) => c.is_initial(),
+            CaretAnimationDuration(c) => c.is_initial(),
+            ScrollPadding(c) => c.is_initial(),
+            Content(c) => c.is_initial(),
+            CounterReset(c) => c.is_initial(),
+            CounterIncrement(c) => c.is_initial(),
+            OverflowAnchor(c) => c.is_initial(),
             Opacity(c) => c.is_initial(),
             Transform(c) => c.is_initial(),
             TransformOrigin(c) => c.is_initial(),
             PerspectiveOrigin(c) => c.is_initial(),
             BackfaceVisibility(c) => c.is_initial(),
+            Perspective(c) => c.is_initial(),
+            TransformStyle(c) => c.is_initial(),
             MixBlendMode(c) => c.is_initial(),
             Filter(c) => c.is_initial(),
             BackdropFilter(c) => c.is_initial(),
@@ -1645,6 +1865,9 @@ impl CssProperty {
     pub const fn const_tab_width(input: StyleTabWidth) -> Self {
         CssProperty::TabWidth(StyleTabWidthValue::Exact(input))
     }
+    pub const fn const_hyphens(input: StyleHyphens) -> Self {
+        CssProperty::Hyphens(StyleHyphensValue::Exact(input))
+    }
     pub const fn const_cursor(input: StyleCursor) -> Self {
         CssProperty::Cursor(StyleCursorValue::Exact(input))
     }
@@ -1813,6 +2036,39 @@ impl CssProperty {
     pub const fn const_box_shadow_bottom(input: StyleBoxShadow) -> Self {
         CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(input))
     }
+    pub const fn const_scrollbar_width(input: StyleScrollbarWidth) -> Self {
+        CssProperty::ScrollbarWidth(StyleScrollbarWidthValue::Exact(input))
+    }
+    pub const fn const_scrollbar_color(input: StyleScrollbarColor) -> Self {
+        CssProperty::ScrollbarColor(StyleScrollbarColorValue::Exact(input))
+    }
+    pub const fn const_scrollbar_thumb_hover_color(input: StyleScrollbarThumbHoverColor) -> Self {
+        CssProperty::ScrollbarThumbHoverColor(StyleScrollbarThumbHoverColorValue::Exact(input))
+    }
+    pub const fn const_scrollbar_thumb_active_color(input: StyleScrollbarThumbActiveColor) -> Self {
+        CssProperty::ScrollbarThumbActiveColor(StyleScrollbarThumbActiveColorValue::Exact(input))
+    }
+    pub const fn const_window_drag_region(input: StyleWindowDragRegion) -> Self {
+        CssProperty::WindowDragRegion(StyleWindowDragRegionValue::Exact(input))
+    }
+    pub const fn const_caret_animation_duration(input: StyleCaretAnimationDuration) -> Self {
+        CssProperty::CaretAnimationDuration(StyleCaretAnimationDurationValue::Exact(input))
+    }
+    pub const fn const_scroll_padding(input: StyleScrollPadding) -> Self {
+        CssProperty::ScrollPadding(StyleScrollPaddingValue::Exact(input))
+    }
+    pub const fn const_content(input: StyleContent) -> Self {
+        CssProperty::Content(StyleContentValue::Exact(input))
+    }
+    pub const fn const_counter_reset(input: StyleCounterReset) -> Self {
+        CssProperty::CounterReset(StyleCounterResetValue::Exact(input))
+    }
+    pub const fn const_counter_increment(input: StyleCounterIncrement) -> Self {
+        CssProperty::CounterIncrement(StyleCounterIncrementValue::Exact(input))
+    }
+    pub const fn const_overflow_anchor(input: StyleOverflowAnchor) -> Self {
+        CssProperty::OverflowAnchor(StyleOverflowAnchorValue::Exact(input))
+    }
     pub const fn const_opacity(input: StyleOpacity) -> Self {
         CssProperty::Opacity(StyleOpacityValue::Exact(input))
     }
@@ -1828,6 +2084,12 @@ impl CssProperty {
     pub const fn const_backface_visiblity(input: StyleBackfaceVisibility) -> Self {
         CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::Exact(input))
     }
+    pub const fn const_perspective(input: StylePerspective) -> Self {
+        CssProperty::Perspective(StylePerspectiveValue::Exact(input))
+    }
+    pub const fn const_transform_style(input: StyleTransformStyle) -> Self {
+        CssProperty::TransformStyle(StyleTransformStyleValue::Exact(input))
+    }
 }
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[repr(C, u8)]
@@ -2298,6 +2560,7 @@ impl CssProperty {
             CssProperty::LineHeight(v) => v.get_css_value_fmt(),
             CssProperty::WordSpacing(v) => v.get_css_value_fmt(),
             CssProperty::TabWidth(v) => v.get_css_value_fmt(),
+            CssProperty::Hyphens(v) => v.get_css_value_fmt(),
             CssProperty::Cursor(v) => v.get_css_value_fmt(),
             CssProperty::Display(v) => v.get_css_value_fmt(),
             CssProperty::Float(v) => v.get_css_value_fmt(),
@@ -2355,11 +2618,24 @@ impl CssProperty {
             CssProperty::BoxShadowTop(v) => v.get_css_value_fmt(),
             CssProperty::BoxShadowBottom(v) => v.get_css_value_fmt(),
             CssProperty::ScrollbarStyle(v) => v.get_css_value_fmt(),
+            CssProperty::ScrollbarWidth(v) => v.get_css_value_fmt(),
+            CssProperty::ScrollbarColor(v) => v.get_css_value_fmt(),
+            CssProperty::ScrollbarThumbHoverColor(v) => v.get_css_value_fmt(),
+            CssProperty::ScrollbarThumbActiveColor(v) => v.get_css_value_fmt(),
+            CssProperty::WindowDragRegion(v) => v.get_css_value_fmt(),
+            CssProperty::CaretAnimationDuration(v) => v.get_css_value_fmt(),
+            CssProperty::ScrollPadding(v) => v.get_css_value_fmt(),
+            CssProperty::Content(v) => v.get_css_value_fmt(),
+            CssProperty::CounterReset(v) => v.get_css_value_fmt(),
+            CssProperty::CounterIncrement(v) => v.get_css_value_fmt(),
+            CssProperty::OverflowAnchor(v) => v.get_css_value_fmt(),
             CssProperty::Opacity(v) => v.get_css_value_fmt(),
             CssProperty::Transform(v) => v.get_css_value_fmt(),
             CssProperty::TransformOrigin(v) => v.get_css_value_fmt(),
             CssProperty::PerspectiveOrigin(v) => v.get_css_value_fmt(),
             CssProperty::BackfaceVisibility(v) => v.get_css_value_fmt(),
+            CssProperty::Perspective(v) => v.get_css_value_fmt(),
+            CssProperty::TransformStyle(v) => v.get_css_value_fmt(),
             CssProperty::MixBlendMode(v) => v.get_css_value_fmt(),
             CssProperty::Filter(v) => v.get_css_value_fmt(),
             CssProperty::BackdropFilter(v) => v.get_css_value_fmt(),
@@ -2614,6 +2890,11 @@ impl CssProperty {
                 let end = end.get_property().copied().unwrap_or_default();
                 CssProperty::PerspectiveOrigin(CssPropertyValue::Exact(start.interpolate(&end, t)))
             }
+            (CssProperty::Perspective(start), CssProperty::Perspective(end)) => {
+                let start = start.get_property().copied().unwrap_or_default();
+                let end = end.get_property().copied().unwrap_or_default();
+                CssProperty::Perspective(CssPropertyValue::Exact(start.interpolate(&end, t)))
+            }
             /*
             animate transform:
             CssProperty::Transform(CssPropertyValue<StyleTransformVec>),
@@ -2665,6 +2946,7 @@ macro_rules! css_property_from_type {
                 CssProperty::WordSpacing(CssPropertyValue::$content_type)
             }
             CssPropertyType::TabWidth => CssProperty::TabWidth(CssPropertyValue::$content_type),
+            CssPropertyType::Hyphens => CssProperty::Hyphens(CssPropertyValue::$content_type),
             CssPropertyType::Cursor => CssProperty::Cursor(CssPropertyValue::$content_type),
             CssPropertyType::Display => CssProperty::Display(CssPropertyValue::$content_type),
             CssPropertyType::Float => CssProperty::Float(CssPropertyValue::$content_type),
@@ -2788,6 +3070,37 @@ macro_rules! css_property_from_type {
             CssPropertyType::ScrollbarStyle => {
                 CssProperty::ScrollbarStyle(CssPropertyValue::$content_type)
             }
+            CssPropertyType::ScrollbarWidth => {
+                CssProperty::ScrollbarWidth(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::ScrollbarColor => {
+                CssProperty::ScrollbarColor(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::ScrollbarThumbHoverColor => {
+                CssProperty::ScrollbarThumbHoverColor(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::ScrollbarThumbActiveColor => {
+                CssProperty::ScrollbarThumbActiveColor(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::WindowDragRegion => {
+                CssProperty::WindowDragRegion(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::CaretAnimationDuration => {
+                CssProperty::CaretAnimationDuration(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::ScrollPadding => {
+                CssProperty::ScrollPadding(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::Content => CssProperty::Content(CssPropertyValue::$content_type),
+            CssPropertyType::CounterReset => {
+                CssProperty::CounterReset(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::CounterIncrement => {
+                CssProperty::CounterIncrement(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::OverflowAnchor => {
+                CssProperty::OverflowAnchor(CssPropertyValue::$content_type)
+            }
             CssPropertyType::Opacity => CssProperty::Opacity(CssPropertyValue::$content_type),
             CssPropertyType::Transform => CssProperty::Transform(CssPropertyValue::$content_type),
             CssPropertyType::PerspectiveOrigin => {
@@ -2799,6 +3112,12 @@ macro_rules! css_property_from_type {
             CssPropertyType::BackfaceVisibility => {
                 CssProperty::BackfaceVisibility(CssPropertyValue::$content_type)
             }
+            CssPropertyType::Perspective => {
+                CssProperty::Perspective(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::TransformStyle => {
+                CssProperty::TransformStyle(CssPropertyValue::$content_type)
+            }
             CssPropertyType::MixBlendMode => {
                 CssProperty::MixBlendMode(CssPropertyValue::$content_type)
             }
@@ -2823,6 +3142,7 @@ impl CssProperty {
             CssProperty::LineHeight(_) => CssPropertyType::LineHeight,
             CssProperty::WordSpacing(_) => CssPropertyType::WordSpacing,
             CssProperty::TabWidth(_) => CssPropertyType::TabWidth,
+            CssProperty::Hyphens(_) => CssPropertyType::Hyphens,
             CssProperty::Cursor(_) => CssPropertyType::Cursor,
             CssProperty::Display(_) => CssPropertyType::Display,
             CssProperty::Float(_) => CssPropertyType::Float,
@@ -2880,11 +3200,24 @@ impl CssProperty {
             CssProperty::BoxShadowTop(_) => CssPropertyType::BoxShadowTop,
             CssProperty::BoxShadowBottom(_) => CssPropertyType::BoxShadowBottom,
             CssProperty::ScrollbarStyle(_) => CssPropertyType::ScrollbarStyle,
+            CssProperty::ScrollbarWidth(_) => CssPropertyType::ScrollbarWidth,
+            CssProperty::ScrollbarColor(_) => CssPropertyType::ScrollbarColor,
+            CssProperty::ScrollbarThumbHoverColor(_) => CssPropertyType::ScrollbarThumbHoverColor,
+            CssProperty::ScrollbarThumbActiveColor(_) => CssPropertyType::ScrollbarThumbActiveColor,
+            CssProperty::WindowDragRegion(_) => CssPropertyType::WindowDragRegion,
+            CssProperty::CaretAnimationDuration(_) => CssPropertyType::CaretAnimationDuration,
+            CssProperty::ScrollPadding(_) => CssPropertyType::ScrollPadding,
+            CssProperty::Content(_) => CssPropertyType::Content,
+            CssProperty::CounterReset(_) => CssPropertyType::CounterReset,
+            CssProperty::CounterIncrement(_) => CssPropertyType::CounterIncrement,
+            CssProperty::OverflowAnchor(_) => CssPropertyType::OverflowAnchor,
             CssProperty::Opacity(_) => CssPropertyType::Opacity,
             CssProperty::Transform(_) => CssPropertyType::Transform,
             CssProperty::PerspectiveOrigin(_) => CssPropertyType::PerspectiveOrigin,
             CssProperty::TransformOrigin(_) => CssPropertyType::TransformOrigin,
             CssProperty::BackfaceVisibility(_) => CssPropertyType::BackfaceVisibility,
+            CssProperty::Perspective(_) => CssPropertyType::Perspective,
+            CssProperty::TransformStyle(_) => CssPropertyType::TransformStyle,
             CssProperty::MixBlendMode(_) => CssPropertyType::MixBlendMode,
             CssProperty::Filter(_) => CssPropertyType::Filter,
             CssProperty::BackdropFilter(_) => CssPropertyType::BackdropFilter,
@@ -2931,6 +3264,9 @@ impl CssProperty {
     pub const fn tab_width(input: StyleTabWidth) -> Self {
         CssProperty::TabWidth(CssPropertyValue::Exact(input))
     }
+    pub const fn hyphens(input: StyleHyphens) -> Self {
+        CssProperty::Hyphens(CssPropertyValue::Exact(input))
+    }
     pub const fn cursor(input: StyleCursor) -> Self {
         CssProperty::Cursor(CssPropertyValue::Exact(input))
     }
@@ -3099,6 +3435,39 @@ impl CssProperty {
     pub const fn box_shadow_bottom(input: StyleBoxShadow) -> Self {
         CssProperty::BoxShadowBottom(CssPropertyValue::Exact(input))
     }
+    pub const fn scrollbar_width(input: StyleScrollbarWidth) -> Self {
+        CssProperty::ScrollbarWidth(CssPropertyValue::Exact(input))
+    }
+    pub const fn scrollbar_color(input: StyleScrollbarColor) -> Self {
+        CssProperty::ScrollbarColor(CssPropertyValue::Exact(input))
+    }
+    pub const fn scrollbar_thumb_hover_color(input: StyleScrollbarThumbHoverColor) -> Self {
+        CssProperty::ScrollbarThumbHoverColor(CssPropertyValue::Exact(input))
+    }
+    pub const fn scrollbar_thumb_active_color(input: StyleScrollbarThumbActiveColor) -> Self {
+        CssProperty::ScrollbarThumbActiveColor(CssPropertyValue::Exact(input))
+    }
+    pub const fn window_drag_region(input: StyleWindowDragRegion) -> Self {
+        CssProperty::WindowDragRegion(CssPropertyValue::Exact(input))
+    }
+    pub const fn caret_animation_duration(input: StyleCaretAnimationDuration) -> Self {
+        CssProperty::CaretAnimationDuration(CssPropertyValue::Exact(input))
+    }
+    pub const fn scroll_padding(input: StyleScrollPadding) -> Self {
+        CssProperty::ScrollPadding(CssPropertyValue::Exact(input))
+    }
+    pub const fn content(input: StyleContent) -> Self {
+        CssProperty::Content(CssPropertyValue::Exact(input))
+    }
+    pub const fn counter_reset(input: StyleCounterReset) -> Self {
+        CssProperty::CounterReset(CssPropertyValue::Exact(input))
+    }
+    pub const fn counter_increment(input: StyleCounterIncrement) -> Self {
+        CssProperty::CounterIncrement(CssPropertyValue::Exact(input))
+    }
+    pub const fn overflow_anchor(input: StyleOverflowAnchor) -> Self {
+        CssProperty::OverflowAnchor(CssPropertyValue::Exact(input))
+    }
     pub const fn opacity(input: StyleOpacity) -> Self {
         CssProperty::Opacity(CssPropertyValue::Exact(input))
     }
@@ -3114,6 +3483,12 @@ impl CssProperty {
     pub const fn backface_visiblity(input: StyleBackfaceVisibility) -> Self {
         CssProperty::BackfaceVisibility(CssPropertyValue::Exact(input))
     }
+    pub const fn perspective(input: StylePerspective) -> Self {
+        CssProperty::Perspective(CssPropertyValue::Exact(input))
+    }
+    pub const fn transform_style(input: StyleTransformStyle) -> Self {
+        CssProperty::TransformStyle(CssPropertyValue::Exact(input))
+    }
 
     // functions that downcast to the concrete CSS type (style)
 
@@ -3189,6 +3564,12 @@ impl CssProperty {
             _ => None,
         }
     }
+    pub const fn as_hyphens(&self) -> Option<&StyleHyphensValue> {
+        match self {
+            CssProperty::Hyphens(f) => Some(f),
+            _ => None,
+        }
+    }
     pub const fn as_cursor(&self) -> Option<&StyleCursorValue> {
         match self {
             CssProperty::Cursor(f) => Some(f),
@@ -3299,6 +3680,72 @@ impl CssProperty {
             _ => None,
         }
     }
+    pub const fn as_scrollbar_width(&self) -> Option<&StyleScrollbarWidthValue> {
+        match self {
+            CssProperty::ScrollbarWidth(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_scrollbar_color(&self) -> Option<&StyleScrollbarColorValue> {
+        match self {
+            CssProperty::ScrollbarColor(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_scrollbar_thumb_hover_color(&self) -> Option<&StyleScrollbarThumbHoverColorValue> {
+        match self {
+            CssProperty::ScrollbarThumbHoverColor(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_scrollbar_thumb_active_color(&self) -> Option<&StyleScrollbarThumbActiveColorValue> {
+        match self {
+            CssProperty::ScrollbarThumbActiveColor(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_window_drag_region(&self) -> Option<&StyleWindowDragRegionValue> {
+        match self {
+            CssProperty::WindowDragRegion(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_caret_animation_duration(&self) -> Option<&StyleCaretAnimationDurationValue> {
+        match self {
+            CssProperty::CaretAnimationDuration(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_scroll_padding(&self) -> Option<&StyleScrollPaddingValue> {
+        match self {
+            CssProperty::ScrollPadding(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_content(&self) -> Option<&StyleContentValue> {
+        match self {
+            CssProperty::Content(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_counter_reset(&self) -> Option<&StyleCounterResetValue> {
+        match self {
+            CssProperty::CounterReset(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_counter_increment(&self) -> Option<&StyleCounterIncrementValue> {
+        match self {
+            CssProperty::CounterIncrement(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_overflow_anchor(&self) -> Option<&StyleOverflowAnchorValue> {
+        match self {
+            CssProperty::OverflowAnchor(f) => Some(f),
+            _ => None,
+        }
+    }
     pub const fn as_transform(&self) -> Option<&StyleTransformVecValue> {
         match self {
             CssProperty::Transform(f) => Some(f),
@@ -3323,6 +3770,18 @@ impl CssProperty {
             _ => None,
         }
     }
+    pub const fn as_perspective(&self) -> Option<&StylePerspectiveValue> {
+        match self {
+            CssProperty::Perspective(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_transform_style(&self) -> Option<&StyleTransformStyleValue> {
+        match self {
+            CssProperty::TransformStyle(f) => Some(f),
+            _ => None,
+        }
+    }
     pub const fn as_mix_blend_mode(&self) -> Option<&StyleMixBlendModeValue> {
         match self {
             CssProperty::MixBlendMode(f) => Some(f),
@@ -3580,6 +4039,7 @@ impl_from_css_prop!(StyleLetterSpacing, CssProperty::LetterSpacing);
 impl_from_css_prop!(StyleLineHeight, CssProperty::LineHeight);
 impl_from_css_prop!(StyleWordSpacing, CssProperty::WordSpacing);
 impl_from_css_prop!(StyleTabWidth, CssProperty::TabWidth);
+impl_from_css_prop!(StyleHyphens, CssProperty::Hyphens);
 impl_from_css_prop!(StyleCursor, CssProperty::Cursor);
 impl_from_css_prop!(LayoutDisplay, CssProperty::Display);
 impl_from_css_prop!(LayoutFloat, CssProperty::Float);
@@ -3637,11 +4097,24 @@ impl_from_css_prop!(LayoutBorderRightWidth, CssProperty::BorderRightWidth);
 impl_from_css_prop!(LayoutBorderLeftWidth, CssProperty::BorderLeftWidth);
 impl_from_css_prop!(LayoutBorderBottomWidth, CssProperty::BorderBottomWidth);
 impl_from_css_prop!(ScrollbarStyle, CssProperty::ScrollbarStyle);
+impl_from_css_prop!(StyleScrollbarWidth, CssProperty::ScrollbarWidth);
+impl_from_css_prop!(StyleScrollbarColor, CssProperty::ScrollbarColor);
+impl_from_css_prop!(StyleScrollbarThumbHoverColor, CssProperty::ScrollbarThumbHoverColor);
+impl_from_css_prop!(StyleScrollbarThumbActiveColor, CssProperty::ScrollbarThumbActiveColor);
+impl_from_css_prop!(StyleWindowDragRegion, CssProperty::WindowDragRegion);
+impl_from_css_prop!(StyleCaretAnimationDuration, CssProperty::CaretAnimationDuration);
+impl_from_css_prop!(StyleScrollPadding, CssProperty::ScrollPadding);
+impl_from_css_prop!(StyleContent, CssProperty::Content);
+impl_from_css_prop!(StyleCounterReset, CssProperty::CounterReset);
+impl_from_css_prop!(StyleCounterIncrement, CssProperty::CounterIncrement);
+impl_from_css_prop!(StyleOverflowAnchor, CssProperty::OverflowAnchor);
 impl_from_css_prop!(StyleOpacity, CssProperty::Opacity);
 impl_from_css_prop!(StyleTransformVec, CssProperty::Transform);
 impl_from_css_prop!(StyleTransformOrigin, CssProperty::TransformOrigin);
 impl_from_css_prop!(StylePerspectiveOrigin, CssProperty::PerspectiveOrigin);
 impl_from_css_prop!(StyleBackfaceVisibility, CssProperty::BackfaceVisibility);
+impl_from_css_prop!(StylePerspective, CssProperty::Perspective);
+impl_from_css_prop!(StyleTransformStyle, CssProperty::TransformStyle);
 impl_from_css_prop!(StyleMixBlendMode, CssProperty::MixBlendMode);
 
 /// Multiplier for floating point accuracy. Elements such as px or %
@@ -4023,6 +4496,17 @@ impl PercentageValue {
         }
     }
 
+    /// Creates the percentage from the (fixed-point) number, without precision loss
+    #[inline]
+    pub const fn from_float_value(number: FloatValue) -> Self {
+        Self { number }
+    }
+
+    #[inline]
+    pub const fn get_float_value(&self) -> FloatValue {
+        self.number
+    }
+
     #[inline]
     pub fn get(&self) -> f32 {
         self.number.get()
@@ -5005,8 +5489,8 @@ impl Default for Shape {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[repr(C)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
 pub enum StyleCursor {
     /// `alias`
     Alias,
@@ -5068,6 +5552,18 @@ pub enum StyleCursor {
     ZoomIn,
     /// `zoom-out`
     ZoomOut,
+    /// `url(id) x y` - image registered in the `ImageCache` under the CSS ID `id`,
+    /// falls back to `default` if the platform does not support custom cursors
+    Image(StyleCursorImage),
+}
+
+/// Custom cursor image, the hotspot is in pixels from the top left of the image
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleCursorImage {
+    pub image: AzString,
+    pub hotspot_x: u32,
+    pub hotspot_y: u32,
 }
 
 impl Default for StyleCursor {
@@ -5722,6 +6218,26 @@ impl Default for StyleTextAlign {
     }
 }
 
+/// Represents a `hyphens` attribute: whether words may be hyphenated when a line
+/// is broken inside of them - default: `Manual`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleHyphens {
+    /// Words are never hyphenated, not even at soft hyphens (U+00AD)
+    None,
+    /// Words are only hyphenated at soft hyphens (U+00AD) in the text
+    Manual,
+    /// Words are hyphenated using the hyphenation dictionary for the
+    /// `lang` of the node (requires the `hyphenation` feature)
+    Auto,
+}
+
+impl Default for StyleHyphens {
+    fn default() -> Self {
+        StyleHyphens::Manual
+    }
+}
+
 /// Vertical text alignment enum (top, center, bottom) - default: `Center`
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -5820,6 +6336,39 @@ impl Default for StyleBackfaceVisibility {
     }
 }
 
+/// Represents a `perspective` attribute: distance between the z = 0 plane and
+/// the viewer, applied to the children of the node (`perspective: none` = no perspective)
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StylePerspective {
+    pub inner: PixelValue,
+}
+
+impl Default for StylePerspective {
+    fn default() -> Self {
+        Self {
+            inner: PixelValue::const_px(0),
+        }
+    }
+}
+
+impl_pixel_value!(StylePerspective);
+
+/// Represents a `transform-style` attribute: whether the children of the node
+/// are flattened into its plane or positioned in the 3D space - default: `Flat`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleTransformStyle {
+    Flat,
+    Preserve3D,
+}
+
+impl Default for StyleTransformStyle {
+    fn default() -> Self {
+        StyleTransformStyle::Flat
+    }
+}
+
 /// Represents an `opacity` attribute
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C, u8)]
@@ -5954,6 +6503,7 @@ pub type StyleLineHeightValue = CssPropertyValue<StyleLineHeight>;
 pub type StyleLetterSpacingValue = CssPropertyValue<StyleLetterSpacing>;
 pub type StyleWordSpacingValue = CssPropertyValue<StyleWordSpacing>;
 pub type StyleTabWidthValue = CssPropertyValue<StyleTabWidth>;
+pub type StyleHyphensValue = CssPropertyValue<StyleHyphens>;
 pub type StyleCursorValue = CssPropertyValue<StyleCursor>;
 pub type StyleBoxShadowValue = CssPropertyValue<StyleBoxShadow>;
 pub type StyleBorderTopColorValue = CssPropertyValue<StyleBorderTopColor>;
@@ -5973,9 +6523,22 @@ pub type StyleTransformVecValue = CssPropertyValue<StyleTransformVec>;
 pub type StyleTransformOriginValue = CssPropertyValue<StyleTransformOrigin>;
 pub type StylePerspectiveOriginValue = CssPropertyValue<StylePerspectiveOrigin>;
 pub type StyleBackfaceVisibilityValue = CssPropertyValue<StyleBackfaceVisibility>;
+pub type StylePerspectiveValue = CssPropertyValue<StylePerspective>;
+pub type StyleTransformStyleValue = CssPropertyValue<StyleTransformStyle>;
 pub type StyleMixBlendModeValue = CssPropertyValue<StyleMixBlendMode>;
 pub type StyleFilterVecValue = CssPropertyValue<StyleFilterVec>;
 pub type ScrollbarStyleValue = CssPropertyValue<ScrollbarStyle>;
+pub type StyleScrollbarWidthValue = CssPropertyValue<StyleScrollbarWidth>;
+pub type StyleScrollbarColorValue = CssPropertyValue<StyleScrollbarColor>;
+pub type StyleScrollbarThumbHoverColorValue = CssPropertyValue<StyleScrollbarThumbHoverColor>;
+pub type StyleScrollbarThumbActiveColorValue = CssPropertyValue<StyleScrollbarThumbActiveColor>;
+pub type StyleWindowDragRegionValue = CssPropertyValue<StyleWindowDragRegion>;
+pub type StyleCaretAnimationDurationValue = CssPropertyValue<StyleCaretAnimationDuration>;
+pub type StyleScrollPaddingValue = CssPropertyValue<StyleScrollPadding>;
+pub type StyleContentValue = CssPropertyValue<StyleContent>;
+pub type StyleCounterResetValue = CssPropertyValue<StyleCounterReset>;
+pub type StyleCounterIncrementValue = CssPropertyValue<StyleCounterIncrement>;
+pub type StyleOverflowAnchorValue = CssPropertyValue<StyleOverflowAnchor>;
 pub type LayoutDisplayValue = CssPropertyValue<LayoutDisplay>;
 impl_option!(
     LayoutDisplayValue,
@@ -6256,6 +6819,204 @@ pub struct ScrollbarStyle {
     pub vertical: ScrollbarInfo,
 }
 
+/// Represents a `scrollbar-width` attribute
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleScrollbarWidth {
+    /// Classic scrollbar: thumb on top of a visible track
+    Auto,
+    /// Thin overlay scrollbar: only the thumb is drawn, on top of the content
+    Thin,
+    /// No scrollbar is drawn, the content can still be scrolled
+    None,
+}
+
+impl Default for StyleScrollbarWidth {
+    fn default() -> Self {
+        StyleScrollbarWidth::Auto
+    }
+}
+
+impl StyleScrollbarWidth {
+    /// Thickness of the scrollbar in pixels, `None` if no scrollbar should be drawn
+    pub const fn get_thickness(&self) -> Option<f32> {
+        match self {
+            StyleScrollbarWidth::Auto => Some(17.0),
+            StyleScrollbarWidth::Thin => Some(8.0),
+            StyleScrollbarWidth::None => None,
+        }
+    }
+
+    /// Whether the scrollbar track is transparent (thin overlay scrollbar)
+    pub const fn is_overlay(&self) -> bool {
+        match self {
+            StyleScrollbarWidth::Thin => true,
+            _ => false,
+        }
+    }
+}
+
+/// Represents an `overflow-anchor` attribute: whether the node can be selected
+/// as the scroll anchor of its scroll container, so that the content doesn't
+/// jump when the content above the viewport changes its size
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleOverflowAnchor {
+    Auto,
+    None,
+}
+
+impl Default for StyleOverflowAnchor {
+    fn default() -> Self {
+        StyleOverflowAnchor::Auto
+    }
+}
+
+/// Represents a `scrollbar-color` attribute (`scrollbar-color: <thumb> <track>`)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleScrollbarColor {
+    pub thumb: ColorU,
+    pub track: ColorU,
+}
+
+impl Default for StyleScrollbarColor {
+    fn default() -> Self {
+        StyleScrollbarColor {
+            thumb: ColorU { r: 193, g: 193, b: 193, a: 255 },
+            track: ColorU { r: 241, g: 241, b: 241, a: 255 },
+        }
+    }
+}
+
+/// Represents a `-azul-scrollbar-thumb-hover-color` attribute
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleScrollbarThumbHoverColor {
+    pub inner: ColorU,
+}
+
+/// Represents a `-azul-scrollbar-thumb-active-color` attribute
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleScrollbarThumbActiveColor {
+    pub inner: ColorU,
+}
+
+derive_debug_zero!(StyleScrollbarThumbHoverColor);
+derive_debug_zero!(StyleScrollbarThumbActiveColor);
+
+derive_display_zero!(StyleScrollbarThumbHoverColor);
+derive_display_zero!(StyleScrollbarThumbActiveColor);
+
+/// Represents a `-azul-window-drag-region` attribute: marks a node as a title bar
+/// or resize border of a window without decorations, so that pressing the mouse
+/// on the node moves or resizes the window like the native window frame does
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleWindowDragRegion {
+    /// Regular content, mouse events are handled by the application
+    None,
+    /// Title bar: dragging the node moves the window
+    Move,
+    ResizeN,
+    ResizeNE,
+    ResizeE,
+    ResizeSE,
+    ResizeS,
+    ResizeSW,
+    ResizeW,
+    ResizeNW,
+    /// Custom maximize button of the title bar: on Windows 11 hovering it shows
+    /// the Snap Layouts flyout, clicking it is forwarded to the application
+    MaximizeButton,
+}
+
+impl Default for StyleWindowDragRegion {
+    fn default() -> Self {
+        StyleWindowDragRegion::None
+    }
+}
+
+/// Represents a `-azul-caret-animation-duration` attribute: the time in milliseconds
+/// that the text caret of an editable node stays visible (and hidden) while blinking,
+/// `0` disables the blinking
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleCaretAnimationDuration {
+    pub inner: u32,
+}
+
+impl Default for StyleCaretAnimationDuration {
+    fn default() -> Self {
+        // default caret blink time on Windows
+        Self { inner: 530 }
+    }
+}
+
+/// Represents a `scroll-padding` attribute: the part of the scroll container
+/// viewport that is obscured (e.g. by a sticky header), subtracted from the
+/// distance that PageUp / PageDown scroll the container
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleScrollPadding {
+    pub inner: PixelValue,
+}
+
+impl Default for StyleScrollPadding {
+    fn default() -> Self {
+        Self {
+            inner: PixelValue::const_px(0),
+        }
+    }
+}
+
+impl_pixel_value!(StyleScrollPadding);
+
+/// Represents a `content` attribute: the content of a `:before` / `:after`
+/// pseudo-element, the pseudo-element is only generated if the content is not `none`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum StyleContent {
+    /// `none` or `normal` - no pseudo-element is generated
+    None,
+    /// `"text"`
+    Text(AzString),
+    /// `url(id)` - image registered in the `ImageCache` under the CSS ID `id`
+    Image(AzString),
+    /// `counter(name)` - value of the innermost `name` counter in scope
+    /// (see `counter-reset` / `counter-increment`), `0` if there is none
+    Counter(AzString),
+}
+
+impl Default for StyleContent {
+    fn default() -> Self {
+        StyleContent::None
+    }
+}
+
+/// Represents a `counter-reset` attribute: `counter-reset: item 0` creates a new
+/// `item` counter, which is visible in the element, its descendants and its following
+/// siblings (only one counter per element)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleCounterReset {
+    pub counter: AzString,
+    /// Initial value of the counter, `0` if omitted
+    pub value: i32,
+}
+
+/// Represents a `counter-increment` attribute: `counter-increment: item 1` adds the
+/// value to the innermost `item` counter, creating it on the element if there is none
+/// (only one counter per element)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleCounterIncrement {
+    pub counter: AzString,
+    /// Value that is added to the counter, `1` if omitted
+    pub value: i32,
+}
+
 /// Represents a `font-size` attribute
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
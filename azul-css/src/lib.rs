@@ -571,6 +571,16 @@ macro_rules! impl_vec_clone {
                 }
             }
 
+            /// Returns whether the memory is library-allocated, i.e. whether
+            /// `into_library_owned_vec` moves the memory out instead of cloning it
+            #[inline(always)]
+            pub fn is_library_owned(&self) -> bool {
+                match self.destructor {
+                    $destructor_name::DefaultRust => true,
+                    $destructor_name::NoDestructor | $destructor_name::External(_) => false,
+                }
+            }
+
             /// NOTE: CLONES the memory if the memory is external or &'static
             /// Moves the memory out if the memory is library-allocated
             #[inline(always)]
@@ -1121,7 +1131,9 @@ impl_option!(f64, OptionF64, [Debug, Copy, Clone, PartialEq, PartialOrd]);
 mod css;
 mod css_properties;
 mod print_css;
+mod style_builder;
 
 pub use crate::css::*;
 pub use crate::css_properties::*;
 pub use crate::print_css::*;
+pub use crate::style_builder::*;
@@ -86,6 +86,16 @@ impl PrintAsCssValue for StyleTextAlign {
     }
 }
 
+impl PrintAsCssValue for StyleHyphens {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleHyphens::None => "none",
+            StyleHyphens::Manual => "manual",
+            StyleHyphens::Auto => "auto",
+        })
+    }
+}
+
 impl PrintAsCssValue for StyleLetterSpacing {
     fn print_as_css_value(&self) -> String {
         format!("{}", self.inner)
@@ -113,6 +123,9 @@ impl PrintAsCssValue for StyleTabWidth {
 impl PrintAsCssValue for StyleCursor {
     fn print_as_css_value(&self) -> String {
         String::from(match self {
+            StyleCursor::Image(i) => {
+                return format!("url(\"{}\") {} {}", i.image.as_str(), i.hotspot_x, i.hotspot_y);
+            }
             StyleCursor::Alias => "alias",
             StyleCursor::AllScroll => "all-scroll",
             StyleCursor::Cell => "cell",
@@ -549,6 +562,96 @@ impl PrintAsCssValue for ScrollbarStyle {
     }
 }
 
+impl PrintAsCssValue for StyleScrollbarWidth {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleScrollbarWidth::Auto => "auto",
+            StyleScrollbarWidth::Thin => "thin",
+            StyleScrollbarWidth::None => "none",
+        })
+    }
+}
+
+impl PrintAsCssValue for StyleOverflowAnchor {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleOverflowAnchor::Auto => "auto",
+            StyleOverflowAnchor::None => "none",
+        })
+    }
+}
+
+impl PrintAsCssValue for StyleScrollbarColor {
+    fn print_as_css_value(&self) -> String {
+        format!("{} {}", self.thumb.to_hash(), self.track.to_hash())
+    }
+}
+
+impl PrintAsCssValue for StyleScrollbarThumbHoverColor {
+    fn print_as_css_value(&self) -> String {
+        self.inner.to_hash()
+    }
+}
+
+impl PrintAsCssValue for StyleScrollbarThumbActiveColor {
+    fn print_as_css_value(&self) -> String {
+        self.inner.to_hash()
+    }
+}
+
+impl PrintAsCssValue for StyleWindowDragRegion {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleWindowDragRegion::None => "none",
+            StyleWindowDragRegion::Move => "move",
+            StyleWindowDragRegion::ResizeN => "resize-n",
+            StyleWindowDragRegion::ResizeNE => "resize-ne",
+            StyleWindowDragRegion::ResizeE => "resize-e",
+            StyleWindowDragRegion::ResizeSE => "resize-se",
+            StyleWindowDragRegion::ResizeS => "resize-s",
+            StyleWindowDragRegion::ResizeSW => "resize-sw",
+            StyleWindowDragRegion::ResizeW => "resize-w",
+            StyleWindowDragRegion::ResizeNW => "resize-nw",
+            StyleWindowDragRegion::MaximizeButton => "maximize-button",
+        })
+    }
+}
+
+impl PrintAsCssValue for StyleCaretAnimationDuration {
+    fn print_as_css_value(&self) -> String {
+        format!("{}ms", self.inner)
+    }
+}
+
+impl PrintAsCssValue for StyleScrollPadding {
+    fn print_as_css_value(&self) -> String {
+        format!("{}", self.inner)
+    }
+}
+
+impl PrintAsCssValue for StyleContent {
+    fn print_as_css_value(&self) -> String {
+        match self {
+            StyleContent::None => String::from("none"),
+            StyleContent::Text(t) => format!("\"{}\"", t.as_str()),
+            StyleContent::Image(i) => format!("url(\"{}\")", i.as_str()),
+            StyleContent::Counter(c) => format!("counter({})", c.as_str()),
+        }
+    }
+}
+
+impl PrintAsCssValue for StyleCounterReset {
+    fn print_as_css_value(&self) -> String {
+        format!("{} {}", self.counter.as_str(), self.value)
+    }
+}
+
+impl PrintAsCssValue for StyleCounterIncrement {
+    fn print_as_css_value(&self) -> String {
+        format!("{} {}", self.counter.as_str(), self.value)
+    }
+}
+
 impl PrintAsCssValue for StyleOpacity {
     fn print_as_css_value(&self) -> String {
         format!("{}", self.inner)
@@ -586,6 +689,21 @@ impl PrintAsCssValue for StyleBackfaceVisibility {
     }
 }
 
+impl PrintAsCssValue for StylePerspective {
+    fn print_as_css_value(&self) -> String {
+        format!("{}", self.inner)
+    }
+}
+
+impl PrintAsCssValue for StyleTransformStyle {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleTransformStyle::Flat => "flat",
+            StyleTransformStyle::Preserve3D => "preserve-3d",
+        })
+    }
+}
+
 // extra ---
 
 impl PrintAsCssValue for StyleTransform {
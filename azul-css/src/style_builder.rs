@@ -0,0 +1,261 @@
+//! Typed builder for CSS properties, as an alternative to parsing CSS strings
+//!
+//! ```rust
+//! # use azul_css::*;
+//! let style = Style::new()
+//!     .display_flex()
+//!     .flex_direction(LayoutFlexDirection::Column)
+//!     .padding(px(8))
+//!     .background(rgb(240, 240, 240));
+//! ```
+//!
+//! The builder produces the same `CssProperty` values as the CSS parser. For
+//! static styles, the properties can be created in a `const` context and
+//! wrapped with `Style::from_const_slice`:
+//!
+//! ```rust
+//! # use azul_css::*;
+//! static BUTTON_STYLE: &[CssProperty] = &[
+//!     CssProperty::const_display(LayoutDisplay::Flex),
+//!     CssProperty::const_padding_left(LayoutPaddingLeft::const_px(8)),
+//! ];
+//!
+//! let style = Style::from_const_slice(BUTTON_STYLE);
+//! ```
+
+use alloc::vec::Vec;
+use crate::{
+    BorderStyle, ColorU, CssDeclaration, CssPath, CssProperty, CssPropertyVec, CssRuleBlock,
+    LayoutAlignItems, LayoutBorderBottomWidth, LayoutBorderLeftWidth, LayoutBorderRightWidth,
+    LayoutBorderTopWidth, LayoutBottom, LayoutDisplay, LayoutFlexDirection, LayoutFlexGrow,
+    LayoutFlexShrink, LayoutHeight, LayoutJustifyContent, LayoutLeft, LayoutMarginBottom,
+    LayoutMarginLeft, LayoutMarginRight, LayoutMarginTop, LayoutMaxHeight, LayoutMaxWidth,
+    LayoutMinHeight, LayoutMinWidth, LayoutOverflow, LayoutPaddingBottom, LayoutPaddingLeft,
+    LayoutPaddingRight, LayoutPaddingTop, LayoutPosition, LayoutRight, LayoutTop, LayoutWidth,
+    PercentageValue, PixelValue, StyleBackgroundContent, StyleBackgroundContentVec,
+    StyleBorderBottomColor, StyleBorderBottomLeftRadius, StyleBorderBottomRightRadius,
+    StyleBorderBottomStyle, StyleBorderLeftColor, StyleBorderLeftStyle, StyleBorderRightColor,
+    StyleBorderRightStyle, StyleBorderTopColor, StyleBorderTopLeftRadius,
+    StyleBorderTopRightRadius, StyleBorderTopStyle, StyleCursor, StyleFontSize, StyleHyphens,
+    StyleOpacity, StyleTextAlign, StyleTextColor,
+};
+
+/// Creates a pixel value (`8px`)
+#[inline]
+pub const fn px(value: isize) -> PixelValue {
+    PixelValue::const_px(value)
+}
+
+/// Creates an em value (`1em`)
+#[inline]
+pub const fn em(value: isize) -> PixelValue {
+    PixelValue::const_em(value)
+}
+
+/// Creates a pt value (`12pt`)
+#[inline]
+pub const fn pt(value: isize) -> PixelValue {
+    PixelValue::const_pt(value)
+}
+
+/// Creates a percentage value (`50%`)
+#[inline]
+pub const fn percent(value: isize) -> PixelValue {
+    PixelValue::const_percent(value)
+}
+
+/// Creates an opaque color (`rgb(r, g, b)`)
+#[inline]
+pub const fn rgb(r: u8, g: u8, b: u8) -> ColorU {
+    ColorU { r, g, b, a: ColorU::ALPHA_OPAQUE }
+}
+
+/// Creates a color with transparency (`rgba(r, g, b, a)`)
+#[inline]
+pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> ColorU {
+    ColorU { r, g, b, a }
+}
+
+/// Builder for a list of CSS properties
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct Style {
+    pub properties: CssPropertyVec,
+}
+
+impl Style {
+
+    #[inline]
+    pub fn new() -> Self {
+        Self { properties: CssPropertyVec::from_vec(Vec::new()) }
+    }
+
+    /// Creates a style from a static list of properties without allocating
+    #[inline]
+    pub const fn from_const_slice(properties: &'static [CssProperty]) -> Self {
+        Self { properties: CssPropertyVec::from_const_slice(properties) }
+    }
+
+    /// Appends any CSS property, for properties that don't have a builder function
+    #[inline]
+    pub fn property(self, property: CssProperty) -> Self {
+        let mut properties = self.properties.into_library_owned_vec();
+        properties.push(property);
+        Self { properties: properties.into() }
+    }
+
+    /// Returns the list of properties
+    #[inline]
+    pub fn into_properties(self) -> CssPropertyVec {
+        self.properties
+    }
+
+    /// Converts the style into a rule block, i.e. to build a `Stylesheet` programmatically
+    pub fn into_rule_block(self, path: CssPath) -> CssRuleBlock {
+        CssRuleBlock::new(
+            path,
+            self.properties
+                .into_library_owned_vec()
+                .into_iter()
+                .map(CssDeclaration::Static)
+                .collect(),
+        )
+    }
+
+    // -- display / flex
+
+    pub fn display(self, display: LayoutDisplay) -> Self { self.property(CssProperty::display(display)) }
+    pub fn display_none(self) -> Self { self.display(LayoutDisplay::None) }
+    pub fn display_flex(self) -> Self { self.display(LayoutDisplay::Flex) }
+    pub fn display_block(self) -> Self { self.display(LayoutDisplay::Block) }
+    pub fn display_inline_block(self) -> Self { self.display(LayoutDisplay::InlineBlock) }
+    pub fn flex_direction(self, direction: LayoutFlexDirection) -> Self { self.property(CssProperty::flex_direction(direction)) }
+    pub fn flex_grow(self, grow: f32) -> Self { self.property(CssProperty::flex_grow(LayoutFlexGrow::new(grow))) }
+    pub fn flex_shrink(self, shrink: f32) -> Self { self.property(CssProperty::flex_shrink(LayoutFlexShrink::new(shrink))) }
+    pub fn justify_content(self, justify: LayoutJustifyContent) -> Self { self.property(CssProperty::justify_content(justify)) }
+    pub fn align_items(self, align: LayoutAlignItems) -> Self { self.property(CssProperty::align_items(align)) }
+
+    // -- size / position
+
+    pub fn width(self, width: PixelValue) -> Self { self.property(CssProperty::width(LayoutWidth { inner: width })) }
+    pub fn height(self, height: PixelValue) -> Self { self.property(CssProperty::height(LayoutHeight { inner: height })) }
+    pub fn min_width(self, width: PixelValue) -> Self { self.property(CssProperty::min_width(LayoutMinWidth { inner: width })) }
+    pub fn min_height(self, height: PixelValue) -> Self { self.property(CssProperty::min_height(LayoutMinHeight { inner: height })) }
+    pub fn max_width(self, width: PixelValue) -> Self { self.property(CssProperty::max_width(LayoutMaxWidth { inner: width })) }
+    pub fn max_height(self, height: PixelValue) -> Self { self.property(CssProperty::max_height(LayoutMaxHeight { inner: height })) }
+    pub fn position(self, position: LayoutPosition) -> Self { self.property(CssProperty::position(position)) }
+    pub fn top(self, top: PixelValue) -> Self { self.property(CssProperty::top(LayoutTop { inner: top })) }
+    pub fn right(self, right: PixelValue) -> Self { self.property(CssProperty::right(LayoutRight { inner: right })) }
+    pub fn bottom(self, bottom: PixelValue) -> Self { self.property(CssProperty::bottom(LayoutBottom { inner: bottom })) }
+    pub fn left(self, left: PixelValue) -> Self { self.property(CssProperty::left(LayoutLeft { inner: left })) }
+    pub fn overflow(self, overflow: LayoutOverflow) -> Self {
+        self.property(CssProperty::overflow_x(overflow))
+            .property(CssProperty::overflow_y(overflow))
+    }
+
+    // -- padding / margin
+
+    /// Sets the padding on all four sides
+    pub fn padding(self, padding: PixelValue) -> Self {
+        self.padding_top(padding)
+            .padding_right(padding)
+            .padding_bottom(padding)
+            .padding_left(padding)
+    }
+    pub fn padding_top(self, padding: PixelValue) -> Self { self.property(CssProperty::padding_top(LayoutPaddingTop { inner: padding })) }
+    pub fn padding_right(self, padding: PixelValue) -> Self { self.property(CssProperty::padding_right(LayoutPaddingRight { inner: padding })) }
+    pub fn padding_bottom(self, padding: PixelValue) -> Self { self.property(CssProperty::padding_bottom(LayoutPaddingBottom { inner: padding })) }
+    pub fn padding_left(self, padding: PixelValue) -> Self { self.property(CssProperty::padding_left(LayoutPaddingLeft { inner: padding })) }
+
+    /// Sets the margin on all four sides
+    pub fn margin(self, margin: PixelValue) -> Self {
+        self.margin_top(margin)
+            .margin_right(margin)
+            .margin_bottom(margin)
+            .margin_left(margin)
+    }
+    pub fn margin_top(self, margin: PixelValue) -> Self { self.property(CssProperty::margin_top(LayoutMarginTop { inner: margin })) }
+    pub fn margin_right(self, margin: PixelValue) -> Self { self.property(CssProperty::margin_right(LayoutMarginRight { inner: margin })) }
+    pub fn margin_bottom(self, margin: PixelValue) -> Self { self.property(CssProperty::margin_bottom(LayoutMarginBottom { inner: margin })) }
+    pub fn margin_left(self, margin: PixelValue) -> Self { self.property(CssProperty::margin_left(LayoutMarginLeft { inner: margin })) }
+
+    // -- border
+
+    /// Sets width, style and color of the border on all four sides
+    pub fn border(self, width: PixelValue, style: BorderStyle, color: ColorU) -> Self {
+        self.property(CssProperty::border_top_width(LayoutBorderTopWidth { inner: width }))
+            .property(CssProperty::border_right_width(LayoutBorderRightWidth { inner: width }))
+            .property(CssProperty::border_bottom_width(LayoutBorderBottomWidth { inner: width }))
+            .property(CssProperty::border_left_width(LayoutBorderLeftWidth { inner: width }))
+            .property(CssProperty::border_top_style(StyleBorderTopStyle { inner: style }))
+            .property(CssProperty::border_right_style(StyleBorderRightStyle { inner: style }))
+            .property(CssProperty::border_bottom_style(StyleBorderBottomStyle { inner: style }))
+            .property(CssProperty::border_left_style(StyleBorderLeftStyle { inner: style }))
+            .property(CssProperty::border_top_color(StyleBorderTopColor { inner: color }))
+            .property(CssProperty::border_right_color(StyleBorderRightColor { inner: color }))
+            .property(CssProperty::border_bottom_color(StyleBorderBottomColor { inner: color }))
+            .property(CssProperty::border_left_color(StyleBorderLeftColor { inner: color }))
+    }
+
+    /// Sets the border radius of all four corners
+    pub fn border_radius(self, radius: PixelValue) -> Self {
+        self.property(CssProperty::border_top_left_radius(StyleBorderTopLeftRadius { inner: radius }))
+            .property(CssProperty::border_top_right_radius(StyleBorderTopRightRadius { inner: radius }))
+            .property(CssProperty::border_bottom_left_radius(StyleBorderBottomLeftRadius { inner: radius }))
+            .property(CssProperty::border_bottom_right_radius(StyleBorderBottomRightRadius { inner: radius }))
+    }
+
+    // -- colors / text
+
+    /// Sets the background to a single color
+    pub fn background(self, color: ColorU) -> Self {
+        let background = StyleBackgroundContentVec::from_vec(vec![StyleBackgroundContent::Color(color)]);
+        self.property(CssProperty::background_content(background))
+    }
+    /// Sets the text color
+    pub fn color(self, color: ColorU) -> Self { self.property(CssProperty::text_color(StyleTextColor { inner: color })) }
+    pub fn font_size(self, size: PixelValue) -> Self { self.property(CssProperty::font_size(StyleFontSize { inner: size })) }
+    pub fn text_align(self, align: StyleTextAlign) -> Self { self.property(CssProperty::text_align(align)) }
+    pub fn hyphens(self, hyphens: StyleHyphens) -> Self { self.property(CssProperty::hyphens(hyphens)) }
+    pub fn cursor(self, cursor: StyleCursor) -> Self { self.property(CssProperty::cursor(cursor)) }
+    /// Sets the opacity, `0.0` = transparent, `1.0` = opaque
+    pub fn opacity(self, opacity: f32) -> Self {
+        self.property(CssProperty::opacity(StyleOpacity { inner: PercentageValue::new(opacity * 100.0) }))
+    }
+}
+
+impl From<Style> for CssPropertyVec {
+    fn from(style: Style) -> CssPropertyVec {
+        style.properties
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_builder_padding() {
+        let style = Style::new().display_flex().padding(px(8));
+        let properties = style.into_properties();
+        assert_eq!(properties.len(), 5);
+        assert_eq!(properties.as_ref()[0], CssProperty::display(LayoutDisplay::Flex));
+        assert_eq!(
+            properties.as_ref()[4],
+            CssProperty::padding_left(LayoutPaddingLeft { inner: PixelValue::px(8.0) })
+        );
+    }
+
+    #[test]
+    fn test_style_builder_const_slice() {
+        static STYLE: &[CssProperty] = &[
+            CssProperty::const_display(LayoutDisplay::Block),
+        ];
+        let style = Style::from_const_slice(STYLE).color(rgb(255, 0, 0));
+        assert_eq!(style.properties.len(), 2);
+        assert_eq!(
+            style.properties.as_ref()[1],
+            CssProperty::text_color(StyleTextColor { inner: rgb(255, 0, 0) })
+        );
+    }
+}
@@ -1,12 +1,13 @@
 use alloc::sync::Arc;
 use azul_core::{
-    app_resources::{AppConfig, ImageCache, ImageRef},
+    app_resources::{AppConfig, ImageCache, ImageRef, LoadedFontSource, SplashOptions},
     callbacks::{RefAny, Update},
     display_list::RenderCallbacks,
-    task::{Timer, TimerId},
+    gl::GlQuirkRule,
+    task::{EventLoopProxy, Timer, TimerId},
     window::{MonitorVec, WindowCreateOptions},
 };
-use azul_css::AzString;
+use azul_css::{AzString, StyleFontFamily};
 use clipboard2::{Clipboard as _, ClipboardError, SystemClipboard};
 use rust_fontconfig::FcFontCache;
 use std::fmt;
@@ -16,7 +17,7 @@ use std::thread::JoinHandle;
 pub(crate) const CALLBACKS: RenderCallbacks = RenderCallbacks {
     insert_into_active_gl_textures_fn: azul_core::gl::insert_into_active_gl_textures,
     layout_fn: azul_layout::do_the_layout,
-    load_font_fn: azulc_lib::font_loading::font_source_get_bytes,
+    load_font_fn: load_font,
     parse_font_fn: azul_text_layout::parse_font_fn,
 };
 
@@ -53,6 +54,19 @@ impl AzAppPtr {
         }
     }
 
+    pub fn with_splash(self, splash: SplashOptions) -> Self {
+        if let Ok(mut l) = (&*self.ptr).try_lock() {
+            l.set_splash(splash);
+        }
+        self
+    }
+
+    pub fn add_gl_quirk_rule(&mut self, rule: GlQuirkRule) {
+        if let Ok(mut l) = (&*self.ptr).try_lock() {
+            l.add_gl_quirk_rule(rule);
+        }
+    }
+
     pub fn get_monitors(&self) -> MonitorVec {
         self.ptr
             .lock()
@@ -60,6 +74,13 @@ impl AzAppPtr {
             .unwrap_or(MonitorVec::from_const_slice(&[]))
     }
 
+    pub fn create_event_loop_proxy(&self) -> EventLoopProxy {
+        self.ptr
+            .lock()
+            .map(|m| m.create_event_loop_proxy())
+            .unwrap_or_else(|_| EventLoopProxy::new())
+    }
+
     pub fn run(&self, root_window: WindowCreateOptions) {
         if let Ok(mut l) = self.ptr.try_lock() {
             let mut app = App::new(RefAny::new(Dummy { _dummy: 0 }), l.config.clone());
@@ -90,6 +111,13 @@ pub struct App {
     /// Font configuration cache - already start building the font cache
     /// while the app is starting
     pub fc_cache: LazyFcCache,
+    /// Shared with all proxies created by `create_event_loop_proxy()`,
+    /// handed to the event loop in `.run()`
+    pub event_loop_proxy: EventLoopProxy,
+    /// Splash screen shown while the app is starting, see `App::with_splash()`
+    pub splash: Option<SplashOptions>,
+    /// Driver workarounds added by the app, applied after the built-in rules
+    pub gl_quirk_rules: Vec<GlQuirkRule>,
 }
 
 impl App {
@@ -99,7 +127,7 @@ impl App {
     ///
     /// This does not open any windows, but it starts the event loop
     /// to the display server
-    pub fn new(initial_data: RefAny, app_config: AppConfig) -> Self {
+    pub fn new(initial_data: RefAny, mut app_config: AppConfig) -> Self {
         use std::thread;
 
         #[cfg(not(miri))]
@@ -107,6 +135,10 @@ impl App {
         #[cfg(miri)]
         let fc_cache = LazyFcCache::Resolved(FcFontCache::default());
 
+        // azul.toml overrides the log level of the app
+        let config_file = crate::config_file::get();
+        config_file.apply_to_app_config(&mut app_config);
+
         #[cfg(all(
             feature = "logging",
             feature = "use_fern_logger",
@@ -116,7 +148,39 @@ impl App {
         }
 
         #[cfg(feature = "logging")] {
-            if app_config.enable_logging_on_panic {
+            for warning in config_file.warnings.iter() {
+                warn!("{}: {}", crate::config_file::CONFIG_FILE_NAME, warning);
+            }
+        }
+
+        #[cfg(feature = "logging")] {
+            app_config.system_callbacks.get_log_lines_fn.cb = crate::logging::get_log_lines;
+            app_config.system_callbacks.set_log_level_fn.cb = crate::logging::set_log_level;
+        }
+
+        // azul-core can't query the idle time or the keyboard layout by itself,
+        // inject the OS-specific functions
+        #[cfg(target_os = "windows")] {
+            app_config.system_callbacks.get_system_idle_time_fn.cb = crate::shell::win32::get_system_idle_time;
+            app_config.system_callbacks.keycode_to_char_fn.cb = crate::shell::win32::keycode_to_char;
+        }
+
+        #[cfg(target_os = "linux")] {
+            app_config.system_callbacks.get_system_idle_time_fn.cb = crate::shell::x11::get_system_idle_time;
+            app_config.system_callbacks.keycode_to_char_fn.cb = crate::shell::x11::keycode_to_char;
+        }
+
+        #[cfg(target_os = "macos")] {
+            app_config.system_callbacks.get_system_idle_time_fn.cb = crate::shell::appkit::get_system_idle_time;
+        }
+
+        #[cfg(feature = "logging")] {
+            if let Some(crash_handler) = app_config.crash_handler.into_option() {
+                crate::logging::set_up_crash_handler(crash_handler);
+            }
+
+            // the crash handler is invoked from the panic hook
+            if app_config.enable_logging_on_panic || app_config.crash_handler.is_some() {
                 crate::logging::set_up_panic_hooks();
             }
 
@@ -132,6 +196,9 @@ impl App {
             config: app_config,
             image_cache: ImageCache::new(),
             fc_cache,
+            event_loop_proxy: EventLoopProxy::new(),
+            splash: None,
+            gl_quirk_rules: Vec::new(),
         }
     }
 
@@ -140,6 +207,30 @@ impl App {
         self.image_cache.add_css_image_id(css_id, image);
     }
 
+    /// Shows a splash screen (logo + progress bar) while the font cache is built
+    /// and the first layout of the windows runs. The progress bar advances as the
+    /// startup stages complete and the splash cross-fades into the root window
+    /// once the root window has presented its first frame.
+    ///
+    /// Currently only implemented on Windows, ignored on other platforms.
+    pub fn with_splash(mut self, splash: SplashOptions) -> Self {
+        self.set_splash(splash);
+        self
+    }
+
+    /// Same as `with_splash()`, but takes `&mut self`
+    pub fn set_splash(&mut self, splash: SplashOptions) {
+        self.splash = Some(splash);
+    }
+
+    /// Adds a rule that enables or disables driver workarounds (i.e. forcing
+    /// software rendering on a specific GPU), see `GlQuirkRule`. The rules are
+    /// evaluated in order after the built-in rules when a window is created,
+    /// the applied workarounds can be queried with `GlContextPtr::get_quirks()`
+    pub fn add_gl_quirk_rule(&mut self, rule: GlQuirkRule) {
+        self.gl_quirk_rules.push(rule);
+    }
+
     /// Spawn a new window on the screen. Note that this should only be used to
     /// create extra windows, the default window will be the window submitted to
     /// the `.run` method.
@@ -147,6 +238,12 @@ impl App {
         self.windows.push(create_options);
     }
 
+    /// Returns a handle that other threads can use to wake up the event loop
+    /// or to send data to the windows (`On::UserEvent`)
+    pub fn create_event_loop_proxy(&self) -> EventLoopProxy {
+        self.event_loop_proxy.clone()
+    }
+
     /// Returns a list of monitors available on the system
     pub fn get_monitors(&self) -> MonitorVec {
         #[cfg(target_os = "windows")] {
@@ -182,10 +279,24 @@ impl App {
             println!("{:?}", e);
         }
     }
+
+    /// Renders the first frame of a window into an image (RGBA8, premultiplied alpha)
+    /// without showing the window or connecting to a display server, i.e. to compare
+    /// the styled DOM against reference images in CI. The window is rendered at
+    /// `WindowState::size` with 96 DPI (or the DPI of the `azul.toml`).
+    ///
+    /// Currently only implemented on Linux (EGL, works with the Mesa software
+    /// rasterizer), returns `HeadlessError::Unsupported` on Windows and macOS.
+    /// There is no CPU fallback, see the `headless` module.
+    #[cfg(all(not(test), feature = "headless"))]
+    pub fn render_to_image(&mut self, window: WindowCreateOptions)
+    -> Result<azul_core::app_resources::RawImage, crate::headless::HeadlessError> {
+        crate::headless::render_to_image(self, window)
+    }
 }
 
-#[cfg(all(feature = "use_fern_logger", not(feature = "use_pyo3_logger")))]
-const fn translate_log_level(log_level: azul_core::app_resources::AppLogLevel)
+#[cfg(feature = "logging")]
+pub(crate) const fn translate_log_level(log_level: azul_core::app_resources::AppLogLevel)
 -> log::LevelFilter {
     match log_level {
         azul_core::app_resources::AppLogLevel::Off => log::LevelFilter::Off,
@@ -197,6 +308,49 @@ const fn translate_log_level(log_level: azul_core::app_resources::AppLogLevel)
     }
 }
 
+/// Font cache that is still being built on a background thread, see `LazyFcCache`
+enum PendingFcCache {
+    None,
+    Building(JoinHandle<FcFontCache>),
+    Built(FcFontCache),
+}
+
+/// Shared between `LazyFcCache::apply_closure` and `load_font`: the layout callbacks
+/// only get a `&FcFontCache`, so the font loader can't reach the `LazyFcCache`
+static PENDING_FC_CACHE: Mutex<PendingFcCache> = Mutex::new(PendingFcCache::None);
+
+/// `RenderCallbacks::load_font_fn` of the desktop: system fonts that are not in the
+/// on-disk font cache are the only fonts that need the `FcFontCache`, so only the
+/// first layout that loads such a font blocks until the font cache has been built
+fn load_font(font_family: &StyleFontFamily, fc_cache: &FcFontCache) -> Option<LoadedFontSource> {
+
+    use azulc_lib::font_loading::font_source_get_bytes;
+
+    if let Some(s) = font_source_get_bytes(font_family, fc_cache) {
+        return Some(s);
+    }
+
+    if let StyleFontFamily::System(_) = font_family {
+        let mut pending = PENDING_FC_CACHE.lock().ok()?;
+        if let PendingFcCache::Building(_) = &*pending {
+            if let PendingFcCache::Building(j) = core::mem::replace(&mut *pending, PendingFcCache::None) {
+                *pending = PendingFcCache::Built(j.join().unwrap_or_default());
+            }
+        }
+        if let PendingFcCache::Built(c) = &*pending {
+            return font_source_get_bytes(font_family, c);
+        }
+    }
+
+    None
+}
+
+/// Font cache that is built on a background thread when the app starts.
+///
+/// Layouts don't wait for the font cache: while it is still being built, they
+/// run against an empty cache (system fonts resolved in previous runs are loaded
+/// from the on-disk cache, see `azulc_lib::font_cache`) and only the first
+/// layout that needs an unresolved system font blocks on the background thread.
 #[derive(Debug)]
 pub enum LazyFcCache {
     Resolved(FcFontCache),
@@ -204,28 +358,51 @@ pub enum LazyFcCache {
 }
 
 impl LazyFcCache {
+    /// Returns whether the font cache has been built, i.e. whether
+    /// system fonts can be loaded without blocking on the background thread
+    pub fn is_ready(&self) -> bool {
+        match self {
+            LazyFcCache::Resolved(_) => true,
+            LazyFcCache::InProgress(Some(j)) => j.is_finished(),
+            LazyFcCache::InProgress(None) => match PENDING_FC_CACHE.lock().as_deref() {
+                Ok(PendingFcCache::Building(j)) => j.is_finished(),
+                _ => true,
+            },
+        }
+    }
+
     pub fn apply_closure<T, F: FnOnce(&mut FcFontCache) -> T>(&mut self, closure: F) -> T {
-        let mut replace = None;
 
-        let result = match self {
-            LazyFcCache::Resolved(c) => closure(c),
-            LazyFcCache::InProgress(j) => {
-                let mut font_cache = j
-                    .take()
-                    .and_then(|j| Some(j.join().ok()))
-                    .unwrap_or_default()
-                    .unwrap_or_default();
-                let r = closure(&mut font_cache);
-                replace = Some(font_cache);
-                r
+        if let LazyFcCache::InProgress(j) = self {
+            let resolved = match PENDING_FC_CACHE.lock() {
+                Ok(mut pending) => {
+                    // hand the background thread over to load_font()
+                    if let Some(j) = j.take() {
+                        *pending = PendingFcCache::Building(j);
+                    }
+                    match core::mem::replace(&mut *pending, PendingFcCache::None) {
+                        PendingFcCache::Building(j) if j.is_finished() => Some(j.join().unwrap_or_default()),
+                        PendingFcCache::Building(j) => {
+                            *pending = PendingFcCache::Building(j);
+                            None
+                        },
+                        PendingFcCache::Built(c) => Some(c),
+                        PendingFcCache::None => Some(FcFontCache::default()),
+                    }
+                },
+                Err(_) => Some(FcFontCache::default()),
+            };
+
+            if let Some(resolved) = resolved {
+                *self = LazyFcCache::Resolved(resolved);
             }
-        };
-
-        if let Some(replace) = replace {
-            *self = LazyFcCache::Resolved(replace);
         }
 
-        result
+        match self {
+            LazyFcCache::Resolved(c) => closure(c),
+            // still building: system fonts are resolved by load_font()
+            LazyFcCache::InProgress(_) => closure(&mut FcFontCache::default()),
+        }
     }
 }
 
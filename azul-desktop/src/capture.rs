@@ -0,0 +1,157 @@
+//! Screen capture of monitors, screen regions and windows into an `ImageRef`
+//! (requires the `capture` feature), i.e. for screenshot / annotation tools
+//!
+//! Backends:
+//!
+//! - Windows: DXGI desktop duplication (Windows 8 or later) for screen regions,
+//!   `PrintWindow` for windows (also captures windows that are covered by other windows)
+//! - macOS: CoreGraphics (`CGWindowListCreateImage`), requires the screen recording
+//!   permission (macOS 10.15 or later)
+//! - Linux: X11 (`XGetImage`), windows are captured via XComposite (if libXcomposite
+//!   is installed, otherwise only the visible parts of the window are captured).
+//!   Capturing on Wayland (wlr-screencopy / xdg-desktop-portal) is not supported yet.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use azul_core::app_resources::{ImageRef, RawImage, RawImageData, RawImageFormat};
+use azul_core::window::{Monitor, PhysicalPositionI32, PhysicalSizeU32, RawWindowHandle};
+
+/// What to capture
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CaptureSource {
+    /// Region of the virtual desktop, in the coordinate space of `Monitor::position` and
+    /// `Monitor::size` (physical pixels on Windows and X11, points on macOS).
+    /// Can span multiple monitors, areas that are not covered by a monitor are transparent.
+    Region { origin: PhysicalPositionI32, size: PhysicalSizeU32 },
+    /// Contents of a window (without the window frame)
+    Window(RawWindowHandle),
+}
+
+impl CaptureSource {
+    /// Captures the entire monitor
+    pub fn monitor(monitor: &Monitor) -> Self {
+        CaptureSource::Region {
+            origin: PhysicalPositionI32::new(monitor.position.x as i32, monitor.position.y as i32),
+            size: PhysicalSizeU32::new(monitor.size.width.max(0) as u32, monitor.size.height.max(0) as u32),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CaptureError {
+    /// Screen capture is not supported on this platform / display server
+    Unsupported,
+    /// The user did not allow the application to record the screen
+    PermissionDenied,
+    /// The region has a width or height of zero, or the window handle is invalid
+    InvalidSource,
+    /// Error of the platform API
+    Platform(String),
+}
+
+impl fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::CaptureError::*;
+        match self {
+            Unsupported => write!(f, "screen capture is not supported on this platform"),
+            PermissionDenied => write!(f, "permission to record the screen was denied"),
+            InvalidSource => write!(f, "invalid capture region or window"),
+            Platform(e) => write!(f, "screen capture failed: {}", e),
+        }
+    }
+}
+
+/// Captures the source into an image (BGRA8 pixels)
+pub fn capture(source: &CaptureSource) -> Result<ImageRef, CaptureError> {
+
+    if let CaptureSource::Region { size, .. } = source {
+        if size.width == 0 || size.height == 0 {
+            return Err(CaptureError::InvalidSource);
+        }
+    }
+
+    let captured = capture_platform(source)?;
+
+    ImageRef::new_rawimage(captured.into_raw_image())
+    .ok_or(CaptureError::Platform(String::from("invalid image size")))
+}
+
+/// Captured pixels, rows are tightly packed (no padding between rows)
+pub(crate) struct CapturedImage {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    /// BGRA8 pixels, alpha is not premultiplied
+    pub(crate) pixels: Vec<u8>,
+}
+
+impl CapturedImage {
+
+    /// Creates a transparent image
+    pub(crate) fn new(width: usize, height: usize) -> Self {
+        Self { width, height, pixels: vec![0; width * height * 4] }
+    }
+
+    /// Copies the BGRA8 pixels of a captured surface (with `row_pitch` bytes per row) that is
+    /// positioned at (`x`, `y`) relative to this image. Parts outside of this image are clipped.
+    pub(crate) fn blit_bgra(&mut self, src: &[u8], src_width: usize, src_height: usize, row_pitch: usize, x: isize, y: isize) {
+
+        let dst_x_start = x.max(0) as usize;
+        let dst_y_start = y.max(0) as usize;
+        let dst_x_end = (x + src_width as isize).min(self.width as isize);
+        let dst_y_end = (y + src_height as isize).min(self.height as isize);
+
+        if dst_x_end <= dst_x_start as isize || dst_y_end <= dst_y_start as isize {
+            return;
+        }
+
+        let row_len = (dst_x_end as usize - dst_x_start) * 4;
+        let src_x = (dst_x_start as isize - x) as usize;
+
+        for dst_y in dst_y_start..(dst_y_end as usize) {
+            let src_y = (dst_y as isize - y) as usize;
+            let src_start = src_y * row_pitch + src_x * 4;
+            let dst_start = (dst_y * self.width + dst_x_start) * 4;
+            if let Some(src_row) = src.get(src_start..(src_start + row_len)) {
+                self.pixels[dst_start..(dst_start + row_len)].copy_from_slice(src_row);
+            }
+        }
+    }
+
+    /// Sets the alpha channel to opaque (for surfaces that have no alpha channel)
+    pub(crate) fn set_opaque(&mut self) {
+        for pixel in self.pixels.chunks_exact_mut(4) {
+            pixel[3] = 0xFF;
+        }
+    }
+
+    fn into_raw_image(self) -> RawImage {
+        RawImage {
+            pixels: RawImageData::U8(self.pixels.into()),
+            width: self.width,
+            height: self.height,
+            premultiplied_alpha: false,
+            data_format: RawImageFormat::BGRA8,
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn capture_platform(source: &CaptureSource) -> Result<CapturedImage, CaptureError> {
+    crate::shell::win32::capture::capture(source)
+}
+
+#[cfg(target_os = "macos")]
+fn capture_platform(source: &CaptureSource) -> Result<CapturedImage, CaptureError> {
+    crate::shell::appkit::capture::capture(source)
+}
+
+#[cfg(target_os = "linux")]
+fn capture_platform(source: &CaptureSource) -> Result<CapturedImage, CaptureError> {
+    crate::shell::x11::capture::capture(source)
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+fn capture_platform(_source: &CaptureSource) -> Result<CapturedImage, CaptureError> {
+    Err(CaptureError::Unsupported)
+}
@@ -0,0 +1,283 @@
+//! Optional `azul.toml` configuration file, so that end users and support
+//! teams can change the rendering behavior of an app without recompiling it.
+//!
+//! The file is searched next to the executable first, then in the user
+//! configuration directory of the application, named after the executable
+//! (`$XDG_CONFIG_HOME/azul/<app>/azul.toml`, `%APPDATA%\azul\<app>\azul.toml` or
+//! `~/Library/Application Support/azul/<app>/azul.toml`), so that the settings of one
+//! application don't affect the other Azul applications of the user.
+//!
+//! ```toml
+//! [renderer]
+//! backend = "software"   # "hardware", "software" or "auto"
+//! vsync = false          # true, false or "auto"
+//!
+//! [display]
+//! dpi = 144              # overrides the DPI of all windows (96 = 100%)
+//!
+//! [logging]
+//! level = "debug"        # "off", "error", "warn", "info", "debug" or "trace"
+//!
+//! [debug]
+//! profiler_dbg = true    # any field of `DebugState`
+//! ```
+//!
+//! The file is parsed with the TOML subset parser of `azulc_lib::toml`.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use azulc_lib::toml::{parse_lines, TomlLine, TomlValue};
+use azul_core::{
+    app_resources::{AppConfig, AppLogLevel},
+    window::{DebugState, HwAcceleration, OptionRendererOptions, Vsync, WindowCreateOptions},
+};
+use once_cell::sync::OnceCell;
+use std::path::PathBuf;
+
+pub const CONFIG_FILE_NAME: &str = "azul.toml";
+
+static CONFIG_FILE: OnceCell<ConfigFile> = OnceCell::new();
+
+/// Settings of the `azul.toml` file, `None` if the setting is not present in the file
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ConfigFile {
+    /// Path of the loaded configuration file, `None` if no file was found
+    pub path: Option<PathBuf>,
+    /// `[renderer] backend`: hardware / software rendering
+    pub backend: Option<HwAcceleration>,
+    /// `[renderer] vsync`
+    pub vsync: Option<Vsync>,
+    /// `[display] dpi`: DPI of all windows, ignores the DPI of the monitor
+    pub dpi: Option<u32>,
+    /// `[logging] level`
+    pub log_level: Option<AppLogLevel>,
+    /// `[debug]`: WebRender debug flags that are enabled / disabled in all windows
+    pub debug_flags: Vec<(String, bool)>,
+    /// Invalid lines of the file (logged once logging has been set up)
+    pub warnings: Vec<String>,
+}
+
+/// Returns the configuration file, loads it on the first call
+pub fn get() -> &'static ConfigFile {
+    CONFIG_FILE.get_or_init(ConfigFile::load)
+}
+
+impl ConfigFile {
+
+    /// Searches and parses the configuration file, returns an empty
+    /// configuration if there is no file (or it can't be read)
+    pub fn load() -> Self {
+        for path in get_search_paths() {
+            if let Ok(contents) = std::fs::read_to_string(&path) {
+                let mut config = Self::parse(&contents);
+                config.path = Some(path);
+                return config;
+            }
+        }
+        Self::default()
+    }
+
+    /// Parses the contents of an `azul.toml` file, invalid lines are
+    /// skipped and recorded in `ConfigFile::warnings`
+    pub fn parse(contents: &str) -> Self {
+
+        let mut config = Self::default();
+        let mut section = String::new();
+
+        for (line_number, line, parsed) in parse_lines(contents) {
+            let result = match parsed {
+                Ok(TomlLine::Section(name)) => { section = name; Ok(()) },
+                Ok(TomlLine::KeyValue(key, value)) => config.set(&section, &key, &value),
+                Err(e) => Err(e.to_string()),
+            };
+            if let Err(e) = result {
+                config.warnings.push(format!("line {}: {}: \"{}\"", line_number, e, line));
+            }
+        }
+
+        config
+    }
+
+    fn set(&mut self, section: &str, key: &str, value: &TomlValue) -> Result<(), String> {
+        match (section, key, value, value.as_str()) {
+            ("renderer", "backend", _, Some("hardware")) => self.backend = Some(HwAcceleration::Enabled),
+            ("renderer", "backend", _, Some("software")) => self.backend = Some(HwAcceleration::Disabled),
+            ("renderer", "backend", _, Some("auto")) => self.backend = Some(HwAcceleration::DontCare),
+            ("renderer", "vsync", TomlValue::Bool(true), _) => self.vsync = Some(Vsync::Enabled),
+            ("renderer", "vsync", TomlValue::Bool(false), _) => self.vsync = Some(Vsync::Disabled),
+            ("renderer", "vsync", _, Some("auto")) => self.vsync = Some(Vsync::DontCare),
+            ("display", "dpi", TomlValue::Number(n), _) if *n > 0.0 => self.dpi = Some(libm::round(*n) as u32),
+            ("logging", "level", _, Some("off")) => self.log_level = Some(AppLogLevel::Off),
+            ("logging", "level", _, Some("error")) => self.log_level = Some(AppLogLevel::Error),
+            ("logging", "level", _, Some("warn")) => self.log_level = Some(AppLogLevel::Warn),
+            ("logging", "level", _, Some("info")) => self.log_level = Some(AppLogLevel::Info),
+            ("logging", "level", _, Some("debug")) => self.log_level = Some(AppLogLevel::Debug),
+            ("logging", "level", _, Some("trace")) => self.log_level = Some(AppLogLevel::Trace),
+            ("debug", flag, TomlValue::Bool(b), _) if set_debug_flag(&mut DebugState::default(), flag, *b) => {
+                self.debug_flags.push((flag.to_string(), *b));
+            },
+            _ => return Err("unknown setting".to_string()),
+        }
+        Ok(())
+    }
+
+    /// Overrides the log level of the app
+    pub fn apply_to_app_config(&self, app_config: &mut AppConfig) {
+        if let Some(log_level) = self.log_level {
+            app_config.log_level = log_level;
+        }
+    }
+
+    /// Overrides the renderer options and debug flags of a window before it is created
+    pub fn apply_to_window(&self, options: &mut WindowCreateOptions) {
+
+        if self.backend.is_some() || self.vsync.is_some() {
+            let mut renderer = options.renderer.into_option().unwrap_or_default();
+            if let Some(backend) = self.backend {
+                renderer.hw_accel = backend;
+            }
+            if let Some(vsync) = self.vsync {
+                renderer.vsync = vsync;
+            }
+            options.renderer = OptionRendererOptions::Some(renderer);
+        }
+
+        for (flag, value) in self.debug_flags.iter() {
+            set_debug_flag(&mut options.state.debug_state, flag, *value);
+        }
+    }
+}
+
+/// Sets the `DebugState` field with the given name, returns false if there is no such field
+fn set_debug_flag(debug_state: &mut DebugState, flag: &str, value: bool) -> bool {
+    let field = match flag {
+        "profiler_dbg" => &mut debug_state.profiler_dbg,
+        "render_target_dbg" => &mut debug_state.render_target_dbg,
+        "texture_cache_dbg" => &mut debug_state.texture_cache_dbg,
+        "gpu_time_queries" => &mut debug_state.gpu_time_queries,
+        "gpu_sample_queries" => &mut debug_state.gpu_sample_queries,
+        "disable_batching" => &mut debug_state.disable_batching,
+        "epochs" => &mut debug_state.epochs,
+        "echo_driver_messages" => &mut debug_state.echo_driver_messages,
+        "show_overdraw" => &mut debug_state.show_overdraw,
+        "gpu_cache_dbg" => &mut debug_state.gpu_cache_dbg,
+        "texture_cache_dbg_clear_evicted" => &mut debug_state.texture_cache_dbg_clear_evicted,
+        "picture_caching_dbg" => &mut debug_state.picture_caching_dbg,
+        "primitive_dbg" => &mut debug_state.primitive_dbg,
+        "zoom_dbg" => &mut debug_state.zoom_dbg,
+        "small_screen" => &mut debug_state.small_screen,
+        "disable_opaque_pass" => &mut debug_state.disable_opaque_pass,
+        "disable_alpha_pass" => &mut debug_state.disable_alpha_pass,
+        "disable_clip_masks" => &mut debug_state.disable_clip_masks,
+        "disable_text_prims" => &mut debug_state.disable_text_prims,
+        "disable_gradient_prims" => &mut debug_state.disable_gradient_prims,
+        "obscure_images" => &mut debug_state.obscure_images,
+        "glyph_flashing" => &mut debug_state.glyph_flashing,
+        "smart_profiler" => &mut debug_state.smart_profiler,
+        "invalidation_dbg" => &mut debug_state.invalidation_dbg,
+        "tile_cache_logging_dbg" => &mut debug_state.tile_cache_logging_dbg,
+        "profiler_capture" => &mut debug_state.profiler_capture,
+        "force_picture_invalidation" => &mut debug_state.force_picture_invalidation,
+        _ => return false,
+    };
+    *field = value;
+    true
+}
+
+/// Next to the executable first, then in the user configuration directory of the app
+fn get_search_paths() -> Vec<PathBuf> {
+
+    let mut paths = Vec::new();
+
+    let exe_path = match std::env::current_exe() {
+        Ok(o) => o,
+        Err(_) => return paths,
+    };
+
+    if let Some(exe_dir) = exe_path.parent() {
+        paths.push(exe_dir.join(CONFIG_FILE_NAME));
+    }
+
+    let app_name = match exe_path.file_stem() {
+        Some(s) => s.to_os_string(),
+        None => return paths,
+    };
+
+    #[cfg(target_os = "windows")]
+    let config_dir = std::env::var_os("APPDATA").map(PathBuf::from);
+
+    #[cfg(target_os = "macos")]
+    let config_dir = std::env::var_os("HOME")
+        .map(|h| PathBuf::from(h).join("Library").join("Application Support"));
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")));
+
+    if let Some(config_dir) = config_dir {
+        paths.push(config_dir.join("azul").join(app_name).join(CONFIG_FILE_NAME));
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_sections() {
+        let config = ConfigFile::parse("
+            [renderer]
+            backend = \"software\"
+            vsync = false
+
+            [display]
+            dpi = 144
+
+            [logging]
+            level = \"debug\"
+        ");
+        assert_eq!(config.backend, Some(HwAcceleration::Disabled));
+        assert_eq!(config.vsync, Some(Vsync::Disabled));
+        assert_eq!(config.dpi, Some(144));
+        assert_eq!(config.log_level, Some(AppLogLevel::Debug));
+        assert!(config.warnings.is_empty());
+    }
+
+    #[test]
+    fn parse_comments() {
+        let config = ConfigFile::parse("
+            # comment
+            [renderer] # comment
+            backend = \"hardware\" # \"software\"
+        ");
+        assert_eq!(config.backend, Some(HwAcceleration::Enabled));
+        assert!(config.warnings.is_empty());
+    }
+
+    #[test]
+    fn parse_invalid_lines() {
+        let config = ConfigFile::parse("[renderer\nbackend\ndpi = 144\n[display]\ndpi = abc\ndpi = -1");
+        assert_eq!(config.dpi, None);
+        assert_eq!(config.warnings.len(), 5);
+        assert!(config.warnings[0].starts_with("line 1: invalid section header"));
+        assert!(config.warnings[1].starts_with("line 2: expected key = value"));
+        // dpi outside of the [display] section
+        assert!(config.warnings[2].starts_with("line 3: unknown setting"));
+        assert!(config.warnings[3].starts_with("line 5: invalid value"));
+        assert!(config.warnings[4].starts_with("line 6: unknown setting"));
+    }
+
+    #[test]
+    fn parse_debug_flags() {
+        let config = ConfigFile::parse("[debug]\nprofiler_dbg = true\nno_such_flag = true\nshow_overdraw = false");
+        assert_eq!(
+            config.debug_flags,
+            vec![("profiler_dbg".to_string(), true), ("show_overdraw".to_string(), false)]
+        );
+        assert_eq!(config.warnings.len(), 1);
+        assert!(config.warnings[0].starts_with("line 3: unknown setting"));
+    }
+}
@@ -0,0 +1,165 @@
+//! Recording and replaying of input events, see `AppConfig::event_recording`
+//!
+//! The events are written to a text file, one event per line:
+//!
+//! ```text
+//! # azul event recording
+//! 0 0 resize 800 600
+//! 1520 0 mouse-move 120.5 48
+//! 1684 0 mouse-down left
+//! 1702 0 key-down 30 A
+//! ```
+//!
+//! The first column is the time in milliseconds since the application started,
+//! the second column is the index of the window (in the order in which the windows
+//! were created) and the rest of the line is the `SyntheticEvent`. Since the format
+//! is platform-independent, a recording can be edited by hand or replayed on a
+//! different machine.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::Write;
+use std::time::{Duration, Instant};
+use azul_core::app_resources::EventRecording;
+use azul_core::window::SyntheticEvent;
+
+/// First line of a recording
+const FILE_HEADER: &str = "# azul event recording";
+
+#[derive(Debug, Clone, PartialEq)]
+struct RecordedEvent {
+    /// Time since the recording started
+    time: Duration,
+    /// Index of the window in the order in which the windows were created
+    window: usize,
+    event: SyntheticEvent,
+}
+
+impl RecordedEvent {
+    fn parse(line: &str) -> Option<Self> {
+        let mut split = line.trim().splitn(3, char::is_whitespace);
+        let time = split.next()?.parse::<u64>().ok()?;
+        let window = split.next()?.parse::<usize>().ok()?;
+        let event = SyntheticEvent::parse(split.next()?)?;
+        Some(Self { time: Duration::from_millis(time), window, event })
+    }
+}
+
+#[derive(Debug)]
+enum Mode {
+    Record(File),
+    /// Remaining events, sorted by time
+    Replay(VecDeque<RecordedEvent>),
+    /// Writing the recording failed
+    Stopped,
+}
+
+/// Records or replays the input events of all windows, windows are
+/// identified by their platform-specific handle (i.e. the `HWND`)
+#[derive(Debug)]
+pub(crate) struct EventRecordingState {
+    /// Time when the recording / replay started
+    start: Instant,
+    /// Handles of all windows, in the order in which they were created
+    windows: Vec<usize>,
+    mode: Mode,
+}
+
+impl EventRecordingState {
+
+    /// Creates the recording file or loads the events to replay
+    pub(crate) fn new(config: &EventRecording) -> Result<Self, String> {
+
+        let mode = match config {
+            EventRecording::Record(path) => {
+                let mut file = File::create(path.as_str())
+                    .map_err(|e| format!("could not create {}: {}", path.as_str(), e))?;
+                writeln!(file, "{}", FILE_HEADER)
+                    .map_err(|e| format!("could not write to {}: {}", path.as_str(), e))?;
+                Mode::Record(file)
+            },
+            EventRecording::Replay(path) => {
+                let source = std::fs::read_to_string(path.as_str())
+                    .map_err(|e| format!("could not read {}: {}", path.as_str(), e))?;
+                let mut events = Vec::new();
+                for (line_number, line) in source.lines().enumerate() {
+                    let line = line.trim();
+                    if line.is_empty() || line.starts_with('#') {
+                        continue;
+                    }
+                    match RecordedEvent::parse(line) {
+                        Some(e) => events.push(e),
+                        None => return Err(format!("{}:{}: invalid event: {}", path.as_str(), line_number + 1, line)),
+                    }
+                }
+                // stable sort: events with the same timestamp stay in order
+                events.sort_by_key(|e| e.time);
+                Mode::Replay(events.into())
+            },
+        };
+
+        Ok(Self {
+            start: Instant::now(),
+            windows: Vec::new(),
+            mode,
+        })
+    }
+
+    /// Has to be called for every window once it is created
+    pub(crate) fn add_window(&mut self, window_id: usize) {
+        if !self.windows.contains(&window_id) {
+            self.windows.push(window_id);
+        }
+    }
+
+    /// Appends the event to the recording, does nothing while replaying
+    pub(crate) fn record(&mut self, window_id: usize, event: &SyntheticEvent) {
+
+        let window = match self.windows.iter().position(|w| *w == window_id) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let time = self.start.elapsed().as_millis();
+
+        if let Mode::Record(file) = &mut self.mode {
+            // write every event immediately, so that the recording
+            // is complete even if the application crashes
+            if let Err(_e) = writeln!(file, "{} {} {}", time, window, event) {
+                #[cfg(feature = "logging")] {
+                    warn!("could not write event recording, recording stopped: {}", _e);
+                }
+                self.mode = Mode::Stopped;
+            }
+        }
+    }
+
+    /// Removes all events that are due from the replay, returns the events (with the
+    /// handle of the target window) and the time until the next event is due
+    /// (`None` if the replay is finished or events are being recorded)
+    pub(crate) fn take_due_events(&mut self) -> (Vec<(usize, SyntheticEvent)>, Option<Duration>) {
+
+        let events = match &mut self.mode {
+            Mode::Replay(events) => events,
+            Mode::Record(_) | Mode::Stopped => return (Vec::new(), None),
+        };
+
+        let elapsed = self.start.elapsed();
+        let mut due = Vec::new();
+
+        while events.front().map(|e| e.time <= elapsed).unwrap_or(false) {
+            let e = match events.pop_front() {
+                Some(s) => s,
+                None => break,
+            };
+            // events of windows that were not created (yet) are dropped
+            if let Some(window_id) = self.windows.get(e.window) {
+                due.push((*window_id, e.event));
+            }
+        }
+
+        let next = events.front().map(|e| e.time.checked_sub(elapsed).unwrap_or_default());
+
+        (due, next)
+    }
+}
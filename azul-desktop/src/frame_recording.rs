@@ -0,0 +1,375 @@
+//! Recording of the presented frames of a window, see `AppConfig::frame_recording`
+//! and `CallbackInfo::render_subtree_to_frames()`
+//!
+//! The back buffer is read back after every rendered frame (before it is swapped).
+//! A frame is only recorded once the next frame is presented, since the time it
+//! was visible is not known before - frames that are presented faster than the
+//! `max_fps` replace the contents of the previous frame instead.
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, SyncSender};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use azul_core::app_resources::{
+    FrameRecording, FrameRecordingCallback, FrameRecordingOutput, ImageRef,
+    RawImage, RawImageData, RawImageFormat, RecordedFrame,
+};
+use azul_core::callbacks::SubtreeRecording;
+use azul_core::task::{Duration as AzDuration, SystemTimeDiff};
+use azul_core::window::{LogicalRect, RawWindowHandle};
+use azul_css::AzString;
+
+/// How long the last frame of a recording is shown
+const LAST_FRAME_DURATION: Duration = Duration::from_secs(1);
+/// Number of frames that can wait for the GIF encoder before the rendering blocks
+const MAX_QUEUED_GIF_FRAMES: usize = 60;
+/// Quality / speed of the GIF color quantization (1 = best quality, 30 = fastest)
+#[cfg(feature = "gif")]
+const GIF_ENCODING_SPEED: i32 = 20;
+
+/// Numbers the recordings of the windows, so that every window gets its own file
+static RECORDING_NUMBER: AtomicUsize = AtomicUsize::new(0);
+
+/// Records the frames of one window, the recording is finished when dropped
+pub(crate) struct FrameRecorder {
+    output: RecorderOutput,
+    window_handle: RawWindowHandle,
+    min_frame_interval: Duration,
+    start: Instant,
+    recording_number: usize,
+    /// Number of frames that have been recorded so far
+    frame_index: usize,
+    /// Last presented frame, recorded once the next frame is presented
+    pending: Option<PendingFrame>,
+    /// Background thread that encodes the GIF of the current window size
+    gif: Option<GifWriter>,
+    /// Number of GIFs written so far (a new GIF is started if the window is resized)
+    gif_count: usize,
+}
+
+enum RecorderOutput {
+    Callback(FrameRecordingCallback),
+    /// One GIF per window, the file names are generated
+    GifDirectory(AzString),
+    /// Single GIF, frames that don't have the size of the first frame are skipped
+    GifFile(PathBuf),
+}
+
+struct PendingFrame {
+    presented: Instant,
+    width: u32,
+    height: u32,
+    /// RGBA8, top row first
+    pixels: Vec<u8>,
+}
+
+impl FrameRecorder {
+
+    pub(crate) fn new(config: &FrameRecording, window_handle: RawWindowHandle) -> Self {
+        let output = match &config.output {
+            FrameRecordingOutput::GifDirectory(directory) => RecorderOutput::GifDirectory(directory.clone()),
+            FrameRecordingOutput::Callback(callback) => RecorderOutput::Callback(*callback),
+        };
+        Self::with_output(output, config.max_fps, window_handle)
+    }
+
+    fn with_output(output: RecorderOutput, max_fps: u32, window_handle: RawWindowHandle) -> Self {
+        Self {
+            output,
+            window_handle,
+            min_frame_interval: Duration::from_secs(1) / max_fps.max(1),
+            start: Instant::now(),
+            recording_number: RECORDING_NUMBER.fetch_add(1, Ordering::SeqCst),
+            frame_index: 0,
+            pending: None,
+            gif: None,
+            gif_count: 0,
+        }
+    }
+
+    /// Called after a frame has been rendered (before the buffers are swapped),
+    /// `read_back_buffer` has to return the RGBA8 pixels of the back buffer as
+    /// returned by `glReadPixels` (bottom row first)
+    pub(crate) fn record_frame<F: FnOnce() -> Vec<u8>>(&mut self, width: u32, height: u32, read_back_buffer: F) {
+
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let now = Instant::now();
+        let pixels = flip_rows(read_back_buffer(), width as usize * 4);
+        if pixels.len() != width as usize * height as usize * 4 {
+            return;
+        }
+
+        match self.pending.take() {
+            Some(mut pending) if now.duration_since(pending.presented) < self.min_frame_interval => {
+                // too fast: the new frame replaces the contents of the pending frame
+                pending.width = width;
+                pending.height = height;
+                pending.pixels = pixels;
+                self.pending = Some(pending);
+                return;
+            },
+            Some(pending) => {
+                let duration = now.duration_since(pending.presented);
+                self.write_frame(pending, duration);
+            },
+            None => { },
+        }
+
+        self.pending = Some(PendingFrame { presented: now, width, height, pixels });
+    }
+
+    fn write_frame(&mut self, frame: PendingFrame, duration: Duration) {
+
+        let timestamp = frame.presented.duration_since(self.start);
+        let frame_index = self.frame_index;
+        self.frame_index += 1;
+
+        match &self.output {
+            RecorderOutput::Callback(callback) => {
+                let image = ImageRef::new_rawimage(RawImage {
+                    pixels: RawImageData::U8(frame.pixels.into()),
+                    width: frame.width as usize,
+                    height: frame.height as usize,
+                    premultiplied_alpha: true,
+                    data_format: RawImageFormat::RGBA8,
+                });
+                let image = match image {
+                    Some(s) => s,
+                    None => return,
+                };
+                (callback.cb)(&RecordedFrame {
+                    window_handle: self.window_handle,
+                    frame_index,
+                    timestamp: AzDuration::System(SystemTimeDiff::from(timestamp)),
+                    duration: AzDuration::System(SystemTimeDiff::from(duration)),
+                    image,
+                });
+            },
+            RecorderOutput::GifDirectory(directory) => {
+
+                // a GIF can't change its size: start a new file
+                let size_changed = self.gif
+                    .as_ref()
+                    .map(|g| g.width != frame.width || g.height != frame.height)
+                    .unwrap_or(true);
+
+                if size_changed {
+                    self.gif = None;
+                    let path = gif_path(directory.as_str(), self.recording_number, self.gif_count);
+                    self.gif_count += 1;
+                    self.gif = GifWriter::start(path, frame.width, frame.height);
+                }
+
+                if let Some(gif) = self.gif.as_ref() {
+                    gif.write_frame(frame.pixels, gif_delay_ms(duration));
+                }
+            },
+            RecorderOutput::GifFile(path) => {
+
+                if self.gif.is_none() && self.gif_count == 0 {
+                    self.gif_count += 1;
+                    self.gif = GifWriter::start(path.clone(), frame.width, frame.height);
+                }
+
+                if let Some(gif) = self.gif.as_ref() {
+                    if gif.width == frame.width && gif.height == frame.height {
+                        gif.write_frame(frame.pixels, gif_delay_ms(duration));
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// Recording of a node, see `CallbackInfo::render_subtree_to_frames()`
+pub(crate) struct SubtreeRecorder {
+    /// Area of the node in physical pixels (origin at the top left of the window)
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    end: Instant,
+    recorder: FrameRecorder,
+}
+
+impl SubtreeRecorder {
+
+    /// Returns `None` if the node is outside of the framebuffer or the duration is not a system time
+    pub(crate) fn new(
+        recording: &SubtreeRecording,
+        node_rect: LogicalRect,
+        hidpi_factor: f32,
+        framebuffer_size: (i32, i32),
+    ) -> Option<Self> {
+
+        let duration: Duration = match recording.duration {
+            AzDuration::System(s) => s.into(),
+            AzDuration::Tick(_) => return None,
+        };
+
+        // clamp the area of the node to the framebuffer
+        let (framebuffer_width, framebuffer_height) = framebuffer_size;
+        let x0 = (libm::roundf(node_rect.origin.x * hidpi_factor) as i32).max(0);
+        let y0 = (libm::roundf(node_rect.origin.y * hidpi_factor) as i32).max(0);
+        let x1 = (libm::roundf((node_rect.origin.x + node_rect.size.width) * hidpi_factor) as i32).min(framebuffer_width);
+        let y1 = (libm::roundf((node_rect.origin.y + node_rect.size.height) * hidpi_factor) as i32).min(framebuffer_height);
+
+        if x1 <= x0 || y1 <= y0 {
+            return None;
+        }
+
+        let output = RecorderOutput::GifFile(PathBuf::from(recording.output_path.as_str()));
+
+        Some(Self {
+            x: x0,
+            y: y0,
+            width: x1 - x0,
+            height: y1 - y0,
+            end: Instant::now() + duration,
+            // the window handle is only passed to frame callbacks
+            recorder: FrameRecorder::with_output(output, recording.fps, RawWindowHandle::Unsupported),
+        })
+    }
+
+    /// Called after a frame has been rendered (before the buffers are swapped),
+    /// `read_pixels(x, y, width, height)` has to return the RGBA8 pixels of the
+    /// area of the back buffer as returned by `glReadPixels` (origin bottom left)
+    pub(crate) fn record_frame<F: FnOnce(i32, i32, i32, i32) -> Vec<u8>>(&mut self, framebuffer_height: i32, read_pixels: F) {
+        let (x, y, width, height) = (self.x, framebuffer_height - self.y - self.height, self.width, self.height);
+        self.recorder.record_frame(width as u32, height as u32, || read_pixels(x, y, width, height));
+    }
+
+    /// Whether the duration of the recording has passed, the GIF is finished once the recorder is dropped
+    pub(crate) fn is_finished(&self) -> bool {
+        Instant::now() >= self.end
+    }
+
+    /// Interval in which the window has to be redrawn, so that the recording gets enough frames
+    pub(crate) fn get_frame_interval(&self) -> Duration {
+        self.recorder.min_frame_interval
+    }
+}
+
+impl Drop for FrameRecorder {
+    fn drop(&mut self) {
+        if let Some(pending) = self.pending.take() {
+            self.write_frame(pending, LAST_FRAME_DURATION);
+        }
+    }
+}
+
+/// Animated GIF that is encoded on a background thread
+struct GifWriter {
+    width: u32,
+    height: u32,
+    sender: Option<SyncSender<(Vec<u8>, u32)>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl GifWriter {
+
+    #[cfg(feature = "gif")]
+    fn start(path: PathBuf, width: u32, height: u32) -> Option<Self> {
+
+        use azulc_lib::image::encode::GifAnimationEncoder;
+        use std::io::BufWriter;
+
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+
+        let file = match std::fs::File::create(&path) {
+            Ok(o) => o,
+            Err(_e) => {
+                #[cfg(feature = "logging")] {
+                    error!("frame recording: could not create {}: {}", path.display(), _e);
+                }
+                return None;
+            },
+        };
+
+        let mut encoder = GifAnimationEncoder::new(BufWriter::new(file), GIF_ENCODING_SPEED).ok()?;
+        let (sender, receiver) = mpsc::sync_channel::<(Vec<u8>, u32)>(MAX_QUEUED_GIF_FRAMES);
+
+        let thread = std::thread::Builder::new()
+            .name(String::from("azul-frame-recording"))
+            .spawn(move || {
+                for (pixels, delay_ms) in receiver {
+                    if encoder.encode_frame(pixels, width, height, delay_ms).is_err() {
+                        break;
+                    }
+                }
+            })
+            .ok()?;
+
+        Some(Self {
+            width,
+            height,
+            sender: Some(sender),
+            thread: Some(thread),
+        })
+    }
+
+    #[cfg(not(feature = "gif"))]
+    fn start(_path: PathBuf, _width: u32, _height: u32) -> Option<Self> {
+        #[cfg(feature = "logging")] {
+            error!("frame recording: azul was compiled without the \"gif\" feature, frames are not recorded");
+        }
+        None
+    }
+
+    fn write_frame(&self, pixels: Vec<u8>, delay_ms: u32) {
+        if let Some(sender) = self.sender.as_ref() {
+            let _ = sender.send((pixels, delay_ms));
+        }
+    }
+}
+
+impl Drop for GifWriter {
+    fn drop(&mut self) {
+        // closing the channel finishes the encoder thread, which writes the end of the GIF
+        self.sender = None;
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// `azul-recording-<unix time>-<window>[-<part>].gif` in the output directory
+fn gif_path(directory: &str, recording_number: usize, gif_count: usize) -> PathBuf {
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let file_name = if gif_count == 0 {
+        format!("azul-recording-{}-{}.gif", timestamp, recording_number)
+    } else {
+        format!("azul-recording-{}-{}-{}.gif", timestamp, recording_number, gif_count + 1)
+    };
+
+    PathBuf::from(directory).join(file_name)
+}
+
+/// GIF delays are stored in 1/100 seconds
+fn gif_delay_ms(duration: Duration) -> u32 {
+    duration.as_millis().max(10).min(u32::MAX as u128) as u32
+}
+
+/// glReadPixels returns the bottom row first
+fn flip_rows(mut pixels: Vec<u8>, row_len: usize) -> Vec<u8> {
+    if row_len == 0 {
+        return pixels;
+    }
+    let rows = pixels.len() / row_len;
+    for i in 0..rows / 2 {
+        let (top, bottom) = pixels.split_at_mut((rows - i - 1) * row_len);
+        top[i * row_len..(i + 1) * row_len].swap_with_slice(&mut bottom[..row_len]);
+    }
+    pixels
+}
@@ -0,0 +1,74 @@
+//! Database of known driver bugs and the workarounds that are applied
+//! when a window creates its renderer, see `App::add_gl_quirk_rule()`
+
+use alloc::vec::Vec;
+use azul_core::gl::{GlDriverInfo, GlQuirkRule, GlQuirks};
+use azul_css::{AzString, OptionAzString};
+
+/// Rule that matches a substring of the `GL_RENDERER` string
+const fn renderer_rule(renderer: &'static str, enable: GlQuirks) -> GlQuirkRule {
+    GlQuirkRule {
+        vendor: OptionAzString::None,
+        renderer: OptionAzString::Some(AzString::from_const_str(renderer)),
+        version: OptionAzString::None,
+        enable,
+        disable: NO_QUIRKS,
+    }
+}
+
+/// Rule that matches a `GL_VERSION` range, i.e. "Mesa < 21.2" (see `GlQuirkRule`)
+const fn version_rule(version: &'static str, enable: GlQuirks) -> GlQuirkRule {
+    GlQuirkRule {
+        vendor: OptionAzString::None,
+        renderer: OptionAzString::None,
+        version: OptionAzString::Some(AzString::from_const_str(version)),
+        enable,
+        disable: NO_QUIRKS,
+    }
+}
+
+const NO_QUIRKS: GlQuirks = GlQuirks {
+    force_software_rendering: false,
+    disable_subpixel_aa: false,
+    disable_optimized_shaders: false,
+    disable_program_cache: false,
+    disable_multithreading: false,
+    disable_partial_present: false,
+};
+
+const BUILTIN_RULES: &[GlQuirkRule] = &[
+    // OpenGL 1.1 fallback of Windows (no GPU driver installed):
+    // too old to run the hardware renderer at all
+    renderer_rule("GDI Generic", GlQuirks { force_software_rendering: true, ..NO_QUIRKS }),
+    // Mesa software rasterizers: subpixel AA needs dual-source
+    // blending, which is very slow when emulated on the CPU
+    renderer_rule("llvmpipe", GlQuirks { disable_subpixel_aa: true, ..NO_QUIRKS }),
+    renderer_rule("softpipe", GlQuirks { disable_subpixel_aa: true, ..NO_QUIRKS }),
+    renderer_rule("SwiftShader", GlQuirks { disable_subpixel_aa: true, ..NO_QUIRKS }),
+    // Mesa drivers before 17.0 (i965, radeonsi, nouveau of older distributions)
+    // crash or render garbage with the WebRender shaders, Firefox doesn't
+    // enable WebRender on them either
+    version_rule("Mesa < 17.0", GlQuirks { force_software_rendering: true, ..NO_QUIRKS }),
+];
+
+/// Returns the workarounds for the driver: the built-in rules
+/// are applied first, then the rules added by the app
+pub(crate) fn resolve_gl_quirks(driver: &GlDriverInfo, app_rules: &[GlQuirkRule]) -> GlQuirks {
+
+    let rules = BUILTIN_RULES.iter().chain(app_rules.iter()).cloned().collect::<Vec<_>>();
+    let quirks = GlQuirkRule::resolve(&rules, driver);
+
+    #[cfg(feature = "logging")] {
+        if !quirks.is_empty() {
+            warn!(
+                "applying driver workarounds for {} / {} / {}: {:?}",
+                driver.vendor.as_str(),
+                driver.renderer.as_str(),
+                driver.version.as_str(),
+                quirks,
+            );
+        }
+    }
+
+    quirks
+}
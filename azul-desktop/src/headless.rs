@@ -0,0 +1,99 @@
+//! Off-screen rendering of a window into an image without a display server
+//! (requires the `headless` feature), i.e. for snapshot tests in CI
+//!
+//! The window is never shown: the layout callback of the `WindowCreateOptions`
+//! is invoked once, the styled DOM is laid out at `WindowState::size` and
+//! rendered into an off-screen framebuffer, which is read back into an image.
+//! There is no event loop, so timers, threads, animations and the
+//! `create_callback` of the window don't run.
+//!
+//! Backends:
+//!
+//! - Linux: EGL without a native display (`EGL_MESA_platform_surfaceless`, falls back
+//!   to the default EGL display) with a pbuffer surface. Works with the software
+//!   rasterizer of Mesa (llvmpipe), so neither a GPU nor X11 / Wayland is required.
+//! - Windows, macOS: not supported yet, `render_to_image()` returns
+//!   `HeadlessError::Unsupported`. WebRender has no CPU rasterizer, so there is no
+//!   software fallback either: rendering needs an off-screen OpenGL context
+//!   (a hidden WGL window / CGL pbuffer), which is not implemented yet. Use a
+//!   Linux CI runner (Mesa llvmpipe) for snapshot tests in the meantime.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use azul_core::app_resources::{RawImage, RawImageData, RawImageFormat};
+use azul_core::window::WindowCreateOptions;
+use crate::app::App;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeadlessError {
+    /// Headless rendering is not supported on this platform (Windows, macOS)
+    Unsupported,
+    /// The window has a width or height of zero
+    InvalidSize,
+    /// Error of the platform API or the renderer
+    Platform(String),
+}
+
+impl fmt::Display for HeadlessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::HeadlessError::*;
+        match self {
+            Unsupported => write!(f, "headless rendering is not supported on this platform"),
+            InvalidSize => write!(f, "window has a width or height of zero"),
+            Platform(e) => write!(f, "headless rendering failed: {}", e),
+        }
+    }
+}
+
+/// Renders the window into an image (RGBA8 pixels, premultiplied alpha),
+/// see `App::render_to_image()`
+pub(crate) fn render_to_image(app: &mut App, options: WindowCreateOptions) -> Result<RawImage, HeadlessError> {
+
+    let size = options.state.size.dimensions;
+    if size.width < 1.0 || size.height < 1.0 {
+        return Err(HeadlessError::InvalidSize);
+    }
+
+    let frame = render_platform(app, options)?;
+
+    Ok(frame.into_raw_image())
+}
+
+/// Pixels as returned by `glReadPixels()`
+pub(crate) struct RenderedFrame {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    /// RGBA8 pixels with premultiplied alpha, the bottom row comes first
+    pub(crate) pixels: Vec<u8>,
+}
+
+impl RenderedFrame {
+
+    fn into_raw_image(self) -> RawImage {
+
+        let row_len = self.width * 4;
+        let mut pixels = Vec::with_capacity(self.pixels.len());
+        for row in self.pixels.chunks_exact(row_len).rev() {
+            pixels.extend_from_slice(row);
+        }
+
+        RawImage {
+            pixels: RawImageData::U8(pixels.into()),
+            width: self.width,
+            height: self.height,
+            premultiplied_alpha: true,
+            data_format: RawImageFormat::RGBA8,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn render_platform(app: &mut App, options: WindowCreateOptions) -> Result<RenderedFrame, HeadlessError> {
+    crate::shell::x11::headless::render(app, options)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn render_platform(_app: &mut App, _options: WindowCreateOptions) -> Result<RenderedFrame, HeadlessError> {
+    Err(HeadlessError::Unsupported)
+}
@@ -0,0 +1,203 @@
+//! Development mode: loads the layout callback from a dynamic library and
+//! reloads the library whenever it is recompiled.
+//!
+//! The UI code has to be compiled as a `cdylib` that exports the layout callback:
+//!
+//! ```rust,ignore
+//! #[no_mangle]
+//! pub extern "C" fn layout(data: &mut RefAny, info: &mut LayoutCallbackInfo) -> StyledDom {
+//!     // ...
+//! }
+//! ```
+//!
+//! The application data (`RefAny`) lives in the main executable and is kept
+//! across reloads. If the type of the data changed in the new library version, the
+//! `downcast_ref` / `downcast_mut` in the layout callback will fail, so the layout
+//! callback should handle that case gracefully (i.e. by showing an error message).
+//!
+//! Combine this with `WindowCreateOptions::hot_reload` so that the window re-runs
+//! the layout callback periodically and picks up the changes (supported on
+//! Windows and X11, window creation fails with an error on macOS).
+//!
+//! Old versions of the library stay loaded until the process exits: the callbacks
+//! of the current UI and the destructors of the `RefAny`s created by the old code
+//! may still point into it. The library is copied before each load, on Windows
+//! the copies stay in the temporary directory (loaded DLLs can't be deleted).
+
+use alloc::string::String;
+use core::ffi::c_void;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use azul_core::callbacks::{
+    LayoutCallback, LayoutCallbackInfo, LayoutCallbackType,
+    MarshaledLayoutCallback, MarshaledLayoutCallbackInner, RefAny,
+};
+use azul_core::styled_dom::StyledDom;
+use azul_css::AzString;
+
+/// Creates a layout callback that loads the function `symbol` from
+/// the library at `library_path` and reloads it on every change
+pub fn hot_reload_layout_callback(library_path: AzString, symbol: AzString) -> LayoutCallback {
+    LayoutCallback::Marshaled(MarshaledLayoutCallback {
+        marshal_data: RefAny::new(HotReloadLibrary {
+            library_path: PathBuf::from(library_path.as_str()),
+            symbol: symbol.as_str().to_string(),
+            last_modified: None,
+            layout_fn: None,
+            reload_count: 0,
+        }),
+        cb: MarshaledLayoutCallbackInner { cb: invoke_hot_reloaded_layout_callback },
+    })
+}
+
+/// State of the hot-reloaded library, stored in the `marshal_data`
+#[derive(Debug)]
+struct HotReloadLibrary {
+    /// Path to the library that the compiler outputs
+    library_path: PathBuf,
+    /// Name of the exported layout function
+    symbol: String,
+    /// Modification time of the library when it was last loaded
+    last_modified: Option<SystemTime>,
+    /// Layout function of the newest version of the library
+    layout_fn: Option<LayoutCallbackType>,
+    /// Used to generate unique file names for the library copies
+    reload_count: usize,
+}
+
+#[derive(Debug)]
+enum HotReloadError {
+    Io(std::io::Error),
+    LibraryNotLoaded(PathBuf),
+    SymbolNotFound(String),
+}
+
+impl HotReloadLibrary {
+
+    /// Reloads the library if it has been modified since the last load
+    fn reload_if_changed(&mut self) -> Result<(), HotReloadError> {
+
+        let last_modified = std::fs::metadata(&self.library_path)
+            .and_then(|m| m.modified())
+            .map_err(HotReloadError::Io)?;
+
+        if self.layout_fn.is_some() && self.last_modified == Some(last_modified) {
+            return Ok(());
+        }
+
+        self.reload_count += 1;
+
+        // the library is copied before loading, so that the original can be
+        // overwritten by the compiler (Windows locks loaded DLLs) and so that
+        // the OS loader doesn't return the cached old version
+        let copy_path = get_copy_path(&self.library_path, self.reload_count);
+        std::fs::copy(&self.library_path, &copy_path).map_err(HotReloadError::Io)?;
+
+        let handle = match unsafe { open_library(&copy_path) } {
+            Some(s) => s,
+            None => {
+                let _ = std::fs::remove_file(&copy_path);
+                return Err(HotReloadError::LibraryNotLoaded(copy_path));
+            },
+        };
+
+        let layout_fn = match unsafe { get_symbol(handle, &self.symbol) } {
+            Some(s) => unsafe { core::mem::transmute::<*mut c_void, LayoutCallbackType>(s) },
+            None => {
+                unsafe { close_library(handle); }
+                let _ = std::fs::remove_file(&copy_path);
+                return Err(HotReloadError::SymbolNotFound(self.symbol.clone()));
+            },
+        };
+
+        // the loaded code stays mapped after the file is deleted
+        #[cfg(unix)] {
+            let _ = std::fs::remove_file(&copy_path);
+        }
+
+        // the handle is leaked on purpose: the library is never unloaded (see the module docs)
+        let _ = handle;
+        self.layout_fn = Some(layout_fn);
+        self.last_modified = Some(last_modified);
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(feature = "logging"), allow(unused_variables))]
+extern "C" fn invoke_hot_reloaded_layout_callback(
+    marshal_data: &mut RefAny,
+    app_data: &mut RefAny,
+    info: &mut LayoutCallbackInfo,
+) -> StyledDom {
+
+    let mut library = match marshal_data.downcast_mut::<HotReloadLibrary>() {
+        Some(s) => s,
+        None => return StyledDom::default(),
+    };
+
+    // if the reload fails (i.e. the compiler is still writing the file),
+    // keep using the old version of the library
+    if let Err(e) = library.reload_if_changed() {
+        #[cfg(feature = "logging")] {
+            warn!("hot reload of {} failed: {:?}", library.library_path.display(), e);
+        }
+    }
+
+    let layout_fn = match library.layout_fn {
+        Some(s) => s,
+        None => return StyledDom::default(),
+    };
+
+    (layout_fn)(app_data, info)
+}
+
+fn get_copy_path(library_path: &Path, reload_count: usize) -> PathBuf {
+    let file_stem = library_path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    let extension = library_path.extension().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    std::env::temp_dir().join(format!("{}-hot-reload-{}-{}.{}", file_stem, std::process::id(), reload_count, extension))
+}
+
+#[cfg(target_os = "windows")]
+unsafe fn open_library(path: &Path) -> Option<*mut c_void> {
+    use std::os::windows::ffi::OsStrExt;
+    use winapi::um::libloaderapi::LoadLibraryW;
+    let mut path_wide = path.as_os_str().encode_wide().collect::<Vec<u16>>();
+    path_wide.push(0);
+    let handle = LoadLibraryW(path_wide.as_ptr());
+    if handle.is_null() { None } else { Some(handle as *mut c_void) }
+}
+
+#[cfg(target_os = "windows")]
+unsafe fn get_symbol(handle: *mut c_void, symbol: &str) -> Option<*mut c_void> {
+    use winapi::um::libloaderapi::GetProcAddress;
+    let symbol = std::ffi::CString::new(symbol).ok()?;
+    let ptr = GetProcAddress(handle as _, symbol.as_ptr());
+    if ptr.is_null() { None } else { Some(ptr as *mut c_void) }
+}
+
+#[cfg(target_os = "windows")]
+unsafe fn close_library(handle: *mut c_void) {
+    use winapi::um::libloaderapi::FreeLibrary;
+    FreeLibrary(handle as _);
+}
+
+#[cfg(unix)]
+unsafe fn open_library(path: &Path) -> Option<*mut c_void> {
+    use std::os::unix::ffi::OsStrExt;
+    let path = std::ffi::CString::new(path.as_os_str().as_bytes()).ok()?;
+    let handle = libc::dlopen(path.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL);
+    if handle.is_null() { None } else { Some(handle) }
+}
+
+#[cfg(unix)]
+unsafe fn get_symbol(handle: *mut c_void, symbol: &str) -> Option<*mut c_void> {
+    let symbol = std::ffi::CString::new(symbol).ok()?;
+    let ptr = libc::dlsym(handle, symbol.as_ptr());
+    if ptr.is_null() { None } else { Some(ptr) }
+}
+
+#[cfg(unix)]
+unsafe fn close_library(handle: *mut c_void) {
+    libc::dlclose(handle);
+}
@@ -0,0 +1,148 @@
+//! Jank detector: records the time spent in the phases of a frame (layout, restyle,
+//! display list, render) and writes a report if the frame took longer than the
+//! threshold configured in `AppConfig::jank_capture`
+
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use azul_core::app_resources::{ImageCache, JankCapture};
+use azul_core::display_list::CachedDisplayList;
+use azul_core::styled_dom::DomId;
+use azul_core::ui_solver::LayoutResult;
+use azul_core::window::WindowInternal;
+
+/// Rendering statistics of a window - clones share the same statistics,
+/// so that phases can also be recorded from background threads
+#[derive(Debug, Default, Clone)]
+pub(crate) struct FrameStats {
+    inner: Arc<Mutex<FrameStatsInner>>,
+}
+
+#[derive(Debug, Default)]
+struct FrameStatsInner {
+    /// When the first phase of the current frame started
+    frame_start: Option<Instant>,
+    /// Name + time spent in each phase of the current frame, in order
+    phases: Vec<(&'static str, Duration)>,
+    /// Number of frames presented so far
+    frame_count: usize,
+    /// Number of frames that took longer than the threshold
+    janky_frame_count: usize,
+}
+
+/// Records the time spent in a phase of the current frame when dropped
+#[derive(Debug)]
+pub(crate) struct FramePhase {
+    stats: FrameStats,
+    name: &'static str,
+    start: Instant,
+}
+
+impl Drop for FramePhase {
+    fn drop(&mut self) {
+        if let Ok(mut inner) = self.stats.inner.lock() {
+            inner.phases.push((self.name, self.start.elapsed()));
+        }
+    }
+}
+
+impl FrameStats {
+
+    /// Starts a new phase, starts a new frame if this is the first phase since the last frame
+    pub(crate) fn phase(&self, name: &'static str) -> FramePhase {
+        let start = Instant::now();
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.frame_start.get_or_insert(start);
+        }
+        FramePhase { stats: self.clone(), name, start }
+    }
+
+    /// Called once the frame was presented, resets the statistics of the current frame.
+    /// Returns the timing report if the frame took longer than the `jank_capture.threshold`
+    pub(crate) fn finish_frame(&self, jank_capture: Option<&JankCapture>) -> Option<String> {
+
+        let mut inner = self.inner.lock().ok()?;
+        let frame_start = inner.frame_start.take();
+        let phases = std::mem::replace(&mut inner.phases, Vec::new());
+        inner.frame_count += 1;
+
+        let jank_capture = jank_capture?;
+        let frame_time = frame_start?.elapsed();
+        let threshold: Duration = match jank_capture.threshold {
+            azul_core::task::Duration::System(s) => s.into(),
+            azul_core::task::Duration::Tick(_) => return None,
+        };
+
+        if frame_time <= threshold {
+            return None;
+        }
+
+        inner.janky_frame_count += 1;
+
+        let mut report = String::new();
+        let _ = writeln!(report, "frame time: {:?} (threshold: {:?})", frame_time, threshold);
+        let _ = writeln!(report, "slow frames: {} of {}", inner.janky_frame_count, inner.frame_count);
+        let _ = writeln!(report);
+        for (name, duration) in phases.iter() {
+            let _ = writeln!(report, "{}: {:?}", name, duration);
+        }
+        let time_in_phases = phases.iter().map(|(_, d)| *d).sum::<Duration>();
+        let _ = writeln!(report, "(idle / waiting): {:?}", frame_time.checked_sub(time_in_phases).unwrap_or_default());
+
+        Some(report)
+    }
+}
+
+/// Returns the display list of the current state of the window
+pub(crate) fn dump_display_list(internal: &WindowInternal, image_cache: &ImageCache) -> CachedDisplayList {
+    LayoutResult::get_cached_display_list(
+        &internal.document_id,
+        DomId::ROOT_ID,
+        internal.epoch,
+        &internal.layout_results,
+        &internal.current_window_state,
+        &internal.gl_texture_cache,
+        &internal.renderer_resources,
+        image_cache,
+        &internal.scroll_states,
+        &internal.scrollbar_interaction,
+    )
+}
+
+/// Writes the jank report to `jank_capture.output_directory` (or the temp directory),
+/// returns the path of the report file on success
+///
+/// The display list is appended to the report and also written in the binary format
+/// next to it (`.azdl`), so that it can be loaded with `CachedDisplayList::from_bytes`
+pub(crate) fn write_report(
+    jank_capture: &JankCapture,
+    window_title: &str,
+    report: &str,
+    display_list: Option<&CachedDisplayList>,
+) -> Option<PathBuf> {
+
+    let directory = match jank_capture.output_directory.as_ref() {
+        Some(s) => PathBuf::from(s.as_str()),
+        None => std::env::temp_dir(),
+    };
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+
+    let mut contents = format!("window: {}\n\n{}", window_title, report);
+    if let Some(display_list) = display_list {
+        contents.push_str("\ndisplay list:\n");
+        let _ = write!(contents, "{:#?}", display_list);
+        let _ = std::fs::write(
+            directory.join(format!("azul-jank-{}.azdl", timestamp)),
+            display_list.to_bytes(),
+        );
+    }
+
+    let path = directory.join(format!("azul-jank-{}.txt", timestamp));
+    std::fs::write(&path, contents).ok()?;
+    Some(path)
+}
@@ -62,6 +62,21 @@ pub mod str;
 pub mod file;
 /// Bindings to the native file-chooser, color picker, etc. dialogs
 pub mod dialogs;
+/// Hot-reloading of layout callbacks from a dynamic library (development mode)
+pub mod hot_reload;
+/// Spell checking of text (system spellchecker or hunspell)
+pub mod spellcheck;
+/// Optional `azul.toml` file that overrides renderer and logging options
+pub mod config_file;
+/// Screen capture of monitors and windows
+#[cfg(feature = "capture")]
+pub mod capture;
+/// Off-screen rendering without a display server
+#[cfg(feature = "headless")]
+pub mod headless;
+/// Sandboxed scripts (rhai) that build DOM fragments and handle callbacks
+#[cfg(feature = "scripting")]
+pub mod script;
 pub use azul_core::dom;
 pub use azul_core::gl;
 pub use azul_core::styled_dom;
@@ -77,12 +92,20 @@ pub mod ui_solver {
     pub use azul_core::ui_solver::{
         PositionInfo, OptionPositionInfo,
         PositionInfoInner,
+        NodeRect, OptionNodeRect,
         ResolvedTextLayoutOptions,
         OptionResolvedTextLayoutOptions,
     };
 }
 
 mod compositor;
+mod gl_quirks;
+#[cfg(target_os = "windows")]
+mod event_recording;
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+mod frame_recording;
+#[cfg(target_os = "windows")]
+mod jank;
 #[cfg(feature = "logging")]
 mod logging;
 mod wr_translate;
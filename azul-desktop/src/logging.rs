@@ -1,9 +1,124 @@
 use core::sync::atomic::{Ordering, AtomicBool};
-use log::LevelFilter;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Mutex, RwLock};
+use std::time::Instant;
+use log::{LevelFilter, Metadata};
+use once_cell::sync::{Lazy, OnceCell};
+use backtrace::{Backtrace, BacktraceFrame};
+use azul_core::app_resources::{AppLogLevel, CrashHandler, CrashInfo};
+use azul_core::window::FullWindowState;
+use azul_css::{AzString, OptionAzString, StringVec};
 use crate::dialogs::msg_box_ok;
 
 pub(crate) static SHOULD_ENABLE_PANIC_HOOK: AtomicBool = AtomicBool::new(false);
 
+/// How many log lines are kept in memory for diagnostics and crash reports
+const LOG_RING_BUFFER_LEN: usize = 500;
+
+/// Log target of the DOM regeneration + layout subsystem
+pub(crate) const LOG_TARGET_LAYOUT: &str = "azul::layout";
+/// Log target of the restyling subsystem (restyle + relayout after callbacks)
+pub(crate) const LOG_TARGET_RESTYLE: &str = "azul::restyle";
+/// Log target of the display list building + rendering subsystem
+pub(crate) const LOG_TARGET_RENDER: &str = "azul::render";
+
+static CRASH_HANDLER: OnceCell<CrashHandler> = OnceCell::new();
+/// Set once the crash handler has run, so that a panic that
+/// leads to an abort doesn't invoke the crash handler twice
+static CRASH_HANDLER_INVOKED: AtomicBool = AtomicBool::new(false);
+static LAST_LOG_LINES: Lazy<Mutex<VecDeque<String>>> = Lazy::new(|| Mutex::new(VecDeque::new()));
+static WINDOW_STATE_SUMMARY: Lazy<Mutex<String>> = Lazy::new(|| Mutex::new(String::new()));
+static LOG_LEVELS: Lazy<RwLock<LogLevels>> = Lazy::new(|| RwLock::new(LogLevels::default()));
+
+/// Global log level + log level overrides for subsystems (log targets)
+#[derive(Debug)]
+struct LogLevels {
+    global: LevelFilter,
+    subsystems: BTreeMap<String, LevelFilter>,
+}
+
+impl Default for LogLevels {
+    fn default() -> Self {
+        Self {
+            global: LevelFilter::Error,
+            subsystems: BTreeMap::new(),
+        }
+    }
+}
+
+impl LogLevels {
+
+    /// Returns the level of the most specific subsystem that the target belongs to,
+    /// i.e. `"azul::layout::text"` uses the level set for `"azul::layout"`
+    fn get_level(&self, target: &str) -> LevelFilter {
+        self.subsystems.iter()
+        .filter(|(subsystem, _)| {
+            target == subsystem.as_str() ||
+            (target.starts_with(subsystem.as_str()) && target[subsystem.len()..].starts_with("::"))
+        })
+        .max_by_key(|(subsystem, _)| subsystem.len())
+        .map(|(_, level)| *level)
+        .unwrap_or(self.global)
+    }
+
+    fn get_max_level(&self) -> LevelFilter {
+        self.subsystems.values().copied().fold(self.global, |a, b| a.max(b))
+    }
+}
+
+fn is_log_enabled(metadata: &Metadata) -> bool {
+    match LOG_LEVELS.read() {
+        Ok(l) => metadata.level() <= l.get_level(metadata.target()),
+        Err(_) => true,
+    }
+}
+
+/// Sets the log level of a subsystem at runtime, an empty
+/// subsystem string changes the global log level instead
+pub(crate) extern "C" fn set_log_level(subsystem: AzString, level: AppLogLevel) {
+
+    let level = crate::app::translate_log_level(level);
+
+    if let Ok(mut l) = LOG_LEVELS.write() {
+        if subsystem.as_str().is_empty() {
+            l.global = level;
+        } else {
+            l.subsystems.insert(subsystem.as_str().to_string(), level);
+        }
+        // the max level is checked by the log macros before the filter runs
+        log::set_max_level(l.get_max_level());
+    }
+}
+
+/// Returns the contents of the log ring buffer (oldest first)
+pub(crate) extern "C" fn get_log_lines() -> StringVec {
+    let lines = LAST_LOG_LINES.lock()
+        .map(|l| l.iter().map(|s| AzString::from(s.clone())).collect::<Vec<AzString>>())
+        .unwrap_or_default();
+    StringVec::from_vec(lines)
+}
+
+/// Logs the time spent in a subsystem (at the trace level) when dropped
+#[derive(Debug)]
+pub(crate) struct LogSpan {
+    target: &'static str,
+    name: &'static str,
+    start: Instant,
+}
+
+/// Starts a new span for the given subsystem - only does work if
+/// trace logging is enabled for the subsystem
+pub(crate) fn span(target: &'static str, name: &'static str) -> LogSpan {
+    log::trace!(target: target, "begin {}", name);
+    LogSpan { target, name, start: Instant::now() }
+}
+
+impl Drop for LogSpan {
+    fn drop(&mut self) {
+        log::trace!(target: self.target, "end {} ({:?})", self.name, self.start.elapsed());
+    }
+}
+
 #[cfg(all(feature = "use_fern_logger", not(feature = "use_pyo3_logger")))]
 pub(crate) fn set_up_logging(log_level: LevelFilter) {
 
@@ -12,6 +127,13 @@ pub(crate) fn set_up_logging(log_level: LevelFilter) {
 
     /// Sets up the global logger
     fn set_up_logging_internal(log_level: LevelFilter) -> Result<(), InitError> {
+
+        if let Ok(mut l) = LOG_LEVELS.write() {
+            l.global = log_level;
+        }
+
+        // the per-subsystem levels are checked in the filter, so that
+        // they can be changed at runtime via `set_log_level`
         fern::Dispatch::new()
         .format(|out, message, record| {
             out.finish(format_args!(
@@ -21,9 +143,15 @@ pub(crate) fn set_up_logging(log_level: LevelFilter) {
                 message
             ))
         })
-        .level(log_level)
+        .level(LevelFilter::Trace)
+        .filter(|metadata| is_log_enabled(metadata))
         .chain(::std::io::stdout())
+        .chain(fern::Output::call(|record| push_log_line(format!("{}", record.args()))))
         .apply()?;
+
+        // fern sets the max level to the dispatch level (trace)
+        log::set_max_level(log_level);
+
         Ok(())
     }
 
@@ -38,12 +166,243 @@ pub(crate) fn set_up_logging(log_level: LevelFilter) {
     }
 }
 
+/// Appends a log line to the ring buffer, discarding the oldest line
+/// if more than `LOG_RING_BUFFER_LEN` lines are stored
+fn push_log_line(line: String) {
+    if let Ok(mut lines) = LAST_LOG_LINES.lock() {
+        if lines.len() >= LOG_RING_BUFFER_LEN {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+}
+
+/// Updates the window state summary that is attached to crash reports.
+/// Does nothing if no crash handler is installed.
+pub(crate) fn update_window_state_summary<'a, I: Iterator<Item = &'a FullWindowState>>(window_states: I) {
+
+    if CRASH_HANDLER.get().is_none() {
+        return;
+    }
+
+    let summary = window_states.map(|w| format!(
+        "\"{}\": {}x{} @ {:?}, dpi: {}, frame: {:?}, theme: {:?}, focused: {}",
+        w.title.as_str(),
+        w.size.dimensions.width,
+        w.size.dimensions.height,
+        w.position,
+        w.size.dpi,
+        w.flags.frame,
+        w.theme,
+        w.flags.has_focus,
+    )).collect::<Vec<String>>().join("\r\n");
+
+    if let Ok(mut s) = WINDOW_STATE_SUMMARY.lock() {
+        *s = summary;
+    }
+}
+
+/// Logs a warning for every window shortcut that is shadowed by
+/// another shortcut with the same key combination (only Windows dispatches shortcuts)
+#[cfg(target_os = "windows")]
+pub(crate) fn warn_shortcut_conflicts(window_title: &str, shortcuts: &azul_core::window::ShortcutMap) {
+    for conflict in shortcuts.get_conflicts().iter() {
+        warn!(
+            "window \"{}\": shortcut #{} ({:?}) is shadowed by shortcut #{} with the same key combination",
+            window_title,
+            conflict.conflicting,
+            conflict.keys.keys.as_ref(),
+            conflict.existing,
+        );
+    }
+}
+
+/// Installs the user-provided crash handler and the signal / SEH handlers
+/// so that the crash handler is also invoked on non-panic crashes
+pub(crate) fn set_up_crash_handler(handler: CrashHandler) {
+    if CRASH_HANDLER.set(handler).is_err() {
+        return; // already installed
+    }
+    install_native_crash_handlers();
+}
+
+/// Collects the crash information and invokes the crash handler (only once)
+fn invoke_crash_handler(
+    message: String,
+    location: String,
+    backtrace: String,
+    minidump_path: Option<String>,
+) {
+
+    use std::thread;
+
+    let handler = match CRASH_HANDLER.get() {
+        Some(s) => s,
+        None => return,
+    };
+
+    if CRASH_HANDLER_INVOKED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    // try_lock: the crash may have happened while the lock was held
+    let last_log_lines = LAST_LOG_LINES.try_lock()
+        .map(|l| l.iter().map(|s| AzString::from(s.clone())).collect::<Vec<AzString>>())
+        .unwrap_or_default();
+
+    let window_state_summary = WINDOW_STATE_SUMMARY.try_lock()
+        .map(|s| s.clone())
+        .unwrap_or_default();
+
+    let thread = thread::current();
+
+    let crash_info = CrashInfo {
+        message: message.into(),
+        location: location.into(),
+        thread_name: thread.name().unwrap_or("<unnamed thread>").to_string().into(),
+        backtrace: backtrace.into(),
+        last_log_lines: StringVec::from_vec(last_log_lines),
+        window_state_summary: window_state_summary.into(),
+        minidump_path: match minidump_path {
+            Some(s) => OptionAzString::Some(s.into()),
+            None => OptionAzString::None,
+        },
+    };
+
+    (handler.cb)(&crash_info);
+}
+
+#[cfg(unix)]
+fn install_native_crash_handlers() {
+
+    use libc::{c_int, SIGABRT, SIGBUS, SIGFPE, SIGILL, SIGSEGV, SIG_DFL};
+
+    extern "C" fn signal_handler(signal: c_int) {
+
+        let signal_name = match signal {
+            SIGABRT => "SIGABRT",
+            SIGBUS => "SIGBUS",
+            SIGFPE => "SIGFPE",
+            SIGILL => "SIGILL",
+            SIGSEGV => "SIGSEGV",
+            _ => "unknown signal",
+        };
+
+        invoke_crash_handler(
+            format!("The application received the fatal signal {} ({})", signal_name, signal),
+            String::new(),
+            format_backtrace(&Backtrace::new()),
+            None,
+        );
+
+        // restore the default handler and re-raise the signal to terminate the process
+        unsafe {
+            libc::signal(signal, SIG_DFL);
+            libc::raise(signal);
+        }
+    }
+
+    for signal in [SIGABRT, SIGBUS, SIGFPE, SIGILL, SIGSEGV].iter() {
+        unsafe { libc::signal(*signal, signal_handler as libc::sighandler_t); }
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn install_native_crash_handlers() {
+
+    use winapi::um::errhandlingapi::SetUnhandledExceptionFilter;
+    use winapi::um::winnt::{EXCEPTION_POINTERS, LONG};
+
+    /// Returned from the filter so that Windows Error Reporting still runs afterwards
+    const EXCEPTION_CONTINUE_SEARCH: LONG = 0;
+
+    unsafe extern "system" fn exception_filter(exception_info: *mut EXCEPTION_POINTERS) -> LONG {
+
+        let exception_code = exception_info
+            .as_ref()
+            .and_then(|e| e.ExceptionRecord.as_ref())
+            .map(|r| r.ExceptionCode)
+            .unwrap_or(0);
+
+        let minidump_path = write_minidump(exception_info);
+
+        invoke_crash_handler(
+            format!("Unhandled SEH exception 0x{:08x}", exception_code),
+            String::new(),
+            format_backtrace(&Backtrace::new()),
+            minidump_path,
+        );
+
+        EXCEPTION_CONTINUE_SEARCH
+    }
+
+    unsafe { SetUnhandledExceptionFilter(Some(exception_filter)); }
+}
+
+/// Writes a minidump of the current process to the temp directory,
+/// returns the path of the minidump file on success
+#[cfg(target_os = "windows")]
+unsafe fn write_minidump(exception_info: *mut winapi::um::winnt::EXCEPTION_POINTERS) -> Option<String> {
+
+    use std::os::windows::ffi::OsStrExt;
+    use winapi::shared::minwindef::FALSE;
+    use winapi::um::dbghelp::{MiniDumpNormal, MiniDumpWriteDump, MINIDUMP_EXCEPTION_INFORMATION};
+    use winapi::um::fileapi::{CreateFileW, CREATE_ALWAYS};
+    use winapi::um::handleapi::{CloseHandle, INVALID_HANDLE_VALUE};
+    use winapi::um::processthreadsapi::{GetCurrentProcess, GetCurrentProcessId, GetCurrentThreadId};
+    use winapi::um::winnt::{FILE_ATTRIBUTE_NORMAL, GENERIC_WRITE};
+
+    let path = std::env::temp_dir().join(format!("azul-crash-{}.dmp", GetCurrentProcessId()));
+    let mut path_wide = path.as_os_str().encode_wide().collect::<Vec<u16>>();
+    path_wide.push(0);
+
+    let file = CreateFileW(
+        path_wide.as_ptr(),
+        GENERIC_WRITE,
+        0,
+        core::ptr::null_mut(),
+        CREATE_ALWAYS,
+        FILE_ATTRIBUTE_NORMAL,
+        core::ptr::null_mut(),
+    );
+
+    if file == INVALID_HANDLE_VALUE {
+        return None;
+    }
+
+    let mut exception_information = MINIDUMP_EXCEPTION_INFORMATION {
+        ThreadId: GetCurrentThreadId(),
+        ExceptionPointers: exception_info,
+        ClientPointers: FALSE,
+    };
+
+    let result = MiniDumpWriteDump(
+        GetCurrentProcess(),
+        GetCurrentProcessId(),
+        file,
+        MiniDumpNormal,
+        &mut exception_information,
+        core::ptr::null_mut(),
+        core::ptr::null_mut(),
+    );
+
+    CloseHandle(file);
+
+    if result == FALSE {
+        None
+    } else {
+        Some(path.to_string_lossy().to_string())
+    }
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+fn install_native_crash_handlers() { }
+
 /// In the (rare) case of a panic, print it to the stdout, log it to the file and
 /// prompt the user with a message box.
 pub(crate) fn set_up_panic_hooks() {
 
     use std::panic::{self, PanicInfo};
-    use backtrace::{Backtrace, BacktraceFrame};
 
     fn panic_fn(panic_info: &PanicInfo) {
 
@@ -84,7 +443,7 @@ pub(crate) fn set_up_panic_hooks() {
              Backtrace:\r\n\
              \r\n\
              {}\r\n",
-            location_str.unwrap_or(format!("<unknown location>")), thread_name, panic_str, backtrace_str);
+            location_str.clone().unwrap_or(format!("<unknown location>")), thread_name, panic_str, backtrace_str);
 
         #[cfg(target_os = "linux")]
         let mut error_str_clone = error_str.clone();
@@ -93,9 +452,15 @@ pub(crate) fn set_up_panic_hooks() {
             error_str_clone = error_str_clone.replace(">", "&gt;");
         }
 
-        // TODO: invoke external app crash handler with the location to the log file
         error!("{}", error_str);
 
+        invoke_crash_handler(
+            panic_str.to_string(),
+            location_str.unwrap_or_default(),
+            backtrace_str.clone(),
+            None,
+        );
+
         if SHOULD_ENABLE_PANIC_HOOK.load(Ordering::SeqCst) {
             #[cfg(not(target_os = "linux"))]
             msg_box_ok("Unexpected fatal error", &error_str, ::tinyfiledialogs::MessageBoxIcon::Error);
@@ -104,68 +469,68 @@ pub(crate) fn set_up_panic_hooks() {
         }
     }
 
-    fn format_backtrace(backtrace: &Backtrace) -> String {
+    panic::set_hook(Box::new(panic_fn));
+}
+
+fn format_backtrace(backtrace: &Backtrace) -> String {
 
-        fn format_frame(frame: &BacktraceFrame) -> String {
+    fn format_frame(frame: &BacktraceFrame) -> String {
 
-            use std::ffi::OsStr;
+        use std::ffi::OsStr;
 
-            let ip = frame.ip();
-            let symbols = frame.symbols();
+        let ip = frame.ip();
+        let symbols = frame.symbols();
 
-            const UNRESOLVED_FN_STR: &str = "unresolved function";
+        const UNRESOLVED_FN_STR: &str = "unresolved function";
 
-            if symbols.is_empty() {
-                return format!("{} @ {:?}", UNRESOLVED_FN_STR, ip);
-            }
+        if symbols.is_empty() {
+            return format!("{} @ {:?}", UNRESOLVED_FN_STR, ip);
+        }
 
-            // skip the first 10 symbols because they belong to the
-            // backtrace library and aren't relevant for debugging
-            symbols.iter().map(|symbol| {
+        // skip the first 10 symbols because they belong to the
+        // backtrace library and aren't relevant for debugging
+        symbols.iter().map(|symbol| {
 
-                let mut nice_string = String::new();
+            let mut nice_string = String::new();
 
-                if let Some(name) = symbol.name() {
-                    let name_demangled = format!("{}", name);
-                    let name_demangled_new = name_demangled.rsplit("::").skip(1).map(|e| e.to_string()).collect::<Vec<String>>();
-                    let name_demangled = name_demangled_new.into_iter().rev().collect::<Vec<String>>().join("::");
-                    nice_string.push_str(&name_demangled);
-                } else {
-                    nice_string.push_str(UNRESOLVED_FN_STR);
-                }
+            if let Some(name) = symbol.name() {
+                let name_demangled = format!("{}", name);
+                let name_demangled_new = name_demangled.rsplit("::").skip(1).map(|e| e.to_string()).collect::<Vec<String>>();
+                let name_demangled = name_demangled_new.into_iter().rev().collect::<Vec<String>>().join("::");
+                nice_string.push_str(&name_demangled);
+            } else {
+                nice_string.push_str(UNRESOLVED_FN_STR);
+            }
 
-                let mut file_string = String::new();
-                if let Some(file) = symbol.filename() {
-                    let origin_file_name = file.file_name()
-                        .unwrap_or(OsStr::new("unresolved file name"))
-                        .to_string_lossy();
-                    file_string.push_str(&format!("{}", origin_file_name));
-                }
+            let mut file_string = String::new();
+            if let Some(file) = symbol.filename() {
+                let origin_file_name = file.file_name()
+                    .unwrap_or(OsStr::new("unresolved file name"))
+                    .to_string_lossy();
+                file_string.push_str(&format!("{}", origin_file_name));
+            }
 
-                if let Some(line) = symbol.lineno() {
-                    file_string.push_str(&format!(":{}", line));
-                }
+            if let Some(line) = symbol.lineno() {
+                file_string.push_str(&format!(":{}", line));
+            }
 
-                if !file_string.is_empty() {
-                    nice_string.push_str(" @ ");
-                    nice_string.push_str(&file_string);
-                    if !nice_string.ends_with("\n") {
-                        nice_string.push_str("\n");
-                    }
+            if !file_string.is_empty() {
+                nice_string.push_str(" @ ");
+                nice_string.push_str(&file_string);
+                if !nice_string.ends_with("\n") {
+                    nice_string.push_str("\n");
                 }
+            }
 
-                nice_string
-
-            }).collect::<Vec<String>>().join("")
-        }
+            nice_string
 
-        backtrace
-            .frames()
-            .iter()
-            .map(|frame| format_frame(frame))
-            .collect::<Vec<String>>()
-            .join("\r\n")
+        }).collect::<Vec<String>>().join("")
     }
 
-    panic::set_hook(Box::new(panic_fn));
-}
\ No newline at end of file
+    backtrace
+        .frames()
+        .iter()
+        .map(|frame| format_frame(frame))
+        .collect::<Vec<String>>()
+        .join("\r\n")
+}
@@ -0,0 +1,320 @@
+//! Sandboxed user scripts (rhai, requires the `scripting` feature), i.e. for plugins
+//! or themes of user-extensible applications
+//!
+//! Scripts can only build DOM fragments and react to events through a small API,
+//! they have no access to the file system, to modules or to the application data:
+//!
+//! ```rhai
+//! fn init() { this.count = 0; }
+//!
+//! fn layout() {
+//!     div().class("counter")
+//!         .child(text(`clicked ${this.count} times`))
+//!         .child(div().id("button").child(text("+1")).on("click", "increment"))
+//! }
+//!
+//! // returning true re-renders the DOM
+//! fn increment() { this.count += 1; true }
+//! ```
+//!
+//! Inside of script functions, `this` is the state of the script (an object map that
+//! is shared by all functions of the script). The node API consists of `div()`,
+//! `body()`, `text(string)` and the builder methods `child(node)`, `id(string)`,
+//! `class(string)`, `style(css)` and `on(event, function_name)`.
+//!
+//! Scripts are limited in the number of operations, the call depth and the size of
+//! strings / arrays / maps, so that a faulty script can't hang the application.
+
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use std::sync::Mutex;
+use azul_core::callbacks::{CallbackInfo, RefAny, Update};
+use azul_core::dom::{Dom, EventFilter, On};
+use rhai::{CallFnOptions, Dynamic, Engine, EvalAltResult, ImmutableString, Map, Scope, AST};
+
+/// Maximum number of operations of a single call into the script
+const MAX_OPERATIONS: u64 = 1_000_000;
+const MAX_CALL_LEVELS: usize = 64;
+const MAX_EXPR_DEPTH: usize = 64;
+const MAX_STRING_SIZE: usize = 1 << 20;
+const MAX_ARRAY_SIZE: usize = 1 << 16;
+const MAX_MAP_SIZE: usize = 1 << 16;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptError {
+    /// Syntax error in the script source
+    Compile(String),
+    /// Error while running a script function (including exceeded limits)
+    Runtime(String),
+    /// The layout function did not return a node
+    NotANode(String),
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::ScriptError::*;
+        match self {
+            Compile(e) => write!(f, "script does not compile: {}", e),
+            Runtime(e) => write!(f, "script error: {}", e),
+            NotANode(t) => write!(f, "script returned {} instead of a node", t),
+        }
+    }
+}
+
+/// Compiled script + its state, cloning the script shares the state
+#[derive(Clone)]
+pub struct Script {
+    inner: Arc<ScriptInner>,
+}
+
+struct ScriptInner {
+    engine: Engine,
+    ast: AST,
+    /// Value of `this` in the script functions
+    state: Mutex<Dynamic>,
+}
+
+impl fmt::Debug for Script {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let functions = self.inner.ast.iter_functions().map(|f| f.name).collect::<Vec<_>>();
+        write!(f, "Script {{ functions: {:?} }}", functions)
+    }
+}
+
+impl Script {
+    /// Compiles the script and calls its `init()` function (if it has one)
+    pub fn new(source: &str) -> Result<Self, ScriptError> {
+        let engine = create_engine();
+        let ast = engine
+            .compile(source)
+            .map_err(|e| ScriptError::Compile(e.to_string()))?;
+
+        let has_init = ast.iter_functions().any(|f| f.name == "init" && f.params.is_empty());
+
+        let script = Self {
+            inner: Arc::new(ScriptInner {
+                engine,
+                ast,
+                state: Mutex::new(Dynamic::from_map(Map::new())),
+            }),
+        };
+
+        if has_init {
+            script.call("init")?;
+        }
+
+        Ok(script)
+    }
+
+    /// Calls the script function `fn_name` (without arguments), which has to
+    /// return a node, and converts the node into a DOM fragment
+    pub fn layout(&self, fn_name: &str) -> Result<Dom, ScriptError> {
+        let result = self.call(fn_name)?;
+        let type_name = result.type_name().to_string();
+        let node = result
+            .try_cast::<ScriptNode>()
+            .ok_or(ScriptError::NotANode(type_name))?;
+        Ok(node.into_dom(self))
+    }
+
+    /// Calls the script function `fn_name` without arguments, with `this` bound to the script state
+    pub fn call(&self, fn_name: &str) -> Result<Dynamic, ScriptError> {
+        let mut state = self
+            .inner
+            .state
+            .lock()
+            .map_err(|_| ScriptError::Runtime(String::from("script state is poisoned")))?;
+        let options = CallFnOptions::new().eval_ast(false).bind_this_ptr(&mut *state);
+        self.inner
+            .engine
+            .call_fn_with_options::<Dynamic>(options, &mut Scope::new(), &self.inner.ast, fn_name, ())
+            .map_err(|e| ScriptError::Runtime(e.to_string()))
+    }
+}
+
+/// Node that is built by a script, callbacks are only attached when
+/// the node is converted into a `Dom` (since they refer to the script)
+#[derive(Clone)]
+struct ScriptNode {
+    node: Dom,
+    children: Vec<ScriptNode>,
+    callbacks: Vec<(EventFilter, String)>,
+}
+
+impl ScriptNode {
+    fn new(node: Dom) -> Self {
+        Self {
+            node,
+            children: Vec::new(),
+            callbacks: Vec::new(),
+        }
+    }
+
+    fn into_dom(self, script: &Script) -> Dom {
+        let mut dom = self.node;
+        for (event, fn_name) in self.callbacks {
+            dom.root.add_callback(
+                event,
+                RefAny::new(ScriptCallback {
+                    script: script.clone(),
+                    fn_name,
+                }),
+                invoke_script_callback,
+            );
+        }
+        for child in self.children {
+            dom.add_child(child.into_dom(script));
+        }
+        dom
+    }
+}
+
+struct ScriptCallback {
+    script: Script,
+    fn_name: String,
+}
+
+extern "C" fn invoke_script_callback(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+    let data = match data.downcast_ref::<ScriptCallback>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    match data.script.call(&data.fn_name) {
+        Ok(result) if result.as_bool().unwrap_or(false) => Update::RefreshDom,
+        Ok(_) => Update::DoNothing,
+        Err(_e) => {
+            #[cfg(feature = "logging")] {
+                error!("{}", _e);
+            }
+            Update::DoNothing
+        }
+    }
+}
+
+/// Maps the event names of `node.on(event, function_name)` to event filters
+fn parse_event(name: &str) -> Option<EventFilter> {
+    let on = match name {
+        "click" | "mouseup" => On::MouseUp,
+        "mousedown" => On::MouseDown,
+        "rightclick" => On::RightMouseUp,
+        "mouseover" => On::MouseOver,
+        "mouseenter" => On::MouseEnter,
+        "mouseleave" => On::MouseLeave,
+        "scroll" => On::Scroll,
+        "textinput" => On::TextInput,
+        "keydown" => On::VirtualKeyDown,
+        "keyup" => On::VirtualKeyUp,
+        "focus" => On::FocusReceived,
+        "blur" => On::FocusLost,
+        _ => return None,
+    };
+    Some(on.into())
+}
+
+fn create_engine() -> Engine {
+    use rhai::module_resolvers::DummyModuleResolver;
+
+    let mut engine = Engine::new();
+
+    // sandbox: no imports, no eval, bounded resource usage
+    engine.set_module_resolver(DummyModuleResolver::new());
+    engine.disable_symbol("eval");
+    engine.set_max_operations(MAX_OPERATIONS);
+    engine.set_max_call_levels(MAX_CALL_LEVELS);
+    engine.set_max_expr_depths(MAX_EXPR_DEPTH, MAX_EXPR_DEPTH);
+    engine.set_max_string_size(MAX_STRING_SIZE);
+    engine.set_max_array_size(MAX_ARRAY_SIZE);
+    engine.set_max_map_size(MAX_MAP_SIZE);
+
+    engine.register_type_with_name::<ScriptNode>("Node");
+    engine.register_fn("div", || ScriptNode::new(Dom::div()));
+    engine.register_fn("body", || ScriptNode::new(Dom::body()));
+    engine.register_fn("text", |s: ImmutableString| ScriptNode::new(Dom::text(s.to_string())));
+    engine.register_fn("child", |mut node: ScriptNode, child: ScriptNode| {
+        node.children.push(child);
+        node
+    });
+    engine.register_fn("id", |mut node: ScriptNode, id: ImmutableString| {
+        node.node.root.add_id(id.to_string().into());
+        node
+    });
+    engine.register_fn("class", |mut node: ScriptNode, class: ImmutableString| {
+        node.node.root.add_class(class.to_string().into());
+        node
+    });
+    engine.register_fn("style", |mut node: ScriptNode, css: ImmutableString| {
+        node.node.set_inline_style(css.as_str());
+        node
+    });
+    engine.register_fn(
+        "on",
+        |mut node: ScriptNode, event: ImmutableString, fn_name: ImmutableString| -> Result<ScriptNode, Box<EvalAltResult>> {
+            let event = parse_event(event.as_str())
+                .ok_or_else(|| format!("unknown event \"{}\"", event))?;
+            node.callbacks.push((event, fn_name.to_string()));
+            Ok(node)
+        },
+    );
+
+    engine
+}
+
+#[cfg(all(test, feature = "scripting"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exceeding_max_operations_is_a_runtime_error() {
+        let script = Script::new("fn spin() { let x = 0; loop { x += 1; } }").unwrap();
+        match script.call("spin") {
+            Err(ScriptError::Runtime(_)) => { },
+            other => panic!("expected a runtime error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn import_is_rejected() {
+        let result = Script::new(r#"fn load() { import "std" as s; 1 }"#)
+            .and_then(|script| script.call("load"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn eval_is_rejected() {
+        let result = Script::new(r#"fn run() { eval("1 + 1") }"#)
+            .and_then(|script| script.call("run"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn layout_builds_dom() {
+        let script = Script::new(r#"
+            fn layout() {
+                div().class("counter")
+                    .child(text("hello"))
+                    .child(div().id("button"))
+            }
+        "#).unwrap();
+
+        let mut expected = Dom::div();
+        expected.root.add_class("counter".into());
+        expected.add_child(Dom::text("hello"));
+        let mut button = Dom::div();
+        button.root.add_id("button".into());
+        expected.add_child(button);
+
+        assert_eq!(script.layout("layout").unwrap(), expected);
+    }
+
+    #[test]
+    fn layout_returning_no_node_is_rejected() {
+        let script = Script::new("fn layout() { 5 }").unwrap();
+        match script.layout("layout") {
+            Err(ScriptError::NotANode(_)) => { },
+            other => panic!("expected NotANode, got {:?}", other),
+        }
+    }
+}
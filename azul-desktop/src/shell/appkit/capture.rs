@@ -0,0 +1,211 @@
+//! Screen capture via CoreGraphics (`CGWindowListCreateImage`)
+//!
+//! Requires the screen recording permission on macOS 10.15 or later, without the
+//! permission only the desktop background and the windows of the application are captured.
+
+use core::ffi::c_void;
+use core_foundation::base::CFRelease;
+use core_foundation::data::{CFDataGetBytePtr, CFDataGetLength, CFDataRef};
+use objc2::foundation::NSInteger;
+use objc2::msg_send;
+use objc2::runtime::Object;
+use azul_core::window::RawWindowHandle;
+use crate::capture::{CapturedImage, CaptureError, CaptureSource};
+
+type CGImageRef = *mut c_void;
+type CGDataProviderRef = *mut c_void;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct CGPoint {
+    x: f64,
+    y: f64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct CGSize {
+    width: f64,
+    height: f64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct CGRect {
+    origin: CGPoint,
+    size: CGSize,
+}
+
+/// `CGRectNull`: the bounds of the window are used
+const CG_RECT_NULL: CGRect = CGRect {
+    origin: CGPoint { x: f64::INFINITY, y: f64::INFINITY },
+    size: CGSize { width: 0.0, height: 0.0 },
+};
+
+// kCGWindowListOptionOnScreenOnly
+const CG_WINDOW_LIST_OPTION_ON_SCREEN_ONLY: u32 = 1 << 0;
+// kCGWindowListOptionIncludingWindow
+const CG_WINDOW_LIST_OPTION_INCLUDING_WINDOW: u32 = 1 << 3;
+// kCGNullWindowID
+const CG_NULL_WINDOW_ID: u32 = 0;
+// kCGWindowImageDefault
+const CG_WINDOW_IMAGE_DEFAULT: u32 = 0;
+// kCGWindowImageBoundsIgnoreFraming
+const CG_WINDOW_IMAGE_BOUNDS_IGNORE_FRAMING: u32 = 1 << 0;
+
+// CGBitmapInfo
+const CG_BITMAP_ALPHA_INFO_MASK: u32 = 0x1F;
+const CG_BITMAP_BYTE_ORDER_MASK: u32 = 0x7000;
+const CG_BITMAP_BYTE_ORDER_32_LITTLE: u32 = 2 << 12;
+// CGImageAlphaInfo
+const CG_IMAGE_ALPHA_PREMULTIPLIED_LAST: u32 = 1;
+const CG_IMAGE_ALPHA_PREMULTIPLIED_FIRST: u32 = 2;
+const CG_IMAGE_ALPHA_LAST: u32 = 3;
+const CG_IMAGE_ALPHA_FIRST: u32 = 4;
+const CG_IMAGE_ALPHA_NONE_SKIP_LAST: u32 = 5;
+const CG_IMAGE_ALPHA_NONE_SKIP_FIRST: u32 = 6;
+
+#[link(name = "CoreGraphics", kind = "framework")]
+extern "C" {
+    fn CGWindowListCreateImage(screen_bounds: CGRect, list_option: u32, window_id: u32, image_option: u32) -> CGImageRef;
+    fn CGImageGetWidth(image: CGImageRef) -> usize;
+    fn CGImageGetHeight(image: CGImageRef) -> usize;
+    fn CGImageGetBytesPerRow(image: CGImageRef) -> usize;
+    fn CGImageGetBitsPerPixel(image: CGImageRef) -> usize;
+    fn CGImageGetBitmapInfo(image: CGImageRef) -> u32;
+    fn CGImageGetDataProvider(image: CGImageRef) -> CGDataProviderRef;
+    fn CGDataProviderCopyData(provider: CGDataProviderRef) -> CFDataRef;
+}
+
+type CGPreflightScreenCaptureAccessFuncType = extern "C" fn() -> bool;
+
+/// Returns false if the application does not have the screen recording permission
+/// (`CGPreflightScreenCaptureAccess` is only available on macOS 10.15 or later)
+fn has_screen_capture_access() -> bool {
+    let symbol = unsafe { libc::dlsym(libc::RTLD_DEFAULT, b"CGPreflightScreenCaptureAccess\0".as_ptr() as *const _) };
+    if symbol.is_null() {
+        return true;
+    }
+    let preflight: CGPreflightScreenCaptureAccessFuncType = unsafe { core::mem::transmute(symbol) };
+    (preflight)()
+}
+
+pub(crate) fn capture(source: &CaptureSource) -> Result<CapturedImage, CaptureError> {
+
+    if !has_screen_capture_access() {
+        return Err(CaptureError::PermissionDenied);
+    }
+
+    let image = match source {
+        CaptureSource::Region { origin, size } => {
+            // global display coordinates in points, origin at the top left of the main display
+            let bounds = CGRect {
+                origin: CGPoint { x: origin.x as f64, y: origin.y as f64 },
+                size: CGSize { width: size.width as f64, height: size.height as f64 },
+            };
+            unsafe {
+                CGWindowListCreateImage(
+                    bounds,
+                    CG_WINDOW_LIST_OPTION_ON_SCREEN_ONLY,
+                    CG_NULL_WINDOW_ID,
+                    CG_WINDOW_IMAGE_DEFAULT,
+                )
+            }
+        },
+        CaptureSource::Window(RawWindowHandle::MacOS(handle)) => {
+            if handle.ns_window.is_null() {
+                return Err(CaptureError::InvalidSource);
+            }
+            let window_number: NSInteger = unsafe {
+                let ns_window = &*(handle.ns_window as *const Object);
+                msg_send![ns_window, windowNumber]
+            };
+            if window_number <= 0 {
+                return Err(CaptureError::InvalidSource);
+            }
+            unsafe {
+                CGWindowListCreateImage(
+                    CG_RECT_NULL,
+                    CG_WINDOW_LIST_OPTION_INCLUDING_WINDOW,
+                    window_number as u32,
+                    CG_WINDOW_IMAGE_BOUNDS_IGNORE_FRAMING,
+                )
+            }
+        },
+        CaptureSource::Window(_) => return Err(CaptureError::InvalidSource),
+    };
+
+    if image.is_null() {
+        return Err(CaptureError::Platform(String::from("CGWindowListCreateImage failed")));
+    }
+
+    let result = unsafe { copy_cgimage(image) };
+    unsafe { CFRelease(image as *const _); }
+    result
+}
+
+/// Converts the pixels of a 32-bit CGImage into (non-premultiplied) BGRA8
+unsafe fn copy_cgimage(image: CGImageRef) -> Result<CapturedImage, CaptureError> {
+
+    let width = CGImageGetWidth(image);
+    let height = CGImageGetHeight(image);
+    let row_pitch = CGImageGetBytesPerRow(image);
+    let bitmap_info = CGImageGetBitmapInfo(image);
+
+    if CGImageGetBitsPerPixel(image) != 32 {
+        return Err(CaptureError::Platform(format!("unsupported pixel format ({} bits per pixel)", CGImageGetBitsPerPixel(image))));
+    }
+
+    let data = CGDataProviderCopyData(CGImageGetDataProvider(image));
+    if data.is_null() {
+        return Err(CaptureError::Platform(String::from("CGDataProviderCopyData failed")));
+    }
+
+    let len = (CFDataGetLength(data).max(0) as usize).min(row_pitch * height);
+    let bytes = core::slice::from_raw_parts(CFDataGetBytePtr(data), len);
+
+    let alpha_info = bitmap_info & CG_BITMAP_ALPHA_INFO_MASK;
+    let little_endian = bitmap_info & CG_BITMAP_BYTE_ORDER_MASK == CG_BITMAP_BYTE_ORDER_32_LITTLE;
+    let alpha_first = match alpha_info {
+        CG_IMAGE_ALPHA_PREMULTIPLIED_FIRST | CG_IMAGE_ALPHA_FIRST | CG_IMAGE_ALPHA_NONE_SKIP_FIRST => true,
+        _ => false,
+    };
+    let has_alpha = match alpha_info {
+        CG_IMAGE_ALPHA_PREMULTIPLIED_LAST | CG_IMAGE_ALPHA_PREMULTIPLIED_FIRST |
+        CG_IMAGE_ALPHA_LAST | CG_IMAGE_ALPHA_FIRST => true,
+        _ => false,
+    };
+    let premultiplied = alpha_info == CG_IMAGE_ALPHA_PREMULTIPLIED_FIRST || alpha_info == CG_IMAGE_ALPHA_PREMULTIPLIED_LAST;
+
+    // byte indices of (b, g, r, a) in memory
+    let order = match (little_endian, alpha_first) {
+        (true, true) => [0, 1, 2, 3],   // BGRA
+        (true, false) => [1, 2, 3, 0],  // ABGR
+        (false, true) => [3, 2, 1, 0],  // ARGB
+        (false, false) => [2, 1, 0, 3], // RGBA
+    };
+
+    let mut captured = CapturedImage::new(width, height);
+
+    for (y, row) in bytes.chunks(row_pitch.max(1)).take(height).enumerate() {
+        for (x, px) in row.chunks_exact(4).take(width).enumerate() {
+            let a = if has_alpha { px[order[3]] } else { 0xFF };
+            let unpremultiply = |c: u8| -> u8 {
+                if premultiplied && a != 0 && a != 0xFF {
+                    ((c as u32 * 255 + a as u32 / 2) / a as u32).min(255) as u8
+                } else {
+                    c
+                }
+            };
+            let dst = (y * width + x) * 4;
+            captured.pixels[dst] = unpremultiply(px[order[0]]);
+            captured.pixels[dst + 1] = unpremultiply(px[order[1]]);
+            captured.pixels[dst + 2] = unpremultiply(px[order[2]]);
+            captured.pixels[dst + 3] = a;
+        }
+    }
+
+    CFRelease(data as *const _);
+
+    Ok(captured)
+}
@@ -26,7 +26,7 @@ use azul_core::{
         DomNodeId, DocumentId
     },
     gl::OptionGlContextPtr,
-    task::{Thread, ThreadId, Timer, TimerId},
+    task::{OptionDuration, Thread, ThreadId, Timer, TimerId},
     ui_solver::LayoutResult,
     styled_dom::DomId,
     dom::NodeId,
@@ -35,7 +35,7 @@ use azul_core::{
         LogicalSize, Menu, MenuCallback, MenuItem,
         MonitorVec, WindowCreateOptions, WindowInternal,
         WindowState, FullWindowState, ScrollResult,
-        MouseCursorType, CallCallbacksResult
+        MouseCursorType, CallCallbacksResult, ShortcutMap
     },
     window_state::NodesToCheck,
 };
@@ -78,6 +78,9 @@ use raw_window_handle::{AppKitDisplayHandle, RawDisplayHandle};
 // Copied from rust-windowing/winit @ 249609889029af3e5ec19afbd02464e11a265378
 // because we need to inline a lot of dependencies for macOS such as glutin / glium
 pub mod winit;
+pub(crate) mod spellcheck;
+#[cfg(feature = "capture")]
+pub(crate) mod capture;
 
 use self::winit::appkit::{NSApp, NSApplicationActivationPolicy, NSEvent};
 
@@ -109,12 +112,41 @@ pub enum CocoaStartupError {
     BorrowMut(BorrowMutError),
     Create(CocoaWindowCreateError),
     Gl(CocoaOpenGlError),
+    /// `WindowCreateOptions::hot_reload` is set, but the macOS event
+    /// loop can't re-run the layout callback periodically yet
+    HotReloadNotSupported,
 }
 
 pub fn get_monitors(app: &App) -> MonitorVec {
     MonitorVec::from_const_slice(&[]) // TODO
 }
 
+#[link(name = "CoreGraphics", kind = "framework")]
+extern "C" {
+    fn CGEventSourceSecondsSinceLastEventType(source_state: i32, event_type: u32) -> f64;
+}
+
+// kCGEventSourceStateCombinedSessionState
+const CG_EVENT_SOURCE_STATE_COMBINED_SESSION_STATE: i32 = 0;
+// kCGAnyInputEventType
+const CG_ANY_INPUT_EVENT_TYPE: u32 = !0;
+
+/// Returns the time since the last keyboard / mouse input in the current login session
+///
+/// NOTE: not polled yet - the appkit event loop doesn't emit `On::UserIdle` / `On::UserActive`
+pub extern "C" fn get_system_idle_time() -> OptionDuration {
+    let secs = unsafe {
+        CGEventSourceSecondsSinceLastEventType(
+            CG_EVENT_SOURCE_STATE_COMBINED_SESSION_STATE,
+            CG_ANY_INPUT_EVENT_TYPE
+        )
+    };
+    if !secs.is_finite() || secs < 0.0 {
+        return OptionDuration::None;
+    }
+    OptionDuration::Some(std::time::Duration::from_secs_f64(secs).into())
+}
+
 
 pub struct EventLoop<T: 'static> {
     /// The delegate is only weakly referenced by NSApplication, so we keep
@@ -134,7 +166,20 @@ pub struct EventLoop<T: 'static> {
 }
 
 /// Main function that starts when app.run() is invoked
-pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, CocoaStartupError> {
+pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, CocoaStartupError> {
+
+    // TODO: the macOS event loop doesn't re-run the layout callback yet
+    if root_window.hot_reload || app.windows.iter().any(|w| w.hot_reload) {
+        return Err(CocoaStartupError::HotReloadNotSupported);
+    }
+
+    // TODO: the macOS event loop doesn't dispatch keyboard events yet
+    if !root_window.shortcuts.shortcuts.is_empty() {
+        #[cfg(feature = "logging")] {
+            warn!("WindowCreateOptions::shortcuts are not supported on macOS yet, ignoring them");
+        }
+        root_window.shortcuts = ShortcutMap::default();
+    }
 
     // This must be done before `NSApp()` (equivalent to sending
     // `sharedApplication`) is called anywhere else, or we'll end up
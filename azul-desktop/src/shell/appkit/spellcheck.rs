@@ -0,0 +1,84 @@
+//! System spellchecker via `NSSpellChecker`
+
+use std::sync::Mutex;
+use objc2::foundation::{NSInteger, NSNotFound, NSRange, NSString, NSUInteger};
+use objc2::rc::autoreleasepool;
+use crate::spellcheck::{utf16_range_to_misspelling, Misspelling, SpellcheckProvider};
+use super::winit::appkit::NSSpellChecker;
+
+/// The shared `NSSpellChecker` has a single current language,
+/// so only one provider may use it at the same time
+static SHARED_SPELL_CHECKER_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug)]
+pub(crate) struct MacSpellChecker {
+    language: String,
+}
+
+impl MacSpellChecker {
+
+    /// Returns `None` if there is no dictionary for the language (i.e. `"en-US"`, the
+    /// spellchecker also accepts the `"en_US"` form)
+    pub(crate) fn new(language: &str) -> Option<Self> {
+        let language = language.replace('-', "_");
+        let _lock = SHARED_SPELL_CHECKER_LOCK.lock().ok()?;
+        let supported = autoreleasepool(|_| {
+            NSSpellChecker::sharedSpellChecker().setLanguage(&NSString::from_str(&language))
+        });
+        if supported { Some(Self { language }) } else { None }
+    }
+}
+
+impl SpellcheckProvider for MacSpellChecker {
+
+    fn check(&self, text: &str) -> Vec<Misspelling> {
+
+        let _lock = match SHARED_SPELL_CHECKER_LOCK.lock() {
+            Ok(o) => o,
+            Err(_) => return Vec::new(),
+        };
+
+        autoreleasepool(|_| {
+
+            let spell_checker = NSSpellChecker::sharedSpellChecker();
+            if !spell_checker.setLanguage(&NSString::from_str(&self.language)) {
+                return Vec::new();
+            }
+
+            let string = NSString::from_str(text);
+            let mut misspellings = Vec::new();
+            let mut offset: NSUInteger = 0;
+
+            loop {
+                // ranges are in UTF-16 code units, the search wraps around at the end of the string
+                let range = spell_checker.checkSpellingOfString_startingAt(&string, offset as NSInteger);
+                if range.location == NSNotFound as NSUInteger || range.length == 0 || range.location < offset {
+                    break;
+                }
+                misspellings.push(utf16_range_to_misspelling(text, range.location, range.length));
+                offset = range.location + range.length;
+            }
+
+            misspellings
+        })
+    }
+
+    fn suggest(&self, word: &str) -> Vec<String> {
+
+        let _lock = match SHARED_SPELL_CHECKER_LOCK.lock() {
+            Ok(o) => o,
+            Err(_) => return Vec::new(),
+        };
+
+        autoreleasepool(|_| {
+            let string = NSString::from_str(word);
+            let range = NSRange { location: 0, length: word.encode_utf16().count() };
+            let language = NSString::from_str(&self.language);
+
+            NSSpellChecker::sharedSpellChecker()
+            .guessesForWordRange_inString_language_inSpellDocumentWithTag(range, &string, Some(&language), 0)
+            .map(|guesses| guesses.iter().map(|s| s.to_string()).collect())
+            .unwrap_or_default()
+        })
+    }
+}
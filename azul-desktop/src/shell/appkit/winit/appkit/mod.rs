@@ -24,6 +24,7 @@ mod menu_item;
 mod pasteboard;
 mod responder;
 mod screen;
+mod spell_checker;
 mod text_input_context;
 mod version;
 mod view;
@@ -49,6 +50,7 @@ pub(crate) use self::pasteboard::{NSFilenamesPboardType, NSPasteboard, NSPastebo
 pub(crate) use self::responder::NSResponder;
 #[allow(unused_imports)]
 pub(crate) use self::screen::{NSDeviceDescriptionKey, NSScreen};
+pub(crate) use self::spell_checker::NSSpellChecker;
 pub(crate) use self::text_input_context::NSTextInputContext;
 pub(crate) use self::version::NSAppKitVersion;
 pub(crate) use self::view::{NSTrackingRectTag, NSView};
@@ -0,0 +1,48 @@
+use objc2::foundation::{NSArray, NSInteger, NSObject, NSRange, NSString};
+use objc2::rc::{Id, Shared};
+use objc2::{extern_class, extern_methods, msg_send_id, ClassType};
+
+extern_class!(
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    pub(crate) struct NSSpellChecker;
+
+    unsafe impl ClassType for NSSpellChecker {
+        type Super = NSObject;
+    }
+);
+
+extern_methods!(
+    unsafe impl NSSpellChecker {
+        pub fn sharedSpellChecker() -> Id<Self, Shared> {
+            unsafe { msg_send_id![Self::class(), sharedSpellChecker] }
+        }
+
+        #[sel(setLanguage:)]
+        pub fn setLanguage(&self, language: &NSString) -> bool;
+
+        #[sel(checkSpellingOfString:startingAt:)]
+        pub fn checkSpellingOfString_startingAt(
+            &self,
+            string: &NSString,
+            starting_offset: NSInteger,
+        ) -> NSRange;
+
+        pub fn guessesForWordRange_inString_language_inSpellDocumentWithTag(
+            &self,
+            range: NSRange,
+            string: &NSString,
+            language: Option<&NSString>,
+            tag: NSInteger,
+        ) -> Option<Id<NSArray<NSString>, Shared>> {
+            unsafe {
+                msg_send_id![
+                    self,
+                    guessesForWordRange: range,
+                    inString: string,
+                    language: language,
+                    inSpellDocumentWithTag: tag,
+                ]
+            }
+        }
+    }
+);
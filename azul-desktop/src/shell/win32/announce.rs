@@ -0,0 +1,143 @@
+#![allow(non_snake_case)]
+
+//! Screen reader announcements via UI Automation notifications
+//! (`UiaRaiseNotificationEvent`, Windows 10 1709 or later)
+
+use std::mem;
+use std::ffi::c_void;
+use std::ptr;
+use winapi::shared::minwindef::HINSTANCE;
+use winapi::shared::ntdef::HRESULT;
+use winapi::shared::windef::HWND;
+use winapi::shared::winerror::S_OK;
+use azul_core::window::{Announcement, Politeness};
+
+type BSTR = *mut u16;
+
+pub type UiaHostProviderFromHwnd =
+    unsafe extern "system" fn(hwnd: HWND, provider: *mut *mut c_void) -> HRESULT;
+pub type UiaRaiseNotificationEvent = unsafe extern "system" fn(
+    provider: *mut c_void,
+    notification_kind: i32,
+    notification_processing: i32,
+    display_string: BSTR,
+    activity_id: BSTR,
+) -> HRESULT;
+pub type SysAllocString = unsafe extern "system" fn(psz: *const u16) -> BSTR;
+pub type SysFreeString = unsafe extern "system" fn(bstr: BSTR);
+
+// NotificationKind_Other
+const NOTIFICATION_KIND_OTHER: i32 = 4;
+// NotificationProcessing_ImportantAll
+const NOTIFICATION_PROCESSING_IMPORTANT_ALL: i32 = 0;
+// NotificationProcessing_All
+const NOTIFICATION_PROCESSING_ALL: i32 = 2;
+
+#[repr(C)]
+struct IUnknownVtbl {
+    QueryInterface: *const c_void,
+    AddRef: *const c_void,
+    Release: unsafe extern "system" fn(this: *mut c_void) -> u32,
+}
+
+#[derive(Debug)]
+pub struct UiaFunctions {
+    uiautomationcore_dll_handle: Option<HINSTANCE>,
+    oleaut32_dll_handle: Option<HINSTANCE>,
+    uia_host_provider_from_hwnd: Option<UiaHostProviderFromHwnd>,
+    uia_raise_notification_event: Option<UiaRaiseNotificationEvent>,
+    sys_alloc_string: Option<SysAllocString>,
+    sys_free_string: Option<SysFreeString>,
+}
+
+impl Drop for UiaFunctions {
+    fn drop(&mut self) {
+        use winapi::um::libloaderapi::FreeLibrary;
+        for dll in [self.uiautomationcore_dll_handle, self.oleaut32_dll_handle].iter() {
+            if let Some(dll) = dll {
+                unsafe { FreeLibrary(*dll); }
+            }
+        }
+    }
+}
+
+impl UiaFunctions {
+
+    pub fn init() -> Self {
+        let uiautomationcore_dll = super::load_dll("UIAutomationCore.dll");
+        let oleaut32_dll = super::load_dll("OleAut32.dll");
+
+        Self {
+            uiautomationcore_dll_handle: uiautomationcore_dll,
+            oleaut32_dll_handle: oleaut32_dll,
+            uia_host_provider_from_hwnd: Self::get_func(uiautomationcore_dll, "UiaHostProviderFromHwnd").map(|e| unsafe { mem::transmute(e) }),
+            uia_raise_notification_event: Self::get_func(uiautomationcore_dll, "UiaRaiseNotificationEvent").map(|e| unsafe { mem::transmute(e) }),
+            sys_alloc_string: Self::get_func(oleaut32_dll, "SysAllocString").map(|e| unsafe { mem::transmute(e) }),
+            sys_free_string: Self::get_func(oleaut32_dll, "SysFreeString").map(|e| unsafe { mem::transmute(e) }),
+        }
+    }
+
+    fn get_func(dll: Option<HINSTANCE>, s: &str) -> Option<*mut c_void> {
+        use winapi::um::libloaderapi::GetProcAddress;
+        let mut func_name = super::encode_ascii(s);
+        dll.and_then(|s| unsafe {
+            let q = GetProcAddress(s, func_name.as_mut_ptr());
+            if q.is_null() { None } else { Some(q as *mut c_void)}
+        })
+    }
+
+    /// Raises a UI Automation notification on the window, so that screen readers
+    /// (Narrator, NVDA, JAWS) speak the text. Returns false if the
+    /// notification API is not available (Windows versions before 10 1709)
+    pub fn announce(&self, hwnd: HWND, announcement: &Announcement) -> bool {
+
+        let (
+            UiaHostProviderFromHwnd,
+            UiaRaiseNotificationEvent,
+            SysAllocString,
+            SysFreeString,
+        ) = match (
+            self.uia_host_provider_from_hwnd,
+            self.uia_raise_notification_event,
+            self.sys_alloc_string,
+            self.sys_free_string,
+        ) {
+            (Some(a), Some(b), Some(c), Some(d)) => (a, b, c, d),
+            _ => return false,
+        };
+
+        let notification_processing = match announcement.politeness {
+            Politeness::Polite => NOTIFICATION_PROCESSING_ALL,
+            Politeness::Assertive => NOTIFICATION_PROCESSING_IMPORTANT_ALL,
+        };
+
+        unsafe {
+
+            let mut provider = ptr::null_mut();
+            if UiaHostProviderFromHwnd(hwnd, &mut provider) != S_OK || provider.is_null() {
+                return false;
+            }
+
+            let text = super::encode_wide(announcement.text.as_str());
+            let activity_id = super::encode_wide("azul-announcement");
+            let text = SysAllocString(text.as_ptr());
+            let activity_id = SysAllocString(activity_id.as_ptr());
+
+            let result = UiaRaiseNotificationEvent(
+                provider,
+                NOTIFICATION_KIND_OTHER,
+                notification_processing,
+                text,
+                activity_id,
+            );
+
+            SysFreeString(text);
+            SysFreeString(activity_id);
+
+            let vtbl = *(provider as *mut *const IUnknownVtbl);
+            ((*vtbl).Release)(provider);
+
+            result == S_OK
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Conversion of CPU-side images into GDI bitmaps and icons
+//! (used for custom cursors, taskbar buttons, taskbar previews and the splash logo)
+
+use core::ptr;
+use winapi::shared::windef::{HBITMAP, HICON};
+use azul_core::app_resources::{DecodedImage, ImageData, ImageRef, RawImageFormat};
+
+/// Creates a 32-bit top-down DIB section from the pixels of the image, scaled down
+/// (nearest neighbor, keeping the aspect ratio) to fit into `max_size` if necessary.
+///
+/// Images are stored with premultiplied alpha, set `premultiplied` to `false` to
+/// convert the pixels to straight alpha (i.e. for icons). Returns `None` if the
+/// image is not a CPU-side BGRA8 image.
+pub(crate) fn create_bitmap(
+    image: &ImageRef,
+    max_size: Option<(usize, usize)>,
+    premultiplied: bool,
+) -> Option<(HBITMAP, usize, usize)> {
+
+    use winapi::um::wingdi::{
+        CreateDIBSection, BITMAPINFO, BITMAPV5HEADER,
+        BI_BITFIELDS, DIB_RGB_COLORS,
+    };
+    use winapi::um::winuser::{GetDC, ReleaseDC};
+
+    let (descriptor, bytes) = match image.get_data() {
+        DecodedImage::Raw((descriptor, ImageData::Raw(bytes))) => (descriptor, bytes),
+        _ => return None,
+    };
+
+    if descriptor.format != RawImageFormat::BGRA8 || descriptor.width == 0 || descriptor.height == 0 {
+        return None;
+    }
+
+    let src_width = descriptor.width;
+    let src_height = descriptor.height;
+    let stride = descriptor.stride.into_option().map(|s| s as usize).unwrap_or(src_width * 4);
+    let offset = descriptor.offset.max(0) as usize;
+    let bytes = bytes.as_ref();
+    if bytes.len() < offset + stride * (src_height - 1) + src_width * 4 {
+        return None;
+    }
+
+    let (width, height) = match max_size {
+        Some((max_width, max_height)) if src_width > max_width || src_height > max_height => {
+            let scale = (max_width as f32 / src_width as f32).min(max_height as f32 / src_height as f32);
+            (
+                ((src_width as f32 * scale) as usize).max(1),
+                ((src_height as f32 * scale) as usize).max(1),
+            )
+        },
+        _ => (src_width, src_height),
+    };
+
+    let mut header: BITMAPV5HEADER = unsafe { core::mem::zeroed() };
+    header.bV5Size = core::mem::size_of::<BITMAPV5HEADER>() as u32;
+    header.bV5Width = width as i32;
+    header.bV5Height = -(height as i32); // top-down
+    header.bV5Planes = 1;
+    header.bV5BitCount = 32;
+    header.bV5Compression = BI_BITFIELDS;
+    header.bV5RedMask = 0x00FF0000;
+    header.bV5GreenMask = 0x0000FF00;
+    header.bV5BlueMask = 0x000000FF;
+    header.bV5AlphaMask = 0xFF000000;
+
+    unsafe {
+        let hdc = GetDC(ptr::null_mut());
+        let mut pixels = ptr::null_mut();
+        let bitmap: HBITMAP = CreateDIBSection(
+            hdc,
+            &header as *const BITMAPV5HEADER as *const BITMAPINFO,
+            DIB_RGB_COLORS,
+            &mut pixels,
+            ptr::null_mut(),
+            0,
+        );
+        ReleaseDC(ptr::null_mut(), hdc);
+
+        if bitmap.is_null() || pixels.is_null() {
+            return None;
+        }
+
+        let dst = core::slice::from_raw_parts_mut(pixels as *mut u8, width * height * 4);
+        for y in 0..height {
+            let src_y = y * src_height / height;
+            let dst_row = &mut dst[(y * width * 4)..((y + 1) * width * 4)];
+            for (x, d) in dst_row.chunks_exact_mut(4).enumerate() {
+                let src_x = x * src_width / width;
+                let start = offset + src_y * stride + src_x * 4;
+                let s = &bytes[start..(start + 4)];
+                if premultiplied {
+                    d.copy_from_slice(s);
+                } else {
+                    let a = s[3] as u32;
+                    let unpremultiply = |c: u8| if a == 0 { 0 } else { ((c as u32 * 255 + a / 2) / a).min(255) as u8 };
+                    d[0] = unpremultiply(s[0]);
+                    d[1] = unpremultiply(s[1]);
+                    d[2] = unpremultiply(s[2]);
+                    d[3] = s[3];
+                }
+            }
+        }
+
+        Some((bitmap, width, height))
+    }
+}
+
+/// Creates an icon (or a cursor, if a hotspot is given) from the image via
+/// `CreateIconIndirect`, returns null if the image can't be converted
+pub(crate) fn create_icon(
+    image: &ImageRef,
+    max_size: Option<(usize, usize)>,
+    cursor_hotspot: Option<(u32, u32)>,
+) -> HICON {
+
+    use winapi::um::wingdi::{CreateBitmap, DeleteObject};
+    use winapi::um::winuser::{CreateIconIndirect, ICONINFO};
+
+    let (color, width, height) = match create_bitmap(image, max_size, false) {
+        Some(s) => s,
+        None => return ptr::null_mut(),
+    };
+
+    unsafe {
+        // the mask is ignored for 32-bit icons with alpha, but has to exist
+        let mask = CreateBitmap(width as i32, height as i32, 1, 1, ptr::null());
+
+        let (hotspot_x, hotspot_y) = cursor_hotspot.unwrap_or((0, 0));
+        let mut icon_info = ICONINFO {
+            fIcon: if cursor_hotspot.is_some() { 0 } else { 1 },
+            xHotspot: hotspot_x.min(width as u32 - 1),
+            yHotspot: hotspot_y.min(height as u32 - 1),
+            hbmMask: mask,
+            hbmColor: color,
+        };
+
+        let handle = CreateIconIndirect(&mut icon_info);
+
+        DeleteObject(color as *mut _);
+        if !mask.is_null() {
+            DeleteObject(mask as *mut _);
+        }
+
+        handle
+    }
+}
@@ -0,0 +1,543 @@
+#![allow(non_snake_case)]
+
+//! Screen capture via DXGI desktop duplication (Windows 8 or later),
+//! windows are captured with `PrintWindow`
+
+use std::mem;
+use std::ffi::c_void;
+use std::ptr;
+use winapi::shared::minwindef::{BOOL, HINSTANCE, UINT};
+use winapi::shared::ntdef::HRESULT;
+use winapi::shared::windef::{HMONITOR, HWND, POINT, RECT};
+use winapi::shared::winerror::S_OK;
+use azul_core::window::RawWindowHandle;
+use crate::capture::{CapturedImage, CaptureError, CaptureSource};
+
+#[repr(C)]
+struct Guid {
+    data1: u32,
+    data2: u16,
+    data3: u16,
+    data4: [u8; 8],
+}
+
+// {770AAE78-F26F-4DBA-A829-253C83D1B387}
+const IID_IDXGI_FACTORY1: Guid = Guid {
+    data1: 0x770AAE78,
+    data2: 0xF26F,
+    data3: 0x4DBA,
+    data4: [0xA8, 0x29, 0x25, 0x3C, 0x83, 0xD1, 0xB3, 0x87],
+};
+
+// {00CDDEA8-939B-4B83-A340-A685226666CC}
+const IID_IDXGI_OUTPUT1: Guid = Guid {
+    data1: 0x00CDDEA8,
+    data2: 0x939B,
+    data3: 0x4B83,
+    data4: [0xA3, 0x40, 0xA6, 0x85, 0x22, 0x66, 0x66, 0xCC],
+};
+
+// {6F15AAF2-D208-4E89-9AB4-489535D34F9C}
+const IID_ID3D11_TEXTURE2D: Guid = Guid {
+    data1: 0x6F15AAF2,
+    data2: 0xD208,
+    data3: 0x4E89,
+    data4: [0x9A, 0xB4, 0x48, 0x95, 0x35, 0xD3, 0x4F, 0x9C],
+};
+
+const DXGI_ERROR_NOT_FOUND: HRESULT = 0x887A0002_u32 as HRESULT;
+const DXGI_ERROR_WAIT_TIMEOUT: HRESULT = 0x887A0027_u32 as HRESULT;
+const DXGI_FORMAT_B8G8R8A8_UNORM: u32 = 87;
+const D3D_DRIVER_TYPE_UNKNOWN: u32 = 0;
+const D3D11_SDK_VERSION: u32 = 7;
+const D3D11_USAGE_STAGING: u32 = 3;
+const D3D11_CPU_ACCESS_READ: u32 = 0x20000;
+const D3D11_MAP_READ: u32 = 1;
+/// Timeout for waiting on the first frame of the desktop duplication
+const ACQUIRE_FRAME_TIMEOUT_MS: u32 = 500;
+const ACQUIRE_FRAME_RETRIES: usize = 4;
+
+type CreateDXGIFactory1 = unsafe extern "system" fn(riid: *const Guid, factory: *mut *mut c_void) -> HRESULT;
+type D3D11CreateDevice = unsafe extern "system" fn(
+    adapter: *mut c_void,
+    driver_type: u32,
+    software: HINSTANCE,
+    flags: u32,
+    feature_levels: *const u32,
+    num_feature_levels: u32,
+    sdk_version: u32,
+    device: *mut *mut c_void,
+    feature_level: *mut u32,
+    immediate_context: *mut *mut c_void,
+) -> HRESULT;
+
+#[repr(C)]
+struct IUnknownVtbl {
+    QueryInterface: unsafe extern "system" fn(this: *mut c_void, riid: *const Guid, object: *mut *mut c_void) -> HRESULT,
+    AddRef: *const c_void,
+    Release: unsafe extern "system" fn(this: *mut c_void) -> u32,
+}
+
+#[repr(C)]
+struct IDXGIFactory1Vtbl {
+    unknown: IUnknownVtbl,
+    // IDXGIObject, IDXGIFactory
+    _reserved: [*const c_void; 9],
+    EnumAdapters1: unsafe extern "system" fn(this: *mut c_void, adapter: UINT, out: *mut *mut c_void) -> HRESULT,
+}
+
+#[repr(C)]
+struct IDXGIAdapterVtbl {
+    unknown: IUnknownVtbl,
+    // IDXGIObject
+    _reserved: [*const c_void; 4],
+    EnumOutputs: unsafe extern "system" fn(this: *mut c_void, output: UINT, out: *mut *mut c_void) -> HRESULT,
+}
+
+#[repr(C)]
+struct DXGI_OUTPUT_DESC {
+    DeviceName: [u16; 32],
+    DesktopCoordinates: RECT,
+    AttachedToDesktop: BOOL,
+    Rotation: u32,
+    Monitor: HMONITOR,
+}
+
+#[repr(C)]
+struct IDXGIOutputVtbl {
+    unknown: IUnknownVtbl,
+    // IDXGIObject
+    _reserved: [*const c_void; 4],
+    GetDesc: unsafe extern "system" fn(this: *mut c_void, desc: *mut DXGI_OUTPUT_DESC) -> HRESULT,
+}
+
+#[repr(C)]
+struct IDXGIOutput1Vtbl {
+    unknown: IUnknownVtbl,
+    // IDXGIObject, IDXGIOutput, GetDisplayModeList1, FindClosestMatchingMode1, GetDisplaySurfaceData1
+    _reserved: [*const c_void; 19],
+    DuplicateOutput: unsafe extern "system" fn(this: *mut c_void, device: *mut c_void, out: *mut *mut c_void) -> HRESULT,
+}
+
+#[repr(C)]
+struct DXGI_OUTDUPL_POINTER_POSITION {
+    Position: POINT,
+    Visible: BOOL,
+}
+
+#[repr(C)]
+struct DXGI_OUTDUPL_FRAME_INFO {
+    LastPresentTime: i64,
+    LastMouseUpdateTime: i64,
+    AccumulatedFrames: u32,
+    RectsCoalesced: BOOL,
+    ProtectedContentMaskedOut: BOOL,
+    PointerPosition: DXGI_OUTDUPL_POINTER_POSITION,
+    TotalMetadataBufferSize: u32,
+    PointerShapeBufferSize: u32,
+}
+
+#[repr(C)]
+struct IDXGIOutputDuplicationVtbl {
+    unknown: IUnknownVtbl,
+    // IDXGIObject, GetDesc
+    _reserved: [*const c_void; 5],
+    AcquireNextFrame: unsafe extern "system" fn(this: *mut c_void, timeout_ms: u32, info: *mut DXGI_OUTDUPL_FRAME_INFO, resource: *mut *mut c_void) -> HRESULT,
+    // GetFrameDirtyRects, GetFrameMoveRects, GetFramePointerShape, MapDesktopSurface, UnMapDesktopSurface
+    _reserved2: [*const c_void; 5],
+    ReleaseFrame: unsafe extern "system" fn(this: *mut c_void) -> HRESULT,
+}
+
+#[repr(C)]
+#[derive(Default, Copy, Clone)]
+struct DXGI_SAMPLE_DESC {
+    Count: u32,
+    Quality: u32,
+}
+
+#[repr(C)]
+#[derive(Default, Copy, Clone)]
+struct D3D11_TEXTURE2D_DESC {
+    Width: u32,
+    Height: u32,
+    MipLevels: u32,
+    ArraySize: u32,
+    Format: u32,
+    SampleDesc: DXGI_SAMPLE_DESC,
+    Usage: u32,
+    BindFlags: u32,
+    CPUAccessFlags: u32,
+    MiscFlags: u32,
+}
+
+#[repr(C)]
+struct ID3D11Texture2DVtbl {
+    unknown: IUnknownVtbl,
+    // ID3D11DeviceChild, ID3D11Resource
+    _reserved: [*const c_void; 7],
+    GetDesc: unsafe extern "system" fn(this: *mut c_void, desc: *mut D3D11_TEXTURE2D_DESC),
+}
+
+#[repr(C)]
+struct ID3D11DeviceVtbl {
+    unknown: IUnknownVtbl,
+    // CreateBuffer, CreateTexture1D
+    _reserved: [*const c_void; 2],
+    CreateTexture2D: unsafe extern "system" fn(this: *mut c_void, desc: *const D3D11_TEXTURE2D_DESC, initial_data: *const c_void, out: *mut *mut c_void) -> HRESULT,
+}
+
+#[repr(C)]
+struct D3D11_MAPPED_SUBRESOURCE {
+    pData: *mut c_void,
+    RowPitch: u32,
+    DepthPitch: u32,
+}
+
+#[repr(C)]
+struct ID3D11DeviceContextVtbl {
+    unknown: IUnknownVtbl,
+    // ID3D11DeviceChild, VSSetConstantBuffers .. Draw
+    _reserved: [*const c_void; 11],
+    Map: unsafe extern "system" fn(this: *mut c_void, resource: *mut c_void, subresource: u32, map_type: u32, flags: u32, mapped: *mut D3D11_MAPPED_SUBRESOURCE) -> HRESULT,
+    Unmap: unsafe extern "system" fn(this: *mut c_void, resource: *mut c_void, subresource: u32),
+    // PSSetConstantBuffers .. CopySubresourceRegion
+    _reserved2: [*const c_void; 31],
+    CopyResource: unsafe extern "system" fn(this: *mut c_void, dst: *mut c_void, src: *mut c_void),
+}
+
+#[inline]
+unsafe fn vtbl<T>(object: *mut c_void) -> &'static T {
+    &**(object as *mut *const T)
+}
+
+/// Releases the COM object when dropped
+struct ComPtr(*mut c_void);
+
+impl Drop for ComPtr {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe { (vtbl::<IUnknownVtbl>(self.0).Release)(self.0); }
+        }
+    }
+}
+
+/// Unloads the library when dropped
+struct Dll(HINSTANCE);
+
+impl Drop for Dll {
+    fn drop(&mut self) {
+        use winapi::um::libloaderapi::FreeLibrary;
+        unsafe { FreeLibrary(self.0); }
+    }
+}
+
+fn get_func(dll: HINSTANCE, s: &str) -> Option<*mut c_void> {
+    use winapi::um::libloaderapi::GetProcAddress;
+    let mut func_name = super::encode_ascii(s);
+    let q = unsafe { GetProcAddress(dll, func_name.as_mut_ptr()) };
+    if q.is_null() { None } else { Some(q as *mut c_void) }
+}
+
+fn platform_error(function: &str, hr: HRESULT) -> CaptureError {
+    CaptureError::Platform(format!("{} failed: HRESULT 0x{:08X}", function, hr as u32))
+}
+
+pub(crate) fn capture(source: &CaptureSource) -> Result<CapturedImage, CaptureError> {
+    match source {
+        CaptureSource::Region { origin, size } => {
+            let rect = RECT {
+                left: origin.x,
+                top: origin.y,
+                right: origin.x + size.width as i32,
+                bottom: origin.y + size.height as i32,
+            };
+            unsafe { capture_region(rect) }
+        },
+        CaptureSource::Window(RawWindowHandle::Windows(handle)) => {
+            unsafe { capture_window(handle.hwnd as HWND) }
+        },
+        CaptureSource::Window(_) => Err(CaptureError::InvalidSource),
+    }
+}
+
+/// Captures all outputs (monitors) that intersect with the region
+unsafe fn capture_region(region: RECT) -> Result<CapturedImage, CaptureError> {
+
+    let dxgi = Dll(super::load_dll("dxgi.dll").ok_or(CaptureError::Unsupported)?);
+    let d3d11 = Dll(super::load_dll("d3d11.dll").ok_or(CaptureError::Unsupported)?);
+
+    let CreateDXGIFactory1: CreateDXGIFactory1 = mem::transmute(get_func(dxgi.0, "CreateDXGIFactory1").ok_or(CaptureError::Unsupported)?);
+    let D3D11CreateDevice: D3D11CreateDevice = mem::transmute(get_func(d3d11.0, "D3D11CreateDevice").ok_or(CaptureError::Unsupported)?);
+
+    let mut factory = ptr::null_mut();
+    let hr = CreateDXGIFactory1(&IID_IDXGI_FACTORY1, &mut factory);
+    if hr != S_OK {
+        return Err(platform_error("CreateDXGIFactory1", hr));
+    }
+    let factory = ComPtr(factory);
+
+    let mut image = CapturedImage::new(
+        (region.right - region.left) as usize,
+        (region.bottom - region.top) as usize,
+    );
+
+    let mut adapter_idx = 0;
+    loop {
+        let mut adapter = ptr::null_mut();
+        let hr = (vtbl::<IDXGIFactory1Vtbl>(factory.0).EnumAdapters1)(factory.0, adapter_idx, &mut adapter);
+        if hr == DXGI_ERROR_NOT_FOUND {
+            break;
+        } else if hr != S_OK {
+            return Err(platform_error("IDXGIFactory1::EnumAdapters1", hr));
+        }
+        let adapter = ComPtr(adapter);
+        adapter_idx += 1;
+
+        // the duplication has to be created on the adapter the output is connected to
+        let mut device = ptr::null_mut();
+        let mut context = ptr::null_mut();
+        let hr = D3D11CreateDevice(
+            adapter.0,
+            D3D_DRIVER_TYPE_UNKNOWN,
+            ptr::null_mut(),
+            0,
+            ptr::null(),
+            0,
+            D3D11_SDK_VERSION,
+            &mut device,
+            ptr::null_mut(),
+            &mut context,
+        );
+        if hr != S_OK {
+            continue;
+        }
+        let device = ComPtr(device);
+        let context = ComPtr(context);
+
+        let mut output_idx = 0;
+        loop {
+            let mut output = ptr::null_mut();
+            let hr = (vtbl::<IDXGIAdapterVtbl>(adapter.0).EnumOutputs)(adapter.0, output_idx, &mut output);
+            if hr == DXGI_ERROR_NOT_FOUND {
+                break;
+            } else if hr != S_OK {
+                return Err(platform_error("IDXGIAdapter::EnumOutputs", hr));
+            }
+            let output = ComPtr(output);
+            output_idx += 1;
+
+            let mut desc: DXGI_OUTPUT_DESC = mem::zeroed();
+            if (vtbl::<IDXGIOutputVtbl>(output.0).GetDesc)(output.0, &mut desc) != S_OK || desc.AttachedToDesktop == 0 {
+                continue;
+            }
+
+            let bounds = desc.DesktopCoordinates;
+            if bounds.right <= region.left || bounds.left >= region.right ||
+               bounds.bottom <= region.top || bounds.top >= region.bottom {
+                continue;
+            }
+
+            capture_output(&output, &device, &context, &mut image, (bounds.left - region.left) as isize, (bounds.top - region.top) as isize)?;
+        }
+    }
+
+    Ok(image)
+}
+
+/// Copies the current frame of the output into the image at (`x`, `y`)
+unsafe fn capture_output(
+    output: &ComPtr,
+    device: &ComPtr,
+    context: &ComPtr,
+    image: &mut CapturedImage,
+    x: isize,
+    y: isize,
+) -> Result<(), CaptureError> {
+
+    use winapi::shared::winerror::E_ACCESSDENIED;
+
+    let mut output1 = ptr::null_mut();
+    if (vtbl::<IUnknownVtbl>(output.0).QueryInterface)(output.0, &IID_IDXGI_OUTPUT1, &mut output1) != S_OK {
+        // IDXGIOutput1 requires Windows 8
+        return Err(CaptureError::Unsupported);
+    }
+    let output1 = ComPtr(output1);
+
+    let mut duplication = ptr::null_mut();
+    let hr = (vtbl::<IDXGIOutput1Vtbl>(output1.0).DuplicateOutput)(output1.0, device.0, &mut duplication);
+    if hr == E_ACCESSDENIED {
+        // i.e. on the secure desktop (UAC prompt, lock screen)
+        return Err(CaptureError::PermissionDenied);
+    } else if hr != S_OK {
+        return Err(platform_error("IDXGIOutput1::DuplicateOutput", hr));
+    }
+    let duplication = ComPtr(duplication);
+    let duplication_vtbl = vtbl::<IDXGIOutputDuplicationVtbl>(duplication.0);
+
+    // the first frames of a new duplication may not contain an image yet
+    let mut desktop_resource = None;
+    for _ in 0..ACQUIRE_FRAME_RETRIES {
+        let mut frame_info: DXGI_OUTDUPL_FRAME_INFO = mem::zeroed();
+        let mut resource = ptr::null_mut();
+        let hr = (duplication_vtbl.AcquireNextFrame)(duplication.0, ACQUIRE_FRAME_TIMEOUT_MS, &mut frame_info, &mut resource);
+        if hr == DXGI_ERROR_WAIT_TIMEOUT {
+            continue;
+        } else if hr != S_OK {
+            return Err(platform_error("IDXGIOutputDuplication::AcquireNextFrame", hr));
+        }
+        if frame_info.LastPresentTime != 0 {
+            desktop_resource = Some(ComPtr(resource));
+            break;
+        }
+        mem::drop(ComPtr(resource));
+        (duplication_vtbl.ReleaseFrame)(duplication.0);
+    }
+
+    let desktop_resource = desktop_resource.ok_or(CaptureError::Platform(String::from("no frame was presented")))?;
+    let result = copy_desktop_texture(&desktop_resource, device, context, image, x, y);
+    mem::drop(desktop_resource);
+    (duplication_vtbl.ReleaseFrame)(duplication.0);
+    result
+}
+
+/// Copies the desktop texture into a CPU-readable staging texture and reads the pixels
+unsafe fn copy_desktop_texture(
+    desktop_resource: &ComPtr,
+    device: &ComPtr,
+    context: &ComPtr,
+    image: &mut CapturedImage,
+    x: isize,
+    y: isize,
+) -> Result<(), CaptureError> {
+
+    let mut texture = ptr::null_mut();
+    let hr = (vtbl::<IUnknownVtbl>(desktop_resource.0).QueryInterface)(desktop_resource.0, &IID_ID3D11_TEXTURE2D, &mut texture);
+    if hr != S_OK {
+        return Err(platform_error("QueryInterface(ID3D11Texture2D)", hr));
+    }
+    let texture = ComPtr(texture);
+
+    let mut desc = D3D11_TEXTURE2D_DESC::default();
+    (vtbl::<ID3D11Texture2DVtbl>(texture.0).GetDesc)(texture.0, &mut desc);
+    if desc.Format != DXGI_FORMAT_B8G8R8A8_UNORM {
+        return Err(CaptureError::Platform(format!("unsupported desktop format {}", desc.Format)));
+    }
+
+    let staging_desc = D3D11_TEXTURE2D_DESC {
+        MipLevels: 1,
+        ArraySize: 1,
+        SampleDesc: DXGI_SAMPLE_DESC { Count: 1, Quality: 0 },
+        Usage: D3D11_USAGE_STAGING,
+        BindFlags: 0,
+        CPUAccessFlags: D3D11_CPU_ACCESS_READ,
+        MiscFlags: 0,
+        .. desc
+    };
+
+    let mut staging = ptr::null_mut();
+    let hr = (vtbl::<ID3D11DeviceVtbl>(device.0).CreateTexture2D)(device.0, &staging_desc, ptr::null(), &mut staging);
+    if hr != S_OK {
+        return Err(platform_error("ID3D11Device::CreateTexture2D", hr));
+    }
+    let staging = ComPtr(staging);
+
+    let context_vtbl = vtbl::<ID3D11DeviceContextVtbl>(context.0);
+    (context_vtbl.CopyResource)(context.0, staging.0, texture.0);
+
+    let mut mapped: D3D11_MAPPED_SUBRESOURCE = mem::zeroed();
+    let hr = (context_vtbl.Map)(context.0, staging.0, 0, D3D11_MAP_READ, 0, &mut mapped);
+    if hr != S_OK {
+        return Err(platform_error("ID3D11DeviceContext::Map", hr));
+    }
+
+    let pixels = std::slice::from_raw_parts(mapped.pData as *const u8, mapped.RowPitch as usize * desc.Height as usize);
+    image.blit_bgra(pixels, desc.Width as usize, desc.Height as usize, mapped.RowPitch as usize, x, y);
+
+    (context_vtbl.Unmap)(context.0, staging.0, 0);
+
+    // the desktop surface has no alpha channel, but parts of the
+    // region that are not covered by a monitor stay transparent
+    for row in 0..(desc.Height as isize) {
+        let dst_y = y + row;
+        if dst_y < 0 || dst_y >= image.height as isize {
+            continue;
+        }
+        let x_start = x.max(0) as usize;
+        let x_end = (x + desc.Width as isize).min(image.width as isize).max(0) as usize;
+        for dst_x in x_start..x_end {
+            image.pixels[(dst_y as usize * image.width + dst_x) * 4 + 3] = 0xFF;
+        }
+    }
+
+    Ok(())
+}
+
+/// Captures the client area of the window, also if it is covered by other windows
+unsafe fn capture_window(hwnd: HWND) -> Result<CapturedImage, CaptureError> {
+
+    use winapi::um::wingdi::{
+        CreateCompatibleDC, CreateDIBSection, DeleteDC, DeleteObject, SelectObject,
+        BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS,
+    };
+    use winapi::um::winuser::{GetClientRect, GetDC, IsWindow, PrintWindow, ReleaseDC};
+
+    // PW_CLIENTONLY | PW_RENDERFULLCONTENT (captures DirectComposition / OpenGL content, Windows 8.1)
+    const PRINT_WINDOW_FLAGS: UINT = 0x1 | 0x2;
+
+    if hwnd.is_null() || IsWindow(hwnd) == 0 {
+        return Err(CaptureError::InvalidSource);
+    }
+
+    let mut rect: RECT = mem::zeroed();
+    GetClientRect(hwnd, &mut rect);
+    let width = (rect.right - rect.left).max(0);
+    let height = (rect.bottom - rect.top).max(0);
+    if width == 0 || height == 0 {
+        return Err(CaptureError::InvalidSource);
+    }
+
+    let window_dc = GetDC(hwnd);
+    let memory_dc = CreateCompatibleDC(window_dc);
+
+    let mut bitmap_info: BITMAPINFO = mem::zeroed();
+    bitmap_info.bmiHeader = BITMAPINFOHEADER {
+        biSize: mem::size_of::<BITMAPINFOHEADER>() as u32,
+        biWidth: width,
+        biHeight: -height, // top-down rows
+        biPlanes: 1,
+        biBitCount: 32,
+        biCompression: BI_RGB,
+        .. mem::zeroed()
+    };
+
+    let mut bits = ptr::null_mut();
+    let bitmap = CreateDIBSection(memory_dc, &bitmap_info, DIB_RGB_COLORS, &mut bits, ptr::null_mut(), 0);
+
+    let result = if bitmap.is_null() || bits.is_null() {
+        Err(CaptureError::Platform(String::from("CreateDIBSection failed")))
+    } else {
+        let previous = SelectObject(memory_dc, bitmap as *mut _);
+        let printed = PrintWindow(hwnd, memory_dc, PRINT_WINDOW_FLAGS);
+        SelectObject(memory_dc, previous);
+
+        if printed == 0 {
+            Err(CaptureError::Platform(String::from("PrintWindow failed")))
+        } else {
+            let (width, height) = (width as usize, height as usize);
+            let pixels = std::slice::from_raw_parts(bits as *const u8, width * height * 4);
+            let mut image = CapturedImage::new(width, height);
+            image.blit_bgra(pixels, width, height, width * 4, 0, 0);
+            // GDI leaves the alpha channel undefined
+            image.set_opaque();
+            Ok(image)
+        }
+    };
+
+    if !bitmap.is_null() {
+        DeleteObject(bitmap as *mut _);
+    }
+    DeleteDC(memory_dc);
+    ReleaseDC(hwnd, window_dc);
+
+    result
+}
@@ -0,0 +1,35 @@
+//! Mouse cursors: system cursors (`IDC_*`) and custom cursors
+//! created from images (`cursor: url(id) x y`) via `CreateIconIndirect`
+
+use core::ptr;
+use winapi::shared::windef::HCURSOR;
+use winapi::um::winnt::LPCWSTR;
+use azul_core::window::{CustomCursor, MouseCursorType};
+
+/// Custom cursor of a window, the handle is null if the cursor
+/// could not be created (the system cursor is used instead)
+#[derive(Debug)]
+pub(crate) struct WindowCursor {
+    pub cursor: CustomCursor,
+    pub handle: HCURSOR,
+}
+
+impl Drop for WindowCursor {
+    fn drop(&mut self) {
+        use winapi::um::winuser::DestroyCursor;
+        if !self.handle.is_null() {
+            unsafe { DestroyCursor(self.handle); }
+        }
+    }
+}
+
+pub(crate) fn load_system_cursor(cursor: MouseCursorType) -> HCURSOR {
+    use winapi::um::winuser::LoadCursorW;
+    unsafe { LoadCursorW(ptr::null_mut(), super::win32_translate_cursor(cursor) as LPCWSTR) }
+}
+
+/// Creates a cursor from the pixels of the image, returns null if the
+/// image is not a CPU-side BGRA8 image or the cursor can't be created
+pub(crate) fn create_custom_cursor(cursor: &CustomCursor) -> HCURSOR {
+    super::bitmap::create_icon(&cursor.image, None, Some((cursor.hotspot_x, cursor.hotspot_y)))
+}
@@ -215,7 +215,7 @@ use core::{
     ptr,
     sync::atomic::{AtomicBool, AtomicPtr, Ordering},
 };
-use azul_core::window::{ScanCode, VirtualKeyCode};
+use azul_core::window::{KeyboardModifiers, KeyboardState, OptionChar, ScanCode, VirtualKeyCode};
 
 use winapi::{
     shared::minwindef::{HKL, HKL__, LPARAM, UINT, WPARAM},
@@ -307,6 +307,52 @@ pub fn get_pressed_keys() -> impl Iterator<Item = i32> {
         .map(|(i, _)| i as i32)
 }
 
+fn key_toggled(vkey: i32) -> bool {
+    unsafe { (winuser::GetKeyState(vkey) & 1) != 0 }
+}
+
+/// Updates the CapsLock / NumLock / ScrollLock state of the keyboard state
+pub fn update_lock_keys(keyboard_state: &mut KeyboardState) {
+    keyboard_state.caps_lock_enabled = key_toggled(winuser::VK_CAPITAL);
+    keyboard_state.num_lock_enabled = key_toggled(winuser::VK_NUMLOCK);
+    keyboard_state.scroll_lock_enabled = key_toggled(winuser::VK_SCROLL);
+}
+
+/// Maps the virtual keycode to the character it produces in the current keyboard
+/// layout (via `ToUnicodeEx`), injected as `ExternalSystemCallbacks::keycode_to_char_fn`
+pub extern "C" fn keycode_to_char(
+    virtual_keycode: VirtualKeyCode,
+    modifiers: KeyboardModifiers,
+) -> OptionChar {
+
+    // the VK_ -> VirtualKeyCode mapping depends on the layout (see map_text_keys),
+    // so search for the VK instead of inverting the mapping
+    let vkey = match (1..=254).find(|vk| vkey_to_winit_vkey(*vk) == Some(virtual_keycode)) {
+        Some(s) => s,
+        None => return OptionChar::None,
+    };
+
+    let mut keyboard_state = [0u8; 256];
+    if modifiers.shift {
+        keyboard_state[winuser::VK_SHIFT as usize] = 0x80;
+    }
+    if modifiers.ctrl {
+        keyboard_state[winuser::VK_CONTROL as usize] = 0x80;
+    }
+    if modifiers.alt {
+        keyboard_state[winuser::VK_MENU as usize] = 0x80;
+    }
+    if modifiers.caps_lock {
+        keyboard_state[winuser::VK_CAPITAL as usize] = 0x01;
+    }
+
+    let hkl = unsafe { winuser::GetKeyboardLayout(0) };
+    match unsafe { get_char(&keyboard_state, vkey as u32, hkl) } {
+        Some(c) if !c.is_control() => OptionChar::Some(c as u32),
+        _ => OptionChar::None,
+    }
+}
+
 unsafe fn get_char(keyboard_state: &[u8; 256], v_key: u32, hkl: HKL) -> Option<char> {
     let mut unicode_bytes = [0u16; 5];
     let len = winuser::ToUnicodeEx(
@@ -0,0 +1,45 @@
+//! Haptic feedback for pens via `Windows.Devices.Input.PenDevice`
+//! (`SimpleHapticsController`, Windows 11 or later)
+
+use azul_core::window::HapticKind;
+use windows::Devices::Haptics::KnownSimpleHapticsControllerWaveforms;
+use windows::Devices::Input::PenDevice;
+use windows::Foundation::TimeSpan;
+
+/// Pause between the repetitions of the `Warning` / `Error` feedback (100 ms, in 100 ns units)
+const REPLAY_PAUSE_INTERVAL: TimeSpan = TimeSpan { Duration: 1_000_000 };
+
+/// Plays the feedback on the pen with the given pointer ID, does nothing
+/// if the pen does not support the waveform
+pub(crate) fn play_haptic_feedback(pen_pointer_id: u32, kind: HapticKind) -> windows::core::Result<()> {
+
+    // (waveform, intensity, play count)
+    let (waveform, intensity, play_count) = match kind {
+        HapticKind::Selection => (KnownSimpleHapticsControllerWaveforms::Click()?, 0.5, 1),
+        HapticKind::LightImpact => (KnownSimpleHapticsControllerWaveforms::Click()?, 0.5, 1),
+        HapticKind::MediumImpact => (KnownSimpleHapticsControllerWaveforms::Click()?, 0.75, 1),
+        HapticKind::HeavyImpact => (KnownSimpleHapticsControllerWaveforms::Press()?, 1.0, 1),
+        HapticKind::Success => (KnownSimpleHapticsControllerWaveforms::Click()?, 1.0, 1),
+        HapticKind::Warning => (KnownSimpleHapticsControllerWaveforms::Click()?, 1.0, 2),
+        HapticKind::Error => (KnownSimpleHapticsControllerWaveforms::Click()?, 1.0, 3),
+    };
+
+    let controller = PenDevice::GetFromPointerId(pen_pointer_id)?.SimpleHapticsController()?;
+
+    let feedback = match controller
+        .SupportedFeedback()?
+        .into_iter()
+        .find(|f| f.Waveform().ok() == Some(waveform))
+    {
+        Some(s) => s,
+        None => return Ok(()),
+    };
+
+    if play_count > 1 && controller.IsPlayCountSupported()? {
+        controller.SendHapticFeedbackForPlayCount(&feedback, intensity, play_count, REPLAY_PAUSE_INTERVAL)
+    } else if controller.IsIntensitySupported()? {
+        controller.SendHapticFeedbackWithIntensity(&feedback, intensity)
+    } else {
+        controller.SendHapticFeedback(&feedback)
+    }
+}
@@ -0,0 +1,93 @@
+#![allow(non_snake_case)]
+
+//! Enabling / disabling the input method editor of a window, the IME is
+//! disabled while a secure text field (i.e. a password input) is focused, so that
+//! the typed text never shows up in the composition or candidate windows.
+//! Only Windows disables the IME: the X11 shell doesn't use an input method (XIM)
+//! yet and the macOS shell doesn't track the focused node yet.
+//!
+//! The `InputMode` of the focused node is forwarded as the input scope of the
+//! window, which selects the layout of the touch keyboard and the IME conversion mode
+
+use std::mem;
+use std::ptr;
+use winapi::shared::minwindef::{BOOL, DWORD};
+use winapi::shared::windef::HWND;
+use azul_core::dom::InputMode;
+
+type HIMC = *mut std::ffi::c_void;
+type ImmAssociateContextEx = unsafe extern "system" fn(hwnd: HWND, himc: HIMC, flags: DWORD) -> BOOL;
+
+// associates the default input context of the window
+const IACE_DEFAULT: DWORD = 0x0010;
+
+/// Disables the IME of the window (by associating a NULL input context)
+/// or restores the default input context. Does nothing if imm32.dll is not available.
+pub(crate) fn set_ime_enabled(hwnd: HWND, enabled: bool) {
+
+    use winapi::um::libloaderapi::{FreeLibrary, GetProcAddress};
+
+    let imm32_dll = match super::load_dll("imm32.dll") {
+        Some(s) => s,
+        None => return,
+    };
+
+    unsafe {
+        let mut func_name = super::encode_ascii("ImmAssociateContextEx");
+        let func = GetProcAddress(imm32_dll, func_name.as_mut_ptr());
+        if !func.is_null() {
+            let ImmAssociateContextEx: ImmAssociateContextEx = mem::transmute(func);
+            if enabled {
+                ImmAssociateContextEx(hwnd, ptr::null_mut(), IACE_DEFAULT);
+            } else {
+                ImmAssociateContextEx(hwnd, ptr::null_mut(), 0);
+            }
+        }
+        FreeLibrary(imm32_dll);
+    }
+}
+
+type SetInputScope = unsafe extern "system" fn(hwnd: HWND, input_scope: u32) -> i32;
+
+// InputScope enum from <inputscope.h>
+const IS_DEFAULT: u32 = 0;
+const IS_URL: u32 = 1;
+const IS_EMAIL_SMTPEMAILADDRESS: u32 = 5;
+const IS_DIGITS: u32 = 28;
+const IS_NUMBER: u32 = 29;
+const IS_PASSWORD: u32 = 31;
+const IS_TELEPHONE_FULLTELEPHONENUMBER: u32 = 32;
+const IS_SEARCH: u32 = 50;
+
+/// Sets the input scope of the window to match the `InputMode` of the focused node.
+/// Does nothing if msctf.dll is not available.
+pub(crate) fn set_input_mode(hwnd: HWND, input_mode: InputMode) {
+
+    use winapi::um::libloaderapi::{FreeLibrary, GetProcAddress};
+
+    let input_scope = match input_mode {
+        InputMode::Text | InputMode::None => IS_DEFAULT,
+        InputMode::Numeric => IS_DIGITS,
+        InputMode::Decimal => IS_NUMBER,
+        InputMode::Tel => IS_TELEPHONE_FULLTELEPHONENUMBER,
+        InputMode::Email => IS_EMAIL_SMTPEMAILADDRESS,
+        InputMode::Url => IS_URL,
+        InputMode::Search => IS_SEARCH,
+        InputMode::Password => IS_PASSWORD,
+    };
+
+    let msctf_dll = match super::load_dll("msctf.dll") {
+        Some(s) => s,
+        None => return,
+    };
+
+    unsafe {
+        let mut func_name = super::encode_ascii("SetInputScope");
+        let func = GetProcAddress(msctf_dll, func_name.as_mut_ptr());
+        if !func.is_null() {
+            let SetInputScope: SetInputScope = mem::transmute(func);
+            SetInputScope(hwnd, input_scope);
+        }
+        FreeLibrary(msctf_dll);
+    }
+}
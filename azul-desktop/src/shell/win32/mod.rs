@@ -6,12 +6,28 @@
 
 mod event;
 mod dpi;
+mod announce;
+mod ime;
+mod monitor;
+mod recording;
+mod touch;
+mod cursor;
+mod bitmap;
+mod splash;
+mod system_style;
+pub mod taskbar;
+#[cfg(feature = "haptics")]
+mod haptics;
+pub(crate) mod spellcheck;
+#[cfg(feature = "capture")]
+pub(crate) mod capture;
 
 use crate::{
     app::{App, LazyFcCache},
     wr_translate::{
         rebuild_display_list,
         generate_frame,
+        generate_scroll_frame,
         synchronize_gpu_values,
         scroll_all_nodes,
         wr_synchronize_updated_images,
@@ -34,8 +50,8 @@ use azul_core::{
         RefAny, UpdateImageType,
         DomNodeId, DocumentId
     },
-    gl::OptionGlContextPtr,
-    task::{Thread, ThreadId, Timer, TimerId},
+    gl::{GlDriverInfo, GlQuirkRule, GlQuirks, OptionGlContextPtr},
+    task::{Duration, EventLoopProxy, Instant, OptionDuration, OptionInstant, Thread, ThreadId, Timer, TimerId},
     ui_solver::LayoutResult,
     styled_dom::DomId,
     dom::NodeId,
@@ -43,8 +59,9 @@ use azul_core::{
     window::{
         LogicalSize, Menu, MenuCallback, MenuItem,
         MonitorVec, WindowCreateOptions, WindowInternal,
-        WindowState, FullWindowState, ScrollResult,
-        MouseCursorType, CallCallbacksResult
+        WindowState, WindowFlags, FullWindowState, ScrollResult, RawWindowHandle,
+        MouseCursorType, CustomCursor, WindowBackgroundEffect, CallCallbacksResult, SyntheticEvent,
+        FirstFrameMode, DebugState,
     },
     window_state::NodesToCheck,
 };
@@ -69,36 +86,79 @@ use webrender::{
         ApiHitTester as WrApiHitTester, DocumentId as WrDocumentId,
         RenderNotifier as WrRenderNotifier,
     },
-    render_api::RenderApi as WrRenderApi,
+    render_api::{RenderApi as WrRenderApi, RenderApiSender as WrRenderApiSender},
     PipelineInfo as WrPipelineInfo, Renderer as WrRenderer, RendererError as WrRendererError,
     RendererOptions as WrRendererOptions, ShaderPrecacheFlags as WrShaderPrecacheFlags,
     Shaders as WrShaders, Transaction as WrTransaction,
 };
 use winapi::{
     shared::{
-        minwindef::{BOOL, HINSTANCE, LPARAM, LRESULT, TRUE, UINT, WPARAM},
+        minwindef::{BOOL, DWORD, FALSE, HINSTANCE, LPARAM, LRESULT, TRUE, UINT, WPARAM},
         ntdef::HRESULT,
-        windef::{HDC, HGLRC, HMENU, HWND, RECT, POINT},
+        windef::{HBITMAP, HDC, HGLRC, HMENU, HWND, RECT, POINT},
     },
     ctypes::wchar_t,
     um::dwmapi::{DWM_BB_ENABLE, DWM_BLURBEHIND},
     um::uxtheme::MARGINS,
     um::winuser::WM_APP,
 };
+pub use self::event::keycode_to_char;
 use self::dpi::DpiFunctions;
-use azul_css::FloatValue;
+use self::splash::SplashWindow;
+use self::touch::{
+    PointerFunctions, WM_POINTERDOWN, WM_POINTERUPDATE,
+    WM_POINTERUP, WM_POINTERCAPTURECHANGED,
+    get_mouse_message_input_modality, set_input_modality,
+};
+use crate::event_recording::EventRecordingState;
+use azul_css::{ColorU, FloatValue};
 
 type TIMERPTR = winapi::shared::basetsd::UINT_PTR;
 
+// IDs of the internal WM_TIMER timers - user timers don't use WM_TIMER (the
+// event loop runs them via AZ_RUN_TIMER), so a TimerId can't collide with them
+//
 // ID sent by WM_TIMER to re-generate the DOM
 const AZ_TICK_REGENERATE_DOM: usize = 1;
 // ID sent by WM_TIMER to check the thread results
 const AZ_THREAD_TICK: usize = 2;
+// ID sent by WM_TIMER to poll the system idle time
+const AZ_IDLE_TICK: usize = 3;
+// ID sent by the event loop (as WM_TIMER) to run the requestAnimationFrame callbacks
+const AZ_ANIMATION_FRAME_TICK: usize = 4;
+// ID sent by WM_TIMER once the cursor rested for the AppConfig::hover_intent_delay
+const AZ_HOVER_INTENT_TICK: usize = 5;
+// ID sent by WM_TIMER every 16ms while the window fades in, see WindowCreateOptions::fade_in
+const AZ_FADE_IN_TICK: usize = 6;
+// ID sent by WM_TIMER while rendering is suspended, to check whether the window is visible again
+const AZ_RESUME_RENDERING_TICK: usize = 7;
+// Redraws the window while a subtree is recorded, see CallbackInfo::render_subtree_to_frames
+const AZ_SUBTREE_RECORDING_TICK: usize = 8;
+// ID sent by WM_TIMER while auto-hiding scrollbars are visible, see ScrollbarConfig::auto_hide_delay
+const AZ_SCROLLBAR_FADE_TICK: usize = 9;
+// Runs the user timers while a modal loop blocks the event loop, see Window::in_modal_loop
+const AZ_MODAL_LOOP_TICK: usize = 10;
 
 const AZ_REGENERATE_DOM: u32 = WM_APP + 1;
 const AZ_REGENERATE_DISPLAY_LIST: u32 = WM_APP + 2;
 const AZ_REDO_HIT_TEST: u32 = WM_APP + 3;
 const AZ_GPU_SCROLL_RENDER: u32 = WM_APP + 4;
+// the decorations were toggled: re-calculate the non-client area
+const AZ_FRAME_CHANGED: u32 = WM_APP + 5;
+// the window entered / left fullscreen mode or the fullscreen monitor changed
+const AZ_FULLSCREEN_CHANGED: u32 = WM_APP + 6;
+// the always-on-top, click-through or tool window flags changed
+const AZ_WINDOW_STYLE_CHANGED: u32 = WM_APP + 7;
+// EventLoopProxy::wake() / send_user_event() was called from another thread
+const AZ_USER_EVENT: u32 = WM_APP + 8;
+// the thumbnail toolbar / preview image was changed, see taskbar::set_thumbnail_buttons()
+const AZ_TASKBAR_CHANGED: u32 = WM_APP + 9;
+// the window size was changed by a callback, i.e. CallbackInfo::animate_window_resize()
+const AZ_WINDOW_SIZE_CHANGED: u32 = WM_APP + 10;
+// sent by the event loop to run the user timer with the TimerId in the wparam
+const AZ_RUN_TIMER: u32 = WM_APP + 11;
+// returned by the WindowProc once the AZ_RUN_TIMER timer ran (DefWindowProc returns 0)
+const AZ_RUN_TIMER_DONE: LRESULT = 1;
 
 const CLASS_NAME: &str = "AzulApplicationClass";
 
@@ -120,25 +180,44 @@ impl RectTrait for RECT {
 }
 
 pub fn get_monitors(app: &App) -> MonitorVec {
-    MonitorVec::from_const_slice(&[]) // TODO
+    self::monitor::get_monitors()
+}
+
+/// Returns the time since the last keyboard / mouse input on the system
+pub extern "C" fn get_system_idle_time() -> OptionDuration {
+
+    use winapi::um::winuser::{GetLastInputInfo, LASTINPUTINFO};
+    use winapi::um::sysinfoapi::GetTickCount;
+
+    let mut last_input = LASTINPUTINFO {
+        cbSize: mem::size_of::<LASTINPUTINFO>() as u32,
+        dwTime: 0,
+    };
+
+    if unsafe { GetLastInputInfo(&mut last_input) } == 0 {
+        return OptionDuration::None;
+    }
+
+    // GetTickCount() wraps around after 49.7 days, same as dwTime
+    let idle_millis = unsafe { GetTickCount() }.wrapping_sub(last_input.dwTime);
+    OptionDuration::Some(std::time::Duration::from_millis(idle_millis as u64).into())
 }
 
 /// Main function that starts when app.run() is invoked
-pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, WindowsStartupError> {
+pub fn run(mut app: App, mut root_window: WindowCreateOptions) -> Result<isize, WindowsStartupError> {
 
     use winapi::{
         shared::minwindef::FALSE,
         um::{
             libloaderapi::GetModuleHandleW,
             wingdi::{wglMakeCurrent, CreateSolidBrush},
-            winbase::{INFINITE, WAIT_FAILED},
+            winbase::WAIT_FAILED,
             winuser::{
-                DispatchMessageW, GetDC, GetMessageW,
-                RegisterClassW, ReleaseDC, SetProcessDPIAware,
-                TranslateMessage, MsgWaitForMultipleObjects,
-                PeekMessageW, GetForegroundWindow,
-                CS_HREDRAW, CS_OWNDC, QS_ALLEVENTS,
-                CS_VREDRAW, MSG, WNDCLASSW, PM_NOREMOVE, PM_NOYIELD
+                DispatchMessageW, GetDC, RegisterClassW, ReleaseDC,
+                SetProcessDPIAware, TranslateMessage, MsgWaitForMultipleObjectsEx,
+                PeekMessageW, SendMessageW, CS_HREDRAW, CS_OWNDC, QS_ALLINPUT,
+                CS_VREDRAW, MSG, WNDCLASSW, PM_REMOVE, MWMO_INPUTAVAILABLE,
+                WM_QUIT, WM_TIMER,
             }
         },
     };
@@ -169,27 +248,70 @@ pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, WindowsS
     let dwm = DwmFunctions::initialize();
     let gl = GlFunctions::initialize();
 
+    // show the splash window while the font cache is being built, then
+    // cross-fade into the root window once it has rendered its first frame
+    let splash = app.splash.take().and_then(|options| {
+        let splash = SplashWindow::create(hinstance, &options, &dpi)?;
+        splash.set_progress(self::splash::SPLASH_PROGRESS_STARTED);
+        while !app.fc_cache.is_ready() {
+            splash.pump_messages();
+            std::thread::sleep(std::time::Duration::from_millis(16));
+        }
+        splash.set_progress(self::splash::SPLASH_PROGRESS_FONTS_LOADED);
+
+        if root_window.first_frame == FirstFrameMode::ShowImmediately {
+            root_window.first_frame = FirstFrameMode::HideUntilRendered;
+        }
+        if root_window.fade_in.is_none() {
+            root_window.fade_in = OptionDuration::Some(options.cross_fade);
+        }
+
+        Some(splash)
+    });
+
     let mut active_hwnds = Rc::new(RefCell::new(BTreeSet::new()));
 
-    {
+    let app_data_inner = {
         let App {
             data,
             config,
             windows,
             image_cache,
             fc_cache,
+            event_loop_proxy,
+            splash: _,
+            gl_quirk_rules,
         } = app;
 
+        let windows_to_create = windows.len() + 1;
+
+        let event_recording = config.event_recording.as_ref().and_then(|e| {
+            match EventRecordingState::new(e) {
+                Ok(o) => Some(o),
+                Err(_e) => {
+                    #[cfg(feature = "logging")] {
+                        error!("event recording disabled: {}", _e);
+                    }
+                    None
+                }
+            }
+        });
+
         let app_data_inner = Rc::new(RefCell::new(ApplicationData {
             hinstance,
             data,
             config,
             image_cache,
             fc_cache,
+            event_loop_proxy,
             windows: BTreeMap::new(),
             active_hwnds: active_hwnds.clone(),
             dwm,
             dpi,
+            pointer: PointerFunctions::init(),
+            event_recording,
+            splash,
+            gl_quirk_rules,
         }));
 
         let w = Window::create(
@@ -199,107 +321,270 @@ pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, WindowsS
         )?;
 
         active_hwnds.try_borrow_mut()?.insert(w.hwnd);
+        set_event_loop_waker(&app_data_inner.try_borrow()?.event_loop_proxy, w.hwnd);
         app_data_inner
             .try_borrow_mut()?
-            .windows
-            .insert(w.get_id(), w);
+            .insert_window(w);
+        app_data_inner.try_borrow()?.set_splash_progress(1, windows_to_create);
 
-        for opts in windows {
+        for (i, opts) in windows.into_iter().enumerate() {
             if let Ok(w) = Window::create(hinstance, opts, SharedApplicationData { inner: app_data_inner.clone() }) {
                 active_hwnds.try_borrow_mut()?.insert(w.hwnd);
                 app_data_inner
                     .try_borrow_mut()?
-                    .windows
-                    .insert(w.get_id(), w);
+                    .insert_window(w);
             }
+            app_data_inner.try_borrow()?.set_splash_progress(i + 2, windows_to_create);
         }
-    }
 
-    // Process the window messages one after another
-    //
-    // Multiple windows will process messages in sequence
-    // to avoid complicated multithreading logic
+        app_data_inner
+    };
+
+    // Only keep a weak reference: the app data has to be dropped
+    // in the WM_DESTROY of the last window, while OpenGL is current
+    let app_data_weak = Rc::downgrade(&app_data_inner);
+    mem::drop(app_data_inner);
+
+    // Process the window messages of all windows one after another (to avoid
+    // complicated multithreading logic), then sleep until either a new message
+    // arrives (EventLoopProxy::wake() posts a message) or the next timer has
+    // to run - the event loop does not use any CPU while the app is idle
     let mut msg: MSG = unsafe { mem::zeroed() };
-    let mut results = Vec::new();
-    let mut hwnds = Vec::new();
 
     'main: loop {
 
-        match active_hwnds.try_borrow().ok() {
-            Some(windows_vec) => {
-                hwnds = windows_vec.clone().into_iter().collect();
-            },
-            None => break 'main, // borrow error
+        unsafe {
+            while PeekMessageW(&mut msg, ptr::null_mut(), 0, 0, PM_REMOVE) > 0 {
+                if msg.message == WM_QUIT {
+                    break 'main;
+                }
+                TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
         }
 
-        // For single-window apps, GetMessageW will block until
-        // the next event comes in. For multi-window apps we have
-        // to use PeekMessage in order to not block in case that
-        // there are no messages for that window
+        match active_hwnds.try_borrow() {
+            Ok(hwnds) if !hwnds.is_empty() => { },
+            _ => break 'main, // all windows closed or borrow error
+        }
 
-        let is_multiwindow = match hwnds.len() {
-            0 | 1 => false,
-            _ => true,
+        let (timers_to_run, timeout) = match app_data_weak.upgrade() {
+            Some(app_data) => get_timers_ready_to_run(&app_data),
+            None => break 'main,
         };
 
-        if is_multiwindow {
-
-            for hwnd in hwnds.iter() {
-                unsafe {
-                    let r = PeekMessageW(&mut msg, *hwnd, 0, 0, PM_NOREMOVE);
-
-                    if r > 0 {
-                        // new message available
-                        let r = GetMessageW(&mut msg, *hwnd, 0, 0);
-                        TranslateMessage(&msg);
-                        DispatchMessageW(&msg);
-                        results.push(r);
-                    }
+        if !timers_to_run.is_empty() {
+            // run the timers in the WindowProc, then process
+            // the messages that the timer callbacks posted
+            let mut timers_not_run = Vec::new();
+            for (hwnd, timer_id) in timers_to_run {
+                if unsafe { SendMessageW(hwnd, AZ_RUN_TIMER, timer_id, 0) } != AZ_RUN_TIMER_DONE {
+                    timers_not_run.push((hwnd, timer_id));
                 }
             }
-
-            // It would be great if there was a function like
-            // MsgWaitForMultipleObjects([hwnd]), so that you could
-            // wait on one of many input events
-            //
-            // The best workaround is to get the foreground window
-            // (that the user is interacting with) and then
-            // wait until some event happens to that foreground window
-            let mut dump_msg: MSG = unsafe { mem::zeroed() };
-            while !hwnds.iter().any(|hwnd| unsafe { PeekMessageW(&mut dump_msg, *hwnd, 0, 0, PM_NOREMOVE) > 0 }) {
-                // reduce CPU load for multi-window apps
-                std::thread::sleep(std::time::Duration::from_millis(1));
-            }
-        } else {
-            for hwnd in hwnds.iter() {
-                unsafe {
-                    let r = GetMessageW(&mut msg, *hwnd, 0, 0);
-                    if r > 0 {
-                        TranslateMessage(&msg);
-                        DispatchMessageW(&msg);
-                    }
-                    results.push(r);
+            // timers that the WindowProc couldn't run (app data borrowed,
+            // window already destroyed) would be ready again immediately:
+            // skip them until their next interval instead of busy-looping
+            if !timers_not_run.is_empty() {
+                match app_data_weak.upgrade() {
+                    Some(app_data) => skip_timers(&app_data, &timers_not_run),
+                    None => break 'main,
                 }
             }
+            continue 'main;
         }
 
-        for r in results.iter() {
-            if !(*r > 0) {
-                break 'main; // error occured
+        let (replay_events, replay_timeout) = match app_data_weak.upgrade() {
+            Some(app_data) => get_replay_events_ready(&app_data),
+            None => break 'main,
+        };
+
+        if !replay_events.is_empty() {
+            // inject the events one after another, then process
+            // the messages that the event handlers posted
+            for (hwnd, event, hidpi_factor) in replay_events {
+                unsafe { self::recording::inject_synthetic_event(hwnd, &event, hidpi_factor) };
             }
+            continue 'main;
         }
 
-        if hwnds.is_empty() {
-            break 'main;
+        let timeout = timeout.min(replay_timeout);
+
+        let (animation_frame_hwnds, dwm_flush) = match app_data_weak.upgrade() {
+            Some(app_data) => get_animation_frame_windows(&app_data),
+            None => break 'main,
+        };
+
+        if !animation_frame_hwnds.is_empty() {
+            // wait for the next vertical blank, so that the requestAnimationFrame
+            // callbacks run once per frame (fall back to ~60 FPS without DWM)
+            let flushed = dwm_flush.map(|f| f() >= 0).unwrap_or(false);
+            if !flushed {
+                std::thread::sleep(std::time::Duration::from_millis(16));
+            }
+            for hwnd in animation_frame_hwnds {
+                unsafe { SendMessageW(hwnd, WM_TIMER, AZ_ANIMATION_FRAME_TICK, 0) };
+            }
+            continue 'main;
         }
 
-        hwnds.clear();
-        results.clear();
+        let r = unsafe {
+            MsgWaitForMultipleObjectsEx(0, ptr::null(), timeout, QS_ALLINPUT, MWMO_INPUTAVAILABLE)
+        };
+
+        if r == WAIT_FAILED {
+            break 'main;
+        }
     }
 
     Ok(msg.wParam as isize)
 }
 
+/// Returns the HWND + timer ID of all timers that are ready to run and the
+/// milliseconds until the next timer has to run (`INFINITE` if there are no timers)
+///
+/// Since the event loop is running again, no modal loop is active anymore:
+/// the AZ_MODAL_LOOP_TICK of all windows is stopped
+fn get_timers_ready_to_run(app_data: &RefCell<ApplicationData>) -> (Vec<(HWND, usize)>, DWORD) {
+
+    use winapi::um::winbase::INFINITE;
+
+    let mut app_data = match app_data.try_borrow_mut() {
+        Ok(o) => o,
+        Err(_) => return (Vec::new(), INFINITE),
+    };
+
+    let app_data = &mut *app_data;
+    let instant_now = (app_data.config.system_callbacks.get_system_time_fn.cb)();
+
+    let mut timers_to_run = Vec::new();
+    let mut next_timer_instant: Option<Instant> = None;
+
+    for window in app_data.windows.values_mut() {
+        if window.in_modal_loop {
+            window.in_modal_loop = false;
+            window.update_modal_loop_tick(&instant_now);
+        }
+        for timer_id in window.internal.get_timers_ready_to_run(&instant_now) {
+            timers_to_run.push((window.hwnd, timer_id.id));
+        }
+        if let Some(next) = window.internal.get_next_timer_instant() {
+            next_timer_instant = Some(match next_timer_instant {
+                Some(s) => s.min(next),
+                None => next,
+            });
+        }
+    }
+
+    let timeout = match next_timer_instant {
+        Some(next) => millis_until(&next, &instant_now),
+        None => INFINITE,
+    };
+
+    (timers_to_run, timeout)
+}
+
+/// Marks the given timers as run without invoking their callbacks,
+/// so that they only become ready again after their next interval
+fn skip_timers(app_data: &RefCell<ApplicationData>, timers: &[(HWND, usize)]) {
+
+    let mut app_data = match app_data.try_borrow_mut() {
+        Ok(o) => o,
+        Err(_) => return,
+    };
+
+    let app_data = &mut *app_data;
+    let instant_now = (app_data.config.system_callbacks.get_system_time_fn.cb)();
+
+    for (hwnd, timer_id) in timers {
+        let timer = app_data.windows
+            .get_mut(&(*hwnd as usize))
+            .and_then(|w| w.internal.timers.get_mut(&TimerId { id: *timer_id }));
+        if let Some(timer) = timer {
+            timer.last_run = OptionInstant::Some(instant_now.clone());
+        }
+    }
+}
+
+/// Returns the milliseconds from `instant_now` until `instant`, rounded up
+/// (waking up too early would only cause another wait)
+fn millis_until(instant: &Instant, instant_now: &Instant) -> DWORD {
+
+    use winapi::um::winbase::INFINITE;
+
+    if instant <= instant_now {
+        return 0;
+    }
+
+    match instant.duration_since(instant_now) {
+        Duration::System(d) => {
+            let d: std::time::Duration = d.into();
+            ((d.as_micros() + 999) / 1000).min((INFINITE - 1) as u128) as DWORD
+        },
+        // ticks have no fixed length (i.e. a custom get_system_time_fn that
+        // counts frames), check again after one frame at 60 FPS
+        Duration::Tick(_) => 16,
+    }
+}
+
+/// Returns the recorded events that are due (with the HWND and the DPI factor of the
+/// target window) and the milliseconds until the next event has to be replayed
+fn get_replay_events_ready(app_data: &RefCell<ApplicationData>) -> (Vec<(HWND, SyntheticEvent, f32)>, DWORD) {
+
+    use winapi::um::winbase::INFINITE;
+
+    let mut app_data = match app_data.try_borrow_mut() {
+        Ok(o) => o,
+        Err(_) => return (Vec::new(), INFINITE),
+    };
+
+    let app_data = &mut *app_data;
+    let (events, next) = match app_data.event_recording.as_mut() {
+        Some(s) => s.take_due_events(),
+        None => return (Vec::new(), INFINITE),
+    };
+
+    let events = events
+        .into_iter()
+        .filter_map(|(window_id, event)| {
+            let window = app_data.windows.get(&window_id)?;
+            Some((window.hwnd, event, window.internal.current_window_state.size.get_hidpi_factor()))
+        })
+        .collect();
+
+    let timeout = match next {
+        // round up, waking up too early would only cause another wait
+        Some(d) => ((d.as_micros() + 999) / 1000).min((INFINITE - 1) as u128) as DWORD,
+        None => INFINITE,
+    };
+
+    (events, timeout)
+}
+
+/// Returns all windows that requested an animation frame and the
+/// DwmFlush function to wait for the next vertical blank (if available)
+fn get_animation_frame_windows(app_data: &RefCell<ApplicationData>) -> (Vec<HWND>, Option<extern "system" fn() -> HRESULT>) {
+
+    let app_data = match app_data.try_borrow() {
+        Ok(o) => o,
+        Err(_) => return (Vec::new(), None),
+    };
+
+    // cross-fades are advanced every frame, too
+    let hwnds = app_data.windows
+        .values()
+        .filter(|window| {
+            !window.rendering_suspended && (
+                !window.internal.animation_frame_callbacks.is_empty() ||
+                !window.internal.crossfades.is_empty()
+            )
+        })
+        .map(|window| window.hwnd)
+        .collect();
+
+    (hwnds, app_data.dwm.as_ref().and_then(|dwm| dwm.DwmFlush))
+}
+
 fn encode_wide(input: &str) -> Vec<u16> {
     input
         .encode_utf16()
@@ -407,6 +692,68 @@ impl WrRenderNotifier for Notifier {
     }
 }
 
+/// Creates the WebRender renderer for an OpenGL context, used when a window
+/// is created and when the context has to be recreated after a GPU reset
+fn create_renderer(
+    gl_functions: Rc<GenericGlContext>,
+    gl_quirks: &GlQuirks,
+    debug_state: &DebugState,
+) -> Result<(WrRenderer, WrRenderApiSender), WrRendererError> {
+
+    use crate::wr_translate::wr_translate_debug_flags;
+    use webrender::api::ColorF as WrColorF;
+    use webrender::ProgramCache as WrProgramCache;
+
+    WrRenderer::new(
+        gl_functions,
+        Box::new(Notifier {}),
+        WrRendererOptions {
+            resource_override_path: None,
+            use_optimized_shaders: !gl_quirks.disable_optimized_shaders,
+            enable_aa: true,
+            enable_subpixel_aa: !gl_quirks.disable_subpixel_aa,
+            force_subpixel_aa: !gl_quirks.disable_subpixel_aa,
+            clear_color: WrColorF {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 0.0,
+            }, // transparent
+            panic_on_gl_error: false,
+            precache_flags: WrShaderPrecacheFlags::EMPTY,
+            cached_programs: if gl_quirks.disable_program_cache {
+                None
+            } else {
+                Some(WrProgramCache::new(None))
+            },
+            enable_multithreading: !gl_quirks.disable_multithreading,
+            debug_flags: wr_translate_debug_flags(debug_state),
+            ..WrRendererOptions::default()
+        },
+        WR_SHADER_CACHE,
+    )
+}
+
+// https://www.khronos.org/registry/OpenGL/extensions/ARB/ARB_robustness.txt
+type GlGetGraphicsResetStatusFn = extern "system" fn() -> u32;
+const GL_NO_ERROR: u32 = 0;
+
+/// Loads glGetGraphicsResetStatus(ARB), the context has to be current
+fn load_gl_reset_status_fn() -> Option<GlGetGraphicsResetStatusFn> {
+    use winapi::um::wingdi::wglGetProcAddress;
+    ["glGetGraphicsResetStatus", "glGetGraphicsResetStatusARB"]
+        .iter()
+        .find_map(|name| {
+            let mut func_name = encode_ascii(name);
+            let addr = unsafe { wglGetProcAddress(func_name.as_mut_ptr()) };
+            // wglGetProcAddress may return 1, 2, 3 or -1 instead of NULL on failure
+            match addr as isize {
+                -1 | 0 | 1 | 2 | 3 => None,
+                _ => Some(unsafe { mem::transmute::<_, GlGetGraphicsResetStatusFn>(addr) }),
+            }
+        })
+}
+
 #[derive(Debug, Clone)]
 struct SharedApplicationData {
     inner: Rc<RefCell<ApplicationData>>,
@@ -420,11 +767,39 @@ struct ApplicationData {
     config: AppConfig,
     image_cache: ImageCache,
     fc_cache: LazyFcCache,
+    // wakes up the event loop from other threads, see AZ_USER_EVENT
+    event_loop_proxy: EventLoopProxy,
     windows: BTreeMap<usize, Window>,
     // active HWNDS, tracked separately from the ApplicationData
     active_hwnds: Rc<RefCell<BTreeSet<HWND>>>,
     dwm: Option<DwmFunctions>,
     dpi: DpiFunctions,
+    // touch input (WM_POINTER messages)
+    pointer: PointerFunctions,
+    // recorded / replayed input events, see AppConfig::event_recording
+    event_recording: Option<EventRecordingState>,
+    // startup splash window, faded out once a window has presented its first frame
+    splash: Option<SplashWindow>,
+    // driver workarounds added by the app, see App::add_gl_quirk_rule
+    gl_quirk_rules: Vec<GlQuirkRule>,
+}
+
+impl ApplicationData {
+    // advances the splash progress bar after `created` of `total` windows have been created
+    fn set_splash_progress(&self, created: usize, total: usize) {
+        use self::splash::SPLASH_PROGRESS_FONTS_LOADED;
+        if let Some(splash) = self.splash.as_ref() {
+            let t = created as f32 / total.max(1) as f32;
+            splash.set_progress(SPLASH_PROGRESS_FONTS_LOADED + (1.0 - SPLASH_PROGRESS_FONTS_LOADED) * t);
+        }
+    }
+
+    fn insert_window(&mut self, window: Window) {
+        if let Some(recording) = self.event_recording.as_mut() {
+            recording.add_window(window.get_id());
+        }
+        self.windows.insert(window.get_id(), window);
+    }
 }
 
 // Extra functions from dwmapi.dll
@@ -433,14 +808,39 @@ struct DwmFunctions {
     DwmEnableBlurBehindWindow: Option<extern "system" fn(HWND, &DWM_BLURBEHIND) -> HRESULT>,
     DwmExtendFrameIntoClientArea: Option<extern "system" fn(HWND, &MARGINS) -> HRESULT>,
     DwmDefWindowProc: Option<extern "system" fn(HWND, u32, WPARAM, LPARAM, *mut LRESULT)>,
+    DwmFlush: Option<extern "system" fn() -> HRESULT>,
+    DwmSetWindowAttribute: Option<extern "system" fn(HWND, u32, *const c_void, u32) -> HRESULT>,
+    DwmGetWindowAttribute: Option<extern "system" fn(HWND, u32, *mut c_void, u32) -> HRESULT>,
+    DwmSetIconicThumbnail: Option<extern "system" fn(HWND, HBITMAP, u32) -> HRESULT>,
+    DwmSetIconicLivePreviewBitmap: Option<extern "system" fn(HWND, HBITMAP, *const POINT, u32) -> HRESULT>,
+    DwmInvalidateIconicBitmaps: Option<extern "system" fn(HWND) -> HRESULT>,
 }
 
+// DWMWINDOWATTRIBUTE::DWMWA_SYSTEMBACKDROP_TYPE (Windows 11 22H2 or later)
+const DWMWA_SYSTEMBACKDROP_TYPE: u32 = 38;
+// DWM_SYSTEMBACKDROP_TYPE
+const DWMSBT_MAINWINDOW: u32 = 2;
+const DWMSBT_TRANSIENTWINDOW: u32 = 3;
+const DWMSBT_TABBEDWINDOW: u32 = 4;
+// DWMWINDOWATTRIBUTE::DWMWA_CLOAK (Windows 8 or later)
+const DWMWA_CLOAK: u32 = 13;
+// DWMWINDOWATTRIBUTE::DWMWA_CLOAKED (Windows 8 or later)
+const DWMWA_CLOAKED: u32 = 14;
+// window was cloaked by the shell, i.e. it is on another virtual desktop
+const DWM_CLOAKED_SHELL: u32 = 0x2;
+
 impl fmt::Debug for DwmFunctions {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         (self._dwmapi_dll_handle as usize).fmt(f)?;
         (self.DwmEnableBlurBehindWindow.map(|f| f as usize)).fmt(f)?;
         (self.DwmExtendFrameIntoClientArea.map(|f| f as usize)).fmt(f)?;
         (self.DwmExtendFrameIntoClientArea.map(|f| f as usize)).fmt(f)?;
+        (self.DwmFlush.map(|f| f as usize)).fmt(f)?;
+        (self.DwmSetWindowAttribute.map(|f| f as usize)).fmt(f)?;
+        (self.DwmGetWindowAttribute.map(|f| f as usize)).fmt(f)?;
+        (self.DwmSetIconicThumbnail.map(|f| f as usize)).fmt(f)?;
+        (self.DwmSetIconicLivePreviewBitmap.map(|f| f as usize)).fmt(f)?;
+        (self.DwmInvalidateIconicBitmaps.map(|f| f as usize)).fmt(f)?;
         Ok(())
     }
 }
@@ -481,13 +881,160 @@ impl DwmFunctions {
             None
         };
 
+        let mut func_name = encode_ascii("DwmFlush");
+        let DwmFlush = unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
+        let DwmFlush = if DwmFlush != ptr::null_mut() {
+            Some(unsafe { mem::transmute(DwmFlush) })
+        } else {
+            None
+        };
+
+        let mut func_name = encode_ascii("DwmSetWindowAttribute");
+        let DwmSetWindowAttribute = unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
+        let DwmSetWindowAttribute = if DwmSetWindowAttribute != ptr::null_mut() {
+            Some(unsafe { mem::transmute(DwmSetWindowAttribute) })
+        } else {
+            None
+        };
+
+        let mut func_name = encode_ascii("DwmGetWindowAttribute");
+        let DwmGetWindowAttribute = unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
+        let DwmGetWindowAttribute = if DwmGetWindowAttribute != ptr::null_mut() {
+            Some(unsafe { mem::transmute(DwmGetWindowAttribute) })
+        } else {
+            None
+        };
+
+        let mut func_name = encode_ascii("DwmSetIconicThumbnail");
+        let DwmSetIconicThumbnail = unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
+        let DwmSetIconicThumbnail = if DwmSetIconicThumbnail != ptr::null_mut() {
+            Some(unsafe { mem::transmute(DwmSetIconicThumbnail) })
+        } else {
+            None
+        };
+
+        let mut func_name = encode_ascii("DwmSetIconicLivePreviewBitmap");
+        let DwmSetIconicLivePreviewBitmap = unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
+        let DwmSetIconicLivePreviewBitmap = if DwmSetIconicLivePreviewBitmap != ptr::null_mut() {
+            Some(unsafe { mem::transmute(DwmSetIconicLivePreviewBitmap) })
+        } else {
+            None
+        };
+
+        let mut func_name = encode_ascii("DwmInvalidateIconicBitmaps");
+        let DwmInvalidateIconicBitmaps = unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
+        let DwmInvalidateIconicBitmaps = if DwmInvalidateIconicBitmaps != ptr::null_mut() {
+            Some(unsafe { mem::transmute(DwmInvalidateIconicBitmaps) })
+        } else {
+            None
+        };
+
         Some(Self {
             _dwmapi_dll_handle: hDwmAPI_DLL,
             DwmEnableBlurBehindWindow,
             DwmExtendFrameIntoClientArea,
             DwmDefWindowProc,
+            DwmFlush,
+            DwmSetWindowAttribute,
+            DwmGetWindowAttribute,
+            DwmSetIconicThumbnail,
+            DwmSetIconicLivePreviewBitmap,
+            DwmInvalidateIconicBitmaps,
         })
     }
+
+    /// Cloaks / uncloaks the window: a cloaked window is shown and rendered
+    /// as usual, but not composed onto the screen. Returns false if cloaking
+    /// isn't supported (Windows 7 or older).
+    /// Returns whether the shell has cloaked the window, i.e. because
+    /// it is on a virtual desktop that is currently not visible
+    fn is_cloaked_by_shell(&self, hwnd: HWND) -> bool {
+
+        use winapi::shared::winerror::SUCCEEDED;
+
+        let get_attribute = match self.DwmGetWindowAttribute {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let mut cloaked: u32 = 0;
+        let result = get_attribute(
+            hwnd,
+            DWMWA_CLOAKED,
+            &mut cloaked as *mut u32 as *mut c_void,
+            mem::size_of::<u32>() as u32,
+        );
+
+        SUCCEEDED(result) && (cloaked & DWM_CLOAKED_SHELL) != 0
+    }
+
+    fn set_cloaked(&self, hwnd: HWND, cloaked: bool) -> bool {
+
+        use winapi::shared::winerror::SUCCEEDED;
+
+        let set_attribute = match self.DwmSetWindowAttribute {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let cloak: BOOL = if cloaked { TRUE } else { FALSE };
+        SUCCEEDED(set_attribute(
+            hwnd,
+            DWMWA_CLOAK,
+            &cloak as *const BOOL as *const c_void,
+            mem::size_of::<BOOL>() as u32,
+        ))
+    }
+
+    /// Enables the translucent backdrop of the window: Mica / Acrylic are set via
+    /// `DWMWA_SYSTEMBACKDROP_TYPE` (Windows 11), everything else or older systems
+    /// fall back to the DWM blur-behind
+    fn set_background_effect(&self, hwnd: HWND, effect: WindowBackgroundEffect) {
+
+        use winapi::shared::winerror::SUCCEEDED;
+
+        let backdrop_type = match effect {
+            WindowBackgroundEffect::None => return,
+            WindowBackgroundEffect::Blur => None,
+            WindowBackgroundEffect::Mica => Some(DWMSBT_MAINWINDOW),
+            WindowBackgroundEffect::MicaAlt => Some(DWMSBT_TABBEDWINDOW),
+            WindowBackgroundEffect::Acrylic => Some(DWMSBT_TRANSIENTWINDOW),
+        };
+
+        if let (Some(backdrop_type), Some(extend_frame), Some(set_attribute)) = (
+            backdrop_type,
+            self.DwmExtendFrameIntoClientArea,
+            self.DwmSetWindowAttribute,
+        ) {
+            // the system backdrop is drawn behind the frame, so the
+            // frame has to cover the entire client area
+            let margins = MARGINS {
+                cxLeftWidth: -1,
+                cxRightWidth: -1,
+                cyTopHeight: -1,
+                cyBottomHeight: -1,
+            };
+            let succeeded = SUCCEEDED(extend_frame(hwnd, &margins)) && SUCCEEDED(set_attribute(
+                hwnd,
+                DWMWA_SYSTEMBACKDROP_TYPE,
+                &backdrop_type as *const u32 as *const c_void,
+                mem::size_of::<u32>() as u32,
+            ));
+            if succeeded {
+                return;
+            }
+        }
+
+        if let Some(enable_blur_behind) = self.DwmEnableBlurBehindWindow {
+            let blur_behind = DWM_BLURBEHIND {
+                dwFlags: DWM_BB_ENABLE,
+                fEnable: TRUE,
+                hRgnBlur: ptr::null_mut(),
+                fTransitionOnMaximized: FALSE,
+            };
+            enable_blur_behind(hwnd, &blur_behind);
+        }
+    }
 }
 
 impl Drop for DwmFunctions {
@@ -1592,6 +2139,9 @@ struct Window {
     gl_functions: GlFunctions,
     /// OpenGL context pointer with compiled SVG and FXAA shaders
     gl_context_ptr: OptionGlContextPtr,
+    /// glGetGraphicsResetStatus, used to detect GPU resets after a frame has been
+    /// rendered. None if the driver doesn't support ARB_robustness
+    gl_reset_status: Option<GlGetGraphicsResetStatusFn>,
     /// Main render API that can be used to register and un-register fonts and images
     render_api: WrRenderApi,
     /// WebRender renderer implementation (software or hardware)
@@ -1602,12 +2152,46 @@ struct Window {
     menu_bar: Option<WindowsMenuBar>,
     /// ID -> Context menu callbacks (cleared when the context menu closes)
     context_menu: Option<CurrentContextMenu>,
-    /// Timer ID -> Win32 timer map
-    timers: BTreeMap<TimerId, TIMERPTR>,
     /// If threads is non-empty, the window will receive a WM_TIMER every 16ms
     thread_timer_running: Option<TIMERPTR>,
     /// characters are combined via two following wparam messages
     high_surrogate: Option<u16>,
+    /// Window style and position before entering fullscreen mode, None if not fullscreen
+    windowed_state: Option<self::monitor::WindowedState>,
+    /// Time spent in the phases of the current frame, see `AppConfig::jank_capture`
+    frame_stats: crate::jank::FrameStats,
+    /// Records the presented frames, see `AppConfig::frame_recording`
+    frame_recorder: Option<crate::frame_recording::FrameRecorder>,
+    /// Nodes that are recorded, see `CallbackInfo::render_subtree_to_frames()`
+    subtree_recorders: Vec<crate::frame_recording::SubtreeRecorder>,
+    /// Pointer ID of the last pen that touched the window (for haptic feedback)
+    pen_pointer_id: Option<u32>,
+    /// Cursor created from a `cursor: url(id)` image, destroyed when it is replaced
+    custom_cursor: Option<self::cursor::WindowCursor>,
+    /// Thumbnail toolbar buttons and preview image, see `taskbar::set_thumbnail_buttons()`
+    taskbar: self::taskbar::WindowTaskbar,
+    /// Message time and client position of the last WM_LBUTTONDOWN,
+    /// used to count double / triple clicks
+    last_left_click: Option<(u32, i32, i32)>,
+    /// Start and duration (in milliseconds) of the fade-in animation,
+    /// None if the window doesn't fade in (anymore)
+    fade_in: Option<(std::time::Instant, u64)>,
+    /// Last time the scrollbars were scrolled, hovered or dragged,
+    /// auto-hiding scrollbars fade out after `ScrollbarConfig::auto_hide_delay`
+    scrollbars_last_used: std::time::Instant,
+    /// Whether the window is cloaked until the first frame has been presented,
+    /// see `FirstFrameMode::HideUntilRendered`
+    cloaked_until_first_frame: bool,
+    /// Color that the window is filled with until the first frame has
+    /// been presented, see `FirstFrameMode::SplashColor`
+    splash_color: Option<ColorU>,
+    /// No frames are rendered while the window is minimized or on another virtual
+    /// desktop, timers and threads keep running. See `update_rendering_suspended()`
+    rendering_suspended: bool,
+    /// Whether a modal loop (moving / resizing the window, menus, modal dialogs)
+    /// blocks the event loop: the user timers are run by AZ_MODAL_LOOP_TICK
+    /// instead, until the event loop regains control
+    in_modal_loop: bool,
 }
 
 impl fmt::Debug for Window {
@@ -1620,6 +2204,7 @@ impl fmt::Debug for Window {
         self.menu_bar.fmt(f)?;
         self.context_menu.fmt(f)?;
         self.high_surrogate.fmt(f)?;
+        self.windowed_state.is_some().fmt(f)?;
         Ok(())
     }
 }
@@ -1640,6 +2225,11 @@ impl Drop for Window {
         if let Some(renderer) = self.renderer.take() {
             renderer.deinit();
         }
+
+        // restore the video mode of the monitor (exclusive fullscreen)
+        if let Some(windowed_state) = self.windowed_state.take() {
+            self::monitor::restore_display_settings(&windowed_state);
+        }
     }
 }
 
@@ -1655,6 +2245,206 @@ impl Window {
         self.hwnd as usize
     }
 
+    /// Checks whether the window is visible and returns whether rendering is suspended.
+    ///
+    /// While the window is minimized or cloaked by the shell (other virtual desktop),
+    /// the window is polled every 250ms, since there is no message when it is moved
+    /// to the current virtual desktop again. Once it is visible, the display list is
+    /// rebuilt, so that the skipped frames are not lost.
+    fn update_rendering_suspended(&mut self, dwm: Option<&DwmFunctions>) -> bool {
+
+        use winapi::um::winuser::{IsIconic, KillTimer, PostMessageW, SetTimer};
+
+        let minimized = unsafe { IsIconic(self.hwnd) } != 0;
+        // the window may be cloaked by azul itself until the first frame is rendered
+        let cloaked = !self.cloaked_until_first_frame &&
+            dwm.map(|d| d.is_cloaked_by_shell(self.hwnd)).unwrap_or(false);
+        let hidden = minimized || cloaked;
+
+        match (self.rendering_suspended, hidden) {
+            (false, true) => unsafe {
+                SetTimer(self.hwnd, AZ_RESUME_RENDERING_TICK, 250, None);
+            },
+            (true, false) => unsafe {
+                KillTimer(self.hwnd, AZ_RESUME_RENDERING_TICK);
+                PostMessageW(self.hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
+            },
+            _ => { },
+        }
+
+        self.rendering_suspended = hidden;
+        hidden
+    }
+
+    /// Returns whether the GPU was reset since the context was created,
+    /// the OpenGL context has to be current
+    fn is_gl_context_lost(&self) -> bool {
+        match self.gl_reset_status {
+            Some(get_graphics_reset_status) => (get_graphics_reset_status)() != GL_NO_ERROR,
+            None => false,
+        }
+    }
+
+    /// Recreates the OpenGL context and the renderer after a GPU reset. All fonts,
+    /// images and OpenGL textures of the window are lost, they are uploaded again
+    /// by the next AZ_REGENERATE_DOM message
+    fn recover_lost_gl_context(&mut self, hinstance: HINSTANCE) -> Result<(), WindowsWindowCreateError> {
+
+        use crate::{
+            compositor::Compositor,
+            wr_translate::{translate_document_id_wr, translate_id_namespace_wr, wr_translate_document_id},
+        };
+        use azul_core::{
+            app_resources::{GlTextureCache, RendererResources},
+            gl::GlContextPtr,
+            window::RendererType,
+        };
+        use winapi::um::{
+            wingdi::{wglDeleteContext, wglMakeCurrent},
+            winuser::{GetDC, ReleaseDC},
+        };
+
+        let old_context = match self.gl_context.take() {
+            Some(s) => s,
+            None => return Ok(()),
+        };
+
+        let gl_quirks = self.gl_context_ptr.as_ref().map(|g| g.get_quirks()).unwrap_or_default();
+
+        let hdc = unsafe { GetDC(self.hwnd) };
+
+        // Release everything that references the old context while it is current:
+        // calls on a lost context are ignored, but deleting the texture IDs with
+        // the new context current would delete the wrong textures
+        unsafe { wglMakeCurrent(hdc, old_context) };
+        if let Some(renderer) = self.renderer.take() {
+            renderer.deinit();
+        }
+        azul_core::gl::gl_textures_remove_active_pipeline(&self.internal.document_id);
+        self.internal.gl_texture_cache = GlTextureCache::default();
+        self.gl_context_ptr = None.into();
+        self.gl_reset_status = None;
+        unsafe {
+            wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+            wglDeleteContext(old_context);
+        }
+
+        let extra = ExtraWglFunctions::load()?;
+        let new_context = match create_gl_context(self.hwnd, hinstance, &extra) {
+            Ok(o) => o,
+            Err(_) => {
+                unsafe { ReleaseDC(self.hwnd, hdc) };
+                return Err(WindowsWindowCreateError::NoGlContext);
+            },
+        };
+
+        unsafe { wglMakeCurrent(hdc, new_context) };
+        self.gl_functions.load();
+        self.gl_context = Some(new_context);
+        self.gl_reset_status = load_gl_reset_status_fn();
+        self.gl_context_ptr = Some(GlContextPtr::new(
+            RendererType::Hardware,
+            self.gl_functions.functions.clone(),
+            gl_quirks,
+        )).into();
+
+        let renderer = create_renderer(
+            self.gl_functions.functions.clone(),
+            &gl_quirks,
+            &self.internal.current_window_state.debug_state,
+        );
+
+        unsafe {
+            wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+            ReleaseDC(self.hwnd, hdc);
+        }
+
+        let (mut renderer, sender) = renderer.map_err(WindowsWindowCreateError::Renderer)?;
+        renderer.set_external_image_handler(Box::new(Compositor::default()));
+
+        // new renderer = new document and ID namespace, all resources
+        // have to be registered again under the new namespace
+        let mut render_api = sender.create_api();
+        let physical_size = self.internal.current_window_state.size.get_physical_size();
+        let framebuffer_size = WrDeviceIntSize::new(physical_size.width as i32, physical_size.height as i32);
+        self.internal.document_id = translate_document_id_wr(render_api.add_document(framebuffer_size));
+        self.internal.id_namespace = translate_id_namespace_wr(render_api.get_namespace_id());
+        self.internal.renderer_resources = RendererResources::default();
+
+        let mut txn = WrTransaction::new();
+        txn.set_document_view(WrDeviceIntRect::from_size(framebuffer_size));
+        render_api.send_transaction(wr_translate_document_id(self.internal.document_id), txn);
+
+        self.hit_tester = AsyncHitTester::Requested(
+            render_api.request_hit_tester(wr_translate_document_id(self.internal.document_id))
+        );
+        self.render_api = render_api;
+        self.renderer = Some(renderer);
+
+        Ok(())
+    }
+
+    /// Returns the current alpha value of the layered window:
+    /// the `WindowState::opacity`, scaled down while the window fades in
+    fn get_current_alpha(&self) -> u8 {
+        let opacity = self.internal.current_window_state.opacity.max(0.0).min(1.0);
+        let t = match self.fade_in {
+            Some((start, duration_millis)) if duration_millis > 0 => {
+                (start.elapsed().as_millis() as f32 / duration_millis as f32).min(1.0)
+            },
+            _ => 1.0,
+        };
+        libm::roundf(opacity * t * 255.0) as u8
+    }
+
+    /// Shows auto-hiding scrollbars again and (re-)starts the fade-out timer,
+    /// returns whether the display list has to be rebuilt because the scrollbars
+    /// were (partially) faded out
+    fn show_auto_hide_scrollbars(&mut self) -> bool {
+
+        use winapi::um::winuser::SetTimer;
+
+        let delay_millis = match self.internal.scrollbar_interaction.config.get_auto_hide_delay_millis() {
+            Some(s) => s,
+            None => return false,
+        };
+
+        self.scrollbars_last_used = std::time::Instant::now();
+        unsafe { SetTimer(self.hwnd, AZ_SCROLLBAR_FADE_TICK, delay_millis.max(1).min(u32::MAX as u64) as u32, None); }
+
+        let was_hidden = self.internal.scrollbar_interaction.opacity < 1.0;
+        self.internal.scrollbar_interaction.opacity = 1.0;
+        was_hidden
+    }
+
+    /// Sets the cursor of the window, custom cursors fall back to `cursor_icon`
+    /// if the cursor can't be created from the image
+    fn set_cursor(&mut self, cursor_icon: MouseCursorType, custom_cursor: Option<CustomCursor>) {
+
+        use winapi::um::winuser::{SetClassLongPtrW, SetCursor, GCLP_HCURSOR};
+        use self::cursor::{create_custom_cursor, load_system_cursor, WindowCursor};
+
+        // only create the cursor again if the image changed
+        let window_cursor = match (self.custom_cursor.take(), custom_cursor) {
+            (Some(current), Some(new)) if current.cursor == new => Some(current),
+            (_, Some(new)) => Some(WindowCursor { handle: create_custom_cursor(&new), cursor: new }),
+            (_, None) => None,
+        };
+
+        let handle = match window_cursor.as_ref() {
+            Some(c) if !c.handle.is_null() => c.handle,
+            _ => load_system_cursor(cursor_icon),
+        };
+
+        unsafe {
+            SetClassLongPtrW(self.hwnd, GCLP_HCURSOR, handle as isize);
+            SetCursor(handle);
+        }
+
+        // the previous custom cursor is destroyed after it has been replaced
+        self.custom_cursor = window_cursor;
+    }
+
     // Creates a new HWND according to the options
     fn create(
         hinstance: HINSTANCE,
@@ -1667,7 +2457,6 @@ impl Window {
             wr_translate::{
                 translate_document_id_wr,
                 translate_id_namespace_wr,
-                wr_translate_debug_flags,
                 wr_translate_document_id,
             },
         };
@@ -1682,8 +2471,6 @@ impl Window {
                 WindowFrame,
             },
         };
-        use webrender::api::ColorF as WrColorF;
-        use webrender::ProgramCache as WrProgramCache;
         use winapi::{
             shared::windef::POINT,
             um::{
@@ -1695,7 +2482,7 @@ impl Window {
                 winuser::{
                     CreateWindowExW, DestroyWindow, GetClientRect, GetCursorPos, GetDC,
                     GetWindowRect, ReleaseDC, ScreenToClient, SetMenu, CW_USEDEFAULT, WS_CAPTION,
-                    WS_EX_ACCEPTFILES, WS_EX_APPWINDOW, WS_MAXIMIZEBOX, WS_MINIMIZEBOX,
+                    WS_CHILD, WS_CLIPSIBLINGS, WS_EX_ACCEPTFILES, WS_MAXIMIZEBOX, WS_MINIMIZEBOX,
                     WS_OVERLAPPED, WS_POPUP, WS_SYSMENU, WS_TABSTOP, WS_THICKFRAME,
                     ShowWindow, SW_HIDE, SW_MAXIMIZE, SW_MINIMIZE, SW_NORMAL, SW_SHOWNORMAL,
                 },
@@ -1704,6 +2491,10 @@ impl Window {
         use winapi::um::winuser::{
             SetWindowPos, HWND_TOP, SWP_FRAMECHANGED, SWP_NOMOVE, SWP_NOZORDER,
         };
+
+        // azul.toml overrides the renderer options and debug flags
+        crate::config_file::get().apply_to_window(&mut options);
+
         let parent_window = match options
             .state
             .platform_specific_options
@@ -1715,17 +2506,31 @@ impl Window {
             None => ptr::null_mut(),
         };
 
-        let mut class_name = encode_wide(CLASS_NAME);
-        let mut window_title = encode_wide(options.state.title.as_str());
+        // embed the window as a child window (WS_CHILD) inside of a foreign HWND
+        let embedded_parent = match options.parent_handle.as_ref() {
+            Some(RawWindowHandle::Windows(handle)) if !handle.hwnd.is_null() => Some(handle.hwnd as HWND),
+            _ => None,
+        };
 
-        let data_ptr = Box::into_raw(Box::new(shared_application_data.clone())) as *mut SharedApplicationData as *mut c_void;
+        // fading windows start out fully transparent
+        let fade_in_millis = match options.fade_in.into_option() {
+            Some(azul_core::task::Duration::System(d)) => Some(d.millis()),
+            _ => None,
+        };
+        let initial_alpha = if fade_in_millis.is_some() {
+            0
+        } else {
+            libm::roundf(options.state.opacity.max(0.0).min(1.0) * 255.0) as u8
+        };
 
-        // Create the window
-        let hwnd = unsafe {
-            CreateWindowExW(
-                WS_EX_APPWINDOW | WS_EX_ACCEPTFILES,
-                class_name.as_mut_ptr(),
-                window_title.as_mut_ptr(),
+        let (parent_window, style, ex_style, x, y) = match embedded_parent {
+            Some(parent) => {
+                // child windows can't have a title bar or be on top of their parent
+                options.state.flags.has_decorations = false;
+                (parent, WS_CHILD | WS_CLIPSIBLINGS | WS_TABSTOP, WS_EX_ACCEPTFILES, 0, 0)
+            },
+            None => (
+                parent_window,
                 WS_OVERLAPPED
                     | WS_CAPTION
                     | WS_SYSMENU
@@ -1734,9 +2539,28 @@ impl Window {
                     | WS_MAXIMIZEBOX
                     | WS_TABSTOP
                     | WS_POPUP,
-                // Size and position: set later, after DPI factor has been queried
-                CW_USEDEFAULT, // x
-                CW_USEDEFAULT, // y
+                get_window_ex_style(&options.state.flags, initial_alpha),
+                // Position: set later, after DPI factor has been queried
+                CW_USEDEFAULT,
+                CW_USEDEFAULT,
+            ),
+        };
+
+        let mut class_name = encode_wide(CLASS_NAME);
+        let mut window_title = encode_wide(options.state.title.as_str());
+
+        let data_ptr = Box::into_raw(Box::new(shared_application_data.clone())) as *mut SharedApplicationData as *mut c_void;
+
+        // Create the window
+        let hwnd = unsafe {
+            CreateWindowExW(
+                ex_style,
+                class_name.as_mut_ptr(),
+                window_title.as_mut_ptr(),
+                style,
+                // Size: set later, after DPI factor has been queried
+                x,
+                y,
                 if options.size_to_content { 0 } else { libm::roundf(options.state.size.dimensions.width) as i32 }, // width
                 if options.size_to_content { 0 } else { libm::roundf(options.state.size.dimensions.height) as i32 }, // height
                 parent_window,
@@ -1752,9 +2576,17 @@ impl Window {
             ));
         }
 
-        // Get / store DPI
+        // layered windows are invisible until their opacity is set
+        if options.state.flags.is_click_through || initial_alpha != 255 {
+            use winapi::um::winuser::{SetLayeredWindowAttributes, LWA_ALPHA};
+            unsafe { SetLayeredWindowAttributes(hwnd, 0, initial_alpha, LWA_ALPHA); }
+        }
+
+        // Get / store DPI (unless overridden by the azul.toml)
         // NOTE: GetDpiForWindow would be easier, but it's Win10 only
-        let dpi = if let Ok(s) = shared_application_data.inner.try_borrow() {
+        let dpi = if let Some(dpi) = crate::config_file::get().dpi {
+            dpi
+        } else if let Ok(s) = shared_application_data.inner.try_borrow() {
             unsafe { s.dpi.hwnd_dpi(hwnd) }
         } else {
             96
@@ -1764,6 +2596,30 @@ impl Window {
 
         options.state.size.dpi = dpi;
 
+        // cloak the window before it is shown for the first time, it is
+        // uncloaked in WM_PAINT once the first frame has been presented
+        let cloaked_until_first_frame = match options.first_frame {
+            FirstFrameMode::HideUntilRendered => shared_application_data.inner
+                .try_borrow()
+                .ok()
+                .and_then(|s| s.dwm.as_ref().map(|dwm| dwm.set_cloaked(hwnd, true)))
+                .unwrap_or(false),
+            _ => false,
+        };
+
+        let splash_color = match options.first_frame {
+            FirstFrameMode::SplashColor(c) => Some(c),
+            _ => None,
+        };
+
+        if options.background_effect != WindowBackgroundEffect::None {
+            if let Ok(s) = shared_application_data.inner.try_borrow() {
+                if let Some(dwm) = s.dwm.as_ref() {
+                    dwm.set_background_effect(hwnd, options.background_effect);
+                }
+            }
+        }
+
         // Window created, now try initializing OpenGL context
         let renderer_types = match options.renderer.into_option() {
             Some(s) => match s.hw_accel {
@@ -1779,6 +2635,12 @@ impl Window {
         let mut extra = ExtraWglFunctions::load()?;
         let mut gl = GlFunctions::initialize();
         let mut gl_context_ptr: OptionGlContextPtr = None.into();
+        let mut gl_quirks = GlQuirks::default();
+
+        let gl_quirk_rules = shared_application_data.inner
+            .try_borrow()
+            .map(|s| s.gl_quirk_rules.clone())
+            .unwrap_or_default();
 
         for r in renderer_types {
             rt = r;
@@ -1786,6 +2648,21 @@ impl Window {
                 RendererType::Software => {}
                 RendererType::Hardware => {
                     if let Ok(o) = create_gl_context(hwnd, hinstance, &extra) {
+                        // check the driver for known bugs before using the context
+                        let driver = unsafe {
+                            let hdc = GetDC(hwnd);
+                            wglMakeCurrent(hdc, o);
+                            gl.load();
+                            let driver = GlDriverInfo::query(&gl.functions);
+                            wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+                            ReleaseDC(hwnd, hdc);
+                            driver
+                        };
+                        gl_quirks = crate::gl_quirks::resolve_gl_quirks(&driver, &gl_quirk_rules);
+                        if gl_quirks.force_software_rendering {
+                            unsafe { wglDeleteContext(o) };
+                            continue;
+                        }
                         opengl_context = Some(o);
                         break;
                     }
@@ -1799,7 +2676,7 @@ impl Window {
                 unsafe { wglMakeCurrent(hdc, hrc) };
                 gl.load();
                 // compiles SVG and FXAA shader programs...
-                let ptr = GlContextPtr::new(rt, gl.functions.clone());
+                let ptr = GlContextPtr::new(rt, gl.functions.clone(), gl_quirks);
 
                 /*
                 match options.renderer.as_ref().map(|v| v.vsync) {
@@ -1831,30 +2708,16 @@ impl Window {
             unsafe { wglMakeCurrent(hdc, *hrc) };
         }
 
+        let gl_reset_status = match opengl_context {
+            Some(_) => load_gl_reset_status_fn(),
+            None => None,
+        };
+
         // Invoke callback to initialize UI for the first time
-        let (mut renderer, sender) = match WrRenderer::new(
+        let (mut renderer, sender) = match create_renderer(
             gl.functions.clone(),
-            Box::new(Notifier {}),
-            WrRendererOptions {
-                resource_override_path: None,
-                use_optimized_shaders: true,
-                enable_aa: true,
-                enable_subpixel_aa: true,
-                force_subpixel_aa: true,
-                clear_color: WrColorF {
-                    r: 0.0,
-                    g: 0.0,
-                    b: 0.0,
-                    a: 0.0,
-                }, // transparent
-                panic_on_gl_error: false,
-                precache_flags: WrShaderPrecacheFlags::EMPTY,
-                cached_programs: Some(WrProgramCache::new(None)),
-                enable_multithreading: true,
-                debug_flags: wr_translate_debug_flags(&options.state.debug_state),
-                ..WrRendererOptions::default()
-            },
-            WR_SHADER_CACHE,
+            &gl_quirks,
+            &options.state.debug_state,
         ) {
             Ok(o) => o,
             Err(e) => unsafe {
@@ -1930,6 +2793,7 @@ impl Window {
                         window_create_options: options.clone(),
                         document_id,
                         id_namespace,
+                        system_style: self::system_style::query_system_style(),
                     },
                     data,
                     image_cache,
@@ -1945,6 +2809,7 @@ impl Window {
                             window_state.focused_node,
                             layout_results,
                             &window_state.mouse_state.cursor_position,
+                            &window_state.touch_state,
                             window_state.size.get_hidpi_factor(),
                         )
                     },
@@ -1952,6 +2817,13 @@ impl Window {
             })
         };
 
+        #[cfg(feature = "logging")] {
+            crate::logging::warn_shortcut_conflicts(
+                internal.current_window_state.title.as_str(),
+                &internal.shortcuts,
+            );
+        }
+
         // Since the menu bar affects the window size, set it first,
         // before querying the window size again
         let mut menu_bar = None;
@@ -1994,6 +2866,21 @@ impl Window {
 
         unsafe { ShowWindow(hwnd, hidden_sw_options); }
 
+        // the window isn't stored yet, so WM_ERASEBKGND can't paint the splash color
+        if let Some(c) = splash_color {
+            use winapi::um::winuser::{FillRect, GetDC, ReleaseDC};
+            use winapi::um::wingdi::{CreateSolidBrush, DeleteObject, RGB};
+            unsafe {
+                let hdc = GetDC(hwnd);
+                let mut rect: RECT = mem::zeroed();
+                GetClientRect(hwnd, &mut rect);
+                let brush = CreateSolidBrush(RGB(c.r, c.g, c.b));
+                FillRect(hdc, &rect, brush);
+                DeleteObject(brush as *mut c_void);
+                ReleaseDC(hwnd, hdc);
+            }
+        }
+
         // Query the client area from Win32 (not DPI adjusted) and adjust framebuffer
         let mut rect: RECT = unsafe { mem::zeroed() };
         let current_window_size = unsafe { GetClientRect(hwnd, &mut rect) };
@@ -2089,23 +2976,71 @@ impl Window {
             unsafe { SetTimer(hwnd, AZ_TICK_REGENERATE_DOM, 200, None); }
         }
 
+        // poll the system idle time once per second, only if the app wants idle events
+        let idle_detection_enabled = shared_application_data.inner
+            .try_borrow()
+            .map(|s| s.config.user_idle_timeout.is_some())
+            .unwrap_or(false);
+
+        if idle_detection_enabled {
+            use winapi::um::winuser::SetTimer;
+            unsafe { SetTimer(hwnd, AZ_IDLE_TICK, 1000, None); }
+        }
+
+        let frame_recorder = shared_application_data.inner
+            .try_borrow()
+            .ok()
+            .and_then(|s| s.config.frame_recording.as_ref().map(|f| {
+                use azul_core::window::{RawWindowHandle, WindowsHandle};
+                crate::frame_recording::FrameRecorder::new(f, RawWindowHandle::Windows(WindowsHandle {
+                    hwnd: hwnd as *mut core::ffi::c_void,
+                    hinstance: hinstance as *mut core::ffi::c_void,
+                }))
+            }));
+
         use winapi::um::winuser::PostMessageW;
         unsafe { PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0 ); }
 
+        // WM_NCCALCSIZE was sent before the window was stored,
+        // remove the frame once the window state is available
+        if !internal.current_window_state.flags.has_decorations {
+            unsafe { PostMessageW(hwnd, AZ_FRAME_CHANGED, 0, 0); }
+        }
+
+        // the window is shown maximized until it is stored,
+        // then it is moved to the monitor selected by fullscreen_mode
+        if internal.current_window_state.flags.frame == WindowFrame::Fullscreen {
+            unsafe { PostMessageW(hwnd, AZ_FULLSCREEN_CHANGED, 0, 0); }
+        }
+
         let mut window = Window {
             hwnd,
             internal,
             gl_context: opengl_context,
             gl_functions: gl,
             gl_context_ptr,
+            gl_reset_status,
             render_api,
             renderer: Some(renderer),
             hit_tester: AsyncHitTester::Requested(hit_tester),
             menu_bar,
             context_menu: None,
-            timers: BTreeMap::new(),
             thread_timer_running: None,
             high_surrogate: None,
+            windowed_state: None,
+            frame_stats: crate::jank::FrameStats::default(),
+            frame_recorder,
+            subtree_recorders: Vec::new(),
+            pen_pointer_id: None,
+            custom_cursor: None,
+            taskbar: self::taskbar::WindowTaskbar::default(),
+            last_left_click: None,
+            fade_in: None,
+            scrollbars_last_used: std::time::Instant::now(),
+            cloaked_until_first_frame,
+            splash_color,
+            rendering_suspended: false,
+            in_modal_loop: false,
         };
 
         // invoke the create callback, if there is any
@@ -2173,6 +3108,15 @@ impl Window {
 
         unsafe { ShowWindow(hwnd, sw_options); }
 
+        if let Some(fade_in_millis) = fade_in_millis {
+            use winapi::um::winuser::SetTimer;
+            window.fade_in = Some((std::time::Instant::now(), fade_in_millis));
+            unsafe { SetTimer(hwnd, AZ_FADE_IN_TICK, 16, None); }
+        }
+
+        // auto-hiding scrollbars are visible at startup, then fade out
+        window.show_auto_hide_scrollbars();
+
         // NOTE: The window is NOT stored yet
         Ok(window)
     }
@@ -2183,20 +3127,41 @@ impl Window {
         removed: FastBTreeSet<TimerId>
     ) {
 
-        use winapi::um::winuser::{SetTimer, KillTimer};
+        use winapi::um::winuser::SetTimer;
+
+        // timers are run by the event loop (see get_timers_ready_to_run),
+        // which wakes up when the next timer is ready
+        let timers_added = !added.is_empty();
 
         for (id, timer) in added {
-            let res = unsafe { SetTimer(self.hwnd, id.id, timer.tick_millis().min(u32::MAX as u64) as u32, None) };
             self.internal.timers.insert(id, timer);
-            self.timers.insert(id, res);
         }
 
         for id in removed {
-            if let Some(_) = self.internal.timers.remove(&id) {
-                if let Some(handle) = self.timers.remove(&id) {
-                    unsafe { KillTimer(self.hwnd, handle) };
-                }
-            }
+            self.internal.timers.remove(&id);
+        }
+
+        // the event loop is blocked: tick immediately, so that
+        // AZ_MODAL_LOOP_TICK picks up the deadline of the new timers
+        if timers_added && self.in_modal_loop {
+            unsafe { SetTimer(self.hwnd, AZ_MODAL_LOOP_TICK, 0, None) };
+        }
+    }
+
+    // While a modal loop blocks the event loop, the window gets a single
+    // AZ_MODAL_LOOP_TICK once its next timer is ready
+    fn update_modal_loop_tick(&self, instant_now: &Instant) {
+
+        use winapi::um::winuser::{SetTimer, KillTimer};
+
+        match self.internal.get_next_timer_instant() {
+            Some(next) if self.in_modal_loop => {
+                let millis = millis_until(&next, instant_now);
+                unsafe { SetTimer(self.hwnd, AZ_MODAL_LOOP_TICK, millis, None) };
+            },
+            _ => {
+                unsafe { KillTimer(self.hwnd, AZ_MODAL_LOOP_TICK) };
+            },
         }
     }
 
@@ -2368,7 +3333,28 @@ fn create_gl_context(hwnd: HWND, hinstance: HINSTANCE, extra: &ExtraWglFunctions
         0,
     ];
 
-    let gl32_context = unsafe { (wglCreateContextAttribsARB)(hDC, ptr::null_mut(), gl32_attribs.as_ptr()) };
+    // https://www.khronos.org/registry/OpenGL/extensions/ARB/WGL_ARB_create_context_robustness.txt
+    const WGL_CONTEXT_FLAGS_ARB: i32 = 0x2094;
+    const WGL_CONTEXT_ROBUST_ACCESS_BIT_ARB: i32 = 0x00000004;
+    const WGL_CONTEXT_RESET_NOTIFICATION_STRATEGY_ARB: i32 = 0x8256;
+    const WGL_LOSE_CONTEXT_ON_RESET_ARB: i32 = 0x8252;
+
+    // Try to create a robust context first, so that GPU resets
+    // (driver updates, TDR) can be detected and recovered from
+    let robust_gl32_attribs = [
+        WGL_CONTEXT_MAJOR_VERSION_ARB, 3,
+        WGL_CONTEXT_MINOR_VERSION_ARB, 2,
+        WGL_CONTEXT_PROFILE_MASK_ARB,  WGL_CONTEXT_CORE_PROFILE_BIT_ARB,
+        WGL_CONTEXT_FLAGS_ARB,         WGL_CONTEXT_ROBUST_ACCESS_BIT_ARB,
+        WGL_CONTEXT_RESET_NOTIFICATION_STRATEGY_ARB, WGL_LOSE_CONTEXT_ON_RESET_ARB,
+        0,
+    ];
+
+    let mut gl32_context = unsafe { (wglCreateContextAttribsARB)(hDC, ptr::null_mut(), robust_gl32_attribs.as_ptr()) };
+    if gl32_context.is_null() {
+        gl32_context = unsafe { (wglCreateContextAttribsARB)(hDC, ptr::null_mut(), gl32_attribs.as_ptr()) };
+    }
+
     if gl32_context.is_null() {
         unsafe { ReleaseDC(hwnd, hDC); }
         return Err(OpenGLNotAvailable(get_last_error()));
@@ -2546,11 +3532,15 @@ unsafe extern "system" fn WindowProc(
         WM_MOUSEMOVE, WM_DESTROY, WM_PAINT, WM_ACTIVATE,
         WM_MOUSEWHEEL, WM_SIZE, WM_NCHITTEST,
         WM_LBUTTONDOWN, WM_DPICHANGED, WM_RBUTTONDOWN,
+        WM_SYSCOLORCHANGE, WM_SETTINGCHANGE, SPI_SETHIGHCONTRAST,
         WM_LBUTTONUP, WM_RBUTTONUP, WM_MBUTTONUP, WM_MBUTTONDOWN,
         WM_MOUSELEAVE, WM_DISPLAYCHANGE, WM_SIZING,
         WM_QUIT, WM_HSCROLL, WM_VSCROLL, WM_WINDOWPOSCHANGED,
         WM_KEYUP, WM_KEYDOWN, WM_SYSKEYUP, WM_SYSKEYDOWN,
         WM_CHAR, WM_SYSCHAR, WHEEL_DELTA, WM_SETFOCUS, WM_KILLFOCUS,
+        WM_QUERYENDSESSION, WM_ENDSESSION, WM_GETMINMAXINFO,
+        WM_NCCALCSIZE, WM_NCMOUSEMOVE, WM_NCLBUTTONDOWN, WM_NCLBUTTONUP,
+        WM_ENTERSIZEMOVE, WM_ENTERMENULOOP, WM_ENTERIDLE,
 
         VK_F4,
         CREATESTRUCTW, GWLP_USERDATA,
@@ -2582,6 +3572,17 @@ unsafe extern "system" fn WindowProc(
 
         let msg_start = std::time::Instant::now();
 
+        // record the input event before it is handled, see AppConfig::event_recording
+        {
+            let ab = &mut *app_borrow;
+            if let (Some(recording), Some(current_window)) = (ab.event_recording.as_mut(), ab.windows.get(&hwnd_key)) {
+                let hidpi_factor = current_window.internal.current_window_state.size.get_hidpi_factor();
+                if let Some(event) = self::recording::message_to_synthetic_event(msg, wparam, lparam, hidpi_factor) {
+                    recording.record(hwnd_key, &event);
+                }
+            }
+        }
+
         let r = match msg {
             AZ_REGENERATE_DOM => {
 
@@ -2622,12 +3623,15 @@ unsafe extern "system" fn WindowProc(
                     let mut hit_tester = &mut current_window.hit_tester;
                     let internal = &mut current_window.internal;
                     let gl_context = &current_window.gl_context_ptr;
+                    let _phase = current_window.frame_stats.phase("regenerate_styled_dom");
 
                     // unset the focus
                     internal.current_window_state.focused_node = None;
 
                     let mut resource_updates = Vec::new();
                     fc_cache.apply_closure(|fc_cache| {
+                        #[cfg(feature = "logging")]
+                        let _span = crate::logging::span(crate::logging::LOG_TARGET_LAYOUT, "regenerate_styled_dom");
                         internal.regenerate_styled_dom(
                             data,
                             image_cache,
@@ -2644,6 +3648,7 @@ unsafe extern "system" fn WindowProc(
                                      window_state.focused_node,
                                      layout_results,
                                      &window_state.mouse_state.cursor_position,
+                                     &window_state.touch_state,
                                      window_state.size.get_hidpi_factor(),
                                 )
                             }
@@ -2691,6 +3696,7 @@ unsafe extern "system" fn WindowProc(
                         current_window.internal.current_window_state.focused_node,
                         &current_window.internal.layout_results,
                         &current_window.internal.current_window_state.mouse_state.cursor_position,
+                        &current_window.internal.current_window_state.touch_state,
                         current_window.internal.current_window_state.size.get_hidpi_factor(),
                     );
 
@@ -2712,6 +3718,8 @@ unsafe extern "system" fn WindowProc(
                         &current_window.internal.document_id,
                         None,
                         None,
+                        None,
+                        None,
                         &None,
                         azul_layout::do_the_relayout,
                     );
@@ -2773,6 +3781,10 @@ unsafe extern "system" fn WindowProc(
                             &mut destroyed_windows,
                         );
 
+                        // On::UserEvent / On::HoverIntent callbacks have been invoked
+                        current_window.internal.current_window_state.user_event = None.into();
+                        current_window.internal.current_window_state.hover_intent = false;
+
                         let mut gl = &mut current_window.gl_functions.functions;
                         gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
                         gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
@@ -2798,6 +3810,12 @@ unsafe extern "system" fn WindowProc(
                 let mut ab = &mut *app_borrow;
                 destroy_windows(ab, destroyed_windows);
 
+                #[cfg(feature = "logging")] {
+                    crate::logging::update_window_state_summary(
+                        ab.windows.values().map(|w| &w.internal.current_window_state)
+                    );
+                }
+
                 mem::drop(ab);
 
                 match ret {
@@ -2836,9 +3854,20 @@ unsafe extern "system" fn WindowProc(
                 let ab = &mut *app_borrow;
                 let image_cache = &ab.image_cache;
                 let windows = &mut ab.windows;
+                let dwm = ab.dwm.as_ref();
 
                 if let Some(current_window) =  windows.get_mut(&hwnd_key) {
 
+                    // rebuilt once the window is visible again
+                    if current_window.update_rendering_suspended(dwm) {
+                        mem::drop(app_borrow);
+                        return 0;
+                    }
+
+                    #[cfg(feature = "logging")]
+                    let _span = crate::logging::span(crate::logging::LOG_TARGET_RENDER, "rebuild_display_list");
+                    let _phase = current_window.frame_stats.phase("rebuild_display_list");
+
                     rebuild_display_list(
                         &mut current_window.internal,
                         &mut current_window.render_api,
@@ -2865,19 +3894,218 @@ unsafe extern "system" fn WindowProc(
                     -1
                 }
             },
+            AZ_FULLSCREEN_CHANGED => {
+
+                use winapi::um::winuser::{ShowWindow, SW_MAXIMIZE, SW_MINIMIZE};
+                use azul_core::window::WindowFrame;
+
+                let (flags, windowed_state) = match app_borrow.windows.get_mut(&hwnd_key) {
+                    Some(current_window) => (
+                        current_window.internal.current_window_state.flags,
+                        current_window.windowed_state.take(),
+                    ),
+                    None => {
+                        mem::drop(app_borrow);
+                        return DefWindowProcW(hwnd, msg, wparam, lparam);
+                    },
+                };
+
+                // SetWindowPos / ShowWindow send WM_SIZE synchronously,
+                // so the application data must not be borrowed
+                mem::drop(app_borrow);
+
+                // leave the current fullscreen mode first (also when switching monitors)
+                if let Some(windowed_state) = windowed_state.as_ref() {
+                    self::monitor::exit_fullscreen(hwnd, windowed_state);
+                }
+
+                let new_windowed_state = match flags.frame {
+                    WindowFrame::Fullscreen => self::monitor::enter_fullscreen(hwnd, flags.fullscreen_mode),
+                    WindowFrame::Maximized => { ShowWindow(hwnd, SW_MAXIMIZE); None },
+                    WindowFrame::Minimized => { ShowWindow(hwnd, SW_MINIMIZE); None },
+                    WindowFrame::Normal => None,
+                };
+
+                if let Ok(mut app_borrow) = shared_application_data.inner.try_borrow_mut() {
+                    if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                        current_window.windowed_state = new_windowed_state;
+                    }
+                }
+
+                0
+            },
+            AZ_USER_EVENT => {
+
+                use azul_core::callbacks::OptionRefAny;
+
+                let ab = &mut *app_borrow;
+
+                // deliver one event at a time: wait until all windows
+                // have processed the previous event (see AZ_REDO_HIT_TEST)
+                let previous_event_pending = ab.windows.values()
+                    .any(|w| w.internal.current_window_state.user_event.is_some());
+
+                if previous_event_pending {
+                    PostMessageW(hwnd, AZ_USER_EVENT, 0, 0);
+                } else if let Some(user_event) = ab.event_loop_proxy.pop_user_event() {
+                    for window in ab.windows.values_mut() {
+                        window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+                        window.internal.current_window_state.user_event = OptionRefAny::Some(user_event.clone());
+                        PostMessageW(window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    }
+                    // process the remaining events after this one
+                    PostMessageW(hwnd, AZ_USER_EVENT, 0, 0);
+                }
+
+                mem::drop(app_borrow);
+                0
+            },
+            AZ_TASKBAR_CHANGED => {
+                let ab = &mut *app_borrow;
+                let dwm = ab.dwm.as_ref();
+                if let Some(current_window) = ab.windows.get_mut(&hwnd_key) {
+                    for update in self::taskbar::take_pending_updates(hwnd) {
+                        current_window.taskbar.apply_update(hwnd, update, dwm);
+                    }
+                }
+                mem::drop(app_borrow);
+                0
+            },
+            self::taskbar::WM_DWMSENDICONICTHUMBNAIL => {
+                use winapi::shared::minwindef::{HIWORD, LOWORD};
+                let max_width = HIWORD(lparam as u32) as usize;
+                let max_height = LOWORD(lparam as u32) as usize;
+                if let (Some(current_window), Some(dwm)) = (app_borrow.windows.get(&hwnd_key), app_borrow.dwm.as_ref()) {
+                    current_window.taskbar.send_iconic_thumbnail(hwnd, max_width, max_height, dwm);
+                }
+                mem::drop(app_borrow);
+                0
+            },
+            self::taskbar::WM_DWMSENDICONICLIVEPREVIEWBITMAP => {
+                if let (Some(current_window), Some(dwm)) = (app_borrow.windows.get(&hwnd_key), app_borrow.dwm.as_ref()) {
+                    current_window.taskbar.send_iconic_live_preview(hwnd, dwm);
+                }
+                mem::drop(app_borrow);
+                0
+            },
+            AZ_WINDOW_STYLE_CHANGED => {
+
+                let (flags, alpha) = match app_borrow.windows.get(&hwnd_key) {
+                    Some(current_window) => (
+                        current_window.internal.current_window_state.flags,
+                        current_window.get_current_alpha(),
+                    ),
+                    None => {
+                        mem::drop(app_borrow);
+                        return DefWindowProcW(hwnd, msg, wparam, lparam);
+                    },
+                };
+
+                // SetWindowPos / ShowWindow send messages synchronously,
+                // so the application data must not be borrowed
+                mem::drop(app_borrow);
+                set_window_ex_style(hwnd, &flags, alpha);
+                0
+            },
+            AZ_WINDOW_SIZE_CHANGED => {
+
+                use winapi::um::winuser::{
+                    GetClientRect, GetWindowRect, SetWindowPos,
+                    SWP_NOMOVE, SWP_NOZORDER, SWP_NOACTIVATE,
+                };
+                use azul_core::window::WindowFrame;
+
+                let new_size = match app_borrow.windows.get(&hwnd_key) {
+                    Some(current_window) if current_window.internal.current_window_state.flags.frame == WindowFrame::Normal => {
+                        current_window.internal.current_window_state.size.get_physical_size()
+                    },
+                    _ => {
+                        mem::drop(app_borrow);
+                        return 0;
+                    },
+                };
+
+                // SetWindowPos sends WM_SIZE synchronously (which re-layouts
+                // the window), so the application data must not be borrowed
+                mem::drop(app_borrow);
+
+                let mut window_rect: RECT = mem::zeroed();
+                let mut client_rect: RECT = mem::zeroed();
+                GetWindowRect(hwnd, &mut window_rect);
+                GetClientRect(hwnd, &mut client_rect);
+
+                let client_width = client_rect.right - client_rect.left;
+                let client_height = client_rect.bottom - client_rect.top;
+
+                // the size is already applied, i.e. after the user resized the window
+                if client_width == new_size.width as i32 && client_height == new_size.height as i32 {
+                    return 0;
+                }
+
+                // the window state stores the client size, SetWindowPos
+                // expects the size including the non-client area
+                let frame_width = (window_rect.right - window_rect.left) - client_width;
+                let frame_height = (window_rect.bottom - window_rect.top) - client_height;
+
+                SetWindowPos(
+                    hwnd,
+                    ptr::null_mut(),
+                    0,
+                    0,
+                    new_size.width as i32 + frame_width,
+                    new_size.height as i32 + frame_height,
+                    SWP_NOMOVE | SWP_NOZORDER | SWP_NOACTIVATE,
+                );
+                0
+            },
+            AZ_FRAME_CHANGED => {
+
+                use winapi::um::winuser::{
+                    SetWindowPos, SWP_FRAMECHANGED, SWP_NOMOVE,
+                    SWP_NOSIZE, SWP_NOZORDER, SWP_NOACTIVATE,
+                };
+
+                // SetWindowPos sends WM_NCCALCSIZE synchronously,
+                // so the application data must not be borrowed
+                mem::drop(app_borrow);
+                SetWindowPos(
+                    hwnd,
+                    ptr::null_mut(),
+                    0,
+                    0,
+                    0,
+                    0,
+                    SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+                );
+                0
+            },
             AZ_GPU_SCROLL_RENDER => {
 
-                match app_borrow.windows.get_mut(&hwnd_key) {
-                    Some(current_window) => {
-                        generate_frame(
-                            &mut current_window.internal,
-                            &mut current_window.render_api,
-                            false,
+                let ab = &mut *app_borrow;
+                let image_cache = &ab.image_cache;
+                let dwm = ab.dwm.as_ref();
+
+                // the scroll position is rendered once the window is visible again
+                let current_window = match ab.windows.get_mut(&hwnd_key) {
+                    Some(w) => if w.update_rendering_suspended(dwm) { None } else { Some(w) },
+                    None => None,
+                };
+
+                if let Some(current_window) = current_window {
+                    let display_list_rebuilt = generate_scroll_frame(
+                        &mut current_window.internal,
+                        &mut current_window.render_api,
+                        image_cache,
+                    );
+
+                    if display_list_rebuilt {
+                        let wr_document_id = wr_translate_document_id(current_window.internal.document_id);
+                        current_window.hit_tester = AsyncHitTester::Requested(
+                            current_window.render_api.request_hit_tester(wr_document_id)
                         );
+                    }
 
-                        PostMessageW(hwnd, WM_PAINT, 0, 0);
-                    },
-                    None => { },
+                    PostMessageW(hwnd, WM_PAINT, 0, 0);
                 }
 
                 mem::drop(app_borrow);
@@ -2894,14 +4122,44 @@ unsafe extern "system" fn WindowProc(
                 mem::drop(app_borrow);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
+            WM_ENTERSIZEMOVE | WM_ENTERMENULOOP | WM_ENTERIDLE => {
+                // moving / resizing the window, menus and modal dialogs run their
+                // own message loop, which blocks the event loop until it ends
+                let instant_now = (app_borrow.config.system_callbacks.get_system_time_fn.cb)();
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    if !current_window.in_modal_loop {
+                        current_window.in_modal_loop = true;
+                        current_window.update_modal_loop_tick(&instant_now);
+                    }
+                }
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
             WM_ERASEBKGND => {
+
+                use winapi::um::winuser::{FillRect, GetClientRect};
+                use winapi::um::wingdi::{CreateSolidBrush, DeleteObject, RGB};
+
+                // the splash color covers the window until the first frame is presented
+                let splash_color = app_borrow.windows.get(&hwnd_key).and_then(|w| w.splash_color);
                 mem::drop(app_borrow);
+
+                if let Some(c) = splash_color {
+                    let mut rect: RECT = mem::zeroed();
+                    GetClientRect(hwnd, &mut rect);
+                    let brush = CreateSolidBrush(RGB(c.r, c.g, c.b));
+                    FillRect(wparam as HDC, &rect, brush);
+                    DeleteObject(brush as *mut c_void);
+                }
+
                 1
             },
             WM_SETFOCUS => {
                 if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
                     current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
                     current_window.internal.current_window_state.flags.has_focus = true;
+                    // the lock keys may have been toggled in another application
+                    event::update_lock_keys(&mut current_window.internal.current_window_state.keyboard_state);
                     PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
                     mem::drop(app_borrow);
                     0
@@ -2926,9 +4184,8 @@ unsafe extern "system" fn WindowProc(
 
                 use winapi::{
                     um::winuser::{
-                        SetClassLongPtrW, TrackMouseEvent,
-                        TME_LEAVE, HOVER_DEFAULT, TRACKMOUSEEVENT,
-                        GCLP_HCURSOR
+                        TrackMouseEvent, TME_LEAVE,
+                        HOVER_DEFAULT, TRACKMOUSEEVENT,
                     },
                     shared::windowsx::{GET_X_LPARAM, GET_Y_LPARAM}
                 };
@@ -2941,13 +4198,26 @@ unsafe extern "system" fn WindowProc(
                 let x = GET_X_LPARAM(lparam);
                 let y = GET_Y_LPARAM(lparam);
 
-                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                let ApplicationData { windows, image_cache, config, .. } = &mut *app_borrow;
+
+                if let Some(current_window) = windows.get_mut(&hwnd_key) {
+
+                    use winapi::um::winuser::SetTimer;
+                    use azul_core::task::Duration;
 
                     let pos = CursorPosition::InWindow(LogicalPosition::new(
                         x as f32 / current_window.internal.current_window_state.size.get_hidpi_factor(),
                         y as f32 / current_window.internal.current_window_state.size.get_hidpi_factor(),
                     ));
 
+                    // (re-)start the hover intent delay, the cursor has to rest
+                    // (WM_MOUSEMOVE is also sent if the cursor didn't move)
+                    if current_window.internal.current_window_state.mouse_state.cursor_position != pos {
+                        if let Some(Duration::System(delay)) = config.hover_intent_delay.into_option() {
+                            SetTimer(hwnd, AZ_HOVER_INTENT_TICK, delay.millis().max(1).min(u32::MAX as u64) as u32, None);
+                        }
+                    }
+
                     // call SetCapture(hwnd) so that we can capture the WM_MOUSELEAVE event
                     let cur_cursor_pos = current_window.internal.current_window_state.mouse_state.cursor_position;
                     let prev_cursor_pos = current_window.internal.previous_window_state
@@ -2966,6 +4236,7 @@ unsafe extern "system" fn WindowProc(
                     let previous_state = current_window.internal.current_window_state.clone();
                     current_window.internal.previous_window_state = Some(previous_state);
                     current_window.internal.current_window_state.mouse_state.cursor_position = pos;
+                    set_input_modality(hwnd, &mut current_window.internal.current_window_state.touch_state, &mut current_window.internal.current_window_state.system_style, get_mouse_message_input_modality());
                     
                     // mouse moved, so we need a new hit test
                     let hit_test = crate::wr_translate::fullhittest_new_webrender(
@@ -2974,21 +4245,18 @@ unsafe extern "system" fn WindowProc(
                         current_window.internal.current_window_state.focused_node,
                         &current_window.internal.layout_results,
                         &current_window.internal.current_window_state.mouse_state.cursor_position,
+                        &current_window.internal.current_window_state.touch_state,
                         current_window.internal.current_window_state.size.get_hidpi_factor(),
                     );
-                    let cht = CursorTypeHitTest::new(&hit_test, &current_window.internal.layout_results);
+                    let cht = CursorTypeHitTest::new(&hit_test, &current_window.internal.layout_results, image_cache);
                     current_window.internal.current_window_state.last_hit_test = hit_test;
 
                     // update the cursor if necessary
-                    if current_window.internal.current_window_state.mouse_state.mouse_cursor_type != OptionMouseCursorType::Some(cht.cursor_icon) {
-                        // TODO: unset previous cursor?
+                    let current_custom_cursor = current_window.custom_cursor.as_ref().map(|c| &c.cursor);
+                    if current_window.internal.current_window_state.mouse_state.mouse_cursor_type != OptionMouseCursorType::Some(cht.cursor_icon) ||
+                       current_custom_cursor != cht.custom_cursor.as_ref() {
                         current_window.internal.current_window_state.mouse_state.mouse_cursor_type = OptionMouseCursorType::Some(cht.cursor_icon);
-                        SetClassLongPtrW(
-                                current_window.hwnd,
-                                GCLP_HCURSOR,
-                                (win32_translate_cursor(cht.cursor_icon) as isize)
-                                .try_into().unwrap_or(0)
-                        );
+                        current_window.set_cursor(cht.cursor_icon, cht.custom_cursor);
                     }
 
                     PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
@@ -3013,6 +4281,7 @@ unsafe extern "system" fn WindowProc(
                                 current_window.internal.current_window_state.keyboard_state.current_virtual_keycode = Some(vk).into();
                                 current_window.internal.current_window_state.keyboard_state.pressed_virtual_keycodes.insert_hm_item(vk);
                             }
+                            event::update_lock_keys(&mut current_window.internal.current_window_state.keyboard_state);
                             mem::drop(app_borrow);
 
                             // NOTE: due to a Win32 bug, the WM_CHAR message gets sent immediately after
@@ -3036,7 +4305,8 @@ unsafe extern "system" fn WindowProc(
             },
             WM_CHAR | WM_SYSCHAR => {
 
-                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                let ab = &mut *app_borrow;
+                if let Some(current_window) = ab.windows.get_mut(&hwnd_key) {
 
                     use std::char;
 
@@ -3061,6 +4331,9 @@ unsafe extern "system" fn WindowProc(
                     }
 
                     if let Some(c) = c {
+                        if let Some(recording) = ab.event_recording.as_mut() {
+                            recording.record(hwnd_key, &SyntheticEvent::Char(c));
+                        }
                         if !c.is_control() {
                             current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
                             current_window.internal.current_window_state.keyboard_state.current_char = Some(c as u32).into();
@@ -3091,6 +4364,7 @@ unsafe extern "system" fn WindowProc(
                             current_window.internal.current_window_state.keyboard_state.pressed_virtual_keycodes.remove_hm_item(&vk);
                             current_window.internal.current_window_state.keyboard_state.current_virtual_keycode = None.into();
                         }
+                        event::update_lock_keys(&mut current_window.internal.current_window_state.keyboard_state);
                         PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
                         mem::drop(app_borrow);
                         0
@@ -3105,11 +4379,14 @@ unsafe extern "system" fn WindowProc(
             },
             WM_MOUSELEAVE => {
 
-                use winapi::um::winuser::{SetClassLongPtrW, GCLP_HCURSOR};
                 use azul_core::window::{
                     FullHitTest, OptionMouseCursorType,
                     CursorPosition, LogicalPosition,
                 };
+                use winapi::um::winuser::KillTimer;
+
+                // the cursor didn't rest long enough, cancel the hover intent
+                KillTimer(hwnd, AZ_HOVER_INTENT_TICK);
 
                 if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
 
@@ -3121,15 +4398,12 @@ unsafe extern "system" fn WindowProc(
                         _ => LogicalPosition::zero(),
                     };
                     current_window.internal.current_window_state.mouse_state.cursor_position = CursorPosition::OutOfWindow(last_seen);
-                    current_window.internal.current_window_state.last_hit_test = FullHitTest::empty(current_focus);
+                    // only the mouse left the window, the touches are still on the screen
+                    let mut hit_test = FullHitTest::empty(current_focus);
+                    hit_test.touch_hit_tests = mem::take(&mut current_window.internal.current_window_state.last_hit_test.touch_hit_tests);
+                    current_window.internal.current_window_state.last_hit_test = hit_test;
                     current_window.internal.current_window_state.mouse_state.mouse_cursor_type = OptionMouseCursorType::None;
-
-                    SetClassLongPtrW(
-                        hwnd,
-                        GCLP_HCURSOR,
-                        (win32_translate_cursor(MouseCursorType::Default) as isize)
-                        .try_into().unwrap_or(0)
-                    );
+                    current_window.set_cursor(MouseCursorType::Default, None);
                     PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
                     mem::drop(app_borrow);
                     0
@@ -3138,11 +4412,69 @@ unsafe extern "system" fn WindowProc(
                     DefWindowProcW(hwnd, msg, wparam, lparam)
                 }
             },
-            WM_RBUTTONDOWN => {
-                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
-                    let previous_state = current_window.internal.current_window_state.clone();
-                    current_window.internal.previous_window_state = Some(previous_state);
-                    current_window.internal.current_window_state.mouse_state.right_down = true;
+            WM_POINTERDOWN | WM_POINTERUPDATE | WM_POINTERUP | WM_POINTERCAPTURECHANGED => {
+
+                use azul_core::window::InputModality;
+
+                // GET_POINTERID_WPARAM
+                let pointer_id = (wparam & 0xFFFF) as u32;
+
+                let ab = &mut *app_borrow;
+                if let Some(current_window) = ab.windows.get_mut(&hwnd_key) {
+
+                    let hidpi_factor = current_window.internal.current_window_state.size.get_hidpi_factor();
+                    let mut touch_state = current_window.internal.current_window_state.touch_state.clone();
+
+                    if msg == WM_POINTERDOWN && ab.pointer.is_pen(pointer_id) {
+                        current_window.pen_pointer_id = Some(pointer_id);
+                        set_input_modality(hwnd, &mut touch_state, &mut current_window.internal.current_window_state.system_style, InputModality::Pen);
+                    }
+
+                    match msg {
+                        WM_POINTERDOWN | WM_POINTERUPDATE => {
+                            match ab.pointer.get_touch_point(hwnd, pointer_id, hidpi_factor) {
+                                Some(touch) => {
+                                    touch_state.set_touch(touch);
+                                    set_input_modality(hwnd, &mut touch_state, &mut current_window.internal.current_window_state.system_style, InputModality::Touch);
+                                },
+                                None => touch_state.remove_touch(pointer_id as u64),
+                            }
+                        },
+                        // touch ended or was cancelled
+                        _ => touch_state.remove_touch(pointer_id as u64),
+                    }
+
+                    if touch_state != current_window.internal.current_window_state.touch_state {
+                        let previous_state = current_window.internal.current_window_state.clone();
+                        current_window.internal.previous_window_state = Some(previous_state);
+                        current_window.internal.current_window_state.touch_state = touch_state;
+
+                        // every touch has its own hit nodes
+                        let hit_test = crate::wr_translate::fullhittest_new_webrender(
+                            &*current_window.hit_tester.resolve(),
+                            current_window.internal.document_id,
+                            current_window.internal.current_window_state.focused_node,
+                            &current_window.internal.layout_results,
+                            &current_window.internal.current_window_state.mouse_state.cursor_position,
+                            &current_window.internal.current_window_state.touch_state,
+                            hidpi_factor,
+                        );
+                        current_window.internal.current_window_state.last_hit_test = hit_test;
+
+                        PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    }
+                }
+
+                mem::drop(app_borrow);
+                // let Windows promote the primary touch to mouse messages
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            WM_RBUTTONDOWN => {
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    let previous_state = current_window.internal.current_window_state.clone();
+                    current_window.internal.previous_window_state = Some(previous_state);
+                    current_window.internal.current_window_state.mouse_state.right_down = true;
+                    set_input_modality(hwnd, &mut current_window.internal.current_window_state.touch_state, &mut current_window.internal.current_window_state.system_style, get_mouse_message_input_modality());
                     PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
                 }
                 mem::drop(app_borrow);
@@ -3215,6 +4547,7 @@ unsafe extern "system" fn WindowProc(
                     let previous_state = current_window.internal.current_window_state.clone();
                     current_window.internal.previous_window_state = Some(previous_state);
                     current_window.internal.current_window_state.mouse_state.middle_down = true;
+                    set_input_modality(hwnd, &mut current_window.internal.current_window_state.touch_state, &mut current_window.internal.current_window_state.system_style, get_mouse_message_input_modality());
                     PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
                 }
                 mem::drop(app_borrow);
@@ -3231,10 +4564,39 @@ unsafe extern "system" fn WindowProc(
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
             WM_LBUTTONDOWN => {
+
+                use winapi::{
+                    shared::windowsx::{GET_X_LPARAM, GET_Y_LPARAM},
+                    um::winuser::{
+                        GetMessageTime, GetDoubleClickTime, GetSystemMetrics,
+                        SM_CXDOUBLECLK, SM_CYDOUBLECLK,
+                    },
+                };
+
                 if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
                     let previous_state = current_window.internal.current_window_state.clone();
                     current_window.internal.previous_window_state = Some(previous_state);
                     current_window.internal.current_window_state.mouse_state.left_down = true;
+                    set_input_modality(hwnd, &mut current_window.internal.current_window_state.touch_state, &mut current_window.internal.current_window_state.system_style, get_mouse_message_input_modality());
+
+                    // count consecutive clicks within the double-click rectangle
+                    // and time of the user settings (the window class does not
+                    // set CS_DBLCLKS, so there is no WM_LBUTTONDBLCLK to rely on)
+                    let time = GetMessageTime() as u32;
+                    let x = GET_X_LPARAM(lparam);
+                    let y = GET_Y_LPARAM(lparam);
+                    let is_repeated_click = match current_window.last_left_click {
+                        Some((last_time, last_x, last_y)) => {
+                            time.wrapping_sub(last_time) <= GetDoubleClickTime() &&
+                            (x - last_x).abs() <= GetSystemMetrics(SM_CXDOUBLECLK) / 2 &&
+                            (y - last_y).abs() <= GetSystemMetrics(SM_CYDOUBLECLK) / 2
+                        },
+                        None => false,
+                    };
+                    let mouse_state = &mut current_window.internal.current_window_state.mouse_state;
+                    mouse_state.click_count = if is_repeated_click { mouse_state.click_count.saturating_add(1) } else { 1 };
+                    current_window.last_left_click = Some((time, x, y));
+
                     PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
                 }
                 mem::drop(app_borrow);
@@ -3318,10 +4680,203 @@ unsafe extern "system" fn WindowProc(
                     DefWindowProcW(hwnd, msg, wparam, lparam)
                 }
             },
-            WM_DPICHANGED => {
+            WM_SYSCOLORCHANGE | WM_SETTINGCHANGE if msg == WM_SYSCOLORCHANGE ||
+                wparam as u32 == SPI_SETHIGHCONTRAST ||
+                wparam as u32 == self::system_style::SPI_SETCLIENTAREAANIMATION => {
+
+                // high contrast mode or animations were toggled or the system colors changed:
+                // regenerate the DOM, so that it is restyled with the new settings
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    let system_style = &mut current_window.internal.current_window_state.system_style;
+                    *system_style = azul_css::SystemStyle {
+                        pointer_coarse: system_style.pointer_coarse,
+                        .. self::system_style::query_system_style()
+                    };
+                }
                 mem::drop(app_borrow);
+                PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
+            WM_DPICHANGED => {
+
+                use winapi::shared::minwindef::LOWORD;
+                use winapi::um::winuser::{SetWindowPos, SWP_NOZORDER, SWP_NOACTIVATE};
+
+                // the window was moved to a monitor with a different scale factor (or the
+                // scale factor of the monitor changed): LOWORD(wparam) is the new DPI,
+                // lparam the suggested window rect at the new DPI
+                // the DPI stays fixed if it is overridden by the azul.toml
+                let new_dpi = crate::config_file::get().dpi.unwrap_or(LOWORD(wparam as u32) as u32);
+
+                let dpi_changed = match app_borrow.windows.get_mut(&hwnd_key) {
+                    Some(current_window) if current_window.internal.current_window_state.size.dpi != new_dpi => {
+                        let internal = &mut current_window.internal;
+                        let old_size = internal.current_window_state.size;
+                        internal.previous_window_state = Some(internal.current_window_state.clone());
+                        internal.current_window_state.size.dpi = new_dpi;
+                        // keep the physical size in case Windows doesn't send a WM_SIZE
+                        internal.current_window_state.size.dimensions = old_size
+                            .get_physical_size()
+                            .to_logical(internal.current_window_state.size.get_hidpi_factor());
+                        true
+                    },
+                    _ => false,
+                };
+
+                // SetWindowPos sends WM_SIZE synchronously, which
+                // re-layouts the window with the new DPI
+                mem::drop(app_borrow);
+
+                let suggested_rect = &*(lparam as *const RECT);
+                SetWindowPos(
+                    hwnd,
+                    ptr::null_mut(),
+                    suggested_rect.left,
+                    suggested_rect.top,
+                    suggested_rect.right - suggested_rect.left,
+                    suggested_rect.bottom - suggested_rect.top,
+                    SWP_NOZORDER | SWP_NOACTIVATE,
+                );
+
+                if dpi_changed {
+                    // font instances and images are registered per DPI,
+                    // so the quick resize of WM_SIZE isn't enough
+                    PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
+                }
+
+                0
+            },
+            WM_GETMINMAXINFO => {
+
+                use winapi::um::winuser::{
+                    AdjustWindowRectEx, GetWindowLongW,
+                    GWL_STYLE, GWL_EXSTYLE, MINMAXINFO,
+                };
+
+                // min / max size of the client area in physical pixels
+                let (min_size, max_size, has_decorations) = match app_borrow.windows.get(&hwnd_key) {
+                    Some(current_window) => {
+                        let hidpi_factor = current_window.internal.current_window_state.size.get_hidpi_factor();
+                        let (min, max) = current_window.internal.get_min_max_size();
+                        (
+                            min.map(|s| s.to_physical(hidpi_factor)),
+                            max.map(|s| s.to_physical(hidpi_factor)),
+                            current_window.internal.current_window_state.flags.has_decorations,
+                        )
+                    },
+                    None => (None, None, true),
+                };
+
+                mem::drop(app_borrow);
+
+                let result = DefWindowProcW(hwnd, msg, wparam, lparam);
+
+                // MINMAXINFO refers to the outer window size, windows without
+                // decorations have no non-client area (see WM_NCCALCSIZE)
+                let client_to_window = |width: u32, height: u32| {
+                    let mut rect = RECT { left: 0, top: 0, right: width as i32, bottom: height as i32 };
+                    if has_decorations {
+                        AdjustWindowRectEx(
+                            &mut rect,
+                            GetWindowLongW(hwnd, GWL_STYLE) as DWORD,
+                            FALSE,
+                            GetWindowLongW(hwnd, GWL_EXSTYLE) as DWORD,
+                        );
+                    }
+                    POINT { x: rect.right - rect.left, y: rect.bottom - rect.top }
+                };
+
+                let min_max_info = &mut *(lparam as *mut MINMAXINFO);
+                if let Some(min) = min_size {
+                    min_max_info.ptMinTrackSize = client_to_window(min.width, min.height);
+                }
+                if let Some(max) = max_size {
+                    min_max_info.ptMaxTrackSize = client_to_window(max.width, max.height);
+                }
+
+                result
+            },
+            WM_SIZING => {
+
+                use azul_core::window::LogicalSize;
+                use winapi::um::winuser::{
+                    GetClientRect, GetWindowRect,
+                    WMSZ_LEFT, WMSZ_TOP, WMSZ_BOTTOM, WMSZ_TOPLEFT,
+                    WMSZ_TOPRIGHT, WMSZ_BOTTOMLEFT,
+                };
+
+                // (resize increments, aspect ratio, base size for the increments), in physical pixels
+                let constraints = app_borrow.windows.get(&hwnd_key).and_then(|current_window| {
+                    let size = &current_window.internal.current_window_state.size;
+                    let hidpi_factor = size.get_hidpi_factor();
+                    let increments = size.resize_increments.into_option()
+                        .map(|i| (i.width * hidpi_factor, i.height * hidpi_factor));
+                    let aspect_ratio = size.aspect_ratio_lock.into_option().filter(|r| *r > 0.0);
+                    if increments.is_none() && aspect_ratio.is_none() {
+                        return None;
+                    }
+                    let base = current_window.internal.get_min_max_size().0.unwrap_or(LogicalSize::zero());
+                    Some((increments, aspect_ratio, base.width * hidpi_factor, base.height * hidpi_factor))
+                });
+
+                mem::drop(app_borrow);
+
+                match constraints {
+                    Some((increments, aspect_ratio, base_width, base_height)) => {
+
+                        let rect = &mut *(lparam as *mut RECT);
+                        let edge = wparam as u32;
+                        let is_edge = |edges: &[WPARAM]| edges.iter().any(|e| *e as u32 == edge);
+
+                        // size of the non-client area (title bar + borders)
+                        let mut window_rect: RECT = mem::zeroed();
+                        let mut client_rect: RECT = mem::zeroed();
+                        GetWindowRect(hwnd, &mut window_rect);
+                        GetClientRect(hwnd, &mut client_rect);
+                        let frame_width = (window_rect.right - window_rect.left) - (client_rect.right - client_rect.left);
+                        let frame_height = (window_rect.bottom - window_rect.top) - (client_rect.bottom - client_rect.top);
+
+                        let mut width = (rect.right - rect.left - frame_width) as f32;
+                        let mut height = (rect.bottom - rect.top - frame_height) as f32;
+
+                        if let Some((increment_width, increment_height)) = increments {
+                            if increment_width >= 1.0 {
+                                width = base_width + libm::roundf((width - base_width).max(0.0) / increment_width) * increment_width;
+                            }
+                            if increment_height >= 1.0 {
+                                height = base_height + libm::roundf((height - base_height).max(0.0) / increment_height) * increment_height;
+                            }
+                        }
+
+                        if let Some(aspect_ratio) = aspect_ratio {
+                            // dragging the top / bottom edge changes the height, otherwise the width decides
+                            if is_edge(&[WMSZ_TOP as WPARAM, WMSZ_BOTTOM as WPARAM]) {
+                                width = height * aspect_ratio;
+                            } else {
+                                height = width / aspect_ratio;
+                            }
+                        }
+
+                        let width = libm::roundf(width) as i32 + frame_width;
+                        let height = libm::roundf(height) as i32 + frame_height;
+
+                        // only move the edges that are being dragged
+                        if is_edge(&[WMSZ_LEFT as WPARAM, WMSZ_TOPLEFT as WPARAM, WMSZ_BOTTOMLEFT as WPARAM]) {
+                            rect.left = rect.right - width;
+                        } else {
+                            rect.right = rect.left + width;
+                        }
+                        if is_edge(&[WMSZ_TOP as WPARAM, WMSZ_TOPLEFT as WPARAM, WMSZ_TOPRIGHT as WPARAM]) {
+                            rect.top = rect.bottom - height;
+                        } else {
+                            rect.bottom = rect.top + height;
+                        }
+
+                        TRUE as LRESULT
+                    },
+                    None => DefWindowProcW(hwnd, msg, wparam, lparam),
+                }
+            },
             WM_SIZE => {
                 use azul_core::window::{WindowFrame, PhysicalSize};
                 use winapi::um::winuser::{
@@ -3341,8 +4896,24 @@ unsafe extern "system" fn WindowProc(
                 let fc_cache = &mut ab.fc_cache;
                 let windows = &mut ab.windows;
                 let image_cache = &ab.image_cache;
+                let dwm = ab.dwm.as_ref();
 
                 if let Some(current_window) = windows.get_mut(&hwnd_key) {
+
+                    // minimized: keep the layout of the window (instead of laying
+                    // it out at 0x0) and stop rendering until it is restored
+                    if wparam == SIZE_MINIMIZED {
+                        let previous_state = current_window.internal.current_window_state.clone();
+                        current_window.internal.previous_window_state = Some(previous_state);
+                        current_window.internal.current_window_state.flags.frame = WindowFrame::Minimized;
+                        current_window.update_rendering_suspended(dwm);
+                        mem::drop(app_borrow);
+                        return 0;
+                    }
+
+                    // restored: the display list is rebuilt below
+                    current_window.update_rendering_suspended(dwm);
+
                     fc_cache.apply_closure(|fc_cache| {
 
                         use winapi::um::winuser::{GetDC, ReleaseDC};
@@ -3354,8 +4925,10 @@ unsafe extern "system" fn WindowProc(
                             SIZE_MAXIMIZED => {
                                 new_window_state.flags.frame = WindowFrame::Maximized;
                             },
-                            SIZE_MINIMIZED => {
-                                new_window_state.flags.frame = WindowFrame::Minimized;
+                            // fullscreen windows are regular (restored) windows
+                            // that cover the monitor, see monitor::enter_fullscreen
+                            SIZE_RESTORED if current_window.internal.current_window_state.flags.frame == WindowFrame::Fullscreen => {
+                                new_window_state.flags.frame = WindowFrame::Fullscreen;
                             },
                             SIZE_RESTORED => {
                                 new_window_state.flags.frame = WindowFrame::Normal;
@@ -3445,9 +5018,184 @@ unsafe extern "system" fn WindowProc(
                 }
             },
             WM_NCHITTEST => {
+
+                use winapi::{
+                    um::winuser::{
+                        ScreenToClient, HTCLIENT, HTCAPTION,
+                        HTTOP, HTTOPRIGHT, HTRIGHT, HTBOTTOMRIGHT,
+                        HTBOTTOM, HTBOTTOMLEFT, HTLEFT, HTTOPLEFT,
+                        HTMAXBUTTON,
+                    },
+                    shared::windowsx::{GET_X_LPARAM, GET_Y_LPARAM}
+                };
+                use azul_core::window::{
+                    CursorPosition, LogicalPosition,
+                    WindowDragRegionHitTest, TouchState,
+                };
+                use azul_css::StyleWindowDragRegion;
+
+                // windows without decorations draw their own title bar: report the
+                // nodes marked with -azul-window-drag-region as caption / border, so
+                // that Windows moves and resizes the window natively (incl. snapping)
+                let drag_region = match app_borrow.windows.get_mut(&hwnd_key) {
+                    Some(current_window) if !current_window.internal.current_window_state.flags.has_decorations => {
+                        let mut point = POINT { x: GET_X_LPARAM(lparam), y: GET_Y_LPARAM(lparam) };
+                        ScreenToClient(hwnd, &mut point);
+
+                        let hidpi_factor = current_window.internal.current_window_state.size.get_hidpi_factor();
+                        let pos = CursorPosition::InWindow(LogicalPosition::new(
+                            point.x as f32 / hidpi_factor,
+                            point.y as f32 / hidpi_factor,
+                        ));
+
+                        let hit_test = crate::wr_translate::fullhittest_new_webrender(
+                            &*current_window.hit_tester.resolve(),
+                            current_window.internal.document_id,
+                            current_window.internal.current_window_state.focused_node,
+                            &current_window.internal.layout_results,
+                            &pos,
+                            &TouchState::default(),
+                            hidpi_factor,
+                        );
+
+                        WindowDragRegionHitTest::new(&hit_test, &current_window.internal.layout_results).drag_region
+                    },
+                    _ => StyleWindowDragRegion::None,
+                };
+
+                mem::drop(app_borrow);
+
+                let hit = DefWindowProcW(hwnd, msg, wparam, lparam);
+                if hit != HTCLIENT as LRESULT {
+                    hit
+                } else {
+                    match drag_region {
+                        StyleWindowDragRegion::None => hit,
+                        StyleWindowDragRegion::Move => HTCAPTION as LRESULT,
+                        StyleWindowDragRegion::ResizeN => HTTOP as LRESULT,
+                        StyleWindowDragRegion::ResizeNE => HTTOPRIGHT as LRESULT,
+                        StyleWindowDragRegion::ResizeE => HTRIGHT as LRESULT,
+                        StyleWindowDragRegion::ResizeSE => HTBOTTOMRIGHT as LRESULT,
+                        StyleWindowDragRegion::ResizeS => HTBOTTOM as LRESULT,
+                        StyleWindowDragRegion::ResizeSW => HTBOTTOMLEFT as LRESULT,
+                        StyleWindowDragRegion::ResizeW => HTLEFT as LRESULT,
+                        StyleWindowDragRegion::ResizeNW => HTTOPLEFT as LRESULT,
+                        // HTMAXBUTTON makes Windows 11 show the Snap Layouts flyout
+                        StyleWindowDragRegion::MaximizeButton => HTMAXBUTTON as LRESULT,
+                    }
+                }
+            },
+            WM_NCCALCSIZE => {
+
+                use winapi::um::winuser::{IsZoomed, NCCALCSIZE_PARAMS};
+
+                let has_decorations = app_borrow.windows.get(&hwnd_key)
+                    .map(|w| w.internal.current_window_state.flags.has_decorations)
+                    .unwrap_or(true);
+
+                mem::drop(app_borrow);
+
+                if wparam == TRUE as WPARAM && !has_decorations {
+                    // windows without decorations: the client area covers the entire window,
+                    // but the window keeps its WS_THICKFRAME / WS_MAXIMIZEBOX styles,
+                    // so that Aero Snap and the maximize / minimize animations still work
+                    let params = &mut *(lparam as *mut NCCALCSIZE_PARAMS);
+                    let original_rect = params.rgrc[0];
+                    DefWindowProcW(hwnd, msg, wparam, lparam);
+                    if IsZoomed(hwnd) != 0 {
+                        // maximized windows overhang the monitor by the frame thickness:
+                        // keep the frame that Windows calculated and apply it to the top, too
+                        params.rgrc[0].top = original_rect.top + (params.rgrc[0].left - original_rect.left);
+                    } else {
+                        params.rgrc[0] = original_rect;
+                    }
+                    0
+                } else {
+                    DefWindowProcW(hwnd, msg, wparam, lparam)
+                }
+            },
+            WM_NCMOUSEMOVE => {
+
+                use winapi::{
+                    um::winuser::{
+                        ScreenToClient, TrackMouseEvent, HTMAXBUTTON,
+                        TME_LEAVE, TME_NONCLIENT, HOVER_DEFAULT, TRACKMOUSEEVENT,
+                    },
+                    shared::windowsx::{GET_X_LPARAM, GET_Y_LPARAM}
+                };
+                use azul_core::window::{CursorPosition, LogicalPosition};
+
+                // the custom maximize button is reported as HTMAXBUTTON (see WM_NCHITTEST),
+                // so the mouse moves over it arrive as non-client messages:
+                // forward the position, so that :hover styles of the button still work
+                if wparam == HTMAXBUTTON as WPARAM {
+                    if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+
+                        let mut point = POINT { x: GET_X_LPARAM(lparam), y: GET_Y_LPARAM(lparam) };
+                        ScreenToClient(hwnd, &mut point);
+
+                        let hidpi_factor = current_window.internal.current_window_state.size.get_hidpi_factor();
+                        let pos = CursorPosition::InWindow(LogicalPosition::new(
+                            point.x as f32 / hidpi_factor,
+                            point.y as f32 / hidpi_factor,
+                        ));
+
+                        if current_window.internal.current_window_state.mouse_state.cursor_position != pos {
+                            TrackMouseEvent(&mut TRACKMOUSEEVENT {
+                                cbSize: mem::size_of::<TRACKMOUSEEVENT>() as u32,
+                                dwFlags: TME_LEAVE | TME_NONCLIENT,
+                                hwndTrack: hwnd,
+                                dwHoverTime: HOVER_DEFAULT,
+                            });
+
+                            let previous_state = current_window.internal.current_window_state.clone();
+                            current_window.internal.previous_window_state = Some(previous_state);
+                            current_window.internal.current_window_state.mouse_state.cursor_position = pos;
+                            PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                        }
+                    }
+                }
+
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            WM_NCMOUSELEAVE => {
+
+                use winapi::um::winuser::SendMessageW;
+
+                let has_decorations = app_borrow.windows.get(&hwnd_key)
+                    .map(|w| w.internal.current_window_state.flags.has_decorations)
+                    .unwrap_or(true);
+
                 mem::drop(app_borrow);
+
+                // mouse left the custom maximize button
+                if !has_decorations {
+                    SendMessageW(hwnd, WM_MOUSELEAVE, 0, 0);
+                }
+
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
+            WM_NCLBUTTONDOWN | WM_NCLBUTTONUP => {
+
+                use winapi::um::winuser::HTMAXBUTTON;
+
+                // clicks on the custom maximize button are handled by the application
+                // (DefWindowProc would draw and track the legacy caption button)
+                if wparam == HTMAXBUTTON as WPARAM {
+                    if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                        let previous_state = current_window.internal.current_window_state.clone();
+                        current_window.internal.previous_window_state = Some(previous_state);
+                        current_window.internal.current_window_state.mouse_state.left_down = msg == WM_NCLBUTTONDOWN;
+                        PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    }
+                    mem::drop(app_borrow);
+                    0
+                } else {
+                    mem::drop(app_borrow);
+                    DefWindowProcW(hwnd, msg, wparam, lparam)
+                }
+            },
             WM_PAINT => {
 
                 use winapi::um::{
@@ -3475,6 +5223,12 @@ unsafe extern "system" fn WindowProc(
                     },
                 };
 
+                if current_window.update_rendering_suspended(app.dwm.as_ref()) {
+                    ReleaseDC(hwnd, hDC);
+                    mem::drop(app_borrow);
+                    return DefWindowProcW(hwnd, msg, wparam, lparam);
+                }
+
                 let gl_context = match current_window.gl_context {
                     Some(s) => s,
                     None => {
@@ -3503,53 +5257,351 @@ unsafe extern "system" fn WindowProc(
                 gl.disable(gl_context_loader::gl::MULTISAMPLE);
                 gl.viewport(0, 0, rect.width() as i32, rect.height() as i32);
 
-                let mut current_program = [0_i32];
-                gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+                let mut current_program = [0_i32];
+                gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+
+                let framebuffer_size = WrDeviceIntSize::new(
+                    rect.width() as i32,
+                    rect.height() as i32
+                );
+
+                // Render
+                {
+                    let _phase = current_window.frame_stats.phase("render");
+                    if let Some(r) = current_window.renderer.as_mut() {
+                        r.update();
+                        let _ = r.render(framebuffer_size, 0);
+                    }
+
+                    // read back the frame before the back buffer is swapped
+                    let gl = &current_window.gl_functions.functions;
+                    let (width, height) = (rect.width() as i32, rect.height() as i32);
+                    if let Some(recorder) = current_window.frame_recorder.as_mut() {
+                        recorder.record_frame(width.max(0) as u32, height.max(0) as u32, || {
+                            gl.read_pixels(
+                                0, 0, width, height,
+                                gl_context_loader::gl::RGBA,
+                                gl_context_loader::gl::UNSIGNED_BYTE,
+                            )
+                        });
+                    }
+                    for recorder in current_window.subtree_recorders.iter_mut() {
+                        recorder.record_frame(height, |x, y, w, h| {
+                            gl.read_pixels(
+                                x, y, w, h,
+                                gl_context_loader::gl::RGBA,
+                                gl_context_loader::gl::UNSIGNED_BYTE,
+                            )
+                        });
+                    }
+
+                    SwapBuffers(hDC);
+                }
+
+                // the GPU was reset (driver update, TDR): the frame was not
+                // rendered, the context is recreated below
+                let gl_context_lost = current_window.is_gl_context_lost();
+
+                // the first frame has been presented: show the window
+                current_window.splash_color = None;
+                if current_window.cloaked_until_first_frame {
+                    current_window.cloaked_until_first_frame = false;
+                    if let Some(dwm) = app.dwm.as_ref() {
+                        dwm.set_cloaked(hwnd, false);
+                    }
+                }
+                if let Some(splash) = app.splash.take() {
+                    splash.fade_out();
+                }
+
+                let mut gl = &mut current_window.gl_functions.functions;
+                gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+                gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+                gl.use_program(current_program[0] as u32);
+
+                wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+                ReleaseDC(hwnd, hDC);
+
+                if gl_context_lost {
+                    match current_window.recover_lost_gl_context(app.hinstance) {
+                        Ok(()) => {
+                            #[cfg(feature = "logging")] {
+                                warn!("OpenGL context was lost (GPU reset), recreated the renderer");
+                            }
+                            // lay out the window again to re-upload all fonts and images
+                            PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
+                        },
+                        Err(_e) => {
+                            #[cfg(feature = "logging")] {
+                                error!("OpenGL context was lost (GPU reset), could not recreate the renderer: {:?}", _e);
+                            }
+                        },
+                    }
+                    mem::drop(app_borrow);
+                    return DefWindowProcW(hwnd, msg, wparam, lparam);
+                }
+
+                // frame presented: write a report if the frame took too long
+                let jank_capture = app.config.jank_capture.as_ref();
+                if let Some(report) = current_window.frame_stats.finish_frame(jank_capture) {
+                    if let Some(jank_capture) = jank_capture {
+                        let display_list = if jank_capture.dump_display_list {
+                            Some(crate::jank::dump_display_list(&current_window.internal, &app.image_cache))
+                        } else {
+                            None
+                        };
+                        match crate::jank::write_report(
+                            jank_capture,
+                            current_window.internal.current_window_state.title.as_str(),
+                            &report,
+                            display_list.as_ref(),
+                        ) {
+                            Some(_path) => {
+                                #[cfg(feature = "logging")] {
+                                    warn!("slow frame, report written to {}", _path.display());
+                                }
+                            },
+                            None => {
+                                #[cfg(feature = "logging")] {
+                                    warn!("slow frame, could not write the report:\n{}", report);
+                                }
+                            },
+                        }
+                    }
+                }
+
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            WM_TIMER | AZ_RUN_TIMER => {
+
+                use winapi::um::winuser::{GetDC, ReleaseDC};
+
+                let mut ab = &mut *app_borrow;
+                let hinstance = ab.hinstance;
+                let windows = &mut ab.windows;
+                let data = &mut ab.data;
+                let image_cache = &mut ab.image_cache;
+                let fc_cache = &mut ab.fc_cache;
+                let config = &ab.config;
+
+                let mut ret = ProcessEventResult::DoNothing;
+                let mut new_windows = Vec::new();
+                let mut destroyed_windows = Vec::new();
+
+                let r = match wparam {
+                    id if msg == AZ_RUN_TIMER => { // run user timer with ID "id"
+                        match windows.get_mut(&hwnd_key) {
+                            Some(current_window) => {
+
+                                let hDC = GetDC(hwnd);
+
+                                let gl_context = match current_window.gl_context {
+                                    Some(c) => {
+                                        if !hDC.is_null() {
+                                            wglMakeCurrent(hDC, c);
+                                        }
+                                    },
+                                    None => { },
+                                };
+
+                                let mut current_program = [0_i32];
+
+                                {
+                                    let mut gl = &mut current_window.gl_functions.functions;
+                                    gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+                                }
+
+                                ret = process_timer(
+                                    id,
+                                    hinstance,
+                                    current_window,
+                                    fc_cache,
+                                    image_cache,
+                                    config,
+                                    &mut new_windows,
+                                    &mut destroyed_windows,
+                                );
+
+                                let mut gl = &mut current_window.gl_functions.functions;
+                                gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+                                gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+                                gl.use_program(current_program[0] as u32);
+
+                                wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+                                if !hDC.is_null() {
+                                    ReleaseDC(hwnd, hDC);
+                                }
+                            },
+                            None => {
+                                mem::drop(app_borrow);
+                                return DefWindowProcW(hwnd, msg, wparam, lparam);
+                            },
+                        }
+                    },
+                    AZ_TICK_REGENERATE_DOM => {
+                        // re-load the layout() callback
+                        PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
+                        mem::drop(app_borrow);
+                        return DefWindowProcW(hwnd, msg, wparam, lparam)
+                    },
+                    AZ_IDLE_TICK => {
+
+                        let idle_timeout = config.user_idle_timeout.into_option();
+                        let idle_time = (config.system_callbacks.get_system_idle_time_fn.cb)().into_option();
+
+                        if let (Some(current_window), Some(idle_timeout), Some(idle_time)) =
+                            (windows.get_mut(&hwnd_key), idle_timeout, idle_time) {
+
+                            if current_window.internal.update_user_is_idle(&idle_time, &idle_timeout) {
+                                // emits On::UserIdle / On::UserActive on the next hit-test
+                                PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                            }
+                        }
+
+                        mem::drop(app_borrow);
+                        return 0;
+                    },
+                    AZ_FADE_IN_TICK => {
+
+                        use winapi::um::winuser::{KillTimer, SetLayeredWindowAttributes, LWA_ALPHA};
+
+                        match windows.get_mut(&hwnd_key) {
+                            Some(current_window) => {
+                                let fade_in_finished = match current_window.fade_in {
+                                    Some((start, duration_millis)) => start.elapsed().as_millis() as u64 >= duration_millis,
+                                    None => true,
+                                };
+
+                                if fade_in_finished {
+                                    KillTimer(hwnd, AZ_FADE_IN_TICK);
+                                    current_window.fade_in = None;
+                                    // removes the WS_EX_LAYERED style again if the window is opaque
+                                    PostMessageW(hwnd, AZ_WINDOW_STYLE_CHANGED, 0, 0);
+                                } else {
+                                    SetLayeredWindowAttributes(hwnd, 0, current_window.get_current_alpha(), LWA_ALPHA);
+                                }
+                            },
+                            None => { KillTimer(hwnd, AZ_FADE_IN_TICK); },
+                        }
+
+                        mem::drop(app_borrow);
+                        return 0;
+                    },
+                    AZ_SCROLLBAR_FADE_TICK => {
+
+                        use winapi::um::winuser::{KillTimer, SetTimer};
+
+                        match windows.get_mut(&hwnd_key) {
+                            Some(current_window) => {
+                                let interaction = &mut current_window.internal.scrollbar_interaction;
+
+                                // scrollbars stay visible while they are hovered or dragged
+                                if interaction.hovered_thumb.is_some() || interaction.dragged_thumb.is_some() {
+                                    current_window.scrollbars_last_used = std::time::Instant::now();
+                                }
+
+                                let idle_millis = current_window.scrollbars_last_used.elapsed().as_millis() as u64;
+                                let delay_millis = interaction.config.get_auto_hide_delay_millis().unwrap_or(0);
+                                let opacity = interaction.config.get_auto_hide_opacity(idle_millis);
+
+                                if opacity != interaction.opacity {
+                                    interaction.opacity = opacity;
+                                    PostMessageW(hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
+                                }
+
+                                if opacity <= 0.0 {
+                                    KillTimer(hwnd, AZ_SCROLLBAR_FADE_TICK);
+                                } else if idle_millis < delay_millis {
+                                    // still visible: wake up again once the delay is over
+                                    SetTimer(hwnd, AZ_SCROLLBAR_FADE_TICK, (delay_millis - idle_millis).max(1) as u32, None);
+                                } else {
+                                    SetTimer(hwnd, AZ_SCROLLBAR_FADE_TICK, 16, None);
+                                }
+                            },
+                            None => { KillTimer(hwnd, AZ_SCROLLBAR_FADE_TICK); },
+                        }
+
+                        mem::drop(app_borrow);
+                        return 0;
+                    },
+                    AZ_SUBTREE_RECORDING_TICK => {
+
+                        use winapi::um::winuser::{InvalidateRect, KillTimer};
+
+                        match windows.get_mut(&hwnd_key) {
+                            Some(current_window) => {
+                                // dropping the recorder writes the end of the GIF
+                                current_window.subtree_recorders.retain(|r| !r.is_finished());
+                                if current_window.subtree_recorders.is_empty() {
+                                    KillTimer(hwnd, AZ_SUBTREE_RECORDING_TICK);
+                                } else {
+                                    InvalidateRect(hwnd, ptr::null_mut(), 0);
+                                }
+                            },
+                            None => { KillTimer(hwnd, AZ_SUBTREE_RECORDING_TICK); },
+                        }
+
+                        mem::drop(app_borrow);
+                        return 0;
+                    },
+                    AZ_RESUME_RENDERING_TICK => {
+
+                        use winapi::um::winuser::KillTimer;
+
+                        let dwm = ab.dwm.as_ref();
+                        match windows.get_mut(&hwnd_key) {
+                            Some(current_window) => { current_window.update_rendering_suspended(dwm); },
+                            None => { KillTimer(hwnd, AZ_RESUME_RENDERING_TICK); },
+                        }
+
+                        mem::drop(app_borrow);
+                        return 0;
+                    },
+                    AZ_MODAL_LOOP_TICK => {
+
+                        use winapi::um::winuser::SendMessageW;
 
-                let framebuffer_size = WrDeviceIntSize::new(
-                    rect.width() as i32,
-                    rect.height() as i32
-                );
+                        // a modal loop blocks the event loop: run the timers
+                        // that are ready, then wait for the next timer
+                        let instant_now = (config.system_callbacks.get_system_time_fn.cb)();
+                        let timers_to_run = windows
+                            .get(&hwnd_key)
+                            .map(|w| w.internal.get_timers_ready_to_run(&instant_now))
+                            .unwrap_or_default();
 
-                // Render
-                if let Some(r) = current_window.renderer.as_mut() {
-                    r.update();
-                    let _ = r.render(framebuffer_size, 0);
-                }
+                        mem::drop(app_borrow);
 
-                SwapBuffers(hDC);
+                        for timer_id in timers_to_run {
+                            SendMessageW(hwnd, AZ_RUN_TIMER, timer_id.id, 0);
+                        }
 
-                gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
-                gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
-                gl.use_program(current_program[0] as u32);
+                        if let Ok(ab) = shared_application_data.inner.try_borrow() {
+                            let instant_now = (ab.config.system_callbacks.get_system_time_fn.cb)();
+                            if let Some(current_window) = ab.windows.get(&hwnd_key) {
+                                current_window.update_modal_loop_tick(&instant_now);
+                            }
+                        }
 
-                wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
-                ReleaseDC(hwnd, hDC);
-                mem::drop(app_borrow);
-                DefWindowProcW(hwnd, msg, wparam, lparam)
-            },
-            WM_TIMER => {
+                        return 0;
+                    },
+                    AZ_HOVER_INTENT_TICK => {
 
-                use winapi::um::winuser::{GetDC, ReleaseDC};
+                        use winapi::um::winuser::KillTimer;
 
-                let mut ab = &mut *app_borrow;
-                let hinstance = ab.hinstance;
-                let windows = &mut ab.windows;
-                let data = &mut ab.data;
-                let image_cache = &mut ab.image_cache;
-                let fc_cache = &mut ab.fc_cache;
-                let config = &ab.config;
+                        KillTimer(hwnd, AZ_HOVER_INTENT_TICK);
 
-                let mut ret = ProcessEventResult::DoNothing;
-                let mut new_windows = Vec::new();
-                let mut destroyed_windows = Vec::new();
+                        if let Some(current_window) = windows.get_mut(&hwnd_key) {
+                            if current_window.internal.current_window_state.mouse_state.cursor_position.is_inside_window() {
+                                // emits On::HoverIntent on the currently hovered nodes
+                                current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                                current_window.internal.current_window_state.hover_intent = true;
+                                PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                            }
+                        }
 
-                let r = match wparam {
-                    AZ_TICK_REGENERATE_DOM => {
-                        // re-load the layout() callback
-                        PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
                         mem::drop(app_borrow);
-                        return DefWindowProcW(hwnd, msg, wparam, lparam)
+                        return 0;
                     },
                     AZ_THREAD_TICK => {
 
@@ -3602,7 +5654,9 @@ unsafe extern "system" fn WindowProc(
                             },
                         }
                     },
-                    id => { // run timer with ID "id"
+                    AZ_ANIMATION_FRAME_TICK => {
+
+                        // sent by the event loop right after the vertical blank
                         match windows.get_mut(&hwnd_key) {
                             Some(current_window) => {
 
@@ -3624,8 +5678,7 @@ unsafe extern "system" fn WindowProc(
                                     gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
                                 }
 
-                                ret = process_timer(
-                                    id,
+                                ret = process_animation_frame(
                                     hinstance,
                                     current_window,
                                     fc_cache,
@@ -3650,7 +5703,12 @@ unsafe extern "system" fn WindowProc(
                                 return DefWindowProcW(hwnd, msg, wparam, lparam);
                             },
                         }
-                    }
+                    },
+                    _ => {
+                        // unknown WM_TIMER ID
+                        mem::drop(app_borrow);
+                        return DefWindowProcW(hwnd, msg, wparam, lparam);
+                    },
                 };
 
                 // create_windows needs to clone the SharedApplicationData RefCell
@@ -3691,7 +5749,7 @@ unsafe extern "system" fn WindowProc(
 
                 mem::drop(ab);
                 mem::drop(app_borrow);
-                0
+                if msg == AZ_RUN_TIMER { AZ_RUN_TIMER_DONE } else { 0 }
             },
             WM_COMMAND => {
 
@@ -3700,8 +5758,9 @@ unsafe extern "system" fn WindowProc(
                 let hiword = HIWORD(wparam.min(core::u32::MAX as usize) as u32);
                 let loword = LOWORD(wparam.min(core::u32::MAX as usize) as u32);
 
-                // assert that the command came from a menu
-                if hiword != 0 {
+                // assert that the command came from a menu or a thumbnail toolbar button
+                let is_thumbnail_button = hiword == self::taskbar::THBN_CLICKED;
+                if hiword != 0 && !is_thumbnail_button {
                     mem::drop(app_borrow);
                     return DefWindowProcW(hwnd, msg, wparam, lparam);
                 }
@@ -3739,9 +5798,27 @@ unsafe extern "system" fn WindowProc(
                         let mb = &mut current_window.menu_bar;
                         let internal = &mut current_window.internal;
                         let context_menu = current_window.context_menu.as_mut();
+                        let thumbnail_buttons = &mut current_window.taskbar.buttons;
                         let gl_context_ptr = &current_window.gl_context_ptr;
 
-                        if let Some(menu_callback) = mb.as_mut().and_then(|m| m.callbacks.get_mut(&loword)) {
+                        if is_thumbnail_button {
+                            thumbnail_buttons.get_mut(loword as usize).map(|button| {
+                                fc_cache.apply_closure(|fc_cache| {
+                                    internal.invoke_menu_callback(
+                                        &mut button.callback,
+                                        DomNodeId {
+                                            dom: DomId::ROOT_ID,
+                                            node: NodeHierarchyItemId::from_crate_internal(None),
+                                        },
+                                        &window_handle,
+                                        &gl_context_ptr,
+                                        image_cache,
+                                        fc_cache,
+                                        &config.system_callbacks,
+                                    )
+                                })
+                            })
+                        } else if let Some(menu_callback) = mb.as_mut().and_then(|m| m.callbacks.get_mut(&loword)) {
                             Some(fc_cache.apply_closure(|fc_cache| {
                                 internal.invoke_menu_callback(
                                     menu_callback,
@@ -3832,6 +5909,58 @@ unsafe extern "system" fn WindowProc(
                     return DefWindowProcW(hwnd, msg, wparam, lparam);
                 }
             },
+            WM_QUERYENDSESSION => {
+
+                use winapi::um::winuser::{SendMessageW, ShutdownBlockReasonCreate};
+
+                match app_borrow.windows.get_mut(&hwnd_key) {
+                    Some(current_window) => {
+                        current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                        current_window.internal.current_window_state.flags.is_session_ending = true;
+                    },
+                    None => {
+                        mem::drop(app_borrow);
+                        return DefWindowProcW(hwnd, msg, wparam, lparam);
+                    },
+                }
+
+                mem::drop(app_borrow);
+
+                // the OS expects an answer to WM_QUERYENDSESSION immediately,
+                // so the On::SessionEnding callbacks have to run synchronously
+                SendMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+
+                let app_borrow = shared_application_data.inner.try_borrow_mut().unwrap();
+                let session_may_end = app_borrow.windows
+                    .get(&hwnd_key)
+                    .map(|w| w.internal.current_window_state.flags.is_session_ending)
+                    .unwrap_or(true);
+                mem::drop(app_borrow);
+
+                if session_may_end {
+                    TRUE as LRESULT
+                } else {
+                    // a callback reset the flag: ask the OS to delay the shutdown
+                    let mut reason = encode_wide("Saving unsaved data");
+                    ShutdownBlockReasonCreate(hwnd, reason.as_mut_ptr());
+                    FALSE as LRESULT
+                }
+            },
+            WM_ENDSESSION => {
+
+                use winapi::um::winuser::ShutdownBlockReasonDestroy;
+
+                // wparam == FALSE: the session end was cancelled (by this or another application)
+                if wparam == FALSE as WPARAM {
+                    if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                        current_window.internal.current_window_state.flags.is_session_ending = false;
+                    }
+                    ShutdownBlockReasonDestroy(hwnd);
+                }
+
+                mem::drop(app_borrow);
+                0
+            },
             WM_QUIT => {
                 // TODO: execute quit callback
                 mem::drop(app_borrow);
@@ -3854,6 +5983,11 @@ unsafe extern "system" fn WindowProc(
 
                 if let Some(mut current_window) = ab.windows.remove(&(hwnd as usize)) {
 
+                    // wake up one of the remaining windows instead
+                    if let Some(next_window) = ab.windows.values().next() {
+                        set_event_loop_waker(&ab.event_loop_proxy, next_window.hwnd);
+                    }
+
                     let hDC = GetDC(hwnd);
                     if let Some(c) = current_window.gl_context {
                         if !hDC.is_null() {
@@ -3903,6 +6037,13 @@ unsafe extern "system" fn WindowProc(
 
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
+            msg if msg == self::taskbar::taskbar_button_created_message() => {
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    current_window.taskbar.on_taskbar_button_created(hwnd);
+                }
+                mem::drop(app_borrow);
+                0
+            },
             _ => {
                 mem::drop(app_borrow);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
@@ -3985,6 +6126,15 @@ fn process_event(
     // window.internal.current_window_state.monitor =
     // win32_translate_monitor(MonitorFromWindow(window.hwnd, MONITOR_DEFAULTTONEAREST));
 
+    // Hover / drag the built-in scrollbars before invoking the user callbacks
+    let mut scrollbars_changed = window.internal.process_scrollbar_interaction();
+
+    // scrolling or touching the scrollbars shows auto-hiding scrollbars again
+    let wheel_scrolled = window.internal.current_window_state.mouse_state.get_scroll_amount().is_some();
+    if scrollbars_changed || wheel_scrolled {
+        scrollbars_changed = window.show_auto_hide_scrollbars() || scrollbars_changed;
+    }
+
     // Get events
     let events = Events::new(
         &window.internal.current_window_state,
@@ -4002,13 +6152,20 @@ fn process_event(
 
         use azul_core::window::{RawWindowHandle, WindowsHandle};
 
-        // Get callbacks for nodes
-        let mut callbacks = CallbacksOfHitTest::new(&nodes_to_check, &events, &window.internal.layout_results);
-
         let window_handle = RawWindowHandle::Windows(WindowsHandle {
             hwnd: window.hwnd as *mut _,
             hinstance: hinstance as *mut _,
         });
+
+        // Get callbacks for nodes (a matching window-level shortcut
+        // replaces the key callbacks of the focused node)
+        let mut callbacks = CallbacksOfHitTest::new(
+            &nodes_to_check,
+            &events,
+            &window.internal.layout_results,
+            &window.internal.shortcuts,
+            &window.internal.current_window_state,
+        );
         let current_scroll_states = window.internal.get_current_scroll_states();
 
         // Invoke user-defined callbacks in the UI
@@ -4024,10 +6181,12 @@ fn process_event(
             fc_cache,
             &config.system_callbacks,
             &window.internal.renderer_resources,
+            &mut window.internal.shortcuts,
+            &mut window.internal.window_data,
         )
     });
 
-    return process_callback_results(
+    let result = process_callback_results(
         callback_result,
         window,
         &nodes_to_check,
@@ -4036,6 +6195,12 @@ fn process_event(
         new_windows,
         destroyed_windows
     );
+
+    if scrollbars_changed {
+        result.max_self(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow)
+    } else {
+        result
+    }
 }
 
 #[must_use]
@@ -4131,6 +6296,64 @@ fn process_threads(
     );
 }
 
+fn process_animation_frame(
+    hinstance: HINSTANCE,
+    window: &mut Window,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>
+) -> ProcessEventResult {
+
+    use azul_core::window::{RawWindowHandle, WindowsHandle};
+
+    let frame_start = (config.system_callbacks.get_system_time_fn.cb)();
+
+    let has_crossfades = !window.internal.crossfades.is_empty();
+    let crossfade_finished = window.internal.advance_crossfades(&frame_start);
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+
+        let window_handle = RawWindowHandle::Windows(WindowsHandle {
+            hwnd: window.hwnd as *mut _,
+            hinstance: hinstance as *mut _,
+        });
+
+        window.internal.run_animation_frame_callbacks(
+            frame_start.clone(),
+            &window_handle,
+            &window.gl_context_ptr,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    let result = process_callback_results(
+        callback_result,
+        window,
+        &NodesToCheck::empty(
+            window.internal.current_window_state.mouse_state.mouse_down(),
+            window.internal.current_window_state.focused_node,
+        ),
+        image_cache,
+        fc_cache,
+        new_windows,
+        destroyed_windows
+    );
+
+    // the opacity of the cross-fade snapshots changes every frame,
+    // finished snapshots have to be removed from the display list
+    if crossfade_finished {
+        result.max_self(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow)
+    } else if has_crossfades {
+        result.max_self(ProcessEventResult::ShouldReRenderCurrentWindow)
+    } else {
+        result
+    }
+}
+
 #[must_use]
 fn process_callback_results(
     mut callback_results: CallCallbacksResult,
@@ -4183,9 +6406,88 @@ fn process_callback_results(
         new_windows.push(w);
     }
 
+    window.internal.animation_frame_callbacks.append(&mut callback_results.animation_frame_callbacks);
+
+    // snapshot the subtrees before the DOM is regenerated
+    if !callback_results.crossfades.is_empty() {
+        let crossfades = mem::replace(&mut callback_results.crossfades, Vec::new());
+        window.internal.start_crossfades(crossfades, &*image_cache);
+        result = result.max_self(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow);
+    }
+
+    // the area of the recorded node is fixed when the recording starts
+    if !callback_results.subtree_recordings.is_empty() {
+
+        use winapi::um::winuser::{GetClientRect, SetTimer};
+
+        let mut rect: RECT = unsafe { mem::zeroed() };
+        unsafe { GetClientRect(window.hwnd, &mut rect) };
+        let framebuffer_size = (rect.width() as i32, rect.height() as i32);
+        let hidpi_factor = window.internal.current_window_state.size.get_hidpi_factor();
+
+        for recording in callback_results.subtree_recordings.iter() {
+            let recorder = window.internal
+                .get_node_rect_in_window(recording.node)
+                .and_then(|node_rect| {
+                    crate::frame_recording::SubtreeRecorder::new(recording, node_rect, hidpi_factor, framebuffer_size)
+                });
+            match recorder {
+                Some(s) => window.subtree_recorders.push(s),
+                None => {
+                    #[cfg(feature = "logging")] {
+                        warn!("render_subtree_to_frames: node is not visible, nothing is recorded to {}", recording.output_path.as_str());
+                    }
+                },
+            }
+        }
+
+        let frame_interval = window.subtree_recorders.iter().map(|r| r.get_frame_interval()).min();
+        if let Some(frame_interval) = frame_interval {
+            let frame_interval_ms = frame_interval.as_millis().max(1).min(u32::MAX as u128) as u32;
+            unsafe { SetTimer(window.hwnd, AZ_SUBTREE_RECORDING_TICK, frame_interval_ms, None) };
+        }
+    }
+
+    if !callback_results.announcements.is_empty() {
+        let uia = self::announce::UiaFunctions::init();
+        for announcement in callback_results.announcements.iter() {
+            if !uia.announce(window.hwnd, announcement) {
+                #[cfg(feature = "logging")] {
+                    warn!("screen reader announcement failed: {}", announcement.text.as_str());
+                }
+            }
+        }
+    }
+
+    // haptic feedback is only supported for pens, ignored if no pen touched the window
+    #[cfg(feature = "haptics")] {
+        if let Some(pen_pointer_id) = window.pen_pointer_id {
+            for kind in callback_results.haptic_feedback.iter() {
+                if let Err(_e) = self::haptics::play_haptic_feedback(pen_pointer_id, *kind) {
+                    #[cfg(feature = "logging")] {
+                        warn!("haptic feedback failed: {}", _e);
+                    }
+                }
+            }
+        }
+    }
+
+
+    // nodes scrolled in timer / thread callbacks (event callbacks already scrolled the nodes)
+    let callbacks_scrolled = match callback_results.nodes_scrolled_in_callbacks.as_ref() {
+        Some(nodes) => window.internal.set_scroll_positions(nodes),
+        None => false,
+    };
 
     let scroll = window.internal.current_window_state.process_system_scroll(&window.internal.scroll_states);
-    let need_scroll_render = scroll.is_some();
+    let need_scroll_render = scroll.is_some() || callback_results.should_scroll_render || callbacks_scrolled;
+
+    // font instances and images are registered per scale factor, so a
+    // zoom change has to re-render the UI at the new resolution
+    let zoom_changed = callback_results.modified_window_state
+        .as_ref()
+        .map(|modified| modified.size.zoom != window.internal.current_window_state.size.zoom)
+        .unwrap_or(false);
 
     if let Some(modified) = callback_results.modified_window_state.as_ref() {
         if modified.flags.is_about_to_close {
@@ -4197,6 +6499,10 @@ fn process_callback_results(
             window.internal.current_window_state.hovered_file.clone(),
             window.internal.current_window_state.focused_node.clone(),
             window.internal.current_window_state.last_hit_test.clone(),
+            window.internal.current_window_state.user_is_idle,
+            window.internal.current_window_state.user_event.clone(),
+            window.internal.current_window_state.hover_intent,
+            window.internal.current_window_state.system_style,
         );
         if modified.size.get_layout_size() != window.internal.current_window_state.size.get_layout_size() {
             result = result.max_self(ProcessEventResult::UpdateHitTesterAndProcessAgain);
@@ -4215,7 +6521,7 @@ fn process_callback_results(
         &window.internal.previous_window_state
     );
 
-    if layout_callback_changed {
+    if layout_callback_changed || zoom_changed {
         return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
     } else {
         match callback_results.callbacks_update_screen {
@@ -4229,19 +6535,29 @@ fn process_callback_results(
         }
     }
 
+    // remember which nodes are visible, in case the relayout resizes nodes above them
+    let scroll_anchors = window.internal.scroll_states.get_scroll_anchors(&window.internal.layout_results);
+
     // Re-layout and re-style the window.internal.layout_results
-    let mut style_layout_changes = StyleAndLayoutChanges::new(
-        &nodes_to_check,
-        &mut window.internal.layout_results,
-        &image_cache,
-        &mut window.internal.renderer_resources,
-        window.internal.current_window_state.size.get_layout_size(),
-        &window.internal.document_id,
-        callback_results.css_properties_changed.as_ref(),
-        callback_results.words_changed.as_ref(),
-        &callback_results.update_focused_node,
-        azul_layout::do_the_relayout,
-    );
+    let mut style_layout_changes = {
+        #[cfg(feature = "logging")]
+        let _span = crate::logging::span(crate::logging::LOG_TARGET_RESTYLE, "restyle");
+        let _phase = window.frame_stats.phase("restyle");
+        StyleAndLayoutChanges::new(
+            &nodes_to_check,
+            &mut window.internal.layout_results,
+            &image_cache,
+            &mut window.internal.renderer_resources,
+            window.internal.current_window_state.size.get_layout_size(),
+            &window.internal.document_id,
+            callback_results.css_properties_changed.as_ref(),
+            callback_results.ids_and_classes_changed.as_ref(),
+            callback_results.node_state_flags_changed.as_ref(),
+            callback_results.words_changed.as_ref(),
+            &callback_results.update_focused_node,
+            azul_layout::do_the_relayout,
+        )
+    };
 
 
     if let Some(rsn) = style_layout_changes.nodes_that_changed_size.as_ref() {
@@ -4271,13 +6587,24 @@ fn process_callback_results(
             wr_synchronize_updated_images(updated_images, &window.internal.document_id, &mut txn);
             window.render_api.send_transaction(wr_translate_document_id(window.internal.document_id), txn);
         }
+
+        window.internal.scroll_states.restore_scroll_anchors(&scroll_anchors, &window.internal.layout_results);
     }
 
     // FOCUS CHANGE HAPPENS HERE!
     if let Some(focus_change) = style_layout_changes.focus_change.clone() {
          window.internal.current_window_state.focused_node = focus_change.new;
+         // secure text fields: typed text must not show up in the IME windows
+         ime::set_ime_enabled(window.hwnd, !window.internal.focused_node_is_protected());
+         ime::set_input_mode(window.hwnd, window.internal.get_focused_input_mode());
     }
 
+    // underlines of nodes whose text changed are outdated
+    let text_underlines_changed = window.internal.update_text_underlines(
+        callback_results.words_changed.as_ref(),
+        callback_results.text_underlines_changed.as_ref(),
+    );
+
     // Perform a system or user scroll event: only
     // scroll nodes that were not scrolled in the current frame
     //
@@ -4292,7 +6619,7 @@ fn process_callback_results(
     if style_layout_changes.did_resize_nodes() {
         // at least update the hit-tester
         result.max_self(ProcessEventResult::UpdateHitTesterAndProcessAgain)
-    } else if style_layout_changes.need_regenerate_display_list() {
+    } else if style_layout_changes.need_regenerate_display_list() || text_underlines_changed {
         result.max_self(ProcessEventResult::ShouldUpdateDisplayListCurrentWindow)
     } else if need_scroll_render || style_layout_changes.need_redraw() {
         result.max_self(ProcessEventResult::ShouldReRenderCurrentWindow)
@@ -4305,12 +6632,22 @@ fn create_windows(hinstance: HINSTANCE, app: &mut SharedApplicationData, new: Ve
     for opts in new {
         if let Ok(w) = Window::create(hinstance, opts, app.clone()) {
             if let Ok(mut a) = app.inner.try_borrow_mut() {
-                a.windows.insert(w.get_id(), w);
+                a.insert_window(w);
             }
         }
     }
 }
 
+/// Lets `EventLoopProxy::wake()` post an `AZ_USER_EVENT` message to the window
+fn set_event_loop_waker(proxy: &EventLoopProxy, hwnd: HWND) {
+    use winapi::um::winuser::PostMessageW;
+    // HWND is not Send, but PostMessageW can be called from any thread
+    let hwnd = hwnd as usize;
+    proxy.set_waker(Box::new(move || unsafe {
+        PostMessageW(hwnd as HWND, AZ_USER_EVENT, 0, 0);
+    }));
+}
+
 fn destroy_windows(app: &mut ApplicationData, old: Vec<usize>) {
     use winapi::um::winuser::{PostMessageW, WM_QUIT};
     for window in old {
@@ -4361,12 +6698,143 @@ fn initialize_os_window(
     */
 }
 
+/// Extended window style for the always-on-top, click-through and tool window flags
+fn get_window_ex_style(flags: &WindowFlags, alpha: u8) -> DWORD {
+
+    use winapi::um::winuser::{
+        WS_EX_ACCEPTFILES, WS_EX_APPWINDOW, WS_EX_LAYERED,
+        WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_EX_TRANSPARENT,
+    };
+
+    let mut ex_style = WS_EX_ACCEPTFILES;
+
+    ex_style |= if flags.is_tool_window { WS_EX_TOOLWINDOW } else { WS_EX_APPWINDOW };
+
+    if flags.is_always_on_top {
+        ex_style |= WS_EX_TOPMOST;
+    }
+
+    // WS_EX_TRANSPARENT only passes the input through for layered windows
+    if flags.is_click_through {
+        ex_style |= WS_EX_LAYERED | WS_EX_TRANSPARENT;
+    }
+
+    // the opacity of the entire window is set via SetLayeredWindowAttributes
+    if alpha != 255 {
+        ex_style |= WS_EX_LAYERED;
+    }
+
+    ex_style
+}
+
+/// Updates the extended window style of an existing window,
+/// must not be called while the application data is borrowed
+unsafe fn set_window_ex_style(hwnd: HWND, flags: &WindowFlags, alpha: u8) {
+
+    use winapi::shared::basetsd::LONG_PTR;
+    use winapi::um::winuser::{
+        GetWindowLongPtrW, IsWindowVisible, SetLayeredWindowAttributes,
+        SetWindowLongPtrW, SetWindowPos, ShowWindow, GWL_EXSTYLE, HWND_NOTOPMOST,
+        HWND_TOPMOST, LWA_ALPHA, SWP_FRAMECHANGED, SWP_NOACTIVATE, SWP_NOMOVE,
+        SWP_NOSIZE, SW_HIDE, SW_SHOWNA, WS_EX_APPWINDOW, WS_EX_LAYERED,
+        WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_EX_TRANSPARENT,
+    };
+
+    const MANAGED_EX_STYLES: DWORD = WS_EX_APPWINDOW | WS_EX_TOOLWINDOW |
+        WS_EX_TOPMOST | WS_EX_LAYERED | WS_EX_TRANSPARENT;
+
+    let old_ex_style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE) as DWORD;
+    // WS_EX_TOPMOST can only be changed via SetWindowPos
+    let new_ex_style = (old_ex_style & !MANAGED_EX_STYLES) |
+        (get_window_ex_style(flags, alpha) & !WS_EX_TOPMOST) |
+        (old_ex_style & WS_EX_TOPMOST);
+
+    // the taskbar only picks up the WS_EX_TOOLWINDOW change
+    // when the window is shown again
+    let was_tool_window = old_ex_style & WS_EX_TOOLWINDOW != 0;
+    let reshow = was_tool_window != flags.is_tool_window && IsWindowVisible(hwnd) != 0;
+
+    if reshow {
+        ShowWindow(hwnd, SW_HIDE);
+    }
+
+    SetWindowLongPtrW(hwnd, GWL_EXSTYLE, new_ex_style as LONG_PTR);
+
+    if new_ex_style & WS_EX_LAYERED != 0 {
+        SetLayeredWindowAttributes(hwnd, 0, alpha, LWA_ALPHA);
+    }
+
+    SetWindowPos(
+        hwnd,
+        if flags.is_always_on_top { HWND_TOPMOST } else { HWND_NOTOPMOST },
+        0,
+        0,
+        0,
+        0,
+        SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE,
+    );
+
+    if reshow {
+        ShowWindow(hwnd, SW_SHOWNA);
+    }
+}
+
 fn synchronize_window_state_with_os(
     window: HWND,
     previous_state: Option<&FullWindowState>,
     current_state: &FullWindowState
 ) {
+
+    use winapi::um::winuser::{
+        IsIconic, IsZoomed, PostMessageW, ShowWindowAsync,
+        SW_MAXIMIZE, SW_MINIMIZE, SW_RESTORE,
+    };
+    use azul_core::window::WindowFrame;
+
     // TODO: window.set_title
+
+    let previous_flags = match previous_state {
+        Some(s) => s.flags,
+        None => return,
+    };
+
+    // NOTE: this function is called while the application data is borrowed,
+    // so the window changes have to be posted instead of being sent directly
+
+    if previous_flags.has_decorations != current_state.flags.has_decorations {
+        unsafe { PostMessageW(window, AZ_FRAME_CHANGED, 0, 0); }
+    }
+
+    if previous_state.map(|s| s.size.dimensions) != Some(current_state.size.dimensions) &&
+       current_state.flags.frame == WindowFrame::Normal {
+        unsafe { PostMessageW(window, AZ_WINDOW_SIZE_CHANGED, 0, 0); }
+    }
+
+    if previous_flags.is_always_on_top != current_state.flags.is_always_on_top ||
+       previous_flags.is_click_through != current_state.flags.is_click_through ||
+       previous_state.map(|s| s.opacity) != Some(current_state.opacity) ||
+       previous_flags.is_tool_window != current_state.flags.is_tool_window {
+        unsafe { PostMessageW(window, AZ_WINDOW_STYLE_CHANGED, 0, 0); }
+    }
+
+    let is_fullscreen = current_state.flags.frame == WindowFrame::Fullscreen;
+    let was_fullscreen = previous_flags.frame == WindowFrame::Fullscreen;
+
+    if is_fullscreen != was_fullscreen ||
+       (is_fullscreen && previous_flags.fullscreen_mode != current_state.flags.fullscreen_mode) {
+        // entering / leaving fullscreen also restores the frame (maximized, minimized)
+        unsafe { PostMessageW(window, AZ_FULLSCREEN_CHANGED, 0, 0); }
+    } else if previous_flags.frame != current_state.flags.frame {
+        // i.e. the maximize / minimize button of a custom title bar was clicked
+        unsafe {
+            match current_state.flags.frame {
+                WindowFrame::Maximized if IsZoomed(window) == 0 => { ShowWindowAsync(window, SW_MAXIMIZE); },
+                WindowFrame::Minimized if IsIconic(window) == 0 => { ShowWindowAsync(window, SW_MINIMIZE); },
+                WindowFrame::Normal if IsZoomed(window) != 0 || IsIconic(window) != 0 => { ShowWindowAsync(window, SW_RESTORE); },
+                _ => { },
+            }
+        }
+    }
 }
 
 fn send_resource_updates(
@@ -4420,4 +6888,30 @@ const fn win32_translate_cursor(input: MouseCursorType) -> *const wchar_t {
         Help => winuser::IDC_HELP,
         _ => winuser::IDC_ARROW,
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // WM_TIMER is matched by ID, two internal timers
+    // with the same ID would swallow each others ticks
+    const INTERNAL_TIMER_IDS: &[usize] = &[
+        AZ_TICK_REGENERATE_DOM,
+        AZ_THREAD_TICK,
+        AZ_IDLE_TICK,
+        AZ_ANIMATION_FRAME_TICK,
+        AZ_HOVER_INTENT_TICK,
+        AZ_FADE_IN_TICK,
+        AZ_RESUME_RENDERING_TICK,
+        AZ_SUBTREE_RECORDING_TICK,
+        AZ_SCROLLBAR_FADE_TICK,
+        AZ_MODAL_LOOP_TICK,
+    ];
+
+    #[test]
+    fn internal_timer_ids_are_unique() {
+        let unique_ids = INTERNAL_TIMER_IDS.iter().collect::<BTreeSet<_>>();
+        assert_eq!(unique_ids.len(), INTERNAL_TIMER_IDS.len());
+    }
+}
@@ -0,0 +1,337 @@
+//! Monitor enumeration and fullscreen handling (borderless / exclusive)
+
+use std::mem;
+use std::ptr;
+use winapi::shared::basetsd::LONG_PTR;
+use winapi::shared::minwindef::{BOOL, LPARAM, TRUE};
+use winapi::shared::windef::{HDC, HMONITOR, HWND, LPRECT, RECT};
+use winapi::um::wingdi::{DEVMODEW, DISPLAYCONFIG_DEVICE_INFO_HEADER};
+use winapi::um::winuser::{MONITORINFOEXW, WINDOWPLACEMENT};
+use azul_core::window::{FullscreenMode, HdrCapabilities, Monitor, MonitorVec, VideoMode};
+use azul_css::{AzString, LayoutPoint, LayoutSize, OptionAzString};
+
+/// Window style and position before the window entered fullscreen mode,
+/// restored when the window leaves fullscreen mode
+pub struct WindowedState {
+    style: LONG_PTR,
+    placement: WINDOWPLACEMENT,
+    /// Device name of the monitor whose video mode was changed (exclusive fullscreen)
+    exclusive_device: Option<[u16; 32]>,
+}
+
+/// Returns all monitors in the order of `EnumDisplayMonitors`,
+/// the index in the list is the `Monitor::id`
+fn enum_display_monitors() -> Vec<HMONITOR> {
+
+    use winapi::um::winuser::EnumDisplayMonitors;
+
+    unsafe extern "system" fn monitor_enum_proc(
+        hmonitor: HMONITOR,
+        _: HDC,
+        _: LPRECT,
+        data: LPARAM,
+    ) -> BOOL {
+        let monitors = &mut *(data as *mut Vec<HMONITOR>);
+        monitors.push(hmonitor);
+        TRUE
+    }
+
+    let mut monitors = Vec::<HMONITOR>::new();
+    unsafe {
+        EnumDisplayMonitors(
+            ptr::null_mut(),
+            ptr::null(),
+            Some(monitor_enum_proc),
+            &mut monitors as *mut Vec<HMONITOR> as LPARAM,
+        );
+    }
+    monitors
+}
+
+fn get_monitor_info(hmonitor: HMONITOR) -> Option<MONITORINFOEXW> {
+
+    use winapi::um::winuser::GetMonitorInfoW;
+
+    unsafe {
+        let mut info: MONITORINFOEXW = mem::zeroed();
+        info.cbSize = mem::size_of::<MONITORINFOEXW>() as u32;
+        if GetMonitorInfoW(hmonitor, &mut info as *mut MONITORINFOEXW as *mut _) == 0 {
+            None
+        } else {
+            Some(info)
+        }
+    }
+}
+
+fn get_video_modes(device_name: &[u16; 32]) -> Vec<VideoMode> {
+
+    use winapi::um::winuser::EnumDisplaySettingsW;
+
+    let mut video_modes = Vec::new();
+    let mut mode_id = 0;
+
+    loop {
+        let mut devmode: DEVMODEW = unsafe { mem::zeroed() };
+        devmode.dmSize = mem::size_of::<DEVMODEW>() as u16;
+        if unsafe { EnumDisplaySettingsW(device_name.as_ptr(), mode_id, &mut devmode) } == 0 {
+            break;
+        }
+        let video_mode = VideoMode {
+            size: LayoutSize::new(devmode.dmPelsWidth as isize, devmode.dmPelsHeight as isize),
+            bit_depth: devmode.dmBitsPerPel as u16,
+            refresh_rate: devmode.dmDisplayFrequency as u16,
+        };
+        if !video_modes.contains(&video_mode) {
+            video_modes.push(video_mode);
+        }
+        mode_id += 1;
+    }
+
+    video_modes
+}
+
+/// DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO (not in winapi)
+#[repr(C)]
+struct DisplayConfigAdvancedColorInfo {
+    header: DISPLAYCONFIG_DEVICE_INFO_HEADER,
+    /// bit 0: supported, bit 1: enabled, bit 2: wide color enforced, bit 3: force disabled
+    value: u32,
+    color_encoding: u32,
+    bits_per_color_channel: u32,
+}
+
+/// DISPLAYCONFIG_SDR_WHITE_LEVEL (not in winapi)
+#[repr(C)]
+struct DisplayConfigSdrWhiteLevel {
+    header: DISPLAYCONFIG_DEVICE_INFO_HEADER,
+    /// SDR white level in units of 80 nits / 1000
+    sdr_white_level: u32,
+}
+
+const DISPLAYCONFIG_DEVICE_INFO_GET_ADVANCED_COLOR_INFO: u32 = 9;
+const DISPLAYCONFIG_DEVICE_INFO_GET_SDR_WHITE_LEVEL: u32 = 11;
+
+/// Queries the "advanced color" state of the display path whose source is
+/// the given GDI device (i.e. "\\.\DISPLAY1"), requires Windows 10 1709
+fn get_hdr_capabilities(device_name: &[u16; 32]) -> HdrCapabilities {
+
+    use winapi::um::winuser::{
+        DisplayConfigGetDeviceInfo, GetDisplayConfigBufferSizes, QueryDisplayConfig,
+    };
+    use winapi::um::wingdi::{
+        DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME, DISPLAYCONFIG_MODE_INFO,
+        DISPLAYCONFIG_PATH_INFO, DISPLAYCONFIG_SOURCE_DEVICE_NAME, QDC_ONLY_ACTIVE_PATHS,
+    };
+    use winapi::shared::winerror::ERROR_SUCCESS;
+
+    let mut capabilities = HdrCapabilities::default();
+
+    unsafe {
+        let mut num_paths = 0;
+        let mut num_modes = 0;
+        if GetDisplayConfigBufferSizes(QDC_ONLY_ACTIVE_PATHS, &mut num_paths, &mut num_modes) != ERROR_SUCCESS as i32 {
+            return capabilities;
+        }
+
+        let mut paths = vec![mem::zeroed::<DISPLAYCONFIG_PATH_INFO>(); num_paths as usize];
+        let mut modes = vec![mem::zeroed::<DISPLAYCONFIG_MODE_INFO>(); num_modes as usize];
+        if QueryDisplayConfig(
+            QDC_ONLY_ACTIVE_PATHS,
+            &mut num_paths,
+            paths.as_mut_ptr(),
+            &mut num_modes,
+            modes.as_mut_ptr(),
+            ptr::null_mut(),
+        ) != ERROR_SUCCESS as i32 {
+            return capabilities;
+        }
+
+        for path in paths.iter().take(num_paths as usize) {
+
+            let mut source_name: DISPLAYCONFIG_SOURCE_DEVICE_NAME = mem::zeroed();
+            source_name.header.type_ = DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME;
+            source_name.header.size = mem::size_of::<DISPLAYCONFIG_SOURCE_DEVICE_NAME>() as u32;
+            source_name.header.adapterId = path.sourceInfo.adapterId;
+            source_name.header.id = path.sourceInfo.id;
+            if DisplayConfigGetDeviceInfo(&mut source_name.header) != ERROR_SUCCESS as i32 ||
+               source_name.viewGdiDeviceName[..] != device_name[..] {
+                continue;
+            }
+
+            let mut color_info: DisplayConfigAdvancedColorInfo = mem::zeroed();
+            color_info.header.type_ = DISPLAYCONFIG_DEVICE_INFO_GET_ADVANCED_COLOR_INFO;
+            color_info.header.size = mem::size_of::<DisplayConfigAdvancedColorInfo>() as u32;
+            color_info.header.adapterId = path.targetInfo.adapterId;
+            color_info.header.id = path.targetInfo.id;
+            if DisplayConfigGetDeviceInfo(&mut color_info.header) == ERROR_SUCCESS as i32 {
+                capabilities.supported = color_info.value & 0b01 != 0;
+                capabilities.enabled = color_info.value & 0b10 != 0;
+                capabilities.bits_per_color_channel = color_info.bits_per_color_channel;
+            }
+
+            let mut white_level: DisplayConfigSdrWhiteLevel = mem::zeroed();
+            white_level.header.type_ = DISPLAYCONFIG_DEVICE_INFO_GET_SDR_WHITE_LEVEL;
+            white_level.header.size = mem::size_of::<DisplayConfigSdrWhiteLevel>() as u32;
+            white_level.header.adapterId = path.targetInfo.adapterId;
+            white_level.header.id = path.targetInfo.id;
+            if capabilities.enabled && DisplayConfigGetDeviceInfo(&mut white_level.header) == ERROR_SUCCESS as i32 {
+                capabilities.sdr_white_level = white_level.sdr_white_level as f32 / 1000.0 * 80.0;
+            }
+
+            break;
+        }
+    }
+
+    capabilities
+}
+
+pub fn get_monitors() -> MonitorVec {
+
+    use winapi::um::winuser::MONITORINFOF_PRIMARY;
+
+    let dpi = super::dpi::DpiFunctions::init();
+
+    enum_display_monitors()
+    .into_iter()
+    .enumerate()
+    .filter_map(|(id, hmonitor)| {
+        let info = get_monitor_info(hmonitor)?;
+        let name_len = info.szDevice.iter().position(|c| *c == 0).unwrap_or(info.szDevice.len());
+        let name = String::from_utf16_lossy(&info.szDevice[..name_len]);
+        let rect = info.rcMonitor;
+        let scale_factor = dpi.get_monitor_dpi(hmonitor)
+            .map(|dpi| super::dpi::dpi_to_scale_factor(dpi) as f64)
+            .unwrap_or(1.0);
+
+        Some(Monitor {
+            id,
+            name: OptionAzString::Some(AzString::from(name)),
+            size: LayoutSize::new((rect.right - rect.left) as isize, (rect.bottom - rect.top) as isize),
+            position: LayoutPoint::new(rect.left as isize, rect.top as isize),
+            scale_factor,
+            video_modes: get_video_modes(&info.szDevice).into(),
+            is_primary_monitor: info.dwFlags & MONITORINFOF_PRIMARY != 0,
+            hdr: get_hdr_capabilities(&info.szDevice),
+        })
+    })
+    .collect::<Vec<_>>()
+    .into()
+}
+
+/// Returns the monitor with the given `Monitor::id` or the monitor
+/// the window is currently on, if no monitor with that id exists
+fn get_fullscreen_monitor(hwnd: HWND, monitor_id: usize) -> Option<MONITORINFOEXW> {
+
+    use winapi::um::winuser::{MonitorFromWindow, MONITOR_DEFAULTTONEAREST};
+
+    let hmonitor = enum_display_monitors()
+        .get(monitor_id)
+        .copied()
+        .unwrap_or_else(|| unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) });
+
+    get_monitor_info(hmonitor)
+}
+
+/// Removes the window frame and covers the entire monitor with the window.
+/// Returns the windowed state that has to be passed to `exit_fullscreen`.
+pub unsafe fn enter_fullscreen(hwnd: HWND, mode: FullscreenMode) -> Option<WindowedState> {
+
+    use winapi::um::winuser::{
+        ChangeDisplaySettingsExW, GetWindowLongPtrW, GetWindowPlacement,
+        SetWindowLongPtrW, SetWindowPos, CDS_FULLSCREEN, DISP_CHANGE_SUCCESSFUL,
+        GWL_STYLE, HWND_TOP, SWP_FRAMECHANGED, SWP_NOOWNERZORDER, WS_OVERLAPPEDWINDOW,
+    };
+    use winapi::um::wingdi::{DM_PELSWIDTH, DM_PELSHEIGHT};
+
+    let monitor_id = match mode {
+        FullscreenMode::Borderless(id) | FullscreenMode::Exclusive(id) => id,
+    };
+
+    let monitor = get_fullscreen_monitor(hwnd, monitor_id)?;
+
+    let style = GetWindowLongPtrW(hwnd, GWL_STYLE);
+    let mut placement: WINDOWPLACEMENT = mem::zeroed();
+    placement.length = mem::size_of::<WINDOWPLACEMENT>() as u32;
+    GetWindowPlacement(hwnd, &mut placement);
+
+    let mut monitor_rect = monitor.rcMonitor;
+    let mut exclusive_device = None;
+
+    if let FullscreenMode::Exclusive(_) = mode {
+        // switch the video mode of the monitor to the current window size
+        let mut client_rect: RECT = mem::zeroed();
+        winapi::um::winuser::GetClientRect(hwnd, &mut client_rect);
+
+        let mut devmode: DEVMODEW = mem::zeroed();
+        devmode.dmSize = mem::size_of::<DEVMODEW>() as u16;
+        devmode.dmPelsWidth = (client_rect.right - client_rect.left) as u32;
+        devmode.dmPelsHeight = (client_rect.bottom - client_rect.top) as u32;
+        devmode.dmFields = DM_PELSWIDTH | DM_PELSHEIGHT;
+
+        let result = ChangeDisplaySettingsExW(
+            monitor.szDevice.as_ptr(),
+            &mut devmode,
+            ptr::null_mut(),
+            CDS_FULLSCREEN,
+            ptr::null_mut(),
+        );
+
+        if result == DISP_CHANGE_SUCCESSFUL {
+            exclusive_device = Some(monitor.szDevice);
+            monitor_rect.right = monitor_rect.left + devmode.dmPelsWidth as i32;
+            monitor_rect.bottom = monitor_rect.top + devmode.dmPelsHeight as i32;
+        }
+    }
+
+    SetWindowLongPtrW(hwnd, GWL_STYLE, style & !(WS_OVERLAPPEDWINDOW as LONG_PTR));
+    SetWindowPos(
+        hwnd,
+        HWND_TOP,
+        monitor_rect.left,
+        monitor_rect.top,
+        monitor_rect.right - monitor_rect.left,
+        monitor_rect.bottom - monitor_rect.top,
+        SWP_NOOWNERZORDER | SWP_FRAMECHANGED,
+    );
+
+    Some(WindowedState {
+        style,
+        placement,
+        exclusive_device,
+    })
+}
+
+/// Restores the video mode of the monitor if it was changed by `enter_fullscreen`
+pub fn restore_display_settings(windowed: &WindowedState) {
+
+    use winapi::um::winuser::ChangeDisplaySettingsExW;
+
+    if let Some(device) = windowed.exclusive_device.as_ref() {
+        unsafe {
+            ChangeDisplaySettingsExW(device.as_ptr(), ptr::null_mut(), ptr::null_mut(), 0, ptr::null_mut());
+        }
+    }
+}
+
+/// Restores the window style, position and the video mode of the monitor
+pub unsafe fn exit_fullscreen(hwnd: HWND, windowed: &WindowedState) {
+
+    use winapi::um::winuser::{
+        SetWindowLongPtrW, SetWindowPlacement, SetWindowPos, GWL_STYLE,
+        SWP_FRAMECHANGED, SWP_NOMOVE, SWP_NOOWNERZORDER, SWP_NOSIZE, SWP_NOZORDER,
+    };
+
+    restore_display_settings(windowed);
+
+    SetWindowLongPtrW(hwnd, GWL_STYLE, windowed.style);
+    SetWindowPlacement(hwnd, &windowed.placement);
+    SetWindowPos(
+        hwnd,
+        ptr::null_mut(),
+        0,
+        0,
+        0,
+        0,
+        SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER | SWP_NOOWNERZORDER | SWP_FRAMECHANGED,
+    );
+}
@@ -0,0 +1,168 @@
+//! Translation between window messages and `SyntheticEvent`s, used to
+//! record and replay input events (see `AppConfig::event_recording`)
+
+use std::ptr;
+use winapi::shared::minwindef::{LPARAM, UINT, WPARAM};
+use winapi::shared::windef::{HWND, RECT};
+use winapi::um::winuser::{
+    SendMessageW, WM_MOUSEMOVE, WM_MOUSELEAVE, WM_LBUTTONDOWN, WM_LBUTTONUP,
+    WM_RBUTTONDOWN, WM_RBUTTONUP, WM_MBUTTONDOWN, WM_MBUTTONUP, WM_MOUSEWHEEL,
+    WM_KEYDOWN, WM_SYSKEYDOWN, WM_KEYUP, WM_SYSKEYUP, WM_CHAR, WM_SETFOCUS,
+    WM_KILLFOCUS, WM_SIZE, SIZE_MINIMIZED, WHEEL_DELTA,
+};
+use azul_core::window::{LogicalPosition, LogicalSize, SyntheticEvent, SyntheticMouseButton};
+use super::event::process_key_params;
+
+/// Translates an input message into a `SyntheticEvent`, returns `None` for
+/// messages that are not input events. `WM_CHAR` is not translated here, because
+/// characters outside of the BMP are sent as two messages (UTF-16 surrogates).
+pub(crate) fn message_to_synthetic_event(
+    msg: UINT,
+    wparam: WPARAM,
+    lparam: LPARAM,
+    hidpi_factor: f32,
+) -> Option<SyntheticEvent> {
+
+    use winapi::shared::windowsx::{GET_X_LPARAM, GET_Y_LPARAM};
+    use winapi::shared::minwindef::{LOWORD, HIWORD};
+
+    let event = match msg {
+        WM_MOUSEMOVE => SyntheticEvent::MouseMove(LogicalPosition::new(
+            GET_X_LPARAM(lparam) as f32 / hidpi_factor,
+            GET_Y_LPARAM(lparam) as f32 / hidpi_factor,
+        )),
+        WM_MOUSELEAVE => SyntheticEvent::MouseLeave,
+        WM_LBUTTONDOWN => SyntheticEvent::MouseDown(SyntheticMouseButton::Left),
+        WM_LBUTTONUP => SyntheticEvent::MouseUp(SyntheticMouseButton::Left),
+        WM_RBUTTONDOWN => SyntheticEvent::MouseDown(SyntheticMouseButton::Right),
+        WM_RBUTTONUP => SyntheticEvent::MouseUp(SyntheticMouseButton::Right),
+        WM_MBUTTONDOWN => SyntheticEvent::MouseDown(SyntheticMouseButton::Middle),
+        WM_MBUTTONUP => SyntheticEvent::MouseUp(SyntheticMouseButton::Middle),
+        WM_MOUSEWHEEL => {
+            let value = (wparam >> 16) as i16;
+            SyntheticEvent::Scroll { x: 0.0, y: value as f32 / WHEEL_DELTA as f32 }
+        },
+        WM_KEYDOWN | WM_SYSKEYDOWN => {
+            let (scancode, key) = process_key_params(wparam, lparam)?;
+            SyntheticEvent::KeyDown { scancode, key: key.into() }
+        },
+        WM_KEYUP | WM_SYSKEYUP => {
+            let (scancode, key) = process_key_params(wparam, lparam)?;
+            SyntheticEvent::KeyUp { scancode, key: key.into() }
+        },
+        WM_SETFOCUS => SyntheticEvent::FocusIn,
+        WM_KILLFOCUS => SyntheticEvent::FocusOut,
+        WM_SIZE if wparam != SIZE_MINIMIZED => SyntheticEvent::Resize(LogicalSize::new(
+            LOWORD(lparam as u32) as f32 / hidpi_factor,
+            HIWORD(lparam as u32) as f32 / hidpi_factor,
+        )),
+        _ => return None,
+    };
+
+    Some(event)
+}
+
+/// Injects the event into the window, as if it was sent by the operating system:
+/// the event is translated back into a window message and handled by the `WindowProc`.
+///
+/// The application data must not be borrowed, since the message is sent synchronously.
+pub(crate) unsafe fn inject_synthetic_event(hwnd: HWND, event: &SyntheticEvent, hidpi_factor: f32) {
+
+    use winapi::um::winuser::{MapVirtualKeyW, MAPVK_VSC_TO_VK_EX};
+
+    // packs two (signed) 16-bit values into an LPARAM, like MAKELPARAM
+    let make_lparam = |low: i32, high: i32| -> LPARAM {
+        ((low as u16 as u32) | ((high as u16 as u32) << 16)) as LPARAM
+    };
+
+    // lparam of WM_KEYDOWN / WM_KEYUP: repeat count = 1, scancode, extended key flag,
+    // previous key state + transition state (both set for WM_KEYUP)
+    let key_lparam = |scancode: u32, key_up: bool| -> LPARAM {
+        let mut lparam = 1 | ((scancode & 0xFF) << 16);
+        if scancode & 0xE000 != 0 {
+            lparam |= 1 << 24;
+        }
+        if key_up {
+            lparam |= (1 << 30) | (1 << 31);
+        }
+        lparam as LPARAM
+    };
+
+    let button_message = |button: &SyntheticMouseButton, down: bool| match (button, down) {
+        (SyntheticMouseButton::Left, true) => WM_LBUTTONDOWN,
+        (SyntheticMouseButton::Left, false) => WM_LBUTTONUP,
+        (SyntheticMouseButton::Right, true) => WM_RBUTTONDOWN,
+        (SyntheticMouseButton::Right, false) => WM_RBUTTONUP,
+        (SyntheticMouseButton::Middle, true) => WM_MBUTTONDOWN,
+        (SyntheticMouseButton::Middle, false) => WM_MBUTTONUP,
+    };
+
+    match event {
+        SyntheticEvent::MouseMove(p) => {
+            let x = libm::roundf(p.x * hidpi_factor) as i32;
+            let y = libm::roundf(p.y * hidpi_factor) as i32;
+            SendMessageW(hwnd, WM_MOUSEMOVE, 0, make_lparam(x, y));
+        },
+        SyntheticEvent::MouseLeave => {
+            SendMessageW(hwnd, WM_MOUSELEAVE, 0, 0);
+        },
+        SyntheticEvent::MouseDown(b) => {
+            SendMessageW(hwnd, button_message(b, true), 0, 0);
+        },
+        SyntheticEvent::MouseUp(b) => {
+            SendMessageW(hwnd, button_message(b, false), 0, 0);
+        },
+        SyntheticEvent::Scroll { x: _, y } => {
+            // horizontal scrolling is not handled by the WindowProc yet
+            let delta = libm::roundf(*y * WHEEL_DELTA as f32) as i32;
+            SendMessageW(hwnd, WM_MOUSEWHEEL, (make_lparam(0, delta) as u32) as WPARAM, 0);
+        },
+        SyntheticEvent::KeyDown { scancode, .. } => {
+            // the virtual key is re-mapped from the scancode by the current keyboard layout
+            let vk = MapVirtualKeyW(*scancode, MAPVK_VSC_TO_VK_EX);
+            SendMessageW(hwnd, WM_KEYDOWN, vk as WPARAM, key_lparam(*scancode, false));
+        },
+        SyntheticEvent::KeyUp { scancode, .. } => {
+            let vk = MapVirtualKeyW(*scancode, MAPVK_VSC_TO_VK_EX);
+            SendMessageW(hwnd, WM_KEYUP, vk as WPARAM, key_lparam(*scancode, true));
+        },
+        SyntheticEvent::Char(c) => {
+            let mut buf = [0_u16; 2];
+            for unit in c.encode_utf16(&mut buf).iter() {
+                SendMessageW(hwnd, WM_CHAR, *unit as WPARAM, 1);
+            }
+        },
+        SyntheticEvent::FocusIn => {
+            SendMessageW(hwnd, WM_SETFOCUS, 0, 0);
+        },
+        SyntheticEvent::FocusOut => {
+            SendMessageW(hwnd, WM_KILLFOCUS, 0, 0);
+        },
+        SyntheticEvent::Resize(size) => {
+
+            use winapi::um::winuser::{
+                GetClientRect, GetWindowRect, SetWindowPos,
+                SWP_NOMOVE, SWP_NOZORDER, SWP_NOACTIVATE,
+            };
+
+            // the recorded size is the client size, SetWindowPos takes the outer size
+            let mut window_rect: RECT = std::mem::zeroed();
+            let mut client_rect: RECT = std::mem::zeroed();
+            GetWindowRect(hwnd, &mut window_rect);
+            GetClientRect(hwnd, &mut client_rect);
+            let frame_width = (window_rect.right - window_rect.left) - (client_rect.right - client_rect.left);
+            let frame_height = (window_rect.bottom - window_rect.top) - (client_rect.bottom - client_rect.top);
+
+            // SetWindowPos sends WM_SIZE, which updates the window state
+            SetWindowPos(
+                hwnd,
+                ptr::null_mut(),
+                0,
+                0,
+                libm::roundf(size.width * hidpi_factor) as i32 + frame_width,
+                libm::roundf(size.height * hidpi_factor) as i32 + frame_height,
+                SWP_NOMOVE | SWP_NOZORDER | SWP_NOACTIVATE,
+            );
+        },
+    }
+}
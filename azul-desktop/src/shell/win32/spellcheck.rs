@@ -0,0 +1,277 @@
+#![allow(non_snake_case)]
+
+//! System spellchecker via the Spell Checking API
+//! (`ISpellCheckerFactory`, Windows 8 or later)
+
+use std::mem;
+use std::ffi::c_void;
+use std::ptr;
+use winapi::shared::minwindef::{BOOL, HINSTANCE, ULONG};
+use winapi::shared::ntdef::HRESULT;
+use winapi::shared::winerror::S_OK;
+use crate::spellcheck::{utf16_range_to_misspelling, Misspelling, SpellcheckProvider};
+
+#[repr(C)]
+struct Guid {
+    data1: u32,
+    data2: u16,
+    data3: u16,
+    data4: [u8; 8],
+}
+
+// {7AB36653-1796-484B-BDFA-E74F1DB7C1DC}
+const CLSID_SPELL_CHECKER_FACTORY: Guid = Guid {
+    data1: 0x7AB36653,
+    data2: 0x1796,
+    data3: 0x484B,
+    data4: [0xBD, 0xFA, 0xE7, 0x4F, 0x1D, 0xB7, 0xC1, 0xDC],
+};
+
+// {8E018A9D-2415-4677-BF08-794EA61F94BB}
+const IID_ISPELL_CHECKER_FACTORY: Guid = Guid {
+    data1: 0x8E018A9D,
+    data2: 0x2415,
+    data3: 0x4677,
+    data4: [0xBF, 0x08, 0x79, 0x4E, 0xA6, 0x1F, 0x94, 0xBB],
+};
+
+const CLSCTX_INPROC_SERVER: u32 = 0x1;
+
+type CoIncrementMTAUsage = unsafe extern "system" fn(cookie: *mut *mut c_void) -> HRESULT;
+type CoCreateInstance = unsafe extern "system" fn(
+    rclsid: *const Guid,
+    outer: *mut c_void,
+    cls_context: u32,
+    riid: *const Guid,
+    ppv: *mut *mut c_void,
+) -> HRESULT;
+type CoTaskMemFree = unsafe extern "system" fn(pv: *mut c_void);
+
+#[repr(C)]
+struct ISpellCheckerFactoryVtbl {
+    QueryInterface: *const c_void,
+    AddRef: *const c_void,
+    Release: unsafe extern "system" fn(this: *mut c_void) -> u32,
+    get_SupportedLanguages: *const c_void,
+    IsSupported: unsafe extern "system" fn(this: *mut c_void, language_tag: *const u16, value: *mut BOOL) -> HRESULT,
+    CreateSpellChecker: unsafe extern "system" fn(this: *mut c_void, language_tag: *const u16, value: *mut *mut c_void) -> HRESULT,
+}
+
+#[repr(C)]
+struct ISpellCheckerVtbl {
+    QueryInterface: *const c_void,
+    AddRef: *const c_void,
+    Release: unsafe extern "system" fn(this: *mut c_void) -> u32,
+    get_LanguageTag: *const c_void,
+    Check: unsafe extern "system" fn(this: *mut c_void, text: *const u16, value: *mut *mut c_void) -> HRESULT,
+    Suggest: unsafe extern "system" fn(this: *mut c_void, word: *const u16, value: *mut *mut c_void) -> HRESULT,
+}
+
+#[repr(C)]
+struct IEnumSpellingErrorVtbl {
+    QueryInterface: *const c_void,
+    AddRef: *const c_void,
+    Release: unsafe extern "system" fn(this: *mut c_void) -> u32,
+    Next: unsafe extern "system" fn(this: *mut c_void, value: *mut *mut c_void) -> HRESULT,
+}
+
+#[repr(C)]
+struct ISpellingErrorVtbl {
+    QueryInterface: *const c_void,
+    AddRef: *const c_void,
+    Release: unsafe extern "system" fn(this: *mut c_void) -> u32,
+    get_StartIndex: unsafe extern "system" fn(this: *mut c_void, value: *mut ULONG) -> HRESULT,
+    get_Length: unsafe extern "system" fn(this: *mut c_void, value: *mut ULONG) -> HRESULT,
+}
+
+#[repr(C)]
+struct IEnumStringVtbl {
+    QueryInterface: *const c_void,
+    AddRef: *const c_void,
+    Release: unsafe extern "system" fn(this: *mut c_void) -> u32,
+    Next: unsafe extern "system" fn(this: *mut c_void, celt: ULONG, rgelt: *mut *mut u16, fetched: *mut ULONG) -> HRESULT,
+}
+
+#[inline]
+unsafe fn vtbl<T>(object: *mut c_void) -> &'static T {
+    &**(object as *mut *const T)
+}
+
+#[derive(Debug)]
+pub(crate) struct WindowsSpellChecker {
+    ole32_dll_handle: HINSTANCE,
+    co_task_mem_free: CoTaskMemFree,
+    /// `ISpellChecker` for the language
+    spell_checker: *mut c_void,
+}
+
+// Spell checker objects are free-threaded, the multithreaded apartment is kept
+// alive with CoIncrementMTAUsage, so that the checker can be used from any thread
+unsafe impl Send for WindowsSpellChecker { }
+unsafe impl Sync for WindowsSpellChecker { }
+
+impl Drop for WindowsSpellChecker {
+    fn drop(&mut self) {
+        use winapi::um::libloaderapi::FreeLibrary;
+        unsafe {
+            (vtbl::<ISpellCheckerVtbl>(self.spell_checker).Release)(self.spell_checker);
+            FreeLibrary(self.ole32_dll_handle);
+        }
+    }
+}
+
+impl WindowsSpellChecker {
+
+    /// Creates the spell checker for the language tag (i.e. `"en-US"`), returns `None`
+    /// if the API is not available or no dictionary is installed for the language
+    pub(crate) fn new(language: &str) -> Option<Self> {
+
+        let ole32_dll = super::load_dll("ole32.dll")?;
+
+        let checker = unsafe { Self::create_spell_checker(ole32_dll, language) };
+
+        match checker {
+            Some((spell_checker, co_task_mem_free)) => Some(Self {
+                ole32_dll_handle: ole32_dll,
+                co_task_mem_free,
+                spell_checker,
+            }),
+            None => {
+                use winapi::um::libloaderapi::FreeLibrary;
+                unsafe { FreeLibrary(ole32_dll); }
+                None
+            }
+        }
+    }
+
+    unsafe fn create_spell_checker(ole32_dll: HINSTANCE, language: &str) -> Option<(*mut c_void, CoTaskMemFree)> {
+
+        let CoIncrementMTAUsage: CoIncrementMTAUsage = mem::transmute(get_func(ole32_dll, "CoIncrementMTAUsage")?);
+        let CoCreateInstance: CoCreateInstance = mem::transmute(get_func(ole32_dll, "CoCreateInstance")?);
+        let CoTaskMemFree: CoTaskMemFree = mem::transmute(get_func(ole32_dll, "CoTaskMemFree")?);
+
+        let mut mta_cookie = ptr::null_mut();
+        if CoIncrementMTAUsage(&mut mta_cookie) != S_OK {
+            return None;
+        }
+
+        let mut factory = ptr::null_mut();
+        let result = CoCreateInstance(
+            &CLSID_SPELL_CHECKER_FACTORY,
+            ptr::null_mut(),
+            CLSCTX_INPROC_SERVER,
+            &IID_ISPELL_CHECKER_FACTORY,
+            &mut factory,
+        );
+
+        if result != S_OK || factory.is_null() {
+            return None;
+        }
+
+        let factory_vtbl = vtbl::<ISpellCheckerFactoryVtbl>(factory);
+        let language_tag = super::encode_wide(language);
+
+        let mut is_supported: BOOL = 0;
+        let mut spell_checker = ptr::null_mut();
+
+        if (factory_vtbl.IsSupported)(factory, language_tag.as_ptr(), &mut is_supported) == S_OK && is_supported != 0 {
+            (factory_vtbl.CreateSpellChecker)(factory, language_tag.as_ptr(), &mut spell_checker);
+        }
+
+        (factory_vtbl.Release)(factory);
+
+        if spell_checker.is_null() {
+            None
+        } else {
+            Some((spell_checker, CoTaskMemFree))
+        }
+    }
+}
+
+fn get_func(dll: HINSTANCE, s: &str) -> Option<*mut c_void> {
+    use winapi::um::libloaderapi::GetProcAddress;
+    let mut func_name = super::encode_ascii(s);
+    let q = unsafe { GetProcAddress(dll, func_name.as_mut_ptr()) };
+    if q.is_null() { None } else { Some(q as *mut c_void) }
+}
+
+impl SpellcheckProvider for WindowsSpellChecker {
+
+    fn check(&self, text: &str) -> Vec<Misspelling> {
+
+        let mut misspellings = Vec::new();
+        let text_wide = super::encode_wide(text);
+
+        unsafe {
+
+            let mut errors = ptr::null_mut();
+            let checker_vtbl = vtbl::<ISpellCheckerVtbl>(self.spell_checker);
+            if (checker_vtbl.Check)(self.spell_checker, text_wide.as_ptr(), &mut errors) != S_OK || errors.is_null() {
+                return misspellings;
+            }
+
+            let errors_vtbl = vtbl::<IEnumSpellingErrorVtbl>(errors);
+
+            loop {
+                // returns S_FALSE if there are no more errors
+                let mut error = ptr::null_mut();
+                if (errors_vtbl.Next)(errors, &mut error) != S_OK || error.is_null() {
+                    break;
+                }
+
+                let error_vtbl = vtbl::<ISpellingErrorVtbl>(error);
+                let mut start: ULONG = 0;
+                let mut length: ULONG = 0;
+                if (error_vtbl.get_StartIndex)(error, &mut start) == S_OK &&
+                   (error_vtbl.get_Length)(error, &mut length) == S_OK {
+                    // indices are in UTF-16 code units
+                    misspellings.push(utf16_range_to_misspelling(text, start as usize, length as usize));
+                }
+
+                (error_vtbl.Release)(error);
+            }
+
+            (errors_vtbl.Release)(errors);
+        }
+
+        misspellings
+    }
+
+    fn suggest(&self, word: &str) -> Vec<String> {
+
+        let mut suggestions = Vec::new();
+        let word_wide = super::encode_wide(word);
+
+        unsafe {
+
+            // returns S_FALSE (and no suggestions) if the word is spelled correctly
+            let mut strings = ptr::null_mut();
+            let checker_vtbl = vtbl::<ISpellCheckerVtbl>(self.spell_checker);
+            if (checker_vtbl.Suggest)(self.spell_checker, word_wide.as_ptr(), &mut strings) != S_OK || strings.is_null() {
+                return suggestions;
+            }
+
+            let strings_vtbl = vtbl::<IEnumStringVtbl>(strings);
+
+            loop {
+                let mut string: *mut u16 = ptr::null_mut();
+                let mut fetched: ULONG = 0;
+                if (strings_vtbl.Next)(strings, 1, &mut string, &mut fetched) != S_OK || fetched != 1 || string.is_null() {
+                    break;
+                }
+
+                let mut len = 0;
+                while *string.add(len) != 0 {
+                    len += 1;
+                }
+
+                suggestions.push(String::from_utf16_lossy(std::slice::from_raw_parts(string, len)));
+                (self.co_task_mem_free)(string as *mut c_void);
+            }
+
+            (strings_vtbl.Release)(strings);
+        }
+
+        suggestions
+    }
+}
@@ -0,0 +1,354 @@
+//! Startup splash window (logo + progress bar), see `App::with_splash()`
+//!
+//! The splash is a plain GDI popup window: it has to be visible before the
+//! font cache is built and before OpenGL / WebRender are initialized, so it
+//! can't be rendered by the regular rendering pipeline.
+
+use core::{mem, ptr};
+use std::time::Instant;
+use azul_core::app_resources::SplashOptions;
+use azul_css::ColorU;
+use winapi::shared::minwindef::{HINSTANCE, LPARAM, LRESULT, UINT, WPARAM};
+use winapi::shared::windef::{HBITMAP, HWND, POINT, RECT};
+use super::dpi::{dpi_to_scale_factor, DpiFunctions, BASE_DPI};
+use super::encode_wide;
+
+const SPLASH_CLASS_NAME: &str = "AzulSplashClass";
+/// WM_TIMER ID of the cross-fade into the main window
+const SPLASH_FADE_OUT_TICK: usize = 1;
+
+/// Progress once the splash window is visible
+pub(crate) const SPLASH_PROGRESS_STARTED: f32 = 0.05;
+/// Progress once the font cache has been built
+pub(crate) const SPLASH_PROGRESS_FONTS_LOADED: f32 = 0.6;
+
+/// State of the splash window, owned by the `GWLP_USERDATA` of the HWND
+/// (freed in `WM_NCDESTROY`)
+struct SplashState {
+    background_color: ColorU,
+    progress_bar_color: ColorU,
+    logo: Option<(HBITMAP, usize, usize)>,
+    scale_factor: f32,
+    progress: f32,
+    // (start, duration in milliseconds) of the fade-out
+    fade_out: Option<(Instant, u64)>,
+    fade_out_millis: u64,
+}
+
+pub(crate) struct SplashWindow {
+    hwnd: HWND,
+}
+
+impl SplashWindow {
+
+    /// Creates and shows the splash window, centered on the primary monitor
+    pub(crate) fn create(
+        hinstance: HINSTANCE,
+        options: &SplashOptions,
+        dpi: &DpiFunctions,
+    ) -> Option<Self> {
+
+        use winapi::um::winuser::{
+            CreateWindowExW, GetMonitorInfoW, MonitorFromPoint, RegisterClassW,
+            SetLayeredWindowAttributes, SetWindowLongPtrW, ShowWindow, UpdateWindow,
+            GWLP_USERDATA, LWA_ALPHA, MONITORINFO, MONITOR_DEFAULTTOPRIMARY,
+            SW_SHOWNORMAL, WNDCLASSW, WS_EX_LAYERED, WS_EX_TOOLWINDOW, WS_POPUP,
+        };
+
+        let mut class_name = encode_wide(SPLASH_CLASS_NAME);
+        let mut wc: WNDCLASSW = unsafe { mem::zeroed() };
+        wc.hInstance = hinstance;
+        wc.lpszClassName = class_name.as_mut_ptr();
+        wc.lpfnWndProc = Some(SplashWindowProc);
+
+        // can fail if the class is already registered, error can be ignored
+        unsafe { RegisterClassW(&wc) };
+
+        let (monitor_rect, scale_factor) = unsafe {
+            let hmonitor = MonitorFromPoint(POINT { x: 0, y: 0 }, MONITOR_DEFAULTTOPRIMARY);
+            let mut info: MONITORINFO = mem::zeroed();
+            info.cbSize = mem::size_of::<MONITORINFO>() as u32;
+            if hmonitor.is_null() || GetMonitorInfoW(hmonitor, &mut info) == 0 {
+                return None;
+            }
+            let dpi = dpi.get_monitor_dpi(hmonitor).unwrap_or(BASE_DPI);
+            (info.rcWork, dpi_to_scale_factor(dpi))
+        };
+
+        let width = libm::roundf(options.size.width * scale_factor) as i32;
+        let height = libm::roundf(options.size.height * scale_factor) as i32;
+        let x = monitor_rect.left + ((monitor_rect.right - monitor_rect.left) - width) / 2;
+        let y = monitor_rect.top + ((monitor_rect.bottom - monitor_rect.top) - height) / 2;
+
+        let mut window_title = encode_wide("");
+        let hwnd = unsafe {
+            CreateWindowExW(
+                WS_EX_LAYERED | WS_EX_TOOLWINDOW,
+                class_name.as_mut_ptr(),
+                window_title.as_mut_ptr(),
+                WS_POPUP,
+                x,
+                y,
+                width,
+                height,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                hinstance,
+                ptr::null_mut(),
+            )
+        };
+
+        if hwnd.is_null() {
+            return None;
+        }
+
+        let logo = options.logo.as_ref().and_then(|logo| {
+            let (max_width, max_height) = get_logo_rect(width, height, scale_factor);
+            super::bitmap::create_bitmap(
+                logo,
+                Some((max_width.max(1) as usize, max_height.max(1) as usize)),
+                true,
+            )
+        });
+
+        let state = Box::new(SplashState {
+            background_color: options.background_color,
+            progress_bar_color: options.progress_bar_color,
+            logo,
+            scale_factor,
+            progress: 0.0,
+            fade_out: None,
+            fade_out_millis: match options.cross_fade {
+                azul_core::task::Duration::System(d) => d.millis(),
+                azul_core::task::Duration::Tick(_) => 0,
+            },
+        });
+
+        unsafe {
+            SetWindowLongPtrW(hwnd, GWLP_USERDATA, Box::into_raw(state) as isize);
+            SetLayeredWindowAttributes(hwnd, 0, 255, LWA_ALPHA);
+            ShowWindow(hwnd, SW_SHOWNORMAL);
+            UpdateWindow(hwnd);
+        }
+
+        Some(Self { hwnd })
+    }
+
+    /// Sets the progress (0.0 - 1.0) and repaints the progress bar immediately
+    pub(crate) fn set_progress(&self, progress: f32) {
+
+        use winapi::um::winuser::{InvalidateRect, UpdateWindow};
+
+        let state = match unsafe { get_state(self.hwnd) } {
+            Some(s) => s,
+            None => return,
+        };
+
+        let progress = progress.max(0.0).min(1.0);
+        if progress <= state.progress {
+            return;
+        }
+
+        state.progress = progress;
+
+        unsafe {
+            InvalidateRect(self.hwnd, ptr::null(), 0);
+            UpdateWindow(self.hwnd);
+        }
+    }
+
+    /// Processes the pending messages of the splash window,
+    /// so that it stays responsive while the app is loading
+    pub(crate) fn pump_messages(&self) {
+
+        use winapi::um::winuser::{DispatchMessageW, PeekMessageW, TranslateMessage, MSG, PM_REMOVE};
+
+        let mut msg: MSG = unsafe { mem::zeroed() };
+        unsafe {
+            while PeekMessageW(&mut msg, self.hwnd, 0, 0, PM_REMOVE) > 0 {
+                TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        }
+    }
+
+    /// Fills the progress bar and starts fading the splash out,
+    /// the window destroys itself once it is fully transparent
+    pub(crate) fn fade_out(mut self) {
+
+        use winapi::um::winuser::SetTimer;
+
+        self.set_progress(1.0);
+
+        let state = match unsafe { get_state(self.hwnd) } {
+            Some(s) => s,
+            None => return,
+        };
+
+        state.fade_out = Some((Instant::now(), state.fade_out_millis));
+
+        if unsafe { SetTimer(self.hwnd, SPLASH_FADE_OUT_TICK, 16, None) } != 0 {
+            // the window is now owned by the fade-out timer
+            self.hwnd = ptr::null_mut();
+        }
+    }
+}
+
+impl Drop for SplashWindow {
+    fn drop(&mut self) {
+        use winapi::um::winuser::DestroyWindow;
+        if !self.hwnd.is_null() {
+            unsafe { DestroyWindow(self.hwnd) };
+        }
+    }
+}
+
+unsafe fn get_state<'a>(hwnd: HWND) -> Option<&'a mut SplashState> {
+    use winapi::um::winuser::{GetWindowLongPtrW, GWLP_USERDATA};
+    let state = GetWindowLongPtrW(hwnd, GWLP_USERDATA) as *mut SplashState;
+    state.as_mut()
+}
+
+/// Returns the (width, height) of the area above the progress bar that the logo is centered in
+fn get_logo_rect(width: i32, height: i32, scale_factor: f32) -> (i32, i32) {
+    let margin = libm::roundf(24.0 * scale_factor) as i32;
+    let bar_height = libm::roundf(6.0 * scale_factor).max(1.0) as i32;
+    (width - 2 * margin, height - 3 * margin - bar_height)
+}
+
+const fn colorref(c: ColorU) -> u32 {
+    (c.r as u32) | ((c.g as u32) << 8) | ((c.b as u32) << 16)
+}
+
+/// Blends the progress bar color with the background color (for the track of the progress bar)
+fn mix(a: ColorU, b: ColorU, t: f32) -> ColorU {
+    let m = |a: u8, b: u8| libm::roundf(a as f32 + (b as f32 - a as f32) * t) as u8;
+    ColorU { r: m(a.r, b.r), g: m(a.g, b.g), b: m(a.b, b.b), a: 255 }
+}
+
+unsafe fn paint(hwnd: HWND, state: &SplashState) {
+
+    use winapi::um::wingdi::{
+        AlphaBlend, CreateCompatibleDC, CreateSolidBrush, DeleteDC, DeleteObject,
+        SelectObject, AC_SRC_ALPHA, AC_SRC_OVER, BLENDFUNCTION,
+    };
+    use winapi::um::winuser::{BeginPaint, EndPaint, FillRect, GetClientRect, PAINTSTRUCT};
+
+    let mut ps: PAINTSTRUCT = mem::zeroed();
+    let hdc = BeginPaint(hwnd, &mut ps);
+    if hdc.is_null() {
+        return;
+    }
+
+    let mut rect: RECT = mem::zeroed();
+    GetClientRect(hwnd, &mut rect);
+    let width = rect.right - rect.left;
+    let height = rect.bottom - rect.top;
+
+    let fill = |r: RECT, color: ColorU| {
+        let brush = CreateSolidBrush(colorref(color));
+        FillRect(hdc, &r, brush);
+        DeleteObject(brush as _);
+    };
+
+    fill(rect, state.background_color);
+
+    let margin = libm::roundf(24.0 * state.scale_factor) as i32;
+    let bar_height = libm::roundf(6.0 * state.scale_factor).max(1.0) as i32;
+
+    // logo, centered above the progress bar
+    if let Some((bitmap, logo_width, logo_height)) = state.logo {
+        let (area_width, area_height) = get_logo_rect(width, height, state.scale_factor);
+        let x = margin + (area_width - logo_width as i32) / 2;
+        let y = margin + (area_height - logo_height as i32) / 2;
+        let mem_dc = CreateCompatibleDC(hdc);
+        if !mem_dc.is_null() {
+            let old = SelectObject(mem_dc, bitmap as _);
+            let blend = BLENDFUNCTION {
+                BlendOp: AC_SRC_OVER,
+                BlendFlags: 0,
+                SourceConstantAlpha: 255,
+                AlphaFormat: AC_SRC_ALPHA,
+            };
+            AlphaBlend(
+                hdc, x, y, logo_width as i32, logo_height as i32,
+                mem_dc, 0, 0, logo_width as i32, logo_height as i32,
+                blend,
+            );
+            SelectObject(mem_dc, old);
+            DeleteDC(mem_dc);
+        }
+    }
+
+    // progress bar: track + filled part
+    let track = RECT {
+        left: margin,
+        top: height - margin - bar_height,
+        right: width - margin,
+        bottom: height - margin,
+    };
+    fill(track, mix(state.background_color, state.progress_bar_color, 0.2));
+
+    let filled = RECT {
+        right: track.left + libm::roundf((track.right - track.left) as f32 * state.progress) as i32,
+        .. track
+    };
+    if filled.right > filled.left {
+        fill(filled, state.progress_bar_color);
+    }
+
+    EndPaint(hwnd, &ps);
+}
+
+unsafe extern "system" fn SplashWindowProc(
+    hwnd: HWND,
+    msg: UINT,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+
+    use winapi::um::winuser::{
+        DefWindowProcW, DestroyWindow, KillTimer, SetLayeredWindowAttributes,
+        SetWindowLongPtrW, GWLP_USERDATA, LWA_ALPHA, WM_ERASEBKGND,
+        WM_NCDESTROY, WM_PAINT, WM_TIMER,
+    };
+
+    match msg {
+        WM_ERASEBKGND => 1, // WM_PAINT fills the entire window
+        WM_PAINT => {
+            match get_state(hwnd) {
+                Some(state) => { paint(hwnd, state); 0 },
+                None => DefWindowProcW(hwnd, msg, wparam, lparam),
+            }
+        },
+        WM_TIMER if wparam == SPLASH_FADE_OUT_TICK => {
+            let (start, duration) = match get_state(hwnd).and_then(|s| s.fade_out) {
+                Some(s) => s,
+                None => return 0,
+            };
+            let t = if duration == 0 {
+                1.0
+            } else {
+                (start.elapsed().as_millis() as f32 / duration as f32).min(1.0)
+            };
+            if t >= 1.0 {
+                KillTimer(hwnd, SPLASH_FADE_OUT_TICK);
+                DestroyWindow(hwnd);
+            } else {
+                SetLayeredWindowAttributes(hwnd, 0, libm::roundf((1.0 - t) * 255.0) as u8, LWA_ALPHA);
+            }
+            0
+        },
+        WM_NCDESTROY => {
+            let state = SetWindowLongPtrW(hwnd, GWLP_USERDATA, 0) as *mut SplashState;
+            if !state.is_null() {
+                let state = Box::from_raw(state);
+                if let Some((bitmap, _, _)) = state.logo {
+                    winapi::um::wingdi::DeleteObject(bitmap as _);
+                }
+            }
+            DefWindowProcW(hwnd, msg, wparam, lparam)
+        },
+        _ => DefWindowProcW(hwnd, msg, wparam, lparam),
+    }
+}
@@ -0,0 +1,71 @@
+//! High contrast mode, animation settings and system colors, used to resolve
+//! the CSS system color keywords and the `@media (forced-colors: active)` /
+//! `@media (prefers-reduced-motion: reduce)` media features
+
+use std::mem;
+use winapi::shared::minwindef::{BOOL, DWORD, TRUE};
+use winapi::um::winuser::{
+    GetSysColor, SystemParametersInfoW, HIGHCONTRASTW, HCF_HIGHCONTRASTON,
+    SPI_GETHIGHCONTRAST, COLOR_WINDOW, COLOR_WINDOWTEXT, COLOR_HOTLIGHT,
+    COLOR_GRAYTEXT, COLOR_HIGHLIGHT, COLOR_HIGHLIGHTTEXT, COLOR_BTNFACE,
+    COLOR_BTNTEXT,
+};
+use azul_css::{ColorU, SystemColors, SystemStyle};
+
+// "Show animations in Windows" setting (Windows Vista or later)
+const SPI_GETCLIENTAREAANIMATION: u32 = 0x1042;
+pub(crate) const SPI_SETCLIENTAREAANIMATION: u32 = 0x1043;
+
+/// Queries whether high contrast mode is enabled, whether animations
+/// are turned off and the current system colors
+pub(crate) fn query_system_style() -> SystemStyle {
+
+    let mut high_contrast: HIGHCONTRASTW = unsafe { mem::zeroed() };
+    high_contrast.cbSize = mem::size_of::<HIGHCONTRASTW>() as u32;
+
+    let forced_colors = unsafe {
+        SystemParametersInfoW(
+            SPI_GETHIGHCONTRAST,
+            high_contrast.cbSize,
+            &mut high_contrast as *mut _ as *mut _,
+            0,
+        ) != 0
+    } && high_contrast.dwFlags & HCF_HIGHCONTRASTON != 0;
+
+    let mut client_area_animation: BOOL = TRUE;
+    let prefers_reduced_motion = unsafe {
+        SystemParametersInfoW(
+            SPI_GETCLIENTAREAANIMATION,
+            0,
+            &mut client_area_animation as *mut _ as *mut _,
+            0,
+        ) != 0
+    } && client_area_animation == 0;
+
+    SystemStyle {
+        forced_colors,
+        prefers_reduced_motion,
+        // depends on the last input of the window, see `touch::set_input_modality`
+        pointer_coarse: false,
+        colors: SystemColors {
+            canvas: get_sys_color(COLOR_WINDOW),
+            canvas_text: get_sys_color(COLOR_WINDOWTEXT),
+            link_text: get_sys_color(COLOR_HOTLIGHT),
+            gray_text: get_sys_color(COLOR_GRAYTEXT),
+            highlight: get_sys_color(COLOR_HIGHLIGHT),
+            highlight_text: get_sys_color(COLOR_HIGHLIGHTTEXT),
+            button_face: get_sys_color(COLOR_BTNFACE),
+            button_text: get_sys_color(COLOR_BTNTEXT),
+        },
+    }
+}
+
+/// Reads the system color, the COLORREF is stored as 0x00BBGGRR
+fn get_sys_color(index: i32) -> ColorU {
+    let color: DWORD = unsafe { GetSysColor(index) };
+    ColorU::new_rgb(
+        (color & 0xFF) as u8,
+        ((color >> 8) & 0xFF) as u8,
+        ((color >> 16) & 0xFF) as u8,
+    )
+}
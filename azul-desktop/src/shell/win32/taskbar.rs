@@ -0,0 +1,464 @@
+#![allow(non_snake_case)]
+
+//! Taskbar integration for media applications (Windows 7 or later): buttons in the
+//! thumbnail preview of the taskbar button (`ITaskbarList3::ThumbBarAddButtons`, i.e.
+//! play / pause / next) and static preview images that are shown instead of the live
+//! window contents (`DwmSetIconicThumbnail` / `DwmSetIconicLivePreviewBitmap`).
+//!
+//! The functions take the `CallbackInfo` of the window and are applied once the
+//! callback has returned:
+//!
+//! ```rust,ignore
+//! extern "C" fn on_window_create(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+//!     taskbar::set_thumbnail_buttons(info, vec![
+//!         ThumbnailButton::new("Play", play_icon.clone(), MenuCallback { callback: Callback { cb: play }, data: data.clone() }),
+//!         ThumbnailButton::new("Next", next_icon.clone(), MenuCallback { callback: Callback { cb: next }, data: data.clone() }),
+//!     ]);
+//!     Update::DoNothing
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::ffi::c_void;
+use std::mem;
+use std::ptr;
+use winapi::shared::minwindef::{BOOL, FALSE, HINSTANCE, TRUE};
+use winapi::shared::ntdef::HRESULT;
+use winapi::shared::windef::{HICON, HWND};
+use winapi::shared::winerror::SUCCEEDED;
+use azul_core::app_resources::ImageRef;
+use azul_core::callbacks::CallbackInfo;
+use azul_core::window::{MenuCallback, RawWindowHandle};
+use azul_css::AzString;
+use super::DwmFunctions;
+
+/// Maximum number of buttons in the thumbnail toolbar
+pub const MAX_THUMBNAIL_BUTTONS: usize = 7;
+
+/// `HIWORD(wparam)` of the `WM_COMMAND` message of a thumbnail button click,
+/// `LOWORD(wparam)` is the index of the button
+pub(crate) const THBN_CLICKED: u16 = 0x1800;
+
+pub(crate) const WM_DWMSENDICONICTHUMBNAIL: u32 = 0x0323;
+pub(crate) const WM_DWMSENDICONICLIVEPREVIEWBITMAP: u32 = 0x0326;
+
+// DWMWINDOWATTRIBUTE
+const DWMWA_FORCE_ICONIC_REPRESENTATION: u32 = 7;
+const DWMWA_HAS_ICONIC_BITMAP: u32 = 10;
+
+// THUMBBUTTONMASK / THUMBBUTTONFLAGS
+const THB_ICON: u32 = 0x2;
+const THB_TOOLTIP: u32 = 0x4;
+const THB_FLAGS: u32 = 0x8;
+const THBF_ENABLED: u32 = 0x0;
+const THBF_DISABLED: u32 = 0x1;
+const THBF_HIDDEN: u32 = 0x8;
+
+/// Button in the thumbnail toolbar of the taskbar preview
+#[derive(Debug, Clone)]
+pub struct ThumbnailButton {
+    /// Tooltip of the button (truncated to 259 characters)
+    pub tooltip: AzString,
+    /// Icon of the button, has to be a BGRA8 image (scaled down to the small icon size)
+    pub icon: ImageRef,
+    /// Disabled buttons are shown grayed out
+    pub enabled: bool,
+    /// Invoked like a menu callback of the window when the button is clicked
+    pub callback: MenuCallback,
+}
+
+impl ThumbnailButton {
+    pub fn new<S: Into<AzString>>(tooltip: S, icon: ImageRef, callback: MenuCallback) -> Self {
+        Self {
+            tooltip: tooltip.into(),
+            icon,
+            enabled: true,
+            callback,
+        }
+    }
+}
+
+/// Sets the buttons of the thumbnail toolbar of the window of the callback, replacing
+/// the previous buttons. Only the first `MAX_THUMBNAIL_BUTTONS` buttons are shown.
+pub fn set_thumbnail_buttons(info: &CallbackInfo, mut buttons: Vec<ThumbnailButton>) {
+    buttons.truncate(MAX_THUMBNAIL_BUTTONS);
+    push_update(info, TaskbarUpdate::Buttons(buttons));
+}
+
+/// Shows the image (i.e. the cover of the current song) in the taskbar preview instead
+/// of the window contents, `None` restores the live preview of the window
+pub fn set_preview_image(info: &CallbackInfo, image: Option<ImageRef>) {
+    push_update(info, TaskbarUpdate::PreviewImage(image));
+}
+
+pub(crate) enum TaskbarUpdate {
+    Buttons(Vec<ThumbnailButton>),
+    PreviewImage(Option<ImageRef>),
+}
+
+thread_local! {
+    // updates made from callbacks (while the window is borrowed),
+    // applied by the window procedure, see AZ_TASKBAR_CHANGED
+    static PENDING_UPDATES: RefCell<Vec<(usize, TaskbarUpdate)>> = RefCell::new(Vec::new());
+    // ITaskbarList3 instance, created on first use
+    static TASKBAR_LIST: RefCell<Option<TaskbarList>> = RefCell::new(None);
+    // ID of the registered "TaskbarButtonCreated" message
+    static TASKBAR_BUTTON_CREATED: u32 = unsafe {
+        use winapi::um::winuser::RegisterWindowMessageW;
+        RegisterWindowMessageW(super::encode_wide("TaskbarButtonCreated").as_ptr())
+    };
+}
+
+fn push_update(info: &CallbackInfo, update: TaskbarUpdate) {
+    use winapi::um::winuser::PostMessageW;
+
+    let hwnd = match info.get_current_window_handle() {
+        RawWindowHandle::Windows(handle) => handle.hwnd as HWND,
+        _ => return,
+    };
+
+    PENDING_UPDATES.with(|p| p.borrow_mut().push((hwnd as usize, update)));
+    unsafe { PostMessageW(hwnd, super::AZ_TASKBAR_CHANGED, 0, 0); }
+}
+
+/// Removes the pending updates of the window from the queue
+pub(crate) fn take_pending_updates(hwnd: HWND) -> Vec<TaskbarUpdate> {
+    PENDING_UPDATES.with(|p| {
+        let mut pending = p.borrow_mut();
+        let (updates, rest) = pending
+            .drain(..)
+            .partition::<Vec<_>, _>(|(h, _)| *h == hwnd as usize);
+        *pending = rest;
+        updates.into_iter().map(|(_, u)| u).collect()
+    })
+}
+
+/// Message that explorer.exe sends once the taskbar button of the window was
+/// created (or re-created after explorer.exe restarted)
+pub(crate) fn taskbar_button_created_message() -> u32 {
+    TASKBAR_BUTTON_CREATED.with(|m| *m)
+}
+
+/// Taskbar state of a window
+#[derive(Debug, Default)]
+pub(crate) struct WindowTaskbar {
+    pub buttons: Vec<ThumbnailButton>,
+    pub preview_image: Option<ImageRef>,
+    /// Icons of the current buttons, destroyed when the buttons change
+    icons: Vec<HICON>,
+    /// `ThumbBarAddButtons` can only be called once per taskbar button,
+    /// later changes have to use `ThumbBarUpdateButtons`
+    buttons_added: bool,
+    /// Whether the taskbar button of the window exists
+    taskbar_button_created: bool,
+}
+
+impl Drop for WindowTaskbar {
+    fn drop(&mut self) {
+        self.destroy_icons();
+    }
+}
+
+impl WindowTaskbar {
+
+    pub(super) fn apply_update(&mut self, hwnd: HWND, update: TaskbarUpdate, dwm: Option<&DwmFunctions>) {
+        match update {
+            TaskbarUpdate::Buttons(buttons) => {
+                self.buttons = buttons;
+                self.update_buttons(hwnd);
+            },
+            TaskbarUpdate::PreviewImage(image) => {
+                self.preview_image = image;
+                if let Some(dwm) = dwm {
+                    self.update_preview_image(hwnd, dwm);
+                }
+            },
+        }
+    }
+
+    pub fn on_taskbar_button_created(&mut self, hwnd: HWND) {
+        self.taskbar_button_created = true;
+        self.buttons_added = false;
+        self.update_buttons(hwnd);
+    }
+
+    fn destroy_icons(&mut self) {
+        use winapi::um::winuser::DestroyIcon;
+        for icon in self.icons.drain(..) {
+            if !icon.is_null() {
+                unsafe { DestroyIcon(icon); }
+            }
+        }
+    }
+
+    fn update_buttons(&mut self, hwnd: HWND) {
+
+        use winapi::um::winuser::{GetSystemMetrics, SM_CXSMICON, SM_CYSMICON};
+
+        // buttons can only be added once the taskbar button exists
+        if !self.taskbar_button_created || (self.buttons.is_empty() && !self.buttons_added) {
+            return;
+        }
+
+        self.destroy_icons();
+
+        let icon_size = unsafe { (GetSystemMetrics(SM_CXSMICON) as usize, GetSystemMetrics(SM_CYSMICON) as usize) };
+        self.icons = self.buttons
+            .iter()
+            .map(|b| super::bitmap::create_icon(&b.icon, Some(icon_size), None))
+            .collect();
+
+        // the number of buttons can't change after ThumbBarAddButtons,
+        // so all slots are added and unused slots are hidden
+        let mut thumb_buttons: [THUMBBUTTON; MAX_THUMBNAIL_BUTTONS] = unsafe { mem::zeroed() };
+        for (i, thumb_button) in thumb_buttons.iter_mut().enumerate() {
+            thumb_button.dwMask = THB_ICON | THB_TOOLTIP | THB_FLAGS;
+            thumb_button.iId = i as u32;
+            match (self.buttons.get(i), self.icons.get(i)) {
+                (Some(button), Some(icon)) => {
+                    thumb_button.hIcon = *icon;
+                    thumb_button.dwFlags = if button.enabled { THBF_ENABLED } else { THBF_DISABLED };
+                    let tooltip = super::encode_wide(button.tooltip.as_str());
+                    let len = (tooltip.len() - 1).min(thumb_button.szTip.len() - 1);
+                    thumb_button.szTip[..len].copy_from_slice(&tooltip[..len]);
+                },
+                _ => {
+                    thumb_button.dwFlags = THBF_HIDDEN;
+                },
+            }
+        }
+
+        let buttons_added = self.buttons_added;
+        let succeeded = with_taskbar_list(|taskbar_list| unsafe {
+            let vtbl = vtbl::<ITaskbarList3Vtbl>(taskbar_list);
+            let result = if buttons_added {
+                (vtbl.ThumbBarUpdateButtons)(taskbar_list, hwnd, MAX_THUMBNAIL_BUTTONS as u32, thumb_buttons.as_mut_ptr())
+            } else {
+                (vtbl.ThumbBarAddButtons)(taskbar_list, hwnd, MAX_THUMBNAIL_BUTTONS as u32, thumb_buttons.as_mut_ptr())
+            };
+            SUCCEEDED(result)
+        }).unwrap_or(false);
+
+        self.buttons_added = buttons_added || succeeded;
+    }
+
+    fn update_preview_image(&mut self, hwnd: HWND, dwm: &DwmFunctions) {
+
+        let set_attribute = match dwm.DwmSetWindowAttribute {
+            Some(s) => s,
+            None => return,
+        };
+
+        let enabled: BOOL = if self.preview_image.is_some() { TRUE } else { FALSE };
+        for attribute in [DWMWA_FORCE_ICONIC_REPRESENTATION, DWMWA_HAS_ICONIC_BITMAP].iter() {
+            set_attribute(hwnd, *attribute, &enabled as *const BOOL as *const c_void, mem::size_of::<BOOL>() as u32);
+        }
+
+        // make DWM request the new image (WM_DWMSENDICONICTHUMBNAIL)
+        if let Some(invalidate) = dwm.DwmInvalidateIconicBitmaps {
+            invalidate(hwnd);
+        }
+    }
+
+    /// Handles `WM_DWMSENDICONICTHUMBNAIL`: the maximum size of the
+    /// thumbnail is in `HIWORD(lparam)` (width) and `LOWORD(lparam)` (height)
+    pub(super) fn send_iconic_thumbnail(&self, hwnd: HWND, max_width: usize, max_height: usize, dwm: &DwmFunctions) {
+        use winapi::um::wingdi::DeleteObject;
+
+        let (image, set_iconic_thumbnail) = match (self.preview_image.as_ref(), dwm.DwmSetIconicThumbnail) {
+            (Some(image), Some(f)) => (image, f),
+            _ => return,
+        };
+
+        if let Some((bitmap, _, _)) = super::bitmap::create_bitmap(image, Some((max_width, max_height)), true) {
+            set_iconic_thumbnail(hwnd, bitmap, 0);
+            unsafe { DeleteObject(bitmap as *mut _); }
+        }
+    }
+
+    /// Handles `WM_DWMSENDICONICLIVEPREVIEWBITMAP` (preview when hovering
+    /// over the thumbnail), the image is fit into the client area
+    pub(super) fn send_iconic_live_preview(&self, hwnd: HWND, dwm: &DwmFunctions) {
+        use winapi::shared::windef::RECT;
+        use winapi::um::wingdi::DeleteObject;
+        use winapi::um::winuser::GetClientRect;
+
+        let (image, set_live_preview) = match (self.preview_image.as_ref(), dwm.DwmSetIconicLivePreviewBitmap) {
+            (Some(image), Some(f)) => (image, f),
+            _ => return,
+        };
+
+        let mut rect: RECT = unsafe { mem::zeroed() };
+        unsafe { GetClientRect(hwnd, &mut rect); }
+        let client_size = ((rect.right - rect.left).max(1) as usize, (rect.bottom - rect.top).max(1) as usize);
+
+        if let Some((bitmap, _, _)) = super::bitmap::create_bitmap(image, Some(client_size), true) {
+            set_live_preview(hwnd, bitmap, ptr::null(), 0);
+            unsafe { DeleteObject(bitmap as *mut _); }
+        }
+    }
+}
+
+// --- ITaskbarList3
+
+#[repr(C)]
+struct Guid {
+    data1: u32,
+    data2: u16,
+    data3: u16,
+    data4: [u8; 8],
+}
+
+// {56FDF344-FD6D-11D0-958A-006097C9A090}
+const CLSID_TASKBAR_LIST: Guid = Guid {
+    data1: 0x56FDF344,
+    data2: 0xFD6D,
+    data3: 0x11D0,
+    data4: [0x95, 0x8A, 0x00, 0x60, 0x97, 0xC9, 0xA0, 0x90],
+};
+
+// {EA1AFB91-9E28-4B86-90E9-9E9F8A5EEFAF}
+const IID_ITASKBAR_LIST3: Guid = Guid {
+    data1: 0xEA1AFB91,
+    data2: 0x9E28,
+    data3: 0x4B86,
+    data4: [0x90, 0xE9, 0x9E, 0x9F, 0x8A, 0x5E, 0xEF, 0xAF],
+};
+
+const CLSCTX_INPROC_SERVER: u32 = 0x1;
+const COINIT_APARTMENTTHREADED: u32 = 0x2;
+
+type CoInitializeEx = unsafe extern "system" fn(reserved: *mut c_void, co_init: u32) -> HRESULT;
+type CoCreateInstance = unsafe extern "system" fn(
+    rclsid: *const Guid,
+    outer: *mut c_void,
+    cls_context: u32,
+    riid: *const Guid,
+    ppv: *mut *mut c_void,
+) -> HRESULT;
+
+#[repr(C)]
+struct THUMBBUTTON {
+    dwMask: u32,
+    iId: u32,
+    iBitmap: u32,
+    hIcon: HICON,
+    szTip: [u16; 260],
+    dwFlags: u32,
+}
+
+#[repr(C)]
+struct ITaskbarList3Vtbl {
+    QueryInterface: *const c_void,
+    AddRef: *const c_void,
+    Release: unsafe extern "system" fn(this: *mut c_void) -> u32,
+    // ITaskbarList
+    HrInit: unsafe extern "system" fn(this: *mut c_void) -> HRESULT,
+    AddTab: *const c_void,
+    DeleteTab: *const c_void,
+    ActivateTab: *const c_void,
+    SetActiveAlt: *const c_void,
+    // ITaskbarList2
+    MarkFullscreenWindow: *const c_void,
+    // ITaskbarList3
+    SetProgressValue: *const c_void,
+    SetProgressState: *const c_void,
+    RegisterTab: *const c_void,
+    UnregisterTab: *const c_void,
+    SetTabOrder: *const c_void,
+    SetTabActive: *const c_void,
+    ThumbBarAddButtons: unsafe extern "system" fn(this: *mut c_void, hwnd: HWND, count: u32, buttons: *mut THUMBBUTTON) -> HRESULT,
+    ThumbBarUpdateButtons: unsafe extern "system" fn(this: *mut c_void, hwnd: HWND, count: u32, buttons: *mut THUMBBUTTON) -> HRESULT,
+}
+
+#[inline]
+unsafe fn vtbl<T>(object: *mut c_void) -> &'static T {
+    &**(object as *mut *const T)
+}
+
+struct TaskbarList {
+    ole32_dll_handle: HINSTANCE,
+    /// `ITaskbarList3`
+    taskbar_list: *mut c_void,
+}
+
+impl Drop for TaskbarList {
+    fn drop(&mut self) {
+        use winapi::um::libloaderapi::FreeLibrary;
+        unsafe {
+            (vtbl::<ITaskbarList3Vtbl>(self.taskbar_list).Release)(self.taskbar_list);
+            FreeLibrary(self.ole32_dll_handle);
+        }
+    }
+}
+
+impl TaskbarList {
+
+    fn new() -> Option<Self> {
+
+        use winapi::um::libloaderapi::FreeLibrary;
+
+        let ole32_dll = super::load_dll("ole32.dll")?;
+
+        match unsafe { Self::create_taskbar_list(ole32_dll) } {
+            Some(taskbar_list) => Some(Self {
+                ole32_dll_handle: ole32_dll,
+                taskbar_list,
+            }),
+            None => {
+                unsafe { FreeLibrary(ole32_dll); }
+                None
+            }
+        }
+    }
+
+    unsafe fn create_taskbar_list(ole32_dll: HINSTANCE) -> Option<*mut c_void> {
+
+        let CoInitializeEx: CoInitializeEx = mem::transmute(get_func(ole32_dll, "CoInitializeEx")?);
+        let CoCreateInstance: CoCreateInstance = mem::transmute(get_func(ole32_dll, "CoCreateInstance")?);
+
+        // S_FALSE if COM is already initialized on the UI thread,
+        // RPC_E_CHANGED_MODE (still usable) if it was initialized as MTA
+        CoInitializeEx(ptr::null_mut(), COINIT_APARTMENTTHREADED);
+
+        let mut taskbar_list = ptr::null_mut();
+        let result = CoCreateInstance(
+            &CLSID_TASKBAR_LIST,
+            ptr::null_mut(),
+            CLSCTX_INPROC_SERVER,
+            &IID_ITASKBAR_LIST3,
+            &mut taskbar_list,
+        );
+
+        if !SUCCEEDED(result) || taskbar_list.is_null() {
+            return None;
+        }
+
+        let taskbar_list_vtbl = vtbl::<ITaskbarList3Vtbl>(taskbar_list);
+        if !SUCCEEDED((taskbar_list_vtbl.HrInit)(taskbar_list)) {
+            (taskbar_list_vtbl.Release)(taskbar_list);
+            return None;
+        }
+
+        Some(taskbar_list)
+    }
+}
+
+/// Runs the function with the `ITaskbarList3` of the UI thread,
+/// returns `None` if the interface is not available
+fn with_taskbar_list<T, F: FnOnce(*mut c_void) -> T>(f: F) -> Option<T> {
+    TASKBAR_LIST.with(|t| {
+        let mut taskbar_list = t.borrow_mut();
+        if taskbar_list.is_none() {
+            *taskbar_list = TaskbarList::new();
+        }
+        taskbar_list.as_ref().map(|t| f(t.taskbar_list))
+    })
+}
+
+fn get_func(dll: HINSTANCE, s: &str) -> Option<*mut c_void> {
+    use winapi::um::libloaderapi::GetProcAddress;
+    let mut func_name = super::encode_ascii(s);
+    let q = unsafe { GetProcAddress(dll, func_name.as_mut_ptr()) };
+    if q.is_null() { None } else { Some(q as *mut c_void) }
+}
@@ -0,0 +1,192 @@
+//! Touch input via the `WM_POINTER*` messages (Windows 8 or later)
+//!
+//! Touches are tracked in the `TouchState` of the window, the primary touch
+//! still gets promoted to mouse messages by `DefWindowProcW`. The device of
+//! the last input is stored in `TouchState::input_modality`.
+
+#![allow(non_snake_case)]
+
+use std::mem;
+use std::ffi::c_void;
+use winapi::shared::minwindef::{BOOL, HINSTANCE};
+use winapi::shared::windef::{HWND, POINT, RECT};
+use winapi::um::winnt::HANDLE;
+use azul_core::window::{InputModality, LogicalPosition, TouchPoint, TouchState};
+use azul_css::SystemStyle;
+
+pub const WM_POINTERUPDATE: u32 = 0x0245;
+pub const WM_POINTERDOWN: u32 = 0x0246;
+pub const WM_POINTERUP: u32 = 0x0247;
+pub const WM_POINTERCAPTURECHANGED: u32 = 0x024C;
+
+// POINTER_INPUT_TYPE
+const PT_TOUCH: u32 = 0x00000002;
+const PT_PEN: u32 = 0x00000003;
+// POINTER_INFO.pointerFlags
+const POINTER_FLAG_INCONTACT: u32 = 0x00000004;
+// POINTER_TOUCH_INFO.touchMask
+const TOUCH_MASK_PRESSURE: u32 = 0x00000004;
+// GetMessageExtraInfo() of mouse messages that were promoted from touch / pen input
+const MI_WP_SIGNATURE: usize = 0xFF515700;
+const SIGNATURE_MASK: usize = 0xFFFFFF00;
+const MI_WP_TOUCH: usize = 0x80;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct POINTER_INFO {
+    pointerType: u32,
+    pointerId: u32,
+    frameId: u32,
+    pointerFlags: u32,
+    sourceDevice: HANDLE,
+    hwndTarget: HWND,
+    ptPixelLocation: POINT,
+    ptHimetricLocation: POINT,
+    ptPixelLocationRaw: POINT,
+    ptHimetricLocationRaw: POINT,
+    dwTime: u32,
+    historyCount: u32,
+    InputData: i32,
+    dwKeyStates: u32,
+    PerformanceCount: u64,
+    ButtonChangeType: i32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct POINTER_TOUCH_INFO {
+    pointerInfo: POINTER_INFO,
+    touchFlags: u32,
+    touchMask: u32,
+    rcContact: RECT,
+    rcContactRaw: RECT,
+    orientation: u32,
+    // 0 - 1024
+    pressure: u32,
+}
+
+type GetPointerType = unsafe extern "system" fn(pointer_id: u32, pointer_type: *mut u32) -> BOOL;
+type GetPointerTouchInfo = unsafe extern "system" fn(pointer_id: u32, touch_info: *mut POINTER_TOUCH_INFO) -> BOOL;
+
+/// `WM_POINTER` functions from user32.dll, not available on Windows 7
+#[derive(Default, Debug)]
+pub struct PointerFunctions {
+    user32_dll_handle: Option<HINSTANCE>,
+    get_pointer_type: Option<GetPointerType>,
+    get_pointer_touch_info: Option<GetPointerTouchInfo>,
+}
+
+impl Drop for PointerFunctions {
+    fn drop(&mut self) {
+        use winapi::um::libloaderapi::FreeLibrary;
+        if let Some(user32) = self.user32_dll_handle {
+            unsafe {
+                FreeLibrary(user32);
+            }
+        }
+    }
+}
+
+impl PointerFunctions {
+
+    pub fn init() -> Self {
+        let user32_dll = super::load_dll("user32.dll");
+        Self {
+            user32_dll_handle: user32_dll,
+            get_pointer_type: Self::get_func(user32_dll, "GetPointerType").map(|e| unsafe { mem::transmute(e) }),
+            get_pointer_touch_info: Self::get_func(user32_dll, "GetPointerTouchInfo").map(|e| unsafe { mem::transmute(e) }),
+        }
+    }
+
+    fn get_func(dll: Option<HINSTANCE>, s: &str) -> Option<*mut c_void> {
+        use winapi::um::libloaderapi::GetProcAddress;
+        let mut func_name = super::encode_ascii(s);
+        dll.and_then(|s| unsafe {
+            let q = GetProcAddress(s, func_name.as_mut_ptr());
+            if q.is_null() { None } else { Some(q as *mut c_void) }
+        })
+    }
+
+    /// Returns whether the pointer ID of a `WM_POINTER*` message belongs to a pen
+    pub fn is_pen(&self, pointer_id: u32) -> bool {
+        let get_pointer_type = match self.get_pointer_type {
+            Some(s) => s,
+            None => return false,
+        };
+        let mut pointer_type = 0;
+        unsafe { get_pointer_type(pointer_id, &mut pointer_type) != 0 && pointer_type == PT_PEN }
+    }
+
+    /// Returns the touch for the pointer ID of a `WM_POINTER*` message (`LOWORD(wparam)`),
+    /// or `None` if the pointer is not a touch (mouse, pen, touchpad) or not in contact anymore
+    pub fn get_touch_point(&self, hwnd: HWND, pointer_id: u32, hidpi_factor: f32) -> Option<TouchPoint> {
+
+        use winapi::um::winuser::ScreenToClient;
+
+        let get_pointer_type = self.get_pointer_type?;
+        let get_pointer_touch_info = self.get_pointer_touch_info?;
+
+        let mut pointer_type = 0;
+        if unsafe { get_pointer_type(pointer_id, &mut pointer_type) } == 0 || pointer_type != PT_TOUCH {
+            return None;
+        }
+
+        let mut touch_info: POINTER_TOUCH_INFO = unsafe { mem::zeroed() };
+        if unsafe { get_pointer_touch_info(pointer_id, &mut touch_info) } == 0 ||
+           touch_info.pointerInfo.pointerFlags & POINTER_FLAG_INCONTACT == 0 {
+            return None;
+        }
+
+        let mut point = touch_info.pointerInfo.ptPixelLocation;
+        unsafe { ScreenToClient(hwnd, &mut point); }
+
+        let force = if touch_info.touchMask & TOUCH_MASK_PRESSURE != 0 {
+            (touch_info.pressure as f32 / 1024.0).max(0.0).min(1.0)
+        } else {
+            1.0
+        };
+
+        Some(TouchPoint {
+            id: pointer_id as u64,
+            position: LogicalPosition::new(
+                point.x as f32 / hidpi_factor,
+                point.y as f32 / hidpi_factor,
+            ),
+            force,
+        })
+    }
+}
+
+/// Returns the device that the current mouse message came from: Windows promotes
+/// touch and pen input to mouse messages and marks them in the message extra info
+pub fn get_mouse_message_input_modality() -> InputModality {
+    use winapi::um::winuser::GetMessageExtraInfo;
+    let extra_info = unsafe { GetMessageExtraInfo() } as usize;
+    if extra_info & SIGNATURE_MASK != MI_WP_SIGNATURE {
+        InputModality::Mouse
+    } else if extra_info & MI_WP_TOUCH != 0 {
+        InputModality::Touch
+    } else {
+        InputModality::Pen
+    }
+}
+
+/// Sets the input modality of the window, if the `(pointer: coarse)` media feature
+/// changes the DOM is regenerated and restyled with the new system style
+pub fn set_input_modality(
+    hwnd: HWND,
+    touch_state: &mut TouchState,
+    system_style: &mut SystemStyle,
+    modality: InputModality,
+) {
+
+    use winapi::um::winuser::PostMessageW;
+
+    touch_state.input_modality = modality;
+
+    let pointer_coarse = modality == InputModality::Touch;
+    if system_style.pointer_coarse != pointer_coarse {
+        system_style.pointer_coarse = pointer_coarse;
+        unsafe { PostMessageW(hwnd, super::AZ_REGENERATE_DOM, 0, 0); }
+    }
+}
@@ -0,0 +1,334 @@
+//! Screen capture via `XGetImage`, windows are captured via XComposite
+//! (libXcomposite), so that covered windows can be captured too
+
+use core::{mem, ptr};
+use core::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use crate::gl::{c_char, c_int, c_uint, c_ulong};
+use crate::capture::{CapturedImage, CaptureError, CaptureSource};
+use azul_core::window::RawWindowHandle;
+use super::{
+    Display, Drawable, Library, Window, X11Bool, X11Pixmap,
+    XOpenDisplayFuncType, XCloseDisplayFuncType, XDefaultRootWindowFuncType,
+};
+
+const Z_PIXMAP: c_int = 2;
+const ALL_PLANES: c_ulong = !0;
+const LSB_FIRST: c_int = 0;
+const COMPOSITE_REDIRECT_AUTOMATIC: c_int = 0;
+
+#[repr(C)]
+struct XImageFuncs {
+    create_image: *const core::ffi::c_void,
+    destroy_image: extern "C" fn(*mut XImage) -> c_int,
+    get_pixel: *const core::ffi::c_void,
+    put_pixel: *const core::ffi::c_void,
+    sub_image: *const core::ffi::c_void,
+    add_pixel: *const core::ffi::c_void,
+}
+
+#[repr(C)]
+struct XImage {
+    width: c_int,
+    height: c_int,
+    xoffset: c_int,
+    format: c_int,
+    data: *mut c_char,
+    byte_order: c_int,
+    bitmap_unit: c_int,
+    bitmap_bit_order: c_int,
+    bitmap_pad: c_int,
+    depth: c_int,
+    bytes_per_line: c_int,
+    bits_per_pixel: c_int,
+    red_mask: c_ulong,
+    green_mask: c_ulong,
+    blue_mask: c_ulong,
+    obdata: *mut c_char,
+    f: XImageFuncs,
+}
+
+#[repr(C)]
+struct XErrorEvent {
+    type_: c_int,
+    display: *mut Display,
+    resourceid: c_ulong,
+    serial: c_ulong,
+    error_code: u8,
+    request_code: u8,
+    minor_code: u8,
+}
+
+type XErrorHandler = Option<extern "C" fn(*mut Display, *mut XErrorEvent) -> c_int>;
+
+type XGetImageFuncType = extern "C" fn(*mut Display, Drawable, c_int, c_int, c_uint, c_uint, c_ulong, c_int) -> *mut XImage;
+type XGetGeometryFuncType = extern "C" fn(*mut Display, Drawable, *mut Window, *mut c_int, *mut c_int, *mut c_uint, *mut c_uint, *mut c_uint, *mut c_uint) -> c_int;
+type XFreePixmapFuncType = extern "C" fn(*mut Display, X11Pixmap) -> c_int;
+type XSyncFuncType = extern "C" fn(*mut Display, X11Bool) -> c_int;
+type XSetErrorHandlerFuncType = extern "C" fn(XErrorHandler) -> XErrorHandler;
+type XCompositeQueryExtensionFuncType = extern "C" fn(*mut Display, *mut c_int, *mut c_int) -> X11Bool;
+type XCompositeRedirectWindowFuncType = extern "C" fn(*mut Display, Window, c_int);
+type XCompositeUnredirectWindowFuncType = extern "C" fn(*mut Display, Window, c_int);
+type XCompositeNameWindowPixmapFuncType = extern "C" fn(*mut Display, Window) -> X11Pixmap;
+
+/// Set by the error handler, X errors (i.e. `BadMatch` for windows that are
+/// not mapped) would otherwise terminate the process
+static X_ERROR_OCCURRED: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn capture_error_handler(_: *mut Display, _: *mut XErrorEvent) -> c_int {
+    X_ERROR_OCCURRED.store(true, AtomicOrdering::SeqCst);
+    0
+}
+
+macro_rules! load_func {
+    ($lib:expr, $name:expr) => {
+        $lib.get($name)
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) }) })
+            .ok_or(CaptureError::Unsupported)?
+    };
+}
+
+struct XComposite {
+    _lib: Library,
+    XCompositeRedirectWindow: XCompositeRedirectWindowFuncType,
+    XCompositeUnredirectWindow: XCompositeUnredirectWindowFuncType,
+    XCompositeNameWindowPixmap: XCompositeNameWindowPixmapFuncType,
+}
+
+impl XComposite {
+    fn new(display: *mut Display) -> Option<Self> {
+        let lib = Library::load("libXcomposite.so.1").ok()?;
+        let load = |name: &str| lib.get(name).filter(|p| !p.is_null());
+        let XCompositeQueryExtension: XCompositeQueryExtensionFuncType = unsafe { mem::transmute(load("XCompositeQueryExtension")?) };
+        let XCompositeRedirectWindow = unsafe { mem::transmute(load("XCompositeRedirectWindow")?) };
+        let XCompositeUnredirectWindow = unsafe { mem::transmute(load("XCompositeUnredirectWindow")?) };
+        let XCompositeNameWindowPixmap = unsafe { mem::transmute(load("XCompositeNameWindowPixmap")?) };
+
+        let mut event_base = 0;
+        let mut error_base = 0;
+        if (XCompositeQueryExtension)(display, &mut event_base, &mut error_base) == 0 {
+            return None;
+        }
+
+        Some(Self {
+            _lib: lib,
+            XCompositeRedirectWindow,
+            XCompositeUnredirectWindow,
+            XCompositeNameWindowPixmap,
+        })
+    }
+}
+
+/// Dedicated display connection, closed after the capture
+struct CaptureConnection {
+    _x11: Library,
+    display: *mut Display,
+    previous_error_handler: XErrorHandler,
+    XCloseDisplay: XCloseDisplayFuncType,
+    XDefaultRootWindow: XDefaultRootWindowFuncType,
+    XGetImage: XGetImageFuncType,
+    XGetGeometry: XGetGeometryFuncType,
+    XFreePixmap: XFreePixmapFuncType,
+    XSync: XSyncFuncType,
+    XSetErrorHandler: XSetErrorHandlerFuncType,
+}
+
+impl Drop for CaptureConnection {
+    fn drop(&mut self) {
+        (self.XSetErrorHandler)(self.previous_error_handler);
+        (self.XCloseDisplay)(self.display);
+    }
+}
+
+impl CaptureConnection {
+
+    fn new() -> Result<Self, CaptureError> {
+
+        // no X server (i.e. on Wayland without XWayland)
+        if std::env::var_os("DISPLAY").is_none() {
+            return Err(CaptureError::Unsupported);
+        }
+
+        let x11 = Library::load("libX11.so.6").map_err(|_| CaptureError::Unsupported)?;
+
+        let XOpenDisplay: XOpenDisplayFuncType = load_func!(x11, "XOpenDisplay");
+        let XCloseDisplay: XCloseDisplayFuncType = load_func!(x11, "XCloseDisplay");
+        let XDefaultRootWindow: XDefaultRootWindowFuncType = load_func!(x11, "XDefaultRootWindow");
+        let XGetImage: XGetImageFuncType = load_func!(x11, "XGetImage");
+        let XGetGeometry: XGetGeometryFuncType = load_func!(x11, "XGetGeometry");
+        let XFreePixmap: XFreePixmapFuncType = load_func!(x11, "XFreePixmap");
+        let XSync: XSyncFuncType = load_func!(x11, "XSync");
+        let XSetErrorHandler: XSetErrorHandlerFuncType = load_func!(x11, "XSetErrorHandler");
+
+        let display = (XOpenDisplay)(ptr::null());
+        if display.is_null() {
+            return Err(CaptureError::Unsupported);
+        }
+
+        let previous_error_handler = (XSetErrorHandler)(Some(capture_error_handler));
+
+        Ok(Self {
+            _x11: x11,
+            display,
+            previous_error_handler,
+            XCloseDisplay,
+            XDefaultRootWindow,
+            XGetImage,
+            XGetGeometry,
+            XFreePixmap,
+            XSync,
+            XSetErrorHandler,
+        })
+    }
+
+    /// Returns the position (relative to the parent) and size of the drawable
+    fn get_geometry(&self, drawable: Drawable) -> Option<(c_int, c_int, c_uint, c_uint)> {
+        let mut root = 0;
+        let (mut x, mut y, mut width, mut height, mut border, mut depth) = (0, 0, 0, 0, 0, 0);
+        X_ERROR_OCCURRED.store(false, AtomicOrdering::SeqCst);
+        let status = (self.XGetGeometry)(self.display, drawable, &mut root, &mut x, &mut y, &mut width, &mut height, &mut border, &mut depth);
+        (self.XSync)(self.display, 0);
+        if status == 0 || X_ERROR_OCCURRED.load(AtomicOrdering::SeqCst) {
+            None
+        } else {
+            Some((x, y, width, height))
+        }
+    }
+
+    /// Copies the pixels of the drawable (`x`, `y`, `width`, `height` has to be
+    /// inside of the drawable) into the image at (`dst_x`, `dst_y`)
+    fn get_image(
+        &self,
+        drawable: Drawable,
+        x: c_int,
+        y: c_int,
+        width: c_uint,
+        height: c_uint,
+        image: &mut CapturedImage,
+        dst_x: isize,
+        dst_y: isize,
+    ) -> Result<(), CaptureError> {
+
+        X_ERROR_OCCURRED.store(false, AtomicOrdering::SeqCst);
+        let ximage = (self.XGetImage)(self.display, drawable, x, y, width, height, ALL_PLANES, Z_PIXMAP);
+        (self.XSync)(self.display, 0);
+
+        if ximage.is_null() || X_ERROR_OCCURRED.load(AtomicOrdering::SeqCst) {
+            return Err(CaptureError::Platform(String::from("XGetImage failed")));
+        }
+
+        let result = unsafe { copy_ximage(&*ximage, image, dst_x, dst_y) };
+        unsafe { ((*ximage).f.destroy_image)(ximage); }
+        result
+    }
+}
+
+pub(crate) fn capture(source: &CaptureSource) -> Result<CapturedImage, CaptureError> {
+
+    let connection = CaptureConnection::new()?;
+
+    match source {
+        CaptureSource::Region { origin, size } => {
+
+            let root = (connection.XDefaultRootWindow)(connection.display);
+            let (_, _, root_width, root_height) = connection.get_geometry(root).ok_or(CaptureError::Unsupported)?;
+
+            // XGetImage fails if the rectangle is not inside of the root window
+            let x_start = origin.x.max(0);
+            let y_start = origin.y.max(0);
+            let x_end = (origin.x as i64 + size.width as i64).min(root_width as i64) as c_int;
+            let y_end = (origin.y as i64 + size.height as i64).min(root_height as i64) as c_int;
+
+            let mut image = CapturedImage::new(size.width as usize, size.height as usize);
+            if x_end > x_start && y_end > y_start {
+                connection.get_image(
+                    root,
+                    x_start,
+                    y_start,
+                    (x_end - x_start) as c_uint,
+                    (y_end - y_start) as c_uint,
+                    &mut image,
+                    (x_start - origin.x) as isize,
+                    (y_start - origin.y) as isize,
+                )?;
+            }
+            Ok(image)
+        },
+        CaptureSource::Window(RawWindowHandle::Xlib(handle)) => {
+            capture_window(&connection, handle.window as Window)
+        },
+        CaptureSource::Window(RawWindowHandle::Xcb(handle)) => {
+            capture_window(&connection, handle.window as Window)
+        },
+        CaptureSource::Window(_) => Err(CaptureError::InvalidSource),
+    }
+}
+
+fn capture_window(connection: &CaptureConnection, window: Window) -> Result<CapturedImage, CaptureError> {
+
+    let (_, _, width, height) = connection.get_geometry(window).ok_or(CaptureError::InvalidSource)?;
+    if width == 0 || height == 0 {
+        return Err(CaptureError::InvalidSource);
+    }
+
+    let mut image = CapturedImage::new(width as usize, height as usize);
+
+    // the off-screen pixmap of the window contains the covered parts, too
+    if let Some(composite) = XComposite::new(connection.display) {
+        (composite.XCompositeRedirectWindow)(connection.display, window, COMPOSITE_REDIRECT_AUTOMATIC);
+        let pixmap = (composite.XCompositeNameWindowPixmap)(connection.display, window);
+        let result = if pixmap != 0 {
+            connection.get_image(pixmap, 0, 0, width, height, &mut image, 0, 0)
+        } else {
+            Err(CaptureError::Platform(String::from("XCompositeNameWindowPixmap failed")))
+        };
+        if pixmap != 0 {
+            (connection.XFreePixmap)(connection.display, pixmap);
+        }
+        (composite.XCompositeUnredirectWindow)(connection.display, window, COMPOSITE_REDIRECT_AUTOMATIC);
+        if result.is_ok() {
+            return Ok(image);
+        }
+    }
+
+    connection.get_image(window, 0, 0, width, height, &mut image, 0, 0)?;
+    Ok(image)
+}
+
+/// Converts the pixels of a 32-bit `ZPixmap` image into BGRA8
+unsafe fn copy_ximage(ximage: &XImage, image: &mut CapturedImage, dst_x: isize, dst_y: isize) -> Result<(), CaptureError> {
+
+    if ximage.bits_per_pixel != 32 || ximage.data.is_null() {
+        return Err(CaptureError::Platform(format!("unsupported pixel format ({} bits per pixel)", ximage.bits_per_pixel)));
+    }
+
+    let width = ximage.width.max(0) as usize;
+    let height = ximage.height.max(0) as usize;
+    let row_pitch = ximage.bytes_per_line.max(0) as usize;
+    let data = core::slice::from_raw_parts(ximage.data as *const u8, row_pitch * height);
+
+    let channel = |pixel: u32, mask: c_ulong| -> u8 {
+        let mask = mask as u32;
+        if mask == 0 { 0 } else { ((pixel & mask) >> mask.trailing_zeros()) as u8 }
+    };
+
+    // convert into tightly packed BGRA rows first
+    let mut bgra = Vec::with_capacity(width * height * 4);
+    for row in data.chunks_exact(row_pitch.max(1)).take(height) {
+        for px in row[..(width * 4).min(row.len())].chunks_exact(4) {
+            let pixel = if ximage.byte_order == LSB_FIRST {
+                u32::from_le_bytes([px[0], px[1], px[2], px[3]])
+            } else {
+                u32::from_be_bytes([px[0], px[1], px[2], px[3]])
+            };
+            let alpha_mask = !(ximage.red_mask | ximage.green_mask | ximage.blue_mask) as u32;
+            bgra.push(channel(pixel, ximage.blue_mask));
+            bgra.push(channel(pixel, ximage.green_mask));
+            bgra.push(channel(pixel, ximage.red_mask));
+            // 24-bit visuals have no alpha channel
+            bgra.push(if ximage.depth == 32 { channel(pixel, alpha_mask as c_ulong) } else { 0xFF });
+        }
+    }
+
+    image.blit_bgra(&bgra, width, height, width * 4, dst_x, dst_y);
+    Ok(())
+}
@@ -0,0 +1,379 @@
+//! Headless rendering via EGL: the window is rendered into a pbuffer surface
+//! of a display that is not connected to X11 / Wayland, see `crate::headless`
+
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::{mem, ptr};
+use std::ffi::CStr;
+use std::sync::mpsc::{channel, Sender};
+use std::time::Duration;
+use azul_core::{
+    gl::{GlContextPtr, OptionGlContextPtr},
+    window::{RendererType, WindowCreateOptions, WindowInternal, WindowInternalInit},
+};
+use gl_context_loader::gl;
+use webrender::{
+    api::{units::DeviceIntSize as WrDeviceIntSize, ColorF as WrColorF, DocumentId as WrDocumentId, RenderNotifier as WrRenderNotifier},
+    ProgramCache as WrProgramCache, Renderer as WrRenderer, RendererOptions as WrRendererOptions,
+    ShaderPrecacheFlags as WrShaderPrecacheFlags,
+};
+use crate::{
+    app::App,
+    compositor::Compositor,
+    headless::{HeadlessError, RenderedFrame},
+    wr_translate::{
+        generate_frame, rebuild_display_list, translate_document_id_wr,
+        translate_id_namespace_wr, wr_translate_debug_flags, wr_translate_document_id,
+    },
+};
+use super::{
+    display_egl_status, encode_ascii, Egl, GlFunctions, WR_SHADER_CACHE,
+    EGLBoolean, EGLConfig, EGLContext, EGLDisplay, EGLSurface, EGLenum, EGLint,
+    EGL_BLUE_SIZE, EGL_CONTEXT_MAJOR_VERSION, EGL_CONTEXT_MINOR_VERSION,
+    EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT, EGL_CONTEXT_OPENGL_PROFILE_MASK, EGL_DEPTH_SIZE,
+    EGL_EXTENSIONS, EGL_GREEN_SIZE, EGL_NONE, EGL_NO_CONTEXT, EGL_NO_DISPLAY, EGL_NO_SURFACE,
+    EGL_OPENGL_API, EGL_OPENGL_BIT, EGL_RED_SIZE, EGL_RENDERABLE_TYPE, EGL_STENCIL_SIZE,
+    EGL_SURFACE_TYPE, EGL_TRUE,
+};
+
+type eglGetPlatformDisplayEXTFuncType = extern "C" fn(EGLenum, *mut core::ffi::c_void, *const EGLint) -> EGLDisplay;
+type eglCreatePbufferSurfaceFuncType = extern "C" fn(EGLDisplay, EGLConfig, *const EGLint) -> EGLSurface;
+type eglDestroySurfaceFuncType = extern "C" fn(EGLDisplay, EGLSurface) -> EGLBoolean;
+type eglDestroyContextFuncType = extern "C" fn(EGLDisplay, EGLContext) -> EGLBoolean;
+type eglTerminateFuncType = extern "C" fn(EGLDisplay) -> EGLBoolean;
+
+const EGL_PLATFORM_SURFACELESS_MESA: EGLenum = 0x31DD;
+const EGL_PBUFFER_BIT: EGLint = 0x0001;
+const EGL_ALPHA_SIZE: EGLint = 0x3021;
+const EGL_WIDTH: EGLint = 0x3057;
+const EGL_HEIGHT: EGLint = 0x3056;
+
+// WebRender builds the frame on a background thread, give up if
+// the frame isn't ready after that time (i.e. the driver hangs)
+const FRAME_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Signals the thread that waits in `render()` once the frame has been built
+struct HeadlessNotifier {
+    frame_ready: Sender<()>,
+}
+
+impl WrRenderNotifier for HeadlessNotifier {
+    fn clone(&self) -> Box<dyn WrRenderNotifier> {
+        Box::new(HeadlessNotifier { frame_ready: self.frame_ready.clone() })
+    }
+    fn wake_up(&self, _composite_needed: bool) {}
+    fn new_frame_ready(
+        &self,
+        _: WrDocumentId,
+        _scrolled: bool,
+        _composite_needed: bool,
+        _render_time: Option<u64>,
+    ) {
+        let _ = self.frame_ready.send(());
+    }
+}
+
+/// EGL display, pbuffer and context, destroyed on drop
+struct HeadlessContext {
+    egl: Rc<Egl>,
+    display: EGLDisplay,
+    surface: EGLSurface,
+    context: EGLContext,
+}
+
+impl HeadlessContext {
+
+    fn new(egl: Rc<Egl>, width: i32, height: i32) -> Result<Self, HeadlessError> {
+
+        let display = get_surfaceless_display(&egl);
+        if display == EGL_NO_DISPLAY {
+            return Err(egl_error("eglGetDisplay(): no display"));
+        }
+
+        let mut major = 0;
+        let mut minor = 0;
+        if (egl.eglInitialize)(display, &mut major, &mut minor) != EGL_TRUE {
+            return Err(egl_error(&format!("eglInitialize(): {}", display_egl_status((egl.eglGetError)()))));
+        }
+
+        // from here on, the display is terminated when the context is dropped
+        let mut headless = Self {
+            egl: egl.clone(),
+            display,
+            surface: EGL_NO_SURFACE,
+            context: EGL_NO_CONTEXT,
+        };
+
+        if (egl.eglBindAPI)(EGL_OPENGL_API) != EGL_TRUE {
+            return Err(egl_error("eglBindAPI(): OpenGL is not supported"));
+        }
+
+        let config_attr = [
+            EGL_SURFACE_TYPE,    EGL_PBUFFER_BIT,
+            EGL_RENDERABLE_TYPE, EGL_OPENGL_BIT,
+
+            EGL_RED_SIZE,      8,
+            EGL_GREEN_SIZE,    8,
+            EGL_BLUE_SIZE,     8,
+            EGL_ALPHA_SIZE,    8,
+            EGL_DEPTH_SIZE,   24,
+            EGL_STENCIL_SIZE,  8,
+
+            EGL_NONE,
+        ];
+
+        let mut config: EGLConfig = unsafe { mem::zeroed() };
+        let mut count = 0;
+        if (egl.eglChooseConfig)(display, config_attr.as_ptr(), &mut config, 1, &mut count) != EGL_TRUE || count != 1 {
+            return Err(egl_error("eglChooseConfig(): no RGBA8 pbuffer config"));
+        }
+
+        let eglCreatePbufferSurface: eglCreatePbufferSurfaceFuncType = load_egl_function(&egl, "eglCreatePbufferSurface")?;
+
+        let surface_attr = [
+            EGL_WIDTH, width,
+            EGL_HEIGHT, height,
+            EGL_NONE,
+        ];
+
+        headless.surface = (eglCreatePbufferSurface)(display, config, surface_attr.as_ptr());
+        if headless.surface == EGL_NO_SURFACE {
+            return Err(egl_error(&format!("eglCreatePbufferSurface(): {}", display_egl_status((egl.eglGetError)()))));
+        }
+
+        let context_attr = [
+            EGL_CONTEXT_MAJOR_VERSION, 3,
+            EGL_CONTEXT_MINOR_VERSION, 2,
+            EGL_CONTEXT_OPENGL_PROFILE_MASK, EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT,
+            EGL_NONE,
+        ];
+
+        headless.context = (egl.eglCreateContext)(display, config, EGL_NO_CONTEXT, context_attr.as_ptr());
+        if headless.context == EGL_NO_CONTEXT {
+            return Err(egl_error(&format!("eglCreateContext(): {}", display_egl_status((egl.eglGetError)()))));
+        }
+
+        if (egl.eglMakeCurrent)(display, headless.surface, headless.surface, headless.context) != EGL_TRUE {
+            return Err(egl_error(&format!("eglMakeCurrent(): {}", display_egl_status((egl.eglGetError)()))));
+        }
+
+        Ok(headless)
+    }
+}
+
+impl Drop for HeadlessContext {
+    fn drop(&mut self) {
+
+        let egl = &self.egl;
+        (egl.eglMakeCurrent)(self.display, EGL_NO_SURFACE, EGL_NO_SURFACE, EGL_NO_CONTEXT);
+
+        if self.context != EGL_NO_CONTEXT {
+            if let Ok(eglDestroyContext) = load_egl_function::<eglDestroyContextFuncType>(egl, "eglDestroyContext") {
+                (eglDestroyContext)(self.display, self.context);
+            }
+        }
+
+        if self.surface != EGL_NO_SURFACE {
+            if let Ok(eglDestroySurface) = load_egl_function::<eglDestroySurfaceFuncType>(egl, "eglDestroySurface") {
+                (eglDestroySurface)(self.display, self.surface);
+            }
+        }
+
+        if let Ok(eglTerminate) = load_egl_function::<eglTerminateFuncType>(egl, "eglTerminate") {
+            (eglTerminate)(self.display);
+        }
+    }
+}
+
+/// Renders the first frame of the window into a pbuffer and reads it back
+pub(crate) fn render(app: &mut App, mut options: WindowCreateOptions) -> Result<RenderedFrame, HeadlessError> {
+
+    // azul.toml overrides the renderer options and debug flags
+    crate::config_file::get().apply_to_window(&mut options);
+
+    // there is no monitor: render at 96 DPI unless azul.toml sets the DPI
+    let dpi_scale_factor = match crate::config_file::get().dpi {
+        Some(dpi) => dpi as f32 / 96.0,
+        None => 1.0,
+    };
+    options.state.size.dpi = (dpi_scale_factor.max(0.0) * 96.0).round() as u32;
+    options.state.size.hidpi_factor = dpi_scale_factor;
+    options.state.size.system_hidpi_factor = dpi_scale_factor;
+
+    let physical_size = options.state.size.dimensions.to_physical(dpi_scale_factor);
+    let width = physical_size.width as i32;
+    let height = physical_size.height as i32;
+    if width <= 0 || height <= 0 {
+        return Err(HeadlessError::InvalidSize);
+    }
+
+    let egl = Rc::new(Egl::new().map_err(|e| HeadlessError::Platform(format!("{:?}", e)))?);
+    let headless_context = HeadlessContext::new(egl.clone(), width, height)?;
+
+    let mut gl_functions = GlFunctions::initialize(egl.clone());
+    gl_functions.load();
+
+    // check the driver for known bugs before initializing WebRender
+    let gl_quirks = {
+        let driver = azul_core::gl::GlDriverInfo::query(&gl_functions.functions);
+        crate::gl_quirks::resolve_gl_quirks(&driver, &app.gl_quirk_rules)
+    };
+
+    let gl_context_ptr: OptionGlContextPtr = Some(GlContextPtr::new(
+        RendererType::Hardware,
+        gl_functions.functions.clone(),
+        gl_quirks,
+    )).into();
+
+    let (frame_ready_sender, frame_ready_receiver) = channel();
+
+    let (mut renderer, sender) = WrRenderer::new(
+        gl_functions.functions.clone(),
+        Box::new(HeadlessNotifier { frame_ready: frame_ready_sender }),
+        WrRendererOptions {
+            resource_override_path: None,
+            use_optimized_shaders: !gl_quirks.disable_optimized_shaders,
+            enable_aa: true,
+            // the background behind the window is unknown (transparent)
+            enable_subpixel_aa: false,
+            force_subpixel_aa: false,
+            clear_color: WrColorF { r: 0.0, g: 0.0, b: 0.0, a: 0.0 }, // transparent
+            panic_on_gl_error: false,
+            precache_flags: WrShaderPrecacheFlags::EMPTY,
+            cached_programs: if gl_quirks.disable_program_cache {
+                None
+            } else {
+                Some(WrProgramCache::new(None))
+            },
+            enable_multithreading: !gl_quirks.disable_multithreading,
+            debug_flags: wr_translate_debug_flags(&options.state.debug_state),
+            ..WrRendererOptions::default()
+        },
+        WR_SHADER_CACHE,
+    ).map_err(|e| HeadlessError::Platform(format!("could not init WebRender: {:?}", e)))?;
+
+    renderer.set_external_image_handler(Box::new(Compositor::default()));
+
+    let mut render_api = sender.create_api();
+
+    let framebuffer_size = WrDeviceIntSize::new(width, height);
+    let document_id = translate_document_id_wr(render_api.add_document(framebuffer_size));
+    let id_namespace = translate_id_namespace_wr(render_api.get_namespace_id());
+
+    // nothing is hit-tested, but WindowInternal::new() needs a hit tester
+    let hit_tester = render_api
+        .request_hit_tester(wr_translate_document_id(document_id))
+        .resolve();
+    let hit_tester_ref = &*hit_tester;
+
+    let App { data, image_cache, fc_cache, .. } = app;
+
+    let mut initial_resource_updates = Vec::new();
+    let mut internal = fc_cache.apply_closure(|fc_cache| {
+        WindowInternal::new(
+            WindowInternalInit {
+                window_create_options: options.clone(),
+                document_id,
+                id_namespace,
+                system_style: azul_css::SystemStyle::DEFAULT,
+            },
+            data,
+            image_cache,
+            &gl_context_ptr,
+            &mut initial_resource_updates,
+            &crate::app::CALLBACKS,
+            fc_cache,
+            azul_layout::do_the_relayout,
+            |window_state, _scroll_states, layout_results| {
+                crate::wr_translate::fullhittest_new_webrender(
+                    hit_tester_ref,
+                    document_id,
+                    window_state.focused_node,
+                    layout_results,
+                    &window_state.mouse_state.cursor_position,
+                    &window_state.touch_state,
+                    window_state.size.hidpi_factor,
+                )
+            },
+        )
+    });
+
+    rebuild_display_list(&mut internal, &mut render_api, image_cache, initial_resource_updates);
+    render_api.flush_scene_builder();
+    generate_frame(&mut internal, &mut render_api, true);
+
+    let pixels = if frame_ready_receiver.recv_timeout(FRAME_TIMEOUT).is_err() {
+        Err(HeadlessError::Platform(String::from("timed out waiting for the frame")))
+    } else {
+        let gl = &gl_functions.functions;
+        gl.bind_framebuffer(gl::FRAMEBUFFER, 0);
+        gl.viewport(0, 0, width, height);
+        gl.clear_color(0.0, 0.0, 0.0, 0.0);
+        gl.clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT | gl::STENCIL_BUFFER_BIT);
+
+        renderer.update();
+        match renderer.render(framebuffer_size, 0) {
+            Ok(_) => {
+                gl.finish();
+                Ok(gl.read_pixels(0, 0, width, height, gl::RGBA, gl::UNSIGNED_BYTE))
+            },
+            Err(e) => Err(HeadlessError::Platform(format!("rendering failed: {:?}", e))),
+        }
+    };
+
+    // GL resources have to be released while the context is still current
+    mem::drop(internal);
+    renderer.deinit();
+    mem::drop(headless_context);
+
+    Ok(RenderedFrame {
+        width: width as usize,
+        height: height as usize,
+        pixels: pixels?,
+    })
+}
+
+/// Returns a display that doesn't need a display server: the surfaceless
+/// platform of Mesa if available, otherwise the default display
+fn get_surfaceless_display(egl: &Egl) -> EGLDisplay {
+
+    // client extensions are queried without a display
+    let client_extensions = {
+        let s = (egl.eglQueryString)(EGL_NO_DISPLAY, EGL_EXTENSIONS);
+        if s.is_null() {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(s) }.to_string_lossy().into_owned()
+        }
+    };
+
+    let has_surfaceless = client_extensions
+        .split_whitespace()
+        .any(|e| e == "EGL_MESA_platform_surfaceless");
+
+    if has_surfaceless {
+        let ptr = (egl.eglGetProcAddress)(encode_ascii("eglGetPlatformDisplayEXT").as_ptr() as *const _);
+        if !ptr.is_null() {
+            let eglGetPlatformDisplayEXT: eglGetPlatformDisplayEXTFuncType = unsafe { mem::transmute(ptr) };
+            let display = (eglGetPlatformDisplayEXT)(EGL_PLATFORM_SURFACELESS_MESA, ptr::null_mut(), ptr::null());
+            if display != EGL_NO_DISPLAY {
+                return display;
+            }
+        }
+    }
+
+    // EGL_DEFAULT_DISPLAY
+    (egl.eglGetDisplay)(ptr::null_mut())
+}
+
+fn load_egl_function<T: Copy>(egl: &Egl, name: &'static str) -> Result<T, HeadlessError> {
+    match egl.library.get(name) {
+        Some(ptr) if !ptr.is_null() => Ok(unsafe { mem::transmute_copy(&ptr) }),
+        _ => Err(egl_error(&format!("no function {}", name))),
+    }
+}
+
+fn egl_error(e: &str) -> HeadlessError {
+    HeadlessError::Platform(format!("EGL: {}", e))
+}
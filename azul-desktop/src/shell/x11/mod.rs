@@ -26,8 +26,8 @@ use azul_core::{
         RefAny, UpdateImageType,
         DomNodeId, DocumentId
     },
-    gl::OptionGlContextPtr,
-    task::{Thread, ThreadId, Timer, TimerId},
+    gl::{GlDriverInfo, GlQuirkRule, OptionGlContextPtr},
+    task::{OptionDuration, Thread, ThreadId, Timer, TimerId},
     ui_solver::LayoutResult,
     styled_dom::DomId,
     dom::NodeId,
@@ -36,7 +36,8 @@ use azul_core::{
         LogicalSize, Menu, MenuCallback, MenuItem,
         MonitorVec, WindowCreateOptions, WindowInternal,
         WindowState, FullWindowState, ScrollResult,
-        MouseCursorType, CallCallbacksResult
+        MouseCursorType, CallCallbacksResult, WindowBackgroundEffect, FirstFrameMode,
+        KeyboardModifiers, OptionChar, VirtualKeyCode, ShortcutMap,
     },
     window_state::NodesToCheck,
 };
@@ -66,10 +67,15 @@ use webrender::{
     RendererOptions as WrRendererOptions, ShaderPrecacheFlags as WrShaderPrecacheFlags,
     Shaders as WrShaders, Transaction as WrTransaction,
 };
-use std::ffi::{CString, OsStr};
+use std::ffi::{CStr, CString, OsStr};
 use std::os::raw;
 use gl_context_loader::gl;
 
+#[cfg(feature = "capture")]
+pub(crate) mod capture;
+#[cfg(feature = "headless")]
+pub(crate) mod headless;
+
 // TODO: Cache compiled shaders between renderers
 const WR_SHADER_CACHE: Option<&Rc<RefCell<WrShaders>>> = None;
 
@@ -120,6 +126,314 @@ pub fn get_monitors(app: &App) -> MonitorVec {
     MonitorVec::from_const_slice(&[]) // TODO
 }
 
+/// Returns the time since the last keyboard / mouse input on the X server
+/// (via the MIT-SCREEN-SAVER extension), `None` if libXss is not installed
+pub extern "C" fn get_system_idle_time() -> OptionDuration {
+
+    use once_cell::sync::OnceCell;
+    use std::sync::Mutex;
+
+    // Xlib is not initialized with XInitThreads(): the display connection
+    // must not be used by two threads at the same time
+    static XSS: OnceCell<Option<Mutex<XScreenSaver>>> = OnceCell::new();
+
+    let xss = match XSS.get_or_init(|| XScreenSaver::new().map(Mutex::new)) {
+        Some(s) => s,
+        None => return OptionDuration::None,
+    };
+
+    let xss = match xss.lock() {
+        Ok(o) => o,
+        Err(_) => return OptionDuration::None,
+    };
+
+    let mut info = XScreenSaverInfo {
+        window: 0,
+        state: 0,
+        kind: 0,
+        til_or_since: 0,
+        idle: 0,
+        event_mask: 0,
+    };
+
+    let root = (xss.XDefaultRootWindow)(xss.display);
+    if (xss.XScreenSaverQueryInfo)(xss.display, root, &mut info) == 0 {
+        return OptionDuration::None;
+    }
+
+    OptionDuration::Some(std::time::Duration::from_millis(info.idle as u64).into())
+}
+
+#[repr(C)]
+struct XScreenSaverInfo {
+    window: Window,
+    state: c_int,
+    kind: c_int,
+    til_or_since: c_ulong,
+    idle: c_ulong,
+    event_mask: c_ulong,
+}
+
+type XDefaultRootWindowFuncType = extern "C" fn(*mut Display) -> c_ulong;
+type XScreenSaverQueryInfoFuncType = extern "C" fn(*mut Display, Drawable, *mut XScreenSaverInfo) -> c_int;
+
+// libXss.so.1 + a dedicated display connection, only used for querying the idle time
+struct XScreenSaver {
+    _x11: Library,
+    _xss: Library,
+    display: *mut Display,
+    XDefaultRootWindow: XDefaultRootWindowFuncType,
+    XScreenSaverQueryInfo: XScreenSaverQueryInfoFuncType,
+}
+
+// the display connection is never closed, get_system_idle_time() only
+// accesses it through a Mutex, so only one thread at a time can use it
+unsafe impl Send for XScreenSaver {}
+
+impl XScreenSaver {
+    fn new() -> Option<Self> {
+
+        let x11 = Library::load("libX11.so.6").ok()?;
+        let xss = Library::load("libXss.so.1").ok()?;
+
+        let XOpenDisplay: XOpenDisplayFuncType = x11.get("XOpenDisplay")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})?;
+        let XDefaultRootWindow: XDefaultRootWindowFuncType = x11.get("XDefaultRootWindow")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})?;
+        let XScreenSaverQueryInfo: XScreenSaverQueryInfoFuncType = xss.get("XScreenSaverQueryInfo")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})?;
+
+        let display = (XOpenDisplay)(ptr::null());
+        if display.is_null() {
+            return None;
+        }
+
+        Some(Self {
+            _x11: x11,
+            _xss: xss,
+            display,
+            XDefaultRootWindow,
+            XScreenSaverQueryInfo,
+        })
+    }
+}
+
+/// Maps the virtual keycode to the character it produces in the current XKB
+/// keyboard layout, `None` if the X server can't be reached. Only keysyms that
+/// directly encode a Unicode codepoint (Latin-1 and `0x01000000 + codepoint`)
+/// are translated, which covers the keys that are shown in shortcuts.
+pub extern "C" fn keycode_to_char(
+    virtual_keycode: VirtualKeyCode,
+    modifiers: KeyboardModifiers,
+) -> OptionChar {
+
+    use once_cell::sync::OnceCell;
+    use std::sync::Mutex;
+
+    // Xlib is not initialized with XInitThreads(): the display connection
+    // must not be used by two threads at the same time
+    static XKB: OnceCell<Option<Mutex<XKeyboard>>> = OnceCell::new();
+
+    let xkb = match XKB.get_or_init(|| XKeyboard::new().map(Mutex::new)) {
+        Some(s) => s,
+        None => return OptionChar::None,
+    };
+
+    let xkb = match xkb.lock() {
+        Ok(o) => o,
+        Err(_) => return OptionChar::None,
+    };
+
+    let keysym = match virtual_keycode_to_keysym(virtual_keycode) {
+        Some(s) => s,
+        None => return OptionChar::None,
+    };
+
+    xkb.refresh_keyboard_mapping();
+
+    // the active layout (i.e. after switching from "us" to "de")
+    let group = xkb.get_current_group();
+
+    // search the physical key that produces the keysym in the current layout
+    let keycode = (xkb.XKeysymToKeycode)(xkb.display, keysym);
+    if keycode == 0 {
+        return OptionChar::None;
+    }
+
+    // shift level 1, AltGr (ctrl + alt) level 2, both level 3
+    let altgr = modifiers.ctrl && modifiers.alt;
+    let level = (modifiers.shift as c_int) + if altgr { 2 } else { 0 };
+    let mut keysym = (xkb.XkbKeycodeToKeysym)(xkb.display, keycode, group, level);
+    if keysym == 0 && altgr {
+        // the key has no AltGr level
+        return OptionChar::None;
+    }
+    if keysym == 0 {
+        keysym = (xkb.XkbKeycodeToKeysym)(xkb.display, keycode, group, 0);
+    }
+
+    let c = match keysym {
+        0x20..=0x7e | 0xa0..=0xff => char::from_u32(keysym as u32),
+        0x0100_0000..=0x0110_ffff => char::from_u32((keysym - 0x0100_0000) as u32),
+        _ => None,
+    };
+
+    let c = match c {
+        Some(c) if modifiers.caps_lock && c.is_alphabetic() => {
+            // CapsLock inverts the case of letters
+            if c.is_lowercase() {
+                Some(c.to_uppercase().next().unwrap_or(c))
+            } else {
+                Some(c.to_lowercase().next().unwrap_or(c))
+            }
+        },
+        other => other,
+    };
+
+    c.map(|c| c as u32).into()
+}
+
+fn virtual_keycode_to_keysym(virtual_keycode: VirtualKeyCode) -> Option<c_ulong> {
+    use azul_core::window::VirtualKeyCode::*;
+    let keysym = match virtual_keycode {
+        Key0 => 0x30, Key1 => 0x31, Key2 => 0x32, Key3 => 0x33, Key4 => 0x34,
+        Key5 => 0x35, Key6 => 0x36, Key7 => 0x37, Key8 => 0x38, Key9 => 0x39,
+        A => 0x61, B => 0x62, C => 0x63, D => 0x64, E => 0x65, F => 0x66, G => 0x67,
+        H => 0x68, I => 0x69, J => 0x6a, K => 0x6b, L => 0x6c, M => 0x6d, N => 0x6e,
+        O => 0x6f, P => 0x70, Q => 0x71, R => 0x72, S => 0x73, T => 0x74, U => 0x75,
+        V => 0x76, W => 0x77, X => 0x78, Y => 0x79, Z => 0x7a,
+        Space => 0x20,
+        Apostrophe => 0x27,
+        Comma => 0x2c,
+        Minus => 0x2d,
+        Period => 0x2e,
+        Slash => 0x2f,
+        Semicolon => 0x3b,
+        Equals => 0x3d,
+        LBracket => 0x5b,
+        Backslash => 0x5c,
+        RBracket => 0x5d,
+        Grave => 0x60,
+        Numpad0 => 0xffb0, Numpad1 => 0xffb1, Numpad2 => 0xffb2, Numpad3 => 0xffb3,
+        Numpad4 => 0xffb4, Numpad5 => 0xffb5, Numpad6 => 0xffb6, Numpad7 => 0xffb7,
+        Numpad8 => 0xffb8, Numpad9 => 0xffb9,
+        NumpadAdd => 0xffab,
+        NumpadSubtract => 0xffad,
+        NumpadMultiply => 0xffaa,
+        NumpadDivide => 0xffaf,
+        NumpadDecimal => 0xffae,
+        _ => return None,
+    };
+    Some(keysym)
+}
+
+#[repr(C)]
+struct XkbStateRec {
+    group: c_uchar,
+    locked_group: c_uchar,
+    base_group: c_ushort,
+    latched_group: c_ushort,
+    mods: c_uchar,
+    base_mods: c_uchar,
+    latched_mods: c_uchar,
+    locked_mods: c_uchar,
+    compat_state: c_uchar,
+    grab_mods: c_uchar,
+    compat_grab_mods: c_uchar,
+    lookup_mods: c_uchar,
+    compat_lookup_mods: c_uchar,
+    ptr_buttons: c_ushort,
+}
+
+const XKB_USE_CORE_KBD: c_uint = 0x0100;
+const X11_MAPPING_NOTIFY: c_int = 34;
+
+type XKeysymToKeycodeFuncType = extern "C" fn(*mut Display, c_ulong) -> c_uchar;
+type XkbKeycodeToKeysymFuncType = extern "C" fn(*mut Display, c_uchar, c_int, c_int) -> c_ulong;
+type XkbGetStateFuncType = extern "C" fn(*mut Display, c_uint, *mut XkbStateRec) -> c_int;
+type XRefreshKeyboardMappingFuncType = extern "C" fn(*mut XMappingEvent) -> c_int;
+
+// libX11.so.6 + a dedicated display connection, only used for keyboard layout
+// queries. Xlib caches the keymap per connection and only updates the cache while
+// reading the MappingNotify / XkbMapNotify events of that connection.
+struct XKeyboard {
+    _x11: Library,
+    display: *mut Display,
+    XKeysymToKeycode: XKeysymToKeycodeFuncType,
+    XkbKeycodeToKeysym: XkbKeycodeToKeysymFuncType,
+    XkbGetState: XkbGetStateFuncType,
+    XPending: XPendingFuncType,
+    XNextEvent: XNextEventFuncType,
+    XRefreshKeyboardMapping: XRefreshKeyboardMappingFuncType,
+}
+
+// the display connection is never closed, keycode_to_char() only
+// accesses it through a Mutex, so only one thread at a time can use it
+unsafe impl Send for XKeyboard {}
+
+impl XKeyboard {
+    fn new() -> Option<Self> {
+
+        let x11 = Library::load("libX11.so.6").ok()?;
+
+        let XOpenDisplay: XOpenDisplayFuncType = x11.get("XOpenDisplay")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})?;
+        let XKeysymToKeycode: XKeysymToKeycodeFuncType = x11.get("XKeysymToKeycode")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})?;
+        let XkbKeycodeToKeysym: XkbKeycodeToKeysymFuncType = x11.get("XkbKeycodeToKeysym")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})?;
+        let XkbGetState: XkbGetStateFuncType = x11.get("XkbGetState")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})?;
+        let XPending: XPendingFuncType = x11.get("XPending")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})?;
+        let XNextEvent: XNextEventFuncType = x11.get("XNextEvent")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})?;
+        let XRefreshKeyboardMapping: XRefreshKeyboardMappingFuncType = x11.get("XRefreshKeyboardMapping")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})?;
+
+        let display = (XOpenDisplay)(ptr::null());
+        if display.is_null() {
+            return None;
+        }
+
+        Some(Self {
+            _x11: x11,
+            display,
+            XKeysymToKeycode,
+            XkbKeycodeToKeysym,
+            XkbGetState,
+            XPending,
+            XNextEvent,
+            XRefreshKeyboardMapping,
+        })
+    }
+
+    /// Reads the pending events of the dedicated connection (it has no event loop),
+    /// so that Xlib updates its cached keymap after the keyboard layout changed:
+    /// XNextEvent() processes the XkbMapNotify events internally, the core
+    /// MappingNotify events have to be passed to XRefreshKeyboardMapping()
+    fn refresh_keyboard_mapping(&self) {
+        while (self.XPending)(self.display) > 0 {
+            let mut event: XEvent = unsafe { mem::zeroed() };
+            (self.XNextEvent)(self.display, &mut event);
+            if event.get_type() == X11_MAPPING_NOTIFY {
+                (self.XRefreshKeyboardMapping)(unsafe { &mut event.mapping });
+            }
+        }
+    }
+
+    /// Returns the currently active XKB group (keyboard layout), 0 if the state
+    /// can't be queried. The state is queried on every call, since switching the
+    /// layout doesn't change the keymap.
+    fn get_current_group(&self) -> c_int {
+        let mut state: XkbStateRec = unsafe { mem::zeroed() };
+        if (self.XkbGetState)(self.display, XKB_USE_CORE_KBD, &mut state) != 0 {
+            return 0;
+        }
+        state.group as c_int
+    }
+}
+
 // Minimal typedefs from <EGL/egl.h>
 
 type EGLDisplay = *mut c_void;
@@ -143,6 +457,9 @@ type eglMakeCurrentFuncType = extern "C" fn(EGLDisplay, EGLSurface, EGLSurface,
 type eglSwapBuffersFuncType = extern "C" fn(EGLDisplay, EGLSurface) -> EGLBoolean;
 type eglGetErrorFuncType = extern "C" fn () -> EGLint;
 type eglGetProcAddressFuncType = extern "C" fn(*const c_char) -> *mut raw::c_void;
+type eglQueryStringFuncType = extern "C" fn(EGLDisplay, EGLint) -> *const c_char;
+type eglQuerySurfaceFuncType = extern "C" fn(EGLDisplay, EGLSurface, EGLint, *mut EGLint) -> EGLBoolean;
+type eglSwapBuffersWithDamageFuncType = extern "C" fn(EGLDisplay, EGLSurface, *const EGLint, EGLint) -> EGLBoolean;
 
 type XDefaultScreenFuncType = extern "C" fn(*mut Display) -> c_int;
 type XRootWindowFuncType = extern "C" fn(*mut Display, c_int) -> c_ulong;
@@ -156,6 +473,14 @@ type XCloseDisplayFuncType = extern "C" fn(*mut Display) -> c_int;
 type XPendingFuncType = extern "C" fn(*mut Display) -> c_int;
 type XNextEventFuncType = extern "C" fn(*mut Display, *mut XEvent) -> c_int;
 type XSelectInputFuncType = extern "C" fn(_: *mut Display, _: c_ulong, _: c_long) -> c_int;
+type XSendEventFuncType = extern "C" fn(*mut Display, c_ulong, X11Bool, c_long, *mut XEvent) -> c_int;
+type XUngrabPointerFuncType = extern "C" fn(*mut Display, Time) -> c_int;
+type XFlushFuncType = extern "C" fn(*mut Display) -> c_int;
+type XClearAreaFuncType = extern "C" fn(*mut Display, c_ulong, c_int, c_int, c_uint, c_uint, X11Bool) -> c_int;
+type XConnectionNumberFuncType = extern "C" fn(*mut Display) -> c_int;
+type XChangePropertyFuncType = extern "C" fn(*mut Display, c_ulong, c_ulong, c_ulong, c_int, c_int, *const c_uchar, c_int) -> c_int;
+type XSetWMNormalHintsFuncType = extern "C" fn(*mut Display, c_ulong, *mut XSizeHints);
+type XShapeCombineRectanglesFuncType = extern "C" fn(*mut Display, c_ulong, c_int, c_int, c_int, *mut c_void, c_int, c_int, c_int);
 
 const EGL_NO_DISPLAY: EGLDisplay = 0 as *mut c_void;
 const EGL_OPENGL_API: EGLenum = 0x30A2;
@@ -180,6 +505,12 @@ const EGL_NO_SURFACE: EGLSurface = 0 as *mut c_void;
 const EGL_NO_CONTEXT: EGLContext = 0 as *mut c_void;
 const EGL_FALSE: EGLBoolean = 0;
 const EGL_TRUE: EGLBoolean = 1;
+const EGL_EXTENSIONS: EGLint = 0x3055;
+const EGL_BUFFER_AGE_EXT: EGLint = 0x313D;
+
+// number of dirty rects that WebRender merges the damage into, the
+// damage is only a hint for the compositor, so one rect is enough
+const MAX_PARTIAL_PRESENT_RECTS: usize = 1;
 
 const EGL_CONTEXT_MAJOR_VERSION: EGLint = 0x00003098;
 const EGL_CONTEXT_MINOR_VERSION: EGLint = 0x000030fb;
@@ -214,6 +545,29 @@ struct Visual {
     map_entries: c_int,
 }
 
+#[derive(Copy, Clone)]
+#[repr(C)]
+struct XSizeHints {
+    flags: c_long,
+    x: c_int,
+    y: c_int,
+    width: c_int,
+    height: c_int,
+    min_width: c_int,
+    min_height: c_int,
+    max_width: c_int,
+    max_height: c_int,
+    width_inc: c_int,
+    height_inc: c_int,
+    min_aspect_x: c_int,
+    min_aspect_y: c_int,
+    max_aspect_x: c_int,
+    max_aspect_y: c_int,
+    base_width: c_int,
+    base_height: c_int,
+    win_gravity: c_int,
+}
+
 type Atom = XID;
 type Time = c_ulong;
 type Drawable = XID;
@@ -913,6 +1267,7 @@ struct XVisibilityEvent {
 
 const X11_INPUT_OUTPUT: c_int = 1;
 const X11_COPY_FROM_PARENT: c_int = 0;
+const X11_CW_BACK_PIXEL: c_ulong = 0x0002;
 const X11_CW_EVENT_MASK: c_ulong = 0x0800;
 const X11_STRUCTURE_NOTIFY_MASK: c_long = 0x0002_0000;
 const X11_EXPOSURE_MASK: c_long = 0x0000_8000;
@@ -922,13 +1277,48 @@ const X11_KEY_RELEASE_MASK: c_long = 0x0000_0002;
 const X11_POINTER_MOTION_MASK: c_long = 0x0000_0040;
 const X11_BUTTON_PRESS_MASK: c_long = 0x0000_0004;
 const X11_BUTTON_RELEASE_MASK: c_long = 0x0000_0008;
+const X11_SUBSTRUCTURE_NOTIFY_MASK: c_long = 0x0008_0000;
+const X11_SUBSTRUCTURE_REDIRECT_MASK: c_long = 0x0010_0000;
 
 const X11_FALSE: X11Bool = 0;
+const X11_TRUE: X11Bool = 1;
 
+const X11_BUTTON_PRESS: c_int = 4;
 const X11_EXPOSE: c_int = 12;
 const X11_RESIZE_REQUEST: c_int = 25;
 const X11_CLIENT_MESSAGE: c_int = 33;
 
+const X11_BUTTON_LEFT: c_uint = 1;
+
+// _NET_WM_MOVERESIZE directions, see the EWMH specification
+const NET_WM_MOVERESIZE_SIZE_TOPLEFT: c_long = 0;
+const NET_WM_MOVERESIZE_SIZE_TOP: c_long = 1;
+const NET_WM_MOVERESIZE_SIZE_TOPRIGHT: c_long = 2;
+const NET_WM_MOVERESIZE_SIZE_RIGHT: c_long = 3;
+const NET_WM_MOVERESIZE_SIZE_BOTTOMRIGHT: c_long = 4;
+const NET_WM_MOVERESIZE_SIZE_BOTTOM: c_long = 5;
+const NET_WM_MOVERESIZE_SIZE_BOTTOMLEFT: c_long = 6;
+const NET_WM_MOVERESIZE_SIZE_LEFT: c_long = 7;
+const NET_WM_MOVERESIZE_MOVE: c_long = 8;
+// source indication: request comes from a regular application
+const NET_WM_SOURCE_APPLICATION: c_long = 1;
+
+const X11_XA_ATOM: c_ulong = 4;
+const X11_XA_CARDINAL: c_ulong = 6;
+
+// XSizeHints.flags
+const X11_P_MIN_SIZE: c_long = 1 << 4;
+const X11_P_MAX_SIZE: c_long = 1 << 5;
+const X11_P_RESIZE_INC: c_long = 1 << 6;
+const X11_P_ASPECT: c_long = 1 << 7;
+const X11_P_BASE_SIZE: c_long = 1 << 8;
+const X11_PROP_MODE_REPLACE: c_int = 0;
+
+// XShape extension (libXext)
+const X11_SHAPE_SET: c_int = 0;
+const X11_SHAPE_INPUT: c_int = 2;
+const X11_UNSORTED: c_int = 0;
+
 type X11Bool = c_int;
 type XID = c_ulong;
 type X11Pixmap = XID;
@@ -966,8 +1356,23 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
         mut windows,
         image_cache,
         fc_cache,
+        event_loop_proxy,
+        // TODO: splash windows are not implemented on X11 yet
+        splash: _,
+        gl_quirk_rules,
     } = app;
 
+    // X11 can't invoke callbacks yet, so user events could never be
+    // delivered: make EventLoopProxy::send_user_event() fail instead
+    event_loop_proxy.reject_user_events();
+
+    // if the pipe can't be created, the event loop only wakes up on X11 events
+    let waker_pipe = WakerPipe::new().map(Arc::new);
+    if let Some(pipe) = waker_pipe.as_ref() {
+        let pipe = pipe.clone();
+        event_loop_proxy.set_waker(Box::new(move || pipe.wake()));
+    }
+
     let xlib = Rc::new(Xlib::new()?);
     let egl = Rc::new(Egl::new()?);
 
@@ -978,6 +1383,7 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
         config,
         image_cache,
         fc_cache,
+        gl_quirk_rules,
     }));
 
     for options in windows.iter_mut() {
@@ -1002,127 +1408,293 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
 
     let mut cur_xevent = XEvent { pad: [0;24] };
 
+    // the system idle time is only polled if the app wants idle events
+    let idle_timeout = app_data_inner.borrow().config.user_idle_timeout.into_option();
+    let mut last_idle_poll: Option<std::time::Instant> = None;
+
     loop {
 
         let mut windows_to_close = Vec::new();
 
         for (window_id, window) in active_windows.iter_mut() {
 
-            // blocks until next event
-            unsafe { (xlib.XNextEvent)(window.dpy.get(), &mut cur_xevent) };
-
-            let cur_event_type = cur_xevent.get_type();
-
-            match cur_event_type {
-                // window shown
-                X11_EXPOSE => {
-                    let expose_data = unsafe { cur_xevent.expose };
-                    let width = expose_data.width;
-                    let height = expose_data.height;
-
-                    window.make_current();
-                    window.render_api.flush_scene_builder();
-
-                    window.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
-                    window.gl_functions.functions.disable(gl_context_loader::gl::FRAMEBUFFER_SRGB);
-                    window.gl_functions.functions.disable(gl_context_loader::gl::MULTISAMPLE);
-
-                    window.gl_functions.functions.viewport(0, 0, width, height);
-                    window.gl_functions.functions.clear_color(0.0, 0.0, 0.0, 1.0);
-                    window.gl_functions.functions.clear(
-                        gl::COLOR_BUFFER_BIT |
-                        gl::DEPTH_BUFFER_BIT |
-                        gl::STENCIL_BUFFER_BIT
-                    );
-
-                    let mut current_program = [0_i32];
-                    unsafe {
-                        window.gl_functions.functions.get_integer_v(
-                            gl_context_loader::gl::CURRENT_PROGRAM,
-                            (&mut current_program[..]).into()
-                        );
-                    }
-
-                    if let Some(r) = window.renderer.as_mut() {
-                        let framebuffer_size = WrDeviceIntSize::new(width, height);
-                        r.update();
-                        let _ = r.render(framebuffer_size, 0);
-                    }
+            // process all queued events without blocking
+            while unsafe { (xlib.XPending)(window.dpy.get()) } > 0 {
+
+                unsafe { (xlib.XNextEvent)(window.dpy.get(), &mut cur_xevent) };
+
+                let cur_event_type = cur_xevent.get_type();
+
+                match cur_event_type {
+                    // window shown
+                    X11_EXPOSE => {
+                        let expose_data = unsafe { cur_xevent.expose };
+                        let width = expose_data.width;
+                        let height = expose_data.height;
+
+                        window.make_current();
+                        window.render_api.flush_scene_builder();
+
+                        window.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+                        window.gl_functions.functions.disable(gl_context_loader::gl::FRAMEBUFFER_SRGB);
+                        window.gl_functions.functions.disable(gl_context_loader::gl::MULTISAMPLE);
+
+                        window.gl_functions.functions.viewport(0, 0, width, height);
+
+                        // the back buffer still contains an older frame: keep
+                        // it, WebRender only redraws what changed since then
+                        let buffer_age = window.query_buffer_age();
+                        if buffer_age == 0 {
+                            window.gl_functions.functions.clear_color(0.0, 0.0, 0.0, 1.0);
+                            window.gl_functions.functions.clear(
+                                gl::COLOR_BUFFER_BIT |
+                                gl::DEPTH_BUFFER_BIT |
+                                gl::STENCIL_BUFFER_BIT
+                            );
+                        }
+
+                        let mut current_program = [0_i32];
+                        unsafe {
+                            window.gl_functions.functions.get_integer_v(
+                                gl_context_loader::gl::CURRENT_PROGRAM,
+                                (&mut current_program[..]).into()
+                            );
+                        }
+
+                        let mut dirty_rects = Vec::new();
+                        if let Some(r) = window.renderer.as_mut() {
+                            let framebuffer_size = WrDeviceIntSize::new(width, height);
+                            r.update();
+                            if let Ok(results) = r.render(framebuffer_size, buffer_age) {
+                                dirty_rects = results.dirty_rects;
+                            }
+                        }
+
+                        window.record_frame(width, height);
+
+                        let swap_result = window.swap_buffers(height, &dirty_rects);
+                        if swap_result != EGL_TRUE {
+                            return Err(Create(EglError(format!("EGL: eglSwapBuffers(): Failed to swap OpenGL buffers: {}", swap_result))));
+                        }
+
+                        // the first frame has been presented: show the window
+                        if window.hidden_until_first_frame {
+                            window.hidden_until_first_frame = false;
+                            window.set_window_opacity();
+                        }
+
+                        window.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+                        window.gl_functions.functions.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+                        window.gl_functions.functions.use_program(current_program[0] as u32);
+                    },
+                    // window resized
+                    X11_RESIZE_REQUEST => {
+
+                        let resize_request_data = unsafe { cur_xevent.resize_request };
+                        let width = resize_request_data.width;
+                        let height = resize_request_data.height;
+
+                        window.make_current();
+                        window.render_api.flush_scene_builder();
+
+                        window.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+                        window.gl_functions.functions.disable(gl_context_loader::gl::FRAMEBUFFER_SRGB);
+                        window.gl_functions.functions.disable(gl_context_loader::gl::MULTISAMPLE);
+
+                        window.gl_functions.functions.viewport(0, 0, width, height);
+
+                        // the back buffer still contains an older frame: keep
+                        // it, WebRender only redraws what changed since then
+                        let buffer_age = window.query_buffer_age();
+                        if buffer_age == 0 {
+                            window.gl_functions.functions.clear_color(0.0, 0.0, 0.0, 1.0);
+                            window.gl_functions.functions.clear(
+                                gl::COLOR_BUFFER_BIT |
+                                gl::DEPTH_BUFFER_BIT |
+                                gl::STENCIL_BUFFER_BIT
+                            );
+                        }
+
+                        let mut current_program = [0_i32];
+                        unsafe {
+                            window.gl_functions.functions.get_integer_v(
+                                gl_context_loader::gl::CURRENT_PROGRAM,
+                                (&mut current_program[..]).into()
+                            );
+                        }
+
+                        let mut dirty_rects = Vec::new();
+                        if let Some(r) = window.renderer.as_mut() {
+                            let framebuffer_size = WrDeviceIntSize::new(width, height);
+                            r.update();
+                            if let Ok(results) = r.render(framebuffer_size, buffer_age) {
+                                dirty_rects = results.dirty_rects;
+                            }
+                        }
+
+                        window.record_frame(width, height);
+
+                        let swap_result = window.swap_buffers(height, &dirty_rects);
+                        if swap_result != EGL_TRUE {
+                            return Err(Create(EglError(format!("EGL: eglSwapBuffers(): Failed to swap OpenGL buffers: {}", swap_result))));
+                        }
+                    },
+                    // mouse button pressed
+                    X11_BUTTON_PRESS => {
+                        let button_data = unsafe { cur_xevent.button };
+                        if button_data.button == X11_BUTTON_LEFT {
+                            window.begin_window_drag(&button_data);
+                        }
+                    },
+                    // window closed
+                    X11_CLIENT_MESSAGE => {
+                        let xclient_data = unsafe { cur_xevent.client_message };
+                        if (xclient_data.data.as_longs().get(0).copied() == Some(window.wm_delete_window_atom)) {
+                            windows_to_close.push(*window_id);
+                        }
+                    },
+                    _ => { },
+                }
+            }
+        }
 
-                    let swap_result = (window.egl.eglSwapBuffers)(window.egl_display, window.egl_surface);
-                    if swap_result != EGL_TRUE {
-                        return Err(Create(EglError(format!("EGL: eglSwapBuffers(): Failed to swap OpenGL buffers: {}", swap_result))));
-                    }
+        for w in windows_to_close {
+            active_windows.remove(&w);
+        }
 
-                    window.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
-                    window.gl_functions.functions.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
-                    window.gl_functions.functions.use_program(current_program[0] as u32);
-                },
-                // window resized
-                X11_RESIZE_REQUEST => {
-
-                    let resize_request_data = unsafe { cur_xevent.resize_request };
-                    let width = resize_request_data.width;
-                    let height = resize_request_data.height;
-
-                    window.make_current();
-                    window.render_api.flush_scene_builder();
-
-                    window.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
-                    window.gl_functions.functions.disable(gl_context_loader::gl::FRAMEBUFFER_SRGB);
-                    window.gl_functions.functions.disable(gl_context_loader::gl::MULTISAMPLE);
-
-                    window.gl_functions.functions.viewport(0, 0, width, height);
-                    window.gl_functions.functions.clear_color(0.0, 0.0, 0.0, 1.0);
-                    window.gl_functions.functions.clear(
-                        gl::COLOR_BUFFER_BIT |
-                        gl::DEPTH_BUFFER_BIT |
-                        gl::STENCIL_BUFFER_BIT
-                    );
-
-                    let mut current_program = [0_i32];
-                    unsafe {
-                        window.gl_functions.functions.get_integer_v(
-                            gl_context_loader::gl::CURRENT_PROGRAM,
-                            (&mut current_program[..]).into()
-                        );
-                    }
+        if active_windows.is_empty() {
+            break;
+        }
 
-                    if let Some(r) = window.renderer.as_mut() {
-                        let framebuffer_size = WrDeviceIntSize::new(width, height);
-                        r.update();
-                        let _ = r.render(framebuffer_size, 0);
-                    }
+        // advance the fade-in animations, wake up again in 16ms while
+        // any window is still fading in
+        let mut any_window_fading_in = false;
+        for window in active_windows.values_mut().filter(|w| w.fade_in.is_some()) {
+            any_window_fading_in |= window.set_window_opacity();
+            unsafe { (xlib.XFlush)(window.dpy.get()) };
+        }
 
-                    let swap_result = (window.egl.eglSwapBuffers)(window.egl_display, window.egl_surface);
-                    if swap_result != EGL_TRUE {
-                        return Err(Create(EglError(format!("EGL: eglSwapBuffers(): Failed to swap OpenGL buffers: {}", swap_result))));
+        // poll the system idle time once per second, the state change emits
+        // On::UserIdle / On::UserActive with the next window state diff
+        if let Some(idle_timeout) = idle_timeout.as_ref() {
+            let poll_due = last_idle_poll
+                .map(|t| t.elapsed() >= std::time::Duration::from_millis(IDLE_POLL_INTERVAL_MS as u64))
+                .unwrap_or(true);
+            if poll_due {
+                last_idle_poll = Some(std::time::Instant::now());
+                let get_system_idle_time = app_data_inner.borrow().config.system_callbacks.get_system_idle_time_fn.cb;
+                if let Some(idle_time) = get_system_idle_time().into_option() {
+                    for window in active_windows.values_mut() {
+                        window.internal.update_user_is_idle(&idle_time, idle_timeout);
                     }
-                },
-                // window closed
-                X11_CLIENT_MESSAGE => {
-                    let xclient_data = unsafe { cur_xevent.client_message };
-                    if (xclient_data.data.as_longs().get(0).copied() == Some(window.wm_delete_window_atom)) {
-                        windows_to_close.push(*window_id);
-                    }
-                },
-                _ => { },
+                }
             }
-
         }
 
-        for w in windows_to_close {
-            active_windows.remove(&w);
+        // WindowCreateOptions::hot_reload: re-run the layout callback
+        // every HOT_RELOAD_INTERVAL_MS, wake up again for the next one
+        let hot_reload_interval = std::time::Duration::from_millis(HOT_RELOAD_INTERVAL_MS as u64);
+        let mut next_hot_reload: Option<std::time::Duration> = None;
+        for window in active_windows.values_mut() {
+            let last_hot_reload = match window.last_hot_reload {
+                Some(s) => s,
+                None => continue,
+            };
+            let remaining = match hot_reload_interval.checked_sub(last_hot_reload.elapsed()) {
+                Some(r) if !r.is_zero() => r,
+                _ => {
+                    window.last_hot_reload = Some(std::time::Instant::now());
+                    window.regenerate_styled_dom(&mut *app_data_inner.borrow_mut());
+                    hot_reload_interval
+                },
+            };
+            next_hot_reload = Some(next_hot_reload.map_or(remaining, |n| n.min(remaining)));
         }
 
-        if active_windows.is_empty() {
-            break;
+        // sleep until one of the X11 connections receives new
+        // events or another thread wakes up the event loop
+        //
+        // NOTE: there is no timer deadline to wait for: X11 can't invoke
+        // callbacks yet, so no timers can be started on X11 windows
+        let mut poll_fds = active_windows
+            .values_mut()
+            .map(|window| libc::pollfd {
+                fd: (xlib.XConnectionNumber)(window.dpy.get()),
+                events: libc::POLLIN,
+                revents: 0,
+            })
+            .chain(waker_pipe.as_ref().map(|pipe| libc::pollfd {
+                fd: pipe.read_fd,
+                events: libc::POLLIN,
+                revents: 0,
+            }))
+            .collect::<Vec<_>>();
+
+        let poll_timeout = match (any_window_fading_in, idle_timeout.is_some()) {
+            (true, _) => 16,
+            (false, true) => IDLE_POLL_INTERVAL_MS,
+            (false, false) => -1,
+        };
+        let poll_timeout = match next_hot_reload.map(|d| d.as_millis() as c_int) {
+            Some(ms) if poll_timeout < 0 => ms,
+            Some(ms) => poll_timeout.min(ms),
+            None => poll_timeout,
+        };
+        unsafe { libc::poll(poll_fds.as_mut_ptr(), poll_fds.len() as libc::nfds_t, poll_timeout) };
+
+        if let Some(pipe) = waker_pipe.as_ref() {
+            pipe.drain();
         }
     }
 
     Ok(0)
 }
 
+// interval of polling the system idle time if `AppConfig::user_idle_timeout` is set
+const IDLE_POLL_INTERVAL_MS: c_int = 1000;
+
+// interval of re-running the layout callback if `WindowCreateOptions::hot_reload` is set
+const HOT_RELOAD_INTERVAL_MS: c_int = 200;
+
+/// Self-pipe to wake up the `poll()` of the event loop from other threads,
+/// closed once the last `EventLoopProxy` is dropped
+#[derive(Debug)]
+struct WakerPipe {
+    read_fd: c_int,
+    write_fd: c_int,
+}
+
+impl WakerPipe {
+
+    fn new() -> Option<Self> {
+        let mut fds = [0 as c_int; 2];
+        let r = unsafe { libc::pipe2(fds.as_mut_ptr(), libc::O_NONBLOCK | libc::O_CLOEXEC) };
+        if r != 0 {
+            return None;
+        }
+        Some(Self { read_fd: fds[0], write_fd: fds[1] })
+    }
+
+    fn wake(&self) {
+        let byte = 1_u8;
+        unsafe { libc::write(self.write_fd, &byte as *const u8 as *const c_void, 1) };
+    }
+
+    fn drain(&self) {
+        let mut buf = [0_u8; 64];
+        while unsafe { libc::read(self.read_fd, buf.as_mut_ptr() as *mut c_void, buf.len()) } > 0 { }
+    }
+}
+
+impl Drop for WakerPipe {
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.read_fd);
+            libc::close(self.write_fd);
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct SharedApplicationData {
     inner: Rc<RefCell<ApplicationData>>,
@@ -1135,6 +1707,8 @@ struct ApplicationData {
     config: AppConfig,
     image_cache: ImageCache,
     fc_cache: LazyFcCache,
+    // driver workarounds added by the app, see App::add_gl_quirk_rule
+    gl_quirk_rules: Vec<GlQuirkRule>,
 }
 
 fn display_egl_status(e: EGLint) -> &'static str {
@@ -1196,8 +1770,27 @@ struct X11Window {
     pub egl_surface: EGLSurface,
     pub egl_display: EGLDisplay,
     pub egl_context: EGLContext,
+    // EGL_EXT_buffer_age: only the dirty rects of a frame are redrawn,
+    // false if not supported or disabled by a driver workaround
+    pub partial_present: bool,
+    // eglSwapBuffersWithDamageKHR / EXT, tells the compositor which area of the window changed
+    pub egl_swap_buffers_with_damage: Option<eglSwapBuffersWithDamageFuncType>,
     // XAtom fired when the window close button is hit
     pub wm_delete_window_atom: c_long,
+    // _NET_WM_MOVERESIZE atom, used to let the window manager move / resize
+    // windows without decorations
+    pub net_wm_moveresize_atom: c_ulong,
+    // WindowCreateOptions::background_effect, X11 only supports the KWin blur
+    pub background_effect: WindowBackgroundEffect,
+    // start and duration (in milliseconds) of the WindowCreateOptions::fade_in
+    // animation, None if the window doesn't fade in (anymore)
+    pub fade_in: Option<(std::time::Instant, u64)>,
+    // FirstFrameMode::HideUntilRendered: the window is fully transparent
+    // until the first frame has been rendered in the Expose handler
+    pub hidden_until_first_frame: bool,
+    // WindowCreateOptions::hot_reload: time when the layout callback was
+    // last re-run, None if the window doesn't hot-reload
+    pub last_hot_reload: Option<std::time::Instant>,
     // X11 library (dynamically loaded)
     pub xlib: Rc<Xlib>,
     // libEGL.so library (dynamically loaded)
@@ -1214,6 +1807,8 @@ struct X11Window {
     pub renderer: Option<WrRenderer>,
     /// Hit-tester, lazily initialized and updated every time the display list changes layout
     pub hit_tester: AsyncHitTester,
+    /// Records the presented frames, see `AppConfig::frame_recording`
+    pub frame_recorder: Option<crate::frame_recording::FrameRecorder>,
 }
 
 struct Xlib {
@@ -1230,6 +1825,13 @@ struct Xlib {
     pub XPending: XPendingFuncType,
     pub XNextEvent: XNextEventFuncType,
     pub XSelectInput: XSelectInputFuncType,
+    pub XSendEvent: XSendEventFuncType,
+    pub XUngrabPointer: XUngrabPointerFuncType,
+    pub XFlush: XFlushFuncType,
+    pub XClearArea: XClearAreaFuncType,
+    pub XChangeProperty: XChangePropertyFuncType,
+    pub XSetWMNormalHints: XSetWMNormalHintsFuncType,
+    pub XConnectionNumber: XConnectionNumberFuncType,
 }
 
 impl Xlib {
@@ -1290,6 +1892,34 @@ impl Xlib {
             .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
             .ok_or(Create(Egl(format!("X11: no function XSelectInput"))))?;
 
+        let XSendEvent: XSendEventFuncType = x11.get("XSendEvent")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XSendEvent"))))?;
+
+        let XUngrabPointer: XUngrabPointerFuncType = x11.get("XUngrabPointer")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XUngrabPointer"))))?;
+
+        let XFlush: XFlushFuncType = x11.get("XFlush")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XFlush"))))?;
+
+        let XClearArea: XClearAreaFuncType = x11.get("XClearArea")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XClearArea"))))?;
+
+        let XChangeProperty: XChangePropertyFuncType = x11.get("XChangeProperty")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XChangeProperty"))))?;
+
+        let XSetWMNormalHints: XSetWMNormalHintsFuncType = x11.get("XSetWMNormalHints")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XSetWMNormalHints"))))?;
+
+        let XConnectionNumber: XConnectionNumberFuncType = x11.get("XConnectionNumber")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XConnectionNumber"))))?;
+
         Ok(Xlib {
             library: x11,
             XDefaultScreen,
@@ -1304,6 +1934,13 @@ impl Xlib {
             XPending,
             XNextEvent,
             XSelectInput,
+            XSendEvent,
+            XUngrabPointer,
+            XFlush,
+            XClearArea,
+            XChangeProperty,
+            XSetWMNormalHints,
+            XConnectionNumber,
         })
     }
 }
@@ -1321,6 +1958,8 @@ struct Egl {
     pub eglCreateContext: eglCreateContextFuncType,
     pub eglGetError: eglGetErrorFuncType,
     pub eglGetProcAddress: eglGetProcAddressFuncType,
+    pub eglQueryString: eglQueryStringFuncType,
+    pub eglQuerySurface: eglQuerySurfaceFuncType,
 }
 
 impl Egl {
@@ -1365,6 +2004,12 @@ impl Egl {
         let eglGetProcAddress: eglGetProcAddressFuncType = egl.get("eglGetProcAddress")
             .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) }) })
             .ok_or(Create(Egl(format!("EGL: no function eglGetProcAddress"))))?;
+        let eglQueryString: eglQueryStringFuncType = egl.get("eglQueryString")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) }) })
+            .ok_or(Create(Egl(format!("EGL: no function eglQueryString"))))?;
+        let eglQuerySurface: eglQuerySurfaceFuncType = egl.get("eglQuerySurface")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) }) })
+            .ok_or(Create(Egl(format!("EGL: no function eglQuerySurface"))))?;
 
         Ok(Self {
             library: egl,
@@ -1379,6 +2024,8 @@ impl Egl {
             eglCreateContext,
             eglGetError,
             eglGetProcAddress,
+            eglQueryString,
+            eglQuerySurface,
         })
     }
 }
@@ -1393,10 +2040,11 @@ impl X11Window {
 
         use self::LinuxStartupError::Create;
         use self::LinuxWindowCreateError::{X, Egl as EglError};
-        use azul_core::window::{RendererType, HwAcceleration};
+        use azul_core::window::{RendererType, HwAcceleration, RawWindowHandle};
         use azul_core::gl::GlContextPtr;
         use webrender::api::ColorF as WrColorF;
         use webrender::ProgramCache as WrProgramCache;
+        use webrender::CompositorConfig as WrCompositorConfig;
         use crate::{
             compositor::Compositor,
             wr_translate::{
@@ -1408,6 +2056,9 @@ impl X11Window {
         };
         use azul_core::callbacks::PipelineId;
 
+        // azul.toml overrides the renderer options and debug flags
+        crate::config_file::get().apply_to_window(options);
+
         let mut dpy = X11Display::open(xlib.clone())
             .ok_or(X(format!("X11: XOpenDisplay(0) failed")))?;
 
@@ -1415,6 +2066,26 @@ impl X11Window {
         let scrnum = unsafe { (xlib.XDefaultScreen)(dpy.get()) };
         let root = unsafe { (xlib.XRootWindow)(dpy.get(), scrnum) };
 
+        // embed the window as a child window inside of a foreign X11 window
+        // (window IDs are global, so the parent may belong to another connection)
+        let parent = match options.parent_handle.as_ref() {
+            Some(RawWindowHandle::Xlib(handle)) if handle.window != 0 => handle.window,
+            Some(RawWindowHandle::Xcb(handle)) if handle.window != 0 => handle.window as c_ulong,
+            _ => root,
+        };
+
+        if parent != root {
+            options.state.flags.has_decorations = false;
+        }
+
+        // TODO: the X11 event loop doesn't dispatch keyboard events yet
+        if !options.shortcuts.shortcuts.is_empty() {
+            #[cfg(feature = "logging")] {
+                warn!("WindowCreateOptions::shortcuts are not supported on X11 yet, ignoring them");
+            }
+            options.shortcuts = ShortcutMap::default();
+        }
+
         let mask = X11_EXPOSURE_MASK |
             X11_KEY_PRESS_MASK |
             X11_KEY_RELEASE_MASK |
@@ -1425,8 +2096,18 @@ impl X11Window {
 
         let mut xattr: XSetWindowAttributes = unsafe { mem::zeroed() };
         xattr.event_mask = mask;
+        let mut xattr_mask = X11_CW_EVENT_MASK;
 
-        let dpi_scale_factor = dpy.get_dpi_scale_factor();
+        // the X server fills the window with the background pixel until the first frame
+        if let FirstFrameMode::SplashColor(c) = options.first_frame {
+            xattr.background_pixel = ((c.r as c_ulong) << 16) | ((c.g as c_ulong) << 8) | (c.b as c_ulong);
+            xattr_mask |= X11_CW_BACK_PIXEL;
+        }
+
+        let dpi_scale_factor = match crate::config_file::get().dpi {
+            Some(dpi) => dpi as f32 / 96.0,
+            None => dpy.get_dpi_scale_factor(),
+        };
         options.state.size.dpi = (dpi_scale_factor.max(0.0) * 96.0).round() as u32;
         options.state.size.hidpi_factor = dpi_scale_factor;
         options.state.size.system_hidpi_factor = dpi_scale_factor;
@@ -1435,7 +2116,7 @@ impl X11Window {
         let physical_size = logical_size.to_physical(dpi_scale_factor);
 
         let window = unsafe { (xlib.XCreateWindow)(
-            dpy.get(), root,
+            dpy.get(), parent,
             0, 0,
             logical_size.width.round().max(0.0) as u32,
             logical_size.height.round().max(0.0) as u32,
@@ -1443,7 +2124,7 @@ impl X11Window {
             X11_COPY_FROM_PARENT,
             X11_INPUT_OUTPUT as u32,
             ptr::null_mut(), // = CopyFromParent
-            X11_CW_EVENT_MASK,
+            xattr_mask,
             &mut xattr,
         ) };
 
@@ -1476,6 +2157,12 @@ impl X11Window {
             1
         ) };
 
+        let net_wm_moveresize_atom = unsafe { (xlib.XInternAtom)(
+            dpy.get(),
+            encode_ascii("_NET_WM_MOVERESIZE").as_ptr() as *const i8,
+            X11_FALSE
+        ) };
+
         let egl_display = (egl.eglGetDisplay)(dpy.display as *mut c_void);
         if egl_display == EGL_NO_DISPLAY {
             return Err(Create(EglError(format!("EGL: eglGetDisplay(): no display"))));
@@ -1560,6 +2247,53 @@ impl X11Window {
         let mut gl_functions = GlFunctions::initialize(egl.clone());
         gl_functions.load();
 
+        // check the driver for known bugs before initializing WebRender
+        let gl_quirks = {
+            let driver = GlDriverInfo::query(&gl_functions.functions);
+            let app_data = shared_application_data.inner.try_borrow();
+            let rules = app_data.as_ref().map(|s| &s.gl_quirk_rules[..]).unwrap_or(&[]);
+            crate::gl_quirks::resolve_gl_quirks(&driver, rules)
+        };
+
+        // TODO: there is no software fallback on X11 yet
+        #[cfg(feature = "logging")] {
+            if gl_quirks.force_software_rendering {
+                warn!("driver requires software rendering, which is not supported on X11 yet");
+            }
+        }
+
+        // partial present: if the driver reports the age of the back buffer,
+        // WebRender only redraws the area that changed since that frame
+        let egl_extensions = {
+            let s = (egl.eglQueryString)(egl_display, EGL_EXTENSIONS);
+            if s.is_null() {
+                String::new()
+            } else {
+                unsafe { CStr::from_ptr(s) }.to_string_lossy().into_owned()
+            }
+        };
+        let has_egl_extension = |name: &str| egl_extensions.split_whitespace().any(|e| e == name);
+
+        let partial_present = has_egl_extension("EGL_EXT_buffer_age") && !gl_quirks.disable_partial_present;
+        let egl_swap_buffers_with_damage = if partial_present {
+            [
+                ("EGL_KHR_swap_buffers_with_damage", "eglSwapBuffersWithDamageKHR"),
+                ("EGL_EXT_swap_buffers_with_damage", "eglSwapBuffersWithDamageEXT"),
+            ]
+            .iter()
+            .filter(|(extension, _)| has_egl_extension(extension))
+            .find_map(|(_, function)| {
+                let ptr = (egl.eglGetProcAddress)(encode_ascii(function).as_ptr() as *const c_char);
+                if ptr.is_null() {
+                    None
+                } else {
+                    Some(unsafe { mem::transmute::<_, eglSwapBuffersWithDamageFuncType>(ptr) })
+                }
+            })
+        } else {
+            None
+        };
+
         // Initialize WebRender
         let mut rt = RendererType::Software;
 
@@ -1582,7 +2316,8 @@ impl X11Window {
         // compiles SVG and FXAA shader programs...
         let gl_context_ptr = Some(GlContextPtr::new(
             rt,
-            gl_functions.functions.clone()
+            gl_functions.functions.clone(),
+            gl_quirks,
         )).into();
 
         // Invoke callback to initialize UI for the first time
@@ -1591,10 +2326,10 @@ impl X11Window {
             Box::new(Notifier {}),
             WrRendererOptions {
                 resource_override_path: None,
-                use_optimized_shaders: true,
+                use_optimized_shaders: !gl_quirks.disable_optimized_shaders,
                 enable_aa: true,
-                enable_subpixel_aa: true,
-                force_subpixel_aa: true,
+                enable_subpixel_aa: !gl_quirks.disable_subpixel_aa,
+                force_subpixel_aa: !gl_quirks.disable_subpixel_aa,
                 clear_color: WrColorF {
                     r: 0.0,
                     g: 0.0,
@@ -1603,9 +2338,23 @@ impl X11Window {
                 }, // transparent
                 panic_on_gl_error: false,
                 precache_flags: WrShaderPrecacheFlags::EMPTY,
-                cached_programs: Some(WrProgramCache::new(None)),
-                enable_multithreading: true,
+                cached_programs: if gl_quirks.disable_program_cache {
+                    None
+                } else {
+                    Some(WrProgramCache::new(None))
+                },
+                enable_multithreading: !gl_quirks.disable_multithreading,
                 debug_flags: wr_translate_debug_flags(&options.state.debug_state),
+                compositor_config: if partial_present {
+                    WrCompositorConfig::Draw {
+                        max_partial_present_rects: MAX_PARTIAL_PRESENT_RECTS,
+                        // the back buffer may be older than one frame
+                        draw_previous_partial_present_regions: true,
+                        partial_present: None,
+                    }
+                } else {
+                    WrCompositorConfig::default()
+                },
                 ..WrRendererOptions::default()
             },
             WR_SHADER_CACHE,
@@ -1646,6 +2395,7 @@ impl X11Window {
                     window_create_options: options.clone(),
                     document_id,
                     id_namespace,
+                    system_style: azul_css::SystemStyle::DEFAULT,
                 },
                 data,
                 image_cache,
@@ -1661,6 +2411,7 @@ impl X11Window {
                         window_state.focused_node,
                         layout_results,
                         &window_state.mouse_state.cursor_position,
+                        &window_state.touch_state,
                         window_state.size.hidpi_factor,
                     )
                 },
@@ -1719,11 +2470,29 @@ impl X11Window {
         // Update the hit-tester to account for the new hit-testing functionality
         let hit_tester = render_api.request_hit_tester(wr_translate_document_id(document_id));
 
+        let frame_recorder = appdata_lock.config.frame_recording.as_ref().map(|f| {
+            use azul_core::window::{RawWindowHandle, XlibHandle};
+            crate::frame_recording::FrameRecorder::new(f, RawWindowHandle::Xlib(XlibHandle {
+                window,
+                display: dpy.display as *mut c_void,
+            }))
+        });
+
         Ok(Self {
             egl_surface,
             egl_display,
             egl_context,
+            partial_present,
+            egl_swap_buffers_with_damage,
             wm_delete_window_atom: wm_delete_window_atom as i64,
+            net_wm_moveresize_atom,
+            background_effect: options.background_effect,
+            fade_in: match options.fade_in.into_option() {
+                Some(azul_core::task::Duration::System(d)) => Some((std::time::Instant::now(), d.millis())),
+                _ => None,
+            },
+            hidden_until_first_frame: options.first_frame == FirstFrameMode::HideUntilRendered,
+            last_hot_reload: if options.hot_reload { Some(std::time::Instant::now()) } else { None },
             id: window,
             dpy,
             xlib,
@@ -1734,9 +2503,65 @@ impl X11Window {
             renderer: Some(renderer),
             gl_functions,
             gl_context_ptr,
+            frame_recorder,
         })
     }
 
+    /// Re-runs the layout callback (`WindowCreateOptions::hot_reload`), sends the
+    /// new display list to WebRender and requests an Expose event to present it
+    fn regenerate_styled_dom(&mut self, app_data: &mut ApplicationData) {
+
+        use crate::wr_translate::wr_translate_document_id;
+
+        self.make_current();
+
+        let ApplicationData { data, image_cache, fc_cache, .. } = app_data;
+        let document_id = self.internal.document_id;
+        let dpi_scale_factor = self.internal.get_dpi_scale_factor();
+        let hit_tester = &mut self.hit_tester;
+        let internal = &mut self.internal;
+        let gl_context_ptr = &self.gl_context_ptr;
+
+        let mut resource_updates = Vec::new();
+        fc_cache.apply_closure(|fc_cache| {
+            internal.regenerate_styled_dom(
+                data,
+                image_cache,
+                gl_context_ptr,
+                &mut resource_updates,
+                dpi_scale_factor,
+                &crate::app::CALLBACKS,
+                fc_cache,
+                azul_layout::do_the_relayout,
+                |window_state, scroll_states, layout_results| {
+                    crate::wr_translate::fullhittest_new_webrender(
+                        &*hit_tester.resolve(),
+                        document_id,
+                        window_state.focused_node,
+                        layout_results,
+                        &window_state.mouse_state.cursor_position,
+                        &window_state.touch_state,
+                        window_state.size.get_hidpi_factor(),
+                    )
+                },
+            );
+        });
+
+        rebuild_display_list(&mut self.internal, &mut self.render_api, image_cache, resource_updates);
+        self.render_api.flush_scene_builder();
+        generate_frame(&mut self.internal, &mut self.render_api, true);
+        self.render_api.flush_scene_builder();
+
+        self.hit_tester = AsyncHitTester::Requested(
+            self.render_api.request_hit_tester(wr_translate_document_id(document_id))
+        );
+
+        // exposures = True: the X server sends an Expose event for the whole
+        // window, the new frame is rendered in the Expose handler
+        (self.xlib.XClearArea)(self.dpy.get(), self.id, 0, 0, 0, 0, X11_TRUE);
+        (self.xlib.XFlush)(self.dpy.get());
+    }
+
     fn make_current(&self) {
         (self.egl.eglMakeCurrent)(
             self.egl_display,
@@ -1746,9 +2571,340 @@ impl X11Window {
         );
     }
 
+    /// Returns the age of the back buffer in frames (`EGL_EXT_buffer_age`),
+    /// 0 if the contents of the back buffer are undefined (i.e. after a resize)
+    fn query_buffer_age(&self) -> usize {
+        if !self.partial_present {
+            return 0;
+        }
+        let mut buffer_age: EGLint = 0;
+        let result = (self.egl.eglQuerySurface)(
+            self.egl_display,
+            self.egl_surface,
+            EGL_BUFFER_AGE_EXT,
+            &mut buffer_age
+        );
+        if result == EGL_TRUE { buffer_age.max(0) as usize } else { 0 }
+    }
+
+    /// Presents the back buffer, passes the dirty rects of the frame
+    /// to the compositor if the driver supports it
+    /// Reads back the rendered frame if the frames are recorded, has to be called
+    /// before the buffers are swapped (see `AppConfig::frame_recording`)
+    fn record_frame(&mut self, width: i32, height: i32) {
+        let gl = &self.gl_functions.functions;
+        if let Some(recorder) = self.frame_recorder.as_mut() {
+            recorder.record_frame(width.max(0) as u32, height.max(0) as u32, || {
+                gl.read_pixels(
+                    0, 0, width, height,
+                    gl_context_loader::gl::RGBA,
+                    gl_context_loader::gl::UNSIGNED_BYTE,
+                )
+            });
+        }
+    }
+
+    fn swap_buffers(&self, framebuffer_height: i32, dirty_rects: &[WrDeviceIntRect]) -> EGLBoolean {
+
+        let swap_buffers_with_damage = match self.egl_swap_buffers_with_damage {
+            Some(s) if !dirty_rects.is_empty() => s,
+            _ => return (self.egl.eglSwapBuffers)(self.egl_display, self.egl_surface),
+        };
+
+        // EGL rects are (x, y, width, height) with the origin in the bottom left corner
+        let damage = dirty_rects
+            .iter()
+            .flat_map(|r| [r.min.x, framebuffer_height - r.max.y, r.width(), r.height()])
+            .collect::<Vec<EGLint>>();
+
+        (swap_buffers_with_damage)(
+            self.egl_display,
+            self.egl_surface,
+            damage.as_ptr(),
+            dirty_rects.len() as EGLint
+        )
+    }
+
     fn show(&mut self) {
+        self.set_window_type_hints();
+        self.set_size_hints();
+        // the fade-in animation starts when the window is mapped
+        if let Some((start, _)) = self.fade_in.as_mut() {
+            *start = std::time::Instant::now();
+        }
+        self.set_window_opacity();
         unsafe { (self.xlib.XMapWindow)(self.dpy.get(), self.id) };
     }
+
+    /// Sets the `_NET_WM_WINDOW_OPACITY` of the window to the `WindowState::opacity`,
+    /// scaled down while the window fades in. Returns whether the window is still fading in.
+    ///
+    /// NOTE: Only has an effect if a compositing manager is running.
+    fn set_window_opacity(&mut self) -> bool {
+
+        // the fade-in starts once the first frame is visible
+        if self.hidden_until_first_frame {
+            if let Some((start, _)) = self.fade_in.as_mut() {
+                *start = std::time::Instant::now();
+            }
+        }
+
+        let opacity = if self.hidden_until_first_frame {
+            0.0
+        } else {
+            self.internal.current_window_state.opacity.max(0.0).min(1.0)
+        };
+        let t = match self.fade_in {
+            Some((start, duration_millis)) if duration_millis > 0 => {
+                (start.elapsed().as_millis() as f32 / duration_millis as f32).min(1.0)
+            },
+            _ => 1.0,
+        };
+
+        if t >= 1.0 {
+            self.fade_in = None;
+        }
+
+        // the opacity is a CARDINAL from 0 (transparent) to 0xFFFFFFFF (opaque)
+        let net_wm_window_opacity: c_ulong = (opacity as f64 * t as f64 * u32::MAX as f64) as c_ulong;
+        let net_wm_window_opacity_atom = self.intern_atom("_NET_WM_WINDOW_OPACITY");
+        unsafe { (self.xlib.XChangeProperty)(
+            self.dpy.get(),
+            self.id,
+            net_wm_window_opacity_atom,
+            X11_XA_CARDINAL,
+            32,
+            X11_PROP_MODE_REPLACE,
+            &net_wm_window_opacity as *const c_ulong as *const c_uchar,
+            1,
+        ) };
+
+        self.fade_in.is_some()
+    }
+
+    fn intern_atom(&mut self, name: &str) -> c_ulong {
+        unsafe { (self.xlib.XInternAtom)(
+            self.dpy.get(),
+            encode_ascii(name).as_ptr() as *const i8,
+            X11_FALSE
+        ) }
+    }
+
+    /// Sets the minimum / maximum size, the resize increments and the aspect
+    /// ratio of the window (`WM_NORMAL_HINTS`), see `WindowSize`
+    fn set_size_hints(&mut self) {
+
+        let hidpi_factor = self.internal.current_window_state.size.get_hidpi_factor();
+        let (min_size, max_size) = self.internal.get_min_max_size();
+
+        let mut size_hints: XSizeHints = unsafe { mem::zeroed() };
+        if let Some(min) = min_size {
+            let min = min.to_physical(hidpi_factor);
+            size_hints.flags |= X11_P_MIN_SIZE;
+            size_hints.min_width = min.width as c_int;
+            size_hints.min_height = min.height as c_int;
+        }
+        if let Some(max) = max_size {
+            let max = max.to_physical(hidpi_factor);
+            size_hints.flags |= X11_P_MAX_SIZE;
+            size_hints.max_width = max.width as c_int;
+            size_hints.max_height = max.height as c_int;
+        }
+
+        let size = self.internal.current_window_state.size;
+        if let Some(increments) = size.resize_increments.into_option() {
+            // the increments start from the minimum size (or zero)
+            let base = min_size.unwrap_or(LogicalSize::zero()).to_physical(hidpi_factor);
+            let increments = increments.to_physical(hidpi_factor);
+            size_hints.flags |= X11_P_RESIZE_INC | X11_P_BASE_SIZE;
+            size_hints.width_inc = increments.width.max(1) as c_int;
+            size_hints.height_inc = increments.height.max(1) as c_int;
+            size_hints.base_width = base.width as c_int;
+            size_hints.base_height = base.height as c_int;
+        }
+        if let Some(aspect_ratio) = size.aspect_ratio_lock.into_option().filter(|r| *r > 0.0) {
+            // the aspect ratio is a fraction of integers: min aspect = max aspect locks the ratio
+            const ASPECT_DENOMINATOR: f32 = 10_000.0;
+            let numerator = libm::roundf(aspect_ratio * ASPECT_DENOMINATOR) as c_int;
+            size_hints.flags |= X11_P_ASPECT;
+            size_hints.min_aspect_x = numerator;
+            size_hints.min_aspect_y = ASPECT_DENOMINATOR as c_int;
+            size_hints.max_aspect_x = numerator;
+            size_hints.max_aspect_y = ASPECT_DENOMINATOR as c_int;
+        }
+
+        unsafe { (self.xlib.XSetWMNormalHints)(self.dpy.get(), self.id, &mut size_hints) };
+    }
+
+    /// Sets the `_NET_WM_STATE` / `_NET_WM_WINDOW_TYPE` hints for the always-on-top
+    /// and tool window flags and removes the input region of click-through windows.
+    ///
+    /// NOTE: The window manager only reads the hints when the window is mapped.
+    fn set_window_type_hints(&mut self) {
+
+        let flags = self.internal.current_window_state.flags;
+
+        let mut net_wm_state = Vec::new();
+        if flags.is_always_on_top {
+            net_wm_state.push(self.intern_atom("_NET_WM_STATE_ABOVE"));
+        }
+        if flags.is_tool_window {
+            net_wm_state.push(self.intern_atom("_NET_WM_STATE_SKIP_TASKBAR"));
+            net_wm_state.push(self.intern_atom("_NET_WM_STATE_SKIP_PAGER"));
+        }
+
+        let net_wm_state_atom = self.intern_atom("_NET_WM_STATE");
+        unsafe { (self.xlib.XChangeProperty)(
+            self.dpy.get(),
+            self.id,
+            net_wm_state_atom,
+            X11_XA_ATOM,
+            32,
+            X11_PROP_MODE_REPLACE,
+            net_wm_state.as_ptr() as *const c_uchar,
+            net_wm_state.len() as c_int,
+        ) };
+
+        let window_type = self.intern_atom(if flags.is_tool_window {
+            "_NET_WM_WINDOW_TYPE_UTILITY"
+        } else {
+            "_NET_WM_WINDOW_TYPE_NORMAL"
+        });
+        let net_wm_window_type_atom = self.intern_atom("_NET_WM_WINDOW_TYPE");
+        unsafe { (self.xlib.XChangeProperty)(
+            self.dpy.get(),
+            self.id,
+            net_wm_window_type_atom,
+            X11_XA_ATOM,
+            32,
+            X11_PROP_MODE_REPLACE,
+            &window_type as *const c_ulong as *const c_uchar,
+            1,
+        ) };
+
+        // KWin blurs the area behind windows with a _KDE_NET_WM_BLUR_BEHIND_REGION,
+        // an empty region blurs the entire window (other window managers ignore it)
+        if self.background_effect != WindowBackgroundEffect::None {
+            let blur_region: [c_ulong; 0] = [];
+            let kde_blur_atom = self.intern_atom("_KDE_NET_WM_BLUR_BEHIND_REGION");
+            unsafe { (self.xlib.XChangeProperty)(
+                self.dpy.get(),
+                self.id,
+                kde_blur_atom,
+                X11_XA_CARDINAL,
+                32,
+                X11_PROP_MODE_REPLACE,
+                blur_region.as_ptr() as *const c_uchar,
+                0,
+            ) };
+        }
+
+        // an empty input shape lets all pointer events pass through the window
+        if flags.is_click_through {
+            let xshape_combine_rectangles = Library::load("libXext.so").ok().and_then(|xext| {
+                let ptr = xext.get("XShapeCombineRectangles")?;
+                if ptr.is_null() { return None; }
+                let func: XShapeCombineRectanglesFuncType = unsafe { mem::transmute(ptr) };
+                Some((xext, func))
+            });
+
+            if let Some((_xext, shape_combine_rectangles)) = xshape_combine_rectangles {
+                shape_combine_rectangles(
+                    self.dpy.get(),
+                    self.id,
+                    X11_SHAPE_INPUT,
+                    0,
+                    0,
+                    ptr::null_mut(),
+                    0,
+                    X11_SHAPE_SET,
+                    X11_UNSORTED,
+                );
+            }
+        }
+    }
+
+    /// If the window has no decorations and the mouse was pressed on a node with a
+    /// `-azul-window-drag-region`, hands the move / resize over to the window manager
+    /// via `_NET_WM_MOVERESIZE`. Returns whether the window manager took over the drag.
+    fn begin_window_drag(&mut self, button: &XButtonEvent) -> bool {
+
+        use azul_core::window::{
+            CursorPosition, LogicalPosition,
+            WindowDragRegionHitTest, TouchState,
+        };
+        use azul_css::StyleWindowDragRegion;
+
+        if self.internal.current_window_state.flags.has_decorations {
+            return false;
+        }
+
+        let hidpi_factor = self.internal.current_window_state.size.get_hidpi_factor();
+        let pos = CursorPosition::InWindow(LogicalPosition::new(
+            button.x as f32 / hidpi_factor,
+            button.y as f32 / hidpi_factor,
+        ));
+
+        let hit_test = crate::wr_translate::fullhittest_new_webrender(
+            &*self.hit_tester.resolve(),
+            self.internal.document_id,
+            self.internal.current_window_state.focused_node,
+            &self.internal.layout_results,
+            &pos,
+            &TouchState::default(),
+            hidpi_factor,
+        );
+
+        let direction = match WindowDragRegionHitTest::new(&hit_test, &self.internal.layout_results).drag_region {
+            StyleWindowDragRegion::None |
+            StyleWindowDragRegion::MaximizeButton => return false,
+            StyleWindowDragRegion::Move => NET_WM_MOVERESIZE_MOVE,
+            StyleWindowDragRegion::ResizeN => NET_WM_MOVERESIZE_SIZE_TOP,
+            StyleWindowDragRegion::ResizeNE => NET_WM_MOVERESIZE_SIZE_TOPRIGHT,
+            StyleWindowDragRegion::ResizeE => NET_WM_MOVERESIZE_SIZE_RIGHT,
+            StyleWindowDragRegion::ResizeSE => NET_WM_MOVERESIZE_SIZE_BOTTOMRIGHT,
+            StyleWindowDragRegion::ResizeS => NET_WM_MOVERESIZE_SIZE_BOTTOM,
+            StyleWindowDragRegion::ResizeSW => NET_WM_MOVERESIZE_SIZE_BOTTOMLEFT,
+            StyleWindowDragRegion::ResizeW => NET_WM_MOVERESIZE_SIZE_LEFT,
+            StyleWindowDragRegion::ResizeNW => NET_WM_MOVERESIZE_SIZE_TOPLEFT,
+        };
+
+        let mut xevent = XEvent { pad: [0; 24] };
+        xevent.client_message = XClientMessageEvent {
+            type_: X11_CLIENT_MESSAGE,
+            serial: 0,
+            send_event: X11_TRUE,
+            display: self.dpy.display,
+            window: self.id,
+            message_type: self.net_wm_moveresize_atom,
+            format: 32,
+            data: ClientMessageData {
+                longs: [
+                    button.x_root as c_long,
+                    button.y_root as c_long,
+                    direction,
+                    button.button as c_long,
+                    NET_WM_SOURCE_APPLICATION,
+                ],
+            },
+        };
+
+        unsafe {
+            // the window manager can only grab the pointer after
+            // the implicit grab of the button press is released
+            (self.xlib.XUngrabPointer)(self.dpy.get(), button.time);
+            (self.xlib.XSendEvent)(
+                self.dpy.get(),
+                button.root,
+                X11_FALSE,
+                X11_SUBSTRUCTURE_REDIRECT_MASK | X11_SUBSTRUCTURE_NOTIFY_MASK,
+                &mut xevent,
+            );
+            (self.xlib.XFlush)(self.dpy.get());
+        }
+
+        true
+    }
 }
 
 struct X11Display {
@@ -0,0 +1,195 @@
+//! Spell checking of text, used by the text input widget
+//!
+//! The provider for a language (BCP 47 tag, i.e. `"en-US"`) is loaded on first use
+//! by `get_spellcheck_provider`. By default this is the system spellchecker
+//! (Windows 8 or later, macOS) or hunspell on Linux (requires the `hunspell` feature,
+//! dictionaries are loaded from `/usr/share/hunspell`). Applications can replace
+//! the provider of a language with `set_spellcheck_provider`.
+//!
+//! Providers are queried from background threads, so checking long texts
+//! does not block the UI.
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use std::sync::Mutex;
+use once_cell::sync::Lazy;
+
+/// Misspelled word in a checked text, indices are counted in `char`s (not in bytes)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Misspelling {
+    /// Index of the first character of the word
+    pub start: usize,
+    /// Index of the character after the word
+    pub end: usize,
+}
+
+/// Spellchecker for a single language
+pub trait SpellcheckProvider: Send + Sync {
+    /// Returns the misspelled words in the text
+    fn check(&self, text: &str) -> Vec<Misspelling>;
+    /// Returns the suggested replacements for a misspelled word, best suggestion first
+    fn suggest(&self, word: &str) -> Vec<String>;
+}
+
+/// Loaded providers, indexed by language (`None` if no spellchecker is available for the language)
+static PROVIDERS: Lazy<Mutex<BTreeMap<String, Option<Arc<dyn SpellcheckProvider>>>>> =
+    Lazy::new(|| Mutex::new(BTreeMap::new()));
+
+/// Returns the spellchecker for the language, loads the system spellchecker on first use.
+/// Returns `None` if no spellchecker is available for the language.
+pub fn get_spellcheck_provider(language: &str) -> Option<Arc<dyn SpellcheckProvider>> {
+    let mut providers = PROVIDERS.lock().ok()?;
+    providers
+        .entry(language.to_string())
+        .or_insert_with(|| load_system_provider(language))
+        .clone()
+}
+
+/// Replaces the spellchecker of the language (i.e. with a spellchecker for a custom dictionary)
+pub fn set_spellcheck_provider(language: &str, provider: Arc<dyn SpellcheckProvider>) {
+    if let Ok(mut providers) = PROVIDERS.lock() {
+        providers.insert(language.to_string(), Some(provider));
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn load_system_provider(language: &str) -> Option<Arc<dyn SpellcheckProvider>> {
+    crate::shell::win32::spellcheck::WindowsSpellChecker::new(language)
+        .map(|s| Arc::new(s) as Arc<dyn SpellcheckProvider>)
+}
+
+#[cfg(target_os = "macos")]
+fn load_system_provider(language: &str) -> Option<Arc<dyn SpellcheckProvider>> {
+    crate::shell::appkit::spellcheck::MacSpellChecker::new(language)
+        .map(|s| Arc::new(s) as Arc<dyn SpellcheckProvider>)
+}
+
+#[cfg(all(not(target_os = "windows"), not(target_os = "macos"), feature = "hunspell"))]
+fn load_system_provider(language: &str) -> Option<Arc<dyn SpellcheckProvider>> {
+    hunspell::HunspellChecker::new(language)
+        .map(|s| Arc::new(s) as Arc<dyn SpellcheckProvider>)
+}
+
+#[cfg(all(not(target_os = "windows"), not(target_os = "macos"), not(feature = "hunspell")))]
+fn load_system_provider(_language: &str) -> Option<Arc<dyn SpellcheckProvider>> {
+    None
+}
+
+/// Converts a range of UTF-16 code units (as returned by the system spellcheckers) into a range of chars
+pub(crate) fn utf16_range_to_misspelling(text: &str, start: usize, len: usize) -> Misspelling {
+    let mut utf16_index = 0;
+    let mut char_start = None;
+    let mut char_index = 0;
+
+    for c in text.chars() {
+        if char_start.is_none() && utf16_index >= start {
+            char_start = Some(char_index);
+        }
+        if utf16_index >= start + len {
+            break;
+        }
+        utf16_index += c.len_utf16();
+        char_index += 1;
+    }
+
+    let start = char_start.unwrap_or(char_index);
+    Misspelling { start, end: char_index.max(start) }
+}
+
+/// Splits the text into words (letters, digits and apostrophes), returns the
+/// char range of each word. Used by providers that only check single words.
+pub(crate) fn split_words(text: &str) -> Vec<(Misspelling, String)> {
+    let mut words = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+
+    for (char_index, c) in text.chars().enumerate() {
+        let is_word_char = c.is_alphanumeric() || c == '\'' || c == '\u{2019}';
+        match (is_word_char, current.as_mut()) {
+            (true, Some((_, word))) => word.push(c),
+            (true, None) => current = Some((char_index, c.to_string())),
+            (false, _) => {
+                if let Some((start, word)) = current.take() {
+                    words.push((Misspelling { start, end: char_index }, word));
+                }
+            },
+        }
+    }
+
+    if let Some((start, word)) = current.take() {
+        let end = start + word.chars().count();
+        words.push((Misspelling { start, end }, word));
+    }
+
+    // "'quoted'" -> "quoted"
+    words.into_iter().filter_map(|(mut range, word)| {
+        let is_quote = |c: char| c == '\'' || c == '\u{2019}';
+        let leading = word.chars().take_while(|c| is_quote(*c)).count();
+        let trimmed = word.trim_start_matches(is_quote).trim_end_matches(is_quote);
+        if trimmed.is_empty() || trimmed.chars().all(|c| c.is_numeric()) {
+            return None;
+        }
+        range.start += leading;
+        range.end = range.start + trimmed.chars().count();
+        Some((range, trimmed.to_string()))
+    }).collect()
+}
+
+#[cfg(all(not(target_os = "windows"), not(target_os = "macos"), feature = "hunspell"))]
+mod hunspell {
+
+    use super::{split_words, Misspelling, SpellcheckProvider};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use std::sync::Mutex;
+    use hunspell_rs::{CheckResult, Hunspell};
+
+    /// Directory of the dictionaries installed by the distribution (`hunspell-en-us`, ...)
+    const DICTIONARY_DIR: &str = "/usr/share/hunspell";
+
+    struct HunspellHandle(Hunspell);
+
+    // the handle is only accessed while the mutex is locked
+    unsafe impl Send for HunspellHandle { }
+
+    pub(super) struct HunspellChecker {
+        handle: Mutex<HunspellHandle>,
+    }
+
+    impl HunspellChecker {
+        /// Loads `/usr/share/hunspell/{language}.aff` and `.dic`,
+        /// hunspell uses underscores in the file names (`en_US.dic`)
+        pub(super) fn new(language: &str) -> Option<Self> {
+            let name = language.replace('-', "_");
+            let aff = format!("{}/{}.aff", DICTIONARY_DIR, name);
+            let dic = format!("{}/{}.dic", DICTIONARY_DIR, name);
+            if !std::path::Path::new(&aff).exists() || !std::path::Path::new(&dic).exists() {
+                return None;
+            }
+            Some(Self { handle: Mutex::new(HunspellHandle(Hunspell::new(&aff, &dic))) })
+        }
+    }
+
+    impl SpellcheckProvider for HunspellChecker {
+        fn check(&self, text: &str) -> Vec<Misspelling> {
+            let handle = match self.handle.lock() {
+                Ok(o) => o,
+                Err(_) => return Vec::new(),
+            };
+            split_words(text)
+            .into_iter()
+            .filter(|(_, word)| handle.0.check(word) == CheckResult::MissingInDictionary)
+            .map(|(range, _)| range)
+            .collect()
+        }
+
+        fn suggest(&self, word: &str) -> Vec<String> {
+            match self.handle.lock() {
+                Ok(handle) => handle.0.suggest(word),
+                Err(_) => Vec::new(),
+            }
+        }
+    }
+}
+
@@ -95,7 +95,7 @@ use azul_core::{
         TransformKey, UpdateImageResult,
     },
     display_list::{
-        CachedDisplayList, GlyphInstance, DisplayListScrollFrame,
+        CachedDisplayList, Crossfade, GlyphInstance, DisplayListScrollFrame,
         DisplayListFrame, LayoutRectContent, DisplayListMsg,
         AlphaType, ImageRendering, StyleBorderRadius, BoxShadow,
     },
@@ -104,13 +104,15 @@ use azul_core::{
     ui_solver::{
         LayoutResult, ExternalScrollId,
         PositionInfo, ComputedTransform3D,
-        QuickResizeResult,
+        QuickResizeResult, HitTest,
     },
     window::{
         LogicalSize, CursorPosition, LogicalPosition,
         FullHitTest, LogicalRect, DebugState,
-        ScrollStates, WindowInternal,
+        ScrollStates, WindowInternal, TouchState,
     },
+    id_tree::NodeId,
+    styled_dom::DomId,
 };
 #[cfg(not(any(target_os = "macos", target_os = "windows")))]
 use azul_core::app_resources::{FontLCDFilter, FontHinting};
@@ -126,10 +128,12 @@ use azul_css::{
     BorderStyle as CssBorderStyle,
     LayoutSideOffsets as CssLayoutSideOffsets,
     StyleMixBlendMode as CssMixBlendMode,
+    StyleTransformStyle as CssTransformStyle,
     U8Vec,
 };
 use webrender::Renderer;
 use alloc::sync::Arc;
+use alloc::collections::BTreeMap;
 use core::mem;
 
 pub enum AsyncHitTester {
@@ -155,6 +159,8 @@ impl AsyncHitTester {
 
 /// Same interface as azul-core: FullHitTest::new
 /// but uses webrender to compare the results of the two hit-testing implementations
+///
+/// The mouse cursor and every touch in the `touch_state` are hit-tested separately
 pub(crate) fn fullhittest_new_webrender(
      wr_hittester: &dyn WrApiHitTester,
      document_id: DocumentId,
@@ -162,27 +168,65 @@ pub(crate) fn fullhittest_new_webrender(
 
      layout_results: &[LayoutResult],
      cursor_position: &CursorPosition,
+     touch_state: &TouchState,
      hidpi_factor: f32,
 ) -> FullHitTest {
 
-    use alloc::collections::BTreeMap;
-    use webrender::api::units::WorldPoint as WrWorldPoint;
-    use azul_core::callbacks::{HitTestItem, ScrollHitTestItem};
-    use azul_core::styled_dom::{DomId, NodeHierarchyItemId};
-
-    let mut cursor_location = match cursor_position {
-        CursorPosition::OutOfWindow(_) | CursorPosition::Uninitialized => return FullHitTest::empty(old_focus_node),
-        CursorPosition::InWindow(pos) => LogicalPosition::new(pos.x, pos.y),
-    };
-
     // If there was no new focus found then the focus is set to none
     // NOTE: The following code should NOT use this field for updating,
     // but rather check if the event was a MouseUp event first
     let mut ret = FullHitTest::empty(None);
 
-    let wr_document_id = wr_translate_document_id(document_id);
+    for touch in touch_state.touches.iter() {
+        let (hit_nodes, _) = hit_test_point(
+            wr_hittester,
+            document_id,
+            layout_results,
+            touch.position,
+            hidpi_factor,
+        );
+        ret.touch_hit_tests.insert(touch.id, hit_nodes);
+    }
+
+    let cursor_location = match cursor_position {
+        CursorPosition::OutOfWindow(_) | CursorPosition::Uninitialized => {
+            ret.focused_node = FullHitTest::empty(old_focus_node).focused_node;
+            return ret;
+        },
+        CursorPosition::InWindow(pos) => LogicalPosition::new(pos.x, pos.y),
+    };
+
+    let (hovered_nodes, focused_node) = hit_test_point(
+        wr_hittester,
+        document_id,
+        layout_results,
+        cursor_location,
+        hidpi_factor,
+    );
+
+    ret.hovered_nodes = hovered_nodes;
+    ret.focused_node = focused_node;
+    ret
+}
 
-    let mut dom_ids = vec![(DomId { inner: 0 }, cursor_location)];
+/// Hit-tests a single point (relative to the window) against the root DOM and all iframes,
+/// returns the hit nodes and the last hit focusable node
+fn hit_test_point(
+     wr_hittester: &dyn WrApiHitTester,
+     document_id: DocumentId,
+     layout_results: &[LayoutResult],
+     point: LogicalPosition,
+     hidpi_factor: f32,
+) -> (BTreeMap<DomId, HitTest>, Option<(DomId, NodeId)>) {
+
+    use webrender::api::units::WorldPoint as WrWorldPoint;
+    use azul_core::callbacks::{HitTestItem, ScrollHitTestItem};
+    use azul_core::styled_dom::NodeHierarchyItemId;
+
+    let mut hit_nodes = BTreeMap::<DomId, HitTest>::new();
+    let mut focused_node = None;
+
+    let mut dom_ids = vec![(DomId { inner: 0 }, point)];
 
     loop {
 
@@ -229,16 +273,25 @@ pub(crate) fn fullhittest_new_webrender(
                 }))
             }).collect::<Vec<_>>();
 
-            for (node_id, item) in hit_items.into_iter() {
+            // WebRender returns the items front-to-back: if the topmost item is
+            // part of an overlay, the content below the overlay is not hit
+            let hit_items = match hit_items.first().and_then(|(node_id, _)| {
+                layout_result.styled_dom.get_overlay_root(*node_id)
+            }) {
+                Some(overlay_root) => hit_items.into_iter().filter(|(node_id, _)| {
+                    layout_result.styled_dom.get_overlay_root(*node_id) == Some(overlay_root)
+                }).collect(),
+                None => hit_items,
+            };
 
-                use azul_core::ui_solver::HitTest;
+            for (node_id, item) in hit_items.into_iter() {
 
                 if let Some(i) = item.is_iframe_hit.as_ref() {
                     new_dom_ids.push(*i);
                 }
 
                 if item.is_focusable {
-                    ret.focused_node = Some((*dom_id, node_id));
+                    focused_node = Some((*dom_id, node_id));
                 }
 
                 let az_node_id = NodeHierarchyItemId::from_crate_internal(Some(node_id));
@@ -248,14 +301,14 @@ pub(crate) fn fullhittest_new_webrender(
                 //
                 // It may ADDITIONALLY inserted into the scroll_hit_test_nodes,
                 // but not as a replacement!
-                ret.hovered_nodes
+                hit_nodes
                 .entry(*dom_id)
                 .or_insert_with(|| HitTest::empty())
                 .regular_hit_test_nodes
                 .insert(node_id, item);
 
                 if let Some(scroll_node) = layout_result.scrollable_nodes.overflowing_nodes.get(&az_node_id) {
-                    ret.hovered_nodes
+                    hit_nodes
                     .entry(*dom_id)
                     .or_insert_with(|| HitTest::empty())
                     .scroll_hit_test_nodes
@@ -275,7 +328,7 @@ pub(crate) fn fullhittest_new_webrender(
         }
     }
 
-    ret
+    (hit_nodes, focused_node)
 }
 
 /// Scroll all nodes in the ScrollStates to their correct position and insert
@@ -296,7 +349,12 @@ pub(crate) fn scroll_all_nodes(scroll_states: &ScrollStates, txn: &mut WrTransac
 }
 
 /// Synchronize transform / opacity keys
-pub(crate) fn synchronize_gpu_values(layout_results: &[LayoutResult], dpi: &DpiScaleFactor, txn: &mut WrTransaction) {
+pub(crate) fn synchronize_gpu_values(
+    layout_results: &[LayoutResult],
+    crossfades: &[Crossfade],
+    dpi: &DpiScaleFactor,
+    txn: &mut WrTransaction,
+) {
 
     use webrender::api::{
         PropertyBindingKey as WrPropertyBindingKey,
@@ -324,6 +382,7 @@ pub(crate) fn synchronize_gpu_values(layout_results: &[LayoutResult], dpi: &DpiS
             Some((key, *value))
         }).collect::<Vec<_>>().into_iter()
     })
+    .chain(crossfades.iter().map(|c| (&c.opacity_key, c.opacity)))
     .map(|(k, v)| WrPropertyValue {
         key: WrPropertyBindingKey::new(k.id as u64),
         value: v,
@@ -388,6 +447,16 @@ pub(crate) fn rebuild_display_list(
         &internal.gl_texture_cache,
         &internal.renderer_resources,
         image_cache,
+        &internal.scroll_states,
+        &internal.scrollbar_interaction,
+    );
+
+    // offscreen items were culled relative to the current scroll positions
+    internal.display_list_scroll_positions = internal.scroll_states.get_scroll_positions();
+
+    // snapshots of cross-faded subtrees are drawn on top of the new content
+    cached_display_list.root.append_children(
+        internal.crossfades.iter().map(|c| c.get_display_list_msg()).collect()
     );
 
     // Scale everything in the display list to the DPI of the window
@@ -451,8 +520,9 @@ pub(crate) fn generate_frame(
     txn.set_document_view(WrDeviceIntRect::from_origin_and_size(WrDeviceIntPoint::new(0, 0), framebuffer_size));
     scroll_all_nodes(&mut internal.scroll_states, &mut txn);
     synchronize_gpu_values(
-        &internal.layout_results, 
-        &internal.get_dpi_scale_factor(), 
+        &internal.layout_results,
+        &internal.crossfades,
+        &internal.get_dpi_scale_factor(),
         &mut txn
     );
 
@@ -465,6 +535,26 @@ pub(crate) fn generate_frame(
     render_api.send_transaction(wr_translate_document_id(internal.document_id), txn);
 }
 
+/// Renders the current scroll positions, usually by only re-compositing the last
+/// display list. If a node was scrolled further than the `DISPLAY_LIST_CULL_MARGIN`
+/// since the display list was built, items that were culled from the display list
+/// may be visible now, so the display list is rebuilt first.
+///
+/// Returns whether the display list was rebuilt (i.e. the hit-tester is outdated)
+#[cfg(not(test))]
+pub(crate) fn generate_scroll_frame(
+    internal: &mut WindowInternal,
+    render_api: &mut WrRenderApi,
+    image_cache: &ImageCache,
+) -> bool {
+    let display_list_outdated = internal.scroll_exceeds_cull_margin();
+    if display_list_outdated {
+        rebuild_display_list(internal, render_api, image_cache, Vec::new());
+    }
+    generate_frame(internal, render_api, display_list_outdated);
+    display_list_outdated
+}
+
 
 #[inline]
 fn wr_translate_image_mask(input: &DisplayListImageMask) -> WrImageMask {
@@ -1103,6 +1193,14 @@ pub(crate) const fn wr_translate_layout_transform(t: &ComputedTransform3D) -> Wr
 }
 
 
+#[inline(always)]
+const fn wr_translate_transform_style(transform_style: CssTransformStyle) -> WrTransformStyle {
+    match transform_style {
+        CssTransformStyle::Flat => WrTransformStyle::Flat,
+        CssTransformStyle::Preserve3D => WrTransformStyle::Preserve3D,
+    }
+}
+
 #[inline(always)]
 pub(crate) fn wr_translate_external_scroll_id(scroll_id: ExternalScrollId) -> WrExternalScrollId {
     WrExternalScrollId(scroll_id.0, wr_translate_pipeline_id(scroll_id.1))
@@ -1171,7 +1269,10 @@ fn push_display_list_msg(
     let opacity = msg.get_opacity_key();
     let mix_blend_mode = msg.get_mix_blend_mode();
     let has_mix_blend_mode_children = msg.has_mix_blend_mode_children();
-    let should_push_stacking_context = transform.is_some() || opacity.is_some() || mix_blend_mode.is_some() || has_mix_blend_mode_children;
+    // preserve-3d establishes a 3D rendering context: webrender depth-sorts
+    // and plane-splits the (transformed) children of the stacking context
+    let transform_style = wr_translate_transform_style(msg.get_transform_style());
+    let should_push_stacking_context = transform.is_some() || opacity.is_some() || mix_blend_mode.is_some() || has_mix_blend_mode_children || transform_style == WrTransformStyle::Preserve3D;
 
     let property_binding = match transform {
         Some(s) => WrPropertyBinding::Binding(
@@ -1183,7 +1284,7 @@ fn push_display_list_msg(
     let rect_spatial_id = builder.push_reference_frame(
         WrLayoutPoint::new(relative_x, relative_y),
         parent_spatial_id,
-        WrTransformStyle::Flat,
+        transform_style,
         property_binding,
         WrReferenceFrameKind::Transform {
             is_2d_scale_translation: false,
@@ -1218,7 +1319,7 @@ fn push_display_list_msg(
             rect_spatial_id,
             WrPrimitiveFlags::IS_BACKFACE_VISIBLE,
             None,
-            WrTransformStyle::Flat,
+            transform_style,
             wr_translate_mix_blend_mode(mix_blend_mode.copied().unwrap_or_default()),
             &opacity_filters,
             &[],
@@ -1323,11 +1424,16 @@ fn push_frame(
         Some(parent_clip_id),
     );
 
-    let wr_border_radius = wr_translate_border_radius(frame.border_radius, frame.size);
-
     // If the rect has an overflow:* property set, clip the children accordingly
     let children_clip_id = match frame.clip_children {
-        Some(size) => content_clip_id,
+        Some(_) => define_overflow_clip(
+            builder,
+            frame.size,
+            frame.border_radius,
+            &frame.content,
+            rect_spatial_id,
+            parent_clip_id,
+        ),
         None => parent_clip_id, // no clipping
     };
 
@@ -1343,6 +1449,19 @@ fn push_frame(
         }, (hit_tag.0, 0));
     }
 
+    // perspective: the children are positioned relative to a perspective
+    // reference frame (the frame itself is not affected by its own perspective)
+    let children_spatial_id = match frame.perspective.as_ref() {
+        Some(perspective) => builder.push_reference_frame(
+            WrLayoutPoint::zero(),
+            rect_spatial_id,
+            wr_translate_transform_style(frame.transform_style),
+            WrPropertyBinding::Value(wr_translate_layout_transform(perspective)),
+            WrReferenceFrameKind::Perspective { scrolling_relative_to: None },
+        ),
+        None => rect_spatial_id,
+    };
+
     // if let Some(image_mask) -> define_image_mask_clip()
     for child in frame.children {
         push_display_list_msg(
@@ -1350,12 +1469,16 @@ fn push_frame(
             render_api,
             builder,
             child,
-            rect_spatial_id,
+            children_spatial_id,
             children_clip_id,
             positioned_items,
             current_hidpi_factor,
         );
     }
+
+    if frame.perspective.is_some() {
+        builder.pop_reference_frame();
+    }
 }
 
 #[inline]
@@ -1393,10 +1516,20 @@ fn push_scroll_frame(
 
     // Push hit-testing + scrolling children
 
-    // scroll frame has the hit-testing clip as a parent
+    // the scrolled content is clipped to the (rounded) padding box of the frame
+    let overflow_clip_id = define_overflow_clip(
+        builder,
+        scroll_frame.frame.size,
+        scroll_frame.frame.border_radius,
+        &scroll_frame.frame.content,
+        rect_spatial_id,
+        parent_clip_id,
+    );
+
+    // scroll frame has the overflow clip as a parent
     let scroll_frame_clip_info = builder.define_scroll_frame(
         /* parent_space_and_clip */ &WrSpaceAndClipInfo {
-            clip_id: content_clip_id,
+            clip_id: overflow_clip_id,
             spatial_id: rect_spatial_id,
         },
         /* external_id */ wr_translate_external_scroll_id(scroll_frame.scroll_id),
@@ -1449,6 +1582,31 @@ fn push_scroll_frame(
             current_hidpi_factor,
         );
     }
+
+    // scrollbars are pushed in the space of the frame (not the scroll frame),
+    // so that they stay in place while the content scrolls
+    for scrollbar in scroll_frame.scrollbars.iter() {
+        let parts = [
+            (scrollbar.track, scrollbar.track_color),
+            (scrollbar.thumb, scrollbar.thumb_color),
+        ];
+        for (rect, color) in parts.iter() {
+            if color.a == 0 {
+                continue; // overlay scrollbars have a transparent track
+            }
+            let wr_rect = wr_translate_logical_rect(*rect);
+            builder.push_rect(
+                &WrCommonItemProperties {
+                    clip_rect: wr_rect,
+                    spatial_id: rect_spatial_id,
+                    clip_id: content_clip_id,
+                    flags: WrPrimitiveFlags::empty(),
+                },
+                wr_rect,
+                wr_translate_color_u(*color).into(),
+            );
+        }
+    }
 }
 
 #[inline]
@@ -1484,6 +1642,73 @@ fn define_border_radius_clip(
     clip
 }
 
+/// Defines the clip for the children of a frame with `overflow: hidden / scroll`:
+/// children are clipped to the padding box, so the border radius of the frame
+/// is shrunk by the border widths (rounded clips are anti-aliased by webrender)
+fn define_overflow_clip(
+    builder: &mut WrDisplayListBuilder,
+    rect_size: LogicalSize,
+    border_radius: StyleBorderRadius,
+    content: &[LayoutRectContent],
+    rect_spatial_id: WrSpatialId,
+    parent_clip_id: WrClipId,
+) -> WrClipId {
+
+    use webrender::api::{
+        ClipMode as WrClipMode,
+        ComplexClipRegion as WrComplexClipRegion,
+    };
+
+    let (top, right, bottom, left) = content.iter().find_map(|c| match c {
+        LayoutRectContent::Border { widths, .. } => Some((
+            widths.top_width(),
+            widths.right_width(),
+            widths.bottom_width(),
+            widths.left_width(),
+        )),
+        _ => None,
+    }).unwrap_or((0.0, 0.0, 0.0, 0.0));
+
+    let padding_rect = WrLayoutRect::from_origin_and_size(
+        WrLayoutPoint::new(left, top),
+        WrLayoutSize::new(
+            (rect_size.width - left - right).max(0.0),
+            (rect_size.height - top - bottom).max(0.0),
+        ),
+    );
+
+    let outer_radius = wr_translate_border_radius(border_radius, rect_size);
+    let inner_radius = WrBorderRadius {
+        top_left: WrLayoutSize::new(
+            (outer_radius.top_left.width - left).max(0.0),
+            (outer_radius.top_left.height - top).max(0.0),
+        ),
+        top_right: WrLayoutSize::new(
+            (outer_radius.top_right.width - right).max(0.0),
+            (outer_radius.top_right.height - top).max(0.0),
+        ),
+        bottom_left: WrLayoutSize::new(
+            (outer_radius.bottom_left.width - left).max(0.0),
+            (outer_radius.bottom_left.height - bottom).max(0.0),
+        ),
+        bottom_right: WrLayoutSize::new(
+            (outer_radius.bottom_right.width - right).max(0.0),
+            (outer_radius.bottom_right.height - bottom).max(0.0),
+        ),
+    };
+
+    let space_and_clip = WrSpaceAndClipInfo { spatial_id: rect_spatial_id, clip_id: parent_clip_id };
+
+    if inner_radius.is_zero() {
+        builder.define_clip_rect(&space_and_clip, padding_rect)
+    } else {
+        builder.define_clip_rounded_rect(
+            &space_and_clip,
+            WrComplexClipRegion::new(padding_rect, inner_radius, WrClipMode::Clip),
+        )
+    }
+}
+
 // returns the clip of the content (i.e. the current rect)
 #[inline]
 fn push_display_list_content(
@@ -1590,6 +1815,13 @@ fn push_display_list_content(
                 // no clip necessary because item will always be in parent bounds
                 border::push_border(builder, &normal_info, border_radius, *widths, *colors, *styles, current_hidpi_factor);
             },
+            Underline { bounds, color, style } => {
+                let mut underline_info = normal_info.clone();
+                underline_info.clip_id = content_clip.get_or_insert_with(|| {
+                    define_border_radius_clip(builder, clip_rect, wr_border_radius, normal_info.spatial_id, parent_clip_id)
+                }).clone();
+                underline::push_underline(builder, &underline_info, *bounds, *color, *style);
+            },
         }
     }
 
@@ -1645,6 +1877,55 @@ mod text {
     }
 }
 
+mod underline {
+
+    use webrender::api::{
+        DisplayListBuilder as WrDisplayListBuilder,
+        CommonItemProperties as WrCommonItemProperties,
+        LineOrientation as WrLineOrientation,
+        LineStyle as WrLineStyle,
+    };
+    use azul_core::{
+        callbacks::TextUnderlineStyle,
+        window::{LogicalRect, LogicalSize},
+    };
+    use azul_css::ColorU;
+
+    pub(in super) fn push_underline(
+        builder: &mut WrDisplayListBuilder,
+        info: &WrCommonItemProperties,
+        bounds: LogicalRect,
+        color: ColorU,
+        style: TextUnderlineStyle,
+    ) {
+        use super::{wr_translate_color_u, wr_translate_logical_rect};
+
+        // the line is in the top third of the bounds, only wavy lines need the full height
+        let line_thickness = bounds.size.height / 3.0;
+
+        let (area, wr_style) = match style {
+            TextUnderlineStyle::Solid => (
+                LogicalRect::new(bounds.origin, LogicalSize::new(bounds.size.width, line_thickness)),
+                WrLineStyle::Solid,
+            ),
+            TextUnderlineStyle::Dotted => (
+                LogicalRect::new(bounds.origin, LogicalSize::new(bounds.size.width, line_thickness)),
+                WrLineStyle::Dotted,
+            ),
+            TextUnderlineStyle::Wavy => (bounds, WrLineStyle::Wavy),
+        };
+
+        builder.push_line(
+            &info,
+            &wr_translate_logical_rect(area),
+            line_thickness,
+            WrLineOrientation::Horizontal,
+            &wr_translate_color_u(color).into(),
+            wr_style,
+        );
+    }
+}
+
 mod background {
 
     use webrender::api::{
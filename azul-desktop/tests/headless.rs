@@ -0,0 +1,36 @@
+//! `App::render_to_image()`: size checks and the platforms that
+//! have no headless backend yet (see the `headless` module docs)
+
+extern crate azul_core;
+extern crate azul_desktop;
+
+use azul_core::app_resources::{AppConfig, LayoutSolverVersion};
+use azul_core::callbacks::{LayoutCallbackInfo, RefAny};
+use azul_core::styled_dom::StyledDom;
+use azul_core::window::{LogicalSize, WindowCreateOptions};
+use azul_desktop::app::App;
+use azul_desktop::headless::HeadlessError;
+
+extern "C" fn layout(_: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
+    StyledDom::default()
+}
+
+fn window(width: f32, height: f32) -> WindowCreateOptions {
+    let mut options = WindowCreateOptions::new(layout);
+    options.state.size.dimensions = LogicalSize::new(width, height);
+    options
+}
+
+#[test]
+fn render_to_image_rejects_empty_window() {
+    let mut app = App::new(RefAny::new(()), AppConfig::new(LayoutSolverVersion::Default));
+    assert_eq!(app.render_to_image(window(0.0, 100.0)).unwrap_err(), HeadlessError::InvalidSize);
+    assert_eq!(app.render_to_image(window(100.0, 0.0)).unwrap_err(), HeadlessError::InvalidSize);
+}
+
+#[test]
+#[cfg(not(target_os = "linux"))]
+fn render_to_image_is_unsupported() {
+    let mut app = App::new(RefAny::new(()), AppConfig::new(LayoutSolverVersion::Default));
+    assert_eq!(app.render_to_image(window(100.0, 100.0)).unwrap_err(), HeadlessError::Unsupported);
+}
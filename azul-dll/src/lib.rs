@@ -27,8 +27,14 @@ pub use AzAppTT as AzApp;
 #[no_mangle] pub extern "C" fn AzApp_addWindow(app: &mut AzApp, window: AzWindowCreateOptions) { app.add_window(window) }
 /// Adds a new image identified by an ID to the image cache
 #[no_mangle] pub extern "C" fn AzApp_addImage(app: &mut AzApp, id: AzString, image: AzImageRef) { app.add_image(id, image) }
+/// Shows a splash screen (logo + progress bar) while the font cache is built and the first layout runs, the splash cross-fades into the root window once it has rendered its first frame. Currently only implemented on Windows.
+#[no_mangle] pub extern "C" fn AzApp_withSplash(app: AzApp, splash: AzSplashOptions) -> AzApp { app.with_splash(splash) }
+/// Adds a rule that enables or disables driver workarounds (i.e. forcing software rendering on a specific GPU). The rules are evaluated in order after the built-in rules when a window is created.
+#[no_mangle] pub extern "C" fn AzApp_addGlQuirkRule(app: &mut AzApp, rule: AzGlQuirkRule) { app.add_gl_quirk_rule(rule) }
 /// Returns a list of monitors - useful for setting the monitor that a window should spawn on.
 #[no_mangle] pub extern "C" fn AzApp_getMonitors(app: &AzApp) -> AzMonitorVec { app.get_monitors() }
+/// Returns a handle that other threads can use to wake up the event loop or to send data to the windows (`On::UserEvent`)
+#[no_mangle] pub extern "C" fn AzApp_createEventLoopProxy(app: &AzApp) -> AzEventLoopProxy { app.create_event_loop_proxy() }
 /// Runs the application. Due to platform restrictions (specifically `WinMain` on Windows), this function never returns.
 #[no_mangle] pub extern "C" fn AzApp_run(app: &AzApp, window: AzWindowCreateOptions) { app.run(window) }
 /// Destructor: Takes ownership of the `App` pointer and deletes it.
@@ -41,11 +47,73 @@ pub use azul_impl::resources::AppConfig as AzAppConfigTT;
 pub use AzAppConfigTT as AzAppConfig;
 /// Constructs a default `AppConfig`, uses the layout solver currently available
 #[no_mangle] pub extern "C" fn AzAppConfig_new(layout_solver: AzLayoutSolver) -> AzAppConfig { AzAppConfig::new(layout_solver) }
+/// Destructor: Takes ownership of the `AppConfig` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzAppConfig_delete(object: &mut AzAppConfig) {  unsafe { core::ptr::drop_in_place(object); } }
 
 /// Configuration to set which messages should be logged.
 pub use azul_impl::resources::AppLogLevel as AzAppLogLevelTT;
 pub use AzAppLogLevelTT as AzAppLogLevel;
 
+/// Configuration of the jank detector, see `AppConfig::jank_capture`
+pub use azul_impl::resources::JankCapture as AzJankCaptureTT;
+pub use AzJankCaptureTT as AzJankCapture;
+/// Creates a new `JankCapture` that writes the reports (without display list) to the temporary directory
+#[no_mangle] pub extern "C" fn AzJankCapture_new(threshold: AzDuration) -> AzJankCapture { AzJankCapture::new(threshold) }
+/// Destructor: Takes ownership of the `JankCapture` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzJankCapture_delete(object: &mut AzJankCapture) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Startup splash screen (logo + progress bar), see `App::with_splash`
+pub use azul_impl::resources::SplashOptions as AzSplashOptionsTT;
+pub use AzSplashOptionsTT as AzSplashOptions;
+/// Creates a white 480x300 splash window without a logo
+#[no_mangle] pub extern "C" fn AzSplashOptions_new() -> AzSplashOptions { AzSplashOptions::new() }
+/// Sets the logo of the splash window
+#[no_mangle] pub extern "C" fn AzSplashOptions_withLogo(splashoptions: AzSplashOptions, logo: AzImageRef) -> AzSplashOptions { splashoptions.with_logo(logo) }
+/// Destructor: Takes ownership of the `SplashOptions` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplashOptions_delete(object: &mut AzSplashOptions) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Whether input events are recorded or replayed, see `AppConfig::event_recording`
+pub use azul_impl::resources::EventRecording as AzEventRecordingTT;
+pub use AzEventRecordingTT as AzEventRecording;
+/// Destructor: Takes ownership of the `EventRecording` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzEventRecording_delete(object: &mut AzEventRecording) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Recording of the presented frames of all windows, see `AppConfig::frame_recording`
+pub use azul_impl::resources::FrameRecording as AzFrameRecordingTT;
+pub use AzFrameRecordingTT as AzFrameRecording;
+/// Creates a new `FrameRecording` that records at most 30 frames per second
+#[no_mangle] pub extern "C" fn AzFrameRecording_new(output: AzFrameRecordingOutput) -> AzFrameRecording { AzFrameRecording::new(output) }
+/// Destructor: Takes ownership of the `FrameRecording` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzFrameRecording_delete(object: &mut AzFrameRecording) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Where the frames of a `FrameRecording` are written to
+pub use azul_impl::resources::FrameRecordingOutput as AzFrameRecordingOutputTT;
+pub use AzFrameRecordingOutputTT as AzFrameRecordingOutput;
+/// Destructor: Takes ownership of the `FrameRecordingOutput` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzFrameRecordingOutput_delete(object: &mut AzFrameRecordingOutput) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// A frame that was presented by a window, passed to the `FrameRecordingCallback`
+pub use azul_impl::resources::RecordedFrame as AzRecordedFrameTT;
+pub use AzRecordedFrameTT as AzRecordedFrame;
+/// Destructor: Takes ownership of the `RecordedFrame` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzRecordedFrame_delete(object: &mut AzRecordedFrame) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzFrameRecordingCallbackType = extern "C" fn(&AzRecordedFrame);
+/// Function that is invoked with every recorded frame, once the next frame has been presented (or the window is closed)
+pub use azul_impl::resources::FrameRecordingCallback as AzFrameRecordingCallbackTT;
+pub use AzFrameRecordingCallbackTT as AzFrameRecordingCallback;
+
+/// Information about a panic or crash, passed to the `CrashHandler`
+pub use azul_impl::resources::CrashInfo as AzCrashInfoTT;
+pub use AzCrashInfoTT as AzCrashInfo;
+/// Destructor: Takes ownership of the `CrashInfo` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCrashInfo_delete(object: &mut AzCrashInfo) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzCrashHandlerType = extern "C" fn(&AzCrashInfo);
+/// Function that is invoked when the app panics or crashes
+pub use azul_impl::resources::CrashHandler as AzCrashHandlerTT;
+pub use AzCrashHandlerTT as AzCrashHandler;
+
 /// Version of the layout solver to use - future binary versions of azul may have more fields here, necessary so that old compiled applications don't break with newer releases of azul. Newer layout versions are opt-in only.
 pub use azul_impl::resources::LayoutSolverVersion as AzLayoutSolverTT;
 pub use AzLayoutSolverTT as AzLayoutSolver;
@@ -61,9 +129,39 @@ pub use azul_core::window::WindowCreateOptions as AzWindowCreateOptionsTT;
 pub use AzWindowCreateOptionsTT as AzWindowCreateOptions;
 /// Creates a new window configuration with a custom layout callback
 #[no_mangle] pub extern "C" fn AzWindowCreateOptions_new(layout_callback: AzLayoutCallbackType) -> AzWindowCreateOptions { AzWindowCreateOptions::new(layout_callback) }
+/// Embeds the window as a child window inside of a foreign window (Win32 HWND, X11 window or NSView)
+#[no_mangle] pub extern "C" fn AzWindowCreateOptions_withParentHandle(windowcreateoptions: AzWindowCreateOptions, parent_handle: AzRawWindowHandle) -> AzWindowCreateOptions { windowcreateoptions.with_parent_handle(parent_handle) }
+/// Attaches window-local data to the window, see `WindowCreateOptions::window_data`
+#[no_mangle] pub extern "C" fn AzWindowCreateOptions_withWindowData(windowcreateoptions: AzWindowCreateOptions, window_data: AzRefAny) -> AzWindowCreateOptions { windowcreateoptions.with_window_data(window_data) }
+/// Sets the translucent backdrop of the window, see `WindowCreateOptions::background_effect`
+#[no_mangle] pub extern "C" fn AzWindowCreateOptions_withBackgroundEffect(windowcreateoptions: AzWindowCreateOptions, background_effect: AzWindowBackgroundEffect) -> AzWindowCreateOptions { windowcreateoptions.with_background_effect(background_effect) }
+/// Fades the window in when it is shown, see `WindowCreateOptions::fade_in`
+#[no_mangle] pub extern "C" fn AzWindowCreateOptions_withFadeIn(windowcreateoptions: AzWindowCreateOptions, duration: AzDuration) -> AzWindowCreateOptions { windowcreateoptions.with_fade_in(duration) }
+/// Sets what the window shows before the first frame, see `WindowCreateOptions::first_frame`
+#[no_mangle] pub extern "C" fn AzWindowCreateOptions_withFirstFrame(windowcreateoptions: AzWindowCreateOptions, first_frame: AzFirstFrameMode) -> AzWindowCreateOptions { windowcreateoptions.with_first_frame(first_frame) }
+/// Configures the built-in scrollbars, see `WindowCreateOptions::scrollbars`
+#[no_mangle] pub extern "C" fn AzWindowCreateOptions_withScrollbars(windowcreateoptions: AzWindowCreateOptions, scrollbars: AzScrollbarConfig) -> AzWindowCreateOptions { windowcreateoptions.with_scrollbars(scrollbars) }
 /// Destructor: Takes ownership of the `WindowCreateOptions` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzWindowCreateOptions_delete(object: &mut AzWindowCreateOptions) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Translucent backdrop of a window, see `WindowCreateOptions::background_effect`
+pub use azul_core::window::WindowBackgroundEffect as AzWindowBackgroundEffectTT;
+pub use AzWindowBackgroundEffectTT as AzWindowBackgroundEffect;
+
+/// What a window shows between being mapped and presenting the first rendered frame, see `WindowCreateOptions::first_frame`
+pub use azul_core::window::FirstFrameMode as AzFirstFrameModeTT;
+pub use AzFirstFrameModeTT as AzFirstFrameMode;
+
+/// Behavior of the built-in scrollbars of a window, see `WindowCreateOptions::scrollbars`
+pub use azul_core::window::ScrollbarConfig as AzScrollbarConfigTT;
+pub use AzScrollbarConfigTT as AzScrollbarConfig;
+/// Creates the default scrollbar configuration (always visible, 20px minimum thumb length)
+#[no_mangle] pub extern "C" fn AzScrollbarConfig_default() -> AzScrollbarConfig { AzScrollbarConfig::default() }
+
+/// How the built-in scrollbars are drawn, see `ScrollbarConfig::mode`
+pub use azul_core::window::ScrollbarMode as AzScrollbarModeTT;
+pub use AzScrollbarModeTT as AzScrollbarMode;
+
 /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
 pub use azul_core::window::RendererOptions as AzRendererOptionsTT;
 pub use AzRendererOptionsTT as AzRendererOptions;
@@ -199,17 +297,31 @@ pub use AzAcceleratorKeyTT as AzAcceleratorKey;
 /// Minimum / maximum / current size of the window in logical dimensions
 pub use azul_core::window::WindowSize as AzWindowSizeTT;
 pub use AzWindowSizeTT as AzWindowSize;
-/// Returns the hidpi factor of the bounds
+/// Returns the hidpi factor of the bounds (DPI scale factor multiplied with the zoom factor)
 #[no_mangle] pub extern "C" fn AzWindowSize_getHidpiFactor(windowsize: &AzWindowSize) -> f32 { windowsize.get_hidpi_factor() }
+/// Sets the UI zoom factor (i.e. for a ctrl + scroll zoom) and adjusts the logical `dimensions`, so that the physical size of the window stays the same. Set the modified `WindowState` via `CallbackInfo::set_window_state()`
+#[no_mangle] pub extern "C" fn AzWindowSize_setZoom(windowsize: &mut AzWindowSize, zoom: f32) { windowsize.set_zoom(zoom) }
 
 /// Boolean flags relating to the current window state
 pub use azul_core::window::WindowFlags as AzWindowFlagsTT;
 pub use AzWindowFlagsTT as AzWindowFlags;
 
+/// How urgently a screen reader should speak an announcement, see `CallbackInfo::announce`
+pub use azul_core::window::Politeness as AzPolitenessTT;
+pub use AzPolitenessTT as AzPoliteness;
+
+/// Kind of tactile feedback, see `CallbackInfo::trigger_haptic`
+pub use azul_core::window::HapticKind as AzHapticKindTT;
+pub use AzHapticKindTT as AzHapticKind;
+
 /// State of the window frame (minimized, maximized, fullscreen or normal window)
 pub use azul_core::window::WindowFrame as AzWindowFrameTT;
 pub use AzWindowFrameTT as AzWindowFrame;
 
+/// How a window covers the monitor in fullscreen mode - the `usize` is the `Monitor::id` of the target monitor (the current monitor of the window if no monitor with that id exists)
+pub use azul_core::window::FullscreenMode as AzFullscreenModeTT;
+pub use AzFullscreenModeTT as AzFullscreenMode;
+
 /// Debugging information, will be rendered as an overlay on top of the UI
 pub use azul_core::window::DebugState as AzDebugStateTT;
 pub use AzDebugStateTT as AzDebugState;
@@ -225,11 +337,35 @@ pub use AzKeyboardStateTT as AzKeyboardState;
 #[no_mangle] pub extern "C" fn AzKeyboardState_altDown(keyboardstate: &AzKeyboardState) -> bool { keyboardstate.alt_down() }
 /// Returns if the `SUPER` ("Windows") key is held down
 #[no_mangle] pub extern "C" fn AzKeyboardState_superDown(keyboardstate: &AzKeyboardState) -> bool { keyboardstate.super_down() }
+/// Returns if the left `SHIFT` key is held down
+#[no_mangle] pub extern "C" fn AzKeyboardState_leftShiftDown(keyboardstate: &AzKeyboardState) -> bool { keyboardstate.left_shift_down() }
+/// Returns if the right `SHIFT` key is held down
+#[no_mangle] pub extern "C" fn AzKeyboardState_rightShiftDown(keyboardstate: &AzKeyboardState) -> bool { keyboardstate.right_shift_down() }
+/// Returns if the left `CTRL` key is held down
+#[no_mangle] pub extern "C" fn AzKeyboardState_leftCtrlDown(keyboardstate: &AzKeyboardState) -> bool { keyboardstate.left_ctrl_down() }
+/// Returns if the right `CTRL` key is held down
+#[no_mangle] pub extern "C" fn AzKeyboardState_rightCtrlDown(keyboardstate: &AzKeyboardState) -> bool { keyboardstate.right_ctrl_down() }
+/// Returns if the left `ALT` key is held down
+#[no_mangle] pub extern "C" fn AzKeyboardState_leftAltDown(keyboardstate: &AzKeyboardState) -> bool { keyboardstate.left_alt_down() }
+/// Returns if the right `ALT` key (`AltGr` on layouts that have one) is held down
+#[no_mangle] pub extern "C" fn AzKeyboardState_rightAltDown(keyboardstate: &AzKeyboardState) -> bool { keyboardstate.right_alt_down() }
+/// Returns if the left `SUPER` ("Windows") key is held down
+#[no_mangle] pub extern "C" fn AzKeyboardState_leftSuperDown(keyboardstate: &AzKeyboardState) -> bool { keyboardstate.left_super_down() }
+/// Returns if the right `SUPER` ("Windows") key is held down
+#[no_mangle] pub extern "C" fn AzKeyboardState_rightSuperDown(keyboardstate: &AzKeyboardState) -> bool { keyboardstate.right_super_down() }
 /// Returns if a key is held down
 #[no_mangle] pub extern "C" fn AzKeyboardState_isKeyDown(keyboardstate: &AzKeyboardState, key: AzVirtualKeyCode) -> bool { keyboardstate.is_key_down(key) }
+/// Returns if the physical key is held down, independent of the keyboard layout
+#[no_mangle] pub extern "C" fn AzKeyboardState_isScancodeDown(keyboardstate: &AzKeyboardState, scancode: u32) -> bool { keyboardstate.is_scancode_down(scancode) }
+/// Returns the currently held down modifiers and the CapsLock state, i.e. to pass them to `CallbackInfo::keycode_to_char`
+#[no_mangle] pub extern "C" fn AzKeyboardState_getModifiers(keyboardstate: &AzKeyboardState) -> AzKeyboardModifiers { keyboardstate.get_modifiers() }
 /// Destructor: Takes ownership of the `KeyboardState` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzKeyboardState_delete(object: &mut AzKeyboardState) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Modifier keys that influence which character a key produces, see `CallbackInfo::keycode_to_char`. `ctrl + alt` is treated as AltGr.
+pub use azul_core::window::KeyboardModifiers as AzKeyboardModifiersTT;
+pub use AzKeyboardModifiersTT as AzKeyboardModifiers;
+
 /// Current icon of the mouse cursor
 pub use azul_core::window::MouseCursorType as AzMouseCursorTypeTT;
 pub use AzMouseCursorTypeTT as AzMouseCursorType;
@@ -305,6 +441,16 @@ pub use AzImePositionTT as AzImePosition;
 /// Current state of touch devices / touch inputs
 pub use azul_core::window::TouchState as AzTouchStateTT;
 pub use AzTouchStateTT as AzTouchState;
+/// Destructor: Takes ownership of the `TouchState` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTouchState_delete(object: &mut AzTouchState) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Device that the last pointer input of the window came from
+pub use azul_core::window::InputModality as AzInputModalityTT;
+pub use AzInputModalityTT as AzInputModality;
+
+/// Single finger / stylus that is currently touching the screen
+pub use azul_core::window::TouchPoint as AzTouchPointTT;
+pub use AzTouchPointTT as AzTouchPoint;
 
 /// Information about a single (or many) monitors, useful for dock widgets
 pub use azul_core::window::Monitor as AzMonitorTT;
@@ -312,6 +458,10 @@ pub use AzMonitorTT as AzMonitor;
 /// Destructor: Takes ownership of the `Monitor` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzMonitor_delete(object: &mut AzMonitor) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// HDR / wide color gamut capabilities of a monitor
+pub use azul_core::window::HdrCapabilities as AzHdrCapabilitiesTT;
+pub use AzHdrCapabilitiesTT as AzHdrCapabilities;
+
 /// Describes a rendering configuration for a monitor
 pub use azul_core::window::VideoMode as AzVideoModeTT;
 pub use AzVideoModeTT as AzVideoMode;
@@ -323,12 +473,16 @@ pub use AzWindowStateTT as AzWindowState;
 #[no_mangle] pub extern "C" fn AzWindowState_new(layout_callback: AzLayoutCallbackType) -> AzWindowState { AzWindowState::new(layout_callback) }
 /// Creates a default WindowState with an empty layout callback - useful only if you use the Rust `WindowState { .. WindowState::default() }` intialization syntax.
 #[no_mangle] pub extern "C" fn AzWindowState_default() -> AzWindowState { AzWindowState::default() }
+/// Puts the window into fullscreen mode on the given monitor. Set `flags.frame` back to `WindowFrame::Normal` to leave fullscreen mode.
+#[no_mangle] pub extern "C" fn AzWindowState_setFullscreen(windowstate: &mut AzWindowState, mode: AzFullscreenMode) { windowstate.set_fullscreen(mode) }
 /// Destructor: Takes ownership of the `WindowState` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzWindowState_delete(object: &mut AzWindowState) {  unsafe { core::ptr::drop_in_place(object); } }
 
 /// Re-export of rust-allocated (stack based) `LayoutCallback` struct
 pub use azul_impl::callbacks::LayoutCallback as AzLayoutCallbackTT;
 pub use AzLayoutCallbackTT as AzLayoutCallback;
+/// Development mode: loads the layout function `symbol` from the dynamic library at `library_path` and reloads the library whenever it changes. The app data is kept across reloads. Use together with `WindowCreateOptions::hot_reload`
+#[no_mangle] pub extern "C" fn AzLayoutCallback_hotReload(library_path: AzString, symbol: AzString) -> AzLayoutCallback { azul_impl::hot_reload::hot_reload_layout_callback(library_path, symbol) }
 /// Destructor: Takes ownership of the `LayoutCallback` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzLayoutCallback_delete(object: &mut AzLayoutCallback) {  unsafe { core::ptr::drop_in_place(object); } }
 
@@ -366,6 +520,12 @@ pub use AzCallbackInfoTT as AzCallbackInfo;
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getCursorRelativeToNode(callbackinfo: &AzCallbackInfo) -> AzOptionLogicalPosition { callbackinfo.get_cursor_relative_to_node() }
 /// Returns a copy of the current windows `WindowState`.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getCurrentWindowState(callbackinfo: &AzCallbackInfo) -> AzWindowState { callbackinfo.get_current_window_state() }
+/// Returns the data of the `EventLoopProxy::send_user_event()` call that triggered the current `On::UserEvent` callback
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getUserEvent(callbackinfo: &AzCallbackInfo) -> AzOptionRefAny { callbackinfo.get_user_event() }
+/// Returns the window-local data of the current window (see `WindowCreateOptions::window_data`)
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getWindowData(callbackinfo: &AzCallbackInfo) -> AzOptionRefAny { callbackinfo.get_window_data() }
+/// Replaces the window-local data of the current window
+#[no_mangle] pub extern "C" fn AzCallbackInfo_setWindowData(callbackinfo: &mut AzCallbackInfo, data: AzRefAny) { callbackinfo.set_window_data(data) }
 /// Returns a copy of the internal `KeyboardState`. Same as `self.get_window_state().keyboard_state`
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getCurrentKeyboardState(callbackinfo: &AzCallbackInfo) -> AzKeyboardState { callbackinfo.get_current_keyboard_state() }
 /// Returns a copy of the internal `MouseState`. Same as `self.get_window_state().mouse_state`
@@ -420,10 +580,20 @@ pub use AzCallbackInfoTT as AzCallbackInfo;
 #[no_mangle] pub extern "C" fn AzCallbackInfo_setFocus(callbackinfo: &mut AzCallbackInfo, target: AzFocusTarget) { callbackinfo.set_focus(target); }
 /// Sets a `CssProperty` on a given node to its new value. If this property change affects the layout, this will automatically trigger a relayout and redraw of the screen.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_setCssProperty(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_property: AzCssProperty) { callbackinfo.set_css_property(node_id, new_property);  }
+/// Adds a class to the node. After the callback returns, only the node and its children are restyled, the DOM does not have to be regenerated.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_addClass(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, class: AzString) { callbackinfo.add_class(node_id, class);  }
+/// Removes a class from the node, see `add_class`
+#[no_mangle] pub extern "C" fn AzCallbackInfo_removeClass(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, class: AzString) { callbackinfo.remove_class(node_id, class);  }
+/// Adds the class to the node if it is not present, removes it otherwise
+#[no_mangle] pub extern "C" fn AzCallbackInfo_toggleClass(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, class: AzString) { callbackinfo.toggle_class(node_id, class);  }
+/// Sets or clears the `:disabled`, `:checked`, `:selected` or `:indeterminate` state of the node, the node is restyled after the callback returns
+#[no_mangle] pub extern "C" fn AzCallbackInfo_setNodeStateFlag(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, flag: AzNodeStateFlag, value: bool) { callbackinfo.set_node_state_flag(node_id, flag, value);  }
 /// Sets the scroll position of the node
 #[no_mangle] pub extern "C" fn AzCallbackInfo_setScrollPosition(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) { callbackinfo.set_scroll_position(node_id, scroll_position) }
 /// If the node is a `Text` node, overwrites the `Text` content with the new string, without requiring the entire UI to be rebuilt.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_setStringContents(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, string: AzString) { callbackinfo.set_string_contents(node_id, string) }
+/// Underlines character ranges of a `Text` node (i.e. spelling errors) without requiring the entire UI to be rebuilt. The underlines replace the previous underlines of the node and are removed when the text of the node changes.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_setTextUnderlines(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, underlines: AzTextUnderlineVec) { callbackinfo.set_text_underlines(node_id, underlines) }
 /// Adds a new image identified by an ID to the image cache
 #[no_mangle] pub extern "C" fn AzCallbackInfo_addImage(callbackinfo: &mut AzCallbackInfo, id: AzString, image: AzImageRef) { callbackinfo.add_image(id, image) }
 /// Returns whether an image with a given CSS ID already exists
@@ -444,6 +614,10 @@ pub use AzCallbackInfoTT as AzCallbackInfo;
 #[no_mangle] pub extern "C" fn AzCallbackInfo_startTimer(callbackinfo: &mut AzCallbackInfo, timer: AzTimer) -> AzTimerId { callbackinfo.start_timer(timer) }
 /// Starts an animation timer on a give NodeId - same as a `Timer`, but uses a pre-configured interpolation function to drive the animation timer
 #[no_mangle] pub extern "C" fn AzCallbackInfo_startAnimation(callbackinfo: &mut AzCallbackInfo, node: AzDomNodeId, animation: AzAnimation) -> AzOptionTimerId { callbackinfo.start_animation(node, animation).into() }
+/// Shows the text caret and starts blinking / fading it every `-azul-caret-animation-duration`, restart the animation on every key press to not blink while typing. Returns `None` if the duration is `0`
+#[no_mangle] pub extern "C" fn AzCallbackInfo_startCaretAnimation(callbackinfo: &mut AzCallbackInfo, caret_node: AzDomNodeId, animation: AzCaretAnimation) -> AzOptionTimerId { callbackinfo.start_caret_animation(caret_node, animation).into() }
+/// Resizes the window to the `target_size` over the `duration` (ease-in-out), re-layouting the UI at every intermediate size. Stop the returned timer to cancel the animation
+#[no_mangle] pub extern "C" fn AzCallbackInfo_animateWindowResize(callbackinfo: &mut AzCallbackInfo, target_size: AzLogicalSize, duration: AzDuration) -> AzTimerId { callbackinfo.animate_window_resize(target_size, duration) }
 /// Stops / cancels a `Timer`. See the documentation for `Timer` for more information.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_stopTimer(callbackinfo: &mut AzCallbackInfo, timer_id: AzTimerId) -> bool { callbackinfo.stop_timer(timer_id) }
 /// Starts a new `Thread` to the runtime. See the documentation for `Thread` for more information.
@@ -452,6 +626,36 @@ pub use AzCallbackInfoTT as AzCallbackInfo;
 #[no_mangle] pub extern "C" fn AzCallbackInfo_sendThreadMsg(callbackinfo: &mut AzCallbackInfo, thread_id: AzThreadId, msg: AzThreadSendMsg) -> bool { callbackinfo.send_thread_msg(thread_id, msg) }
 /// Stops a thread at the nearest possible opportunity. Sends a `ThreadSendMsg::TerminateThread` message to the thread and joins the thread.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_stopThread(callbackinfo: &mut AzCallbackInfo, thread_id: AzThreadId) -> bool { callbackinfo.stop_thread(thread_id) }
+/// Returns how long the user has not interacted with the system, `None` if the platform can't report the idle time
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getSystemIdleTime(callbackinfo: &AzCallbackInfo) -> AzOptionDuration { callbackinfo.get_system_idle_time() }
+/// Returns the character that the key produces in the current keyboard layout (i.e. to display shortcuts in menus), `None` if the key doesn't produce a character or the platform can't map it
+#[no_mangle] pub extern "C" fn AzCallbackInfo_keycodeToChar(callbackinfo: &AzCallbackInfo, virtual_keycode: AzVirtualKeyCode, modifiers: AzKeyboardModifiers) -> AzOptionChar { callbackinfo.keycode_to_char(virtual_keycode, modifiers) }
+/// Returns the most recent log lines (oldest first), i.e. to display them in a diagnostics panel. Empty if logging is disabled.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getLogLines(callbackinfo: &AzCallbackInfo) -> AzStringVec { callbackinfo.get_log_lines() }
+/// Sets the log level of a subsystem (`azul::layout`, `azul::restyle` or `azul::render`) at runtime - an empty string sets the global log level
+#[no_mangle] pub extern "C" fn AzCallbackInfo_setLogLevel(callbackinfo: &AzCallbackInfo, subsystem: AzString, level: AzAppLogLevel) { callbackinfo.set_log_level(subsystem, level) }
+/// Returns the margin / border / padding / content boxes of the node after the last layout pass, relative to the window origin. Useful for positioning popups or drag handles relative to a node.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getNodeRect(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionNodeRect { callbackinfo.get_node_rect(node_id).into() }
+/// Same as `get_inline_text()`, but also returns the content box that the text lines are positioned in
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getTextLayout(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionTextLayout { callbackinfo.get_text_layout(node_id).into() }
+/// Returns the viewport, content size, current and maximum scroll offset of the node or `None` if the node does not overflow its parent
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getScrollInfo(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionScrollInfo { callbackinfo.get_scroll_info(node_id).into() }
+/// Adds a window-level keyboard shortcut. If the key combination is already used by another shortcut, the shortcut is not added and the conflict is returned
+#[no_mangle] pub extern "C" fn AzCallbackInfo_addShortcut(callbackinfo: &mut AzCallbackInfo, shortcut: AzShortcut) -> AzOptionShortcutConflict { callbackinfo.add_shortcut(shortcut) }
+/// Removes the window-level shortcut with the given key combination, returns whether a shortcut was removed
+#[no_mangle] pub extern "C" fn AzCallbackInfo_removeShortcut(callbackinfo: &mut AzCallbackInfo, keys: AzVirtualKeyCodeCombo) -> bool { callbackinfo.remove_shortcut(keys) }
+/// Returns all window-level shortcuts that share the same key combination
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getShortcutConflicts(callbackinfo: &AzCallbackInfo) -> AzShortcutConflictVec { callbackinfo.get_shortcut_conflicts() }
+/// Asks the screen reader to speak the `text` (i.e. "3 results found"), without having to move the focus. Currently only implemented on Windows (UI Automation notifications)
+#[no_mangle] pub extern "C" fn AzCallbackInfo_announce(callbackinfo: &mut AzCallbackInfo, text: AzString, politeness: AzPoliteness) { callbackinfo.announce(text, politeness) }
+/// Plays a short tactile feedback on devices that support it. Currently only implemented for haptic pens on Windows 11 (requires the `haptics` feature), ignored elsewhere
+#[no_mangle] pub extern "C" fn AzCallbackInfo_triggerHaptic(callbackinfo: &mut AzCallbackInfo, kind: AzHapticKind) { callbackinfo.trigger_haptic(kind) }
+/// Runs the `callback` once, right before the next frame is rendered (synchronized to the vertical blank where supported). `TimerCallbackInfo::frame_start` is the same for all callbacks of one frame. Call this function again from the callback to run it on the next frame, too.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_requestAnimationFrame(callbackinfo: &mut AzCallbackInfo, data: AzRefAny, callback: AzTimerCallbackType) { callbackinfo.request_animation_frame(data, callback) }
+/// Cross-fades the subtree of the `node` to its new content after the next DOM update: the current content is kept as a (non-interactive) snapshot and faded out over the new content during the `duration`. Only subtrees of the root DOM can be cross-faded. Currently only implemented on Windows
+#[no_mangle] pub extern "C" fn AzCallbackInfo_crossfadeSubtree(callbackinfo: &mut AzCallbackInfo, node: AzDomNodeId, duration: AzDuration) { callbackinfo.crossfade_subtree(node, duration) }
+/// Records the subtree of the `node` (including its running animations) for the given `duration` at up to `fps` frames per second and writes it as an animated GIF to the `output_path` (requires the `gif` feature), i.e. to generate animated screenshots of widgets for documentation. The frames are taken from the rendered window, so the node has to be visible. Only nodes of the root DOM can be recorded. Currently only implemented on Windows
+#[no_mangle] pub extern "C" fn AzCallbackInfo_renderSubtreeToFrames(callbackinfo: &mut AzCallbackInfo, node: AzDomNodeId, duration: AzDuration, fps: u32, output_path: AzString) { callbackinfo.render_subtree_to_frames(node, duration, fps, output_path) }
 /// Destructor: Takes ownership of the `CallbackInfo` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_delete(object: &mut AzCallbackInfo) {  unsafe { core::ptr::drop_in_place(object); } }
 
@@ -485,6 +689,14 @@ pub use AzPositionInfoTT as AzPositionInfo;
 /// Equivalent to the Rust `PositionInfo::get_relative_offset()` function.
 #[no_mangle] pub extern "C" fn AzPositionInfo_getRelativeOffset(positioninfo: &AzPositionInfo) -> AzLogicalPosition { positioninfo.get_relative_offset() }
 
+/// Post-layout geometry of a node, all rects are relative to the window origin
+pub use azul_impl::ui_solver::NodeRect as AzNodeRectTT;
+pub use AzNodeRectTT as AzNodeRect;
+
+/// Scroll extents of a scrollable (overflowing) node
+pub use azul_impl::callbacks::ScrollInfo as AzScrollInfoTT;
+pub use AzScrollInfoTT as AzScrollInfo;
+
 /// Re-export of rust-allocated (stack based) `PositionInfoInner` struct
 pub use azul_impl::ui_solver::PositionInfoInner as AzPositionInfoInnerTT;
 pub use AzPositionInfoInnerTT as AzPositionInfoInner;
@@ -507,6 +719,12 @@ pub use AzInlineTextTT as AzInlineText;
 /// Destructor: Takes ownership of the `InlineText` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzInlineText_delete(object: &mut AzInlineText) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Laid out text of a `Text` node, together with the rect that the text lines are positioned in
+pub use azul_impl::callbacks::TextLayout as AzTextLayoutTT;
+pub use AzTextLayoutTT as AzTextLayout;
+/// Destructor: Takes ownership of the `TextLayout` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextLayout_delete(object: &mut AzTextLayout) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `InlineLine` struct
 pub use azul_impl::callbacks::InlineLine as AzInlineLineTT;
 pub use AzInlineLineTT as AzInlineLine;
@@ -529,6 +747,14 @@ pub use AzInlineTextContentsTT as AzInlineTextContents;
 pub use azul_core::callbacks::InlineGlyph as AzInlineGlyphTT;
 pub use AzInlineGlyphTT as AzInlineGlyph;
 
+/// Underlines a range of characters of a text node, see `CallbackInfo::set_text_underlines`
+pub use azul_impl::callbacks::TextUnderline as AzTextUnderlineTT;
+pub use AzTextUnderlineTT as AzTextUnderline;
+
+/// Line style of a `TextUnderline`
+pub use azul_impl::callbacks::TextUnderlineStyle as AzTextUnderlineStyleTT;
+pub use AzTextUnderlineStyleTT as AzTextUnderlineStyle;
+
 /// Re-export of rust-allocated (stack based) `InlineTextHit` struct
 pub use azul_core::callbacks::InlineTextHit as AzInlineTextHitTT;
 pub use AzInlineTextHitTT as AzInlineTextHit;
@@ -560,6 +786,10 @@ pub use AzAnimationTT as AzAnimation;
 /// Destructor: Takes ownership of the `Animation` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzAnimation_delete(object: &mut AzAnimation) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// How the text caret of an editable node is animated
+pub use azul_impl::callbacks::CaretAnimation as AzCaretAnimationTT;
+pub use AzCaretAnimationTT as AzCaretAnimation;
+
 /// How should an animation repeat (loop, ping-pong, etc.)
 pub use azul_impl::callbacks::AnimationRepeat as AzAnimationRepeatTT;
 pub use AzAnimationRepeatTT as AzAnimationRepeat;
@@ -581,6 +811,41 @@ pub type AzIFrameCallbackType = extern "C" fn(&mut AzRefAny, &mut AzIFrameCallba
 pub use azul_impl::callbacks::IFrameCallbackInfo as AzIFrameCallbackInfoTT;
 pub use AzIFrameCallbackInfoTT as AzIFrameCallbackInfo;
 
+/// C-ABI wrapper over a `MeasureCallbackType`
+pub use azul_impl::callbacks::MeasureCallback as AzMeasureCallbackTT;
+pub use AzMeasureCallbackTT as AzMeasureCallback;
+
+pub type AzMeasureCallbackType = extern "C" fn(&mut AzRefAny, &AzMeasureCallbackInfo) -> AzLogicalSize;
+/// Re-export of rust-allocated (stack based) `MeasureCallbackInfo` struct
+pub use azul_impl::callbacks::MeasureCallbackInfo as AzMeasureCallbackInfoTT;
+pub use AzMeasureCallbackInfoTT as AzMeasureCallbackInfo;
+
+/// C-ABI wrapper over an `ArrangeCallbackType`
+pub use azul_impl::callbacks::ArrangeCallback as AzArrangeCallbackTT;
+pub use AzArrangeCallbackTT as AzArrangeCallback;
+
+pub type AzArrangeCallbackType = extern "C" fn(&mut AzRefAny, &AzArrangeCallbackInfo);
+/// Re-export of rust-allocated (stack based) `ArrangeCallbackInfo` struct
+pub use azul_impl::callbacks::ArrangeCallbackInfo as AzArrangeCallbackInfoTT;
+pub use AzArrangeCallbackInfoTT as AzArrangeCallbackInfo;
+
+/// C-ABI wrapper over a `PaintCallbackType`
+pub use azul_impl::callbacks::PaintCallback as AzPaintCallbackTT;
+pub use AzPaintCallbackTT as AzPaintCallback;
+
+pub type AzPaintCallbackType = extern "C" fn(&mut AzRefAny, &AzPaintCallbackInfo) -> AzPaintPrimitiveVec;
+/// Re-export of rust-allocated (stack based) `PaintCallbackInfo` struct
+pub use azul_impl::callbacks::PaintCallbackInfo as AzPaintCallbackInfoTT;
+pub use AzPaintCallbackInfoTT as AzPaintCallbackInfo;
+
+/// Primitive drawn by a `PaintCallback`
+pub use azul_impl::callbacks::PaintPrimitive as AzPaintPrimitiveTT;
+pub use AzPaintPrimitiveTT as AzPaintPrimitive;
+
+/// Filled (rounded) rectangle
+pub use azul_impl::callbacks::PaintRect as AzPaintRectTT;
+pub use AzPaintRectTT as AzPaintRect;
+
 /// <img src="../images/scrollbounds.png"/>
 pub use azul_impl::callbacks::IFrameCallbackReturn as AzIFrameCallbackReturnTT;
 pub use AzIFrameCallbackReturnTT as AzIFrameCallbackReturn;
@@ -688,6 +953,8 @@ pub use AzLayoutCallbackInfoTT as AzLayoutCallbackInfo;
 #[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_getSystemFonts(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzStringPairVec { layoutcallbackinfo.get_system_fonts().into() }
 /// Returns an `ImageRef` referenced by a CSS ID
 #[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_getImage(layoutcallbackinfo: &AzLayoutCallbackInfo, id: AzString) -> AzOptionImageRef { layoutcallbackinfo.get_image(&id).into() }
+/// Returns whether high contrast mode is enabled, whether the user prefers reduced motion and the current system colors
+#[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_getSystemStyle(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzSystemStyle { layoutcallbackinfo.get_system_style() }
 /// Destructor: Takes ownership of the `LayoutCallbackInfo` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_delete(object: &mut AzLayoutCallbackInfo) {  unsafe { core::ptr::drop_in_place(object); } }
 
@@ -715,6 +982,15 @@ pub use AzDomTT as AzDom;
 /// Creates a new `Dom` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `Dom::iframe()` constructor.
 #[no_mangle] pub extern "C" fn AzDom_iframe(data: AzRefAny, callback: AzIFrameCallbackType) -> AzDom { AzDom::iframe(data, callback) }
+/// Creates a new `Dom` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Dom::custom_layout()` constructor.
+#[no_mangle] pub extern "C" fn AzDom_customLayout(data: AzRefAny, measure: AzMeasureCallbackType, arrange: AzArrangeCallbackType) -> AzDom { AzDom::custom_layout(data, measure, arrange) }
+/// Creates a node of a type that was registered with `CustomNodeType::register`, returns `None` if no type with the name `type_name` is registered
+#[no_mangle] pub extern "C" fn AzDom_customNode(type_name: AzString, data: AzRefAny) -> AzOptionDom { AzDom::custom_node(type_name.as_str(), data).into() }
+/// Same as `iframe()`, but only re-invokes the callback if the position, width or height of the iframe changes by more than `reinvoke_threshold` logical pixels
+#[no_mangle] pub extern "C" fn AzDom_iframeWithReinvokeThreshold(data: AzRefAny, callback: AzIFrameCallbackType, reinvoke_threshold: f32) -> AzDom { AzDom::iframe_with_reinvoke_threshold(data, callback, reinvoke_threshold) }
+/// Creates a container whose children are rendered in a top-level layer above all other content, positioned next to the node with the ID `anchor` (dropdowns, comboboxes). The overlay is flipped / shifted so that it stays inside the window
+#[no_mangle] pub extern "C" fn AzDom_overlay(anchor: AzString, placement: AzOverlayPlacement) -> AzDom { AzDom::overlay(anchor, placement) }
 /// Equivalent to the Rust `Dom::set_node_type()` function.
 #[no_mangle] pub extern "C" fn AzDom_setNodeType(dom: &mut AzDom, node_type: AzNodeType) { dom.root.set_node_type(node_type) }
 /// Equivalent to the Rust `Dom::with_node_type()` function.
@@ -807,6 +1083,22 @@ pub use AzDomTT as AzDom;
 #[no_mangle] pub extern "C" fn AzDom_setContextMenu(dom: &mut AzDom, context_menu: AzMenu) { dom.root.set_context_menu(context_menu) }
 /// Same as set_context_menu, but as a builder method
 #[no_mangle] pub extern "C" fn AzDom_withContextMenu(dom: &mut AzDom, context_menu: AzMenu) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_context_menu(context_menu); dom }
+/// Overrides the arrow key focus navigation for the DOM root node. See `NodeData::set_focus_navigation` for more information.
+#[no_mangle] pub extern "C" fn AzDom_setFocusNavigation(dom: &mut AzDom, focus_navigation: AzFocusNavigation) { dom.root.set_focus_navigation(focus_navigation) }
+/// Same as set_focus_navigation, but as a builder method
+#[no_mangle] pub extern "C" fn AzDom_withFocusNavigation(dom: &mut AzDom, focus_navigation: AzFocusNavigation) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_focus_navigation(focus_navigation); dom }
+/// Sets the language of the text in the DOM root node and its children (BCP 47 tag, i.e. `"de"` or `"en-US"`), used for hyphenation (`hyphens: auto`) and line breaking
+#[no_mangle] pub extern "C" fn AzDom_setLang(dom: &mut AzDom, lang: AzString) { dom.root.set_lang(lang) }
+/// Same as set_lang, but as a builder method
+#[no_mangle] pub extern "C" fn AzDom_withLang(dom: &mut AzDom, lang: AzString) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_lang(lang); dom }
+/// Sets the kind of text that is entered into the DOM root node (same as the HTML `inputmode` attribute), forwarded to the IME / touch keyboard when the node is focused
+#[no_mangle] pub extern "C" fn AzDom_setInputMode(dom: &mut AzDom, input_mode: AzInputMode) { dom.root.set_input_mode(input_mode) }
+/// Same as set_input_mode, but as a builder method
+#[no_mangle] pub extern "C" fn AzDom_withInputMode(dom: &mut AzDom, input_mode: AzInputMode) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_input_mode(input_mode); dom }
+/// Sets the initial `:disabled`, `:checked`, `:selected` or `:indeterminate` state of the DOM root node
+#[no_mangle] pub extern "C" fn AzDom_setStateFlag(dom: &mut AzDom, flag: AzNodeStateFlag, enabled: bool) { dom.root.set_state_flag(flag, enabled) }
+/// Same as set_state_flag, but as a builder method
+#[no_mangle] pub extern "C" fn AzDom_withStateFlag(dom: &mut AzDom, flag: AzNodeStateFlag, enabled: bool) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_state_flag(flag, enabled); dom }
 /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
 #[no_mangle] pub extern "C" fn AzDom_hash(dom: &AzDom) -> u64 { dom.root.calculate_node_data_hash().0 }
 /// Returns the number of nodes in the DOM, including all child DOM trees. Result is equal to `self.total_children + 1` (count of all child trees + the root node)
@@ -826,6 +1118,25 @@ pub use AzIFrameNodeTT as AzIFrameNode;
 /// Destructor: Takes ownership of the `IFrameNode` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzIFrameNode_delete(object: &mut AzIFrameNode) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `CustomLayoutNode` struct
+pub use azul_impl::dom::CustomLayoutNode as AzCustomLayoutNodeTT;
+pub use AzCustomLayoutNodeTT as AzCustomLayoutNode;
+/// Destructor: Takes ownership of the `CustomLayoutNode` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCustomLayoutNode_delete(object: &mut AzCustomLayoutNode) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Node type defined outside of azul (map view, terminal widget, ...): the measure / arrange / paint hooks that all nodes of this type share
+pub use azul_impl::dom::CustomNodeType as AzCustomNodeTypeTT;
+pub use AzCustomNodeTypeTT as AzCustomNodeType;
+/// Creates a new `CustomNodeType` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `CustomNodeType::new()` constructor.
+#[no_mangle] pub extern "C" fn AzCustomNodeType_new(name: AzString, measure: AzMeasureCallbackType, arrange: AzArrangeCallbackType) -> AzCustomNodeType { AzCustomNodeType::new(name, measure, arrange) }
+/// Draws the content of the nodes of this type with the given callback
+#[no_mangle] pub extern "C" fn AzCustomNodeType_withPaint(customnodetype: AzCustomNodeType, paint: AzPaintCallbackType) -> AzCustomNodeType { customnodetype.with_paint(paint) }
+/// Registers the node type (replacing a type with the same name), so that nodes can be created by name via `Dom::custom_node`
+#[no_mangle] pub extern "C" fn AzCustomNodeType_register(customnodetype: AzCustomNodeType) { customnodetype.register() }
+/// Destructor: Takes ownership of the `CustomNodeType` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCustomNodeType_delete(object: &mut AzCustomNodeType) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `CallbackData` struct
 pub use azul_impl::dom::CallbackData as AzCallbackDataTT;
 pub use AzCallbackDataTT as AzCallbackData;
@@ -855,6 +1166,11 @@ pub use AzNodeDataTT as AzNodeData;
 /// Creates a new `NodeData` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `NodeData::iframe()` constructor.
 #[no_mangle] pub extern "C" fn AzNodeData_iframe(data: AzRefAny, callback: AzIFrameCallbackType) -> AzNodeData { AzNodeData::iframe(data, callback) }
+/// Creates a new `NodeData` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `NodeData::custom_layout()` constructor.
+#[no_mangle] pub extern "C" fn AzNodeData_customLayout(data: AzRefAny, measure: AzMeasureCallbackType, arrange: AzArrangeCallbackType) -> AzNodeData { AzNodeData::custom_layout(data, measure, arrange) }
+/// Same as `iframe()`, but only re-invokes the callback if the position, width or height of the iframe changes by more than `reinvoke_threshold` logical pixels
+#[no_mangle] pub extern "C" fn AzNodeData_iframeWithReinvokeThreshold(data: AzRefAny, callback: AzIFrameCallbackType, reinvoke_threshold: f32) -> AzNodeData { AzNodeData::iframe_with_reinvoke_threshold(data, callback, reinvoke_threshold) }
 /// Equivalent to the Rust `NodeData::set_node_type()` function.
 #[no_mangle] pub extern "C" fn AzNodeData_setNodeType(nodedata: &mut AzNodeData, node_type: AzNodeType) { nodedata.set_node_type(node_type) }
 /// Equivalent to the Rust `NodeData::with_node_type()` function.
@@ -905,8 +1221,18 @@ pub use AzNodeDataTT as AzNodeData;
 #[no_mangle] pub extern "C" fn AzNodeData_setMenuBar(nodedata: &mut AzNodeData, menu_bar: AzMenu) { nodedata.set_menu_bar(menu_bar) }
 /// Signalizes that this node has a (native) context-aware menu. If set, the user can left-click the node to open the menu
 #[no_mangle] pub extern "C" fn AzNodeData_setContextMenu(nodedata: &mut AzNodeData, context_menu: AzMenu) { nodedata.set_context_menu(context_menu) }
+/// Overrides the node that receives the focus when an arrow key is pressed while this node is focused (see `WindowFlags::spatial_navigation_enabled`)
+#[no_mangle] pub extern "C" fn AzNodeData_setFocusNavigation(nodedata: &mut AzNodeData, focus_navigation: AzFocusNavigation) { nodedata.set_focus_navigation(focus_navigation) }
 /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
 #[no_mangle] pub extern "C" fn AzNodeData_hash(nodedata: &AzNodeData) -> u64 { nodedata.calculate_node_data_hash().0 }
+/// Turns the node into an overlay that is positioned next to the node with the ID `overlay.anchor` and rendered above all other content (see `Dom::overlay`)
+#[no_mangle] pub extern "C" fn AzNodeData_setOverlay(nodedata: &mut AzNodeData, overlay: AzOverlayInfo) { nodedata.set_overlay(overlay) }
+/// Sets the language of the text in this node and its children (BCP 47 tag, i.e. `"de"` or `"en-US"`), same as the HTML `lang` attribute. Used for hyphenation (`hyphens: auto`) and line breaking
+#[no_mangle] pub extern "C" fn AzNodeData_setLang(nodedata: &mut AzNodeData, lang: AzString) { nodedata.set_lang(lang) }
+/// Sets the kind of text that is entered into this node (same as the HTML `inputmode` attribute), forwarded to the IME / touch keyboard when the node is focused
+#[no_mangle] pub extern "C" fn AzNodeData_setInputMode(nodedata: &mut AzNodeData, input_mode: AzInputMode) { nodedata.set_input_mode(input_mode) }
+/// Sets the initial `:disabled`, `:checked`, `:selected` or `:indeterminate` state of this node
+#[no_mangle] pub extern "C" fn AzNodeData_setStateFlag(nodedata: &mut AzNodeData, flag: AzNodeStateFlag, enabled: bool) { nodedata.set_state_flag(flag, enabled) }
 /// Destructor: Takes ownership of the `NodeData` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzNodeData_delete(object: &mut AzNodeData) {  unsafe { core::ptr::drop_in_place(object); } }
 
@@ -956,6 +1282,34 @@ pub use AzAccessibilityInfoTT as AzAccessibilityInfo;
 /// Destructor: Takes ownership of the `AccessibilityInfo` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzAccessibilityInfo_delete(object: &mut AzAccessibilityInfo) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Direction of the spatial (arrow key) focus navigation
+pub use azul_impl::dom::FocusDirection as AzFocusDirectionTT;
+pub use AzFocusDirectionTT as AzFocusDirection;
+
+/// Kind of text that is entered into a text input node, same as the HTML `inputmode` attribute. Forwarded to the operating system so that touch keyboards and IMEs can show the appropriate layout
+pub use azul_impl::dom::InputMode as AzInputModeTT;
+pub use AzInputModeTT as AzInputMode;
+
+/// Semantic state of a node that is set by the application (instead of by the mouse / keyboard), matched by the `:disabled`, `:checked`, `:selected` and `:indeterminate` CSS selectors
+pub use azul_impl::dom::NodeStateFlag as AzNodeStateFlagTT;
+pub use AzNodeStateFlagTT as AzNodeStateFlag;
+
+/// Per-node overrides for the spatial focus navigation, similar to the `nav-up: #id` CSS properties
+pub use azul_impl::dom::FocusNavigation as AzFocusNavigationTT;
+pub use AzFocusNavigationTT as AzFocusNavigation;
+/// Destructor: Takes ownership of the `FocusNavigation` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzFocusNavigation_delete(object: &mut AzFocusNavigation) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Where an overlay is placed relative to its anchor node: the first part is the side of the anchor, the second part the alignment along that side (`BottomStart` = below the anchor, left edges aligned)
+pub use azul_impl::dom::OverlayPlacement as AzOverlayPlacementTT;
+pub use AzOverlayPlacementTT as AzOverlayPlacement;
+
+/// Marks a node as an overlay: the node is taken out of the regular layout, positioned next to the node with the ID `anchor` and rendered above all other content, clipped only by the window. If the overlay doesn't fit on the requested side, it is flipped to the opposite side and shifted back into the window
+pub use azul_impl::dom::OverlayInfo as AzOverlayInfoTT;
+pub use AzOverlayInfoTT as AzOverlayInfo;
+/// Destructor: Takes ownership of the `OverlayInfo` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOverlayInfo_delete(object: &mut AzOverlayInfo) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// MSAA Accessibility role constants. For information on what each role does, see the <a href="https://docs.microsoft.com/en-us/windows/win32/winauto/object-roles">MSDN Role Constants page</a>
 pub use azul_impl::dom::AccessibilityRole as AzAccessibilityRoleTT;
 pub use AzAccessibilityRoleTT as AzAccessibilityRole;
@@ -1041,6 +1395,34 @@ pub use AzMenuCallbackTT as AzMenuCallback;
 /// Destructor: Takes ownership of the `MenuCallback` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzMenuCallback_delete(object: &mut AzMenuCallback) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Window-level keyboard shortcut: invokes the callback when the key combination is pressed, instead of the `VirtualKeyDown` callbacks of the focused node
+pub use azul_core::window::Shortcut as AzShortcutTT;
+pub use AzShortcutTT as AzShortcut;
+/// Creates a new shortcut from a key combination and a callback
+#[no_mangle] pub extern "C" fn AzShortcut_new(keys: AzVirtualKeyCodeCombo, data: AzRefAny, callback: AzCallbackType) -> AzShortcut { AzShortcut::new(keys, data, callback) }
+/// Destructor: Takes ownership of the `Shortcut` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzShortcut_delete(object: &mut AzShortcut) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Two shortcuts in a `ShortcutMap` that use the same key combination
+pub use azul_core::window::ShortcutConflict as AzShortcutConflictTT;
+pub use AzShortcutConflictTT as AzShortcutConflict;
+/// Destructor: Takes ownership of the `ShortcutConflict` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzShortcutConflict_delete(object: &mut AzShortcutConflict) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// List of window-level keyboard shortcuts
+pub use azul_core::window::ShortcutMap as AzShortcutMapTT;
+pub use AzShortcutMapTT as AzShortcutMap;
+/// Creates an empty shortcut map
+#[no_mangle] pub extern "C" fn AzShortcutMap_new() -> AzShortcutMap { AzShortcutMap::new() }
+/// Adds a shortcut to the map. If another shortcut already uses the same key combination, the new shortcut is NOT added and the conflict is returned
+#[no_mangle] pub extern "C" fn AzShortcutMap_add(shortcutmap: &mut AzShortcutMap, shortcut: AzShortcut) -> AzOptionShortcutConflict { shortcutmap.add(shortcut) }
+/// Removes the shortcut with the given key combination, returns whether a shortcut was removed
+#[no_mangle] pub extern "C" fn AzShortcutMap_remove(shortcutmap: &mut AzShortcutMap, keys: AzVirtualKeyCodeCombo) -> bool { shortcutmap.remove(&keys) }
+/// Returns all pairs of shortcuts with the same key combination
+#[no_mangle] pub extern "C" fn AzShortcutMap_getConflicts(shortcutmap: &AzShortcutMap) -> AzShortcutConflictVec { shortcutmap.get_conflicts() }
+/// Destructor: Takes ownership of the `ShortcutMap` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzShortcutMap_delete(object: &mut AzShortcutMap) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Icon of a menu entry
 pub use azul_core::window::MenuItemIcon as AzMenuItemIconTT;
 pub use AzMenuItemIconTT as AzMenuItemIcon;
@@ -1057,6 +1439,10 @@ pub use AzCssRuleBlockTT as AzCssRuleBlock;
 /// Destructor: Takes ownership of the `CssRuleBlock` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzCssRuleBlock_delete(object: &mut AzCssRuleBlock) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `CssMediaCondition` struct
+pub use azul_impl::css::CssMediaCondition as AzCssMediaConditionTT;
+pub use AzCssMediaConditionTT as AzCssMediaCondition;
+
 /// Re-export of rust-allocated (stack based) `CssDeclaration` struct
 pub use azul_impl::css::CssDeclaration as AzCssDeclarationTT;
 pub use AzCssDeclarationTT as AzCssDeclaration;
@@ -1069,6 +1455,12 @@ pub use AzDynamicCssPropertyTT as AzDynamicCssProperty;
 /// Destructor: Takes ownership of the `DynamicCssProperty` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzDynamicCssProperty_delete(object: &mut AzDynamicCssProperty) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `SystemCssProperty` struct
+pub use azul_impl::css::SystemCssProperty as AzSystemCssPropertyTT;
+pub use AzSystemCssPropertyTT as AzSystemCssProperty;
+/// Destructor: Takes ownership of the `SystemCssProperty` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSystemCssProperty_delete(object: &mut AzSystemCssProperty) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `CssPath` struct
 pub use azul_impl::css::CssPath as AzCssPathTT;
 pub use AzCssPathTT as AzCssPath;
@@ -1143,6 +1535,14 @@ pub use AzColorUTT as AzColorU;
 /// Equivalent to the Rust `ColorU::to_hash()` function.
 #[no_mangle] pub extern "C" fn AzColorU_toHash(coloru: &AzColorU) -> AzString { coloru.to_hash().into() }
 
+/// Colors of the CSS system color keywords (`Canvas`, `CanvasText`, `Highlight`, ...)
+pub use azul_impl::css::SystemColors as AzSystemColorsTT;
+pub use AzSystemColorsTT as AzSystemColors;
+
+/// High contrast / forced colors mode, reduced motion preference and system colors of the operating system
+pub use azul_impl::css::SystemStyle as AzSystemStyleTT;
+pub use AzSystemStyleTT as AzSystemStyle;
+
 /// Re-export of rust-allocated (stack based) `SizeMetric` struct
 pub use azul_impl::css::SizeMetric as AzSizeMetricTT;
 pub use AzSizeMetricTT as AzSizeMetric;
@@ -1481,9 +1881,67 @@ pub use AzScrollbarStyleTT as AzScrollbarStyle;
 /// Destructor: Takes ownership of the `ScrollbarStyle` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzScrollbarStyle_delete(object: &mut AzScrollbarStyle) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `StyleScrollbarWidth` struct
+pub use azul_impl::css::StyleScrollbarWidth as AzStyleScrollbarWidthTT;
+pub use AzStyleScrollbarWidthTT as AzStyleScrollbarWidth;
+
+/// Re-export of rust-allocated (stack based) `StyleOverflowAnchor` struct
+pub use azul_impl::css::StyleOverflowAnchor as AzStyleOverflowAnchorTT;
+pub use AzStyleOverflowAnchorTT as AzStyleOverflowAnchor;
+
+/// Re-export of rust-allocated (stack based) `StyleScrollbarColor` struct
+pub use azul_impl::css::StyleScrollbarColor as AzStyleScrollbarColorTT;
+pub use AzStyleScrollbarColorTT as AzStyleScrollbarColor;
+
+/// Re-export of rust-allocated (stack based) `StyleScrollbarThumbHoverColor` struct
+pub use azul_impl::css::StyleScrollbarThumbHoverColor as AzStyleScrollbarThumbHoverColorTT;
+pub use AzStyleScrollbarThumbHoverColorTT as AzStyleScrollbarThumbHoverColor;
+
+/// Re-export of rust-allocated (stack based) `StyleScrollbarThumbActiveColor` struct
+pub use azul_impl::css::StyleScrollbarThumbActiveColor as AzStyleScrollbarThumbActiveColorTT;
+pub use AzStyleScrollbarThumbActiveColorTT as AzStyleScrollbarThumbActiveColor;
+
+/// Re-export of rust-allocated (stack based) `StyleWindowDragRegion` struct
+pub use azul_impl::css::StyleWindowDragRegion as AzStyleWindowDragRegionTT;
+pub use AzStyleWindowDragRegionTT as AzStyleWindowDragRegion;
+
+/// Re-export of rust-allocated (stack based) `StyleCaretAnimationDuration` struct
+pub use azul_impl::css::StyleCaretAnimationDuration as AzStyleCaretAnimationDurationTT;
+pub use AzStyleCaretAnimationDurationTT as AzStyleCaretAnimationDuration;
+
+/// Re-export of rust-allocated (stack based) `StyleScrollPadding` struct
+pub use azul_impl::css::StyleScrollPadding as AzStyleScrollPaddingTT;
+pub use AzStyleScrollPaddingTT as AzStyleScrollPadding;
+
+/// Re-export of rust-allocated (stack based) `StyleContent` struct
+pub use azul_impl::css::StyleContent as AzStyleContentTT;
+pub use AzStyleContentTT as AzStyleContent;
+/// Destructor: Takes ownership of the `StyleContent` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleContent_delete(object: &mut AzStyleContent) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Represents a `counter-reset` attribute: `counter-reset: name [<integer>]`
+pub use azul_impl::css::StyleCounterReset as AzStyleCounterResetTT;
+pub use AzStyleCounterResetTT as AzStyleCounterReset;
+/// Destructor: Takes ownership of the `StyleCounterReset` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleCounterReset_delete(object: &mut AzStyleCounterReset) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Represents a `counter-increment` attribute: `counter-increment: name [<integer>]`
+pub use azul_impl::css::StyleCounterIncrement as AzStyleCounterIncrementTT;
+pub use AzStyleCounterIncrementTT as AzStyleCounterIncrement;
+/// Destructor: Takes ownership of the `StyleCounterIncrement` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleCounterIncrement_delete(object: &mut AzStyleCounterIncrement) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `StyleCursor` struct
 pub use azul_impl::css::StyleCursor as AzStyleCursorTT;
 pub use AzStyleCursorTT as AzStyleCursor;
+/// Destructor: Takes ownership of the `StyleCursor` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleCursor_delete(object: &mut AzStyleCursor) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Custom cursor image, the hotspot is in pixels from the top left of the image
+pub use azul_impl::css::StyleCursorImage as AzStyleCursorImageTT;
+pub use AzStyleCursorImageTT as AzStyleCursorImage;
+/// Destructor: Takes ownership of the `StyleCursorImage` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleCursorImage_delete(object: &mut AzStyleCursorImage) {  unsafe { core::ptr::drop_in_place(object); } }
 
 /// Re-export of rust-allocated (stack based) `StyleFontFamily` struct
 pub use azul_impl::css::StyleFontFamily as AzStyleFontFamilyTT;
@@ -1523,6 +1981,14 @@ pub use AzStylePerspectiveOriginTT as AzStylePerspectiveOrigin;
 pub use azul_impl::css::StyleBackfaceVisibility as AzStyleBackfaceVisibilityTT;
 pub use AzStyleBackfaceVisibilityTT as AzStyleBackfaceVisibility;
 
+/// Distance between the z = 0 plane and the viewer, applied to the children of the node
+pub use azul_impl::css::StylePerspective as AzStylePerspectiveTT;
+pub use AzStylePerspectiveTT as AzStylePerspective;
+
+/// Whether the children of a node are flattened into its plane or positioned in the 3D space
+pub use azul_impl::css::StyleTransformStyle as AzStyleTransformStyleTT;
+pub use AzStyleTransformStyleTT as AzStyleTransformStyle;
+
 /// Re-export of rust-allocated (stack based) `StyleTransform` struct
 pub use azul_impl::css::StyleTransform as AzStyleTransformTT;
 pub use AzStyleTransformTT as AzStyleTransform;
@@ -1563,6 +2029,10 @@ pub use AzStyleTransformSkew2DTT as AzStyleTransformSkew2D;
 pub use azul_impl::css::StyleTextAlign as AzStyleTextAlignTT;
 pub use AzStyleTextAlignTT as AzStyleTextAlign;
 
+/// Whether words may be hyphenated when a line is broken inside of them
+pub use azul_impl::css::StyleHyphens as AzStyleHyphensTT;
+pub use AzStyleHyphensTT as AzStyleHyphens;
+
 /// Re-export of rust-allocated (stack based) `StyleTextColor` struct
 pub use azul_impl::css::StyleTextColor as AzStyleTextColorTT;
 pub use AzStyleTextColorTT as AzStyleTextColor;
@@ -1701,6 +2171,56 @@ pub use AzScrollbarStyleValueTT as AzScrollbarStyleValue;
 /// Destructor: Takes ownership of the `ScrollbarStyleValue` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzScrollbarStyleValue_delete(object: &mut AzScrollbarStyleValue) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `StyleScrollbarWidthValue` struct
+pub use azul_impl::css::StyleScrollbarWidthValue as AzStyleScrollbarWidthValueTT;
+pub use AzStyleScrollbarWidthValueTT as AzStyleScrollbarWidthValue;
+
+/// Re-export of rust-allocated (stack based) `StyleScrollbarColorValue` struct
+pub use azul_impl::css::StyleScrollbarColorValue as AzStyleScrollbarColorValueTT;
+pub use AzStyleScrollbarColorValueTT as AzStyleScrollbarColorValue;
+
+/// Re-export of rust-allocated (stack based) `StyleScrollbarThumbHoverColorValue` struct
+pub use azul_impl::css::StyleScrollbarThumbHoverColorValue as AzStyleScrollbarThumbHoverColorValueTT;
+pub use AzStyleScrollbarThumbHoverColorValueTT as AzStyleScrollbarThumbHoverColorValue;
+
+/// Re-export of rust-allocated (stack based) `StyleScrollbarThumbActiveColorValue` struct
+pub use azul_impl::css::StyleScrollbarThumbActiveColorValue as AzStyleScrollbarThumbActiveColorValueTT;
+pub use AzStyleScrollbarThumbActiveColorValueTT as AzStyleScrollbarThumbActiveColorValue;
+
+/// Re-export of rust-allocated (stack based) `StyleWindowDragRegionValue` struct
+pub use azul_impl::css::StyleWindowDragRegionValue as AzStyleWindowDragRegionValueTT;
+pub use AzStyleWindowDragRegionValueTT as AzStyleWindowDragRegionValue;
+
+/// Re-export of rust-allocated (stack based) `StyleCaretAnimationDurationValue` struct
+pub use azul_impl::css::StyleCaretAnimationDurationValue as AzStyleCaretAnimationDurationValueTT;
+pub use AzStyleCaretAnimationDurationValueTT as AzStyleCaretAnimationDurationValue;
+
+/// Re-export of rust-allocated (stack based) `StyleScrollPaddingValue` struct
+pub use azul_impl::css::StyleScrollPaddingValue as AzStyleScrollPaddingValueTT;
+pub use AzStyleScrollPaddingValueTT as AzStyleScrollPaddingValue;
+
+/// Re-export of rust-allocated (stack based) `StyleContentValue` struct
+pub use azul_impl::css::StyleContentValue as AzStyleContentValueTT;
+pub use AzStyleContentValueTT as AzStyleContentValue;
+/// Destructor: Takes ownership of the `StyleContentValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleContentValue_delete(object: &mut AzStyleContentValue) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `StyleCounterResetValue` struct
+pub use azul_impl::css::StyleCounterResetValue as AzStyleCounterResetValueTT;
+pub use AzStyleCounterResetValueTT as AzStyleCounterResetValue;
+/// Destructor: Takes ownership of the `StyleCounterResetValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleCounterResetValue_delete(object: &mut AzStyleCounterResetValue) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `StyleCounterIncrementValue` struct
+pub use azul_impl::css::StyleCounterIncrementValue as AzStyleCounterIncrementValueTT;
+pub use AzStyleCounterIncrementValueTT as AzStyleCounterIncrementValue;
+/// Destructor: Takes ownership of the `StyleCounterIncrementValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleCounterIncrementValue_delete(object: &mut AzStyleCounterIncrementValue) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `StyleOverflowAnchorValue` struct
+pub use azul_impl::css::StyleOverflowAnchorValue as AzStyleOverflowAnchorValueTT;
+pub use AzStyleOverflowAnchorValueTT as AzStyleOverflowAnchorValue;
+
 /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
 pub use azul_impl::css::StyleBackgroundContentVecValue as AzStyleBackgroundContentVecValueTT;
 pub use AzStyleBackgroundContentVecValueTT as AzStyleBackgroundContentVecValue;
@@ -1792,6 +2312,8 @@ pub use AzLayoutBorderTopWidthValueTT as AzLayoutBorderTopWidthValue;
 /// Re-export of rust-allocated (stack based) `StyleCursorValue` struct
 pub use azul_impl::css::StyleCursorValue as AzStyleCursorValueTT;
 pub use AzStyleCursorValueTT as AzStyleCursorValue;
+/// Destructor: Takes ownership of the `StyleCursorValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleCursorValue_delete(object: &mut AzStyleCursorValue) {  unsafe { core::ptr::drop_in_place(object); } }
 
 /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecValue` struct
 pub use azul_impl::css::StyleFontFamilyVecValue as AzStyleFontFamilyVecValueTT;
@@ -1815,6 +2337,10 @@ pub use AzStyleLineHeightValueTT as AzStyleLineHeightValue;
 pub use azul_impl::css::StyleTabWidthValue as AzStyleTabWidthValueTT;
 pub use AzStyleTabWidthValueTT as AzStyleTabWidthValue;
 
+/// Re-export of rust-allocated (stack based) `StyleHyphensValue` struct
+pub use azul_impl::css::StyleHyphensValue as AzStyleHyphensValueTT;
+pub use AzStyleHyphensValueTT as AzStyleHyphensValue;
+
 /// Re-export of rust-allocated (stack based) `StyleTextAlignValue` struct
 pub use azul_impl::css::StyleTextAlignValue as AzStyleTextAlignValueTT;
 pub use AzStyleTextAlignValueTT as AzStyleTextAlignValue;
@@ -1849,6 +2375,14 @@ pub use AzStylePerspectiveOriginValueTT as AzStylePerspectiveOriginValue;
 pub use azul_impl::css::StyleBackfaceVisibilityValue as AzStyleBackfaceVisibilityValueTT;
 pub use AzStyleBackfaceVisibilityValueTT as AzStyleBackfaceVisibilityValue;
 
+/// Re-export of rust-allocated (stack based) `StylePerspectiveValue` struct
+pub use azul_impl::css::StylePerspectiveValue as AzStylePerspectiveValueTT;
+pub use AzStylePerspectiveValueTT as AzStylePerspectiveValue;
+
+/// Re-export of rust-allocated (stack based) `StyleTransformStyleValue` struct
+pub use azul_impl::css::StyleTransformStyleValue as AzStyleTransformStyleValueTT;
+pub use AzStyleTransformStyleValueTT as AzStyleTransformStyleValue;
+
 /// Re-export of rust-allocated (stack based) `StyleMixBlendModeValue` struct
 pub use azul_impl::css::StyleMixBlendModeValue as AzStyleMixBlendModeValueTT;
 pub use AzStyleMixBlendModeValueTT as AzStyleMixBlendModeValue;
@@ -2053,6 +2587,22 @@ pub use AzTextInputTT as AzTextInput;
 #[no_mangle] pub extern "C" fn AzTextInput_setOnFocusLost(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnFocusLostCallbackType) { textinput.set_on_focus_lost(data, callback) }
 /// Equivalent to the Rust `TextInput::with_on_focus_lost()` function.
 #[no_mangle] pub extern "C" fn AzTextInput_withOnFocusLost(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnFocusLostCallbackType) -> AzTextInput { let mut textinput = textinput.swap_with_default(); textinput.set_on_focus_lost(data, callback); textinput }
+/// Equivalent to the Rust `TextInput::set_caret_animation()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_setCaretAnimation(textinput: &mut AzTextInput, caret_animation: AzCaretAnimation) { textinput.set_caret_animation(caret_animation) }
+/// Equivalent to the Rust `TextInput::with_caret_animation()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_withCaretAnimation(textinput: &mut AzTextInput, caret_animation: AzCaretAnimation) -> AzTextInput { let mut textinput = textinput.swap_with_default(); textinput.set_caret_animation(caret_animation); textinput }
+/// Underlines misspelled words, the text is checked on a background thread whenever it changes. Does nothing if there is no spellchecker for the language (i.e. "en-US").
+#[no_mangle] pub extern "C" fn AzTextInput_setSpellcheck(textinput: &mut AzTextInput, language: AzString) { textinput.set_spellcheck(language) }
+/// Equivalent to the Rust `TextInput::with_spellcheck()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_withSpellcheck(textinput: &mut AzTextInput, language: AzString) -> AzTextInput { let mut textinput = textinput.swap_with_default(); textinput.set_spellcheck(language); textinput }
+/// Equivalent to the Rust `TextInput::set_on_spelling_suggestions()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_setOnSpellingSuggestions(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnSpellingSuggestionsCallbackType) { textinput.set_on_spelling_suggestions(data, callback) }
+/// Equivalent to the Rust `TextInput::with_on_spelling_suggestions()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_withOnSpellingSuggestions(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnSpellingSuggestionsCallbackType) -> AzTextInput { let mut textinput = textinput.swap_with_default(); textinput.set_on_spelling_suggestions(data, callback); textinput }
+/// Secure text entry for passwords: renders the text as bullets, disables the spellchecker and the IME while the input is focused and zeroizes the text when it is freed. NOTE: the IME is only disabled on Windows - the X11 shell doesn't use an input method yet and the macOS shell doesn't track the focused node yet.
+#[no_mangle] pub extern "C" fn AzTextInput_setSecure(textinput: &mut AzTextInput, secure: bool) { textinput.set_secure(secure) }
+/// Equivalent to the Rust `TextInput::with_secure()` function.
+#[no_mangle] pub extern "C" fn AzTextInput_withSecure(textinput: &mut AzTextInput, secure: bool) -> AzTextInput { let mut textinput = textinput.swap_with_default(); textinput.set_secure(secure); textinput }
 /// Equivalent to the Rust `TextInput::set_placeholder_style()` function.
 #[no_mangle] pub extern "C" fn AzTextInput_setPlaceholderStyle(textinput: &mut AzTextInput, placeholder_style: AzNodeDataInlineCssPropertyVec) { textinput.set_placeholder_style(placeholder_style) }
 /// Equivalent to the Rust `TextInput::with_placeholder_style()` function.
@@ -2125,6 +2675,23 @@ pub use crate::widgets::text_input::TextInputOnFocusLostCallback as AzTextInputO
 pub use AzTextInputOnFocusLostCallbackTT as AzTextInputOnFocusLostCallback;
 
 pub type AzTextInputOnFocusLostCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTextInputState) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TextInputOnSpellingSuggestions` struct
+pub use crate::widgets::text_input::TextInputOnSpellingSuggestions as AzTextInputOnSpellingSuggestionsTT;
+pub use AzTextInputOnSpellingSuggestionsTT as AzTextInputOnSpellingSuggestions;
+/// Destructor: Takes ownership of the `TextInputOnSpellingSuggestions` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextInputOnSpellingSuggestions_delete(object: &mut AzTextInputOnSpellingSuggestions) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TextInputOnSpellingSuggestionsCallback` struct
+pub use crate::widgets::text_input::TextInputOnSpellingSuggestionsCallback as AzTextInputOnSpellingSuggestionsCallbackTT;
+pub use AzTextInputOnSpellingSuggestionsCallbackTT as AzTextInputOnSpellingSuggestionsCallback;
+
+pub type AzTextInputOnSpellingSuggestionsCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTextInputState, &AzTextInputSpellingSuggestions) -> AzUpdate;
+/// Misspelled word that was right-clicked
+pub use crate::widgets::text_input::TextInputSpellingSuggestions as AzTextInputSpellingSuggestionsTT;
+pub use AzTextInputSpellingSuggestionsTT as AzTextInputSpellingSuggestions;
+/// Destructor: Takes ownership of the `TextInputSpellingSuggestions` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextInputSpellingSuggestions_delete(object: &mut AzTextInputSpellingSuggestions) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OnTextInputReturn` struct
 pub use crate::widgets::text_input::OnTextInputReturn as AzOnTextInputReturnTT;
 pub use AzOnTextInputReturnTT as AzOnTextInputReturn;
@@ -2138,7 +2705,7 @@ pub use crate::widgets::number_input::NumberInput as AzNumberInputTT;
 pub use AzNumberInputTT as AzNumberInput;
 /// Creates a new `NumberInput` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `NumberInput::new()` constructor.
-#[no_mangle] pub extern "C" fn AzNumberInput_new(number: f32) -> AzNumberInput { AzNumberInput::new(number) }
+#[no_mangle] pub extern "C" fn AzNumberInput_new(number: f64) -> AzNumberInput { AzNumberInput::new(number) }
 /// Equivalent to the Rust `NumberInput::set_on_text_input()` function.
 #[no_mangle] pub extern "C" fn AzNumberInput_setOnTextInput(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzTextInputOnTextInputCallbackType) { numberinput.set_on_text_input(data, callback) }
 /// Equivalent to the Rust `NumberInput::with_on_text_input()` function.
@@ -2167,6 +2734,34 @@ pub use AzNumberInputTT as AzNumberInput;
 #[no_mangle] pub extern "C" fn AzNumberInput_setOnValueChange(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzNumberInputOnValueChangeCallbackType) { numberinput.set_on_value_change(data, callback) }
 /// Equivalent to the Rust `NumberInput::with_on_value_change()` function.
 #[no_mangle] pub extern "C" fn AzNumberInput_withOnValueChange(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzNumberInputOnValueChangeCallbackType) -> AzNumberInput { let mut numberinput = numberinput.swap_with_default(); numberinput.set_on_value_change(data, callback); numberinput }
+/// Equivalent to the Rust `NumberInput::set_spinner_button_style()` function.
+#[no_mangle] pub extern "C" fn AzNumberInput_setSpinnerButtonStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) { numberinput.set_spinner_button_style(style) }
+/// Equivalent to the Rust `NumberInput::with_spinner_button_style()` function.
+#[no_mangle] pub extern "C" fn AzNumberInput_withSpinnerButtonStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) -> AzNumberInput { let mut numberinput = numberinput.swap_with_default(); numberinput.set_spinner_button_style(style); numberinput }
+/// Sets the properties that are applied to the text input container while the input is not a valid number (default: red border)
+#[no_mangle] pub extern "C" fn AzNumberInput_setInvalidStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) { numberinput.set_invalid_style(style) }
+/// Equivalent to the Rust `NumberInput::with_invalid_style()` function.
+#[no_mangle] pub extern "C" fn AzNumberInput_withInvalidStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) -> AzNumberInput { let mut numberinput = numberinput.swap_with_default(); numberinput.set_invalid_style(style); numberinput }
+/// Equivalent to the Rust `NumberInput::set_mode()` function.
+#[no_mangle] pub extern "C" fn AzNumberInput_setMode(numberinput: &mut AzNumberInput, mode: AzNumberInputMode) { numberinput.set_mode(mode) }
+/// Equivalent to the Rust `NumberInput::with_mode()` function.
+#[no_mangle] pub extern "C" fn AzNumberInput_withMode(numberinput: &mut AzNumberInput, mode: AzNumberInputMode) -> AzNumberInput { let mut numberinput = numberinput.swap_with_default(); numberinput.set_mode(mode); numberinput }
+/// Equivalent to the Rust `NumberInput::set_min()` function.
+#[no_mangle] pub extern "C" fn AzNumberInput_setMin(numberinput: &mut AzNumberInput, min: f64) { numberinput.set_min(min) }
+/// Equivalent to the Rust `NumberInput::with_min()` function.
+#[no_mangle] pub extern "C" fn AzNumberInput_withMin(numberinput: &mut AzNumberInput, min: f64) -> AzNumberInput { let mut numberinput = numberinput.swap_with_default(); numberinput.set_min(min); numberinput }
+/// Equivalent to the Rust `NumberInput::set_max()` function.
+#[no_mangle] pub extern "C" fn AzNumberInput_setMax(numberinput: &mut AzNumberInput, max: f64) { numberinput.set_max(max) }
+/// Equivalent to the Rust `NumberInput::with_max()` function.
+#[no_mangle] pub extern "C" fn AzNumberInput_withMax(numberinput: &mut AzNumberInput, max: f64) -> AzNumberInput { let mut numberinput = numberinput.swap_with_default(); numberinput.set_max(max); numberinput }
+/// Sets the amount that the spinner buttons and the mouse wheel add or subtract
+#[no_mangle] pub extern "C" fn AzNumberInput_setStep(numberinput: &mut AzNumberInput, step: f64) { numberinput.set_step(step) }
+/// Equivalent to the Rust `NumberInput::with_step()` function.
+#[no_mangle] pub extern "C" fn AzNumberInput_withStep(numberinput: &mut AzNumberInput, step: f64) -> AzNumberInput { let mut numberinput = numberinput.swap_with_default(); numberinput.set_step(step); numberinput }
+/// Sets the character used to display the decimal point (i.e. `,` for German locales)
+#[no_mangle] pub extern "C" fn AzNumberInput_setDecimalSeparator(numberinput: &mut AzNumberInput, decimal_separator: u32) { numberinput.set_decimal_separator(decimal_separator) }
+/// Equivalent to the Rust `NumberInput::with_decimal_separator()` function.
+#[no_mangle] pub extern "C" fn AzNumberInput_withDecimalSeparator(numberinput: &mut AzNumberInput, decimal_separator: u32) -> AzNumberInput { let mut numberinput = numberinput.swap_with_default(); numberinput.set_decimal_separator(decimal_separator); numberinput }
 /// Equivalent to the Rust `NumberInput::dom()` function.
 #[no_mangle] pub extern "C" fn AzNumberInput_dom(numberinput: &mut AzNumberInput) -> AzDom { numberinput.swap_with_default().dom() }
 /// Destructor: Takes ownership of the `NumberInput` pointer and deletes it.
@@ -2178,6 +2773,10 @@ pub use AzNumberInputStateWrapperTT as AzNumberInputStateWrapper;
 /// Destructor: Takes ownership of the `NumberInputStateWrapper` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzNumberInputStateWrapper_delete(object: &mut AzNumberInputStateWrapper) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Whether the `NumberInput` accepts any number or only integers
+pub use crate::widgets::number_input::NumberInputMode as AzNumberInputModeTT;
+pub use AzNumberInputModeTT as AzNumberInputMode;
+
 /// Re-export of rust-allocated (stack based) `NumberInputState` struct
 pub use crate::widgets::number_input::NumberInputState as AzNumberInputStateTT;
 pub use AzNumberInputStateTT as AzNumberInputState;
@@ -2584,6 +3183,73 @@ pub use AzDropDownOnChoiceChangeTT as AzDropDownOnChoiceChange;
 /// Destructor: Takes ownership of the `DropDownOnChoiceChange` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzDropDownOnChoiceChange_delete(object: &mut AzDropDownOnChoiceChange) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Manages the queue of toast / snackbar notifications of a window. Store it in the application data and insert `Toasts::dom()` as the last child of the root node
+pub use crate::widgets::toasts::Toasts as AzToastsTT;
+pub use AzToastsTT as AzToasts;
+/// Creates a new `Toasts` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Toasts::new()` constructor.
+#[no_mangle] pub extern "C" fn AzToasts_new() -> AzToasts { AzToasts::new() }
+/// Queues the toast and starts the timer that animates it in and out. The DOM is refreshed automatically once the toast becomes visible
+#[no_mangle] pub extern "C" fn AzToasts_show(toasts: &mut AzToasts, info: &mut AzCallbackInfo, toast: AzToast) -> AzToastId { toasts.show(info, toast) }
+/// Fades out the toast (or removes it from the queue if it isn't visible yet). Returns false if the toast doesn't exist anymore
+#[no_mangle] pub extern "C" fn AzToasts_dismiss(toasts: &mut AzToasts, info: &mut AzCallbackInfo, toast_id: AzToastId) -> bool { toasts.dismiss(info, toast_id) }
+/// Sets how many toasts can be visible at the same time, all other toasts are queued
+#[no_mangle] pub extern "C" fn AzToasts_setMaxVisible(toasts: &mut AzToasts, max_visible: usize) { toasts.set_max_visible(max_visible) }
+/// Equivalent to the Rust `Toasts::with_max_visible()` function.
+#[no_mangle] pub extern "C" fn AzToasts_withMaxVisible(toasts: &mut AzToasts, max_visible: usize) -> AzToasts { let mut toasts = toasts.swap_with_default(); toasts.set_max_visible(max_visible); toasts }
+/// Equivalent to the Rust `Toasts::set_layer_style()` function.
+#[no_mangle] pub extern "C" fn AzToasts_setLayerStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) { toasts.set_layer_style(style) }
+/// Equivalent to the Rust `Toasts::with_layer_style()` function.
+#[no_mangle] pub extern "C" fn AzToasts_withLayerStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) -> AzToasts { let mut toasts = toasts.swap_with_default(); toasts.set_layer_style(style); toasts }
+/// Equivalent to the Rust `Toasts::set_toast_style()` function.
+#[no_mangle] pub extern "C" fn AzToasts_setToastStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) { toasts.set_toast_style(style) }
+/// Equivalent to the Rust `Toasts::with_toast_style()` function.
+#[no_mangle] pub extern "C" fn AzToasts_withToastStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) -> AzToasts { let mut toasts = toasts.swap_with_default(); toasts.set_toast_style(style); toasts }
+/// Equivalent to the Rust `Toasts::set_label_style()` function.
+#[no_mangle] pub extern "C" fn AzToasts_setLabelStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) { toasts.set_label_style(style) }
+/// Equivalent to the Rust `Toasts::with_label_style()` function.
+#[no_mangle] pub extern "C" fn AzToasts_withLabelStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) -> AzToasts { let mut toasts = toasts.swap_with_default(); toasts.set_label_style(style); toasts }
+/// Equivalent to the Rust `Toasts::set_action_style()` function.
+#[no_mangle] pub extern "C" fn AzToasts_setActionStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) { toasts.set_action_style(style) }
+/// Equivalent to the Rust `Toasts::with_action_style()` function.
+#[no_mangle] pub extern "C" fn AzToasts_withActionStyle(toasts: &mut AzToasts, style: AzNodeDataInlineCssPropertyVec) -> AzToasts { let mut toasts = toasts.swap_with_default(); toasts.set_action_style(style); toasts }
+/// Equivalent to the Rust `Toasts::dom()` function.
+#[no_mangle] pub extern "C" fn AzToasts_dom(toasts: &mut AzToasts) -> AzDom { toasts.dom() }
+/// Destructor: Takes ownership of the `Toasts` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzToasts_delete(object: &mut AzToasts) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Single notification message
+pub use crate::widgets::toasts::Toast as AzToastTT;
+pub use AzToastTT as AzToast;
+/// Creates a new `Toast` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Toast::new()` constructor.
+#[no_mangle] pub extern "C" fn AzToast_new(message: AzString) -> AzToast { AzToast::new(message) }
+/// Equivalent to the Rust `Toast::set_duration()` function.
+#[no_mangle] pub extern "C" fn AzToast_setDuration(toast: &mut AzToast, duration: AzOptionDuration) { toast.set_duration(duration) }
+/// Equivalent to the Rust `Toast::with_duration()` function.
+#[no_mangle] pub extern "C" fn AzToast_withDuration(toast: &mut AzToast, duration: AzOptionDuration) -> AzToast { let mut toast = toast.swap_with_default(); toast.set_duration(duration); toast }
+/// Equivalent to the Rust `Toast::set_action()` function.
+#[no_mangle] pub extern "C" fn AzToast_setAction(toast: &mut AzToast, label: AzString, data: AzRefAny, callback: AzToastOnActionCallbackType) { toast.set_action(label, data, callback) }
+/// Equivalent to the Rust `Toast::with_action()` function.
+#[no_mangle] pub extern "C" fn AzToast_withAction(toast: &mut AzToast, label: AzString, data: AzRefAny, callback: AzToastOnActionCallbackType) -> AzToast { let mut toast = toast.swap_with_default(); toast.set_action(label, data, callback); toast }
+/// Destructor: Takes ownership of the `Toast` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzToast_delete(object: &mut AzToast) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Identifies a toast that was shown with `Toasts::show`
+pub use crate::widgets::toasts::ToastId as AzToastIdTT;
+pub use AzToastIdTT as AzToastId;
+
+/// Re-export of rust-allocated (stack based) `ToastOnAction` struct
+pub use crate::widgets::toasts::ToastOnAction as AzToastOnActionTT;
+pub use AzToastOnActionTT as AzToastOnAction;
+/// Destructor: Takes ownership of the `ToastOnAction` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzToastOnAction_delete(object: &mut AzToastOnAction) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `ToastOnActionCallback` struct
+pub use crate::widgets::toasts::ToastOnActionCallback as AzToastOnActionCallbackTT;
+pub use AzToastOnActionCallbackTT as AzToastOnActionCallback;
+
+pub type AzToastOnActionCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `NodeHierarchyItem` struct
 pub use azul_impl::styled_dom::NodeHierarchyItem as AzNodeHierarchyItemTT;
 pub use AzNodeHierarchyItemTT as AzNodeHierarchyItem;
@@ -2704,6 +3370,8 @@ pub use azul_impl::gl::GlContextPtr as AzGlTT;
 pub use AzGlTT as AzGl;
 /// Equivalent to the Rust `Gl::get_type()` function.
 #[no_mangle] pub extern "C" fn AzGl_getType(gl: &AzGl) -> AzGlType { gl.get_type() }
+/// Returns the driver workarounds that were applied to the context
+#[no_mangle] pub extern "C" fn AzGl_getQuirks(gl: &AzGl) -> AzGlQuirks { gl.get_quirks() }
 /// Equivalent to the Rust `Gl::buffer_data_untyped()` function.
 #[no_mangle] pub extern "C" fn AzGl_bufferDataUntyped(gl: &AzGl, target: u32, size: isize, data: AzGlVoidPtrConst, usage: u32) { gl.buffer_data_untyped(target, size, data, usage) }
 /// Equivalent to the Rust `Gl::buffer_sub_data_untyped()` function.
@@ -3149,6 +3817,28 @@ pub use AzGlTT as AzGl;
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzGl_deepCopy(object: &AzGl) -> AzGl { object.clone() }
 
+/// Workarounds for driver bugs, applied when the renderer is created
+pub use azul_impl::gl::GlQuirks as AzGlQuirksTT;
+pub use AzGlQuirksTT as AzGlQuirks;
+
+/// Enables / disables workarounds for all drivers that match the rule. The patterns are matched case-insensitively against substrings of the `GL_VENDOR`, `GL_RENDERER` and `GL_VERSION` strings, a `None` pattern matches every driver. The `version` pattern can also compare version numbers: `"Mesa < 21.2"` matches if `GL_VERSION` contains "Mesa" and the number after it is lower than 21.2, without a prefix (`">= 4.5"`) the first number of `GL_VERSION` is compared. Supported operators are `<`, `<=`, `>`, `>=` and `==`.
+pub use azul_impl::gl::GlQuirkRule as AzGlQuirkRuleTT;
+pub use AzGlQuirkRuleTT as AzGlQuirkRule;
+/// Creates a rule that matches every driver and doesn't change anything
+#[no_mangle] pub extern "C" fn AzGlQuirkRule_new() -> AzGlQuirkRule { AzGlQuirkRule::new() }
+/// Equivalent to the Rust `GlQuirkRule::with_vendor()` function.
+#[no_mangle] pub extern "C" fn AzGlQuirkRule_withVendor(glquirkrule: AzGlQuirkRule, vendor: AzString) -> AzGlQuirkRule { glquirkrule.with_vendor(vendor) }
+/// Equivalent to the Rust `GlQuirkRule::with_renderer()` function.
+#[no_mangle] pub extern "C" fn AzGlQuirkRule_withRenderer(glquirkrule: AzGlQuirkRule, renderer: AzString) -> AzGlQuirkRule { glquirkrule.with_renderer(renderer) }
+/// Equivalent to the Rust `GlQuirkRule::with_version()` function.
+#[no_mangle] pub extern "C" fn AzGlQuirkRule_withVersion(glquirkrule: AzGlQuirkRule, version: AzString) -> AzGlQuirkRule { glquirkrule.with_version(version) }
+/// Equivalent to the Rust `GlQuirkRule::with_enable()` function.
+#[no_mangle] pub extern "C" fn AzGlQuirkRule_withEnable(glquirkrule: AzGlQuirkRule, enable: AzGlQuirks) -> AzGlQuirkRule { glquirkrule.with_enable(enable) }
+/// Equivalent to the Rust `GlQuirkRule::with_disable()` function.
+#[no_mangle] pub extern "C" fn AzGlQuirkRule_withDisable(glquirkrule: AzGlQuirkRule, disable: AzGlQuirks) -> AzGlQuirkRule { glquirkrule.with_disable(disable) }
+/// Destructor: Takes ownership of the `GlQuirkRule` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzGlQuirkRule_delete(object: &mut AzGlQuirkRule) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `GlShaderPrecisionFormatReturn` struct
 pub use azul_impl::gl::GlShaderPrecisionFormatReturn as AzGlShaderPrecisionFormatReturnTT;
 pub use AzGlShaderPrecisionFormatReturnTT as AzGlShaderPrecisionFormatReturn;
@@ -4040,6 +4730,18 @@ pub use AzThreadReceiverTT as AzThreadReceiver;
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzThreadReceiver_deepCopy(object: &AzThreadReceiver) -> AzThreadReceiver { object.clone() }
 
+/// Handle to wake up the event loop from other threads, created by `App::create_event_loop_proxy()`. NOTE: user events are currently only delivered on Windows, the X11 event loop can't invoke callbacks or run timers yet and rejects them.
+pub use azul_impl::task::EventLoopProxy as AzEventLoopProxyTT;
+pub use AzEventLoopProxyTT as AzEventLoopProxy;
+/// Wakes up the event loop. Returns `false` if the event loop is not running yet (it will then wake up once it starts).
+#[no_mangle] pub extern "C" fn AzEventLoopProxy_wake(eventloopproxy: &AzEventLoopProxy) -> bool { eventloopproxy.wake() }
+/// Sends the data to all windows, which invoke their `On::UserEvent` callbacks (see `CallbackInfo::get_user_event()`). Returns `false` and drops the data if the event loop can't deliver user events (currently on X11).
+#[no_mangle] pub extern "C" fn AzEventLoopProxy_sendUserEvent(eventloopproxy: &AzEventLoopProxy, data: AzRefAny) -> bool { eventloopproxy.send_user_event(data) }
+/// Destructor: Takes ownership of the `EventLoopProxy` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzEventLoopProxy_delete(object: &mut AzEventLoopProxy) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+/// Clones the object
+#[no_mangle] pub extern "C" fn AzEventLoopProxy_deepCopy(object: &AzEventLoopProxy) -> AzEventLoopProxy { object.clone() }
+
 /// Re-export of rust-allocated (stack based) `ThreadSendMsg` struct
 pub use azul_impl::task::ThreadSendMsg as AzThreadSendMsgTT;
 pub use AzThreadSendMsgTT as AzThreadSendMsg;
@@ -4068,6 +4770,26 @@ pub type AzGetSystemTimeFnType = extern "C" fn() -> AzInstant;
 pub use azul_impl::task::GetSystemTimeCallback as AzGetSystemTimeFnTT;
 pub use AzGetSystemTimeFnTT as AzGetSystemTimeFn;
 
+pub type AzGetSystemIdleTimeFnType = extern "C" fn() -> AzOptionDuration;
+/// Get the time since the last user input on the entire system, `None` if the platform does not support querying the idle time
+pub use azul_impl::task::GetSystemIdleTimeCallback as AzGetSystemIdleTimeFnTT;
+pub use AzGetSystemIdleTimeFnTT as AzGetSystemIdleTimeFn;
+
+pub type AzGetLogLinesFnType = extern "C" fn() -> AzStringVec;
+/// Returns the most recent lines of the log ring buffer (oldest first)
+pub use azul_impl::task::GetLogLinesCallback as AzGetLogLinesFnTT;
+pub use AzGetLogLinesFnTT as AzGetLogLinesFn;
+
+pub type AzKeycodeToCharFnType = extern "C" fn(AzVirtualKeyCode, AzKeyboardModifiers) -> AzOptionChar;
+/// Returns the character that the key produces with the given modifiers in the current keyboard layout of the operating system
+pub use azul_impl::task::KeycodeToCharCallback as AzKeycodeToCharFnTT;
+pub use AzKeycodeToCharFnTT as AzKeycodeToCharFn;
+
+pub type AzSetLogLevelFnType = extern "C" fn(AzString, AzAppLogLevel);
+/// Sets the log level of a subsystem at runtime
+pub use azul_impl::task::SetLogLevelCallback as AzSetLogLevelFnTT;
+pub use AzSetLogLevelFnTT as AzSetLogLevelFn;
+
 pub type AzCheckThreadFinishedFnType = extern "C" fn(&c_void) -> bool;
 /// Function called to check if the thread has finished
 pub use azul_impl::task::CheckThreadFinishedCallback as AzCheckThreadFinishedFnTT;
@@ -4264,12 +4986,24 @@ pub use AzInlineWordVecTT as AzInlineWordVec;
 /// Destructor: Takes ownership of the `InlineWordVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzInlineWordVec_delete(object: &mut AzInlineWordVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<PaintPrimitive>`
+pub use azul_impl::callbacks::PaintPrimitiveVec as AzPaintPrimitiveVecTT;
+pub use AzPaintPrimitiveVecTT as AzPaintPrimitiveVec;
+/// Destructor: Takes ownership of the `PaintPrimitiveVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzPaintPrimitiveVec_delete(object: &mut AzPaintPrimitiveVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `Vec<InlineGlyph>`
 pub use azul_impl::callbacks::InlineGlyphVec as AzInlineGlyphVecTT;
 pub use AzInlineGlyphVecTT as AzInlineGlyphVec;
 /// Destructor: Takes ownership of the `InlineGlyphVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzInlineGlyphVec_delete(object: &mut AzInlineGlyphVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<TextUnderline>`
+pub use azul_impl::callbacks::TextUnderlineVec as AzTextUnderlineVecTT;
+pub use AzTextUnderlineVecTT as AzTextUnderlineVec;
+/// Destructor: Takes ownership of the `TextUnderlineVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextUnderlineVec_delete(object: &mut AzTextUnderlineVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `Vec<InlineTextHit>`
 pub use azul_impl::callbacks::InlineTextHitVec as AzInlineTextHitVecTT;
 pub use AzInlineTextHitVecTT as AzInlineTextHitVec;
@@ -4282,6 +5016,12 @@ pub use AzMonitorVecTT as AzMonitorVec;
 /// Destructor: Takes ownership of the `MonitorVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzMonitorVec_delete(object: &mut AzMonitorVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<TouchPoint>`
+pub use azul_core::window::TouchPointVec as AzTouchPointVecTT;
+pub use AzTouchPointVecTT as AzTouchPointVec;
+/// Destructor: Takes ownership of the `TouchPointVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTouchPointVec_delete(object: &mut AzTouchPointVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `Vec<VideoMode>`
 pub use azul_core::window::VideoModeVec as AzVideoModeVecTT;
 pub use AzVideoModeVecTT as AzVideoModeVec;
@@ -4402,6 +5142,18 @@ pub use AzVirtualKeyCodeVecTT as AzVirtualKeyCodeVec;
 /// Destructor: Takes ownership of the `VirtualKeyCodeVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzVirtualKeyCodeVec_delete(object: &mut AzVirtualKeyCodeVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `ShortcutConflict`
+pub use azul_core::window::ShortcutConflictVec as AzShortcutConflictVecTT;
+pub use AzShortcutConflictVecTT as AzShortcutConflictVec;
+/// Destructor: Takes ownership of the `ShortcutConflictVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzShortcutConflictVec_delete(object: &mut AzShortcutConflictVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Shortcut`
+pub use azul_core::window::ShortcutVec as AzShortcutVecTT;
+pub use AzShortcutVecTT as AzShortcutVec;
+/// Destructor: Takes ownership of the `ShortcutVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzShortcutVec_delete(object: &mut AzShortcutVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `CascadeInfo`
 pub use azul_impl::style::CascadeInfoVec as AzCascadeInfoVecTT;
 pub use AzCascadeInfoVecTT as AzCascadeInfoVec;
@@ -4649,11 +5401,21 @@ pub use azul_impl::callbacks::InlineWordVecDestructor as AzInlineWordVecDestruct
 pub use AzInlineWordVecDestructorTT as AzInlineWordVecDestructor;
 
 pub type AzInlineWordVecDestructorType = extern "C" fn(&mut AzInlineWordVec);
+/// Re-export of rust-allocated (stack based) `PaintPrimitiveVecDestructor` struct
+pub use azul_impl::callbacks::PaintPrimitiveVecDestructor as AzPaintPrimitiveVecDestructorTT;
+pub use AzPaintPrimitiveVecDestructorTT as AzPaintPrimitiveVecDestructor;
+
+pub type AzPaintPrimitiveVecDestructorType = extern "C" fn(&mut AzPaintPrimitiveVec);
 /// Re-export of rust-allocated (stack based) `InlineGlyphVecDestructor` struct
 pub use azul_impl::callbacks::InlineGlyphVecDestructor as AzInlineGlyphVecDestructorTT;
 pub use AzInlineGlyphVecDestructorTT as AzInlineGlyphVecDestructor;
 
 pub type AzInlineGlyphVecDestructorType = extern "C" fn(&mut AzInlineGlyphVec);
+/// Re-export of rust-allocated (stack based) `TextUnderlineVecDestructor` struct
+pub use azul_impl::callbacks::TextUnderlineVecDestructor as AzTextUnderlineVecDestructorTT;
+pub use AzTextUnderlineVecDestructorTT as AzTextUnderlineVecDestructor;
+
+pub type AzTextUnderlineVecDestructorType = extern "C" fn(&mut AzTextUnderlineVec);
 /// Re-export of rust-allocated (stack based) `InlineTextHitVecDestructor` struct
 pub use azul_impl::callbacks::InlineTextHitVecDestructor as AzInlineTextHitVecDestructorTT;
 pub use AzInlineTextHitVecDestructorTT as AzInlineTextHitVecDestructor;
@@ -4664,6 +5426,11 @@ pub use azul_core::window::MonitorVecDestructor as AzMonitorVecDestructorTT;
 pub use AzMonitorVecDestructorTT as AzMonitorVecDestructor;
 
 pub type AzMonitorVecDestructorType = extern "C" fn(&mut AzMonitorVec);
+/// Re-export of rust-allocated (stack based) `TouchPointVecDestructor` struct
+pub use azul_core::window::TouchPointVecDestructor as AzTouchPointVecDestructorTT;
+pub use AzTouchPointVecDestructorTT as AzTouchPointVecDestructor;
+
+pub type AzTouchPointVecDestructorType = extern "C" fn(&mut AzTouchPointVec);
 /// Re-export of rust-allocated (stack based) `VideoModeVecDestructor` struct
 pub use azul_core::window::VideoModeVecDestructor as AzVideoModeVecDestructorTT;
 pub use AzVideoModeVecDestructorTT as AzVideoModeVecDestructor;
@@ -4764,6 +5531,16 @@ pub use azul_core::window::VirtualKeyCodeVecDestructor as AzVirtualKeyCodeVecDes
 pub use AzVirtualKeyCodeVecDestructorTT as AzVirtualKeyCodeVecDestructor;
 
 pub type AzVirtualKeyCodeVecDestructorType = extern "C" fn(&mut AzVirtualKeyCodeVec);
+/// Re-export of rust-allocated (stack based) `ShortcutConflictVecDestructor` struct
+pub use azul_core::window::ShortcutConflictVecDestructor as AzShortcutConflictVecDestructorTT;
+pub use AzShortcutConflictVecDestructorTT as AzShortcutConflictVecDestructor;
+
+pub type AzShortcutConflictVecDestructorType = extern "C" fn(&mut AzShortcutConflictVec);
+/// Re-export of rust-allocated (stack based) `ShortcutVecDestructor` struct
+pub use azul_core::window::ShortcutVecDestructor as AzShortcutVecDestructorTT;
+pub use AzShortcutVecDestructorTT as AzShortcutVecDestructor;
+
+pub type AzShortcutVecDestructorType = extern "C" fn(&mut AzShortcutVec);
 /// Re-export of rust-allocated (stack based) `CascadeInfoVecDestructor` struct
 pub use azul_impl::style::CascadeInfoVecDestructor as AzCascadeInfoVecDestructorTT;
 pub use AzCascadeInfoVecDestructorTT as AzCascadeInfoVecDestructor;
@@ -4917,6 +5694,12 @@ pub use AzOptionDropDownOnChoiceChangeTT as AzOptionDropDownOnChoiceChange;
 /// Destructor: Takes ownership of the `OptionDropDownOnChoiceChange` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzOptionDropDownOnChoiceChange_delete(object: &mut AzOptionDropDownOnChoiceChange) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `OptionToastOnAction` struct
+pub use crate::widgets::toasts::OptionToastOnAction as AzOptionToastOnActionTT;
+pub use AzOptionToastOnActionTT as AzOptionToastOnAction;
+/// Destructor: Takes ownership of the `OptionToastOnAction` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionToastOnAction_delete(object: &mut AzOptionToastOnAction) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionResolvedTextLayoutOptions` struct
 pub use azul_impl::ui_solver::OptionResolvedTextLayoutOptions as AzOptionResolvedTextLayoutOptionsTT;
 pub use AzOptionResolvedTextLayoutOptionsTT as AzOptionResolvedTextLayoutOptions;
@@ -5019,6 +5802,12 @@ pub use AzOptionTextInputOnFocusLostTT as AzOptionTextInputOnFocusLost;
 /// Destructor: Takes ownership of the `OptionTextInputOnFocusLost` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzOptionTextInputOnFocusLost_delete(object: &mut AzOptionTextInputOnFocusLost) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `OptionTextInputOnSpellingSuggestions` struct
+pub use crate::widgets::text_input::OptionTextInputOnSpellingSuggestions as AzOptionTextInputOnSpellingSuggestionsTT;
+pub use AzOptionTextInputOnSpellingSuggestionsTT as AzOptionTextInputOnSpellingSuggestions;
+/// Destructor: Takes ownership of the `OptionTextInputOnSpellingSuggestions` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTextInputOnSpellingSuggestions_delete(object: &mut AzOptionTextInputOnSpellingSuggestions) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionTextInputSelection` struct
 pub use crate::widgets::text_input::OptionTextInputSelection as AzOptionTextInputSelectionTT;
 pub use AzOptionTextInputSelectionTT as AzOptionTextInputSelection;
@@ -5053,6 +5842,12 @@ pub use AzOptionVirtualKeyCodeComboTT as AzOptionVirtualKeyCodeCombo;
 /// Destructor: Takes ownership of the `OptionVirtualKeyCodeCombo` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzOptionVirtualKeyCodeCombo_delete(object: &mut AzOptionVirtualKeyCodeCombo) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `OptionShortcutConflict` struct
+pub use azul_core::window::OptionShortcutConflict as AzOptionShortcutConflictTT;
+pub use AzOptionShortcutConflictTT as AzOptionShortcutConflict;
+/// Destructor: Takes ownership of the `OptionShortcutConflict` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionShortcutConflict_delete(object: &mut AzOptionShortcutConflict) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionCssProperty` struct
 pub use azul_impl::css::OptionCssProperty as AzOptionCssPropertyTT;
 pub use AzOptionCssPropertyTT as AzOptionCssProperty;
@@ -5063,6 +5858,14 @@ pub use AzOptionCssPropertyTT as AzOptionCssProperty;
 pub use azul_impl::ui_solver::OptionPositionInfo as AzOptionPositionInfoTT;
 pub use AzOptionPositionInfoTT as AzOptionPositionInfo;
 
+/// Re-export of rust-allocated (stack based) `OptionNodeRect` struct
+pub use azul_impl::ui_solver::OptionNodeRect as AzOptionNodeRectTT;
+pub use AzOptionNodeRectTT as AzOptionNodeRect;
+
+/// Re-export of rust-allocated (stack based) `OptionScrollInfo` struct
+pub use azul_impl::callbacks::OptionScrollInfo as AzOptionScrollInfoTT;
+pub use AzOptionScrollInfoTT as AzOptionScrollInfo;
+
 /// Re-export of rust-allocated (stack based) `OptionTimerId` struct
 pub use azul_impl::task::OptionTimerId as AzOptionTimerIdTT;
 pub use AzOptionTimerIdTT as AzOptionTimerId;
@@ -5163,6 +5966,32 @@ pub use AzOptionRendererOptionsTT as AzOptionRendererOptions;
 pub use azul_impl::callbacks::OptionCallback as AzOptionCallbackTT;
 pub use AzOptionCallbackTT as AzOptionCallback;
 
+/// Re-export of rust-allocated (stack based) `OptionPaintCallback` struct
+pub use azul_impl::callbacks::OptionPaintCallback as AzOptionPaintCallbackTT;
+pub use AzOptionPaintCallbackTT as AzOptionPaintCallback;
+
+/// Re-export of rust-allocated (stack based) `OptionJankCapture` struct
+pub use azul_impl::resources::OptionJankCapture as AzOptionJankCaptureTT;
+pub use AzOptionJankCaptureTT as AzOptionJankCapture;
+/// Destructor: Takes ownership of the `OptionJankCapture` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionJankCapture_delete(object: &mut AzOptionJankCapture) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionEventRecording` struct
+pub use azul_impl::resources::OptionEventRecording as AzOptionEventRecordingTT;
+pub use AzOptionEventRecordingTT as AzOptionEventRecording;
+/// Destructor: Takes ownership of the `OptionEventRecording` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionEventRecording_delete(object: &mut AzOptionEventRecording) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionFrameRecording` struct
+pub use azul_impl::resources::OptionFrameRecording as AzOptionFrameRecordingTT;
+pub use AzOptionFrameRecordingTT as AzOptionFrameRecording;
+/// Destructor: Takes ownership of the `OptionFrameRecording` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionFrameRecording_delete(object: &mut AzOptionFrameRecording) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionCrashHandler` struct
+pub use azul_impl::resources::OptionCrashHandler as AzOptionCrashHandlerTT;
+pub use AzOptionCrashHandlerTT as AzOptionCrashHandler;
+
 /// Re-export of rust-allocated (stack based) `OptionThreadSendMsg` struct
 pub use azul_impl::task::OptionThreadSendMsg as AzOptionThreadSendMsgTT;
 pub use AzOptionThreadSendMsgTT as AzOptionThreadSendMsg;
@@ -5185,6 +6014,12 @@ pub use AzOptionInlineTextTT as AzOptionInlineText;
 /// Destructor: Takes ownership of the `OptionInlineText` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzOptionInlineText_delete(object: &mut AzOptionInlineText) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `OptionTextLayout` struct
+pub use azul_impl::callbacks::OptionTextLayout as AzOptionTextLayoutTT;
+pub use AzOptionTextLayoutTT as AzOptionTextLayout;
+/// Destructor: Takes ownership of the `OptionTextLayout` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTextLayout_delete(object: &mut AzOptionTextLayout) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionLayoutPoint` struct
 pub use azul_impl::css::OptionLayoutPoint as AzOptionLayoutPointTT;
 pub use AzOptionLayoutPointTT as AzOptionLayoutPoint;
@@ -5231,6 +6066,10 @@ pub use AzOptionTaskBarIconTT as AzOptionTaskBarIcon;
 /// Destructor: Takes ownership of the `OptionTaskBarIcon` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzOptionTaskBarIcon_delete(object: &mut AzOptionTaskBarIcon) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `OptionRawWindowHandle` struct
+pub use azul_core::window::OptionRawWindowHandle as AzOptionRawWindowHandleTT;
+pub use AzOptionRawWindowHandleTT as AzOptionRawWindowHandle;
+
 /// Re-export of rust-allocated (stack based) `OptionHwndHandle` struct
 pub use azul_core::window::OptionHwndHandle as AzOptionHwndHandleTT;
 pub use AzOptionHwndHandleTT as AzOptionHwndHandle;
@@ -5565,6 +6404,24 @@ mod test_sizes {
         Trace,
     }
 
+    /// `AzFrameRecordingCallbackType` struct
+    pub type AzFrameRecordingCallbackType = extern "C" fn(&AzRecordedFrame);
+
+    /// Function that is invoked with every recorded frame, once the next frame has been presented (or the window is closed)
+    #[repr(C)]
+    pub struct AzFrameRecordingCallback {
+        pub cb: AzFrameRecordingCallbackType,
+    }
+
+    /// `AzCrashHandlerType` struct
+    pub type AzCrashHandlerType = extern "C" fn(&AzCrashInfo);
+
+    /// Function that is invoked when the app panics or crashes
+    #[repr(C)]
+    pub struct AzCrashHandler {
+        pub cb: AzCrashHandlerType,
+    }
+
     /// Version of the layout solver to use - future binary versions of azul may have more fields here, necessary so that old compiled applications don't break with newer releases of azul. Newer layout versions are opt-in only.
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -5572,6 +6429,24 @@ mod test_sizes {
         Default,
     }
 
+    /// Translucent backdrop of a window, see `WindowCreateOptions::background_effect`
+    #[repr(C)]
+    #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+    pub enum AzWindowBackgroundEffect {
+        None,
+        Blur,
+        Mica,
+        MicaAlt,
+        Acrylic,
+    }
+
+    /// How the built-in scrollbars are drawn, see `ScrollbarConfig::mode`
+    #[repr(C)]
+    pub enum AzScrollbarMode {
+        Default,
+        Overlay,
+    }
+
     /// Whether the renderer has VSync enabled
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -5897,6 +6772,25 @@ mod test_sizes {
         Cut,
     }
 
+    /// How urgently a screen reader should speak an announcement, see `CallbackInfo::announce`
+    #[repr(C)]
+    pub enum AzPoliteness {
+        Polite,
+        Assertive,
+    }
+
+    /// Kind of tactile feedback, see `CallbackInfo::trigger_haptic`
+    #[repr(C)]
+    pub enum AzHapticKind {
+        Selection,
+        LightImpact,
+        MediumImpact,
+        HeavyImpact,
+        Success,
+        Warning,
+        Error,
+    }
+
     /// State of the window frame (minimized, maximized, fullscreen or normal window)
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -5907,6 +6801,14 @@ mod test_sizes {
         Fullscreen,
     }
 
+    /// How a window covers the monitor in fullscreen mode - the `usize` is the `Monitor::id` of the target monitor (the current monitor of the window if no monitor with that id exists)
+    #[repr(C, u8)]
+    #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+    pub enum AzFullscreenMode {
+        Borderless(usize),
+        Exclusive(usize),
+    }
+
     /// Debugging information, will be rendered as an overlay on top of the UI
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -5940,6 +6842,15 @@ mod test_sizes {
         pub force_picture_invalidation: bool,
     }
 
+    /// Modifier keys that influence which character a key produces, see `CallbackInfo::keycode_to_char`. `ctrl + alt` is treated as AltGr.
+    #[repr(C)]
+    pub struct AzKeyboardModifiers {
+        pub shift: bool,
+        pub ctrl: bool,
+        pub alt: bool,
+        pub caps_lock: bool,
+    }
+
     /// Current icon of the mouse cursor
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -6018,10 +6929,21 @@ mod test_sizes {
         LightMode,
     }
 
-    /// Current state of touch devices / touch inputs
+    /// Device that the last pointer input of the window came from
     #[repr(C)]
-    pub struct AzTouchState {
-        pub unused: u8,
+    pub enum AzInputModality {
+        Mouse,
+        Touch,
+        Pen,
+    }
+
+    /// HDR / wide color gamut capabilities of a monitor
+    #[repr(C)]
+    pub struct AzHdrCapabilities {
+        pub supported: bool,
+        pub enabled: bool,
+        pub bits_per_color_channel: u32,
+        pub sdr_white_level: f32,
     }
 
     /// C-ABI stable wrapper over a `MarshaledLayoutCallbackInner`
@@ -6089,6 +7011,21 @@ mod test_sizes {
         pub static_y_offset: f32,
     }
 
+    /// Line style of a `TextUnderline`
+    #[repr(C)]
+    pub enum AzTextUnderlineStyle {
+        Solid,
+        Dotted,
+        Wavy,
+    }
+
+    /// How the text caret of an editable node is animated
+    #[repr(C)]
+    pub enum AzCaretAnimation {
+        Blink,
+        Fade,
+    }
+
     /// How should an animation repeat (loop, ping-pong, etc.)
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -6115,6 +7052,33 @@ mod test_sizes {
     /// `AzIFrameCallbackType` struct
     pub type AzIFrameCallbackType = extern "C" fn(&mut AzRefAny, &mut AzIFrameCallbackInfo) -> AzIFrameCallbackReturn;
 
+    /// C-ABI wrapper over a `MeasureCallbackType`
+    #[repr(C)]
+    pub struct AzMeasureCallback {
+        pub cb: AzMeasureCallbackType,
+    }
+
+    /// `AzMeasureCallbackType` struct
+    pub type AzMeasureCallbackType = extern "C" fn(&mut AzRefAny, &AzMeasureCallbackInfo) -> AzLogicalSize;
+
+    /// C-ABI wrapper over an `ArrangeCallbackType`
+    #[repr(C)]
+    pub struct AzArrangeCallback {
+        pub cb: AzArrangeCallbackType,
+    }
+
+    /// `AzArrangeCallbackType` struct
+    pub type AzArrangeCallbackType = extern "C" fn(&mut AzRefAny, &AzArrangeCallbackInfo);
+
+    /// C-ABI wrapper over a `PaintCallbackType`
+    #[repr(C)]
+    pub struct AzPaintCallback {
+        pub cb: AzPaintCallbackType,
+    }
+
+    /// `AzPaintCallbackType` struct
+    pub type AzPaintCallbackType = extern "C" fn(&mut AzRefAny, &AzPaintCallbackInfo) -> AzPaintPrimitiveVec;
+
     /// Re-export of rust-allocated (stack based) `RenderImageCallback` struct
     #[repr(C)]
     pub struct AzRenderImageCallback {
@@ -6173,6 +7137,9 @@ mod test_sizes {
         LeftMouseUp,
         MiddleMouseUp,
         RightMouseUp,
+        DoubleClick,
+        TripleClick,
+        HoverIntent,
         MouseEnter,
         MouseLeave,
         Scroll,
@@ -6184,6 +7151,21 @@ mod test_sizes {
         HoveredFileCancelled,
         FocusReceived,
         FocusLost,
+        UserIdle,
+        UserActive,
+        SessionEnding,
+        FullscreenEntered,
+        FullscreenExited,
+        WindowResized,
+        WindowMoved,
+        WindowFocusReceived,
+        WindowFocusLost,
+        DpiChanged,
+        Minimized,
+        Maximized,
+        Restored,
+        UserEvent,
+        InputModalityChanged,
     }
 
     /// Re-export of rust-allocated (stack based) `HoverEventFilter` struct
@@ -6198,6 +7180,9 @@ mod test_sizes {
         LeftMouseUp,
         RightMouseUp,
         MiddleMouseUp,
+        DoubleClick,
+        TripleClick,
+        HoverIntent,
         MouseEnter,
         MouseLeave,
         Scroll,
@@ -6227,6 +7212,8 @@ mod test_sizes {
         LeftMouseUp,
         RightMouseUp,
         MiddleMouseUp,
+        DoubleClick,
+        TripleClick,
         MouseEnter,
         MouseLeave,
         Scroll,
@@ -6251,6 +7238,9 @@ mod test_sizes {
         LeftMouseUp,
         RightMouseUp,
         MiddleMouseUp,
+        DoubleClick,
+        TripleClick,
+        HoverIntent,
         MouseEnter,
         MouseLeave,
         Scroll,
@@ -6272,6 +7262,19 @@ mod test_sizes {
         FocusLost,
         CloseRequested,
         ThemeChanged,
+        WindowFocusReceived,
+        WindowFocusLost,
+        UserIdle,
+        UserActive,
+        SessionEnding,
+        FullscreenEntered,
+        FullscreenExited,
+        DpiChanged,
+        Minimized,
+        Maximized,
+        Restored,
+        UserEvent,
+        InputModalityChanged,
     }
 
     /// Re-export of rust-allocated (stack based) `ComponentEventFilter` struct
@@ -6291,6 +7294,55 @@ mod test_sizes {
         DeviceDisconnected,
     }
 
+    /// Direction of the spatial (arrow key) focus navigation
+    #[repr(C)]
+    pub enum AzFocusDirection {
+        Up,
+        Down,
+        Left,
+        Right,
+    }
+
+    /// Kind of text that is entered into a text input node, same as the HTML `inputmode` attribute. Forwarded to the operating system so that touch keyboards and IMEs can show the appropriate layout
+    #[repr(C)]
+    pub enum AzInputMode {
+        Text,
+        None,
+        Numeric,
+        Decimal,
+        Tel,
+        Email,
+        Url,
+        Search,
+        Password,
+    }
+
+    /// Semantic state of a node that is set by the application (instead of by the mouse / keyboard), matched by the `:disabled`, `:checked`, `:selected` and `:indeterminate` CSS selectors
+    #[repr(C)]
+    pub enum AzNodeStateFlag {
+        Disabled,
+        Checked,
+        Selected,
+        Indeterminate,
+    }
+
+    /// Where an overlay is placed relative to its anchor node: the first part is the side of the anchor, the second part the alignment along that side (`BottomStart` = below the anchor, left edges aligned)
+    #[repr(C)]
+    pub enum AzOverlayPlacement {
+        TopStart,
+        Top,
+        TopEnd,
+        BottomStart,
+        Bottom,
+        BottomEnd,
+        LeftStart,
+        Left,
+        LeftEnd,
+        RightStart,
+        Right,
+        RightEnd,
+    }
+
     /// MSAA Accessibility role constants. For information on what each role does, see the <a href="https://docs.microsoft.com/en-us/windows/win32/winauto/object-roles">MSDN Role Constants page</a>
     #[repr(C)]
     pub enum AzAccessibilityRole {
@@ -6420,6 +7472,12 @@ mod test_sizes {
         Disabled,
     }
 
+    /// Re-export of rust-allocated (stack based) `CssMediaCondition` struct
+    #[repr(C)]
+    pub struct AzCssMediaCondition {
+        pub matching_styles: u8,
+    }
+
     /// Re-export of rust-allocated (stack based) `NodeTypeKey` struct
     #[repr(C)]
     pub enum AzNodeTypeKey {
@@ -6449,6 +7507,7 @@ mod test_sizes {
         LineHeight,
         WordSpacing,
         TabWidth,
+        Hyphens,
         Cursor,
         Display,
         Float,
@@ -6506,11 +7565,24 @@ mod test_sizes {
         BoxShadowTop,
         BoxShadowBottom,
         ScrollbarStyle,
+        ScrollbarWidth,
+        ScrollbarColor,
+        ScrollbarThumbHoverColor,
+        ScrollbarThumbActiveColor,
+        WindowDragRegion,
+        CaretAnimationDuration,
+        ScrollPadding,
+        Content,
+        CounterReset,
+        CounterIncrement,
+        OverflowAnchor,
         Opacity,
         Transform,
         TransformOrigin,
         PerspectiveOrigin,
         BackfaceVisibility,
+        Perspective,
+        TransformStyle,
         MixBlendMode,
         Filter,
         BackdropFilter,
@@ -6737,39 +7809,41 @@ mod test_sizes {
         Outset,
     }
 
-    /// Re-export of rust-allocated (stack based) `StyleCursor` struct
+    /// Re-export of rust-allocated (stack based) `StyleScrollbarWidth` struct
     #[repr(C)]
-    pub enum AzStyleCursor {
-        Alias,
-        AllScroll,
-        Cell,
-        ColResize,
-        ContextMenu,
-        Copy,
-        Crosshair,
-        Default,
-        EResize,
-        EwResize,
-        Grab,
-        Grabbing,
-        Help,
+    pub enum AzStyleScrollbarWidth {
+        Auto,
+        Thin,
+        None,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleOverflowAnchor` struct
+    #[repr(C)]
+    pub enum AzStyleOverflowAnchor {
+        Auto,
+        None,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleWindowDragRegion` struct
+    #[repr(C)]
+    pub enum AzStyleWindowDragRegion {
+        None,
         Move,
-        NResize,
-        NsResize,
-        NeswResize,
-        NwseResize,
-        Pointer,
-        Progress,
-        RowResize,
-        SResize,
-        SeResize,
-        Text,
-        Unset,
-        VerticalText,
-        WResize,
-        Wait,
-        ZoomIn,
-        ZoomOut,
+        ResizeN,
+        ResizeNE,
+        ResizeE,
+        ResizeSE,
+        ResizeS,
+        ResizeSW,
+        ResizeW,
+        ResizeNW,
+        MaximizeButton,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleCaretAnimationDuration` struct
+    #[repr(C)]
+    pub struct AzStyleCaretAnimationDuration {
+        pub inner: u32,
     }
 
     /// Re-export of rust-allocated (stack based) `StyleBackfaceVisibility` struct
@@ -6779,6 +7853,13 @@ mod test_sizes {
         Visible,
     }
 
+    /// Whether the children of a node are flattened into its plane or positioned in the 3D space
+    #[repr(C)]
+    pub enum AzStyleTransformStyle {
+        Flat,
+        Preserve3D,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTextAlign` struct
     #[repr(C)]
     pub enum AzStyleTextAlign {
@@ -6787,6 +7868,14 @@ mod test_sizes {
         Right,
     }
 
+    /// Whether words may be hyphenated when a line is broken inside of them
+    #[repr(C)]
+    pub enum AzStyleHyphens {
+        None,
+        Manual,
+        Auto,
+    }
+
     /// Re-export of rust-allocated (stack based) `Ribbon` struct
     #[repr(C)]
     pub struct AzRibbon {
@@ -6869,6 +7958,15 @@ mod test_sizes {
     /// `AzTextInputOnFocusLostCallbackType` struct
     pub type AzTextInputOnFocusLostCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTextInputState) -> AzUpdate;
 
+    /// Re-export of rust-allocated (stack based) `TextInputOnSpellingSuggestionsCallback` struct
+    #[repr(C)]
+    pub struct AzTextInputOnSpellingSuggestionsCallback {
+        pub cb: AzTextInputOnSpellingSuggestionsCallbackType,
+    }
+
+    /// `AzTextInputOnSpellingSuggestionsCallbackType` struct
+    pub type AzTextInputOnSpellingSuggestionsCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTextInputState, &AzTextInputSpellingSuggestions) -> AzUpdate;
+
     /// Re-export of rust-allocated (stack based) `TextInputValid` struct
     #[repr(C)]
     pub enum AzTextInputValid {
@@ -6876,13 +7974,11 @@ mod test_sizes {
         No,
     }
 
-    /// Re-export of rust-allocated (stack based) `NumberInputState` struct
+    /// Whether the `NumberInput` accepts any number or only integers
     #[repr(C)]
-    pub struct AzNumberInputState {
-        pub previous: f32,
-        pub number: f32,
-        pub min: f32,
-        pub max: f32,
+    pub enum AzNumberInputMode {
+        Integer,
+        Float,
     }
 
     /// Re-export of rust-allocated (stack based) `NumberInputOnValueChangeCallback` struct
@@ -7088,11 +8184,26 @@ mod test_sizes {
         pub cb: AzDropDownOnChoiceChangeCallbackType,
     }
 
-    /// Re-export of rust-allocated (stack based) `NodeHierarchyItem` struct
+    /// Identifies a toast that was shown with `Toasts::show`
     #[repr(C)]
-    pub struct AzNodeHierarchyItem {
-        pub parent: usize,
-        pub previous_sibling: usize,
+    pub struct AzToastId {
+        pub id: usize,
+    }
+
+    /// Re-export of rust-allocated (stack based) `ToastOnActionCallback` struct
+    #[repr(C)]
+    pub struct AzToastOnActionCallback {
+        pub cb: AzToastOnActionCallbackType,
+    }
+
+    /// `AzToastOnActionCallbackType` struct
+    pub type AzToastOnActionCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `NodeHierarchyItem` struct
+    #[repr(C)]
+    pub struct AzNodeHierarchyItem {
+        pub parent: usize,
+        pub previous_sibling: usize,
         pub next_sibling: usize,
         pub last_child: usize,
     }
@@ -7111,6 +8222,10 @@ mod test_sizes {
         pub hover: bool,
         pub active: bool,
         pub focused: bool,
+        pub disabled: bool,
+        pub checked: bool,
+        pub selected: bool,
+        pub indeterminate: bool,
     }
 
     /// Re-export of rust-allocated (stack based) `TagId` struct
@@ -7139,6 +8254,17 @@ mod test_sizes {
         pub(crate) ptr: *mut c_void,
     }
 
+    /// Workarounds for driver bugs, applied when the renderer is created
+    #[repr(C)]
+    pub struct AzGlQuirks {
+        pub force_software_rendering: bool,
+        pub disable_subpixel_aa: bool,
+        pub disable_optimized_shaders: bool,
+        pub disable_program_cache: bool,
+        pub disable_multithreading: bool,
+        pub disable_partial_present: bool,
+    }
+
     /// Re-export of rust-allocated (stack based) `GlShaderPrecisionFormatReturn` struct
     #[repr(C)]
     pub struct AzGlShaderPrecisionFormatReturn {
@@ -7622,6 +8748,13 @@ mod test_sizes {
         pub run_destructor: bool,
     }
 
+    /// Handle to wake up the event loop from other threads, created by `App::create_event_loop_proxy()`. NOTE: user events are currently only delivered on Windows, the X11 event loop can't invoke callbacks or run timers yet and rejects them.
+    #[repr(C)]
+    pub struct AzEventLoopProxy {
+        pub(crate) ptr: *const c_void,
+        pub run_destructor: bool,
+    }
+
     /// `AzCreateThreadFnType` struct
     pub type AzCreateThreadFnType = extern "C" fn(AzRefAny, AzRefAny, AzThreadCallback) -> AzThread;
 
@@ -7640,6 +8773,42 @@ mod test_sizes {
         pub cb: AzGetSystemTimeFnType,
     }
 
+    /// `AzGetSystemIdleTimeFnType` struct
+    pub type AzGetSystemIdleTimeFnType = extern "C" fn() -> AzOptionDuration;
+
+    /// Get the time since the last user input on the entire system, `None` if the platform does not support querying the idle time
+    #[repr(C)]
+    pub struct AzGetSystemIdleTimeFn {
+        pub cb: AzGetSystemIdleTimeFnType,
+    }
+
+    /// `AzGetLogLinesFnType` struct
+    pub type AzGetLogLinesFnType = extern "C" fn() -> AzStringVec;
+
+    /// Returns the most recent lines of the log ring buffer (oldest first)
+    #[repr(C)]
+    pub struct AzGetLogLinesFn {
+        pub cb: AzGetLogLinesFnType,
+    }
+
+    /// `AzKeycodeToCharFnType` struct
+    pub type AzKeycodeToCharFnType = extern "C" fn(AzVirtualKeyCode, AzKeyboardModifiers) -> AzOptionChar;
+
+    /// Returns the character that the key produces with the given modifiers in the current keyboard layout of the operating system
+    #[repr(C)]
+    pub struct AzKeycodeToCharFn {
+        pub cb: AzKeycodeToCharFnType,
+    }
+
+    /// `AzSetLogLevelFnType` struct
+    pub type AzSetLogLevelFnType = extern "C" fn(AzString, AzAppLogLevel);
+
+    /// Sets the log level of a subsystem at runtime
+    #[repr(C)]
+    pub struct AzSetLogLevelFn {
+        pub cb: AzSetLogLevelFnType,
+    }
+
     /// `AzCheckThreadFinishedFnType` struct
     pub type AzCheckThreadFinishedFnType = extern "C" fn(&c_void) -> bool;
 
@@ -7943,6 +9112,17 @@ mod test_sizes {
     /// `AzInlineWordVecDestructorType` struct
     pub type AzInlineWordVecDestructorType = extern "C" fn(&mut AzInlineWordVec);
 
+    /// Re-export of rust-allocated (stack based) `PaintPrimitiveVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzPaintPrimitiveVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzPaintPrimitiveVecDestructorType),
+    }
+
+    /// `AzPaintPrimitiveVecDestructorType` struct
+    pub type AzPaintPrimitiveVecDestructorType = extern "C" fn(&mut AzPaintPrimitiveVec);
+
     /// Re-export of rust-allocated (stack based) `InlineGlyphVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzInlineGlyphVecDestructor {
@@ -7954,6 +9134,17 @@ mod test_sizes {
     /// `AzInlineGlyphVecDestructorType` struct
     pub type AzInlineGlyphVecDestructorType = extern "C" fn(&mut AzInlineGlyphVec);
 
+    /// Re-export of rust-allocated (stack based) `TextUnderlineVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzTextUnderlineVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTextUnderlineVecDestructorType),
+    }
+
+    /// `AzTextUnderlineVecDestructorType` struct
+    pub type AzTextUnderlineVecDestructorType = extern "C" fn(&mut AzTextUnderlineVec);
+
     /// Re-export of rust-allocated (stack based) `InlineTextHitVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzInlineTextHitVecDestructor {
@@ -7976,6 +9167,17 @@ mod test_sizes {
     /// `AzMonitorVecDestructorType` struct
     pub type AzMonitorVecDestructorType = extern "C" fn(&mut AzMonitorVec);
 
+    /// Re-export of rust-allocated (stack based) `TouchPointVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzTouchPointVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTouchPointVecDestructorType),
+    }
+
+    /// `AzTouchPointVecDestructorType` struct
+    pub type AzTouchPointVecDestructorType = extern "C" fn(&mut AzTouchPointVec);
+
     /// Re-export of rust-allocated (stack based) `VideoModeVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzVideoModeVecDestructor {
@@ -8196,6 +9398,28 @@ mod test_sizes {
     /// `AzVirtualKeyCodeVecDestructorType` struct
     pub type AzVirtualKeyCodeVecDestructorType = extern "C" fn(&mut AzVirtualKeyCodeVec);
 
+    /// Re-export of rust-allocated (stack based) `ShortcutConflictVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzShortcutConflictVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzShortcutConflictVecDestructorType),
+    }
+
+    /// `AzShortcutConflictVecDestructorType` struct
+    pub type AzShortcutConflictVecDestructorType = extern "C" fn(&mut AzShortcutConflictVec);
+
+    /// Re-export of rust-allocated (stack based) `ShortcutVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzShortcutVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzShortcutVecDestructorType),
+    }
+
+    /// `AzShortcutVecDestructorType` struct
+    pub type AzShortcutVecDestructorType = extern "C" fn(&mut AzShortcutVec);
+
     /// Re-export of rust-allocated (stack based) `CascadeInfoVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzCascadeInfoVecDestructor {
@@ -8524,6 +9748,18 @@ mod test_sizes {
     pub struct AzSystemCallbacks {
         pub create_thread_fn: AzCreateThreadFn,
         pub get_system_time_fn: AzGetSystemTimeFn,
+        pub get_system_idle_time_fn: AzGetSystemIdleTimeFn,
+        pub get_log_lines_fn: AzGetLogLinesFn,
+        pub set_log_level_fn: AzSetLogLevelFn,
+        pub keycode_to_char_fn: AzKeycodeToCharFn,
+    }
+
+    /// What a window shows between being mapped and presenting the first rendered frame, see `WindowCreateOptions::first_frame`
+    #[repr(C, u8)]
+    pub enum AzFirstFrameMode {
+        ShowImmediately,
+        HideUntilRendered,
+        SplashColor(AzColorU),
     }
 
     /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
@@ -8584,12 +9820,17 @@ mod test_sizes {
         pub has_decorations: bool,
         pub is_visible: bool,
         pub is_always_on_top: bool,
+        pub is_click_through: bool,
+        pub is_tool_window: bool,
         pub is_resizable: bool,
         pub has_focus: bool,
         pub has_extended_window_frame: bool,
         pub has_blur_behind_window: bool,
         pub smooth_scroll_enabled: bool,
         pub autotab_enabled: bool,
+        pub spatial_navigation_enabled: bool,
+        pub is_session_ending: bool,
+        pub fullscreen_mode: AzFullscreenMode,
     }
 
     /// Current position of the mouse cursor, relative to the window. Set to `Uninitialized` on startup (gets initialized on the first frame).
@@ -8617,6 +9858,14 @@ mod test_sizes {
         Initialized(AzLogicalPosition),
     }
 
+    /// Single finger / stylus that is currently touching the screen
+    #[repr(C)]
+    pub struct AzTouchPoint {
+        pub id: u64,
+        pub position: AzLogicalPosition,
+        pub force: f32,
+    }
+
     /// Describes a rendering configuration for a monitor
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -8642,6 +9891,24 @@ mod test_sizes {
         Relative(AzPositionInfoInner),
     }
 
+    /// Post-layout geometry of a node, all rects are relative to the window origin
+    #[repr(C)]
+    pub struct AzNodeRect {
+        pub margin_box: AzLogicalRect,
+        pub border_box: AzLogicalRect,
+        pub padding_box: AzLogicalRect,
+        pub content_box: AzLogicalRect,
+    }
+
+    /// Scroll extents of a scrollable (overflowing) node
+    #[repr(C)]
+    pub struct AzScrollInfo {
+        pub scroll_position: AzLogicalPosition,
+        pub viewport: AzLogicalRect,
+        pub content_size: AzLogicalSize,
+        pub max_scroll: AzLogicalSize,
+    }
+
     /// Re-export of rust-allocated (stack based) `HidpiAdjustedBounds` struct
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -8658,6 +9925,15 @@ mod test_sizes {
         pub glyph_index: u32,
     }
 
+    /// Underlines a range of characters of a text node, see `CallbackInfo::set_text_underlines`
+    #[repr(C)]
+    pub struct AzTextUnderline {
+        pub start: usize,
+        pub end: usize,
+        pub color: AzColorU,
+        pub style: AzTextUnderlineStyle,
+    }
+
     /// Re-export of rust-allocated (stack based) `InlineTextHit` struct
     #[repr(C)]
     pub struct AzInlineTextHit {
@@ -8694,6 +9970,33 @@ mod test_sizes {
         pub _reserved_mut: *mut c_void,
     }
 
+    /// Re-export of rust-allocated (stack based) `MeasureCallbackInfo` struct
+    #[repr(C)]
+    pub struct AzMeasureCallbackInfo {
+        pub available_size: AzLogicalSize,
+        pub width_is_final: bool,
+    }
+
+    /// Re-export of rust-allocated (stack based) `ArrangeCallbackInfo` struct
+    #[repr(C)]
+    pub struct AzArrangeCallbackInfo {
+        pub bounds: AzNodeRect,
+    }
+
+    /// Re-export of rust-allocated (stack based) `PaintCallbackInfo` struct
+    #[repr(C)]
+    pub struct AzPaintCallbackInfo {
+        pub size: AzLogicalSize,
+    }
+
+    /// Filled (rounded) rectangle
+    #[repr(C)]
+    pub struct AzPaintRect {
+        pub bounds: AzLogicalRect,
+        pub color: AzColorU,
+        pub border_radius: f32,
+    }
+
     /// Re-export of rust-allocated (stack based) `TimerCallbackReturn` struct
     #[repr(C)]
     pub struct AzTimerCallbackReturn {
@@ -8715,6 +10018,7 @@ mod test_sizes {
     pub struct AzIFrameNode {
         pub callback: AzIFrameCallback,
         pub data: AzRefAny,
+        pub reinvoke_threshold: AzFloatValue,
     }
 
     /// Re-export of rust-allocated (stack based) `NotEventFilter` struct
@@ -8747,6 +10051,28 @@ mod test_sizes {
         Pattern(AzCssNthChildPattern),
     }
 
+    /// Colors of the CSS system color keywords (`Canvas`, `CanvasText`, `Highlight`, ...)
+    #[repr(C)]
+    pub struct AzSystemColors {
+        pub canvas: AzColorU,
+        pub canvas_text: AzColorU,
+        pub link_text: AzColorU,
+        pub gray_text: AzColorU,
+        pub highlight: AzColorU,
+        pub highlight_text: AzColorU,
+        pub button_face: AzColorU,
+        pub button_text: AzColorU,
+    }
+
+    /// High contrast / forced colors mode, reduced motion preference and system colors of the operating system
+    #[repr(C)]
+    pub struct AzSystemStyle {
+        pub forced_colors: bool,
+        pub prefers_reduced_motion: bool,
+        pub pointer_coarse: bool,
+        pub colors: AzSystemColors,
+    }
+
     /// Re-export of rust-allocated (stack based) `PixelValue` struct
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -9107,6 +10433,31 @@ mod test_sizes {
         pub inner: AzPixelValue,
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleScrollbarColor` struct
+    #[repr(C)]
+    pub struct AzStyleScrollbarColor {
+        pub thumb: AzColorU,
+        pub track: AzColorU,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleScrollbarThumbHoverColor` struct
+    #[repr(C)]
+    pub struct AzStyleScrollbarThumbHoverColor {
+        pub inner: AzColorU,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleScrollbarThumbActiveColor` struct
+    #[repr(C)]
+    pub struct AzStyleScrollbarThumbActiveColor {
+        pub inner: AzColorU,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleScrollPadding` struct
+    #[repr(C)]
+    pub struct AzStyleScrollPadding {
+        pub inner: AzPixelValue,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleFontSize` struct
     #[repr(C)]
     pub struct AzStyleFontSize {
@@ -9151,6 +10502,12 @@ mod test_sizes {
         pub y: AzPixelValue,
     }
 
+    /// Distance between the z = 0 plane and the viewer, applied to the children of the node
+    #[repr(C)]
+    pub struct AzStylePerspective {
+        pub inner: AzPixelValue,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTransformMatrix2D` struct
     #[repr(C)]
     pub struct AzStyleTransformMatrix2D {
@@ -9551,6 +10908,86 @@ mod test_sizes {
         Exact(AzLayoutOverflow),
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleScrollbarWidthValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleScrollbarWidthValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleScrollbarWidth),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleScrollbarColorValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleScrollbarColorValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleScrollbarColor),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleScrollbarThumbHoverColorValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleScrollbarThumbHoverColorValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleScrollbarThumbHoverColor),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleScrollbarThumbActiveColorValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleScrollbarThumbActiveColorValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleScrollbarThumbActiveColor),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleWindowDragRegionValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleWindowDragRegionValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleWindowDragRegion),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleCaretAnimationDurationValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleCaretAnimationDurationValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleCaretAnimationDuration),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleScrollPaddingValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleScrollPaddingValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleScrollPadding),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleOverflowAnchorValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleOverflowAnchorValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleOverflowAnchor),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBorderBottomColorValue` struct
     #[repr(C, u8)]
     pub enum AzStyleBorderBottomColorValue {
@@ -9711,16 +11148,6 @@ mod test_sizes {
         Exact(AzLayoutBorderTopWidth),
     }
 
-    /// Re-export of rust-allocated (stack based) `StyleCursorValue` struct
-    #[repr(C, u8)]
-    pub enum AzStyleCursorValue {
-        Auto,
-        None,
-        Inherit,
-        Initial,
-        Exact(AzStyleCursor),
-    }
-
     /// Re-export of rust-allocated (stack based) `StyleFontSizeValue` struct
     #[repr(C, u8)]
     pub enum AzStyleFontSizeValue {
@@ -9761,6 +11188,16 @@ mod test_sizes {
         Exact(AzStyleTabWidth),
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleHyphensValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleHyphensValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleHyphens),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTextAlignValue` struct
     #[repr(C, u8)]
     pub enum AzStyleTextAlignValue {
@@ -9831,6 +11268,26 @@ mod test_sizes {
         Exact(AzStyleBackfaceVisibility),
     }
 
+    /// Re-export of rust-allocated (stack based) `StylePerspectiveValue` struct
+    #[repr(C, u8)]
+    pub enum AzStylePerspectiveValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStylePerspective),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTransformStyleValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTransformStyleValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTransformStyle),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleMixBlendModeValue` struct
     #[repr(C, u8)]
     pub enum AzStyleMixBlendModeValue {
@@ -9903,6 +11360,13 @@ mod test_sizes {
         pub callback: AzTextInputOnFocusLostCallback,
     }
 
+    /// Re-export of rust-allocated (stack based) `TextInputOnSpellingSuggestions` struct
+    #[repr(C)]
+    pub struct AzTextInputOnSpellingSuggestions {
+        pub data: AzRefAny,
+        pub callback: AzTextInputOnSpellingSuggestionsCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `OnTextInputReturn` struct
     #[repr(C)]
     pub struct AzOnTextInputReturn {
@@ -9910,6 +11374,19 @@ mod test_sizes {
         pub valid: AzTextInputValid,
     }
 
+    /// Re-export of rust-allocated (stack based) `NumberInputState` struct
+    #[repr(C)]
+    pub struct AzNumberInputState {
+        pub previous: f64,
+        pub number: f64,
+        pub min: f64,
+        pub max: f64,
+        pub step: f64,
+        pub mode: AzNumberInputMode,
+        pub decimal_separator: u32,
+        pub is_valid: bool,
+    }
+
     /// Re-export of rust-allocated (stack based) `NumberInputOnValueChange` struct
     #[repr(C)]
     pub struct AzNumberInputOnValueChange {
@@ -10029,6 +11506,13 @@ mod test_sizes {
         pub callback: AzDropDownOnChoiceChangeCallback,
     }
 
+    /// Re-export of rust-allocated (stack based) `ToastOnAction` struct
+    #[repr(C)]
+    pub struct AzToastOnAction {
+        pub data: AzRefAny,
+        pub callback: AzToastOnActionCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `ParentWithNodeDepth` struct
     #[repr(C)]
     pub struct AzParentWithNodeDepth {
@@ -10269,6 +11753,15 @@ mod test_sizes {
         pub destructor: AzInlineGlyphVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<TextUnderline>`
+    #[repr(C)]
+    pub struct AzTextUnderlineVec {
+        pub(crate) ptr: *const AzTextUnderline,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTextUnderlineVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<InlineTextHit>`
     #[repr(C)]
     pub struct AzInlineTextHitVec {
@@ -10278,6 +11771,15 @@ mod test_sizes {
         pub destructor: AzInlineTextHitVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<TouchPoint>`
+    #[repr(C)]
+    pub struct AzTouchPointVec {
+        pub(crate) ptr: *const AzTouchPoint,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTouchPointVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<VideoMode>`
     #[repr(C)]
     pub struct AzVideoModeVec {
@@ -10518,6 +12020,13 @@ mod test_sizes {
         Some(AzDropDownOnChoiceChange),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionToastOnAction` struct
+    #[repr(C, u8)]
+    pub enum AzOptionToastOnAction {
+        None,
+        Some(AzToastOnAction),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeAdded` struct
     #[repr(C, u8)]
     pub enum AzOptionNodeGraphOnNodeAdded {
@@ -10630,6 +12139,13 @@ mod test_sizes {
         Some(AzTextInputOnFocusLost),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTextInputOnSpellingSuggestions` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTextInputOnSpellingSuggestions {
+        None,
+        Some(AzTextInputOnSpellingSuggestions),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionTextInputSelection` struct
     #[repr(C, u8)]
     pub enum AzOptionTextInputSelection {
@@ -10672,6 +12188,20 @@ mod test_sizes {
         Some(AzPositionInfo),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionNodeRect` struct
+    #[repr(C, u8)]
+    pub enum AzOptionNodeRect {
+        None,
+        Some(AzNodeRect),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionScrollInfo` struct
+    #[repr(C, u8)]
+    pub enum AzOptionScrollInfo {
+        None,
+        Some(AzScrollInfo),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionTimerId` struct
     #[repr(C, u8)]
     pub enum AzOptionTimerId {
@@ -10742,18 +12272,32 @@ mod test_sizes {
         Some(AzCallback),
     }
 
-    /// Re-export of rust-allocated (stack based) `OptionThreadSendMsg` struct
+    /// Re-export of rust-allocated (stack based) `OptionPaintCallback` struct
     #[repr(C, u8)]
-    pub enum AzOptionThreadSendMsg {
+    pub enum AzOptionPaintCallback {
         None,
-        Some(AzThreadSendMsg),
+        Some(AzPaintCallback),
     }
 
-    /// Re-export of rust-allocated (stack based) `OptionLayoutRect` struct
+    /// Re-export of rust-allocated (stack based) `OptionCrashHandler` struct
     #[repr(C, u8)]
-    pub enum AzOptionLayoutRect {
+    pub enum AzOptionCrashHandler {
         None,
-        Some(AzLayoutRect),
+        Some(AzCrashHandler),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionThreadSendMsg` struct
+    #[repr(C, u8)]
+    pub enum AzOptionThreadSendMsg {
+        None,
+        Some(AzThreadSendMsg),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionLayoutRect` struct
+    #[repr(C, u8)]
+    pub enum AzOptionLayoutRect {
+        None,
+        Some(AzLayoutRect),
     }
 
     /// Re-export of rust-allocated (stack based) `OptionRefAny` struct
@@ -10812,6 +12356,13 @@ mod test_sizes {
         Some(AzSvgDashPattern),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionRawWindowHandle` struct
+    #[repr(C, u8)]
+    pub enum AzOptionRawWindowHandle {
+        None,
+        Some(AzRawWindowHandle),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionLogicalPosition` struct
     #[repr(C, u8)]
     pub enum AzOptionLogicalPosition {
@@ -10935,15 +12486,34 @@ mod test_sizes {
         pub pos: AzSvgParseErrorPosition,
     }
 
-    /// Configuration for optional features, such as whether to enable logging or panic hooks
+    /// Startup splash screen (logo + progress bar), see `App::with_splash`
     #[repr(C)]
-    pub struct AzAppConfig {
-        pub layout_solver: AzLayoutSolver,
-        pub log_level: AzAppLogLevel,
-        pub enable_visual_panic_hook: bool,
-        pub enable_logging_on_panic: bool,
-        pub enable_tab_navigation: bool,
-        pub system_callbacks: AzSystemCallbacks,
+    pub struct AzSplashOptions {
+        pub logo: AzOptionImageRef,
+        pub size: AzLogicalSize,
+        pub background_color: AzColorU,
+        pub progress_bar_color: AzColorU,
+        pub cross_fade: AzDuration,
+    }
+
+    /// A frame that was presented by a window, passed to the `FrameRecordingCallback`
+    #[repr(C)]
+    pub struct AzRecordedFrame {
+        pub window_handle: AzRawWindowHandle,
+        pub frame_index: usize,
+        pub timestamp: AzDuration,
+        pub duration: AzDuration,
+        pub image: AzImageRef,
+    }
+
+    /// Behavior of the built-in scrollbars of a window, see `WindowCreateOptions::scrollbars`
+    #[repr(C)]
+    pub struct AzScrollbarConfig {
+        pub mode: AzScrollbarMode,
+        pub min_thumb_length: f32,
+        pub hit_area_margin: f32,
+        pub auto_hide_delay: AzOptionDuration,
+        pub auto_hide_fade_duration: AzDuration,
     }
 
     /// Small (16x16x4) window icon, usually shown in the window titlebar
@@ -10982,6 +12552,10 @@ mod test_sizes {
         pub dpi: u32,
         pub min_dimensions: AzOptionLogicalSize,
         pub max_dimensions: AzOptionLogicalSize,
+        pub min_dimensions_from_content: bool,
+        pub resize_increments: AzOptionLogicalSize,
+        pub aspect_ratio_lock: AzOptionF32,
+        pub zoom: f32,
     }
 
     /// Current keyboard state, stores what keys / characters have been pressed
@@ -10991,6 +12565,9 @@ mod test_sizes {
         pub current_virtual_keycode: AzOptionVirtualKeyCode,
         pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
         pub pressed_scancodes: AzScanCodeVec,
+        pub caps_lock_enabled: bool,
+        pub num_lock_enabled: bool,
+        pub scroll_lock_enabled: bool,
     }
 
     /// Current mouse / cursor state
@@ -11002,10 +12579,18 @@ mod test_sizes {
         pub left_down: bool,
         pub right_down: bool,
         pub middle_down: bool,
+        pub click_count: u32,
         pub scroll_x: AzOptionF32,
         pub scroll_y: AzOptionF32,
     }
 
+    /// Current state of touch devices / touch inputs
+    #[repr(C)]
+    pub struct AzTouchState {
+        pub touches: AzTouchPointVec,
+        pub input_modality: AzInputModality,
+    }
+
     /// C-ABI stable wrapper over a `MarshaledLayoutCallback`
     #[repr(C)]
     pub struct AzMarshaledLayoutCallback {
@@ -11045,6 +12630,12 @@ mod test_sizes {
         CubicBezier(AzSvgCubicCurve),
     }
 
+    /// Primitive drawn by a `PaintCallback`
+    #[repr(C, u8)]
+    pub enum AzPaintPrimitive {
+        Rect(AzPaintRect),
+    }
+
     /// Re-export of rust-allocated (stack based) `RenderImageCallbackInfo` struct
     #[repr(C)]
     pub struct AzRenderImageCallbackInfo {
@@ -11074,6 +12665,15 @@ mod test_sizes {
         pub _reserved_mut: *mut c_void,
     }
 
+    /// Re-export of rust-allocated (stack based) `CustomLayoutNode` struct
+    #[repr(C)]
+    pub struct AzCustomLayoutNode {
+        pub measure: AzMeasureCallback,
+        pub arrange: AzArrangeCallback,
+        pub paint: AzOptionPaintCallback,
+        pub data: AzRefAny,
+    }
+
     /// Re-export of rust-allocated (stack based) `EventFilter` struct
     #[repr(C, u8)]
     pub enum AzEventFilter {
@@ -11099,6 +12699,22 @@ mod test_sizes {
         pub keys: AzVirtualKeyCodeVec,
     }
 
+    /// Window-level keyboard shortcut: invokes the callback when the key combination is pressed, instead of the `VirtualKeyDown` callbacks of the focused node
+    #[repr(C)]
+    pub struct AzShortcut {
+        pub keys: AzVirtualKeyCodeCombo,
+        pub callback: AzCallback,
+        pub data: AzRefAny,
+    }
+
+    /// Two shortcuts in a `ShortcutMap` that use the same key combination
+    #[repr(C)]
+    pub struct AzShortcutConflict {
+        pub keys: AzVirtualKeyCodeCombo,
+        pub existing: usize,
+        pub conflicting: usize,
+    }
+
     /// Re-export of rust-allocated (stack based) `CssPathPseudoSelector` struct
     #[repr(C, u8)]
     pub enum AzCssPathPseudoSelector {
@@ -11108,6 +12724,12 @@ mod test_sizes {
         Hover,
         Active,
         Focus,
+        Disabled,
+        Checked,
+        Selected,
+        Indeterminate,
+        Before,
+        After,
     }
 
     /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
@@ -11235,14 +12857,6 @@ mod test_sizes {
         pub on_toggle: AzOptionCheckBoxOnToggle,
     }
 
-    /// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
-    #[repr(C)]
-    pub struct AzNumberInputStateWrapper {
-        pub inner: AzNumberInputState,
-        pub on_value_change: AzOptionNumberInputOnValueChange,
-        pub on_focus_lost: AzOptionNumberInputOnFocusLost,
-    }
-
     /// Re-export of rust-allocated (stack based) `NodeGraphCallbacks` struct
     #[repr(C)]
     pub struct AzNodeGraphCallbacks {
@@ -11471,6 +13085,15 @@ mod test_sizes {
         pub destructor: AzTessellatedColoredSvgNodeVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<PaintPrimitive>`
+    #[repr(C)]
+    pub struct AzPaintPrimitiveVec {
+        pub(crate) ptr: *const AzPaintPrimitive,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzPaintPrimitiveVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<StyleTransform>`
     #[repr(C)]
     pub struct AzStyleTransformVec {
@@ -11489,6 +13112,24 @@ mod test_sizes {
         pub destructor: AzSvgPathElementVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `ShortcutConflict`
+    #[repr(C)]
+    pub struct AzShortcutConflictVec {
+        pub(crate) ptr: *const AzShortcutConflict,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzShortcutConflictVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Shortcut`
+    #[repr(C)]
+    pub struct AzShortcutVec {
+        pub(crate) ptr: *const AzShortcut,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzShortcutVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `StringVec`
     #[repr(C)]
     pub struct AzStringVec {
@@ -11537,6 +13178,13 @@ mod test_sizes {
         Some(AzVirtualKeyCodeCombo),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionShortcutConflict` struct
+    #[repr(C, u8)]
+    pub enum AzOptionShortcutConflict {
+        None,
+        Some(AzShortcutConflict),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionMouseState` struct
     #[repr(C, u8)]
     pub enum AzOptionMouseState {
@@ -11644,6 +13292,40 @@ mod test_sizes {
         pub pos: AzSvgParseErrorPosition,
     }
 
+    /// Configuration of the jank detector, see `AppConfig::jank_capture`
+    #[repr(C)]
+    pub struct AzJankCapture {
+        pub threshold: AzDuration,
+        pub dump_display_list: bool,
+        pub output_directory: AzOptionString,
+    }
+
+    /// Whether input events are recorded or replayed, see `AppConfig::event_recording`
+    #[repr(C, u8)]
+    pub enum AzEventRecording {
+        Record(AzString),
+        Replay(AzString),
+    }
+
+    /// Where the frames of a `FrameRecording` are written to
+    #[repr(C, u8)]
+    pub enum AzFrameRecordingOutput {
+        GifDirectory(AzString),
+        Callback(AzFrameRecordingCallback),
+    }
+
+    /// Information about a panic or crash, passed to the `CrashHandler`
+    #[repr(C)]
+    pub struct AzCrashInfo {
+        pub message: AzString,
+        pub location: AzString,
+        pub thread_name: AzString,
+        pub backtrace: AzString,
+        pub last_log_lines: AzStringVec,
+        pub window_state_summary: AzString,
+        pub minidump_path: AzOptionString,
+    }
+
     /// Window configuration specific to Win32
     #[repr(C)]
     pub struct AzWindowsWindowOptions {
@@ -11721,6 +13403,7 @@ mod test_sizes {
         pub scale_factor: f64,
         pub video_modes: AzVideoModeVec,
         pub is_primary_monitor: bool,
+        pub hdr: AzHdrCapabilities,
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutCallback` struct
@@ -11739,6 +13422,15 @@ mod test_sizes {
         Word(AzInlineTextContents),
     }
 
+    /// Node type defined outside of azul (map view, terminal widget, ...): the measure / arrange / paint hooks that all nodes of this type share
+    #[repr(C)]
+    pub struct AzCustomNodeType {
+        pub name: AzString,
+        pub measure: AzMeasureCallback,
+        pub arrange: AzArrangeCallback,
+        pub paint: AzOptionPaintCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `CallbackData` struct
     #[repr(C)]
     pub struct AzCallbackData {
@@ -11756,6 +13448,7 @@ mod test_sizes {
         Text(AzString),
         Image(AzImageRef),
         IFrame(AzIFrameNode),
+        CustomLayout(AzCustomLayoutNode),
     }
 
     /// Accessibility information (MSAA wrapper). See `NodeData.set_accessibility_info()`
@@ -11769,6 +13462,22 @@ mod test_sizes {
         pub default_action: AzOptionString,
     }
 
+    /// Per-node overrides for the spatial focus navigation, similar to the `nav-up: #id` CSS properties
+    #[repr(C)]
+    pub struct AzFocusNavigation {
+        pub up: AzOptionString,
+        pub down: AzOptionString,
+        pub left: AzOptionString,
+        pub right: AzOptionString,
+    }
+
+    /// Marks a node as an overlay: the node is taken out of the regular layout, positioned next to the node with the ID `anchor` and rendered above all other content, clipped only by the window. If the overlay doesn't fit on the requested side, it is flipped to the opposite side and shifted back into the window
+    #[repr(C)]
+    pub struct AzOverlayInfo {
+        pub anchor: AzString,
+        pub placement: AzOverlayPlacement,
+    }
+
     /// Re-export of rust-allocated (stack based) `IdOrClass` struct
     #[repr(C, u8)]
     pub enum AzIdOrClass {
@@ -11787,6 +13496,12 @@ mod test_sizes {
         pub children: AzMenuItemVec,
     }
 
+    /// List of window-level keyboard shortcuts
+    #[repr(C)]
+    pub struct AzShortcutMap {
+        pub shortcuts: AzShortcutVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `CssPathSelector` struct
     #[repr(C, u8)]
     pub enum AzCssPathSelector {
@@ -11829,6 +13544,37 @@ mod test_sizes {
         pub vertical: AzScrollbarInfo,
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleContent` struct
+    #[repr(C, u8)]
+    pub enum AzStyleContent {
+        None,
+        Text(AzString),
+        Image(AzString),
+        Counter(AzString),
+    }
+
+    /// Represents a `counter-reset` attribute: `counter-reset: name [<integer>]`
+    #[repr(C)]
+    pub struct AzStyleCounterReset {
+        pub counter: AzString,
+        pub value: i32,
+    }
+
+    /// Represents a `counter-increment` attribute: `counter-increment: name [<integer>]`
+    #[repr(C)]
+    pub struct AzStyleCounterIncrement {
+        pub counter: AzString,
+        pub value: i32,
+    }
+
+    /// Custom cursor image, the hotspot is in pixels from the top left of the image
+    #[repr(C)]
+    pub struct AzStyleCursorImage {
+        pub image: AzString,
+        pub hotspot_x: u32,
+        pub hotspot_y: u32,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleFontFamily` struct
     #[repr(C, u8)]
     pub enum AzStyleFontFamily {
@@ -11847,6 +13593,36 @@ mod test_sizes {
         Exact(AzScrollbarStyle),
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleContentValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleContentValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleContent),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleCounterResetValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleCounterResetValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleCounterReset),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleCounterIncrementValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleCounterIncrementValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleCounterIncrement),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTransformVecValue` struct
     #[repr(C, u8)]
     pub enum AzStyleTransformVecValue {
@@ -11891,6 +13667,14 @@ mod test_sizes {
         pub cursor_pos: usize,
     }
 
+    /// Misspelled word that was right-clicked
+    #[repr(C)]
+    pub struct AzTextInputSpellingSuggestions {
+        pub range: AzTextInputSelectionRange,
+        pub word: AzString,
+        pub suggestions: AzStringVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `TabHeader` struct
     #[repr(C)]
     pub struct AzTabHeader {
@@ -11967,6 +13751,25 @@ mod test_sizes {
         pub on_choice_change: AzOptionDropDownOnChoiceChange,
     }
 
+    /// Single notification message
+    #[repr(C)]
+    pub struct AzToast {
+        pub message: AzString,
+        pub duration: AzOptionDuration,
+        pub action_label: AzString,
+        pub on_action: AzOptionToastOnAction,
+    }
+
+    /// Enables / disables workarounds for all drivers that match the rule. The patterns are matched case-insensitively against substrings of the `GL_VENDOR`, `GL_RENDERER` and `GL_VERSION` strings, a `None` pattern matches every driver. The `version` pattern can also compare version numbers: `"Mesa < 21.2"` matches if `GL_VERSION` contains "Mesa" and the number after it is lower than 21.2, without a prefix (`">= 4.5"`) the first number of `GL_VERSION` is compared. Supported operators are `<`, `<=`, `>`, `>=` and `==`.
+    #[repr(C)]
+    pub struct AzGlQuirkRule {
+        pub vendor: AzOptionString,
+        pub renderer: AzOptionString,
+        pub version: AzOptionString,
+        pub enable: AzGlQuirks,
+        pub disable: AzGlQuirks,
+    }
+
     /// Re-export of rust-allocated (stack based) `VertexAttribute` struct
     #[repr(C)]
     pub struct AzVertexAttribute {
@@ -12219,6 +14022,20 @@ mod test_sizes {
         Some(AzFile),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionJankCapture` struct
+    #[repr(C, u8)]
+    pub enum AzOptionJankCapture {
+        None,
+        Some(AzJankCapture),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionEventRecording` struct
+    #[repr(C, u8)]
+    pub enum AzOptionEventRecording {
+        None,
+        Some(AzEventRecording),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionRawImage` struct
     #[repr(C, u8)]
     pub enum AzOptionRawImage {
@@ -12258,6 +14075,13 @@ mod test_sizes {
         InvalidCharacterData,
     }
 
+    /// Recording of the presented frames of all windows, see `AppConfig::frame_recording`
+    #[repr(C)]
+    pub struct AzFrameRecording {
+        pub output: AzFrameRecordingOutput,
+        pub max_fps: u32,
+    }
+
     /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
     #[repr(C)]
     pub struct AzLinuxWindowOptions {
@@ -12296,6 +14120,42 @@ mod test_sizes {
         pub selectors: AzCssPathSelectorVec,
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleCursor` struct
+    #[repr(C, u8)]
+    pub enum AzStyleCursor {
+        Alias,
+        AllScroll,
+        Cell,
+        ColResize,
+        ContextMenu,
+        Copy,
+        Crosshair,
+        Default,
+        EResize,
+        EwResize,
+        Grab,
+        Grabbing,
+        Help,
+        Move,
+        NResize,
+        NsResize,
+        NeswResize,
+        NwseResize,
+        Pointer,
+        Progress,
+        RowResize,
+        SResize,
+        SeResize,
+        Text,
+        Unset,
+        VerticalText,
+        WResize,
+        Wait,
+        ZoomIn,
+        ZoomOut,
+        Image(AzStyleCursorImage),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
     #[repr(C, u8)]
     pub enum AzStyleBackgroundContentVecValue {
@@ -12306,6 +14166,16 @@ mod test_sizes {
         Exact(AzStyleBackgroundContentVec),
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleCursorValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleCursorValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleCursor),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecValue` struct
     #[repr(C, u8)]
     pub enum AzStyleFontFamilyVecValue {
@@ -12327,6 +14197,7 @@ mod test_sizes {
         LineHeight(AzStyleLineHeightValue),
         WordSpacing(AzStyleWordSpacingValue),
         TabWidth(AzStyleTabWidthValue),
+        Hyphens(AzStyleHyphensValue),
         Cursor(AzStyleCursorValue),
         Display(AzLayoutDisplayValue),
         Float(AzLayoutFloatValue),
@@ -12384,11 +14255,24 @@ mod test_sizes {
         BoxShadowTop(AzStyleBoxShadowValue),
         BoxShadowBottom(AzStyleBoxShadowValue),
         ScrollbarStyle(AzScrollbarStyleValue),
+        ScrollbarWidth(AzStyleScrollbarWidthValue),
+        ScrollbarColor(AzStyleScrollbarColorValue),
+        ScrollbarThumbHoverColor(AzStyleScrollbarThumbHoverColorValue),
+        ScrollbarThumbActiveColor(AzStyleScrollbarThumbActiveColorValue),
+        WindowDragRegion(AzStyleWindowDragRegionValue),
+        CaretAnimationDuration(AzStyleCaretAnimationDurationValue),
+        ScrollPadding(AzStyleScrollPaddingValue),
+        Content(AzStyleContentValue),
+        CounterReset(AzStyleCounterResetValue),
+        CounterIncrement(AzStyleCounterIncrementValue),
+        OverflowAnchor(AzStyleOverflowAnchorValue),
         Opacity(AzStyleOpacityValue),
         Transform(AzStyleTransformVecValue),
         TransformOrigin(AzStyleTransformOriginValue),
         PerspectiveOrigin(AzStylePerspectiveOriginValue),
         BackfaceVisibility(AzStyleBackfaceVisibilityValue),
+        Perspective(AzStylePerspectiveValue),
+        TransformStyle(AzStyleTransformStyleValue),
         MixBlendMode(AzStyleMixBlendModeValue),
         Filter(AzStyleFilterVecValue),
         BackdropFilter(AzStyleFilterVecValue),
@@ -12415,6 +14299,10 @@ mod test_sizes {
         pub update_text_input_before_calling_focus_lost_fn: bool,
         pub update_text_input_before_calling_vk_down_fn: bool,
         pub cursor_animation: AzOptionTimerId,
+        pub caret_animation: AzCaretAnimation,
+        pub spellcheck_language: AzOptionString,
+        pub on_spelling_suggestions: AzOptionTextInputOnSpellingSuggestions,
+        pub secure: bool,
     }
 
     /// Re-export of rust-allocated (stack based) `ProgressBar` struct
@@ -12593,6 +14481,13 @@ mod test_sizes {
         Some(AzCssProperty),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionFrameRecording` struct
+    #[repr(C, u8)]
+    pub enum AzOptionFrameRecording {
+        None,
+        Some(AzFrameRecording),
+    }
+
     /// Re-export of rust-allocated (stack based) `XmlTextError` struct
     #[repr(C)]
     pub struct AzXmlTextError {
@@ -12600,6 +14495,23 @@ mod test_sizes {
         pub pos: AzSvgParseErrorPosition,
     }
 
+    /// Configuration for optional features, such as whether to enable logging or panic hooks
+    #[repr(C)]
+    pub struct AzAppConfig {
+        pub layout_solver: AzLayoutSolver,
+        pub log_level: AzAppLogLevel,
+        pub enable_visual_panic_hook: bool,
+        pub enable_logging_on_panic: bool,
+        pub enable_tab_navigation: bool,
+        pub system_callbacks: AzSystemCallbacks,
+        pub user_idle_timeout: AzOptionDuration,
+        pub crash_handler: AzOptionCrashHandler,
+        pub jank_capture: AzOptionJankCapture,
+        pub event_recording: AzOptionEventRecording,
+        pub frame_recording: AzOptionFrameRecording,
+        pub hover_intent_delay: AzOptionDuration,
+    }
+
     /// Platform-specific window configuration, i.e. WM options that are not cross-platform
     #[repr(C)]
     pub struct AzPlatformSpecificOptions {
@@ -12626,6 +14538,7 @@ mod test_sizes {
         pub platform_specific_options: AzPlatformSpecificOptions,
         pub renderer_options: AzRendererOptions,
         pub background_color: AzColorU,
+        pub opacity: f32,
         pub layout_callback: AzLayoutCallback,
         pub close_callback: AzOptionCallback,
     }
@@ -12648,13 +14561,22 @@ mod test_sizes {
         pub threads_removed: *mut c_void,
         pub current_window_handle: *const AzRawWindowHandle,
         pub new_windows: *mut c_void,
+        pub shortcuts: *mut AzShortcutMap,
+        pub window_data: *mut c_void,
+        pub announcements: *mut c_void,
+        pub haptic_feedback: *mut c_void,
+        pub animation_frame_callbacks: *mut c_void,
+        pub crossfades: *mut c_void,
+        pub subtree_recordings: *mut c_void,
         pub system_callbacks: *const AzSystemCallbacks,
         pub stop_propagation: *mut bool,
         pub focus_target: *mut c_void,
         pub words_changed_in_callbacks: *mut c_void,
+        pub text_underlines_changed_in_callbacks: *mut c_void,
         pub images_changed_in_callbacks: *mut c_void,
         pub image_masks_changed_in_callbacks: *mut c_void,
         pub css_properties_changed_in_callbacks: *mut c_void,
+        pub ids_and_classes_changed_in_callbacks: *mut c_void,
         pub current_scroll_states: *const c_void,
         pub nodes_scrolled_in_callback: *mut c_void,
         pub hit_dom_node: AzDomNodeId,
@@ -12674,6 +14596,13 @@ mod test_sizes {
         pub baseline_descender_px: f32,
     }
 
+    /// Laid out text of a `Text` node, together with the rect that the text lines are positioned in
+    #[repr(C)]
+    pub struct AzTextLayout {
+        pub bounds: AzLogicalRect,
+        pub inline_text: AzInlineText,
+    }
+
     /// CSS path to set the keyboard input focus
     #[repr(C)]
     pub struct AzFocusTargetPath {
@@ -12721,6 +14650,14 @@ mod test_sizes {
         pub default_value: AzCssProperty,
     }
 
+    /// Re-export of rust-allocated (stack based) `SystemCssProperty` struct
+    #[repr(C)]
+    pub struct AzSystemCssProperty {
+        pub key: AzString,
+        pub value: AzString,
+        pub default_value: AzCssProperty,
+    }
+
     /// Re-export of rust-allocated (stack based) `Node` struct
     #[repr(C)]
     pub struct AzNode {
@@ -12772,6 +14709,13 @@ mod test_sizes {
         Some(AzInlineText),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTextLayout` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTextLayout {
+        None,
+        Some(AzTextLayout),
+    }
+
     /// Re-export of rust-allocated (stack based) `XmlParseError` struct
     #[repr(C, u8)]
     pub enum AzXmlParseError {
@@ -12796,6 +14740,13 @@ mod test_sizes {
         pub theme: AzOptionWindowTheme,
         pub create_callback: AzOptionCallback,
         pub hot_reload: bool,
+        pub shortcuts: AzShortcutMap,
+        pub parent_handle: AzOptionRawWindowHandle,
+        pub window_data: AzOptionRefAny,
+        pub background_effect: AzWindowBackgroundEffect,
+        pub fade_in: AzOptionDuration,
+        pub first_frame: AzFirstFrameMode,
+        pub scrollbars: AzScrollbarConfig,
     }
 
     /// Defines the keyboard input focus target
@@ -12807,6 +14758,7 @@ mod test_sizes {
         Next,
         First,
         Last,
+        Direction(AzFocusDirection),
         NoFocus,
     }
 
@@ -12827,6 +14779,7 @@ mod test_sizes {
     pub enum AzCssDeclaration {
         Static(AzCssProperty),
         Dynamic(AzDynamicCssProperty),
+        System(AzSystemCssProperty),
     }
 
     /// Re-export of rust-allocated (stack based) `Button` struct
@@ -12882,11 +14835,14 @@ mod test_sizes {
         pub label_style: AzNodeDataInlineCssPropertyVec,
     }
 
-    /// Re-export of rust-allocated (stack based) `NumberInput` struct
+    /// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
     #[repr(C)]
-    pub struct AzNumberInput {
-        pub text_input: AzTextInput,
-        pub state: AzNumberInputStateWrapper,
+    pub struct AzNumberInputStateWrapper {
+        pub inner: AzNumberInputState,
+        pub on_value_change: AzOptionNumberInputOnValueChange,
+        pub on_focus_lost: AzOptionNumberInputOnFocusLost,
+        pub invalid_style: AzNodeDataInlineCssPropertyVec,
+        pub valid_style: AzNodeDataInlineCssPropertyVec,
     }
 
     /// Re-export of rust-allocated (stack based) `NodeIdNodeMap` struct
@@ -12896,6 +14852,16 @@ mod test_sizes {
         pub node: AzNode,
     }
 
+    /// Manages the queue of toast / snackbar notifications of a window. Store it in the application data and insert `Toasts::dom()` as the last child of the root node
+    #[repr(C)]
+    pub struct AzToasts {
+        pub state: AzRefAny,
+        pub layer_style: AzNodeDataInlineCssPropertyVec,
+        pub toast_style: AzNodeDataInlineCssPropertyVec,
+        pub label_style: AzNodeDataInlineCssPropertyVec,
+        pub action_style: AzNodeDataInlineCssPropertyVec,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<NodeIdNodeMap>`
     #[repr(C)]
     pub struct AzNodeIdNodeMapVec {
@@ -12958,6 +14924,15 @@ mod test_sizes {
     pub struct AzCssRuleBlock {
         pub path: AzCssPath,
         pub declarations: AzCssDeclarationVec,
+        pub media: AzCssMediaCondition,
+    }
+
+    /// Re-export of rust-allocated (stack based) `NumberInput` struct
+    #[repr(C)]
+    pub struct AzNumberInput {
+        pub text_input: AzTextInput,
+        pub state: AzNumberInputStateWrapper,
+        pub spinner_button_style: AzNodeDataInlineCssPropertyVec,
     }
 
     /// Re-export of rust-allocated (stack based) `TabContent` struct
@@ -13092,7 +15067,11 @@ mod test_sizes {
          use core::alloc::Layout;
         assert_eq!((Layout::new::<azul_impl::app::AzAppPtr>(), "AzApp"), (Layout::new::<AzApp>(), "AzApp"));
         assert_eq!((Layout::new::<azul_impl::resources::AppLogLevel>(), "AzAppLogLevel"), (Layout::new::<AzAppLogLevel>(), "AzAppLogLevel"));
+        assert_eq!((Layout::new::<azul_impl::resources::FrameRecordingCallback>(), "AzFrameRecordingCallback"), (Layout::new::<AzFrameRecordingCallback>(), "AzFrameRecordingCallback"));
+        assert_eq!((Layout::new::<azul_impl::resources::CrashHandler>(), "AzCrashHandler"), (Layout::new::<AzCrashHandler>(), "AzCrashHandler"));
         assert_eq!((Layout::new::<azul_impl::resources::LayoutSolverVersion>(), "AzLayoutSolver"), (Layout::new::<AzLayoutSolver>(), "AzLayoutSolver"));
+        assert_eq!((Layout::new::<azul_core::window::WindowBackgroundEffect>(), "AzWindowBackgroundEffect"), (Layout::new::<AzWindowBackgroundEffect>(), "AzWindowBackgroundEffect"));
+        assert_eq!((Layout::new::<azul_core::window::ScrollbarMode>(), "AzScrollbarMode"), (Layout::new::<AzScrollbarMode>(), "AzScrollbarMode"));
         assert_eq!((Layout::new::<azul_core::window::Vsync>(), "AzVsync"), (Layout::new::<AzVsync>(), "AzVsync"));
         assert_eq!((Layout::new::<azul_core::window::Srgb>(), "AzSrgb"), (Layout::new::<AzSrgb>(), "AzSrgb"));
         assert_eq!((Layout::new::<azul_core::window::HwAcceleration>(), "AzHwAcceleration"), (Layout::new::<AzHwAcceleration>(), "AzHwAcceleration"));
@@ -13113,15 +15092,20 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::LogicalSize>(), "AzLogicalSize"), (Layout::new::<AzLogicalSize>(), "AzLogicalSize"));
         assert_eq!((Layout::new::<azul_core::window::IconKey>(), "AzIconKey"), (Layout::new::<AzIconKey>(), "AzIconKey"));
         assert_eq!((Layout::new::<azul_core::window::VirtualKeyCode>(), "AzVirtualKeyCode"), (Layout::new::<AzVirtualKeyCode>(), "AzVirtualKeyCode"));
+        assert_eq!((Layout::new::<azul_core::window::Politeness>(), "AzPoliteness"), (Layout::new::<AzPoliteness>(), "AzPoliteness"));
+        assert_eq!((Layout::new::<azul_core::window::HapticKind>(), "AzHapticKind"), (Layout::new::<AzHapticKind>(), "AzHapticKind"));
         assert_eq!((Layout::new::<azul_core::window::WindowFrame>(), "AzWindowFrame"), (Layout::new::<AzWindowFrame>(), "AzWindowFrame"));
+        assert_eq!((Layout::new::<azul_core::window::FullscreenMode>(), "AzFullscreenMode"), (Layout::new::<AzFullscreenMode>(), "AzFullscreenMode"));
         assert_eq!((Layout::new::<azul_core::window::DebugState>(), "AzDebugState"), (Layout::new::<AzDebugState>(), "AzDebugState"));
+        assert_eq!((Layout::new::<azul_core::window::KeyboardModifiers>(), "AzKeyboardModifiers"), (Layout::new::<AzKeyboardModifiers>(), "AzKeyboardModifiers"));
         assert_eq!((Layout::new::<azul_core::window::MouseCursorType>(), "AzMouseCursorType"), (Layout::new::<AzMouseCursorType>(), "AzMouseCursorType"));
         assert_eq!((Layout::new::<azul_core::window::RendererType>(), "AzRendererType"), (Layout::new::<AzRendererType>(), "AzRendererType"));
         assert_eq!((Layout::new::<azul_core::window::MacWindowOptions>(), "AzMacWindowOptions"), (Layout::new::<AzMacWindowOptions>(), "AzMacWindowOptions"));
         assert_eq!((Layout::new::<azul_core::window::WasmWindowOptions>(), "AzWasmWindowOptions"), (Layout::new::<AzWasmWindowOptions>(), "AzWasmWindowOptions"));
         assert_eq!((Layout::new::<azul_core::window::FullScreenMode>(), "AzFullScreenMode"), (Layout::new::<AzFullScreenMode>(), "AzFullScreenMode"));
         assert_eq!((Layout::new::<azul_core::window::WindowTheme>(), "AzWindowTheme"), (Layout::new::<AzWindowTheme>(), "AzWindowTheme"));
-        assert_eq!((Layout::new::<azul_core::window::TouchState>(), "AzTouchState"), (Layout::new::<AzTouchState>(), "AzTouchState"));
+        assert_eq!((Layout::new::<azul_core::window::InputModality>(), "AzInputModality"), (Layout::new::<AzInputModality>(), "AzInputModality"));
+        assert_eq!((Layout::new::<azul_core::window::HdrCapabilities>(), "AzHdrCapabilities"), (Layout::new::<AzHdrCapabilities>(), "AzHdrCapabilities"));
         assert_eq!((Layout::new::<azul_impl::callbacks::MarshaledLayoutCallbackInner>(), "AzMarshaledLayoutCallbackInner"), (Layout::new::<AzMarshaledLayoutCallbackInner>(), "AzMarshaledLayoutCallbackInner"));
         assert_eq!((Layout::new::<azul_impl::callbacks::LayoutCallbackInner>(), "AzLayoutCallbackInner"), (Layout::new::<AzLayoutCallbackInner>(), "AzLayoutCallbackInner"));
         assert_eq!((Layout::new::<azul_impl::callbacks::Callback>(), "AzCallback"), (Layout::new::<AzCallback>(), "AzCallback"));
@@ -13130,9 +15114,14 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::styled_dom::NodeHierarchyItemId>(), "AzNodeId"), (Layout::new::<AzNodeId>(), "AzNodeId"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::DomId>(), "AzDomId"), (Layout::new::<AzDomId>(), "AzDomId"));
         assert_eq!((Layout::new::<azul_impl::ui_solver::PositionInfoInner>(), "AzPositionInfoInner"), (Layout::new::<AzPositionInfoInner>(), "AzPositionInfoInner"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::TextUnderlineStyle>(), "AzTextUnderlineStyle"), (Layout::new::<AzTextUnderlineStyle>(), "AzTextUnderlineStyle"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::CaretAnimation>(), "AzCaretAnimation"), (Layout::new::<AzCaretAnimation>(), "AzCaretAnimation"));
         assert_eq!((Layout::new::<azul_impl::callbacks::AnimationRepeat>(), "AzAnimationRepeat"), (Layout::new::<AzAnimationRepeat>(), "AzAnimationRepeat"));
         assert_eq!((Layout::new::<azul_impl::callbacks::AnimationRepeatCount>(), "AzAnimationRepeatCount"), (Layout::new::<AzAnimationRepeatCount>(), "AzAnimationRepeatCount"));
         assert_eq!((Layout::new::<azul_impl::callbacks::IFrameCallback>(), "AzIFrameCallback"), (Layout::new::<AzIFrameCallback>(), "AzIFrameCallback"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::MeasureCallback>(), "AzMeasureCallback"), (Layout::new::<AzMeasureCallback>(), "AzMeasureCallback"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::ArrangeCallback>(), "AzArrangeCallback"), (Layout::new::<AzArrangeCallback>(), "AzArrangeCallback"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::PaintCallback>(), "AzPaintCallback"), (Layout::new::<AzPaintCallback>(), "AzPaintCallback"));
         assert_eq!((Layout::new::<azul_impl::callbacks::RenderImageCallback>(), "AzRenderImageCallback"), (Layout::new::<AzRenderImageCallback>(), "AzRenderImageCallback"));
         assert_eq!((Layout::new::<azul_impl::callbacks::TimerCallback>(), "AzTimerCallback"), (Layout::new::<AzTimerCallback>(), "AzTimerCallback"));
         assert_eq!((Layout::new::<azul_impl::callbacks::WriteBackCallback>(), "AzWriteBackCallback"), (Layout::new::<AzWriteBackCallback>(), "AzWriteBackCallback"));
@@ -13144,12 +15133,17 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::dom::WindowEventFilter>(), "AzWindowEventFilter"), (Layout::new::<AzWindowEventFilter>(), "AzWindowEventFilter"));
         assert_eq!((Layout::new::<azul_impl::dom::ComponentEventFilter>(), "AzComponentEventFilter"), (Layout::new::<AzComponentEventFilter>(), "AzComponentEventFilter"));
         assert_eq!((Layout::new::<azul_impl::dom::ApplicationEventFilter>(), "AzApplicationEventFilter"), (Layout::new::<AzApplicationEventFilter>(), "AzApplicationEventFilter"));
+        assert_eq!((Layout::new::<azul_impl::dom::FocusDirection>(), "AzFocusDirection"), (Layout::new::<AzFocusDirection>(), "AzFocusDirection"));
+        assert_eq!((Layout::new::<azul_impl::dom::InputMode>(), "AzInputMode"), (Layout::new::<AzInputMode>(), "AzInputMode"));
+        assert_eq!((Layout::new::<azul_impl::dom::NodeStateFlag>(), "AzNodeStateFlag"), (Layout::new::<AzNodeStateFlag>(), "AzNodeStateFlag"));
+        assert_eq!((Layout::new::<azul_impl::dom::OverlayPlacement>(), "AzOverlayPlacement"), (Layout::new::<AzOverlayPlacement>(), "AzOverlayPlacement"));
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityRole>(), "AzAccessibilityRole"), (Layout::new::<AzAccessibilityRole>(), "AzAccessibilityRole"));
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityState>(), "AzAccessibilityState"), (Layout::new::<AzAccessibilityState>(), "AzAccessibilityState"));
         assert_eq!((Layout::new::<azul_impl::dom::TabIndex>(), "AzTabIndex"), (Layout::new::<AzTabIndex>(), "AzTabIndex"));
         assert_eq!((Layout::new::<azul_core::window::ContextMenuMouseButton>(), "AzContextMenuMouseButton"), (Layout::new::<AzContextMenuMouseButton>(), "AzContextMenuMouseButton"));
         assert_eq!((Layout::new::<azul_core::window::MenuPopupPosition>(), "AzMenuPopupPosition"), (Layout::new::<AzMenuPopupPosition>(), "AzMenuPopupPosition"));
         assert_eq!((Layout::new::<azul_core::window::MenuItemState>(), "AzMenuItemState"), (Layout::new::<AzMenuItemState>(), "AzMenuItemState"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaCondition>(), "AzCssMediaCondition"), (Layout::new::<AzCssMediaCondition>(), "AzCssMediaCondition"));
         assert_eq!((Layout::new::<azul_impl::css::NodeTypeTag>(), "AzNodeTypeKey"), (Layout::new::<AzNodeTypeKey>(), "AzNodeTypeKey"));
         assert_eq!((Layout::new::<azul_impl::css::CssNthChildPattern>(), "AzCssNthChildPattern"), (Layout::new::<AzCssNthChildPattern>(), "AzCssNthChildPattern"));
         assert_eq!((Layout::new::<azul_impl::css::CssPropertyType>(), "AzCssPropertyType"), (Layout::new::<AzCssPropertyType>(), "AzCssPropertyType"));
@@ -13175,9 +15169,14 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::RadialGradientSize>(), "AzRadialGradientSize"), (Layout::new::<AzRadialGradientSize>(), "AzRadialGradientSize"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundRepeat>(), "AzStyleBackgroundRepeat"), (Layout::new::<AzStyleBackgroundRepeat>(), "AzStyleBackgroundRepeat"));
         assert_eq!((Layout::new::<azul_impl::css::BorderStyle>(), "AzBorderStyle"), (Layout::new::<AzBorderStyle>(), "AzBorderStyle"));
-        assert_eq!((Layout::new::<azul_impl::css::StyleCursor>(), "AzStyleCursor"), (Layout::new::<AzStyleCursor>(), "AzStyleCursor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollbarWidth>(), "AzStyleScrollbarWidth"), (Layout::new::<AzStyleScrollbarWidth>(), "AzStyleScrollbarWidth"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleOverflowAnchor>(), "AzStyleOverflowAnchor"), (Layout::new::<AzStyleOverflowAnchor>(), "AzStyleOverflowAnchor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleWindowDragRegion>(), "AzStyleWindowDragRegion"), (Layout::new::<AzStyleWindowDragRegion>(), "AzStyleWindowDragRegion"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCaretAnimationDuration>(), "AzStyleCaretAnimationDuration"), (Layout::new::<AzStyleCaretAnimationDuration>(), "AzStyleCaretAnimationDuration"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackfaceVisibility>(), "AzStyleBackfaceVisibility"), (Layout::new::<AzStyleBackfaceVisibility>(), "AzStyleBackfaceVisibility"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransformStyle>(), "AzStyleTransformStyle"), (Layout::new::<AzStyleTransformStyle>(), "AzStyleTransformStyle"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextAlign>(), "AzStyleTextAlign"), (Layout::new::<AzStyleTextAlign>(), "AzStyleTextAlign"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleHyphens>(), "AzStyleHyphens"), (Layout::new::<AzStyleHyphens>(), "AzStyleHyphens"));
         assert_eq!((Layout::new::<crate::widgets::ribbon::Ribbon>(), "AzRibbon"), (Layout::new::<AzRibbon>(), "AzRibbon"));
         assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonOnTabClickedCallback>(), "AzRibbonOnTabClickedCallback"), (Layout::new::<AzRibbonOnTabClickedCallback>(), "AzRibbonOnTabClickedCallback"));
         assert_eq!((Layout::new::<crate::widgets::file_input::FileInputOnPathChangeCallback>(), "AzFileInputOnPathChangeCallback"), (Layout::new::<AzFileInputOnPathChangeCallback>(), "AzFileInputOnPathChangeCallback"));
@@ -13188,8 +15187,9 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnTextInputCallback>(), "AzTextInputOnTextInputCallback"), (Layout::new::<AzTextInputOnTextInputCallback>(), "AzTextInputOnTextInputCallback"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnVirtualKeyDownCallback>(), "AzTextInputOnVirtualKeyDownCallback"), (Layout::new::<AzTextInputOnVirtualKeyDownCallback>(), "AzTextInputOnVirtualKeyDownCallback"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnFocusLostCallback>(), "AzTextInputOnFocusLostCallback"), (Layout::new::<AzTextInputOnFocusLostCallback>(), "AzTextInputOnFocusLostCallback"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnSpellingSuggestionsCallback>(), "AzTextInputOnSpellingSuggestionsCallback"), (Layout::new::<AzTextInputOnSpellingSuggestionsCallback>(), "AzTextInputOnSpellingSuggestionsCallback"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputValid>(), "AzTextInputValid"), (Layout::new::<AzTextInputValid>(), "AzTextInputValid"));
-        assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputState>(), "AzNumberInputState"), (Layout::new::<AzNumberInputState>(), "AzNumberInputState"));
+        assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputMode>(), "AzNumberInputMode"), (Layout::new::<AzNumberInputMode>(), "AzNumberInputMode"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputOnValueChangeCallback>(), "AzNumberInputOnValueChangeCallback"), (Layout::new::<AzNumberInputOnValueChangeCallback>(), "AzNumberInputOnValueChangeCallback"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputOnFocusLostCallback>(), "AzNumberInputOnFocusLostCallback"), (Layout::new::<AzNumberInputOnFocusLostCallback>(), "AzNumberInputOnFocusLostCallback"));
         assert_eq!((Layout::new::<crate::widgets::progressbar::ProgressBarState>(), "AzProgressBarState"), (Layout::new::<AzProgressBarState>(), "AzProgressBarState"));
@@ -13214,6 +15214,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnColumnClickCallback>(), "AzListViewOnColumnClickCallback"), (Layout::new::<AzListViewOnColumnClickCallback>(), "AzListViewOnColumnClickCallback"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnRowClickCallback>(), "AzListViewOnRowClickCallback"), (Layout::new::<AzListViewOnRowClickCallback>(), "AzListViewOnRowClickCallback"));
         assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownOnChoiceChangeCallback>(), "AzDropDownOnChoiceChangeCallback"), (Layout::new::<AzDropDownOnChoiceChangeCallback>(), "AzDropDownOnChoiceChangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::toasts::ToastId>(), "AzToastId"), (Layout::new::<AzToastId>(), "AzToastId"));
+        assert_eq!((Layout::new::<crate::widgets::toasts::ToastOnActionCallback>(), "AzToastOnActionCallback"), (Layout::new::<AzToastOnActionCallback>(), "AzToastOnActionCallback"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::NodeHierarchyItem>(), "AzNodeHierarchyItem"), (Layout::new::<AzNodeHierarchyItem>(), "AzNodeHierarchyItem"));
         assert_eq!((Layout::new::<azul_impl::style::CascadeInfo>(), "AzCascadeInfo"), (Layout::new::<AzCascadeInfo>(), "AzCascadeInfo"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::StyledNodeState>(), "AzStyledNodeState"), (Layout::new::<AzStyledNodeState>(), "AzStyledNodeState"));
@@ -13221,6 +15223,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::styled_dom::CssPropertyCachePtr>(), "AzCssPropertyCache"), (Layout::new::<AzCssPropertyCache>(), "AzCssPropertyCache"));
         assert_eq!((Layout::new::<azul_impl::gl::GlVoidPtrConst>(), "AzGlVoidPtrConst"), (Layout::new::<AzGlVoidPtrConst>(), "AzGlVoidPtrConst"));
         assert_eq!((Layout::new::<azul_impl::gl::GlVoidPtrMut>(), "AzGlVoidPtrMut"), (Layout::new::<AzGlVoidPtrMut>(), "AzGlVoidPtrMut"));
+        assert_eq!((Layout::new::<azul_impl::gl::GlQuirks>(), "AzGlQuirks"), (Layout::new::<AzGlQuirks>(), "AzGlQuirks"));
         assert_eq!((Layout::new::<azul_impl::gl::GlShaderPrecisionFormatReturn>(), "AzGlShaderPrecisionFormatReturn"), (Layout::new::<AzGlShaderPrecisionFormatReturn>(), "AzGlShaderPrecisionFormatReturn"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexAttributeType>(), "AzVertexAttributeType"), (Layout::new::<AzVertexAttributeType>(), "AzVertexAttributeType"));
         assert_eq!((Layout::new::<azul_impl::gl::IndexBufferFormat>(), "AzIndexBufferFormat"), (Layout::new::<AzIndexBufferFormat>(), "AzIndexBufferFormat"));
@@ -13281,8 +15284,13 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::task::Thread>(), "AzThread"), (Layout::new::<AzThread>(), "AzThread"));
         assert_eq!((Layout::new::<azul_impl::task::ThreadSender>(), "AzThreadSender"), (Layout::new::<AzThreadSender>(), "AzThreadSender"));
         assert_eq!((Layout::new::<azul_impl::task::ThreadReceiver>(), "AzThreadReceiver"), (Layout::new::<AzThreadReceiver>(), "AzThreadReceiver"));
+        assert_eq!((Layout::new::<azul_impl::task::EventLoopProxy>(), "AzEventLoopProxy"), (Layout::new::<AzEventLoopProxy>(), "AzEventLoopProxy"));
         assert_eq!((Layout::new::<azul_impl::task::CreateThreadCallback>(), "AzCreateThreadFn"), (Layout::new::<AzCreateThreadFn>(), "AzCreateThreadFn"));
         assert_eq!((Layout::new::<azul_impl::task::GetSystemTimeCallback>(), "AzGetSystemTimeFn"), (Layout::new::<AzGetSystemTimeFn>(), "AzGetSystemTimeFn"));
+        assert_eq!((Layout::new::<azul_impl::task::GetSystemIdleTimeCallback>(), "AzGetSystemIdleTimeFn"), (Layout::new::<AzGetSystemIdleTimeFn>(), "AzGetSystemIdleTimeFn"));
+        assert_eq!((Layout::new::<azul_impl::task::GetLogLinesCallback>(), "AzGetLogLinesFn"), (Layout::new::<AzGetLogLinesFn>(), "AzGetLogLinesFn"));
+        assert_eq!((Layout::new::<azul_impl::task::KeycodeToCharCallback>(), "AzKeycodeToCharFn"), (Layout::new::<AzKeycodeToCharFn>(), "AzKeycodeToCharFn"));
+        assert_eq!((Layout::new::<azul_impl::task::SetLogLevelCallback>(), "AzSetLogLevelFn"), (Layout::new::<AzSetLogLevelFn>(), "AzSetLogLevelFn"));
         assert_eq!((Layout::new::<azul_impl::task::CheckThreadFinishedCallback>(), "AzCheckThreadFinishedFn"), (Layout::new::<AzCheckThreadFinishedFn>(), "AzCheckThreadFinishedFn"));
         assert_eq!((Layout::new::<azul_impl::task::LibrarySendThreadMsgCallback>(), "AzLibrarySendThreadMsgFn"), (Layout::new::<AzLibrarySendThreadMsgFn>(), "AzLibrarySendThreadMsgFn"));
         assert_eq!((Layout::new::<azul_impl::task::LibraryReceiveThreadMsgCallback>(), "AzLibraryReceiveThreadMsgFn"), (Layout::new::<AzLibraryReceiveThreadMsgFn>(), "AzLibraryReceiveThreadMsgFn"));
@@ -13312,9 +15320,12 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::str::FmtArgVecDestructor>(), "AzFmtArgVecDestructor"), (Layout::new::<AzFmtArgVecDestructor>(), "AzFmtArgVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineLineVecDestructor>(), "AzInlineLineVecDestructor"), (Layout::new::<AzInlineLineVecDestructor>(), "AzInlineLineVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineWordVecDestructor>(), "AzInlineWordVecDestructor"), (Layout::new::<AzInlineWordVecDestructor>(), "AzInlineWordVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::PaintPrimitiveVecDestructor>(), "AzPaintPrimitiveVecDestructor"), (Layout::new::<AzPaintPrimitiveVecDestructor>(), "AzPaintPrimitiveVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineGlyphVecDestructor>(), "AzInlineGlyphVecDestructor"), (Layout::new::<AzInlineGlyphVecDestructor>(), "AzInlineGlyphVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::TextUnderlineVecDestructor>(), "AzTextUnderlineVecDestructor"), (Layout::new::<AzTextUnderlineVecDestructor>(), "AzTextUnderlineVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineTextHitVecDestructor>(), "AzInlineTextHitVecDestructor"), (Layout::new::<AzInlineTextHitVecDestructor>(), "AzInlineTextHitVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::MonitorVecDestructor>(), "AzMonitorVecDestructor"), (Layout::new::<AzMonitorVecDestructor>(), "AzMonitorVecDestructor"));
+        assert_eq!((Layout::new::<azul_core::window::TouchPointVecDestructor>(), "AzTouchPointVecDestructor"), (Layout::new::<AzTouchPointVecDestructor>(), "AzTouchPointVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::VideoModeVecDestructor>(), "AzVideoModeVecDestructor"), (Layout::new::<AzVideoModeVecDestructor>(), "AzVideoModeVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::dom::DomVecDestructor>(), "AzDomVecDestructor"), (Layout::new::<AzDomVecDestructor>(), "AzDomVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::dom::IdOrClassVecDestructor>(), "AzIdOrClassVecDestructor"), (Layout::new::<AzIdOrClassVecDestructor>(), "AzIdOrClassVecDestructor"));
@@ -13335,6 +15346,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::U32VecDestructor>(), "AzU32VecDestructor"), (Layout::new::<AzU32VecDestructor>(), "AzU32VecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::XWindowTypeVecDestructor>(), "AzXWindowTypeVecDestructor"), (Layout::new::<AzXWindowTypeVecDestructor>(), "AzXWindowTypeVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::VirtualKeyCodeVecDestructor>(), "AzVirtualKeyCodeVecDestructor"), (Layout::new::<AzVirtualKeyCodeVecDestructor>(), "AzVirtualKeyCodeVecDestructor"));
+        assert_eq!((Layout::new::<azul_core::window::ShortcutConflictVecDestructor>(), "AzShortcutConflictVecDestructor"), (Layout::new::<AzShortcutConflictVecDestructor>(), "AzShortcutConflictVecDestructor"));
+        assert_eq!((Layout::new::<azul_core::window::ShortcutVecDestructor>(), "AzShortcutVecDestructor"), (Layout::new::<AzShortcutVecDestructor>(), "AzShortcutVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::style::CascadeInfoVecDestructor>(), "AzCascadeInfoVecDestructor"), (Layout::new::<AzCascadeInfoVecDestructor>(), "AzCascadeInfoVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::ScanCodeVecDestructor>(), "AzScanCodeVecDestructor"), (Layout::new::<AzScanCodeVecDestructor>(), "AzScanCodeVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::CssDeclarationVecDestructor>(), "AzCssDeclarationVecDestructor"), (Layout::new::<AzCssDeclarationVecDestructor>(), "AzCssDeclarationVecDestructor"));
@@ -13369,6 +15382,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::gl::OptionUsize>(), "AzOptionUsize"), (Layout::new::<AzOptionUsize>(), "AzOptionUsize"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlTextPos>(), "AzSvgParseErrorPosition"), (Layout::new::<AzSvgParseErrorPosition>(), "AzSvgParseErrorPosition"));
         assert_eq!((Layout::new::<azul_impl::task::ExternalSystemCallbacks>(), "AzSystemCallbacks"), (Layout::new::<AzSystemCallbacks>(), "AzSystemCallbacks"));
+        assert_eq!((Layout::new::<azul_core::window::FirstFrameMode>(), "AzFirstFrameMode"), (Layout::new::<AzFirstFrameMode>(), "AzFirstFrameMode"));
         assert_eq!((Layout::new::<azul_core::window::RendererOptions>(), "AzRendererOptions"), (Layout::new::<AzRendererOptions>(), "AzRendererOptions"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutRect>(), "AzLayoutRect"), (Layout::new::<AzLayoutRect>(), "AzLayoutRect"));
         assert_eq!((Layout::new::<azul_core::window::RawWindowHandle>(), "AzRawWindowHandle"), (Layout::new::<AzRawWindowHandle>(), "AzRawWindowHandle"));
@@ -13378,13 +15392,21 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::CursorPosition>(), "AzCursorPosition"), (Layout::new::<AzCursorPosition>(), "AzCursorPosition"));
         assert_eq!((Layout::new::<azul_core::window::WindowPosition>(), "AzWindowPosition"), (Layout::new::<AzWindowPosition>(), "AzWindowPosition"));
         assert_eq!((Layout::new::<azul_core::window::ImePosition>(), "AzImePosition"), (Layout::new::<AzImePosition>(), "AzImePosition"));
+        assert_eq!((Layout::new::<azul_core::window::TouchPoint>(), "AzTouchPoint"), (Layout::new::<AzTouchPoint>(), "AzTouchPoint"));
         assert_eq!((Layout::new::<azul_core::window::VideoMode>(), "AzVideoMode"), (Layout::new::<AzVideoMode>(), "AzVideoMode"));
         assert_eq!((Layout::new::<azul_impl::callbacks::DomNodeId>(), "AzDomNodeId"), (Layout::new::<AzDomNodeId>(), "AzDomNodeId"));
         assert_eq!((Layout::new::<azul_impl::ui_solver::PositionInfo>(), "AzPositionInfo"), (Layout::new::<AzPositionInfo>(), "AzPositionInfo"));
+        assert_eq!((Layout::new::<azul_impl::ui_solver::NodeRect>(), "AzNodeRect"), (Layout::new::<AzNodeRect>(), "AzNodeRect"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::ScrollInfo>(), "AzScrollInfo"), (Layout::new::<AzScrollInfo>(), "AzScrollInfo"));
         assert_eq!((Layout::new::<azul_impl::callbacks::HidpiAdjustedBounds>(), "AzHidpiAdjustedBounds"), (Layout::new::<AzHidpiAdjustedBounds>(), "AzHidpiAdjustedBounds"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineGlyph>(), "AzInlineGlyph"), (Layout::new::<AzInlineGlyph>(), "AzInlineGlyph"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::TextUnderline>(), "AzTextUnderline"), (Layout::new::<AzTextUnderline>(), "AzTextUnderline"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineTextHit>(), "AzInlineTextHit"), (Layout::new::<AzInlineTextHit>(), "AzInlineTextHit"));
         assert_eq!((Layout::new::<azul_impl::callbacks::IFrameCallbackInfo>(), "AzIFrameCallbackInfo"), (Layout::new::<AzIFrameCallbackInfo>(), "AzIFrameCallbackInfo"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::MeasureCallbackInfo>(), "AzMeasureCallbackInfo"), (Layout::new::<AzMeasureCallbackInfo>(), "AzMeasureCallbackInfo"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::ArrangeCallbackInfo>(), "AzArrangeCallbackInfo"), (Layout::new::<AzArrangeCallbackInfo>(), "AzArrangeCallbackInfo"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::PaintCallbackInfo>(), "AzPaintCallbackInfo"), (Layout::new::<AzPaintCallbackInfo>(), "AzPaintCallbackInfo"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::PaintRect>(), "AzPaintRect"), (Layout::new::<AzPaintRect>(), "AzPaintRect"));
         assert_eq!((Layout::new::<azul_impl::callbacks::TimerCallbackReturn>(), "AzTimerCallbackReturn"), (Layout::new::<AzTimerCallbackReturn>(), "AzTimerCallbackReturn"));
         assert_eq!((Layout::new::<azul_impl::callbacks::RefAny>(), "AzRefAny"), (Layout::new::<AzRefAny>(), "AzRefAny"));
         assert_eq!((Layout::new::<azul_impl::dom::IFrameNode>(), "AzIFrameNode"), (Layout::new::<AzIFrameNode>(), "AzIFrameNode"));
@@ -13392,6 +15414,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::MenuCallback>(), "AzMenuCallback"), (Layout::new::<AzMenuCallback>(), "AzMenuCallback"));
         assert_eq!((Layout::new::<azul_core::window::MenuItemIcon>(), "AzMenuItemIcon"), (Layout::new::<AzMenuItemIcon>(), "AzMenuItemIcon"));
         assert_eq!((Layout::new::<azul_impl::css::CssNthChildSelector>(), "AzCssNthChildSelector"), (Layout::new::<AzCssNthChildSelector>(), "AzCssNthChildSelector"));
+        assert_eq!((Layout::new::<azul_impl::css::SystemColors>(), "AzSystemColors"), (Layout::new::<AzSystemColors>(), "AzSystemColors"));
+        assert_eq!((Layout::new::<azul_impl::css::SystemStyle>(), "AzSystemStyle"), (Layout::new::<AzSystemStyle>(), "AzSystemStyle"));
         assert_eq!((Layout::new::<azul_impl::css::PixelValue>(), "AzPixelValue"), (Layout::new::<AzPixelValue>(), "AzPixelValue"));
         assert_eq!((Layout::new::<azul_impl::css::PixelValueNoPercent>(), "AzPixelValueNoPercent"), (Layout::new::<AzPixelValueNoPercent>(), "AzPixelValueNoPercent"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBoxShadow>(), "AzStyleBoxShadow"), (Layout::new::<AzStyleBoxShadow>(), "AzStyleBoxShadow"));
@@ -13445,6 +15469,10 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleBorderTopRightRadius>(), "AzStyleBorderTopRightRadius"), (Layout::new::<AzStyleBorderTopRightRadius>(), "AzStyleBorderTopRightRadius"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBorderTopStyle>(), "AzStyleBorderTopStyle"), (Layout::new::<AzStyleBorderTopStyle>(), "AzStyleBorderTopStyle"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBorderTopWidth>(), "AzLayoutBorderTopWidth"), (Layout::new::<AzLayoutBorderTopWidth>(), "AzLayoutBorderTopWidth"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollbarColor>(), "AzStyleScrollbarColor"), (Layout::new::<AzStyleScrollbarColor>(), "AzStyleScrollbarColor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollbarThumbHoverColor>(), "AzStyleScrollbarThumbHoverColor"), (Layout::new::<AzStyleScrollbarThumbHoverColor>(), "AzStyleScrollbarThumbHoverColor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollbarThumbActiveColor>(), "AzStyleScrollbarThumbActiveColor"), (Layout::new::<AzStyleScrollbarThumbActiveColor>(), "AzStyleScrollbarThumbActiveColor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollPadding>(), "AzStyleScrollPadding"), (Layout::new::<AzStyleScrollPadding>(), "AzStyleScrollPadding"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontSize>(), "AzStyleFontSize"), (Layout::new::<AzStyleFontSize>(), "AzStyleFontSize"));
         assert_eq!((Layout::new::<azul_impl::css::StyleLetterSpacing>(), "AzStyleLetterSpacing"), (Layout::new::<AzStyleLetterSpacing>(), "AzStyleLetterSpacing"));
         assert_eq!((Layout::new::<azul_impl::css::StyleLineHeight>(), "AzStyleLineHeight"), (Layout::new::<AzStyleLineHeight>(), "AzStyleLineHeight"));
@@ -13452,6 +15480,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleOpacity>(), "AzStyleOpacity"), (Layout::new::<AzStyleOpacity>(), "AzStyleOpacity"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformOrigin>(), "AzStyleTransformOrigin"), (Layout::new::<AzStyleTransformOrigin>(), "AzStyleTransformOrigin"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformOrigin>(), "AzStylePerspectiveOrigin"), (Layout::new::<AzStylePerspectiveOrigin>(), "AzStylePerspectiveOrigin"));
+        assert_eq!((Layout::new::<azul_impl::css::StylePerspective>(), "AzStylePerspective"), (Layout::new::<AzStylePerspective>(), "AzStylePerspective"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformMatrix2D>(), "AzStyleTransformMatrix2D"), (Layout::new::<AzStyleTransformMatrix2D>(), "AzStyleTransformMatrix2D"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformMatrix3D>(), "AzStyleTransformMatrix3D"), (Layout::new::<AzStyleTransformMatrix3D>(), "AzStyleTransformMatrix3D"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformTranslate2D>(), "AzStyleTransformTranslate2D"), (Layout::new::<AzStyleTransformTranslate2D>(), "AzStyleTransformTranslate2D"));
@@ -13493,6 +15522,14 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::LayoutWidthValue>(), "AzLayoutWidthValue"), (Layout::new::<AzLayoutWidthValue>(), "AzLayoutWidthValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexWrapValue>(), "AzLayoutFlexWrapValue"), (Layout::new::<AzLayoutFlexWrapValue>(), "AzLayoutFlexWrapValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutOverflowValue>(), "AzLayoutOverflowValue"), (Layout::new::<AzLayoutOverflowValue>(), "AzLayoutOverflowValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollbarWidthValue>(), "AzStyleScrollbarWidthValue"), (Layout::new::<AzStyleScrollbarWidthValue>(), "AzStyleScrollbarWidthValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollbarColorValue>(), "AzStyleScrollbarColorValue"), (Layout::new::<AzStyleScrollbarColorValue>(), "AzStyleScrollbarColorValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollbarThumbHoverColorValue>(), "AzStyleScrollbarThumbHoverColorValue"), (Layout::new::<AzStyleScrollbarThumbHoverColorValue>(), "AzStyleScrollbarThumbHoverColorValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollbarThumbActiveColorValue>(), "AzStyleScrollbarThumbActiveColorValue"), (Layout::new::<AzStyleScrollbarThumbActiveColorValue>(), "AzStyleScrollbarThumbActiveColorValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleWindowDragRegionValue>(), "AzStyleWindowDragRegionValue"), (Layout::new::<AzStyleWindowDragRegionValue>(), "AzStyleWindowDragRegionValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCaretAnimationDurationValue>(), "AzStyleCaretAnimationDurationValue"), (Layout::new::<AzStyleCaretAnimationDurationValue>(), "AzStyleCaretAnimationDurationValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleScrollPaddingValue>(), "AzStyleScrollPaddingValue"), (Layout::new::<AzStyleScrollPaddingValue>(), "AzStyleScrollPaddingValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleOverflowAnchorValue>(), "AzStyleOverflowAnchorValue"), (Layout::new::<AzStyleOverflowAnchorValue>(), "AzStyleOverflowAnchorValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBorderBottomColorValue>(), "AzStyleBorderBottomColorValue"), (Layout::new::<AzStyleBorderBottomColorValue>(), "AzStyleBorderBottomColorValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBorderBottomLeftRadiusValue>(), "AzStyleBorderBottomLeftRadiusValue"), (Layout::new::<AzStyleBorderBottomLeftRadiusValue>(), "AzStyleBorderBottomLeftRadiusValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBorderBottomRightRadiusValue>(), "AzStyleBorderBottomRightRadiusValue"), (Layout::new::<AzStyleBorderBottomRightRadiusValue>(), "AzStyleBorderBottomRightRadiusValue"));
@@ -13509,11 +15546,11 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleBorderTopRightRadiusValue>(), "AzStyleBorderTopRightRadiusValue"), (Layout::new::<AzStyleBorderTopRightRadiusValue>(), "AzStyleBorderTopRightRadiusValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBorderTopStyleValue>(), "AzStyleBorderTopStyleValue"), (Layout::new::<AzStyleBorderTopStyleValue>(), "AzStyleBorderTopStyleValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBorderTopWidthValue>(), "AzLayoutBorderTopWidthValue"), (Layout::new::<AzLayoutBorderTopWidthValue>(), "AzLayoutBorderTopWidthValue"));
-        assert_eq!((Layout::new::<azul_impl::css::StyleCursorValue>(), "AzStyleCursorValue"), (Layout::new::<AzStyleCursorValue>(), "AzStyleCursorValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontSizeValue>(), "AzStyleFontSizeValue"), (Layout::new::<AzStyleFontSizeValue>(), "AzStyleFontSizeValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleLetterSpacingValue>(), "AzStyleLetterSpacingValue"), (Layout::new::<AzStyleLetterSpacingValue>(), "AzStyleLetterSpacingValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleLineHeightValue>(), "AzStyleLineHeightValue"), (Layout::new::<AzStyleLineHeightValue>(), "AzStyleLineHeightValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTabWidthValue>(), "AzStyleTabWidthValue"), (Layout::new::<AzStyleTabWidthValue>(), "AzStyleTabWidthValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleHyphensValue>(), "AzStyleHyphensValue"), (Layout::new::<AzStyleHyphensValue>(), "AzStyleHyphensValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextAlignValue>(), "AzStyleTextAlignValue"), (Layout::new::<AzStyleTextAlignValue>(), "AzStyleTextAlignValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextColorValue>(), "AzStyleTextColorValue"), (Layout::new::<AzStyleTextColorValue>(), "AzStyleTextColorValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleWordSpacingValue>(), "AzStyleWordSpacingValue"), (Layout::new::<AzStyleWordSpacingValue>(), "AzStyleWordSpacingValue"));
@@ -13521,6 +15558,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformOriginValue>(), "AzStyleTransformOriginValue"), (Layout::new::<AzStyleTransformOriginValue>(), "AzStyleTransformOriginValue"));
         assert_eq!((Layout::new::<azul_impl::css::StylePerspectiveOriginValue>(), "AzStylePerspectiveOriginValue"), (Layout::new::<AzStylePerspectiveOriginValue>(), "AzStylePerspectiveOriginValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackfaceVisibilityValue>(), "AzStyleBackfaceVisibilityValue"), (Layout::new::<AzStyleBackfaceVisibilityValue>(), "AzStyleBackfaceVisibilityValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StylePerspectiveValue>(), "AzStylePerspectiveValue"), (Layout::new::<AzStylePerspectiveValue>(), "AzStylePerspectiveValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransformStyleValue>(), "AzStyleTransformStyleValue"), (Layout::new::<AzStyleTransformStyleValue>(), "AzStyleTransformStyleValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleMixBlendModeValue>(), "AzStyleMixBlendModeValue"), (Layout::new::<AzStyleMixBlendModeValue>(), "AzStyleMixBlendModeValue"));
         assert_eq!((Layout::new::<crate::widgets::button::ButtonOnClick>(), "AzButtonOnClick"), (Layout::new::<AzButtonOnClick>(), "AzButtonOnClick"));
         assert_eq!((Layout::new::<crate::widgets::file_input::FileInputOnPathChange>(), "AzFileInputOnPathChange"), (Layout::new::<AzFileInputOnPathChange>(), "AzFileInputOnPathChange"));
@@ -13531,7 +15570,9 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnTextInput>(), "AzTextInputOnTextInput"), (Layout::new::<AzTextInputOnTextInput>(), "AzTextInputOnTextInput"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnVirtualKeyDown>(), "AzTextInputOnVirtualKeyDown"), (Layout::new::<AzTextInputOnVirtualKeyDown>(), "AzTextInputOnVirtualKeyDown"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnFocusLost>(), "AzTextInputOnFocusLost"), (Layout::new::<AzTextInputOnFocusLost>(), "AzTextInputOnFocusLost"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnSpellingSuggestions>(), "AzTextInputOnSpellingSuggestions"), (Layout::new::<AzTextInputOnSpellingSuggestions>(), "AzTextInputOnSpellingSuggestions"));
         assert_eq!((Layout::new::<crate::widgets::text_input::OnTextInputReturn>(), "AzOnTextInputReturn"), (Layout::new::<AzOnTextInputReturn>(), "AzOnTextInputReturn"));
+        assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputState>(), "AzNumberInputState"), (Layout::new::<AzNumberInputState>(), "AzNumberInputState"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputOnValueChange>(), "AzNumberInputOnValueChange"), (Layout::new::<AzNumberInputOnValueChange>(), "AzNumberInputOnValueChange"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputOnFocusLost>(), "AzNumberInputOnFocusLost"), (Layout::new::<AzNumberInputOnFocusLost>(), "AzNumberInputOnFocusLost"));
         assert_eq!((Layout::new::<crate::widgets::tabs::TabOnClick>(), "AzTabOnClick"), (Layout::new::<AzTabOnClick>(), "AzTabOnClick"));
@@ -13549,6 +15590,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnColumnClick>(), "AzListViewOnColumnClick"), (Layout::new::<AzListViewOnColumnClick>(), "AzListViewOnColumnClick"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnRowClick>(), "AzListViewOnRowClick"), (Layout::new::<AzListViewOnRowClick>(), "AzListViewOnRowClick"));
         assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownOnChoiceChange>(), "AzDropDownOnChoiceChange"), (Layout::new::<AzDropDownOnChoiceChange>(), "AzDropDownOnChoiceChange"));
+        assert_eq!((Layout::new::<crate::widgets::toasts::ToastOnAction>(), "AzToastOnAction"), (Layout::new::<AzToastOnAction>(), "AzToastOnAction"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::ParentWithNodeDepth>(), "AzParentWithNodeDepth"), (Layout::new::<AzParentWithNodeDepth>(), "AzParentWithNodeDepth"));
         assert_eq!((Layout::new::<azul_impl::gl::GlContextPtr>(), "AzGl"), (Layout::new::<AzGl>(), "AzGl"));
         assert_eq!((Layout::new::<azul_impl::gl::RefstrVecRef>(), "AzRefstrVecRef"), (Layout::new::<AzRefstrVecRef>(), "AzRefstrVecRef"));
@@ -13571,7 +15613,9 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::MenuItemVec>(), "AzMenuItemVec"), (Layout::new::<AzMenuItemVec>(), "AzMenuItemVec"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlNodeVec>(), "AzXmlNodeVec"), (Layout::new::<AzXmlNodeVec>(), "AzXmlNodeVec"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineGlyphVec>(), "AzInlineGlyphVec"), (Layout::new::<AzInlineGlyphVec>(), "AzInlineGlyphVec"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::TextUnderlineVec>(), "AzTextUnderlineVec"), (Layout::new::<AzTextUnderlineVec>(), "AzTextUnderlineVec"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineTextHitVec>(), "AzInlineTextHitVec"), (Layout::new::<AzInlineTextHitVec>(), "AzInlineTextHitVec"));
+        assert_eq!((Layout::new::<azul_core::window::TouchPointVec>(), "AzTouchPointVec"), (Layout::new::<AzTouchPointVec>(), "AzTouchPointVec"));
         assert_eq!((Layout::new::<azul_core::window::VideoModeVec>(), "AzVideoModeVec"), (Layout::new::<AzVideoModeVec>(), "AzVideoModeVec"));
         assert_eq!((Layout::new::<azul_impl::dom::DomVec>(), "AzDomVec"), (Layout::new::<AzDomVec>(), "AzDomVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundPositionVec>(), "AzStyleBackgroundPositionVec"), (Layout::new::<AzStyleBackgroundPositionVec>(), "AzStyleBackgroundPositionVec"));
@@ -13600,6 +15644,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::list_view::OptionListViewOnLazyLoadScroll>(), "AzOptionListViewOnLazyLoadScroll"), (Layout::new::<AzOptionListViewOnLazyLoadScroll>(), "AzOptionListViewOnLazyLoadScroll"));
         assert_eq!((Layout::new::<azul_impl::css::OptionPixelValueNoPercent>(), "AzOptionPixelValueNoPercent"), (Layout::new::<AzOptionPixelValueNoPercent>(), "AzOptionPixelValueNoPercent"));
         assert_eq!((Layout::new::<crate::widgets::drop_down::OptionDropDownOnChoiceChange>(), "AzOptionDropDownOnChoiceChange"), (Layout::new::<AzOptionDropDownOnChoiceChange>(), "AzOptionDropDownOnChoiceChange"));
+        assert_eq!((Layout::new::<crate::widgets::toasts::OptionToastOnAction>(), "AzOptionToastOnAction"), (Layout::new::<AzOptionToastOnAction>(), "AzOptionToastOnAction"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OptionOnNodeAdded>(), "AzOptionNodeGraphOnNodeAdded"), (Layout::new::<AzOptionNodeGraphOnNodeAdded>(), "AzOptionNodeGraphOnNodeAdded"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OptionOnNodeRemoved>(), "AzOptionNodeGraphOnNodeRemoved"), (Layout::new::<AzOptionNodeGraphOnNodeRemoved>(), "AzOptionNodeGraphOnNodeRemoved"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OptionOnNodeGraphDragged>(), "AzOptionNodeGraphOnNodeGraphDragged"), (Layout::new::<AzOptionNodeGraphOnNodeGraphDragged>(), "AzOptionNodeGraphOnNodeGraphDragged"));
@@ -13616,12 +15661,15 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputOnTextInput>(), "AzOptionTextInputOnTextInput"), (Layout::new::<AzOptionTextInputOnTextInput>(), "AzOptionTextInputOnTextInput"));
         assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputOnVirtualKeyDown>(), "AzOptionTextInputOnVirtualKeyDown"), (Layout::new::<AzOptionTextInputOnVirtualKeyDown>(), "AzOptionTextInputOnVirtualKeyDown"));
         assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputOnFocusLost>(), "AzOptionTextInputOnFocusLost"), (Layout::new::<AzOptionTextInputOnFocusLost>(), "AzOptionTextInputOnFocusLost"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputOnSpellingSuggestions>(), "AzOptionTextInputOnSpellingSuggestions"), (Layout::new::<AzOptionTextInputOnSpellingSuggestions>(), "AzOptionTextInputOnSpellingSuggestions"));
         assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputSelection>(), "AzOptionTextInputSelection"), (Layout::new::<AzOptionTextInputSelection>(), "AzOptionTextInputSelection"));
         assert_eq!((Layout::new::<crate::widgets::number_input::OptionNumberInputOnFocusLost>(), "AzOptionNumberInputOnFocusLost"), (Layout::new::<AzOptionNumberInputOnFocusLost>(), "AzOptionNumberInputOnFocusLost"));
         assert_eq!((Layout::new::<crate::widgets::number_input::OptionNumberInputOnValueChange>(), "AzOptionNumberInputOnValueChange"), (Layout::new::<AzOptionNumberInputOnValueChange>(), "AzOptionNumberInputOnValueChange"));
         assert_eq!((Layout::new::<azul_core::window::OptionMenuItemIcon>(), "AzOptionMenuItemIcon"), (Layout::new::<AzOptionMenuItemIcon>(), "AzOptionMenuItemIcon"));
         assert_eq!((Layout::new::<azul_core::window::OptionMenuCallback>(), "AzOptionMenuCallback"), (Layout::new::<AzOptionMenuCallback>(), "AzOptionMenuCallback"));
         assert_eq!((Layout::new::<azul_impl::ui_solver::OptionPositionInfo>(), "AzOptionPositionInfo"), (Layout::new::<AzOptionPositionInfo>(), "AzOptionPositionInfo"));
+        assert_eq!((Layout::new::<azul_impl::ui_solver::OptionNodeRect>(), "AzOptionNodeRect"), (Layout::new::<AzOptionNodeRect>(), "AzOptionNodeRect"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::OptionScrollInfo>(), "AzOptionScrollInfo"), (Layout::new::<AzOptionScrollInfo>(), "AzOptionScrollInfo"));
         assert_eq!((Layout::new::<azul_impl::task::OptionTimerId>(), "AzOptionTimerId"), (Layout::new::<AzOptionTimerId>(), "AzOptionTimerId"));
         assert_eq!((Layout::new::<azul_impl::task::OptionThreadId>(), "AzOptionThreadId"), (Layout::new::<AzOptionThreadId>(), "AzOptionThreadId"));
         assert_eq!((Layout::new::<azul_impl::resources::OptionImageRef>(), "AzOptionImageRef"), (Layout::new::<AzOptionImageRef>(), "AzOptionImageRef"));
@@ -13632,6 +15680,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::OptionAngleValue>(), "AzOptionAngleValue"), (Layout::new::<AzOptionAngleValue>(), "AzOptionAngleValue"));
         assert_eq!((Layout::new::<azul_core::window::OptionRendererOptions>(), "AzOptionRendererOptions"), (Layout::new::<AzOptionRendererOptions>(), "AzOptionRendererOptions"));
         assert_eq!((Layout::new::<azul_impl::callbacks::OptionCallback>(), "AzOptionCallback"), (Layout::new::<AzOptionCallback>(), "AzOptionCallback"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::OptionPaintCallback>(), "AzOptionPaintCallback"), (Layout::new::<AzOptionPaintCallback>(), "AzOptionPaintCallback"));
+        assert_eq!((Layout::new::<azul_impl::resources::OptionCrashHandler>(), "AzOptionCrashHandler"), (Layout::new::<AzOptionCrashHandler>(), "AzOptionCrashHandler"));
         assert_eq!((Layout::new::<azul_impl::task::OptionThreadSendMsg>(), "AzOptionThreadSendMsg"), (Layout::new::<AzOptionThreadSendMsg>(), "AzOptionThreadSendMsg"));
         assert_eq!((Layout::new::<azul_impl::css::OptionLayoutRect>(), "AzOptionLayoutRect"), (Layout::new::<AzOptionLayoutRect>(), "AzOptionLayoutRect"));
         assert_eq!((Layout::new::<azul_impl::callbacks::OptionRefAny>(), "AzOptionRefAny"), (Layout::new::<AzOptionRefAny>(), "AzOptionRefAny"));
@@ -13642,6 +15692,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::OptionDomNodeId>(), "AzOptionDomNodeId"), (Layout::new::<AzOptionDomNodeId>(), "AzOptionDomNodeId"));
         assert_eq!((Layout::new::<azul_impl::css::OptionColorU>(), "AzOptionColorU"), (Layout::new::<AzOptionColorU>(), "AzOptionColorU"));
         assert_eq!((Layout::new::<azul_impl::svg::OptionSvgDashPattern>(), "AzOptionSvgDashPattern"), (Layout::new::<AzOptionSvgDashPattern>(), "AzOptionSvgDashPattern"));
+        assert_eq!((Layout::new::<azul_core::window::OptionRawWindowHandle>(), "AzOptionRawWindowHandle"), (Layout::new::<AzOptionRawWindowHandle>(), "AzOptionRawWindowHandle"));
         assert_eq!((Layout::new::<azul_core::window::OptionLogicalPosition>(), "AzOptionLogicalPosition"), (Layout::new::<AzOptionLogicalPosition>(), "AzOptionLogicalPosition"));
         assert_eq!((Layout::new::<azul_core::window::OptionPhysicalPositionI32>(), "AzOptionPhysicalPositionI32"), (Layout::new::<AzOptionPhysicalPositionI32>(), "AzOptionPhysicalPositionI32"));
         assert_eq!((Layout::new::<azul_core::window::OptionMouseCursorType>(), "AzOptionMouseCursorType"), (Layout::new::<AzOptionMouseCursorType>(), "AzOptionMouseCursorType"));
@@ -13659,7 +15710,9 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::xml::InvalidCharMultipleError>(), "AzInvalidCharMultipleError"), (Layout::new::<AzInvalidCharMultipleError>(), "AzInvalidCharMultipleError"));
         assert_eq!((Layout::new::<azul_impl::xml::InvalidQuoteError>(), "AzInvalidQuoteError"), (Layout::new::<AzInvalidQuoteError>(), "AzInvalidQuoteError"));
         assert_eq!((Layout::new::<azul_impl::xml::InvalidSpaceError>(), "AzInvalidSpaceError"), (Layout::new::<AzInvalidSpaceError>(), "AzInvalidSpaceError"));
-        assert_eq!((Layout::new::<azul_impl::resources::AppConfig>(), "AzAppConfig"), (Layout::new::<AzAppConfig>(), "AzAppConfig"));
+        assert_eq!((Layout::new::<azul_impl::resources::SplashOptions>(), "AzSplashOptions"), (Layout::new::<AzSplashOptions>(), "AzSplashOptions"));
+        assert_eq!((Layout::new::<azul_impl::resources::RecordedFrame>(), "AzRecordedFrame"), (Layout::new::<AzRecordedFrame>(), "AzRecordedFrame"));
+        assert_eq!((Layout::new::<azul_core::window::ScrollbarConfig>(), "AzScrollbarConfig"), (Layout::new::<AzScrollbarConfig>(), "AzScrollbarConfig"));
         assert_eq!((Layout::new::<azul_core::window::SmallWindowIconBytes>(), "AzSmallWindowIconBytes"), (Layout::new::<AzSmallWindowIconBytes>(), "AzSmallWindowIconBytes"));
         assert_eq!((Layout::new::<azul_core::window::LargeWindowIconBytes>(), "AzLargeWindowIconBytes"), (Layout::new::<AzLargeWindowIconBytes>(), "AzLargeWindowIconBytes"));
         assert_eq!((Layout::new::<azul_core::window::WindowIcon>(), "AzWindowIcon"), (Layout::new::<AzWindowIcon>(), "AzWindowIcon"));
@@ -13667,15 +15720,20 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::WindowSize>(), "AzWindowSize"), (Layout::new::<AzWindowSize>(), "AzWindowSize"));
         assert_eq!((Layout::new::<azul_core::window::KeyboardState>(), "AzKeyboardState"), (Layout::new::<AzKeyboardState>(), "AzKeyboardState"));
         assert_eq!((Layout::new::<azul_core::window::MouseState>(), "AzMouseState"), (Layout::new::<AzMouseState>(), "AzMouseState"));
+        assert_eq!((Layout::new::<azul_core::window::TouchState>(), "AzTouchState"), (Layout::new::<AzTouchState>(), "AzTouchState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::MarshaledLayoutCallback>(), "AzMarshaledLayoutCallback"), (Layout::new::<AzMarshaledLayoutCallback>(), "AzMarshaledLayoutCallback"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineTextContents>(), "AzInlineTextContents"), (Layout::new::<AzInlineTextContents>(), "AzInlineTextContents"));
         assert_eq!((Layout::new::<azul_impl::ui_solver::ResolvedTextLayoutOptions>(), "AzResolvedTextLayoutOptions"), (Layout::new::<AzResolvedTextLayoutOptions>(), "AzResolvedTextLayoutOptions"));
         assert_eq!((Layout::new::<azul_impl::css::AnimationInterpolationFunction>(), "AzAnimationEasing"), (Layout::new::<AzAnimationEasing>(), "AzAnimationEasing"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::PaintPrimitive>(), "AzPaintPrimitive"), (Layout::new::<AzPaintPrimitive>(), "AzPaintPrimitive"));
         assert_eq!((Layout::new::<azul_impl::callbacks::RenderImageCallbackInfo>(), "AzRenderImageCallbackInfo"), (Layout::new::<AzRenderImageCallbackInfo>(), "AzRenderImageCallbackInfo"));
         assert_eq!((Layout::new::<azul_impl::callbacks::LayoutCallbackInfo>(), "AzLayoutCallbackInfo"), (Layout::new::<AzLayoutCallbackInfo>(), "AzLayoutCallbackInfo"));
+        assert_eq!((Layout::new::<azul_impl::dom::CustomLayoutNode>(), "AzCustomLayoutNode"), (Layout::new::<AzCustomLayoutNode>(), "AzCustomLayoutNode"));
         assert_eq!((Layout::new::<azul_impl::dom::EventFilter>(), "AzEventFilter"), (Layout::new::<AzEventFilter>(), "AzEventFilter"));
         assert_eq!((Layout::new::<azul_core::window::Menu>(), "AzMenu"), (Layout::new::<AzMenu>(), "AzMenu"));
         assert_eq!((Layout::new::<azul_core::window::VirtualKeyCodeCombo>(), "AzVirtualKeyCodeCombo"), (Layout::new::<AzVirtualKeyCodeCombo>(), "AzVirtualKeyCodeCombo"));
+        assert_eq!((Layout::new::<azul_core::window::Shortcut>(), "AzShortcut"), (Layout::new::<AzShortcut>(), "AzShortcut"));
+        assert_eq!((Layout::new::<azul_core::window::ShortcutConflict>(), "AzShortcutConflict"), (Layout::new::<AzShortcutConflict>(), "AzShortcutConflict"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathPseudoSelector>(), "AzCssPathPseudoSelector"), (Layout::new::<AzCssPathPseudoSelector>(), "AzCssPathPseudoSelector"));
         assert_eq!((Layout::new::<azul_impl::css::AnimationInterpolationFunction>(), "AzAnimationInterpolationFunction"), (Layout::new::<AzAnimationInterpolationFunction>(), "AzAnimationInterpolationFunction"));
         assert_eq!((Layout::new::<azul_impl::css::InterpolateResolver>(), "AzInterpolateContext"), (Layout::new::<AzInterpolateContext>(), "AzInterpolateContext"));
@@ -13688,7 +15746,6 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundRepeatVecValue>(), "AzStyleBackgroundRepeatVecValue"), (Layout::new::<AzStyleBackgroundRepeatVecValue>(), "AzStyleBackgroundRepeatVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundSizeVecValue>(), "AzStyleBackgroundSizeVecValue"), (Layout::new::<AzStyleBackgroundSizeVecValue>(), "AzStyleBackgroundSizeVecValue"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBoxStateWrapper>(), "AzCheckBoxStateWrapper"), (Layout::new::<AzCheckBoxStateWrapper>(), "AzCheckBoxStateWrapper"));
-        assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputStateWrapper>(), "AzNumberInputStateWrapper"), (Layout::new::<AzNumberInputStateWrapper>(), "AzNumberInputStateWrapper"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphCallbacks>(), "AzNodeGraphCallbacks"), (Layout::new::<AzNodeGraphCallbacks>(), "AzNodeGraphCallbacks"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputConnection>(), "AzInputConnection"), (Layout::new::<AzInputConnection>(), "AzInputConnection"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputConnection>(), "AzOutputConnection"), (Layout::new::<AzOutputConnection>(), "AzOutputConnection"));
@@ -13716,14 +15773,18 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputConnectionVec>(), "AzOutputConnectionVec"), (Layout::new::<AzOutputConnectionVec>(), "AzOutputConnectionVec"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedSvgNodeVec>(), "AzTessellatedSvgNodeVec"), (Layout::new::<AzTessellatedSvgNodeVec>(), "AzTessellatedSvgNodeVec"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedColoredSvgNodeVec>(), "AzTessellatedColoredSvgNodeVec"), (Layout::new::<AzTessellatedColoredSvgNodeVec>(), "AzTessellatedColoredSvgNodeVec"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::PaintPrimitiveVec>(), "AzPaintPrimitiveVec"), (Layout::new::<AzPaintPrimitiveVec>(), "AzPaintPrimitiveVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformVec>(), "AzStyleTransformVec"), (Layout::new::<AzStyleTransformVec>(), "AzStyleTransformVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgPathElementVec>(), "AzSvgPathElementVec"), (Layout::new::<AzSvgPathElementVec>(), "AzSvgPathElementVec"));
+        assert_eq!((Layout::new::<azul_core::window::ShortcutConflictVec>(), "AzShortcutConflictVec"), (Layout::new::<AzShortcutConflictVec>(), "AzShortcutConflictVec"));
+        assert_eq!((Layout::new::<azul_core::window::ShortcutVec>(), "AzShortcutVec"), (Layout::new::<AzShortcutVec>(), "AzShortcutVec"));
         assert_eq!((Layout::new::<azul_impl::css::StringVec>(), "AzStringVec"), (Layout::new::<AzStringVec>(), "AzStringVec"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::StyledNodeVec>(), "AzStyledNodeVec"), (Layout::new::<AzStyledNodeVec>(), "AzStyledNodeVec"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::TagIdToNodeIdMappingVec>(), "AzTagIdToNodeIdMappingVec"), (Layout::new::<AzTagIdToNodeIdMappingVec>(), "AzTagIdToNodeIdMappingVec"));
         assert_eq!((Layout::new::<azul_core::window::OptionMenu>(), "AzOptionMenu"), (Layout::new::<AzOptionMenu>(), "AzOptionMenu"));
         assert_eq!((Layout::new::<azul_impl::ui_solver::OptionResolvedTextLayoutOptions>(), "AzOptionResolvedTextLayoutOptions"), (Layout::new::<AzOptionResolvedTextLayoutOptions>(), "AzOptionResolvedTextLayoutOptions"));
         assert_eq!((Layout::new::<azul_core::window::OptionVirtualKeyCodeCombo>(), "AzOptionVirtualKeyCodeCombo"), (Layout::new::<AzOptionVirtualKeyCodeCombo>(), "AzOptionVirtualKeyCodeCombo"));
+        assert_eq!((Layout::new::<azul_core::window::OptionShortcutConflict>(), "AzOptionShortcutConflict"), (Layout::new::<AzOptionShortcutConflict>(), "AzOptionShortcutConflict"));
         assert_eq!((Layout::new::<azul_core::window::OptionMouseState>(), "AzOptionMouseState"), (Layout::new::<AzOptionMouseState>(), "AzOptionMouseState"));
         assert_eq!((Layout::new::<azul_core::window::OptionKeyboardState>(), "AzOptionKeyboardState"), (Layout::new::<AzOptionKeyboardState>(), "AzOptionKeyboardState"));
         assert_eq!((Layout::new::<azul_impl::css::OptionStringVec>(), "AzOptionStringVec"), (Layout::new::<AzOptionStringVec>(), "AzOptionStringVec"));
@@ -13739,28 +15800,44 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::xml::UnknownEntityReferenceError>(), "AzUnknownEntityReferenceError"), (Layout::new::<AzUnknownEntityReferenceError>(), "AzUnknownEntityReferenceError"));
         assert_eq!((Layout::new::<azul_impl::xml::DuplicatedAttributeError>(), "AzDuplicatedAttributeError"), (Layout::new::<AzDuplicatedAttributeError>(), "AzDuplicatedAttributeError"));
         assert_eq!((Layout::new::<azul_impl::xml::InvalidStringError>(), "AzInvalidStringError"), (Layout::new::<AzInvalidStringError>(), "AzInvalidStringError"));
+        assert_eq!((Layout::new::<azul_impl::resources::JankCapture>(), "AzJankCapture"), (Layout::new::<AzJankCapture>(), "AzJankCapture"));
+        assert_eq!((Layout::new::<azul_impl::resources::EventRecording>(), "AzEventRecording"), (Layout::new::<AzEventRecording>(), "AzEventRecording"));
+        assert_eq!((Layout::new::<azul_impl::resources::FrameRecordingOutput>(), "AzFrameRecordingOutput"), (Layout::new::<AzFrameRecordingOutput>(), "AzFrameRecordingOutput"));
+        assert_eq!((Layout::new::<azul_impl::resources::CrashInfo>(), "AzCrashInfo"), (Layout::new::<AzCrashInfo>(), "AzCrashInfo"));
         assert_eq!((Layout::new::<azul_core::window::WindowsWindowOptions>(), "AzWindowsWindowOptions"), (Layout::new::<AzWindowsWindowOptions>(), "AzWindowsWindowOptions"));
         assert_eq!((Layout::new::<azul_core::window::WaylandTheme>(), "AzWaylandTheme"), (Layout::new::<AzWaylandTheme>(), "AzWaylandTheme"));
         assert_eq!((Layout::new::<azul_core::window::AzStringPair>(), "AzStringPair"), (Layout::new::<AzStringPair>(), "AzStringPair"));
         assert_eq!((Layout::new::<azul_core::window::Monitor>(), "AzMonitor"), (Layout::new::<AzMonitor>(), "AzMonitor"));
         assert_eq!((Layout::new::<azul_impl::callbacks::LayoutCallback>(), "AzLayoutCallback"), (Layout::new::<AzLayoutCallback>(), "AzLayoutCallback"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineWord>(), "AzInlineWord"), (Layout::new::<AzInlineWord>(), "AzInlineWord"));
+        assert_eq!((Layout::new::<azul_impl::dom::CustomNodeType>(), "AzCustomNodeType"), (Layout::new::<AzCustomNodeType>(), "AzCustomNodeType"));
         assert_eq!((Layout::new::<azul_impl::dom::CallbackData>(), "AzCallbackData"), (Layout::new::<AzCallbackData>(), "AzCallbackData"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeType>(), "AzNodeType"), (Layout::new::<AzNodeType>(), "AzNodeType"));
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityInfo>(), "AzAccessibilityInfo"), (Layout::new::<AzAccessibilityInfo>(), "AzAccessibilityInfo"));
+        assert_eq!((Layout::new::<azul_impl::dom::FocusNavigation>(), "AzFocusNavigation"), (Layout::new::<AzFocusNavigation>(), "AzFocusNavigation"));
+        assert_eq!((Layout::new::<azul_impl::dom::OverlayInfo>(), "AzOverlayInfo"), (Layout::new::<AzOverlayInfo>(), "AzOverlayInfo"));
         assert_eq!((Layout::new::<azul_impl::dom::IdOrClass>(), "AzIdOrClass"), (Layout::new::<AzIdOrClass>(), "AzIdOrClass"));
         assert_eq!((Layout::new::<azul_core::window::StringMenuItem>(), "AzStringMenuItem"), (Layout::new::<AzStringMenuItem>(), "AzStringMenuItem"));
+        assert_eq!((Layout::new::<azul_core::window::ShortcutMap>(), "AzShortcutMap"), (Layout::new::<AzShortcutMap>(), "AzShortcutMap"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathSelector>(), "AzCssPathSelector"), (Layout::new::<AzCssPathSelector>(), "AzCssPathSelector"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContent>(), "AzStyleBackgroundContent"), (Layout::new::<AzStyleBackgroundContent>(), "AzStyleBackgroundContent"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarInfo>(), "AzScrollbarInfo"), (Layout::new::<AzScrollbarInfo>(), "AzScrollbarInfo"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarStyle>(), "AzScrollbarStyle"), (Layout::new::<AzScrollbarStyle>(), "AzScrollbarStyle"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleContent>(), "AzStyleContent"), (Layout::new::<AzStyleContent>(), "AzStyleContent"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCounterReset>(), "AzStyleCounterReset"), (Layout::new::<AzStyleCounterReset>(), "AzStyleCounterReset"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCounterIncrement>(), "AzStyleCounterIncrement"), (Layout::new::<AzStyleCounterIncrement>(), "AzStyleCounterIncrement"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCursorImage>(), "AzStyleCursorImage"), (Layout::new::<AzStyleCursorImage>(), "AzStyleCursorImage"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamily>(), "AzStyleFontFamily"), (Layout::new::<AzStyleFontFamily>(), "AzStyleFontFamily"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarStyleValue>(), "AzScrollbarStyleValue"), (Layout::new::<AzScrollbarStyleValue>(), "AzScrollbarStyleValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleContentValue>(), "AzStyleContentValue"), (Layout::new::<AzStyleContentValue>(), "AzStyleContentValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCounterResetValue>(), "AzStyleCounterResetValue"), (Layout::new::<AzStyleCounterResetValue>(), "AzStyleCounterResetValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCounterIncrementValue>(), "AzStyleCounterIncrementValue"), (Layout::new::<AzStyleCounterIncrementValue>(), "AzStyleCounterIncrementValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformVecValue>(), "AzStyleTransformVecValue"), (Layout::new::<AzStyleTransformVecValue>(), "AzStyleTransformVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFilterVecValue>(), "AzStyleFilterVecValue"), (Layout::new::<AzStyleFilterVecValue>(), "AzStyleFilterVecValue"));
         assert_eq!((Layout::new::<crate::widgets::file_input::FileInputState>(), "AzFileInputState"), (Layout::new::<AzFileInputState>(), "AzFileInputState"));
         assert_eq!((Layout::new::<crate::widgets::color_input::ColorInputStateWrapper>(), "AzColorInputStateWrapper"), (Layout::new::<AzColorInputStateWrapper>(), "AzColorInputStateWrapper"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputState>(), "AzTextInputState"), (Layout::new::<AzTextInputState>(), "AzTextInputState"));
+        assert_eq!((Layout::new::<crate::widgets::text_input::TextInputSpellingSuggestions>(), "AzTextInputSpellingSuggestions"), (Layout::new::<AzTextInputSpellingSuggestions>(), "AzTextInputSpellingSuggestions"));
         assert_eq!((Layout::new::<crate::widgets::tabs::TabHeader>(), "AzTabHeader"), (Layout::new::<AzTabHeader>(), "AzTabHeader"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeTypeFieldValue>(), "AzNodeTypeFieldValue"), (Layout::new::<AzNodeTypeFieldValue>(), "AzNodeTypeFieldValue"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeTypeInfo>(), "AzNodeTypeInfo"), (Layout::new::<AzNodeTypeInfo>(), "AzNodeTypeInfo"));
@@ -13769,6 +15846,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewState>(), "AzListViewState"), (Layout::new::<AzListViewState>(), "AzListViewState"));
         assert_eq!((Layout::new::<crate::widgets::tree_view::TreeView>(), "AzTreeView"), (Layout::new::<AzTreeView>(), "AzTreeView"));
         assert_eq!((Layout::new::<crate::widgets::drop_down::DropDown>(), "AzDropDown"), (Layout::new::<AzDropDown>(), "AzDropDown"));
+        assert_eq!((Layout::new::<crate::widgets::toasts::Toast>(), "AzToast"), (Layout::new::<AzToast>(), "AzToast"));
+        assert_eq!((Layout::new::<azul_impl::gl::GlQuirkRule>(), "AzGlQuirkRule"), (Layout::new::<AzGlQuirkRule>(), "AzGlQuirkRule"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexAttribute>(), "AzVertexAttribute"), (Layout::new::<AzVertexAttribute>(), "AzVertexAttribute"));
         assert_eq!((Layout::new::<azul_impl::gl::AzDebugMessage>(), "AzDebugMessage"), (Layout::new::<AzDebugMessage>(), "AzDebugMessage"));
         assert_eq!((Layout::new::<azul_impl::gl::GetActiveAttribReturn>(), "AzGetActiveAttribReturn"), (Layout::new::<AzGetActiveAttribReturn>(), "AzGetActiveAttribReturn"));
@@ -13796,15 +15875,20 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::StringPairVec>(), "AzStringPairVec"), (Layout::new::<AzStringPairVec>(), "AzStringPairVec"));
         assert_eq!((Layout::new::<azul_impl::dialogs::OptionFileTypeList>(), "AzOptionFileTypeList"), (Layout::new::<AzOptionFileTypeList>(), "AzOptionFileTypeList"));
         assert_eq!((Layout::new::<azul_impl::file::OptionFile>(), "AzOptionFile"), (Layout::new::<AzOptionFile>(), "AzOptionFile"));
+        assert_eq!((Layout::new::<azul_impl::resources::OptionJankCapture>(), "AzOptionJankCapture"), (Layout::new::<AzOptionJankCapture>(), "AzOptionJankCapture"));
+        assert_eq!((Layout::new::<azul_impl::resources::OptionEventRecording>(), "AzOptionEventRecording"), (Layout::new::<AzOptionEventRecording>(), "AzOptionEventRecording"));
         assert_eq!((Layout::new::<azul_impl::resources::OptionRawImage>(), "AzOptionRawImage"), (Layout::new::<AzOptionRawImage>(), "AzOptionRawImage"));
         assert_eq!((Layout::new::<azul_core::window::OptionWaylandTheme>(), "AzOptionWaylandTheme"), (Layout::new::<AzOptionWaylandTheme>(), "AzOptionWaylandTheme"));
         assert_eq!((Layout::new::<azul_impl::resources::decode::ResultRawImageDecodeImageError>(), "AzResultRawImageDecodeImageError"), (Layout::new::<AzResultRawImageDecodeImageError>(), "AzResultRawImageDecodeImageError"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlStreamError>(), "AzXmlStreamError"), (Layout::new::<AzXmlStreamError>(), "AzXmlStreamError"));
+        assert_eq!((Layout::new::<azul_impl::resources::FrameRecording>(), "AzFrameRecording"), (Layout::new::<AzFrameRecording>(), "AzFrameRecording"));
         assert_eq!((Layout::new::<azul_core::window::LinuxWindowOptions>(), "AzLinuxWindowOptions"), (Layout::new::<AzLinuxWindowOptions>(), "AzLinuxWindowOptions"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineLine>(), "AzInlineLine"), (Layout::new::<AzInlineLine>(), "AzInlineLine"));
         assert_eq!((Layout::new::<azul_core::window::MenuItem>(), "AzMenuItem"), (Layout::new::<AzMenuItem>(), "AzMenuItem"));
         assert_eq!((Layout::new::<azul_impl::css::CssPath>(), "AzCssPath"), (Layout::new::<AzCssPath>(), "AzCssPath"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCursor>(), "AzStyleCursor"), (Layout::new::<AzStyleCursor>(), "AzStyleCursor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContentVecValue>(), "AzStyleBackgroundContentVecValue"), (Layout::new::<AzStyleBackgroundContentVecValue>(), "AzStyleBackgroundContentVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCursorValue>(), "AzStyleCursorValue"), (Layout::new::<AzStyleCursorValue>(), "AzStyleCursorValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamilyVecValue>(), "AzStyleFontFamilyVecValue"), (Layout::new::<AzStyleFontFamilyVecValue>(), "AzStyleFontFamilyVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::CssProperty>(), "AzCssProperty"), (Layout::new::<AzCssProperty>(), "AzCssProperty"));
         assert_eq!((Layout::new::<crate::widgets::file_input::FileInputStateWrapper>(), "AzFileInputStateWrapper"), (Layout::new::<AzFileInputStateWrapper>(), "AzFileInputStateWrapper"));
@@ -13830,22 +15914,27 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::svg::SvgMultiPolygonVec>(), "AzSvgMultiPolygonVec"), (Layout::new::<AzSvgMultiPolygonVec>(), "AzSvgMultiPolygonVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgSimpleNodeVec>(), "AzSvgSimpleNodeVec"), (Layout::new::<AzSvgSimpleNodeVec>(), "AzSvgSimpleNodeVec"));
         assert_eq!((Layout::new::<azul_impl::css::OptionCssProperty>(), "AzOptionCssProperty"), (Layout::new::<AzOptionCssProperty>(), "AzOptionCssProperty"));
+        assert_eq!((Layout::new::<azul_impl::resources::OptionFrameRecording>(), "AzOptionFrameRecording"), (Layout::new::<AzOptionFrameRecording>(), "AzOptionFrameRecording"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlTextError>(), "AzXmlTextError"), (Layout::new::<AzXmlTextError>(), "AzXmlTextError"));
+        assert_eq!((Layout::new::<azul_impl::resources::AppConfig>(), "AzAppConfig"), (Layout::new::<AzAppConfig>(), "AzAppConfig"));
         assert_eq!((Layout::new::<azul_core::window::PlatformSpecificOptions>(), "AzPlatformSpecificOptions"), (Layout::new::<AzPlatformSpecificOptions>(), "AzPlatformSpecificOptions"));
         assert_eq!((Layout::new::<azul_core::window::WindowState>(), "AzWindowState"), (Layout::new::<AzWindowState>(), "AzWindowState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::CallbackInfo>(), "AzCallbackInfo"), (Layout::new::<AzCallbackInfo>(), "AzCallbackInfo"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineText>(), "AzInlineText"), (Layout::new::<AzInlineText>(), "AzInlineText"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::TextLayout>(), "AzTextLayout"), (Layout::new::<AzTextLayout>(), "AzTextLayout"));
         assert_eq!((Layout::new::<azul_impl::callbacks::FocusTargetPath>(), "AzFocusTargetPath"), (Layout::new::<AzFocusTargetPath>(), "AzFocusTargetPath"));
         assert_eq!((Layout::new::<azul_impl::callbacks::Animation>(), "AzAnimation"), (Layout::new::<AzAnimation>(), "AzAnimation"));
         assert_eq!((Layout::new::<azul_impl::callbacks::TimerCallbackInfo>(), "AzTimerCallbackInfo"), (Layout::new::<AzTimerCallbackInfo>(), "AzTimerCallbackInfo"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataInlineCssProperty>(), "AzNodeDataInlineCssProperty"), (Layout::new::<AzNodeDataInlineCssProperty>(), "AzNodeDataInlineCssProperty"));
         assert_eq!((Layout::new::<azul_impl::css::DynamicCssProperty>(), "AzDynamicCssProperty"), (Layout::new::<AzDynamicCssProperty>(), "AzDynamicCssProperty"));
+        assert_eq!((Layout::new::<azul_impl::css::SystemCssProperty>(), "AzSystemCssProperty"), (Layout::new::<AzSystemCssProperty>(), "AzSystemCssProperty"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::Node>(), "AzNode"), (Layout::new::<AzNode>(), "AzNode"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgNode>(), "AzSvgNode"), (Layout::new::<AzSvgNode>(), "AzSvgNode"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgStyledNode>(), "AzSvgStyledNode"), (Layout::new::<AzSvgStyledNode>(), "AzSvgStyledNode"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataInlineCssPropertyVec>(), "AzNodeDataInlineCssPropertyVec"), (Layout::new::<AzNodeDataInlineCssPropertyVec>(), "AzNodeDataInlineCssPropertyVec"));
         assert_eq!((Layout::new::<azul_core::window::OptionWindowState>(), "AzOptionWindowState"), (Layout::new::<AzOptionWindowState>(), "AzOptionWindowState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::OptionInlineText>(), "AzOptionInlineText"), (Layout::new::<AzOptionInlineText>(), "AzOptionInlineText"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::OptionTextLayout>(), "AzOptionTextLayout"), (Layout::new::<AzOptionTextLayout>(), "AzOptionTextLayout"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlParseError>(), "AzXmlParseError"), (Layout::new::<AzXmlParseError>(), "AzXmlParseError"));
         assert_eq!((Layout::new::<azul_core::window::WindowCreateOptions>(), "AzWindowCreateOptions"), (Layout::new::<AzWindowCreateOptions>(), "AzWindowCreateOptions"));
         assert_eq!((Layout::new::<azul_impl::callbacks::FocusTarget>(), "AzFocusTarget"), (Layout::new::<AzFocusTarget>(), "AzFocusTarget"));
@@ -13857,14 +15946,16 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::label::Label>(), "AzLabel"), (Layout::new::<AzLabel>(), "AzLabel"));
         assert_eq!((Layout::new::<crate::widgets::color_input::ColorInput>(), "AzColorInput"), (Layout::new::<AzColorInput>(), "AzColorInput"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInput>(), "AzTextInput"), (Layout::new::<AzTextInput>(), "AzTextInput"));
-        assert_eq!((Layout::new::<crate::widgets::number_input::NumberInput>(), "AzNumberInput"), (Layout::new::<AzNumberInput>(), "AzNumberInput"));
+        assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputStateWrapper>(), "AzNumberInputStateWrapper"), (Layout::new::<AzNumberInputStateWrapper>(), "AzNumberInputStateWrapper"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeIdNodeMap>(), "AzNodeIdNodeMap"), (Layout::new::<AzNodeIdNodeMap>(), "AzNodeIdNodeMap"));
+        assert_eq!((Layout::new::<crate::widgets::toasts::Toasts>(), "AzToasts"), (Layout::new::<AzToasts>(), "AzToasts"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeIdNodeMapVec>(), "AzNodeIdNodeMapVec"), (Layout::new::<AzNodeIdNodeMapVec>(), "AzNodeIdNodeMapVec"));
         assert_eq!((Layout::new::<azul_impl::css::CssDeclarationVec>(), "AzCssDeclarationVec"), (Layout::new::<AzCssDeclarationVec>(), "AzCssDeclarationVec"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataVec>(), "AzNodeDataVec"), (Layout::new::<AzNodeDataVec>(), "AzNodeDataVec"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlError>(), "AzXmlError"), (Layout::new::<AzXmlError>(), "AzXmlError"));
         assert_eq!((Layout::new::<azul_impl::dom::Dom>(), "AzDom"), (Layout::new::<AzDom>(), "AzDom"));
         assert_eq!((Layout::new::<azul_impl::css::CssRuleBlock>(), "AzCssRuleBlock"), (Layout::new::<AzCssRuleBlock>(), "AzCssRuleBlock"));
+        assert_eq!((Layout::new::<crate::widgets::number_input::NumberInput>(), "AzNumberInput"), (Layout::new::<AzNumberInput>(), "AzNumberInput"));
         assert_eq!((Layout::new::<crate::widgets::tabs::TabContent>(), "AzTabContent"), (Layout::new::<AzTabContent>(), "AzTabContent"));
         assert_eq!((Layout::new::<crate::widgets::frame::Frame>(), "AzFrame"), (Layout::new::<AzFrame>(), "AzFrame"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraph>(), "AzNodeGraph"), (Layout::new::<AzNodeGraph>(), "AzNodeGraph"));
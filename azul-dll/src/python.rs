@@ -715,6 +715,26 @@ impl PyGCProtocol for AzNodeData {
             data._py_data = None;
         }
     }
+}
+
+// CrashHandler has no marshal data, so the Python callable is stored globally
+static PY_CRASH_HANDLER: std::sync::Mutex<Option<PyObject>> = std::sync::Mutex::new(None);
+
+extern "C" fn invoke_py_crash_handler(info: &AzCrashInfo) {
+    Python::with_gil(|py| {
+
+        let pyfunction = match PY_CRASH_HANDLER.lock().ok().and_then(|h| h.as_ref().map(|f| f.clone_ref(py))) {
+            Some(s) => s,
+            None => return,
+        };
+
+        // call crash handler into python
+        if let Err(e) = pyfunction.call1(py, (info.clone(),)) {
+            #[cfg(feature = "logging")] {
+                log::error!("Exception caught when invoking CrashHandler: {}", e);
+            }
+        }
+    })
 }
 /// Main application class
 #[repr(C)]
@@ -734,12 +754,47 @@ pub enum AzAppLogLevel {
     Trace,
 }
 
+/// `AzFrameRecordingCallbackType` struct
+pub type AzFrameRecordingCallbackType = extern "C" fn(&AzRecordedFrame);
+
+/// Function that is invoked with every recorded frame, once the next frame has been presented (or the window is closed)
+#[repr(C)]
+pub struct AzFrameRecordingCallback {
+    pub cb: AzFrameRecordingCallbackType,
+}
+
+/// `AzCrashHandlerType` struct
+pub type AzCrashHandlerType = extern "C" fn(&AzCrashInfo);
+
+/// Function that is invoked when the app panics or crashes
+#[repr(C)]
+pub struct AzCrashHandler {
+    pub cb: AzCrashHandlerType,
+}
+
 /// Version of the layout solver to use - future binary versions of azul may have more fields here, necessary so that old compiled applications don't break with newer releases of azul. Newer layout versions are opt-in only.
 #[repr(C)]
 pub enum AzLayoutSolver {
     Default,
 }
 
+/// Translucent backdrop of a window, see `WindowCreateOptions::background_effect`
+#[repr(C)]
+pub enum AzWindowBackgroundEffect {
+    None,
+    Blur,
+    Mica,
+    MicaAlt,
+    Acrylic,
+}
+
+/// How the built-in scrollbars are drawn, see `ScrollbarConfig::mode`
+#[repr(C)]
+pub enum AzScrollbarMode {
+    Default,
+    Overlay,
+}
+
 /// Whether the renderer has VSync enabled
 #[repr(C)]
 pub enum AzVsync {
@@ -1054,6 +1109,25 @@ pub enum AzVirtualKeyCode {
     Cut,
 }
 
+/// How urgently a screen reader should speak an announcement, see `CallbackInfo::announce`
+#[repr(C)]
+pub enum AzPoliteness {
+    Polite,
+    Assertive,
+}
+
+/// Kind of tactile feedback, see `CallbackInfo::trigger_haptic`
+#[repr(C)]
+pub enum AzHapticKind {
+    Selection,
+    LightImpact,
+    MediumImpact,
+    HeavyImpact,
+    Success,
+    Warning,
+    Error,
+}
+
 /// State of the window frame (minimized, maximized, fullscreen or normal window)
 #[repr(C)]
 pub enum AzWindowFrame {
@@ -1063,6 +1137,13 @@ pub enum AzWindowFrame {
     Fullscreen,
 }
 
+/// How a window covers the monitor in fullscreen mode - the `usize` is the `Monitor::id` of the target monitor (the current monitor of the window if no monitor with that id exists)
+#[repr(C, u8)]
+pub enum AzFullscreenMode {
+    Borderless(usize),
+    Exclusive(usize),
+}
+
 /// Debugging information, will be rendered as an overlay on top of the UI
 #[repr(C)]
 pub struct AzDebugState {
@@ -1095,6 +1176,15 @@ pub struct AzDebugState {
     pub force_picture_invalidation: bool,
 }
 
+/// Modifier keys that influence which character a key produces, see `CallbackInfo::keycode_to_char`. `ctrl + alt` is treated as AltGr.
+#[repr(C)]
+pub struct AzKeyboardModifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub caps_lock: bool,
+}
+
 /// Current icon of the mouse cursor
 #[repr(C)]
 pub enum AzMouseCursorType {
@@ -1170,10 +1260,21 @@ pub enum AzWindowTheme {
     LightMode,
 }
 
-/// Current state of touch devices / touch inputs
+/// Device that the last pointer input of the window came from
 #[repr(C)]
-pub struct AzTouchState {
-    pub unused: u8,
+pub enum AzInputModality {
+    Mouse,
+    Touch,
+    Pen,
+}
+
+/// HDR / wide color gamut capabilities of a monitor
+#[repr(C)]
+pub struct AzHdrCapabilities {
+    pub supported: bool,
+    pub enabled: bool,
+    pub bits_per_color_channel: u32,
+    pub sdr_white_level: f32,
 }
 
 /// C-ABI stable wrapper over a `MarshaledLayoutCallbackInner`
@@ -1239,6 +1340,21 @@ pub struct AzPositionInfoInner {
     pub static_y_offset: f32,
 }
 
+/// Line style of a `TextUnderline`
+#[repr(C)]
+pub enum AzTextUnderlineStyle {
+    Solid,
+    Dotted,
+    Wavy,
+}
+
+/// How the text caret of an editable node is animated
+#[repr(C)]
+pub enum AzCaretAnimation {
+    Blink,
+    Fade,
+}
+
 /// How should an animation repeat (loop, ping-pong, etc.)
 #[repr(C)]
 pub enum AzAnimationRepeat {
@@ -1263,6 +1379,33 @@ pub struct AzIFrameCallback {
 /// `AzIFrameCallbackType` struct
 pub type AzIFrameCallbackType = extern "C" fn(&mut AzRefAny, &mut AzIFrameCallbackInfo) -> AzIFrameCallbackReturn;
 
+/// C-ABI wrapper over a `MeasureCallbackType`
+#[repr(C)]
+pub struct AzMeasureCallback {
+    pub cb: AzMeasureCallbackType,
+}
+
+/// `AzMeasureCallbackType` struct
+pub type AzMeasureCallbackType = extern "C" fn(&mut AzRefAny, &AzMeasureCallbackInfo) -> AzLogicalSize;
+
+/// C-ABI wrapper over an `ArrangeCallbackType`
+#[repr(C)]
+pub struct AzArrangeCallback {
+    pub cb: AzArrangeCallbackType,
+}
+
+/// `AzArrangeCallbackType` struct
+pub type AzArrangeCallbackType = extern "C" fn(&mut AzRefAny, &AzArrangeCallbackInfo);
+
+/// C-ABI wrapper over a `PaintCallbackType`
+#[repr(C)]
+pub struct AzPaintCallback {
+    pub cb: AzPaintCallbackType,
+}
+
+/// `AzPaintCallbackType` struct
+pub type AzPaintCallbackType = extern "C" fn(&mut AzRefAny, &AzPaintCallbackInfo) -> AzPaintPrimitiveVec;
+
 /// Re-export of rust-allocated (stack based) `RenderImageCallback` struct
 #[repr(C)]
 pub struct AzRenderImageCallback {
@@ -1321,6 +1464,9 @@ pub enum AzOn {
     LeftMouseUp,
     MiddleMouseUp,
     RightMouseUp,
+    DoubleClick,
+    TripleClick,
+    HoverIntent,
     MouseEnter,
     MouseLeave,
     Scroll,
@@ -1332,6 +1478,21 @@ pub enum AzOn {
     HoveredFileCancelled,
     FocusReceived,
     FocusLost,
+    UserIdle,
+    UserActive,
+    SessionEnding,
+    FullscreenEntered,
+    FullscreenExited,
+    WindowResized,
+    WindowMoved,
+    WindowFocusReceived,
+    WindowFocusLost,
+    DpiChanged,
+    Minimized,
+    Maximized,
+    Restored,
+    UserEvent,
+    InputModalityChanged,
 }
 
 /// Re-export of rust-allocated (stack based) `HoverEventFilter` struct
@@ -1346,6 +1507,9 @@ pub enum AzHoverEventFilter {
     LeftMouseUp,
     RightMouseUp,
     MiddleMouseUp,
+    DoubleClick,
+    TripleClick,
+    HoverIntent,
     MouseEnter,
     MouseLeave,
     Scroll,
@@ -1375,6 +1539,8 @@ pub enum AzFocusEventFilter {
     LeftMouseUp,
     RightMouseUp,
     MiddleMouseUp,
+    DoubleClick,
+    TripleClick,
     MouseEnter,
     MouseLeave,
     Scroll,
@@ -1399,6 +1565,9 @@ pub enum AzWindowEventFilter {
     LeftMouseUp,
     RightMouseUp,
     MiddleMouseUp,
+    DoubleClick,
+    TripleClick,
+    HoverIntent,
     MouseEnter,
     MouseLeave,
     Scroll,
@@ -1420,6 +1589,19 @@ pub enum AzWindowEventFilter {
     FocusLost,
     CloseRequested,
     ThemeChanged,
+    WindowFocusReceived,
+    WindowFocusLost,
+    UserIdle,
+    UserActive,
+    SessionEnding,
+    FullscreenEntered,
+    FullscreenExited,
+    DpiChanged,
+    Minimized,
+    Maximized,
+    Restored,
+    UserEvent,
+    InputModalityChanged,
 }
 
 /// Re-export of rust-allocated (stack based) `ComponentEventFilter` struct
@@ -1439,6 +1621,55 @@ pub enum AzApplicationEventFilter {
     DeviceDisconnected,
 }
 
+/// Direction of the spatial (arrow key) focus navigation
+#[repr(C)]
+pub enum AzFocusDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Kind of text that is entered into a text input node, same as the HTML `inputmode` attribute. Forwarded to the operating system so that touch keyboards and IMEs can show the appropriate layout
+#[repr(C)]
+pub enum AzInputMode {
+    Text,
+    None,
+    Numeric,
+    Decimal,
+    Tel,
+    Email,
+    Url,
+    Search,
+    Password,
+}
+
+/// Semantic state of a node that is set by the application (instead of by the mouse / keyboard), matched by the `:disabled`, `:checked`, `:selected` and `:indeterminate` CSS selectors
+#[repr(C)]
+pub enum AzNodeStateFlag {
+    Disabled,
+    Checked,
+    Selected,
+    Indeterminate,
+}
+
+/// Where an overlay is placed relative to its anchor node: the first part is the side of the anchor, the second part the alignment along that side (`BottomStart` = below the anchor, left edges aligned)
+#[repr(C)]
+pub enum AzOverlayPlacement {
+    TopStart,
+    Top,
+    TopEnd,
+    BottomStart,
+    Bottom,
+    BottomEnd,
+    LeftStart,
+    Left,
+    LeftEnd,
+    RightStart,
+    Right,
+    RightEnd,
+}
+
 /// MSAA Accessibility role constants. For information on what each role does, see the <a href="https://docs.microsoft.com/en-us/windows/win32/winauto/object-roles">MSDN Role Constants page</a>
 #[repr(C)]
 pub enum AzAccessibilityRole {
@@ -1568,6 +1799,12 @@ pub enum AzMenuItemState {
     Disabled,
 }
 
+/// Re-export of rust-allocated (stack based) `CssMediaCondition` struct
+#[repr(C)]
+pub struct AzCssMediaCondition {
+    pub matching_styles: u8,
+}
+
 /// Re-export of rust-allocated (stack based) `NodeTypeKey` struct
 #[repr(C)]
 pub enum AzNodeTypeKey {
@@ -1597,6 +1834,7 @@ pub enum AzCssPropertyType {
     LineHeight,
     WordSpacing,
     TabWidth,
+    Hyphens,
     Cursor,
     Display,
     Float,
@@ -1654,11 +1892,24 @@ pub enum AzCssPropertyType {
     BoxShadowTop,
     BoxShadowBottom,
     ScrollbarStyle,
+    ScrollbarWidth,
+    ScrollbarColor,
+    ScrollbarThumbHoverColor,
+    ScrollbarThumbActiveColor,
+    WindowDragRegion,
+    CaretAnimationDuration,
+    ScrollPadding,
+    Content,
+    CounterReset,
+    CounterIncrement,
+    OverflowAnchor,
     Opacity,
     Transform,
     TransformOrigin,
     PerspectiveOrigin,
     BackfaceVisibility,
+    Perspective,
+    TransformStyle,
     MixBlendMode,
     Filter,
     BackdropFilter,
@@ -1875,39 +2126,41 @@ pub enum AzBorderStyle {
     Outset,
 }
 
-/// Re-export of rust-allocated (stack based) `StyleCursor` struct
+/// Re-export of rust-allocated (stack based) `StyleScrollbarWidth` struct
 #[repr(C)]
-pub enum AzStyleCursor {
-    Alias,
-    AllScroll,
-    Cell,
-    ColResize,
-    ContextMenu,
-    Copy,
-    Crosshair,
-    Default,
-    EResize,
-    EwResize,
-    Grab,
-    Grabbing,
-    Help,
+pub enum AzStyleScrollbarWidth {
+    Auto,
+    Thin,
+    None,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleOverflowAnchor` struct
+#[repr(C)]
+pub enum AzStyleOverflowAnchor {
+    Auto,
+    None,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleWindowDragRegion` struct
+#[repr(C)]
+pub enum AzStyleWindowDragRegion {
+    None,
     Move,
-    NResize,
-    NsResize,
-    NeswResize,
-    NwseResize,
-    Pointer,
-    Progress,
-    RowResize,
-    SResize,
-    SeResize,
-    Text,
-    Unset,
-    VerticalText,
-    WResize,
-    Wait,
-    ZoomIn,
-    ZoomOut,
+    ResizeN,
+    ResizeNE,
+    ResizeE,
+    ResizeSE,
+    ResizeS,
+    ResizeSW,
+    ResizeW,
+    ResizeNW,
+    MaximizeButton,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleCaretAnimationDuration` struct
+#[repr(C)]
+pub struct AzStyleCaretAnimationDuration {
+    pub inner: u32,
 }
 
 /// Re-export of rust-allocated (stack based) `StyleBackfaceVisibility` struct
@@ -1917,6 +2170,13 @@ pub enum AzStyleBackfaceVisibility {
     Visible,
 }
 
+/// Whether the children of a node are flattened into its plane or positioned in the 3D space
+#[repr(C)]
+pub enum AzStyleTransformStyle {
+    Flat,
+    Preserve3D,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleTextAlign` struct
 #[repr(C)]
 pub enum AzStyleTextAlign {
@@ -1925,6 +2185,14 @@ pub enum AzStyleTextAlign {
     Right,
 }
 
+/// Whether words may be hyphenated when a line is broken inside of them
+#[repr(C)]
+pub enum AzStyleHyphens {
+    None,
+    Manual,
+    Auto,
+}
+
 /// Re-export of rust-allocated (stack based) `Ribbon` struct
 #[repr(C)]
 pub struct AzRibbon {
@@ -2007,6 +2275,15 @@ pub struct AzTextInputOnFocusLostCallback {
 /// `AzTextInputOnFocusLostCallbackType` struct
 pub type AzTextInputOnFocusLostCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTextInputState) -> AzUpdate;
 
+/// Re-export of rust-allocated (stack based) `TextInputOnSpellingSuggestionsCallback` struct
+#[repr(C)]
+pub struct AzTextInputOnSpellingSuggestionsCallback {
+    pub cb: AzTextInputOnSpellingSuggestionsCallbackType,
+}
+
+/// `AzTextInputOnSpellingSuggestionsCallbackType` struct
+pub type AzTextInputOnSpellingSuggestionsCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTextInputState, &AzTextInputSpellingSuggestions) -> AzUpdate;
+
 /// Re-export of rust-allocated (stack based) `TextInputValid` struct
 #[repr(C)]
 pub enum AzTextInputValid {
@@ -2014,13 +2291,11 @@ pub enum AzTextInputValid {
     No,
 }
 
-/// Re-export of rust-allocated (stack based) `NumberInputState` struct
+/// Whether the `NumberInput` accepts any number or only integers
 #[repr(C)]
-pub struct AzNumberInputState {
-    pub previous: f32,
-    pub number: f32,
-    pub min: f32,
-    pub max: f32,
+pub enum AzNumberInputMode {
+    Integer,
+    Float,
 }
 
 /// Re-export of rust-allocated (stack based) `NumberInputOnValueChangeCallback` struct
@@ -2216,6 +2491,21 @@ pub struct AzDropDownOnChoiceChangeCallback {
     pub cb: AzDropDownOnChoiceChangeCallbackType,
 }
 
+/// Identifies a toast that was shown with `Toasts::show`
+#[repr(C)]
+pub struct AzToastId {
+    pub id: usize,
+}
+
+/// Re-export of rust-allocated (stack based) `ToastOnActionCallback` struct
+#[repr(C)]
+pub struct AzToastOnActionCallback {
+    pub cb: AzToastOnActionCallbackType,
+}
+
+/// `AzToastOnActionCallbackType` struct
+pub type AzToastOnActionCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo) -> AzUpdate;
+
 /// Re-export of rust-allocated (stack based) `NodeHierarchyItem` struct
 #[repr(C)]
 pub struct AzNodeHierarchyItem {
@@ -2239,6 +2529,10 @@ pub struct AzStyledNodeState {
     pub hover: bool,
     pub active: bool,
     pub focused: bool,
+    pub disabled: bool,
+    pub checked: bool,
+    pub selected: bool,
+    pub indeterminate: bool,
 }
 
 /// Re-export of rust-allocated (stack based) `TagId` struct
@@ -2267,6 +2561,17 @@ pub struct AzGlVoidPtrMut {
     pub ptr: *mut c_void,
 }
 
+/// Workarounds for driver bugs, applied when the renderer is created
+#[repr(C)]
+pub struct AzGlQuirks {
+    pub force_software_rendering: bool,
+    pub disable_subpixel_aa: bool,
+    pub disable_optimized_shaders: bool,
+    pub disable_program_cache: bool,
+    pub disable_multithreading: bool,
+    pub disable_partial_present: bool,
+}
+
 /// Re-export of rust-allocated (stack based) `GlShaderPrecisionFormatReturn` struct
 #[repr(C)]
 pub struct AzGlShaderPrecisionFormatReturn {
@@ -2745,6 +3050,13 @@ pub struct AzThreadReceiver {
     pub run_destructor: bool,
 }
 
+/// Handle to wake up the event loop from other threads, created by `App::create_event_loop_proxy()`. NOTE: user events are currently only delivered on Windows, the X11 event loop can't invoke callbacks or run timers yet and rejects them.
+#[repr(C)]
+pub struct AzEventLoopProxy {
+    pub ptr: *const c_void,
+    pub run_destructor: bool,
+}
+
 /// `AzCreateThreadFnType` struct
 pub type AzCreateThreadFnType = extern "C" fn(AzRefAny, AzRefAny, AzThreadCallback) -> AzThread;
 
@@ -2763,6 +3075,42 @@ pub struct AzGetSystemTimeFn {
     pub cb: AzGetSystemTimeFnType,
 }
 
+/// `AzGetSystemIdleTimeFnType` struct
+pub type AzGetSystemIdleTimeFnType = extern "C" fn() -> AzOptionDuration;
+
+/// Get the time since the last user input on the entire system, `None` if the platform does not support querying the idle time
+#[repr(C)]
+pub struct AzGetSystemIdleTimeFn {
+    pub cb: AzGetSystemIdleTimeFnType,
+}
+
+/// `AzGetLogLinesFnType` struct
+pub type AzGetLogLinesFnType = extern "C" fn() -> AzStringVec;
+
+/// Returns the most recent lines of the log ring buffer (oldest first)
+#[repr(C)]
+pub struct AzGetLogLinesFn {
+    pub cb: AzGetLogLinesFnType,
+}
+
+/// `AzKeycodeToCharFnType` struct
+pub type AzKeycodeToCharFnType = extern "C" fn(AzVirtualKeyCode, AzKeyboardModifiers) -> AzOptionChar;
+
+/// Returns the character that the key produces with the given modifiers in the current keyboard layout of the operating system
+#[repr(C)]
+pub struct AzKeycodeToCharFn {
+    pub cb: AzKeycodeToCharFnType,
+}
+
+/// `AzSetLogLevelFnType` struct
+pub type AzSetLogLevelFnType = extern "C" fn(AzString, AzAppLogLevel);
+
+/// Sets the log level of a subsystem at runtime
+#[repr(C)]
+pub struct AzSetLogLevelFn {
+    pub cb: AzSetLogLevelFnType,
+}
+
 /// `AzCheckThreadFinishedFnType` struct
 pub type AzCheckThreadFinishedFnType = extern "C" fn(&c_void) -> bool;
 
@@ -3066,6 +3414,17 @@ pub enum AzInlineWordVecDestructor {
 /// `AzInlineWordVecDestructorType` struct
 pub type AzInlineWordVecDestructorType = extern "C" fn(&mut AzInlineWordVec);
 
+/// Re-export of rust-allocated (stack based) `PaintPrimitiveVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzPaintPrimitiveVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzPaintPrimitiveVecDestructorType),
+}
+
+/// `AzPaintPrimitiveVecDestructorType` struct
+pub type AzPaintPrimitiveVecDestructorType = extern "C" fn(&mut AzPaintPrimitiveVec);
+
 /// Re-export of rust-allocated (stack based) `InlineGlyphVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzInlineGlyphVecDestructor {
@@ -3077,6 +3436,17 @@ pub enum AzInlineGlyphVecDestructor {
 /// `AzInlineGlyphVecDestructorType` struct
 pub type AzInlineGlyphVecDestructorType = extern "C" fn(&mut AzInlineGlyphVec);
 
+/// Re-export of rust-allocated (stack based) `TextUnderlineVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTextUnderlineVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTextUnderlineVecDestructorType),
+}
+
+/// `AzTextUnderlineVecDestructorType` struct
+pub type AzTextUnderlineVecDestructorType = extern "C" fn(&mut AzTextUnderlineVec);
+
 /// Re-export of rust-allocated (stack based) `InlineTextHitVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzInlineTextHitVecDestructor {
@@ -3099,6 +3469,17 @@ pub enum AzMonitorVecDestructor {
 /// `AzMonitorVecDestructorType` struct
 pub type AzMonitorVecDestructorType = extern "C" fn(&mut AzMonitorVec);
 
+/// Re-export of rust-allocated (stack based) `TouchPointVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTouchPointVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTouchPointVecDestructorType),
+}
+
+/// `AzTouchPointVecDestructorType` struct
+pub type AzTouchPointVecDestructorType = extern "C" fn(&mut AzTouchPointVec);
+
 /// Re-export of rust-allocated (stack based) `VideoModeVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzVideoModeVecDestructor {
@@ -3319,6 +3700,28 @@ pub enum AzVirtualKeyCodeVecDestructor {
 /// `AzVirtualKeyCodeVecDestructorType` struct
 pub type AzVirtualKeyCodeVecDestructorType = extern "C" fn(&mut AzVirtualKeyCodeVec);
 
+/// Re-export of rust-allocated (stack based) `ShortcutConflictVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzShortcutConflictVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzShortcutConflictVecDestructorType),
+}
+
+/// `AzShortcutConflictVecDestructorType` struct
+pub type AzShortcutConflictVecDestructorType = extern "C" fn(&mut AzShortcutConflictVec);
+
+/// Re-export of rust-allocated (stack based) `ShortcutVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzShortcutVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzShortcutVecDestructorType),
+}
+
+/// `AzShortcutVecDestructorType` struct
+pub type AzShortcutVecDestructorType = extern "C" fn(&mut AzShortcutVec);
+
 /// Re-export of rust-allocated (stack based) `CascadeInfoVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzCascadeInfoVecDestructor {
@@ -3647,6 +4050,18 @@ pub struct AzSvgParseErrorPosition {
 pub struct AzSystemCallbacks {
     pub create_thread_fn: AzCreateThreadFn,
     pub get_system_time_fn: AzGetSystemTimeFn,
+    pub get_system_idle_time_fn: AzGetSystemIdleTimeFn,
+    pub get_log_lines_fn: AzGetLogLinesFn,
+    pub set_log_level_fn: AzSetLogLevelFn,
+    pub keycode_to_char_fn: AzKeycodeToCharFn,
+}
+
+/// What a window shows between being mapped and presenting the first rendered frame, see `WindowCreateOptions::first_frame`
+#[repr(C, u8)]
+pub enum AzFirstFrameMode {
+    ShowImmediately,
+    HideUntilRendered,
+    SplashColor(AzColorU),
 }
 
 /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
@@ -3702,12 +4117,17 @@ pub struct AzWindowFlags {
     pub has_decorations: bool,
     pub is_visible: bool,
     pub is_always_on_top: bool,
+    pub is_click_through: bool,
+    pub is_tool_window: bool,
     pub is_resizable: bool,
     pub has_focus: bool,
     pub has_extended_window_frame: bool,
     pub has_blur_behind_window: bool,
     pub smooth_scroll_enabled: bool,
     pub autotab_enabled: bool,
+    pub spatial_navigation_enabled: bool,
+    pub is_session_ending: bool,
+    pub fullscreen_mode: AzFullscreenModeEnumWrapper,
 }
 
 /// Current position of the mouse cursor, relative to the window. Set to `Uninitialized` on startup (gets initialized on the first frame).
@@ -3732,6 +4152,14 @@ pub enum AzImePosition {
     Initialized(AzLogicalPosition),
 }
 
+/// Single finger / stylus that is currently touching the screen
+#[repr(C)]
+pub struct AzTouchPoint {
+    pub id: u64,
+    pub position: AzLogicalPosition,
+    pub force: f32,
+}
+
 /// Describes a rendering configuration for a monitor
 #[repr(C)]
 pub struct AzVideoMode {
@@ -3756,6 +4184,24 @@ pub enum AzPositionInfo {
     Relative(AzPositionInfoInner),
 }
 
+/// Post-layout geometry of a node, all rects are relative to the window origin
+#[repr(C)]
+pub struct AzNodeRect {
+    pub margin_box: AzLogicalRect,
+    pub border_box: AzLogicalRect,
+    pub padding_box: AzLogicalRect,
+    pub content_box: AzLogicalRect,
+}
+
+/// Scroll extents of a scrollable (overflowing) node
+#[repr(C)]
+pub struct AzScrollInfo {
+    pub scroll_position: AzLogicalPosition,
+    pub viewport: AzLogicalRect,
+    pub content_size: AzLogicalSize,
+    pub max_scroll: AzLogicalSize,
+}
+
 /// Re-export of rust-allocated (stack based) `HidpiAdjustedBounds` struct
 #[repr(C)]
 pub struct AzHidpiAdjustedBounds {
@@ -3771,6 +4217,15 @@ pub struct AzInlineGlyph {
     pub glyph_index: u32,
 }
 
+/// Underlines a range of characters of a text node, see `CallbackInfo::set_text_underlines`
+#[repr(C)]
+pub struct AzTextUnderline {
+    pub start: usize,
+    pub end: usize,
+    pub color: AzColorU,
+    pub style: AzTextUnderlineStyleEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `InlineTextHit` struct
 #[repr(C)]
 pub struct AzInlineTextHit {
@@ -3807,6 +4262,33 @@ pub struct AzIFrameCallbackInfo {
     pub _reserved_mut: *mut c_void,
 }
 
+/// Re-export of rust-allocated (stack based) `MeasureCallbackInfo` struct
+#[repr(C)]
+pub struct AzMeasureCallbackInfo {
+    pub available_size: AzLogicalSize,
+    pub width_is_final: bool,
+}
+
+/// Re-export of rust-allocated (stack based) `ArrangeCallbackInfo` struct
+#[repr(C)]
+pub struct AzArrangeCallbackInfo {
+    pub bounds: AzNodeRect,
+}
+
+/// Re-export of rust-allocated (stack based) `PaintCallbackInfo` struct
+#[repr(C)]
+pub struct AzPaintCallbackInfo {
+    pub size: AzLogicalSize,
+}
+
+/// Filled (rounded) rectangle
+#[repr(C)]
+pub struct AzPaintRect {
+    pub bounds: AzLogicalRect,
+    pub color: AzColorU,
+    pub border_radius: f32,
+}
+
 /// Re-export of rust-allocated (stack based) `TimerCallbackReturn` struct
 #[repr(C)]
 pub struct AzTimerCallbackReturn {
@@ -3828,6 +4310,7 @@ pub struct AzRefAny {
 pub struct AzIFrameNode {
     pub callback: AzIFrameCallback,
     pub data: AzRefAny,
+    pub reinvoke_threshold: AzFloatValue,
 }
 
 /// Re-export of rust-allocated (stack based) `NotEventFilter` struct
@@ -3860,6 +4343,28 @@ pub enum AzCssNthChildSelector {
     Pattern(AzCssNthChildPattern),
 }
 
+/// Colors of the CSS system color keywords (`Canvas`, `CanvasText`, `Highlight`, ...)
+#[repr(C)]
+pub struct AzSystemColors {
+    pub canvas: AzColorU,
+    pub canvas_text: AzColorU,
+    pub link_text: AzColorU,
+    pub gray_text: AzColorU,
+    pub highlight: AzColorU,
+    pub highlight_text: AzColorU,
+    pub button_face: AzColorU,
+    pub button_text: AzColorU,
+}
+
+/// High contrast / forced colors mode, reduced motion preference and system colors of the operating system
+#[repr(C)]
+pub struct AzSystemStyle {
+    pub forced_colors: bool,
+    pub prefers_reduced_motion: bool,
+    pub pointer_coarse: bool,
+    pub colors: AzSystemColors,
+}
+
 /// Re-export of rust-allocated (stack based) `PixelValue` struct
 #[repr(C)]
 pub struct AzPixelValue {
@@ -4205,6 +4710,31 @@ pub struct AzLayoutBorderTopWidth {
     pub inner: AzPixelValue,
 }
 
+/// Re-export of rust-allocated (stack based) `StyleScrollbarColor` struct
+#[repr(C)]
+pub struct AzStyleScrollbarColor {
+    pub thumb: AzColorU,
+    pub track: AzColorU,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleScrollbarThumbHoverColor` struct
+#[repr(C)]
+pub struct AzStyleScrollbarThumbHoverColor {
+    pub inner: AzColorU,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleScrollbarThumbActiveColor` struct
+#[repr(C)]
+pub struct AzStyleScrollbarThumbActiveColor {
+    pub inner: AzColorU,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleScrollPadding` struct
+#[repr(C)]
+pub struct AzStyleScrollPadding {
+    pub inner: AzPixelValue,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleFontSize` struct
 #[repr(C)]
 pub struct AzStyleFontSize {
@@ -4249,6 +4779,12 @@ pub struct AzStylePerspectiveOrigin {
     pub y: AzPixelValue,
 }
 
+/// Distance between the z = 0 plane and the viewer, applied to the children of the node
+#[repr(C)]
+pub struct AzStylePerspective {
+    pub inner: AzPixelValue,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleTransformMatrix2D` struct
 #[repr(C)]
 pub struct AzStyleTransformMatrix2D {
@@ -4649,6 +5185,86 @@ pub enum AzLayoutOverflowValue {
     Exact(AzLayoutOverflow),
 }
 
+/// Re-export of rust-allocated (stack based) `StyleScrollbarWidthValue` struct
+#[repr(C, u8)]
+pub enum AzStyleScrollbarWidthValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleScrollbarWidth),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleScrollbarColorValue` struct
+#[repr(C, u8)]
+pub enum AzStyleScrollbarColorValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleScrollbarColor),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleScrollbarThumbHoverColorValue` struct
+#[repr(C, u8)]
+pub enum AzStyleScrollbarThumbHoverColorValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleScrollbarThumbHoverColor),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleScrollbarThumbActiveColorValue` struct
+#[repr(C, u8)]
+pub enum AzStyleScrollbarThumbActiveColorValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleScrollbarThumbActiveColor),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleWindowDragRegionValue` struct
+#[repr(C, u8)]
+pub enum AzStyleWindowDragRegionValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleWindowDragRegion),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleCaretAnimationDurationValue` struct
+#[repr(C, u8)]
+pub enum AzStyleCaretAnimationDurationValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleCaretAnimationDuration),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleScrollPaddingValue` struct
+#[repr(C, u8)]
+pub enum AzStyleScrollPaddingValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleScrollPadding),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleOverflowAnchorValue` struct
+#[repr(C, u8)]
+pub enum AzStyleOverflowAnchorValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleOverflowAnchor),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleBorderBottomColorValue` struct
 #[repr(C, u8)]
 pub enum AzStyleBorderBottomColorValue {
@@ -4809,16 +5425,6 @@ pub enum AzLayoutBorderTopWidthValue {
     Exact(AzLayoutBorderTopWidth),
 }
 
-/// Re-export of rust-allocated (stack based) `StyleCursorValue` struct
-#[repr(C, u8)]
-pub enum AzStyleCursorValue {
-    Auto,
-    None,
-    Inherit,
-    Initial,
-    Exact(AzStyleCursor),
-}
-
 /// Re-export of rust-allocated (stack based) `StyleFontSizeValue` struct
 #[repr(C, u8)]
 pub enum AzStyleFontSizeValue {
@@ -4859,6 +5465,16 @@ pub enum AzStyleTabWidthValue {
     Exact(AzStyleTabWidth),
 }
 
+/// Re-export of rust-allocated (stack based) `StyleHyphensValue` struct
+#[repr(C, u8)]
+pub enum AzStyleHyphensValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleHyphens),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleTextAlignValue` struct
 #[repr(C, u8)]
 pub enum AzStyleTextAlignValue {
@@ -4929,6 +5545,26 @@ pub enum AzStyleBackfaceVisibilityValue {
     Exact(AzStyleBackfaceVisibility),
 }
 
+/// Re-export of rust-allocated (stack based) `StylePerspectiveValue` struct
+#[repr(C, u8)]
+pub enum AzStylePerspectiveValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStylePerspective),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleTransformStyleValue` struct
+#[repr(C, u8)]
+pub enum AzStyleTransformStyleValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleTransformStyle),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleMixBlendModeValue` struct
 #[repr(C, u8)]
 pub enum AzStyleMixBlendModeValue {
@@ -5001,6 +5637,13 @@ pub struct AzTextInputOnFocusLost {
     pub callback: AzTextInputOnFocusLostCallback,
 }
 
+/// Re-export of rust-allocated (stack based) `TextInputOnSpellingSuggestions` struct
+#[repr(C)]
+pub struct AzTextInputOnSpellingSuggestions {
+    pub data: AzRefAny,
+    pub callback: AzTextInputOnSpellingSuggestionsCallback,
+}
+
 /// Re-export of rust-allocated (stack based) `OnTextInputReturn` struct
 #[repr(C)]
 pub struct AzOnTextInputReturn {
@@ -5008,6 +5651,19 @@ pub struct AzOnTextInputReturn {
     pub valid: AzTextInputValidEnumWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `NumberInputState` struct
+#[repr(C)]
+pub struct AzNumberInputState {
+    pub previous: f64,
+    pub number: f64,
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+    pub mode: AzNumberInputModeEnumWrapper,
+    pub decimal_separator: u32,
+    pub is_valid: bool,
+}
+
 /// Re-export of rust-allocated (stack based) `NumberInputOnValueChange` struct
 #[repr(C)]
 pub struct AzNumberInputOnValueChange {
@@ -5127,6 +5783,13 @@ pub struct AzDropDownOnChoiceChange {
     pub callback: AzDropDownOnChoiceChangeCallback,
 }
 
+/// Re-export of rust-allocated (stack based) `ToastOnAction` struct
+#[repr(C)]
+pub struct AzToastOnAction {
+    pub data: AzRefAny,
+    pub callback: AzToastOnActionCallback,
+}
+
 /// Re-export of rust-allocated (stack based) `ParentWithNodeDepth` struct
 #[repr(C)]
 pub struct AzParentWithNodeDepth {
@@ -5364,6 +6027,15 @@ pub struct AzInlineGlyphVec {
     pub destructor: AzInlineGlyphVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<TextUnderline>`
+#[repr(C)]
+pub struct AzTextUnderlineVec {
+    pub(crate) ptr: *const AzTextUnderline,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzTextUnderlineVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<InlineTextHit>`
 #[repr(C)]
 pub struct AzInlineTextHitVec {
@@ -5373,6 +6045,15 @@ pub struct AzInlineTextHitVec {
     pub destructor: AzInlineTextHitVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<TouchPoint>`
+#[repr(C)]
+pub struct AzTouchPointVec {
+    pub(crate) ptr: *const AzTouchPoint,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzTouchPointVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<VideoMode>`
 #[repr(C)]
 pub struct AzVideoModeVec {
@@ -5613,6 +6294,13 @@ pub enum AzOptionDropDownOnChoiceChange {
     Some(AzDropDownOnChoiceChange),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionToastOnAction` struct
+#[repr(C, u8)]
+pub enum AzOptionToastOnAction {
+    None,
+    Some(AzToastOnAction),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeAdded` struct
 #[repr(C, u8)]
 pub enum AzOptionNodeGraphOnNodeAdded {
@@ -5725,6 +6413,13 @@ pub enum AzOptionTextInputOnFocusLost {
     Some(AzTextInputOnFocusLost),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionTextInputOnSpellingSuggestions` struct
+#[repr(C, u8)]
+pub enum AzOptionTextInputOnSpellingSuggestions {
+    None,
+    Some(AzTextInputOnSpellingSuggestions),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionTextInputSelection` struct
 #[repr(C, u8)]
 pub enum AzOptionTextInputSelection {
@@ -5767,6 +6462,20 @@ pub enum AzOptionPositionInfo {
     Some(AzPositionInfo),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionNodeRect` struct
+#[repr(C, u8)]
+pub enum AzOptionNodeRect {
+    None,
+    Some(AzNodeRect),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionScrollInfo` struct
+#[repr(C, u8)]
+pub enum AzOptionScrollInfo {
+    None,
+    Some(AzScrollInfo),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionTimerId` struct
 #[repr(C, u8)]
 pub enum AzOptionTimerId {
@@ -5837,6 +6546,20 @@ pub enum AzOptionCallback {
     Some(AzCallback),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionPaintCallback` struct
+#[repr(C, u8)]
+pub enum AzOptionPaintCallback {
+    None,
+    Some(AzPaintCallback),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionCrashHandler` struct
+#[repr(C, u8)]
+pub enum AzOptionCrashHandler {
+    None,
+    Some(AzCrashHandler),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionThreadSendMsg` struct
 #[repr(C, u8)]
 pub enum AzOptionThreadSendMsg {
@@ -5907,6 +6630,13 @@ pub enum AzOptionSvgDashPattern {
     Some(AzSvgDashPattern),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionRawWindowHandle` struct
+#[repr(C, u8)]
+pub enum AzOptionRawWindowHandle {
+    None,
+    Some(AzRawWindowHandle),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionLogicalPosition` struct
 #[repr(C, u8)]
 pub enum AzOptionLogicalPosition {
@@ -6028,15 +6758,34 @@ pub struct AzInvalidSpaceError {
     pub pos: AzSvgParseErrorPosition,
 }
 
-/// Configuration for optional features, such as whether to enable logging or panic hooks
+/// Startup splash screen (logo + progress bar), see `App::with_splash`
 #[repr(C)]
-pub struct AzAppConfig {
-    pub layout_solver: AzLayoutSolverEnumWrapper,
-    pub log_level: AzAppLogLevelEnumWrapper,
-    pub enable_visual_panic_hook: bool,
-    pub enable_logging_on_panic: bool,
-    pub enable_tab_navigation: bool,
-    pub system_callbacks: AzSystemCallbacks,
+pub struct AzSplashOptions {
+    pub logo: AzOptionImageRefEnumWrapper,
+    pub size: AzLogicalSize,
+    pub background_color: AzColorU,
+    pub progress_bar_color: AzColorU,
+    pub cross_fade: AzDurationEnumWrapper,
+}
+
+/// A frame that was presented by a window, passed to the `FrameRecordingCallback`
+#[repr(C)]
+pub struct AzRecordedFrame {
+    pub window_handle: AzRawWindowHandleEnumWrapper,
+    pub frame_index: usize,
+    pub timestamp: AzDurationEnumWrapper,
+    pub duration: AzDurationEnumWrapper,
+    pub image: AzImageRef,
+}
+
+/// Behavior of the built-in scrollbars of a window, see `WindowCreateOptions::scrollbars`
+#[repr(C)]
+pub struct AzScrollbarConfig {
+    pub mode: AzScrollbarModeEnumWrapper,
+    pub min_thumb_length: f32,
+    pub hit_area_margin: f32,
+    pub auto_hide_delay: AzOptionDurationEnumWrapper,
+    pub auto_hide_fade_duration: AzDurationEnumWrapper,
 }
 
 /// Small (16x16x4) window icon, usually shown in the window titlebar
@@ -6074,6 +6823,10 @@ pub struct AzWindowSize {
     pub dpi: u32,
     pub min_dimensions: AzOptionLogicalSizeEnumWrapper,
     pub max_dimensions: AzOptionLogicalSizeEnumWrapper,
+    pub min_dimensions_from_content: bool,
+    pub resize_increments: AzOptionLogicalSizeEnumWrapper,
+    pub aspect_ratio_lock: AzOptionF32EnumWrapper,
+    pub zoom: f32,
 }
 
 /// Current keyboard state, stores what keys / characters have been pressed
@@ -6083,6 +6836,9 @@ pub struct AzKeyboardState {
     pub current_virtual_keycode: AzOptionVirtualKeyCodeEnumWrapper,
     pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
     pub pressed_scancodes: AzScanCodeVec,
+    pub caps_lock_enabled: bool,
+    pub num_lock_enabled: bool,
+    pub scroll_lock_enabled: bool,
 }
 
 /// Current mouse / cursor state
@@ -6094,10 +6850,18 @@ pub struct AzMouseState {
     pub left_down: bool,
     pub right_down: bool,
     pub middle_down: bool,
+    pub click_count: u32,
     pub scroll_x: AzOptionF32EnumWrapper,
     pub scroll_y: AzOptionF32EnumWrapper,
 }
 
+/// Current state of touch devices / touch inputs
+#[repr(C)]
+pub struct AzTouchState {
+    pub touches: AzTouchPointVec,
+    pub input_modality: AzInputModalityEnumWrapper,
+}
+
 /// C-ABI stable wrapper over a `MarshaledLayoutCallback`
 #[repr(C)]
 pub struct AzMarshaledLayoutCallback {
@@ -6136,6 +6900,12 @@ pub enum AzAnimationEasing {
     CubicBezier(AzSvgCubicCurve),
 }
 
+/// Primitive drawn by a `PaintCallback`
+#[repr(C, u8)]
+pub enum AzPaintPrimitive {
+    Rect(AzPaintRect),
+}
+
 /// Re-export of rust-allocated (stack based) `RenderImageCallbackInfo` struct
 #[repr(C)]
 pub struct AzRenderImageCallbackInfo {
@@ -6165,6 +6935,15 @@ pub struct AzLayoutCallbackInfo {
     pub _reserved_mut: *mut c_void,
 }
 
+/// Re-export of rust-allocated (stack based) `CustomLayoutNode` struct
+#[repr(C)]
+pub struct AzCustomLayoutNode {
+    pub measure: AzMeasureCallback,
+    pub arrange: AzArrangeCallback,
+    pub paint: AzOptionPaintCallbackEnumWrapper,
+    pub data: AzRefAny,
+}
+
 /// Re-export of rust-allocated (stack based) `EventFilter` struct
 #[repr(C, u8)]
 pub enum AzEventFilter {
@@ -6190,6 +6969,22 @@ pub struct AzVirtualKeyCodeCombo {
     pub keys: AzVirtualKeyCodeVec,
 }
 
+/// Window-level keyboard shortcut: invokes the callback when the key combination is pressed, instead of the `VirtualKeyDown` callbacks of the focused node
+#[repr(C)]
+pub struct AzShortcut {
+    pub keys: AzVirtualKeyCodeCombo,
+    pub callback: AzCallback,
+    pub data: AzRefAny,
+}
+
+/// Two shortcuts in a `ShortcutMap` that use the same key combination
+#[repr(C)]
+pub struct AzShortcutConflict {
+    pub keys: AzVirtualKeyCodeCombo,
+    pub existing: usize,
+    pub conflicting: usize,
+}
+
 /// Re-export of rust-allocated (stack based) `CssPathPseudoSelector` struct
 #[repr(C, u8)]
 pub enum AzCssPathPseudoSelector {
@@ -6199,6 +6994,12 @@ pub enum AzCssPathPseudoSelector {
     Hover,
     Active,
     Focus,
+    Disabled,
+    Checked,
+    Selected,
+    Indeterminate,
+    Before,
+    After,
 }
 
 /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
@@ -6326,14 +7127,6 @@ pub struct AzCheckBoxStateWrapper {
     pub on_toggle: AzOptionCheckBoxOnToggleEnumWrapper,
 }
 
-/// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
-#[repr(C)]
-pub struct AzNumberInputStateWrapper {
-    pub inner: AzNumberInputState,
-    pub on_value_change: AzOptionNumberInputOnValueChangeEnumWrapper,
-    pub on_focus_lost: AzOptionNumberInputOnFocusLostEnumWrapper,
-}
-
 /// Re-export of rust-allocated (stack based) `NodeGraphCallbacks` struct
 #[repr(C)]
 pub struct AzNodeGraphCallbacks {
@@ -6561,6 +7354,15 @@ pub struct AzTessellatedColoredSvgNodeVec {
     pub destructor: AzTessellatedColoredSvgNodeVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<PaintPrimitive>`
+#[repr(C)]
+pub struct AzPaintPrimitiveVec {
+    pub(crate) ptr: *const AzPaintPrimitiveEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzPaintPrimitiveVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<StyleTransform>`
 #[repr(C)]
 pub struct AzStyleTransformVec {
@@ -6579,6 +7381,24 @@ pub struct AzSvgPathElementVec {
     pub destructor: AzSvgPathElementVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `ShortcutConflict`
+#[repr(C)]
+pub struct AzShortcutConflictVec {
+    pub(crate) ptr: *const AzShortcutConflict,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzShortcutConflictVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Shortcut`
+#[repr(C)]
+pub struct AzShortcutVec {
+    pub(crate) ptr: *const AzShortcut,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzShortcutVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `StringVec`
 #[repr(C)]
 pub struct AzStringVec {
@@ -6627,6 +7447,13 @@ pub enum AzOptionVirtualKeyCodeCombo {
     Some(AzVirtualKeyCodeCombo),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionShortcutConflict` struct
+#[repr(C, u8)]
+pub enum AzOptionShortcutConflict {
+    None,
+    Some(AzShortcutConflict),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionMouseState` struct
 #[repr(C, u8)]
 pub enum AzOptionMouseState {
@@ -6733,6 +7560,40 @@ pub struct AzInvalidStringError {
     pub pos: AzSvgParseErrorPosition,
 }
 
+/// Configuration of the jank detector, see `AppConfig::jank_capture`
+#[repr(C)]
+pub struct AzJankCapture {
+    pub threshold: AzDurationEnumWrapper,
+    pub dump_display_list: bool,
+    pub output_directory: AzOptionStringEnumWrapper,
+}
+
+/// Whether input events are recorded or replayed, see `AppConfig::event_recording`
+#[repr(C, u8)]
+pub enum AzEventRecording {
+    Record(AzString),
+    Replay(AzString),
+}
+
+/// Where the frames of a `FrameRecording` are written to
+#[repr(C, u8)]
+pub enum AzFrameRecordingOutput {
+    GifDirectory(AzString),
+    Callback(AzFrameRecordingCallback),
+}
+
+/// Information about a panic or crash, passed to the `CrashHandler`
+#[repr(C)]
+pub struct AzCrashInfo {
+    pub message: AzString,
+    pub location: AzString,
+    pub thread_name: AzString,
+    pub backtrace: AzString,
+    pub last_log_lines: AzStringVec,
+    pub window_state_summary: AzString,
+    pub minidump_path: AzOptionStringEnumWrapper,
+}
+
 /// Window configuration specific to Win32
 #[repr(C)]
 pub struct AzWindowsWindowOptions {
@@ -6809,6 +7670,7 @@ pub struct AzMonitor {
     pub scale_factor: f64,
     pub video_modes: AzVideoModeVec,
     pub is_primary_monitor: bool,
+    pub hdr: AzHdrCapabilities,
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutCallback` struct
@@ -6827,6 +7689,15 @@ pub enum AzInlineWord {
     Word(AzInlineTextContents),
 }
 
+/// Node type defined outside of azul (map view, terminal widget, ...): the measure / arrange / paint hooks that all nodes of this type share
+#[repr(C)]
+pub struct AzCustomNodeType {
+    pub name: AzString,
+    pub measure: AzMeasureCallback,
+    pub arrange: AzArrangeCallback,
+    pub paint: AzOptionPaintCallbackEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `CallbackData` struct
 #[repr(C)]
 pub struct AzCallbackData {
@@ -6844,6 +7715,7 @@ pub enum AzNodeType {
     Text(AzString),
     Image(AzImageRef),
     IFrame(AzIFrameNode),
+    CustomLayout(AzCustomLayoutNode),
 }
 
 /// Accessibility information (MSAA wrapper). See `NodeData.set_accessibility_info()`
@@ -6857,6 +7729,22 @@ pub struct AzAccessibilityInfo {
     pub default_action: AzOptionStringEnumWrapper,
 }
 
+/// Per-node overrides for the spatial focus navigation, similar to the `nav-up: #id` CSS properties
+#[repr(C)]
+pub struct AzFocusNavigation {
+    pub up: AzOptionStringEnumWrapper,
+    pub down: AzOptionStringEnumWrapper,
+    pub left: AzOptionStringEnumWrapper,
+    pub right: AzOptionStringEnumWrapper,
+}
+
+/// Marks a node as an overlay: the node is taken out of the regular layout, positioned next to the node with the ID `anchor` and rendered above all other content, clipped only by the window. If the overlay doesn't fit on the requested side, it is flipped to the opposite side and shifted back into the window
+#[repr(C)]
+pub struct AzOverlayInfo {
+    pub anchor: AzString,
+    pub placement: AzOverlayPlacementEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `IdOrClass` struct
 #[repr(C, u8)]
 pub enum AzIdOrClass {
@@ -6875,6 +7763,12 @@ pub struct AzStringMenuItem {
     pub children: AzMenuItemVec,
 }
 
+/// List of window-level keyboard shortcuts
+#[repr(C)]
+pub struct AzShortcutMap {
+    pub shortcuts: AzShortcutVec,
+}
+
 /// Re-export of rust-allocated (stack based) `CssPathSelector` struct
 #[repr(C, u8)]
 pub enum AzCssPathSelector {
@@ -6917,6 +7811,37 @@ pub struct AzScrollbarStyle {
     pub vertical: AzScrollbarInfo,
 }
 
+/// Re-export of rust-allocated (stack based) `StyleContent` struct
+#[repr(C, u8)]
+pub enum AzStyleContent {
+    None,
+    Text(AzString),
+    Image(AzString),
+    Counter(AzString),
+}
+
+/// Represents a `counter-reset` attribute: `counter-reset: name [<integer>]`
+#[repr(C)]
+pub struct AzStyleCounterReset {
+    pub counter: AzString,
+    pub value: i32,
+}
+
+/// Represents a `counter-increment` attribute: `counter-increment: name [<integer>]`
+#[repr(C)]
+pub struct AzStyleCounterIncrement {
+    pub counter: AzString,
+    pub value: i32,
+}
+
+/// Custom cursor image, the hotspot is in pixels from the top left of the image
+#[repr(C)]
+pub struct AzStyleCursorImage {
+    pub image: AzString,
+    pub hotspot_x: u32,
+    pub hotspot_y: u32,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleFontFamily` struct
 #[repr(C, u8)]
 pub enum AzStyleFontFamily {
@@ -6935,6 +7860,36 @@ pub enum AzScrollbarStyleValue {
     Exact(AzScrollbarStyle),
 }
 
+/// Re-export of rust-allocated (stack based) `StyleContentValue` struct
+#[repr(C, u8)]
+pub enum AzStyleContentValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleContent),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleCounterResetValue` struct
+#[repr(C, u8)]
+pub enum AzStyleCounterResetValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleCounterReset),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleCounterIncrementValue` struct
+#[repr(C, u8)]
+pub enum AzStyleCounterIncrementValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleCounterIncrement),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleTransformVecValue` struct
 #[repr(C, u8)]
 pub enum AzStyleTransformVecValue {
@@ -6979,6 +7934,14 @@ pub struct AzTextInputState {
     pub cursor_pos: usize,
 }
 
+/// Misspelled word that was right-clicked
+#[repr(C)]
+pub struct AzTextInputSpellingSuggestions {
+    pub range: AzTextInputSelectionRange,
+    pub word: AzString,
+    pub suggestions: AzStringVec,
+}
+
 /// Re-export of rust-allocated (stack based) `TabHeader` struct
 #[repr(C)]
 pub struct AzTabHeader {
@@ -7052,6 +8015,25 @@ pub struct AzDropDown {
     pub on_choice_change: AzOptionDropDownOnChoiceChangeEnumWrapper,
 }
 
+/// Single notification message
+#[repr(C)]
+pub struct AzToast {
+    pub message: AzString,
+    pub duration: AzOptionDurationEnumWrapper,
+    pub action_label: AzString,
+    pub on_action: AzOptionToastOnActionEnumWrapper,
+}
+
+/// Enables / disables workarounds for all drivers that match the rule. The patterns are matched case-insensitively against substrings of the `GL_VENDOR`, `GL_RENDERER` and `GL_VERSION` strings, a `None` pattern matches every driver. The `version` pattern can also compare version numbers: `"Mesa < 21.2"` matches if `GL_VERSION` contains "Mesa" and the number after it is lower than 21.2, without a prefix (`">= 4.5"`) the first number of `GL_VERSION` is compared. Supported operators are `<`, `<=`, `>`, `>=` and `==`.
+#[repr(C)]
+pub struct AzGlQuirkRule {
+    pub vendor: AzOptionStringEnumWrapper,
+    pub renderer: AzOptionStringEnumWrapper,
+    pub version: AzOptionStringEnumWrapper,
+    pub enable: AzGlQuirks,
+    pub disable: AzGlQuirks,
+}
+
 /// Re-export of rust-allocated (stack based) `VertexAttribute` struct
 #[repr(C)]
 pub struct AzVertexAttribute {
@@ -7303,6 +8285,20 @@ pub enum AzOptionFile {
     Some(AzFile),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionJankCapture` struct
+#[repr(C, u8)]
+pub enum AzOptionJankCapture {
+    None,
+    Some(AzJankCapture),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionEventRecording` struct
+#[repr(C, u8)]
+pub enum AzOptionEventRecording {
+    None,
+    Some(AzEventRecording),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionRawImage` struct
 #[repr(C, u8)]
 pub enum AzOptionRawImage {
@@ -7342,6 +8338,13 @@ pub enum AzXmlStreamError {
     InvalidCharacterData,
 }
 
+/// Recording of the presented frames of all windows, see `AppConfig::frame_recording`
+#[repr(C)]
+pub struct AzFrameRecording {
+    pub output: AzFrameRecordingOutputEnumWrapper,
+    pub max_fps: u32,
+}
+
 /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
 #[repr(C)]
 pub struct AzLinuxWindowOptions {
@@ -7380,6 +8383,42 @@ pub struct AzCssPath {
     pub selectors: AzCssPathSelectorVec,
 }
 
+/// Re-export of rust-allocated (stack based) `StyleCursor` struct
+#[repr(C, u8)]
+pub enum AzStyleCursor {
+    Alias,
+    AllScroll,
+    Cell,
+    ColResize,
+    ContextMenu,
+    Copy,
+    Crosshair,
+    Default,
+    EResize,
+    EwResize,
+    Grab,
+    Grabbing,
+    Help,
+    Move,
+    NResize,
+    NsResize,
+    NeswResize,
+    NwseResize,
+    Pointer,
+    Progress,
+    RowResize,
+    SResize,
+    SeResize,
+    Text,
+    Unset,
+    VerticalText,
+    WResize,
+    Wait,
+    ZoomIn,
+    ZoomOut,
+    Image(AzStyleCursorImage),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
 #[repr(C, u8)]
 pub enum AzStyleBackgroundContentVecValue {
@@ -7390,6 +8429,16 @@ pub enum AzStyleBackgroundContentVecValue {
     Exact(AzStyleBackgroundContentVec),
 }
 
+/// Re-export of rust-allocated (stack based) `StyleCursorValue` struct
+#[repr(C, u8)]
+pub enum AzStyleCursorValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleCursor),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecValue` struct
 #[repr(C, u8)]
 pub enum AzStyleFontFamilyVecValue {
@@ -7411,6 +8460,7 @@ pub enum AzCssProperty {
     LineHeight(AzStyleLineHeightValue),
     WordSpacing(AzStyleWordSpacingValue),
     TabWidth(AzStyleTabWidthValue),
+    Hyphens(AzStyleHyphensValue),
     Cursor(AzStyleCursorValue),
     Display(AzLayoutDisplayValue),
     Float(AzLayoutFloatValue),
@@ -7468,11 +8518,24 @@ pub enum AzCssProperty {
     BoxShadowTop(AzStyleBoxShadowValue),
     BoxShadowBottom(AzStyleBoxShadowValue),
     ScrollbarStyle(AzScrollbarStyleValue),
+    ScrollbarWidth(AzStyleScrollbarWidthValue),
+    ScrollbarColor(AzStyleScrollbarColorValue),
+    ScrollbarThumbHoverColor(AzStyleScrollbarThumbHoverColorValue),
+    ScrollbarThumbActiveColor(AzStyleScrollbarThumbActiveColorValue),
+    WindowDragRegion(AzStyleWindowDragRegionValue),
+    CaretAnimationDuration(AzStyleCaretAnimationDurationValue),
+    ScrollPadding(AzStyleScrollPaddingValue),
+    Content(AzStyleContentValue),
+    CounterReset(AzStyleCounterResetValue),
+    CounterIncrement(AzStyleCounterIncrementValue),
+    OverflowAnchor(AzStyleOverflowAnchorValue),
     Opacity(AzStyleOpacityValue),
     Transform(AzStyleTransformVecValue),
     TransformOrigin(AzStyleTransformOriginValue),
     PerspectiveOrigin(AzStylePerspectiveOriginValue),
     BackfaceVisibility(AzStyleBackfaceVisibilityValue),
+    Perspective(AzStylePerspectiveValue),
+    TransformStyle(AzStyleTransformStyleValue),
     MixBlendMode(AzStyleMixBlendModeValue),
     Filter(AzStyleFilterVecValue),
     BackdropFilter(AzStyleFilterVecValue),
@@ -7499,6 +8562,10 @@ pub struct AzTextInputStateWrapper {
     pub update_text_input_before_calling_focus_lost_fn: bool,
     pub update_text_input_before_calling_vk_down_fn: bool,
     pub cursor_animation: AzOptionTimerIdEnumWrapper,
+    pub caret_animation: AzCaretAnimationEnumWrapper,
+    pub spellcheck_language: AzOptionStringEnumWrapper,
+    pub on_spelling_suggestions: AzOptionTextInputOnSpellingSuggestionsEnumWrapper,
+    pub secure: bool,
 }
 
 /// Re-export of rust-allocated (stack based) `ProgressBar` struct
@@ -7674,6 +8741,13 @@ pub enum AzOptionCssProperty {
     Some(AzCssProperty),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionFrameRecording` struct
+#[repr(C, u8)]
+pub enum AzOptionFrameRecording {
+    None,
+    Some(AzFrameRecording),
+}
+
 /// Re-export of rust-allocated (stack based) `XmlTextError` struct
 #[repr(C)]
 pub struct AzXmlTextError {
@@ -7681,6 +8755,23 @@ pub struct AzXmlTextError {
     pub pos: AzSvgParseErrorPosition,
 }
 
+/// Configuration for optional features, such as whether to enable logging or panic hooks
+#[repr(C)]
+pub struct AzAppConfig {
+    pub layout_solver: AzLayoutSolverEnumWrapper,
+    pub log_level: AzAppLogLevelEnumWrapper,
+    pub enable_visual_panic_hook: bool,
+    pub enable_logging_on_panic: bool,
+    pub enable_tab_navigation: bool,
+    pub system_callbacks: AzSystemCallbacks,
+    pub user_idle_timeout: AzOptionDurationEnumWrapper,
+    pub crash_handler: AzOptionCrashHandlerEnumWrapper,
+    pub jank_capture: AzOptionJankCaptureEnumWrapper,
+    pub event_recording: AzOptionEventRecordingEnumWrapper,
+    pub frame_recording: AzOptionFrameRecordingEnumWrapper,
+    pub hover_intent_delay: AzOptionDurationEnumWrapper,
+}
+
 /// Platform-specific window configuration, i.e. WM options that are not cross-platform
 #[repr(C)]
 pub struct AzPlatformSpecificOptions {
@@ -7707,6 +8798,7 @@ pub struct AzWindowState {
     pub platform_specific_options: AzPlatformSpecificOptions,
     pub renderer_options: AzRendererOptions,
     pub background_color: AzColorU,
+    pub opacity: f32,
     pub layout_callback: AzLayoutCallbackEnumWrapper,
     pub close_callback: AzOptionCallbackEnumWrapper,
 }
@@ -7729,13 +8821,22 @@ pub struct AzCallbackInfo {
     pub threads_removed: *mut c_void,
     pub current_window_handle: *const AzRawWindowHandleEnumWrapper,
     pub new_windows: *mut c_void,
+    pub shortcuts: *mut AzShortcutMap,
+    pub window_data: *mut c_void,
+    pub announcements: *mut c_void,
+    pub haptic_feedback: *mut c_void,
+    pub animation_frame_callbacks: *mut c_void,
+    pub crossfades: *mut c_void,
+    pub subtree_recordings: *mut c_void,
     pub system_callbacks: *const AzSystemCallbacks,
     pub stop_propagation: *mut bool,
     pub focus_target: *mut c_void,
     pub words_changed_in_callbacks: *mut c_void,
+    pub text_underlines_changed_in_callbacks: *mut c_void,
     pub images_changed_in_callbacks: *mut c_void,
     pub image_masks_changed_in_callbacks: *mut c_void,
     pub css_properties_changed_in_callbacks: *mut c_void,
+    pub ids_and_classes_changed_in_callbacks: *mut c_void,
     pub current_scroll_states: *const c_void,
     pub nodes_scrolled_in_callback: *mut c_void,
     pub hit_dom_node: AzDomNodeId,
@@ -7755,6 +8856,13 @@ pub struct AzInlineText {
     pub baseline_descender_px: f32,
 }
 
+/// Laid out text of a `Text` node, together with the rect that the text lines are positioned in
+#[repr(C)]
+pub struct AzTextLayout {
+    pub bounds: AzLogicalRect,
+    pub inline_text: AzInlineText,
+}
+
 /// CSS path to set the keyboard input focus
 #[repr(C)]
 pub struct AzFocusTargetPath {
@@ -7802,6 +8910,14 @@ pub struct AzDynamicCssProperty {
     pub default_value: AzCssPropertyEnumWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `SystemCssProperty` struct
+#[repr(C)]
+pub struct AzSystemCssProperty {
+    pub key: AzString,
+    pub value: AzString,
+    pub default_value: AzCssPropertyEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `Node` struct
 #[repr(C)]
 pub struct AzNode {
@@ -7853,6 +8969,13 @@ pub enum AzOptionInlineText {
     Some(AzInlineText),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionTextLayout` struct
+#[repr(C, u8)]
+pub enum AzOptionTextLayout {
+    None,
+    Some(AzTextLayout),
+}
+
 /// Re-export of rust-allocated (stack based) `XmlParseError` struct
 #[repr(C, u8)]
 pub enum AzXmlParseError {
@@ -7877,6 +9000,13 @@ pub struct AzWindowCreateOptions {
     pub theme: AzOptionWindowThemeEnumWrapper,
     pub create_callback: AzOptionCallbackEnumWrapper,
     pub hot_reload: bool,
+    pub shortcuts: AzShortcutMap,
+    pub parent_handle: AzOptionRawWindowHandleEnumWrapper,
+    pub window_data: AzOptionRefAnyEnumWrapper,
+    pub background_effect: AzWindowBackgroundEffectEnumWrapper,
+    pub fade_in: AzOptionDurationEnumWrapper,
+    pub first_frame: AzFirstFrameModeEnumWrapper,
+    pub scrollbars: AzScrollbarConfig,
 }
 
 /// Defines the keyboard input focus target
@@ -7888,6 +9018,7 @@ pub enum AzFocusTarget {
     Next,
     First,
     Last,
+    Direction(AzFocusDirection),
     NoFocus,
 }
 
@@ -7908,6 +9039,7 @@ pub struct AzNodeData {
 pub enum AzCssDeclaration {
     Static(AzCssProperty),
     Dynamic(AzDynamicCssProperty),
+    System(AzSystemCssProperty),
 }
 
 /// Re-export of rust-allocated (stack based) `Button` struct
@@ -7963,11 +9095,14 @@ pub struct AzTextInput {
     pub label_style: AzNodeDataInlineCssPropertyVec,
 }
 
-/// Re-export of rust-allocated (stack based) `NumberInput` struct
+/// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
 #[repr(C)]
-pub struct AzNumberInput {
-    pub text_input: AzTextInput,
-    pub state: AzNumberInputStateWrapper,
+pub struct AzNumberInputStateWrapper {
+    pub inner: AzNumberInputState,
+    pub on_value_change: AzOptionNumberInputOnValueChangeEnumWrapper,
+    pub on_focus_lost: AzOptionNumberInputOnFocusLostEnumWrapper,
+    pub invalid_style: AzNodeDataInlineCssPropertyVec,
+    pub valid_style: AzNodeDataInlineCssPropertyVec,
 }
 
 /// Re-export of rust-allocated (stack based) `NodeIdNodeMap` struct
@@ -7977,6 +9112,16 @@ pub struct AzNodeIdNodeMap {
     pub node: AzNode,
 }
 
+/// Manages the queue of toast / snackbar notifications of a window. Store it in the application data and insert `Toasts::dom()` as the last child of the root node
+#[repr(C)]
+pub struct AzToasts {
+    pub state: AzRefAny,
+    pub layer_style: AzNodeDataInlineCssPropertyVec,
+    pub toast_style: AzNodeDataInlineCssPropertyVec,
+    pub label_style: AzNodeDataInlineCssPropertyVec,
+    pub action_style: AzNodeDataInlineCssPropertyVec,
+}
+
 /// Wrapper over a Rust-allocated `Vec<NodeIdNodeMap>`
 #[repr(C)]
 pub struct AzNodeIdNodeMapVec {
@@ -8039,6 +9184,15 @@ pub struct AzDom {
 pub struct AzCssRuleBlock {
     pub path: AzCssPath,
     pub declarations: AzCssDeclarationVec,
+    pub media: AzCssMediaCondition,
+}
+
+/// Re-export of rust-allocated (stack based) `NumberInput` struct
+#[repr(C)]
+pub struct AzNumberInput {
+    pub text_input: AzTextInput,
+    pub state: AzNumberInputStateWrapper,
+    pub spinner_button_style: AzNodeDataInlineCssPropertyVec,
 }
 
 /// Re-export of rust-allocated (stack based) `TabContent` struct
@@ -8177,6 +9331,18 @@ pub struct AzLayoutSolverEnumWrapper {
     pub inner: AzLayoutSolver,
 }
 
+/// `AzWindowBackgroundEffectEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzWindowBackgroundEffectEnumWrapper {
+    pub inner: AzWindowBackgroundEffect,
+}
+
+/// `AzScrollbarModeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzScrollbarModeEnumWrapper {
+    pub inner: AzScrollbarMode,
+}
+
 /// `AzVsyncEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzVsyncEnumWrapper {
@@ -8207,12 +9373,30 @@ pub struct AzVirtualKeyCodeEnumWrapper {
     pub inner: AzVirtualKeyCode,
 }
 
+/// `AzPolitenessEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzPolitenessEnumWrapper {
+    pub inner: AzPoliteness,
+}
+
+/// `AzHapticKindEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzHapticKindEnumWrapper {
+    pub inner: AzHapticKind,
+}
+
 /// `AzWindowFrameEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzWindowFrameEnumWrapper {
     pub inner: AzWindowFrame,
 }
 
+/// `AzFullscreenModeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzFullscreenModeEnumWrapper {
+    pub inner: AzFullscreenMode,
+}
+
 /// `AzMouseCursorTypeEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzMouseCursorTypeEnumWrapper {
@@ -8237,6 +9421,12 @@ pub struct AzWindowThemeEnumWrapper {
     pub inner: AzWindowTheme,
 }
 
+/// `AzInputModalityEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzInputModalityEnumWrapper {
+    pub inner: AzInputModality,
+}
+
 /// `AzUpdateImageTypeEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzUpdateImageTypeEnumWrapper {
@@ -8249,6 +9439,18 @@ pub struct AzUpdateEnumWrapper {
     pub inner: AzUpdate,
 }
 
+/// `AzTextUnderlineStyleEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTextUnderlineStyleEnumWrapper {
+    pub inner: AzTextUnderlineStyle,
+}
+
+/// `AzCaretAnimationEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCaretAnimationEnumWrapper {
+    pub inner: AzCaretAnimation,
+}
+
 /// `AzAnimationRepeatEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzAnimationRepeatEnumWrapper {
@@ -8297,6 +9499,30 @@ pub struct AzApplicationEventFilterEnumWrapper {
     pub inner: AzApplicationEventFilter,
 }
 
+/// `AzFocusDirectionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzFocusDirectionEnumWrapper {
+    pub inner: AzFocusDirection,
+}
+
+/// `AzInputModeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzInputModeEnumWrapper {
+    pub inner: AzInputMode,
+}
+
+/// `AzNodeStateFlagEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzNodeStateFlagEnumWrapper {
+    pub inner: AzNodeStateFlag,
+}
+
+/// `AzOverlayPlacementEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOverlayPlacementEnumWrapper {
+    pub inner: AzOverlayPlacement,
+}
+
 /// `AzAccessibilityRoleEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzAccessibilityRoleEnumWrapper {
@@ -8465,10 +9691,22 @@ pub struct AzBorderStyleEnumWrapper {
     pub inner: AzBorderStyle,
 }
 
-/// `AzStyleCursorEnumWrapper` struct
+/// `AzStyleScrollbarWidthEnumWrapper` struct
 #[repr(transparent)]
-pub struct AzStyleCursorEnumWrapper {
-    pub inner: AzStyleCursor,
+pub struct AzStyleScrollbarWidthEnumWrapper {
+    pub inner: AzStyleScrollbarWidth,
+}
+
+/// `AzStyleOverflowAnchorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleOverflowAnchorEnumWrapper {
+    pub inner: AzStyleOverflowAnchor,
+}
+
+/// `AzStyleWindowDragRegionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleWindowDragRegionEnumWrapper {
+    pub inner: AzStyleWindowDragRegion,
 }
 
 /// `AzStyleBackfaceVisibilityEnumWrapper` struct
@@ -8477,18 +9715,36 @@ pub struct AzStyleBackfaceVisibilityEnumWrapper {
     pub inner: AzStyleBackfaceVisibility,
 }
 
+/// `AzStyleTransformStyleEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTransformStyleEnumWrapper {
+    pub inner: AzStyleTransformStyle,
+}
+
 /// `AzStyleTextAlignEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleTextAlignEnumWrapper {
     pub inner: AzStyleTextAlign,
 }
 
+/// `AzStyleHyphensEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleHyphensEnumWrapper {
+    pub inner: AzStyleHyphens,
+}
+
 /// `AzTextInputValidEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzTextInputValidEnumWrapper {
     pub inner: AzTextInputValid,
 }
 
+/// `AzNumberInputModeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzNumberInputModeEnumWrapper {
+    pub inner: AzNumberInputMode,
+}
+
 /// `AzNodeGraphStyleEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzNodeGraphStyleEnumWrapper {
@@ -8735,12 +9991,24 @@ pub struct AzInlineWordVecDestructorEnumWrapper {
     pub inner: AzInlineWordVecDestructor,
 }
 
+/// `AzPaintPrimitiveVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzPaintPrimitiveVecDestructorEnumWrapper {
+    pub inner: AzPaintPrimitiveVecDestructor,
+}
+
 /// `AzInlineGlyphVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzInlineGlyphVecDestructorEnumWrapper {
     pub inner: AzInlineGlyphVecDestructor,
 }
 
+/// `AzTextUnderlineVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTextUnderlineVecDestructorEnumWrapper {
+    pub inner: AzTextUnderlineVecDestructor,
+}
+
 /// `AzInlineTextHitVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzInlineTextHitVecDestructorEnumWrapper {
@@ -8753,6 +10021,12 @@ pub struct AzMonitorVecDestructorEnumWrapper {
     pub inner: AzMonitorVecDestructor,
 }
 
+/// `AzTouchPointVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTouchPointVecDestructorEnumWrapper {
+    pub inner: AzTouchPointVecDestructor,
+}
+
 /// `AzVideoModeVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzVideoModeVecDestructorEnumWrapper {
@@ -8873,6 +10147,18 @@ pub struct AzVirtualKeyCodeVecDestructorEnumWrapper {
     pub inner: AzVirtualKeyCodeVecDestructor,
 }
 
+/// `AzShortcutConflictVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzShortcutConflictVecDestructorEnumWrapper {
+    pub inner: AzShortcutConflictVecDestructor,
+}
+
+/// `AzShortcutVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzShortcutVecDestructorEnumWrapper {
+    pub inner: AzShortcutVecDestructor,
+}
+
 /// `AzCascadeInfoVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzCascadeInfoVecDestructorEnumWrapper {
@@ -9065,6 +10351,12 @@ pub struct AzOptionUsizeEnumWrapper {
     pub inner: AzOptionUsize,
 }
 
+/// `AzFirstFrameModeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzFirstFrameModeEnumWrapper {
+    pub inner: AzFirstFrameMode,
+}
+
 /// `AzRawWindowHandleEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzRawWindowHandleEnumWrapper {
@@ -9335,6 +10627,54 @@ pub struct AzLayoutOverflowValueEnumWrapper {
     pub inner: AzLayoutOverflowValue,
 }
 
+/// `AzStyleScrollbarWidthValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleScrollbarWidthValueEnumWrapper {
+    pub inner: AzStyleScrollbarWidthValue,
+}
+
+/// `AzStyleScrollbarColorValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleScrollbarColorValueEnumWrapper {
+    pub inner: AzStyleScrollbarColorValue,
+}
+
+/// `AzStyleScrollbarThumbHoverColorValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleScrollbarThumbHoverColorValueEnumWrapper {
+    pub inner: AzStyleScrollbarThumbHoverColorValue,
+}
+
+/// `AzStyleScrollbarThumbActiveColorValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleScrollbarThumbActiveColorValueEnumWrapper {
+    pub inner: AzStyleScrollbarThumbActiveColorValue,
+}
+
+/// `AzStyleWindowDragRegionValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleWindowDragRegionValueEnumWrapper {
+    pub inner: AzStyleWindowDragRegionValue,
+}
+
+/// `AzStyleCaretAnimationDurationValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleCaretAnimationDurationValueEnumWrapper {
+    pub inner: AzStyleCaretAnimationDurationValue,
+}
+
+/// `AzStyleScrollPaddingValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleScrollPaddingValueEnumWrapper {
+    pub inner: AzStyleScrollPaddingValue,
+}
+
+/// `AzStyleOverflowAnchorValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleOverflowAnchorValueEnumWrapper {
+    pub inner: AzStyleOverflowAnchorValue,
+}
+
 /// `AzStyleBorderBottomColorValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleBorderBottomColorValueEnumWrapper {
@@ -9431,12 +10771,6 @@ pub struct AzLayoutBorderTopWidthValueEnumWrapper {
     pub inner: AzLayoutBorderTopWidthValue,
 }
 
-/// `AzStyleCursorValueEnumWrapper` struct
-#[repr(transparent)]
-pub struct AzStyleCursorValueEnumWrapper {
-    pub inner: AzStyleCursorValue,
-}
-
 /// `AzStyleFontSizeValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleFontSizeValueEnumWrapper {
@@ -9461,6 +10795,12 @@ pub struct AzStyleTabWidthValueEnumWrapper {
     pub inner: AzStyleTabWidthValue,
 }
 
+/// `AzStyleHyphensValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleHyphensValueEnumWrapper {
+    pub inner: AzStyleHyphensValue,
+}
+
 /// `AzStyleTextAlignValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleTextAlignValueEnumWrapper {
@@ -9503,6 +10843,18 @@ pub struct AzStyleBackfaceVisibilityValueEnumWrapper {
     pub inner: AzStyleBackfaceVisibilityValue,
 }
 
+/// `AzStylePerspectiveValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStylePerspectiveValueEnumWrapper {
+    pub inner: AzStylePerspectiveValue,
+}
+
+/// `AzStyleTransformStyleValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTransformStyleValueEnumWrapper {
+    pub inner: AzStyleTransformStyleValue,
+}
+
 /// `AzStyleMixBlendModeValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleMixBlendModeValueEnumWrapper {
@@ -9563,6 +10915,12 @@ pub struct AzOptionDropDownOnChoiceChangeEnumWrapper {
     pub inner: AzOptionDropDownOnChoiceChange,
 }
 
+/// `AzOptionToastOnActionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionToastOnActionEnumWrapper {
+    pub inner: AzOptionToastOnAction,
+}
+
 /// `AzOptionNodeGraphOnNodeAddedEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionNodeGraphOnNodeAddedEnumWrapper {
@@ -9659,6 +11017,12 @@ pub struct AzOptionTextInputOnFocusLostEnumWrapper {
     pub inner: AzOptionTextInputOnFocusLost,
 }
 
+/// `AzOptionTextInputOnSpellingSuggestionsEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTextInputOnSpellingSuggestionsEnumWrapper {
+    pub inner: AzOptionTextInputOnSpellingSuggestions,
+}
+
 /// `AzOptionTextInputSelectionEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionTextInputSelectionEnumWrapper {
@@ -9695,6 +11059,18 @@ pub struct AzOptionPositionInfoEnumWrapper {
     pub inner: AzOptionPositionInfo,
 }
 
+/// `AzOptionNodeRectEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionNodeRectEnumWrapper {
+    pub inner: AzOptionNodeRect,
+}
+
+/// `AzOptionScrollInfoEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionScrollInfoEnumWrapper {
+    pub inner: AzOptionScrollInfo,
+}
+
 /// `AzOptionTimerIdEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionTimerIdEnumWrapper {
@@ -9755,6 +11131,18 @@ pub struct AzOptionCallbackEnumWrapper {
     pub inner: AzOptionCallback,
 }
 
+/// `AzOptionPaintCallbackEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionPaintCallbackEnumWrapper {
+    pub inner: AzOptionPaintCallback,
+}
+
+/// `AzOptionCrashHandlerEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionCrashHandlerEnumWrapper {
+    pub inner: AzOptionCrashHandler,
+}
+
 /// `AzOptionThreadSendMsgEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionThreadSendMsgEnumWrapper {
@@ -9815,6 +11203,12 @@ pub struct AzOptionSvgDashPatternEnumWrapper {
     pub inner: AzOptionSvgDashPattern,
 }
 
+/// `AzOptionRawWindowHandleEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionRawWindowHandleEnumWrapper {
+    pub inner: AzOptionRawWindowHandle,
+}
+
 /// `AzOptionLogicalPositionEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionLogicalPositionEnumWrapper {
@@ -9899,6 +11293,12 @@ pub struct AzAnimationEasingEnumWrapper {
     pub inner: AzAnimationEasing,
 }
 
+/// `AzPaintPrimitiveEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzPaintPrimitiveEnumWrapper {
+    pub inner: AzPaintPrimitive,
+}
+
 /// `AzEventFilterEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzEventFilterEnumWrapper {
@@ -9989,6 +11389,12 @@ pub struct AzOptionVirtualKeyCodeComboEnumWrapper {
     pub inner: AzOptionVirtualKeyCodeCombo,
 }
 
+/// `AzOptionShortcutConflictEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionShortcutConflictEnumWrapper {
+    pub inner: AzOptionShortcutConflict,
+}
+
 /// `AzOptionMouseStateEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionMouseStateEnumWrapper {
@@ -10043,6 +11449,18 @@ pub struct AzOptionInstantEnumWrapper {
     pub inner: AzOptionInstant,
 }
 
+/// `AzEventRecordingEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzEventRecordingEnumWrapper {
+    pub inner: AzEventRecording,
+}
+
+/// `AzFrameRecordingOutputEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzFrameRecordingOutputEnumWrapper {
+    pub inner: AzFrameRecordingOutput,
+}
+
 /// `AzLayoutCallbackEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzLayoutCallbackEnumWrapper {
@@ -10079,6 +11497,12 @@ pub struct AzStyleBackgroundContentEnumWrapper {
     pub inner: AzStyleBackgroundContent,
 }
 
+/// `AzStyleContentEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleContentEnumWrapper {
+    pub inner: AzStyleContent,
+}
+
 /// `AzStyleFontFamilyEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleFontFamilyEnumWrapper {
@@ -10091,6 +11515,24 @@ pub struct AzScrollbarStyleValueEnumWrapper {
     pub inner: AzScrollbarStyleValue,
 }
 
+/// `AzStyleContentValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleContentValueEnumWrapper {
+    pub inner: AzStyleContentValue,
+}
+
+/// `AzStyleCounterResetValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleCounterResetValueEnumWrapper {
+    pub inner: AzStyleCounterResetValue,
+}
+
+/// `AzStyleCounterIncrementValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleCounterIncrementValueEnumWrapper {
+    pub inner: AzStyleCounterIncrementValue,
+}
+
 /// `AzStyleTransformVecValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleTransformVecValueEnumWrapper {
@@ -10133,6 +11575,18 @@ pub struct AzOptionFileEnumWrapper {
     pub inner: AzOptionFile,
 }
 
+/// `AzOptionJankCaptureEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionJankCaptureEnumWrapper {
+    pub inner: AzOptionJankCapture,
+}
+
+/// `AzOptionEventRecordingEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionEventRecordingEnumWrapper {
+    pub inner: AzOptionEventRecording,
+}
+
 /// `AzOptionRawImageEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionRawImageEnumWrapper {
@@ -10163,12 +11617,24 @@ pub struct AzMenuItemEnumWrapper {
     pub inner: AzMenuItem,
 }
 
+/// `AzStyleCursorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleCursorEnumWrapper {
+    pub inner: AzStyleCursor,
+}
+
 /// `AzStyleBackgroundContentVecValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleBackgroundContentVecValueEnumWrapper {
     pub inner: AzStyleBackgroundContentVecValue,
 }
 
+/// `AzStyleCursorValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleCursorValueEnumWrapper {
+    pub inner: AzStyleCursorValue,
+}
+
 /// `AzStyleFontFamilyVecValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleFontFamilyVecValueEnumWrapper {
@@ -10199,6 +11665,12 @@ pub struct AzOptionCssPropertyEnumWrapper {
     pub inner: AzOptionCssProperty,
 }
 
+/// `AzOptionFrameRecordingEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionFrameRecordingEnumWrapper {
+    pub inner: AzOptionFrameRecording,
+}
+
 /// `AzNodeDataInlineCssPropertyEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzNodeDataInlineCssPropertyEnumWrapper {
@@ -10223,6 +11695,12 @@ pub struct AzOptionInlineTextEnumWrapper {
     pub inner: AzOptionInlineText,
 }
 
+/// `AzOptionTextLayoutEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTextLayoutEnumWrapper {
+    pub inner: AzOptionTextLayout,
+}
+
 /// `AzXmlParseErrorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzXmlParseErrorEnumWrapper {
@@ -10311,6 +11789,7 @@ unsafe impl Send for AzSystemClipboard { }
 unsafe impl Send for AzThread { }
 unsafe impl Send for AzThreadSender { }
 unsafe impl Send for AzThreadReceiver { }
+unsafe impl Send for AzEventLoopProxy { }
 unsafe impl Send for AzOptionHwndHandle { }
 unsafe impl Send for AzOptionX11Visual { }
 unsafe impl Send for AzIFrameCallbackInfo { }
@@ -10331,7 +11810,9 @@ unsafe impl Send for AzAccessibilityStateVec { }
 unsafe impl Send for AzMenuItemVec { }
 unsafe impl Send for AzXmlNodeVec { }
 unsafe impl Send for AzInlineGlyphVec { }
+unsafe impl Send for AzTextUnderlineVec { }
 unsafe impl Send for AzInlineTextHitVec { }
+unsafe impl Send for AzTouchPointVec { }
 unsafe impl Send for AzVideoModeVec { }
 unsafe impl Send for AzDomVec { }
 unsafe impl Send for AzStyleBackgroundPositionVec { }
@@ -10365,8 +11846,11 @@ unsafe impl Send for AzInputConnectionVec { }
 unsafe impl Send for AzOutputConnectionVec { }
 unsafe impl Send for AzTessellatedSvgNodeVec { }
 unsafe impl Send for AzTessellatedColoredSvgNodeVec { }
+unsafe impl Send for AzPaintPrimitiveVec { }
 unsafe impl Send for AzStyleTransformVec { }
 unsafe impl Send for AzSvgPathElementVec { }
+unsafe impl Send for AzShortcutConflictVec { }
+unsafe impl Send for AzShortcutVec { }
 unsafe impl Send for AzStringVec { }
 unsafe impl Send for AzStyledNodeVec { }
 unsafe impl Send for AzTagIdToNodeIdMappingVec { }
@@ -10407,7 +11891,11 @@ unsafe impl Send for AzStylesheetVec { }
 // Python objects must implement Clone at minimum
 impl Clone for AzApp { fn clone(&self) -> Self { let r: &azul_impl::app::AzAppPtr = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAppLogLevelEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::AppLogLevel = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzFrameRecordingCallback { fn clone(&self) -> Self { let r: &azul_impl::resources::FrameRecordingCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCrashHandler { fn clone(&self) -> Self { let r: &azul_impl::resources::CrashHandler = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutSolverEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::LayoutSolverVersion = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzWindowBackgroundEffectEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowBackgroundEffect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzScrollbarModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ScrollbarMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVsyncEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::Vsync = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSrgbEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::Srgb = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzHwAccelerationEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::HwAcceleration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10428,15 +11916,20 @@ impl Clone for AzLogicalPosition { fn clone(&self) -> Self { let r: &azul_core::
 impl Clone for AzLogicalSize { fn clone(&self) -> Self { let r: &azul_core::window::LogicalSize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIconKey { fn clone(&self) -> Self { let r: &azul_core::window::IconKey = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVirtualKeyCodeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzPolitenessEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::Politeness = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzHapticKindEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::HapticKind = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowFrameEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowFrame = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzFullscreenModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::FullscreenMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDebugState { fn clone(&self) -> Self { let r: &azul_core::window::DebugState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzKeyboardModifiers { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardModifiers = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMouseCursorTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MouseCursorType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRendererTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::RendererType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMacWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::MacWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWasmWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::WasmWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFullScreenModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::FullScreenMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowThemeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowTheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzTouchState { fn clone(&self) -> Self { let r: &azul_core::window::TouchState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzInputModalityEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::InputModality = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzHdrCapabilities { fn clone(&self) -> Self { let r: &azul_core::window::HdrCapabilities = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMarshaledLayoutCallbackInner { fn clone(&self) -> Self { let r: &azul_impl::callbacks::MarshaledLayoutCallbackInner = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutCallbackInner { fn clone(&self) -> Self { let r: &azul_impl::callbacks::LayoutCallbackInner = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::Callback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10445,9 +11938,14 @@ impl Clone for AzUpdateEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl
 impl Clone for AzNodeId { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::NodeHierarchyItemId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDomId { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::DomId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPositionInfoInner { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::PositionInfoInner = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextUnderlineStyleEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TextUnderlineStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCaretAnimationEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::CaretAnimation = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAnimationRepeatEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::AnimationRepeat = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAnimationRepeatCountEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::AnimationRepeatCount = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIFrameCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::IFrameCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzMeasureCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::MeasureCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzArrangeCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::ArrangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzPaintCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::PaintCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRenderImageCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::RenderImageCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTimerCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TimerCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWriteBackCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::WriteBackCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10459,12 +11957,17 @@ impl Clone for AzFocusEventFilterEnumWrapper { fn clone(&self) -> Self { let r:
 impl Clone for AzWindowEventFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::WindowEventFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzComponentEventFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::ComponentEventFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzApplicationEventFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::ApplicationEventFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzFocusDirectionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::FocusDirection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzInputModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::InputMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeStateFlagEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeStateFlag = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOverlayPlacementEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::OverlayPlacement = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAccessibilityRoleEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityRole = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAccessibilityStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTabIndexEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::TabIndex = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzContextMenuMouseButtonEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ContextMenuMouseButton = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuPopupPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuPopupPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaCondition { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaCondition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeKeyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::NodeTypeTag = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssNthChildPattern { fn clone(&self) -> Self { let r: &azul_impl::css::CssNthChildPattern = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertyTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPropertyType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10490,9 +11993,14 @@ impl Clone for AzShapeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl:
 impl Clone for AzRadialGradientSizeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::RadialGradientSize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundRepeatEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundRepeat = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzBorderStyleEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::BorderStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzStyleCursorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollbarWidthEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollbarWidth = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleOverflowAnchorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleOverflowAnchor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleWindowDragRegionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleWindowDragRegion = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCaretAnimationDuration { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCaretAnimationDuration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackfaceVisibilityEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackfaceVisibility = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransformStyleEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextAlignEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextAlign = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleHyphensEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleHyphens = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRibbon { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::Ribbon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRibbonOnTabClickedCallback { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonOnTabClickedCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFileInputOnPathChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::file_input::FileInputOnPathChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10503,8 +12011,9 @@ impl Clone for AzTextInputSelectionRange { fn clone(&self) -> Self { let r: &cra
 impl Clone for AzTextInputOnTextInputCallback { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnTextInputCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputOnVirtualKeyDownCallback { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnVirtualKeyDownCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputOnFocusLostCallback { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnFocusLostCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextInputOnSpellingSuggestionsCallback { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnSpellingSuggestionsCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputValidEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputValid = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzNumberInputState { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNumberInputModeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputOnValueChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputOnValueChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputOnFocusLostCallback { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputOnFocusLostCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzProgressBarState { fn clone(&self) -> Self { let r: &crate::widgets::progressbar::ProgressBarState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10529,6 +12038,8 @@ impl Clone for AzListViewOnLazyLoadScrollCallback { fn clone(&self) -> Self { le
 impl Clone for AzListViewOnColumnClickCallback { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnColumnClickCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewOnRowClickCallback { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnRowClickCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDropDownOnChoiceChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownOnChoiceChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzToastId { fn clone(&self) -> Self { let r: &crate::widgets::toasts::ToastId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzToastOnActionCallback { fn clone(&self) -> Self { let r: &crate::widgets::toasts::ToastOnActionCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeHierarchyItem { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::NodeHierarchyItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCascadeInfo { fn clone(&self) -> Self { let r: &azul_impl::style::CascadeInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyledNodeState { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::StyledNodeState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10536,6 +12047,7 @@ impl Clone for AzTagId { fn clone(&self) -> Self { let r: &azul_impl::styled_dom
 impl Clone for AzCssPropertyCache { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::CssPropertyCachePtr = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzGlVoidPtrConst { fn clone(&self) -> Self { let r: &azul_impl::gl::GlVoidPtrConst = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzGlVoidPtrMut { fn clone(&self) -> Self { let r: &azul_impl::gl::GlVoidPtrMut = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGlQuirks { fn clone(&self) -> Self { let r: &azul_impl::gl::GlQuirks = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzGlShaderPrecisionFormatReturn { fn clone(&self) -> Self { let r: &azul_impl::gl::GlShaderPrecisionFormatReturn = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexAttributeTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexAttributeType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIndexBufferFormatEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::gl::IndexBufferFormat = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10596,8 +12108,13 @@ impl Clone for AzThreadId { fn clone(&self) -> Self { let r: &azul_impl::task::T
 impl Clone for AzThread { fn clone(&self) -> Self { let r: &azul_impl::task::Thread = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadSender { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadSender = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadReceiver { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadReceiver = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzEventLoopProxy { fn clone(&self) -> Self { let r: &azul_impl::task::EventLoopProxy = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCreateThreadFn { fn clone(&self) -> Self { let r: &azul_impl::task::CreateThreadCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzGetSystemTimeFn { fn clone(&self) -> Self { let r: &azul_impl::task::GetSystemTimeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGetSystemIdleTimeFn { fn clone(&self) -> Self { let r: &azul_impl::task::GetSystemIdleTimeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGetLogLinesFn { fn clone(&self) -> Self { let r: &azul_impl::task::GetLogLinesCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzKeycodeToCharFn { fn clone(&self) -> Self { let r: &azul_impl::task::KeycodeToCharCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSetLogLevelFn { fn clone(&self) -> Self { let r: &azul_impl::task::SetLogLevelCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckThreadFinishedFn { fn clone(&self) -> Self { let r: &azul_impl::task::CheckThreadFinishedCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLibrarySendThreadMsgFn { fn clone(&self) -> Self { let r: &azul_impl::task::LibrarySendThreadMsgCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLibraryReceiveThreadMsgFn { fn clone(&self) -> Self { let r: &azul_impl::task::LibraryReceiveThreadMsgCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10627,9 +12144,12 @@ impl Clone for AzXmlNodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let
 impl Clone for AzFmtArgVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::str::FmtArgVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineLineVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineLineVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineWordVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineWordVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzPaintPrimitiveVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::PaintPrimitiveVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineGlyphVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineGlyphVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextUnderlineVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TextUnderlineVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextHitVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineTextHitVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMonitorVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MonitorVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTouchPointVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::TouchPointVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVideoModeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::VideoModeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDomVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::DomVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIdOrClassVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::IdOrClassVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10650,6 +12170,8 @@ impl Clone for AzSvgColoredVertexVecDestructorEnumWrapper { fn clone(&self) -> S
 impl Clone for AzU32VecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::U32VecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXWindowTypeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::XWindowTypeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVirtualKeyCodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzShortcutConflictVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ShortcutConflictVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzShortcutVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ShortcutVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCascadeInfoVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::style::CascadeInfoVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScanCodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ScanCodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssDeclarationVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssDeclarationVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10684,6 +12206,7 @@ impl Clone for AzOptionCharEnumWrapper { fn clone(&self) -> Self { let r: &azul_
 impl Clone for AzOptionUsizeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::gl::OptionUsize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgParseErrorPosition { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlTextPos = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSystemCallbacks { fn clone(&self) -> Self { let r: &azul_impl::task::ExternalSystemCallbacks = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzFirstFrameModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::FirstFrameMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRendererOptions { fn clone(&self) -> Self { let r: &azul_core::window::RendererOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutRect { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutRect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRawWindowHandleEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::RawWindowHandle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10693,13 +12216,21 @@ impl Clone for AzWindowFlags { fn clone(&self) -> Self { let r: &azul_core::wind
 impl Clone for AzCursorPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::CursorPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzImePositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ImePosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTouchPoint { fn clone(&self) -> Self { let r: &azul_core::window::TouchPoint = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVideoMode { fn clone(&self) -> Self { let r: &azul_core::window::VideoMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDomNodeId { fn clone(&self) -> Self { let r: &azul_impl::callbacks::DomNodeId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPositionInfoEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::PositionInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNodeRect { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::NodeRect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzScrollInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::ScrollInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzHidpiAdjustedBounds { fn clone(&self) -> Self { let r: &azul_impl::callbacks::HidpiAdjustedBounds = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineGlyph { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineGlyph = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextUnderline { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TextUnderline = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextHit { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineTextHit = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIFrameCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::IFrameCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzMeasureCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::MeasureCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzArrangeCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::ArrangeCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzPaintCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::PaintCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzPaintRect { fn clone(&self) -> Self { let r: &azul_impl::callbacks::PaintRect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTimerCallbackReturn { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TimerCallbackReturn = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRefAny { fn clone(&self) -> Self { let r: &azul_impl::callbacks::RefAny = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIFrameNode { fn clone(&self) -> Self { let r: &azul_impl::dom::IFrameNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10707,6 +12238,8 @@ impl Clone for AzNotEventFilterEnumWrapper { fn clone(&self) -> Self { let r: &a
 impl Clone for AzMenuCallback { fn clone(&self) -> Self { let r: &azul_core::window::MenuCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssNthChildSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssNthChildSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSystemColors { fn clone(&self) -> Self { let r: &azul_impl::css::SystemColors = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSystemStyle { fn clone(&self) -> Self { let r: &azul_impl::css::SystemStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPixelValue { fn clone(&self) -> Self { let r: &azul_impl::css::PixelValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPixelValueNoPercent { fn clone(&self) -> Self { let r: &azul_impl::css::PixelValueNoPercent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBoxShadow { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBoxShadow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10760,6 +12293,10 @@ impl Clone for AzStyleBorderTopLeftRadius { fn clone(&self) -> Self { let r: &az
 impl Clone for AzStyleBorderTopRightRadius { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBorderTopRightRadius = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBorderTopStyle { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBorderTopStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBorderTopWidth { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBorderTopWidth = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollbarColor { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollbarColor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollbarThumbHoverColor { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollbarThumbHoverColor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollbarThumbActiveColor { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollbarThumbActiveColor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollPadding { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollPadding = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontSize { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontSize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleLetterSpacing { fn clone(&self) -> Self { let r: &azul_impl::css::StyleLetterSpacing = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleLineHeight { fn clone(&self) -> Self { let r: &azul_impl::css::StyleLineHeight = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10767,6 +12304,7 @@ impl Clone for AzStyleTabWidth { fn clone(&self) -> Self { let r: &azul_impl::cs
 impl Clone for AzStyleOpacity { fn clone(&self) -> Self { let r: &azul_impl::css::StyleOpacity = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformOrigin { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformOrigin = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStylePerspectiveOrigin { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformOrigin = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStylePerspective { fn clone(&self) -> Self { let r: &azul_impl::css::StylePerspective = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformMatrix2D { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformMatrix2D = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformMatrix3D { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformMatrix3D = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformTranslate2D { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformTranslate2D = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10808,6 +12346,14 @@ impl Clone for AzLayoutTopValueEnumWrapper { fn clone(&self) -> Self { let r: &a
 impl Clone for AzLayoutWidthValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutWidthValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFlexWrapValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexWrapValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutOverflowValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutOverflowValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollbarWidthValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollbarWidthValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollbarColorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollbarColorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollbarThumbHoverColorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollbarThumbHoverColorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollbarThumbActiveColorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollbarThumbActiveColorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleWindowDragRegionValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleWindowDragRegionValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCaretAnimationDurationValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCaretAnimationDurationValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleScrollPaddingValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleScrollPaddingValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleOverflowAnchorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleOverflowAnchorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBorderBottomColorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBorderBottomColorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBorderBottomLeftRadiusValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBorderBottomLeftRadiusValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBorderBottomRightRadiusValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBorderBottomRightRadiusValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10824,11 +12370,11 @@ impl Clone for AzStyleBorderTopLeftRadiusValueEnumWrapper { fn clone(&self) -> S
 impl Clone for AzStyleBorderTopRightRadiusValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBorderTopRightRadiusValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBorderTopStyleValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBorderTopStyleValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBorderTopWidthValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBorderTopWidthValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzStyleCursorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontSizeValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontSizeValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleLetterSpacingValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleLetterSpacingValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleLineHeightValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleLineHeightValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTabWidthValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTabWidthValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleHyphensValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleHyphensValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextAlignValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextAlignValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextColorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextColorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleWordSpacingValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleWordSpacingValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10836,6 +12382,8 @@ impl Clone for AzStyleOpacityValueEnumWrapper { fn clone(&self) -> Self { let r:
 impl Clone for AzStyleTransformOriginValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformOriginValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStylePerspectiveOriginValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StylePerspectiveOriginValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackfaceVisibilityValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackfaceVisibilityValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStylePerspectiveValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StylePerspectiveValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransformStyleValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformStyleValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleMixBlendModeValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleMixBlendModeValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzButtonOnClick { fn clone(&self) -> Self { let r: &crate::widgets::button::ButtonOnClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFileInputOnPathChange { fn clone(&self) -> Self { let r: &crate::widgets::file_input::FileInputOnPathChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10846,7 +12394,9 @@ impl Clone for AzTextInputSelectionEnumWrapper { fn clone(&self) -> Self { let r
 impl Clone for AzTextInputOnTextInput { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnTextInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputOnVirtualKeyDown { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnVirtualKeyDown = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputOnFocusLost { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnFocusLost = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextInputOnSpellingSuggestions { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnSpellingSuggestions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOnTextInputReturn { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OnTextInputReturn = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNumberInputState { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputOnValueChange { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputOnFocusLost { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputOnFocusLost = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTabOnClick { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabOnClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10864,6 +12414,7 @@ impl Clone for AzListViewOnLazyLoadScroll { fn clone(&self) -> Self { let r: &cr
 impl Clone for AzListViewOnColumnClick { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnColumnClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewOnRowClick { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnRowClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDropDownOnChoiceChange { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownOnChoiceChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzToastOnAction { fn clone(&self) -> Self { let r: &crate::widgets::toasts::ToastOnAction = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzParentWithNodeDepth { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::ParentWithNodeDepth = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzGl { fn clone(&self) -> Self { let r: &azul_impl::gl::GlContextPtr = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRefstrVecRef { fn clone(&self) -> Self { let r: &azul_impl::gl::RefstrVecRef = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10886,7 +12437,9 @@ impl Clone for AzAccessibilityStateVec { fn clone(&self) -> Self { let r: &azul_
 impl Clone for AzMenuItemVec { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlNodeVec { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineGlyphVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineGlyphVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextUnderlineVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TextUnderlineVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextHitVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineTextHitVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTouchPointVec { fn clone(&self) -> Self { let r: &azul_core::window::TouchPointVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVideoModeVec { fn clone(&self) -> Self { let r: &azul_core::window::VideoModeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDomVec { fn clone(&self) -> Self { let r: &azul_impl::dom::DomVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundPositionVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundPositionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10915,6 +12468,7 @@ impl Clone for AzOptionListViewOnColumnClickEnumWrapper { fn clone(&self) -> Sel
 impl Clone for AzOptionListViewOnLazyLoadScrollEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::OptionListViewOnLazyLoadScroll = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionPixelValueNoPercentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionPixelValueNoPercent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionDropDownOnChoiceChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::OptionDropDownOnChoiceChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionToastOnActionEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::toasts::OptionToastOnAction = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionNodeGraphOnNodeAddedEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OptionOnNodeAdded = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionNodeGraphOnNodeRemovedEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OptionOnNodeRemoved = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionNodeGraphOnNodeGraphDraggedEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OptionOnNodeGraphDragged = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10931,12 +12485,15 @@ impl Clone for AzOptionCheckBoxOnToggleEnumWrapper { fn clone(&self) -> Self { l
 impl Clone for AzOptionTextInputOnTextInputEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputOnTextInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTextInputOnVirtualKeyDownEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputOnVirtualKeyDown = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTextInputOnFocusLostEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputOnFocusLost = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTextInputOnSpellingSuggestionsEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputOnSpellingSuggestions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTextInputSelectionEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputSelection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionNumberInputOnFocusLostEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::number_input::OptionNumberInputOnFocusLost = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionNumberInputOnValueChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::number_input::OptionNumberInputOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMenuItemIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMenuItemIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMenuCallbackEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMenuCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionPositionInfoEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::OptionPositionInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionNodeRectEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::OptionNodeRect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionScrollInfoEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionScrollInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTimerIdEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::OptionTimerId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionThreadIdEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::OptionThreadId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionImageRefEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::OptionImageRef = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10947,6 +12504,8 @@ impl Clone for AzOptionPercentageValueEnumWrapper { fn clone(&self) -> Self { le
 impl Clone for AzOptionAngleValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionAngleValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionRendererOptionsEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionRendererOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionCallbackEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionPaintCallbackEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionPaintCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionCrashHandlerEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::OptionCrashHandler = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionThreadSendMsgEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::OptionThreadSendMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionLayoutRectEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionLayoutRect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionRefAnyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionRefAny = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10957,6 +12516,7 @@ impl Clone for AzOptionNodeIdEnumWrapper { fn clone(&self) -> Self { let r: &azu
 impl Clone for AzOptionDomNodeIdEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionDomNodeId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionColorUEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionColorU = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionSvgDashPatternEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::OptionSvgDashPattern = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionRawWindowHandleEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionRawWindowHandle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionLogicalPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionLogicalPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionPhysicalPositionI32EnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionPhysicalPositionI32 = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMouseCursorTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMouseCursorType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10974,7 +12534,9 @@ impl Clone for AzInvalidCharError { fn clone(&self) -> Self { let r: &azul_impl:
 impl Clone for AzInvalidCharMultipleError { fn clone(&self) -> Self { let r: &azul_impl::xml::InvalidCharMultipleError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInvalidQuoteError { fn clone(&self) -> Self { let r: &azul_impl::xml::InvalidQuoteError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInvalidSpaceError { fn clone(&self) -> Self { let r: &azul_impl::xml::InvalidSpaceError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzAppConfig { fn clone(&self) -> Self { let r: &azul_impl::resources::AppConfig = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplashOptions { fn clone(&self) -> Self { let r: &azul_impl::resources::SplashOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRecordedFrame { fn clone(&self) -> Self { let r: &azul_impl::resources::RecordedFrame = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzScrollbarConfig { fn clone(&self) -> Self { let r: &azul_core::window::ScrollbarConfig = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSmallWindowIconBytes { fn clone(&self) -> Self { let r: &azul_core::window::SmallWindowIconBytes = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLargeWindowIconBytes { fn clone(&self) -> Self { let r: &azul_core::window::LargeWindowIconBytes = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10982,15 +12544,20 @@ impl Clone for AzTaskBarIcon { fn clone(&self) -> Self { let r: &azul_core::wind
 impl Clone for AzWindowSize { fn clone(&self) -> Self { let r: &azul_core::window::WindowSize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzKeyboardState { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMouseState { fn clone(&self) -> Self { let r: &azul_core::window::MouseState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTouchState { fn clone(&self) -> Self { let r: &azul_core::window::TouchState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMarshaledLayoutCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::MarshaledLayoutCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextContents { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineTextContents = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResolvedTextLayoutOptions { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::ResolvedTextLayoutOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAnimationEasingEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::AnimationInterpolationFunction = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzPaintPrimitiveEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::PaintPrimitive = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRenderImageCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::RenderImageCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::LayoutCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCustomLayoutNode { fn clone(&self) -> Self { let r: &azul_impl::dom::CustomLayoutNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzEventFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::EventFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenu { fn clone(&self) -> Self { let r: &azul_core::window::Menu = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVirtualKeyCodeCombo { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCodeCombo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzShortcut { fn clone(&self) -> Self { let r: &azul_core::window::Shortcut = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzShortcutConflict { fn clone(&self) -> Self { let r: &azul_core::window::ShortcutConflict = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathPseudoSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathPseudoSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAnimationInterpolationFunctionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::AnimationInterpolationFunction = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInterpolateContext { fn clone(&self) -> Self { let r: &azul_impl::css::InterpolateResolver = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11003,7 +12570,6 @@ impl Clone for AzStyleBackgroundPositionVecValueEnumWrapper { fn clone(&self) ->
 impl Clone for AzStyleBackgroundRepeatVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundRepeatVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundSizeVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundSizeVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBoxStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBoxStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzNumberInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraphCallbacks { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphCallbacks = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputConnection { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputConnection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOutputConnection { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputConnection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11031,14 +12597,18 @@ impl Clone for AzInputConnectionVec { fn clone(&self) -> Self { let r: &crate::w
 impl Clone for AzOutputConnectionVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputConnectionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedSvgNodeVec { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedSvgNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedColoredSvgNodeVec { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedColoredSvgNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzPaintPrimitiveVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::PaintPrimitiveVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgPathElementVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgPathElementVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzShortcutConflictVec { fn clone(&self) -> Self { let r: &azul_core::window::ShortcutConflictVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzShortcutVec { fn clone(&self) -> Self { let r: &azul_core::window::ShortcutVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringVec { fn clone(&self) -> Self { let r: &azul_impl::css::StringVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyledNodeVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::StyledNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTagIdToNodeIdMappingVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::TagIdToNodeIdMappingVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMenuEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMenu = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionResolvedTextLayoutOptionsEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::OptionResolvedTextLayoutOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionVirtualKeyCodeComboEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionVirtualKeyCodeCombo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionShortcutConflictEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionShortcutConflict = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMouseStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMouseState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionKeyboardStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionKeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionStringVecEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionStringVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11054,28 +12624,44 @@ impl Clone for AzUnexpectedCloseTagError { fn clone(&self) -> Self { let r: &azu
 impl Clone for AzUnknownEntityReferenceError { fn clone(&self) -> Self { let r: &azul_impl::xml::UnknownEntityReferenceError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDuplicatedAttributeError { fn clone(&self) -> Self { let r: &azul_impl::xml::DuplicatedAttributeError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInvalidStringError { fn clone(&self) -> Self { let r: &azul_impl::xml::InvalidStringError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzJankCapture { fn clone(&self) -> Self { let r: &azul_impl::resources::JankCapture = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzEventRecordingEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::EventRecording = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzFrameRecordingOutputEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::FrameRecordingOutput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCrashInfo { fn clone(&self) -> Self { let r: &azul_impl::resources::CrashInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowsWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::WindowsWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWaylandTheme { fn clone(&self) -> Self { let r: &azul_core::window::WaylandTheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringPair { fn clone(&self) -> Self { let r: &azul_core::window::AzStringPair = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMonitor { fn clone(&self) -> Self { let r: &azul_core::window::Monitor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutCallbackEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::LayoutCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineWordEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineWord = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCustomNodeType { fn clone(&self) -> Self { let r: &azul_impl::dom::CustomNodeType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCallbackData { fn clone(&self) -> Self { let r: &azul_impl::dom::CallbackData = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAccessibilityInfo { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzFocusNavigation { fn clone(&self) -> Self { let r: &azul_impl::dom::FocusNavigation = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOverlayInfo { fn clone(&self) -> Self { let r: &azul_impl::dom::OverlayInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIdOrClassEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::IdOrClass = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringMenuItem { fn clone(&self) -> Self { let r: &azul_core::window::StringMenuItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzShortcutMap { fn clone(&self) -> Self { let r: &azul_core::window::ShortcutMap = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarInfo { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarStyle { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCounterReset { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCounterReset = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCounterIncrement { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCounterIncrement = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCursorImage { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursorImage = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamily = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarStyleValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarStyleValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleContentValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleContentValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCounterResetValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCounterResetValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCounterIncrementValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCounterIncrementValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFilterVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFilterVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFileInputState { fn clone(&self) -> Self { let r: &crate::widgets::file_input::FileInputState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzColorInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::color_input::ColorInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputState { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextInputSpellingSuggestions { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputSpellingSuggestions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTabHeader { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabHeader = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeFieldValueEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeTypeFieldValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeInfo { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeTypeInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11084,6 +12670,8 @@ impl Clone for AzListView { fn clone(&self) -> Self { let r: &crate::widgets::li
 impl Clone for AzListViewState { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTreeView { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeView = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDropDown { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDown = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzToast { fn clone(&self) -> Self { let r: &crate::widgets::toasts::Toast = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGlQuirkRule { fn clone(&self) -> Self { let r: &azul_impl::gl::GlQuirkRule = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexAttribute { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexAttribute = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDebugMessage { fn clone(&self) -> Self { let r: &azul_impl::gl::AzDebugMessage = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzGetActiveAttribReturn { fn clone(&self) -> Self { let r: &azul_impl::gl::GetActiveAttribReturn = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11111,15 +12699,20 @@ impl Clone for AzDebugMessageVec { fn clone(&self) -> Self { let r: &azul_impl::
 impl Clone for AzStringPairVec { fn clone(&self) -> Self { let r: &azul_core::window::StringPairVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionFileTypeListEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dialogs::OptionFileTypeList = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionFileEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::file::OptionFile = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionJankCaptureEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::OptionJankCapture = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionEventRecordingEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::OptionEventRecording = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionRawImageEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::OptionRawImage = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionWaylandThemeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWaylandTheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResultRawImageDecodeImageErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::decode::ResultRawImageDecodeImageError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlStreamErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlStreamError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzFrameRecording { fn clone(&self) -> Self { let r: &azul_impl::resources::FrameRecording = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLinuxWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::LinuxWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineLine { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineLine = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPath { fn clone(&self) -> Self { let r: &azul_impl::css::CssPath = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCursorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundContentVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContentVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCursorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamilyVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFileInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::file_input::FileInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11145,22 +12738,27 @@ impl Clone for AzCssPropertyVec { fn clone(&self) -> Self { let r: &azul_impl::c
 impl Clone for AzSvgMultiPolygonVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgMultiPolygonVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgSimpleNodeVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgSimpleNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionFrameRecordingEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::OptionFrameRecording = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlTextError { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlTextError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzAppConfig { fn clone(&self) -> Self { let r: &azul_impl::resources::AppConfig = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPlatformSpecificOptions { fn clone(&self) -> Self { let r: &azul_core::window::PlatformSpecificOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowState { fn clone(&self) -> Self { let r: &azul_core::window::WindowState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::CallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineText { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineText = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextLayout { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TextLayout = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFocusTargetPath { fn clone(&self) -> Self { let r: &azul_impl::callbacks::FocusTargetPath = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAnimation { fn clone(&self) -> Self { let r: &azul_impl::callbacks::Animation = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTimerCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TimerCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataInlineCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataInlineCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDynamicCssProperty { fn clone(&self) -> Self { let r: &azul_impl::css::DynamicCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSystemCssProperty { fn clone(&self) -> Self { let r: &azul_impl::css::SystemCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNode { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::Node = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgNodeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgStyledNode { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgStyledNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataInlineCssPropertyVec { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataInlineCssPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionWindowStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWindowState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionInlineTextEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionInlineText = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTextLayoutEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionTextLayout = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlParseErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlParseError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowCreateOptions { fn clone(&self) -> Self { let r: &azul_core::window::WindowCreateOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFocusTargetEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::FocusTarget = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11172,14 +12770,16 @@ impl Clone for AzCheckBox { fn clone(&self) -> Self { let r: &crate::widgets::ch
 impl Clone for AzLabel { fn clone(&self) -> Self { let r: &crate::widgets::label::Label = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzColorInput { fn clone(&self) -> Self { let r: &crate::widgets::color_input::ColorInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInput { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzNumberInput { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNumberInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeIdNodeMap { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeIdNodeMap = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzToasts { fn clone(&self) -> Self { let r: &crate::widgets::toasts::Toasts = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeIdNodeMapVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeIdNodeMapVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssDeclarationVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssDeclarationVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataVec { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDom { fn clone(&self) -> Self { let r: &azul_impl::dom::Dom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssRuleBlock { fn clone(&self) -> Self { let r: &azul_impl::css::CssRuleBlock = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzNumberInput { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTabContent { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFrame { fn clone(&self) -> Self { let r: &crate::widgets::frame::Frame = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraph { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraph = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11209,6 +12809,7 @@ impl Drop for AzSystemClipboard { fn drop(&mut self) { crate::AzSystemClipboard_
 impl Drop for AzThread { fn drop(&mut self) { crate::AzThread_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzThreadSender { fn drop(&mut self) { crate::AzThreadSender_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzThreadReceiver { fn drop(&mut self) { crate::AzThreadReceiver_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzEventLoopProxy { fn drop(&mut self) { crate::AzEventLoopProxy_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzRefAny { fn drop(&mut self) { crate::AzRefAny_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzGl { fn drop(&mut self) { crate::AzGl_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInstantPtr { fn drop(&mut self) { crate::AzInstantPtr_delete(unsafe { mem::transmute(self) }); } }
@@ -11220,7 +12821,9 @@ impl Drop for AzAccessibilityStateVec { fn drop(&mut self) { crate::AzAccessibil
 impl Drop for AzMenuItemVec { fn drop(&mut self) { crate::AzMenuItemVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzXmlNodeVec { fn drop(&mut self) { crate::AzXmlNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInlineGlyphVec { fn drop(&mut self) { crate::AzInlineGlyphVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTextUnderlineVec { fn drop(&mut self) { crate::AzTextUnderlineVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInlineTextHitVec { fn drop(&mut self) { crate::AzInlineTextHitVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTouchPointVec { fn drop(&mut self) { crate::AzTouchPointVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzVideoModeVec { fn drop(&mut self) { crate::AzVideoModeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzDomVec { fn drop(&mut self) { crate::AzDomVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleBackgroundPositionVec { fn drop(&mut self) { crate::AzStyleBackgroundPositionVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11250,8 +12853,11 @@ impl Drop for AzInputConnectionVec { fn drop(&mut self) { crate::AzInputConnecti
 impl Drop for AzOutputConnectionVec { fn drop(&mut self) { crate::AzOutputConnectionVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTessellatedSvgNodeVec { fn drop(&mut self) { crate::AzTessellatedSvgNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTessellatedColoredSvgNodeVec { fn drop(&mut self) { crate::AzTessellatedColoredSvgNodeVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzPaintPrimitiveVec { fn drop(&mut self) { crate::AzPaintPrimitiveVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleTransformVec { fn drop(&mut self) { crate::AzStyleTransformVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgPathElementVec { fn drop(&mut self) { crate::AzSvgPathElementVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzShortcutConflictVec { fn drop(&mut self) { crate::AzShortcutConflictVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzShortcutVec { fn drop(&mut self) { crate::AzShortcutVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStringVec { fn drop(&mut self) { crate::AzStringVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyledNodeVec { fn drop(&mut self) { crate::AzStyledNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTagIdToNodeIdMappingVec { fn drop(&mut self) { crate::AzTagIdToNodeIdMappingVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11301,15 +12907,26 @@ impl AzApp {
             mem::transmute(image),
         )) }
     }
+    fn with_splash(self, splash: AzSplashOptions) -> AzApp {
+        unsafe { mem::transmute(crate::AzApp_withSplash(
+            mem::transmute(self),
+            mem::transmute(splash),
+        )) }
+    }
+    fn add_gl_quirk_rule(&mut self, rule: AzGlQuirkRule) -> () {
+        unsafe { mem::transmute(crate::AzApp_addGlQuirkRule(
+            mem::transmute(self),
+            mem::transmute(rule),
+        )) }
+    }
     fn get_monitors(&self) -> AzMonitorVec {
         unsafe { mem::transmute(crate::AzApp_getMonitors(
             mem::transmute(self),
         )) }
     }
-    fn run(&self, window: AzWindowCreateOptions) -> () {
-        unsafe { mem::transmute(crate::AzApp_run(
+    fn create_event_loop_proxy(&self) -> AzEventLoopProxy {
+        unsafe { mem::transmute(crate::AzApp_createEventLoopProxy(
             mem::transmute(self),
-            mem::transmute(window),
         )) }
     }
     // impl App {
@@ -11324,6 +12941,29 @@ impl AzApp {
 
         let app_refany = azul_impl::callbacks::RefAny::new(AppDataTy { _py_app_data: Some(data) });
         Ok(unsafe { mem::transmute(crate::AzApp_new(app_refany, mem::transmute(config))) })
+    }
+
+    pub fn run(&self, py: Python, window: AzWindowCreateOptions) -> () {
+
+        // AzApp and AzWindowCreateOptions are not Send, but allow_threads
+        // runs the closure on the current thread, so nothing actually
+        // crosses a thread boundary here
+        struct RunArgs<'a> {
+            app: &'a AzApp,
+            window: AzWindowCreateOptions,
+        }
+
+        unsafe impl<'a> Send for RunArgs<'a> { }
+
+        let args = RunArgs { app: self, window };
+
+        // Release the GIL while the event loop is running, otherwise Python threads
+        // (and azul threads calling back into Python) would block until the app exits.
+        // The marshaled callbacks re-acquire the GIL via Python::with_gil.
+        py.allow_threads(move || {
+            let RunArgs { app, window } = args;
+            crate::AzApp_run(app, window)
+        })
     }}
 
 #[pyproto]
@@ -11392,6 +13032,232 @@ impl PyObjectProtocol for AzAppLogLevelEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzJankCapture {
+    #[new]
+    fn new(threshold: AzDurationEnumWrapper) -> AzJankCapture {
+        unsafe { mem::transmute(crate::AzJankCapture_new(
+            mem::transmute(threshold),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzJankCapture {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::JankCapture = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::JankCapture = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplashOptions {
+    #[new]
+    fn new() -> AzSplashOptions {
+        unsafe { mem::transmute(crate::AzSplashOptions_new()) }
+    }
+    fn with_logo(self, logo: AzImageRef) -> AzSplashOptions {
+        unsafe { mem::transmute(crate::AzSplashOptions_withLogo(
+            mem::transmute(self),
+            mem::transmute(logo),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplashOptions {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::SplashOptions = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::SplashOptions = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzEventRecordingEnumWrapper {
+    #[staticmethod]
+    fn Record(v: AzString) -> AzEventRecordingEnumWrapper { AzEventRecordingEnumWrapper { inner: AzEventRecording::Record(v) } }
+    #[staticmethod]
+    fn Replay(v: AzString) -> AzEventRecordingEnumWrapper { AzEventRecordingEnumWrapper { inner: AzEventRecording::Replay(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzEventRecording;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzEventRecording::Record(v) => Ok(vec!["Record".into_py(py), v.clone().into_py(py)]),
+            AzEventRecording::Replay(v) => Ok(vec!["Replay".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzEventRecordingEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::EventRecording = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::EventRecording = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzFrameRecording {
+    #[new]
+    fn new(output: AzFrameRecordingOutputEnumWrapper) -> AzFrameRecording {
+        unsafe { mem::transmute(crate::AzFrameRecording_new(
+            mem::transmute(output),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzFrameRecording {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::FrameRecording = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::FrameRecording = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzFrameRecordingOutputEnumWrapper {
+    #[staticmethod]
+    fn GifDirectory(v: AzString) -> AzFrameRecordingOutputEnumWrapper { AzFrameRecordingOutputEnumWrapper { inner: AzFrameRecordingOutput::GifDirectory(v) } }
+    #[staticmethod]
+    fn Callback(v: AzFrameRecordingCallback) -> AzFrameRecordingOutputEnumWrapper { AzFrameRecordingOutputEnumWrapper { inner: AzFrameRecordingOutput::Callback(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzFrameRecordingOutput;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzFrameRecordingOutput::GifDirectory(v) => Ok(vec!["GifDirectory".into_py(py), v.clone().into_py(py)]),
+            AzFrameRecordingOutput::Callback(v) => Ok(vec!["Callback".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzFrameRecordingOutputEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::FrameRecordingOutput = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::FrameRecordingOutput = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRecordedFrame {
+    #[new]
+    fn __new__(window_handle: AzRawWindowHandleEnumWrapper, frame_index: usize, timestamp: AzDurationEnumWrapper, duration: AzDurationEnumWrapper, image: AzImageRef) -> Self {
+        Self {
+            window_handle,
+            frame_index,
+            timestamp,
+            duration,
+            image,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRecordedFrame {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::RecordedFrame = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::RecordedFrame = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzFrameRecordingCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzFrameRecordingCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::FrameRecordingCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::FrameRecordingCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCrashInfo {
+    #[new]
+    fn __new__(message: AzString, location: AzString, thread_name: AzString, backtrace: AzString, last_log_lines: AzStringVec, window_state_summary: AzString, minidump_path: AzOptionStringEnumWrapper) -> Self {
+        Self {
+            message,
+            location,
+            thread_name,
+            backtrace,
+            last_log_lines,
+            window_state_summary,
+            minidump_path,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCrashInfo {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::CrashInfo = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::CrashInfo = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCrashHandler {
+    // impl CrashHandler {
+
+    #[new]
+    pub fn __new__(py: Python, cb: PyObject) -> Result<Self, PyErr> {
+        use pyo3::type_object::PyTypeInfo;
+
+        {
+            let cb_any = cb.as_ref(py);
+            if !cb_any.is_callable() {
+                let type_name = cb_any.get_type().name().unwrap_or("<unknown>");
+                return Err(PyException::new_err(format!("ERROR in CrashHandler.new: - argument \"cb\" is of type \"{}\", expected function", type_name)));
+            }
+        }
+
+        // the crash handler is a plain function pointer without any
+        // user data, so the last created handler replaces the previous one
+        if let Ok(mut handler) = PY_CRASH_HANDLER.lock() {
+            *handler = Some(cb);
+        }
+
+        Ok(Self { cb: invoke_py_crash_handler })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCrashHandler {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::CrashHandler = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::CrashHandler = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzLayoutSolverEnumWrapper {
     #[classattr]
@@ -11438,6 +13304,36 @@ impl PyObjectProtocol for AzSystemCallbacks {
 
 #[pymethods]
 impl AzWindowCreateOptions {
+    fn with_parent_handle(self, parent_handle: AzRawWindowHandleEnumWrapper) -> AzWindowCreateOptions {
+        unsafe { mem::transmute(crate::AzWindowCreateOptions_withParentHandle(
+            mem::transmute(self),
+            mem::transmute(parent_handle),
+        )) }
+    }
+    fn with_background_effect(self, background_effect: AzWindowBackgroundEffectEnumWrapper) -> AzWindowCreateOptions {
+        unsafe { mem::transmute(crate::AzWindowCreateOptions_withBackgroundEffect(
+            mem::transmute(self),
+            mem::transmute(background_effect),
+        )) }
+    }
+    fn with_fade_in(self, duration: AzDurationEnumWrapper) -> AzWindowCreateOptions {
+        unsafe { mem::transmute(crate::AzWindowCreateOptions_withFadeIn(
+            mem::transmute(self),
+            mem::transmute(duration),
+        )) }
+    }
+    fn with_first_frame(self, first_frame: AzFirstFrameModeEnumWrapper) -> AzWindowCreateOptions {
+        unsafe { mem::transmute(crate::AzWindowCreateOptions_withFirstFrame(
+            mem::transmute(self),
+            mem::transmute(first_frame),
+        )) }
+    }
+    fn with_scrollbars(self, scrollbars: AzScrollbarConfig) -> AzWindowCreateOptions {
+        unsafe { mem::transmute(crate::AzWindowCreateOptions_withScrollbars(
+            mem::transmute(self),
+            mem::transmute(scrollbars),
+        )) }
+    }
     // impl WindowCreateOptions {
 
     #[new]
@@ -11447,7 +13343,22 @@ impl AzWindowCreateOptions {
             .. Default::default()
         };
         Ok(unsafe { mem::transmute(window) })
-    }}
+    }
+    fn with_window_data(&self, py: Python, window_data: PyObject) -> Result<Self, PyErr> { // RefAny<DatasetTy>
+        use pyo3::type_object::PyTypeInfo;
+
+        if window_data.as_ref(py.clone()).is_callable() {
+            return Err(PyException::new_err(format!("ERROR in WindowCreateOptions.with_window_data: - argument \"window_data\" is a function callback, expected class")));
+        }
+
+        let window_data_refany = azul_impl::callbacks::RefAny::new(DatasetTy {
+            _py_data: Some(window_data),
+        });
+
+        let window: azul_core::window::WindowCreateOptions = unsafe { mem::transmute(self.clone()) };
+        Ok(unsafe { mem::transmute(window.with_window_data(window_data_refany)) })
+    }
+}
 
 #[pyproto]
 impl PyObjectProtocol for AzWindowCreateOptions {
@@ -11459,6 +13370,116 @@ impl PyObjectProtocol for AzWindowCreateOptions {
     }
 }
 
+#[pymethods]
+impl AzWindowBackgroundEffectEnumWrapper {
+    #[classattr]
+    fn None() -> AzWindowBackgroundEffectEnumWrapper { AzWindowBackgroundEffectEnumWrapper { inner: AzWindowBackgroundEffect::None } }
+    #[classattr]
+    fn Blur() -> AzWindowBackgroundEffectEnumWrapper { AzWindowBackgroundEffectEnumWrapper { inner: AzWindowBackgroundEffect::Blur } }
+    #[classattr]
+    fn Mica() -> AzWindowBackgroundEffectEnumWrapper { AzWindowBackgroundEffectEnumWrapper { inner: AzWindowBackgroundEffect::Mica } }
+    #[classattr]
+    fn MicaAlt() -> AzWindowBackgroundEffectEnumWrapper { AzWindowBackgroundEffectEnumWrapper { inner: AzWindowBackgroundEffect::MicaAlt } }
+    #[classattr]
+    fn Acrylic() -> AzWindowBackgroundEffectEnumWrapper { AzWindowBackgroundEffectEnumWrapper { inner: AzWindowBackgroundEffect::Acrylic } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzWindowBackgroundEffectEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::WindowBackgroundEffect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::WindowBackgroundEffect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzWindowBackgroundEffectEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzFirstFrameModeEnumWrapper {
+    #[classattr]
+    fn ShowImmediately() -> AzFirstFrameModeEnumWrapper { AzFirstFrameModeEnumWrapper { inner: AzFirstFrameMode::ShowImmediately } }
+    #[classattr]
+    fn HideUntilRendered() -> AzFirstFrameModeEnumWrapper { AzFirstFrameModeEnumWrapper { inner: AzFirstFrameMode::HideUntilRendered } }
+    #[staticmethod]
+    fn SplashColor(v: AzColorU) -> AzFirstFrameModeEnumWrapper { AzFirstFrameModeEnumWrapper { inner: AzFirstFrameMode::SplashColor(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzFirstFrameMode;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzFirstFrameMode::ShowImmediately => Ok(vec!["ShowImmediately".into_py(py), ().into_py(py)]),
+            AzFirstFrameMode::HideUntilRendered => Ok(vec!["HideUntilRendered".into_py(py), ().into_py(py)]),
+            AzFirstFrameMode::SplashColor(v) => Ok(vec!["SplashColor".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzFirstFrameModeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::FirstFrameMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::FirstFrameMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzScrollbarConfig {
+    #[staticmethod]
+    fn default() -> AzScrollbarConfig {
+        unsafe { mem::transmute(crate::AzScrollbarConfig_default()) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzScrollbarConfig {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ScrollbarConfig = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ScrollbarConfig = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzScrollbarModeEnumWrapper {
+    #[classattr]
+    fn Default() -> AzScrollbarModeEnumWrapper { AzScrollbarModeEnumWrapper { inner: AzScrollbarMode::Default } }
+    #[classattr]
+    fn Overlay() -> AzScrollbarModeEnumWrapper { AzScrollbarModeEnumWrapper { inner: AzScrollbarMode::Overlay } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzScrollbarModeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ScrollbarMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ScrollbarMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzScrollbarModeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzRendererOptions {
     #[new]
@@ -11662,9 +13683,7 @@ impl AzRawWindowHandleEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzRawWindowHandle;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzRawWindowHandle::IOS(v) => Ok(vec!["IOS".into_py(py), v.clone().into_py(py)]),
             AzRawWindowHandle::MacOS(v) => Ok(vec!["MacOS".into_py(py), v.clone().into_py(py)]),
             AzRawWindowHandle::Xlib(v) => Ok(vec!["Xlib".into_py(py), v.clone().into_py(py)]),
@@ -11674,7 +13693,7 @@ impl AzRawWindowHandleEnumWrapper {
             AzRawWindowHandle::Web(v) => Ok(vec!["Web".into_py(py), v.clone().into_py(py)]),
             AzRawWindowHandle::Android(v) => Ok(vec!["Android".into_py(py), v.clone().into_py(py)]),
             AzRawWindowHandle::Unsupported => Ok(vec!["Unsupported".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -12053,12 +14072,10 @@ impl AzWindowIconEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzWindowIcon;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzWindowIcon::Small(v) => Ok(vec!["Small".into_py(py), v.clone().into_py(py)]),
             AzWindowIcon::Large(v) => Ok(vec!["Large".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -12458,14 +14475,12 @@ impl AzAcceleratorKeyEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzAcceleratorKey;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzAcceleratorKey::Ctrl => Ok(vec!["Ctrl".into_py(py), ().into_py(py)]),
             AzAcceleratorKey::Alt => Ok(vec!["Alt".into_py(py), ().into_py(py)]),
             AzAcceleratorKey::Shift => Ok(vec!["Shift".into_py(py), ().into_py(py)]),
             AzAcceleratorKey::Key(v) => Ok(vec!["Key".into_py(py), { let m: &AzVirtualKeyCodeEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -12482,12 +14497,16 @@ impl PyObjectProtocol for AzAcceleratorKeyEnumWrapper {
 #[pymethods]
 impl AzWindowSize {
     #[new]
-    fn __new__(dimensions: AzLogicalSize, dpi: u32, min_dimensions: AzOptionLogicalSizeEnumWrapper, max_dimensions: AzOptionLogicalSizeEnumWrapper) -> Self {
+    fn __new__(dimensions: AzLogicalSize, dpi: u32, min_dimensions: AzOptionLogicalSizeEnumWrapper, max_dimensions: AzOptionLogicalSizeEnumWrapper, min_dimensions_from_content: bool, resize_increments: AzOptionLogicalSizeEnumWrapper, aspect_ratio_lock: AzOptionF32EnumWrapper, zoom: f32) -> Self {
         Self {
             dimensions,
             dpi,
             min_dimensions,
             max_dimensions,
+            min_dimensions_from_content,
+            resize_increments,
+            aspect_ratio_lock,
+            zoom,
         }
     }
 
@@ -12496,6 +14515,12 @@ impl AzWindowSize {
             mem::transmute(self),
         )) }
     }
+    fn set_zoom(&mut self, zoom: f32) -> () {
+        unsafe { mem::transmute(crate::AzWindowSize_setZoom(
+            mem::transmute(self),
+            mem::transmute(zoom),
+        )) }
+    }
 }
 
 #[pyproto]
@@ -12511,19 +14536,24 @@ impl PyObjectProtocol for AzWindowSize {
 #[pymethods]
 impl AzWindowFlags {
     #[new]
-    fn __new__(frame: AzWindowFrameEnumWrapper, is_about_to_close: bool, has_decorations: bool, is_visible: bool, is_always_on_top: bool, is_resizable: bool, has_focus: bool, has_extended_window_frame: bool, has_blur_behind_window: bool, smooth_scroll_enabled: bool, autotab_enabled: bool) -> Self {
+    fn __new__(frame: AzWindowFrameEnumWrapper, is_about_to_close: bool, has_decorations: bool, is_visible: bool, is_always_on_top: bool, is_click_through: bool, is_tool_window: bool, is_resizable: bool, has_focus: bool, has_extended_window_frame: bool, has_blur_behind_window: bool, smooth_scroll_enabled: bool, autotab_enabled: bool, spatial_navigation_enabled: bool, is_session_ending: bool, fullscreen_mode: AzFullscreenModeEnumWrapper) -> Self {
         Self {
             frame,
             is_about_to_close,
             has_decorations,
             is_visible,
             is_always_on_top,
+            is_click_through,
+            is_tool_window,
             is_resizable,
             has_focus,
             has_extended_window_frame,
             has_blur_behind_window,
             smooth_scroll_enabled,
             autotab_enabled,
+            spatial_navigation_enabled,
+            is_session_ending,
+            fullscreen_mode,
         }
     }
 
@@ -12539,6 +14569,72 @@ impl PyObjectProtocol for AzWindowFlags {
     }
 }
 
+#[pymethods]
+impl AzPolitenessEnumWrapper {
+    #[classattr]
+    fn Polite() -> AzPolitenessEnumWrapper { AzPolitenessEnumWrapper { inner: AzPoliteness::Polite } }
+    #[classattr]
+    fn Assertive() -> AzPolitenessEnumWrapper { AzPolitenessEnumWrapper { inner: AzPoliteness::Assertive } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzPolitenessEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::Politeness = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::Politeness = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzPolitenessEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzHapticKindEnumWrapper {
+    #[classattr]
+    fn Selection() -> AzHapticKindEnumWrapper { AzHapticKindEnumWrapper { inner: AzHapticKind::Selection } }
+    #[classattr]
+    fn LightImpact() -> AzHapticKindEnumWrapper { AzHapticKindEnumWrapper { inner: AzHapticKind::LightImpact } }
+    #[classattr]
+    fn MediumImpact() -> AzHapticKindEnumWrapper { AzHapticKindEnumWrapper { inner: AzHapticKind::MediumImpact } }
+    #[classattr]
+    fn HeavyImpact() -> AzHapticKindEnumWrapper { AzHapticKindEnumWrapper { inner: AzHapticKind::HeavyImpact } }
+    #[classattr]
+    fn Success() -> AzHapticKindEnumWrapper { AzHapticKindEnumWrapper { inner: AzHapticKind::Success } }
+    #[classattr]
+    fn Warning() -> AzHapticKindEnumWrapper { AzHapticKindEnumWrapper { inner: AzHapticKind::Warning } }
+    #[classattr]
+    fn Error() -> AzHapticKindEnumWrapper { AzHapticKindEnumWrapper { inner: AzHapticKind::Error } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzHapticKindEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::HapticKind = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::HapticKind = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzHapticKindEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzWindowFrameEnumWrapper {
     #[classattr]
@@ -12571,6 +14667,33 @@ impl PyObjectProtocol for AzWindowFrameEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzFullscreenModeEnumWrapper {
+    #[staticmethod]
+    fn Borderless(v: usize) -> AzFullscreenModeEnumWrapper { AzFullscreenModeEnumWrapper { inner: AzFullscreenMode::Borderless(v) } }
+    #[staticmethod]
+    fn Exclusive(v: usize) -> AzFullscreenModeEnumWrapper { AzFullscreenModeEnumWrapper { inner: AzFullscreenMode::Exclusive(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzFullscreenMode;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzFullscreenMode::Borderless(v) => Ok(vec!["Borderless".into_py(py), v.into_py(py)]),
+            AzFullscreenMode::Exclusive(v) => Ok(vec!["Exclusive".into_py(py), v.into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzFullscreenModeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::FullscreenMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::FullscreenMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzDebugState {
     #[new]
@@ -12621,12 +14744,15 @@ impl PyObjectProtocol for AzDebugState {
 #[pymethods]
 impl AzKeyboardState {
     #[new]
-    fn __new__(current_char: AzOptionCharEnumWrapper, current_virtual_keycode: AzOptionVirtualKeyCodeEnumWrapper, pressed_virtual_keycodes: AzVirtualKeyCodeVec, pressed_scancodes: AzScanCodeVec) -> Self {
+    fn __new__(current_char: AzOptionCharEnumWrapper, current_virtual_keycode: AzOptionVirtualKeyCodeEnumWrapper, pressed_virtual_keycodes: AzVirtualKeyCodeVec, pressed_scancodes: AzScanCodeVec, caps_lock_enabled: bool, num_lock_enabled: bool, scroll_lock_enabled: bool) -> Self {
         Self {
             current_char,
             current_virtual_keycode,
             pressed_virtual_keycodes,
             pressed_scancodes,
+            caps_lock_enabled,
+            num_lock_enabled,
+            scroll_lock_enabled,
         }
     }
 
@@ -12650,12 +14776,63 @@ impl AzKeyboardState {
             mem::transmute(self),
         )) }
     }
+    fn left_shift_down(&self) -> bool {
+        unsafe { mem::transmute(crate::AzKeyboardState_leftShiftDown(
+            mem::transmute(self),
+        )) }
+    }
+    fn right_shift_down(&self) -> bool {
+        unsafe { mem::transmute(crate::AzKeyboardState_rightShiftDown(
+            mem::transmute(self),
+        )) }
+    }
+    fn left_ctrl_down(&self) -> bool {
+        unsafe { mem::transmute(crate::AzKeyboardState_leftCtrlDown(
+            mem::transmute(self),
+        )) }
+    }
+    fn right_ctrl_down(&self) -> bool {
+        unsafe { mem::transmute(crate::AzKeyboardState_rightCtrlDown(
+            mem::transmute(self),
+        )) }
+    }
+    fn left_alt_down(&self) -> bool {
+        unsafe { mem::transmute(crate::AzKeyboardState_leftAltDown(
+            mem::transmute(self),
+        )) }
+    }
+    fn right_alt_down(&self) -> bool {
+        unsafe { mem::transmute(crate::AzKeyboardState_rightAltDown(
+            mem::transmute(self),
+        )) }
+    }
+    fn left_super_down(&self) -> bool {
+        unsafe { mem::transmute(crate::AzKeyboardState_leftSuperDown(
+            mem::transmute(self),
+        )) }
+    }
+    fn right_super_down(&self) -> bool {
+        unsafe { mem::transmute(crate::AzKeyboardState_rightSuperDown(
+            mem::transmute(self),
+        )) }
+    }
     fn is_key_down(&self, key: AzVirtualKeyCodeEnumWrapper) -> bool {
         unsafe { mem::transmute(crate::AzKeyboardState_isKeyDown(
             mem::transmute(self),
             mem::transmute(key),
         )) }
     }
+    fn is_scancode_down(&self, scancode: u32) -> bool {
+        unsafe { mem::transmute(crate::AzKeyboardState_isScancodeDown(
+            mem::transmute(self),
+            mem::transmute(scancode),
+        )) }
+    }
+    fn get_modifiers(&self) -> AzKeyboardModifiers {
+        unsafe { mem::transmute(crate::AzKeyboardState_getModifiers(
+            mem::transmute(self),
+        )) }
+    }
 }
 
 #[pyproto]
@@ -12668,6 +14845,30 @@ impl PyObjectProtocol for AzKeyboardState {
     }
 }
 
+#[pymethods]
+impl AzKeyboardModifiers {
+    #[new]
+    fn __new__(shift: bool, ctrl: bool, alt: bool, caps_lock: bool) -> Self {
+        Self {
+            shift,
+            ctrl,
+            alt,
+            caps_lock,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzKeyboardModifiers {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::KeyboardModifiers = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::KeyboardModifiers = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzMouseCursorTypeEnumWrapper {
     #[classattr]
@@ -12774,13 +14975,11 @@ impl AzCursorPositionEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCursorPosition;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzCursorPosition::OutOfWindow(v) => Ok(vec!["OutOfWindow".into_py(py), v.clone().into_py(py)]),
             AzCursorPosition::Uninitialized => Ok(vec!["Uninitialized".into_py(py), ().into_py(py)]),
             AzCursorPosition::InWindow(v) => Ok(vec!["InWindow".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -12797,7 +14996,7 @@ impl PyObjectProtocol for AzCursorPositionEnumWrapper {
 #[pymethods]
 impl AzMouseState {
     #[new]
-    fn __new__(mouse_cursor_type: AzOptionMouseCursorTypeEnumWrapper, cursor_position: AzCursorPositionEnumWrapper, is_cursor_locked: bool, left_down: bool, right_down: bool, middle_down: bool, scroll_x: AzOptionF32EnumWrapper, scroll_y: AzOptionF32EnumWrapper) -> Self {
+    fn __new__(mouse_cursor_type: AzOptionMouseCursorTypeEnumWrapper, cursor_position: AzCursorPositionEnumWrapper, is_cursor_locked: bool, left_down: bool, right_down: bool, middle_down: bool, click_count: u32, scroll_x: AzOptionF32EnumWrapper, scroll_y: AzOptionF32EnumWrapper) -> Self {
         Self {
             mouse_cursor_type,
             cursor_position,
@@ -12805,6 +15004,7 @@ impl AzMouseState {
             left_down,
             right_down,
             middle_down,
+            click_count,
             scroll_x,
             scroll_y,
         }
@@ -13079,12 +15279,10 @@ impl AzWindowPositionEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzWindowPosition;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzWindowPosition::Uninitialized => Ok(vec!["Uninitialized".into_py(py), ().into_py(py)]),
             AzWindowPosition::Initialized(v) => Ok(vec!["Initialized".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -13108,12 +15306,10 @@ impl AzImePositionEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzImePosition;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzImePosition::Uninitialized => Ok(vec!["Uninitialized".into_py(py), ().into_py(py)]),
             AzImePosition::Initialized(v) => Ok(vec!["Initialized".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -13130,9 +15326,10 @@ impl PyObjectProtocol for AzImePositionEnumWrapper {
 #[pymethods]
 impl AzTouchState {
     #[new]
-    fn __new__(unused: u8) -> Self {
+    fn __new__(touches: AzTouchPointVec, input_modality: AzInputModalityEnumWrapper) -> Self {
         Self {
-            unused,
+            touches,
+            input_modality,
         }
     }
 
@@ -13148,10 +15345,63 @@ impl PyObjectProtocol for AzTouchState {
     }
 }
 
+#[pymethods]
+impl AzInputModalityEnumWrapper {
+    #[classattr]
+    fn Mouse() -> AzInputModalityEnumWrapper { AzInputModalityEnumWrapper { inner: AzInputModality::Mouse } }
+    #[classattr]
+    fn Touch() -> AzInputModalityEnumWrapper { AzInputModalityEnumWrapper { inner: AzInputModality::Touch } }
+    #[classattr]
+    fn Pen() -> AzInputModalityEnumWrapper { AzInputModalityEnumWrapper { inner: AzInputModality::Pen } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzInputModalityEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::InputModality = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::InputModality = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzInputModalityEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzTouchPoint {
+    #[new]
+    fn __new__(id: u64, position: AzLogicalPosition, force: f32) -> Self {
+        Self {
+            id,
+            position,
+            force,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTouchPoint {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TouchPoint = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TouchPoint = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzMonitor {
     #[new]
-    fn __new__(id: usize, name: AzOptionStringEnumWrapper, size: AzLayoutSize, position: AzLayoutPoint, scale_factor: f64, video_modes: AzVideoModeVec, is_primary_monitor: bool) -> Self {
+    fn __new__(id: usize, name: AzOptionStringEnumWrapper, size: AzLayoutSize, position: AzLayoutPoint, scale_factor: f64, video_modes: AzVideoModeVec, is_primary_monitor: bool, hdr: AzHdrCapabilities) -> Self {
         Self {
             id,
             name,
@@ -13160,6 +15410,7 @@ impl AzMonitor {
             scale_factor,
             video_modes,
             is_primary_monitor,
+            hdr,
         }
     }
 
@@ -13175,6 +15426,30 @@ impl PyObjectProtocol for AzMonitor {
     }
 }
 
+#[pymethods]
+impl AzHdrCapabilities {
+    #[new]
+    fn __new__(supported: bool, enabled: bool, bits_per_color_channel: u32, sdr_white_level: f32) -> Self {
+        Self {
+            supported,
+            enabled,
+            bits_per_color_channel,
+            sdr_white_level,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzHdrCapabilities {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::HdrCapabilities = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::HdrCapabilities = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzVideoMode {
     #[new]
@@ -13204,6 +15479,12 @@ impl AzWindowState {
     fn default() -> AzWindowState {
         unsafe { mem::transmute(crate::AzWindowState_default()) }
     }
+    fn set_fullscreen(&mut self, mode: AzFullscreenModeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzWindowState_setFullscreen(
+            mem::transmute(self),
+            mem::transmute(mode),
+        )) }
+    }
     // impl AzWindowState {
 
     #[new]
@@ -13258,12 +15539,10 @@ impl AzLayoutCallbackEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutCallback;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutCallback::Raw(v) => Ok(vec!["Raw".into_py(py), v.clone().into_py(py)]),
             AzLayoutCallback::Marshaled(v) => Ok(vec!["Marshaled".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -13378,6 +15657,26 @@ impl AzCallbackInfo {
             mem::transmute(self),
         )) }
     }
+    fn get_user_event(&self) -> Option<AzRefAny> {
+        let m: AzOptionRefAny = unsafe { mem::transmute(crate::AzCallbackInfo_getUserEvent(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionRefAny::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionRefAny::None => None,
+        }
+
+    }
+    fn get_window_data(&self) -> Option<AzRefAny> {
+        let m: AzOptionRefAny = unsafe { mem::transmute(crate::AzCallbackInfo_getWindowData(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionRefAny::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionRefAny::None => None,
+        }
+
+    }
     fn get_current_keyboard_state(&self) -> AzKeyboardState {
         unsafe { mem::transmute(crate::AzCallbackInfo_getCurrentKeyboardState(
             mem::transmute(self),
@@ -13626,6 +15925,38 @@ impl AzCallbackInfo {
             mem::transmute(new_property),
         )) }
     }
+    fn add_class(&mut self, node_id: AzDomNodeId, class: String) -> () {
+        let class = pystring_to_azstring(&class);
+        unsafe { mem::transmute(crate::AzCallbackInfo_addClass(
+            mem::transmute(self),
+            mem::transmute(node_id),
+            mem::transmute(class),
+        )) }
+    }
+    fn remove_class(&mut self, node_id: AzDomNodeId, class: String) -> () {
+        let class = pystring_to_azstring(&class);
+        unsafe { mem::transmute(crate::AzCallbackInfo_removeClass(
+            mem::transmute(self),
+            mem::transmute(node_id),
+            mem::transmute(class),
+        )) }
+    }
+    fn toggle_class(&mut self, node_id: AzDomNodeId, class: String) -> () {
+        let class = pystring_to_azstring(&class);
+        unsafe { mem::transmute(crate::AzCallbackInfo_toggleClass(
+            mem::transmute(self),
+            mem::transmute(node_id),
+            mem::transmute(class),
+        )) }
+    }
+    fn set_node_state_flag(&mut self, node_id: AzDomNodeId, flag: AzNodeStateFlagEnumWrapper, value: bool) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_setNodeStateFlag(
+            mem::transmute(self),
+            mem::transmute(node_id),
+            mem::transmute(flag),
+            mem::transmute(value),
+        )) }
+    }
     fn set_scroll_position(&mut self, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) -> () {
         unsafe { mem::transmute(crate::AzCallbackInfo_setScrollPosition(
             mem::transmute(self),
@@ -13641,6 +15972,13 @@ impl AzCallbackInfo {
             mem::transmute(string),
         )) }
     }
+    fn set_text_underlines(&mut self, node_id: AzDomNodeId, underlines: AzTextUnderlineVec) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_setTextUnderlines(
+            mem::transmute(self),
+            mem::transmute(node_id),
+            mem::transmute(underlines),
+        )) }
+    }
     fn add_image(&mut self, id: String, image: AzImageRef) -> () {
         let id = pystring_to_azstring(&id);
         unsafe { mem::transmute(crate::AzCallbackInfo_addImage(
@@ -13719,6 +16057,25 @@ impl AzCallbackInfo {
         }
 
     }
+    fn start_caret_animation(&mut self, caret_node: AzDomNodeId, animation: AzCaretAnimationEnumWrapper) -> Option<AzTimerId> {
+        let m: AzOptionTimerId = unsafe { mem::transmute(crate::AzCallbackInfo_startCaretAnimation(
+            mem::transmute(self),
+            mem::transmute(caret_node),
+            mem::transmute(animation),
+        )) };
+        match m {
+            AzOptionTimerId::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionTimerId::None => None,
+        }
+
+    }
+    fn animate_window_resize(&mut self, target_size: AzLogicalSize, duration: AzDurationEnumWrapper) -> AzTimerId {
+        unsafe { mem::transmute(crate::AzCallbackInfo_animateWindowResize(
+            mem::transmute(self),
+            mem::transmute(target_size),
+            mem::transmute(duration),
+        )) }
+    }
     fn stop_timer(&mut self, timer_id: AzTimerId) -> bool {
         unsafe { mem::transmute(crate::AzCallbackInfo_stopTimer(
             mem::transmute(self),
@@ -13738,6 +16095,127 @@ impl AzCallbackInfo {
             mem::transmute(thread_id),
         )) }
     }
+    fn get_system_idle_time(&self) -> Option<AzDurationEnumWrapper> {
+        let m: AzOptionDuration = unsafe { mem::transmute(crate::AzCallbackInfo_getSystemIdleTime(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionDuration::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionDuration::None => None,
+        }
+
+    }
+    fn keycode_to_char(&self, virtual_keycode: AzVirtualKeyCodeEnumWrapper, modifiers: AzKeyboardModifiers) -> Option<u32> {
+        let m: AzOptionChar = unsafe { mem::transmute(crate::AzCallbackInfo_keycodeToChar(
+            mem::transmute(self),
+            mem::transmute(virtual_keycode),
+            mem::transmute(modifiers),
+        )) };
+        match m {
+            AzOptionChar::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionChar::None => None,
+        }
+
+    }
+    fn get_log_lines(&self) -> AzStringVec {
+        unsafe { mem::transmute(crate::AzCallbackInfo_getLogLines(
+            mem::transmute(self),
+        )) }
+    }
+    fn set_log_level(&self, subsystem: String, level: AzAppLogLevelEnumWrapper) -> () {
+        let subsystem = pystring_to_azstring(&subsystem);
+        unsafe { mem::transmute(crate::AzCallbackInfo_setLogLevel(
+            mem::transmute(self),
+            mem::transmute(subsystem),
+            mem::transmute(level),
+        )) }
+    }
+    fn get_node_rect(&self, node_id: AzDomNodeId) -> Option<AzNodeRect> {
+        let m: AzOptionNodeRect = unsafe { mem::transmute(crate::AzCallbackInfo_getNodeRect(
+            mem::transmute(self),
+            mem::transmute(node_id),
+        )) };
+        match m {
+            AzOptionNodeRect::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionNodeRect::None => None,
+        }
+
+    }
+    fn get_text_layout(&self, node_id: AzDomNodeId) -> Option<AzTextLayout> {
+        let m: AzOptionTextLayout = unsafe { mem::transmute(crate::AzCallbackInfo_getTextLayout(
+            mem::transmute(self),
+            mem::transmute(node_id),
+        )) };
+        match m {
+            AzOptionTextLayout::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionTextLayout::None => None,
+        }
+
+    }
+    fn get_scroll_info(&self, node_id: AzDomNodeId) -> Option<AzScrollInfo> {
+        let m: AzOptionScrollInfo = unsafe { mem::transmute(crate::AzCallbackInfo_getScrollInfo(
+            mem::transmute(self),
+            mem::transmute(node_id),
+        )) };
+        match m {
+            AzOptionScrollInfo::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionScrollInfo::None => None,
+        }
+
+    }
+    fn add_shortcut(&mut self, shortcut: AzShortcut) -> Option<AzShortcutConflict> {
+        let m: AzOptionShortcutConflict = unsafe { mem::transmute(crate::AzCallbackInfo_addShortcut(
+            mem::transmute(self),
+            mem::transmute(shortcut),
+        )) };
+        match m {
+            AzOptionShortcutConflict::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionShortcutConflict::None => None,
+        }
+
+    }
+    fn remove_shortcut(&mut self, keys: AzVirtualKeyCodeCombo) -> bool {
+        unsafe { mem::transmute(crate::AzCallbackInfo_removeShortcut(
+            mem::transmute(self),
+            mem::transmute(keys),
+        )) }
+    }
+    fn get_shortcut_conflicts(&self) -> AzShortcutConflictVec {
+        unsafe { mem::transmute(crate::AzCallbackInfo_getShortcutConflicts(
+            mem::transmute(self),
+        )) }
+    }
+    fn announce(&mut self, text: String, politeness: AzPolitenessEnumWrapper) -> () {
+        let text = pystring_to_azstring(&text);
+        unsafe { mem::transmute(crate::AzCallbackInfo_announce(
+            mem::transmute(self),
+            mem::transmute(text),
+            mem::transmute(politeness),
+        )) }
+    }
+    fn trigger_haptic(&mut self, kind: AzHapticKindEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_triggerHaptic(
+            mem::transmute(self),
+            mem::transmute(kind),
+        )) }
+    }
+    fn crossfade_subtree(&mut self, node: AzDomNodeId, duration: AzDurationEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_crossfadeSubtree(
+            mem::transmute(self),
+            mem::transmute(node),
+            mem::transmute(duration),
+        )) }
+    }
+    fn render_subtree_to_frames(&mut self, node: AzDomNodeId, duration: AzDurationEnumWrapper, fps: u32, output_path: String) -> () {
+        let output_path = pystring_to_azstring(&output_path);
+        unsafe { mem::transmute(crate::AzCallbackInfo_renderSubtreeToFrames(
+            mem::transmute(self),
+            mem::transmute(node),
+            mem::transmute(duration),
+            mem::transmute(fps),
+            mem::transmute(output_path),
+        )) }
+    }
 }
 
 #[pyproto]
@@ -13886,14 +16364,12 @@ impl AzPositionInfoEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzPositionInfo;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzPositionInfo::Static(v) => Ok(vec!["Static".into_py(py), v.clone().into_py(py)]),
             AzPositionInfo::Fixed(v) => Ok(vec!["Fixed".into_py(py), v.clone().into_py(py)]),
             AzPositionInfo::Absolute(v) => Ok(vec!["Absolute".into_py(py), v.clone().into_py(py)]),
             AzPositionInfo::Relative(v) => Ok(vec!["Relative".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -13907,6 +16383,54 @@ impl PyObjectProtocol for AzPositionInfoEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzNodeRect {
+    #[new]
+    fn __new__(margin_box: AzLogicalRect, border_box: AzLogicalRect, padding_box: AzLogicalRect, content_box: AzLogicalRect) -> Self {
+        Self {
+            margin_box,
+            border_box,
+            padding_box,
+            content_box,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeRect {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::ui_solver::NodeRect = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::ui_solver::NodeRect = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzScrollInfo {
+    #[new]
+    fn __new__(scroll_position: AzLogicalPosition, viewport: AzLogicalRect, content_size: AzLogicalSize, max_scroll: AzLogicalSize) -> Self {
+        Self {
+            scroll_position,
+            viewport,
+            content_size,
+            max_scroll,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzScrollInfo {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::ScrollInfo = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::ScrollInfo = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzPositionInfoInner {
     #[new]
@@ -13999,6 +16523,28 @@ impl PyObjectProtocol for AzInlineText {
     }
 }
 
+#[pymethods]
+impl AzTextLayout {
+    #[new]
+    fn __new__(bounds: AzLogicalRect, inline_text: AzInlineText) -> Self {
+        Self {
+            bounds,
+            inline_text,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextLayout {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextLayout = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextLayout = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzInlineLine {
     #[new]
@@ -14035,14 +16581,12 @@ impl AzInlineWordEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzInlineWord;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzInlineWord::Tab => Ok(vec!["Tab".into_py(py), ().into_py(py)]),
             AzInlineWord::Return => Ok(vec!["Return".into_py(py), ().into_py(py)]),
             AzInlineWord::Space => Ok(vec!["Space".into_py(py), ().into_py(py)]),
             AzInlineWord::Word(v) => Ok(vec!["Word".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -14101,6 +16645,60 @@ impl PyObjectProtocol for AzInlineGlyph {
     }
 }
 
+#[pymethods]
+impl AzTextUnderline {
+    #[new]
+    fn __new__(start: usize, end: usize, color: AzColorU, style: AzTextUnderlineStyleEnumWrapper) -> Self {
+        Self {
+            start,
+            end,
+            color,
+            style,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextUnderline {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextUnderline = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextUnderline = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextUnderlineStyleEnumWrapper {
+    #[classattr]
+    fn Solid() -> AzTextUnderlineStyleEnumWrapper { AzTextUnderlineStyleEnumWrapper { inner: AzTextUnderlineStyle::Solid } }
+    #[classattr]
+    fn Dotted() -> AzTextUnderlineStyleEnumWrapper { AzTextUnderlineStyleEnumWrapper { inner: AzTextUnderlineStyle::Dotted } }
+    #[classattr]
+    fn Wavy() -> AzTextUnderlineStyleEnumWrapper { AzTextUnderlineStyleEnumWrapper { inner: AzTextUnderlineStyle::Wavy } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextUnderlineStyleEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextUnderlineStyle = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextUnderlineStyle = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzTextUnderlineStyleEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzInlineTextHit {
     #[new]
@@ -14151,23 +16749,24 @@ impl AzFocusTargetEnumWrapper {
     fn First() -> AzFocusTargetEnumWrapper { AzFocusTargetEnumWrapper { inner: AzFocusTarget::First } }
     #[classattr]
     fn Last() -> AzFocusTargetEnumWrapper { AzFocusTargetEnumWrapper { inner: AzFocusTarget::Last } }
+    #[staticmethod]
+    fn Direction(v: AzFocusDirectionEnumWrapper) -> AzFocusTargetEnumWrapper { AzFocusTargetEnumWrapper { inner: AzFocusTarget::Direction(unsafe { mem::transmute(v) }) } }
     #[classattr]
     fn NoFocus() -> AzFocusTargetEnumWrapper { AzFocusTargetEnumWrapper { inner: AzFocusTarget::NoFocus } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzFocusTarget;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzFocusTarget::Id(v) => Ok(vec!["Id".into_py(py), v.clone().into_py(py)]),
             AzFocusTarget::Path(v) => Ok(vec!["Path".into_py(py), v.clone().into_py(py)]),
             AzFocusTarget::Previous => Ok(vec!["Previous".into_py(py), ().into_py(py)]),
             AzFocusTarget::Next => Ok(vec!["Next".into_py(py), ().into_py(py)]),
             AzFocusTarget::First => Ok(vec!["First".into_py(py), ().into_py(py)]),
             AzFocusTarget::Last => Ok(vec!["Last".into_py(py), ().into_py(py)]),
+            AzFocusTarget::Direction(v) => Ok(vec!["Direction".into_py(py), { let m: &AzFocusDirectionEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzFocusTarget::NoFocus => Ok(vec!["NoFocus".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -14248,6 +16847,34 @@ impl PyObjectProtocol for AzAnimation {
     }
 }
 
+#[pymethods]
+impl AzCaretAnimationEnumWrapper {
+    #[classattr]
+    fn Blink() -> AzCaretAnimationEnumWrapper { AzCaretAnimationEnumWrapper { inner: AzCaretAnimation::Blink } }
+    #[classattr]
+    fn Fade() -> AzCaretAnimationEnumWrapper { AzCaretAnimationEnumWrapper { inner: AzCaretAnimation::Fade } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCaretAnimationEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::CaretAnimation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::CaretAnimation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzCaretAnimationEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzAnimationRepeatEnumWrapper {
     #[classattr]
@@ -14288,12 +16915,10 @@ impl AzAnimationRepeatCountEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzAnimationRepeatCount;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzAnimationRepeatCount::Times(v) => Ok(vec!["Times".into_py(py), v.into_py(py)]),
             AzAnimationRepeatCount::Infinite => Ok(vec!["Infinite".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -14325,16 +16950,14 @@ impl AzAnimationEasingEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzAnimationEasing;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzAnimationEasing::Ease => Ok(vec!["Ease".into_py(py), ().into_py(py)]),
             AzAnimationEasing::Linear => Ok(vec!["Linear".into_py(py), ().into_py(py)]),
             AzAnimationEasing::EaseIn => Ok(vec!["EaseIn".into_py(py), ().into_py(py)]),
             AzAnimationEasing::EaseOut => Ok(vec!["EaseOut".into_py(py), ().into_py(py)]),
             AzAnimationEasing::EaseInOut => Ok(vec!["EaseInOut".into_py(py), ().into_py(py)]),
             AzAnimationEasing::CubicBezier(v) => Ok(vec!["CubicBezier".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -14376,6 +16999,165 @@ impl PyObjectProtocol for AzIFrameCallbackInfo {
     }
 }
 
+#[pymethods]
+impl AzMeasureCallback {
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzMeasureCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::MeasureCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::MeasureCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzMeasureCallbackInfo {
+    #[new]
+    fn __new__(available_size: AzLogicalSize, width_is_final: bool) -> Self {
+        Self {
+            available_size,
+            width_is_final,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzMeasureCallbackInfo {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::MeasureCallbackInfo = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::MeasureCallbackInfo = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzArrangeCallback {
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzArrangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::ArrangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::ArrangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzArrangeCallbackInfo {
+    #[new]
+    fn __new__(bounds: AzNodeRect) -> Self {
+        Self {
+            bounds,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzArrangeCallbackInfo {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::ArrangeCallbackInfo = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::ArrangeCallbackInfo = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzPaintCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzPaintCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::PaintCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::PaintCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzPaintCallbackInfo {
+    #[new]
+    fn __new__(size: AzLogicalSize) -> Self {
+        Self {
+            size,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzPaintCallbackInfo {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::PaintCallbackInfo = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::PaintCallbackInfo = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzPaintPrimitiveEnumWrapper {
+    #[staticmethod]
+    fn Rect(v: AzPaintRect) -> AzPaintPrimitiveEnumWrapper { AzPaintPrimitiveEnumWrapper { inner: AzPaintPrimitive::Rect(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzPaintPrimitive;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzPaintPrimitive::Rect(v) => Ok(vec!["Rect".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzPaintPrimitiveEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::PaintPrimitive = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::PaintPrimitive = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzPaintRect {
+    #[new]
+    fn __new__(bounds: AzLogicalRect, color: AzColorU, border_radius: f32) -> Self {
+        Self {
+            bounds,
+            color,
+            border_radius,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzPaintRect {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::PaintRect = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::PaintRect = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzIFrameCallbackReturn {
     #[new]
@@ -14696,6 +17478,11 @@ impl AzLayoutCallbackInfo {
         }
 
     }
+    fn get_system_style(&self) -> AzSystemStyle {
+        unsafe { mem::transmute(crate::AzLayoutCallbackInfo_getSystemStyle(
+            mem::transmute(self),
+        )) }
+    }
 }
 
 #[pyproto]
@@ -14741,6 +17528,14 @@ impl AzDom {
             mem::transmute(image),
         )) }
     }
+    #[staticmethod]
+    fn overlay(anchor: String, placement: AzOverlayPlacementEnumWrapper) -> AzDom {
+        let anchor = pystring_to_azstring(&anchor);
+        unsafe { mem::transmute(crate::AzDom_overlay(
+            mem::transmute(anchor),
+            mem::transmute(placement),
+        )) }
+    }
     fn set_node_type(&mut self, node_type: AzNodeTypeEnumWrapper) -> () {
         unsafe { mem::transmute(crate::AzDom_setNodeType(
             mem::transmute(self),
@@ -15005,6 +17800,58 @@ impl AzDom {
             mem::transmute(context_menu),
         )) }
     }
+    fn set_focus_navigation(&mut self, focus_navigation: AzFocusNavigation) -> () {
+        unsafe { mem::transmute(crate::AzDom_setFocusNavigation(
+            mem::transmute(self),
+            mem::transmute(focus_navigation),
+        )) }
+    }
+    fn with_focus_navigation(&mut self, focus_navigation: AzFocusNavigation) -> AzDom {
+        unsafe { mem::transmute(crate::AzDom_withFocusNavigation(
+            mem::transmute(self),
+            mem::transmute(focus_navigation),
+        )) }
+    }
+    fn set_lang(&mut self, lang: String) -> () {
+        let lang = pystring_to_azstring(&lang);
+        unsafe { mem::transmute(crate::AzDom_setLang(
+            mem::transmute(self),
+            mem::transmute(lang),
+        )) }
+    }
+    fn with_lang(&mut self, lang: String) -> AzDom {
+        let lang = pystring_to_azstring(&lang);
+        unsafe { mem::transmute(crate::AzDom_withLang(
+            mem::transmute(self),
+            mem::transmute(lang),
+        )) }
+    }
+    fn set_input_mode(&mut self, input_mode: AzInputModeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzDom_setInputMode(
+            mem::transmute(self),
+            mem::transmute(input_mode),
+        )) }
+    }
+    fn with_input_mode(&mut self, input_mode: AzInputModeEnumWrapper) -> AzDom {
+        unsafe { mem::transmute(crate::AzDom_withInputMode(
+            mem::transmute(self),
+            mem::transmute(input_mode),
+        )) }
+    }
+    fn set_state_flag(&mut self, flag: AzNodeStateFlagEnumWrapper, enabled: bool) -> () {
+        unsafe { mem::transmute(crate::AzDom_setStateFlag(
+            mem::transmute(self),
+            mem::transmute(flag),
+            mem::transmute(enabled),
+        )) }
+    }
+    fn with_state_flag(&mut self, flag: AzNodeStateFlagEnumWrapper, enabled: bool) -> AzDom {
+        unsafe { mem::transmute(crate::AzDom_withStateFlag(
+            mem::transmute(self),
+            mem::transmute(flag),
+            mem::transmute(enabled),
+        )) }
+    }
     fn hash(&self) -> u64 {
         unsafe { mem::transmute(crate::AzDom_hash(
             mem::transmute(self),
@@ -15128,10 +17975,11 @@ impl PyObjectProtocol for AzDom {
 #[pymethods]
 impl AzIFrameNode {
     #[new]
-    fn __new__(callback: AzIFrameCallback, data: AzRefAny) -> Self {
+    fn __new__(callback: AzIFrameCallback, data: AzRefAny, reinvoke_threshold: AzFloatValue) -> Self {
         Self {
             callback,
             data,
+            reinvoke_threshold,
         }
     }
 
@@ -15147,6 +17995,49 @@ impl PyObjectProtocol for AzIFrameNode {
     }
 }
 
+#[pymethods]
+impl AzCustomLayoutNode {
+    #[new]
+    fn __new__(measure: AzMeasureCallback, arrange: AzArrangeCallback, paint: AzOptionPaintCallbackEnumWrapper, data: AzRefAny) -> Self {
+        Self {
+            measure,
+            arrange,
+            paint,
+            data,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCustomLayoutNode {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::CustomLayoutNode = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::CustomLayoutNode = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCustomNodeType {
+    fn register(self) -> () {
+        unsafe { mem::transmute(crate::AzCustomNodeType_register(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCustomNodeType {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::CustomNodeType = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::CustomNodeType = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCallbackData {
     #[new]
@@ -15337,11 +18228,43 @@ impl AzNodeData {
             mem::transmute(context_menu),
         )) }
     }
+    fn set_focus_navigation(&mut self, focus_navigation: AzFocusNavigation) -> () {
+        unsafe { mem::transmute(crate::AzNodeData_setFocusNavigation(
+            mem::transmute(self),
+            mem::transmute(focus_navigation),
+        )) }
+    }
     fn hash(&self) -> u64 {
         unsafe { mem::transmute(crate::AzNodeData_hash(
             mem::transmute(self),
         )) }
     }
+    fn set_overlay(&mut self, overlay: AzOverlayInfo) -> () {
+        unsafe { mem::transmute(crate::AzNodeData_setOverlay(
+            mem::transmute(self),
+            mem::transmute(overlay),
+        )) }
+    }
+    fn set_lang(&mut self, lang: String) -> () {
+        let lang = pystring_to_azstring(&lang);
+        unsafe { mem::transmute(crate::AzNodeData_setLang(
+            mem::transmute(self),
+            mem::transmute(lang),
+        )) }
+    }
+    fn set_input_mode(&mut self, input_mode: AzInputModeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzNodeData_setInputMode(
+            mem::transmute(self),
+            mem::transmute(input_mode),
+        )) }
+    }
+    fn set_state_flag(&mut self, flag: AzNodeStateFlagEnumWrapper, enabled: bool) -> () {
+        unsafe { mem::transmute(crate::AzNodeData_setStateFlag(
+            mem::transmute(self),
+            mem::transmute(flag),
+            mem::transmute(enabled),
+        )) }
+    }
 // impl NodeData {
 
     #[staticmethod]
@@ -15449,20 +18372,21 @@ impl AzNodeTypeEnumWrapper {
     fn Image(v: AzImageRef) -> AzNodeTypeEnumWrapper { AzNodeTypeEnumWrapper { inner: AzNodeType::Image(v) } }
     #[staticmethod]
     fn IFrame(v: AzIFrameNode) -> AzNodeTypeEnumWrapper { AzNodeTypeEnumWrapper { inner: AzNodeType::IFrame(v) } }
+    #[staticmethod]
+    fn CustomLayout(v: AzCustomLayoutNode) -> AzNodeTypeEnumWrapper { AzNodeTypeEnumWrapper { inner: AzNodeType::CustomLayout(v) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzNodeType;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzNodeType::Body => Ok(vec!["Body".into_py(py), ().into_py(py)]),
             AzNodeType::Div => Ok(vec!["Div".into_py(py), ().into_py(py)]),
             AzNodeType::Br => Ok(vec!["Br".into_py(py), ().into_py(py)]),
             AzNodeType::Text(v) => Ok(vec!["Text".into_py(py), v.clone().into_py(py)]),
             AzNodeType::Image(v) => Ok(vec!["Image".into_py(py), v.clone().into_py(py)]),
             AzNodeType::IFrame(v) => Ok(vec!["IFrame".into_py(py), v.clone().into_py(py)]),
-        }
+            AzNodeType::CustomLayout(v) => Ok(vec!["CustomLayout".into_py(py), v.clone().into_py(py)]),
+        })
     }
 }
 
@@ -15497,6 +18421,12 @@ impl AzOnEnumWrapper {
     #[classattr]
     fn RightMouseUp() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::RightMouseUp } }
     #[classattr]
+    fn DoubleClick() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::DoubleClick } }
+    #[classattr]
+    fn TripleClick() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::TripleClick } }
+    #[classattr]
+    fn HoverIntent() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::HoverIntent } }
+    #[classattr]
     fn MouseEnter() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::MouseEnter } }
     #[classattr]
     fn MouseLeave() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::MouseLeave } }
@@ -15518,6 +18448,36 @@ impl AzOnEnumWrapper {
     fn FocusReceived() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::FocusReceived } }
     #[classattr]
     fn FocusLost() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::FocusLost } }
+    #[classattr]
+    fn UserIdle() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::UserIdle } }
+    #[classattr]
+    fn UserActive() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::UserActive } }
+    #[classattr]
+    fn SessionEnding() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::SessionEnding } }
+    #[classattr]
+    fn FullscreenEntered() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::FullscreenEntered } }
+    #[classattr]
+    fn FullscreenExited() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::FullscreenExited } }
+    #[classattr]
+    fn WindowResized() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::WindowResized } }
+    #[classattr]
+    fn WindowMoved() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::WindowMoved } }
+    #[classattr]
+    fn WindowFocusReceived() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::WindowFocusReceived } }
+    #[classattr]
+    fn WindowFocusLost() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::WindowFocusLost } }
+    #[classattr]
+    fn DpiChanged() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::DpiChanged } }
+    #[classattr]
+    fn Minimized() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::Minimized } }
+    #[classattr]
+    fn Maximized() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::Maximized } }
+    #[classattr]
+    fn Restored() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::Restored } }
+    #[classattr]
+    fn UserEvent() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::UserEvent } }
+    #[classattr]
+    fn InputModalityChanged() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::InputModalityChanged } }
 }
 
 #[pyproto]
@@ -15558,16 +18518,14 @@ impl AzEventFilterEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzEventFilter;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzEventFilter::Hover(v) => Ok(vec!["Hover".into_py(py), { let m: &AzHoverEventFilterEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzEventFilter::Not(v) => Ok(vec!["Not".into_py(py), { let m: &AzNotEventFilterEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzEventFilter::Focus(v) => Ok(vec!["Focus".into_py(py), { let m: &AzFocusEventFilterEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzEventFilter::Window(v) => Ok(vec!["Window".into_py(py), { let m: &AzWindowEventFilterEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzEventFilter::Component(v) => Ok(vec!["Component".into_py(py), { let m: &AzComponentEventFilterEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzEventFilter::Application(v) => Ok(vec!["Application".into_py(py), { let m: &AzApplicationEventFilterEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -15602,6 +18560,12 @@ impl AzHoverEventFilterEnumWrapper {
     #[classattr]
     fn MiddleMouseUp() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::MiddleMouseUp } }
     #[classattr]
+    fn DoubleClick() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::DoubleClick } }
+    #[classattr]
+    fn TripleClick() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::TripleClick } }
+    #[classattr]
+    fn HoverIntent() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::HoverIntent } }
+    #[classattr]
     fn MouseEnter() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::MouseEnter } }
     #[classattr]
     fn MouseLeave() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::MouseLeave } }
@@ -15674,6 +18638,10 @@ impl AzFocusEventFilterEnumWrapper {
     #[classattr]
     fn MiddleMouseUp() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::MiddleMouseUp } }
     #[classattr]
+    fn DoubleClick() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::DoubleClick } }
+    #[classattr]
+    fn TripleClick() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::TripleClick } }
+    #[classattr]
     fn MouseEnter() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::MouseEnter } }
     #[classattr]
     fn MouseLeave() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::MouseLeave } }
@@ -15725,12 +18693,10 @@ impl AzNotEventFilterEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzNotEventFilter;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzNotEventFilter::Hover(v) => Ok(vec!["Hover".into_py(py), { let m: &AzHoverEventFilterEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzNotEventFilter::Focus(v) => Ok(vec!["Focus".into_py(py), { let m: &AzFocusEventFilterEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -15765,6 +18731,12 @@ impl AzWindowEventFilterEnumWrapper {
     #[classattr]
     fn MiddleMouseUp() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::MiddleMouseUp } }
     #[classattr]
+    fn DoubleClick() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::DoubleClick } }
+    #[classattr]
+    fn TripleClick() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::TripleClick } }
+    #[classattr]
+    fn HoverIntent() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::HoverIntent } }
+    #[classattr]
     fn MouseEnter() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::MouseEnter } }
     #[classattr]
     fn MouseLeave() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::MouseLeave } }
@@ -15806,6 +18778,32 @@ impl AzWindowEventFilterEnumWrapper {
     fn CloseRequested() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::CloseRequested } }
     #[classattr]
     fn ThemeChanged() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::ThemeChanged } }
+    #[classattr]
+    fn WindowFocusReceived() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::WindowFocusReceived } }
+    #[classattr]
+    fn WindowFocusLost() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::WindowFocusLost } }
+    #[classattr]
+    fn UserIdle() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::UserIdle } }
+    #[classattr]
+    fn UserActive() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::UserActive } }
+    #[classattr]
+    fn SessionEnding() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::SessionEnding } }
+    #[classattr]
+    fn FullscreenEntered() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::FullscreenEntered } }
+    #[classattr]
+    fn FullscreenExited() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::FullscreenExited } }
+    #[classattr]
+    fn DpiChanged() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::DpiChanged } }
+    #[classattr]
+    fn Minimized() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::Minimized } }
+    #[classattr]
+    fn Maximized() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::Maximized } }
+    #[classattr]
+    fn Restored() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::Restored } }
+    #[classattr]
+    fn UserEvent() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::UserEvent } }
+    #[classattr]
+    fn InputModalityChanged() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::InputModalityChanged } }
 }
 
 #[pyproto]
@@ -15916,6 +18914,206 @@ impl PyObjectProtocol for AzAccessibilityInfo {
     }
 }
 
+#[pymethods]
+impl AzFocusDirectionEnumWrapper {
+    #[classattr]
+    fn Up() -> AzFocusDirectionEnumWrapper { AzFocusDirectionEnumWrapper { inner: AzFocusDirection::Up } }
+    #[classattr]
+    fn Down() -> AzFocusDirectionEnumWrapper { AzFocusDirectionEnumWrapper { inner: AzFocusDirection::Down } }
+    #[classattr]
+    fn Left() -> AzFocusDirectionEnumWrapper { AzFocusDirectionEnumWrapper { inner: AzFocusDirection::Left } }
+    #[classattr]
+    fn Right() -> AzFocusDirectionEnumWrapper { AzFocusDirectionEnumWrapper { inner: AzFocusDirection::Right } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzFocusDirectionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::FocusDirection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::FocusDirection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzFocusDirectionEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzInputModeEnumWrapper {
+    #[classattr]
+    fn Text() -> AzInputModeEnumWrapper { AzInputModeEnumWrapper { inner: AzInputMode::Text } }
+    #[classattr]
+    fn None() -> AzInputModeEnumWrapper { AzInputModeEnumWrapper { inner: AzInputMode::None } }
+    #[classattr]
+    fn Numeric() -> AzInputModeEnumWrapper { AzInputModeEnumWrapper { inner: AzInputMode::Numeric } }
+    #[classattr]
+    fn Decimal() -> AzInputModeEnumWrapper { AzInputModeEnumWrapper { inner: AzInputMode::Decimal } }
+    #[classattr]
+    fn Tel() -> AzInputModeEnumWrapper { AzInputModeEnumWrapper { inner: AzInputMode::Tel } }
+    #[classattr]
+    fn Email() -> AzInputModeEnumWrapper { AzInputModeEnumWrapper { inner: AzInputMode::Email } }
+    #[classattr]
+    fn Url() -> AzInputModeEnumWrapper { AzInputModeEnumWrapper { inner: AzInputMode::Url } }
+    #[classattr]
+    fn Search() -> AzInputModeEnumWrapper { AzInputModeEnumWrapper { inner: AzInputMode::Search } }
+    #[classattr]
+    fn Password() -> AzInputModeEnumWrapper { AzInputModeEnumWrapper { inner: AzInputMode::Password } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzInputModeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::InputMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::InputMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzInputModeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzNodeStateFlagEnumWrapper {
+    #[classattr]
+    fn Disabled() -> AzNodeStateFlagEnumWrapper { AzNodeStateFlagEnumWrapper { inner: AzNodeStateFlag::Disabled } }
+    #[classattr]
+    fn Checked() -> AzNodeStateFlagEnumWrapper { AzNodeStateFlagEnumWrapper { inner: AzNodeStateFlag::Checked } }
+    #[classattr]
+    fn Selected() -> AzNodeStateFlagEnumWrapper { AzNodeStateFlagEnumWrapper { inner: AzNodeStateFlag::Selected } }
+    #[classattr]
+    fn Indeterminate() -> AzNodeStateFlagEnumWrapper { AzNodeStateFlagEnumWrapper { inner: AzNodeStateFlag::Indeterminate } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNodeStateFlagEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::NodeStateFlag = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::NodeStateFlag = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzNodeStateFlagEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzFocusNavigation {
+    #[new]
+    fn __new__(up: AzOptionStringEnumWrapper, down: AzOptionStringEnumWrapper, left: AzOptionStringEnumWrapper, right: AzOptionStringEnumWrapper) -> Self {
+        Self {
+            up,
+            down,
+            left,
+            right,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzFocusNavigation {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::FocusNavigation = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::FocusNavigation = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOverlayPlacementEnumWrapper {
+    #[classattr]
+    fn TopStart() -> AzOverlayPlacementEnumWrapper { AzOverlayPlacementEnumWrapper { inner: AzOverlayPlacement::TopStart } }
+    #[classattr]
+    fn Top() -> AzOverlayPlacementEnumWrapper { AzOverlayPlacementEnumWrapper { inner: AzOverlayPlacement::Top } }
+    #[classattr]
+    fn TopEnd() -> AzOverlayPlacementEnumWrapper { AzOverlayPlacementEnumWrapper { inner: AzOverlayPlacement::TopEnd } }
+    #[classattr]
+    fn BottomStart() -> AzOverlayPlacementEnumWrapper { AzOverlayPlacementEnumWrapper { inner: AzOverlayPlacement::BottomStart } }
+    #[classattr]
+    fn Bottom() -> AzOverlayPlacementEnumWrapper { AzOverlayPlacementEnumWrapper { inner: AzOverlayPlacement::Bottom } }
+    #[classattr]
+    fn BottomEnd() -> AzOverlayPlacementEnumWrapper { AzOverlayPlacementEnumWrapper { inner: AzOverlayPlacement::BottomEnd } }
+    #[classattr]
+    fn LeftStart() -> AzOverlayPlacementEnumWrapper { AzOverlayPlacementEnumWrapper { inner: AzOverlayPlacement::LeftStart } }
+    #[classattr]
+    fn Left() -> AzOverlayPlacementEnumWrapper { AzOverlayPlacementEnumWrapper { inner: AzOverlayPlacement::Left } }
+    #[classattr]
+    fn LeftEnd() -> AzOverlayPlacementEnumWrapper { AzOverlayPlacementEnumWrapper { inner: AzOverlayPlacement::LeftEnd } }
+    #[classattr]
+    fn RightStart() -> AzOverlayPlacementEnumWrapper { AzOverlayPlacementEnumWrapper { inner: AzOverlayPlacement::RightStart } }
+    #[classattr]
+    fn Right() -> AzOverlayPlacementEnumWrapper { AzOverlayPlacementEnumWrapper { inner: AzOverlayPlacement::Right } }
+    #[classattr]
+    fn RightEnd() -> AzOverlayPlacementEnumWrapper { AzOverlayPlacementEnumWrapper { inner: AzOverlayPlacement::RightEnd } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOverlayPlacementEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::OverlayPlacement = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::OverlayPlacement = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzOverlayPlacementEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzOverlayInfo {
+    #[new]
+    fn __new__(anchor: AzString, placement: AzOverlayPlacementEnumWrapper) -> Self {
+        Self {
+            anchor,
+            placement,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOverlayInfo {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::OverlayInfo = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::OverlayInfo = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzAccessibilityRoleEnumWrapper {
     #[classattr]
@@ -16136,13 +19334,11 @@ impl AzTabIndexEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzTabIndex;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzTabIndex::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzTabIndex::OverrideInParent(v) => Ok(vec!["OverrideInParent".into_py(py), v.into_py(py)]),
             AzTabIndex::NoKeyboardFocus => Ok(vec!["NoKeyboardFocus".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -16166,12 +19362,10 @@ impl AzIdOrClassEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzIdOrClass;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzIdOrClass::Id(v) => Ok(vec!["Id".into_py(py), v.clone().into_py(py)]),
             AzIdOrClass::Class(v) => Ok(vec!["Class".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -16199,14 +19393,12 @@ impl AzNodeDataInlineCssPropertyEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzNodeDataInlineCssProperty;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzNodeDataInlineCssProperty::Normal(v) => Ok(vec!["Normal".into_py(py), { let m: &AzCssPropertyEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzNodeDataInlineCssProperty::Active(v) => Ok(vec!["Active".into_py(py), { let m: &AzCssPropertyEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzNodeDataInlineCssProperty::Focus(v) => Ok(vec!["Focus".into_py(py), { let m: &AzCssPropertyEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzNodeDataInlineCssProperty::Hover(v) => Ok(vec!["Hover".into_py(py), { let m: &AzCssPropertyEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -16338,13 +19530,11 @@ impl AzMenuItemEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzMenuItem;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzMenuItem::String(v) => Ok(vec!["String".into_py(py), v.clone().into_py(py)]),
             AzMenuItem::Separator => Ok(vec!["Separator".into_py(py), ().into_py(py)]),
             AzMenuItem::BreakLine => Ok(vec!["BreakLine".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -16438,6 +19628,83 @@ impl PyObjectProtocol for AzMenuCallback {
     }
 }
 
+#[pymethods]
+impl AzShortcut {
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzShortcut {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::Shortcut = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::Shortcut = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzShortcutConflict {
+    #[new]
+    fn __new__(keys: AzVirtualKeyCodeCombo, existing: usize, conflicting: usize) -> Self {
+        Self {
+            keys,
+            existing,
+            conflicting,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzShortcutConflict {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutConflict = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutConflict = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzShortcutMap {
+    #[new]
+    fn new() -> AzShortcutMap {
+        unsafe { mem::transmute(crate::AzShortcutMap_new()) }
+    }
+    fn add(&mut self, shortcut: AzShortcut) -> Option<AzShortcutConflict> {
+        let m: AzOptionShortcutConflict = unsafe { mem::transmute(crate::AzShortcutMap_add(
+            mem::transmute(self),
+            mem::transmute(shortcut),
+        )) };
+        match m {
+            AzOptionShortcutConflict::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionShortcutConflict::None => None,
+        }
+
+    }
+    fn remove(&mut self, keys: AzVirtualKeyCodeCombo) -> bool {
+        unsafe { mem::transmute(crate::AzShortcutMap_remove(
+            mem::transmute(self),
+            mem::transmute(keys),
+        )) }
+    }
+    fn get_conflicts(&self) -> AzShortcutConflictVec {
+        unsafe { mem::transmute(crate::AzShortcutMap_getConflicts(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzShortcutMap {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutMap = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutMap = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzMenuItemIconEnumWrapper {
     #[staticmethod]
@@ -16448,12 +19715,10 @@ impl AzMenuItemIconEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzMenuItemIcon;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzMenuItemIcon::Checkbox(v) => Ok(vec!["Checkbox".into_py(py), v.into_py(py)]),
             AzMenuItemIcon::Image(v) => Ok(vec!["Image".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -16500,10 +19765,11 @@ impl PyObjectProtocol for AzMenuItemStateEnumWrapper {
 #[pymethods]
 impl AzCssRuleBlock {
     #[new]
-    fn __new__(path: AzCssPath, declarations: AzCssDeclarationVec) -> Self {
+    fn __new__(path: AzCssPath, declarations: AzCssDeclarationVec, media: AzCssMediaCondition) -> Self {
         Self {
             path,
             declarations,
+            media,
         }
     }
 
@@ -16519,22 +19785,44 @@ impl PyObjectProtocol for AzCssRuleBlock {
     }
 }
 
+#[pymethods]
+impl AzCssMediaCondition {
+    #[new]
+    fn __new__(matching_styles: u8) -> Self {
+        Self {
+            matching_styles,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaCondition {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaCondition = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaCondition = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssDeclarationEnumWrapper {
     #[staticmethod]
     fn Static(v: AzCssPropertyEnumWrapper) -> AzCssDeclarationEnumWrapper { AzCssDeclarationEnumWrapper { inner: AzCssDeclaration::Static(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn Dynamic(v: AzDynamicCssProperty) -> AzCssDeclarationEnumWrapper { AzCssDeclarationEnumWrapper { inner: AzCssDeclaration::Dynamic(v) } }
+    #[staticmethod]
+    fn System(v: AzSystemCssProperty) -> AzCssDeclarationEnumWrapper { AzCssDeclarationEnumWrapper { inner: AzCssDeclaration::System(v) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssDeclaration;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzCssDeclaration::Static(v) => Ok(vec!["Static".into_py(py), { let m: &AzCssPropertyEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssDeclaration::Dynamic(v) => Ok(vec!["Dynamic".into_py(py), v.clone().into_py(py)]),
-        }
+            AzCssDeclaration::System(v) => Ok(vec!["System".into_py(py), v.clone().into_py(py)]),
+        })
     }
 }
 
@@ -16570,6 +19858,29 @@ impl PyObjectProtocol for AzDynamicCssProperty {
     }
 }
 
+#[pymethods]
+impl AzSystemCssProperty {
+    #[new]
+    fn __new__(key: AzString, value: AzString, default_value: AzCssPropertyEnumWrapper) -> Self {
+        Self {
+            key,
+            value,
+            default_value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSystemCssProperty {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::SystemCssProperty = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::SystemCssProperty = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssPath {
     #[new]
@@ -16611,9 +19922,7 @@ impl AzCssPathSelectorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssPathSelector;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzCssPathSelector::Global => Ok(vec!["Global".into_py(py), ().into_py(py)]),
             AzCssPathSelector::Type(v) => Ok(vec!["Type".into_py(py), { let m: &AzNodeTypeKeyEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssPathSelector::Class(v) => Ok(vec!["Class".into_py(py), v.clone().into_py(py)]),
@@ -16621,7 +19930,7 @@ impl AzCssPathSelectorEnumWrapper {
             AzCssPathSelector::PseudoSelector(v) => Ok(vec!["PseudoSelector".into_py(py), { let m: &AzCssPathPseudoSelectorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssPathSelector::DirectChildren => Ok(vec!["DirectChildren".into_py(py), ().into_py(py)]),
             AzCssPathSelector::Children => Ok(vec!["Children".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -16685,20 +19994,36 @@ impl AzCssPathPseudoSelectorEnumWrapper {
     fn Active() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::Active } }
     #[classattr]
     fn Focus() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::Focus } }
+    #[classattr]
+    fn Disabled() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::Disabled } }
+    #[classattr]
+    fn Checked() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::Checked } }
+    #[classattr]
+    fn Selected() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::Selected } }
+    #[classattr]
+    fn Indeterminate() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::Indeterminate } }
+    #[classattr]
+    fn Before() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::Before } }
+    #[classattr]
+    fn After() -> AzCssPathPseudoSelectorEnumWrapper { AzCssPathPseudoSelectorEnumWrapper { inner: AzCssPathPseudoSelector::After } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssPathPseudoSelector;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzCssPathPseudoSelector::First => Ok(vec!["First".into_py(py), ().into_py(py)]),
             AzCssPathPseudoSelector::Last => Ok(vec!["Last".into_py(py), ().into_py(py)]),
             AzCssPathPseudoSelector::NthChild(v) => Ok(vec!["NthChild".into_py(py), { let m: &AzCssNthChildSelectorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssPathPseudoSelector::Hover => Ok(vec!["Hover".into_py(py), ().into_py(py)]),
             AzCssPathPseudoSelector::Active => Ok(vec!["Active".into_py(py), ().into_py(py)]),
             AzCssPathPseudoSelector::Focus => Ok(vec!["Focus".into_py(py), ().into_py(py)]),
-        }
+            AzCssPathPseudoSelector::Disabled => Ok(vec!["Disabled".into_py(py), ().into_py(py)]),
+            AzCssPathPseudoSelector::Checked => Ok(vec!["Checked".into_py(py), ().into_py(py)]),
+            AzCssPathPseudoSelector::Selected => Ok(vec!["Selected".into_py(py), ().into_py(py)]),
+            AzCssPathPseudoSelector::Indeterminate => Ok(vec!["Indeterminate".into_py(py), ().into_py(py)]),
+            AzCssPathPseudoSelector::Before => Ok(vec!["Before".into_py(py), ().into_py(py)]),
+            AzCssPathPseudoSelector::After => Ok(vec!["After".into_py(py), ().into_py(py)]),
+        })
     }
 }
 
@@ -16726,14 +20051,12 @@ impl AzCssNthChildSelectorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssNthChildSelector;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzCssNthChildSelector::Number(v) => Ok(vec!["Number".into_py(py), v.into_py(py)]),
             AzCssNthChildSelector::Even => Ok(vec!["Even".into_py(py), ().into_py(py)]),
             AzCssNthChildSelector::Odd => Ok(vec!["Odd".into_py(py), ().into_py(py)]),
             AzCssNthChildSelector::Pattern(v) => Ok(vec!["Pattern".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -16834,6 +20157,8 @@ impl AzCssPropertyTypeEnumWrapper {
     #[classattr]
     fn TabWidth() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TabWidth } }
     #[classattr]
+    fn Hyphens() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Hyphens } }
+    #[classattr]
     fn Cursor() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Cursor } }
     #[classattr]
     fn Display() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Display } }
@@ -16948,6 +20273,28 @@ impl AzCssPropertyTypeEnumWrapper {
     #[classattr]
     fn ScrollbarStyle() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::ScrollbarStyle } }
     #[classattr]
+    fn ScrollbarWidth() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::ScrollbarWidth } }
+    #[classattr]
+    fn ScrollbarColor() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::ScrollbarColor } }
+    #[classattr]
+    fn ScrollbarThumbHoverColor() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::ScrollbarThumbHoverColor } }
+    #[classattr]
+    fn ScrollbarThumbActiveColor() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::ScrollbarThumbActiveColor } }
+    #[classattr]
+    fn WindowDragRegion() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::WindowDragRegion } }
+    #[classattr]
+    fn CaretAnimationDuration() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::CaretAnimationDuration } }
+    #[classattr]
+    fn ScrollPadding() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::ScrollPadding } }
+    #[classattr]
+    fn Content() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Content } }
+    #[classattr]
+    fn CounterReset() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::CounterReset } }
+    #[classattr]
+    fn CounterIncrement() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::CounterIncrement } }
+    #[classattr]
+    fn OverflowAnchor() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::OverflowAnchor } }
+    #[classattr]
     fn Opacity() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Opacity } }
     #[classattr]
     fn Transform() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Transform } }
@@ -16958,6 +20305,10 @@ impl AzCssPropertyTypeEnumWrapper {
     #[classattr]
     fn BackfaceVisibility() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::BackfaceVisibility } }
     #[classattr]
+    fn Perspective() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Perspective } }
+    #[classattr]
+    fn TransformStyle() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TransformStyle } }
+    #[classattr]
     fn MixBlendMode() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::MixBlendMode } }
     #[classattr]
     fn Filter() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Filter } }
@@ -17005,16 +20356,14 @@ impl AzAnimationInterpolationFunctionEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzAnimationInterpolationFunction;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzAnimationInterpolationFunction::Ease => Ok(vec!["Ease".into_py(py), ().into_py(py)]),
             AzAnimationInterpolationFunction::Linear => Ok(vec!["Linear".into_py(py), ().into_py(py)]),
             AzAnimationInterpolationFunction::EaseIn => Ok(vec!["EaseIn".into_py(py), ().into_py(py)]),
             AzAnimationInterpolationFunction::EaseOut => Ok(vec!["EaseOut".into_py(py), ().into_py(py)]),
             AzAnimationInterpolationFunction::EaseInOut => Ok(vec!["EaseInOut".into_py(py), ().into_py(py)]),
             AzAnimationInterpolationFunction::CubicBezier(v) => Ok(vec!["CubicBezier".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -17091,6 +20440,58 @@ impl PyObjectProtocol for AzColorU {
     }
 }
 
+#[pymethods]
+impl AzSystemColors {
+    #[new]
+    fn __new__(canvas: AzColorU, canvas_text: AzColorU, link_text: AzColorU, gray_text: AzColorU, highlight: AzColorU, highlight_text: AzColorU, button_face: AzColorU, button_text: AzColorU) -> Self {
+        Self {
+            canvas,
+            canvas_text,
+            link_text,
+            gray_text,
+            highlight,
+            highlight_text,
+            button_face,
+            button_text,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSystemColors {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::SystemColors = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::SystemColors = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSystemStyle {
+    #[new]
+    fn __new__(forced_colors: bool, prefers_reduced_motion: bool, pointer_coarse: bool, colors: AzSystemColors) -> Self {
+        Self {
+            forced_colors,
+            prefers_reduced_motion,
+            pointer_coarse,
+            colors,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSystemStyle {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::SystemStyle = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::SystemStyle = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzSizeMetricEnumWrapper {
     #[classattr]
@@ -17309,9 +20710,7 @@ impl AzStyleFilterEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleFilter;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleFilter::Blend(v) => Ok(vec!["Blend".into_py(py), { let m: &AzStyleMixBlendModeEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzStyleFilter::Flood(v) => Ok(vec!["Flood".into_py(py), v.clone().into_py(py)]),
             AzStyleFilter::Blur(v) => Ok(vec!["Blur".into_py(py), v.clone().into_py(py)]),
@@ -17321,7 +20720,7 @@ impl AzStyleFilterEnumWrapper {
             AzStyleFilter::ComponentTransfer => Ok(vec!["ComponentTransfer".into_py(py), ().into_py(py)]),
             AzStyleFilter::Offset(v) => Ok(vec!["Offset".into_py(py), v.clone().into_py(py)]),
             AzStyleFilter::Composite(v) => Ok(vec!["Composite".into_py(py), { let m: &AzStyleCompositeFilterEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -17417,9 +20816,7 @@ impl AzStyleCompositeFilterEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleCompositeFilter;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleCompositeFilter::Over => Ok(vec!["Over".into_py(py), ().into_py(py)]),
             AzStyleCompositeFilter::In => Ok(vec!["In".into_py(py), ().into_py(py)]),
             AzStyleCompositeFilter::Atop => Ok(vec!["Atop".into_py(py), ().into_py(py)]),
@@ -17427,7 +20824,7 @@ impl AzStyleCompositeFilterEnumWrapper {
             AzStyleCompositeFilter::Xor => Ok(vec!["Xor".into_py(py), ().into_py(py)]),
             AzStyleCompositeFilter::Lighter => Ok(vec!["Lighter".into_py(py), ().into_py(py)]),
             AzStyleCompositeFilter::Arithmetic(v) => Ok(vec!["Arithmetic".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -18375,12 +21772,10 @@ impl AzDirectionEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzDirection;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzDirection::Angle(v) => Ok(vec!["Angle".into_py(py), v.clone().into_py(py)]),
             AzDirection::FromTo(v) => Ok(vec!["FromTo".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -18570,15 +21965,13 @@ impl AzStyleBackgroundContentEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBackgroundContent;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBackgroundContent::LinearGradient(v) => Ok(vec!["LinearGradient".into_py(py), v.clone().into_py(py)]),
             AzStyleBackgroundContent::RadialGradient(v) => Ok(vec!["RadialGradient".into_py(py), v.clone().into_py(py)]),
             AzStyleBackgroundContent::ConicGradient(v) => Ok(vec!["ConicGradient".into_py(py), v.clone().into_py(py)]),
             AzStyleBackgroundContent::Image(v) => Ok(vec!["Image".into_py(py), v.clone().into_py(py)]),
             AzStyleBackgroundContent::Color(v) => Ok(vec!["Color".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -18606,14 +21999,12 @@ impl AzBackgroundPositionHorizontalEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzBackgroundPositionHorizontal;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzBackgroundPositionHorizontal::Left => Ok(vec!["Left".into_py(py), ().into_py(py)]),
             AzBackgroundPositionHorizontal::Center => Ok(vec!["Center".into_py(py), ().into_py(py)]),
             AzBackgroundPositionHorizontal::Right => Ok(vec!["Right".into_py(py), ().into_py(py)]),
             AzBackgroundPositionHorizontal::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -18641,14 +22032,12 @@ impl AzBackgroundPositionVerticalEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzBackgroundPositionVertical;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzBackgroundPositionVertical::Top => Ok(vec!["Top".into_py(py), ().into_py(py)]),
             AzBackgroundPositionVertical::Center => Ok(vec!["Center".into_py(py), ().into_py(py)]),
             AzBackgroundPositionVertical::Bottom => Ok(vec!["Bottom".into_py(py), ().into_py(py)]),
             AzBackgroundPositionVertical::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -18726,13 +22115,11 @@ impl AzStyleBackgroundSizeEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBackgroundSize;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBackgroundSize::ExactSize(v) => Ok(vec!["ExactSize".into_py(py), v.to_vec().into_py(py)]),
             AzStyleBackgroundSize::Contain => Ok(vec!["Contain".into_py(py), ().into_py(py)]),
             AzStyleBackgroundSize::Cover => Ok(vec!["Cover".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -19176,6 +22563,293 @@ impl PyObjectProtocol for AzScrollbarStyle {
     }
 }
 
+#[pymethods]
+impl AzStyleScrollbarWidthEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleScrollbarWidthEnumWrapper { AzStyleScrollbarWidthEnumWrapper { inner: AzStyleScrollbarWidth::Auto } }
+    #[classattr]
+    fn Thin() -> AzStyleScrollbarWidthEnumWrapper { AzStyleScrollbarWidthEnumWrapper { inner: AzStyleScrollbarWidth::Thin } }
+    #[classattr]
+    fn None() -> AzStyleScrollbarWidthEnumWrapper { AzStyleScrollbarWidthEnumWrapper { inner: AzStyleScrollbarWidth::None } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollbarWidthEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarWidth = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarWidth = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleScrollbarWidthEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzStyleOverflowAnchorEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleOverflowAnchorEnumWrapper { AzStyleOverflowAnchorEnumWrapper { inner: AzStyleOverflowAnchor::Auto } }
+    #[classattr]
+    fn None() -> AzStyleOverflowAnchorEnumWrapper { AzStyleOverflowAnchorEnumWrapper { inner: AzStyleOverflowAnchor::None } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleOverflowAnchorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleOverflowAnchor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleOverflowAnchor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleOverflowAnchorEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzStyleScrollbarColor {
+    #[new]
+    fn __new__(thumb: AzColorU, track: AzColorU) -> Self {
+        Self {
+            thumb,
+            track,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollbarColor {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarColor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarColor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleScrollbarThumbHoverColor {
+    #[new]
+    fn __new__(inner: AzColorU) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollbarThumbHoverColor {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarThumbHoverColor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarThumbHoverColor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleScrollbarThumbActiveColor {
+    #[new]
+    fn __new__(inner: AzColorU) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollbarThumbActiveColor {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarThumbActiveColor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarThumbActiveColor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleWindowDragRegionEnumWrapper {
+    #[classattr]
+    fn None() -> AzStyleWindowDragRegionEnumWrapper { AzStyleWindowDragRegionEnumWrapper { inner: AzStyleWindowDragRegion::None } }
+    #[classattr]
+    fn Move() -> AzStyleWindowDragRegionEnumWrapper { AzStyleWindowDragRegionEnumWrapper { inner: AzStyleWindowDragRegion::Move } }
+    #[classattr]
+    fn ResizeN() -> AzStyleWindowDragRegionEnumWrapper { AzStyleWindowDragRegionEnumWrapper { inner: AzStyleWindowDragRegion::ResizeN } }
+    #[classattr]
+    fn ResizeNE() -> AzStyleWindowDragRegionEnumWrapper { AzStyleWindowDragRegionEnumWrapper { inner: AzStyleWindowDragRegion::ResizeNE } }
+    #[classattr]
+    fn ResizeE() -> AzStyleWindowDragRegionEnumWrapper { AzStyleWindowDragRegionEnumWrapper { inner: AzStyleWindowDragRegion::ResizeE } }
+    #[classattr]
+    fn ResizeSE() -> AzStyleWindowDragRegionEnumWrapper { AzStyleWindowDragRegionEnumWrapper { inner: AzStyleWindowDragRegion::ResizeSE } }
+    #[classattr]
+    fn ResizeS() -> AzStyleWindowDragRegionEnumWrapper { AzStyleWindowDragRegionEnumWrapper { inner: AzStyleWindowDragRegion::ResizeS } }
+    #[classattr]
+    fn ResizeSW() -> AzStyleWindowDragRegionEnumWrapper { AzStyleWindowDragRegionEnumWrapper { inner: AzStyleWindowDragRegion::ResizeSW } }
+    #[classattr]
+    fn ResizeW() -> AzStyleWindowDragRegionEnumWrapper { AzStyleWindowDragRegionEnumWrapper { inner: AzStyleWindowDragRegion::ResizeW } }
+    #[classattr]
+    fn ResizeNW() -> AzStyleWindowDragRegionEnumWrapper { AzStyleWindowDragRegionEnumWrapper { inner: AzStyleWindowDragRegion::ResizeNW } }
+    #[classattr]
+    fn MaximizeButton() -> AzStyleWindowDragRegionEnumWrapper { AzStyleWindowDragRegionEnumWrapper { inner: AzStyleWindowDragRegion::MaximizeButton } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleWindowDragRegionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleWindowDragRegion = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleWindowDragRegion = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleWindowDragRegionEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzStyleCaretAnimationDuration {
+    #[new]
+    fn __new__(inner: u32) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleCaretAnimationDuration {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCaretAnimationDuration = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCaretAnimationDuration = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleScrollPadding {
+    #[new]
+    fn __new__(inner: AzPixelValue) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollPadding {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollPadding = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollPadding = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleContentEnumWrapper {
+    #[classattr]
+    fn None() -> AzStyleContentEnumWrapper { AzStyleContentEnumWrapper { inner: AzStyleContent::None } }
+    #[staticmethod]
+    fn Text(v: AzString) -> AzStyleContentEnumWrapper { AzStyleContentEnumWrapper { inner: AzStyleContent::Text(v) } }
+    #[staticmethod]
+    fn Image(v: AzString) -> AzStyleContentEnumWrapper { AzStyleContentEnumWrapper { inner: AzStyleContent::Image(v) } }
+    #[staticmethod]
+    fn Counter(v: AzString) -> AzStyleContentEnumWrapper { AzStyleContentEnumWrapper { inner: AzStyleContent::Counter(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleContent;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStyleContent::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleContent::Text(v) => Ok(vec!["Text".into_py(py), v.clone().into_py(py)]),
+            AzStyleContent::Image(v) => Ok(vec!["Image".into_py(py), v.clone().into_py(py)]),
+            AzStyleContent::Counter(v) => Ok(vec!["Counter".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleContentEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleContent = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleContent = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleCounterReset {
+    #[new]
+    fn __new__(counter: AzString, value: i32) -> Self {
+        Self {
+            counter,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleCounterReset {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCounterReset = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCounterReset = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleCounterIncrement {
+    #[new]
+    fn __new__(counter: AzString, value: i32) -> Self {
+        Self {
+            counter,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleCounterIncrement {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCounterIncrement = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCounterIncrement = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleCursorEnumWrapper {
     #[classattr]
@@ -19238,6 +22912,46 @@ impl AzStyleCursorEnumWrapper {
     fn ZoomIn() -> AzStyleCursorEnumWrapper { AzStyleCursorEnumWrapper { inner: AzStyleCursor::ZoomIn } }
     #[classattr]
     fn ZoomOut() -> AzStyleCursorEnumWrapper { AzStyleCursorEnumWrapper { inner: AzStyleCursor::ZoomOut } }
+    #[staticmethod]
+    fn Image(v: AzStyleCursorImage) -> AzStyleCursorEnumWrapper { AzStyleCursorEnumWrapper { inner: AzStyleCursor::Image(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleCursor;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStyleCursor::Alias => Ok(vec!["Alias".into_py(py), ().into_py(py)]),
+            AzStyleCursor::AllScroll => Ok(vec!["AllScroll".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Cell => Ok(vec!["Cell".into_py(py), ().into_py(py)]),
+            AzStyleCursor::ColResize => Ok(vec!["ColResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::ContextMenu => Ok(vec!["ContextMenu".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Copy => Ok(vec!["Copy".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Crosshair => Ok(vec!["Crosshair".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Default => Ok(vec!["Default".into_py(py), ().into_py(py)]),
+            AzStyleCursor::EResize => Ok(vec!["EResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::EwResize => Ok(vec!["EwResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Grab => Ok(vec!["Grab".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Grabbing => Ok(vec!["Grabbing".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Help => Ok(vec!["Help".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Move => Ok(vec!["Move".into_py(py), ().into_py(py)]),
+            AzStyleCursor::NResize => Ok(vec!["NResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::NsResize => Ok(vec!["NsResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::NeswResize => Ok(vec!["NeswResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::NwseResize => Ok(vec!["NwseResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Pointer => Ok(vec!["Pointer".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Progress => Ok(vec!["Progress".into_py(py), ().into_py(py)]),
+            AzStyleCursor::RowResize => Ok(vec!["RowResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::SResize => Ok(vec!["SResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::SeResize => Ok(vec!["SeResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Text => Ok(vec!["Text".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Unset => Ok(vec!["Unset".into_py(py), ().into_py(py)]),
+            AzStyleCursor::VerticalText => Ok(vec!["VerticalText".into_py(py), ().into_py(py)]),
+            AzStyleCursor::WResize => Ok(vec!["WResize".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Wait => Ok(vec!["Wait".into_py(py), ().into_py(py)]),
+            AzStyleCursor::ZoomIn => Ok(vec!["ZoomIn".into_py(py), ().into_py(py)]),
+            AzStyleCursor::ZoomOut => Ok(vec!["ZoomOut".into_py(py), ().into_py(py)]),
+            AzStyleCursor::Image(v) => Ok(vec!["Image".into_py(py), v.clone().into_py(py)]),
+        })
+    }
 }
 
 #[pyproto]
@@ -19248,16 +22962,29 @@ impl PyObjectProtocol for AzStyleCursorEnumWrapper {
     fn __repr__(&self) -> Result<String, PyErr> { 
         let m: &azul_impl::css::StyleCursor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
     }
-    fn __richcmp__(&self, other: AzStyleCursorEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
-        match op {
-            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
-            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
-            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
-            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
-            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
-            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+}
+
+#[pymethods]
+impl AzStyleCursorImage {
+    #[new]
+    fn __new__(image: AzString, hotspot_x: u32, hotspot_y: u32) -> Self {
+        Self {
+            image,
+            hotspot_x,
+            hotspot_y,
         }
     }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleCursorImage {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCursorImage = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCursorImage = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
 }
 
 #[pymethods]
@@ -19272,13 +22999,11 @@ impl AzStyleFontFamilyEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleFontFamily;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleFontFamily::System(v) => Ok(vec!["System".into_py(py), v.clone().into_py(py)]),
             AzStyleFontFamily::File(v) => Ok(vec!["File".into_py(py), v.clone().into_py(py)]),
             AzStyleFontFamily::Ref(v) => Ok(vec!["Ref".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -19469,6 +23194,55 @@ impl PyObjectProtocol for AzStyleBackfaceVisibilityEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStylePerspective {
+    #[new]
+    fn __new__(inner: AzPixelValue) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStylePerspective {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StylePerspective = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StylePerspective = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransformStyleEnumWrapper {
+    #[classattr]
+    fn Flat() -> AzStyleTransformStyleEnumWrapper { AzStyleTransformStyleEnumWrapper { inner: AzStyleTransformStyle::Flat } }
+    #[classattr]
+    fn Preserve3D() -> AzStyleTransformStyleEnumWrapper { AzStyleTransformStyleEnumWrapper { inner: AzStyleTransformStyle::Preserve3D } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransformStyleEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransformStyle = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransformStyle = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleTransformStyleEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzStyleTransformEnumWrapper {
     #[staticmethod]
@@ -19517,9 +23291,7 @@ impl AzStyleTransformEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleTransform;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleTransform::Matrix(v) => Ok(vec!["Matrix".into_py(py), v.clone().into_py(py)]),
             AzStyleTransform::Matrix3D(v) => Ok(vec!["Matrix3D".into_py(py), v.clone().into_py(py)]),
             AzStyleTransform::Translate(v) => Ok(vec!["Translate".into_py(py), v.clone().into_py(py)]),
@@ -19541,7 +23313,7 @@ impl AzStyleTransformEnumWrapper {
             AzStyleTransform::SkewX(v) => Ok(vec!["SkewX".into_py(py), v.clone().into_py(py)]),
             AzStyleTransform::SkewY(v) => Ok(vec!["SkewY".into_py(py), v.clone().into_py(py)]),
             AzStyleTransform::Perspective(v) => Ok(vec!["Perspective".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -19783,6 +23555,36 @@ impl PyObjectProtocol for AzStyleTextAlignEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleHyphensEnumWrapper {
+    #[classattr]
+    fn None() -> AzStyleHyphensEnumWrapper { AzStyleHyphensEnumWrapper { inner: AzStyleHyphens::None } }
+    #[classattr]
+    fn Manual() -> AzStyleHyphensEnumWrapper { AzStyleHyphensEnumWrapper { inner: AzStyleHyphens::Manual } }
+    #[classattr]
+    fn Auto() -> AzStyleHyphensEnumWrapper { AzStyleHyphensEnumWrapper { inner: AzStyleHyphens::Auto } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleHyphensEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleHyphens = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleHyphens = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleHyphensEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzStyleTextColor {
     #[new]
@@ -19841,15 +23643,13 @@ impl AzStyleBoxShadowValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBoxShadowValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBoxShadowValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBoxShadowValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBoxShadowValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBoxShadowValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBoxShadowValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -19879,15 +23679,13 @@ impl AzLayoutAlignContentValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutAlignContentValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutAlignContentValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutAlignContentValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutAlignContentValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutAlignContentValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutAlignContentValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzLayoutAlignContentEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -19917,15 +23715,13 @@ impl AzLayoutAlignItemsValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutAlignItemsValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutAlignItemsValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutAlignItemsValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutAlignItemsValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutAlignItemsValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutAlignItemsValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzLayoutAlignItemsEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -19955,15 +23751,13 @@ impl AzLayoutBottomValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutBottomValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutBottomValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutBottomValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutBottomValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutBottomValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutBottomValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -19993,15 +23787,13 @@ impl AzLayoutBoxSizingValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutBoxSizingValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutBoxSizingValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutBoxSizingValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutBoxSizingValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutBoxSizingValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutBoxSizingValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzLayoutBoxSizingEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20031,15 +23823,13 @@ impl AzLayoutFlexDirectionValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutFlexDirectionValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutFlexDirectionValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutFlexDirectionValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutFlexDirectionValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutFlexDirectionValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutFlexDirectionValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzLayoutFlexDirectionEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20069,15 +23859,13 @@ impl AzLayoutDisplayValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutDisplayValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutDisplayValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutDisplayValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutDisplayValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutDisplayValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutDisplayValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzLayoutDisplayEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20107,15 +23895,13 @@ impl AzLayoutFlexGrowValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutFlexGrowValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutFlexGrowValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutFlexGrowValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutFlexGrowValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutFlexGrowValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutFlexGrowValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20145,15 +23931,13 @@ impl AzLayoutFlexShrinkValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutFlexShrinkValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutFlexShrinkValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutFlexShrinkValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutFlexShrinkValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutFlexShrinkValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutFlexShrinkValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20183,15 +23967,13 @@ impl AzLayoutFloatValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutFloatValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutFloatValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutFloatValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutFloatValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutFloatValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutFloatValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzLayoutFloatEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20221,15 +24003,13 @@ impl AzLayoutHeightValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutHeightValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutHeightValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutHeightValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutHeightValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutHeightValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutHeightValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20259,15 +24039,13 @@ impl AzLayoutJustifyContentValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutJustifyContentValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutJustifyContentValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutJustifyContentValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutJustifyContentValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutJustifyContentValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutJustifyContentValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzLayoutJustifyContentEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20297,15 +24075,13 @@ impl AzLayoutLeftValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutLeftValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutLeftValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutLeftValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutLeftValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutLeftValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutLeftValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20335,15 +24111,13 @@ impl AzLayoutMarginBottomValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutMarginBottomValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutMarginBottomValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutMarginBottomValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutMarginBottomValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMarginBottomValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMarginBottomValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20373,15 +24147,13 @@ impl AzLayoutMarginLeftValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutMarginLeftValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutMarginLeftValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutMarginLeftValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutMarginLeftValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMarginLeftValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMarginLeftValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20411,15 +24183,13 @@ impl AzLayoutMarginRightValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutMarginRightValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutMarginRightValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutMarginRightValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutMarginRightValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMarginRightValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMarginRightValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20449,15 +24219,13 @@ impl AzLayoutMarginTopValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutMarginTopValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutMarginTopValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutMarginTopValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutMarginTopValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMarginTopValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMarginTopValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20487,15 +24255,13 @@ impl AzLayoutMaxHeightValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutMaxHeightValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutMaxHeightValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutMaxHeightValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutMaxHeightValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMaxHeightValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMaxHeightValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20525,15 +24291,13 @@ impl AzLayoutMaxWidthValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutMaxWidthValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutMaxWidthValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutMaxWidthValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutMaxWidthValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMaxWidthValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMaxWidthValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20563,15 +24327,13 @@ impl AzLayoutMinHeightValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutMinHeightValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutMinHeightValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutMinHeightValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutMinHeightValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMinHeightValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMinHeightValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20601,15 +24363,13 @@ impl AzLayoutMinWidthValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutMinWidthValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutMinWidthValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutMinWidthValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutMinWidthValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMinWidthValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMinWidthValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20639,15 +24399,13 @@ impl AzLayoutPaddingBottomValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutPaddingBottomValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutPaddingBottomValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutPaddingBottomValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutPaddingBottomValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutPaddingBottomValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutPaddingBottomValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20677,15 +24435,13 @@ impl AzLayoutPaddingLeftValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutPaddingLeftValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutPaddingLeftValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutPaddingLeftValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutPaddingLeftValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutPaddingLeftValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutPaddingLeftValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20715,15 +24471,13 @@ impl AzLayoutPaddingRightValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutPaddingRightValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutPaddingRightValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutPaddingRightValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutPaddingRightValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutPaddingRightValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutPaddingRightValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20753,15 +24507,13 @@ impl AzLayoutPaddingTopValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutPaddingTopValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutPaddingTopValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutPaddingTopValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutPaddingTopValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutPaddingTopValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutPaddingTopValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20791,15 +24543,13 @@ impl AzLayoutPositionValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutPositionValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutPositionValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutPositionValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutPositionValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutPositionValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutPositionValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzLayoutPositionEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20829,15 +24579,13 @@ impl AzLayoutRightValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutRightValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutRightValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutRightValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutRightValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutRightValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutRightValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20867,15 +24615,13 @@ impl AzLayoutTopValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutTopValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutTopValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutTopValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutTopValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutTopValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutTopValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20905,15 +24651,13 @@ impl AzLayoutWidthValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutWidthValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutWidthValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutWidthValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutWidthValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutWidthValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutWidthValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20943,15 +24687,13 @@ impl AzLayoutFlexWrapValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutFlexWrapValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutFlexWrapValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutFlexWrapValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutFlexWrapValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutFlexWrapValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutFlexWrapValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzLayoutFlexWrapEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -20981,15 +24723,13 @@ impl AzLayoutOverflowValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutOverflowValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutOverflowValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutOverflowValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutOverflowValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutOverflowValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutOverflowValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzLayoutOverflowEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21019,15 +24759,13 @@ impl AzScrollbarStyleValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzScrollbarStyleValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzScrollbarStyleValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzScrollbarStyleValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzScrollbarStyleValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzScrollbarStyleValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzScrollbarStyleValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21041,6 +24779,402 @@ impl PyObjectProtocol for AzScrollbarStyleValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleScrollbarWidthValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleScrollbarWidthValueEnumWrapper { AzStyleScrollbarWidthValueEnumWrapper { inner: AzStyleScrollbarWidthValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleScrollbarWidthValueEnumWrapper { AzStyleScrollbarWidthValueEnumWrapper { inner: AzStyleScrollbarWidthValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleScrollbarWidthValueEnumWrapper { AzStyleScrollbarWidthValueEnumWrapper { inner: AzStyleScrollbarWidthValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleScrollbarWidthValueEnumWrapper { AzStyleScrollbarWidthValueEnumWrapper { inner: AzStyleScrollbarWidthValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleScrollbarWidthEnumWrapper) -> AzStyleScrollbarWidthValueEnumWrapper { AzStyleScrollbarWidthValueEnumWrapper { inner: AzStyleScrollbarWidthValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleScrollbarWidthValue;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStyleScrollbarWidthValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarWidthValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarWidthValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarWidthValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarWidthValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleScrollbarWidthEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollbarWidthValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarWidthValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarWidthValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleScrollbarColorValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleScrollbarColorValueEnumWrapper { AzStyleScrollbarColorValueEnumWrapper { inner: AzStyleScrollbarColorValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleScrollbarColorValueEnumWrapper { AzStyleScrollbarColorValueEnumWrapper { inner: AzStyleScrollbarColorValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleScrollbarColorValueEnumWrapper { AzStyleScrollbarColorValueEnumWrapper { inner: AzStyleScrollbarColorValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleScrollbarColorValueEnumWrapper { AzStyleScrollbarColorValueEnumWrapper { inner: AzStyleScrollbarColorValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleScrollbarColor) -> AzStyleScrollbarColorValueEnumWrapper { AzStyleScrollbarColorValueEnumWrapper { inner: AzStyleScrollbarColorValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleScrollbarColorValue;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStyleScrollbarColorValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarColorValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarColorValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarColorValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarColorValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollbarColorValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarColorValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarColorValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleScrollbarThumbHoverColorValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleScrollbarThumbHoverColorValueEnumWrapper { AzStyleScrollbarThumbHoverColorValueEnumWrapper { inner: AzStyleScrollbarThumbHoverColorValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleScrollbarThumbHoverColorValueEnumWrapper { AzStyleScrollbarThumbHoverColorValueEnumWrapper { inner: AzStyleScrollbarThumbHoverColorValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleScrollbarThumbHoverColorValueEnumWrapper { AzStyleScrollbarThumbHoverColorValueEnumWrapper { inner: AzStyleScrollbarThumbHoverColorValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleScrollbarThumbHoverColorValueEnumWrapper { AzStyleScrollbarThumbHoverColorValueEnumWrapper { inner: AzStyleScrollbarThumbHoverColorValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleScrollbarThumbHoverColor) -> AzStyleScrollbarThumbHoverColorValueEnumWrapper { AzStyleScrollbarThumbHoverColorValueEnumWrapper { inner: AzStyleScrollbarThumbHoverColorValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleScrollbarThumbHoverColorValue;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStyleScrollbarThumbHoverColorValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarThumbHoverColorValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarThumbHoverColorValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarThumbHoverColorValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarThumbHoverColorValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollbarThumbHoverColorValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarThumbHoverColorValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarThumbHoverColorValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleScrollbarThumbActiveColorValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleScrollbarThumbActiveColorValueEnumWrapper { AzStyleScrollbarThumbActiveColorValueEnumWrapper { inner: AzStyleScrollbarThumbActiveColorValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleScrollbarThumbActiveColorValueEnumWrapper { AzStyleScrollbarThumbActiveColorValueEnumWrapper { inner: AzStyleScrollbarThumbActiveColorValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleScrollbarThumbActiveColorValueEnumWrapper { AzStyleScrollbarThumbActiveColorValueEnumWrapper { inner: AzStyleScrollbarThumbActiveColorValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleScrollbarThumbActiveColorValueEnumWrapper { AzStyleScrollbarThumbActiveColorValueEnumWrapper { inner: AzStyleScrollbarThumbActiveColorValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleScrollbarThumbActiveColor) -> AzStyleScrollbarThumbActiveColorValueEnumWrapper { AzStyleScrollbarThumbActiveColorValueEnumWrapper { inner: AzStyleScrollbarThumbActiveColorValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleScrollbarThumbActiveColorValue;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStyleScrollbarThumbActiveColorValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarThumbActiveColorValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarThumbActiveColorValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarThumbActiveColorValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleScrollbarThumbActiveColorValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollbarThumbActiveColorValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarThumbActiveColorValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollbarThumbActiveColorValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleWindowDragRegionValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleWindowDragRegionValueEnumWrapper { AzStyleWindowDragRegionValueEnumWrapper { inner: AzStyleWindowDragRegionValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleWindowDragRegionValueEnumWrapper { AzStyleWindowDragRegionValueEnumWrapper { inner: AzStyleWindowDragRegionValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleWindowDragRegionValueEnumWrapper { AzStyleWindowDragRegionValueEnumWrapper { inner: AzStyleWindowDragRegionValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleWindowDragRegionValueEnumWrapper { AzStyleWindowDragRegionValueEnumWrapper { inner: AzStyleWindowDragRegionValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleWindowDragRegionEnumWrapper) -> AzStyleWindowDragRegionValueEnumWrapper { AzStyleWindowDragRegionValueEnumWrapper { inner: AzStyleWindowDragRegionValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleWindowDragRegionValue;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStyleWindowDragRegionValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleWindowDragRegionValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleWindowDragRegionValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleWindowDragRegionValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleWindowDragRegionValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleWindowDragRegionEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleWindowDragRegionValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleWindowDragRegionValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleWindowDragRegionValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleCaretAnimationDurationValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleCaretAnimationDurationValueEnumWrapper { AzStyleCaretAnimationDurationValueEnumWrapper { inner: AzStyleCaretAnimationDurationValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleCaretAnimationDurationValueEnumWrapper { AzStyleCaretAnimationDurationValueEnumWrapper { inner: AzStyleCaretAnimationDurationValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleCaretAnimationDurationValueEnumWrapper { AzStyleCaretAnimationDurationValueEnumWrapper { inner: AzStyleCaretAnimationDurationValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleCaretAnimationDurationValueEnumWrapper { AzStyleCaretAnimationDurationValueEnumWrapper { inner: AzStyleCaretAnimationDurationValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleCaretAnimationDuration) -> AzStyleCaretAnimationDurationValueEnumWrapper { AzStyleCaretAnimationDurationValueEnumWrapper { inner: AzStyleCaretAnimationDurationValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleCaretAnimationDurationValue;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStyleCaretAnimationDurationValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleCaretAnimationDurationValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleCaretAnimationDurationValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleCaretAnimationDurationValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleCaretAnimationDurationValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleCaretAnimationDurationValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCaretAnimationDurationValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCaretAnimationDurationValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleScrollPaddingValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleScrollPaddingValueEnumWrapper { AzStyleScrollPaddingValueEnumWrapper { inner: AzStyleScrollPaddingValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleScrollPaddingValueEnumWrapper { AzStyleScrollPaddingValueEnumWrapper { inner: AzStyleScrollPaddingValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleScrollPaddingValueEnumWrapper { AzStyleScrollPaddingValueEnumWrapper { inner: AzStyleScrollPaddingValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleScrollPaddingValueEnumWrapper { AzStyleScrollPaddingValueEnumWrapper { inner: AzStyleScrollPaddingValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleScrollPadding) -> AzStyleScrollPaddingValueEnumWrapper { AzStyleScrollPaddingValueEnumWrapper { inner: AzStyleScrollPaddingValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleScrollPaddingValue;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStyleScrollPaddingValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleScrollPaddingValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleScrollPaddingValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleScrollPaddingValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleScrollPaddingValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleScrollPaddingValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollPaddingValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleScrollPaddingValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleContentValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleContentValueEnumWrapper { AzStyleContentValueEnumWrapper { inner: AzStyleContentValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleContentValueEnumWrapper { AzStyleContentValueEnumWrapper { inner: AzStyleContentValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleContentValueEnumWrapper { AzStyleContentValueEnumWrapper { inner: AzStyleContentValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleContentValueEnumWrapper { AzStyleContentValueEnumWrapper { inner: AzStyleContentValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleContentEnumWrapper) -> AzStyleContentValueEnumWrapper { AzStyleContentValueEnumWrapper { inner: AzStyleContentValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleContentValue;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStyleContentValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleContentValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleContentValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleContentValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleContentValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleContentEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleContentValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleContentValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleContentValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleCounterResetValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleCounterResetValueEnumWrapper { AzStyleCounterResetValueEnumWrapper { inner: AzStyleCounterResetValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleCounterResetValueEnumWrapper { AzStyleCounterResetValueEnumWrapper { inner: AzStyleCounterResetValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleCounterResetValueEnumWrapper { AzStyleCounterResetValueEnumWrapper { inner: AzStyleCounterResetValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleCounterResetValueEnumWrapper { AzStyleCounterResetValueEnumWrapper { inner: AzStyleCounterResetValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleCounterReset) -> AzStyleCounterResetValueEnumWrapper { AzStyleCounterResetValueEnumWrapper { inner: AzStyleCounterResetValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleCounterResetValue;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStyleCounterResetValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleCounterResetValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleCounterResetValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleCounterResetValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleCounterResetValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleCounterResetValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCounterResetValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCounterResetValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleCounterIncrementValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleCounterIncrementValueEnumWrapper { AzStyleCounterIncrementValueEnumWrapper { inner: AzStyleCounterIncrementValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleCounterIncrementValueEnumWrapper { AzStyleCounterIncrementValueEnumWrapper { inner: AzStyleCounterIncrementValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleCounterIncrementValueEnumWrapper { AzStyleCounterIncrementValueEnumWrapper { inner: AzStyleCounterIncrementValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleCounterIncrementValueEnumWrapper { AzStyleCounterIncrementValueEnumWrapper { inner: AzStyleCounterIncrementValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleCounterIncrement) -> AzStyleCounterIncrementValueEnumWrapper { AzStyleCounterIncrementValueEnumWrapper { inner: AzStyleCounterIncrementValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleCounterIncrementValue;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStyleCounterIncrementValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleCounterIncrementValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleCounterIncrementValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleCounterIncrementValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleCounterIncrementValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleCounterIncrementValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCounterIncrementValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCounterIncrementValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleOverflowAnchorValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleOverflowAnchorValueEnumWrapper { AzStyleOverflowAnchorValueEnumWrapper { inner: AzStyleOverflowAnchorValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleOverflowAnchorValueEnumWrapper { AzStyleOverflowAnchorValueEnumWrapper { inner: AzStyleOverflowAnchorValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleOverflowAnchorValueEnumWrapper { AzStyleOverflowAnchorValueEnumWrapper { inner: AzStyleOverflowAnchorValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleOverflowAnchorValueEnumWrapper { AzStyleOverflowAnchorValueEnumWrapper { inner: AzStyleOverflowAnchorValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleOverflowAnchorEnumWrapper) -> AzStyleOverflowAnchorValueEnumWrapper { AzStyleOverflowAnchorValueEnumWrapper { inner: AzStyleOverflowAnchorValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleOverflowAnchorValue;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStyleOverflowAnchorValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleOverflowAnchorValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleOverflowAnchorValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleOverflowAnchorValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleOverflowAnchorValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleOverflowAnchorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleOverflowAnchorValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleOverflowAnchorValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleOverflowAnchorValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleBackgroundContentVecValueEnumWrapper {
     #[classattr]
@@ -21057,15 +25191,13 @@ impl AzStyleBackgroundContentVecValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBackgroundContentVecValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBackgroundContentVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBackgroundContentVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBackgroundContentVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBackgroundContentVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBackgroundContentVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21095,15 +25227,13 @@ impl AzStyleBackgroundPositionVecValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBackgroundPositionVecValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBackgroundPositionVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBackgroundPositionVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBackgroundPositionVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBackgroundPositionVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBackgroundPositionVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21133,15 +25263,13 @@ impl AzStyleBackgroundRepeatVecValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBackgroundRepeatVecValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBackgroundRepeatVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBackgroundRepeatVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBackgroundRepeatVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBackgroundRepeatVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBackgroundRepeatVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21171,15 +25299,13 @@ impl AzStyleBackgroundSizeVecValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBackgroundSizeVecValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBackgroundSizeVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBackgroundSizeVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBackgroundSizeVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBackgroundSizeVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBackgroundSizeVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21209,15 +25335,13 @@ impl AzStyleBorderBottomColorValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBorderBottomColorValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBorderBottomColorValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomColorValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomColorValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomColorValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomColorValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21247,15 +25371,13 @@ impl AzStyleBorderBottomLeftRadiusValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBorderBottomLeftRadiusValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBorderBottomLeftRadiusValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomLeftRadiusValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomLeftRadiusValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomLeftRadiusValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomLeftRadiusValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21285,15 +25407,13 @@ impl AzStyleBorderBottomRightRadiusValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBorderBottomRightRadiusValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBorderBottomRightRadiusValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomRightRadiusValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomRightRadiusValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomRightRadiusValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomRightRadiusValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21323,15 +25443,13 @@ impl AzStyleBorderBottomStyleValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBorderBottomStyleValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBorderBottomStyleValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomStyleValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomStyleValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomStyleValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBorderBottomStyleValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21361,15 +25479,13 @@ impl AzLayoutBorderBottomWidthValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutBorderBottomWidthValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutBorderBottomWidthValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutBorderBottomWidthValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutBorderBottomWidthValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutBorderBottomWidthValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutBorderBottomWidthValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21399,15 +25515,13 @@ impl AzStyleBorderLeftColorValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBorderLeftColorValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBorderLeftColorValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBorderLeftColorValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBorderLeftColorValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBorderLeftColorValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBorderLeftColorValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21437,15 +25551,13 @@ impl AzStyleBorderLeftStyleValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBorderLeftStyleValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBorderLeftStyleValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBorderLeftStyleValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBorderLeftStyleValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBorderLeftStyleValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBorderLeftStyleValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21475,15 +25587,13 @@ impl AzLayoutBorderLeftWidthValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutBorderLeftWidthValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutBorderLeftWidthValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutBorderLeftWidthValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutBorderLeftWidthValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutBorderLeftWidthValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutBorderLeftWidthValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21513,15 +25623,13 @@ impl AzStyleBorderRightColorValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBorderRightColorValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBorderRightColorValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBorderRightColorValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBorderRightColorValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBorderRightColorValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBorderRightColorValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21551,15 +25659,13 @@ impl AzStyleBorderRightStyleValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBorderRightStyleValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBorderRightStyleValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBorderRightStyleValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBorderRightStyleValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBorderRightStyleValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBorderRightStyleValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21589,15 +25695,13 @@ impl AzLayoutBorderRightWidthValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutBorderRightWidthValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutBorderRightWidthValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutBorderRightWidthValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutBorderRightWidthValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutBorderRightWidthValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutBorderRightWidthValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21627,15 +25731,13 @@ impl AzStyleBorderTopColorValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBorderTopColorValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBorderTopColorValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBorderTopColorValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBorderTopColorValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBorderTopColorValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBorderTopColorValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21665,15 +25767,13 @@ impl AzStyleBorderTopLeftRadiusValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBorderTopLeftRadiusValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBorderTopLeftRadiusValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBorderTopLeftRadiusValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBorderTopLeftRadiusValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBorderTopLeftRadiusValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBorderTopLeftRadiusValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21703,15 +25803,13 @@ impl AzStyleBorderTopRightRadiusValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBorderTopRightRadiusValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBorderTopRightRadiusValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBorderTopRightRadiusValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBorderTopRightRadiusValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBorderTopRightRadiusValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBorderTopRightRadiusValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21741,15 +25839,13 @@ impl AzStyleBorderTopStyleValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBorderTopStyleValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBorderTopStyleValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBorderTopStyleValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBorderTopStyleValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBorderTopStyleValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBorderTopStyleValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21779,15 +25875,13 @@ impl AzLayoutBorderTopWidthValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLayoutBorderTopWidthValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLayoutBorderTopWidthValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzLayoutBorderTopWidthValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzLayoutBorderTopWidthValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutBorderTopWidthValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutBorderTopWidthValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21817,15 +25911,13 @@ impl AzStyleCursorValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleCursorValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleCursorValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleCursorValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleCursorValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleCursorValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleCursorValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleCursorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21855,15 +25947,13 @@ impl AzStyleFontFamilyVecValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleFontFamilyVecValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleFontFamilyVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleFontFamilyVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleFontFamilyVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleFontFamilyVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleFontFamilyVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21893,15 +25983,13 @@ impl AzStyleFontSizeValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleFontSizeValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleFontSizeValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleFontSizeValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleFontSizeValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleFontSizeValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleFontSizeValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21931,15 +26019,13 @@ impl AzStyleLetterSpacingValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleLetterSpacingValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleLetterSpacingValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleLetterSpacingValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleLetterSpacingValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleLetterSpacingValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleLetterSpacingValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -21969,15 +26055,13 @@ impl AzStyleLineHeightValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleLineHeightValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleLineHeightValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleLineHeightValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleLineHeightValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleLineHeightValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleLineHeightValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -22007,15 +26091,13 @@ impl AzStyleTabWidthValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleTabWidthValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleTabWidthValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleTabWidthValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleTabWidthValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleTabWidthValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleTabWidthValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -22029,6 +26111,42 @@ impl PyObjectProtocol for AzStyleTabWidthValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleHyphensValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleHyphensValueEnumWrapper { AzStyleHyphensValueEnumWrapper { inner: AzStyleHyphensValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleHyphensValueEnumWrapper { AzStyleHyphensValueEnumWrapper { inner: AzStyleHyphensValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleHyphensValueEnumWrapper { AzStyleHyphensValueEnumWrapper { inner: AzStyleHyphensValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleHyphensValueEnumWrapper { AzStyleHyphensValueEnumWrapper { inner: AzStyleHyphensValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleHyphensEnumWrapper) -> AzStyleHyphensValueEnumWrapper { AzStyleHyphensValueEnumWrapper { inner: AzStyleHyphensValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleHyphensValue;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStyleHyphensValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleHyphensValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleHyphensValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleHyphensValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleHyphensValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleHyphensEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleHyphensValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleHyphensValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleHyphensValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleTextAlignValueEnumWrapper {
     #[classattr]
@@ -22045,15 +26163,13 @@ impl AzStyleTextAlignValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleTextAlignValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleTextAlignValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleTextAlignValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleTextAlignValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleTextAlignValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleTextAlignValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleTextAlignEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -22083,15 +26199,13 @@ impl AzStyleTextColorValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleTextColorValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleTextColorValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleTextColorValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleTextColorValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleTextColorValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleTextColorValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -22121,15 +26235,13 @@ impl AzStyleWordSpacingValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleWordSpacingValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleWordSpacingValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleWordSpacingValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleWordSpacingValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleWordSpacingValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleWordSpacingValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -22159,15 +26271,13 @@ impl AzStyleOpacityValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleOpacityValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleOpacityValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleOpacityValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleOpacityValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleOpacityValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleOpacityValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -22197,15 +26307,13 @@ impl AzStyleTransformVecValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleTransformVecValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleTransformVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleTransformVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleTransformVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleTransformVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleTransformVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -22235,15 +26343,13 @@ impl AzStyleTransformOriginValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleTransformOriginValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleTransformOriginValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleTransformOriginValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleTransformOriginValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleTransformOriginValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleTransformOriginValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -22273,15 +26379,13 @@ impl AzStylePerspectiveOriginValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStylePerspectiveOriginValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStylePerspectiveOriginValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStylePerspectiveOriginValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStylePerspectiveOriginValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStylePerspectiveOriginValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStylePerspectiveOriginValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -22311,15 +26415,13 @@ impl AzStyleBackfaceVisibilityValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBackfaceVisibilityValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBackfaceVisibilityValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleBackfaceVisibilityValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleBackfaceVisibilityValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleBackfaceVisibilityValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleBackfaceVisibilityValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleBackfaceVisibilityEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -22333,6 +26435,78 @@ impl PyObjectProtocol for AzStyleBackfaceVisibilityValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStylePerspectiveValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStylePerspectiveValueEnumWrapper { AzStylePerspectiveValueEnumWrapper { inner: AzStylePerspectiveValue::Auto } }
+    #[classattr]
+    fn None() -> AzStylePerspectiveValueEnumWrapper { AzStylePerspectiveValueEnumWrapper { inner: AzStylePerspectiveValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStylePerspectiveValueEnumWrapper { AzStylePerspectiveValueEnumWrapper { inner: AzStylePerspectiveValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStylePerspectiveValueEnumWrapper { AzStylePerspectiveValueEnumWrapper { inner: AzStylePerspectiveValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStylePerspective) -> AzStylePerspectiveValueEnumWrapper { AzStylePerspectiveValueEnumWrapper { inner: AzStylePerspectiveValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStylePerspectiveValue;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStylePerspectiveValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStylePerspectiveValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStylePerspectiveValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStylePerspectiveValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStylePerspectiveValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStylePerspectiveValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StylePerspectiveValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StylePerspectiveValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransformStyleValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleTransformStyleValueEnumWrapper { AzStyleTransformStyleValueEnumWrapper { inner: AzStyleTransformStyleValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleTransformStyleValueEnumWrapper { AzStyleTransformStyleValueEnumWrapper { inner: AzStyleTransformStyleValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleTransformStyleValueEnumWrapper { AzStyleTransformStyleValueEnumWrapper { inner: AzStyleTransformStyleValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleTransformStyleValueEnumWrapper { AzStyleTransformStyleValueEnumWrapper { inner: AzStyleTransformStyleValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleTransformStyleEnumWrapper) -> AzStyleTransformStyleValueEnumWrapper { AzStyleTransformStyleValueEnumWrapper { inner: AzStyleTransformStyleValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransformStyleValue;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzStyleTransformStyleValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleTransformStyleValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleTransformStyleValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleTransformStyleValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleTransformStyleValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleTransformStyleEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransformStyleValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransformStyleValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransformStyleValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleMixBlendModeValueEnumWrapper {
     #[classattr]
@@ -22349,15 +26523,13 @@ impl AzStyleMixBlendModeValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleMixBlendModeValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleMixBlendModeValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleMixBlendModeValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleMixBlendModeValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleMixBlendModeValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleMixBlendModeValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleMixBlendModeEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -22387,15 +26559,13 @@ impl AzStyleFilterVecValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleFilterVecValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleFilterVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
             AzStyleFilterVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzStyleFilterVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzStyleFilterVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzStyleFilterVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -22428,6 +26598,8 @@ impl AzCssPropertyEnumWrapper {
     #[staticmethod]
     fn TabWidth(v: AzStyleTabWidthValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TabWidth(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
+    fn Hyphens(v: AzStyleHyphensValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Hyphens(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
     fn Cursor(v: AzStyleCursorValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Cursor(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn Display(v: AzLayoutDisplayValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Display(unsafe { mem::transmute(v) }) } }
@@ -22542,6 +26714,28 @@ impl AzCssPropertyEnumWrapper {
     #[staticmethod]
     fn ScrollbarStyle(v: AzScrollbarStyleValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::ScrollbarStyle(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
+    fn ScrollbarWidth(v: AzStyleScrollbarWidthValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::ScrollbarWidth(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn ScrollbarColor(v: AzStyleScrollbarColorValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::ScrollbarColor(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn ScrollbarThumbHoverColor(v: AzStyleScrollbarThumbHoverColorValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::ScrollbarThumbHoverColor(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn ScrollbarThumbActiveColor(v: AzStyleScrollbarThumbActiveColorValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::ScrollbarThumbActiveColor(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn WindowDragRegion(v: AzStyleWindowDragRegionValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::WindowDragRegion(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn CaretAnimationDuration(v: AzStyleCaretAnimationDurationValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::CaretAnimationDuration(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn ScrollPadding(v: AzStyleScrollPaddingValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::ScrollPadding(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn Content(v: AzStyleContentValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Content(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn CounterReset(v: AzStyleCounterResetValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::CounterReset(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn CounterIncrement(v: AzStyleCounterIncrementValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::CounterIncrement(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn OverflowAnchor(v: AzStyleOverflowAnchorValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::OverflowAnchor(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
     fn Opacity(v: AzStyleOpacityValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Opacity(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn Transform(v: AzStyleTransformVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Transform(unsafe { mem::transmute(v) }) } }
@@ -22552,6 +26746,10 @@ impl AzCssPropertyEnumWrapper {
     #[staticmethod]
     fn BackfaceVisibility(v: AzStyleBackfaceVisibilityValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::BackfaceVisibility(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
+    fn Perspective(v: AzStylePerspectiveValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Perspective(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn TransformStyle(v: AzStyleTransformStyleValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TransformStyle(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
     fn MixBlendMode(v: AzStyleMixBlendModeValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::MixBlendMode(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn Filter(v: AzStyleFilterVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Filter(unsafe { mem::transmute(v) }) } }
@@ -22563,9 +26761,7 @@ impl AzCssPropertyEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssProperty;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzCssProperty::TextColor(v) => Ok(vec!["TextColor".into_py(py), { let m: &AzStyleTextColorValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::FontSize(v) => Ok(vec!["FontSize".into_py(py), { let m: &AzStyleFontSizeValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::FontFamily(v) => Ok(vec!["FontFamily".into_py(py), { let m: &AzStyleFontFamilyVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
@@ -22574,6 +26770,7 @@ impl AzCssPropertyEnumWrapper {
             AzCssProperty::LineHeight(v) => Ok(vec!["LineHeight".into_py(py), { let m: &AzStyleLineHeightValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::WordSpacing(v) => Ok(vec!["WordSpacing".into_py(py), { let m: &AzStyleWordSpacingValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::TabWidth(v) => Ok(vec!["TabWidth".into_py(py), { let m: &AzStyleTabWidthValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::Hyphens(v) => Ok(vec!["Hyphens".into_py(py), { let m: &AzStyleHyphensValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::Cursor(v) => Ok(vec!["Cursor".into_py(py), { let m: &AzStyleCursorValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::Display(v) => Ok(vec!["Display".into_py(py), { let m: &AzLayoutDisplayValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::Float(v) => Ok(vec!["Float".into_py(py), { let m: &AzLayoutFloatValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
@@ -22631,16 +26828,29 @@ impl AzCssPropertyEnumWrapper {
             AzCssProperty::BoxShadowTop(v) => Ok(vec!["BoxShadowTop".into_py(py), { let m: &AzStyleBoxShadowValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BoxShadowBottom(v) => Ok(vec!["BoxShadowBottom".into_py(py), { let m: &AzStyleBoxShadowValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::ScrollbarStyle(v) => Ok(vec!["ScrollbarStyle".into_py(py), { let m: &AzScrollbarStyleValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::ScrollbarWidth(v) => Ok(vec!["ScrollbarWidth".into_py(py), { let m: &AzStyleScrollbarWidthValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::ScrollbarColor(v) => Ok(vec!["ScrollbarColor".into_py(py), { let m: &AzStyleScrollbarColorValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::ScrollbarThumbHoverColor(v) => Ok(vec!["ScrollbarThumbHoverColor".into_py(py), { let m: &AzStyleScrollbarThumbHoverColorValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::ScrollbarThumbActiveColor(v) => Ok(vec!["ScrollbarThumbActiveColor".into_py(py), { let m: &AzStyleScrollbarThumbActiveColorValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::WindowDragRegion(v) => Ok(vec!["WindowDragRegion".into_py(py), { let m: &AzStyleWindowDragRegionValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::CaretAnimationDuration(v) => Ok(vec!["CaretAnimationDuration".into_py(py), { let m: &AzStyleCaretAnimationDurationValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::ScrollPadding(v) => Ok(vec!["ScrollPadding".into_py(py), { let m: &AzStyleScrollPaddingValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::Content(v) => Ok(vec!["Content".into_py(py), { let m: &AzStyleContentValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::CounterReset(v) => Ok(vec!["CounterReset".into_py(py), { let m: &AzStyleCounterResetValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::CounterIncrement(v) => Ok(vec!["CounterIncrement".into_py(py), { let m: &AzStyleCounterIncrementValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::OverflowAnchor(v) => Ok(vec!["OverflowAnchor".into_py(py), { let m: &AzStyleOverflowAnchorValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::Opacity(v) => Ok(vec!["Opacity".into_py(py), { let m: &AzStyleOpacityValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::Transform(v) => Ok(vec!["Transform".into_py(py), { let m: &AzStyleTransformVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::TransformOrigin(v) => Ok(vec!["TransformOrigin".into_py(py), { let m: &AzStyleTransformOriginValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::PerspectiveOrigin(v) => Ok(vec!["PerspectiveOrigin".into_py(py), { let m: &AzStylePerspectiveOriginValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackfaceVisibility(v) => Ok(vec!["BackfaceVisibility".into_py(py), { let m: &AzStyleBackfaceVisibilityValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::Perspective(v) => Ok(vec!["Perspective".into_py(py), { let m: &AzStylePerspectiveValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::TransformStyle(v) => Ok(vec!["TransformStyle".into_py(py), { let m: &AzStyleTransformStyleValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::MixBlendMode(v) => Ok(vec!["MixBlendMode".into_py(py), { let m: &AzStyleMixBlendModeValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::Filter(v) => Ok(vec!["Filter".into_py(py), { let m: &AzStyleFilterVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackdropFilter(v) => Ok(vec!["BackdropFilter".into_py(py), { let m: &AzStyleFilterVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::TextShadow(v) => Ok(vec!["TextShadow".into_py(py), { let m: &AzStyleBoxShadowValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -23175,6 +27385,44 @@ impl AzTextInput {
             mem::transmute(text),
         )) }
     }
+    fn set_caret_animation(&mut self, caret_animation: AzCaretAnimationEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzTextInput_setCaretAnimation(
+            mem::transmute(self),
+            mem::transmute(caret_animation),
+        )) }
+    }
+    fn with_caret_animation(&mut self, caret_animation: AzCaretAnimationEnumWrapper) -> AzTextInput {
+        unsafe { mem::transmute(crate::AzTextInput_withCaretAnimation(
+            mem::transmute(self),
+            mem::transmute(caret_animation),
+        )) }
+    }
+    fn set_spellcheck(&mut self, language: String) -> () {
+        let language = pystring_to_azstring(&language);
+        unsafe { mem::transmute(crate::AzTextInput_setSpellcheck(
+            mem::transmute(self),
+            mem::transmute(language),
+        )) }
+    }
+    fn with_spellcheck(&mut self, language: String) -> AzTextInput {
+        let language = pystring_to_azstring(&language);
+        unsafe { mem::transmute(crate::AzTextInput_withSpellcheck(
+            mem::transmute(self),
+            mem::transmute(language),
+        )) }
+    }
+    fn set_secure(&mut self, secure: bool) -> () {
+        unsafe { mem::transmute(crate::AzTextInput_setSecure(
+            mem::transmute(self),
+            mem::transmute(secure),
+        )) }
+    }
+    fn with_secure(&mut self, secure: bool) -> AzTextInput {
+        unsafe { mem::transmute(crate::AzTextInput_withSecure(
+            mem::transmute(self),
+            mem::transmute(secure),
+        )) }
+    }
     fn set_placeholder_style(&mut self, placeholder_style: AzNodeDataInlineCssPropertyVec) -> () {
         unsafe { mem::transmute(crate::AzTextInput_setPlaceholderStyle(
             mem::transmute(self),
@@ -23231,7 +27479,7 @@ impl PyObjectProtocol for AzTextInput {
 #[pymethods]
 impl AzTextInputStateWrapper {
     #[new]
-    fn __new__(inner: AzTextInputState, on_text_input: AzOptionTextInputOnTextInputEnumWrapper, on_virtual_key_down: AzOptionTextInputOnVirtualKeyDownEnumWrapper, on_focus_lost: AzOptionTextInputOnFocusLostEnumWrapper, update_text_input_before_calling_focus_lost_fn: bool, update_text_input_before_calling_vk_down_fn: bool, cursor_animation: AzOptionTimerIdEnumWrapper) -> Self {
+    fn __new__(inner: AzTextInputState, on_text_input: AzOptionTextInputOnTextInputEnumWrapper, on_virtual_key_down: AzOptionTextInputOnVirtualKeyDownEnumWrapper, on_focus_lost: AzOptionTextInputOnFocusLostEnumWrapper, update_text_input_before_calling_focus_lost_fn: bool, update_text_input_before_calling_vk_down_fn: bool, cursor_animation: AzOptionTimerIdEnumWrapper, caret_animation: AzCaretAnimationEnumWrapper, spellcheck_language: AzOptionStringEnumWrapper, on_spelling_suggestions: AzOptionTextInputOnSpellingSuggestionsEnumWrapper, secure: bool) -> Self {
         Self {
             inner,
             on_text_input,
@@ -23240,6 +27488,10 @@ impl AzTextInputStateWrapper {
             update_text_input_before_calling_focus_lost_fn,
             update_text_input_before_calling_vk_down_fn,
             cursor_animation,
+            caret_animation,
+            spellcheck_language,
+            on_spelling_suggestions,
+            secure,
         }
     }
 
@@ -23295,12 +27547,10 @@ impl AzTextInputSelectionEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzTextInputSelection;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzTextInputSelection::All => Ok(vec!["All".into_py(py), ().into_py(py)]),
             AzTextInputSelection::FromTo(v) => Ok(vec!["FromTo".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -23444,6 +27694,71 @@ impl PyObjectProtocol for AzTextInputOnFocusLostCallback {
     }
 }
 
+#[pymethods]
+impl AzTextInputOnSpellingSuggestions {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTextInputOnSpellingSuggestionsCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextInputOnSpellingSuggestions {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputOnSpellingSuggestions = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputOnSpellingSuggestions = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextInputOnSpellingSuggestionsCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextInputOnSpellingSuggestionsCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputOnSpellingSuggestionsCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputOnSpellingSuggestionsCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextInputSpellingSuggestions {
+    #[new]
+    fn __new__(range: AzTextInputSelectionRange, word: AzString, suggestions: AzStringVec) -> Self {
+        Self {
+            range,
+            word,
+            suggestions,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextInputSpellingSuggestions {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputSpellingSuggestions = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::TextInputSpellingSuggestions = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOnTextInputReturn {
     #[new]
@@ -23497,7 +27812,7 @@ impl PyObjectProtocol for AzTextInputValidEnumWrapper {
 #[pymethods]
 impl AzNumberInput {
     #[new]
-    fn new(number: f32) -> AzNumberInput {
+    fn new(number: f64) -> AzNumberInput {
         unsafe { mem::transmute(crate::AzNumberInput_new(
             mem::transmute(number),
         )) }
@@ -23538,6 +27853,90 @@ impl AzNumberInput {
             mem::transmute(style),
         )) }
     }
+    fn set_spinner_button_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzNumberInput_setSpinnerButtonStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn with_spinner_button_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> AzNumberInput {
+        unsafe { mem::transmute(crate::AzNumberInput_withSpinnerButtonStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_invalid_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzNumberInput_setInvalidStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn with_invalid_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> AzNumberInput {
+        unsafe { mem::transmute(crate::AzNumberInput_withInvalidStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_mode(&mut self, mode: AzNumberInputModeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzNumberInput_setMode(
+            mem::transmute(self),
+            mem::transmute(mode),
+        )) }
+    }
+    fn with_mode(&mut self, mode: AzNumberInputModeEnumWrapper) -> AzNumberInput {
+        unsafe { mem::transmute(crate::AzNumberInput_withMode(
+            mem::transmute(self),
+            mem::transmute(mode),
+        )) }
+    }
+    fn set_min(&mut self, min: f64) -> () {
+        unsafe { mem::transmute(crate::AzNumberInput_setMin(
+            mem::transmute(self),
+            mem::transmute(min),
+        )) }
+    }
+    fn with_min(&mut self, min: f64) -> AzNumberInput {
+        unsafe { mem::transmute(crate::AzNumberInput_withMin(
+            mem::transmute(self),
+            mem::transmute(min),
+        )) }
+    }
+    fn set_max(&mut self, max: f64) -> () {
+        unsafe { mem::transmute(crate::AzNumberInput_setMax(
+            mem::transmute(self),
+            mem::transmute(max),
+        )) }
+    }
+    fn with_max(&mut self, max: f64) -> AzNumberInput {
+        unsafe { mem::transmute(crate::AzNumberInput_withMax(
+            mem::transmute(self),
+            mem::transmute(max),
+        )) }
+    }
+    fn set_step(&mut self, step: f64) -> () {
+        unsafe { mem::transmute(crate::AzNumberInput_setStep(
+            mem::transmute(self),
+            mem::transmute(step),
+        )) }
+    }
+    fn with_step(&mut self, step: f64) -> AzNumberInput {
+        unsafe { mem::transmute(crate::AzNumberInput_withStep(
+            mem::transmute(self),
+            mem::transmute(step),
+        )) }
+    }
+    fn set_decimal_separator(&mut self, decimal_separator: u32) -> () {
+        unsafe { mem::transmute(crate::AzNumberInput_setDecimalSeparator(
+            mem::transmute(self),
+            mem::transmute(decimal_separator),
+        )) }
+    }
+    fn with_decimal_separator(&mut self, decimal_separator: u32) -> AzNumberInput {
+        unsafe { mem::transmute(crate::AzNumberInput_withDecimalSeparator(
+            mem::transmute(self),
+            mem::transmute(decimal_separator),
+        )) }
+    }
     fn dom(&mut self) -> AzDom {
         unsafe { mem::transmute(crate::AzNumberInput_dom(
             mem::transmute(self),
@@ -23558,11 +27957,13 @@ impl PyObjectProtocol for AzNumberInput {
 #[pymethods]
 impl AzNumberInputStateWrapper {
     #[new]
-    fn __new__(inner: AzNumberInputState, on_value_change: AzOptionNumberInputOnValueChangeEnumWrapper, on_focus_lost: AzOptionNumberInputOnFocusLostEnumWrapper) -> Self {
+    fn __new__(inner: AzNumberInputState, on_value_change: AzOptionNumberInputOnValueChangeEnumWrapper, on_focus_lost: AzOptionNumberInputOnFocusLostEnumWrapper, invalid_style: AzNodeDataInlineCssPropertyVec, valid_style: AzNodeDataInlineCssPropertyVec) -> Self {
         Self {
             inner,
             on_value_change,
             on_focus_lost,
+            invalid_style,
+            valid_style,
         }
     }
 
@@ -23578,15 +27979,47 @@ impl PyObjectProtocol for AzNumberInputStateWrapper {
     }
 }
 
+#[pymethods]
+impl AzNumberInputModeEnumWrapper {
+    #[classattr]
+    fn Integer() -> AzNumberInputModeEnumWrapper { AzNumberInputModeEnumWrapper { inner: AzNumberInputMode::Integer } }
+    #[classattr]
+    fn Float() -> AzNumberInputModeEnumWrapper { AzNumberInputModeEnumWrapper { inner: AzNumberInputMode::Float } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzNumberInputModeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::number_input::NumberInputMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::number_input::NumberInputMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzNumberInputModeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzNumberInputState {
     #[new]
-    fn __new__(previous: f32, number: f32, min: f32, max: f32) -> Self {
+    fn __new__(previous: f64, number: f64, min: f64, max: f64, step: f64, mode: AzNumberInputModeEnumWrapper, decimal_separator: u32, is_valid: bool) -> Self {
         Self {
             previous,
             number,
             min,
             max,
+            step,
+            mode,
+            decimal_separator,
+            is_valid,
         }
     }
 
@@ -24555,15 +28988,13 @@ impl AzNodeTypeFieldValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzNodeTypeFieldValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzNodeTypeFieldValue::TextInput(v) => Ok(vec!["TextInput".into_py(py), v.clone().into_py(py)]),
             AzNodeTypeFieldValue::NumberInput(v) => Ok(vec!["NumberInput".into_py(py), v.into_py(py)]),
             AzNodeTypeFieldValue::CheckBox(v) => Ok(vec!["CheckBox".into_py(py), v.into_py(py)]),
             AzNodeTypeFieldValue::ColorInput(v) => Ok(vec!["ColorInput".into_py(py), v.clone().into_py(py)]),
             AzNodeTypeFieldValue::FileInput(v) => Ok(vec!["FileInput".into_py(py), { let m: &AzOptionStringEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -25075,6 +29506,179 @@ impl PyObjectProtocol for AzDropDownOnChoiceChange {
     }
 }
 
+#[pymethods]
+impl AzToasts {
+    #[new]
+    fn new() -> AzToasts {
+        unsafe { mem::transmute(crate::AzToasts_new()) }
+    }
+    fn set_max_visible(&mut self, max_visible: usize) -> () {
+        unsafe { mem::transmute(crate::AzToasts_setMaxVisible(
+            mem::transmute(self),
+            mem::transmute(max_visible),
+        )) }
+    }
+    fn with_max_visible(&mut self, max_visible: usize) -> AzToasts {
+        unsafe { mem::transmute(crate::AzToasts_withMaxVisible(
+            mem::transmute(self),
+            mem::transmute(max_visible),
+        )) }
+    }
+    fn set_layer_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzToasts_setLayerStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn with_layer_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> AzToasts {
+        unsafe { mem::transmute(crate::AzToasts_withLayerStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_toast_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzToasts_setToastStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn with_toast_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> AzToasts {
+        unsafe { mem::transmute(crate::AzToasts_withToastStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_label_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzToasts_setLabelStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn with_label_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> AzToasts {
+        unsafe { mem::transmute(crate::AzToasts_withLabelStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_action_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> () {
+        unsafe { mem::transmute(crate::AzToasts_setActionStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn with_action_style(&mut self, style: AzNodeDataInlineCssPropertyVec) -> AzToasts {
+        unsafe { mem::transmute(crate::AzToasts_withActionStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzToasts_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzToasts {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::toasts::Toasts = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::toasts::Toasts = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzToast {
+    #[new]
+    fn new(message: String) -> AzToast {
+        let message = pystring_to_azstring(&message);
+        unsafe { mem::transmute(crate::AzToast_new(
+            mem::transmute(message),
+        )) }
+    }
+    fn set_duration(&mut self, duration: AzOptionDurationEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzToast_setDuration(
+            mem::transmute(self),
+            mem::transmute(duration),
+        )) }
+    }
+    fn with_duration(&mut self, duration: AzOptionDurationEnumWrapper) -> AzToast {
+        unsafe { mem::transmute(crate::AzToast_withDuration(
+            mem::transmute(self),
+            mem::transmute(duration),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzToast {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::toasts::Toast = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::toasts::Toast = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzToastId {
+    #[new]
+    fn __new__(id: usize) -> Self {
+        Self {
+            id,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzToastId {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::toasts::ToastId = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::toasts::ToastId = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzToastOnAction {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzToastOnActionCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzToastOnAction {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::toasts::ToastOnAction = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::toasts::ToastOnAction = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzToastOnActionCallback {
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzToastOnActionCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::toasts::ToastOnActionCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::toasts::ToastOnActionCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzNodeHierarchyItem {
     #[new]
@@ -25131,12 +29735,10 @@ impl AzCssPropertySourceEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssPropertySource;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzCssPropertySource::Css(v) => Ok(vec!["Css".into_py(py), v.clone().into_py(py)]),
             AzCssPropertySource::Inline => Ok(vec!["Inline".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -25153,12 +29755,16 @@ impl PyObjectProtocol for AzCssPropertySourceEnumWrapper {
 #[pymethods]
 impl AzStyledNodeState {
     #[new]
-    fn __new__(normal: bool, hover: bool, active: bool, focused: bool) -> Self {
+    fn __new__(normal: bool, hover: bool, active: bool, focused: bool, disabled: bool, checked: bool, selected: bool, indeterminate: bool) -> Self {
         Self {
             normal,
             hover,
             active,
             focused,
+            disabled,
+            checked,
+            selected,
+            indeterminate,
         }
     }
 
@@ -28338,6 +32944,11 @@ impl AzGl {
             mem::transmute(self),
         )) }
     }
+    fn get_quirks(&self) -> AzGlQuirks {
+        unsafe { mem::transmute(crate::AzGl_getQuirks(
+            mem::transmute(self),
+        )) }
+    }
     fn buffer_data_untyped(&self, target: u32, size: isize, data: AzGlVoidPtrConst, usage: u32) -> () {
         unsafe { mem::transmute(crate::AzGl_bufferDataUntyped(
             mem::transmute(self),
@@ -30138,6 +34749,83 @@ impl PyObjectProtocol for AzGl {
     }
 }
 
+#[pymethods]
+impl AzGlQuirks {
+    #[new]
+    fn __new__(force_software_rendering: bool, disable_subpixel_aa: bool, disable_optimized_shaders: bool, disable_program_cache: bool, disable_multithreading: bool, disable_partial_present: bool) -> Self {
+        Self {
+            force_software_rendering,
+            disable_subpixel_aa,
+            disable_optimized_shaders,
+            disable_program_cache,
+            disable_multithreading,
+            disable_partial_present,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGlQuirks {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::gl::GlQuirks = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::gl::GlQuirks = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGlQuirkRule {
+    #[new]
+    fn new() -> AzGlQuirkRule {
+        unsafe { mem::transmute(crate::AzGlQuirkRule_new()) }
+    }
+    fn with_vendor(self, vendor: String) -> AzGlQuirkRule {
+        let vendor = pystring_to_azstring(&vendor);
+        unsafe { mem::transmute(crate::AzGlQuirkRule_withVendor(
+            mem::transmute(self),
+            mem::transmute(vendor),
+        )) }
+    }
+    fn with_renderer(self, renderer: String) -> AzGlQuirkRule {
+        let renderer = pystring_to_azstring(&renderer);
+        unsafe { mem::transmute(crate::AzGlQuirkRule_withRenderer(
+            mem::transmute(self),
+            mem::transmute(renderer),
+        )) }
+    }
+    fn with_version(self, version: String) -> AzGlQuirkRule {
+        let version = pystring_to_azstring(&version);
+        unsafe { mem::transmute(crate::AzGlQuirkRule_withVersion(
+            mem::transmute(self),
+            mem::transmute(version),
+        )) }
+    }
+    fn with_enable(self, enable: AzGlQuirks) -> AzGlQuirkRule {
+        unsafe { mem::transmute(crate::AzGlQuirkRule_withEnable(
+            mem::transmute(self),
+            mem::transmute(enable),
+        )) }
+    }
+    fn with_disable(self, disable: AzGlQuirks) -> AzGlQuirkRule {
+        unsafe { mem::transmute(crate::AzGlQuirkRule_withDisable(
+            mem::transmute(self),
+            mem::transmute(disable),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGlQuirkRule {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::gl::GlQuirkRule = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::gl::GlQuirkRule = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzGlShaderPrecisionFormatReturn {
     #[new]
@@ -30982,13 +35670,11 @@ impl AzRawImageDataEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzRawImageData;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzRawImageData::U8(v) => Ok(vec!["U8".into_py(py), v.clone().into_py(py)]),
             AzRawImageData::U16(v) => Ok(vec!["U16".into_py(py), v.clone().into_py(py)]),
             AzRawImageData::F32(v) => Ok(vec!["F32".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -31405,16 +36091,14 @@ impl AzSvgNodeEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzSvgNode;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzSvgNode::MultiPolygonCollection(v) => Ok(vec!["MultiPolygonCollection".into_py(py), v.clone().into_py(py)]),
             AzSvgNode::MultiPolygon(v) => Ok(vec!["MultiPolygon".into_py(py), v.clone().into_py(py)]),
             AzSvgNode::MultiShape(v) => Ok(vec!["MultiShape".into_py(py), v.clone().into_py(py)]),
             AzSvgNode::Path(v) => Ok(vec!["Path".into_py(py), v.clone().into_py(py)]),
             AzSvgNode::Circle(v) => Ok(vec!["Circle".into_py(py), v.clone().into_py(py)]),
             AzSvgNode::Rect(v) => Ok(vec!["Rect".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -31444,15 +36128,13 @@ impl AzSvgSimpleNodeEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzSvgSimpleNode;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzSvgSimpleNode::Path(v) => Ok(vec!["Path".into_py(py), v.clone().into_py(py)]),
             AzSvgSimpleNode::Circle(v) => Ok(vec!["Circle".into_py(py), v.clone().into_py(py)]),
             AzSvgSimpleNode::Rect(v) => Ok(vec!["Rect".into_py(py), v.clone().into_py(py)]),
             AzSvgSimpleNode::CircleHole(v) => Ok(vec!["CircleHole".into_py(py), v.clone().into_py(py)]),
             AzSvgSimpleNode::RectHole(v) => Ok(vec!["RectHole".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -31628,13 +36310,11 @@ impl AzSvgPathElementEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzSvgPathElement;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzSvgPathElement::Line(v) => Ok(vec!["Line".into_py(py), v.clone().into_py(py)]),
             AzSvgPathElement::QuadraticCurve(v) => Ok(vec!["QuadraticCurve".into_py(py), v.clone().into_py(py)]),
             AzSvgPathElement::CubicCurve(v) => Ok(vec!["CubicCurve".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -32384,13 +37064,11 @@ impl AzIndentEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzIndent;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzIndent::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzIndent::Spaces(v) => Ok(vec!["Spaces".into_py(py), v.into_py(py)]),
             AzIndent::Tabs => Ok(vec!["Tabs".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -32418,14 +37096,12 @@ impl AzSvgFitToEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzSvgFitTo;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzSvgFitTo::Original => Ok(vec!["Original".into_py(py), ().into_py(py)]),
             AzSvgFitTo::Width(v) => Ok(vec!["Width".into_py(py), v.into_py(py)]),
             AzSvgFitTo::Height(v) => Ok(vec!["Height".into_py(py), v.into_py(py)]),
             AzSvgFitTo::Zoom(v) => Ok(vec!["Zoom".into_py(py), v.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -32449,12 +37125,10 @@ impl AzSvgStyleEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzSvgStyle;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzSvgStyle::Fill(v) => Ok(vec!["Fill".into_py(py), v.clone().into_py(py)]),
             AzSvgStyle::Stroke(v) => Ok(vec!["Stroke".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -33103,12 +37777,10 @@ impl AzInstantEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzInstant;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzInstant::System(v) => Ok(vec!["System".into_py(py), v.clone().into_py(py)]),
             AzInstant::Tick(v) => Ok(vec!["Tick".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -33195,12 +37867,10 @@ impl AzDurationEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzDuration;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzDuration::System(v) => Ok(vec!["System".into_py(py), v.clone().into_py(py)]),
             AzDuration::Tick(v) => Ok(vec!["Tick".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -33417,6 +38087,25 @@ impl PyObjectProtocol for AzThreadReceiver {
     }
 }
 
+#[pymethods]
+impl AzEventLoopProxy {
+    fn wake(&self) -> bool {
+        unsafe { mem::transmute(crate::AzEventLoopProxy_wake(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzEventLoopProxy {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::EventLoopProxy = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::EventLoopProxy = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzThreadSendMsgEnumWrapper {
     #[classattr]
@@ -33429,13 +38118,11 @@ impl AzThreadSendMsgEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzThreadSendMsg;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzThreadSendMsg::TerminateThread => Ok(vec!["TerminateThread".into_py(py), ().into_py(py)]),
             AzThreadSendMsg::Tick => Ok(vec!["Tick".into_py(py), ().into_py(py)]),
             AzThreadSendMsg::Custom(v) => Ok(vec!["Custom".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -33459,12 +38146,10 @@ impl AzThreadReceiveMsgEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzThreadReceiveMsg;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzThreadReceiveMsg::WriteBack(v) => Ok(vec!["WriteBack".into_py(py), v.clone().into_py(py)]),
             AzThreadReceiveMsg::Update(v) => Ok(vec!["Update".into_py(py), { let m: &AzUpdateEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -33528,6 +38213,89 @@ impl PyObjectProtocol for AzGetSystemTimeFn {
     }
 }
 
+#[pymethods]
+impl AzGetSystemIdleTimeFn {
+    // impl GetSystemIdleTimeFn {
+
+    #[new]
+    pub fn __new__() -> Self {
+        let callbacks: AzSystemCallbacks = unsafe { mem::transmute(crate::AzSystemCallbacks_libraryInternal()) };
+        callbacks.get_system_idle_time_fn
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGetSystemIdleTimeFn {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::GetSystemIdleTimeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::GetSystemIdleTimeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGetLogLinesFn {
+    // impl GetLogLinesFn {
+
+    #[new]
+    pub fn __new__() -> Self {
+        let callbacks: AzSystemCallbacks = unsafe { mem::transmute(crate::AzSystemCallbacks_libraryInternal()) };
+        callbacks.get_log_lines_fn
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGetLogLinesFn {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::GetLogLinesCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::GetLogLinesCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzKeycodeToCharFn {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzKeycodeToCharFn {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::KeycodeToCharCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::KeycodeToCharCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSetLogLevelFn {
+    // impl SetLogLevelFn {
+
+    #[new]
+    pub fn __new__() -> Self {
+        let callbacks: AzSystemCallbacks = unsafe { mem::transmute(crate::AzSystemCallbacks_libraryInternal()) };
+        callbacks.set_log_level_fn
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSetLogLevelFn {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::SetLogLevelCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::SetLogLevelCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCheckThreadFinishedFn {
 }
@@ -33676,9 +38444,7 @@ impl AzFmtValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzFmtValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzFmtValue::Bool(v) => Ok(vec!["Bool".into_py(py), v.into_py(py)]),
             AzFmtValue::Uchar(v) => Ok(vec!["Uchar".into_py(py), v.into_py(py)]),
             AzFmtValue::Schar(v) => Ok(vec!["Schar".into_py(py), v.into_py(py)]),
@@ -33694,7 +38460,7 @@ impl AzFmtValueEnumWrapper {
             AzFmtValue::Double(v) => Ok(vec!["Double".into_py(py), v.into_py(py)]),
             AzFmtValue::Str(v) => Ok(vec!["Str".into_py(py), v.clone().into_py(py)]),
             AzFmtValue::StrVec(v) => Ok(vec!["StrVec".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -34292,6 +39058,31 @@ impl PyObjectProtocol for AzInlineWordVec {
     }
 }
 
+#[pymethods]
+impl AzPaintPrimitiveVec {
+    /// Creates a new `PaintPrimitiveEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzPaintPrimitiveEnumWrapper>) -> Self {
+        let m: azul_impl::callbacks::PaintPrimitiveVec = azul_impl::callbacks::PaintPrimitiveVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the PaintPrimitiveEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzPaintPrimitiveEnumWrapper> {
+        let m: &azul_impl::callbacks::PaintPrimitiveVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzPaintPrimitiveVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::PaintPrimitiveVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::PaintPrimitiveVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzInlineGlyphVec {
     /// Creates a new `InlineGlyphVec` from a Python array
@@ -34317,6 +39108,31 @@ impl PyObjectProtocol for AzInlineGlyphVec {
     }
 }
 
+#[pymethods]
+impl AzTextUnderlineVec {
+    /// Creates a new `TextUnderlineVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTextUnderline>) -> Self {
+        let m: azul_impl::callbacks::TextUnderlineVec = azul_impl::callbacks::TextUnderlineVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TextUnderline as a Python array
+    fn array(&self) -> Vec<AzTextUnderline> {
+        let m: &azul_impl::callbacks::TextUnderlineVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextUnderlineVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextUnderlineVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextUnderlineVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzInlineTextHitVec {
     /// Creates a new `InlineTextHitVec` from a Python array
@@ -34367,6 +39183,31 @@ impl PyObjectProtocol for AzMonitorVec {
     }
 }
 
+#[pymethods]
+impl AzTouchPointVec {
+    /// Creates a new `TouchPointVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTouchPoint>) -> Self {
+        let m: azul_core::window::TouchPointVec = azul_core::window::TouchPointVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TouchPoint as a Python array
+    fn array(&self) -> Vec<AzTouchPoint> {
+        let m: &azul_core::window::TouchPointVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTouchPointVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TouchPointVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TouchPointVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzVideoModeVec {
     /// Creates a new `VideoModeVec` from a Python array
@@ -34867,6 +39708,56 @@ impl PyObjectProtocol for AzVirtualKeyCodeVec {
     }
 }
 
+#[pymethods]
+impl AzShortcutConflictVec {
+    /// Creates a new `ShortcutConflictVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzShortcutConflict>) -> Self {
+        let m: azul_core::window::ShortcutConflictVec = azul_core::window::ShortcutConflictVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the ShortcutConflict as a Python array
+    fn array(&self) -> Vec<AzShortcutConflict> {
+        let m: &azul_core::window::ShortcutConflictVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzShortcutConflictVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutConflictVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutConflictVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzShortcutVec {
+    /// Creates a new `ShortcutVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzShortcut>) -> Self {
+        let m: azul_core::window::ShortcutVec = azul_core::window::ShortcutVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the Shortcut as a Python array
+    fn array(&self) -> Vec<AzShortcut> {
+        let m: &azul_core::window::ShortcutVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzShortcutVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCascadeInfoVec {
     /// Creates a new `CascadeInfoVec` from a Python array
@@ -35441,13 +40332,11 @@ impl AzStyleFontFamilyVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleFontFamilyVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleFontFamilyVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzStyleFontFamilyVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzStyleFontFamilyVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35471,13 +40360,11 @@ impl AzListViewRowVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzListViewRowVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzListViewRowVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzListViewRowVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzListViewRowVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35501,13 +40388,11 @@ impl AzStyleFilterVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleFilterVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleFilterVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzStyleFilterVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzStyleFilterVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35531,13 +40416,11 @@ impl AzLogicalRectVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzLogicalRectVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzLogicalRectVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzLogicalRectVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzLogicalRectVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35561,13 +40444,11 @@ impl AzNodeTypeIdInfoMapVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzNodeTypeIdInfoMapVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzNodeTypeIdInfoMapVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzNodeTypeIdInfoMapVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzNodeTypeIdInfoMapVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35591,13 +40472,11 @@ impl AzInputOutputTypeIdInfoMapVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzInputOutputTypeIdInfoMapVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzInputOutputTypeIdInfoMapVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzInputOutputTypeIdInfoMapVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzInputOutputTypeIdInfoMapVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35621,13 +40500,11 @@ impl AzNodeIdNodeMapVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzNodeIdNodeMapVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzNodeIdNodeMapVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzNodeIdNodeMapVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzNodeIdNodeMapVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35651,13 +40528,11 @@ impl AzInputOutputTypeIdVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzInputOutputTypeIdVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzInputOutputTypeIdVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzInputOutputTypeIdVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzInputOutputTypeIdVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35681,13 +40556,11 @@ impl AzNodeTypeFieldVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzNodeTypeFieldVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzNodeTypeFieldVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzNodeTypeFieldVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzNodeTypeFieldVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35711,13 +40584,11 @@ impl AzInputConnectionVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzInputConnectionVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzInputConnectionVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzInputConnectionVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzInputConnectionVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35741,13 +40612,11 @@ impl AzOutputNodeAndIndexVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOutputNodeAndIndexVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOutputNodeAndIndexVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzOutputNodeAndIndexVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzOutputNodeAndIndexVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35771,13 +40640,11 @@ impl AzOutputConnectionVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOutputConnectionVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOutputConnectionVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzOutputConnectionVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzOutputConnectionVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35801,13 +40668,11 @@ impl AzInputNodeAndIndexVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzInputNodeAndIndexVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzInputNodeAndIndexVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzInputNodeAndIndexVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzInputNodeAndIndexVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35831,13 +40696,11 @@ impl AzAccessibilityStateVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzAccessibilityStateVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzAccessibilityStateVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzAccessibilityStateVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzAccessibilityStateVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35861,13 +40724,11 @@ impl AzMenuItemVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzMenuItemVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzMenuItemVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzMenuItemVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzMenuItemVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35891,13 +40752,11 @@ impl AzTessellatedSvgNodeVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzTessellatedSvgNodeVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzTessellatedSvgNodeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzTessellatedSvgNodeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzTessellatedSvgNodeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35921,13 +40780,11 @@ impl AzTessellatedColoredSvgNodeVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzTessellatedColoredSvgNodeVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzTessellatedColoredSvgNodeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzTessellatedColoredSvgNodeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzTessellatedColoredSvgNodeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35951,13 +40808,11 @@ impl AzXmlNodeVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzXmlNodeVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzXmlNodeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzXmlNodeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzXmlNodeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -35981,13 +40836,11 @@ impl AzFmtArgVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzFmtArgVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzFmtArgVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzFmtArgVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzFmtArgVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36011,13 +40864,11 @@ impl AzInlineLineVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzInlineLineVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzInlineLineVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzInlineLineVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzInlineLineVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36041,13 +40892,11 @@ impl AzInlineWordVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzInlineWordVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzInlineWordVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzInlineWordVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzInlineWordVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36061,6 +40910,34 @@ impl PyObjectProtocol for AzInlineWordVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzPaintPrimitiveVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzPaintPrimitiveVecDestructorEnumWrapper { AzPaintPrimitiveVecDestructorEnumWrapper { inner: AzPaintPrimitiveVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzPaintPrimitiveVecDestructorEnumWrapper { AzPaintPrimitiveVecDestructorEnumWrapper { inner: AzPaintPrimitiveVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzPaintPrimitiveVecDestructor;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzPaintPrimitiveVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzPaintPrimitiveVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzPaintPrimitiveVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzPaintPrimitiveVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::PaintPrimitiveVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::PaintPrimitiveVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzInlineGlyphVecDestructorEnumWrapper {
     #[classattr]
@@ -36071,13 +40948,11 @@ impl AzInlineGlyphVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzInlineGlyphVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzInlineGlyphVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzInlineGlyphVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzInlineGlyphVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36091,6 +40966,34 @@ impl PyObjectProtocol for AzInlineGlyphVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzTextUnderlineVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTextUnderlineVecDestructorEnumWrapper { AzTextUnderlineVecDestructorEnumWrapper { inner: AzTextUnderlineVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTextUnderlineVecDestructorEnumWrapper { AzTextUnderlineVecDestructorEnumWrapper { inner: AzTextUnderlineVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTextUnderlineVecDestructor;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzTextUnderlineVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTextUnderlineVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTextUnderlineVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextUnderlineVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextUnderlineVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TextUnderlineVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzInlineTextHitVecDestructorEnumWrapper {
     #[classattr]
@@ -36101,13 +41004,11 @@ impl AzInlineTextHitVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzInlineTextHitVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzInlineTextHitVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzInlineTextHitVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzInlineTextHitVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36131,13 +41032,11 @@ impl AzMonitorVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzMonitorVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzMonitorVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzMonitorVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzMonitorVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36151,6 +41050,34 @@ impl PyObjectProtocol for AzMonitorVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzTouchPointVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTouchPointVecDestructorEnumWrapper { AzTouchPointVecDestructorEnumWrapper { inner: AzTouchPointVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTouchPointVecDestructorEnumWrapper { AzTouchPointVecDestructorEnumWrapper { inner: AzTouchPointVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTouchPointVecDestructor;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzTouchPointVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTouchPointVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTouchPointVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTouchPointVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TouchPointVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::TouchPointVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzVideoModeVecDestructorEnumWrapper {
     #[classattr]
@@ -36161,13 +41088,11 @@ impl AzVideoModeVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzVideoModeVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzVideoModeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzVideoModeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzVideoModeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36191,13 +41116,11 @@ impl AzDomVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzDomVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzDomVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzDomVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzDomVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36221,13 +41144,11 @@ impl AzIdOrClassVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzIdOrClassVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzIdOrClassVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzIdOrClassVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzIdOrClassVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36251,13 +41172,11 @@ impl AzNodeDataInlineCssPropertyVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzNodeDataInlineCssPropertyVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzNodeDataInlineCssPropertyVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzNodeDataInlineCssPropertyVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzNodeDataInlineCssPropertyVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36281,13 +41200,11 @@ impl AzStyleBackgroundContentVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBackgroundContentVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBackgroundContentVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzStyleBackgroundContentVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzStyleBackgroundContentVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36311,13 +41228,11 @@ impl AzStyleBackgroundPositionVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBackgroundPositionVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBackgroundPositionVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzStyleBackgroundPositionVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzStyleBackgroundPositionVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36341,13 +41256,11 @@ impl AzStyleBackgroundRepeatVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBackgroundRepeatVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBackgroundRepeatVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzStyleBackgroundRepeatVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzStyleBackgroundRepeatVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36371,13 +41284,11 @@ impl AzStyleBackgroundSizeVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBackgroundSizeVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleBackgroundSizeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzStyleBackgroundSizeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzStyleBackgroundSizeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36401,13 +41312,11 @@ impl AzStyleTransformVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleTransformVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyleTransformVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzStyleTransformVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzStyleTransformVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36431,13 +41340,11 @@ impl AzCssPropertyVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssPropertyVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzCssPropertyVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzCssPropertyVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzCssPropertyVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36461,13 +41368,11 @@ impl AzSvgMultiPolygonVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzSvgMultiPolygonVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzSvgMultiPolygonVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzSvgMultiPolygonVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzSvgMultiPolygonVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36491,13 +41396,11 @@ impl AzSvgSimpleNodeVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzSvgSimpleNodeVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzSvgSimpleNodeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzSvgSimpleNodeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzSvgSimpleNodeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36521,13 +41424,11 @@ impl AzSvgPathVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzSvgPathVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzSvgPathVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzSvgPathVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzSvgPathVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36551,13 +41452,11 @@ impl AzVertexAttributeVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzVertexAttributeVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzVertexAttributeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzVertexAttributeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzVertexAttributeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36581,13 +41480,11 @@ impl AzSvgPathElementVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzSvgPathElementVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzSvgPathElementVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzSvgPathElementVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzSvgPathElementVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36611,13 +41508,11 @@ impl AzSvgVertexVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzSvgVertexVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzSvgVertexVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzSvgVertexVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzSvgVertexVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36641,13 +41536,11 @@ impl AzSvgColoredVertexVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzSvgColoredVertexVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzSvgColoredVertexVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzSvgColoredVertexVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzSvgColoredVertexVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36671,13 +41564,11 @@ impl AzU32VecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzU32VecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzU32VecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzU32VecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzU32VecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36701,13 +41592,11 @@ impl AzXWindowTypeVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzXWindowTypeVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzXWindowTypeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzXWindowTypeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzXWindowTypeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36731,13 +41620,11 @@ impl AzVirtualKeyCodeVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzVirtualKeyCodeVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzVirtualKeyCodeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzVirtualKeyCodeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzVirtualKeyCodeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36751,6 +41638,62 @@ impl PyObjectProtocol for AzVirtualKeyCodeVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzShortcutConflictVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzShortcutConflictVecDestructorEnumWrapper { AzShortcutConflictVecDestructorEnumWrapper { inner: AzShortcutConflictVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzShortcutConflictVecDestructorEnumWrapper { AzShortcutConflictVecDestructorEnumWrapper { inner: AzShortcutConflictVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzShortcutConflictVecDestructor;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzShortcutConflictVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzShortcutConflictVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzShortcutConflictVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzShortcutConflictVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutConflictVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutConflictVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzShortcutVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzShortcutVecDestructorEnumWrapper { AzShortcutVecDestructorEnumWrapper { inner: AzShortcutVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzShortcutVecDestructorEnumWrapper { AzShortcutVecDestructorEnumWrapper { inner: AzShortcutVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzShortcutVecDestructor;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzShortcutVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzShortcutVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzShortcutVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzShortcutVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ShortcutVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCascadeInfoVecDestructorEnumWrapper {
     #[classattr]
@@ -36761,13 +41704,11 @@ impl AzCascadeInfoVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCascadeInfoVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzCascadeInfoVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzCascadeInfoVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzCascadeInfoVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36791,13 +41732,11 @@ impl AzScanCodeVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzScanCodeVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzScanCodeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzScanCodeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzScanCodeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36821,13 +41760,11 @@ impl AzCssDeclarationVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssDeclarationVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzCssDeclarationVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzCssDeclarationVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzCssDeclarationVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36851,13 +41788,11 @@ impl AzCssPathSelectorVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssPathSelectorVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzCssPathSelectorVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzCssPathSelectorVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzCssPathSelectorVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36881,13 +41816,11 @@ impl AzStylesheetVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStylesheetVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStylesheetVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzStylesheetVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzStylesheetVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36911,13 +41844,11 @@ impl AzCssRuleBlockVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssRuleBlockVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzCssRuleBlockVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzCssRuleBlockVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzCssRuleBlockVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36941,13 +41872,11 @@ impl AzF32VecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzF32VecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzF32VecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzF32VecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzF32VecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -36971,13 +41900,11 @@ impl AzU16VecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzU16VecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzU16VecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzU16VecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzU16VecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37001,13 +41928,11 @@ impl AzU8VecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzU8VecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzU8VecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzU8VecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzU8VecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37031,13 +41956,11 @@ impl AzCallbackDataVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCallbackDataVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzCallbackDataVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzCallbackDataVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzCallbackDataVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37061,13 +41984,11 @@ impl AzDebugMessageVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzDebugMessageVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzDebugMessageVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzDebugMessageVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzDebugMessageVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37091,13 +42012,11 @@ impl AzGLuintVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzGLuintVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzGLuintVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzGLuintVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzGLuintVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37121,13 +42040,11 @@ impl AzGLintVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzGLintVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzGLintVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzGLintVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzGLintVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37151,13 +42068,11 @@ impl AzStringVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStringVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStringVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzStringVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzStringVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37181,13 +42096,11 @@ impl AzStringPairVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStringPairVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStringPairVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzStringPairVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzStringPairVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37211,13 +42124,11 @@ impl AzNormalizedLinearColorStopVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzNormalizedLinearColorStopVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzNormalizedLinearColorStopVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzNormalizedLinearColorStopVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzNormalizedLinearColorStopVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37241,13 +42152,11 @@ impl AzNormalizedRadialColorStopVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzNormalizedRadialColorStopVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzNormalizedRadialColorStopVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzNormalizedRadialColorStopVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzNormalizedRadialColorStopVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37271,13 +42180,11 @@ impl AzNodeIdVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzNodeIdVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzNodeIdVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzNodeIdVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzNodeIdVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37301,13 +42208,11 @@ impl AzNodeHierarchyItemVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzNodeHierarchyItemVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzNodeHierarchyItemVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzNodeHierarchyItemVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzNodeHierarchyItemVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37331,13 +42236,11 @@ impl AzStyledNodeVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyledNodeVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzStyledNodeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzStyledNodeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzStyledNodeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37361,13 +42264,11 @@ impl AzTagIdToNodeIdMappingVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzTagIdToNodeIdMappingVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzTagIdToNodeIdMappingVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzTagIdToNodeIdMappingVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzTagIdToNodeIdMappingVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37391,13 +42292,11 @@ impl AzParentWithNodeDepthVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzParentWithNodeDepthVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzParentWithNodeDepthVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzParentWithNodeDepthVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzParentWithNodeDepthVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37421,13 +42320,11 @@ impl AzNodeDataVecDestructorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzNodeDataVecDestructor;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzNodeDataVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
             AzNodeDataVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
             AzNodeDataVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37451,12 +42348,10 @@ impl AzOptionSvgPointEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionSvgPoint;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionSvgPoint::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionSvgPoint::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37480,12 +42375,10 @@ impl AzOptionListViewOnRowClickEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionListViewOnRowClick;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionListViewOnRowClick::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionListViewOnRowClick::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37509,12 +42402,10 @@ impl AzOptionListViewOnColumnClickEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionListViewOnColumnClick;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionListViewOnColumnClick::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionListViewOnColumnClick::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37538,12 +42429,10 @@ impl AzOptionListViewOnLazyLoadScrollEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionListViewOnLazyLoadScroll;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionListViewOnLazyLoadScroll::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionListViewOnLazyLoadScroll::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37567,12 +42456,10 @@ impl AzOptionMenuEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionMenu;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionMenu::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionMenu::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37596,12 +42483,10 @@ impl AzOptionPixelValueNoPercentEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionPixelValueNoPercent;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionPixelValueNoPercent::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionPixelValueNoPercent::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37625,12 +42510,10 @@ impl AzOptionDropDownOnChoiceChangeEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionDropDownOnChoiceChange;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionDropDownOnChoiceChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionDropDownOnChoiceChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37644,6 +42527,33 @@ impl PyObjectProtocol for AzOptionDropDownOnChoiceChangeEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionToastOnActionEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionToastOnActionEnumWrapper { AzOptionToastOnActionEnumWrapper { inner: AzOptionToastOnAction::None } }
+    #[staticmethod]
+    fn Some(v: AzToastOnAction) -> AzOptionToastOnActionEnumWrapper { AzOptionToastOnActionEnumWrapper { inner: AzOptionToastOnAction::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionToastOnAction;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzOptionToastOnAction::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionToastOnAction::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionToastOnActionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::toasts::OptionToastOnAction = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::toasts::OptionToastOnAction = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionResolvedTextLayoutOptionsEnumWrapper {
     #[classattr]
@@ -37654,12 +42564,10 @@ impl AzOptionResolvedTextLayoutOptionsEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionResolvedTextLayoutOptions;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionResolvedTextLayoutOptions::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionResolvedTextLayoutOptions::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37683,12 +42591,10 @@ impl AzOptionNodeGraphOnNodeAddedEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionNodeGraphOnNodeAdded;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionNodeGraphOnNodeAdded::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionNodeGraphOnNodeAdded::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37712,12 +42618,10 @@ impl AzOptionNodeGraphOnNodeRemovedEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionNodeGraphOnNodeRemoved;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionNodeGraphOnNodeRemoved::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionNodeGraphOnNodeRemoved::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37741,12 +42645,10 @@ impl AzOptionNodeGraphOnNodeGraphDraggedEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionNodeGraphOnNodeGraphDragged;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionNodeGraphOnNodeGraphDragged::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionNodeGraphOnNodeGraphDragged::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37770,12 +42672,10 @@ impl AzOptionNodeGraphOnNodeDraggedEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionNodeGraphOnNodeDragged;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionNodeGraphOnNodeDragged::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionNodeGraphOnNodeDragged::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37799,12 +42699,10 @@ impl AzOptionNodeGraphOnNodeConnectedEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionNodeGraphOnNodeConnected;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionNodeGraphOnNodeConnected::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionNodeGraphOnNodeConnected::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37828,12 +42726,10 @@ impl AzOptionNodeGraphOnNodeInputDisconnectedEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionNodeGraphOnNodeInputDisconnected;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionNodeGraphOnNodeInputDisconnected::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionNodeGraphOnNodeInputDisconnected::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37857,12 +42753,10 @@ impl AzOptionNodeGraphOnNodeOutputDisconnectedEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionNodeGraphOnNodeOutputDisconnected;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionNodeGraphOnNodeOutputDisconnected::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionNodeGraphOnNodeOutputDisconnected::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37886,12 +42780,10 @@ impl AzOptionNodeGraphOnNodeFieldEditedEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionNodeGraphOnNodeFieldEdited;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionNodeGraphOnNodeFieldEdited::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionNodeGraphOnNodeFieldEdited::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37915,12 +42807,10 @@ impl AzOptionColorInputOnValueChangeEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionColorInputOnValueChange;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionColorInputOnValueChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionColorInputOnValueChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37944,12 +42834,10 @@ impl AzOptionButtonOnClickEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionButtonOnClick;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionButtonOnClick::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionButtonOnClick::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -37973,12 +42861,10 @@ impl AzOptionTabOnClickEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionTabOnClick;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionTabOnClick::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionTabOnClick::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38002,12 +42888,10 @@ impl AzOptionFileInputOnPathChangeEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionFileInputOnPathChange;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionFileInputOnPathChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionFileInputOnPathChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38031,12 +42915,10 @@ impl AzOptionCheckBoxOnToggleEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionCheckBoxOnToggle;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionCheckBoxOnToggle::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionCheckBoxOnToggle::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38060,12 +42942,10 @@ impl AzOptionTextInputOnTextInputEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionTextInputOnTextInput;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionTextInputOnTextInput::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionTextInputOnTextInput::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38089,12 +42969,10 @@ impl AzOptionTextInputOnVirtualKeyDownEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionTextInputOnVirtualKeyDown;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionTextInputOnVirtualKeyDown::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionTextInputOnVirtualKeyDown::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38118,12 +42996,10 @@ impl AzOptionTextInputOnFocusLostEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionTextInputOnFocusLost;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionTextInputOnFocusLost::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionTextInputOnFocusLost::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38137,6 +43013,33 @@ impl PyObjectProtocol for AzOptionTextInputOnFocusLostEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionTextInputOnSpellingSuggestionsEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTextInputOnSpellingSuggestionsEnumWrapper { AzOptionTextInputOnSpellingSuggestionsEnumWrapper { inner: AzOptionTextInputOnSpellingSuggestions::None } }
+    #[staticmethod]
+    fn Some(v: AzTextInputOnSpellingSuggestions) -> AzOptionTextInputOnSpellingSuggestionsEnumWrapper { AzOptionTextInputOnSpellingSuggestionsEnumWrapper { inner: AzOptionTextInputOnSpellingSuggestions::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTextInputOnSpellingSuggestions;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzOptionTextInputOnSpellingSuggestions::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTextInputOnSpellingSuggestions::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTextInputOnSpellingSuggestionsEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::OptionTextInputOnSpellingSuggestions = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_input::OptionTextInputOnSpellingSuggestions = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionTextInputSelectionEnumWrapper {
     #[classattr]
@@ -38147,12 +43050,10 @@ impl AzOptionTextInputSelectionEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionTextInputSelection;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionTextInputSelection::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionTextInputSelection::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzTextInputSelectionEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38176,12 +43077,10 @@ impl AzOptionNumberInputOnFocusLostEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionNumberInputOnFocusLost;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionNumberInputOnFocusLost::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionNumberInputOnFocusLost::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38205,12 +43104,10 @@ impl AzOptionNumberInputOnValueChangeEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionNumberInputOnValueChange;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionNumberInputOnValueChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionNumberInputOnValueChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38234,12 +43131,10 @@ impl AzOptionMenuItemIconEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionMenuItemIcon;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionMenuItemIcon::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionMenuItemIcon::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzMenuItemIconEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38263,12 +43158,10 @@ impl AzOptionMenuCallbackEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionMenuCallback;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionMenuCallback::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionMenuCallback::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38292,12 +43185,10 @@ impl AzOptionVirtualKeyCodeComboEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionVirtualKeyCodeCombo;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionVirtualKeyCodeCombo::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionVirtualKeyCodeCombo::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38311,6 +43202,33 @@ impl PyObjectProtocol for AzOptionVirtualKeyCodeComboEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionShortcutConflictEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionShortcutConflictEnumWrapper { AzOptionShortcutConflictEnumWrapper { inner: AzOptionShortcutConflict::None } }
+    #[staticmethod]
+    fn Some(v: AzShortcutConflict) -> AzOptionShortcutConflictEnumWrapper { AzOptionShortcutConflictEnumWrapper { inner: AzOptionShortcutConflict::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionShortcutConflict;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzOptionShortcutConflict::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionShortcutConflict::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionShortcutConflictEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionShortcutConflict = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionShortcutConflict = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionCssPropertyEnumWrapper {
     #[classattr]
@@ -38321,12 +43239,10 @@ impl AzOptionCssPropertyEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionCssProperty;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionCssProperty::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionCssProperty::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzCssPropertyEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38350,12 +43266,10 @@ impl AzOptionPositionInfoEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionPositionInfo;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionPositionInfo::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionPositionInfo::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzPositionInfoEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38369,6 +43283,60 @@ impl PyObjectProtocol for AzOptionPositionInfoEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionNodeRectEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionNodeRectEnumWrapper { AzOptionNodeRectEnumWrapper { inner: AzOptionNodeRect::None } }
+    #[staticmethod]
+    fn Some(v: AzNodeRect) -> AzOptionNodeRectEnumWrapper { AzOptionNodeRectEnumWrapper { inner: AzOptionNodeRect::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionNodeRect;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzOptionNodeRect::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionNodeRect::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionNodeRectEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::ui_solver::OptionNodeRect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::ui_solver::OptionNodeRect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionScrollInfoEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionScrollInfoEnumWrapper { AzOptionScrollInfoEnumWrapper { inner: AzOptionScrollInfo::None } }
+    #[staticmethod]
+    fn Some(v: AzScrollInfo) -> AzOptionScrollInfoEnumWrapper { AzOptionScrollInfoEnumWrapper { inner: AzOptionScrollInfo::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionScrollInfo;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzOptionScrollInfo::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionScrollInfo::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionScrollInfoEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::OptionScrollInfo = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::OptionScrollInfo = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionTimerIdEnumWrapper {
     #[classattr]
@@ -38379,12 +43347,10 @@ impl AzOptionTimerIdEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionTimerId;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionTimerId::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionTimerId::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38408,12 +43374,10 @@ impl AzOptionThreadIdEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionThreadId;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionThreadId::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionThreadId::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38437,12 +43401,10 @@ impl AzOptionI16EnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionI16;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionI16::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionI16::Some(v) => Ok(vec!["Some".into_py(py), v.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38466,12 +43428,10 @@ impl AzOptionU16EnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionU16;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionU16::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionU16::Some(v) => Ok(vec!["Some".into_py(py), v.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38495,12 +43455,10 @@ impl AzOptionU32EnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionU32;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionU32::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionU32::Some(v) => Ok(vec!["Some".into_py(py), v.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38524,12 +43482,10 @@ impl AzOptionImageRefEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionImageRef;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionImageRef::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionImageRef::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38553,12 +43509,10 @@ impl AzOptionFontRefEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionFontRef;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionFontRef::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionFontRef::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38582,12 +43536,10 @@ impl AzOptionSystemClipboardEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionSystemClipboard;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionSystemClipboard::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionSystemClipboard::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38611,12 +43563,10 @@ impl AzOptionFileTypeListEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionFileTypeList;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionFileTypeList::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionFileTypeList::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38640,12 +43590,10 @@ impl AzOptionWindowStateEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionWindowState;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionWindowState::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionWindowState::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38669,12 +43617,10 @@ impl AzOptionMouseStateEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionMouseState;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionMouseState::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionMouseState::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38698,12 +43644,10 @@ impl AzOptionKeyboardStateEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionKeyboardState;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionKeyboardState::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionKeyboardState::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38727,12 +43671,10 @@ impl AzOptionStringVecEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionStringVec;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionStringVec::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionStringVec::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38756,12 +43698,10 @@ impl AzOptionFileEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionFile;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionFile::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionFile::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38785,12 +43725,10 @@ impl AzOptionGlEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionGl;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionGl::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionGl::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38814,12 +43752,10 @@ impl AzOptionThreadReceiveMsgEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionThreadReceiveMsg;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionThreadReceiveMsg::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionThreadReceiveMsg::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzThreadReceiveMsgEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38843,12 +43779,10 @@ impl AzOptionPercentageValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionPercentageValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionPercentageValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionPercentageValue::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38872,12 +43806,10 @@ impl AzOptionAngleValueEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionAngleValue;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionAngleValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionAngleValue::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38901,12 +43833,10 @@ impl AzOptionRendererOptionsEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionRendererOptions;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionRendererOptions::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionRendererOptions::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38930,12 +43860,10 @@ impl AzOptionCallbackEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionCallback;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionCallback::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionCallback::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38949,6 +43877,141 @@ impl PyObjectProtocol for AzOptionCallbackEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionPaintCallbackEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionPaintCallbackEnumWrapper { AzOptionPaintCallbackEnumWrapper { inner: AzOptionPaintCallback::None } }
+    #[staticmethod]
+    fn Some(v: AzPaintCallback) -> AzOptionPaintCallbackEnumWrapper { AzOptionPaintCallbackEnumWrapper { inner: AzOptionPaintCallback::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionPaintCallback;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzOptionPaintCallback::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionPaintCallback::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionPaintCallbackEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::OptionPaintCallback = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::OptionPaintCallback = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionJankCaptureEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionJankCaptureEnumWrapper { AzOptionJankCaptureEnumWrapper { inner: AzOptionJankCapture::None } }
+    #[staticmethod]
+    fn Some(v: AzJankCapture) -> AzOptionJankCaptureEnumWrapper { AzOptionJankCaptureEnumWrapper { inner: AzOptionJankCapture::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionJankCapture;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzOptionJankCapture::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionJankCapture::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionJankCaptureEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::OptionJankCapture = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::OptionJankCapture = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionEventRecordingEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionEventRecordingEnumWrapper { AzOptionEventRecordingEnumWrapper { inner: AzOptionEventRecording::None } }
+    #[staticmethod]
+    fn Some(v: AzEventRecordingEnumWrapper) -> AzOptionEventRecordingEnumWrapper { AzOptionEventRecordingEnumWrapper { inner: AzOptionEventRecording::Some(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionEventRecording;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzOptionEventRecording::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionEventRecording::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzEventRecordingEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionEventRecordingEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::OptionEventRecording = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::OptionEventRecording = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionFrameRecordingEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionFrameRecordingEnumWrapper { AzOptionFrameRecordingEnumWrapper { inner: AzOptionFrameRecording::None } }
+    #[staticmethod]
+    fn Some(v: AzFrameRecording) -> AzOptionFrameRecordingEnumWrapper { AzOptionFrameRecordingEnumWrapper { inner: AzOptionFrameRecording::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionFrameRecording;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzOptionFrameRecording::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionFrameRecording::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionFrameRecordingEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::OptionFrameRecording = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::OptionFrameRecording = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionCrashHandlerEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionCrashHandlerEnumWrapper { AzOptionCrashHandlerEnumWrapper { inner: AzOptionCrashHandler::None } }
+    #[staticmethod]
+    fn Some(v: AzCrashHandler) -> AzOptionCrashHandlerEnumWrapper { AzOptionCrashHandlerEnumWrapper { inner: AzOptionCrashHandler::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionCrashHandler;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzOptionCrashHandler::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionCrashHandler::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionCrashHandlerEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::OptionCrashHandler = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::OptionCrashHandler = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionThreadSendMsgEnumWrapper {
     #[classattr]
@@ -38959,12 +44022,10 @@ impl AzOptionThreadSendMsgEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionThreadSendMsg;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionThreadSendMsg::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionThreadSendMsg::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzThreadSendMsgEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -38988,12 +44049,10 @@ impl AzOptionLayoutRectEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionLayoutRect;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionLayoutRect::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionLayoutRect::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39017,12 +44076,10 @@ impl AzOptionRefAnyEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionRefAny;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionRefAny::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionRefAny::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39046,12 +44103,10 @@ impl AzOptionInlineTextEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionInlineText;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionInlineText::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionInlineText::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39065,6 +44120,33 @@ impl PyObjectProtocol for AzOptionInlineTextEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionTextLayoutEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTextLayoutEnumWrapper { AzOptionTextLayoutEnumWrapper { inner: AzOptionTextLayout::None } }
+    #[staticmethod]
+    fn Some(v: AzTextLayout) -> AzOptionTextLayoutEnumWrapper { AzOptionTextLayoutEnumWrapper { inner: AzOptionTextLayout::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTextLayout;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzOptionTextLayout::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTextLayout::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTextLayoutEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::OptionTextLayout = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::OptionTextLayout = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionLayoutPointEnumWrapper {
     #[classattr]
@@ -39075,12 +44157,10 @@ impl AzOptionLayoutPointEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionLayoutPoint;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionLayoutPoint::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionLayoutPoint::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39104,12 +44184,10 @@ impl AzOptionLayoutSizeEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionLayoutSize;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionLayoutSize::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionLayoutSize::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39133,12 +44211,10 @@ impl AzOptionWindowThemeEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionWindowTheme;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionWindowTheme::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionWindowTheme::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzWindowThemeEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39162,12 +44238,10 @@ impl AzOptionNodeIdEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionNodeId;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionNodeId::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionNodeId::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39191,12 +44265,10 @@ impl AzOptionDomNodeIdEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionDomNodeId;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionDomNodeId::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionDomNodeId::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39220,12 +44292,10 @@ impl AzOptionColorUEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionColorU;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionColorU::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionColorU::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39249,12 +44319,10 @@ impl AzOptionRawImageEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionRawImage;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionRawImage::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionRawImage::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39278,12 +44346,10 @@ impl AzOptionSvgDashPatternEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionSvgDashPattern;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionSvgDashPattern::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionSvgDashPattern::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39307,12 +44373,10 @@ impl AzOptionWaylandThemeEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionWaylandTheme;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionWaylandTheme::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionWaylandTheme::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39336,12 +44400,10 @@ impl AzOptionTaskBarIconEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionTaskBarIcon;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionTaskBarIcon::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionTaskBarIcon::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39355,6 +44417,33 @@ impl PyObjectProtocol for AzOptionTaskBarIconEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionRawWindowHandleEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionRawWindowHandleEnumWrapper { AzOptionRawWindowHandleEnumWrapper { inner: AzOptionRawWindowHandle::None } }
+    #[staticmethod]
+    fn Some(v: AzRawWindowHandleEnumWrapper) -> AzOptionRawWindowHandleEnumWrapper { AzOptionRawWindowHandleEnumWrapper { inner: AzOptionRawWindowHandle::Some(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionRawWindowHandle;
+        use pyo3::conversion::IntoPy;
+        Python::with_gil(|py| match &self.inner {
+            AzOptionRawWindowHandle::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionRawWindowHandle::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzRawWindowHandleEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        })
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionRawWindowHandleEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionRawWindowHandle = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionRawWindowHandle = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionHwndHandleEnumWrapper {
     #[classattr]
@@ -39363,12 +44452,10 @@ impl AzOptionHwndHandleEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionHwndHandle;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionHwndHandle::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionHwndHandle::Some(v) => Ok(vec!["Some".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39392,12 +44479,10 @@ impl AzOptionLogicalPositionEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionLogicalPosition;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionLogicalPosition::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionLogicalPosition::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39421,12 +44506,10 @@ impl AzOptionPhysicalPositionI32EnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionPhysicalPositionI32;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionPhysicalPositionI32::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionPhysicalPositionI32::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39450,12 +44533,10 @@ impl AzOptionWindowIconEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionWindowIcon;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionWindowIcon::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionWindowIcon::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzWindowIconEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39479,12 +44560,10 @@ impl AzOptionStringEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionString;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionString::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionString::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39506,12 +44585,10 @@ impl AzOptionX11VisualEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionX11Visual;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionX11Visual::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionX11Visual::Some(v) => Ok(vec!["Some".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39535,12 +44612,10 @@ impl AzOptionI32EnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionI32;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionI32::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionI32::Some(v) => Ok(vec!["Some".into_py(py), v.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39564,12 +44639,10 @@ impl AzOptionF32EnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionF32;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionF32::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionF32::Some(v) => Ok(vec!["Some".into_py(py), v.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39593,12 +44666,10 @@ impl AzOptionMouseCursorTypeEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionMouseCursorType;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionMouseCursorType::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionMouseCursorType::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzMouseCursorTypeEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39622,12 +44693,10 @@ impl AzOptionLogicalSizeEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionLogicalSize;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionLogicalSize::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionLogicalSize::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39651,12 +44720,10 @@ impl AzOptionCharEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionChar;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionChar::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionChar::Some(v) => Ok(vec!["Some".into_py(py), v.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39680,12 +44747,10 @@ impl AzOptionVirtualKeyCodeEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionVirtualKeyCode;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionVirtualKeyCode::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionVirtualKeyCode::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzVirtualKeyCodeEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39709,12 +44774,10 @@ impl AzOptionDomEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionDom;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionDom::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionDom::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39738,12 +44801,10 @@ impl AzOptionTextureEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionTexture;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionTexture::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionTexture::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39767,12 +44828,10 @@ impl AzOptionImageMaskEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionImageMask;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionImageMask::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionImageMask::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39796,12 +44855,10 @@ impl AzOptionTabIndexEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionTabIndex;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionTabIndex::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionTabIndex::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzTabIndexEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39825,12 +44882,10 @@ impl AzOptionTagIdEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionTagId;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionTagId::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionTagId::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39854,12 +44909,10 @@ impl AzOptionDurationEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionDuration;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionDuration::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionDuration::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzDurationEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39883,12 +44936,10 @@ impl AzOptionInstantEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionInstant;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionInstant::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionInstant::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzInstantEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39912,12 +44963,10 @@ impl AzOptionUsizeEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionUsize;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionUsize::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionUsize::Some(v) => Ok(vec!["Some".into_py(py), v.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39941,12 +44990,10 @@ impl AzOptionU8VecEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionU8Vec;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionU8Vec::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionU8Vec::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39970,12 +45017,10 @@ impl AzOptionU8VecRefEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzOptionU8VecRef;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzOptionU8VecRef::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
             AzOptionU8VecRef::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -39999,12 +45044,10 @@ impl AzResultXmlXmlErrorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzResultXmlXmlError;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzResultXmlXmlError::Ok(v) => Ok(vec!["Ok".into_py(py), v.clone().into_py(py)]),
             AzResultXmlXmlError::Err(v) => Ok(vec!["Err".into_py(py), { let m: &AzXmlErrorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -40028,12 +45071,10 @@ impl AzResultRawImageDecodeImageErrorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzResultRawImageDecodeImageError;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzResultRawImageDecodeImageError::Ok(v) => Ok(vec!["Ok".into_py(py), v.clone().into_py(py)]),
             AzResultRawImageDecodeImageError::Err(v) => Ok(vec!["Err".into_py(py), { let m: &AzDecodeImageErrorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -40057,12 +45098,10 @@ impl AzResultU8VecEncodeImageErrorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzResultU8VecEncodeImageError;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzResultU8VecEncodeImageError::Ok(v) => Ok(vec!["Ok".into_py(py), v.clone().into_py(py)]),
             AzResultU8VecEncodeImageError::Err(v) => Ok(vec!["Err".into_py(py), { let m: &AzEncodeImageErrorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -40086,12 +45125,10 @@ impl AzResultSvgXmlNodeSvgParseErrorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzResultSvgXmlNodeSvgParseError;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzResultSvgXmlNodeSvgParseError::Ok(v) => Ok(vec!["Ok".into_py(py), v.clone().into_py(py)]),
             AzResultSvgXmlNodeSvgParseError::Err(v) => Ok(vec!["Err".into_py(py), { let m: &AzSvgParseErrorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -40115,12 +45152,10 @@ impl AzResultSvgSvgParseErrorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzResultSvgSvgParseError;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzResultSvgSvgParseError::Ok(v) => Ok(vec!["Ok".into_py(py), v.clone().into_py(py)]),
             AzResultSvgSvgParseError::Err(v) => Ok(vec!["Err".into_py(py), { let m: &AzSvgParseErrorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -40152,16 +45187,14 @@ impl AzSvgParseErrorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzSvgParseError;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzSvgParseError::NoParserAvailable => Ok(vec!["NoParserAvailable".into_py(py), ().into_py(py)]),
             AzSvgParseError::ElementsLimitReached => Ok(vec!["ElementsLimitReached".into_py(py), ().into_py(py)]),
             AzSvgParseError::NotAnUtf8Str => Ok(vec!["NotAnUtf8Str".into_py(py), ().into_py(py)]),
             AzSvgParseError::MalformedGZip => Ok(vec!["MalformedGZip".into_py(py), ().into_py(py)]),
             AzSvgParseError::InvalidSize => Ok(vec!["InvalidSize".into_py(py), ().into_py(py)]),
             AzSvgParseError::ParsingFailed(v) => Ok(vec!["ParsingFailed".into_py(py), { let m: &AzXmlErrorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -40215,9 +45248,7 @@ impl AzXmlErrorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzXmlError;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzXmlError::NoParserAvailable => Ok(vec!["NoParserAvailable".into_py(py), ().into_py(py)]),
             AzXmlError::InvalidXmlPrefixUri(v) => Ok(vec!["InvalidXmlPrefixUri".into_py(py), v.clone().into_py(py)]),
             AzXmlError::UnexpectedXmlUri(v) => Ok(vec!["UnexpectedXmlUri".into_py(py), v.clone().into_py(py)]),
@@ -40235,7 +45266,7 @@ impl AzXmlErrorEnumWrapper {
             AzXmlError::NoRootNode => Ok(vec!["NoRootNode".into_py(py), ().into_py(py)]),
             AzXmlError::SizeLimit => Ok(vec!["SizeLimit".into_py(py), ().into_py(py)]),
             AzXmlError::ParserError(v) => Ok(vec!["ParserError".into_py(py), { let m: &AzXmlParseErrorEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
-        }
+        })
     }
 }
 
@@ -40386,9 +45417,7 @@ impl AzXmlParseErrorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzXmlParseError;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzXmlParseError::InvalidDeclaration(v) => Ok(vec!["InvalidDeclaration".into_py(py), v.clone().into_py(py)]),
             AzXmlParseError::InvalidComment(v) => Ok(vec!["InvalidComment".into_py(py), v.clone().into_py(py)]),
             AzXmlParseError::InvalidPI(v) => Ok(vec!["InvalidPI".into_py(py), v.clone().into_py(py)]),
@@ -40399,7 +45428,7 @@ impl AzXmlParseErrorEnumWrapper {
             AzXmlParseError::InvalidCdata(v) => Ok(vec!["InvalidCdata".into_py(py), v.clone().into_py(py)]),
             AzXmlParseError::InvalidCharData(v) => Ok(vec!["InvalidCharData".into_py(py), v.clone().into_py(py)]),
             AzXmlParseError::UnknownToken(v) => Ok(vec!["UnknownToken".into_py(py), v.clone().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -40467,9 +45496,7 @@ impl AzXmlStreamErrorEnumWrapper {
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzXmlStreamError;
         use pyo3::conversion::IntoPy;
-        let gil = Python::acquire_gil();
-        let py = gil.python();
-        match &self.inner {
+        Python::with_gil(|py| match &self.inner {
             AzXmlStreamError::UnexpectedEndOfStream => Ok(vec!["UnexpectedEndOfStream".into_py(py), ().into_py(py)]),
             AzXmlStreamError::InvalidName => Ok(vec!["InvalidName".into_py(py), ().into_py(py)]),
             AzXmlStreamError::NonXmlChar(v) => Ok(vec!["NonXmlChar".into_py(py), v.clone().into_py(py)]),
@@ -40483,7 +45510,7 @@ impl AzXmlStreamErrorEnumWrapper {
             AzXmlStreamError::InvalidCommentData => Ok(vec!["InvalidCommentData".into_py(py), ().into_py(py)]),
             AzXmlStreamError::InvalidCommentEnd => Ok(vec!["InvalidCommentEnd".into_py(py), ().into_py(py)]),
             AzXmlStreamError::InvalidCharacterData => Ok(vec!["InvalidCharacterData".into_py(py), ().into_py(py)]),
-        }
+        })
     }
 }
 
@@ -40700,10 +45727,23 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzApp>()?;
     m.add_class::<AzAppConfig>()?;
     m.add_class::<AzAppLogLevelEnumWrapper>()?;
+    m.add_class::<AzJankCapture>()?;
+    m.add_class::<AzSplashOptions>()?;
+    m.add_class::<AzEventRecordingEnumWrapper>()?;
+    m.add_class::<AzFrameRecording>()?;
+    m.add_class::<AzFrameRecordingOutputEnumWrapper>()?;
+    m.add_class::<AzRecordedFrame>()?;
+    m.add_class::<AzFrameRecordingCallback>()?;
+    m.add_class::<AzCrashInfo>()?;
+    m.add_class::<AzCrashHandler>()?;
     m.add_class::<AzLayoutSolverEnumWrapper>()?;
     m.add_class::<AzSystemCallbacks>()?;
 
     m.add_class::<AzWindowCreateOptions>()?;
+    m.add_class::<AzWindowBackgroundEffectEnumWrapper>()?;
+    m.add_class::<AzFirstFrameModeEnumWrapper>()?;
+    m.add_class::<AzScrollbarConfig>()?;
+    m.add_class::<AzScrollbarModeEnumWrapper>()?;
     m.add_class::<AzRendererOptions>()?;
     m.add_class::<AzVsyncEnumWrapper>()?;
     m.add_class::<AzSrgbEnumWrapper>()?;
@@ -40735,9 +45775,13 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzAcceleratorKeyEnumWrapper>()?;
     m.add_class::<AzWindowSize>()?;
     m.add_class::<AzWindowFlags>()?;
+    m.add_class::<AzPolitenessEnumWrapper>()?;
+    m.add_class::<AzHapticKindEnumWrapper>()?;
     m.add_class::<AzWindowFrameEnumWrapper>()?;
+    m.add_class::<AzFullscreenModeEnumWrapper>()?;
     m.add_class::<AzDebugState>()?;
     m.add_class::<AzKeyboardState>()?;
+    m.add_class::<AzKeyboardModifiers>()?;
     m.add_class::<AzMouseCursorTypeEnumWrapper>()?;
     m.add_class::<AzCursorPositionEnumWrapper>()?;
     m.add_class::<AzMouseState>()?;
@@ -40754,7 +45798,10 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzWindowPositionEnumWrapper>()?;
     m.add_class::<AzImePositionEnumWrapper>()?;
     m.add_class::<AzTouchState>()?;
+    m.add_class::<AzInputModalityEnumWrapper>()?;
+    m.add_class::<AzTouchPoint>()?;
     m.add_class::<AzMonitor>()?;
+    m.add_class::<AzHdrCapabilities>()?;
     m.add_class::<AzVideoMode>()?;
     m.add_class::<AzWindowState>()?;
 
@@ -40770,23 +45817,37 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzDomId>()?;
     m.add_class::<AzDomNodeId>()?;
     m.add_class::<AzPositionInfoEnumWrapper>()?;
+    m.add_class::<AzNodeRect>()?;
+    m.add_class::<AzScrollInfo>()?;
     m.add_class::<AzPositionInfoInner>()?;
     m.add_class::<AzHidpiAdjustedBounds>()?;
     m.add_class::<AzInlineText>()?;
+    m.add_class::<AzTextLayout>()?;
     m.add_class::<AzInlineLine>()?;
     m.add_class::<AzInlineWordEnumWrapper>()?;
     m.add_class::<AzInlineTextContents>()?;
     m.add_class::<AzInlineGlyph>()?;
+    m.add_class::<AzTextUnderline>()?;
+    m.add_class::<AzTextUnderlineStyleEnumWrapper>()?;
     m.add_class::<AzInlineTextHit>()?;
     m.add_class::<AzFocusTargetEnumWrapper>()?;
     m.add_class::<AzFocusTargetPath>()?;
     m.add_class::<AzResolvedTextLayoutOptions>()?;
     m.add_class::<AzAnimation>()?;
+    m.add_class::<AzCaretAnimationEnumWrapper>()?;
     m.add_class::<AzAnimationRepeatEnumWrapper>()?;
     m.add_class::<AzAnimationRepeatCountEnumWrapper>()?;
     m.add_class::<AzAnimationEasingEnumWrapper>()?;
     m.add_class::<AzIFrameCallback>()?;
     m.add_class::<AzIFrameCallbackInfo>()?;
+    m.add_class::<AzMeasureCallback>()?;
+    m.add_class::<AzMeasureCallbackInfo>()?;
+    m.add_class::<AzArrangeCallback>()?;
+    m.add_class::<AzArrangeCallbackInfo>()?;
+    m.add_class::<AzPaintCallback>()?;
+    m.add_class::<AzPaintCallbackInfo>()?;
+    m.add_class::<AzPaintPrimitiveEnumWrapper>()?;
+    m.add_class::<AzPaintRect>()?;
     m.add_class::<AzIFrameCallbackReturn>()?;
     m.add_class::<AzRenderImageCallback>()?;
     m.add_class::<AzRenderImageCallbackInfo>()?;
@@ -40801,6 +45862,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
 
     m.add_class::<AzDom>()?;
     m.add_class::<AzIFrameNode>()?;
+    m.add_class::<AzCustomLayoutNode>()?;
+    m.add_class::<AzCustomNodeType>()?;
     m.add_class::<AzCallbackData>()?;
     m.add_class::<AzNodeData>()?;
     m.add_class::<AzNodeTypeEnumWrapper>()?;
@@ -40813,6 +45876,12 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzComponentEventFilterEnumWrapper>()?;
     m.add_class::<AzApplicationEventFilterEnumWrapper>()?;
     m.add_class::<AzAccessibilityInfo>()?;
+    m.add_class::<AzFocusDirectionEnumWrapper>()?;
+    m.add_class::<AzInputModeEnumWrapper>()?;
+    m.add_class::<AzNodeStateFlagEnumWrapper>()?;
+    m.add_class::<AzFocusNavigation>()?;
+    m.add_class::<AzOverlayPlacementEnumWrapper>()?;
+    m.add_class::<AzOverlayInfo>()?;
     m.add_class::<AzAccessibilityRoleEnumWrapper>()?;
     m.add_class::<AzAccessibilityStateEnumWrapper>()?;
     m.add_class::<AzTabIndexEnumWrapper>()?;
@@ -40826,12 +45895,17 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStringMenuItem>()?;
     m.add_class::<AzVirtualKeyCodeCombo>()?;
     m.add_class::<AzMenuCallback>()?;
+    m.add_class::<AzShortcut>()?;
+    m.add_class::<AzShortcutConflict>()?;
+    m.add_class::<AzShortcutMap>()?;
     m.add_class::<AzMenuItemIconEnumWrapper>()?;
     m.add_class::<AzMenuItemStateEnumWrapper>()?;
 
     m.add_class::<AzCssRuleBlock>()?;
+    m.add_class::<AzCssMediaCondition>()?;
     m.add_class::<AzCssDeclarationEnumWrapper>()?;
     m.add_class::<AzDynamicCssProperty>()?;
+    m.add_class::<AzSystemCssProperty>()?;
     m.add_class::<AzCssPath>()?;
     m.add_class::<AzCssPathSelectorEnumWrapper>()?;
     m.add_class::<AzNodeTypeKeyEnumWrapper>()?;
@@ -40844,6 +45918,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzAnimationInterpolationFunctionEnumWrapper>()?;
     m.add_class::<AzInterpolateContext>()?;
     m.add_class::<AzColorU>()?;
+    m.add_class::<AzSystemColors>()?;
+    m.add_class::<AzSystemStyle>()?;
     m.add_class::<AzSizeMetricEnumWrapper>()?;
     m.add_class::<AzFloatValue>()?;
     m.add_class::<AzPixelValue>()?;
@@ -40925,7 +46001,19 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzLayoutBorderTopWidth>()?;
     m.add_class::<AzScrollbarInfo>()?;
     m.add_class::<AzScrollbarStyle>()?;
+    m.add_class::<AzStyleScrollbarWidthEnumWrapper>()?;
+    m.add_class::<AzStyleOverflowAnchorEnumWrapper>()?;
+    m.add_class::<AzStyleScrollbarColor>()?;
+    m.add_class::<AzStyleScrollbarThumbHoverColor>()?;
+    m.add_class::<AzStyleScrollbarThumbActiveColor>()?;
+    m.add_class::<AzStyleWindowDragRegionEnumWrapper>()?;
+    m.add_class::<AzStyleCaretAnimationDuration>()?;
+    m.add_class::<AzStyleScrollPadding>()?;
+    m.add_class::<AzStyleContentEnumWrapper>()?;
+    m.add_class::<AzStyleCounterReset>()?;
+    m.add_class::<AzStyleCounterIncrement>()?;
     m.add_class::<AzStyleCursorEnumWrapper>()?;
+    m.add_class::<AzStyleCursorImage>()?;
     m.add_class::<AzStyleFontFamilyEnumWrapper>()?;
     m.add_class::<AzStyleFontSize>()?;
     m.add_class::<AzStyleLetterSpacing>()?;
@@ -40935,6 +46023,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleTransformOrigin>()?;
     m.add_class::<AzStylePerspectiveOrigin>()?;
     m.add_class::<AzStyleBackfaceVisibilityEnumWrapper>()?;
+    m.add_class::<AzStylePerspective>()?;
+    m.add_class::<AzStyleTransformStyleEnumWrapper>()?;
     m.add_class::<AzStyleTransformEnumWrapper>()?;
     m.add_class::<AzStyleTransformMatrix2D>()?;
     m.add_class::<AzStyleTransformMatrix3D>()?;
@@ -40945,6 +46035,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleTransformScale3D>()?;
     m.add_class::<AzStyleTransformSkew2D>()?;
     m.add_class::<AzStyleTextAlignEnumWrapper>()?;
+    m.add_class::<AzStyleHyphensEnumWrapper>()?;
     m.add_class::<AzStyleTextColor>()?;
     m.add_class::<AzStyleWordSpacing>()?;
     m.add_class::<AzStyleBoxShadowValueEnumWrapper>()?;
@@ -40979,6 +46070,17 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzLayoutFlexWrapValueEnumWrapper>()?;
     m.add_class::<AzLayoutOverflowValueEnumWrapper>()?;
     m.add_class::<AzScrollbarStyleValueEnumWrapper>()?;
+    m.add_class::<AzStyleScrollbarWidthValueEnumWrapper>()?;
+    m.add_class::<AzStyleScrollbarColorValueEnumWrapper>()?;
+    m.add_class::<AzStyleScrollbarThumbHoverColorValueEnumWrapper>()?;
+    m.add_class::<AzStyleScrollbarThumbActiveColorValueEnumWrapper>()?;
+    m.add_class::<AzStyleWindowDragRegionValueEnumWrapper>()?;
+    m.add_class::<AzStyleCaretAnimationDurationValueEnumWrapper>()?;
+    m.add_class::<AzStyleScrollPaddingValueEnumWrapper>()?;
+    m.add_class::<AzStyleContentValueEnumWrapper>()?;
+    m.add_class::<AzStyleCounterResetValueEnumWrapper>()?;
+    m.add_class::<AzStyleCounterIncrementValueEnumWrapper>()?;
+    m.add_class::<AzStyleOverflowAnchorValueEnumWrapper>()?;
     m.add_class::<AzStyleBackgroundContentVecValueEnumWrapper>()?;
     m.add_class::<AzStyleBackgroundPositionVecValueEnumWrapper>()?;
     m.add_class::<AzStyleBackgroundRepeatVecValueEnumWrapper>()?;
@@ -41005,6 +46107,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleLetterSpacingValueEnumWrapper>()?;
     m.add_class::<AzStyleLineHeightValueEnumWrapper>()?;
     m.add_class::<AzStyleTabWidthValueEnumWrapper>()?;
+    m.add_class::<AzStyleHyphensValueEnumWrapper>()?;
     m.add_class::<AzStyleTextAlignValueEnumWrapper>()?;
     m.add_class::<AzStyleTextColorValueEnumWrapper>()?;
     m.add_class::<AzStyleWordSpacingValueEnumWrapper>()?;
@@ -41013,6 +46116,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleTransformOriginValueEnumWrapper>()?;
     m.add_class::<AzStylePerspectiveOriginValueEnumWrapper>()?;
     m.add_class::<AzStyleBackfaceVisibilityValueEnumWrapper>()?;
+    m.add_class::<AzStylePerspectiveValueEnumWrapper>()?;
+    m.add_class::<AzStyleTransformStyleValueEnumWrapper>()?;
     m.add_class::<AzStyleMixBlendModeValueEnumWrapper>()?;
     m.add_class::<AzStyleFilterVecValueEnumWrapper>()?;
     m.add_class::<AzCssPropertyEnumWrapper>()?;
@@ -41048,10 +46153,14 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzTextInputOnVirtualKeyDownCallback>()?;
     m.add_class::<AzTextInputOnFocusLost>()?;
     m.add_class::<AzTextInputOnFocusLostCallback>()?;
+    m.add_class::<AzTextInputOnSpellingSuggestions>()?;
+    m.add_class::<AzTextInputOnSpellingSuggestionsCallback>()?;
+    m.add_class::<AzTextInputSpellingSuggestions>()?;
     m.add_class::<AzOnTextInputReturn>()?;
     m.add_class::<AzTextInputValidEnumWrapper>()?;
     m.add_class::<AzNumberInput>()?;
     m.add_class::<AzNumberInputStateWrapper>()?;
+    m.add_class::<AzNumberInputModeEnumWrapper>()?;
     m.add_class::<AzNumberInputState>()?;
     m.add_class::<AzNumberInputOnValueChange>()?;
     m.add_class::<AzNumberInputOnValueChangeCallback>()?;
@@ -41115,6 +46224,11 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzDropDown>()?;
     m.add_class::<AzDropDownOnChoiceChangeCallback>()?;
     m.add_class::<AzDropDownOnChoiceChange>()?;
+    m.add_class::<AzToasts>()?;
+    m.add_class::<AzToast>()?;
+    m.add_class::<AzToastId>()?;
+    m.add_class::<AzToastOnAction>()?;
+    m.add_class::<AzToastOnActionCallback>()?;
 
     m.add_class::<AzNodeHierarchyItem>()?;
     m.add_class::<AzCascadeInfo>()?;
@@ -41131,6 +46245,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzGlVoidPtrConst>()?;
     m.add_class::<AzGlVoidPtrMut>()?;
     m.add_class::<AzGl>()?;
+    m.add_class::<AzGlQuirks>()?;
+    m.add_class::<AzGlQuirkRule>()?;
     m.add_class::<AzGlShaderPrecisionFormatReturn>()?;
     m.add_class::<AzVertexAttributeTypeEnumWrapper>()?;
     m.add_class::<AzVertexAttribute>()?;
@@ -41243,11 +46359,16 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzThread>()?;
     m.add_class::<AzThreadSender>()?;
     m.add_class::<AzThreadReceiver>()?;
+    m.add_class::<AzEventLoopProxy>()?;
     m.add_class::<AzThreadSendMsgEnumWrapper>()?;
     m.add_class::<AzThreadReceiveMsgEnumWrapper>()?;
     m.add_class::<AzThreadWriteBackMsg>()?;
     m.add_class::<AzCreateThreadFn>()?;
     m.add_class::<AzGetSystemTimeFn>()?;
+    m.add_class::<AzGetSystemIdleTimeFn>()?;
+    m.add_class::<AzGetLogLinesFn>()?;
+    m.add_class::<AzKeycodeToCharFn>()?;
+    m.add_class::<AzSetLogLevelFn>()?;
     m.add_class::<AzCheckThreadFinishedFn>()?;
     m.add_class::<AzLibrarySendThreadMsgFn>()?;
     m.add_class::<AzLibraryReceiveThreadMsgFn>()?;
@@ -41282,9 +46403,12 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzFmtArgVec>()?;
     m.add_class::<AzInlineLineVec>()?;
     m.add_class::<AzInlineWordVec>()?;
+    m.add_class::<AzPaintPrimitiveVec>()?;
     m.add_class::<AzInlineGlyphVec>()?;
+    m.add_class::<AzTextUnderlineVec>()?;
     m.add_class::<AzInlineTextHitVec>()?;
     m.add_class::<AzMonitorVec>()?;
+    m.add_class::<AzTouchPointVec>()?;
     m.add_class::<AzVideoModeVec>()?;
     m.add_class::<AzDomVec>()?;
     m.add_class::<AzIdOrClassVec>()?;
@@ -41305,6 +46429,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzU32Vec>()?;
     m.add_class::<AzXWindowTypeVec>()?;
     m.add_class::<AzVirtualKeyCodeVec>()?;
+    m.add_class::<AzShortcutConflictVec>()?;
+    m.add_class::<AzShortcutVec>()?;
     m.add_class::<AzCascadeInfoVec>()?;
     m.add_class::<AzScanCodeVec>()?;
     m.add_class::<AzCssDeclarationVec>()?;
@@ -41349,9 +46475,12 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzFmtArgVecDestructorEnumWrapper>()?;
     m.add_class::<AzInlineLineVecDestructorEnumWrapper>()?;
     m.add_class::<AzInlineWordVecDestructorEnumWrapper>()?;
+    m.add_class::<AzPaintPrimitiveVecDestructorEnumWrapper>()?;
     m.add_class::<AzInlineGlyphVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTextUnderlineVecDestructorEnumWrapper>()?;
     m.add_class::<AzInlineTextHitVecDestructorEnumWrapper>()?;
     m.add_class::<AzMonitorVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTouchPointVecDestructorEnumWrapper>()?;
     m.add_class::<AzVideoModeVecDestructorEnumWrapper>()?;
     m.add_class::<AzDomVecDestructorEnumWrapper>()?;
     m.add_class::<AzIdOrClassVecDestructorEnumWrapper>()?;
@@ -41372,6 +46501,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzU32VecDestructorEnumWrapper>()?;
     m.add_class::<AzXWindowTypeVecDestructorEnumWrapper>()?;
     m.add_class::<AzVirtualKeyCodeVecDestructorEnumWrapper>()?;
+    m.add_class::<AzShortcutConflictVecDestructorEnumWrapper>()?;
+    m.add_class::<AzShortcutVecDestructorEnumWrapper>()?;
     m.add_class::<AzCascadeInfoVecDestructorEnumWrapper>()?;
     m.add_class::<AzScanCodeVecDestructorEnumWrapper>()?;
     m.add_class::<AzCssDeclarationVecDestructorEnumWrapper>()?;
@@ -41403,6 +46534,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionMenuEnumWrapper>()?;
     m.add_class::<AzOptionPixelValueNoPercentEnumWrapper>()?;
     m.add_class::<AzOptionDropDownOnChoiceChangeEnumWrapper>()?;
+    m.add_class::<AzOptionToastOnActionEnumWrapper>()?;
     m.add_class::<AzOptionResolvedTextLayoutOptionsEnumWrapper>()?;
     m.add_class::<AzOptionNodeGraphOnNodeAddedEnumWrapper>()?;
     m.add_class::<AzOptionNodeGraphOnNodeRemovedEnumWrapper>()?;
@@ -41420,14 +46552,18 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionTextInputOnTextInputEnumWrapper>()?;
     m.add_class::<AzOptionTextInputOnVirtualKeyDownEnumWrapper>()?;
     m.add_class::<AzOptionTextInputOnFocusLostEnumWrapper>()?;
+    m.add_class::<AzOptionTextInputOnSpellingSuggestionsEnumWrapper>()?;
     m.add_class::<AzOptionTextInputSelectionEnumWrapper>()?;
     m.add_class::<AzOptionNumberInputOnFocusLostEnumWrapper>()?;
     m.add_class::<AzOptionNumberInputOnValueChangeEnumWrapper>()?;
     m.add_class::<AzOptionMenuItemIconEnumWrapper>()?;
     m.add_class::<AzOptionMenuCallbackEnumWrapper>()?;
     m.add_class::<AzOptionVirtualKeyCodeComboEnumWrapper>()?;
+    m.add_class::<AzOptionShortcutConflictEnumWrapper>()?;
     m.add_class::<AzOptionCssPropertyEnumWrapper>()?;
     m.add_class::<AzOptionPositionInfoEnumWrapper>()?;
+    m.add_class::<AzOptionNodeRectEnumWrapper>()?;
+    m.add_class::<AzOptionScrollInfoEnumWrapper>()?;
     m.add_class::<AzOptionTimerIdEnumWrapper>()?;
     m.add_class::<AzOptionThreadIdEnumWrapper>()?;
     m.add_class::<AzOptionI16EnumWrapper>()?;
@@ -41448,10 +46584,16 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionAngleValueEnumWrapper>()?;
     m.add_class::<AzOptionRendererOptionsEnumWrapper>()?;
     m.add_class::<AzOptionCallbackEnumWrapper>()?;
+    m.add_class::<AzOptionPaintCallbackEnumWrapper>()?;
+    m.add_class::<AzOptionJankCaptureEnumWrapper>()?;
+    m.add_class::<AzOptionEventRecordingEnumWrapper>()?;
+    m.add_class::<AzOptionFrameRecordingEnumWrapper>()?;
+    m.add_class::<AzOptionCrashHandlerEnumWrapper>()?;
     m.add_class::<AzOptionThreadSendMsgEnumWrapper>()?;
     m.add_class::<AzOptionLayoutRectEnumWrapper>()?;
     m.add_class::<AzOptionRefAnyEnumWrapper>()?;
     m.add_class::<AzOptionInlineTextEnumWrapper>()?;
+    m.add_class::<AzOptionTextLayoutEnumWrapper>()?;
     m.add_class::<AzOptionLayoutPointEnumWrapper>()?;
     m.add_class::<AzOptionLayoutSizeEnumWrapper>()?;
     m.add_class::<AzOptionWindowThemeEnumWrapper>()?;
@@ -41462,6 +46604,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionSvgDashPatternEnumWrapper>()?;
     m.add_class::<AzOptionWaylandThemeEnumWrapper>()?;
     m.add_class::<AzOptionTaskBarIconEnumWrapper>()?;
+    m.add_class::<AzOptionRawWindowHandleEnumWrapper>()?;
     m.add_class::<AzOptionHwndHandleEnumWrapper>()?;
     m.add_class::<AzOptionLogicalPositionEnumWrapper>()?;
     m.add_class::<AzOptionPhysicalPositionI32EnumWrapper>()?;
@@ -356,6 +356,7 @@ fn on_dropdown_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
 
     use azul_core::window::{
         WindowCreateOptions,
+        ShortcutMap,
         OptionHwndHandle,
         WindowPosition,
         RawWindowHandle,
@@ -436,6 +437,8 @@ fn on_dropdown_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
         theme: None.into(),
         create_callback: None.into(),
         hot_reload: false,
+        shortcuts: ShortcutMap::default(),
+        .. Default::default()
     });
 
     println!("5!");
@@ -110,6 +110,8 @@ pub mod tree_view;
 pub mod drop_down;
 /// Ribbon widget
 pub mod ribbon;
+/// Toast / snackbar notifications
+pub mod toasts;
 // /// Spreadsheet (iframe) widget
 // pub mod spreadsheet;
 // /// Slider widget
@@ -2258,7 +2258,7 @@ fn render_node(
                                            .dom()
                                        },
                                        NodeTypeFieldValue::NumberInput(initial_value) => {
-                                           NumberInput::new(*initial_value)
+                                           NumberInput::new(*initial_value as f64)
                                            .with_on_focus_lost(field_local_dataset, nodegraph_on_numberinput_focus_lost)
                                            .dom()
                                        },
@@ -3190,7 +3190,7 @@ extern "C" fn nodegraph_on_numberinput_focus_lost(data: &mut RefAny, info: &mut
     };
 
     let result = match node_graph.callbacks.on_node_field_edited.as_mut() {
-        Some(OnNodeFieldEdited { data, callback }) => (callback.cb)(data, info, node_id, field_idx, node_type, NodeTypeFieldValue::NumberInput(numberinputstate.number)),
+        Some(OnNodeFieldEdited { data, callback }) => (callback.cb)(data, info, node_id, field_idx, node_type, NodeTypeFieldValue::NumberInput(numberinputstate.number as f32)),
         None => Update::DoNothing,
     };
 
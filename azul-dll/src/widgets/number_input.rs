@@ -1,8 +1,15 @@
-//! Same as TextInput, but only allows a number
+//! Same as TextInput, but only allows a number. Supports integer / float
+//! numbers, min / max / step, spinner buttons, incrementing with the
+//! mouse wheel and locale-specific decimal separators
 
+use azul_desktop::css::*;
 use azul_desktop::css::AzString;
-use azul_desktop::callbacks::{RefAny, CallbackInfo, Update};
-use azul_desktop::dom::{Dom, NodeDataInlineCssPropertyVec};
+use azul_desktop::callbacks::{RefAny, Callback, CallbackInfo, Update};
+use azul_desktop::dom::{
+    Dom, NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
+    NodeDataInlineCssProperty::{Normal, Hover, Active},
+};
+use azul_core::callbacks::DomNodeId;
 use core::ops::Deref;
 use core::ops::DerefMut;
 use std::string::String;
@@ -16,34 +23,137 @@ use crate::widgets::text_input::{
     TextInputOnTextInputCallbackType,
 };
 
+const COLOR_4C4C4C: ColorU = ColorU { r: 76, g: 76, b: 76, a: 255 }; // #4C4C4C
+const COLOR_9B9B9B: ColorU = ColorU { r: 155, g: 155, b: 155, a: 255 }; // #9b9b9b
+const COLOR_D93025: ColorU = ColorU { r: 217, g: 48, b: 37, a: 255 }; // #d93025
+
+const SPINNER_BACKGROUND_NORMAL: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 229, g: 229, b: 229, a: 255 })];
+const SPINNER_BACKGROUND_HOVER: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 208, g: 208, b: 208, a: 255 })];
+const SPINNER_BACKGROUND_ACTIVE: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 176, g: 176, b: 176, a: 255 })];
+
+const SANS_SERIF_STR: &str = "sans-serif";
+const SANS_SERIF: AzString = AzString::from_const_str(SANS_SERIF_STR);
+const SANS_SERIF_FAMILIES: &[StyleFontFamily] = &[StyleFontFamily::System(SANS_SERIF)];
+const SANS_SERIF_FAMILY: StyleFontFamilyVec = StyleFontFamilyVec::from_const_slice(SANS_SERIF_FAMILIES);
+
+static NUMBER_INPUT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+];
+
+static NUMBER_INPUT_SPINNER_BUTTON_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_cursor(StyleCursor::Default)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(0))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(16))),
+    Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SPINNER_BACKGROUND_NORMAL))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
+    Normal(CssProperty::const_text_align(StyleTextAlign::Center)),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+
+    // border: 1px solid #9b9b9b;
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_9B9B9B })),
+
+    Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SPINNER_BACKGROUND_HOVER))),
+    Active(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SPINNER_BACKGROUND_ACTIVE))),
+];
+
+// applied to the text input container while the text is not a valid number
+static NUMBER_INPUT_INVALID_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_D93025 })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_D93025 })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_D93025 })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_D93025 })),
+];
+
 pub type NumberInputOnValueChangeCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &NumberInputState) -> Update;
 impl_callback!(NumberInputOnValueChange, OptionNumberInputOnValueChange, NumberInputOnValueChangeCallback, NumberInputOnValueChangeCallbackType);
 
 pub type NumberInputOnFocusLostCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &NumberInputState) -> Update;
 impl_callback!(NumberInputOnFocusLost, OptionNumberInputOnFocusLost, NumberInputOnFocusLostCallback, NumberInputOnFocusLostCallbackType);
 
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 #[repr(C)]
 pub struct NumberInput {
     pub text_input: TextInput,
     pub state: NumberInputStateWrapper,
+    /// Style of the increment / decrement buttons
+    pub spinner_button_style: NodeDataInlineCssPropertyVec,
 }
 
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 #[repr(C)]
 pub struct NumberInputStateWrapper {
     pub inner: NumberInputState,
     pub on_value_change: OptionNumberInputOnValueChange,
     pub on_focus_lost: OptionNumberInputOnFocusLost,
+    /// Properties that are set on the text input container while the text is invalid
+    pub invalid_style: NodeDataInlineCssPropertyVec,
+    /// Properties that undo the `invalid_style` once the text is valid again,
+    /// filled out when the DOM is created
+    pub valid_style: NodeDataInlineCssPropertyVec,
+}
+
+/// Whether the `NumberInput` accepts any number or only integers
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub enum NumberInputMode {
+    Integer,
+    Float,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 #[repr(C)]
 pub struct NumberInputState {
-    pub previous: f32,
-    pub number: f32,
-    pub min: f32,
-    pub max: f32,
+    /// Last valid number before the current one
+    pub previous: f64,
+    /// Current (valid) number
+    pub number: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Amount that the spinner buttons / mouse wheel add or subtract
+    pub step: f64,
+    pub mode: NumberInputMode,
+    /// Character used to display the decimal point (i.e. `,` for German locales).
+    /// Both `.` and `,` are always accepted as input
+    pub decimal_separator: u32,
+    /// Whether the current text is a number in the `min..=max` range
+    pub is_valid: bool,
+}
+
+impl Default for NumberInput {
+    fn default() -> Self {
+        Self {
+            text_input: TextInput::default(),
+            state: NumberInputStateWrapper::default(),
+            spinner_button_style: NodeDataInlineCssPropertyVec::from_const_slice(NUMBER_INPUT_SPINNER_BUTTON_PROPS),
+        }
+    }
+}
+
+impl Default for NumberInputStateWrapper {
+    fn default() -> Self {
+        Self {
+            inner: NumberInputState::default(),
+            on_value_change: None.into(),
+            on_focus_lost: None.into(),
+            invalid_style: NodeDataInlineCssPropertyVec::from_const_slice(NUMBER_INPUT_INVALID_PROPS),
+            valid_style: NodeDataInlineCssPropertyVec::from_const_slice(&[]),
+        }
+    }
 }
 
 impl Default for NumberInputState {
@@ -52,18 +162,84 @@ impl Default for NumberInputState {
             previous: 0.0,
             number: 0.0,
             min: 0.0,
-            max: core::f32::MAX,
+            max: core::f64::MAX,
+            step: 1.0,
+            mode: NumberInputMode::Float,
+            decimal_separator: '.' as u32,
+            is_valid: true,
         }
     }
 }
 
+impl NumberInputState {
+
+    /// Clamps the number to the `min..=max` range (and rounds it in integer mode)
+    pub fn clamp(&self, number: f64) -> f64 {
+        let number = match self.mode {
+            NumberInputMode::Integer => number.round(),
+            NumberInputMode::Float => number,
+        };
+        number.max(self.min).min(self.max)
+    }
+
+    pub fn is_in_range(&self, number: f64) -> bool {
+        number >= self.min && number <= self.max
+    }
+
+    /// Formats the number using the `decimal_separator`
+    pub fn format_number(&self, number: f64) -> String {
+        match self.mode {
+            NumberInputMode::Integer => format!("{:.0}", number),
+            NumberInputMode::Float => {
+                let separator = core::char::from_u32(self.decimal_separator).unwrap_or('.');
+                format!("{}", number).replace('.', &separator.to_string())
+            },
+        }
+    }
+
+    /// Returns whether the text could still become a valid number while typing
+    /// (i.e. `"-"` or `"1."`): only digits, a leading sign and one decimal separator
+    pub fn is_partial_number(&self, text: &str) -> bool {
+        let mut has_separator = false;
+        for (i, c) in text.trim().chars().enumerate() {
+            match c {
+                '0'..='9' => { },
+                '-' | '+' if i == 0 => { },
+                _ if self.is_decimal_separator(c) && !has_separator && self.mode == NumberInputMode::Float => {
+                    has_separator = true;
+                },
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// Parses the text as a number, accepting both `.` and the `decimal_separator`
+    pub fn parse_number(&self, text: &str) -> Option<f64> {
+        if !self.is_partial_number(text) {
+            return None;
+        }
+
+        let normalized: String = text.trim().chars()
+            .map(|c| if self.is_decimal_separator(c) { '.' } else { c })
+            .collect();
+
+        normalized.parse::<f64>().ok().filter(|n| n.is_finite())
+    }
+
+    fn is_decimal_separator(&self, c: char) -> bool {
+        c == '.' || c == ',' || c as u32 == self.decimal_separator
+    }
+}
+
 impl NumberInput {
 
-    pub fn new(input: f32) -> Self {
+    pub fn new(input: f64) -> Self {
         Self {
             state: NumberInputStateWrapper {
                 inner: NumberInputState {
                     number: input,
+                    previous: input,
                     .. Default::default()
                 },
                 .. Default::default()
@@ -92,6 +268,36 @@ impl NumberInput {
         self.text_input.label_style = style;
     }
 
+    pub fn set_spinner_button_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.spinner_button_style = style;
+    }
+
+    /// Sets the properties that are applied to the text input container while
+    /// the input is not a valid number (default: red border)
+    pub fn set_invalid_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.state.invalid_style = style;
+    }
+
+    pub fn set_mode(&mut self, mode: NumberInputMode) {
+        self.state.inner.mode = mode;
+    }
+
+    pub fn set_min(&mut self, min: f64) {
+        self.state.inner.min = min;
+    }
+
+    pub fn set_max(&mut self, max: f64) {
+        self.state.inner.max = max;
+    }
+
+    pub fn set_step(&mut self, step: f64) {
+        self.state.inner.step = step;
+    }
+
+    pub fn set_decimal_separator(&mut self, decimal_separator: u32) {
+        self.state.inner.decimal_separator = decimal_separator;
+    }
+
     // Function called when the input has been parsed as a number
     pub fn set_on_value_change(&mut self, data: RefAny, callback: NumberInputOnValueChangeCallbackType) {
         self.state.on_value_change = Some(NumberInputOnValueChange {
@@ -120,18 +326,214 @@ impl NumberInput {
 
     pub fn dom(mut self) -> Dom {
 
-        let number_string = format!("{}", self.state.inner.number);
-        self.text_input.state.inner.text = number_string.chars()
-        .map(|s| s as u32).collect::<Vec<_>>().into();
+        use azul_desktop::dom::{
+            CallbackData, EventFilter, HoverEventFilter,
+            IdOrClass::Class,
+        };
+
+        self.state.inner.number = self.state.inner.clamp(self.state.inner.number);
+        self.state.inner.previous = self.state.inner.number;
+        self.state.inner.is_valid = true;
+        self.state.valid_style = get_valid_style(&self.state.invalid_style, &self.text_input.container_style);
+
+        let number_string = self.state.inner.format_number(self.state.inner.number);
+        self.text_input.set_text(number_string.into());
 
         let state = RefAny::new(self.state);
 
         self.text_input.set_on_text_input(state.clone(), validate_text_input);
-        self.text_input.set_on_focus_lost(state, on_focus_lost);
-        self.text_input.dom()
+        self.text_input.set_on_virtual_key_down(state.clone(), validate_virtual_key_down);
+        self.text_input.set_on_focus_lost(state.clone(), on_focus_lost);
+
+        Dom::div()
+        .with_ids_and_classes(vec![Class("__azul-native-number-input-container".into())].into())
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(NUMBER_INPUT_CONTAINER_PROPS))
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::Scroll),
+                data: state.clone(),
+                callback: Callback { cb: on_mouse_wheel },
+            },
+        ].into())
+        .with_children(vec![
+            self.text_input.dom(),
+            Dom::text("-")
+            .with_ids_and_classes(vec![Class("__azul-native-number-input-decrement".into())].into())
+            .with_inline_css_props(self.spinner_button_style.clone())
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::MouseUp),
+                    data: state.clone(),
+                    callback: Callback { cb: on_decrement },
+                },
+            ].into()),
+            Dom::text("+")
+            .with_ids_and_classes(vec![Class("__azul-native-number-input-increment".into())].into())
+            .with_inline_css_props(self.spinner_button_style)
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::MouseUp),
+                    data: state,
+                    callback: Callback { cb: on_increment },
+                },
+            ].into()),
+        ].into())
     }
 }
 
+// For every property in the invalid_style, find the property of the same
+// type in the container style (or the initial value) to restore it later
+fn get_valid_style(
+    invalid_style: &NodeDataInlineCssPropertyVec,
+    container_style: &NodeDataInlineCssPropertyVec,
+) -> NodeDataInlineCssPropertyVec {
+    invalid_style.iter().map(|invalid_prop| {
+        let prop_type = get_inline_css_property(invalid_prop).get_type();
+        let restored = container_style.iter().find_map(|p| match p {
+            Normal(p) if p.get_type() == prop_type => Some(p.clone()),
+            _ => None,
+        }).unwrap_or(CssProperty::initial(prop_type));
+        Normal(restored)
+    }).collect::<Vec<_>>().into()
+}
+
+fn get_inline_css_property(prop: &NodeDataInlineCssProperty) -> &CssProperty {
+    match prop {
+        NodeDataInlineCssProperty::Normal(p) |
+        NodeDataInlineCssProperty::Active(p) |
+        NodeDataInlineCssProperty::Focus(p) |
+        NodeDataInlineCssProperty::Hover(p) => p,
+    }
+}
+
+// Applies the invalid / valid style to the text input container if the validity changed
+fn set_valid(
+    number_input: &mut NumberInputStateWrapper,
+    info: &mut CallbackInfo,
+    text_input_node: DomNodeId,
+    is_valid: bool,
+) {
+    if number_input.inner.is_valid == is_valid {
+        return;
+    }
+
+    number_input.inner.is_valid = is_valid;
+
+    let style = if is_valid { &number_input.valid_style } else { &number_input.invalid_style };
+    for prop in style.iter() {
+        info.set_css_property(text_input_node, get_inline_css_property(prop).clone());
+    }
+}
+
+// Stores the new number and invokes the on_value_change callback if the number changed
+fn set_number(
+    number_input: &mut NumberInputStateWrapper,
+    info: &mut CallbackInfo,
+    number: f64,
+) -> Update {
+
+    if number == number_input.inner.number {
+        return Update::DoNothing;
+    }
+
+    let onvaluechange = &mut number_input.on_value_change;
+    let inner = &mut number_input.inner;
+
+    inner.previous = inner.number;
+    inner.number = number;
+
+    match onvaluechange.as_mut() {
+        Some(NumberInputOnValueChange { callback, data }) => (callback.cb)(data, info, &inner),
+        None => Update::DoNothing,
+    }
+}
+
+// Validates the current text of the text input, returns the update of the on_value_change callback
+fn validate_text(
+    number_input: &mut NumberInputStateWrapper,
+    info: &mut CallbackInfo,
+    text: &str,
+) -> Update {
+
+    let text_input_node = info.get_hit_node();
+
+    match number_input.inner.parse_number(text) {
+        Some(n) if number_input.inner.is_in_range(n) => {
+            set_valid(number_input, info, text_input_node, true);
+            set_number(number_input, info, n)
+        },
+        _ => {
+            set_valid(number_input, info, text_input_node, false);
+            Update::DoNothing
+        }
+    }
+}
+
+// Adds `direction * step` to the number and updates the text of the text input
+fn step_number(
+    data: &mut RefAny,
+    info: &mut CallbackInfo,
+    text_input_node: DomNodeId,
+    direction: f64,
+) -> Option<Update> {
+
+    let mut number_input = data.downcast_mut::<NumberInputStateWrapper>()?;
+    let number_input = &mut *number_input;
+
+    let new_number = number_input.inner.clamp(number_input.inner.number + direction * number_input.inner.step);
+    if new_number == number_input.inner.number && number_input.inner.is_valid {
+        return None;
+    }
+
+    let new_text = number_input.inner.format_number(new_number);
+
+    // update the text of the TextInput
+    let mut text_input_state = info.get_dataset(text_input_node)?;
+    let mut text_input = text_input_state.downcast_mut::<TextInputStateWrapper>()?;
+    text_input.inner.text = new_text.chars().map(|c| c as u32).collect::<Vec<_>>().into();
+    text_input.inner.cursor_pos = text_input.inner.text.len();
+
+    let placeholder_node_id = info.get_first_child(text_input_node)?;
+    let label_node_id = info.get_next_sibling(placeholder_node_id)?;
+    info.set_css_property(placeholder_node_id, CssProperty::const_opacity(StyleOpacity::const_new(0)));
+    info.set_string_contents(label_node_id, new_text.into());
+
+    set_valid(number_input, info, text_input_node, true);
+    Some(set_number(number_input, info, new_number))
+}
+
+extern "C" fn on_increment(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+    let text_input_node = match info.get_parent(info.get_hit_node()).and_then(|p| info.get_first_child(p)) {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+    step_number(data, info, text_input_node, 1.0).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_decrement(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+    let text_input_node = match info.get_parent(info.get_hit_node()).and_then(|p| info.get_first_child(p)) {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+    step_number(data, info, text_input_node, -1.0).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_mouse_wheel(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    let scroll_y = info.get_current_mouse_state().get_scroll_y();
+    if scroll_y == 0.0 {
+        return Update::DoNothing;
+    }
+
+    let text_input_node = match info.get_first_child(info.get_hit_node()) {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    // scrolling up increments the number
+    step_number(data, info, text_input_node, scroll_y.signum() as f64).unwrap_or(Update::DoNothing)
+}
+
 extern "C" fn on_focus_lost(data: &mut RefAny, info: &mut CallbackInfo, state: &TextInputState) -> Update {
 
     let mut data = match data.downcast_mut::<NumberInputStateWrapper>() {
@@ -163,39 +565,36 @@ extern "C" fn validate_text_input(data: &mut RefAny, info: &mut CallbackInfo, st
         },
     };
 
-    let validated_input: String = state.text.iter()
-        .filter_map(|c| core::char::from_u32(*c))
-        .map(|c| if c == ',' { '.' } else { c })
-        .collect();
-
-    let validated_f32 = match validated_input.parse::<f32>() {
-        Ok(s) => s,
-        Err(_) => {
-            // do not re-layout the entire screen,
-            // but don't handle the character
-            return OnTextInputReturn {
-                update: Update::DoNothing,
-                valid: TextInputValid::No,
-            };
-        }
-    };
+    let text = state.get_text();
 
-    let result = {
-        let number_input = &mut *data;
-        let onvaluechange = &mut number_input.on_value_change;
-        let inner = &mut number_input.inner;
+    // do not re-layout the entire screen,
+    // but don't handle the character
+    if !data.inner.is_partial_number(&text) {
+        return OnTextInputReturn {
+            update: Update::DoNothing,
+            valid: TextInputValid::No,
+        };
+    }
 
-        inner.previous = inner.number;
-        inner.number = validated_f32;
+    OnTextInputReturn {
+        update: validate_text(&mut *data, info, &text),
+        valid: TextInputValid::Yes
+    }
+}
 
-        match onvaluechange.as_mut() {
-            Some(NumberInputOnValueChange { callback, data }) => (callback.cb)(data, info, &inner),
-            None => Update::DoNothing,
-        }
+// re-validates the text after a character has been deleted
+extern "C" fn validate_virtual_key_down(data: &mut RefAny, info: &mut CallbackInfo, state: &TextInputState) -> OnTextInputReturn {
+
+    let mut data = match data.downcast_mut::<NumberInputStateWrapper>() {
+        Some(s) => s,
+        None => return OnTextInputReturn {
+            update: Update::DoNothing,
+            valid: TextInputValid::Yes
+        },
     };
 
     OnTextInputReturn {
-        update: result,
+        update: validate_text(&mut *data, info, &state.get_text()),
         valid: TextInputValid::Yes
     }
-}
\ No newline at end of file
+}
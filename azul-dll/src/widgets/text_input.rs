@@ -1,21 +1,27 @@
 //! Text input (demonstrates two-way data binding)
 
 use core::ops::Range;
+use core::sync::atomic::{compiler_fence, Ordering};
 use azul_desktop::{
     css::*,
     css::AzString,
     styled_dom::StyledDom,
     dom::{
         Dom, NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
+        AccessibilityInfo, AccessibilityRole, AccessibilityState,
         NodeDataInlineCssProperty::{Normal, Hover, Focus}
     },
     task::OptionTimerId,
     callbacks::{RefAny, Callback, CallbackInfo, Update},
+    spellcheck::{get_spellcheck_provider, Misspelling},
 };
 use azul_core::{
-    callbacks::{Animation, AnimationRepeatCount, InlineText, DomNodeId},
-    task::SystemTimeDiff,
-    window::{KeyboardState, LogicalPosition, VirtualKeyCode},
+    callbacks::{
+        Animation, AnimationRepeatCount, CaretAnimation, InlineText, DomNodeId,
+        TextUnderline, TextUnderlineStyle, WriteBackCallback,
+    },
+    task::{SystemTimeDiff, ThreadReceiveMsg, ThreadReceiver, ThreadSender, ThreadWriteBackMsg},
+    window::{KeyboardState, LogicalPosition, LogicalRect, LogicalSize, VirtualKeyCode},
 };
 use alloc::vec::Vec;
 use alloc::string::String;
@@ -27,6 +33,8 @@ const TEXT_COLOR: StyleTextColor = StyleTextColor { inner: BLACK  }; // black
 const COLOR_9B9B9B: ColorU = ColorU { r: 155, g: 155, b: 155, a: 255 }; // #9b9b9b
 const COLOR_4286F4: ColorU = ColorU { r: 66, g: 134, b: 244, a: 255 }; // #4286f4
 const COLOR_4C4C4C: ColorU = ColorU { r: 76, g: 76, b: 76, a: 255 }; // #4C4C4C
+const SPELLING_ERROR_COLOR: ColorU = ColorU { r: 230, g: 30, b: 30, a: 255 }; // #e61e1e
+const SECURE_TEXT_BULLET: char = '\u{2022}'; // •
 
 const CURSOR_COLOR_BLACK: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(BLACK)];
 const CURSOR_COLOR: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(CURSOR_COLOR_BLACK);
@@ -320,6 +328,13 @@ pub struct TextInputStateWrapper {
     pub update_text_input_before_calling_focus_lost_fn: bool,
     pub update_text_input_before_calling_vk_down_fn: bool,
     pub cursor_animation: OptionTimerId,
+    pub caret_animation: CaretAnimation,
+    /// Language of the spellchecker (i.e. "en-US"), spell checking is disabled if `None`
+    pub spellcheck_language: OptionAzString,
+    pub on_spelling_suggestions: OptionTextInputOnSpellingSuggestions,
+    /// Secure text entry (passwords): the text is rendered as bullets, is never spellchecked
+    /// or shown in the IME windows and its memory is zeroized when it is freed
+    pub secure: bool,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -347,6 +362,21 @@ impl_callback!(TextInputOnVirtualKeyDown, OptionTextInputOnVirtualKeyDown, TextI
 pub type TextInputOnFocusLostCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &TextInputState) -> Update;
 impl_callback!(TextInputOnFocusLost, OptionTextInputOnFocusLost, TextInputOnFocusLostCallback, TextInputOnFocusLostCallbackType);
 
+// Invoked when a misspelled word is right-clicked, i.e. to show a context menu with the suggestions
+pub type TextInputOnSpellingSuggestionsCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &TextInputState, &TextInputSpellingSuggestions) -> Update;
+impl_callback!(TextInputOnSpellingSuggestions, OptionTextInputOnSpellingSuggestions, TextInputOnSpellingSuggestionsCallback, TextInputOnSpellingSuggestionsCallbackType);
+
+/// Misspelled word that was right-clicked
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextInputSpellingSuggestions {
+    /// Range of the word in the text (in characters)
+    pub range: TextInputSelectionRange,
+    pub word: AzString,
+    /// Suggested replacements, best suggestion first
+    pub suggestions: StringVec,
+}
+
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 #[repr(C, u8)]
@@ -408,10 +438,77 @@ impl Default for TextInputStateWrapper {
             update_text_input_before_calling_focus_lost_fn: true,
             update_text_input_before_calling_vk_down_fn: true,
             cursor_animation: None.into(),
+            caret_animation: CaretAnimation::Blink,
+            spellcheck_language: None.into(),
+            on_spelling_suggestions: None.into(),
+            secure: false,
+        }
+    }
+}
+
+// A clone of a secure input is secure as well,
+// so every copy of the text is zeroized when it is dropped
+impl Drop for TextInputStateWrapper {
+    fn drop(&mut self) {
+        if self.secure {
+            zeroize_text(&mut self.inner.text);
         }
     }
 }
 
+impl TextInputStateWrapper {
+
+    /// Text that is displayed in the label, bullets instead of the characters in secure mode
+    fn get_label_text(&self) -> String {
+        if self.secure {
+            // the text is a Vec<char>, so len() is the number of characters
+            core::iter::repeat(SECURE_TEXT_BULLET).take(self.inner.text.len()).collect()
+        } else {
+            self.inner.get_text()
+        }
+    }
+
+    /// Edits a copy of the text and replaces the old text with it. In secure mode
+    /// the old text is zeroized, the copy has enough capacity to append one character
+    /// without reallocating (which would leave the old buffer in the freed memory).
+    fn edit_text<F: FnOnce(&mut Vec<u32>)>(&mut self, f: F) {
+        let mut new_text = Vec::with_capacity(self.inner.text.len() + 1);
+        new_text.extend_from_slice(self.inner.text.as_ref());
+        f(&mut new_text);
+        self.replace_text(new_text.into());
+    }
+
+    /// Replaces the text, the old text is zeroized in secure mode
+    fn replace_text(&mut self, new_text: U32Vec) {
+        let mut old_text = core::mem::replace(&mut self.inner.text, new_text);
+        if self.secure {
+            zeroize_text(&mut old_text);
+        }
+    }
+}
+
+/// Overwrites the characters (and the unused capacity) with zeros and frees the
+/// memory, leaves an empty text behind. The writes are volatile so that the compiler
+/// can't optimize them away. Static or external texts are not owned by the text
+/// input and are only released, not zeroized.
+fn zeroize_text(text: &mut U32Vec) {
+    let text = core::mem::replace(text, U32Vec::new());
+    if !text.is_library_owned() {
+        return;
+    }
+
+    // moves the memory out without copying it
+    let mut owned = text.into_library_owned_vec();
+    for c in owned.iter_mut() {
+        unsafe { core::ptr::write_volatile(c, 0); }
+    }
+    // removing characters leaves them behind in the unused capacity
+    for c in owned.spare_capacity_mut() {
+        unsafe { core::ptr::write_volatile(c.as_mut_ptr(), 0); }
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
 impl TextInput {
 
     pub fn new() -> Self {
@@ -425,12 +522,9 @@ impl TextInput {
     }
 
     pub fn set_text(&mut self, text: AzString) {
-        self.state.inner.text = text
-            .as_str()
-            .chars()
-            .map(|c| c as u32)
-            .collect::<Vec<_>>()
-            .into();
+        let mut new_text = Vec::with_capacity(text.as_str().chars().count());
+        new_text.extend(text.as_str().chars().map(|c| c as u32));
+        self.state.replace_text(new_text.into());
     }
 
     pub fn set_placeholder(&mut self, placeholder: AzString) {
@@ -469,6 +563,54 @@ impl TextInput {
         self
     }
 
+    pub fn set_caret_animation(&mut self, caret_animation: CaretAnimation) {
+        self.state.caret_animation = caret_animation;
+    }
+
+    pub fn with_caret_animation(mut self, caret_animation: CaretAnimation) -> Self {
+        self.set_caret_animation(caret_animation);
+        self
+    }
+
+    /// Underlines misspelled words, the text is checked on a background thread
+    /// whenever it changes. Does nothing if there is no spellchecker for the language.
+    pub fn set_spellcheck(&mut self, language: AzString) {
+        self.state.spellcheck_language = Some(language).into();
+    }
+
+    pub fn with_spellcheck(mut self, language: AzString) -> Self {
+        self.set_spellcheck(language);
+        self
+    }
+
+    pub fn set_on_spelling_suggestions(&mut self, data: RefAny, callback: TextInputOnSpellingSuggestionsCallbackType) {
+        self.state.on_spelling_suggestions = Some(TextInputOnSpellingSuggestions {
+            callback: TextInputOnSpellingSuggestionsCallback { cb: callback },
+            data
+        }).into();
+    }
+
+    pub fn with_on_spelling_suggestions(mut self, data: RefAny, callback: TextInputOnSpellingSuggestionsCallbackType) -> Self {
+        self.set_on_spelling_suggestions(data, callback);
+        self
+    }
+
+    /// Secure text entry for passwords: renders the text as bullets, disables the
+    /// spellchecker and the IME while the input is focused and zeroizes the text
+    /// when it is freed. Note that the `AzString` passed to `set_text` is not zeroized.
+    ///
+    /// NOTE: the IME is only disabled on Windows. The X11 shell doesn't use an input
+    /// method yet (typed text never reaches an IME) and the macOS shell doesn't track
+    /// the focused node yet, so there the IME is not disabled for secure inputs.
+    pub fn set_secure(&mut self, secure: bool) {
+        self.state.secure = secure;
+    }
+
+    pub fn with_secure(mut self, secure: bool) -> Self {
+        self.set_secure(secure);
+        self
+    }
+
     pub fn set_placeholder_style(&mut self, style: NodeDataInlineCssPropertyVec) {
         self.placeholder_style = style;
     }
@@ -497,9 +639,8 @@ impl TextInput {
 
         self.state.inner.cursor_pos = self.state.inner.text.len();
 
-        let label_text: String = self.state.inner.text.iter().filter_map(|s| {
-            core::char::from_u32(*s)
-        }).collect();
+        let label_text = self.state.get_label_text();
+        let secure = self.state.secure;
 
         let placeholder = self.state.inner.placeholder
             .as_ref()
@@ -508,7 +649,7 @@ impl TextInput {
 
         let state_ref = RefAny::new(self.state);
 
-        Dom::div()
+        let mut dom = Dom::div()
         .with_ids_and_classes(vec![Class("__azul-native-text-input-container".into())].into())
         .with_inline_css_props(self.container_style)
         .with_tab_index(TabIndex::Auto)
@@ -539,6 +680,11 @@ impl TextInput {
                 data: state_ref.clone(),
                 callback: Callback { cb: default_on_mouse_hover }
             },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::RightMouseDown),
+                data: state_ref.clone(),
+                callback: Callback { cb: default_on_right_mouse_down }
+            },
         ].into())
         .with_children(vec![
             Dom::text(placeholder)
@@ -552,7 +698,21 @@ impl TextInput {
                 .with_ids_and_classes(vec![Class("__azul-native-text-input-cursor".into())].into())
                 .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_CURSOR_PROPS))
             ].into())
-        ].into())
+        ].into());
+
+        // the shell disables the IME while a protected node is focused
+        if secure {
+            dom.root.set_accessibility_info(AccessibilityInfo {
+                name: None.into(),
+                value: None.into(),
+                role: AccessibilityRole::Text,
+                states: vec![AccessibilityState::Focusable, AccessibilityState::Protected].into(),
+                accelerator: None.into(),
+                default_action: None.into(),
+            });
+        }
+
+        dom
     }
 }
 
@@ -584,6 +744,13 @@ fn default_on_focus_received(
 
     text_input.inner.cursor_pos = text_input.inner.text.len();
 
+    if let Some(label_node_id) = info.get_next_sibling(placeholder_text_node_id) {
+        if let Some(cursor_node_id) = info.get_first_child(label_node_id) {
+            restart_cursor_animation(text_input, info, cursor_node_id);
+        }
+        start_spellcheck(text_input, info, label_node_id);
+    }
+
     Update::DoNothing
 }
 
@@ -613,6 +780,21 @@ fn default_on_focus_lost(
         );
     }
 
+    // stop blinking and hide the cursor
+    if let Some(timer_id) = text_input.cursor_animation.into_option() {
+        info.stop_timer(timer_id);
+    }
+    text_input.cursor_animation = None.into();
+
+    if let Some(cursor_node_id) = info
+        .get_next_sibling(placeholder_text_node_id)
+        .and_then(|label_node_id| info.get_first_child(label_node_id)) {
+        info.set_css_property(
+            cursor_node_id,
+            CssProperty::const_opacity(StyleOpacity::const_new(0))
+        );
+    }
+
     let result = {
         // rustc doesn't understand the borrowing lifetime here
         let text_input = &mut *text_input;
@@ -657,21 +839,32 @@ fn default_on_text_input_inner(
         let ontextinput = &mut text_input.on_text_input;
 
         // inner_clone has the new text
-        let mut inner_clone = text_input.inner.clone();
-        inner_clone.cursor_pos = inner_clone.cursor_pos.saturating_add(1);
-        inner_clone.text = {
-            let mut internal = inner_clone.text.clone().into_library_owned_vec();
-            internal.push(c);
-            internal.into()
+        let mut inner_clone = TextInputState {
+            text: {
+                let mut internal = Vec::with_capacity(text_input.inner.text.len() + 1);
+                internal.extend_from_slice(text_input.inner.text.as_ref());
+                internal.push(c);
+                internal.into()
+            },
+            placeholder: text_input.inner.placeholder.clone(),
+            max_len: text_input.inner.max_len,
+            selection: text_input.inner.selection.clone(),
+            cursor_pos: text_input.inner.cursor_pos.saturating_add(1),
         };
 
-        match ontextinput.as_mut() {
+        let result = match ontextinput.as_mut() {
             Some(TextInputOnTextInput { callback, data }) => (callback.cb)(data, info, &inner_clone),
             None => OnTextInputReturn {
                 update: Update::DoNothing,
                 valid: TextInputValid::Yes,
             },
+        };
+
+        if text_input.secure {
+            zeroize_text(&mut inner_clone.text);
         }
+
+        result
     };
 
     if result.valid == TextInputValid::Yes {
@@ -682,16 +875,16 @@ fn default_on_text_input_inner(
         );
 
         // append to the text
-        text_input.inner.text = {
-            let mut internal = text_input.inner.text.clone().into_library_owned_vec();
-            internal.push(c);
-            internal.into()
-        };
+        text_input.edit_text(|t| t.push(c));
         text_input.inner.cursor_pos = text_input.inner.cursor_pos.saturating_add(1);
 
-        info.set_string_contents(label_node_id, text_input.inner.get_text().into());
+        info.set_string_contents(label_node_id, text_input.get_label_text().into());
+        start_spellcheck(&*text_input, info, label_node_id);
     }
 
+    // don't blink while typing
+    restart_cursor_animation(&mut *text_input, info, cursor_node_id);
+
     Some(result.update)
 }
 
@@ -717,20 +910,215 @@ fn default_on_virtual_key_down_inner(
     let label_node_id = info.get_next_sibling(placeholder_node_id)?;
     let cursor_node_id = info.get_first_child(label_node_id)?;
 
-    if c != VirtualKeyCode::Back {
+    if c == VirtualKeyCode::Back {
+        text_input.edit_text(|t| { t.pop(); });
+        text_input.inner.cursor_pos = text_input.inner.cursor_pos.saturating_sub(1);
+
+        info.set_string_contents(label_node_id, text_input.get_label_text().into());
+        start_spellcheck(&*text_input, info, label_node_id);
+    }
+
+    // don't blink while typing
+    restart_cursor_animation(&mut *text_input, info, cursor_node_id);
+
+    let result = {
+        // rustc doesn't understand the borrowing lifetime here
+        let text_input = &mut *text_input;
+        let onvirtualkeydown = &mut text_input.on_virtual_key_down;
+        let inner = &text_input.inner;
+
+        match onvirtualkeydown.as_mut() {
+            Some(TextInputOnVirtualKeyDown { callback, data }) => (callback.cb)(data, info, &inner),
+            None => return None,
+        }
+    };
+
+    Some(result.update)
+}
+
+/// Shows the cursor and (re-)starts blinking after the `-azul-caret-animation-duration`
+fn restart_cursor_animation(
+    text_input: &mut TextInputStateWrapper,
+    info: &mut CallbackInfo,
+    cursor_node_id: DomNodeId,
+) {
+    if let Some(timer_id) = text_input.cursor_animation.into_option() {
+        info.stop_timer(timer_id);
+    }
+    text_input.cursor_animation = info
+        .start_caret_animation(cursor_node_id, text_input.caret_animation)
+        .into();
+}
+
+/// Text that is checked on the spellcheck thread
+struct SpellcheckRequest {
+    language: String,
+    text: String,
+}
+
+/// Misspelled words of the `SpellcheckRequest`, sent back to the main thread
+struct SpellcheckResult {
+    text: String,
+    misspellings: Vec<Misspelling>,
+}
+
+/// Checks the spelling of the current text on a background thread,
+/// the misspelled words are underlined once the thread has finished
+fn start_spellcheck(
+    text_input: &TextInputStateWrapper,
+    info: &mut CallbackInfo,
+    label_node_id: DomNodeId,
+) {
+    let language = match text_input.spellcheck_language.as_ref() {
+        Some(s) => s.as_str().to_string(),
+        None => return,
+    };
+
+    // the underlines of the old text are removed when the text changes
+    if text_input.secure || text_input.inner.text.is_empty() {
+        return;
+    }
+
+    let request = RefAny::new(SpellcheckRequest {
+        language,
+        text: text_input.inner.get_text(),
+    });
+
+    let _ = info.start_thread(request, RefAny::new(label_node_id), spellcheck_thread);
+}
+
+extern "C"
+fn spellcheck_thread(
+    mut request: RefAny,
+    mut sender: ThreadSender,
+    _: ThreadReceiver,
+) {
+    let request = match request.downcast_ref::<SpellcheckRequest>() {
+        Some(s) => s,
+        None => return,
+    };
+
+    let provider = match get_spellcheck_provider(&request.language) {
+        Some(s) => s,
+        None => return,
+    };
+
+    let misspellings = provider.check(&request.text);
+
+    sender.send(ThreadReceiveMsg::WriteBack(ThreadWriteBackMsg {
+        data: RefAny::new(SpellcheckResult { text: request.text.clone(), misspellings }),
+        callback: WriteBackCallback { cb: spellcheck_writeback },
+    }));
+}
+
+extern "C"
+fn spellcheck_writeback(
+    label_node_id: &mut RefAny,
+    result: &mut RefAny,
+    info: &mut CallbackInfo,
+) -> Update {
+
+    let label_node_id = match label_node_id.downcast_ref::<DomNodeId>() {
+        Some(s) => *s,
+        None => return Update::DoNothing,
+    };
+
+    let result = match result.downcast_ref::<SpellcheckResult>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    // text was edited while the thread was running: result is outdated,
+    // the thread that checks the new text is already running
+    let text_is_current = info
+        .get_string_contents(label_node_id)
+        .map(|s| s.as_str() == result.text.as_str())
+        .unwrap_or(false);
+
+    if !text_is_current {
+        return Update::DoNothing;
+    }
+
+    let underlines = result.misspellings.iter().map(|m| TextUnderline {
+        start: m.start,
+        end: m.end,
+        color: SPELLING_ERROR_COLOR,
+        style: TextUnderlineStyle::Wavy,
+    }).collect::<Vec<_>>();
+
+    info.set_text_underlines(label_node_id, underlines.into());
+
+    Update::DoNothing
+}
+
+extern "C"
+fn default_on_right_mouse_down(
+    text_input: &mut RefAny,
+    info: &mut CallbackInfo
+) -> Update {
+    default_on_right_mouse_down_inner(text_input, info)
+    .unwrap_or(Update::DoNothing)
+}
+
+/// Looks up the suggestions for the misspelled word under the cursor
+/// and invokes the `on_spelling_suggestions` callback
+fn default_on_right_mouse_down_inner(
+    text_input: &mut RefAny,
+    info: &mut CallbackInfo
+) -> Option<Update> {
+
+    let mut text_input = text_input.downcast_mut::<TextInputStateWrapper>()?;
+    let text_input = &mut *text_input;
+
+    if text_input.secure || text_input.on_spelling_suggestions.as_ref().is_none() {
         return None;
     }
 
-    text_input.inner.text = {
-        let mut internal = text_input.inner.text.clone().into_library_owned_vec();
-        internal.pop();
-        internal.into()
+    let provider = get_spellcheck_provider(text_input.spellcheck_language.as_ref()?.as_str())?;
+
+    let placeholder_node_id = info.get_first_child(info.get_hit_node())?;
+    let label_node_id = info.get_next_sibling(placeholder_node_id)?;
+    let inline_text = info.get_inline_text(label_node_id)?;
+
+    let cursor = info.get_cursor_relative_to_viewport().into_option()?;
+    let label_origin = info.get_node_position(label_node_id)?.get_static_offset();
+    let cursor = LogicalPosition::new(cursor.x - label_origin.x, cursor.y - label_origin.y);
+
+    // the underline rects are below the baseline, extend them
+    // upwards to the top of the line to hit-test the word
+    let font_size_px = inline_text.font_size_px;
+    let is_hit = |m: &Misspelling| {
+        inline_text.get_underline_rects(m.start, m.end).into_iter().any(|r| {
+            LogicalRect::new(
+                LogicalPosition::new(r.origin.x, r.origin.y - font_size_px),
+                LogicalSize::new(r.size.width, r.size.height + font_size_px),
+            ).hit_test(&cursor).is_some()
+        })
     };
-    text_input.inner.cursor_pos = text_input.inner.cursor_pos.saturating_sub(1);
 
-    info.set_string_contents(label_node_id, text_input.inner.get_text().into());
+    // checking the text of a single text input is fast enough to do it on the main thread
+    let text = text_input.inner.get_text();
+    let misspelling = provider.check(&text).into_iter().find(|m| is_hit(m))?;
 
-    None
+    let word = text.chars().skip(misspelling.start).take(misspelling.end - misspelling.start).collect::<String>();
+    let suggestions = provider.suggest(&word)
+        .into_iter()
+        .map(|s| s.into())
+        .collect::<Vec<AzString>>();
+
+    let spelling_suggestions = TextInputSpellingSuggestions {
+        range: TextInputSelectionRange { from: misspelling.start, to: misspelling.end },
+        word: word.into(),
+        suggestions: suggestions.into(),
+    };
+
+    let inner = &text_input.inner;
+    match text_input.on_spelling_suggestions.as_mut() {
+        Some(TextInputOnSpellingSuggestions { callback, data }) => {
+            Some((callback.cb)(data, info, &inner, &spelling_suggestions))
+        },
+        None => None,
+    }
 }
 
 extern "C"
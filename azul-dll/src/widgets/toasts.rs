@@ -0,0 +1,614 @@
+//! Toast / snackbar notifications: short messages that slide in at the
+//! bottom right corner of the window and disappear after a few seconds.
+//!
+//! The `Toasts` manager has to be stored in the application data, so that the
+//! queue survives DOM refreshes. The DOM of the manager (`Toasts::dom()`)
+//! should be inserted as the last child of the root node, so that it is drawn
+//! on top of the rest of the UI:
+//!
+//! ```rust,ignore
+//! // in the layout callback
+//! Dom::body()
+//! .with_children(vec![content, data.toasts.dom()].into())
+//!
+//! // in any callback
+//! data.toasts.show(info, Toast::new("File deleted".into())
+//!     .with_action("Undo".into(), data_clone, on_undo));
+//! ```
+
+use azul_desktop::css::*;
+use azul_desktop::css::AzString;
+use azul_desktop::callbacks::{
+    RefAny, Callback, CallbackInfo, Update,
+    TimerCallbackInfo, TimerCallbackReturn,
+};
+use azul_desktop::task::{
+    Duration, OptionDuration, Instant,
+    SystemTimeDiff, Timer, TerminateTimer,
+};
+use azul_desktop::dom::{
+    Dom, NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
+    NodeDataInlineCssProperty::{Normal, Hover},
+};
+use std::vec::Vec;
+
+const COLOR_323232: ColorU = ColorU { r: 50, g: 50, b: 50, a: 255 }; // #323232
+const COLOR_8AB4F8: ColorU = ColorU { r: 138, g: 180, b: 248, a: 255 }; // #8ab4f8
+
+const TOAST_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_323232)];
+const TOAST_ACTION_BACKGROUND_HOVER: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 255, g: 255, b: 255, a: 31 })];
+
+const SANS_SERIF_STR: &str = "sans-serif";
+const SANS_SERIF: AzString = AzString::from_const_str(SANS_SERIF_STR);
+const SANS_SERIF_FAMILIES: &[StyleFontFamily] = &[StyleFontFamily::System(SANS_SERIF)];
+const SANS_SERIF_FAMILY: StyleFontFamilyVec = StyleFontFamilyVec::from_const_slice(SANS_SERIF_FAMILIES);
+
+/// How long the slide / fade in and out animations take
+const TOAST_ANIMATION_MS: u64 = 200;
+/// How far the toast slides in from below
+const TOAST_SLIDE_DISTANCE_PX: f32 = 20.0;
+/// Interval of the timer that animates the toast
+const TOAST_TIMER_INTERVAL_MS: u64 = 16;
+/// Default time that a toast stays fully visible
+const TOAST_DEFAULT_DURATION_MS: u64 = 4000;
+/// Default maximum number of toasts visible at the same time
+const TOAST_DEFAULT_MAX_VISIBLE: usize = 3;
+
+static TOASTS_LAYER_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_bottom(LayoutBottom::const_px(24))),
+    Normal(CssProperty::const_right(LayoutRight::const_px(24))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(320))),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(0))),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+];
+
+static TOAST_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::Flex)),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    Normal(CssProperty::const_cursor(StyleCursor::Pointer)),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(TOAST_BACKGROUND))),
+    Normal(CssProperty::const_margin_top(LayoutMarginTop::const_px(8))),
+
+    // padding: 10px 16px
+
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(16))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(16))),
+    Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(10))),
+    Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(10))),
+
+    // border-radius: 4px
+
+    Normal(CssProperty::const_border_top_left_radius(StyleBorderTopLeftRadius::const_px(4))),
+    Normal(CssProperty::const_border_top_right_radius(StyleBorderTopRightRadius::const_px(4))),
+    Normal(CssProperty::const_border_bottom_left_radius(StyleBorderBottomLeftRadius::const_px(4))),
+    Normal(CssProperty::const_border_bottom_right_radius(StyleBorderBottomRightRadius::const_px(4))),
+];
+
+static TOAST_LABEL_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: ColorU::WHITE })),
+];
+
+static TOAST_ACTION_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(0))),
+    Normal(CssProperty::const_margin_left(LayoutMarginLeft::const_px(16))),
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(4))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(4))),
+    Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(2))),
+    Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(2))),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_8AB4F8 })),
+    Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(TOAST_ACTION_BACKGROUND_HOVER))),
+];
+
+pub type ToastOnActionCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo) -> Update;
+impl_callback!(ToastOnAction, OptionToastOnAction, ToastOnActionCallback, ToastOnActionCallbackType);
+
+/// Identifies a toast that was shown with `Toasts::show`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct ToastId {
+    pub id: usize,
+}
+
+/// Single notification message
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct Toast {
+    pub message: AzString,
+    /// How long the toast stays visible - `None` shows the toast
+    /// until it is clicked or dismissed via `Toasts::dismiss`
+    pub duration: OptionDuration,
+    /// Label of the action button (i.e. "Undo"), only shown if `on_action` is set
+    pub action_label: AzString,
+    /// Called when the action button is clicked, the toast is dismissed afterwards
+    pub on_action: OptionToastOnAction,
+}
+
+/// Manages the queue of toasts of a window
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct Toasts {
+    /// Queue of toasts, shared between all clones of this `Toasts`
+    pub state: RefAny,
+    /// Style of the container holding all toasts (bottom right corner by default)
+    pub layer_style: NodeDataInlineCssPropertyVec,
+    /// Style of a single toast
+    pub toast_style: NodeDataInlineCssPropertyVec,
+    /// Style of the message text
+    pub label_style: NodeDataInlineCssPropertyVec,
+    /// Style of the action button
+    pub action_style: NodeDataInlineCssPropertyVec,
+}
+
+#[derive(Debug)]
+struct ToastsState {
+    toasts: Vec<ToastEntry>,
+    max_visible: usize,
+    next_id: usize,
+}
+
+#[derive(Debug)]
+struct ToastEntry {
+    id: ToastId,
+    toast: Toast,
+    /// `ToastDataset`, set as the dataset of the toast node, so that the
+    /// timer can find the node again after the DOM has been refreshed
+    dataset: RefAny,
+    /// `None` while the toast is still queued
+    shown_at: Option<Instant>,
+    dismissed_at: Option<Instant>,
+    /// 0.0 = hidden, 1.0 = fully visible
+    progress: f32,
+    /// The action callback is only invoked once, even if the
+    /// button is clicked again while the toast fades out
+    action_invoked: bool,
+}
+
+#[derive(Debug)]
+struct ToastDataset {
+    toasts: RefAny,
+    id: ToastId,
+}
+
+impl Default for Toast {
+    fn default() -> Self {
+        Self::new(AzString::from_const_str(""))
+    }
+}
+
+impl Toast {
+
+    #[inline]
+    pub fn new(message: AzString) -> Self {
+        Self {
+            message,
+            duration: Some(Duration::System(SystemTimeDiff::from_millis(TOAST_DEFAULT_DURATION_MS))).into(),
+            action_label: AzString::from_const_str(""),
+            on_action: None.into(),
+        }
+    }
+
+    #[inline]
+    pub fn set_duration(&mut self, duration: OptionDuration) {
+        self.duration = duration;
+    }
+
+    #[inline]
+    pub fn with_duration(mut self, duration: OptionDuration) -> Self {
+        self.set_duration(duration);
+        self
+    }
+
+    #[inline]
+    pub fn set_action(&mut self, label: AzString, data: RefAny, callback: ToastOnActionCallbackType) {
+        self.action_label = label;
+        self.on_action = Some(ToastOnAction {
+            data,
+            callback: ToastOnActionCallback { cb: callback },
+        }).into();
+    }
+
+    #[inline]
+    pub fn with_action(mut self, label: AzString, data: RefAny, callback: ToastOnActionCallbackType) -> Self {
+        self.set_action(label, data, callback);
+        self
+    }
+
+    #[inline]
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::default();
+        core::mem::swap(&mut s, self);
+        s
+    }
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self {
+            state: RefAny::new(ToastsState {
+                toasts: Vec::new(),
+                max_visible: TOAST_DEFAULT_MAX_VISIBLE,
+                next_id: 0,
+            }),
+            layer_style: NodeDataInlineCssPropertyVec::from_const_slice(TOASTS_LAYER_PROPS),
+            toast_style: NodeDataInlineCssPropertyVec::from_const_slice(TOAST_CONTAINER_PROPS),
+            label_style: NodeDataInlineCssPropertyVec::from_const_slice(TOAST_LABEL_PROPS),
+            action_style: NodeDataInlineCssPropertyVec::from_const_slice(TOAST_ACTION_PROPS),
+        }
+    }
+}
+
+impl Toasts {
+
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues the toast and starts the timer that animates it in and out.
+    /// The DOM is refreshed automatically once the toast becomes visible.
+    pub fn show(&mut self, info: &mut CallbackInfo, toast: Toast) -> ToastId {
+
+        let toasts_clone = self.state.clone();
+
+        let mut toasts = match self.state.downcast_mut::<ToastsState>() {
+            Some(s) => s,
+            None => return ToastId { id: 0 },
+        };
+
+        let id = ToastId { id: toasts.next_id };
+        toasts.next_id += 1;
+
+        let dataset = RefAny::new(ToastDataset { toasts: toasts_clone, id });
+
+        toasts.toasts.push(ToastEntry {
+            id,
+            toast,
+            dataset: dataset.clone(),
+            shown_at: None,
+            dismissed_at: None,
+            progress: 0.0,
+            action_invoked: false,
+        });
+
+        // the timer is not attached to a node, so that it keeps running
+        // when the DOM is recreated - it terminates itself once the toast is removed
+        let timer = Timer::new(dataset, animate_toast, info.get_system_time_fn())
+            .with_interval(Duration::System(SystemTimeDiff::from_millis(TOAST_TIMER_INTERVAL_MS)));
+
+        info.start_timer(timer);
+
+        id
+    }
+
+    /// Fades out the toast (or removes it from the queue if it isn't
+    /// visible yet). Returns false if the toast doesn't exist anymore.
+    pub fn dismiss(&mut self, info: &mut CallbackInfo, toast_id: ToastId) -> bool {
+        let now = info.get_current_time();
+        match self.state.downcast_mut::<ToastsState>() {
+            Some(mut s) => s.dismiss(toast_id, now),
+            None => false,
+        }
+    }
+
+    /// Sets how many toasts can be visible at the same time, all other toasts are queued
+    pub fn set_max_visible(&mut self, max_visible: usize) {
+        if let Some(mut s) = self.state.downcast_mut::<ToastsState>() {
+            s.max_visible = max_visible.max(1);
+        }
+    }
+
+    #[inline]
+    pub fn with_max_visible(mut self, max_visible: usize) -> Self {
+        self.set_max_visible(max_visible);
+        self
+    }
+
+    #[inline]
+    pub fn set_layer_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.layer_style = style;
+    }
+
+    #[inline]
+    pub fn with_layer_style(mut self, style: NodeDataInlineCssPropertyVec) -> Self {
+        self.set_layer_style(style);
+        self
+    }
+
+    #[inline]
+    pub fn set_toast_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.toast_style = style;
+    }
+
+    #[inline]
+    pub fn with_toast_style(mut self, style: NodeDataInlineCssPropertyVec) -> Self {
+        self.set_toast_style(style);
+        self
+    }
+
+    #[inline]
+    pub fn set_label_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.label_style = style;
+    }
+
+    #[inline]
+    pub fn with_label_style(mut self, style: NodeDataInlineCssPropertyVec) -> Self {
+        self.set_label_style(style);
+        self
+    }
+
+    #[inline]
+    pub fn set_action_style(&mut self, style: NodeDataInlineCssPropertyVec) {
+        self.action_style = style;
+    }
+
+    #[inline]
+    pub fn with_action_style(mut self, style: NodeDataInlineCssPropertyVec) -> Self {
+        self.set_action_style(style);
+        self
+    }
+
+    #[inline]
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::default();
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    /// Renders the currently visible toasts
+    pub fn dom(&mut self) -> Dom {
+
+        use azul_desktop::dom::{
+            CallbackData, EventFilter, HoverEventFilter,
+            IdOrClass::Class,
+        };
+
+        let toast_style = &self.toast_style;
+        let label_style = &self.label_style;
+        let action_style = &self.action_style;
+
+        let toasts = match self.state.downcast_ref::<ToastsState>() {
+            Some(s) => s,
+            None => return Dom::div(),
+        };
+
+        let children = toasts.toasts.iter().take(toasts.max_visible).map(|entry| {
+
+            let mut toast_style = toast_style.clone().into_library_owned_vec();
+            toast_style.extend(get_toast_animation_props(entry.progress).iter().cloned().map(Normal));
+
+            let mut children = vec![
+                Dom::text(entry.toast.message.clone())
+                .with_ids_and_classes(vec![Class("__azul-native-toast-label".into())].into())
+                .with_inline_css_props(label_style.clone())
+            ];
+
+            if entry.toast.on_action.is_some() {
+                children.push(
+                    Dom::text(entry.toast.action_label.clone())
+                    .with_ids_and_classes(vec![Class("__azul-native-toast-action".into())].into())
+                    .with_inline_css_props(action_style.clone())
+                    .with_callbacks(vec![
+                        CallbackData {
+                            event: EventFilter::Hover(HoverEventFilter::MouseUp),
+                            data: entry.dataset.clone(),
+                            callback: Callback { cb: on_toast_action },
+                        },
+                    ].into())
+                );
+            }
+
+            Dom::div()
+            .with_dataset(Some(entry.dataset.clone()).into())
+            .with_ids_and_classes(vec![Class("__azul-native-toast".into())].into())
+            .with_inline_css_props(toast_style.into())
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::MouseUp),
+                    data: entry.dataset.clone(),
+                    callback: Callback { cb: on_toast_click },
+                },
+            ].into())
+            .with_children(children.into())
+        }).collect::<Vec<_>>();
+
+        Dom::div()
+        .with_ids_and_classes(vec![Class("__azul-native-toasts".into())].into())
+        .with_inline_css_props(self.layer_style.clone())
+        .with_children(children.into())
+    }
+}
+
+impl ToastsState {
+    fn dismiss(&mut self, toast_id: ToastId, now: Instant) -> bool {
+        let position = match self.toasts.iter().position(|t| t.id == toast_id) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        if self.toasts[position].shown_at.is_none() {
+            self.toasts.remove(position);
+        } else if self.toasts[position].dismissed_at.is_none() {
+            self.toasts[position].dismissed_at = Some(now);
+        }
+
+        true
+    }
+}
+
+fn get_toast_animation_props(progress: f32) -> [CssProperty;2] {
+    let progress = progress.max(0.0).min(1.0);
+    [
+        CssProperty::const_opacity(StyleOpacity { inner: PercentageValue::new(progress * 100.0) }),
+        CssProperty::const_transform(vec![
+            StyleTransform::TranslateY(PixelValue::px((1.0 - progress) * TOAST_SLIDE_DISTANCE_PX))
+        ].into()),
+    ]
+}
+
+fn get_toast_animation_duration() -> Duration {
+    Duration::System(SystemTimeDiff::from_millis(TOAST_ANIMATION_MS))
+}
+
+extern "C" fn animate_toast(data: &mut RefAny, info: &mut TimerCallbackInfo) -> TimerCallbackReturn {
+
+    let terminate = TimerCallbackReturn {
+        should_update: Update::DoNothing,
+        should_terminate: TerminateTimer::Terminate,
+    };
+
+    let search_key = data.clone();
+    let now = info.callback_info.get_current_time();
+    let animation_duration = get_toast_animation_duration();
+
+    let mut dataset = match data.downcast_mut::<ToastDataset>() {
+        Some(s) => s,
+        None => return terminate,
+    };
+
+    let toast_id = dataset.id;
+
+    let (should_update, progress_changed, progress) = {
+
+        let mut toasts = match dataset.toasts.downcast_mut::<ToastsState>() {
+            Some(s) => s,
+            None => return terminate,
+        };
+
+        let position = match toasts.toasts.iter().position(|t| t.id == toast_id) {
+            Some(s) => s,
+            None => return terminate,
+        };
+
+        // still queued
+        if position >= toasts.max_visible {
+            return TimerCallbackReturn {
+                should_update: Update::DoNothing,
+                should_terminate: TerminateTimer::Continue,
+            };
+        }
+
+        let mut should_update = Update::DoNothing;
+        let entry = &mut toasts.toasts[position];
+
+        let shown_at = match entry.shown_at.clone() {
+            Some(s) => s,
+            None => {
+                // toast just became visible, add it to the DOM
+                entry.shown_at = Some(now.clone());
+                should_update = Update::RefreshDom;
+                now.clone()
+            }
+        };
+
+        if entry.dismissed_at.is_none() {
+            if let Some(duration) = entry.toast.duration.as_ref() {
+                if now.duration_since(&shown_at).greater_than(duration) {
+                    entry.dismissed_at = Some(now.clone());
+                }
+            }
+        }
+
+        let mut fade_out_finished = false;
+        let progress = match entry.dismissed_at.as_ref() {
+            Some(dismissed_at) => {
+                let t = now.duration_since(dismissed_at).div(&animation_duration).min(1.0);
+                fade_out_finished = t >= 1.0;
+                1.0 - AnimationInterpolationFunction::EaseIn.evaluate(t as f64)
+            },
+            None => {
+                let t = now.duration_since(&shown_at).div(&animation_duration).min(1.0);
+                AnimationInterpolationFunction::EaseOut.evaluate(t as f64)
+            },
+        };
+
+        // fade out finished, remove the toast (the next queued toast moves up)
+        if fade_out_finished {
+            toasts.toasts.remove(position);
+            return TimerCallbackReturn {
+                should_update: Update::RefreshDom,
+                should_terminate: TerminateTimer::Terminate,
+            };
+        }
+
+        let progress_changed = entry.progress != progress;
+        entry.progress = progress;
+
+        (should_update, progress_changed, progress)
+    };
+
+    if progress_changed {
+        if let Some(node_id) = info.callback_info.get_node_id_of_root_dataset(search_key) {
+            for prop in get_toast_animation_props(progress).iter() {
+                info.callback_info.set_css_property(node_id, prop.clone());
+            }
+        }
+    }
+
+    TimerCallbackReturn {
+        should_update,
+        should_terminate: TerminateTimer::Continue,
+    }
+}
+
+// Clicking on a toast dismisses it
+extern "C" fn on_toast_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    let now = info.get_current_time();
+
+    let mut dataset = match data.downcast_mut::<ToastDataset>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    let toast_id = dataset.id;
+
+    if let Some(mut toasts) = dataset.toasts.downcast_mut::<ToastsState>() {
+        toasts.dismiss(toast_id, now);
+    }
+
+    Update::DoNothing
+}
+
+extern "C" fn on_toast_action(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    let now = info.get_current_time();
+
+    let mut dataset = match data.downcast_mut::<ToastDataset>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    let toast_id = dataset.id;
+
+    let on_action = {
+        let mut toasts = match dataset.toasts.downcast_mut::<ToastsState>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+
+        let on_action = toasts.toasts.iter_mut()
+            .find(|t| t.id == toast_id && !t.action_invoked)
+            .and_then(|t| {
+                t.action_invoked = true;
+                t.toast.on_action.as_ref().cloned()
+            });
+
+        toasts.dismiss(toast_id, now);
+
+        on_action
+    };
+
+    // the toast state is not borrowed anymore, so the
+    // action callback can show another toast
+    match on_action {
+        Some(mut on_action) => (on_action.callback.cb)(&mut on_action.data, info),
+        None => Update::DoNothing,
+    }
+}
@@ -0,0 +1,144 @@
+//! Benchmarks for the stages of the rendering pipeline: restyle (CSS cascade),
+//! layout property resolution, layout and display list generation.
+//!
+//! Run with `cargo bench -p azul-layout --features bench`
+
+extern crate azul_core;
+extern crate azul_css;
+extern crate azul_css_parser;
+extern crate azul_layout;
+#[macro_use]
+extern crate criterion;
+
+use azul_core::{
+    app_resources::{Epoch, GlTextureCache, IdNamespace, ImageCache, RendererResources},
+    callbacks::DocumentId,
+    dom::{Dom, IdOrClass},
+    styled_dom::{DomId, StyledDom},
+    ui_solver::LayoutResult,
+    window::{FullWindowState, LogicalPosition, LogicalRect, LogicalSize, ScrollStates, ScrollbarInteraction},
+};
+use azul_css_parser::CssApiWrapper;
+use azul_layout::bench::{do_the_layout_internal, precalculate_layout_properties};
+use criterion::{BatchSize, Criterion};
+
+const CSS: &str = "
+    body { display: flex; flex-direction: column; font-size: 14px; }
+    .item { height: 20px; padding: 2px 5px; margin: 1px; border: 1px solid #ccc; }
+    .item:hover { background: #eee; }
+    .node { flex-grow: 1; padding-left: 2px; }
+    .row { display: flex; flex-direction: row; flex-grow: 1; }
+    .cell { flex-grow: 1; min-width: 5px; background: #fafafa; }
+";
+
+const WINDOW_SIZE: LogicalSize = LogicalSize { width: 1024.0, height: 768.0 };
+
+fn class(name: &str) -> azul_core::dom::IdOrClassVec {
+    vec![IdOrClass::Class(name.into())].into()
+}
+
+/// 10 000 flat list items
+fn list_dom() -> Dom {
+    let items = (0..10_000).map(|_| Dom::div().with_ids_and_classes(class("item"))).collect::<Vec<_>>();
+    Dom::body().with_children(items.into())
+}
+
+/// 500 levels deep chain of nodes
+fn deep_dom() -> Dom {
+    let mut dom = Dom::div().with_ids_and_classes(class("node"));
+    for _ in 0..500 {
+        dom = Dom::div().with_ids_and_classes(class("node")).with_children(vec![dom].into());
+    }
+    Dom::body().with_children(vec![dom].into())
+}
+
+/// 100 x 100 grid of flex rows / cells
+fn grid_dom() -> Dom {
+    let rows = (0..100).map(|_| {
+        let cells = (0..100).map(|_| Dom::div().with_ids_and_classes(class("cell"))).collect::<Vec<_>>();
+        Dom::div().with_ids_and_classes(class("row")).with_children(cells.into())
+    }).collect::<Vec<_>>();
+    Dom::body().with_children(rows.into())
+}
+
+fn css() -> CssApiWrapper {
+    CssApiWrapper::from_string(CSS.into())
+}
+
+fn document_id() -> DocumentId {
+    DocumentId { namespace_id: IdNamespace(0), id: 0 }
+}
+
+fn layout(styled_dom: StyledDom, renderer_resources: &mut RendererResources) -> LayoutResult {
+    do_the_layout_internal(
+        DomId::ROOT_ID,
+        None,
+        styled_dom,
+        renderer_resources,
+        &document_id(),
+        LogicalRect::new(LogicalPosition::zero(), WINDOW_SIZE),
+    )
+}
+
+fn bench_dom(c: &mut Criterion, name: &str, dom: fn() -> Dom) {
+
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("styled_dom_new", |b| {
+        b.iter_batched(dom, |mut dom| StyledDom::new(&mut dom, css()), BatchSize::LargeInput)
+    });
+
+    let styled_dom = StyledDom::new(&mut dom(), css());
+
+    group.bench_function("restyle", |b| {
+        b.iter_batched(|| styled_dom.clone(), |mut s| { s.restyle(css()); s }, BatchSize::LargeInput)
+    });
+
+    group.bench_function("layout_properties", |b| {
+        b.iter(|| precalculate_layout_properties(&styled_dom))
+    });
+
+    group.bench_function("layout", |b| {
+        b.iter_batched(
+            || (styled_dom.clone(), RendererResources::default()),
+            |(s, mut renderer_resources)| layout(s, &mut renderer_resources),
+            BatchSize::LargeInput,
+        )
+    });
+
+    let mut renderer_resources = RendererResources::default();
+    let layout_result = layout(styled_dom.clone(), &mut renderer_resources);
+    let layout_results = [layout_result];
+    let mut window_state = FullWindowState::default();
+    window_state.size.dimensions = WINDOW_SIZE;
+    let gl_texture_cache = GlTextureCache::default();
+    let image_cache = ImageCache::default();
+    let scroll_states = ScrollStates::default();
+    let scrollbar_interaction = ScrollbarInteraction::default();
+
+    group.bench_function("display_list", |b| {
+        b.iter(|| LayoutResult::get_cached_display_list(
+            &document_id(),
+            DomId::ROOT_ID,
+            Epoch::new(),
+            &layout_results,
+            &window_state,
+            &gl_texture_cache,
+            &renderer_resources,
+            &image_cache,
+            &scroll_states,
+            &scrollbar_interaction,
+        ))
+    });
+
+    group.finish();
+}
+
+fn stages(c: &mut Criterion) {
+    bench_dom(c, "list_10k", list_dom);
+    bench_dom(c, "deep_500", deep_dom);
+    bench_dom(c, "grid_100x100", grid_dom);
+}
+
+criterion_group!(benches, stages);
+criterion_main!(benches);
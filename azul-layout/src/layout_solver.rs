@@ -11,7 +11,7 @@ use azul_core::{
         NodeId, NodeDataContainer,
         NodeDataContainerRef, NodeDataContainerRefMut
     },
-    dom::{NodeData, NodeType},
+    dom::{NodeData, NodeType, OverlayAlignment, OverlaySide},
     styled_dom::{
         StyledDom, DomId, StyledNode, NodeHierarchyItem, StyledNodeState,
         ParentWithNodeDepth, ChangedCssProperty, CssPropertyCache,
@@ -29,7 +29,7 @@ use azul_core::{
         FontInstanceKey, Epoch, ShapedWords,
         WordPositions, Words, ImageCache, DpiScaleFactor,
     },
-    callbacks::DocumentId,
+    callbacks::{DocumentId, MeasureCallbackInfo, ArrangeCallbackInfo},
     display_list::RenderCallbacks,
     window::{
         FullWindowState, LogicalRect,
@@ -64,6 +64,49 @@ pub struct HeightConfig {
     pub overflow: Option<LayoutOverflow>,
 }
 
+/// Internal stages of the layout solver, exposed for benchmarking only (`--features bench`)
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod bench {
+
+    use super::*;
+
+    #[cfg(feature = "text_layout")]
+    pub use super::do_the_layout_internal;
+
+    /// Layout-relevant CSS properties of all nodes, as resolved
+    /// from the `CssPropertyCache` before the layout is solved
+    pub struct PrecalculatedLayoutProperties {
+        pub(crate) positions: NodeDataContainer<LayoutPosition>,
+        pub(crate) flex_grows: NodeDataContainer<f32>,
+        pub(crate) displays: NodeDataContainer<CssPropertyValue<LayoutDisplay>>,
+        pub(crate) directions: NodeDataContainer<LayoutFlexDirection>,
+        pub(crate) justify_contents: NodeDataContainer<LayoutJustifyContent>,
+        pub(crate) offsets: NodeDataContainer<AllOffsets>,
+        pub(crate) width_heights: NodeDataContainer<WhConfig>,
+    }
+
+    impl PrecalculatedLayoutProperties {
+        pub fn len(&self) -> usize {
+            self.width_heights.len()
+        }
+    }
+
+    /// Queries the `CssPropertyCache` for all properties that the layout solver needs
+    /// (first stage of `do_the_layout_internal`)
+    pub fn precalculate_layout_properties(styled_dom: &StyledDom) -> PrecalculatedLayoutProperties {
+        PrecalculatedLayoutProperties {
+            positions: get_layout_positions(styled_dom),
+            flex_grows: get_layout_flex_grows(styled_dom),
+            displays: get_layout_displays(styled_dom),
+            directions: get_layout_flex_directions(styled_dom),
+            justify_contents: get_layout_justify_contents(styled_dom),
+            offsets: precalculate_all_offsets(styled_dom),
+            width_heights: precalculate_wh_config(styled_dom),
+        }
+    }
+}
+
 fn precalculate_wh_config(styled_dom: &StyledDom) -> NodeDataContainer<WhConfig> {
 
     use rayon::prelude::*;
@@ -1254,6 +1297,10 @@ pub fn get_layout_positions<'a>(styled_dom: &StyledDom) -> NodeDataContainer<Lay
     assert!(node_data_container.internal.len() == styled_nodes.internal.len()); // elide bounds checking
     NodeDataContainer {
         internal: styled_nodes.internal.par_iter().enumerate().map(|(node_id, styled_node)| {
+            // overlays are taken out of the regular flow, see position_overlays()
+            if node_data_container.internal[node_id].is_overlay() {
+                return LayoutPosition::Absolute;
+            }
             cache.get_position(
                 &node_data_container.internal[node_id],
                 &NodeId::new(node_id),
@@ -1584,6 +1631,7 @@ pub fn do_the_layout(
             );
 
             let mut iframe_mapping = BTreeMap::new();
+            let mut iframe_invoked_rects = BTreeMap::new();
 
             for iframe_node_id in layout_result.styled_dom.scan_for_iframe_callbacks() {
 
@@ -1593,6 +1641,7 @@ pub fn do_the_layout(
                 iframe_mapping.insert(iframe_node_id, iframe_dom_id);
 
                 let bounds = &layout_result.rects.as_ref()[iframe_node_id];
+                iframe_invoked_rects.insert(iframe_node_id, LogicalRect::new(bounds.position.get_static_offset(), bounds.size));
                 let bounds_size = LayoutSize::new(
                     bounds.size.width.round() as isize,
                     bounds.size.height.round() as isize
@@ -1636,6 +1685,7 @@ pub fn do_the_layout(
                 } = iframe_return;
 
                 let mut iframe_dom = dom;
+                iframe_dom.set_system_style(&full_window_state.system_style);
                 let (scroll_node_id, scroll_dom_id) = match parent_dom_id {
                     Some(s) => (iframe_node_id, s),
                     None => (NodeId::ZERO, DomId { inner: 0 }),
@@ -1693,6 +1743,7 @@ pub fn do_the_layout(
             }
 
             layout_result.iframe_mapping = iframe_mapping;
+            layout_result.iframe_invoked_rects = iframe_invoked_rects;
             resolved_doms.push(layout_result);
         }
 
@@ -1751,7 +1802,7 @@ pub fn do_the_layout_internal(
     let layout_width_heights = precalculate_wh_config(&styled_dom);
 
     // Break all strings into words and / or resolve the TextIds
-    let word_cache = create_word_cache(&styled_dom.node_data.as_container());
+    let word_cache = create_word_cache(&styled_dom);
     // Scale the words to the correct size - TODO: Cache this in the app_resources!
     let shaped_words = create_shaped_words(renderer_resources, &word_cache, &styled_dom);
 
@@ -1803,6 +1854,16 @@ pub fn do_the_layout_internal(
         content_widths_pre.as_ref_mut()[*node_id] = Some(word_positions.0.content_size.width);
     }
 
+    // custom layout nodes: ask the embedder for the desired width
+    let custom_layout_sizes = measure_custom_layout_nodes(
+        &mut styled_dom.node_data.as_container_mut(),
+        &display_none_nodes,
+        |_| MeasureCallbackInfo { available_size: rect_size, width_is_final: false },
+    );
+    for (node_id, size) in custom_layout_sizes.iter() {
+        content_widths_pre.as_ref_mut()[*node_id] = Some(size.width);
+    }
+
     let mut width_calculated_arena = width_calculated_rect_arena_from_rect_layout_arena(
         &layout_width_heights.as_ref(),
         &layout_offsets.as_ref(),
@@ -1883,6 +1944,22 @@ pub fn do_the_layout_internal(
         content_heights_pre.as_ref_mut()[*node_id] = Some(word_positions.0.content_size.height);
     }
 
+    // custom layout nodes: measure again, now that the final width is known
+    let custom_layout_sizes = measure_custom_layout_nodes(
+        &mut styled_dom.node_data.as_container_mut(),
+        &display_none_nodes,
+        |node_id| MeasureCallbackInfo {
+            available_size: LogicalSize::new(
+                width_calculated_arena.as_ref()[node_id].total(),
+                rect_size.height,
+            ),
+            width_is_final: true,
+        },
+    );
+    for (node_id, size) in custom_layout_sizes.iter() {
+        content_heights_pre.as_ref_mut()[*node_id] = Some(size.height);
+    }
+
     // TODO: The content height is not the final height!
     let mut height_calculated_arena = height_calculated_rect_arena_from_rect_layout_arena(
         &layout_width_heights.as_ref(),
@@ -1941,6 +2018,15 @@ pub fn do_the_layout_internal(
         &all_parents_btreeset,
     );
 
+    position_overlays(
+        &mut x_positions,
+        &mut y_positions,
+        &width_calculated_arena.as_ref(),
+        &height_calculated_arena.as_ref(),
+        &styled_dom,
+        bounds,
+    );
+
     let mut positioned_rects = NodeDataContainer {
         internal: vec![PositionedRectangle::default(); styled_dom.node_data.len()].into()
     };
@@ -1964,6 +2050,12 @@ pub fn do_the_layout_internal(
         document_id
     );
 
+    arrange_custom_layout_nodes(
+        &mut styled_dom.node_data.as_container_mut(),
+        &positioned_rects.as_ref(),
+        &nodes_that_updated_positions,
+    );
+
     let mut overflowing_rects = ScrolledNodes::default();
     get_nodes_that_need_scroll_clip(
         &mut overflowing_rects,
@@ -2002,7 +2094,44 @@ pub fn do_the_layout_internal(
         positioned_words_cache: word_positions_with_max_width,
         scrollable_nodes: overflowing_rects,
         iframe_mapping: BTreeMap::new(),
+        iframe_invoked_rects: BTreeMap::new(),
         gpu_value_cache,
+        text_underlines: BTreeMap::new(),
+    }
+}
+
+/// Invokes the `MeasureCallback` of all visible `NodeType::CustomLayout` nodes
+///
+/// NOTE: runs sequentially, since the callbacks are user code that may mutate the `RefAny`
+#[cfg(feature = "text_layout")]
+fn measure_custom_layout_nodes<'a, F: Fn(NodeId) -> MeasureCallbackInfo>(
+    node_data: &mut NodeDataContainerRefMut<'a, NodeData>,
+    display_none_nodes: &[bool],
+    get_measure_info: F,
+) -> BTreeMap<NodeId, LogicalSize> {
+    node_data.internal.iter_mut().enumerate().filter_map(|(node_id, node)| {
+        if display_none_nodes[node_id] { return None; }
+        let custom_layout_node = node.get_custom_layout_node()?;
+        let node_id = NodeId::new(node_id);
+        let info = get_measure_info(node_id);
+        let size = (custom_layout_node.measure.cb)(&mut custom_layout_node.data, &info);
+        Some((node_id, size))
+    }).collect()
+}
+
+/// Invokes the `ArrangeCallback` of all `NodeType::CustomLayout` nodes whose position was updated
+fn arrange_custom_layout_nodes<'a, 'b>(
+    node_data: &mut NodeDataContainerRefMut<'a, NodeData>,
+    positioned_rects: &NodeDataContainerRef<'b, PositionedRectangle>,
+    nodes_that_updated_positions: &BTreeSet<NodeId>,
+) {
+    for node_id in nodes_that_updated_positions.iter() {
+        let custom_layout_node = match node_data.internal.get_mut(node_id.index()).and_then(|n| n.get_custom_layout_node()) {
+            Some(s) => s,
+            None => continue,
+        };
+        let info = ArrangeCallbackInfo { bounds: positioned_rects[*node_id].get_node_rect() };
+        (custom_layout_node.arrange.cb)(&mut custom_layout_node.data, &info);
     }
 }
 
@@ -2140,6 +2269,10 @@ fn position_nodes<'a>(
                 static_y_offset: y_pos,
             }),
         };
+        let parent_position_info = get_overlay_position_info(
+            &styled_dom.node_data.as_container()[parent_node_id],
+            parent_position_info,
+        );
         let parent_size = LogicalSize::new(width.total(), height.total());
 
         let parent_offsets = match offsets.get_offsets_for_node(&parent_node_id) {
@@ -2203,6 +2336,7 @@ fn position_nodes<'a>(
                 }),
             };
 
+            let child_position = get_overlay_position_info(child_node_data, child_position);
             let child_size_logical = LogicalSize::new(width.total(), height.total());
             let child_offsets = match offsets.get_offsets_for_node(&child_node_id) {
                 Some(s) => s,
@@ -2291,27 +2425,216 @@ fn position_nodes<'a>(
     }
 }
 
+/// Returns all nodes that are overlays (see `Dom::overlay`), in DOM order
+fn get_overlay_nodes(styled_dom: &StyledDom) -> Vec<NodeId> {
+    styled_dom.node_data.as_container().internal.iter().enumerate()
+    .filter(|(_, node_data)| node_data.is_overlay())
+    .map(|(node_id, _)| NodeId::new(node_id))
+    .collect()
+}
+
+/// Overlays are rendered relative to the window instead of their parent,
+/// so that they are only clipped by the window
+fn get_overlay_position_info(
+    node_data: &NodeData,
+    position: azul_core::ui_solver::PositionInfo
+) -> azul_core::ui_solver::PositionInfo {
+    use azul_core::ui_solver::PositionInfo;
+    if !node_data.is_overlay() {
+        return position;
+    }
+    let static_offset = position.get_static_offset();
+    PositionInfo::Fixed(PositionInfoInner {
+        x_offset: static_offset.x,
+        y_offset: static_offset.y,
+        static_x_offset: static_offset.x,
+        static_y_offset: static_offset.y,
+    })
+}
+
+/// Moves every overlay (and its children) next to its anchor node. If the overlay
+/// doesn't fit into the `bounds` on the requested side of the anchor, it is flipped
+/// to the opposite side (if it fits there) and then shifted back into the `bounds`.
+///
+/// Overlays whose anchor node can't be found stay at their original position.
+fn position_overlays<'a>(
+    x_positions: &mut NodeDataContainer<HorizontalSolvedPosition>,
+    y_positions: &mut NodeDataContainer<VerticalSolvedPosition>,
+    solved_widths: &NodeDataContainerRef<'a, WidthCalculatedRect>,
+    solved_heights: &NodeDataContainerRef<'a, HeightCalculatedRect>,
+    styled_dom: &StyledDom,
+    bounds: LogicalRect,
+) {
+    let node_data_container = styled_dom.node_data.as_container();
+
+    // anchors are resolved in DOM order, so an overlay may be
+    // anchored to a node inside of a previous overlay
+    for overlay_node_id in get_overlay_nodes(styled_dom) {
+
+        let overlay = match node_data_container[overlay_node_id].get_overlay() {
+            Some(s) => s,
+            None => continue,
+        };
+
+        let anchor_id = overlay.get_anchor_id();
+        let anchor_node_id = match node_data_container.linear_iter().find(|n| {
+            *n != overlay_node_id && node_data_container[*n].has_id(anchor_id)
+        }) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        let anchor = LogicalRect::new(
+            LogicalPosition::new(x_positions.as_ref()[anchor_node_id].0, y_positions.as_ref()[anchor_node_id].0),
+            LogicalSize::new(solved_widths[anchor_node_id].total(), solved_heights[anchor_node_id].total()),
+        );
+        let overlay_size = LogicalSize::new(
+            solved_widths[overlay_node_id].total(),
+            solved_heights[overlay_node_id].total(),
+        );
+
+        let new_origin = get_overlay_origin(
+            anchor,
+            overlay_size,
+            overlay.placement.get_side(),
+            overlay.placement.get_alignment(),
+            bounds,
+        );
+
+        let dx = new_origin.x - x_positions.as_ref()[overlay_node_id].0;
+        let dy = new_origin.y - y_positions.as_ref()[overlay_node_id].0;
+
+        // get_subtree() returns an empty Vec for leaf nodes
+        let mut subtree = styled_dom.get_subtree(overlay_node_id);
+        if subtree.is_empty() {
+            subtree.push(overlay_node_id);
+        }
+
+        for node_id in subtree {
+            x_positions.as_ref_mut()[node_id].0 += dx;
+            y_positions.as_ref_mut()[node_id].0 += dy;
+        }
+    }
+}
+
+/// Calculates the origin of an overlay with the size `overlay` next to the `anchor`
+fn get_overlay_origin(
+    anchor: LogicalRect,
+    overlay: LogicalSize,
+    side: OverlaySide,
+    alignment: OverlayAlignment,
+    bounds: LogicalRect,
+) -> LogicalPosition {
+
+    let bounds_right = bounds.origin.x + bounds.size.width;
+    let bounds_bottom = bounds.origin.y + bounds.size.height;
+    let anchor_right = anchor.origin.x + anchor.size.width;
+    let anchor_bottom = anchor.origin.y + anchor.size.height;
+
+    // position along the side of the anchor
+    let align = |anchor_start: f32, anchor_len: f32, overlay_len: f32| match alignment {
+        OverlayAlignment::Start => anchor_start,
+        OverlayAlignment::Center => anchor_start + (anchor_len - overlay_len) / 2.0,
+        OverlayAlignment::End => anchor_start + anchor_len - overlay_len,
+    };
+
+    // flip to the opposite side if the overlay overflows on the requested side
+    // and there is enough space on the opposite side
+    let (x, y) = match side {
+        OverlaySide::Bottom | OverlaySide::Top => {
+            let below = anchor_bottom;
+            let above = anchor.origin.y - overlay.height;
+            let fits_below = below + overlay.height <= bounds_bottom;
+            let fits_above = above >= bounds.origin.y;
+            let y = match side {
+                OverlaySide::Bottom if !fits_below && fits_above => above,
+                OverlaySide::Bottom => below,
+                _ if !fits_above && fits_below => below,
+                _ => above,
+            };
+            (align(anchor.origin.x, anchor.size.width, overlay.width), y)
+        },
+        OverlaySide::Right | OverlaySide::Left => {
+            let right = anchor_right;
+            let left = anchor.origin.x - overlay.width;
+            let fits_right = right + overlay.width <= bounds_right;
+            let fits_left = left >= bounds.origin.x;
+            let x = match side {
+                OverlaySide::Right if !fits_right && fits_left => left,
+                OverlaySide::Right => right,
+                _ if !fits_left && fits_right => right,
+                _ => left,
+            };
+            (x, align(anchor.origin.y, anchor.size.height, overlay.height))
+        },
+    };
+
+    // shift the overlay back into the bounds (the top left corner wins
+    // if the overlay is larger than the bounds)
+    let x = x.min(bounds_right - overlay.width).max(bounds.origin.x);
+    let y = y.min(bounds_bottom - overlay.height).max(bounds.origin.y);
+
+    LogicalPosition::new(x, y)
+}
+
 #[cfg(feature = "text_layout")]
 fn create_word_cache<'a>(
-    node_data: &NodeDataContainerRef<'a, NodeData>,
+    styled_dom: &'a StyledDom,
 ) -> BTreeMap<NodeId, Words>
 {
-    use azul_text_layout::text_layout::split_text_into_words;
+    use azul_text_layout::text_layout::split_text_into_words_with_options;
 
-    let word_map = node_data.internal
-    .par_iter()
+    let node_data = styled_dom.node_data.as_container();
+
+    let text_nodes = node_data.internal
+    .iter()
     .enumerate()
-    .map(|(node_id, node)| {
+    .filter_map(|(node_id, node)| {
         let node_id = NodeId::new(node_id);
         let string = match node.get_node_type() {
             NodeType::Text(string) => Some(string.as_str()),
             _ => None,
         }?;
-        Some((node_id, split_text_into_words(string)))
+        Some((node_id, string, get_line_break_options(styled_dom, node_id)))
     })
     .collect::<Vec<_>>();
 
-    word_map.into_iter().filter_map(|a| a).collect()
+    text_nodes
+    .into_par_iter()
+    .map(|(node_id, string, line_break_options)| {
+        (node_id, split_text_into_words_with_options(string, &line_break_options))
+    })
+    .collect()
+}
+
+/// Returns the `hyphens` property and the language (`lang` attribute of
+/// the node or of the closest parent that has one) of a text node
+#[cfg(feature = "text_layout")]
+fn get_line_break_options(styled_dom: &StyledDom, node_id: NodeId) -> azul_text_layout::text_layout::LineBreakOptions {
+
+    use azul_text_layout::text_layout::LineBreakOptions;
+
+    let css_property_cache = styled_dom.get_css_property_cache();
+    let styled_nodes = styled_dom.styled_nodes.as_container();
+    let node_data = styled_dom.node_data.as_container();
+    let node_hierarchy = styled_dom.node_hierarchy.as_container();
+
+    let hyphens = css_property_cache
+        .get_hyphens(&node_data[node_id], &node_id, &styled_nodes[node_id].state)
+        .and_then(|h| h.get_property().copied())
+        .unwrap_or_default();
+
+    let mut lang = None;
+    let mut current = Some(node_id);
+    while let Some(id) = current {
+        if let Some(l) = node_data[id].get_lang() {
+            lang = Some(l.clone());
+            break;
+        }
+        current = node_hierarchy[id].parent_id();
+    }
+
+    LineBreakOptions { lang, hyphens }
 }
 
 // same as get_inline_text(), but shapes a new word instead of using the internal one
@@ -2819,13 +3142,13 @@ pub fn do_the_relayout(
         for (node_id, new_string) in words_to_relayout.iter() {
 
             use azul_text_layout::text_layout::word_positions_to_inline_text_layout;
-            use azul_text_layout::text_layout::split_text_into_words;
+            use azul_text_layout::text_layout::split_text_into_words_incremental;
             use azul_core::styled_dom::StyleFontFamiliesHash;
-            use azul_text_layout::text_layout::shape_words;
+            use azul_text_layout::text_layout::shape_words_incremental;
             use azul_core::ui_solver::DEFAULT_LETTER_SPACING;
             use azul_core::ui_solver::DEFAULT_WORD_SPACING;
             use azul_core::ui_solver::ResolvedTextLayoutOptions;
-            use azul_text_layout::text_layout::position_words;
+            use azul_text_layout::text_layout::position_words_incremental;
             use azul_text_layout::text_shaping::ParsedFont;
 
             if layout_result.words_cache.get(&node_id).is_none() { continue; }
@@ -2836,7 +3159,12 @@ pub fn do_the_relayout(
                 Some(s) => s.0.clone(),
             };
 
-            let new_words = split_text_into_words(new_string.as_str());
+            // only the edited paragraphs are split, shaped and positioned again
+            let (new_words, words_change) = split_text_into_words_incremental(
+                &layout_result.words_cache[node_id],
+                new_string.as_str(),
+                &get_line_break_options(&layout_result.styled_dom, *node_id),
+            );
 
             let css_property_cache = layout_result.styled_dom.get_css_property_cache();
             let styled_nodes = layout_result.styled_dom.styled_nodes.as_container();
@@ -2860,7 +3188,13 @@ pub fn do_the_relayout(
             };
             let font_data = font_ref.get_data();
             let parsed_font_downcasted = unsafe { &*(font_data.parsed as *const ParsedFont) };
-            let new_shaped_words = shape_words(&new_words, parsed_font_downcasted);
+            let new_shaped_words = shape_words_incremental(
+                &layout_result.words_cache[node_id],
+                &layout_result.shaped_words_cache[node_id],
+                &new_words,
+                &words_change,
+                parsed_font_downcasted,
+            );
 
             let font_size = css_property_cache.get_font_size_or_default(node_data, node_id, &styled_node_state);
             let font_size_px = font_size.inner.to_pixels(DEFAULT_FONT_SIZE_PX as f32);
@@ -2881,7 +3215,13 @@ pub fn do_the_relayout(
             .get_tab_width(node_data, node_id, &styled_node_state)
             .and_then(|tw| Some(tw.get_property()?.inner.get()));
 
-            let new_word_positions = position_words(&new_words, &new_shaped_words, &text_layout_options);
+            let new_word_positions = position_words_incremental(
+                &new_words,
+                &new_shaped_words,
+                &layout_result.positioned_words_cache[node_id].0,
+                &words_change,
+                &text_layout_options,
+            );
             let new_inline_text_layout = word_positions_to_inline_text_layout(&new_word_positions);
 
             let old_word_dimensions = layout_result.rects.as_ref()
@@ -2901,13 +3241,68 @@ pub fn do_the_relayout(
         }
     }
 
+    // Re-measure the custom layout nodes that are affected by the relayout (all of them if
+    // the root size changed, otherwise the ones inside a subtree with changed properties).
+    // A changed measured size is handled like a changed text content.
+    let mut custom_layout_nodes_resized = BTreeSet::new();
+
+    #[cfg(feature = "text_layout")] {
+        let display_none_nodes = get_display_none_nodes(
+            &layout_result.styled_dom.node_hierarchy.as_container(),
+            &layout_result.layout_displays.as_ref(),
+        );
+
+        let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+        let skip_nodes = layout_result.styled_dom.node_data.as_container().internal.iter()
+        .enumerate()
+        .map(|(node_id, node_data)| {
+            if display_none_nodes[node_id] { return true; }
+            if !matches!(node_data.get_node_type(), NodeType::CustomLayout(_)) { return true; }
+            if root_size_changed { return false; }
+            let nodes_to_relayout = match nodes_to_relayout.as_ref() {
+                Some(s) => s,
+                None => return true,
+            };
+            let mut current = Some(NodeId::new(node_id));
+            while let Some(id) = current {
+                if nodes_to_relayout.contains_key(&id) { return false; }
+                current = node_hierarchy[id].parent_id();
+            }
+            true
+        }).collect::<Vec<_>>();
+
+        let width_calculated_rects = layout_result.width_calculated_rects.as_ref();
+        let custom_layout_sizes = measure_custom_layout_nodes(
+            &mut layout_result.styled_dom.node_data.as_container_mut(),
+            &skip_nodes,
+            |node_id| MeasureCallbackInfo {
+                available_size: LogicalSize::new(
+                    width_calculated_rects[node_id].total(),
+                    root_size.height as f32,
+                ),
+                width_is_final: true,
+            },
+        );
+
+        for (node_id, size) in custom_layout_sizes.iter() {
+            let preferred_width = &mut layout_result.preferred_widths.as_ref_mut()[*node_id];
+            let preferred_height = &mut layout_result.preferred_heights.as_ref_mut()[*node_id];
+            if *preferred_width != Some(size.width) || *preferred_height != Some(size.height) {
+                *preferred_width = Some(size.width);
+                *preferred_height = Some(size.height);
+                custom_layout_nodes_resized.insert(*node_id);
+            }
+        }
+    }
+
     // parents need to be adjust before children
     for ParentWithNodeDepth { depth: _, node_id } in layout_result.styled_dom.non_leaf_nodes.iter() {
 
         macro_rules! detect_changes {($node_id:expr, $parent_id:expr) => (
 
             let node_data = &layout_result.styled_dom.node_data.as_container()[$node_id];
-            let text_content_has_changed = node_ids_that_changed_text_content.contains(&$node_id);
+            let text_content_has_changed = node_ids_that_changed_text_content.contains(&$node_id) ||
+                                           custom_layout_nodes_resized.contains(&$node_id);
             let default_changes = BTreeMap::new();
             let changes_for_this_node = match nodes_to_relayout.as_ref().and_then(|n| n.get(&$node_id)) {
                 Some(s) => Some(s),
@@ -3331,6 +3726,19 @@ pub fn do_the_relayout(
         &parents_that_need_to_recalc_height_of_children
     );
 
+    // overlays have to be re-positioned from scratch, since the anchor may have moved
+    let overlay_nodes = get_overlay_nodes(&layout_result.styled_dom);
+    let mut overlay_subtree_parents = BTreeSet::new();
+    for overlay_node_id in overlay_nodes.iter() {
+        let overlay_parent = layout_result.styled_dom.node_hierarchy
+            .as_container()[*overlay_node_id].parent_id()
+            .unwrap_or(NodeId::ZERO);
+        overlay_subtree_parents.insert(overlay_parent);
+        overlay_subtree_parents.extend(layout_result.styled_dom.get_subtree_parents(*overlay_node_id).into_iter());
+    }
+    parents_that_need_to_reposition_children_x.extend(overlay_subtree_parents.iter().cloned());
+    parents_that_need_to_reposition_children_y.extend(overlay_subtree_parents.iter().cloned());
+
     // -- step 2: recalc position for those parents that need it
 
     get_x_positions(
@@ -3357,6 +3765,20 @@ pub fn do_the_relayout(
         &parents_that_need_to_reposition_children_y, // <- important
     );
 
+    if !overlay_nodes.is_empty() {
+        position_overlays(
+            &mut layout_result.solved_pos_x,
+            &mut layout_result.solved_pos_y,
+            &layout_result.width_calculated_rects.as_ref(),
+            &layout_result.height_calculated_rects.as_ref(),
+            &layout_result.styled_dom,
+            LogicalRect::new(
+                LogicalPosition::new(root_bounds.origin.x as f32, root_bounds.origin.y as f32),
+                LogicalSize::new(root_bounds.size.width as f32, root_bounds.size.height as f32),
+            ),
+        );
+    }
+
     // update positioned_word_cache
     let mut updated_word_caches = parents_that_need_to_recalc_width_of_children.clone();
     for parent_id in parents_that_need_to_recalc_width_of_children.iter() {
@@ -3367,6 +3789,7 @@ pub fn do_the_relayout(
     }
 
     updated_word_caches.extend(node_ids_that_changed_text_content.clone().into_iter());
+    node_ids_that_changed_text_content.extend(custom_layout_nodes_resized.into_iter());
 
     #[cfg(feature = "text_layout")]
     create_word_positions(
@@ -3400,6 +3823,7 @@ pub fn do_the_relayout(
         nodes_that_changed_size.insert(parent);
     }
     nodes_that_changed_size.extend(node_ids_that_changed_text_content.into_iter());
+    nodes_that_changed_size.extend(overlay_subtree_parents.into_iter());
 
     let css_property_cache = layout_result.styled_dom.get_css_property_cache();
     let node_data_container = layout_result.styled_dom.node_data.as_container();
@@ -3439,6 +3863,12 @@ pub fn do_the_relayout(
         document_id,
     );
 
+    arrange_custom_layout_nodes(
+        &mut layout_result.styled_dom.node_data.as_container_mut(),
+        &layout_result.rects.as_ref(),
+        &nodes_that_changed_size,
+    );
+
     layout_result.root_size = root_bounds.size;
     layout_result.root_position = root_bounds.origin;
 
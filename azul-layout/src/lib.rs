@@ -24,6 +24,9 @@ pub use layout_solver::{
     do_the_relayout,
 };
 
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub use layout_solver::bench;
 #[cfg(feature = "text_layout")]
 pub use layout_solver::callback_info_shape_text;
 #[cfg(feature = "text_layout")]
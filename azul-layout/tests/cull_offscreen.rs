@@ -0,0 +1,85 @@
+//! Culling of offscreen content groups on a solved layout
+
+extern crate azul_core;
+extern crate azul_css;
+extern crate azul_css_parser;
+extern crate azul_layout;
+extern crate rust_fontconfig;
+
+use azul_core::{
+    app_resources::{
+        DpiScaleFactor, Epoch, IdNamespace, ImageCache, LoadedFontSource, RendererResources,
+    },
+    callbacks::DocumentId,
+    display_list::{cull_offscreen_content_groups, RenderCallbacks},
+    dom::{Dom, NodeDataInlineCssProperty},
+    id_tree::NodeId,
+    window::{FullWindowState, LogicalPosition, LogicalRect, LogicalSize, ScrollStates},
+};
+use azul_css::{CssProperty, FloatValue, LayoutHeight, PixelValue, StyleFontFamily};
+use azul_css_parser::CssApiWrapper;
+use rust_fontconfig::FcFontCache;
+
+// the DOM doesn't contain any text
+fn no_font(_: &StyleFontFamily, _: &FcFontCache) -> Option<LoadedFontSource> {
+    None
+}
+
+#[test]
+fn test_cull_offscreen_content_groups() {
+    // 100 rows with a height of 100px each
+    let rows = (0..100)
+        .map(|_| {
+            Dom::div().with_inline_css_props(
+                vec![NodeDataInlineCssProperty::Normal(CssProperty::height(LayoutHeight {
+                    inner: PixelValue::px(100.0),
+                }))]
+                .into(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let styled_dom = Dom::body()
+        .with_children(rows.into())
+        .style(CssApiWrapper::empty());
+
+    let mut full_window_state = FullWindowState::default();
+    full_window_state.size.dimensions = LogicalSize::new(800.0, 600.0);
+
+    let callbacks = RenderCallbacks {
+        insert_into_active_gl_textures_fn: azul_core::gl::insert_into_active_gl_textures,
+        layout_fn: azul_layout::do_the_layout,
+        load_font_fn: no_font,
+        parse_font_fn: azul_layout::parse_font_fn,
+    };
+
+    let mut layout_results = azul_layout::do_the_layout(
+        styled_dom,
+        &ImageCache::default(),
+        &FcFontCache::default(),
+        &mut RendererResources::default(),
+        DpiScaleFactor { inner: FloatValue::new(1.0) },
+        &mut Vec::new(),
+        IdNamespace(0),
+        &DocumentId { namespace_id: IdNamespace(0), id: 0 },
+        Epoch::new(),
+        &callbacks,
+        &full_window_state,
+    );
+    let layout_result = layout_results.remove(0);
+
+    let mut content_group = layout_result.styled_dom.get_rects_in_rendering_order();
+    assert_eq!(content_group.children.len(), 100);
+
+    // rows 1 - 10 overlap the cull rect, row 11 starts at its bottom edge
+    let cull_rect = LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(800.0, 1000.0));
+    cull_offscreen_content_groups(&mut content_group, &layout_result, &ScrollStates::new(), &cull_rect);
+
+    let surviving_rows = content_group
+        .children
+        .iter()
+        .filter_map(|c| c.root.into_crate_internal())
+        .collect::<Vec<_>>();
+
+    assert_eq!(surviving_rows, (1..11).map(NodeId::new).collect::<Vec<_>>());
+}
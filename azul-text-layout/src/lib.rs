@@ -81,6 +81,9 @@ extern crate azul_css;
 extern crate azul_core;
 extern crate unicode_normalization;
 extern crate allsorts;
+extern crate unicode_linebreak;
+#[cfg(feature = "hyphenation")]
+extern crate hyphenation;
 #[macro_use]
 extern crate tinyvec;
 
@@ -89,14 +92,16 @@ use core::ffi::c_void;
 use crate::text_shaping::ParsedFont;
 use azul_css::{FontData, FontRef};
 
+pub mod line_breaking;
 pub mod script;
+pub mod text_buffer;
 pub mod text_layout;
 pub mod text_shaping;
 
 use azul_core::{
     traits::GetTextLayout,
     ui_solver::{ResolvedTextLayoutOptions, InlineTextLayout},
-    app_resources::{Words, ShapedWords, LoadedFontSource},
+    app_resources::{Words, ShapedWords, WordPositions, WordsChange, LoadedFontSource},
     callbacks::DocumentId,
     id_tree::NodeId,
 };
@@ -119,6 +124,24 @@ impl<'a> GetTextLayout for InlineText<'a> {
         // TODO: Cache the layouted text block on the &mut self
         text_layout::word_positions_to_inline_text_layout(&layouted_text_block)
     }
+
+    fn get_text_layout_incremental(
+        &mut self,
+        _: &DocumentId,
+        _: NodeId,
+        text_layout_options: &ResolvedTextLayoutOptions,
+        previous: &WordPositions,
+        change: &WordsChange,
+    ) -> InlineTextLayout {
+        let layouted_text_block = text_layout::position_words_incremental(
+            self.words,
+            self.shaped_words,
+            previous,
+            change,
+            text_layout_options,
+        );
+        text_layout::word_positions_to_inline_text_layout(&layouted_text_block)
+    }
 }
 
 fn parsed_font_destructor(ptr: *mut c_void) {
@@ -0,0 +1,391 @@
+//! Line break opportunities inside of words: words are split at the break
+//! opportunities of the Unicode line breaking algorithm (UAX #14, with tailorings
+//! for the language of the text) and at hyphenation points, so that long words
+//! and text without spaces (Chinese, Japanese) can be wrapped.
+//!
+//! Hyphenation dictionaries (`hyphens: auto`) are only available with the
+//! `hyphenation` feature, which embeds the TeX hyphenation patterns.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use azul_core::app_resources::{Word, WordType};
+use azul_css::{AzString, StyleHyphens};
+
+/// `U+00AD`, marks a hyphenation point in the text (`hyphens: manual`)
+const SOFT_HYPHEN: char = '\u{00AD}';
+
+/// Words shorter than this are never hyphenated automatically
+const MIN_HYPHENATION_WORD_LEN: usize = 5;
+
+/// Where lines may be broken inside of words, resolved from the
+/// `lang` attribute and the `hyphens` CSS property of a text node
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct LineBreakOptions {
+    /// Language of the text (BCP 47 tag, i.e. `"de"` or `"ja-JP"`)
+    pub lang: Option<AzString>,
+    /// Whether words may be hyphenated
+    pub hyphens: StyleHyphens,
+}
+
+/// Language + region of a BCP 47 tag, lowercase (`"de-CH"` -> `("de", Some("ch"))`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Locale {
+    language: String,
+    region: Option<String>,
+}
+
+impl Locale {
+    fn new(tag: &str) -> Self {
+        let mut subtags = tag.split(|c| c == '-' || c == '_');
+        let language = subtags.next().unwrap_or_default().to_ascii_lowercase();
+        // skip the script subtag ("zh-Hant-TW")
+        let region = subtags
+            .find(|s| s.len() == 2 || (s.len() == 3 && s.chars().all(|c| c.is_ascii_digit())))
+            .map(|s| s.to_ascii_lowercase());
+        Self { language, region }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum BreakKind {
+    /// Regular break opportunity, the parts of the word are placed next to each other
+    Plain,
+    /// Break after a soft hyphen character (U+00AD)
+    SoftHyphenChar,
+    /// Hyphenation point from the dictionary
+    Hyphenation,
+}
+
+/// Splits the words (created by splitting the text at whitespace) at the line break
+/// opportunities inside of them. The parts of a word are consecutive `WordType::Word`
+/// items, hyphenation points are inserted as `WordType::SoftHyphen` items.
+pub(crate) fn break_words(chars: &[char], words: Vec<Word>, options: &LineBreakOptions) -> Vec<Word> {
+
+    let locale = options.lang.as_ref().map(|l| Locale::new(l.as_str()));
+    let mut result = Vec::with_capacity(words.len());
+
+    for word in words {
+        if word.word_type != WordType::Word || word.end < word.start + 2 {
+            result.push(word);
+            continue;
+        }
+        push_word_parts(chars, word, locale.as_ref(), options.hyphens, &mut result);
+    }
+
+    result
+}
+
+fn push_word_parts(
+    chars: &[char],
+    word: Word,
+    locale: Option<&Locale>,
+    hyphens: StyleHyphens,
+    result: &mut Vec<Word>,
+) {
+    let text = &chars[word.start..word.end];
+
+    let mut breaks = line_break_opportunities(text, locale)
+        .into_iter()
+        .filter_map(|offset| {
+            if text[offset - 1] != SOFT_HYPHEN {
+                Some((offset, BreakKind::Plain))
+            } else if hyphens == StyleHyphens::None || offset < 2 {
+                None
+            } else {
+                Some((offset, BreakKind::SoftHyphenChar))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // soft hyphens in the text take precedence over the dictionary
+    if hyphens == StyleHyphens::Auto && !text.contains(&SOFT_HYPHEN) {
+        if let Some(locale) = locale {
+            for offset in hyphenation_points(text, locale) {
+                if !breaks.iter().any(|(o, _)| *o == offset) {
+                    breaks.push((offset, BreakKind::Hyphenation));
+                }
+            }
+            breaks.sort();
+        }
+    }
+
+    let mut part_start = 0;
+    let mut push = |start: usize, end: usize, word_type: WordType| {
+        // i.e. two consecutive soft hyphens
+        if start == end && word_type == WordType::Word {
+            return;
+        }
+        result.push(Word { start: word.start + start, end: word.start + end, word_type });
+    };
+
+    for (offset, kind) in breaks {
+        match kind {
+            BreakKind::Plain => {
+                push(part_start, offset, WordType::Word);
+            },
+            BreakKind::SoftHyphenChar => {
+                push(part_start, offset - 1, WordType::Word);
+                push(offset - 1, offset, WordType::SoftHyphen);
+            },
+            BreakKind::Hyphenation => {
+                push(part_start, offset, WordType::Word);
+                push(offset, offset, WordType::SoftHyphen);
+            },
+        }
+        part_start = offset;
+    }
+
+    push(part_start, text.len(), WordType::Word);
+}
+
+/// Returns the char offsets inside of the word (never `0` or the length of the word)
+/// after which the line may be broken, according to UAX #14 and the tailorings of the language
+fn line_break_opportunities(text: &[char], locale: Option<&Locale>) -> Vec<usize> {
+
+    use unicode_linebreak::{break_property, linebreaks, BreakClass};
+
+    let string = text.iter().collect::<String>();
+
+    // UAX #14 returns byte offsets
+    let byte_offsets = string.char_indices().map(|(i, _)| i).collect::<Vec<_>>();
+    let mut opportunities = linebreaks(&string)
+        .filter_map(|(byte_offset, _)| byte_offsets.binary_search(&byte_offset).ok())
+        .filter(|o| *o > 0)
+        .collect::<Vec<_>>();
+
+    let language = match locale {
+        Some(l) => l.language.as_str(),
+        None => return opportunities,
+    };
+
+    let is_hangul = |c: char| matches!(
+        break_property(c as u32),
+        BreakClass::HangulLvSyllable | BreakClass::HangulLvtSyllable |
+        BreakClass::HangulLJamo | BreakClass::HangulVJamo | BreakClass::HangulTJamo
+    );
+
+    match language {
+        // "normal" line breaking: small kana and the prolonged sound mark
+        // (class CJ) may start a line, UAX #14 only allows this for "loose"
+        "ja" | "zh" => {
+            let is_ideographic = |c: char| matches!(
+                break_property(c as u32),
+                BreakClass::Ideographic | BreakClass::ConditionalJapaneseStarter
+            );
+            for i in 1..text.len() {
+                if break_property(text[i] as u32) == BreakClass::ConditionalJapaneseStarter &&
+                   is_ideographic(text[i - 1]) &&
+                   !opportunities.contains(&i) {
+                    opportunities.push(i);
+                }
+            }
+            opportunities.sort();
+        },
+        // Korean separates words with spaces: don't break inside of words ("keep-all")
+        "ko" => {
+            opportunities.retain(|i| !(is_hangul(text[*i - 1]) && is_hangul(text[*i])));
+        },
+        _ => { },
+    }
+
+    opportunities
+}
+
+/// Returns the char offsets of the hyphenation points in the word
+fn hyphenation_points(text: &[char], locale: &Locale) -> Vec<usize> {
+
+    let mut points = Vec::new();
+    let mut run_start = 0;
+
+    // only hyphenate runs of letters ("well-known" -> "well", "known")
+    while run_start < text.len() {
+        if !text[run_start].is_alphabetic() {
+            run_start += 1;
+            continue;
+        }
+        let run_end = text[run_start..]
+            .iter()
+            .position(|c| !c.is_alphabetic())
+            .map(|p| run_start + p)
+            .unwrap_or(text.len());
+
+        if run_end - run_start >= MIN_HYPHENATION_WORD_LEN {
+            let run = text[run_start..run_end].iter().collect::<String>();
+            points.extend(
+                dictionaries::hyphenate(&run, locale)
+                .into_iter()
+                .map(|byte_offset| run_start + run[..byte_offset].chars().count())
+                .filter(|o| *o > run_start && *o < run_end)
+            );
+        }
+
+        run_start = run_end;
+    }
+
+    points
+}
+
+#[cfg(feature = "hyphenation")]
+mod dictionaries {
+
+    use super::Locale;
+    use alloc::collections::BTreeMap;
+    use alloc::sync::Arc;
+    use alloc::vec::Vec;
+    use std::sync::Mutex;
+    use hyphenation::{Hyphenator, Language, Load, Standard};
+
+    /// Loaded dictionaries (`None` if the dictionary could not be loaded)
+    static DICTIONARIES: Mutex<BTreeMap<&'static str, Option<Arc<Standard>>>> = Mutex::new(BTreeMap::new());
+
+    /// Returns the byte offsets of the hyphenation points in the word
+    pub(super) fn hyphenate(word: &str, locale: &Locale) -> Vec<usize> {
+
+        let (key, language) = match get_language(locale) {
+            Some(s) => s,
+            None => return Vec::new(),
+        };
+
+        // parsing a dictionary is expensive, dictionaries are loaded on first use
+        let dictionary = match DICTIONARIES.lock() {
+            Ok(mut d) => d
+                .entry(key)
+                .or_insert_with(|| Standard::from_embedded(language).ok().map(Arc::new))
+                .clone(),
+            Err(_) => None,
+        };
+
+        match dictionary {
+            Some(d) => d.hyphenate(word).breaks,
+            None => Vec::new(),
+        }
+    }
+
+    fn get_language(locale: &Locale) -> Option<(&'static str, Language)> {
+        use self::Language::*;
+        let region = locale.region.as_deref();
+        let language = match (locale.language.as_str(), region) {
+            ("af", _) => ("af", Afrikaans),
+            ("bg", _) => ("bg", Bulgarian),
+            ("ca", _) => ("ca", Catalan),
+            ("cs", _) => ("cs", Czech),
+            ("cy", _) => ("cy", Welsh),
+            ("da", _) => ("da", Danish),
+            ("de", Some("ch")) => ("de-ch", GermanSwiss),
+            ("de", _) => ("de", German1996),
+            ("el", _) => ("el", GreekMono),
+            ("en", Some("gb")) => ("en-gb", EnglishGB),
+            ("en", _) => ("en-us", EnglishUS),
+            ("eo", _) => ("eo", Esperanto),
+            ("es", _) => ("es", Spanish),
+            ("et", _) => ("et", Estonian),
+            ("eu", _) => ("eu", Basque),
+            ("fi", _) => ("fi", Finnish),
+            ("fr", _) => ("fr", French),
+            ("ga", _) => ("ga", Irish),
+            ("gl", _) => ("gl", Galician),
+            ("hr", _) => ("hr", Croatian),
+            ("hu", _) => ("hu", Hungarian),
+            ("id", _) => ("id", Indonesian),
+            ("is", _) => ("is", Icelandic),
+            ("it", _) => ("it", Italian),
+            ("la", _) => ("la", Latin),
+            ("lt", _) => ("lt", Lithuanian),
+            ("lv", _) => ("lv", Latvian),
+            ("nb", _) | ("no", _) => ("nb", NorwegianBokmal),
+            ("nl", _) => ("nl", Dutch),
+            ("nn", _) => ("nn", NorwegianNynorsk),
+            ("pl", _) => ("pl", Polish),
+            ("pt", _) => ("pt", Portuguese),
+            ("ro", _) => ("ro", Romanian),
+            ("ru", _) => ("ru", Russian),
+            ("sk", _) => ("sk", Slovak),
+            ("sl", _) => ("sl", Slovenian),
+            ("sv", _) => ("sv", Swedish),
+            ("tr", _) => ("tr", Turkish),
+            ("uk", _) => ("uk", Ukrainian),
+            _ => return None,
+        };
+        Some(language)
+    }
+}
+
+#[cfg(not(feature = "hyphenation"))]
+mod dictionaries {
+
+    use super::Locale;
+    use alloc::vec::Vec;
+
+    /// No dictionaries are embedded without the `hyphenation` feature
+    pub(super) fn hyphenate(_word: &str, _locale: &Locale) -> Vec<usize> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(text: &str, lang: Option<&str>, hyphens: StyleHyphens) -> Vec<(String, WordType)> {
+        let chars = text.chars().collect::<Vec<_>>();
+        let words = vec![Word { start: 0, end: chars.len(), word_type: WordType::Word }];
+        let options = LineBreakOptions { lang: lang.map(|l| l.to_string().into()), hyphens };
+        break_words(&chars, words, &options)
+            .into_iter()
+            .map(|w| (chars[w.start..w.end].iter().collect(), w.word_type))
+            .collect()
+    }
+
+    #[test]
+    fn test_locale() {
+        assert_eq!(Locale::new("de-CH"), Locale { language: "de".into(), region: Some("ch".into()) });
+        assert_eq!(Locale::new("zh-Hant-TW"), Locale { language: "zh".into(), region: Some("tw".into()) });
+        assert_eq!(Locale::new("ja"), Locale { language: "ja".into(), region: None });
+    }
+
+    #[test]
+    fn test_break_after_hyphen() {
+        assert_eq!(split("well-known", None, StyleHyphens::Manual), vec![
+            ("well-".into(), WordType::Word),
+            ("known".into(), WordType::Word),
+        ]);
+    }
+
+    #[test]
+    fn test_break_ideographs() {
+        assert_eq!(split("漢字", None, StyleHyphens::Manual), vec![
+            ("漢".into(), WordType::Word),
+            ("字".into(), WordType::Word),
+        ]);
+    }
+
+    #[test]
+    fn test_soft_hyphen() {
+        assert_eq!(split("hy\u{00AD}phen", None, StyleHyphens::Manual), vec![
+            ("hy".into(), WordType::Word),
+            ("\u{00AD}".into(), WordType::SoftHyphen),
+            ("phen".into(), WordType::Word),
+        ]);
+        assert_eq!(split("hy\u{00AD}phen", None, StyleHyphens::None), vec![
+            ("hy\u{00AD}phen".into(), WordType::Word),
+        ]);
+    }
+
+    #[test]
+    fn test_korean_keep_all() {
+        assert_eq!(split("한국어", Some("ko"), StyleHyphens::Manual), vec![
+            ("한국어".into(), WordType::Word),
+        ]);
+        assert_eq!(split("한국어", None, StyleHyphens::Manual).len(), 3);
+    }
+
+    #[cfg(feature = "hyphenation")]
+    #[test]
+    fn test_hyphenation() {
+        let parts = split("hyphenation", Some("en-US"), StyleHyphens::Auto);
+        assert!(parts.iter().any(|(_, t)| *t == WordType::SoftHyphen));
+        let text = parts.iter().map(|(s, _)| s.as_str()).collect::<String>();
+        assert_eq!(text, "hyphenation");
+        assert_eq!(split("hyphenation", Some("en-US"), StyleHyphens::Manual).len(), 1);
+    }
+}
@@ -0,0 +1,969 @@
+//! Rope-based text buffer for editing large texts
+//!
+//! `TextBuffer` stores the text in a balanced tree of small chunks (a "rope"), so that
+//! inserting or removing text is `O(log n)` instead of copying the entire text on every
+//! keystroke. Cloning a buffer is cheap (the chunks are reference-counted), so a snapshot
+//! of the text can be sent to a layout thread while the user keeps typing.
+//!
+//! All indices are `char` indices. Lines are separated by `'\n'`, so a text with `n`
+//! line breaks has `n + 1` lines.
+//!
+//! Every edit increments the revision of the buffer and is reported as a `TextChange`,
+//! either to the listeners (`add_listener`) or by polling `changes_since(revision)`.
+//! The change contains the edited line range, so that the text layout only has to
+//! re-shape the lines that changed. Edits can be undone / redone, consecutive typed
+//! characters are merged into a single undo step.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use azul_text_layout::text_buffer::TextBuffer;
+//! let mut buffer = TextBuffer::from("Hello\nWorld");
+//! buffer.insert(5, ",");
+//! buffer.replace(7..12, "Rope");
+//! assert_eq!(buffer.to_string(), "Hello,\nRope");
+//! assert_eq!(buffer.line(1).to_string(), "Rope");
+//! buffer.undo();
+//! assert_eq!(buffer.to_string(), "Hello,\nWorld");
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+/// Maximum size of a chunk in bytes
+const MAX_LEAF_BYTES: usize = 1024;
+/// Number of changes that are kept for `changes_since`
+pub const MAX_CHANGE_LOG: usize = 256;
+/// Default number of undo steps
+const DEFAULT_UNDO_LIMIT: usize = 1000;
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+#[derive(Debug)]
+enum RopeNode {
+    Leaf {
+        text: String,
+        chars: usize,
+        newlines: usize,
+    },
+    Branch {
+        left: Arc<RopeNode>,
+        right: Arc<RopeNode>,
+        chars: usize,
+        newlines: usize,
+        depth: usize,
+        leaves: usize,
+    },
+}
+
+impl RopeNode {
+
+    fn chars(&self) -> usize {
+        match self {
+            RopeNode::Leaf { chars, .. } | RopeNode::Branch { chars, .. } => *chars,
+        }
+    }
+
+    fn newlines(&self) -> usize {
+        match self {
+            RopeNode::Leaf { newlines, .. } | RopeNode::Branch { newlines, .. } => *newlines,
+        }
+    }
+
+    fn depth(&self) -> usize {
+        match self {
+            RopeNode::Leaf { .. } => 0,
+            RopeNode::Branch { depth, .. } => *depth,
+        }
+    }
+
+    fn leaves(&self) -> usize {
+        match self {
+            RopeNode::Leaf { .. } => 1,
+            RopeNode::Branch { leaves, .. } => *leaves,
+        }
+    }
+
+    fn char_at(&self, char_idx: usize) -> Option<char> {
+        match self {
+            RopeNode::Leaf { text, .. } => text.chars().nth(char_idx),
+            RopeNode::Branch { left, right, .. } => {
+                if char_idx < left.chars() {
+                    left.char_at(char_idx)
+                } else {
+                    right.char_at(char_idx - left.chars())
+                }
+            },
+        }
+    }
+
+    /// Number of line breaks before the char index
+    fn newlines_before(&self, char_idx: usize) -> usize {
+        match self {
+            RopeNode::Leaf { text, .. } => text.chars().take(char_idx).filter(|c| *c == '\n').count(),
+            RopeNode::Branch { left, right, .. } => {
+                if char_idx <= left.chars() {
+                    left.newlines_before(char_idx)
+                } else {
+                    left.newlines() + right.newlines_before(char_idx - left.chars())
+                }
+            },
+        }
+    }
+
+    /// Char index after the n-th line break (`n >= 1`)
+    fn char_after_newline(&self, n: usize) -> usize {
+        match self {
+            RopeNode::Leaf { text, chars, .. } => {
+                let mut found = 0;
+                for (i, c) in text.chars().enumerate() {
+                    if c == '\n' {
+                        found += 1;
+                        if found == n {
+                            return i + 1;
+                        }
+                    }
+                }
+                *chars
+            },
+            RopeNode::Branch { left, right, .. } => {
+                if n <= left.newlines() {
+                    left.char_after_newline(n)
+                } else {
+                    left.chars() + right.char_after_newline(n - left.newlines())
+                }
+            },
+        }
+    }
+
+    fn collect_leaves<'a>(&'a self, leaves: &mut Vec<&'a str>) {
+        match self {
+            RopeNode::Leaf { text, .. } => leaves.push(text.as_str()),
+            RopeNode::Branch { left, right, .. } => {
+                left.collect_leaves(leaves);
+                right.collect_leaves(leaves);
+            },
+        }
+    }
+}
+
+fn new_leaf(text: String) -> Arc<RopeNode> {
+    let chars = text.chars().count();
+    let newlines = text.bytes().filter(|b| *b == b'\n').count();
+    Arc::new(RopeNode::Leaf { text, chars, newlines })
+}
+
+fn new_branch(left: Arc<RopeNode>, right: Arc<RopeNode>) -> Arc<RopeNode> {
+    Arc::new(RopeNode::Branch {
+        chars: left.chars() + right.chars(),
+        newlines: left.newlines() + right.newlines(),
+        depth: left.depth().max(right.depth()) + 1,
+        leaves: left.leaves() + right.leaves(),
+        left,
+        right,
+    })
+}
+
+fn merge_leaves(a: &str, b: &str) -> Option<Arc<RopeNode>> {
+    if a.len() + b.len() > MAX_LEAF_BYTES {
+        return None;
+    }
+    let mut s = String::with_capacity(a.len() + b.len());
+    s.push_str(a);
+    s.push_str(b);
+    Some(new_leaf(s))
+}
+
+/// Concatenates two ropes. Small chunks at the seam are merged,
+/// so that typing doesn't create a long chain of one-character chunks.
+fn concat(left: Arc<RopeNode>, right: Arc<RopeNode>) -> Arc<RopeNode> {
+
+    if left.chars() == 0 {
+        return right;
+    }
+    if right.chars() == 0 {
+        return left;
+    }
+
+    match (&*left, &*right) {
+        (RopeNode::Leaf { text: a, .. }, RopeNode::Leaf { text: b, .. }) => {
+            if let Some(merged) = merge_leaves(a, b) {
+                return merged;
+            }
+        },
+        (RopeNode::Branch { left: ll, right: lr, .. }, RopeNode::Leaf { text: b, .. }) => {
+            if let RopeNode::Leaf { text: a, .. } = &**lr {
+                if let Some(merged) = merge_leaves(a, b) {
+                    return new_branch(ll.clone(), merged);
+                }
+            }
+        },
+        (RopeNode::Leaf { text: a, .. }, RopeNode::Branch { left: rl, right: rr, .. }) => {
+            if let RopeNode::Leaf { text: b, .. } = &**rl {
+                if let Some(merged) = merge_leaves(a, b) {
+                    return new_branch(merged, rr.clone());
+                }
+            }
+        },
+        _ => { },
+    }
+
+    new_branch(left, right)
+}
+
+/// Splits the rope into `[0, char_idx)` and `[char_idx, len)`
+fn split(node: &Arc<RopeNode>, char_idx: usize) -> (Arc<RopeNode>, Arc<RopeNode>) {
+
+    if char_idx == 0 {
+        return (new_leaf(String::new()), node.clone());
+    }
+    if char_idx >= node.chars() {
+        return (node.clone(), new_leaf(String::new()));
+    }
+
+    match &**node {
+        RopeNode::Leaf { text, .. } => {
+            let byte_idx = char_to_byte(text, char_idx);
+            (new_leaf(text[..byte_idx].to_string()), new_leaf(text[byte_idx..].to_string()))
+        },
+        RopeNode::Branch { left, right, .. } => {
+            let left_chars = left.chars();
+            if char_idx == left_chars {
+                (left.clone(), right.clone())
+            } else if char_idx < left_chars {
+                let (a, b) = split(left, char_idx);
+                (a, concat(b, right.clone()))
+            } else {
+                let (a, b) = split(right, char_idx - left_chars);
+                (concat(left.clone(), a), b)
+            }
+        },
+    }
+}
+
+/// Builds a balanced rope from a list of chunks (which must not exceed `MAX_LEAF_BYTES`)
+fn build_balanced(mut nodes: Vec<Arc<RopeNode>>) -> Arc<RopeNode> {
+    if nodes.is_empty() {
+        return new_leaf(String::new());
+    }
+    while nodes.len() > 1 {
+        let mut parents = Vec::with_capacity((nodes.len() + 1) / 2);
+        let mut iter = nodes.into_iter();
+        while let Some(left) = iter.next() {
+            match iter.next() {
+                Some(right) => parents.push(new_branch(left, right)),
+                None => parents.push(left),
+            }
+        }
+        nodes = parents;
+    }
+    nodes.pop().unwrap()
+}
+
+/// Splits the text into chunks of at most `MAX_LEAF_BYTES` (at char boundaries)
+fn build_rope(text: &str) -> Arc<RopeNode> {
+    let mut leaves = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let mut end = rest.len().min(MAX_LEAF_BYTES);
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        leaves.push(new_leaf(rest[..end].to_string()));
+        rest = &rest[end..];
+    }
+    build_balanced(leaves)
+}
+
+/// Rebuilds the tree if it got too deep, merges small adjacent chunks
+fn rebalance(node: Arc<RopeNode>) -> Arc<RopeNode> {
+
+    let leaves = node.leaves();
+    let max_depth = 2 * (usize::BITS - leaves.leading_zeros()) as usize + 8;
+    if node.depth() <= max_depth {
+        return node;
+    }
+
+    let mut chunks = Vec::new();
+    node.collect_leaves(&mut chunks);
+
+    let mut merged = Vec::new();
+    let mut current = String::new();
+    for chunk in chunks {
+        if current.len() + chunk.len() > MAX_LEAF_BYTES && !current.is_empty() {
+            merged.push(new_leaf(core::mem::replace(&mut current, String::new())));
+        }
+        current.push_str(chunk);
+    }
+    if !current.is_empty() {
+        merged.push(new_leaf(current));
+    }
+
+    build_balanced(merged)
+}
+
+fn char_to_byte(text: &str, char_idx: usize) -> usize {
+    text.char_indices().nth(char_idx).map(|(b, _)| b).unwrap_or(text.len())
+}
+
+/// Iterator over the chunks of a `TextSlice`
+#[derive(Debug, Clone)]
+pub struct Chunks<'a> {
+    stack: Vec<&'a RopeNode>,
+    /// Chars to skip before the slice starts
+    skip: usize,
+    /// Chars left in the slice
+    remaining: usize,
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        while self.remaining > 0 {
+            match self.stack.pop()? {
+                RopeNode::Branch { left, right, .. } => {
+                    if self.skip >= left.chars() {
+                        self.skip -= left.chars();
+                        self.stack.push(&**right);
+                    } else {
+                        self.stack.push(&**right);
+                        self.stack.push(&**left);
+                    }
+                },
+                RopeNode::Leaf { text, chars, .. } => {
+                    if self.skip >= *chars {
+                        self.skip -= *chars;
+                        continue;
+                    }
+                    let start = char_to_byte(text, self.skip);
+                    let take = (*chars - self.skip).min(self.remaining);
+                    let end = start + char_to_byte(&text[start..], take);
+                    self.skip = 0;
+                    self.remaining -= take;
+                    return Some(&text[start..end]);
+                },
+            }
+        }
+        None
+    }
+}
+
+/// Borrowed range of a `TextBuffer`, i.e. a single line that is passed to the text layout
+#[derive(Debug, Clone, Copy)]
+pub struct TextSlice<'a> {
+    root: &'a RopeNode,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> TextSlice<'a> {
+
+    /// Char index of the slice in the buffer
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn len_chars(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Iterates over the text in chunks, without copying the text
+    pub fn chunks(&self) -> Chunks<'a> {
+        Chunks {
+            stack: vec![self.root],
+            skip: self.start,
+            remaining: self.end - self.start,
+        }
+    }
+
+    pub fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        self.chunks().flat_map(|c| c.chars())
+    }
+
+    /// Sub-slice, the range is relative to the start of this slice
+    pub fn slice(&self, range: Range<usize>) -> TextSlice<'a> {
+        let end = (self.start + range.end).min(self.end);
+        let start = (self.start + range.start).min(end);
+        TextSlice { root: self.root, start, end }
+    }
+}
+
+impl<'a> fmt::Display for TextSlice<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for chunk in self.chunks() {
+            f.write_str(chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> PartialEq<&str> for TextSlice<'a> {
+    fn eq(&self, other: &&str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+/// What caused a `TextChange`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TextChangeKind {
+    Edit,
+    Undo,
+    Redo,
+}
+
+/// Edit of a `TextBuffer`, reported to the listeners of the buffer
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TextChange {
+    /// Revision of the buffer after the change
+    pub revision: u64,
+    pub kind: TextChangeKind,
+    /// Char index where the text was replaced
+    pub start: usize,
+    pub removed_chars: usize,
+    pub inserted_chars: usize,
+    /// Line that contains `start`
+    pub start_line: usize,
+    /// Number of line breaks that were removed, lines `start_line..=start_line + removed_lines`
+    /// (of the text before the change) have to be laid out again
+    pub removed_lines: usize,
+    /// Number of line breaks that were inserted, lines `start_line..=start_line + inserted_lines`
+    /// (of the text after the change) have to be laid out again
+    pub inserted_lines: usize,
+}
+
+/// Id of a listener, see `TextBuffer::add_listener`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TextBufferListenerId(usize);
+
+/// Callback that is invoked after every change of the buffer
+pub type TextBufferListener = Box<dyn FnMut(&TextChange) + Send>;
+
+/// Replacement of `removed` by `inserted` at `start`, in the undo history
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TextEdit {
+    start: usize,
+    removed: String,
+    inserted: String,
+}
+
+/// Undo step, consists of one or more edits
+type UndoGroup = Vec<TextEdit>;
+
+/// Undoable, observable text buffer, see the module documentation
+pub struct TextBuffer {
+    root: Arc<RopeNode>,
+    revision: u64,
+    changes: VecDeque<TextChange>,
+    undo_stack: VecDeque<UndoGroup>,
+    redo_stack: Vec<UndoGroup>,
+    undo_limit: usize,
+    /// Nesting depth of `begin_undo_group`
+    undo_group_depth: usize,
+    /// Whether the next typed character may be merged into the last undo step
+    coalesce_typing: bool,
+    listeners: Vec<(TextBufferListenerId, TextBufferListener)>,
+    next_listener_id: usize,
+}
+
+impl fmt::Debug for TextBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TextBuffer")
+            .field("text", &self.to_string())
+            .field("revision", &self.revision)
+            .field("undo_steps", &self.undo_stack.len())
+            .field("redo_steps", &self.redo_stack.len())
+            .field("listeners", &self.listeners.len())
+            .finish()
+    }
+}
+
+/// Clones the text and the undo history (cheap, the text is shared),
+/// the listeners are not cloned
+impl Clone for TextBuffer {
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            revision: self.revision,
+            changes: self.changes.clone(),
+            undo_stack: self.undo_stack.clone(),
+            redo_stack: self.redo_stack.clone(),
+            undo_limit: self.undo_limit,
+            undo_group_depth: 0,
+            coalesce_typing: self.coalesce_typing,
+            listeners: Vec::new(),
+            next_listener_id: 0,
+        }
+    }
+}
+
+impl Default for TextBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&str> for TextBuffer {
+    fn from(text: &str) -> Self {
+        let mut buffer = Self::new();
+        buffer.root = build_rope(text);
+        buffer
+    }
+}
+
+impl From<String> for TextBuffer {
+    fn from(text: String) -> Self {
+        Self::from(text.as_str())
+    }
+}
+
+impl fmt::Display for TextBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.slice(0..self.len_chars()).fmt(f)
+    }
+}
+
+impl PartialEq for TextBuffer {
+    fn eq(&self, other: &Self) -> bool {
+        self.len_chars() == other.len_chars() && self.chars().eq(other.chars())
+    }
+}
+
+impl TextBuffer {
+
+    pub fn new() -> Self {
+        Self {
+            root: new_leaf(String::new()),
+            revision: 0,
+            changes: VecDeque::new(),
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            undo_limit: DEFAULT_UNDO_LIMIT,
+            undo_group_depth: 0,
+            coalesce_typing: false,
+            listeners: Vec::new(),
+            next_listener_id: 0,
+        }
+    }
+
+    pub fn len_chars(&self) -> usize {
+        self.root.chars()
+    }
+
+    /// Depth of the rope, stays logarithmic in the number of chunks (exposed for the tests only)
+    #[doc(hidden)]
+    pub fn depth(&self) -> usize {
+        self.root.depth()
+    }
+
+    /// Number of lines, always at least 1
+    pub fn len_lines(&self) -> usize {
+        self.root.newlines() + 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len_chars() == 0
+    }
+
+    /// Incremented on every change of the text
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    pub fn char_at(&self, char_idx: usize) -> Option<char> {
+        self.root.char_at(char_idx)
+    }
+
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.slice(0..self.len_chars()).chars()
+    }
+
+    /// Returns a range of the text, the range is clamped to the length of the text
+    pub fn slice(&self, range: Range<usize>) -> TextSlice {
+        let end = range.end.min(self.len_chars());
+        let start = range.start.min(end);
+        TextSlice { root: &*self.root, start, end }
+    }
+
+    /// Returns the line without the line break (empty if the line doesn't exist)
+    pub fn line(&self, line_idx: usize) -> TextSlice {
+        if line_idx >= self.len_lines() {
+            return self.slice(self.len_chars()..self.len_chars());
+        }
+        let start = self.line_to_char(line_idx);
+        let end = if line_idx + 1 < self.len_lines() {
+            self.line_to_char(line_idx + 1) - 1
+        } else {
+            self.len_chars()
+        };
+        self.slice(start..end)
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = TextSlice> + '_ {
+        (0..self.len_lines()).map(move |i| self.line(i))
+    }
+
+    /// Returns the line that contains the char
+    pub fn char_to_line(&self, char_idx: usize) -> usize {
+        self.root.newlines_before(char_idx.min(self.len_chars()))
+    }
+
+    /// Returns the char index of the start of the line
+    pub fn line_to_char(&self, line_idx: usize) -> usize {
+        if line_idx == 0 {
+            0
+        } else if line_idx > self.root.newlines() {
+            self.len_chars()
+        } else {
+            self.root.char_after_newline(line_idx)
+        }
+    }
+
+    pub fn insert(&mut self, char_idx: usize, text: &str) {
+        self.replace(char_idx..char_idx, text);
+    }
+
+    pub fn remove(&mut self, range: Range<usize>) {
+        self.replace(range, "");
+    }
+
+    /// Replaces the range (clamped to the length of the text) with the text
+    pub fn replace(&mut self, range: Range<usize>, text: &str) {
+
+        let end = range.end.min(self.len_chars());
+        let start = range.start.min(end);
+
+        if start == end && text.is_empty() {
+            return;
+        }
+
+        let removed = self.slice(start..end).to_string();
+        self.apply_edit(start, &removed, text, TextChangeKind::Edit);
+        self.record_undo(TextEdit { start, removed, inserted: text.to_string() });
+    }
+
+    /// Replaces the entire text, can be undone
+    pub fn set_text(&mut self, text: &str) {
+        self.replace(0..self.len_chars(), text);
+    }
+
+    fn apply_edit(&mut self, start: usize, removed: &str, inserted: &str, kind: TextChangeKind) {
+
+        let removed_chars = removed.chars().count();
+        let start_line = self.char_to_line(start);
+
+        let (left, rest) = split(&self.root, start);
+        let (_, right) = split(&rest, removed_chars);
+        self.root = rebalance(concat(concat(left, build_rope(inserted)), right));
+        self.revision += 1;
+
+        let change = TextChange {
+            revision: self.revision,
+            kind,
+            start,
+            removed_chars,
+            inserted_chars: inserted.chars().count(),
+            start_line,
+            removed_lines: removed.bytes().filter(|b| *b == b'\n').count(),
+            inserted_lines: inserted.bytes().filter(|b| *b == b'\n').count(),
+        };
+
+        if self.changes.len() == MAX_CHANGE_LOG {
+            self.changes.pop_front();
+        }
+        self.changes.push_back(change);
+
+        for (_, listener) in self.listeners.iter_mut() {
+            listener(&change);
+        }
+    }
+
+    fn record_undo(&mut self, edit: TextEdit) {
+
+        self.redo_stack.clear();
+
+        if self.undo_group_depth > 0 {
+            if let Some(group) = self.undo_stack.back_mut() {
+                group.push(edit);
+                return;
+            }
+        }
+
+        // typing a word is undone in one step
+        let is_typed_char = edit.removed.is_empty() &&
+            edit.inserted.chars().count() == 1 &&
+            !edit.inserted.chars().all(char::is_whitespace);
+
+        if is_typed_char && self.coalesce_typing {
+            let last_edit = self.undo_stack.back_mut().and_then(|g| g.last_mut());
+            if let Some(last) = last_edit {
+                if last.removed.is_empty() && last.start + last.inserted.chars().count() == edit.start {
+                    last.inserted.push_str(&edit.inserted);
+                    return;
+                }
+            }
+        }
+
+        self.coalesce_typing = is_typed_char;
+        self.push_undo_group(vec![edit]);
+    }
+
+    fn push_undo_group(&mut self, group: UndoGroup) {
+        self.undo_stack.push_back(group);
+        while self.undo_stack.len() > self.undo_limit {
+            self.undo_stack.pop_front();
+        }
+    }
+
+    /// Groups all edits until the matching `end_undo_group` into a single undo step
+    /// (i.e. for "replace all"), groups can be nested
+    pub fn begin_undo_group(&mut self) {
+        if self.undo_group_depth == 0 {
+            self.coalesce_typing = false;
+            self.push_undo_group(Vec::new());
+        }
+        self.undo_group_depth += 1;
+    }
+
+    pub fn end_undo_group(&mut self) {
+        if self.undo_group_depth == 0 {
+            return;
+        }
+        self.undo_group_depth -= 1;
+        if self.undo_group_depth == 0 && self.undo_stack.back().map(|g| g.is_empty()).unwrap_or(false) {
+            self.undo_stack.pop_back();
+        }
+    }
+
+    /// Starts a new undo step for the next typed character,
+    /// should be called when the cursor is moved
+    pub fn break_undo_coalescing(&mut self) {
+        self.coalesce_typing = false;
+    }
+
+    /// Sets the maximum number of undo steps, older steps are discarded
+    pub fn set_undo_limit(&mut self, undo_limit: usize) {
+        self.undo_limit = undo_limit;
+        while self.undo_stack.len() > self.undo_limit {
+            self.undo_stack.pop_front();
+        }
+    }
+
+    pub fn clear_undo_history(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.undo_group_depth = 0;
+        self.coalesce_typing = false;
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.undo_stack.back().map(|g| !g.is_empty()).unwrap_or(false)
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Reverts the last undo step, returns the char index after the restored text
+    /// (to place the cursor) or `None` if there is nothing to undo
+    pub fn undo(&mut self) -> Option<usize> {
+
+        if self.undo_group_depth > 0 || !self.can_undo() {
+            return None;
+        }
+
+        let group = self.undo_stack.pop_back()?;
+        self.coalesce_typing = false;
+
+        let mut cursor = 0;
+        for edit in group.iter().rev() {
+            self.apply_edit(edit.start, &edit.inserted, &edit.removed, TextChangeKind::Undo);
+            cursor = edit.start + edit.removed.chars().count();
+        }
+
+        self.redo_stack.push(group);
+        Some(cursor)
+    }
+
+    /// Re-applies the last undone step, returns the char index after
+    /// the inserted text or `None` if there is nothing to redo
+    pub fn redo(&mut self) -> Option<usize> {
+
+        if self.undo_group_depth > 0 {
+            return None;
+        }
+
+        let group = self.redo_stack.pop()?;
+        self.coalesce_typing = false;
+
+        let mut cursor = 0;
+        for edit in group.iter() {
+            self.apply_edit(edit.start, &edit.removed, &edit.inserted, TextChangeKind::Redo);
+            cursor = edit.start + edit.inserted.chars().count();
+        }
+
+        self.push_undo_group(group);
+        Some(cursor)
+    }
+
+    /// Returns the changes after the revision (oldest first), or `None` if the
+    /// changes are no longer available, in which case the entire text has to be laid out again
+    pub fn changes_since(&self, revision: u64) -> Option<Vec<TextChange>> {
+        if revision >= self.revision {
+            return Some(Vec::new());
+        }
+        let first_available = self.changes.front()?.revision;
+        if revision + 1 < first_available {
+            return None;
+        }
+        Some(self.changes.iter().filter(|c| c.revision > revision).copied().collect())
+    }
+
+    /// Invokes the listener after every change of the text
+    pub fn add_listener(&mut self, listener: TextBufferListener) -> TextBufferListenerId {
+        let id = TextBufferListenerId(self.next_listener_id);
+        self.next_listener_id += 1;
+        self.listeners.push((id, listener));
+        id
+    }
+
+    pub fn remove_listener(&mut self, id: TextBufferListenerId) {
+        self.listeners.retain(|(listener_id, _)| *listener_id != id);
+    }
+
+    /// Returns the char index of the next grapheme cluster boundary, i.e.
+    /// where the cursor moves when pressing the right arrow key
+    pub fn next_grapheme_boundary(&self, char_idx: usize) -> usize {
+        let len = self.len_chars();
+        let mut i = char_idx.min(len);
+        if i == len {
+            return len;
+        }
+        i += 1;
+        while i < len && !self.is_grapheme_boundary(i) {
+            i += 1;
+        }
+        i
+    }
+
+    /// Returns the char index of the previous grapheme cluster boundary, i.e.
+    /// where the cursor moves when pressing the left arrow key
+    pub fn prev_grapheme_boundary(&self, char_idx: usize) -> usize {
+        let mut i = char_idx.min(self.len_chars());
+        if i == 0 {
+            return 0;
+        }
+        i -= 1;
+        while i > 0 && !self.is_grapheme_boundary(i) {
+            i -= 1;
+        }
+        i
+    }
+
+    /// Whether the cursor may be placed before the char, without splitting a
+    /// user-perceived character (simplified rules of UAX #29: CR LF, combining marks,
+    /// variation selectors, emoji modifiers / ZWJ sequences and flags)
+    pub fn is_grapheme_boundary(&self, char_idx: usize) -> bool {
+
+        if char_idx == 0 || char_idx >= self.len_chars() {
+            return true;
+        }
+
+        let (prev, next) = match (self.char_at(char_idx - 1), self.char_at(char_idx)) {
+            (Some(p), Some(n)) => (p, n),
+            _ => return true,
+        };
+
+        if prev == '\r' && next == '\n' {
+            return false;
+        }
+        if prev.is_control() || next.is_control() {
+            return true;
+        }
+        if is_grapheme_extend(next) {
+            return false;
+        }
+        if prev == ZERO_WIDTH_JOINER && is_pictographic(next) {
+            return false;
+        }
+        if is_regional_indicator(prev) && is_regional_indicator(next) {
+            // flags are pairs of regional indicators
+            let mut preceding = 0;
+            let mut i = char_idx;
+            while i > 0 && self.char_at(i - 1).map(is_regional_indicator).unwrap_or(false) {
+                preceding += 1;
+                i -= 1;
+            }
+            return preceding % 2 == 0;
+        }
+
+        true
+    }
+
+    /// Returns the end of the next word (ctrl + right arrow key)
+    pub fn next_word_boundary(&self, char_idx: usize) -> usize {
+        let len = self.len_chars();
+        let mut i = char_idx.min(len);
+        while i < len && self.char_at(i).map(char::is_whitespace).unwrap_or(false) {
+            i += 1;
+        }
+        if i < len && self.char_at(i).map(is_word_char).unwrap_or(false) {
+            while i < len && self.char_at(i).map(is_word_char).unwrap_or(false) {
+                i += 1;
+            }
+            i
+        } else {
+            self.next_grapheme_boundary(i)
+        }
+    }
+
+    /// Returns the start of the previous word (ctrl + left arrow key)
+    pub fn prev_word_boundary(&self, char_idx: usize) -> usize {
+        let mut i = char_idx.min(self.len_chars());
+        while i > 0 && self.char_at(i - 1).map(char::is_whitespace).unwrap_or(false) {
+            i -= 1;
+        }
+        if i > 0 && self.char_at(i - 1).map(is_word_char).unwrap_or(false) {
+            while i > 0 && self.char_at(i - 1).map(is_word_char).unwrap_or(false) {
+                i -= 1;
+            }
+            i
+        } else {
+            self.prev_grapheme_boundary(i)
+        }
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn is_grapheme_extend(c: char) -> bool {
+    unicode_normalization::char::is_combining_mark(c) || matches!(c,
+        '\u{200C}' | ZERO_WIDTH_JOINER |
+        '\u{FE00}'..='\u{FE0F}' |   // variation selectors
+        '\u{1F3FB}'..='\u{1F3FF}' | // emoji skin tone modifiers
+        '\u{E0020}'..='\u{E007F}' | // tags
+        '\u{E0100}'..='\u{E01EF}'   // variation selectors supplement
+    )
+}
+
+fn is_pictographic(c: char) -> bool {
+    matches!(c,
+        '\u{2600}'..='\u{27BF}' |
+        '\u{1F300}'..='\u{1FAFF}'
+    )
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c, '\u{1F1E6}'..='\u{1F1FF}')
+}
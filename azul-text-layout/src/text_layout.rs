@@ -4,7 +4,7 @@
 pub use crate::text_shaping::ParsedFont;
 pub use azul_core::{
     app_resources::{
-        Words, Word, WordType,
+        Words, Word, WordType, WordsChange,
         ShapedWords, ShapedWord, WordIndex, GlyphIndex, LineLength, IndexOfLineBreak,
         RemainingSpaceToRight, LineBreaks, WordPositions, LayoutedGlyphs, FontMetrics,
     },
@@ -17,6 +17,9 @@ pub use azul_core::{
     window::{LogicalRect, LogicalSize, LogicalPosition},
 };
 pub use azul_css::FontRef;
+pub use azul_core::svg::{SvgMultiPolygon, SvgPath, SvgPathElement, SvgLine, SvgPoint, SvgQuadraticCurve, SvgCubicCurve};
+use crate::text_shaping::GlyphOutlineOperation;
+pub use crate::line_breaking::LineBreakOptions;
 use alloc::vec::Vec;
 use alloc::string::String;
 
@@ -38,15 +41,134 @@ pub fn split_text_into_words(text: &str) -> Words {
     let normalized_chars = normalized_string.chars().collect::<Vec<char>>();
 
     let mut words = Vec::new();
+    split_chars_into_words(&normalized_chars, 0, normalized_chars.len(), &mut words);
+
+    // If the last item is a `Return`, remove it
+    if let Some(Word { word_type: WordType::Return, .. }) = words.last() {
+        words.pop();
+    }
+
+    Words {
+        items: words.into(),
+        internal_str: normalized_string.into(),
+        internal_chars: normalized_chars.iter().map(|c| *c as u32).collect(),
+    }
+}
+
+/// Splits the text into words like `split_text_into_words`, but additionally splits
+/// the words at the line break opportunities inside of them (UAX #14, with tailorings
+/// for the language of the text) and inserts `WordType::SoftHyphen` items at the
+/// hyphenation points.
+pub fn split_text_into_words_with_options(text: &str, options: &LineBreakOptions) -> Words {
+
+    use unicode_normalization::UnicodeNormalization;
+
+    let normalized_string = text.nfc().collect::<String>();
+    let normalized_chars = normalized_string.chars().collect::<Vec<char>>();
+
+    let mut words = Vec::new();
+    split_chars_into_words(&normalized_chars, 0, normalized_chars.len(), &mut words);
+    let mut words = crate::line_breaking::break_words(&normalized_chars, words, options);
+
+    // If the last item is a `Return`, remove it
+    if let Some(Word { word_type: WordType::Return, .. }) = words.last() {
+        words.pop();
+    }
+
+    Words {
+        items: words.into(),
+        internal_str: normalized_string.into(),
+        internal_chars: normalized_chars.iter().map(|c| *c as u32).collect(),
+    }
+}
+
+/// Splits the text again after it was edited, only the paragraphs between the first
+/// and the last changed character are split again, the words before and after them are
+/// copied from the `old_words` (which have to be split with the same `options`).
+/// Returns the new words and which of them have changed.
+pub fn split_text_into_words_incremental(old_words: &Words, new_text: &str, options: &LineBreakOptions) -> (Words, WordsChange) {
+
+    use unicode_normalization::UnicodeNormalization;
+
+    let normalized_string = new_text.nfc().collect::<String>();
+    let new_chars = normalized_string.chars().collect::<Vec<char>>();
+    let old_chars = old_words.internal_chars.as_ref();
+    let old_items = old_words.items.as_ref();
+
+    let min_len = old_chars.len().min(new_chars.len());
+    let common_prefix = old_chars.iter().zip(new_chars.iter())
+        .take_while(|(o, n)| **o == **n as u32)
+        .count();
+    let common_suffix = old_chars.iter().rev().zip(new_chars.iter().rev())
+        .take(min_len - common_prefix)
+        .take_while(|(o, n)| **o == **n as u32)
+        .count();
+
+    // The unchanged paragraphs at the start end with a return that is not modified by the edit
+    let paragraph_start = new_chars[..common_prefix].iter().rposition(|c| *c == '\n').map(|i| i + 1).unwrap_or(0);
+    let mut prefix_words = old_items.iter().take_while(|w| w.end <= paragraph_start).count();
+    let prefix_ends_with_return = prefix_words > 0 &&
+        old_items[prefix_words - 1].word_type == WordType::Return &&
+        old_items[prefix_words - 1].end == paragraph_start;
+    let paragraph_start = if prefix_ends_with_return { paragraph_start } else { prefix_words = 0; 0 };
+
+    // The unchanged paragraphs at the end start with a return: the character before
+    // the return has to be unchanged too, so that "\n" can't become "\r\n"
+    let suffix_start_idx = old_items.iter().position(|w| {
+        w.word_type == WordType::Return && w.start > old_chars.len() - common_suffix
+    }).filter(|idx| *idx >= prefix_words);
+
+    let (suffix_words, paragraph_end) = match suffix_start_idx {
+        Some(idx) => (
+            old_items.len() - idx,
+            new_chars.len() + old_items[idx].start - old_chars.len(),
+        ),
+        None => (0, new_chars.len()),
+    };
+
+    let mut words = Vec::with_capacity(prefix_words + suffix_words);
+    words.extend_from_slice(&old_items[..prefix_words]);
+    let mut changed_words = Vec::new();
+    split_chars_into_words(&new_chars, paragraph_start, paragraph_end, &mut changed_words);
+    words.extend(crate::line_breaking::break_words(&new_chars, changed_words, options));
+    words.extend(old_items[(old_items.len() - suffix_words)..].iter().map(|w| Word {
+        start: w.start + new_chars.len() - old_chars.len(),
+        end: w.end + new_chars.len() - old_chars.len(),
+        word_type: w.word_type,
+    }));
+
+    // If the last item is a `Return`, remove it
+    if let Some(Word { word_type: WordType::Return, .. }) = words.last() {
+        words.pop();
+    }
+
+    let change = WordsChange {
+        prefix_words,
+        suffix_words,
+        old_words: old_items.len(),
+    };
+
+    let words = Words {
+        items: words.into(),
+        internal_str: normalized_string.into(),
+        internal_chars: new_chars.iter().map(|c| *c as u32).collect(),
+    };
+
+    (words, change)
+}
+
+/// Splits the characters `normalized_chars[start..end]` into words, `start` has to be
+/// either 0 or the index after a line break (so that no word crosses the range start)
+fn split_chars_into_words(normalized_chars: &[char], start: usize, end: usize, words: &mut Vec<Word>) {
 
     // Instead of storing the actual word, the word is only stored as an index instead,
     // which reduces allocations and is important for later on introducing RTL text
     // (where the position of the character data does not correspond to the actual glyph order).
-    let mut current_word_start = 0;
-    let mut last_char_idx = 0;
-    let mut last_char_was_whitespace = false;
+    let mut current_word_start = start;
+    let mut last_char_idx = start.saturating_sub(1);
+    let mut last_char_was_whitespace = start > 0;
 
-    for (ch_idx, ch) in normalized_chars.iter().enumerate() {
+    for (ch_idx, ch) in normalized_chars.iter().enumerate().take(end).skip(start) {
 
         let ch = *ch;
         let current_char_is_whitespace = ch == ' ' || ch == '\t' || ch == '\r' || ch == '\n';
@@ -115,21 +237,10 @@ pub fn split_text_into_words(text: &str) -> Words {
     if current_word_start != last_char_idx + 1 {
         words.push(Word {
             start: current_word_start,
-            end: normalized_chars.len(),
+            end,
             word_type: WordType::Word
         });
     }
-
-    // If the last item is a `Return`, remove it
-    if let Some(Word { word_type: WordType::Return, .. }) = words.last() {
-        words.pop();
-    }
-
-    Words {
-        items: words.into(),
-        internal_str: normalized_string.into(),
-        internal_chars: normalized_chars.iter().map(|c| *c as u32).collect(),
-    }
 }
 
 /// Takes a text broken into semantic items and shape all the words
@@ -140,34 +251,100 @@ pub fn shape_words(words: &Words, font: &ParsedFont) -> ShapedWords {
 
     let (script, lang) = text_shaping::estimate_script_and_language(&words.internal_str);
 
-    // Get the dimensions of the space glyph
-    let space_advance = font.get_space_width().unwrap_or(font.font_metrics.units_per_em as usize);
-
-    let mut longest_word_width = 0_usize;
-
     // NOTE: This takes the longest part of the entire layout process -- NEED TO PARALLELIZE
     let shaped_words = words.items
     .iter()
-    .filter(|w| w.word_type == WordType::Word)
-    .map(|word| {
-        use crate::text_shaping::ShapedTextBufferUnsized;
+    .filter(|w| has_shaped_word(w.word_type))
+    .map(|word| shape_word(words, word, font, script, lang))
+    .collect::<Vec<_>>();
+
+    new_shaped_words(shaped_words, font)
+}
 
-        let chars = &words.internal_chars.as_ref()[word.start..word.end];
-        let shaped_word = font.shape(chars, script, lang);
-        let word_width = shaped_word.get_word_visual_width_unscaled();
+/// Shapes the words after the text was edited: the shaped words of the unchanged
+/// words before and after the edit are copied from `old_shaped_words` (which has
+/// to be shaped with the same font), only the changed words are shaped again.
+pub fn shape_words_incremental(
+    old_words: &Words,
+    old_shaped_words: &ShapedWords,
+    new_words: &Words,
+    change: &WordsChange,
+    font: &ParsedFont,
+) -> ShapedWords {
 
-        longest_word_width = longest_word_width.max(word_width);
+    use crate::text_shaping;
 
-        let ShapedTextBufferUnsized { infos } = shaped_word;
+    let (script, lang) = text_shaping::estimate_script_and_language(&new_words.internal_str);
 
-        ShapedWord {
-            glyph_infos: infos.into(),
-            word_width,
-        }
-    }).collect();
+    let count_shaped = |items: &[Word]| items.iter().filter(|w| has_shaped_word(w.word_type)).count();
+    let old_items = old_words.items.as_ref();
+    let new_items = new_words.items.as_ref();
+    let old_shaped = old_shaped_words.items.as_ref();
+
+    // changing the script of the text may change the shaping of all words
+    if text_shaping::estimate_script_and_language(&old_words.internal_str) != (script, lang) ||
+       change.old_words != old_items.len() ||
+       change.prefix_words + change.suffix_words > new_items.len() ||
+       count_shaped(old_items) != old_shaped.len() {
+        return shape_words(new_words, font);
+    }
+
+    let prefix_shaped = count_shaped(&old_items[..change.prefix_words]);
+    let suffix_shaped = count_shaped(&old_items[(old_items.len() - change.suffix_words)..]);
+    let changed_items = &new_items[change.prefix_words..(new_items.len() - change.suffix_words)];
+
+    let mut shaped_words = Vec::with_capacity(prefix_shaped + suffix_shaped + changed_items.len());
+    shaped_words.extend_from_slice(&old_shaped[..prefix_shaped]);
+    shaped_words.extend(
+        changed_items.iter()
+        .filter(|w| has_shaped_word(w.word_type))
+        .map(|word| shape_word(new_words, word, font, script, lang))
+    );
+    shaped_words.extend_from_slice(&old_shaped[(old_shaped.len() - suffix_shaped)..]);
+
+    new_shaped_words(shaped_words, font)
+}
+
+/// Returns whether the word type has an entry in the `ShapedWords`
+#[inline]
+fn has_shaped_word(word_type: WordType) -> bool {
+    match word_type {
+        WordType::Word | WordType::SoftHyphen => true,
+        _ => false,
+    }
+}
+
+fn shape_word(words: &Words, word: &Word, font: &ParsedFont, script: u32, lang: Option<u32>) -> ShapedWord {
+
+    use crate::text_shaping::ShapedTextBufferUnsized;
+
+    // soft hyphens are shaped as the hyphen that is displayed if the line is broken there
+    const HYPHEN: [u32; 1] = ['-' as u32];
+
+    let chars = match word.word_type {
+        WordType::SoftHyphen => &HYPHEN[..],
+        _ => &words.internal_chars.as_ref()[word.start..word.end],
+    };
+    let shaped_word = font.shape(chars, script, lang);
+    let word_width = shaped_word.get_word_visual_width_unscaled();
+
+    let ShapedTextBufferUnsized { infos } = shaped_word;
+
+    ShapedWord {
+        glyph_infos: infos.into(),
+        word_width,
+    }
+}
+
+fn new_shaped_words(shaped_words: Vec<ShapedWord>, font: &ParsedFont) -> ShapedWords {
+
+    // Get the dimensions of the space glyph
+    let space_advance = font.get_space_width().unwrap_or(font.font_metrics.units_per_em as usize);
+
+    let longest_word_width = shaped_words.iter().map(|w| w.word_width).max().unwrap_or(0);
 
     ShapedWords {
-        items: shaped_words,
+        items: shaped_words.into(),
         longest_word_width: longest_word_width,
         space_advance,
         font_metrics_units_per_em: font.font_metrics.units_per_em,
@@ -181,163 +358,296 @@ pub fn shape_words(words: &Words, font: &ParsedFont) -> ShapedWords {
 /// the intrinsic width + height of the text content.
 pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_options: &ResolvedTextLayoutOptions) -> WordPositions {
 
-    use self::WordType::*;
-    use self::LineCaretIntersection::*;
-    use core::f32;
-    use azul_core::app_resources::WordPosition;
+    let mut positioner = WordPositioner::new(shaped_words, text_layout_options, words.items.len());
+
+    // The last word is a bit special: Any text must have at least one line break!
+    for (word_idx, word) in words.items.iter().enumerate() {
+        positioner.position_word(word_idx, word);
+    }
+
+    positioner.finish()
+}
+
+/// Positions the words after the text was edited: the lines before the changed paragraphs
+/// are copied from `previous` (the positions of the old words), only the changed paragraphs
+/// are positioned again and the lines after them are moved up / down. Lays out the entire
+/// text if the layout options have changed.
+pub fn position_words_incremental(
+    words: &Words,
+    shaped_words: &ShapedWords,
+    previous: &WordPositions,
+    change: &WordsChange,
+    text_layout_options: &ResolvedTextLayoutOptions,
+) -> WordPositions {
+
     use azul_core::ui_solver::InlineTextLine;
 
-    let font_size_px = text_layout_options.font_size_px;
-    let space_advance_px = shaped_words.get_space_advance_px(text_layout_options.font_size_px);
-    let word_spacing_px = space_advance_px * text_layout_options.word_spacing.as_ref().copied().unwrap_or(DEFAULT_WORD_SPACING);
-    let line_height_px = space_advance_px * text_layout_options.line_height.as_ref().copied().unwrap_or(DEFAULT_LINE_HEIGHT);
-    let tab_width_px = space_advance_px * text_layout_options.tab_width.as_ref().copied().unwrap_or(DEFAULT_TAB_WIDTH);
-    let spacing_multiplier = text_layout_options.letter_spacing.as_ref().copied().unwrap_or(0.0);
+    let items = words.items.as_ref();
+    let old_positions = previous.word_positions.as_slice();
+
+    // the unchanged paragraphs before and after the edit are separated from the edit by a return
+    let can_reuse_previous = previous.text_layout_options == *text_layout_options &&
+        change.prefix_words > 0 &&
+        change.suffix_words > 0 &&
+        change.old_words == old_positions.len() &&
+        change.prefix_words + change.suffix_words <= change.old_words.min(items.len()) &&
+        items[change.prefix_words - 1].word_type == WordType::Return &&
+        items[items.len() - change.suffix_words].word_type == WordType::Return &&
+        items[(items.len() - change.suffix_words)..].iter().any(|w| w.word_type == WordType::Word);
+
+    if !can_reuse_previous {
+        return position_words(words, shaped_words, text_layout_options);
+    }
 
-    let mut line_breaks = Vec::new();
-    let mut word_positions = Vec::new();
-    let mut line_caret_x = text_layout_options.leading.as_ref().copied().unwrap_or(0.0);
-    let mut line_caret_y = font_size_px + line_height_px;
-    let mut shaped_word_idx = 0;
-    let mut last_shaped_word_word_idx = 0;
-    let mut last_line_start_idx = 0;
+    let new_return_idx = items.len() - change.suffix_words;
+    let old_return_idx = change.old_words - change.suffix_words;
 
-    let last_word_idx = words.items.len().saturating_sub(1);
+    let mut positioner = WordPositioner::new(shaped_words, text_layout_options, items.len());
+
+    // restore the state after the return at the end of the unchanged paragraphs
+    let prefix = &items[..change.prefix_words];
+    positioner.word_positions.extend_from_slice(&old_positions[..change.prefix_words]);
+    positioner.line_breaks.extend(previous.line_breaks.iter().filter(|l| l.word_start < change.prefix_words).cloned());
+    positioner.line_caret_x = 0.0;
+    positioner.line_caret_y = old_positions[change.prefix_words - 1].position.y + positioner.line_advance_px();
+    positioner.shaped_word_idx = prefix.iter().filter(|w| has_shaped_word(w.word_type)).count();
+    positioner.last_shaped_word_word_idx = prefix.iter().rposition(|w| w.word_type == WordType::Word).unwrap_or(0);
+    positioner.last_line_start_idx = change.prefix_words;
+
+    // position the changed paragraphs, including the return after them
+    for (word_idx, word) in items.iter().enumerate().take(new_return_idx + 1).skip(change.prefix_words) {
+        positioner.position_word(word_idx, word);
+    }
+
+    // words without a shaped word are skipped
+    if positioner.word_positions.len() != new_return_idx + 1 {
+        return position_words(words, shaped_words, text_layout_options);
+    }
+
+    // the lines after the edit only move up or down
+    let dy = positioner.word_positions[new_return_idx].position.y - old_positions[old_return_idx].position.y;
+    // hidden soft hyphens have no shaped word index, so count the shaped words of the unchanged words instead
+    let suffix_shaped = items[new_return_idx..].iter().filter(|w| has_shaped_word(w.word_type)).count();
+    let old_shaped_idx = match previous.number_of_shaped_words.checked_sub(suffix_shaped) {
+        Some(s) => s,
+        None => return position_words(words, shaped_words, text_layout_options),
+    };
+    let new_shaped_idx = positioner.shaped_word_idx;
+
+    positioner.word_positions.extend(old_positions[(old_return_idx + 1)..].iter().map(|p| {
+        let mut p = p.clone();
+        p.shaped_word_index = p.shaped_word_index.map(|i| i + new_shaped_idx - old_shaped_idx);
+        p.position.y += dy;
+        p
+    }));
+
+    positioner.line_breaks.extend(previous.line_breaks.iter().filter(|l| l.word_start > old_return_idx).map(|l| {
+        InlineTextLine {
+            word_start: l.word_start + new_return_idx - old_return_idx,
+            word_end: l.word_end + new_return_idx - old_return_idx,
+            bounds: LogicalRect::new(
+                LogicalPosition::new(l.bounds.origin.x, l.bounds.origin.y + dy),
+                l.bounds.size,
+            ),
+        }
+    }));
+
+    positioner.shaped_word_idx = previous.number_of_shaped_words + new_shaped_idx - old_shaped_idx;
+    positioner.line_caret_x = previous.trailing;
+    positioner.into_word_positions()
+}
+
+/// State of the line caret while positioning the words of a text
+struct WordPositioner<'a> {
+    shaped_words: &'a ShapedWords,
+    text_layout_options: &'a ResolvedTextLayoutOptions,
+    last_word_idx: usize,
+    font_size_px: f32,
+    word_spacing_px: f32,
+    line_height_px: f32,
+    tab_width_px: f32,
+    spacing_multiplier: f32,
+    line_breaks: Vec<azul_core::ui_solver::InlineTextLine>,
+    word_positions: Vec<azul_core::app_resources::WordPosition>,
+    line_caret_x: f32,
+    line_caret_y: f32,
+    shaped_word_idx: usize,
+    last_shaped_word_word_idx: usize,
+    last_line_start_idx: usize,
+    /// Soft hyphen directly before the current word: (word index, shaped word index, width of the hyphen)
+    soft_hyphen: Option<(usize, usize, f32)>,
+}
+
+impl<'a> WordPositioner<'a> {
+
+    fn new(shaped_words: &'a ShapedWords, text_layout_options: &'a ResolvedTextLayoutOptions, number_of_words: usize) -> Self {
+
+        let font_size_px = text_layout_options.font_size_px;
+        let space_advance_px = shaped_words.get_space_advance_px(text_layout_options.font_size_px);
+        let line_height_px = space_advance_px * text_layout_options.line_height.as_ref().copied().unwrap_or(DEFAULT_LINE_HEIGHT);
+
+        Self {
+            shaped_words,
+            text_layout_options,
+            last_word_idx: number_of_words.saturating_sub(1),
+            font_size_px,
+            word_spacing_px: space_advance_px * text_layout_options.word_spacing.as_ref().copied().unwrap_or(DEFAULT_WORD_SPACING),
+            line_height_px,
+            tab_width_px: space_advance_px * text_layout_options.tab_width.as_ref().copied().unwrap_or(DEFAULT_TAB_WIDTH),
+            spacing_multiplier: text_layout_options.letter_spacing.as_ref().copied().unwrap_or(0.0),
+            line_breaks: Vec::new(),
+            word_positions: Vec::with_capacity(number_of_words),
+            line_caret_x: text_layout_options.leading.as_ref().copied().unwrap_or(0.0),
+            line_caret_y: font_size_px + line_height_px,
+            shaped_word_idx: 0,
+            last_shaped_word_word_idx: 0,
+            last_line_start_idx: 0,
+            soft_hyphen: None,
+        }
+    }
+
+    #[inline]
+    fn line_advance_px(&self) -> f32 {
+        self.font_size_px + self.line_height_px
+    }
+
+    fn push_line_break(&mut self, word_idx: usize) {
+        use azul_core::ui_solver::InlineTextLine;
+        self.line_breaks.push(InlineTextLine {
+            word_start: self.last_line_start_idx,
+            word_end: word_idx.saturating_sub(1).max(self.last_line_start_idx),
+            bounds: LogicalRect::new(
+                LogicalPosition::new(0.0, self.line_caret_y),
+                LogicalSize::new(self.line_caret_x, self.line_advance_px())
+            ),
+        });
+    }
+
+    fn push_word_position(&mut self, shaped_word_index: Option<usize>, x: f32, y: f32, width: f32) {
+        use azul_core::app_resources::WordPosition;
+        self.word_positions.push(WordPosition {
+            shaped_word_index,
+            position: LogicalPosition::new(x, y),
+            size: LogicalSize::new(width, self.line_advance_px()),
+        });
+    }
+
+    fn position_word(&mut self, word_idx: usize, word: &Word) {
+
+        use self::LineCaretIntersection::*;
+
+        let max_horizontal_width = self.text_layout_options.max_horizontal_width.as_ref().copied();
+        let soft_hyphen = self.soft_hyphen.take();
 
-    // The last word is a bit special: Any text must have at least one line break!
-    for (word_idx, word) in words.items.iter().enumerate() {
         match word.word_type {
-            Word => {
+            WordType::Word => {
 
                 // shaped words only contains the actual shaped words, not spaces / tabs / return chars
-                let shaped_word = match shaped_words.items.get(shaped_word_idx) {
+                let shaped_word = match self.shaped_words.items.get(self.shaped_word_idx) {
                     Some(s) => s,
-                    None => continue,
+                    None => return,
                 };
 
-                let letter_spacing_px = spacing_multiplier * shaped_word
+                let letter_spacing_px = self.spacing_multiplier * shaped_word
                 .number_of_glyphs().saturating_sub(1) as f32;
 
                 // Calculate where the caret would be for the next word
                 let shaped_word_width = shaped_word.get_word_width(
-                    shaped_words.font_metrics_units_per_em,
-                    text_layout_options.font_size_px
+                    self.shaped_words.font_metrics_units_per_em,
+                    self.font_size_px
                 ) + letter_spacing_px;
 
                 // Determine if a line break is necessary
                 let caret_intersection = LineCaretIntersection::new(
-                    line_caret_x,
+                    self.line_caret_x,
                     shaped_word_width,
-                    line_caret_y,
-                    font_size_px + line_height_px,
-                    text_layout_options.max_horizontal_width.as_ref().copied(),
+                    self.line_caret_y,
+                    self.line_advance_px(),
+                    max_horizontal_width,
                 );
 
                 // Correct and advance the line caret position
                 match caret_intersection {
                     NoLineBreak { new_x, new_y } => {
-                        word_positions.push(WordPosition {
-                            shaped_word_index: Some(shaped_word_idx),
-                            position: LogicalPosition::new(line_caret_x, line_caret_y),
-                            size: LogicalSize::new(shaped_word_width, font_size_px + line_height_px),
-                        });
-                        line_caret_x = new_x;
-                        line_caret_y = new_y;
+                        self.push_word_position(Some(self.shaped_word_idx), self.line_caret_x, self.line_caret_y, shaped_word_width);
+                        self.line_caret_x = new_x;
+                        self.line_caret_y = new_y;
                     },
                     LineBreak { new_x, new_y } => {
+                        // the line is broken at a soft hyphen: display the hyphen at the end of the line
+                        if let Some((_, hyphen_shaped_idx, hyphen_width)) = soft_hyphen.filter(|(i, _, _)| i + 1 == word_idx) {
+                            if let Some(p) = self.word_positions.last_mut() {
+                                p.shaped_word_index = Some(hyphen_shaped_idx);
+                                p.size.width = hyphen_width;
+                            }
+                            self.line_caret_x += hyphen_width;
+                        }
+
                         // push the line break first
-                        line_breaks.push(InlineTextLine {
-                            word_start: last_line_start_idx,
-                            word_end: word_idx.saturating_sub(1).max(last_line_start_idx),
-                            bounds: LogicalRect::new(
-                                LogicalPosition::new(0.0, line_caret_y),
-                                LogicalSize::new(line_caret_x, font_size_px + line_height_px)
-                            ),
-                        });
-                        last_line_start_idx = word_idx;
-
-                        word_positions.push(WordPosition {
-                            shaped_word_index: Some(shaped_word_idx),
-                            position: LogicalPosition::new(new_x, new_y),
-                            size: LogicalSize::new(shaped_word_width, font_size_px + line_height_px),
-                        });
-                        line_caret_x = new_x + shaped_word_width; // add word width for the next word
-                        line_caret_y = new_y;
+                        self.push_line_break(word_idx);
+                        self.last_line_start_idx = word_idx;
+
+                        self.push_word_position(Some(self.shaped_word_idx), new_x, new_y, shaped_word_width);
+                        self.line_caret_x = new_x + shaped_word_width; // add word width for the next word
+                        self.line_caret_y = new_y;
                     },
                 }
 
-                shaped_word_idx += 1;
-                last_shaped_word_word_idx = word_idx;
+                self.shaped_word_idx += 1;
+                self.last_shaped_word_word_idx = word_idx;
             },
-            Return => {
-                if word_idx != last_word_idx {
-                    line_breaks.push(InlineTextLine {
-                        word_start: last_line_start_idx,
-                        word_end: word_idx.saturating_sub(1).max(last_line_start_idx),
-                        bounds: LogicalRect::new(
-                            LogicalPosition::new(0.0, line_caret_y),
-                            LogicalSize::new(line_caret_x, font_size_px + line_height_px),
-                        ),
-                    });
+            WordType::SoftHyphen => {
+                // the hyphen is hidden (zero width, no shaped word) unless the line is broken here
+                let hyphen_width = match self.shaped_words.items.get(self.shaped_word_idx) {
+                    Some(s) => s.get_word_width(self.shaped_words.font_metrics_units_per_em, self.font_size_px),
+                    None => return,
+                };
+                self.push_word_position(None, self.line_caret_x, self.line_caret_y, 0.0);
+                self.soft_hyphen = Some((word_idx, self.shaped_word_idx, hyphen_width));
+                self.shaped_word_idx += 1;
+            },
+            WordType::Return => {
+                if word_idx != self.last_word_idx {
+                    self.push_line_break(word_idx);
                     // don't include the return char in the next line again
-                    last_line_start_idx = word_idx + 1;
+                    self.last_line_start_idx = word_idx + 1;
                 }
-                word_positions.push(WordPosition {
-                    shaped_word_index: None,
-                    position: LogicalPosition::new(line_caret_x, line_caret_y),
-                    size: LogicalSize::new(0.0, font_size_px + line_height_px),
-                });
-                if word_idx != last_word_idx {
-                    line_caret_x = 0.0;
-                    line_caret_y = line_caret_y + font_size_px + line_height_px;
+                self.push_word_position(None, self.line_caret_x, self.line_caret_y, 0.0);
+                if word_idx != self.last_word_idx {
+                    self.line_caret_x = 0.0;
+                    self.line_caret_y = self.line_caret_y + self.line_advance_px();
                 }
             },
-            Space | Tab => {
+            WordType::Space | WordType::Tab => {
                 let x_advance = match word.word_type {
-                    Space => word_spacing_px,
-                    Tab => tab_width_px,
-                    _ => word_spacing_px, // unreachable
+                    WordType::Tab => self.tab_width_px,
+                    _ => self.word_spacing_px,
                 };
 
                 let caret_intersection = LineCaretIntersection::new(
-                    line_caret_x,
+                    self.line_caret_x,
                     x_advance, // advance by space / tab width
-                    line_caret_y,
-                    font_size_px + line_height_px,
-                    text_layout_options.max_horizontal_width.as_ref().copied(),
+                    self.line_caret_y,
+                    self.line_advance_px(),
+                    max_horizontal_width,
                 );
 
                 match caret_intersection {
                     NoLineBreak { new_x, new_y } => {
-                        word_positions.push(WordPosition {
-                            shaped_word_index: None,
-                            position: LogicalPosition::new(line_caret_x, line_caret_y),
-                            size: LogicalSize::new(x_advance, font_size_px + line_height_px),
-                        });
-                        line_caret_x = new_x;
-                        line_caret_y = new_y;
+                        self.push_word_position(None, self.line_caret_x, self.line_caret_y, x_advance);
+                        self.line_caret_x = new_x;
+                        self.line_caret_y = new_y;
                     },
                     LineBreak { new_x, new_y } => {
                         // push the line break before increasing
-                        if word_idx != last_word_idx {
-                            line_breaks.push(InlineTextLine {
-                                word_start: last_line_start_idx,
-                                word_end: word_idx.saturating_sub(1).max(last_line_start_idx),
-                                bounds: LogicalRect::new(
-                                    LogicalPosition::new(0.0, line_caret_y),
-                                    LogicalSize::new(line_caret_x, font_size_px + line_height_px)
-                                ),
-                            });
-                            last_line_start_idx = word_idx;
+                        if word_idx != self.last_word_idx {
+                            self.push_line_break(word_idx);
+                            self.last_line_start_idx = word_idx;
                         }
-                        word_positions.push(WordPosition {
-                            shaped_word_index: None,
-                            position: LogicalPosition::new(line_caret_x, line_caret_y),
-                            size: LogicalSize::new(x_advance, font_size_px + line_height_px),
-                        });
-                        if word_idx != last_word_idx {
-                            line_caret_x = new_x; // don't add the space width here when pushing onto new line
-                            line_caret_y = new_y;
+                        self.push_word_position(None, self.line_caret_x, self.line_caret_y, x_advance);
+                        if word_idx != self.last_word_idx {
+                            self.line_caret_x = new_x; // don't add the space width here when pushing onto new line
+                            self.line_caret_y = new_y;
                         }
                     },
                 }
@@ -345,31 +655,41 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
         }
     }
 
-    line_breaks.push(InlineTextLine {
-        word_start: last_line_start_idx,
-        word_end: last_shaped_word_word_idx,
-        bounds: LogicalRect::new(
-            LogicalPosition::new(0.0, line_caret_y),
-            LogicalSize::new(line_caret_x, font_size_px + line_height_px)
-        ),
-    });
-
-    let longest_line_width = line_breaks.iter()
-    .map(|line| line.bounds.size.width)
-    .fold(0.0_f32, f32::max);
-
-    let content_size_y = line_breaks.len() as f32 * (font_size_px + line_height_px);
-    let content_size_x = text_layout_options.max_horizontal_width.as_ref().copied().unwrap_or(longest_line_width);
-    let content_size = LogicalSize::new(content_size_x, content_size_y);
-
-    WordPositions {
-        text_layout_options: text_layout_options.clone(),
-        trailing: line_caret_x,
-        number_of_shaped_words: shaped_word_idx,
-        number_of_lines: line_breaks.len(),
-        content_size,
-        word_positions,
-        line_breaks,
+    /// Pushes the last line
+    fn finish(mut self) -> WordPositions {
+        use azul_core::ui_solver::InlineTextLine;
+        self.line_breaks.push(InlineTextLine {
+            word_start: self.last_line_start_idx,
+            word_end: self.last_shaped_word_word_idx,
+            bounds: LogicalRect::new(
+                LogicalPosition::new(0.0, self.line_caret_y),
+                LogicalSize::new(self.line_caret_x, self.line_advance_px())
+            ),
+        });
+        self.into_word_positions()
+    }
+
+    fn into_word_positions(self) -> WordPositions {
+
+        use core::f32;
+
+        let longest_line_width = self.line_breaks.iter()
+        .map(|line| line.bounds.size.width)
+        .fold(0.0_f32, f32::max);
+
+        let content_size_y = self.line_breaks.len() as f32 * self.line_advance_px();
+        let content_size_x = self.text_layout_options.max_horizontal_width.as_ref().copied().unwrap_or(longest_line_width);
+        let content_size = LogicalSize::new(content_size_x, content_size_y);
+
+        WordPositions {
+            text_layout_options: self.text_layout_options.clone(),
+            trailing: self.line_caret_x,
+            number_of_shaped_words: self.shaped_word_idx,
+            number_of_lines: self.line_breaks.len(),
+            content_size,
+            word_positions: self.word_positions,
+            line_breaks: self.line_breaks,
+        }
     }
 }
 
@@ -447,6 +767,151 @@ pub fn shape_text(font: &FontRef, text: &str, options: &ResolvedTextLayoutOption
     )
 }
 
+/// Converts the glyphs of a layouted text into vector outlines (one ring per glyph contour),
+/// in the same coordinate space as `InlineText::get_layouted_glyphs()`. Used to export text
+/// as SVG / PDF paths, so that the text does not depend on the font being installed.
+///
+/// Outlines are read from the `ParsedFont` if the font was loaded with `parse_glyph_outlines`,
+/// otherwise they are decoded from the font bytes.
+pub fn glyphs_to_svg_paths(layout: &InlineText, font: &FontRef) -> SvgMultiPolygon {
+
+    use crate::text_shaping::get_glyph_outline;
+
+    let font_data = font.get_data();
+    let parsed_font = unsafe { &*(font_data.parsed as *const ParsedFont) };
+
+    // font units -> px
+    let units_per_em = parsed_font.font_metrics.units_per_em.max(1) as f32;
+    let scale = layout.font_size_px / units_per_em;
+
+    // only parsed if the outlines were not loaded with the font
+    let mut face = None;
+    let mut rings = Vec::new();
+
+    for glyph in layout.get_layouted_glyphs().glyphs.iter() {
+
+        let glyph_index = glyph.index as u16;
+
+        let decoded = parsed_font.glyph_records_decoded
+            .get(&glyph_index)
+            .and_then(|g| g.outline.clone());
+
+        let outline = match decoded {
+            Some(s) => Some(s),
+            None => face
+                .get_or_insert_with(|| ttf_parser::Face::from_slice(font_data.bytes.as_ref(), font_data.font_index).ok())
+                .as_ref()
+                .and_then(|f| get_glyph_outline(f, glyph_index)),
+        };
+
+        if let Some(outline) = outline {
+            outline_to_svg_paths(outline.operations.as_ref(), glyph.point, scale, &mut rings);
+        }
+    }
+
+    SvgMultiPolygon { rings: rings.into() }
+}
+
+/// Converts the outline of one glyph (in font units, y axis pointing up) into closed
+/// paths relative to the `origin` (the left end of the glyph baseline, y axis pointing down)
+fn outline_to_svg_paths(
+    operations: &[GlyphOutlineOperation],
+    origin: LogicalPosition,
+    scale: f32,
+    rings: &mut Vec<SvgPath>,
+) {
+
+    fn close_ring(items: &mut Vec<SvgPathElement>, current: SvgPoint, start: SvgPoint, rings: &mut Vec<SvgPath>) {
+        if current != start && !items.is_empty() {
+            items.push(SvgPathElement::Line(SvgLine { start: current, end: start }));
+        }
+        if !items.is_empty() {
+            rings.push(SvgPath { items: core::mem::replace(items, Vec::new()).into() });
+        }
+    }
+
+    let transform = |x: f32, y: f32| SvgPoint {
+        x: origin.x + x * scale,
+        y: origin.y - y * scale,
+    };
+
+    let mut items = Vec::new();
+    let mut start = transform(0.0, 0.0);
+    let mut current = start;
+
+    for op in operations {
+        match op {
+            GlyphOutlineOperation::MoveTo(m) => {
+                close_ring(&mut items, current, start, rings);
+                start = transform(m.x, m.y);
+                current = start;
+            },
+            GlyphOutlineOperation::LineTo(l) => {
+                let end = transform(l.x, l.y);
+                items.push(SvgPathElement::Line(SvgLine { start: current, end }));
+                current = end;
+            },
+            GlyphOutlineOperation::QuadraticCurveTo(q) => {
+                let end = transform(q.end_x, q.end_y);
+                items.push(SvgPathElement::QuadraticCurve(SvgQuadraticCurve {
+                    start: current,
+                    ctrl: transform(q.ctrl_1_x, q.ctrl_1_y),
+                    end,
+                }));
+                current = end;
+            },
+            GlyphOutlineOperation::CubicCurveTo(c) => {
+                let end = transform(c.end_x, c.end_y);
+                items.push(SvgPathElement::CubicCurve(SvgCubicCurve {
+                    start: current,
+                    ctrl_1: transform(c.ctrl_1_x, c.ctrl_1_y),
+                    ctrl_2: transform(c.ctrl_2_x, c.ctrl_2_y),
+                    end,
+                }));
+                current = end;
+            },
+            GlyphOutlineOperation::ClosePath => {
+                close_ring(&mut items, current, start, rings);
+                current = start;
+            },
+        }
+    }
+
+    close_ring(&mut items, current, start, rings);
+}
+
+#[test]
+fn test_outline_to_svg_paths() {
+
+    use crate::text_shaping::{OutlineMoveTo, OutlineLineTo, OutlineQuadTo};
+
+    // triangle + open contour, scaled by 0.5
+    let operations = vec![
+        GlyphOutlineOperation::MoveTo(OutlineMoveTo { x: 0.0, y: 0.0 }),
+        GlyphOutlineOperation::LineTo(OutlineLineTo { x: 500.0, y: 1000.0 }),
+        GlyphOutlineOperation::LineTo(OutlineLineTo { x: 1000.0, y: 0.0 }),
+        GlyphOutlineOperation::ClosePath,
+        GlyphOutlineOperation::MoveTo(OutlineMoveTo { x: 100.0, y: 100.0 }),
+        GlyphOutlineOperation::QuadraticCurveTo(OutlineQuadTo { ctrl_1_x: 200.0, ctrl_1_y: 200.0, end_x: 300.0, end_y: 100.0 }),
+    ];
+
+    let mut rings = Vec::new();
+    outline_to_svg_paths(&operations, LogicalPosition::new(10.0, 20.0), 0.5, &mut rings);
+
+    assert_eq!(rings.len(), 2);
+    assert!(rings.iter().all(|r| r.is_closed()));
+
+    let triangle = rings[0].items.as_ref();
+    assert_eq!(triangle.len(), 3);
+    assert_eq!(triangle[0].get_start(), SvgPoint { x: 10.0, y: 20.0 });
+    assert_eq!(triangle[0].get_end(), SvgPoint { x: 260.0, y: -480.0 });
+    assert_eq!(triangle[2].get_end(), SvgPoint { x: 10.0, y: 20.0 });
+
+    let curve = rings[1].items.as_ref();
+    assert_eq!(curve.len(), 2);
+    assert_eq!(curve[0].get_end(), SvgPoint { x: 160.0, y: -30.0 });
+}
+
 #[test]
 fn test_split_words() {
 
@@ -521,6 +986,44 @@ fn test_split_words() {
     assert_words(&words_single_str_expected, &words_single_str);
 }
 
+#[test]
+fn test_split_words_incremental() {
+
+    let edits = [
+        ("abc def\nghi jkl\nmno", "abc def\nghi XY jkl\nmno"),
+        ("abc def\nghi jkl\nmno", "abc def\nghi\n\njkl\nmno"),
+        ("abc def\r\nghi jkl\r\nmno", "abc def\r\nghi jk\r\nmno"),
+        ("abc\ndef\nghi", "abc\ndef\r\nghi"),
+        ("abc\ndef\nghi", "abc\ndef ghi"),
+        ("abc\ndef\nghi", "abc\ndef\nghi\n"),
+        ("abc\ndef\nghi\n", "abc\ndef\nghi"),
+        ("abc\ndef\nghi", "xabc\ndef\nghi"),
+        ("abc\ndef\nghi", " abc\ndef\nghi"),
+        ("abc\ndef\nghi", "\ndef\nghi"),
+        ("abc\ndef\nghi", ""),
+        ("", "abc\ndef"),
+        ("a\tb  \n\n c\n d", "a\tb  \n\n c\n\t d"),
+        ("well-known\n漢字\nabc", "well-known\n漢字かな\nabc"),
+    ];
+
+    let options = LineBreakOptions::default();
+
+    for (old_text, new_text) in edits.iter() {
+        let old_words = split_text_into_words_with_options(old_text, &options);
+        let (words, change) = split_text_into_words_incremental(&old_words, new_text, &options);
+        let expected = split_text_into_words_with_options(new_text, &options);
+        assert_eq!(words.items, expected.items, "{:?} -> {:?}", old_text, new_text);
+        assert_eq!(words.internal_chars, expected.internal_chars);
+        assert_eq!(change.old_words, old_words.items.len());
+        assert_eq!(&words.items.as_ref()[..change.prefix_words], &old_words.items.as_ref()[..change.prefix_words]);
+    }
+
+    // editing the second line keeps the first and the last line
+    let old_words = split_text_into_words_with_options("abc def\nghi jkl\nmno", &options);
+    let (_, change) = split_text_into_words_incremental(&old_words, "abc def\nghi XY jkl\nmno", &options);
+    assert_eq!(change, WordsChange { prefix_words: 4, suffix_words: 2, old_words: 9 });
+}
+
 // Scenario 1:
 //
 // +---------+
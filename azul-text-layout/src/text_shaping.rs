@@ -290,6 +290,13 @@ impl ttf_parser::OutlineBuilder for GlyphOutlineBuilder {
     fn close(&mut self) { self.operations.push(GlyphOutlineOperation::ClosePath); }
 }
 
+/// Decodes the outline of a glyph, in font units (y axis pointing up)
+pub(crate) fn get_glyph_outline(face: &ttf_parser::Face, glyph_index: u16) -> Option<GlyphOutline> {
+    let mut outline = GlyphOutlineBuilder::default();
+    face.outline_glyph(ttf_parser::GlyphId(glyph_index), &mut outline)?;
+    Some(GlyphOutline { operations: outline.operations.into() })
+}
+
 impl_vec!(GlyphOutlineOperation, GlyphOutlineOperationVec, GlyphOutlineOperationVecDestructor);
 impl_vec_clone!(GlyphOutlineOperation, GlyphOutlineOperationVec, GlyphOutlineOperationVecDestructor);
 impl_vec_debug!(GlyphOutlineOperation, GlyphOutlineOperationVec);
@@ -385,7 +392,15 @@ impl ParsedFont {
                 }
             }).collect::<Vec<_>>();
 
-        let glyph_records_decoded = glyph_records_decoded.into_iter().collect();
+        let mut glyph_records_decoded = glyph_records_decoded.into_iter().collect::<BTreeMap<_, _>>();
+
+        if parse_glyph_outlines {
+            if let Some(face) = ttf_parser::Face::from_slice(font_bytes, font_index as u32).ok() {
+                for (glyph_index, glyph) in glyph_records_decoded.iter_mut() {
+                    glyph.outline = get_glyph_outline(&face, *glyph_index);
+                }
+            }
+        }
 
         let mut font_data_impl = allsorts::font::Font::new(provider).ok()??;
 
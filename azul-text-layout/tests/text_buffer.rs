@@ -0,0 +1,137 @@
+//! Editing, line indexing, undo / redo and change tracking of the rope-based `TextBuffer`
+
+extern crate azul_text_layout;
+
+use azul_text_layout::text_buffer::{TextBuffer, MAX_CHANGE_LOG};
+
+fn long_text() -> String {
+    (0..2000).map(|i| format!("line {} with some text\n", i)).collect()
+}
+
+fn char_to_byte(text: &str, char_idx: usize) -> usize {
+    text.char_indices().nth(char_idx).map(|(b, _)| b).unwrap_or(text.len())
+}
+
+#[test]
+fn test_insert_remove() {
+    let mut buffer = TextBuffer::from("Hello World");
+    buffer.insert(5, ",");
+    assert_eq!(buffer.to_string(), "Hello, World");
+    buffer.remove(0..7);
+    assert_eq!(buffer.to_string(), "World");
+    buffer.replace(0..5, "Rope");
+    assert_eq!(buffer.to_string(), "Rope");
+    assert_eq!(buffer.len_chars(), 4);
+}
+
+#[test]
+fn test_large_text() {
+    let text = long_text();
+    let mut expected = text.clone();
+    let mut buffer = TextBuffer::from(text.as_str());
+    assert_eq!(buffer.len_lines(), 2001);
+
+    for i in 0..500 {
+        let idx = (i * 7919) % buffer.len_chars();
+        buffer.insert(idx, "ä");
+        let byte_idx = char_to_byte(&expected, idx);
+        expected.insert(byte_idx, 'ä');
+    }
+
+    assert_eq!(buffer.to_string(), expected);
+    assert!(buffer.depth() < 64);
+}
+
+#[test]
+fn test_lines() {
+    let buffer = TextBuffer::from("first\nsecond\n\nfourth");
+    assert_eq!(buffer.len_lines(), 4);
+    assert_eq!(buffer.line(0), "first");
+    assert_eq!(buffer.line(1), "second");
+    assert_eq!(buffer.line(2), "");
+    assert_eq!(buffer.line(3), "fourth");
+    assert_eq!(buffer.line_to_char(1), 6);
+    assert_eq!(buffer.char_to_line(6), 1);
+    assert_eq!(buffer.char_to_line(5), 0);
+
+    let text = long_text();
+    let buffer = TextBuffer::from(text.as_str());
+    assert_eq!(buffer.line(1500), "line 1500 with some text");
+    assert_eq!(buffer.slice(5..9).to_string(), "0 wi");
+}
+
+#[test]
+fn test_undo_redo() {
+    let mut buffer = TextBuffer::from("abc");
+    for c in "def".chars() {
+        let len = buffer.len_chars();
+        buffer.insert(len, &c.to_string());
+    }
+    buffer.insert(6, " ");
+    buffer.insert(7, "g");
+    assert_eq!(buffer.to_string(), "abcdef g");
+
+    // "g", " ", "def"
+    assert_eq!(buffer.undo(), Some(7));
+    assert_eq!(buffer.undo(), Some(6));
+    assert_eq!(buffer.to_string(), "abcdef");
+    assert_eq!(buffer.undo(), Some(3));
+    assert_eq!(buffer.to_string(), "abc");
+    assert_eq!(buffer.undo(), None);
+
+    assert_eq!(buffer.redo(), Some(6));
+    assert_eq!(buffer.to_string(), "abcdef");
+
+    buffer.begin_undo_group();
+    buffer.remove(0..1);
+    buffer.remove(0..1);
+    buffer.end_undo_group();
+    assert_eq!(buffer.to_string(), "cdef");
+    buffer.undo();
+    assert_eq!(buffer.to_string(), "abcdef");
+}
+
+#[test]
+fn test_changes() {
+    use std::sync::{Arc, Mutex};
+
+    let mut buffer = TextBuffer::from("one\ntwo\nthree");
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = received.clone();
+    buffer.add_listener(Box::new(move |c| received_clone.lock().unwrap().push(*c)));
+
+    let revision = buffer.revision();
+    buffer.replace(4..7, "2\n2");
+
+    let changes = buffer.changes_since(revision).unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].start_line, 1);
+    assert_eq!(changes[0].removed_lines, 0);
+    assert_eq!(changes[0].inserted_lines, 1);
+    assert_eq!(*received.lock().unwrap(), changes);
+
+    for i in 0..(MAX_CHANGE_LOG + 1) {
+        buffer.insert(0, if i % 2 == 0 { "a" } else { " " });
+    }
+    assert_eq!(buffer.changes_since(revision), None);
+    assert_eq!(buffer.changes_since(buffer.revision()), Some(Vec::new()));
+}
+
+#[test]
+fn test_grapheme_boundaries() {
+    // "e" + combining acute accent, flag (two regional indicators), CR LF
+    let buffer = TextBuffer::from("ae\u{301}\u{1F1E9}\u{1F1EA}\r\nb");
+    assert_eq!(buffer.next_grapheme_boundary(0), 1);
+    assert_eq!(buffer.next_grapheme_boundary(1), 3);
+    assert_eq!(buffer.next_grapheme_boundary(3), 5);
+    assert_eq!(buffer.next_grapheme_boundary(5), 7);
+    assert_eq!(buffer.prev_grapheme_boundary(7), 5);
+    assert_eq!(buffer.prev_grapheme_boundary(5), 3);
+    assert_eq!(buffer.prev_grapheme_boundary(3), 1);
+
+    let buffer = TextBuffer::from("hello, world");
+    assert_eq!(buffer.next_word_boundary(0), 5);
+    assert_eq!(buffer.next_word_boundary(5), 6);
+    assert_eq!(buffer.next_word_boundary(6), 12);
+    assert_eq!(buffer.prev_word_boundary(12), 7);
+}
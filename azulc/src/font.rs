@@ -89,6 +89,15 @@ const KNOWN_SYSTEM_SANS_SERIF_FONTS: &[&str] = &[
 pub fn load_system_font(id: &str, fc_cache: &FcFontCache) -> Option<(U8Vec, i32)> {
     use rust_fontconfig::{FcPattern, FcFontPath, PatternMatch};
 
+    // resolved in a previous run: doesn't need the (possibly still building) font cache
+    #[cfg(feature = "std")] {
+        if let Some((path, font_index)) = crate::font_cache::get_cached_system_font(id) {
+            if let Ok(bytes) = std::fs::read(&path) {
+                return Some((bytes.into(), font_index));
+            }
+        }
+    }
+
     let mut patterns = Vec::new();
 
     match id {
@@ -228,6 +237,9 @@ pub fn load_system_font(id: &str, fc_cache: &FcFontCache) -> Option<(U8Vec, i32)
             use std::fs;
             use std::path::Path;
             if let Ok(bytes) = fs::read(Path::new(path)) {
+                #[cfg(feature = "std")] {
+                    crate::font_cache::insert_cached_system_font(id, path, *font_index as i32);
+                }
                 return Some((bytes.into(), *font_index as i32));
             }
         }
@@ -0,0 +1,216 @@
+//! Persistent on-disk cache of resolved system fonts
+//!
+//! Building the `FcFontCache` has to parse the header of every installed font,
+//! which takes hundreds of milliseconds on systems with many fonts. The results
+//! of `load_system_font` (font name -> file path + font index) are stored in the
+//! user cache directory, so that later runs can load their system fonts without
+//! waiting for the `FcFontCache`. The cache is keyed by the modification times of
+//! the font directories and discarded as soon as a font is installed or removed.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
+
+const CACHE_FILE_NAME: &str = "azul-font-cache.txt";
+const CACHE_FILE_HEADER: &str = "azul-font-cache 1";
+
+/// Loaded once per process, `None` if the cache has not been loaded yet
+static DISK_CACHE: Mutex<Option<DiskFontCache>> = Mutex::new(None);
+
+#[derive(Debug, Default)]
+struct DiskFontCache {
+    /// Hash over the paths + modification times of all font directories
+    key: u64,
+    /// font name -> (path, font index)
+    fonts: BTreeMap<String, (String, i32)>,
+}
+
+/// Returns the (path, font index) of a system font that has been resolved in a
+/// previous run, `None` if the font is unknown or the font directories have changed
+pub fn get_cached_system_font(id: &str) -> Option<(String, i32)> {
+    let mut cache = DISK_CACHE.lock().ok()?;
+    let cache = cache.get_or_insert_with(load_disk_cache);
+    cache.fonts.get(id).cloned()
+}
+
+/// Stores the resolved system font and writes the cache file
+pub fn insert_cached_system_font(id: &str, path: &str, font_index: i32) {
+
+    let mut cache = match DISK_CACHE.lock() {
+        Ok(o) => o,
+        Err(_) => return,
+    };
+
+    let cache = cache.get_or_insert_with(load_disk_cache);
+
+    let new_entry = (path.to_string(), font_index);
+    if cache.fonts.get(id) == Some(&new_entry) {
+        return;
+    }
+
+    cache.fonts.insert(id.to_string(), new_entry);
+
+    let cache_file = match get_cache_file() {
+        Some(s) => s,
+        None => return,
+    };
+
+    let mut contents = format!("{}\n{:016x}\n", CACHE_FILE_HEADER, cache.key);
+    for (id, (path, font_index)) in cache.fonts.iter() {
+        // names and paths containing tabs or newlines can't be stored
+        if id.contains(&['\t', '\n'][..]) || path.contains(&['\t', '\n'][..]) {
+            continue;
+        }
+        contents.push_str(&format!("{}\t{}\t{}\n", id, font_index, path));
+    }
+
+    if let Some(parent) = cache_file.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    let _ = fs::write(&cache_file, contents);
+}
+
+/// Loads the cache file, returns an empty cache (with the current key)
+/// if the file doesn't exist or the font directories have changed
+fn load_disk_cache() -> DiskFontCache {
+
+    let key = get_font_directories_key();
+
+    let contents = match get_cache_file().and_then(|f| fs::read_to_string(f).ok()) {
+        Some(s) => s,
+        None => return DiskFontCache { key, fonts: BTreeMap::new() },
+    };
+
+    let mut lines = contents.lines();
+
+    let valid = lines.next() == Some(CACHE_FILE_HEADER) &&
+        lines.next().and_then(|k| u64::from_str_radix(k, 16).ok()) == Some(key);
+
+    if !valid {
+        return DiskFontCache { key, fonts: BTreeMap::new() };
+    }
+
+    let fonts = lines.filter_map(|line| {
+        let mut split = line.splitn(3, '\t');
+        let id = split.next()?;
+        let font_index = split.next()?.parse::<i32>().ok()?;
+        let path = split.next()?;
+        Some((id.to_string(), (path.to_string(), font_index)))
+    }).collect();
+
+    DiskFontCache { key, fonts }
+}
+
+fn get_cache_file() -> Option<PathBuf> {
+    get_cache_directory().map(|d| d.join("azul").join(CACHE_FILE_NAME))
+}
+
+#[cfg(target_os = "windows")]
+fn get_cache_directory() -> Option<PathBuf> {
+    std::env::var_os("LOCALAPPDATA").map(PathBuf::from)
+}
+
+#[cfg(target_os = "macos")]
+fn get_cache_directory() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|h| PathBuf::from(h).join("Library").join("Caches"))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn get_cache_directory() -> Option<PathBuf> {
+    std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".cache")))
+}
+
+/// Root directories that the installed fonts are stored in
+fn get_font_directories() -> Vec<PathBuf> {
+
+    let mut dirs = Vec::new();
+
+    #[cfg(target_os = "windows")] {
+        if let Some(windir) = std::env::var_os("WINDIR") {
+            dirs.push(PathBuf::from(windir).join("Fonts"));
+        }
+        if let Some(local) = std::env::var_os("LOCALAPPDATA") {
+            dirs.push(PathBuf::from(local).join("Microsoft").join("Windows").join("Fonts"));
+        }
+    }
+
+    #[cfg(target_os = "macos")] {
+        dirs.push(PathBuf::from("/System/Library/Fonts"));
+        dirs.push(PathBuf::from("/Library/Fonts"));
+        if let Some(home) = std::env::var_os("HOME") {
+            dirs.push(PathBuf::from(home).join("Library").join("Fonts"));
+        }
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))] {
+        dirs.push(PathBuf::from("/usr/share/fonts"));
+        dirs.push(PathBuf::from("/usr/local/share/fonts"));
+        if let Some(home) = std::env::var_os("HOME") {
+            let home = PathBuf::from(home);
+            dirs.push(home.join(".fonts"));
+            dirs.push(home.join(".local").join("share").join("fonts"));
+        }
+    }
+
+    dirs
+}
+
+/// Hashes the paths and modification times of the font directories (and their
+/// subdirectories): installing or removing a font changes the modification
+/// time of the directory that contains it
+fn get_font_directories_key() -> u64 {
+
+    fn hash_dir(dir: &Path, hash: &mut u64, depth: usize) {
+
+        let modified = match fs::metadata(dir).and_then(|m| m.modified()) {
+            Ok(o) => o,
+            Err(_) => return,
+        };
+
+        let nanos = modified.duration_since(UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or(0);
+        fnv1a(hash, dir.to_string_lossy().as_bytes());
+        fnv1a(hash, &nanos.to_le_bytes());
+
+        // fonts are never nested that deep, protects against symlink loops
+        if depth >= 8 {
+            return;
+        }
+
+        let mut subdirs = match fs::read_dir(dir) {
+            Ok(o) => o
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+                .map(|e| e.path())
+                .collect::<Vec<_>>(),
+            Err(_) => return,
+        };
+
+        subdirs.sort();
+
+        for subdir in subdirs {
+            hash_dir(&subdir, hash, depth + 1);
+        }
+    }
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for dir in get_font_directories() {
+        hash_dir(&dir, &mut hash, 0);
+    }
+    hash
+}
+
+// FNV-1a, used instead of the std hasher because the key has to be stable across builds
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+fn fnv1a(hash: &mut u64, bytes: &[u8]) {
+    for b in bytes {
+        *hash ^= *b as u64;
+        *hash = hash.wrapping_mul(FNV_PRIME);
+    }
+}
@@ -258,4 +258,34 @@ pub mod encode {
     pub fn encode_jpeg(image: &RawImage) -> ResultU8VecEncodeImageError {
         ResultU8VecEncodeImageError::Err(EncodeImageError::EncoderNotAvailable)
     }
+
+    /// Encodes an infinitely looping animated GIF one frame at a time,
+    /// the GIF is finished when the encoder is dropped
+    #[cfg(feature = "gif")]
+    pub struct GifAnimationEncoder<W: std::io::Write> {
+        encoder: GifEncoder<W>,
+    }
+
+    #[cfg(feature = "gif")]
+    impl<W: std::io::Write> GifAnimationEncoder<W> {
+
+        /// `speed` trades quality for encoding time (1 = best quality, 30 = fastest)
+        pub fn new(writer: W, speed: i32) -> Result<Self, EncodeImageError> {
+            let mut encoder = GifEncoder::new_with_speed(writer, speed);
+            encoder
+                .set_repeat(image_crate::codecs::gif::Repeat::Infinite)
+                .map_err(translate_image_error_encode)?;
+            Ok(Self { encoder })
+        }
+
+        /// Appends a frame (RGBA8 pixels, top row first) that is shown for `delay_ms` milliseconds
+        pub fn encode_frame(&mut self, rgba: Vec<u8>, width: u32, height: u32, delay_ms: u32) -> Result<(), EncodeImageError> {
+            let buffer = image_crate::RgbaImage::from_raw(width, height, rgba)
+                .ok_or(EncodeImageError::DimensionError)?;
+            let delay = image_crate::Delay::from_numer_denom_ms(delay_ms, 1);
+            self.encoder
+                .encode_frame(image_crate::Frame::from_parts(buffer, 0, 0, delay))
+                .map_err(translate_image_error_encode)
+        }
+    }
 }
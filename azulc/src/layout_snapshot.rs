@@ -0,0 +1,582 @@
+//! Layout snapshots: serializes the solved layout (node rects, overflow, text line boxes)
+//! to JSON and compares it against a golden file with a tolerance.
+//!
+//! Compared to reftests, a failing snapshot test shows exactly which node moved and
+//! by how much, instead of a number of differing pixels.
+
+#![cfg(feature = "std")]
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use std::path::{Path, PathBuf};
+use azul_core::{
+    id_tree::NodeId,
+    dom::IdOrClass,
+    styled_dom::NodeHierarchyItemId,
+    ui_solver::LayoutResult,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct SnapshotRect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl fmt::Display for SnapshotRect {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}x{} @ ({}, {})", self.width, self.height, self.x, self.y)
+    }
+}
+
+/// Solved layout of a single node
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeLayoutSnapshot {
+    pub node_id: usize,
+    pub parent: Option<usize>,
+    /// Node type + ids + classes, i.e. "div#foo.bar", to identify the node in the output
+    pub selector: String,
+    /// Outer bounds of the node
+    pub rect: SnapshotRect,
+    /// Bounds of the children, if the children overflow the node
+    pub overflow: Option<SnapshotRect>,
+    /// Line boxes of the text of the node (relative to the node), the bottom
+    /// of each line box is the baseline of the line
+    pub lines: Vec<SnapshotRect>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LayoutSnapshot {
+    pub width: f32,
+    pub height: f32,
+    pub nodes: Vec<NodeLayoutSnapshot>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayoutSnapshotError {
+    Io(PathBuf, String),
+    /// Golden file is not valid JSON or doesn't have the expected structure
+    Parse(PathBuf, String),
+}
+
+impl fmt::Display for LayoutSnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LayoutSnapshotError::Io(path, e) => write!(f, "I/O error on \"{}\": {}", path.display(), e),
+            LayoutSnapshotError::Parse(path, e) => write!(f, "invalid layout snapshot \"{}\": {}", path.display(), e),
+        }
+    }
+}
+
+impl std::error::Error for LayoutSnapshotError { }
+
+/// Difference between the current layout and the golden snapshot
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayoutDifference {
+    RootSize { expected: (f32, f32), actual: (f32, f32) },
+    NodeCount { expected: usize, actual: usize },
+    /// Node has a different parent or type / id / class (the DOM structure changed)
+    Structure { node_id: usize, expected: String, actual: String },
+    /// A value of the node differs by more than the tolerance
+    Value { node_id: usize, selector: String, field: String, expected: String, actual: String },
+}
+
+impl fmt::Display for LayoutDifference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::LayoutDifference::*;
+        match self {
+            RootSize { expected, actual } => write!(f, "root size: expected {}x{}, got {}x{}", expected.0, expected.1, actual.0, actual.1),
+            NodeCount { expected, actual } => write!(f, "node count: expected {}, got {}", expected, actual),
+            Structure { node_id, expected, actual } => write!(f, "node {}: expected {}, got {}", node_id, expected, actual),
+            Value { node_id, selector, field, expected, actual } => write!(f, "node {} ({}): {}: expected {}, got {}", node_id, selector, field, expected, actual),
+        }
+    }
+}
+
+impl LayoutSnapshot {
+
+    pub fn new(layout_result: &LayoutResult) -> Self {
+
+        let styled_dom = &layout_result.styled_dom;
+        let node_hierarchy = styled_dom.node_hierarchy.as_ref();
+        let node_data = styled_dom.node_data.as_ref();
+
+        let nodes = (0..node_hierarchy.len()).map(|i| {
+
+            let node_id = NodeId::new(i);
+            let data = &node_data[i];
+
+            let mut selector = data.get_node_type().get_path().to_string();
+            for id_or_class in data.get_ids_and_classes().as_ref().iter() {
+                match id_or_class {
+                    IdOrClass::Id(id) => { selector.push('#'); selector.push_str(id.as_str()); },
+                    IdOrClass::Class(class) => { selector.push('.'); selector.push_str(class.as_str()); },
+                }
+            }
+
+            let rect = SnapshotRect {
+                x: layout_result.solved_pos_x.as_ref()[node_id].0,
+                y: layout_result.solved_pos_y.as_ref()[node_id].0,
+                width: layout_result.width_calculated_rects.as_ref()[node_id].total(),
+                height: layout_result.height_calculated_rects.as_ref()[node_id].total(),
+            };
+
+            let overflow = layout_result.scrollable_nodes.overflowing_nodes
+                .get(&NodeHierarchyItemId::from_crate_internal(Some(node_id)))
+                .map(|o| SnapshotRect {
+                    x: o.child_rect.origin.x,
+                    y: o.child_rect.origin.y,
+                    width: o.child_rect.size.width,
+                    height: o.child_rect.size.height,
+                });
+
+            let lines = layout_result.rects.as_ref()[node_id].resolved_text_layout_options
+                .as_ref()
+                .map(|(_, inline_text_layout)| {
+                    inline_text_layout.lines.as_ref().iter().map(|line| SnapshotRect {
+                        x: line.bounds.origin.x,
+                        y: line.bounds.origin.y,
+                        width: line.bounds.size.width,
+                        height: line.bounds.size.height,
+                    }).collect()
+                })
+                .unwrap_or_default();
+
+            NodeLayoutSnapshot {
+                node_id: i,
+                parent: node_hierarchy[i].parent_id().map(|p| p.index()),
+                selector,
+                rect,
+                overflow,
+                lines,
+            }
+        }).collect();
+
+        Self {
+            width: layout_result.root_size.width as f32,
+            height: layout_result.root_size.height as f32,
+            nodes,
+        }
+    }
+
+    /// Serializes the snapshot as pretty-printed JSON, one node per line
+    pub fn to_json(&self) -> String {
+
+        fn rect_json(r: &SnapshotRect) -> String {
+            format!("{{ \"x\": {}, \"y\": {}, \"width\": {}, \"height\": {} }}", r.x, r.y, r.width, r.height)
+        }
+
+        let mut s = String::new();
+        s.push_str("{\n");
+        s.push_str(&format!("  \"width\": {},\n", self.width));
+        s.push_str(&format!("  \"height\": {},\n", self.height));
+        s.push_str("  \"nodes\": [\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            s.push_str(&format!(
+                "    {{ \"id\": {}, \"parent\": {}, \"selector\": {}, \"rect\": {}, \"overflow\": {}, \"lines\": [{}] }}",
+                node.node_id,
+                node.parent.map(|p| p.to_string()).unwrap_or_else(|| "null".to_string()),
+                json::escape(&node.selector),
+                rect_json(&node.rect),
+                node.overflow.as_ref().map(rect_json).unwrap_or_else(|| "null".to_string()),
+                node.lines.iter().map(rect_json).collect::<Vec<_>>().join(", "),
+            ));
+            s.push_str(if i + 1 == self.nodes.len() { "\n" } else { ",\n" });
+        }
+        s.push_str("  ]\n");
+        s.push_str("}\n");
+        s
+    }
+
+    /// Parses a snapshot that was written with `to_json`
+    pub fn from_json(source: &str) -> Result<Self, String> {
+
+        use self::json::JsonValue;
+
+        fn rect(v: &JsonValue) -> Result<SnapshotRect, String> {
+            Ok(SnapshotRect {
+                x: v.get("x")?.as_f32()?,
+                y: v.get("y")?.as_f32()?,
+                width: v.get("width")?.as_f32()?,
+                height: v.get("height")?.as_f32()?,
+            })
+        }
+
+        let root = json::parse(source)?;
+        let nodes = root.get("nodes")?.as_array()?.iter().map(|node| {
+            Ok(NodeLayoutSnapshot {
+                node_id: node.get("id")?.as_f32()? as usize,
+                parent: match node.get("parent")? {
+                    JsonValue::Null => None,
+                    p => Some(p.as_f32()? as usize),
+                },
+                selector: node.get("selector")?.as_str()?.to_string(),
+                rect: rect(node.get("rect")?)?,
+                overflow: match node.get("overflow")? {
+                    JsonValue::Null => None,
+                    o => Some(rect(o)?),
+                },
+                lines: node.get("lines")?.as_array()?.iter().map(rect).collect::<Result<_, _>>()?,
+            })
+        }).collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self {
+            width: root.get("width")?.as_f32()?,
+            height: root.get("height")?.as_f32()?,
+            nodes,
+        })
+    }
+
+    /// Compares the snapshot against the golden snapshot, positions and sizes
+    /// that differ by less than `tolerance` pixels are considered equal
+    pub fn compare(&self, golden: &Self, tolerance: f32) -> Vec<LayoutDifference> {
+
+        let mut differences = Vec::new();
+        let differs = |a: f32, b: f32| (a - b).abs() > tolerance;
+
+        if differs(self.width, golden.width) || differs(self.height, golden.height) {
+            differences.push(LayoutDifference::RootSize {
+                expected: (golden.width, golden.height),
+                actual: (self.width, self.height),
+            });
+        }
+
+        if self.nodes.len() != golden.nodes.len() {
+            differences.push(LayoutDifference::NodeCount {
+                expected: golden.nodes.len(),
+                actual: self.nodes.len(),
+            });
+        }
+
+        for (actual, expected) in self.nodes.iter().zip(golden.nodes.iter()) {
+
+            if actual.selector != expected.selector || actual.parent != expected.parent {
+                differences.push(LayoutDifference::Structure {
+                    node_id: actual.node_id,
+                    expected: format!("{} (parent: {:?})", expected.selector, expected.parent),
+                    actual: format!("{} (parent: {:?})", actual.selector, actual.parent),
+                });
+                continue;
+            }
+
+            let rect_differs = |a: &SnapshotRect, b: &SnapshotRect| {
+                differs(a.x, b.x) || differs(a.y, b.y) || differs(a.width, b.width) || differs(a.height, b.height)
+            };
+
+            let mut push = |field: String, expected_value: String, actual_value: String| {
+                differences.push(LayoutDifference::Value {
+                    node_id: actual.node_id,
+                    selector: actual.selector.clone(),
+                    field,
+                    expected: expected_value,
+                    actual: actual_value,
+                });
+            };
+
+            if rect_differs(&actual.rect, &expected.rect) {
+                push("rect".to_string(), expected.rect.to_string(), actual.rect.to_string());
+            }
+
+            match (actual.overflow.as_ref(), expected.overflow.as_ref()) {
+                (None, None) => { },
+                (Some(a), Some(e)) if !rect_differs(a, e) => { },
+                (a, e) => push(
+                    "overflow".to_string(),
+                    e.map(|e| e.to_string()).unwrap_or_else(|| "none".to_string()),
+                    a.map(|a| a.to_string()).unwrap_or_else(|| "none".to_string()),
+                ),
+            }
+
+            if actual.lines.len() != expected.lines.len() {
+                push("lines".to_string(), format!("{} lines", expected.lines.len()), format!("{} lines", actual.lines.len()));
+            } else {
+                for (i, (a, e)) in actual.lines.iter().zip(expected.lines.iter()).enumerate() {
+                    if rect_differs(a, e) {
+                        push(format!("lines[{}]", i), e.to_string(), a.to_string());
+                    }
+                }
+            }
+        }
+
+        differences
+    }
+}
+
+/// Compares the snapshot against the golden JSON file at `path`.
+///
+/// If the golden file doesn't exist or `update` is set, the golden file is
+/// (re-)written from the snapshot and no differences are returned.
+pub fn check_golden_file(
+    snapshot: &LayoutSnapshot,
+    path: &Path,
+    tolerance: f32,
+    update: bool,
+) -> Result<Vec<LayoutDifference>, LayoutSnapshotError> {
+
+    if update || !path.exists() {
+        std::fs::write(path, snapshot.to_json())
+            .map_err(|e| LayoutSnapshotError::Io(path.to_path_buf(), e.to_string()))?;
+        return Ok(Vec::new());
+    }
+
+    let source = std::fs::read_to_string(path)
+        .map_err(|e| LayoutSnapshotError::Io(path.to_path_buf(), e.to_string()))?;
+    let golden = LayoutSnapshot::from_json(&source)
+        .map_err(|e| LayoutSnapshotError::Parse(path.to_path_buf(), e))?;
+
+    Ok(snapshot.compare(&golden, tolerance))
+}
+
+/// Minimal JSON parser, only used to read back the golden files
+mod json {
+
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum JsonValue {
+        Null,
+        Bool(bool),
+        Number(f64),
+        String(String),
+        Array(Vec<JsonValue>),
+        Object(Vec<(String, JsonValue)>),
+    }
+
+    impl JsonValue {
+
+        pub fn get(&self, key: &str) -> Result<&JsonValue, String> {
+            match self {
+                JsonValue::Object(o) => o.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+                    .ok_or_else(|| format!("missing key \"{}\"", key)),
+                _ => Err(format!("expected object with key \"{}\"", key)),
+            }
+        }
+
+        pub fn as_f32(&self) -> Result<f32, String> {
+            match self {
+                JsonValue::Number(n) => Ok(*n as f32),
+                other => Err(format!("expected number, got {:?}", other)),
+            }
+        }
+
+        pub fn as_str(&self) -> Result<&str, String> {
+            match self {
+                JsonValue::String(s) => Ok(s.as_str()),
+                other => Err(format!("expected string, got {:?}", other)),
+            }
+        }
+
+        pub fn as_array(&self) -> Result<&[JsonValue], String> {
+            match self {
+                JsonValue::Array(a) => Ok(a.as_slice()),
+                other => Err(format!("expected array, got {:?}", other)),
+            }
+        }
+    }
+
+    pub fn escape(s: &str) -> String {
+        let mut out = String::with_capacity(s.len() + 2);
+        out.push('"');
+        for c in s.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+        out
+    }
+
+    pub fn parse(source: &str) -> Result<JsonValue, String> {
+        let mut parser = Parser { chars: source.chars().collect(), pos: 0 };
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        if parser.pos != parser.chars.len() {
+            return Err(format!("unexpected trailing characters at {}", parser.pos));
+        }
+        Ok(value)
+    }
+
+    struct Parser {
+        chars: Vec<char>,
+        pos: usize,
+    }
+
+    impl Parser {
+
+        fn skip_whitespace(&mut self) {
+            while self.chars.get(self.pos).map(|c| c.is_whitespace()).unwrap_or(false) {
+                self.pos += 1;
+            }
+        }
+
+        fn expect(&mut self, c: char) -> Result<(), String> {
+            self.skip_whitespace();
+            if self.chars.get(self.pos) == Some(&c) {
+                self.pos += 1;
+                Ok(())
+            } else {
+                Err(format!("expected '{}' at {}", c, self.pos))
+            }
+        }
+
+        fn parse_literal(&mut self, literal: &str, value: JsonValue) -> Result<JsonValue, String> {
+            let end = self.pos + literal.chars().count();
+            if end <= self.chars.len() && self.chars[self.pos..end].iter().copied().eq(literal.chars()) {
+                self.pos = end;
+                Ok(value)
+            } else {
+                Err(format!("unexpected character at {}", self.pos))
+            }
+        }
+
+        fn parse_value(&mut self) -> Result<JsonValue, String> {
+            self.skip_whitespace();
+            match self.chars.get(self.pos) {
+                None => Err("unexpected end of input".to_string()),
+                Some('n') => self.parse_literal("null", JsonValue::Null),
+                Some('t') => self.parse_literal("true", JsonValue::Bool(true)),
+                Some('f') => self.parse_literal("false", JsonValue::Bool(false)),
+                Some('"') => self.parse_string().map(JsonValue::String),
+                Some('[') => {
+                    self.pos += 1;
+                    let mut items = Vec::new();
+                    self.skip_whitespace();
+                    if self.chars.get(self.pos) == Some(&']') {
+                        self.pos += 1;
+                        return Ok(JsonValue::Array(items));
+                    }
+                    loop {
+                        items.push(self.parse_value()?);
+                        self.skip_whitespace();
+                        match self.chars.get(self.pos) {
+                            Some(',') => { self.pos += 1; },
+                            Some(']') => { self.pos += 1; return Ok(JsonValue::Array(items)); },
+                            _ => return Err(format!("expected ',' or ']' at {}", self.pos)),
+                        }
+                    }
+                },
+                Some('{') => {
+                    self.pos += 1;
+                    let mut fields = Vec::new();
+                    self.skip_whitespace();
+                    if self.chars.get(self.pos) == Some(&'}') {
+                        self.pos += 1;
+                        return Ok(JsonValue::Object(fields));
+                    }
+                    loop {
+                        self.skip_whitespace();
+                        let key = self.parse_string()?;
+                        self.expect(':')?;
+                        fields.push((key, self.parse_value()?));
+                        self.skip_whitespace();
+                        match self.chars.get(self.pos) {
+                            Some(',') => { self.pos += 1; },
+                            Some('}') => { self.pos += 1; return Ok(JsonValue::Object(fields)); },
+                            _ => return Err(format!("expected ',' or '}}' at {}", self.pos)),
+                        }
+                    }
+                },
+                Some(_) => {
+                    let start = self.pos;
+                    while self.chars.get(self.pos).map(|c| c.is_ascii_digit() || "+-.eE".contains(*c)).unwrap_or(false) {
+                        self.pos += 1;
+                    }
+                    let number = self.chars[start..self.pos].iter().collect::<String>();
+                    number.parse::<f64>().map(JsonValue::Number)
+                        .map_err(|_| format!("invalid number \"{}\" at {}", number, start))
+                },
+            }
+        }
+
+        fn parse_string(&mut self) -> Result<String, String> {
+            self.expect('"')?;
+            let mut s = String::new();
+            loop {
+                match self.chars.get(self.pos).copied() {
+                    None => return Err("unterminated string".to_string()),
+                    Some('"') => { self.pos += 1; return Ok(s); },
+                    Some('\\') => {
+                        let escaped = self.chars.get(self.pos + 1).copied();
+                        self.pos += 2;
+                        match escaped {
+                            Some('n') => s.push('\n'),
+                            Some('t') => s.push('\t'),
+                            Some('u') => {
+                                let hex = self.chars.get(self.pos..self.pos + 4)
+                                    .ok_or_else(|| "invalid unicode escape".to_string())?
+                                    .iter().collect::<String>();
+                                let c = u32::from_str_radix(&hex, 16).ok().and_then(core::char::from_u32)
+                                    .ok_or_else(|| format!("invalid unicode escape \"{}\"", hex))?;
+                                s.push(c);
+                                self.pos += 4;
+                            },
+                            Some(c) => s.push(c),
+                            None => return Err("unterminated string".to_string()),
+                        }
+                    },
+                    Some(c) => { s.push(c); self.pos += 1; },
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn snapshot() -> LayoutSnapshot {
+        LayoutSnapshot {
+            width: 600.0,
+            height: 100.0,
+            nodes: vec![
+                NodeLayoutSnapshot {
+                    node_id: 0,
+                    parent: None,
+                    selector: "body".to_string(),
+                    rect: SnapshotRect { x: 0.0, y: 0.0, width: 600.0, height: 100.0 },
+                    overflow: None,
+                    lines: Vec::new(),
+                },
+                NodeLayoutSnapshot {
+                    node_id: 1,
+                    parent: Some(0),
+                    selector: "div#foo".to_string(),
+                    rect: SnapshotRect { x: 0.0, y: 0.0, width: 100.0, height: 50.5 },
+                    overflow: Some(SnapshotRect { x: 0.0, y: 0.0, width: 100.0, height: 200.0 }),
+                    lines: vec![SnapshotRect { x: 0.0, y: 0.0, width: 80.0, height: 16.0 }],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let s = snapshot();
+        assert_eq!(LayoutSnapshot::from_json(&s.to_json()), Ok(s));
+    }
+
+    #[test]
+    fn test_compare_with_tolerance() {
+        let golden = snapshot();
+        let mut actual = snapshot();
+        actual.nodes[1].rect.width = 100.4;
+        assert!(actual.compare(&golden, 0.5).is_empty());
+
+        actual.nodes[1].rect.width = 120.0;
+        let differences = actual.compare(&golden, 0.5);
+        assert_eq!(differences.len(), 1);
+        assert_eq!(
+            differences[0].to_string(),
+            "node 1 (div#foo): rect: expected 100x50.5 @ (0, 0), got 120x50.5 @ (0, 0)",
+        );
+    }
+}
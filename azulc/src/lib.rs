@@ -28,8 +28,18 @@ pub mod xml;
 pub mod svg;
 #[cfg(feature = "font_loading")]
 pub mod font;
+#[cfg(all(feature = "std", feature = "font_loading"))]
+pub mod font_cache;
 #[cfg(feature = "image_loading")]
 pub mod image;
+/// Golden JSON snapshots of solved layouts
+#[cfg(feature = "std")]
+pub mod layout_snapshot;
+/// Parser for the subset of TOML used by configuration and manifest files
+pub mod toml;
+/// Reference tests: compare rendered XML files against reference images
+#[cfg(all(feature = "std", feature = "image_loading"))]
+pub mod reftest;
 /// Module for compiling CSS to Rust code
 pub mod css {
     pub use azul_core::css::*;
@@ -6,16 +6,18 @@ use std::fs;
 use std::path::Path;
 use std::process::exit;
 
+use azul_css::FloatValue;
+
 use azul_core::{
     gl::OptionGlContextPtr,
-    window::FullWindowState,
+    window::{FullWindowState, ScrollStates, ScrollbarInteraction},
     xml::{XmlComponentMap, XmlNode},
     window::LogicalSize,
     styled_dom::{StyledDom, DomId},
     callbacks::{PipelineId, DocumentId},
     ui_solver::LayoutResult,
     app_resources::{
-        IdNamespace, LoadFontFn,
+        IdNamespace, LoadFontFn, DpiScaleFactor,
         Epoch, RendererResources,
         ImageCache, GlTextureCache,
     },
@@ -38,6 +40,7 @@ enum Action {
     PrintDebugLayout(LogicalSize),
     PrintScrollClips(LogicalSize),
     PrintDisplayList(LogicalSize),
+    PrintLayoutJson(LogicalSize),
 }
 
 fn print_help() {
@@ -48,6 +51,7 @@ fn print_help() {
     eprintln!("    --debug-layout WIDTHxHEIGHT: print a debug output of the layout solver");
     eprintln!("    --display-list WIDTHxHEIGHT: print the display list given WIDTH and HEIGHT");
     eprintln!("    --scroll-clips WIDTHxHEIGHT: print the overflowing scroll clips given WIDTH and HEIGHT");
+    eprintln!("    --layout-json WIDTHxHEIGHT: print the solved layout as JSON (for golden layout snapshots)");
     eprintln!("    --cascade: print the cascaded styled DOM");
     eprintln!("");
     eprintln!("If OPTIONS is empty, the file will be printed to Rust code");
@@ -113,6 +117,18 @@ fn main() {
             };
             Action::PrintDisplayList(LogicalSize::new(size_parsed.0, size_parsed.1))
         },
+        Some("--layout-json")           => {
+            let size = env::args().nth(2).expect("no output size specified for layout snapshot");
+            let size_parsed = match azulc_lib::parse_display_list_size(&size) {
+                Some(s) => s,
+                None => {
+                    eprintln!("error: layout snapshot size \"{}\" could not be parsed", size);
+                    print_help();
+                    exit(-1);
+                }
+            };
+            Action::PrintLayoutJson(LogicalSize::new(size_parsed.0, size_parsed.1))
+        },
         _ => Action::PrintRustCode,
     };
 
@@ -226,6 +242,18 @@ fn process(action: Action, file: Option<&String>) {
             let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources);
             println!("{:#?}", layout.scrollable_nodes);
         },
+        Action::PrintLayoutJson(size) => {
+            let document_id = DocumentId {
+                namespace_id: IdNamespace(0),
+                id: 0,
+            };
+            let epoch = Epoch::new();
+            let mut fake_window_state = FullWindowState::default();
+            fake_window_state.size.dimensions = size;
+            let mut renderer_resources = RendererResources::default();
+            let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources);
+            print!("{}", azulc_lib::layout_snapshot::LayoutSnapshot::new(&layout).to_json());
+        },
         Action::PrintDisplayList(size) => {
             let epoch = Epoch::new();
             let document_id = DocumentId {
@@ -247,6 +275,8 @@ fn process(action: Action, file: Option<&String>) {
                 &GlTextureCache::default(),
                 &renderer_resources,
                 &image_cache,
+                &ScrollStates::default(),
+                &ScrollbarInteraction::default(),
             );
 
             println!("{:#?}", display_list.root);
@@ -287,6 +317,7 @@ fn solve_layout(
         &fc_cache,
         &callbacks,
         renderer_resources,
+        DpiScaleFactor { inner: FloatValue::new(fake_window_state.size.get_hidpi_factor()) },
     );
 
     solved_layout.layout_results.remove(0)
@@ -0,0 +1,309 @@
+//! Perceptual image comparison for reftests
+//!
+//! Pixels are compared in the YIQ color space (like pixelmatch), so that small
+//! differences that are not visible to the human eye are not counted. Pixels that
+//! only differ because of antialiasing can optionally be ignored. Additionally,
+//! the structural similarity index (SSIM) of the two images is computed, which
+//! is much less sensitive to "a lot of small differences" than a pixel count.
+
+use alloc::vec::Vec;
+use super::{ReftestImage, ReftestThreshold};
+
+/// Maximum possible YIQ delta between two colors
+const MAX_YIQ_DELTA: f32 = 35215.0;
+/// Size of the square windows that the SSIM is computed over
+const SSIM_WINDOW: u32 = 8;
+/// Distance between two SSIM windows, windows overlap by half
+const SSIM_STEP: u32 = SSIM_WINDOW / 2;
+
+/// Color of pixels that differ only by antialiasing in the diff image
+const COLOR_ANTIALIASED: [u8; 4] = [255, 255, 0, 255];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompareResult {
+    /// Number of pixels with a perceptual difference above `ReftestThreshold::color_threshold`
+    pub differing_pixels: usize,
+    /// Number of differing pixels that were detected as antialiasing (not counted in `differing_pixels`)
+    pub antialiased_pixels: usize,
+    /// Mean structural similarity of the two images, 1.0 = identical
+    pub ssim: f32,
+    /// Heatmap of the differences: the reference is drawn in faded grayscale,
+    /// differing pixels are drawn in orange (small difference) to red (large difference),
+    /// antialiased pixels are drawn in yellow
+    pub diff_image: ReftestImage,
+}
+
+impl CompareResult {
+    /// Whether the result is within the tolerances of the threshold
+    pub fn is_within(&self, threshold: &ReftestThreshold) -> bool {
+        self.differing_pixels <= threshold.max_differing_pixels &&
+        self.ssim >= threshold.min_ssim
+    }
+}
+
+/// Compares the two images, returns `None` if they have different dimensions
+pub fn compare_images(rendered: &ReftestImage, reference: &ReftestImage, threshold: &ReftestThreshold) -> Option<CompareResult> {
+
+    if rendered.width != reference.width || rendered.height != reference.height {
+        return None;
+    }
+
+    let width = rendered.width;
+    let height = rendered.height;
+    let max_delta = MAX_YIQ_DELTA * threshold.color_threshold * threshold.color_threshold;
+
+    let mut diff_pixels = Vec::with_capacity(reference.pixels.len());
+    let mut differing_pixels = 0;
+    let mut antialiased_pixels = 0;
+
+    for y in 0..height {
+        for x in 0..width {
+            let a = get_pixel(rendered, x, y);
+            let b = get_pixel(reference, x, y);
+            let delta = color_delta(a, b, false);
+
+            let color = if delta.abs() > max_delta {
+                if threshold.ignore_antialiasing && (
+                    is_antialiased(rendered, reference, x, y) ||
+                    is_antialiased(reference, rendered, x, y)
+                ) {
+                    antialiased_pixels += 1;
+                    COLOR_ANTIALIASED
+                } else {
+                    differing_pixels += 1;
+                    let t = (delta.abs() / MAX_YIQ_DELTA).sqrt().min(1.0);
+                    [255, ((1.0 - t) * 160.0) as u8, 0, 255]
+                }
+            } else {
+                let gray = blend_white(luminance(b), b[3] as f32 * 0.1) as u8;
+                [gray, gray, gray, 255]
+            };
+
+            diff_pixels.extend_from_slice(&color);
+        }
+    }
+
+    Some(CompareResult {
+        differing_pixels,
+        antialiased_pixels,
+        ssim: ssim(rendered, reference),
+        diff_image: ReftestImage { width, height, pixels: diff_pixels },
+    })
+}
+
+/// Computes the mean SSIM of the luminance of the two images (which must have the same size)
+pub fn ssim(a: &ReftestImage, b: &ReftestImage) -> f32 {
+
+    const C1: f64 = (0.01 * 255.0) * (0.01 * 255.0);
+    const C2: f64 = (0.03 * 255.0) * (0.03 * 255.0);
+
+    let luma_a = get_luminance_plane(a);
+    let luma_b = get_luminance_plane(b);
+
+    let window_w = SSIM_WINDOW.min(a.width);
+    let window_h = SSIM_WINDOW.min(a.height);
+    if window_w == 0 || window_h == 0 {
+        return 1.0;
+    }
+
+    let mut total = 0.0;
+    let mut windows = 0;
+
+    let mut wy = 0;
+    while wy + window_h <= a.height {
+        let mut wx = 0;
+        while wx + window_w <= a.width {
+
+            let n = (window_w * window_h) as f64;
+            let (mut sum_a, mut sum_b) = (0.0, 0.0);
+            let (mut sum_aa, mut sum_bb, mut sum_ab) = (0.0, 0.0, 0.0);
+
+            for y in wy..(wy + window_h) {
+                for x in wx..(wx + window_w) {
+                    let i = (y * a.width + x) as usize;
+                    let (pa, pb) = (luma_a[i], luma_b[i]);
+                    sum_a += pa;
+                    sum_b += pb;
+                    sum_aa += pa * pa;
+                    sum_bb += pb * pb;
+                    sum_ab += pa * pb;
+                }
+            }
+
+            let mean_a = sum_a / n;
+            let mean_b = sum_b / n;
+            let var_a = sum_aa / n - mean_a * mean_a;
+            let var_b = sum_bb / n - mean_b * mean_b;
+            let covar = sum_ab / n - mean_a * mean_b;
+
+            total += ((2.0 * mean_a * mean_b + C1) * (2.0 * covar + C2)) /
+                     ((mean_a * mean_a + mean_b * mean_b + C1) * (var_a + var_b + C2));
+            windows += 1;
+
+            wx += SSIM_STEP;
+        }
+        wy += SSIM_STEP;
+    }
+
+    (total / windows as f64) as f32
+}
+
+fn get_luminance_plane(image: &ReftestImage) -> Vec<f64> {
+    image.pixels.chunks_exact(4).map(|p| {
+        let alpha = p[3] as f32;
+        let rgb = [p[0], p[1], p[2], 255];
+        blend_white(luminance(rgb), alpha) as f64
+    }).collect()
+}
+
+#[inline]
+fn get_pixel(image: &ReftestImage, x: u32, y: u32) -> [u8; 4] {
+    let i = ((y * image.width + x) * 4) as usize;
+    [image.pixels[i], image.pixels[i + 1], image.pixels[i + 2], image.pixels[i + 3]]
+}
+
+/// Blends a color channel with a white background
+#[inline]
+fn blend_white(c: f32, alpha: f32) -> f32 {
+    255.0 + (c - 255.0) * (alpha / 255.0)
+}
+
+#[inline]
+fn luminance(p: [u8; 4]) -> f32 {
+    p[0] as f32 * 0.29889531 + p[1] as f32 * 0.58662247 + p[2] as f32 * 0.11448223
+}
+
+/// Squared YIQ distance between two colors, negative if `b` is brighter than `a`.
+/// If `luminance_only` is set, only the brightness difference is returned.
+fn color_delta(a: [u8; 4], b: [u8; 4], luminance_only: bool) -> f32 {
+
+    if a == b {
+        return 0.0;
+    }
+
+    let blend = |p: [u8; 4]| {
+        let alpha = p[3] as f32;
+        [
+            blend_white(p[0] as f32, alpha),
+            blend_white(p[1] as f32, alpha),
+            blend_white(p[2] as f32, alpha),
+        ]
+    };
+
+    let [r1, g1, b1] = blend(a);
+    let [r2, g2, b2] = blend(b);
+
+    let y1 = r1 * 0.29889531 + g1 * 0.58662247 + b1 * 0.11448223;
+    let y2 = r2 * 0.29889531 + g2 * 0.58662247 + b2 * 0.11448223;
+    let dy = y1 - y2;
+
+    if luminance_only {
+        return dy;
+    }
+
+    let di = (r1 * 0.59597799 - g1 * 0.27417610 - b1 * 0.32180189) -
+             (r2 * 0.59597799 - g2 * 0.27417610 - b2 * 0.32180189);
+    let dq = (r1 * 0.21147017 - g1 * 0.52261711 + b1 * 0.31114694) -
+             (r2 * 0.21147017 - g2 * 0.52261711 + b2 * 0.31114694);
+
+    let delta = 0.5053 * dy * dy + 0.299 * di * di + 0.1957 * dq * dq;
+    if y1 > y2 { -delta } else { delta }
+}
+
+/// Returns the coordinates of the 3x3 neighborhood of the pixel (excluding the pixel itself),
+/// and whether the pixel is on the edge of the image
+fn neighbors(image: &ReftestImage, x: u32, y: u32) -> (impl Iterator<Item = (u32, u32)>, bool) {
+    let x0 = x.saturating_sub(1);
+    let y0 = y.saturating_sub(1);
+    let x1 = (x + 1).min(image.width - 1);
+    let y1 = (y + 1).min(image.height - 1);
+    let on_edge = x == x0 || x == x1 || y == y0 || y == y1;
+    let iter = (y0..=y1)
+        .flat_map(move |ny| (x0..=x1).map(move |nx| (nx, ny)))
+        .filter(move |(nx, ny)| *nx != x || *ny != y);
+    (iter, on_edge)
+}
+
+/// Whether the pixel has more than two identical neighbors
+fn has_many_siblings(image: &ReftestImage, x: u32, y: u32) -> bool {
+    let color = get_pixel(image, x, y);
+    let (neighbors, on_edge) = neighbors(image, x, y);
+    let mut identical = if on_edge { 1 } else { 0 };
+    for (nx, ny) in neighbors {
+        if get_pixel(image, nx, ny) == color {
+            identical += 1;
+            if identical > 2 {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Whether the pixel in `image` is likely an antialiased edge pixel: it sits between
+/// a darker and a brighter neighbor, which are both part of a solid area in both images
+fn is_antialiased(image: &ReftestImage, other: &ReftestImage, x: u32, y: u32) -> bool {
+
+    let color = get_pixel(image, x, y);
+    let (neighbors, on_edge) = neighbors(image, x, y);
+
+    let mut identical = if on_edge { 1 } else { 0 };
+    let mut darkest = (0.0, x, y);
+    let mut brightest = (0.0, x, y);
+
+    for (nx, ny) in neighbors {
+        let delta = color_delta(color, get_pixel(image, nx, ny), true);
+        if delta == 0.0 {
+            identical += 1;
+            if identical > 2 {
+                return false;
+            }
+        } else if delta < darkest.0 {
+            darkest = (delta, nx, ny);
+        } else if delta > brightest.0 {
+            brightest = (delta, nx, ny);
+        }
+    }
+
+    if darkest.0 == 0.0 || brightest.0 == 0.0 {
+        return false;
+    }
+
+    (has_many_siblings(image, darkest.1, darkest.2) && has_many_siblings(other, darkest.1, darkest.2)) ||
+    (has_many_siblings(image, brightest.1, brightest.2) && has_many_siblings(other, brightest.1, brightest.2))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn solid(width: u32, height: u32, color: [u8; 4]) -> ReftestImage {
+        ReftestImage {
+            width,
+            height,
+            pixels: color.iter().copied().cycle().take((width * height * 4) as usize).collect(),
+        }
+    }
+
+    #[test]
+    fn test_identical_images() {
+        let a = solid(16, 16, [12, 34, 56, 255]);
+        let result = compare_images(&a, &a, &ReftestThreshold::default()).unwrap();
+        assert_eq!(result.differing_pixels, 0);
+        assert!((result.ssim - 1.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_small_differences_are_ignored() {
+        let a = solid(16, 16, [200, 200, 200, 255]);
+        let mut b = a.clone();
+        b.pixels[0] = 202;
+        let mut c = a.clone();
+        c.pixels[0] = 0;
+
+        let threshold = ReftestThreshold::default();
+        assert_eq!(compare_images(&a, &b, &threshold).unwrap().differing_pixels, 0);
+        assert_eq!(compare_images(&a, &c, &threshold).unwrap().differing_pixels, 1);
+    }
+}
@@ -0,0 +1,225 @@
+//! Parser for the `manifest.toml` file next to the reftest files
+//!
+//! The manifest overrides the comparison thresholds for all tests (`[default]`)
+//! or for a single test (`[test-name]`, the file name without extension).
+//! Tests can also be marked as expected failures (for CSS features that are not
+//! implemented yet), skipped (on all or on specific platforms) and tagged:
+//!
+//! ```toml
+//! [default]
+//! max-differing-pixels = 100
+//!
+//! ["002-absolute-positioning"]
+//! color-threshold = 0.2
+//! min-ssim = 0.95
+//! ignore-antialiasing = false
+//! tags = ["position"]
+//!
+//! ["003-flex-wrap"]
+//! expected-fail = true
+//! skip = ["macos"]        # or skip = true to skip on all platforms
+//! ```
+//!
+//! Only the subset of TOML that is parsed by `crate::toml` is supported.
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use std::path::{Path, PathBuf};
+use super::{ReftestConfig, ReftestError, ReftestThreshold};
+use crate::toml::{parse_lines, TomlLine, TomlValue};
+
+/// File name of the manifest in the test directory
+pub const MANIFEST_FILE_NAME: &str = "manifest.toml";
+
+/// Section name of the entry that applies to all tests
+const DEFAULT_SECTION: &str = "default";
+
+/// On which platforms a test is skipped
+#[derive(Debug, Clone, PartialEq)]
+pub enum SkipCondition {
+    Never,
+    Always,
+    /// Skip if `std::env::consts::OS` (or `ReftestConfig::platform`) is one of the platforms
+    Platforms(Vec<String>),
+}
+
+impl Default for SkipCondition {
+    fn default() -> Self { SkipCondition::Never }
+}
+
+impl SkipCondition {
+    pub fn is_skipped_on(&self, platform: &str) -> bool {
+        match self {
+            SkipCondition::Never => false,
+            SkipCondition::Always => true,
+            SkipCondition::Platforms(p) => p.iter().any(|p| p == platform),
+        }
+    }
+}
+
+/// Settings of a single test (or of all tests, for the `[default]` section)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ManifestEntry {
+    pub color_threshold: Option<f32>,
+    pub ignore_antialiasing: Option<bool>,
+    pub max_differing_pixels: Option<usize>,
+    pub min_ssim: Option<f32>,
+    /// Test is known to fail, a failure is not reported as a regression
+    pub expected_fail: bool,
+    pub skip: SkipCondition,
+    /// Tags to select a subset of the tests with `ReftestConfig::tags`
+    pub tags: Vec<String>,
+}
+
+impl ManifestEntry {
+
+    /// Overrides the fields of the threshold that are set in the manifest
+    pub fn apply(&self, mut threshold: ReftestThreshold) -> ReftestThreshold {
+        if let Some(s) = self.color_threshold { threshold.color_threshold = s; }
+        if let Some(s) = self.ignore_antialiasing { threshold.ignore_antialiasing = s; }
+        if let Some(s) = self.max_differing_pixels { threshold.max_differing_pixels = s; }
+        if let Some(s) = self.min_ssim { threshold.min_ssim = s; }
+        threshold
+    }
+
+    fn set(&mut self, key: &str, value: TomlValue) -> Result<(), String> {
+        match (key, value) {
+            ("color-threshold", TomlValue::Number(n)) => { self.color_threshold = Some(n as f32); },
+            ("ignore-antialiasing", TomlValue::Bool(b)) => { self.ignore_antialiasing = Some(b); },
+            ("max-differing-pixels", TomlValue::Number(n)) if n >= 0.0 => { self.max_differing_pixels = Some(n as usize); },
+            ("min-ssim", TomlValue::Number(n)) => { self.min_ssim = Some(n as f32); },
+            ("expected-fail", TomlValue::Bool(b)) => { self.expected_fail = b; },
+            ("skip", TomlValue::Bool(true)) => { self.skip = SkipCondition::Always; },
+            ("skip", TomlValue::Bool(false)) => { self.skip = SkipCondition::Never; },
+            ("skip", TomlValue::Array(platforms)) => { self.skip = SkipCondition::Platforms(platforms); },
+            ("tags", TomlValue::Array(tags)) => { self.tags = tags; },
+            (key, value) => { return Err(format!("invalid key or value: {} = {:?}", key, value)); },
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ReftestManifest {
+    /// Settings from the `[default]` section
+    pub default: ManifestEntry,
+    /// Per-test settings, indexed by test name
+    pub tests: BTreeMap<String, ManifestEntry>,
+}
+
+impl ReftestManifest {
+
+    /// Loads `manifest.toml` from the test directory, returns an empty manifest if the file doesn't exist
+    pub fn load(test_directory: &Path) -> Result<Self, ReftestError> {
+        let path = test_directory.join(MANIFEST_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let source = std::fs::read_to_string(&path)
+            .map_err(|e| ReftestError::Io(path.clone(), e.to_string()))?;
+        Self::parse(&source).map_err(|(line, e)| ReftestError::Manifest(path, line, e))
+    }
+
+    /// Parses the manifest, returns the (1-based) line number and the error message on failure
+    pub fn parse(source: &str) -> Result<Self, (usize, String)> {
+
+        let mut manifest = Self::default();
+        let mut current_section: Option<String> = None;
+
+        for (line_number, line, parsed) in parse_lines(source) {
+
+            let (key, value) = match parsed.map_err(|e| (line_number, format!("{}: {}", e, line)))? {
+                TomlLine::Section(section) => {
+                    current_section = Some(section);
+                    continue;
+                },
+                TomlLine::KeyValue(key, value) => (key, value),
+            };
+
+            let entry = match current_section.as_deref() {
+                None => return Err((line_number, format!("key \"{}\" outside of a section", key))),
+                Some(DEFAULT_SECTION) => &mut manifest.default,
+                Some(test) => manifest.tests.entry(test.to_string()).or_insert_with(ManifestEntry::default),
+            };
+
+            entry.set(&key, value).map_err(|e| (line_number, e))?;
+        }
+
+        Ok(manifest)
+    }
+
+    /// Returns the entry of the test (with the expectations / tags of the test itself),
+    /// falls back to the `[default]` entry
+    pub fn get_entry(&self, test_name: &str) -> &ManifestEntry {
+        self.tests.get(test_name).unwrap_or(&self.default)
+    }
+
+    /// Returns a copy of the config with the thresholds of the manifest applied,
+    /// thresholds that are explicitly set in `ReftestConfig::test_thresholds` take precedence
+    pub fn apply_to_config(&self, config: &ReftestConfig) -> ReftestConfig {
+        let mut config = config.clone();
+        config.default_threshold = self.default.apply(config.default_threshold);
+        for (test_name, entry) in self.tests.iter() {
+            if !config.test_thresholds.contains_key(test_name) {
+                let threshold = entry.apply(config.default_threshold);
+                config.test_thresholds.insert(test_name.clone(), threshold);
+            }
+        }
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest = ReftestManifest::parse(r#"
+            # thresholds for all tests
+            [default]
+            max-differing-pixels = 100
+
+            ["002-absolute-positioning"] # antialiased borders
+            color-threshold = 0.2
+            ignore-antialiasing = false
+        "#).unwrap();
+
+        assert_eq!(manifest.default.max_differing_pixels, Some(100));
+        let entry = manifest.tests.get("002-absolute-positioning").unwrap();
+        assert_eq!(entry.color_threshold, Some(0.2));
+        assert_eq!(entry.ignore_antialiasing, Some(false));
+        assert_eq!(entry.min_ssim, None);
+
+        let config = manifest.apply_to_config(&ReftestConfig::default());
+        assert_eq!(config.get_threshold("001-basic-sizes").max_differing_pixels, 100);
+        assert_eq!(config.get_threshold("002-absolute-positioning").max_differing_pixels, 100);
+        assert_eq!(config.get_threshold("002-absolute-positioning").ignore_antialiasing, false);
+    }
+
+    #[test]
+    fn test_parse_expectations() {
+        let manifest = ReftestManifest::parse(r#"
+            [flex-wrap]
+            expected-fail = true
+            skip = ["macos", "ios"]
+            tags = ["flex"]
+        "#).unwrap();
+
+        let entry = manifest.get_entry("flex-wrap");
+        assert!(entry.expected_fail);
+        assert!(entry.skip.is_skipped_on("macos"));
+        assert!(!entry.skip.is_skipped_on("linux"));
+        assert_eq!(entry.tags, vec!["flex".to_string()]);
+        assert!(!manifest.get_entry("other").expected_fail);
+    }
+
+    #[test]
+    fn test_parse_manifest_errors() {
+        assert_eq!(ReftestManifest::parse("max-differing-pixels = 1").unwrap_err().0, 1);
+        assert_eq!(ReftestManifest::parse("[default]\nmin-ssim = \"high\"").unwrap_err().0, 2);
+        assert_eq!(ReftestManifest::parse("[default]\nunknown-key = 1").unwrap_err().0, 2);
+    }
+}
@@ -0,0 +1,672 @@
+//! Reference tests: renders XML test files and compares the result against
+//! reference images, so that widget libraries built on azul can be tested
+//! against a browser (Chrome, servo) or against a folder of pre-rendered images.
+//!
+//! The renderer (how to turn the XML into pixels) and the reference provider
+//! (where the expected image comes from) are traits, so that they can be
+//! swapped out, for example to render with a headless OpenGL context in CI.
+
+#![cfg(all(feature = "std", feature = "image_loading"))]
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+pub mod compare;
+pub mod manifest;
+
+pub use self::compare::{compare_images, CompareResult};
+pub use self::manifest::{ManifestEntry, ReftestManifest, SkipCondition};
+
+/// RGBA8 image, either rendered by a `ReftestRenderer` or loaded by a `ReferenceProvider`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReftestImage {
+    pub width: u32,
+    pub height: u32,
+    /// Non-premultiplied RGBA pixels, row by row, `width * height * 4` bytes
+    pub pixels: Vec<u8>,
+}
+
+impl ReftestImage {
+
+    /// Decodes a PNG / JPEG / BMP / ... file (depending on the enabled codecs)
+    pub fn load(path: &Path) -> Result<Self, ReftestError> {
+        let image = image_crate::open(path)
+            .map_err(|e| ReftestError::Image(path.to_path_buf(), e.to_string()))?
+            .into_rgba8();
+        Ok(Self {
+            width: image.width(),
+            height: image.height(),
+            pixels: image.into_raw(),
+        })
+    }
+
+    /// Encodes the image as a PNG file, requires the `png` feature
+    pub fn save(&self, path: &Path) -> Result<(), ReftestError> {
+        image_crate::save_buffer(path, &self.pixels, self.width, self.height, image_crate::ColorType::Rgba8)
+            .map_err(|e| ReftestError::Image(path.to_path_buf(), e.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReftestError {
+    /// Could not read or write a file / directory
+    Io(PathBuf, String),
+    /// Image could not be decoded or encoded
+    Image(PathBuf, String),
+    /// External reference command could not be started or returned an error
+    Command(String, String),
+    /// No reference image exists for the given test
+    MissingReference(PathBuf),
+    /// Error returned by the `ReftestRenderer`
+    Render(String),
+    /// Syntax error in the `manifest.toml` file (path, line, error)
+    Manifest(PathBuf, usize, String),
+}
+
+impl fmt::Display for ReftestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::ReftestError::*;
+        match self {
+            Io(path, e) => write!(f, "I/O error on \"{}\": {}", path.display(), e),
+            Image(path, e) => write!(f, "could not load or save image \"{}\": {}", path.display(), e),
+            Command(program, e) => write!(f, "reference command \"{}\" failed: {}", program, e),
+            MissingReference(path) => write!(f, "missing reference image \"{}\"", path.display()),
+            Render(e) => write!(f, "render error: {}", e),
+            Manifest(path, line, e) => write!(f, "error in \"{}\" on line {}: {}", path.display(), line, e),
+        }
+    }
+}
+
+impl std::error::Error for ReftestError { }
+
+/// Test file that is rendered by the renderer and the reference provider
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReftestInput {
+    /// Name of the test (file name without extension)
+    pub name: String,
+    /// Path to the XML file of the test
+    pub path: PathBuf,
+    /// Contents of the XML file
+    pub source: String,
+}
+
+/// Size + DPI that a test is rendered at
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RenderConfig {
+    /// Width of the viewport in logical pixels
+    pub width: u32,
+    /// Height of the viewport in logical pixels
+    pub height: u32,
+    /// HiDPI factor, the rendered image has a size of `width * scale_factor`
+    pub scale_factor: f32,
+}
+
+impl RenderConfig {
+
+    /// Size of the rendered image in physical pixels
+    pub fn physical_size(&self) -> (u32, u32) {
+        (
+            (self.width as f32 * self.scale_factor).round() as u32,
+            (self.height as f32 * self.scale_factor).round() as u32,
+        )
+    }
+
+    /// Suffix used in file names, i.e. "600x100@1x"
+    pub fn file_suffix(&self) -> String {
+        format!("{}x{}@{}x", self.width, self.height, self.scale_factor)
+    }
+}
+
+impl fmt::Display for RenderConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}x{} @ {}x", self.width, self.height, self.scale_factor)
+    }
+}
+
+/// Renders a test file with the library that is being tested
+pub trait ReftestRenderer {
+    fn render(&self, test: &ReftestInput, config: &RenderConfig) -> Result<ReftestImage, ReftestError>;
+}
+
+/// Returns the expected image for a test file
+pub trait ReferenceProvider {
+    /// Name of the reference, used in the test output
+    fn name(&self) -> &str;
+    fn get_reference(&self, test: &ReftestInput, config: &RenderConfig) -> Result<ReftestImage, ReftestError>;
+}
+
+/// Loads pre-rendered reference images from a folder.
+///
+/// For each test, the provider first looks for `<test>-<width>x<height>@<scale>x.png`,
+/// then falls back to `<test>.png` (for tests that are only rendered at one size)
+#[derive(Debug, Clone, PartialEq)]
+pub struct FolderReferenceProvider {
+    pub directory: PathBuf,
+}
+
+impl FolderReferenceProvider {
+    pub fn new<P: Into<PathBuf>>(directory: P) -> Self {
+        Self { directory: directory.into() }
+    }
+}
+
+impl ReferenceProvider for FolderReferenceProvider {
+
+    fn name(&self) -> &str {
+        "folder"
+    }
+
+    fn get_reference(&self, test: &ReftestInput, config: &RenderConfig) -> Result<ReftestImage, ReftestError> {
+        let sized = self.directory.join(format!("{}-{}.png", test.name, config.file_suffix()));
+        if sized.exists() {
+            return ReftestImage::load(&sized);
+        }
+        let unsized_path = self.directory.join(format!("{}.png", test.name));
+        if unsized_path.exists() {
+            return ReftestImage::load(&unsized_path);
+        }
+        Err(ReftestError::MissingReference(sized))
+    }
+}
+
+/// Renders the reference image by running an external program
+/// (headless browser), which writes a screenshot to a temporary file.
+///
+/// The arguments may contain the placeholders `{input}` (path to the test file),
+/// `{output}` (path of the screenshot), `{width}`, `{height}` and `{scale}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandReferenceProvider {
+    pub name: String,
+    pub program: String,
+    pub args: Vec<String>,
+    /// Directory to write the screenshots to, defaults to the temp directory
+    pub output_directory: Option<PathBuf>,
+}
+
+impl CommandReferenceProvider {
+
+    pub fn new(name: &str, program: &str, args: &[&str]) -> Self {
+        Self {
+            name: name.to_string(),
+            program: program.to_string(),
+            args: args.iter().map(|s| s.to_string()).collect(),
+            output_directory: None,
+        }
+    }
+
+    /// Renders the reference with headless Chrome / Chromium, `program` is the path to the executable
+    pub fn chrome(program: &str) -> Self {
+        Self::new("chrome", program, &[
+            "--headless",
+            "--disable-gpu",
+            "--hide-scrollbars",
+            "--force-device-scale-factor={scale}",
+            "--window-size={width},{height}",
+            "--screenshot={output}",
+            "file://{input}",
+        ])
+    }
+
+    /// Renders the reference with servo, `program` is the path to the executable
+    pub fn servo(program: &str) -> Self {
+        Self::new("servo", program, &[
+            "--headless",
+            "--exit",
+            "--resolution={width}x{height}",
+            "--device-pixel-ratio={scale}",
+            "--output={output}",
+            "file://{input}",
+        ])
+    }
+}
+
+impl ReferenceProvider for CommandReferenceProvider {
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_reference(&self, test: &ReftestInput, config: &RenderConfig) -> Result<ReftestImage, ReftestError> {
+
+        let output_directory = self.output_directory.clone().unwrap_or_else(std::env::temp_dir);
+        let output = output_directory.join(format!("{}-{}-{}.png", test.name, self.name, config.file_suffix()));
+        let input = test.path.canonicalize().unwrap_or_else(|_| test.path.clone());
+
+        let args = self.args.iter().map(|arg| {
+            arg
+            .replace("{input}", &input.display().to_string())
+            .replace("{output}", &output.display().to_string())
+            .replace("{width}", &config.width.to_string())
+            .replace("{height}", &config.height.to_string())
+            .replace("{scale}", &config.scale_factor.to_string())
+        }).collect::<Vec<_>>();
+
+        let result = Command::new(&self.program)
+            .args(&args)
+            .output()
+            .map_err(|e| ReftestError::Command(self.program.clone(), e.to_string()))?;
+
+        if !result.status.success() {
+            return Err(ReftestError::Command(
+                self.program.clone(),
+                String::from_utf8_lossy(&result.stderr).to_string(),
+            ));
+        }
+
+        ReftestImage::load(&output)
+    }
+}
+
+/// When a rendered image is considered equal to the reference
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ReftestThreshold {
+    /// Perceptual color difference (0.0 - 1.0) above which two pixels are considered different
+    pub color_threshold: f32,
+    /// Whether pixels that only differ because of antialiasing are ignored
+    pub ignore_antialiasing: bool,
+    /// Maximum number of pixels that may differ before the test fails
+    pub max_differing_pixels: usize,
+    /// Minimum structural similarity (0.0 - 1.0) of the rendered image and the reference
+    pub min_ssim: f32,
+}
+
+impl Default for ReftestThreshold {
+    fn default() -> Self {
+        Self {
+            color_threshold: 0.1,
+            ignore_antialiasing: true,
+            max_differing_pixels: 1000,
+            min_ssim: 0.95,
+        }
+    }
+}
+
+/// Configuration of a reftest run
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReftestConfig {
+    /// Logical viewport sizes that each test is rendered at
+    pub sizes: Vec<(u32, u32)>,
+    /// HiDPI factors that each size is rendered at
+    pub scale_factors: Vec<f32>,
+    /// Threshold for tests that don't have an entry in `test_thresholds`
+    pub default_threshold: ReftestThreshold,
+    /// Per-test thresholds, indexed by test name
+    pub test_thresholds: BTreeMap<String, ReftestThreshold>,
+    /// Platform name that is matched against the `skip` list of the manifest,
+    /// defaults to `std::env::consts::OS`
+    pub platform: String,
+    /// Only run tests that have at least one of these tags in the manifest (empty = run all tests)
+    pub tags: Vec<String>,
+    /// If set, the rendered image, the reference image and the diff image
+    /// of failed tests are written to this directory
+    pub output_directory: Option<PathBuf>,
+}
+
+impl Default for ReftestConfig {
+    fn default() -> Self {
+        Self {
+            sizes: vec![(800, 600)],
+            scale_factors: vec![1.0],
+            default_threshold: ReftestThreshold::default(),
+            test_thresholds: BTreeMap::new(),
+            platform: std::env::consts::OS.to_string(),
+            tags: Vec::new(),
+            output_directory: None,
+        }
+    }
+}
+
+impl ReftestConfig {
+
+    /// Returns the size x DPI matrix that every test is rendered at
+    pub fn get_render_configs(&self) -> Vec<RenderConfig> {
+        self.sizes.iter().flat_map(|(width, height)| {
+            self.scale_factors.iter().map(move |scale_factor| RenderConfig {
+                width: *width,
+                height: *height,
+                scale_factor: *scale_factor,
+            })
+        }).collect()
+    }
+
+    pub fn get_threshold(&self, test_name: &str) -> ReftestThreshold {
+        self.test_thresholds.get(test_name).copied().unwrap_or(self.default_threshold)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReftestOutcome {
+    Passed { differing_pixels: usize, ssim: f32 },
+    Failed { differing_pixels: usize, ssim: f32 },
+    /// Rendered image and reference have different dimensions
+    SizeMismatch { rendered: (u32, u32), reference: (u32, u32) },
+    /// Test could not be rendered or the reference could not be loaded
+    Error(ReftestError),
+    /// Test is skipped on this platform in the manifest
+    Skipped,
+}
+
+impl ReftestOutcome {
+    pub fn is_pass(&self) -> bool {
+        match self {
+            ReftestOutcome::Passed { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+/// Outcome of a test compared to the expectation in the manifest
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ReftestStatus {
+    Pass,
+    /// Test fails, but is not marked as `expected-fail`
+    Regression,
+    /// Test fails and is marked as `expected-fail`
+    ExpectedFailure,
+    /// Test passes, but is marked as `expected-fail` - the annotation should be removed
+    NewlyPassing,
+    Skipped,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReftestResult {
+    pub test_name: String,
+    pub config: RenderConfig,
+    pub outcome: ReftestOutcome,
+    /// Whether the test is marked as `expected-fail` in the manifest
+    pub expected_fail: bool,
+}
+
+impl ReftestResult {
+    pub fn status(&self) -> ReftestStatus {
+        match (&self.outcome, self.expected_fail) {
+            (ReftestOutcome::Skipped, _) => ReftestStatus::Skipped,
+            (o, false) if o.is_pass() => ReftestStatus::Pass,
+            (o, true) if o.is_pass() => ReftestStatus::NewlyPassing,
+            (_, false) => ReftestStatus::Regression,
+            (_, true) => ReftestStatus::ExpectedFailure,
+        }
+    }
+}
+
+impl fmt::Display for ReftestResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.outcome {
+            ReftestOutcome::Passed { differing_pixels, ssim } => write!(f, "PASS {} ({}): {} pixels differ, SSIM {:.4}", self.test_name, self.config, differing_pixels, ssim),
+            ReftestOutcome::Failed { differing_pixels, ssim } => write!(f, "FAIL {} ({}): {} pixels differ, SSIM {:.4}", self.test_name, self.config, differing_pixels, ssim),
+            ReftestOutcome::SizeMismatch { rendered, reference } => write!(f, "FAIL {} ({}): rendered image is {}x{}, reference is {}x{}",
+                self.test_name, self.config, rendered.0, rendered.1, reference.0, reference.1),
+            ReftestOutcome::Error(e) => write!(f, "ERROR {} ({}): {}", self.test_name, self.config, e),
+            ReftestOutcome::Skipped => write!(f, "SKIP {} ({})", self.test_name, self.config),
+        }?;
+        if self.expected_fail {
+            write!(f, " [expected-fail]")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the number of pixels where any channel differs by more than `channel_tolerance`
+/// or `None` if the images have different dimensions
+pub fn count_differing_pixels(a: &ReftestImage, b: &ReftestImage, channel_tolerance: u8) -> Option<usize> {
+
+    if a.width != b.width || a.height != b.height {
+        return None;
+    }
+
+    let differing = a.pixels.chunks_exact(4)
+        .zip(b.pixels.chunks_exact(4))
+        .filter(|(pa, pb)| {
+            pa.iter().zip(pb.iter()).any(|(ca, cb)| (*ca as i16 - *cb as i16).abs() > channel_tolerance as i16)
+        })
+        .count();
+
+    Some(differing)
+}
+
+/// Returns all `*.xml` files in the directory, sorted by name
+pub fn load_reftests(directory: &Path) -> Result<Vec<ReftestInput>, ReftestError> {
+
+    let io_error = |e: std::io::Error| ReftestError::Io(directory.to_path_buf(), e.to_string());
+
+    let mut tests = Vec::new();
+    for entry in std::fs::read_dir(directory).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("xml") {
+            continue;
+        }
+        let name = match path.file_stem().and_then(|s| s.to_str()) {
+            Some(s) => s.to_string(),
+            None => continue,
+        };
+        let source = std::fs::read_to_string(&path)
+            .map_err(|e| ReftestError::Io(path.clone(), e.to_string()))?;
+        tests.push(ReftestInput { name, path, source });
+    }
+
+    tests.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(tests)
+}
+
+/// Renders a single test in a single configuration and compares it against the reference
+pub fn run_reftest(
+    test: &ReftestInput,
+    render_config: &RenderConfig,
+    renderer: &dyn ReftestRenderer,
+    reference_provider: &dyn ReferenceProvider,
+    config: &ReftestConfig,
+) -> ReftestResult {
+
+    let outcome = match (
+        renderer.render(test, render_config),
+        reference_provider.get_reference(test, render_config),
+    ) {
+        (Err(e), _) | (_, Err(e)) => ReftestOutcome::Error(e),
+        (Ok(rendered), Ok(reference)) => {
+            let threshold = config.get_threshold(&test.name);
+            let result = compare_images(&rendered, &reference, &threshold);
+            let outcome = match result.as_ref() {
+                None => ReftestOutcome::SizeMismatch {
+                    rendered: (rendered.width, rendered.height),
+                    reference: (reference.width, reference.height),
+                },
+                Some(r) if r.is_within(&threshold) => ReftestOutcome::Passed {
+                    differing_pixels: r.differing_pixels,
+                    ssim: r.ssim,
+                },
+                Some(r) => ReftestOutcome::Failed {
+                    differing_pixels: r.differing_pixels,
+                    ssim: r.ssim,
+                },
+            };
+
+            if let Some(output_directory) = config.output_directory.as_ref() {
+                if !outcome.is_pass() {
+                    let prefix = format!("{}-{}", test.name, render_config.file_suffix());
+                    let _ = rendered.save(&output_directory.join(format!("{}-rendered.png", prefix)));
+                    let _ = reference.save(&output_directory.join(format!("{}-{}.png", prefix, reference_provider.name())));
+                    if let Some(r) = result.as_ref() {
+                        let _ = r.diff_image.save(&output_directory.join(format!("{}-diff.png", prefix)));
+                    }
+                }
+            }
+
+            outcome
+        }
+    };
+
+    ReftestResult {
+        test_name: test.name.clone(),
+        config: *render_config,
+        outcome,
+        expected_fail: false,
+    }
+}
+
+/// Results of a reftest run, grouped by status, so that CI can fail on
+/// regressions only while unimplemented features are marked as `expected-fail`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ReftestSummary {
+    pub passed: Vec<ReftestResult>,
+    pub regressions: Vec<ReftestResult>,
+    pub expected_failures: Vec<ReftestResult>,
+    pub newly_passing: Vec<ReftestResult>,
+    pub skipped: Vec<ReftestResult>,
+}
+
+impl ReftestSummary {
+
+    pub fn new(results: &[ReftestResult]) -> Self {
+        let mut summary = Self::default();
+        for result in results {
+            let list = match result.status() {
+                ReftestStatus::Pass => &mut summary.passed,
+                ReftestStatus::Regression => &mut summary.regressions,
+                ReftestStatus::ExpectedFailure => &mut summary.expected_failures,
+                ReftestStatus::NewlyPassing => &mut summary.newly_passing,
+                ReftestStatus::Skipped => &mut summary.skipped,
+            };
+            list.push(result.clone());
+        }
+        summary
+    }
+
+    /// Whether the run should be considered successful (no regressions)
+    pub fn is_success(&self) -> bool {
+        self.regressions.is_empty()
+    }
+}
+
+impl fmt::Display for ReftestSummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.regressions.is_empty() {
+            writeln!(f, "regressions:")?;
+            for r in self.regressions.iter() { writeln!(f, "    {}", r)?; }
+        }
+        if !self.newly_passing.is_empty() {
+            writeln!(f, "newly passing (remove expected-fail from manifest.toml):")?;
+            for r in self.newly_passing.iter() { writeln!(f, "    {}", r)?; }
+        }
+        writeln!(f,
+            "{} passed, {} regressions, {} expected failures, {} newly passing, {} skipped",
+            self.passed.len(), self.regressions.len(), self.expected_failures.len(),
+            self.newly_passing.len(), self.skipped.len(),
+        )
+    }
+}
+
+/// Runs all `*.xml` tests in `test_directory` at all sizes and DPI factors of the `config`.
+///
+/// If the directory contains a `manifest.toml`, its thresholds are applied to the config,
+/// tests are skipped / filtered by tag and marked as expected failures according to the manifest.
+/// Use `ReftestSummary::new` to group the results into regressions and newly passing tests.
+pub fn run_reftests(
+    test_directory: &Path,
+    renderer: &dyn ReftestRenderer,
+    reference_provider: &dyn ReferenceProvider,
+    config: &ReftestConfig,
+) -> Result<Vec<ReftestResult>, ReftestError> {
+
+    if let Some(output_directory) = config.output_directory.as_ref() {
+        std::fs::create_dir_all(output_directory)
+            .map_err(|e| ReftestError::Io(output_directory.clone(), e.to_string()))?;
+    }
+
+    let manifest = ReftestManifest::load(test_directory)?;
+    let config = &manifest.apply_to_config(config);
+    let tests = load_reftests(test_directory)?;
+    let render_configs = config.get_render_configs();
+
+    let results = tests.iter().filter(|test| {
+        config.tags.is_empty() ||
+        manifest.get_entry(&test.name).tags.iter().any(|t| config.tags.contains(t))
+    }).flat_map(|test| {
+        let entry = manifest.get_entry(&test.name);
+        render_configs.iter().map(move |render_config| {
+            if entry.skip.is_skipped_on(&config.platform) {
+                ReftestResult {
+                    test_name: test.name.clone(),
+                    config: *render_config,
+                    outcome: ReftestOutcome::Skipped,
+                    expected_fail: entry.expected_fail,
+                }
+            } else {
+                let mut result = run_reftest(test, render_config, renderer, reference_provider, config);
+                result.expected_fail = entry.expected_fail;
+                result
+            }
+        })
+    }).collect();
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn solid(width: u32, height: u32, color: [u8; 4]) -> ReftestImage {
+        ReftestImage {
+            width,
+            height,
+            pixels: color.iter().copied().cycle().take((width * height * 4) as usize).collect(),
+        }
+    }
+
+    #[test]
+    fn test_count_differing_pixels() {
+        let a = solid(10, 10, [255, 0, 0, 255]);
+        let mut b = a.clone();
+        b.pixels[0] = 250;
+        b.pixels[4] = 200;
+        assert_eq!(count_differing_pixels(&a, &a, 0), Some(0));
+        assert_eq!(count_differing_pixels(&a, &b, 0), Some(2));
+        assert_eq!(count_differing_pixels(&a, &b, 10), Some(1));
+        assert_eq!(count_differing_pixels(&a, &solid(5, 5, [0; 4]), 0), None);
+    }
+
+    #[test]
+    fn test_render_config_matrix() {
+        let config = ReftestConfig {
+            sizes: vec![(600, 100), (800, 1200)],
+            scale_factors: vec![1.0, 2.0],
+            .. ReftestConfig::default()
+        };
+        let configs = config.get_render_configs();
+        assert_eq!(configs.len(), 4);
+        assert_eq!(configs[1].physical_size(), (1200, 200));
+        assert_eq!(configs[1].file_suffix(), "600x100@2x");
+    }
+
+    #[test]
+    fn test_summary_status() {
+        let result = |outcome, expected_fail| ReftestResult {
+            test_name: String::new(),
+            config: RenderConfig { width: 100, height: 100, scale_factor: 1.0 },
+            outcome,
+            expected_fail,
+        };
+        let pass = || ReftestOutcome::Passed { differing_pixels: 0, ssim: 1.0 };
+        let fail = || ReftestOutcome::Failed { differing_pixels: 5000, ssim: 0.5 };
+
+        let summary = ReftestSummary::new(&[
+            result(pass(), false),
+            result(fail(), false),
+            result(fail(), true),
+            result(pass(), true),
+            result(ReftestOutcome::Skipped, true),
+        ]);
+
+        assert_eq!(summary.passed.len(), 1);
+        assert_eq!(summary.regressions.len(), 1);
+        assert_eq!(summary.expected_failures.len(), 1);
+        assert_eq!(summary.newly_passing.len(), 1);
+        assert_eq!(summary.skipped.len(), 1);
+        assert!(!summary.is_success());
+    }
+}
@@ -0,0 +1,190 @@
+//! Parser for the subset of TOML that is used by the reftest `manifest.toml`
+//! and the `azul.toml` configuration file of azul-desktop
+//!
+//! Supported are `[section]` headers (optionally quoted), `# comments` and
+//! `key = value` pairs, where the value is a string (with the `\"`, `\\`, `\n`,
+//! `\t` and `\r` escapes), a number, a boolean or an array of strings.
+//! Multi-line values, inline tables and nested sections are not supported.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TomlValue {
+    String(String),
+    Number(f64),
+    Bool(bool),
+    Array(Vec<String>),
+}
+
+impl TomlValue {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            TomlValue::String(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// Non-empty line of a TOML file
+#[derive(Debug, Clone, PartialEq)]
+pub enum TomlLine {
+    /// `[section]` or `["section"]`
+    Section(String),
+    /// `key = value`, the key is unquoted
+    KeyValue(String, TomlValue),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TomlError {
+    InvalidSectionHeader,
+    ExpectedKeyValue,
+    InvalidValue,
+}
+
+impl fmt::Display for TomlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::TomlError::*;
+        match self {
+            InvalidSectionHeader => write!(f, "invalid section header"),
+            ExpectedKeyValue => write!(f, "expected key = value"),
+            InvalidValue => write!(f, "invalid value"),
+        }
+    }
+}
+
+/// Parses the source line by line, skipping empty and comment-only lines.
+///
+/// Yields the (1-based) line number, the line without the comment and the parsed line,
+/// so that the caller can decide whether to abort or to skip invalid lines.
+pub fn parse_lines<'a>(source: &'a str) -> impl Iterator<Item = (usize, &'a str, Result<TomlLine, TomlError>)> + 'a {
+    source.lines().enumerate().filter_map(|(line_number, line)| {
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            None
+        } else {
+            Some((line_number + 1, line, parse_line(line)))
+        }
+    })
+}
+
+/// Parses a single line that has already been stripped of comments
+pub fn parse_line(line: &str) -> Result<TomlLine, TomlError> {
+
+    if line.starts_with('[') {
+        let section = line.strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or(TomlError::InvalidSectionHeader)?
+            .trim();
+        return parse_key(section).map(TomlLine::Section).ok_or(TomlError::InvalidSectionHeader);
+    }
+
+    let (key, value) = split_key_value(line).ok_or(TomlError::ExpectedKeyValue)?;
+    let key = parse_key(key.trim()).ok_or(TomlError::ExpectedKeyValue)?;
+    let value = parse_value(value.trim()).ok_or(TomlError::InvalidValue)?;
+
+    Ok(TomlLine::KeyValue(key, value))
+}
+
+/// Removes a trailing `# comment` (that is not part of a string) from the line
+pub fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => { escaped = false; },
+            '\\' if in_string => { escaped = true; },
+            '"' => { in_string = !in_string; },
+            '#' if !in_string => { return &line[..i]; },
+            _ => { },
+        }
+    }
+    line
+}
+
+/// Parses a `true`, `false`, number, `"string"` or `["string", ...]` value
+pub fn parse_value(value: &str) -> Option<TomlValue> {
+
+    match value {
+        "true" => return Some(TomlValue::Bool(true)),
+        "false" => return Some(TomlValue::Bool(false)),
+        _ => { },
+    }
+
+    if value.starts_with('"') {
+        return match parse_string(value)? {
+            (s, "") => Some(TomlValue::String(s)),
+            _ => None,
+        };
+    }
+
+    if value.starts_with('[') {
+        let mut items = Vec::new();
+        let mut rest = value[1..].trim_start();
+        loop {
+            if let Some(r) = rest.strip_prefix(']') {
+                return if r.trim().is_empty() { Some(TomlValue::Array(items)) } else { None };
+            }
+            let (item, r) = parse_string(rest)?;
+            items.push(item);
+            let r = r.trim_start();
+            rest = match r.strip_prefix(',') {
+                Some(r) => r.trim_start(),
+                None if r.starts_with(']') => r,
+                None => return None,
+            };
+        }
+    }
+
+    value.replace('_', "").parse::<f64>().ok().map(TomlValue::Number)
+}
+
+/// Bare keys are returned as-is, quoted keys are unescaped
+fn parse_key(key: &str) -> Option<String> {
+    if key.starts_with('"') {
+        match parse_string(key)? {
+            (s, "") => Some(s),
+            _ => None,
+        }
+    } else if key.is_empty() {
+        None
+    } else {
+        Some(key.into())
+    }
+}
+
+/// Splits the line at the first `=` that is not part of a quoted key
+fn split_key_value(line: &str) -> Option<(&str, &str)> {
+    let key_end = if line.starts_with('"') {
+        let (_, rest) = parse_string(line)?;
+        line.len() - rest.len()
+    } else {
+        0
+    };
+    let pos = key_end + line[key_end..].find('=')?;
+    Some((&line[..pos], &line[pos + 1..]))
+}
+
+/// Parses a `"string"` at the start of `s`, returns the unescaped
+/// string and the remaining input after the closing quote
+fn parse_string(s: &str) -> Option<(String, &str)> {
+    let s = s.strip_prefix('"')?;
+    let mut out = String::new();
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((out, &s[i + 1..])),
+            '\\' => out.push(match chars.next()?.1 {
+                '"' => '"',
+                '\\' => '\\',
+                'n' => '\n',
+                't' => '\t',
+                'r' => '\r',
+                _ => return None,
+            }),
+            c => out.push(c),
+        }
+    }
+    None
+}
@@ -110,7 +110,13 @@ pub fn parse_xml_string(xml: &str) -> Result<XmlNodeVec, XmlError> {
 
         match token {
             ElementStart { local, .. } => {
-                if let Some(current_parent) = get_item(&current_hierarchy, &mut root_node) {
+                // top-level elements are children of the (virtual) root node
+                let current_parent = if current_hierarchy.is_empty() {
+                    Some(&mut root_node)
+                } else {
+                    get_item(&current_hierarchy, &mut root_node)
+                };
+                if let Some(current_parent) = current_parent {
                     let children_len = current_parent.children.as_ref().len();
                     current_parent.children.push(XmlNode {
                         node_type: normalize_casing(local.as_str()).into(),
@@ -158,6 +164,23 @@ pub fn parse_xml_string(xml: &str) -> Result<XmlNodeVec, XmlError> {
     Ok(root_node.children)
 }
 
+/// Entry point for fuzzing (see the `fuzz` directory in the repository root):
+/// parses the input as XML and renders it to a `StyledDom`, returns
+/// `FuzzError::Panic` if the parser panics (requires `panic = "unwind"`)
+#[doc(hidden)]
+#[cfg(all(feature = "xml", feature = "std"))]
+pub fn fuzz_parse_xml(data: &[u8]) -> Result<(), azul_css_parser::fuzz::FuzzError> {
+    use azul_css_parser::fuzz::{catch_panic, FuzzError};
+    let input = core::str::from_utf8(data).map_err(|_| FuzzError::InvalidUtf8)?;
+    catch_panic(|| {
+        let root_nodes = parse_xml_string(input).map_err(|e| FuzzError::Parse(format!("{:?}", e)))?;
+        let mut component_map = XmlComponentMap::default();
+        str_to_dom(root_nodes.as_ref(), &mut component_map)
+            .map(|_| ())
+            .map_err(|e| FuzzError::Parse(format!("{}", e)))
+    })
+}
+
 #[cfg(feature = "xml")]
 pub fn parse_xml(s: &str) -> Result<Xml, XmlError> {
     Ok(Xml { root: parse_xml_string(s)? })
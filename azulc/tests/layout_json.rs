@@ -0,0 +1,37 @@
+//! Runs `azulc --layout-json` on the XML files in `tests/layout_json` and compares
+//! the output against the golden `.json` file next to each XML file
+
+extern crate azulc_lib;
+
+use azulc_lib::layout_snapshot::LayoutSnapshot;
+use std::path::Path;
+use std::process::Command;
+
+fn check_layout_json(name: &str, size: &str) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("layout_json");
+    let output = Command::new(env!("CARGO_BIN_EXE_azulc"))
+        .arg("--layout-json")
+        .arg(size)
+        .arg(dir.join(format!("{}.xml", name)))
+        .output()
+        .expect("could not run azulc");
+
+    assert!(output.status.success(), "azulc failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let actual = LayoutSnapshot::from_json(&String::from_utf8(output.stdout).unwrap()).unwrap();
+    let golden = std::fs::read_to_string(dir.join(format!("{}.json", name))).unwrap();
+    let golden = LayoutSnapshot::from_json(&golden).unwrap();
+
+    let differences = actual.compare(&golden, 0.5);
+    assert!(
+        differences.is_empty(),
+        "{}:\r\n{}",
+        name,
+        differences.iter().map(|d| d.to_string()).collect::<Vec<_>>().join("\r\n")
+    );
+}
+
+#[test]
+fn test_layout_json_basic_sizes() {
+    check_layout_json("basic-sizes", "600x100");
+}
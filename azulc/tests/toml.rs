@@ -0,0 +1,52 @@
+//! Tests for the TOML subset parser that is shared by the reftest
+//! manifest and the `azul.toml` configuration file
+
+extern crate azulc_lib;
+
+use azulc_lib::toml::{parse_lines, parse_value, strip_comment, TomlError, TomlLine, TomlValue};
+
+#[test]
+fn test_strip_comment() {
+    assert_eq!(strip_comment("level = \"debug\" # comment"), "level = \"debug\" ");
+    assert_eq!(strip_comment("backend = \"#hardware\""), "backend = \"#hardware\"");
+    assert_eq!(strip_comment("name = \"a \\\" # b\" # comment"), "name = \"a \\\" # b\" ");
+    assert_eq!(strip_comment("# comment"), "");
+}
+
+#[test]
+fn test_parse_values() {
+    assert_eq!(parse_value("true"), Some(TomlValue::Bool(true)));
+    assert_eq!(parse_value("1_000"), Some(TomlValue::Number(1000.0)));
+    assert_eq!(parse_value("-0.5"), Some(TomlValue::Number(-0.5)));
+    assert_eq!(parse_value("\"a \\\"b\\\" \\\\ c\""), Some(TomlValue::String("a \"b\" \\ c".into())));
+    assert_eq!(
+        parse_value("[\"a, b\", \"c\\\"\",]"),
+        Some(TomlValue::Array(vec!["a, b".into(), "c\"".into()]))
+    );
+    assert_eq!(parse_value("[]"), Some(TomlValue::Array(Vec::new())));
+    assert_eq!(parse_value("\"unterminated"), None);
+    assert_eq!(parse_value("\"a\" \"b\""), None);
+    assert_eq!(parse_value("\"\\x\""), None);
+    assert_eq!(parse_value("[1, 2]"), None);
+    assert_eq!(parse_value("abc"), None);
+}
+
+#[test]
+fn test_parse_lines() {
+    let lines = parse_lines("
+        # comment
+        [renderer] # comment
+        [\"002-absolute-positioning\"]
+        \"quoted = key\" = \"value # not a comment\"
+        backend
+        [display
+    ").map(|(line, _, result)| (line, result)).collect::<Vec<_>>();
+
+    assert_eq!(lines, vec![
+        (3, Ok(TomlLine::Section("renderer".into()))),
+        (4, Ok(TomlLine::Section("002-absolute-positioning".into()))),
+        (5, Ok(TomlLine::KeyValue("quoted = key".into(), TomlValue::String("value # not a comment".into())))),
+        (6, Err(TomlError::ExpectedKeyValue)),
+        (7, Err(TomlError::InvalidSectionHeader)),
+    ]);
+}
@@ -142,9 +142,7 @@ def get_all_imports(apiData, module, module_name):
 
     for arg in arg_types_to_search:
 
-        arg = arg.replace("*const", "")
-        arg = arg.replace("*mut", "")
-        arg = arg.strip()
+        arg = analyze_type(arg)[1]
 
         if arg in basic_types:
             continue
@@ -395,7 +393,7 @@ def rust_bindings_fn_args(f, class_name, class_ptr_name, self_as_first_arg, api_
                 if arg_type_class_name is None:
                     raise Exception("arg type " + arg_type + " not found!")
                 arg_type_class = get_class(api_data, arg_type_class_name[0], arg_type_class_name[1])
-                if class_is_typedef(arg_type_class):
+                if class_is_typedef(arg_type_class) or start == "&mut " or start == "&":
                     fn_args += arg_name + ": " + start + arg_type_class_name[1] + ", "
                 elif start == "*const " or start == "*mut ":
                     fn_args += arg_name + ": _" + str(generic_counter) + ", "
@@ -453,6 +451,9 @@ def rust_bindings_call_fn_args(f, class_name, class_ptr_name, self_as_first_arg,
                         fn_args += "unsafe { core::mem::transmute(" + arg_name + ".into()) }, "
                     else:
                         fn_args += arg_name + self_ext + ".into(), "
+                elif start == "&mut " or start == "&":
+                    # references are passed through as-is
+                    fn_args += arg_name + self_ext + ", "
                 else:
                     if class_is_typedef(arg_type_class):
                         fn_args += start + arg_name + self_ext + ", "
@@ -1288,7 +1289,9 @@ def generate_python_api(api_data, structs_map, functions_map):
     manual_implementations = [
 
         ("app", "App", "new"), # ok: replaced
+        ("app", "App", "run"), # ok: replaced
         ("window", "WindowCreateOptions", "new"), # ok: replaced
+        ("window", "WindowCreateOptions", "with_window_data"), # ok: replaced
         ("window", "WindowState", "new"), # ok: replaced
 
         ("dom", "Dom", "iframe"), # ok: replaced
@@ -1315,9 +1318,20 @@ def generate_python_api(api_data, structs_map, functions_map):
         ("svg", "TessellatedColoredGPUSvgNode", "new"),
 
         ("task", "Timer", "new"),
+        ("task", "EventLoopProxy", "send_user_event"),
         ("callbacks", "CallbackInfo", "start_thread"),
+        ("callbacks", "CallbackInfo", "request_animation_frame"),
         ("callbacks", "CallbackInfo", "get_node_id_of_root_dataset"),
+        ("callbacks", "CallbackInfo", "set_window_data"),
         ("image", "ImageRef", "callback"),
+        ("dom", "Dom", "custom_layout"),
+        ("dom", "NodeData", "custom_layout"),
+        ("dom", "Dom", "custom_node"),
+        ("dom", "CustomNodeType", "new"),
+        ("dom", "CustomNodeType", "with_paint"),
+        ("dom", "Dom", "iframe_with_reinvoke_threshold"),
+        ("dom", "NodeData", "iframe_with_reinvoke_threshold"),
+        ("menu", "Shortcut", "new"),
 
         ("widgets", "FileInput", "set_on_path_change"),
         ("widgets", "FileInput", "with_on_path_change"),
@@ -1331,6 +1345,8 @@ def generate_python_api(api_data, structs_map, functions_map):
         ("widgets", "TextInput", "with_on_virtual_key_down"),
         ("widgets", "TextInput", "set_on_focus_lost"),
         ("widgets", "TextInput", "with_on_focus_lost"),
+        ("widgets", "TextInput", "set_on_spelling_suggestions"),
+        ("widgets", "TextInput", "with_on_spelling_suggestions"),
         ("widgets", "NumberInput", "set_on_value_change"),
         ("widgets", "NumberInput", "with_on_value_change"),
         ("widgets", "NumberInput", "with_on_text_input"),
@@ -1342,6 +1358,10 @@ def generate_python_api(api_data, structs_map, functions_map):
         ("widgets", "TabHeader", "with_on_click"),
         ("widgets", "TabHeader", "set_on_click"),
         ("widgets", "Ribbon", "dom"),
+        ("widgets", "Toasts", "show"),
+        ("widgets", "Toasts", "dismiss"),
+        ("widgets", "Toast", "set_action"),
+        ("widgets", "Toast", "with_action"),
 
         # unnecessary due to Python string wrappers
         ("str", "String", "as_refstr"),
@@ -1354,12 +1374,16 @@ def generate_python_api(api_data, structs_map, functions_map):
 
     inject_impls = {
         ("app", "App"): read_file(root_folder + "/api/_patches/python/app.rs"),
+        ("app", "CrashHandler"): read_file(root_folder + "/api/_patches/python/crash_handler.rs"),
         ("dom", "Dom"): read_file(root_folder + "/api/_patches/python/dom.rs"),
         ("dom", "NodeData"): read_file(root_folder + "/api/_patches/python/nodedata.rs"),
         ("widgets", "Button"): read_file(root_folder + "/api/_patches/python/button.rs"),
         ("callbacks", "LayoutCallback"): read_file(root_folder + "/api/_patches/python/layout_callback.rs"),
         ("window", "WindowCreateOptions"): read_file(root_folder + "/api/_patches/python/window_create_options.rs"),
         ("window", "WindowState"): read_file(root_folder + "/api/_patches/python/window_state.rs"),
+        ("task", "GetSystemIdleTimeFn"): read_file(root_folder + "/api/_patches/python/get_system_idle_time_fn.rs"),
+        ("task", "GetLogLinesFn"): read_file(root_folder + "/api/_patches/python/get_log_lines_fn.rs"),
+        ("task", "SetLogLevelFn"): read_file(root_folder + "/api/_patches/python/set_log_level_fn.rs"),
     }
 
     staticmethods = [
@@ -1388,6 +1412,7 @@ def generate_python_api(api_data, structs_map, functions_map):
         "Gl": {},
         "ThreadSender": {},
         "ThreadReceiver": {},
+        "EventLoopProxy": {},
         "Thread": {},
         "TesselatedSvgNodeVec": {},
         "U8Vec": {},
@@ -1410,6 +1435,8 @@ def generate_python_api(api_data, structs_map, functions_map):
         "LayoutCallbackInner": {},
         "Callback": {},
         "IFrameCallback": {},
+        "MeasureCallback": {},
+        "ArrangeCallback": {},
         "RenderImageCallback": {},
         "TimerCallback": {},
         "WriteBackCallback": {},
@@ -1437,6 +1464,10 @@ def generate_python_api(api_data, structs_map, functions_map):
         "InstantPtrDestructorFn": {},
         "CreateThreadFn": {},
         "GetSystemTimeFn": {},
+        "GetSystemIdleTimeFn": {},
+        "GetLogLinesFn": {},
+        "SetLogLevelFn": {},
+        "CrashHandler": {},
         "CheckThreadFinishedFn": {},
         "LibrarySendThreadMsgFn": {},
         "LibraryReceiveThreadMsgFn": {},
@@ -1452,6 +1483,7 @@ def generate_python_api(api_data, structs_map, functions_map):
         "TextInputOnFocusLostCallback": {},
         "NumberInputOnValueChangeCallback": {},
         "CheckBoxOnToggleCallback": {},
+        "ToastOnActionCallback": {},
     }
 
     python_replacements = {
@@ -1812,9 +1844,7 @@ def generate_python_api(api_data, structs_map, functions_map):
                     pyo3_code += "    fn r#match(&self) -> PyResult<Vec<PyObject>> {\r\n"
                     pyo3_code += "        use crate::python::" + prefix + class_name + ";\r\n"
                     pyo3_code += "        use pyo3::conversion::IntoPy;\r\n"
-                    pyo3_code += "        let gil = Python::acquire_gil();\r\n"
-                    pyo3_code += "        let py = gil.python();\r\n"
-                    pyo3_code += "        match &self.inner {\r\n"
+                    pyo3_code += "        Python::with_gil(|py| match &self.inner {\r\n"
                     for enum_name in struct["enum_fields"]:
                         variant_name = list(enum_name.keys())[0]
                         variant = enum_name[variant_name]
@@ -1841,7 +1871,7 @@ def generate_python_api(api_data, structs_map, functions_map):
                                         opt_variant_value = "v"
 
                         pyo3_code += "            " + prefix + class_name + "::" + variant_name + opt_variant_type_match + " => Ok(vec![\"" + variant_name + "\".into_py(py), " + opt_variant_value + ".into_py(py)]),\r\n"
-                    pyo3_code += "        }\r\n"
+                    pyo3_code += "        })\r\n"
                     pyo3_code += "    }\r\n"
 
                 pyo3_code += "}\r\n"
@@ -2010,7 +2040,9 @@ def format_py_return(python_replacements, return_type, api_data, errlist, constr
         found_c = quick_get_class(api_data, return_type["type"])
         ret_type = found_c["enum_fields"][1]["Some"]["type"]
         return_type_opt = ret_type
-        if not(ret_type in python_replacements.keys()):
+        if is_primitive_arg(ret_type):
+            pass
+        elif not(ret_type in python_replacements.keys()):
             return_type_opt = prefix + ret_type
             ret_type_c = quick_get_class(api_data, ret_type)
             if "enum_fields" in ret_type_c.keys():
@@ -2779,6 +2811,8 @@ def replace_primitive_ctype(input):
     switcher = {
         "*const": "* ", # TODO: figure out proper c semantics - the VALUE is const, not the POINTER!
         "*mut": "* restrict ",
+        "&": "* ",
+        "&mut": "* restrict ",
         "i8": "int8_t",
         "u8": "uint8_t",
         "i16": "int16_t",
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // parse errors are expected, panics are bugs
+    if let Err(e) = azul_css_parser::fuzz::fuzz_parse_css_property(data) {
+        assert!(!e.is_panic(), "{:?}", e);
+    }
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // parse errors are expected, panics are bugs
+    if let Err(e) = azulc_lib::xml::fuzz_parse_xml(data) {
+        assert!(!e.is_panic(), "{:?}", e);
+    }
+});